@@ -19,12 +19,14 @@ impl Hash for GenIndex {
     }
 }
 
+#[derive(Clone)]
 struct Slot<T> {
     generation: u32,
     value: Option<T>,
     next_free: Option<u32>,
 }
 
+#[derive(Clone)]
 pub struct Arena<T> {
     slots: Vec<Slot<T>>,
     free_head: Option<u32>,
@@ -2,46 +2,12 @@
 ///
 /// Provides key schedule expansion, encryption, and decryption of single 16-byte blocks.
 /// Used as the underlying primitive for AES-GCM.
-
-/// AES forward S-box (SubBytes substitution table).
-pub const SBOX: [u8; 256] = [
-    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
-    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
-    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
-    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
-    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
-    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
-    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
-    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
-    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
-    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
-    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
-    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
-    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
-    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
-    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
-    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
-];
-
-/// AES inverse S-box (InvSubBytes substitution table).
-pub const INV_SBOX: [u8; 256] = [
-    0x52, 0x09, 0x6a, 0xd5, 0x30, 0x36, 0xa5, 0x38, 0xbf, 0x40, 0xa3, 0x9e, 0x81, 0xf3, 0xd7, 0xfb,
-    0x7c, 0xe3, 0x39, 0x82, 0x9b, 0x2f, 0xff, 0x87, 0x34, 0x8e, 0x43, 0x44, 0xc4, 0xde, 0xe9, 0xcb,
-    0x54, 0x7b, 0x94, 0x32, 0xa6, 0xc2, 0x23, 0x3d, 0xee, 0x4c, 0x95, 0x0b, 0x42, 0xfa, 0xc3, 0x4e,
-    0x08, 0x2e, 0xa1, 0x66, 0x28, 0xd9, 0x24, 0xb2, 0x76, 0x5b, 0xa2, 0x49, 0x6d, 0x8b, 0xd1, 0x25,
-    0x72, 0xf8, 0xf6, 0x64, 0x86, 0x68, 0x98, 0x16, 0xd4, 0xa4, 0x5c, 0xcc, 0x5d, 0x65, 0xb6, 0x92,
-    0x6c, 0x70, 0x48, 0x50, 0xfd, 0xed, 0xb9, 0xda, 0x5e, 0x15, 0x46, 0x57, 0xa7, 0x8d, 0x9d, 0x84,
-    0x90, 0xd8, 0xab, 0x00, 0x8c, 0xbc, 0xd3, 0x0a, 0xf7, 0xe4, 0x58, 0x05, 0xb8, 0xb3, 0x45, 0x06,
-    0xd0, 0x2c, 0x1e, 0x8f, 0xca, 0x3f, 0x0f, 0x02, 0xc1, 0xaf, 0xbd, 0x03, 0x01, 0x13, 0x8a, 0x6b,
-    0x3a, 0x91, 0x11, 0x41, 0x4f, 0x67, 0xdc, 0xea, 0x97, 0xf2, 0xcf, 0xce, 0xf0, 0xb4, 0xe6, 0x73,
-    0x96, 0xac, 0x74, 0x22, 0xe7, 0xad, 0x35, 0x85, 0xe2, 0xf9, 0x37, 0xe8, 0x1c, 0x75, 0xdf, 0x6e,
-    0x47, 0xf1, 0x1a, 0x71, 0x1d, 0x29, 0xc5, 0x89, 0x6f, 0xb7, 0x62, 0x0e, 0xaa, 0x18, 0xbe, 0x1b,
-    0xfc, 0x56, 0x3e, 0x4b, 0xc6, 0xd2, 0x79, 0x20, 0x9a, 0xdb, 0xc0, 0xfe, 0x78, 0xcd, 0x5a, 0xf4,
-    0x1f, 0xdd, 0xa8, 0x33, 0x88, 0x07, 0xc7, 0x31, 0xb1, 0x12, 0x10, 0x59, 0x27, 0x80, 0xec, 0x5f,
-    0x60, 0x51, 0x7f, 0xa9, 0x19, 0xb5, 0x4a, 0x0d, 0x2d, 0xe5, 0x7a, 0x9f, 0x93, 0xc9, 0x9c, 0xef,
-    0xa0, 0xe0, 0x3b, 0x4d, 0xae, 0x2a, 0xf5, 0xb0, 0xc8, 0xeb, 0xbb, 0x3c, 0x83, 0x53, 0x99, 0x61,
-    0x17, 0x2b, 0x04, 0x7e, 0xba, 0x77, 0xd6, 0x26, 0xe1, 0x69, 0x14, 0x63, 0x55, 0x21, 0x0c, 0x7d,
-];
+///
+/// The implementation is bitsliced (after BearSSL's `aes_ct`): two blocks are
+/// spread across eight 32-bit words, one bit of each byte per word, and the
+/// S-box is evaluated as a Boolean circuit (Boyar–Peralta). There are no
+/// secret-dependent table lookups or branches, so the key does not leak
+/// through cache timing.
 
 /// AES round constants for key schedule.
 pub const RCON: [u8; 11] = [
@@ -52,8 +18,10 @@ pub const RCON: [u8; 11] = [
 pub struct AesKeySchedule {
     /// Number of rounds: 10 for AES-128, 14 for AES-256.
     pub nr: usize,
-    /// Expanded round key words. Up to 60 words for AES-256.
+    /// Expanded round key words (FIPS 197 order). Up to 60 words for AES-256.
     pub round_keys: [u32; 60],
+    /// The round keys in bitsliced form, eight words per round.
+    bitsliced: [u32; 120],
 }
 
 impl AesKeySchedule {
@@ -97,33 +65,39 @@ impl AesKeySchedule {
             w[i] = w[i - nk] ^ temp;
         }
 
+        // Bitslice each round key, duplicated for both block lanes.
+        let mut bitsliced = [0u32; 120];
+        for round in 0..=nr {
+            let q = &mut bitsliced[round * 8..round * 8 + 8];
+            for col in 0..4 {
+                let word = w[round * 4 + col].swap_bytes();
+                q[col * 2] = word;
+                q[col * 2 + 1] = word;
+            }
+            ortho(q);
+        }
+
         Ok(Self {
             nr,
             round_keys: w,
+            bitsliced,
         })
     }
 
-    /// Get the round key for a specific round as 4 words.
+    /// Get the bitsliced round key for a specific round.
     #[inline]
-    fn round_key(&self, round: usize) -> [u32; 4] {
-        let base = round * 4;
-        [
-            self.round_keys[base],
-            self.round_keys[base + 1],
-            self.round_keys[base + 2],
-            self.round_keys[base + 3],
-        ]
+    fn round_key(&self, round: usize) -> &[u32] {
+        &self.bitsliced[round * 8..round * 8 + 8]
     }
 }
 
-/// Apply S-box to each byte of a 32-bit word.
-#[inline]
+/// Apply the S-box to each byte of a 32-bit word.
 fn sub_word(w: u32) -> u32 {
-    let b0 = SBOX[((w >> 24) & 0xff) as usize] as u32;
-    let b1 = SBOX[((w >> 16) & 0xff) as usize] as u32;
-    let b2 = SBOX[((w >> 8) & 0xff) as usize] as u32;
-    let b3 = SBOX[(w & 0xff) as usize] as u32;
-    (b0 << 24) | (b1 << 16) | (b2 << 8) | b3
+    let mut q = [w; 8];
+    ortho(&mut q);
+    bitslice_sbox(&mut q);
+    ortho(&mut q);
+    q[0]
 }
 
 /// Rotate a 32-bit word left by 8 bits: [a0,a1,a2,a3] -> [a1,a2,a3,a0].
@@ -132,207 +106,339 @@ fn rot_word(w: u32) -> u32 {
     (w << 8) | (w >> 24)
 }
 
-/// Convert a 16-byte block to a 4×4 state matrix (column-major, stored as [u32; 4]).
-/// Each u32 is a column: state[col] = [row0, row1, row2, row3] packed big-endian.
+// ─────────────────────────────────────────────────────────────────────────────
+// Bitsliced representation
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Swap bit groups between `x` and `y`: the bits of `x` selected by `hi`
+/// trade places with the bits of `y` selected by `lo`.
 #[inline]
-fn block_to_state(block: &[u8; 16]) -> [u32; 4] {
-    [
-        u32::from_be_bytes([block[0], block[1], block[2], block[3]]),
-        u32::from_be_bytes([block[4], block[5], block[6], block[7]]),
-        u32::from_be_bytes([block[8], block[9], block[10], block[11]]),
-        u32::from_be_bytes([block[12], block[13], block[14], block[15]]),
-    ]
+fn swap_bits(q: &mut [u32], i: usize, j: usize, lo: u32, hi: u32, shift: u32) {
+    let (a, b) = (q[i], q[j]);
+    q[i] = (a & lo) | ((b & lo) << shift);
+    q[j] = ((a & hi) >> shift) | (b & hi);
 }
 
-/// Convert state matrix back to a 16-byte block.
-#[inline]
-fn state_to_block(state: &[u32; 4], block: &mut [u8; 16]) {
-    for col in 0..4 {
-        let bytes = state[col].to_be_bytes();
-        block[col * 4] = bytes[0];
-        block[col * 4 + 1] = bytes[1];
-        block[col * 4 + 2] = bytes[2];
-        block[col * 4 + 3] = bytes[3];
+/// Transpose between eight little-endian words holding two interleaved
+/// blocks and the bitsliced form (word `i` holds bit `i` of every byte).
+/// The transform is an involution.
+fn ortho(q: &mut [u32]) {
+    for i in (0..8).step_by(2) {
+        swap_bits(q, i, i + 1, 0x5555_5555, 0xAAAA_AAAA, 1);
+    }
+    for i in [0, 1, 4, 5] {
+        swap_bits(q, i, i + 2, 0x3333_3333, 0xCCCC_CCCC, 2);
+    }
+    for i in 0..4 {
+        swap_bits(q, i, i + 4, 0x0F0F_0F0F, 0xF0F0_F0F0, 4);
     }
 }
 
-/// SubBytes: apply S-box to each byte of the state.
-#[inline]
-fn sub_bytes(state: &mut [u32; 4]) {
-    for col in state.iter_mut() {
-        *col = sub_word(*col);
+/// Load two blocks into bitsliced form.
+fn load_blocks(a: &[u8; 16], b: &[u8; 16]) -> [u32; 8] {
+    let mut q = [0u32; 8];
+    for col in 0..4 {
+        q[col * 2] = u32::from_le_bytes([a[col * 4], a[col * 4 + 1], a[col * 4 + 2], a[col * 4 + 3]]);
+        q[col * 2 + 1] =
+            u32::from_le_bytes([b[col * 4], b[col * 4 + 1], b[col * 4 + 2], b[col * 4 + 3]]);
     }
+    ortho(&mut q);
+    q
 }
 
-/// InvSubBytes: apply inverse S-box to each byte of the state.
-#[inline]
-fn inv_sub_bytes(state: &mut [u32; 4]) {
-    for col in state.iter_mut() {
-        let b0 = INV_SBOX[((*col >> 24) & 0xff) as usize] as u32;
-        let b1 = INV_SBOX[((*col >> 16) & 0xff) as usize] as u32;
-        let b2 = INV_SBOX[((*col >> 8) & 0xff) as usize] as u32;
-        let b3 = INV_SBOX[(*col & 0xff) as usize] as u32;
-        *col = (b0 << 24) | (b1 << 16) | (b2 << 8) | b3;
+/// Store bitsliced state back into two blocks.
+fn store_blocks(mut q: [u32; 8], a: &mut [u8; 16], b: &mut [u8; 16]) {
+    ortho(&mut q);
+    for col in 0..4 {
+        a[col * 4..col * 4 + 4].copy_from_slice(&q[col * 2].to_le_bytes());
+        b[col * 4..col * 4 + 4].copy_from_slice(&q[col * 2 + 1].to_le_bytes());
     }
 }
 
-/// Extract byte at position (row) from a column word.
-#[inline]
-fn get_byte(col: u32, row: usize) -> u8 {
-    ((col >> (24 - row * 8)) & 0xff) as u8
+// ─────────────────────────────────────────────────────────────────────────────
+// Round functions
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// SubBytes on bitsliced state: the Boyar–Peralta circuit for the AES
+/// S-box (113 gates).
+fn bitslice_sbox(q: &mut [u32]) {
+    let (x0, x1, x2, x3) = (q[7], q[6], q[5], q[4]);
+    let (x4, x5, x6, x7) = (q[3], q[2], q[1], q[0]);
+
+    // Top linear transformation.
+    let y14 = x3 ^ x5;
+    let y13 = x0 ^ x6;
+    let y9 = x0 ^ x3;
+    let y8 = x0 ^ x5;
+    let t0 = x1 ^ x2;
+    let y1 = t0 ^ x7;
+    let y4 = y1 ^ x3;
+    let y12 = y13 ^ y14;
+    let y2 = y1 ^ x0;
+    let y5 = y1 ^ x6;
+    let y3 = y5 ^ y8;
+    let t1 = x4 ^ y12;
+    let y15 = t1 ^ x5;
+    let y20 = t1 ^ x1;
+    let y6 = y15 ^ x7;
+    let y10 = y15 ^ t0;
+    let y11 = y20 ^ y9;
+    let y7 = x7 ^ y11;
+    let y17 = y10 ^ y11;
+    let y19 = y10 ^ y8;
+    let y16 = t0 ^ y11;
+    let y21 = y13 ^ y16;
+    let y18 = x0 ^ y16;
+
+    // Non-linear section.
+    let t2 = y12 & y15;
+    let t3 = y3 & y6;
+    let t4 = t3 ^ t2;
+    let t5 = y4 & x7;
+    let t6 = t5 ^ t2;
+    let t7 = y13 & y16;
+    let t8 = y5 & y1;
+    let t9 = t8 ^ t7;
+    let t10 = y2 & y7;
+    let t11 = t10 ^ t7;
+    let t12 = y9 & y11;
+    let t13 = y14 & y17;
+    let t14 = t13 ^ t12;
+    let t15 = y8 & y10;
+    let t16 = t15 ^ t12;
+    let t17 = t4 ^ t14;
+    let t18 = t6 ^ t16;
+    let t19 = t9 ^ t14;
+    let t20 = t11 ^ t16;
+    let t21 = t17 ^ y20;
+    let t22 = t18 ^ y19;
+    let t23 = t19 ^ y21;
+    let t24 = t20 ^ y18;
+
+    let t25 = t21 ^ t22;
+    let t26 = t21 & t23;
+    let t27 = t24 ^ t26;
+    let t28 = t25 & t27;
+    let t29 = t28 ^ t22;
+    let t30 = t23 ^ t24;
+    let t31 = t22 ^ t26;
+    let t32 = t31 & t30;
+    let t33 = t32 ^ t24;
+    let t34 = t23 ^ t33;
+    let t35 = t27 ^ t33;
+    let t36 = t24 & t35;
+    let t37 = t36 ^ t34;
+    let t38 = t27 ^ t36;
+    let t39 = t29 & t38;
+    let t40 = t25 ^ t39;
+
+    let t41 = t40 ^ t37;
+    let t42 = t29 ^ t33;
+    let t43 = t29 ^ t40;
+    let t44 = t33 ^ t37;
+    let t45 = t42 ^ t41;
+    let z0 = t44 & y15;
+    let z1 = t37 & y6;
+    let z2 = t33 & x7;
+    let z3 = t43 & y16;
+    let z4 = t40 & y1;
+    let z5 = t29 & y7;
+    let z6 = t42 & y11;
+    let z7 = t45 & y17;
+    let z8 = t41 & y10;
+    let z9 = t44 & y12;
+    let z10 = t37 & y3;
+    let z11 = t33 & y4;
+    let z12 = t43 & y13;
+    let z13 = t40 & y5;
+    let z14 = t29 & y2;
+    let z15 = t42 & y9;
+    let z16 = t45 & y14;
+    let z17 = t41 & y8;
+
+    // Bottom linear transformation.
+    let t46 = z15 ^ z16;
+    let t47 = z10 ^ z11;
+    let t48 = z5 ^ z13;
+    let t49 = z9 ^ z10;
+    let t50 = z2 ^ z12;
+    let t51 = z2 ^ z5;
+    let t52 = z7 ^ z8;
+    let t53 = z0 ^ z3;
+    let t54 = z6 ^ z7;
+    let t55 = z16 ^ z17;
+    let t56 = z12 ^ t48;
+    let t57 = t50 ^ t53;
+    let t58 = z4 ^ t46;
+    let t59 = z3 ^ t54;
+    let t60 = t46 ^ t57;
+    let t61 = z14 ^ t57;
+    let t62 = t52 ^ t58;
+    let t63 = t49 ^ t58;
+    let t64 = z4 ^ t59;
+    let t65 = t61 ^ t62;
+    let t66 = z1 ^ t63;
+    let s0 = t59 ^ t63;
+    let s6 = t56 ^ !t62;
+    let s7 = t48 ^ !t60;
+    let t67 = t64 ^ t65;
+    let s3 = t53 ^ t66;
+    let s4 = t51 ^ t66;
+    let s5 = t47 ^ t65;
+    let s1 = t64 ^ !s3;
+    let s2 = t55 ^ !t67;
+
+    q[7] = s0;
+    q[6] = s1;
+    q[5] = s2;
+    q[4] = s3;
+    q[3] = s4;
+    q[2] = s5;
+    q[1] = s6;
+    q[0] = s7;
 }
 
-/// Set byte at position (row) in a column word.
-#[inline]
-fn set_byte(row: usize, val: u8) -> u32 {
-    (val as u32) << (24 - row * 8)
+/// The affine map that, composed with the forward circuit on both sides,
+/// yields the inverse S-box: S⁻¹(x) = A⁻¹(S(A⁻¹(x))).
+fn inv_affine(q: &mut [u32]) {
+    let (q0, q1, q2, q3) = (!q[0], !q[1], q[2], q[3]);
+    let (q4, q5, q6, q7) = (q[4], !q[5], !q[6], q[7]);
+    q[7] = q1 ^ q4 ^ q6;
+    q[6] = q0 ^ q3 ^ q5;
+    q[5] = q7 ^ q2 ^ q4;
+    q[4] = q6 ^ q1 ^ q3;
+    q[3] = q5 ^ q0 ^ q2;
+    q[2] = q4 ^ q7 ^ q1;
+    q[1] = q3 ^ q6 ^ q0;
+    q[0] = q2 ^ q5 ^ q7;
 }
 
-/// ShiftRows: cyclically shift rows 1-3 of the state left.
-/// Row 0: no shift, Row 1: shift 1, Row 2: shift 2, Row 3: shift 3.
-fn shift_rows(state: &mut [u32; 4]) {
-    // The state is stored column-major. We need to shift rows across columns.
-    let mut tmp = [0u32; 4];
-    for col in 0..4 {
-        // Row 0 stays in same column
-        tmp[col] |= set_byte(0, get_byte(state[col], 0));
-        // Row 1 shifts left by 1
-        tmp[col] |= set_byte(1, get_byte(state[(col + 1) % 4], 1));
-        // Row 2 shifts left by 2
-        tmp[col] |= set_byte(2, get_byte(state[(col + 2) % 4], 2));
-        // Row 3 shifts left by 3
-        tmp[col] |= set_byte(3, get_byte(state[(col + 3) % 4], 3));
-    }
-    *state = tmp;
+/// InvSubBytes on bitsliced state.
+fn bitslice_inv_sbox(q: &mut [u32]) {
+    inv_affine(q);
+    bitslice_sbox(q);
+    inv_affine(q);
 }
 
-/// InvShiftRows: cyclically shift rows 1-3 of the state right.
-fn inv_shift_rows(state: &mut [u32; 4]) {
-    let mut tmp = [0u32; 4];
-    for col in 0..4 {
-        tmp[col] |= set_byte(0, get_byte(state[col], 0));
-        // Row 1 shifts right by 1 = left by 3
-        tmp[col] |= set_byte(1, get_byte(state[(col + 3) % 4], 1));
-        // Row 2 shifts right by 2 = left by 2
-        tmp[col] |= set_byte(2, get_byte(state[(col + 2) % 4], 2));
-        // Row 3 shifts right by 3 = left by 1
-        tmp[col] |= set_byte(3, get_byte(state[(col + 1) % 4], 3));
+/// ShiftRows: cyclically shift rows 1-3 of the state left.
+fn shift_rows(q: &mut [u32; 8]) {
+    for x in q.iter_mut() {
+        let v = *x;
+        *x = (v & 0x0000_00FF)
+            | ((v & 0x0000_FC00) >> 2)
+            | ((v & 0x0000_0300) << 6)
+            | ((v & 0x00F0_0000) >> 4)
+            | ((v & 0x000F_0000) << 4)
+            | ((v & 0xC000_0000) >> 6)
+            | ((v & 0x3F00_0000) << 2);
     }
-    *state = tmp;
 }
 
-/// Multiply by x (i.e., {02}) in GF(2^8) with the AES irreducible polynomial.
-#[inline]
-fn xtime(a: u8) -> u8 {
-    let shifted = (a as u16) << 1;
-    let reduced = shifted ^ (if a & 0x80 != 0 { 0x1b } else { 0x00 });
-    reduced as u8
-}
-
-/// Multiply two bytes in GF(2^8).
-#[inline]
-fn gmul(mut a: u8, mut b: u8) -> u8 {
-    let mut result: u8 = 0;
-    for _ in 0..8 {
-        if b & 1 != 0 {
-            result ^= a;
-        }
-        a = xtime(a);
-        b >>= 1;
+/// InvShiftRows: cyclically shift rows 1-3 of the state right.
+fn inv_shift_rows(q: &mut [u32; 8]) {
+    for x in q.iter_mut() {
+        let v = *x;
+        *x = (v & 0x0000_00FF)
+            | ((v & 0x0000_3F00) << 2)
+            | ((v & 0x0000_C000) >> 6)
+            | ((v & 0x000F_0000) << 4)
+            | ((v & 0x00F0_0000) >> 4)
+            | ((v & 0x0300_0000) << 6)
+            | ((v & 0xFC00_0000) >> 2);
     }
-    result
 }
 
-/// MixColumns: mix each column of the state using GF(2^8) arithmetic.
-///
-/// The fixed polynomial is: {03}x³ + {01}x² + {01}x + {02}
-fn mix_columns(state: &mut [u32; 4]) {
-    for col in state.iter_mut() {
-        let s0 = get_byte(*col, 0);
-        let s1 = get_byte(*col, 1);
-        let s2 = get_byte(*col, 2);
-        let s3 = get_byte(*col, 3);
-
-        let r0 = gmul(0x02, s0) ^ gmul(0x03, s1) ^ s2 ^ s3;
-        let r1 = s0 ^ gmul(0x02, s1) ^ gmul(0x03, s2) ^ s3;
-        let r2 = s0 ^ s1 ^ gmul(0x02, s2) ^ gmul(0x03, s3);
-        let r3 = gmul(0x03, s0) ^ s1 ^ s2 ^ gmul(0x02, s3);
-
-        *col = set_byte(0, r0) | set_byte(1, r1) | set_byte(2, r2) | set_byte(3, r3);
-    }
+/// MixColumns on bitsliced state; `r` holds each word rotated by one row.
+fn mix_columns(q: &mut [u32; 8]) {
+    let s = *q;
+    let r = s.map(|x| x.rotate_right(8));
+    let rot16 = |x: u32| x.rotate_right(16);
+    q[0] = s[7] ^ r[7] ^ r[0] ^ rot16(s[0] ^ r[0]);
+    q[1] = s[0] ^ r[0] ^ s[7] ^ r[7] ^ r[1] ^ rot16(s[1] ^ r[1]);
+    q[2] = s[1] ^ r[1] ^ r[2] ^ rot16(s[2] ^ r[2]);
+    q[3] = s[2] ^ r[2] ^ s[7] ^ r[7] ^ r[3] ^ rot16(s[3] ^ r[3]);
+    q[4] = s[3] ^ r[3] ^ s[7] ^ r[7] ^ r[4] ^ rot16(s[4] ^ r[4]);
+    q[5] = s[4] ^ r[4] ^ r[5] ^ rot16(s[5] ^ r[5]);
+    q[6] = s[5] ^ r[5] ^ r[6] ^ rot16(s[6] ^ r[6]);
+    q[7] = s[6] ^ r[6] ^ r[7] ^ rot16(s[7] ^ r[7]);
 }
 
-/// InvMixColumns: inverse of MixColumns.
-///
-/// The inverse polynomial is: {0b}x³ + {0d}x² + {09}x + {0e}
-fn inv_mix_columns(state: &mut [u32; 4]) {
-    for col in state.iter_mut() {
-        let s0 = get_byte(*col, 0);
-        let s1 = get_byte(*col, 1);
-        let s2 = get_byte(*col, 2);
-        let s3 = get_byte(*col, 3);
-
-        let r0 = gmul(0x0e, s0) ^ gmul(0x0b, s1) ^ gmul(0x0d, s2) ^ gmul(0x09, s3);
-        let r1 = gmul(0x09, s0) ^ gmul(0x0e, s1) ^ gmul(0x0b, s2) ^ gmul(0x0d, s3);
-        let r2 = gmul(0x0d, s0) ^ gmul(0x09, s1) ^ gmul(0x0e, s2) ^ gmul(0x0b, s3);
-        let r3 = gmul(0x0b, s0) ^ gmul(0x0d, s1) ^ gmul(0x09, s2) ^ gmul(0x0e, s3);
-
-        *col = set_byte(0, r0) | set_byte(1, r1) | set_byte(2, r2) | set_byte(3, r3);
-    }
+/// InvMixColumns: MixColumns has order 4, so its inverse is its cube.
+fn inv_mix_columns(q: &mut [u32; 8]) {
+    mix_columns(q);
+    mix_columns(q);
+    mix_columns(q);
 }
 
 /// AddRoundKey: XOR state with round key.
 #[inline]
-fn add_round_key(state: &mut [u32; 4], rk: &[u32; 4]) {
-    for i in 0..4 {
-        state[i] ^= rk[i];
+fn add_round_key(q: &mut [u32; 8], rk: &[u32]) {
+    for i in 0..8 {
+        q[i] ^= rk[i];
     }
 }
 
-/// Encrypt a single 16-byte block in-place using AES.
-pub fn aes_encrypt_block(sched: &AesKeySchedule, block: &mut [u8; 16]) {
-    let mut state = block_to_state(block);
+// ─────────────────────────────────────────────────────────────────────────────
+// Block encryption
+// ─────────────────────────────────────────────────────────────────────────────
 
+fn encrypt_bitsliced(sched: &AesKeySchedule, q: &mut [u32; 8]) {
     // Initial round key addition
-    add_round_key(&mut state, &sched.round_key(0));
+    add_round_key(q, sched.round_key(0));
 
     // Rounds 1..nr-1: SubBytes → ShiftRows → MixColumns → AddRoundKey
     for round in 1..sched.nr {
-        sub_bytes(&mut state);
-        shift_rows(&mut state);
-        mix_columns(&mut state);
-        add_round_key(&mut state, &sched.round_key(round));
+        bitslice_sbox(q);
+        shift_rows(q);
+        mix_columns(q);
+        add_round_key(q, sched.round_key(round));
     }
 
     // Final round (no MixColumns): SubBytes → ShiftRows → AddRoundKey
-    sub_bytes(&mut state);
-    shift_rows(&mut state);
-    add_round_key(&mut state, &sched.round_key(sched.nr));
-
-    state_to_block(&state, block);
+    bitslice_sbox(q);
+    shift_rows(q);
+    add_round_key(q, sched.round_key(sched.nr));
 }
 
-/// Decrypt a single 16-byte block in-place using AES.
-pub fn aes_decrypt_block(sched: &AesKeySchedule, block: &mut [u8; 16]) {
-    let mut state = block_to_state(block);
-
+fn decrypt_bitsliced(sched: &AesKeySchedule, q: &mut [u32; 8]) {
     // Initial round key addition (last round key)
-    add_round_key(&mut state, &sched.round_key(sched.nr));
+    add_round_key(q, sched.round_key(sched.nr));
 
     // Rounds nr-1..1: InvShiftRows → InvSubBytes → AddRoundKey → InvMixColumns
     for round in (1..sched.nr).rev() {
-        inv_shift_rows(&mut state);
-        inv_sub_bytes(&mut state);
-        add_round_key(&mut state, &sched.round_key(round));
-        inv_mix_columns(&mut state);
+        inv_shift_rows(q);
+        bitslice_inv_sbox(q);
+        add_round_key(q, sched.round_key(round));
+        inv_mix_columns(q);
     }
 
     // Final round (no InvMixColumns): InvShiftRows → InvSubBytes → AddRoundKey
-    inv_shift_rows(&mut state);
-    inv_sub_bytes(&mut state);
-    add_round_key(&mut state, &sched.round_key(0));
+    inv_shift_rows(q);
+    bitslice_inv_sbox(q);
+    add_round_key(q, sched.round_key(0));
+}
 
-    state_to_block(&state, block);
+/// Encrypt a single 16-byte block in-place using AES.
+pub fn aes_encrypt_block(sched: &AesKeySchedule, block: &mut [u8; 16]) {
+    let mut q = load_blocks(block, block);
+    encrypt_bitsliced(sched, &mut q);
+    let mut unused = [0u8; 16];
+    store_blocks(q, block, &mut unused);
+}
+
+/// Encrypt two 16-byte blocks in-place at the cost of one (CTR mode).
+pub fn aes_encrypt_blocks2(sched: &AesKeySchedule, a: &mut [u8; 16], b: &mut [u8; 16]) {
+    let mut q = load_blocks(a, b);
+    encrypt_bitsliced(sched, &mut q);
+    store_blocks(q, a, b);
+}
+
+/// Decrypt a single 16-byte block in-place using AES.
+pub fn aes_decrypt_block(sched: &AesKeySchedule, block: &mut [u8; 16]) {
+    let mut q = load_blocks(block, block);
+    decrypt_bitsliced(sched, &mut q);
+    let mut unused = [0u8; 16];
+    store_blocks(q, block, &mut unused);
 }
 
 #[cfg(test)]
@@ -497,17 +603,22 @@ mod tests {
     }
 
     #[test]
-    fn test_xtime() {
-        assert_eq!(xtime(0x57), 0xae);
-        assert_eq!(xtime(0xae), 0x47);
-        assert_eq!(xtime(0x47), 0x8e);
-        assert_eq!(xtime(0x8e), 0x07);
+    fn test_sub_word() {
+        // FIPS 197 §5.1.1: S(00) = 63, S(53) = ed; and a few more entries.
+        assert_eq!(sub_word(0x0000_0000), 0x6363_6363);
+        assert_eq!(sub_word(0x5300_01ff), 0xed63_7c16);
+        // Key expansion example from FIPS 197 Appendix A.1.
+        assert_eq!(sub_word(0xcf4f_3c09), 0x8a84_eb01);
     }
 
     #[test]
-    fn test_gmul() {
-        // {57} • {83} = {c1} in GF(2^8)
-        assert_eq!(gmul(0x57, 0x83), 0xc1);
+    fn test_two_blocks_match_single_blocks() {
+        let sched = AesKeySchedule::new(&from_hex("2b7e151628aed2a6abf7158809cf4f3c")).unwrap();
+        let mut a: [u8; 16] = from_hex("6bc1bee22e409f96e93d7e117393172a").try_into().unwrap();
+        let mut b: [u8; 16] = from_hex("ae2d8a571e03ac9c9eb76fac45af8e51").try_into().unwrap();
+        aes_encrypt_blocks2(&sched, &mut a, &mut b);
+        assert_eq!(to_hex(&a), "3ad77bb40d7a3660a89ecaf32466ef97");
+        assert_eq!(to_hex(&b), "f5d3d58503b9699de785895a96fdbaaf");
     }
 
     #[test]
@@ -0,0 +1,288 @@
+//! Modular arithmetic on big unsigned integers, for signature verification.
+//!
+//! Numbers are little-endian `u32` limbs, all as wide as the modulus.
+//! [`Modulus`] multiplies in Montgomery form (CIOS), which needs an odd
+//! modulus: RSA moduli and the NIST curve primes and orders all are.
+//!
+//! Only public values (keys, signatures, digests) pass through here, so
+//! nothing is constant-time.
+
+use std::cmp::Ordering;
+
+/// An odd modulus with its precomputed Montgomery constants.
+#[derive(Clone, Debug)]
+pub struct Modulus {
+    n: Vec<u32>,
+    /// `-n⁻¹ mod 2³²`.
+    n0_inv: u32,
+    /// `R² mod n`, where `R = 2^(32·limbs)`.
+    r2: Vec<u32>,
+}
+
+impl Modulus {
+    /// The modulus with big-endian bytes `n`, or `None` if it is even or
+    /// less than 3.
+    pub fn new(n: &[u8]) -> Option<Self> {
+        let n = limbs_from_be(n, 0);
+        let n = n[..n.iter().rposition(|&l| l != 0)? + 1].to_vec();
+        if n[0] & 1 == 0 || (n.len() == 1 && n[0] < 3) {
+            return None;
+        }
+        // Newton's iteration for n⁻¹ mod 2³²; each step doubles the
+        // correct low bits, starting from 1 (n is odd).
+        let mut inv: u32 = 1;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(n[0].wrapping_mul(inv)));
+        }
+        let mut modulus = Self {
+            n0_inv: inv.wrapping_neg(),
+            r2: Vec::new(),
+            n,
+        };
+        // R² mod n by doubling 1 (2·32·limbs) times.
+        let mut r2 = vec![0; modulus.n.len()];
+        r2[0] = 1;
+        for _ in 0..64 * modulus.n.len() {
+            r2 = modulus.add(&r2, &r2);
+        }
+        modulus.r2 = r2;
+        Some(modulus)
+    }
+
+    /// The number of limbs in every value mod this modulus.
+    pub fn limbs(&self) -> usize {
+        self.n.len()
+    }
+
+    /// The size of the modulus in bytes.
+    pub fn byte_len(&self) -> usize {
+        let top = self.n[self.n.len() - 1];
+        (self.n.len() - 1) * 4 + (32 - top.leading_zeros() as usize).div_ceil(8)
+    }
+
+    /// `x mod n` for big-endian bytes `x` of any length.
+    pub fn reduce(&self, x: &[u8]) -> Vec<u32> {
+        let mut acc = vec![0; self.n.len()];
+        for byte in x {
+            for bit in (0..8).rev() {
+                acc = self.add(&acc, &acc);
+                if byte >> bit & 1 == 1 {
+                    let mut one = vec![0; self.n.len()];
+                    one[0] = 1;
+                    acc = self.add(&acc, &one);
+                }
+            }
+        }
+        acc
+    }
+
+    /// Whether big-endian bytes `x` are a value in `[0, n)`.
+    pub fn contains(&self, x: &[u8]) -> bool {
+        let significant = x.iter().position(|&b| b != 0).map_or(&[][..], |i| &x[i..]);
+        significant.len() <= self.n.len() * 4
+            && compare(&limbs_from_be(significant, self.n.len()), &self.n) == Ordering::Less
+    }
+
+    /// Big-endian bytes of `x`, `len` bytes long.
+    pub fn to_be_bytes(&self, x: &[u32], len: usize) -> Vec<u8> {
+        let mut out = vec![0; len];
+        for (i, byte) in out.iter_mut().rev().enumerate() {
+            if let Some(limb) = x.get(i / 4) {
+                *byte = (limb >> (8 * (i % 4))) as u8;
+            }
+        }
+        out
+    }
+
+    /// `a + b mod n`, for `a, b < n`.
+    pub fn add(&self, a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut sum = vec![0; self.n.len()];
+        let mut carry = 0u64;
+        for i in 0..self.n.len() {
+            let s = a[i] as u64 + b[i] as u64 + carry;
+            sum[i] = s as u32;
+            carry = s >> 32;
+        }
+        if carry != 0 || compare(&sum, &self.n) != Ordering::Less {
+            sub_in_place(&mut sum, &self.n);
+        }
+        sum
+    }
+
+    /// `a - b mod n`, for `a, b < n`.
+    pub fn sub(&self, a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut diff = a.to_vec();
+        if sub_in_place(&mut diff, b) {
+            let mut carry = 0u64;
+            for (d, &n) in diff.iter_mut().zip(&self.n) {
+                let s = *d as u64 + n as u64 + carry;
+                *d = s as u32;
+                carry = s >> 32;
+            }
+        }
+        diff
+    }
+
+    /// Montgomery product `a·b·R⁻¹ mod n`.
+    pub fn mont_mul(&self, a: &[u32], b: &[u32]) -> Vec<u32> {
+        let k = self.n.len();
+        let mut t = vec![0u32; k + 2];
+        for &ai in a.iter().take(k) {
+            let mut carry = 0u64;
+            for j in 0..k {
+                let s = t[j] as u64 + ai as u64 * b[j] as u64 + carry;
+                t[j] = s as u32;
+                carry = s >> 32;
+            }
+            let s = t[k] as u64 + carry;
+            t[k] = s as u32;
+            t[k + 1] = (s >> 32) as u32;
+
+            let m = t[0].wrapping_mul(self.n0_inv);
+            let mut carry = (t[0] as u64 + m as u64 * self.n[0] as u64) >> 32;
+            for j in 1..k {
+                let s = t[j] as u64 + m as u64 * self.n[j] as u64 + carry;
+                t[j - 1] = s as u32;
+                carry = s >> 32;
+            }
+            let s = t[k] as u64 + carry;
+            t[k - 1] = s as u32;
+            t[k] = t[k + 1] + (s >> 32) as u32;
+        }
+        let mut result = t[..k].to_vec();
+        if t[k] != 0 || compare(&result, &self.n) != Ordering::Less {
+            sub_in_place(&mut result, &self.n);
+        }
+        result
+    }
+
+    /// `a` in Montgomery form (`a·R mod n`).
+    pub fn to_mont(&self, a: &[u32]) -> Vec<u32> {
+        self.mont_mul(a, &self.r2)
+    }
+
+    /// `a` out of Montgomery form.
+    pub fn from_mont(&self, a: &[u32]) -> Vec<u32> {
+        let mut one = vec![0; self.n.len()];
+        one[0] = 1;
+        self.mont_mul(a, &one)
+    }
+
+    /// `a·b mod n`.
+    pub fn mul(&self, a: &[u32], b: &[u32]) -> Vec<u32> {
+        self.mont_mul(&self.to_mont(a), b)
+    }
+
+    /// `baseᵉ mod n`, with big-endian exponent bytes `e`.
+    pub fn pow(&self, base: &[u32], e: &[u8]) -> Vec<u32> {
+        let base = self.to_mont(base);
+        let mut one = vec![0; self.n.len()];
+        one[0] = 1;
+        let mut acc = self.to_mont(&one);
+        for byte in e {
+            for bit in (0..8).rev() {
+                acc = self.mont_mul(&acc, &acc);
+                if byte >> bit & 1 == 1 {
+                    acc = self.mont_mul(&acc, &base);
+                }
+            }
+        }
+        self.from_mont(&acc)
+    }
+
+    /// `a⁻¹ mod n` by Fermat's little theorem; `n` must be prime.
+    pub fn inv(&self, a: &[u32]) -> Vec<u32> {
+        let mut exponent = self.n.clone();
+        sub_in_place(&mut exponent, &[2]);
+        let e = self.to_be_bytes(&exponent, self.n.len() * 4);
+        self.pow(a, &e)
+    }
+}
+
+/// Whether every limb is zero.
+pub fn is_zero(x: &[u32]) -> bool {
+    x.iter().all(|&l| l == 0)
+}
+
+/// Little-endian limbs of big-endian bytes, padded to at least `min_limbs`.
+fn limbs_from_be(bytes: &[u8], min_limbs: usize) -> Vec<u32> {
+    let mut limbs = vec![0u32; bytes.len().div_ceil(4).max(min_limbs).max(1)];
+    for (i, &byte) in bytes.iter().rev().enumerate() {
+        limbs[i / 4] |= (byte as u32) << (8 * (i % 4));
+    }
+    limbs
+}
+
+fn compare(a: &[u32], b: &[u32]) -> Ordering {
+    for i in (0..a.len().max(b.len())).rev() {
+        let (x, y) = (a.get(i).copied().unwrap_or(0), b.get(i).copied().unwrap_or(0));
+        if x != y {
+            return x.cmp(&y);
+        }
+    }
+    Ordering::Equal
+}
+
+/// `a -= b`, returning whether it borrowed out of the top limb.
+fn sub_in_place(a: &mut [u32], b: &[u32]) -> bool {
+    let mut borrow = 0i64;
+    for (i, limb) in a.iter_mut().enumerate() {
+        let d = *limb as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        *limb = d as u32;
+        borrow = (d < 0) as i64;
+    }
+    borrow != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(m: &Modulus, n: u64) -> Vec<u32> {
+        m.reduce(&n.to_be_bytes())
+    }
+
+    #[test]
+    fn test_rejects_even_modulus() {
+        assert!(Modulus::new(&[0x10]).is_none());
+        assert!(Modulus::new(&[0, 0]).is_none());
+        assert!(Modulus::new(&[0x11]).is_some());
+    }
+
+    #[test]
+    fn test_small_arithmetic() {
+        let m = Modulus::new(&97u64.to_be_bytes()).unwrap();
+        assert_eq!(m.limbs(), 1);
+        assert_eq!(m.byte_len(), 1);
+        assert_eq!(value(&m, 200), vec![6]);
+        assert_eq!(m.add(&value(&m, 90), &value(&m, 10)), vec![3]);
+        assert_eq!(m.sub(&value(&m, 3), &value(&m, 10)), vec![90]);
+        assert_eq!(m.mul(&value(&m, 50), &value(&m, 50)), vec![2500 % 97]);
+        assert_eq!(m.pow(&value(&m, 5), &[3]), vec![125 % 97]);
+        assert_eq!(m.mul(&m.inv(&value(&m, 5)), &value(&m, 5)), vec![1]);
+    }
+
+    #[test]
+    fn test_multi_limb_matches_u128() {
+        // A 64-bit prime, so products fit in u128.
+        let p: u64 = 0xffff_ffff_0000_0001;
+        let m = Modulus::new(&p.to_be_bytes()).unwrap();
+        let (a, b) = (0x1234_5678_9abc_def0u64, 0xfedc_ba98_7654_3210u64 % p);
+        let product = (a as u128 * b as u128 % p as u128) as u64;
+        let got = m.mul(&value(&m, a), &value(&m, b));
+        assert_eq!(m.to_be_bytes(&got, 8), product.to_be_bytes());
+        let sum = ((a as u128 + b as u128) % p as u128) as u64;
+        assert_eq!(m.to_be_bytes(&m.add(&value(&m, a), &value(&m, b)), 8), sum.to_be_bytes());
+        let inv = m.inv(&value(&m, a));
+        assert_eq!(m.mul(&inv, &value(&m, a)), vec![1, 0]);
+    }
+
+    #[test]
+    fn test_contains() {
+        let m = Modulus::new(&[0x01, 0x01]).unwrap();
+        assert!(m.contains(&[0x01, 0x00]));
+        assert!(m.contains(&[0, 0, 0x01, 0x00]));
+        assert!(!m.contains(&[0x01, 0x01]));
+        assert!(!m.contains(&[0x01, 0x00, 0x00]));
+    }
+}
@@ -0,0 +1,322 @@
+//! ECDSA signature verification (FIPS 186-4 §6.4) on the NIST curves
+//! P-256 and P-384.
+//!
+//! Points are kept in Jacobian coordinates with field elements in
+//! Montgomery form; both curves have `a = -3`, which the doubling formula
+//! relies on.
+
+use crate::bignum::{self, Modulus};
+
+/// A supported curve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Curve {
+    P256,
+    P384,
+}
+
+struct Params {
+    p: &'static str,
+    b: &'static str,
+    n: &'static str,
+    gx: &'static str,
+    gy: &'static str,
+}
+
+const P256: Params = Params {
+    p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+    b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+    n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+    gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+    gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+};
+
+const P384: Params = Params {
+    p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe\
+        ffffffff0000000000000000ffffffff",
+    b: "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875a\
+        c656398d8a2ed19d2a85c8edd3ec2aef",
+    n: "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf\
+        581a0db248b0a77aecec196accc52973",
+    gx: "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a38\
+         5502f25dbf55296c3a545e3872760ab7",
+    gy: "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c0\
+         0a60b1ce1d7e819d7a431d7c90ea0e5f",
+};
+
+impl Curve {
+    /// The size of a field element or scalar in bytes.
+    pub fn byte_len(self) -> usize {
+        match self {
+            Curve::P256 => 32,
+            Curve::P384 => 48,
+        }
+    }
+
+    fn params(self) -> &'static Params {
+        match self {
+            Curve::P256 => &P256,
+            Curve::P384 => &P384,
+        }
+    }
+}
+
+/// Whether `(r, s)` is a valid signature of `digest` under the public key
+/// `point`, an uncompressed SEC 1 point (`04 || x || y`).
+pub fn verify(curve: Curve, point: &[u8], digest: &[u8], r: &[u8], s: &[u8]) -> bool {
+    let group = Group::new(curve);
+    let len = curve.byte_len();
+    if point.len() != 1 + 2 * len || point[0] != 0x04 {
+        return false;
+    }
+    let Some(q) = group.affine(&point[1..1 + len], &point[1 + len..]) else {
+        return false;
+    };
+
+    let order = &group.order;
+    let in_range = |x: &[u8]| order.contains(x) && !bignum::is_zero(&order.reduce(x));
+    if !in_range(r) || !in_range(s) {
+        return false;
+    }
+    // The digest's leftmost bits, as many as the order has; both orders
+    // are a whole number of bytes.
+    let e = order.reduce(&digest[..digest.len().min(len)]);
+    let r = order.reduce(r);
+    let w = order.inv(&order.reduce(s));
+    let u1 = order.to_be_bytes(&order.mul(&e, &w), len);
+    let u2 = order.to_be_bytes(&order.mul(&r, &w), len);
+
+    let sum = group.add(&group.scalar_mul(&group.g, &u1), &group.scalar_mul(&q, &u2));
+    let Some(x) = group.affine_x(&sum) else {
+        return false;
+    };
+    order.reduce(&x) == r
+}
+
+/// A point in Jacobian coordinates; `z = 0` is the point at infinity.
+#[derive(Clone, Debug)]
+struct Point {
+    x: Vec<u32>,
+    y: Vec<u32>,
+    z: Vec<u32>,
+}
+
+/// Field and group arithmetic for one curve.
+struct Group {
+    field: Modulus,
+    order: Modulus,
+    len: usize,
+    /// `b` in Montgomery form.
+    b: Vec<u32>,
+    g: Point,
+}
+
+impl Group {
+    fn new(curve: Curve) -> Self {
+        let params = curve.params();
+        let field = Modulus::new(&hex_bytes(params.p)).expect("curve prime is odd");
+        let order = Modulus::new(&hex_bytes(params.n)).expect("curve order is odd");
+        let b = field.to_mont(&field.reduce(&hex_bytes(params.b)));
+        let mut group = Self {
+            field,
+            order,
+            len: curve.byte_len(),
+            b,
+            g: Point {
+                x: Vec::new(),
+                y: Vec::new(),
+                z: Vec::new(),
+            },
+        };
+        group.g = group
+            .affine(&hex_bytes(params.gx), &hex_bytes(params.gy))
+            .expect("generator is on the curve");
+        group
+    }
+
+    fn one(&self) -> Vec<u32> {
+        let mut one = vec![0; self.field.limbs()];
+        one[0] = 1;
+        self.field.to_mont(&one)
+    }
+
+    fn infinity(&self) -> Point {
+        let one = self.one();
+        Point {
+            x: one.clone(),
+            y: one,
+            z: vec![0; self.field.limbs()],
+        }
+    }
+
+    /// The affine point `(x, y)`, if it is on the curve.
+    fn affine(&self, x: &[u8], y: &[u8]) -> Option<Point> {
+        let f = &self.field;
+        if !f.contains(x) || !f.contains(y) {
+            return None;
+        }
+        let x = f.to_mont(&f.reduce(x));
+        let y = f.to_mont(&f.reduce(y));
+        // y² = x³ - 3x + b
+        let x3 = f.mont_mul(&f.mont_mul(&x, &x), &x);
+        let three_x = f.add(&f.add(&x, &x), &x);
+        let rhs = f.add(&f.sub(&x3, &three_x), &self.b);
+        if f.mont_mul(&y, &y) != rhs {
+            return None;
+        }
+        Some(Point { x, y, z: self.one() })
+    }
+
+    /// The affine x coordinate as big-endian bytes, or `None` at infinity.
+    fn affine_x(&self, p: &Point) -> Option<Vec<u8>> {
+        if bignum::is_zero(&p.z) {
+            return None;
+        }
+        let f = &self.field;
+        let z = f.from_mont(&p.z);
+        let z_inv = f.inv(&z);
+        let z_inv2 = f.mul(&z_inv, &z_inv);
+        let x = f.mul(&f.from_mont(&p.x), &z_inv2);
+        Some(f.to_be_bytes(&x, self.len))
+    }
+
+    /// `2p` (dbl-2001-b, for `a = -3`).
+    fn double(&self, p: &Point) -> Point {
+        if bignum::is_zero(&p.z) {
+            return p.clone();
+        }
+        let f = &self.field;
+        let delta = f.mont_mul(&p.z, &p.z);
+        let gamma = f.mont_mul(&p.y, &p.y);
+        let beta = f.mont_mul(&p.x, &gamma);
+        let t = f.mont_mul(&f.sub(&p.x, &delta), &f.add(&p.x, &delta));
+        let alpha = f.add(&f.add(&t, &t), &t);
+        let beta2 = f.add(&beta, &beta);
+        let beta4 = f.add(&beta2, &beta2);
+        let beta8 = f.add(&beta4, &beta4);
+        let x3 = f.sub(&f.mont_mul(&alpha, &alpha), &beta8);
+        let yz = f.add(&p.y, &p.z);
+        let z3 = f.sub(&f.sub(&f.mont_mul(&yz, &yz), &gamma), &delta);
+        let gamma2 = f.mont_mul(&gamma, &gamma);
+        let gamma2_2 = f.add(&gamma2, &gamma2);
+        let gamma2_4 = f.add(&gamma2_2, &gamma2_2);
+        let gamma2_8 = f.add(&gamma2_4, &gamma2_4);
+        let y3 = f.sub(&f.mont_mul(&alpha, &f.sub(&beta4, &x3)), &gamma2_8);
+        Point { x: x3, y: y3, z: z3 }
+    }
+
+    /// `p + q` (add-2007-bl).
+    fn add(&self, p: &Point, q: &Point) -> Point {
+        if bignum::is_zero(&p.z) {
+            return q.clone();
+        }
+        if bignum::is_zero(&q.z) {
+            return p.clone();
+        }
+        let f = &self.field;
+        let z1z1 = f.mont_mul(&p.z, &p.z);
+        let z2z2 = f.mont_mul(&q.z, &q.z);
+        let u1 = f.mont_mul(&p.x, &z2z2);
+        let u2 = f.mont_mul(&q.x, &z1z1);
+        let s1 = f.mont_mul(&f.mont_mul(&p.y, &q.z), &z2z2);
+        let s2 = f.mont_mul(&f.mont_mul(&q.y, &p.z), &z1z1);
+        let h = f.sub(&u2, &u1);
+        let s_diff = f.sub(&s2, &s1);
+        if bignum::is_zero(&h) {
+            return if bignum::is_zero(&s_diff) {
+                self.double(p)
+            } else {
+                self.infinity()
+            };
+        }
+        let h2 = f.add(&h, &h);
+        let i = f.mont_mul(&h2, &h2);
+        let j = f.mont_mul(&h, &i);
+        let r = f.add(&s_diff, &s_diff);
+        let v = f.mont_mul(&u1, &i);
+        let x3 = f.sub(&f.sub(&f.mont_mul(&r, &r), &j), &f.add(&v, &v));
+        let s1j = f.mont_mul(&s1, &j);
+        let y3 = f.sub(&f.mont_mul(&r, &f.sub(&v, &x3)), &f.add(&s1j, &s1j));
+        let zs = f.add(&p.z, &q.z);
+        let z3 = f.mont_mul(&f.sub(&f.sub(&f.mont_mul(&zs, &zs), &z1z1), &z2z2), &h);
+        Point { x: x3, y: y3, z: z3 }
+    }
+
+    /// `k·p` for big-endian scalar bytes `k`, by double-and-add.
+    fn scalar_mul(&self, p: &Point, k: &[u8]) -> Point {
+        let mut acc = self.infinity();
+        for byte in k {
+            for bit in (0..8).rev() {
+                acc = self.double(&acc);
+                if byte >> bit & 1 == 1 {
+                    acc = self.add(&acc, p);
+                }
+            }
+        }
+        acc
+    }
+}
+
+fn hex_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha256::sha256;
+    use crate::sha512::sha384;
+
+    // Keys from `openssl ecparam -genkey` and signatures over "abc" from
+    // `openssl dgst -sign`, with r and s taken from the DER signature.
+    const P256_KEY: &str = "0459bc4054d3d9bec30fc234fcd326a390d6acfae25957068ebab9421828d4bf\
+                            8fa279962136fbeb71ecdef132e76137a4ec4b0047286d99cf61c2b62990b519\
+                            31";
+    const P256_R: &str = "3ef0711211ceaf8c7042fda5a5aac8b1b9cd91a75951f50a54021eeaf253f3ba";
+    const P256_S: &str = "a034e039669157c34ea31e2bc0614de010c6a0311831cad71b0db437a6add94a";
+    const P384_KEY: &str = "046823a99f7e1e1f824c2ff7a789202fa1dba6dd8b6ad4f7ea3d6f9b49c4882d\
+                            b6d068f7bdf3f60c60d4a93871cff9b437138dc4a96d07e24701c19caad46dde\
+                            2b2071c4b678879508f14674b2dde7dafcb00b023b5a58ec7cd0f4afae547597\
+                            0b";
+    const P384_R: &str = "b2bf8ee04d0a92a1ddf82c742a66e955eee8c36099a7b304a58080a7db0c0c3a\
+                          e27625697925f7b822f782d209460636";
+    const P384_S: &str = "5823672e84630fd50d35712b6f1b8aff72ccef9f51d4836ebe4378d79baede1b\
+                          0953a80b4cfabcec0e467601f2dcf755";
+
+    #[test]
+    fn test_verifies_openssl_signatures() {
+        let (key, r, s) = (hex_bytes(P256_KEY), hex_bytes(P256_R), hex_bytes(P256_S));
+        assert!(verify(Curve::P256, &key, &sha256(b"abc"), &r, &s));
+        assert!(!verify(Curve::P256, &key, &sha256(b"abd"), &r, &s));
+        assert!(!verify(Curve::P256, &key, &sha256(b"abc"), &s, &r));
+
+        let (key, r, s) = (hex_bytes(P384_KEY), hex_bytes(P384_R), hex_bytes(P384_S));
+        assert!(verify(Curve::P384, &key, &sha384(b"abc"), &r, &s));
+        assert!(!verify(Curve::P384, &key, &sha384(b"abd"), &r, &s));
+        assert!(!verify(Curve::P256, &key, &sha384(b"abc"), &r, &s));
+    }
+
+    #[test]
+    fn test_generator_has_the_curve_order() {
+        for curve in [Curve::P256, Curve::P384] {
+            let group = Group::new(curve);
+            let n = hex_bytes(curve.params().n);
+            assert!(bignum::is_zero(&group.scalar_mul(&group.g, &n).z));
+            let mut n_minus_1 = n.clone();
+            *n_minus_1.last_mut().unwrap() -= 1;
+            let almost = group.scalar_mul(&group.g, &n_minus_1);
+            // (n-1)·G = -G, which shares G's x coordinate.
+            assert_eq!(group.affine_x(&almost), group.affine_x(&group.g));
+        }
+    }
+
+    #[test]
+    fn test_rejects_points_off_the_curve() {
+        let mut point = vec![0x04];
+        point.extend(hex_bytes(P256.gx));
+        point.extend(hex_bytes(P256.gx));
+        assert!(!verify(Curve::P256, &point, &[0; 32], &[1], &[1]));
+    }
+}
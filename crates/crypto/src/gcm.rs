@@ -6,7 +6,7 @@
 ///
 /// Supports both AES-128-GCM and AES-256-GCM depending on the key size.
 
-use crate::aes::{AesKeySchedule, aes_encrypt_block};
+use crate::aes::{AesKeySchedule, aes_encrypt_block, aes_encrypt_blocks2};
 use crate::constant_time;
 
 /// AES-GCM context. Pre-computes H = AES_K(0^128) for GHASH.
@@ -148,17 +148,23 @@ impl AesGcm {
         let mut offset = 0;
 
         while offset < input.len() {
-            // Increment counter (big-endian increment of last 4 bytes)
+            // Encrypt the next two counter blocks together; the bitsliced
+            // cipher processes a pair for the price of one.
             inc32(ctr);
-
-            // Encrypt counter block
-            let mut keystream = *ctr;
-            aes_encrypt_block(&self.aes, &mut keystream);
-
-            // XOR with input
+            let mut keystream = [0u8; 32];
+            let (first, second) = keystream.split_at_mut(16);
+            let first: &mut [u8; 16] = first.try_into().unwrap();
+            let second: &mut [u8; 16] = second.try_into().unwrap();
+            *first = *ctr;
             let remaining = input.len() - offset;
-            let chunk_len = if remaining >= 16 { 16 } else { remaining };
+            if remaining > 16 {
+                inc32(ctr);
+                *second = *ctr;
+            }
+            aes_encrypt_blocks2(&self.aes, first, second);
 
+            // XOR with input
+            let chunk_len = remaining.min(32);
             for i in 0..chunk_len {
                 output.push(input[offset + i] ^ keystream[i]);
             }
@@ -5,24 +5,41 @@
 /// # Modules
 ///
 /// - [`sha256`] — SHA-256 hash function (FIPS 180-4)
+/// - [`sha512`] — SHA-512 and SHA-384 hash functions (FIPS 180-4)
+/// - [`md5`] — MD5 hash function (RFC 1321), for legacy protocols only
 /// - [`hmac`] — HMAC-SHA256 message authentication (RFC 2104)
 /// - [`hkdf`] — HKDF-SHA256 key derivation (RFC 5869)
-/// - [`aes`] — AES-128/256 block cipher (FIPS 197)
+/// - [`aes`] — AES-128/256 block cipher (FIPS 197), bitsliced and constant-time
 /// - [`gcm`] — AES-GCM authenticated encryption (NIST SP 800-38D)
 /// - [`constant_time`] — Constant-time comparison utilities
+/// - [`chacha20`] — ChaCha20 stream cipher (RFC 8439)
+/// - [`drbg`] — ChaCha20 DRBG seeded from the kernel; all randomness comes from here
+/// - [`bignum`] — Modular big-integer arithmetic for public-key verification
+/// - [`rsa`] — RSASSA-PKCS1-v1_5 signature verification (RFC 8017)
+/// - [`ecdsa`] — ECDSA signature verification on P-256 and P-384 (FIPS 186-4)
 
 pub mod sha256;
+pub mod sha512;
+pub mod md5;
 pub mod hmac;
 pub mod hkdf;
 pub mod aes;
 pub mod gcm;
 pub mod constant_time;
+pub mod chacha20;
+pub mod drbg;
+pub mod bignum;
+pub mod rsa;
+pub mod ecdsa;
 
 // Re-export the most commonly used items at the crate root for convenience.
 
 pub use sha256::{Sha256, sha256};
+pub use sha512::{Sha512, sha384, sha512};
+pub use md5::{Md5, md5};
 pub use hmac::{HmacSha256, hmac_sha256};
 pub use hkdf::{HkdfSha256, hkdf_sha256};
-pub use aes::{AesKeySchedule, aes_encrypt_block, aes_decrypt_block};
+pub use aes::{AesKeySchedule, aes_encrypt_block, aes_encrypt_blocks2, aes_decrypt_block};
 pub use gcm::AesGcm;
 pub use constant_time::ct_eq;
+pub use drbg::{fill_random, random_bytes};
@@ -0,0 +1,121 @@
+//! RSASSA-PKCS1-v1_5 signature verification (RFC 8017 §8.2.2).
+//!
+//! The signed digest's hash is identified by its length: 32, 48 and 64
+//! bytes are SHA-256, SHA-384 and SHA-512, the hashes X.509 certificates
+//! use with RSA.
+
+use crate::bignum::Modulus;
+use crate::constant_time::ct_eq;
+
+/// DER `DigestInfo` prefixes (RFC 8017 §9.2, note 1), each followed by the
+/// digest itself.
+const SHA256_PREFIX: &[u8] = &[
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01,
+    0x05, 0x00, 0x04, 0x20,
+];
+const SHA384_PREFIX: &[u8] = &[
+    0x30, 0x41, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02,
+    0x05, 0x00, 0x04, 0x30,
+];
+const SHA512_PREFIX: &[u8] = &[
+    0x30, 0x51, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03,
+    0x05, 0x00, 0x04, 0x40,
+];
+
+/// Keys below this size are refused, as browsers do.
+pub const MIN_MODULUS_BITS: usize = 1024;
+
+/// Whether `signature` is a valid PKCS #1 v1.5 signature of `digest` under
+/// the public key with big-endian modulus `n` and exponent `e`.
+pub fn verify_pkcs1_v15(n: &[u8], e: &[u8], digest: &[u8], signature: &[u8]) -> bool {
+    let prefix = match digest.len() {
+        32 => SHA256_PREFIX,
+        48 => SHA384_PREFIX,
+        64 => SHA512_PREFIX,
+        _ => return false,
+    };
+    let Some(modulus) = Modulus::new(n) else {
+        return false;
+    };
+    let k = modulus.byte_len();
+    if k * 8 < MIN_MODULUS_BITS || signature.len() != k || !modulus.contains(signature) {
+        return false;
+    }
+
+    // EM = 0x00 || 0x01 || 0xFF… || 0x00 || DigestInfo
+    let t_len = prefix.len() + digest.len();
+    if k < t_len + 11 {
+        return false;
+    }
+    let mut expected = vec![0xff; k];
+    expected[0] = 0x00;
+    expected[1] = 0x01;
+    expected[k - t_len - 1] = 0x00;
+    expected[k - t_len..k - digest.len()].copy_from_slice(prefix);
+    expected[k - digest.len()..].copy_from_slice(digest);
+
+    let s = modulus.reduce(signature);
+    let m = modulus.pow(&s, e);
+    ct_eq(&modulus.to_be_bytes(&m, k), &expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha256::sha256;
+    use crate::sha512::sha384;
+
+    /// The modulus of a 1024-bit key from `openssl genrsa` (e = 65537).
+    const N: &str = "d47efc146e98add9da1bd58b6a0b0478b4b641175a46e12c9cb05db168ac831d\
+                     3cfd7fa0ab4f11a51d70f92b2c78e160c639329b335e3e1b49f467627a90bb6a\
+                     41552ea11fc549d78204fe2098efa99b06bbaa747fe622aaebb579f8f92a471e\
+                     fc44e465e7426bc68d7902f902319da3dc9f2eba5547f07de7ca1ca5ec13bc11";
+
+    /// `openssl dgst -sha256 -sign` over "abc".
+    const SIG_SHA256: &str = "8bc3a1e3b7c2e40f38faf6430c93f9e2f7be2fd47295b42b6e093aede077589b\
+                              c29c88b90f865300dfea2040264628e1f9077de0c32908f3465a4d9915d0341b\
+                              8ff5c8b4014e6ba4322f69daf1db01553959581eb282167b4a1821c3e7244fce\
+                              449398c291d072a19c925e04aba45e2e5c70cdf53763cf427dee1b3e066fcb7d";
+
+    /// `openssl dgst -sha384 -sign` over "abc".
+    const SIG_SHA384: &str = "20f9155bffd51474330a3d35d315c704fe0f9c6a8b381b3157b8e3514c6fb157\
+                              3f9050db367224142d4ab22b3075813cd9b02d0162cc84b0d8487529087663b2\
+                              df0004a7becd37db2ec449d3e1f1c94350e5e67e67f3c733290f1d76c3c52eb9\
+                              e8bff15c34199bd8f5ac228cea479895e060ae5884cd9477d1ac6b090554790a";
+
+    const E: &[u8] = &[0x01, 0x00, 0x01];
+
+    fn hex_bytes(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_verifies_openssl_signatures() {
+        let n = hex_bytes(N);
+        assert!(verify_pkcs1_v15(&n, E, &sha256(b"abc"), &hex_bytes(SIG_SHA256)));
+        assert!(verify_pkcs1_v15(&n, E, &sha384(b"abc"), &hex_bytes(SIG_SHA384)));
+    }
+
+    #[test]
+    fn test_rejects_wrong_message_or_hash() {
+        let n = hex_bytes(N);
+        assert!(!verify_pkcs1_v15(&n, E, &sha256(b"abd"), &hex_bytes(SIG_SHA256)));
+        assert!(!verify_pkcs1_v15(&n, E, &sha256(b"abc"), &hex_bytes(SIG_SHA384)));
+        let mut tampered = hex_bytes(SIG_SHA256);
+        tampered[10] ^= 1;
+        assert!(!verify_pkcs1_v15(&n, E, &sha256(b"abc"), &tampered));
+    }
+
+    #[test]
+    fn test_rejects_malformed_inputs() {
+        let n = hex_bytes(N);
+        let sig = hex_bytes(SIG_SHA256);
+        assert!(!verify_pkcs1_v15(&n, E, &[0; 20], &sig));
+        assert!(!verify_pkcs1_v15(&n, E, &sha256(b"abc"), &sig[1..]));
+        assert!(!verify_pkcs1_v15(&n[..64], E, &sha256(b"abc"), &sig[..64]));
+        assert!(!verify_pkcs1_v15(&n, E, &sha256(b"abc"), &n));
+    }
+}
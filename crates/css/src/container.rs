@@ -0,0 +1,104 @@
+//! Container queries (CSS Containment Level 3): the preludes of
+//! `@container` rules, evaluated against the size of a query container.
+//!
+//! A query's condition is a media condition over the size features
+//! `width`, `height`, `inline-size`, `block-size`, `aspect-ratio` and
+//! `orientation`; anything else is unknown and never matches.
+
+use crate::media::{FeatureValue, MediaCondition, parse_media_condition};
+use crate::token::CssToken;
+
+/// The prelude of an `@container` rule, e.g. `sidebar (min-width: 400px)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerQuery {
+    /// The `container-name` the query container must have, if any.
+    pub name: Option<String>,
+    pub condition: MediaCondition,
+}
+
+impl ContainerQuery {
+    /// Parse an `@container` prelude: an optional name, then a condition.
+    pub fn parse(prelude: &[CssToken]) -> Option<Self> {
+        let start = prelude.iter().position(|t| *t != CssToken::Whitespace)?;
+        let (name, rest) = match &prelude[start] {
+            CssToken::Ident(name) if !is_reserved_name(name) => (Some(name.clone()), &prelude[start + 1..]),
+            _ => (None, &prelude[start..]),
+        };
+        Some(Self { name, condition: parse_media_condition(rest)? })
+    }
+
+    /// The axes the query's features need a container to measure:
+    /// `(width, height)`.
+    pub fn axes(&self) -> (bool, bool) {
+        fn visit(condition: &MediaCondition, axes: &mut (bool, bool)) {
+            match condition {
+                MediaCondition::Feature(feature) => match feature.name.as_str() {
+                    "width" | "inline-size" => axes.0 = true,
+                    "height" | "block-size" => axes.1 = true,
+                    "aspect-ratio" | "orientation" => *axes = (true, true),
+                    _ => {}
+                },
+                MediaCondition::Not(inner) => visit(inner, axes),
+                MediaCondition::And(items) | MediaCondition::Or(items) => items.iter().for_each(|c| visit(c, axes)),
+                MediaCondition::Unknown => {}
+            }
+        }
+        let mut axes = (false, false);
+        visit(&self.condition, &mut axes);
+        axes
+    }
+
+    /// Whether the query holds for a container whose content box is
+    /// `width` by `height`; an axis the container doesn't contain is
+    /// `None`, and features of it are unknown.
+    pub fn matches(&self, width: Option<f32>, height: Option<f32>) -> bool {
+        let feature = |name: &str| match name {
+            "width" | "inline-size" => width.map(FeatureValue::Number),
+            "height" | "block-size" => height.map(FeatureValue::Number),
+            "aspect-ratio" => Some(FeatureValue::Number(width? / height.filter(|h| *h > 0.0)?)),
+            "orientation" => {
+                let portrait = height? >= width?;
+                Some(FeatureValue::Ident(if portrait { "portrait" } else { "landscape" }.to_string()))
+            }
+            _ => None,
+        };
+        self.condition.evaluate_with(&feature) == Some(true)
+    }
+}
+
+fn is_reserved_name(name: &str) -> bool {
+    ["not", "and", "or", "none", "initial", "inherit", "unset", "default"]
+        .iter()
+        .any(|reserved| name.eq_ignore_ascii_case(reserved))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token::CssTokenizer;
+
+    fn query(prelude: &str) -> Option<ContainerQuery> {
+        ContainerQuery::parse(&CssTokenizer::new(prelude).tokenize_all())
+    }
+
+    #[test]
+    fn test_container_queries_test_the_container_size() {
+        let card = query("card (min-width: 400px) and (orientation: landscape)").unwrap();
+        assert_eq!(card.name.as_deref(), Some("card"));
+        assert_eq!(card.axes(), (true, true));
+        assert!(card.matches(Some(500.0), Some(300.0)));
+        assert!(!card.matches(Some(300.0), Some(200.0)));
+        assert!(!card.matches(Some(500.0), None), "inline-size containers have no orientation");
+
+        let range = query("(200px < inline-size <= 600px)").unwrap();
+        assert_eq!(range.name, None);
+        assert_eq!(range.axes(), (true, false));
+        assert!(range.matches(Some(600.0), None));
+        assert!(!range.matches(Some(200.0), None));
+
+        assert!(query("not (width > 10px)").unwrap().matches(Some(5.0), None));
+        assert!(!query("style(--theme: dark)").unwrap().matches(Some(5.0), Some(5.0)));
+        assert_eq!(query("card"), None);
+        assert_eq!(query(""), None);
+    }
+}
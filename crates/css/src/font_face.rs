@@ -0,0 +1,334 @@
+//! `@font-face` rules.
+//!
+//! Descriptors are read straight from the block's tokens rather than as
+//! property values: `unicode-range` in particular does not survive the
+//! value parser (`U+0025-00FF` lexes as an ident, a number and a
+//! dimension), so its text is pieced back together from the tokens.
+
+use std::rc::Rc;
+
+use crate::media::MediaQueryList;
+use crate::supports::SupportsQuery;
+use crate::token::CssToken;
+
+/// A parsed `@font-face` rule.
+#[derive(Debug, Clone)]
+pub struct FontFaceRule {
+    /// The family name the face is registered under.
+    pub family: String,
+    /// Where to load the face from, in order of preference.
+    pub sources: Vec<FontSource>,
+    /// The weights the face covers, inclusive.
+    pub weight: (u16, u16),
+    pub style: FontFaceStyle,
+    /// Code point ranges the face is used for, inclusive; empty for all.
+    pub unicode_range: Vec<(u32, u32)>,
+    pub display: FontDisplay,
+    /// Queries of the `@media` rules the face is nested in, outermost first.
+    pub media: Vec<Rc<MediaQueryList>>,
+    /// Conditions of the `@supports` rules the face is nested in.
+    pub supports: Vec<Rc<SupportsQuery>>,
+}
+
+impl FontFaceRule {
+    /// Whether the face is meant for code point `ch`.
+    pub fn covers(&self, ch: char) -> bool {
+        let ch = ch as u32;
+        self.unicode_range.is_empty() || self.unicode_range.iter().any(|&(start, end)| (start..=end).contains(&ch))
+    }
+}
+
+/// One entry of the `src` descriptor.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontSource {
+    /// `url(...)` with its `format()` and `tech()` hints, lowercased.
+    Url { url: String, format: Option<String>, tech: Vec<String> },
+    /// `local(...)`: a font installed on the system, by full name.
+    Local(String),
+}
+
+/// The `font-style` descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontFaceStyle {
+    Normal,
+    Italic,
+    Oblique,
+}
+
+/// The `font-display` descriptor: how long text waits for the face.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontDisplay {
+    Auto,
+    Block,
+    Swap,
+    Fallback,
+    Optional,
+}
+
+/// Parse the block of an `@font-face` rule. Without a `font-family` and a
+/// usable `src` the rule is invalid.
+pub fn parse_font_face(block: &[CssToken]) -> Option<FontFaceRule> {
+    let mut face = FontFaceRule {
+        family: String::new(),
+        sources: Vec::new(),
+        weight: (400, 400),
+        style: FontFaceStyle::Normal,
+        unicode_range: Vec::new(),
+        display: FontDisplay::Auto,
+        media: Vec::new(),
+        supports: Vec::new(),
+    };
+    for declaration in block.split(|t| *t == CssToken::Semicolon) {
+        let declaration = trim(declaration);
+        let Some((CssToken::Ident(name), rest)) = declaration.split_first() else {
+            continue;
+        };
+        let value = match trim(rest).split_first() {
+            Some((CssToken::Colon, value)) => trim(value),
+            _ => continue,
+        };
+        match name.to_ascii_lowercase().as_str() {
+            "font-family" => {
+                if let Some(family) = parse_family(value) {
+                    face.family = family;
+                }
+            }
+            "src" => face.sources = parse_sources(value),
+            "font-weight" => {
+                if let Some(weight) = parse_weight(value) {
+                    face.weight = weight;
+                }
+            }
+            "font-style" => {
+                let keyword = value.iter().find_map(|t| match t {
+                    CssToken::Ident(ident) => Some(ident.to_ascii_lowercase()),
+                    _ => None,
+                });
+                face.style = match keyword.as_deref() {
+                    Some("italic") => FontFaceStyle::Italic,
+                    Some("oblique") => FontFaceStyle::Oblique,
+                    _ => FontFaceStyle::Normal,
+                };
+            }
+            "unicode-range" => {
+                let ranges: Option<Vec<_>> =
+                    value.split(|t| *t == CssToken::Comma).map(|range| parse_unicode_range(trim(range))).collect();
+                if let Some(ranges) = ranges {
+                    face.unicode_range = ranges;
+                }
+            }
+            "font-display" => {
+                face.display = match value {
+                    [CssToken::Ident(ident)] => match ident.to_ascii_lowercase().as_str() {
+                        "block" => FontDisplay::Block,
+                        "swap" => FontDisplay::Swap,
+                        "fallback" => FontDisplay::Fallback,
+                        "optional" => FontDisplay::Optional,
+                        _ => FontDisplay::Auto,
+                    },
+                    _ => FontDisplay::Auto,
+                };
+            }
+            _ => {}
+        }
+    }
+    (!face.family.is_empty() && !face.sources.is_empty()).then_some(face)
+}
+
+fn trim(tokens: &[CssToken]) -> &[CssToken] {
+    let start = tokens.iter().position(|t| *t != CssToken::Whitespace).unwrap_or(tokens.len());
+    let end = tokens.iter().rposition(|t| *t != CssToken::Whitespace).map_or(start, |i| i + 1);
+    &tokens[start..end]
+}
+
+/// A family name: one string, or identifiers joined by single spaces.
+fn parse_family(value: &[CssToken]) -> Option<String> {
+    match value {
+        [CssToken::String(name)] => Some(name.clone()),
+        _ => {
+            let mut words = Vec::new();
+            for token in value {
+                match token {
+                    CssToken::Ident(word) => words.push(word.as_str()),
+                    CssToken::Whitespace => {}
+                    _ => return None,
+                }
+            }
+            (!words.is_empty()).then(|| words.join(" "))
+        }
+    }
+}
+
+/// The string or identifiers inside a function's parentheses.
+fn function_argument(args: &[CssToken]) -> Option<String> {
+    parse_family(trim(args)).map(|arg| arg.to_ascii_lowercase())
+}
+
+fn parse_sources(value: &[CssToken]) -> Vec<FontSource> {
+    let mut sources = Vec::new();
+    let mut pos = 0;
+    'entries: while pos < value.len() {
+        let mut source = None;
+        while pos < value.len() && value[pos] != CssToken::Comma {
+            match &value[pos] {
+                CssToken::Url(url) => {
+                    source = Some(FontSource::Url { url: url.clone(), format: None, tech: Vec::new() });
+                    pos += 1;
+                }
+                CssToken::Function(name) => {
+                    let end = value[pos..].iter().position(|t| *t == CssToken::RParen).map_or(value.len(), |i| pos + i);
+                    let args = &value[pos + 1..end];
+                    match (name.to_ascii_lowercase().as_str(), &mut source) {
+                        ("url", None) => match trim(args) {
+                            [CssToken::String(url)] => {
+                                source = Some(FontSource::Url { url: url.clone(), format: None, tech: Vec::new() })
+                            }
+                            _ => source = None,
+                        },
+                        ("local", None) => {
+                            source = parse_family(trim(args)).map(FontSource::Local);
+                        }
+                        ("format", Some(FontSource::Url { format, .. })) => *format = function_argument(args),
+                        ("tech", Some(FontSource::Url { tech, .. })) => {
+                            *tech = args.split(|t| *t == CssToken::Comma).filter_map(function_argument).collect();
+                        }
+                        _ => {
+                            // Skip the rest of an entry we can't read.
+                            pos = value[end..].iter().position(|t| *t == CssToken::Comma).map_or(value.len(), |i| end + i) + 1;
+                            continue 'entries;
+                        }
+                    }
+                    pos = end + 1;
+                }
+                _ => pos += 1,
+            }
+        }
+        sources.extend(source);
+        pos += 1;
+    }
+    sources
+}
+
+/// `font-weight`: `normal`, `bold`, a number, or a range of two.
+fn parse_weight(value: &[CssToken]) -> Option<(u16, u16)> {
+    let weights: Vec<u16> = value
+        .iter()
+        .filter(|t| **t != CssToken::Whitespace)
+        .map(|t| match t {
+            CssToken::Ident(ident) if ident.eq_ignore_ascii_case("normal") => Some(400),
+            CssToken::Ident(ident) if ident.eq_ignore_ascii_case("bold") => Some(700),
+            CssToken::Number { value, .. } if (1.0..=1000.0).contains(value) => Some(value.round() as u16),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+    match weights[..] {
+        [weight] => Some((weight, weight)),
+        [a, b] => Some((a.min(b), a.max(b))),
+        _ => None,
+    }
+}
+
+/// One `U+...` range: a start, an optional `-end`, or `?` wildcards for
+/// the low hex digits.
+fn parse_unicode_range(tokens: &[CssToken]) -> Option<(u32, u32)> {
+    let (first, rest) = tokens.split_first()?;
+    if !matches!(first, CssToken::Ident(u) if u.eq_ignore_ascii_case("u")) {
+        return None;
+    }
+    let mut text = String::new();
+    for token in rest {
+        match token {
+            CssToken::Delim(c) => text.push(*c),
+            CssToken::Ident(ident) => text.push_str(ident),
+            CssToken::Number { value, is_integer: true } => push_integer(&mut text, *value),
+            CssToken::Dimension { value, unit } if value.fract() == 0.0 => {
+                push_integer(&mut text, *value);
+                text.push_str(unit);
+            }
+            _ => return None,
+        }
+    }
+    let text = text.strip_prefix('+')?;
+    let (start, end) = match text.split_once('-') {
+        Some((start, end)) => (start, Some(end)),
+        None => (text, None),
+    };
+    let hex = |digits: &str| {
+        (!digits.is_empty() && digits.len() <= 6).then_some(())?;
+        u32::from_str_radix(digits, 16).ok()
+    };
+    let range = match end {
+        Some(end) => (hex(start)?, hex(end)?),
+        None if start.contains('?') => {
+            let digits = start.trim_end_matches('?');
+            if digits.contains('?') || start.len() > 6 {
+                return None;
+            }
+            let low = hex(&start.replace('?', "0"))?;
+            let wild = 16u32.pow((start.len() - digits.len()) as u32);
+            (low, low + wild - 1)
+        }
+        None => (hex(start)?, hex(start)?),
+    };
+    (range.0 <= range.1 && range.1 <= 0x10ffff).then_some(range)
+}
+
+/// Append the text of a lexed integer: its sign, then its digits.
+fn push_integer(text: &mut String, value: f64) {
+    text.push(if value.is_sign_negative() { '-' } else { '+' });
+    text.push_str(&(value.abs() as u64).to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::media::Device;
+    use crate::parser::parse_stylesheet;
+
+    #[test]
+    fn test_descriptors_are_parsed() {
+        let sheet = parse_stylesheet(
+            r#"
+            @font-face {
+                font-family: "Open Sans";
+                src: local(Open Sans Regular), url(/fonts/open.woff2) format("woff2"),
+                     url('open.woff') format(woff) tech(variations, color-COLRv1), url(open.eot);
+                font-weight: 300 700;
+                font-style: italic;
+                unicode-range: U+0025-00FF, u+4??, U+1F600, U+E000-F8FF;
+                font-display: swap;
+            }
+            p { color: red; }
+            @media print { @font-face { font-family: Print Face; src: url(p.ttf); } }
+            @font-face { font-family: Broken; }
+            "#,
+        );
+        assert_eq!(sheet.rules.len(), 1);
+        assert_eq!(sheet.font_faces.len(), 2);
+
+        let face = &sheet.font_faces[0];
+        assert_eq!(face.family, "Open Sans");
+        assert_eq!(
+            face.sources,
+            [
+                FontSource::Local("Open Sans Regular".into()),
+                FontSource::Url { url: "/fonts/open.woff2".into(), format: Some("woff2".into()), tech: vec![] },
+                FontSource::Url {
+                    url: "open.woff".into(),
+                    format: Some("woff".into()),
+                    tech: vec!["variations".into(), "color-colrv1".into()],
+                },
+                FontSource::Url { url: "open.eot".into(), format: None, tech: vec![] },
+            ]
+        );
+        assert_eq!(face.weight, (300, 700));
+        assert_eq!(face.style, FontFaceStyle::Italic);
+        assert_eq!(face.unicode_range, [(0x25, 0xff), (0x400, 0x4ff), (0x1f600, 0x1f600), (0xe000, 0xf8ff)]);
+        assert_eq!(face.display, FontDisplay::Swap);
+        assert!(face.covers('é') && face.covers('😀') && !face.covers('中'));
+
+        let print = &sheet.font_faces[1];
+        assert_eq!((print.family.as_str(), print.weight), ("Print Face", (400, 400)));
+        assert!(!print.media[0].matches(&Device::default()));
+    }
+}
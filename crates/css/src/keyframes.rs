@@ -0,0 +1,134 @@
+//! `@keyframes` rules.
+//!
+//! A keyframe's selector is a list of `from`, `to` and percentages; each
+//! offset becomes its own [`Keyframe`] carrying the block's declarations,
+//! so a rule's keyframes are sorted by offset, ties in source order.
+
+use std::rc::Rc;
+
+use crate::media::MediaQueryList;
+use crate::parser::{Declaration, parse_declaration_block};
+use crate::supports::SupportsQuery;
+use crate::token::CssToken;
+
+/// A parsed `@keyframes` rule.
+#[derive(Debug, Clone)]
+pub struct KeyframesRule {
+    /// The name animations refer to it by. Identifiers are lowercased, as
+    /// the value parser lowercases the `animation-name` that names them.
+    pub name: String,
+    /// The keyframes, by offset.
+    pub keyframes: Vec<Keyframe>,
+    /// Queries of the `@media` rules the rule is nested in, outermost first.
+    pub media: Vec<Rc<MediaQueryList>>,
+    /// Conditions of the `@supports` rules the rule is nested in.
+    pub supports: Vec<Rc<SupportsQuery>>,
+}
+
+/// One keyframe of a [`KeyframesRule`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframe {
+    /// Where in an iteration the keyframe sits, from 0 to 1.
+    pub offset: f32,
+    /// The keyframe's declarations; `!important` ones are dropped.
+    pub declarations: Vec<Declaration>,
+}
+
+/// Parse an `@keyframes` rule from its prelude and the inside of its
+/// block. A rule without a valid name is invalid; keyframes whose
+/// selector doesn't parse are dropped.
+pub fn parse_keyframes(prelude: &[CssToken], block: &[CssToken]) -> Option<KeyframesRule> {
+    let name = match trim(prelude) {
+        [CssToken::Ident(name)] if !is_reserved_name(name) => name.to_ascii_lowercase(),
+        [CssToken::String(name)] if !name.is_empty() => name.clone(),
+        _ => return None,
+    };
+    let mut keyframes = Vec::new();
+    let mut pos = 0;
+    while pos < block.len() {
+        let Some(open) = block[pos..].iter().position(|t| *t == CssToken::LBrace).map(|i| pos + i) else {
+            break;
+        };
+        let mut depth = 0;
+        let close = block[open..]
+            .iter()
+            .position(|t| {
+                match t {
+                    CssToken::LBrace => depth += 1,
+                    CssToken::RBrace => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            })
+            .map_or(block.len(), |i| open + i);
+        let selector = &block[pos..open];
+        let declarations: Vec<Declaration> = parse_declaration_block(&block[open + 1..close])
+            .into_iter()
+            .filter(|d| !d.important)
+            .collect();
+        if let Some(offsets) = parse_offsets(selector) {
+            keyframes.extend(offsets.into_iter().map(|offset| Keyframe { offset, declarations: declarations.clone() }));
+        }
+        pos = close + 1;
+    }
+    keyframes.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+    Some(KeyframesRule { name, keyframes, media: Vec::new(), supports: Vec::new() })
+}
+
+fn is_reserved_name(name: &str) -> bool {
+    ["none", "initial", "inherit", "unset", "default"].iter().any(|reserved| name.eq_ignore_ascii_case(reserved))
+}
+
+fn trim(tokens: &[CssToken]) -> &[CssToken] {
+    let start = tokens.iter().position(|t| *t != CssToken::Whitespace).unwrap_or(tokens.len());
+    let end = tokens.iter().rposition(|t| *t != CssToken::Whitespace).map_or(start, |i| i + 1);
+    &tokens[start..end]
+}
+
+/// The offsets a keyframe selector lists, or `None` if any is invalid.
+fn parse_offsets(selector: &[CssToken]) -> Option<Vec<f32>> {
+    selector
+        .split(|t| *t == CssToken::Comma)
+        .map(|offset| match trim(offset) {
+            [CssToken::Ident(ident)] if ident.eq_ignore_ascii_case("from") => Some(0.0),
+            [CssToken::Ident(ident)] if ident.eq_ignore_ascii_case("to") => Some(1.0),
+            [CssToken::Percentage(pct)] if (0.0..=100.0).contains(pct) => Some((*pct / 100.0) as f32),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_stylesheet;
+    use crate::value::CssValue;
+
+    #[test]
+    fn test_keyframes_are_parsed_by_offset() {
+        let sheet = parse_stylesheet(
+            r#"
+            @keyframes Pulse {
+                to { opacity: 1 }
+                from, 50% { opacity: 0; color: red !important; }
+                150% { opacity: 2 }
+                bogus { opacity: 3 }
+            }
+            @-webkit-keyframes "Slide In" { 0% { left: 0 } }
+            @media print { @keyframes pulse { from { opacity: 1 } } }
+            @keyframes none { from { opacity: 1 } }
+            p { animation: pulse 1s; }
+            "#,
+        );
+        assert_eq!(sheet.rules.len(), 1);
+        let names: Vec<&str> = sheet.keyframes.iter().map(|k| k.name.as_str()).collect();
+        assert_eq!(names, ["pulse", "Slide In", "pulse"]);
+
+        let pulse = &sheet.keyframes[0];
+        let offsets: Vec<f32> = pulse.keyframes.iter().map(|k| k.offset).collect();
+        assert_eq!(offsets, [0.0, 0.5, 1.0]);
+        assert_eq!(pulse.keyframes[0].declarations.len(), 1);
+        assert_eq!(pulse.keyframes[2].declarations[0].value, [CssValue::Number(1.0)]);
+        assert!(pulse.media.is_empty());
+        assert_eq!(sheet.keyframes[2].media.len(), 1);
+    }
+}
@@ -2,6 +2,11 @@ pub mod token;
 pub mod selector;
 pub mod value;
 pub mod parser;
+pub mod media;
+pub mod supports;
+pub mod font_face;
+pub mod keyframes;
+pub mod container;
 
 pub use token::{CssToken, CssTokenizer};
 pub use selector::{
@@ -9,4 +14,9 @@ pub use selector::{
     AttrOp, PseudoClass, PseudoElement, Specificity, compute_specificity,
 };
 pub use value::{CssValue, LengthUnit, CssColor};
-pub use parser::{CssRule, Declaration, Stylesheet, parse_stylesheet, parse_declaration_block};
+pub use parser::{CssRule, Declaration, ImportRule, Stylesheet, parse_stylesheet, parse_declaration_block};
+pub use media::{Device, MediaQueryList};
+pub use supports::{SupportsCondition, SupportsQuery};
+pub use font_face::{FontDisplay, FontFaceRule, FontFaceStyle, FontSource};
+pub use keyframes::{Keyframe, KeyframesRule};
+pub use container::ContainerQuery;
@@ -0,0 +1,599 @@
+//! Media queries (Media Queries Level 4): parsing `@media` preludes and
+//! `media` attributes, and evaluating them against a [`Device`].
+
+use crate::token::{CssToken, CssTokenizer};
+
+/// The output device and user preferences that media queries test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Device {
+    pub media_type: MediaType,
+    /// Viewport width in CSS pixels.
+    pub width: f32,
+    /// Viewport height in CSS pixels.
+    pub height: f32,
+    /// Device pixels per CSS pixel.
+    pub resolution: f32,
+    pub prefers_dark: bool,
+    pub prefers_reduced_motion: bool,
+}
+
+impl Device {
+    /// A screen with a `width`×`height` viewport and default preferences.
+    pub fn screen(width: f32, height: f32) -> Self {
+        Self {
+            media_type: MediaType::Screen,
+            width,
+            height,
+            resolution: 1.0,
+            prefers_dark: false,
+            prefers_reduced_motion: false,
+        }
+    }
+
+    /// The device's value for a (lowercase, unprefixed) media feature.
+    fn feature(&self, name: &str) -> Option<FeatureValue> {
+        let ident = |s: &str| FeatureValue::Ident(s.to_string());
+        Some(match name {
+            "width" | "device-width" => FeatureValue::Number(self.width),
+            "height" | "device-height" => FeatureValue::Number(self.height),
+            "aspect-ratio" | "device-aspect-ratio" => FeatureValue::Number(self.width / self.height.max(1.0)),
+            "resolution" | "device-pixel-ratio" => FeatureValue::Number(self.resolution),
+            "orientation" => ident(if self.height >= self.width { "portrait" } else { "landscape" }),
+            "prefers-color-scheme" => ident(if self.prefers_dark { "dark" } else { "light" }),
+            "prefers-reduced-motion" => ident(if self.prefers_reduced_motion { "reduce" } else { "no-preference" }),
+            "hover" | "any-hover" => ident("hover"),
+            "pointer" | "any-pointer" => ident("fine"),
+            _ => return None,
+        })
+    }
+}
+
+impl Default for Device {
+    fn default() -> Self {
+        Self::screen(1280.0, 800.0)
+    }
+}
+
+/// A media type. Types other than `all`, `screen` and `print` never match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaType {
+    All,
+    Screen,
+    Print,
+    Other,
+}
+
+/// A comma-separated list of media queries; it matches if any query does.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaQueryList {
+    /// An empty list matches every device.
+    pub queries: Vec<MediaQuery>,
+}
+
+/// One media query, e.g. `not print and (min-width: 600px)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaQuery {
+    /// `not`: the query matches when the rest doesn't.
+    pub negated: bool,
+    pub media_type: MediaType,
+    pub condition: Option<MediaCondition>,
+}
+
+/// A media condition: features combined with `and`, `or` and `not`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MediaCondition {
+    Feature(MediaFeature),
+    Not(Box<MediaCondition>),
+    And(Vec<MediaCondition>),
+    Or(Vec<MediaCondition>),
+    /// A parenthesized expression or function that isn't understood.
+    /// It is neither true nor false, so it never makes a query match.
+    Unknown,
+}
+
+/// A media feature test, e.g. `(orientation: portrait)` or
+/// `(400px <= width < 800px)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaFeature {
+    /// Lowercase, with any `min-`/`max-` and `-webkit-` prefix removed.
+    pub name: String,
+    pub test: FeatureTest,
+}
+
+/// How a [`MediaFeature`] compares the device's value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeatureTest {
+    /// `(name)`: the value isn't zero, `none` or `no-preference`.
+    Boolean,
+    /// `(name: value)`.
+    Equals(FeatureValue),
+    /// `min-`/`max-` features and range syntax: each `feature <op> value`
+    /// comparison must hold.
+    Range(Vec<(Comparison, FeatureValue)>),
+}
+
+/// A media feature value. Lengths are in CSS pixels, resolutions in
+/// dppx and ratios are divided out.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeatureValue {
+    Number(f32),
+    Ident(String),
+}
+
+/// A range comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+}
+
+impl Comparison {
+    /// The operator that holds with the operands swapped.
+    fn flipped(self) -> Self {
+        match self {
+            Comparison::Lt => Comparison::Gt,
+            Comparison::Le => Comparison::Ge,
+            Comparison::Gt => Comparison::Lt,
+            Comparison::Ge => Comparison::Le,
+            Comparison::Eq => Comparison::Eq,
+        }
+    }
+
+    fn holds(self, a: f32, b: f32) -> bool {
+        match self {
+            Comparison::Lt => a < b,
+            Comparison::Le => a <= b,
+            Comparison::Gt => a > b,
+            Comparison::Ge => a >= b,
+            Comparison::Eq => (a - b).abs() < 1e-3,
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Evaluation
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl MediaQueryList {
+    /// Parse a `media` attribute value or other standalone query list.
+    pub fn parse(input: &str) -> Self {
+        parse_media_query_list(&CssTokenizer::new(input).tokenize_all())
+    }
+
+    pub fn matches(&self, device: &Device) -> bool {
+        self.queries.is_empty() || self.queries.iter().any(|q| q.matches(device))
+    }
+}
+
+impl MediaQuery {
+    /// The query that is never true, standing in for one that didn't parse.
+    fn not_all() -> Self {
+        Self { negated: true, media_type: MediaType::All, condition: None }
+    }
+
+    pub fn matches(&self, device: &Device) -> bool {
+        let type_matches = match self.media_type {
+            MediaType::All => true,
+            MediaType::Other => false,
+            t => t == device.media_type,
+        };
+        // An unknown result stays false even under `not`.
+        let result = match &self.condition {
+            Some(condition) if type_matches => condition.evaluate(device),
+            _ => Some(type_matches),
+        };
+        result.is_some_and(|matched| matched != self.negated)
+    }
+}
+
+impl MediaCondition {
+    /// Three-valued: `None` when the answer is unknown.
+    pub fn evaluate(&self, device: &Device) -> Option<bool> {
+        self.evaluate_with(&|name| device.feature(name))
+    }
+
+    /// [`evaluate`](Self::evaluate) against whatever `feature` gives the
+    /// value of each feature by name, like a query container's size.
+    pub fn evaluate_with(&self, feature: &dyn Fn(&str) -> Option<FeatureValue>) -> Option<bool> {
+        match self {
+            MediaCondition::Feature(test) => test.evaluate_with(feature),
+            MediaCondition::Not(inner) => inner.evaluate_with(feature).map(|v| !v),
+            MediaCondition::And(items) => {
+                let results: Vec<_> = items.iter().map(|c| c.evaluate_with(feature)).collect();
+                if results.contains(&Some(false)) {
+                    Some(false)
+                } else if results.contains(&None) {
+                    None
+                } else {
+                    Some(true)
+                }
+            }
+            MediaCondition::Or(items) => {
+                let results: Vec<_> = items.iter().map(|c| c.evaluate_with(feature)).collect();
+                if results.contains(&Some(true)) {
+                    Some(true)
+                } else if results.contains(&None) {
+                    None
+                } else {
+                    Some(false)
+                }
+            }
+            MediaCondition::Unknown => None,
+        }
+    }
+}
+
+impl MediaFeature {
+    /// `None` for features the device doesn't know or mistyped values.
+    pub fn evaluate(&self, device: &Device) -> Option<bool> {
+        self.evaluate_with(&|name| device.feature(name))
+    }
+
+    /// [`evaluate`](Self::evaluate) against the values `feature` gives.
+    pub fn evaluate_with(&self, feature: &dyn Fn(&str) -> Option<FeatureValue>) -> Option<bool> {
+        let actual = feature(&self.name)?;
+        match (&self.test, &actual) {
+            (FeatureTest::Boolean, FeatureValue::Number(n)) => Some(*n != 0.0),
+            (FeatureTest::Boolean, FeatureValue::Ident(s)) => Some(s != "none" && s != "no-preference"),
+            (FeatureTest::Equals(FeatureValue::Number(v)), FeatureValue::Number(n)) => {
+                Some(Comparison::Eq.holds(*n, *v))
+            }
+            (FeatureTest::Equals(FeatureValue::Ident(v)), FeatureValue::Ident(s)) => Some(v == s),
+            (FeatureTest::Range(comparisons), FeatureValue::Number(n)) => {
+                let mut all = true;
+                for (op, value) in comparisons {
+                    let FeatureValue::Number(v) = value else {
+                        return None;
+                    };
+                    all &= op.holds(*n, *v);
+                }
+                Some(all)
+            }
+            _ => None,
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Parsing
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Parse a media query list from the tokens of an `@media` prelude.
+/// Queries that don't parse become `not all`, leaving the others intact.
+pub fn parse_media_query_list(tokens: &[CssToken]) -> MediaQueryList {
+    if tokens.iter().all(|t| *t == CssToken::Whitespace) {
+        return MediaQueryList { queries: Vec::new() };
+    }
+    let queries = split_top_level_commas(tokens)
+        .into_iter()
+        .map(|query| parse_media_query(query).unwrap_or_else(MediaQuery::not_all))
+        .collect();
+    MediaQueryList { queries }
+}
+
+/// Parse a bare condition, like the one after an `@container` name.
+pub(crate) fn parse_media_condition(tokens: &[CssToken]) -> Option<MediaCondition> {
+    let mut p = Cursor { tokens, pos: 0 };
+    let condition = p.condition(true)?;
+    p.at_end().then_some(condition)
+}
+
+fn split_top_level_commas(tokens: &[CssToken]) -> Vec<&[CssToken]> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            CssToken::LParen | CssToken::Function(_) => depth += 1,
+            CssToken::RParen => depth = depth.saturating_sub(1),
+            CssToken::Comma if depth == 0 => {
+                parts.push(&tokens[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&tokens[start..]);
+    parts
+}
+
+fn parse_media_query(tokens: &[CssToken]) -> Option<MediaQuery> {
+    let mut p = Cursor { tokens, pos: 0 };
+    let query = match p.peek_ident() {
+        // `not (...)` is a condition; `not screen` negates a type.
+        Some(word) if (word == "not" || word == "only") && matches!(p.peek_nth(1), Some(CssToken::Ident(_))) => {
+            p.next();
+            let media_type = p.next_ident()?;
+            p.typed_query(word == "not", &media_type)?
+        }
+        Some(word) if !matches!(word.as_str(), "not" | "only" | "and" | "or") => {
+            p.next();
+            p.typed_query(false, &word)?
+        }
+        _ => MediaQuery { negated: false, media_type: MediaType::All, condition: Some(p.condition(true)?) },
+    };
+    p.at_end().then_some(query)
+}
+
+/// Cursor over prelude tokens that skips whitespace.
+struct Cursor<'a> {
+    tokens: &'a [CssToken],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.tokens.get(self.pos) == Some(&CssToken::Whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.pos >= self.tokens.len()
+    }
+
+    fn next(&mut self) -> Option<&'a CssToken> {
+        self.skip_whitespace();
+        let token = self.tokens.get(self.pos)?;
+        self.pos += 1;
+        Some(token)
+    }
+
+    /// The `n`th upcoming non-whitespace token.
+    fn peek_nth(&self, n: usize) -> Option<&'a CssToken> {
+        self.tokens[self.pos.min(self.tokens.len())..].iter().filter(|t| **t != CssToken::Whitespace).nth(n)
+    }
+
+    fn peek_ident(&self) -> Option<String> {
+        match self.peek_nth(0)? {
+            CssToken::Ident(name) => Some(name.to_ascii_lowercase()),
+            _ => None,
+        }
+    }
+
+    fn next_ident(&mut self) -> Option<String> {
+        let name = self.peek_ident()?;
+        self.next();
+        Some(name)
+    }
+
+    /// The rest of `[not|only] <type> [and <condition-without-or>]`.
+    fn typed_query(&mut self, negated: bool, media_type: &str) -> Option<MediaQuery> {
+        let media_type = match media_type {
+            "all" => MediaType::All,
+            "screen" => MediaType::Screen,
+            "print" => MediaType::Print,
+            "not" | "only" | "and" | "or" | "layer" => return None,
+            _ => MediaType::Other,
+        };
+        let condition = if self.peek_ident().as_deref() == Some("and") {
+            self.next();
+            Some(self.condition(false)?)
+        } else {
+            None
+        };
+        Some(MediaQuery { negated, media_type, condition })
+    }
+
+    /// `not <in-parens>`, or `<in-parens>` joined by all-`and` or all-`or`.
+    fn condition(&mut self, allow_or: bool) -> Option<MediaCondition> {
+        if self.peek_ident().as_deref() == Some("not") {
+            self.next();
+            return Some(MediaCondition::Not(Box::new(self.in_parens()?)));
+        }
+        let mut items = vec![self.in_parens()?];
+        let mut joiner: Option<String> = None;
+        while let Some(word) = self.peek_ident() {
+            if !(word == "and" || (word == "or" && allow_or)) || joiner.as_ref().is_some_and(|j| *j != word) {
+                break;
+            }
+            self.next();
+            items.push(self.in_parens()?);
+            joiner = Some(word);
+        }
+        Some(match joiner.as_deref() {
+            None => items.pop()?,
+            Some("and") => MediaCondition::And(items),
+            _ => MediaCondition::Or(items),
+        })
+    }
+
+    /// `( <condition> )`, `( <feature> )` or anything else in parentheses
+    /// or a function, which is unknown.
+    fn in_parens(&mut self) -> Option<MediaCondition> {
+        let opener = self.next()?;
+        if !matches!(opener, CssToken::LParen | CssToken::Function(_)) {
+            return None;
+        }
+        let start = self.pos;
+        let mut depth = 1;
+        while depth > 0 {
+            match self.tokens.get(self.pos)? {
+                CssToken::LParen | CssToken::Function(_) => depth += 1,
+                CssToken::RParen => depth -= 1,
+                _ => {}
+            }
+            self.pos += 1;
+        }
+        let inner = &self.tokens[start..self.pos - 1];
+        if matches!(opener, CssToken::Function(_)) {
+            return Some(MediaCondition::Unknown);
+        }
+        let mut nested = Cursor { tokens: inner, pos: 0 };
+        if let Some(condition) = nested.condition(true)
+            && nested.at_end()
+        {
+            return Some(condition);
+        }
+        Some(parse_feature(inner).map_or(MediaCondition::Unknown, MediaCondition::Feature))
+    }
+}
+
+/// The inside of `(...)` as a media feature: `name`, `name: value`, or a
+/// range like `width >= 600px` or `400px < width <= 800px`.
+fn parse_feature(tokens: &[CssToken]) -> Option<MediaFeature> {
+    let tokens: Vec<&CssToken> = tokens.iter().filter(|t| **t != CssToken::Whitespace).collect();
+    let name_of = |token: &CssToken| match token {
+        CssToken::Ident(name) => Some(name.to_ascii_lowercase()),
+        _ => None,
+    };
+
+    if tokens.len() == 1 {
+        return Some(MediaFeature { name: unprefixed(&name_of(tokens[0])?), test: FeatureTest::Boolean });
+    }
+    if tokens.get(1) == Some(&&CssToken::Colon) {
+        let name = unprefixed(&name_of(tokens[0])?);
+        let value = parse_value(&tokens[2..])?;
+        let (name, test) = if let Some(name) = name.strip_prefix("min-") {
+            (name, FeatureTest::Range(vec![(Comparison::Ge, value)]))
+        } else if let Some(name) = name.strip_prefix("max-") {
+            (name, FeatureTest::Range(vec![(Comparison::Le, value)]))
+        } else {
+            (name.as_str(), FeatureTest::Equals(value))
+        };
+        return Some(MediaFeature { name: name.to_string(), test });
+    }
+
+    // Range syntax: split into operands and operators.
+    let mut operands: Vec<Vec<&CssToken>> = vec![Vec::new()];
+    let mut operators = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let op = match tokens[i] {
+            CssToken::Delim('<') => Comparison::Lt,
+            CssToken::Delim('>') => Comparison::Gt,
+            CssToken::Delim('=') => Comparison::Eq,
+            token => {
+                operands.last_mut()?.push(token);
+                i += 1;
+                continue;
+            }
+        };
+        let or_equal = op != Comparison::Eq && tokens.get(i + 1) == Some(&&CssToken::Delim('='));
+        operators.push(match (op, or_equal) {
+            (Comparison::Lt, true) => Comparison::Le,
+            (Comparison::Gt, true) => Comparison::Ge,
+            _ => op,
+        });
+        operands.push(Vec::new());
+        i += if or_equal { 2 } else { 1 };
+    }
+    let single_name = |operand: &[&CssToken]| match operand {
+        [token] => name_of(token),
+        _ => None,
+    };
+    match (operands.as_slice(), operators.as_slice()) {
+        ([left, right], [op]) => {
+            if let Some(name) = single_name(left) {
+                let value = parse_value(right)?;
+                Some(MediaFeature { name, test: FeatureTest::Range(vec![(*op, value)]) })
+            } else {
+                let name = single_name(right)?;
+                let value = parse_value(left)?;
+                Some(MediaFeature { name, test: FeatureTest::Range(vec![(op.flipped(), value)]) })
+            }
+        }
+        ([low, name, high], [op1, op2]) => {
+            let ascending = |op: &Comparison| matches!(op, Comparison::Lt | Comparison::Le);
+            let descending = |op: &Comparison| matches!(op, Comparison::Gt | Comparison::Ge);
+            if !((ascending(op1) && ascending(op2)) || (descending(op1) && descending(op2))) {
+                return None;
+            }
+            let name = single_name(name)?;
+            let comparisons = vec![(op1.flipped(), parse_value(low)?), (*op2, parse_value(high)?)];
+            Some(MediaFeature { name, test: FeatureTest::Range(comparisons) })
+        }
+        _ => None,
+    }
+}
+
+/// Drop the `-webkit-` prefix that `-webkit-min-device-pixel-ratio`
+/// and friends carry, keeping `min-`/`max-`.
+fn unprefixed(name: &str) -> String {
+    name.strip_prefix("-webkit-").unwrap_or(name).to_string()
+}
+
+/// A feature value: a number, a ratio (`16/9`), a length, a resolution,
+/// or an identifier.
+fn parse_value(tokens: &[&CssToken]) -> Option<FeatureValue> {
+    match tokens {
+        [CssToken::Number { value, .. }] => Some(FeatureValue::Number(*value as f32)),
+        [CssToken::Number { value: w, .. }, CssToken::Delim('/'), CssToken::Number { value: h, .. }] => {
+            (*h != 0.0).then(|| FeatureValue::Number((*w / *h) as f32))
+        }
+        [CssToken::Dimension { value, unit }] => {
+            let scale = match unit.to_ascii_lowercase().as_str() {
+                "px" | "dppx" | "x" => 1.0,
+                "em" | "rem" => 16.0,
+                "in" => 96.0,
+                "cm" => 96.0 / 2.54,
+                "mm" => 96.0 / 25.4,
+                "q" => 96.0 / 101.6,
+                "pt" => 96.0 / 72.0,
+                "pc" => 16.0,
+                "dpi" => 1.0 / 96.0,
+                "dpcm" => 2.54 / 96.0,
+                _ => return None,
+            };
+            Some(FeatureValue::Number((*value * scale) as f32))
+        }
+        [CssToken::Ident(name)] => Some(FeatureValue::Ident(name.to_ascii_lowercase())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(query: &str, device: &Device) -> bool {
+        MediaQueryList::parse(query).matches(device)
+    }
+
+    #[test]
+    fn test_width_ranges_and_types() {
+        let narrow = Device::screen(500.0, 800.0);
+        let wide = Device::screen(1200.0, 800.0);
+        for query in ["(max-width: 600px)", "screen and (width < 37.5em)", "(400px <= width <= 600px)", "(600px > width)"] {
+            assert!(matches(query, &narrow), "{query}");
+            assert!(!matches(query, &wide), "{query}");
+        }
+        assert!(matches("", &narrow));
+        assert!(matches("only screen and (min-width: 768px)", &wide));
+        assert!(!matches("print", &wide));
+        assert!(matches("not print", &wide));
+        assert!(matches("print, (orientation: portrait)", &narrow));
+        assert!(!matches("tv", &wide));
+        assert!(matches("(orientation: landscape) and (min-aspect-ratio: 4/3)", &wide));
+    }
+
+    #[test]
+    fn test_preferences_resolution_and_logic() {
+        let mut device = Device::default();
+        assert!(matches("(prefers-color-scheme: light)", &device));
+        assert!(!matches("(prefers-reduced-motion)", &device));
+        device.prefers_dark = true;
+        device.prefers_reduced_motion = true;
+        device.resolution = 2.0;
+        assert!(matches("(prefers-color-scheme: dark) and (prefers-reduced-motion: reduce)", &device));
+        assert!(matches("(min-resolution: 192dpi)", &device));
+        assert!(matches("(-webkit-min-device-pixel-ratio: 1.5)", &device));
+        assert!(matches("(max-width: 100px) or (not (hover: none))", &device));
+        assert!(matches("not ((max-width: 100px) or (pointer: coarse))", &device));
+    }
+
+    #[test]
+    fn test_unknown_and_invalid_queries_never_match() {
+        let device = Device::default();
+        assert!(!matches("(unknown-feature)", &device));
+        assert!(!matches("not (unknown-feature)", &device));
+        assert!(!matches("not screen and (min-width: 1px) or (hover)", &device));
+        assert!(!matches("(min-width: 10px) and", &device));
+        // A bad query doesn't spoil the rest of the list.
+        assert!(matches("screen and, (min-width: 10px)", &device));
+    }
+}
@@ -1,4 +1,12 @@
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::container::ContainerQuery;
+use crate::font_face::{FontFaceRule, parse_font_face};
+use crate::keyframes::{KeyframesRule, parse_keyframes};
+use crate::media::{Device, MediaQueryList, parse_media_query_list};
 use crate::selector::{ComplexSelector, parse_selector_list_from_tokens};
+use crate::supports::{SupportsCondition, SupportsQuery, parse_supports_condition};
 use crate::token::{CssToken, CssTokenizer};
 use crate::value::{CssValue, parse_value_from_tokens};
 
@@ -20,6 +28,68 @@ pub struct CssRule {
     pub selectors: Vec<ComplexSelector>,
     /// The declarations in the rule body.
     pub declarations: Vec<Declaration>,
+    /// Queries of the `@media` rules (and `media` attribute) this rule is
+    /// nested in, outermost first. It applies only while all of them match.
+    pub media: Vec<Rc<MediaQueryList>>,
+    /// Conditions of the `@supports` rules this rule is nested in. The
+    /// style engine decides which declarations they accept.
+    pub supports: Vec<Rc<SupportsQuery>>,
+    /// The cascade layer the rule is in, as the path of layer names from
+    /// the outermost (`@layer a { @layer b { ... } }` is `["a", "b"]`).
+    /// Empty for unlayered rules.
+    pub layer: Vec<String>,
+    /// Queries of the `@container` rules this rule is nested in, outermost
+    /// first. The style engine tests them against query containers.
+    pub containers: Vec<Rc<ContainerQuery>>,
+}
+
+impl CssRule {
+    /// Whether the rule's media queries match `device`.
+    pub fn applies_to(&self, device: &Device) -> bool {
+        self.media.iter().all(|m| m.matches(device))
+    }
+}
+
+/// An `@import` rule: another stylesheet whose rules apply ahead of the
+/// importing sheet's own.
+#[derive(Debug, Clone)]
+pub struct ImportRule {
+    /// The URL as written, relative to the importing sheet.
+    pub url: String,
+    /// The cascade layer named by `layer` or `layer(name)`; `Some("")` for
+    /// an anonymous layer.
+    pub layer: Option<String>,
+    /// The `supports(...)` condition.
+    pub supports: Option<Rc<SupportsQuery>>,
+    /// The media query list after the URL, if any.
+    pub media: Option<Rc<MediaQueryList>>,
+}
+
+impl ImportRule {
+    /// The rules of `sheet`, loaded for this import, carrying its
+    /// conditions.
+    pub fn scoped_rules(&self, mut sheet: Stylesheet) -> Vec<CssRule> {
+        self.scope(&mut sheet);
+        sheet.rules
+    }
+
+    /// Make everything in `sheet`, loaded for this import, carry its
+    /// conditions.
+    pub fn scope(&self, sheet: &mut Stylesheet) {
+        if let Some(media) = &self.media {
+            sheet.add_media_since(SheetLengths::default(), media);
+        }
+        if let Some(supports) = &self.supports {
+            sheet.add_supports_since(SheetLengths::default(), supports);
+        }
+        if let Some(layer) = &self.layer {
+            let path = match layer.as_str() {
+                "" => vec![anonymous_layer_name()],
+                name => name.split('.').map(str::to_string).collect(),
+            };
+            sheet.add_layer_since(SheetLengths::default(), &path);
+        }
+    }
 }
 
 /// A parsed CSS stylesheet.
@@ -27,19 +97,229 @@ pub struct CssRule {
 pub struct Stylesheet {
     /// All rules in the stylesheet, in source order.
     pub rules: Vec<CssRule>,
+    /// The sheet's `@import` rules, in order. Loading them is up to the
+    /// caller; their rules come before [`rules`](Self::rules).
+    pub imports: Vec<ImportRule>,
+    /// The sheet's `@font-face` rules, in source order, including those
+    /// nested in conditional rules.
+    pub font_faces: Vec<FontFaceRule>,
+    /// The sheet's `@keyframes` rules, in source order, including those
+    /// nested in conditional rules.
+    pub keyframes: Vec<KeyframesRule>,
+    /// The cascade layers the sheet declares, as paths like
+    /// [`CssRule::layer`], in the order they first appear. A layer
+    /// declared earlier loses to one declared later.
+    pub layers: Vec<Vec<String>>,
+    /// How many of [`layers`](Self::layers) the `@layer` statements ahead
+    /// of the `@import`s declare; the layers of imported sheets go after
+    /// them.
+    pub layers_before_imports: usize,
+}
+
+/// How many of each kind of rule a sheet held at some point of parsing,
+/// so the rules a conditional block added can be told apart.
+#[derive(Debug, Clone, Copy, Default)]
+struct SheetLengths {
+    rules: usize,
+    font_faces: usize,
+    keyframes: usize,
+    layers: usize,
+}
+
+impl Stylesheet {
+    /// Restrict every rule to `media`, as a `media` attribute on the
+    /// `<style>` or `<link>` that brought the sheet in does.
+    pub fn with_media(mut self, media: MediaQueryList) -> Self {
+        self.add_media_since(SheetLengths::default(), &Rc::new(media));
+        self
+    }
+
+    fn lengths(&self) -> SheetLengths {
+        SheetLengths {
+            rules: self.rules.len(),
+            font_faces: self.font_faces.len(),
+            keyframes: self.keyframes.len(),
+            layers: self.layers.len(),
+        }
+    }
+
+    /// Whether any rule is in an `@container` rule, so styles depend on
+    /// layout.
+    pub fn has_container_queries(&self) -> bool {
+        self.rules.iter().any(|r| !r.containers.is_empty())
+    }
+
+    /// Move the rules and layers added after `first` into the layer
+    /// `path`, declaring it ahead of them.
+    fn add_layer_since(&mut self, first: SheetLengths, path: &[String]) {
+        for rule in &mut self.rules[first.rules..] {
+            rule.layer.splice(0..0, path.iter().cloned());
+        }
+        for layer in &mut self.layers[first.layers..] {
+            layer.splice(0..0, path.iter().cloned());
+        }
+        self.layers.insert(first.layers, path.to_vec());
+    }
+
+    /// Nest the rules added after `first` in `query`.
+    fn add_container_since(&mut self, first: SheetLengths, query: &Rc<ContainerQuery>) {
+        for rule in &mut self.rules[first.rules..] {
+            rule.containers.insert(0, Rc::clone(query));
+        }
+    }
+
+    /// Nest the rules added after `first` in `media`.
+    fn add_media_since(&mut self, first: SheetLengths, media: &Rc<MediaQueryList>) {
+        let rules = self.rules[first.rules..].iter_mut().map(|r| &mut r.media);
+        let faces = self.font_faces[first.font_faces..].iter_mut().map(|f| &mut f.media);
+        let keyframes = self.keyframes[first.keyframes..].iter_mut().map(|k| &mut k.media);
+        for list in rules.chain(faces).chain(keyframes) {
+            list.insert(0, Rc::clone(media));
+        }
+    }
+
+    /// Nest the rules added after `first` in `supports`.
+    fn add_supports_since(&mut self, first: SheetLengths, supports: &Rc<SupportsQuery>) {
+        let rules = self.rules[first.rules..].iter_mut().map(|r| &mut r.supports);
+        let faces = self.font_faces[first.font_faces..].iter_mut().map(|f| &mut f.supports);
+        let keyframes = self.keyframes[first.keyframes..].iter_mut().map(|k| &mut k.supports);
+        for list in rules.chain(faces).chain(keyframes) {
+            list.insert(0, Rc::clone(supports));
+        }
+    }
 }
 
 /// Parse a complete CSS stylesheet from a string.
 pub fn parse_stylesheet(input: &str) -> Stylesheet {
     let mut tokenizer = CssTokenizer::new(input);
     let tokens = tokenizer.tokenize_all();
-    let rules = parse_rules(&tokens);
-    Stylesheet { rules }
+    let (imports, layers_before_imports) = parse_imports(&tokens);
+    let mut sheet = Stylesheet {
+        rules: Vec::new(),
+        imports,
+        font_faces: Vec::new(),
+        keyframes: Vec::new(),
+        layers: Vec::new(),
+        layers_before_imports,
+    };
+    parse_rules(&tokens, &mut sheet);
+    sheet
 }
 
-/// Parse a list of CSS rules from a token stream.
-fn parse_rules(tokens: &[CssToken]) -> Vec<CssRule> {
-    let mut rules = Vec::new();
+/// Parse the `@import` rules at the start of a sheet. Only `@charset` and
+/// `@layer` statements may come before one; an `@import` after any other
+/// rule is ignored. Also counts the layers the `@layer` statements
+/// ahead of the first `@import` declare.
+fn parse_imports(tokens: &[CssToken]) -> (Vec<ImportRule>, usize) {
+    let mut imports = Vec::new();
+    let mut layers = 0;
+    let mut pos = 0;
+    while pos < tokens.len() {
+        match &tokens[pos] {
+            CssToken::Whitespace | CssToken::CDO | CssToken::CDC => pos += 1,
+            CssToken::AtKeyword(name) => {
+                let next = skip_at_rule(tokens, pos);
+                let is_statement = tokens.get(next - 1) == Some(&CssToken::Semicolon)
+                    || (next == tokens.len() && !tokens[pos..].contains(&CssToken::LBrace));
+                match name.to_ascii_lowercase().as_str() {
+                    "import" if is_statement => {
+                        let prelude = &tokens[pos + 1..next];
+                        let prelude = prelude.strip_suffix(&[CssToken::Semicolon]).unwrap_or(prelude);
+                        imports.extend(parse_import_prelude(prelude));
+                    }
+                    "charset" => {}
+                    "layer" if is_statement => {
+                        let prelude = &tokens[pos + 1..next];
+                        let prelude = prelude.strip_suffix(&[CssToken::Semicolon]).unwrap_or(prelude);
+                        if imports.is_empty() {
+                            layers += parse_layer_names(prelude).map_or(0, |names| names.len());
+                        }
+                    }
+                    _ => break,
+                }
+                pos = next;
+            }
+            _ => break,
+        }
+    }
+    (imports, layers)
+}
+
+/// Parse `<url> [layer | layer(<name>)]? [supports(...)]? <media-query-list>?`.
+fn parse_import_prelude(tokens: &[CssToken]) -> Option<ImportRule> {
+    let mut rest = tokens.iter().skip_while(|t| **t == CssToken::Whitespace);
+    let url = match rest.next()? {
+        CssToken::Url(url) | CssToken::String(url) => url.clone(),
+        CssToken::Function(name) if name.eq_ignore_ascii_case("url") => {
+            let mut args = rest.by_ref().take_while(|t| **t != CssToken::RParen).filter(|t| **t != CssToken::Whitespace);
+            match (args.next(), args.next()) {
+                (Some(CssToken::String(url)), None) => url.clone(),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    let mut rest: Vec<CssToken> = rest.cloned().collect();
+    let mut import = ImportRule { url, layer: None, supports: None, media: None };
+
+    // Take a leading function (by name) or ident off `rest`, returning the
+    // tokens inside the parentheses.
+    fn take_leading(rest: &mut Vec<CssToken>, name: &str) -> Option<Vec<CssToken>> {
+        let start = rest.iter().position(|t| *t != CssToken::Whitespace)?;
+        match &rest[start] {
+            CssToken::Ident(ident) if ident.eq_ignore_ascii_case(name) => {
+                rest.drain(..=start);
+                Some(Vec::new())
+            }
+            CssToken::Function(function) if function.eq_ignore_ascii_case(name) => {
+                let mut depth = 0;
+                let end = rest[start..].iter().position(|t| {
+                    match t {
+                        CssToken::Function(_) | CssToken::LParen => depth += 1,
+                        CssToken::RParen => depth -= 1,
+                        _ => {}
+                    }
+                    depth == 0
+                })? + start;
+                let inner = rest[start + 1..end].to_vec();
+                rest.drain(..=end);
+                Some(inner)
+            }
+            _ => None,
+        }
+    }
+
+    if let Some(name) = take_leading(&mut rest, "layer") {
+        let name: String = name
+            .iter()
+            .map(|t| match t {
+                CssToken::Ident(part) => part.clone(),
+                CssToken::Delim('.') => ".".to_string(),
+                _ => String::new(),
+            })
+            .collect();
+        import.layer = Some(name);
+    }
+    if let Some(inner) = take_leading(&mut rest, "supports") {
+        // `supports()` takes a condition or a bare declaration.
+        let mut condition = parse_supports_condition(&inner);
+        if condition == SupportsCondition::Unknown {
+            let wrapped: Vec<CssToken> = std::iter::once(CssToken::LParen)
+                .chain(inner)
+                .chain(std::iter::once(CssToken::RParen))
+                .collect();
+            condition = parse_supports_condition(&wrapped);
+        }
+        import.supports = Some(Rc::new(SupportsQuery::new(condition)));
+    }
+    if rest.iter().any(|t| *t != CssToken::Whitespace) {
+        import.media = Some(Rc::new(parse_media_query_list(&rest)));
+    }
+    Some(import)
+}
+
+/// Parse a list of CSS rules from a token stream into `sheet`.
+fn parse_rules(tokens: &[CssToken], sheet: &mut Stylesheet) {
     let mut pos = 0;
 
     loop {
@@ -52,42 +332,76 @@ fn parse_rules(tokens: &[CssToken]) -> Vec<CssRule> {
             break;
         }
 
-        // Handle at-rules: parse @media/@supports content, skip others.
+        // Handle at-rules: parse @media and @supports (keeping their
+        // conditions with the rules inside), @font-face, @keyframes, and @layer etc.
+        // content, skip others.
         if let CssToken::AtKeyword(name) = &tokens[pos] {
             let lower_name = name.to_ascii_lowercase();
             match lower_name.as_str() {
-                "media" | "supports" | "document" | "-moz-document" | "layer" | "container" => {
-                    pos += 1; // skip @keyword
-                    // Skip the condition/query part until '{'
-                    while pos < tokens.len() && tokens[pos] != CssToken::LBrace {
-                        pos += 1;
-                    }
-                    if pos < tokens.len() {
-                        pos += 1; // skip '{'
-                        // Find the matching '}' accounting for nested blocks
-                        let block_start = pos;
-                        let mut depth = 1;
-                        while pos < tokens.len() && depth > 0 {
-                            match &tokens[pos] {
-                                CssToken::LBrace => depth += 1,
-                                CssToken::RBrace => depth -= 1,
-                                _ => {}
-                            }
-                            if depth > 0 {
-                                pos += 1;
-                            }
-                        }
-                        let block_tokens = &tokens[block_start..pos];
-                        // Recursively parse rules inside the block
-                        let inner_rules = parse_rules(block_tokens);
-                        rules.extend(inner_rules);
-                        if pos < tokens.len() {
-                            pos += 1; // skip closing '}'
+                "media" => {
+                    let (prelude, block, next) = split_block_at_rule(tokens, pos);
+                    let media = Rc::new(parse_media_query_list(prelude));
+                    let first = sheet.lengths();
+                    parse_rules(block, sheet);
+                    sheet.add_media_since(first, &media);
+                    pos = next;
+                }
+                "supports" => {
+                    let (prelude, block, next) = split_block_at_rule(tokens, pos);
+                    let supports = Rc::new(SupportsQuery::new(parse_supports_condition(prelude)));
+                    let first = sheet.lengths();
+                    parse_rules(block, sheet);
+                    sheet.add_supports_since(first, &supports);
+                    pos = next;
+                }
+                "font-face" => {
+                    let (_, block, next) = split_block_at_rule(tokens, pos);
+                    sheet.font_faces.extend(parse_font_face(block));
+                    pos = next;
+                }
+                "keyframes" | "-webkit-keyframes" | "-moz-keyframes" => {
+                    let (prelude, block, next) = split_block_at_rule(tokens, pos);
+                    sheet.keyframes.extend(parse_keyframes(prelude, block));
+                    pos = next;
+                }
+                "layer" => {
+                    let (prelude, block, next) = split_block_at_rule(tokens, pos);
+                    let names = parse_layer_names(prelude);
+                    if tokens[pos..next].contains(&CssToken::LBrace) {
+                        // `@layer name { ... }` or an anonymous `@layer { ... }`.
+                        let path = match names.as_deref() {
+                            Some([]) => Some(vec![anonymous_layer_name()]),
+                            Some([path]) => Some(path.clone()),
+                            _ => None,
+                        };
+                        if let Some(path) = path {
+                            let first = sheet.lengths();
+                            parse_rules(block, sheet);
+                            sheet.add_layer_since(first, &path);
                         }
+                    } else if let Some(names) = names {
+                        // `@layer a, b.c;` only fixes the layers' order.
+                        sheet.layers.extend(names);
                     }
+                    pos = next;
+                }
+                "container" => {
+                    let (prelude, block, next) = split_block_at_rule(tokens, pos);
+                    if let Some(query) = ContainerQuery::parse(prelude) {
+                        let query = Rc::new(query);
+                        let first = sheet.lengths();
+                        parse_rules(block, sheet);
+                        sheet.add_container_since(first, &query);
+                    }
+                    pos = next;
+                }
+                "document" | "-moz-document" => {
+                    let (_, block, next) = split_block_at_rule(tokens, pos);
+                    parse_rules(block, sheet);
+                    pos = next;
                 }
                 _ => {
-                    // For @import, @charset, @namespace, @keyframes, @font-face, etc.
+                    // For @import, @charset, @namespace, etc.
                     pos = skip_at_rule(tokens, pos);
                 }
             }
@@ -103,7 +417,7 @@ fn parse_rules(tokens: &[CssToken]) -> Vec<CssRule> {
         // Try to parse a qualified rule (selector { declarations })
         match parse_qualified_rule(tokens, pos) {
             Some((rule, new_pos)) => {
-                rules.push(rule);
+                sheet.rules.push(rule);
                 pos = new_pos;
             }
             None => {
@@ -112,8 +426,72 @@ fn parse_rules(tokens: &[CssToken]) -> Vec<CssRule> {
             }
         }
     }
+}
+
+/// Parse the comma-separated layer names of an `@layer` prelude, each a
+/// dotted path like `base.reset`. `None` if the prelude is malformed.
+fn parse_layer_names(prelude: &[CssToken]) -> Option<Vec<Vec<String>>> {
+    let mut names = Vec::new();
+    let tokens: Vec<&CssToken> = prelude.iter().filter(|t| **t != CssToken::Whitespace).collect();
+    if tokens.is_empty() {
+        return Some(names);
+    }
+    for name in tokens.split(|t| **t == CssToken::Comma) {
+        let mut path = Vec::new();
+        for (i, token) in name.iter().enumerate() {
+            match (i % 2, token) {
+                (0, CssToken::Ident(part)) => path.push(part.clone()),
+                (1, CssToken::Delim('.')) => {}
+                _ => return None,
+            }
+        }
+        if name.len() % 2 == 0 {
+            return None;
+        }
+        names.push(path);
+    }
+    Some(names)
+}
 
-    rules
+/// A name for an anonymous layer no other layer can have.
+fn anonymous_layer_name() -> String {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    format!("@anonymous-{}", NEXT.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Split the at-rule starting at `start` into its prelude and the inside
+/// of its `{ ... }` block, returning the position just past the block.
+/// A statement (like `@layer a, b;`) has an empty block.
+fn split_block_at_rule(tokens: &[CssToken], start: usize) -> (&[CssToken], &[CssToken], usize) {
+    let prelude_start = start + 1; // skip @keyword
+    let mut pos = prelude_start;
+    while pos < tokens.len() && !matches!(tokens[pos], CssToken::LBrace | CssToken::Semicolon) {
+        pos += 1;
+    }
+    let prelude = &tokens[prelude_start..pos];
+    if pos >= tokens.len() {
+        return (prelude, &[], pos);
+    }
+    if tokens[pos] == CssToken::Semicolon {
+        return (prelude, &[], pos + 1);
+    }
+    let block_start = pos + 1;
+    let mut depth = 0;
+    while pos < tokens.len() {
+        match &tokens[pos] {
+            CssToken::LBrace => depth += 1,
+            CssToken::RBrace => {
+                depth -= 1;
+                if depth == 0 {
+                    return (prelude, &tokens[block_start..pos], pos + 1);
+                }
+            }
+            _ => {}
+        }
+        pos += 1;
+    }
+    // An unclosed block runs to the end of the sheet.
+    (prelude, &tokens[block_start..], pos)
 }
 
 /// Skip an at-rule (consume until matching `;` or `{ ... }`).
@@ -214,6 +592,10 @@ fn parse_qualified_rule(tokens: &[CssToken], start: usize) -> Option<(CssRule, u
         CssRule {
             selectors,
             declarations,
+            media: Vec::new(),
+            supports: Vec::new(),
+            layer: Vec::new(),
+            containers: Vec::new(),
         },
         pos,
     ))
@@ -646,6 +1028,104 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_media_rules_keep_their_queries() {
+        let css = r#"
+            p { color: black; }
+            @media print {
+                p { color: gray; }
+                @media (max-width: 600px) {
+                    p { color: red; }
+                }
+            }
+        "#;
+        let stylesheet = parse_stylesheet(css);
+        let media: Vec<usize> = stylesheet.rules.iter().map(|r| r.media.len()).collect();
+        assert_eq!(media, [0, 1, 2]);
+        let screen = Device::screen(500.0, 800.0);
+        assert!(stylesheet.rules[0].applies_to(&screen));
+        assert!(!stylesheet.rules[2].applies_to(&screen));
+        let print = Device { media_type: crate::media::MediaType::Print, ..screen };
+        assert!(stylesheet.rules[2].applies_to(&print));
+
+        let restricted = parse_stylesheet("p { color: red; }").with_media(MediaQueryList::parse("print"));
+        assert!(!restricted.rules[0].applies_to(&Device::default()));
+    }
+
+    #[test]
+    fn test_parse_imports() {
+        let css = r#"
+            @charset "utf-8";
+            @layer base, theme;
+            @import url("reset.css");
+            @import 'theme.css' layer(theme.dark) supports(display: grid) screen and (min-width: 600px);
+            @import url(print.css) layer print;
+            p { color: red; }
+            @import "too-late.css";
+        "#;
+        let stylesheet = parse_stylesheet(css);
+        assert_eq!(stylesheet.rules.len(), 1);
+        let urls: Vec<&str> = stylesheet.imports.iter().map(|i| i.url.as_str()).collect();
+        assert_eq!(urls, ["reset.css", "theme.css", "print.css"]);
+
+        let theme = &stylesheet.imports[1];
+        assert_eq!(theme.layer.as_deref(), Some("theme.dark"));
+        let supports = theme.supports.as_ref().unwrap();
+        assert!(supports.matches(|d| d.name == "display"));
+        let media = theme.media.as_ref().unwrap();
+        assert!(media.matches(&Device::screen(800.0, 600.0)));
+        assert!(!media.matches(&Device::screen(500.0, 600.0)));
+
+        let print = &stylesheet.imports[2];
+        assert_eq!(print.layer.as_deref(), Some(""));
+        assert!(print.supports.is_none());
+        let scoped = print.scoped_rules(parse_stylesheet("p { color: blue; }"));
+        assert!(!scoped[0].applies_to(&Device::default()));
+        assert!(stylesheet.imports[0].scoped_rules(parse_stylesheet("p {}"))[0].media.is_empty());
+
+        assert!(parse_stylesheet("@media print {} @import 'x.css';").imports.is_empty());
+        assert!(parse_stylesheet("@import 'x.css' { }").imports.is_empty());
+    }
+
+    #[test]
+    fn test_layers_and_container_rules() {
+        let css = r#"
+            @layer reset, theme.dark;
+            @import "base.css" layer(base);
+            @layer theme {
+                @layer dark { p { color: white; } }
+                p { color: black; }
+            }
+            @layer { p { color: red; } }
+            @container card (min-width: 400px) {
+                @layer reset { p { margin: 0; } }
+            }
+            @container (orientation) and {} p { color: blue; }
+            p { color: green; }
+        "#;
+        let stylesheet = parse_stylesheet(css);
+        let layers: Vec<String> = stylesheet.rules.iter().map(|r| r.layer.join(".")).collect();
+        assert_eq!(layers.len(), 6, "the invalid @container is dropped");
+        assert_eq!(layers[..2], ["theme.dark", "theme"]);
+        assert!(layers[2].starts_with('@'), "anonymous layers get a unique name: {}", layers[2]);
+        assert_eq!(layers[3..], ["reset", "", ""]);
+
+        let names: Vec<String> = stylesheet.layers.iter().map(|l| l.join(".")).collect();
+        assert_eq!(names[..4], ["reset", "theme.dark", "theme", "theme.dark"]);
+        assert_eq!(names[5], "reset");
+        assert_eq!(stylesheet.layers_before_imports, 2);
+
+        let query = &stylesheet.rules[3].containers;
+        assert_eq!(query.len(), 1);
+        assert_eq!(query[0].name.as_deref(), Some("card"));
+        assert!(stylesheet.has_container_queries());
+
+        let mut imported = parse_stylesheet("@layer a; p {}");
+        stylesheet.imports[0].scope(&mut imported);
+        assert_eq!(imported.layers, [vec!["base".to_string()], vec!["base".to_string(), "a".to_string()]]);
+        assert_eq!(imported.rules[0].layer, ["base"]);
+    }
+
     #[test]
     fn test_parse_percentage_value() {
         let css = "div { width: 50%; }";
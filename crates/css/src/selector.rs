@@ -383,14 +383,7 @@ fn parse_compound_selector(tokens: &[CssToken], start: usize) -> (CompoundSelect
                 pos += 2; // skip ::
                 if pos < tokens.len() {
                     if let CssToken::Ident(name) = &tokens[pos] {
-                        let pe = match name.to_ascii_lowercase().as_str() {
-                            "before" => Some(PseudoElement::Before),
-                            "after" => Some(PseudoElement::After),
-                            "first-line" => Some(PseudoElement::FirstLine),
-                            "first-letter" => Some(PseudoElement::FirstLetter),
-                            _ => None,
-                        };
-                        if let Some(pe) = pe {
+                        if let Some(pe) = pseudo_element_named(name) {
                             simples.push(SimpleSelector::PseudoElement(pe));
                         }
                         pos += 1;
@@ -404,30 +397,7 @@ fn parse_compound_selector(tokens: &[CssToken], start: usize) -> (CompoundSelect
                 if pos < tokens.len() {
                     match &tokens[pos] {
                         CssToken::Ident(name) => {
-                            let pc = match name.to_ascii_lowercase().as_str() {
-                                "hover" => Some(PseudoClass::Hover),
-                                "active" => Some(PseudoClass::Active),
-                                "focus" => Some(PseudoClass::Focus),
-                                "focus-visible" => Some(PseudoClass::FocusVisible),
-                                "focus-within" => Some(PseudoClass::FocusWithin),
-                                "first-child" => Some(PseudoClass::FirstChild),
-                                "last-child" => Some(PseudoClass::LastChild),
-                                "first-of-type" => Some(PseudoClass::FirstOfType),
-                                "last-of-type" => Some(PseudoClass::LastOfType),
-                                "only-child" => Some(PseudoClass::OnlyChild),
-                                "only-of-type" => Some(PseudoClass::OnlyOfType),
-                                "empty" => Some(PseudoClass::Empty),
-                                "enabled" => Some(PseudoClass::Enabled),
-                                "disabled" => Some(PseudoClass::Disabled),
-                                "checked" => Some(PseudoClass::Checked),
-                                "any-link" => Some(PseudoClass::AnyLink),
-                                "placeholder-shown" => Some(PseudoClass::Placeholder),
-                                "link" => Some(PseudoClass::Link),
-                                "visited" => Some(PseudoClass::Visited),
-                                "root" => Some(PseudoClass::Root),
-                                _ => None,
-                            };
-                            if let Some(pc) = pc {
+                            if let Some(pc) = pseudo_class_named(name) {
                                 simples.push(SimpleSelector::PseudoClass(pc));
                             }
                             pos += 1;
@@ -474,6 +444,99 @@ fn parse_compound_selector(tokens: &[CssToken], start: usize) -> (CompoundSelect
     (CompoundSelector { simples }, pos)
 }
 
+fn pseudo_element_named(name: &str) -> Option<PseudoElement> {
+    match name.to_ascii_lowercase().as_str() {
+        "before" => Some(PseudoElement::Before),
+        "after" => Some(PseudoElement::After),
+        "first-line" => Some(PseudoElement::FirstLine),
+        "first-letter" => Some(PseudoElement::FirstLetter),
+        _ => None,
+    }
+}
+
+fn pseudo_class_named(name: &str) -> Option<PseudoClass> {
+    match name.to_ascii_lowercase().as_str() {
+        "hover" => Some(PseudoClass::Hover),
+        "active" => Some(PseudoClass::Active),
+        "focus" => Some(PseudoClass::Focus),
+        "focus-visible" => Some(PseudoClass::FocusVisible),
+        "focus-within" => Some(PseudoClass::FocusWithin),
+        "first-child" => Some(PseudoClass::FirstChild),
+        "last-child" => Some(PseudoClass::LastChild),
+        "first-of-type" => Some(PseudoClass::FirstOfType),
+        "last-of-type" => Some(PseudoClass::LastOfType),
+        "only-child" => Some(PseudoClass::OnlyChild),
+        "only-of-type" => Some(PseudoClass::OnlyOfType),
+        "empty" => Some(PseudoClass::Empty),
+        "enabled" => Some(PseudoClass::Enabled),
+        "disabled" => Some(PseudoClass::Disabled),
+        "checked" => Some(PseudoClass::Checked),
+        "any-link" => Some(PseudoClass::AnyLink),
+        "placeholder-shown" => Some(PseudoClass::Placeholder),
+        "link" => Some(PseudoClass::Link),
+        "visited" => Some(PseudoClass::Visited),
+        "root" => Some(PseudoClass::Root),
+        _ => None,
+    }
+}
+
+/// Whether the selector list is understood exactly, for `@supports
+/// selector(...)`: every selector parses to the end, and there are no
+/// pseudo-classes or pseudo-elements that [`parse_selector_list_from_tokens`]
+/// drops or only approximates (like `:is()` and `:has()`).
+pub fn is_supported_selector_list(tokens: &[CssToken]) -> bool {
+    let mut pos = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        if i < pos {
+            continue;
+        }
+        pos = i + 1;
+        if *token != CssToken::Colon {
+            continue;
+        }
+        let supported = match (tokens.get(i + 1), tokens.get(i + 2)) {
+            (Some(CssToken::Colon), Some(CssToken::Ident(name))) => {
+                pos = i + 3;
+                pseudo_element_named(name).is_some()
+            }
+            (Some(CssToken::Ident(name)), _) => pseudo_class_named(name).is_some(),
+            (Some(CssToken::Function(name)), _) => {
+                let end = skip_to_matching_rparen(tokens, i + 2);
+                let args = &tokens[i + 2..end.saturating_sub(1).max(i + 2)];
+                match name.to_ascii_lowercase().as_str() {
+                    "nth-child" => true,
+                    // `:not()` takes one compound selector.
+                    "not" => {
+                        let args = trim_whitespace(args);
+                        let (compound, end) = parse_compound_selector(args, 0);
+                        !compound.simples.is_empty() && end == args.len()
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        };
+        if !supported {
+            return false;
+        }
+    }
+
+    tokens.split(|t| *t == CssToken::Comma).all(|part| {
+        let part = trim_whitespace(part);
+        if part.is_empty() {
+            return false;
+        }
+        let (selector, end) = parse_complex_selector(part, 0);
+        !selector.parts.is_empty() && end == part.len()
+    })
+}
+
+fn trim_whitespace(tokens: &[CssToken]) -> &[CssToken] {
+    let start = tokens.iter().position(|t| *t != CssToken::Whitespace).unwrap_or(tokens.len());
+    let end = tokens.iter().rposition(|t| *t != CssToken::Whitespace).map_or(start, |i| i + 1);
+    &tokens[start..end]
+}
+
 fn has_type_or_universal(simples: &[SimpleSelector]) -> bool {
     simples.iter().any(|s| {
         matches!(s, SimpleSelector::Type(_) | SimpleSelector::Universal)
@@ -0,0 +1,191 @@
+//! Feature queries: the `@supports` prelude (CSS Conditional Rules 4).
+//!
+//! `selector(...)` tests are answered here by the selector parser.
+//! Declaration tests need to know which properties and values the style
+//! engine handles, so [`SupportsQuery::matches`] asks the caller.
+
+use std::cell::OnceCell;
+
+use crate::parser::{Declaration, parse_declaration_block};
+use crate::selector::is_supported_selector_list;
+use crate::token::CssToken;
+
+/// A parsed `@supports` condition.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SupportsCondition {
+    /// `(property: value)`.
+    Declaration(Declaration),
+    /// `selector(...)`: whether the selector parser understands it.
+    Selector(bool),
+    Not(Box<SupportsCondition>),
+    And(Vec<SupportsCondition>),
+    Or(Vec<SupportsCondition>),
+    /// An unrecognised function or parenthesized text, or a prelude that
+    /// didn't parse. Always false.
+    Unknown,
+}
+
+impl SupportsCondition {
+    pub fn evaluate(&self, declaration_supported: &dyn Fn(&Declaration) -> bool) -> bool {
+        match self {
+            SupportsCondition::Declaration(decl) => declaration_supported(decl),
+            SupportsCondition::Selector(supported) => *supported,
+            SupportsCondition::Not(inner) => !inner.evaluate(declaration_supported),
+            SupportsCondition::And(items) => items.iter().all(|c| c.evaluate(declaration_supported)),
+            SupportsCondition::Or(items) => items.iter().any(|c| c.evaluate(declaration_supported)),
+            SupportsCondition::Unknown => false,
+        }
+    }
+}
+
+/// The condition of one `@supports` rule. Support doesn't change while
+/// the engine runs, so the answer is worked out once and shared by every
+/// rule inside.
+#[derive(Debug, Clone)]
+pub struct SupportsQuery {
+    pub condition: SupportsCondition,
+    result: OnceCell<bool>,
+}
+
+impl SupportsQuery {
+    pub fn new(condition: SupportsCondition) -> Self {
+        Self { condition, result: OnceCell::new() }
+    }
+
+    /// Whether the condition holds, given which declarations the style
+    /// engine supports.
+    pub fn matches(&self, declaration_supported: impl Fn(&Declaration) -> bool) -> bool {
+        *self.result.get_or_init(|| self.condition.evaluate(&declaration_supported))
+    }
+}
+
+/// Parse an `@supports` prelude. One that doesn't parse is
+/// [`SupportsCondition::Unknown`], so its block is ignored.
+pub fn parse_supports_condition(tokens: &[CssToken]) -> SupportsCondition {
+    let mut pos = 0;
+    match condition(tokens, &mut pos) {
+        Some(condition) if skip_whitespace(tokens, &mut pos) == tokens.len() => condition,
+        _ => SupportsCondition::Unknown,
+    }
+}
+
+/// Skip whitespace, returning the new position.
+fn skip_whitespace(tokens: &[CssToken], pos: &mut usize) -> usize {
+    while tokens.get(*pos) == Some(&CssToken::Whitespace) {
+        *pos += 1;
+    }
+    *pos
+}
+
+fn keyword_at(tokens: &[CssToken], pos: &mut usize) -> Option<String> {
+    skip_whitespace(tokens, pos);
+    match tokens.get(*pos) {
+        Some(CssToken::Ident(word)) => Some(word.to_ascii_lowercase()),
+        _ => None,
+    }
+}
+
+/// `not <in-parens>`, or `<in-parens>` joined by all-`and` or all-`or`.
+fn condition(tokens: &[CssToken], pos: &mut usize) -> Option<SupportsCondition> {
+    if keyword_at(tokens, pos).as_deref() == Some("not") {
+        *pos += 1;
+        return Some(SupportsCondition::Not(Box::new(in_parens(tokens, pos)?)));
+    }
+    let mut items = vec![in_parens(tokens, pos)?];
+    let mut joiner: Option<String> = None;
+    while let Some(word) = keyword_at(tokens, pos) {
+        if !(word == "and" || word == "or") || joiner.as_ref().is_some_and(|j| *j != word) {
+            break;
+        }
+        *pos += 1;
+        items.push(in_parens(tokens, pos)?);
+        joiner = Some(word);
+    }
+    Some(match joiner.as_deref() {
+        None => items.pop()?,
+        Some("and") => SupportsCondition::And(items),
+        _ => SupportsCondition::Or(items),
+    })
+}
+
+/// `( <condition> )`, `( <declaration> )`, `selector( ... )`, or any
+/// other function or parenthesized text, which is unknown.
+fn in_parens(tokens: &[CssToken], pos: &mut usize) -> Option<SupportsCondition> {
+    skip_whitespace(tokens, pos);
+    let opener = tokens.get(*pos)?;
+    if !matches!(opener, CssToken::LParen | CssToken::Function(_)) {
+        return None;
+    }
+    *pos += 1;
+    let start = *pos;
+    let mut depth = 1;
+    while depth > 0 {
+        match tokens.get(*pos)? {
+            CssToken::LParen | CssToken::Function(_) => depth += 1,
+            CssToken::RParen => depth -= 1,
+            _ => {}
+        }
+        *pos += 1;
+    }
+    let inner = &tokens[start..*pos - 1];
+
+    if let CssToken::Function(name) = opener {
+        return Some(if name.eq_ignore_ascii_case("selector") {
+            SupportsCondition::Selector(is_supported_selector_list(inner))
+        } else {
+            SupportsCondition::Unknown
+        });
+    }
+    let mut inner_pos = 0;
+    if let Some(nested) = condition(inner, &mut inner_pos)
+        && skip_whitespace(inner, &mut inner_pos) == inner.len()
+    {
+        return Some(nested);
+    }
+    // A declaration: exactly one `name: value`, with a value.
+    let is_declaration = matches!(inner.iter().find(|t| **t != CssToken::Whitespace), Some(CssToken::Ident(_)))
+        && !inner.contains(&CssToken::Semicolon);
+    let mut declarations = if is_declaration { parse_declaration_block(inner) } else { Vec::new() };
+    Some(match (declarations.pop(), declarations.is_empty()) {
+        (Some(decl), true) if !decl.value.is_empty() => SupportsCondition::Declaration(decl),
+        _ => SupportsCondition::Unknown,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token::CssTokenizer;
+    use crate::value::CssValue;
+
+    fn evaluate(prelude: &str) -> bool {
+        let tokens = CssTokenizer::new(prelude).tokenize_all();
+        // Pretend only `display: grid` and `gap` are supported.
+        parse_supports_condition(&tokens)
+            .evaluate(&|d| (d.name == "display" && d.value == [CssValue::Keyword("grid".into())]) || d.name == "gap")
+    }
+
+    #[test]
+    fn test_declarations_and_logic() {
+        assert!(evaluate("(display: grid)"));
+        assert!(!evaluate("(display: subgrid)"));
+        assert!(evaluate("(display: grid) and (gap: 1rem)"));
+        assert!(evaluate("not (display: subgrid)"));
+        assert!(evaluate("(display: subgrid) or ((gap: 1px) and (not (color: oklch(1 0 0))))"));
+        assert!(!evaluate("(display: grid) and (gap: 1px) or (color: red)"));
+        assert!(!evaluate("display: grid"));
+        assert!(!evaluate("(gap)"));
+        assert!(!evaluate("font-tech(color-COLRv1)"));
+        assert!(evaluate("not font-tech(color-COLRv1)"));
+    }
+
+    #[test]
+    fn test_selector_tests_use_the_selector_parser() {
+        assert!(evaluate("selector(ul > li:first-child, a[href^='http']:hover)"));
+        assert!(evaluate("selector(p:not(.intro))"));
+        assert!(!evaluate("selector(:has(> img))"));
+        assert!(!evaluate("selector(::-webkit-scrollbar)"));
+        assert!(!evaluate("selector(a:unknown-state)"));
+        assert!(!evaluate("selector(a <> b)"));
+    }
+}
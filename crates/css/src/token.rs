@@ -56,6 +56,13 @@ impl CssTokenizer {
         tokens
     }
 
+    /// The offset, in characters, of the next character to be read. A
+    /// token's text runs from the position before `next_token` to the one
+    /// after, including any comments in front of it.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
     /// Consume and return the next token.
     pub fn next_token(&mut self) -> CssToken {
         self.consume_comments();
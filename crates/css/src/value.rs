@@ -13,6 +13,10 @@ pub enum LengthUnit {
     Ex,
     Percent,
     Fr,
+    /// Seconds, for durations and delays.
+    S,
+    /// Milliseconds.
+    Ms,
 }
 
 /// An RGBA color value.
@@ -436,6 +440,8 @@ pub fn parse_value_from_tokens(tokens: &[crate::token::CssToken]) -> Vec<CssValu
                     "ex" => LengthUnit::Ex,
                     "%" => LengthUnit::Percent,
                     "fr" => LengthUnit::Fr,
+                    "s" => LengthUnit::S,
+                    "ms" => LengthUnit::Ms,
                     _ => LengthUnit::Px, // default fallback
                 };
                 values.push(CssValue::Length(*value, length_unit));
@@ -541,6 +547,7 @@ pub fn parse_value_from_tokens(tokens: &[crate::token::CssToken]) -> Vec<CssValu
                 i += 1;
             }
             CssToken::Comma => {
+                values.push(CssValue::Keyword(",".to_string()));
                 i += 1;
             }
             CssToken::Delim(ch) => {
@@ -51,6 +51,9 @@ pub struct ElementData {
     pub id: Option<String>,
     /// Cached list of class names (split from the `class` attribute).
     pub classes: Vec<String>,
+    /// For `<template>`: the document fragment holding its contents, which
+    /// are not children of the element itself.
+    pub template_contents: Option<NodeId>,
 }
 
 // ---------------------------------------------------------------------------
@@ -63,6 +66,8 @@ pub enum NodeData {
     Document {
         compat_mode: CompatMode,
     },
+    /// A parentless container, e.g. the contents of a `<template>`.
+    DocumentFragment,
     DocumentType {
         name: String,
         public_id: String,
@@ -14,6 +14,7 @@ use crate::node::{
 // ---------------------------------------------------------------------------
 
 /// The complete DOM tree.
+#[derive(Clone)]
 pub struct Dom {
     pub nodes: Arena<Node>,
 }
@@ -44,6 +45,11 @@ impl Dom {
         self.nodes.allocate(node)
     }
 
+    /// Create a DocumentFragment node.
+    pub fn create_document_fragment(&mut self) -> NodeId {
+        self.nodes.allocate(Node::new(NodeData::DocumentFragment))
+    }
+
     /// Create a DocumentType node.
     pub fn create_doctype(
         &mut self,
@@ -90,6 +96,7 @@ impl Dom {
             attrs,
             id,
             classes,
+            template_contents: None,
         }));
         self.nodes.allocate(node)
     }
@@ -219,6 +226,35 @@ impl Dom {
         }
     }
 
+    /// Deep-copy `node` and its descendants from `other` into this DOM.
+    ///
+    /// The copy is detached; insert it with [`append_child`](Self::append_child)
+    /// or [`insert_before`](Self::insert_before).
+    pub fn import_node(&mut self, other: &Dom, node: NodeId) -> NodeId {
+        let data = match other.nodes.get(node) {
+            Some(n) => n.data.clone(),
+            None => NodeData::Text {
+                data: String::new(),
+            },
+        };
+        let contents = match &data {
+            NodeData::Element(e) => e.template_contents,
+            _ => None,
+        };
+        let copy = self.nodes.allocate(Node::new(data));
+        for child in other.children(node) {
+            let child_copy = self.import_node(other, child);
+            self.append_child(copy, child_copy);
+        }
+        if let Some(contents) = contents {
+            let contents_copy = self.import_node(other, contents);
+            if let Some(e) = self.nodes.get_mut(copy).and_then(|n| n.as_element_mut()) {
+                e.template_contents = Some(contents_copy);
+            }
+        }
+        copy
+    }
+
     /// Internal: detach a node from its parent without deallocating it.
     fn detach(&mut self, node_id: NodeId) {
         let (parent_id, prev, next) = match self.nodes.get(node_id) {
@@ -804,4 +840,20 @@ mod tests {
 
         assert_eq!(dom.children(parent), vec![b, a, c]);
     }
+
+    #[test]
+    fn import_node_copies_subtree() {
+        let (src, _doc, _html, _head, _body, div, p1, p2) = build_sample_tree();
+        let mut dom = Dom::new();
+        let copy = dom.import_node(&src, div);
+
+        assert!(dom.nodes.get(copy).unwrap().parent.is_none());
+        assert_eq!(dom.nodes.get(copy).unwrap().as_element().unwrap().id.as_deref(), Some("main"));
+        let children = dom.children(copy);
+        assert_eq!(children.len(), 2);
+        for (copied, original) in children.iter().zip([p1, p2]) {
+            assert_eq!(dom.nodes.get(*copied).unwrap().data, src.nodes.get(original).unwrap().data);
+            assert_eq!(dom.children(*copied).len(), src.children(original).len());
+        }
+    }
 }
@@ -0,0 +1,257 @@
+//! Chinese multi-byte encodings: GBK / gb18030 and Big5 (WHATWG Encoding
+//! Standard §10–11).
+
+use crate::EncodeSink;
+use crate::index;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// gb18030 / GBK
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Decode gb18030 bytes. GBK shares this decoder.
+pub(crate) fn decode_gb18030(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len());
+    let (mut first, mut second, mut third) = (0u8, 0u8, 0u8);
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+        i += 1;
+
+        if third != 0 {
+            if !(0x30..=0x39).contains(&byte) {
+                // Re-read the second, third and this byte.
+                i -= 3;
+                (first, second, third) = (0, 0, 0);
+                out.push('\u{FFFD}');
+                continue;
+            }
+            let pointer = (first as u32 - 0x81) * (10 * 126 * 10)
+                + (second as u32 - 0x30) * (10 * 126)
+                + (third as u32 - 0x81) * 10
+                + byte as u32
+                - 0x30;
+            (first, second, third) = (0, 0, 0);
+            out.push(index::gb18030_ranges_code_point(pointer).unwrap_or('\u{FFFD}'));
+            continue;
+        }
+        if second != 0 {
+            if (0x81..=0xFE).contains(&byte) {
+                third = byte;
+                continue;
+            }
+            // Re-read the second and this byte.
+            i -= 2;
+            (first, second) = (0, 0);
+            out.push('\u{FFFD}');
+            continue;
+        }
+        if first != 0 {
+            if (0x30..=0x39).contains(&byte) {
+                second = byte;
+                continue;
+            }
+            let lead = first as usize;
+            first = 0;
+            if matches!(byte, 0x40..=0x7E | 0x80..=0xFE) {
+                let offset = if byte < 0x7F { 0x40 } else { 0x41 };
+                let pointer = (lead - 0x81) * 190 + byte as usize - offset;
+                if let Some(c) = index::gb18030_code_point(pointer) {
+                    out.push(c);
+                    continue;
+                }
+            }
+            if byte.is_ascii() {
+                i -= 1;
+            }
+            out.push('\u{FFFD}');
+            continue;
+        }
+
+        match byte {
+            0x00..=0x7F => out.push(byte as char),
+            0x80 => out.push('\u{20AC}'),
+            0x81..=0xFE => first = byte,
+            _ => out.push('\u{FFFD}'),
+        }
+    }
+
+    if first != 0 || second != 0 || third != 0 {
+        out.push('\u{FFFD}');
+    }
+    out
+}
+
+/// Encode `text` as gb18030, or as GBK when `gbk` is set (no four-byte
+/// sequences, and the euro sign as the single byte 0x80).
+pub(crate) fn encode_gb18030(text: &str, gbk: bool, sink: &mut dyn EncodeSink) {
+    for c in text.chars() {
+        if c.is_ascii() {
+            sink.push_byte(c as u8);
+            continue;
+        }
+        if c == '\u{E5E5}' {
+            sink.unmappable(c);
+            continue;
+        }
+        if gbk && c == '\u{20AC}' {
+            sink.push_byte(0x80);
+            continue;
+        }
+        if let Some(pointer) = index::gb18030_pointer(c) {
+            let lead = pointer / 190 + 0x81;
+            let trail = pointer % 190;
+            let offset = if trail < 0x3F { 0x40 } else { 0x41 };
+            sink.push_bytes(&[lead as u8, (trail + offset) as u8]);
+            continue;
+        }
+        if gbk {
+            sink.unmappable(c);
+            continue;
+        }
+        let mut pointer = index::gb18030_ranges_pointer(c);
+        let byte1 = pointer / (10 * 126 * 10);
+        pointer %= 10 * 126 * 10;
+        let byte2 = pointer / (10 * 126);
+        pointer %= 10 * 126;
+        let byte3 = pointer / 10;
+        let byte4 = pointer % 10;
+        sink.push_bytes(&[
+            (byte1 + 0x81) as u8,
+            (byte2 + 0x30) as u8,
+            (byte3 + 0x81) as u8,
+            (byte4 + 0x30) as u8,
+        ]);
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Big5
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Decode Big5 (HKSCS) bytes.
+pub(crate) fn decode_big5(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len());
+    let mut lead: u8 = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+        i += 1;
+
+        if lead != 0 {
+            let l = lead as usize;
+            lead = 0;
+            if matches!(byte, 0x40..=0x7E | 0xA1..=0xFE) {
+                let offset = if byte < 0x7F { 0x40 } else { 0x62 };
+                let pointer = (l - 0x81) * 157 + byte as usize - offset;
+                // Four pointers decode to a base letter plus combining mark.
+                let pair = match pointer {
+                    1133 => Some("\u{CA}\u{304}"),
+                    1135 => Some("\u{CA}\u{30C}"),
+                    1164 => Some("\u{EA}\u{304}"),
+                    1166 => Some("\u{EA}\u{30C}"),
+                    _ => None,
+                };
+                if let Some(pair) = pair {
+                    out.push_str(pair);
+                    continue;
+                }
+                if let Some(c) = index::big5_code_point(pointer) {
+                    out.push(c);
+                    continue;
+                }
+            }
+            if byte.is_ascii() {
+                i -= 1;
+            }
+            out.push('\u{FFFD}');
+            continue;
+        }
+
+        match byte {
+            0x00..=0x7F => out.push(byte as char),
+            0x81..=0xFE => lead = byte,
+            _ => out.push('\u{FFFD}'),
+        }
+    }
+
+    if lead != 0 {
+        out.push('\u{FFFD}');
+    }
+    out
+}
+
+/// Encode `text` as Big5.
+pub(crate) fn encode_big5(text: &str, sink: &mut dyn EncodeSink) {
+    for c in text.chars() {
+        if c.is_ascii() {
+            sink.push_byte(c as u8);
+            continue;
+        }
+        let Some(pointer) = index::big5_pointer(c) else {
+            sink.unmappable(c);
+            continue;
+        };
+        let lead = pointer / 157 + 0x81;
+        let trail = pointer % 157;
+        let offset = if trail < 0x3F { 0x40 } else { 0x62 };
+        sink.push_bytes(&[lead as u8, (trail + offset) as u8]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NcrSink;
+
+    fn encode(text: &str, f: impl Fn(&str, &mut dyn EncodeSink)) -> Vec<u8> {
+        let mut sink = NcrSink::default();
+        f(text, &mut sink);
+        sink.bytes
+    }
+
+    #[test]
+    fn test_gb18030_decode() {
+        // "中文" (two-byte) and the single-byte euro sign.
+        assert_eq!(decode_gb18030(&[0xD6, 0xD0, 0xCE, 0xC4, 0x80]), "中文€");
+        // Four-byte sequences: U+0080 and U+10000.
+        assert_eq!(decode_gb18030(&[0x81, 0x30, 0x81, 0x30]), "\u{80}");
+        assert_eq!(decode_gb18030(&[0x90, 0x30, 0x81, 0x30]), "\u{10000}");
+        // A broken four-byte sequence re-reads the following bytes.
+        assert_eq!(decode_gb18030(&[0x81, 0x30, 0x41]), "\u{FFFD}0A");
+        assert_eq!(decode_gb18030(&[0xD6]), "\u{FFFD}");
+    }
+
+    #[test]
+    fn test_gb18030_round_trip() {
+        let text = "中文 \u{80}\u{E7C7}\u{1F600}";
+        let bytes = encode(text, |t, s| encode_gb18030(t, false, s));
+        assert_eq!(decode_gb18030(&bytes), text);
+    }
+
+    #[test]
+    fn test_gbk_encode() {
+        // GBK has no four-byte form and encodes the euro sign as 0x80.
+        let bytes = encode("中€\u{1F600}", |t, s| encode_gb18030(t, true, s));
+        assert_eq!(bytes, b"\xD6\xD0\x80&#128512;");
+    }
+
+    #[test]
+    fn test_big5_decode() {
+        // "中文", then a pointer that decodes to two code points.
+        assert_eq!(
+            decode_big5(&[0xA4, 0xA4, 0xA4, 0xE5, 0x88, 0x62]),
+            "中文\u{CA}\u{304}"
+        );
+        assert_eq!(decode_big5(&[0xA4, 0x20]), "\u{FFFD} ");
+    }
+
+    #[test]
+    fn test_big5_round_trip() {
+        let text = "繁體中文 ═";
+        let bytes = encode(text, encode_big5);
+        assert_eq!(decode_big5(&bytes), text);
+        assert!(bytes.ends_with(&[0xF9, 0xF9]));
+    }
+}
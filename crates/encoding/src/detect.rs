@@ -0,0 +1,435 @@
+//! Statistical encoding detection for unlabeled documents.
+//!
+//! Each candidate encoding is paired with frequency models of the languages
+//! usually written in it. The sample is decoded with every candidate and the
+//! result scored against those models: frequent characters of the language
+//! score high, other characters of its script score a little, and malformed
+//! sequences, C1 controls, private-use characters and foreign scripts are
+//! penalized. Latin-script models additionally penalize runs of non-ASCII
+//! letters, which are rare in real text but typical of CJK bytes decoded as a
+//! single-byte encoding. The candidate with the best average score wins.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::{EncodingLabel, decode_to_utf8, is_valid_utf8};
+
+/// At most this many bytes are examined.
+pub const DETECTOR_SAMPLE_LEN: usize = 64 * 1024;
+
+/// Score for a byte sequence the candidate cannot decode.
+const MALFORMED: i64 = -100;
+/// Score for C1 controls and private-use characters.
+const IMPLAUSIBLE: i64 = -30;
+/// Score for a letter of the model's script that is not among its frequent
+/// characters.
+const SCRIPT: i64 = 2;
+/// Score for punctuation shared by most legacy encodings (curly quotes,
+/// dashes, the euro sign, …).
+const PUNCTUATION: i64 = 1;
+/// Score for anything else.
+const FOREIGN: i64 = -5;
+/// Penalty for a non-ASCII letter directly after another one, for
+/// Latin-script models.
+const ADJACENT: i64 = 15;
+/// How far (per character, ×100) another candidate must outscore
+/// windows-1252, the default for unlabeled documents, to be chosen instead.
+const DEFAULT_MARGIN: i64 = 300;
+/// Frequent characters score between these, most frequent first.
+const FREQUENT_MIN: i64 = 4;
+const FREQUENT_MAX: i64 = 20;
+
+const COMMON_PUNCTUATION: &str = "\u{A0}’‘“”„–—…•«»°€£©®™§·¡¿";
+
+struct LanguageModel {
+    /// Encodings the language is commonly written in, in tie-break order.
+    encodings: &'static [EncodingLabel],
+    /// The language's non-ASCII characters, most frequent first.
+    frequent: &'static str,
+    /// Code point ranges of the language's script(s).
+    script: &'static [(u32, u32)],
+    /// Whether non-ASCII letters normally sit among ASCII letters.
+    latin: bool,
+}
+
+const LATIN_1: &[(u32, u32)] = &[(0xC0, 0xFF), (0x152, 0x153), (0x160, 0x161), (0x178, 0x17E)];
+const LATIN_EXTENDED: &[(u32, u32)] = &[(0xC0, 0x24F)];
+const CYRILLIC: &[(u32, u32)] = &[(0x400, 0x45F), (0x490, 0x491)];
+const GREEK: &[(u32, u32)] = &[(0x386, 0x3CE)];
+const HEBREW: &[(u32, u32)] = &[(0x5B0, 0x5F4)];
+const ARABIC: &[(u32, u32)] = &[(0x60C, 0x6FF)];
+const THAI: &[(u32, u32)] = &[(0xE01, 0xE5B)];
+const JAPANESE: &[(u32, u32)] = &[
+    (0x3000, 0x303F),
+    (0x3041, 0x30FF),
+    (0x4E00, 0x9FFF),
+    (0xFF01, 0xFF5E),
+];
+const CHINESE: &[(u32, u32)] = &[(0x3000, 0x303F), (0x4E00, 0x9FFF), (0xFF01, 0xFF5E)];
+const KOREAN: &[(u32, u32)] = &[
+    (0x3000, 0x303F),
+    (0x3131, 0x318E),
+    (0xAC00, 0xD7A3),
+    (0xFF01, 0xFF5E),
+];
+
+static MODELS: &[LanguageModel] = &[
+    // French, German, Spanish, Portuguese, Italian, Dutch, Nordic.
+    LanguageModel {
+        encodings: &[EncodingLabel::Windows1252],
+        frequent: "éäüöàèáíóñçêãßúâôîõûùœåøæëïìòÉÄÜÖÁÓÀÇÅØÆÍÚÈÊÑÿ",
+        script: LATIN_1,
+        latin: true,
+    },
+    // Polish, Czech, Slovak, Hungarian, Romanian, Croatian, Slovenian.
+    LanguageModel {
+        encodings: &[EncodingLabel::Windows1250, EncodingLabel::Iso8859_2],
+        frequent: "áéíóěšłčřąžýęůśżőúćńöüűźăâîşţđťďňľŁŚŻŠČŘŽÁÉÍÓÚĆŃŹĐ",
+        script: LATIN_EXTENDED,
+        latin: true,
+    },
+    // Turkish.
+    LanguageModel {
+        encodings: &[EncodingLabel::Windows1254],
+        frequent: "ıüşöçğİâîûÇŞÖÜĞ",
+        script: LATIN_EXTENDED,
+        latin: true,
+    },
+    // Lithuanian, Latvian, Estonian.
+    LanguageModel {
+        encodings: &[EncodingLabel::Windows1257],
+        frequent: "āėšīūųįčžęąēõäöüļņķģŠŽČĀĒĪŪ",
+        script: LATIN_EXTENDED,
+        latin: true,
+    },
+    // Russian, Ukrainian, Bulgarian, Serbian.
+    LanguageModel {
+        encodings: &[
+            EncodingLabel::Windows1251,
+            EncodingLabel::Koi8R,
+            EncodingLabel::Koi8U,
+            EncodingLabel::Ibm866,
+            EncodingLabel::Iso8859_5,
+            EncodingLabel::XMacCyrillic,
+        ],
+        frequent: "оеаинтсрвлкмдпуяызьбгчйхжшюцщэфіїєъёґ",
+        script: CYRILLIC,
+        latin: false,
+    },
+    LanguageModel {
+        encodings: &[EncodingLabel::Windows1253, EncodingLabel::Iso8859_7],
+        frequent: "αοετινσρκπμλυηωςγδάέίόθήύφχώβξζψΑΤΚΠΣΕΟ",
+        script: GREEK,
+        latin: false,
+    },
+    LanguageModel {
+        encodings: &[EncodingLabel::Windows1255, EncodingLabel::Iso8859_8],
+        frequent: "יוהלארבמתשנכעדקםפחסןגצטזךףץ",
+        script: HEBREW,
+        latin: false,
+    },
+    LanguageModel {
+        encodings: &[EncodingLabel::Windows1256, EncodingLabel::Iso8859_6],
+        frequent: "اليمنوةربهتعفدكقسحجأشصإطىذخضزثءئظغآؤ،",
+        script: ARABIC,
+        latin: false,
+    },
+    LanguageModel {
+        encodings: &[EncodingLabel::Windows874],
+        frequent: "านรอกเงมยิ่้ลดทวสตีัะบขปคไชพใหจำโุูผฟซถญศษฉฐฝฎฆฑธฒณฬฮ",
+        script: THAI,
+        latin: false,
+    },
+    LanguageModel {
+        encodings: &[EncodingLabel::ShiftJis, EncodingLabel::EucJp],
+        frequent: "、。のにはをたがでてとしれさあいうるなかこもらりますんっくきけつせそ\
+                   ちへめよわ「」ンールトスイクラリタドロシカテアマジ・日本人年大一中国出\
+                   上会時行見子生事自分者間方今社手作前新代気長地学何物合理同発定\
+                   業下用後的化思部高家外入場目記言話全問題",
+        script: JAPANESE,
+        latin: false,
+    },
+    LanguageModel {
+        encodings: &[EncodingLabel::Gbk, EncodingLabel::Big5],
+        frequent: "，的。一是不了在人有我他这个们中来上大为和国地到以说时要就出会可也你\
+                   对生能而子那得于着下自之年过发后作里用道行所然家种事成方多经么去法学\
+                   如都同现当没动面起看定天分还进好小部其些主样理心她本前开但因只从想实\
+                   日、军者意无力它与长把机十民第公此已工使情明性知全三又关点正业外将两\
+                   高间由问很最重并物手应战向头文体政美相见被利什二等产或新己制身果加西\
+                   斯月话合回特代内信表化老给世位次度门任常先海通教儿原东声提立及比员解\
+                   水名真论处走义各入几口认条平系气题活尔更别打女变四神总何电数安少报才\
+                   结反受目太量再感建务做接必场件计管期市直德资命山金指克许统区保至队形\
+                   社便空决治展马科司五基眼书非则听白却界达光放强即像难且权思王象完设式\
+                   色路记南品住告类求据程北边死张该交规万取拉格望觉术领共确传师观清今切\
+                   院让识候带导争运笑飞风步改收根干造言联持组每济车亲极林服快办议往元英\
+                   士证近失转夫令准布始怎呢存未远叫台单影具罗字爱击流备兵连调深商算质团\
+                   集百需价花党华城石级整府离况亚请技际约示复病息究线似官火断精满支视消\
+                   越器容照须九增研写称企八功吗包片史委乎查轻易早曾除农找装广显吧阿李标\
+                   谈吃图念六引历首医局突专费号尽另周较注语仅考落青随选列武红响虽推势参\
+                   希古众构房半节土投某案黑维革划敌致陈律足态护七兴派孩验责营星够章音跟\
+                   志底站严巴例防族供效续施留讲型料终答紧黄绝奇察母京段依批群项故按河米\
+                   围江织害斗双境客纪采举杀攻父苏密低朝友诉止细愿千值仍男钱破网热助倒育\
+                   們個來說時會對學這國現問過還經動發頭當書點樣聽義開關長門間題實體從應\
+                   認難與機種業電樂無見話進東車華語氣幾買錢讓愛畫紅張臺灣變親專單記統師\
+                   組織歷際價選區處務報戰軍將員運設計資產權總導興萬葉雲陽醫藥飛風黨條\
+                   「」：；！？（）《》“”",
+        script: CHINESE,
+        latin: false,
+    },
+    LanguageModel {
+        encodings: &[EncodingLabel::EucKr],
+        frequent: "이다의는에가하을를고지서기로한사자리도수대어나인시해아정부라상게보전\
+                   만적일들구주국것니요우있화동제으장성과오소비면공내생진여방위않거관개\
+                   문신세되연무치유안경원물모발조까분회없교행했학미알중터통말각실명당음\
+                   재선할히데간및저산더같또때점러후집결체현된입식받업법단을년월합",
+        script: KOREAN,
+        latin: false,
+    },
+];
+
+/// Frequent-character weights for each model, built on first use.
+fn model_weights() -> &'static [HashMap<char, i64>] {
+    static WEIGHTS: OnceLock<Vec<HashMap<char, i64>>> = OnceLock::new();
+    WEIGHTS.get_or_init(|| {
+        MODELS
+            .iter()
+            .map(|model| {
+                let chars: Vec<char> = model
+                    .frequent
+                    .chars()
+                    .filter(|c| !c.is_whitespace())
+                    .collect();
+                let n = chars.len() as i64;
+                let mut weights = HashMap::with_capacity(chars.len());
+                for (rank, c) in chars.into_iter().enumerate() {
+                    let w = FREQUENT_MIN + (FREQUENT_MAX - FREQUENT_MIN) * (n - rank as i64) / n;
+                    weights.entry(c).or_insert(w);
+                }
+                weights
+            })
+            .collect()
+    })
+}
+
+/// Guess the encoding of unlabeled bytes.
+///
+/// Valid UTF-8 (including pure ASCII) is UTF-8, and 7-bit text with
+/// ISO-2022-JP escape sequences is ISO-2022-JP. Otherwise the answer is
+/// windows-1252 unless another legacy encoding clearly outscores it.
+pub fn detect_statistically(bytes: &[u8]) -> EncodingLabel {
+    let sample = &bytes[..bytes.len().min(DETECTOR_SAMPLE_LEN)];
+    if sample.is_ascii() {
+        let has_jis_escape = sample
+            .windows(3)
+            .any(|w| matches!(w, [0x1B, b'$', b'@' | b'B'] | [0x1B, b'(', b'J' | b'I']));
+        if has_jis_escape {
+            return EncodingLabel::Iso2022Jp;
+        }
+    }
+    if is_valid_utf8(trim_partial_utf8(sample)) {
+        return EncodingLabel::Utf8;
+    }
+
+    let weights = model_weights();
+    let mut scores: Vec<(EncodingLabel, i64)> = Vec::new();
+    for (model, weights) in MODELS.iter().zip(weights) {
+        for &encoding in model.encodings {
+            let text = decode_to_utf8(sample, encoding);
+            let score = score_text(&text, model, weights);
+            match scores.iter_mut().find(|(e, _)| *e == encoding) {
+                Some(entry) => entry.1 = entry.1.max(score),
+                None => scores.push((encoding, score)),
+            }
+        }
+    }
+    let default_score = scores
+        .iter()
+        .find(|(e, _)| *e == EncodingLabel::Windows1252)
+        .map_or(0, |&(_, score)| score.max(0));
+    let mut best = (EncodingLabel::Windows1252, default_score + DEFAULT_MARGIN);
+    for (encoding, score) in scores {
+        if score > best.1 {
+            best = (encoding, score);
+        }
+    }
+    best.0
+}
+
+/// Drop a UTF-8 sequence cut off by the end of the sample.
+fn trim_partial_utf8(sample: &[u8]) -> &[u8] {
+    if sample.len() < DETECTOR_SAMPLE_LEN {
+        return sample;
+    }
+    let tail = sample
+        .iter()
+        .rev()
+        .take(3)
+        .take_while(|&&b| b & 0xC0 == 0x80)
+        .count();
+    let lead = sample.len() - tail - 1;
+    if sample[lead] >= 0xC0 {
+        &sample[..lead]
+    } else {
+        sample
+    }
+}
+
+/// Average score per non-ASCII character of `text` under `model`, scaled by
+/// 100 to keep integer precision.
+fn score_text(text: &str, model: &LanguageModel, weights: &HashMap<char, i64>) -> i64 {
+    let mut total = 0i64;
+    let mut count = 0i64;
+    let mut prev_letter = false;
+    for c in text.chars() {
+        if c.is_ascii() {
+            prev_letter = false;
+            continue;
+        }
+        count += 1;
+        let cp = c as u32;
+        let mut score = if c == '\u{FFFD}' {
+            MALFORMED
+        } else if let Some(&w) = weights.get(&c) {
+            w
+        } else if model.script.iter().any(|&(lo, hi)| (lo..=hi).contains(&cp)) {
+            SCRIPT
+        } else if (0x80..=0x9F).contains(&cp) || (0xE000..=0xF8FF).contains(&cp) {
+            IMPLAUSIBLE
+        } else if COMMON_PUNCTUATION.contains(c) {
+            PUNCTUATION
+        } else {
+            FOREIGN
+        };
+        let letter = c.is_alphabetic();
+        if model.latin && letter && prev_letter {
+            score -= ADJACENT;
+        }
+        prev_letter = letter;
+        total += score;
+    }
+    if count == 0 { 0 } else { total * 100 / count }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode_from_utf8;
+
+    fn detect(text: &str, encoding: EncodingLabel) -> EncodingLabel {
+        let bytes = encode_from_utf8(text, encoding);
+        assert_ne!(bytes, text.as_bytes(), "sample must not be pure ASCII");
+        detect_statistically(&bytes)
+    }
+
+    #[test]
+    fn test_detect_utf8_and_ascii() {
+        assert_eq!(
+            detect_statistically("Grüße".as_bytes()),
+            EncodingLabel::Utf8
+        );
+        assert_eq!(detect_statistically(b"plain text"), EncodingLabel::Utf8);
+        assert_eq!(
+            detect_statistically(b"\x1b$BF|K\\\x1b(B"),
+            EncodingLabel::Iso2022Jp
+        );
+    }
+
+    #[test]
+    fn test_detect_western_and_central_european() {
+        let french = "Le cœur a ses raisons que la raison ne connaît point. Où êtes-vous allé?";
+        assert_eq!(
+            detect(french, EncodingLabel::Windows1252),
+            EncodingLabel::Windows1252
+        );
+        let german = "Größere Änderungen für die Straße müssen geprüft werden.";
+        assert_eq!(
+            detect(german, EncodingLabel::Windows1252),
+            EncodingLabel::Windows1252
+        );
+        let polish = "Zażółć gęślą jaźń. Właściwie każdy człowiek może się nauczyć.";
+        assert_eq!(
+            detect(polish, EncodingLabel::Windows1250),
+            EncodingLabel::Windows1250
+        );
+        assert_eq!(
+            detect(polish, EncodingLabel::Iso8859_2),
+            EncodingLabel::Iso8859_2
+        );
+        let czech = "Příliš žluťoučký kůň úpěl ďábelské ódy. Čeština není těžká.";
+        assert_eq!(
+            detect(czech, EncodingLabel::Windows1250),
+            EncodingLabel::Windows1250
+        );
+        let turkish = "Türkçe öğrenmek için çok çalışmak gerekir. Işık şimdi yanıyor.";
+        assert_eq!(
+            detect(turkish, EncodingLabel::Windows1254),
+            EncodingLabel::Windows1254
+        );
+    }
+
+    #[test]
+    fn test_detect_cyrillic() {
+        let russian = "Съешь же ещё этих мягких французских булок, да выпей чаю. \
+                       Москва является столицей России.";
+        assert_eq!(
+            detect(russian, EncodingLabel::Windows1251),
+            EncodingLabel::Windows1251
+        );
+        assert_eq!(detect(russian, EncodingLabel::Koi8R), EncodingLabel::Koi8R);
+        assert_eq!(
+            detect(russian, EncodingLabel::Ibm866),
+            EncodingLabel::Ibm866
+        );
+    }
+
+    #[test]
+    fn test_detect_greek_hebrew_arabic_thai() {
+        let greek = "Η γλώσσα μου έδωσαν ελληνική, το σπίτι φτωχικό στις αμμουδιές του Ομήρου.";
+        assert_eq!(
+            detect(greek, EncodingLabel::Windows1253),
+            EncodingLabel::Windows1253
+        );
+        let hebrew = "שלום עולם, זוהי דוגמה של טקסט בעברית עם מילים רבות.";
+        assert_eq!(
+            detect(hebrew, EncodingLabel::Windows1255),
+            EncodingLabel::Windows1255
+        );
+        let arabic = "مرحبا بالعالم، هذا مثال على نص باللغة العربية.";
+        assert_eq!(
+            detect(arabic, EncodingLabel::Windows1256),
+            EncodingLabel::Windows1256
+        );
+        let thai = "ภาษาไทยเป็นภาษาที่มีวรรณยุกต์ และใช้อักษรไทยในการเขียน";
+        assert_eq!(
+            detect(thai, EncodingLabel::Windows874),
+            EncodingLabel::Windows874
+        );
+    }
+
+    #[test]
+    fn test_detect_cjk() {
+        let japanese = "日本語のテキストを正しく判定できるかどうかを確認します。";
+        assert_eq!(
+            detect(japanese, EncodingLabel::ShiftJis),
+            EncodingLabel::ShiftJis
+        );
+        assert_eq!(detect(japanese, EncodingLabel::EucJp), EncodingLabel::EucJp);
+        let chinese = "我们的国家有很多人，他们在这里生活和工作。";
+        assert_eq!(detect(chinese, EncodingLabel::Gbk), EncodingLabel::Gbk);
+        let traditional = "我們的國家有很多人，他們在這裡生活和工作。";
+        assert_eq!(
+            detect(traditional, EncodingLabel::Big5),
+            EncodingLabel::Big5
+        );
+        let korean = "대한민국은 민주공화국이다. 모든 권력은 국민으로부터 나온다.";
+        assert_eq!(detect(korean, EncodingLabel::EucKr), EncodingLabel::EucKr);
+    }
+
+    #[test]
+    fn test_detect_without_evidence_falls_back() {
+        assert_eq!(
+            detect_statistically(&[0x80, 0x81, 0x82]),
+            EncodingLabel::Windows1252
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! WHATWG Encoding Standard indexes used by the CJK decoders and encoders.
+//!
+//! Decoding is a direct table lookup by pointer. Encoding needs the reverse
+//! direction, which is built lazily into a hash map the first time an encoder
+//! for that index runs.
+
+mod big5;
+mod euc_kr;
+mod gb18030;
+mod gb18030_ranges;
+mod jis0208;
+mod jis0212;
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// `index-iso-2022-jp-katakana`: half-width katakana U+FF61..=U+FF9F to their
+/// full-width forms, used by the ISO-2022-JP encoder.
+#[rustfmt::skip]
+pub(crate) static ISO_2022_JP_KATAKANA: [u16; 63] = [
+    0x3002, 0x300C, 0x300D, 0x3001, 0x30FB, 0x30F2, 0x30A1, 0x30A3, 0x30A5, 0x30A7, 0x30A9, 0x30E3,
+    0x30E5, 0x30E7, 0x30C3, 0x30FC, 0x30A2, 0x30A4, 0x30A6, 0x30A8, 0x30AA, 0x30AB, 0x30AD, 0x30AF,
+    0x30B1, 0x30B3, 0x30B5, 0x30B7, 0x30B9, 0x30BB, 0x30BD, 0x30BF, 0x30C1, 0x30C4, 0x30C6, 0x30C8,
+    0x30CA, 0x30CB, 0x30CC, 0x30CD, 0x30CE, 0x30CF, 0x30D2, 0x30D5, 0x30D8, 0x30DB, 0x30DE, 0x30DF,
+    0x30E0, 0x30E1, 0x30E2, 0x30E4, 0x30E6, 0x30E8, 0x30E9, 0x30EA, 0x30EB, 0x30EC, 0x30ED, 0x30EF,
+    0x30F3, 0x309B, 0x309C,
+];
+
+/// Look up `pointer` in a `u16` index, treating `0` as "no code point".
+fn lookup16(table: &[u16], pointer: usize) -> Option<char> {
+    match table.get(pointer) {
+        Some(&0) | None => None,
+        Some(&cp) => char::from_u32(cp as u32),
+    }
+}
+
+/// Build a code point → pointer map keeping the first pointer for each code
+/// point, skipping pointers for which `skip` returns true.
+fn first_pointers<T: Copy + Into<u32>>(
+    table: &[T],
+    skip: impl Fn(usize) -> bool,
+) -> HashMap<u32, usize> {
+    let mut map = HashMap::with_capacity(table.len());
+    for (pointer, &cp) in table.iter().enumerate() {
+        let cp = cp.into();
+        if cp != 0 && !skip(pointer) {
+            map.entry(cp).or_insert(pointer);
+        }
+    }
+    map
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Japanese
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Code point for `pointer` in `index-jis0208`.
+pub(crate) fn jis0208_code_point(pointer: usize) -> Option<char> {
+    lookup16(&jis0208::JIS0208, pointer)
+}
+
+/// First `index-jis0208` pointer for `c` (the "index pointer").
+pub(crate) fn jis0208_pointer(c: char) -> Option<usize> {
+    static MAP: OnceLock<HashMap<u32, usize>> = OnceLock::new();
+    MAP.get_or_init(|| first_pointers(&jis0208::JIS0208, |_| false))
+        .get(&(c as u32))
+        .copied()
+}
+
+/// The "index Shift_JIS pointer": like [`jis0208_pointer`] but ignoring the
+/// NEC-selected IBM extension rows (pointers 8272..=8835), so those characters
+/// encode to their IBM extension positions instead.
+pub(crate) fn shift_jis_pointer(c: char) -> Option<usize> {
+    static MAP: OnceLock<HashMap<u32, usize>> = OnceLock::new();
+    MAP.get_or_init(|| first_pointers(&jis0208::JIS0208, |p| (8272..=8835).contains(&p)))
+        .get(&(c as u32))
+        .copied()
+}
+
+/// Code point for `pointer` in `index-jis0212`.
+pub(crate) fn jis0212_code_point(pointer: usize) -> Option<char> {
+    lookup16(&jis0212::JIS0212, pointer)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Korean
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Code point for `pointer` in `index-euc-kr`.
+pub(crate) fn euc_kr_code_point(pointer: usize) -> Option<char> {
+    lookup16(&euc_kr::EUC_KR, pointer)
+}
+
+/// First `index-euc-kr` pointer for `c`.
+pub(crate) fn euc_kr_pointer(c: char) -> Option<usize> {
+    static MAP: OnceLock<HashMap<u32, usize>> = OnceLock::new();
+    MAP.get_or_init(|| first_pointers(&euc_kr::EUC_KR, |_| false))
+        .get(&(c as u32))
+        .copied()
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Chinese
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Code point for `pointer` in `index-gb18030`.
+pub(crate) fn gb18030_code_point(pointer: usize) -> Option<char> {
+    lookup16(&gb18030::GB18030, pointer)
+}
+
+/// First `index-gb18030` pointer for `c`.
+pub(crate) fn gb18030_pointer(c: char) -> Option<usize> {
+    static MAP: OnceLock<HashMap<u32, usize>> = OnceLock::new();
+    MAP.get_or_init(|| first_pointers(&gb18030::GB18030, |_| false))
+        .get(&(c as u32))
+        .copied()
+}
+
+/// The "index gb18030 ranges code point" for a four-byte `pointer`.
+pub(crate) fn gb18030_ranges_code_point(pointer: u32) -> Option<char> {
+    if (39420..189000).contains(&pointer) || pointer > 1_237_575 {
+        return None;
+    }
+    if pointer == 7457 {
+        return Some('\u{E7C7}');
+    }
+    if pointer >= 189000 {
+        return char::from_u32(0x10000 + pointer - 189000);
+    }
+    let ranges = &gb18030_ranges::GB18030_RANGES;
+    let idx = ranges.partition_point(|&(p, _)| p <= pointer) - 1;
+    let (start, cp) = ranges[idx];
+    char::from_u32(cp + pointer - start)
+}
+
+/// The "index gb18030 ranges pointer" for `c`. Every code point not covered by
+/// the two-byte index has one.
+pub(crate) fn gb18030_ranges_pointer(c: char) -> u32 {
+    let cp = c as u32;
+    if cp == 0xE7C7 {
+        return 7457;
+    }
+    if cp >= 0x10000 {
+        return 189000 + cp - 0x10000;
+    }
+    let ranges = &gb18030_ranges::GB18030_RANGES;
+    let idx = ranges.partition_point(|&(_, start)| start <= cp) - 1;
+    let (pointer, start) = ranges[idx];
+    pointer + cp - start
+}
+
+/// Code point for `pointer` in `index-big5`.
+pub(crate) fn big5_code_point(pointer: usize) -> Option<char> {
+    match big5::BIG5.get(pointer) {
+        Some(&0) | None => None,
+        Some(&cp) => char::from_u32(cp),
+    }
+}
+
+/// The "index Big5 pointer" for `c`.
+///
+/// HKSCS pointers below `(0xA1 - 0x81) × 157` are never produced, and a handful
+/// of box-drawing characters and ideographs that appear twice use their last
+/// pointer rather than their first.
+pub(crate) fn big5_pointer(c: char) -> Option<usize> {
+    static MAP: OnceLock<HashMap<u32, usize>> = OnceLock::new();
+    const HKSCS_END: usize = (0xA1 - 0x81) * 157;
+    const USE_LAST: [u32; 6] = [0x2550, 0x255E, 0x2561, 0x256A, 0x5341, 0x5345];
+    let map = MAP.get_or_init(|| {
+        let mut map = first_pointers(&big5::BIG5, |p| p < HKSCS_END);
+        for (pointer, &cp) in big5::BIG5.iter().enumerate().skip(HKSCS_END) {
+            if USE_LAST.contains(&cp) {
+                map.insert(cp, pointer);
+            }
+        }
+        map
+    });
+    map.get(&(c as u32)).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gb18030_ranges_round_trip() {
+        for pointer in [0, 36, 7457, 19056, 39419, 189000, 1_237_575] {
+            let c = gb18030_ranges_code_point(pointer).unwrap();
+            assert_eq!(gb18030_ranges_pointer(c), pointer);
+        }
+        assert_eq!(gb18030_ranges_code_point(0), Some('\u{80}'));
+        assert_eq!(gb18030_ranges_code_point(39420), None);
+        assert_eq!(gb18030_ranges_code_point(189000), Some('\u{10000}'));
+    }
+
+    #[test]
+    fn test_big5_pointer_prefers_last_for_duplicates() {
+        // U+2550 appears at pointers 18991 (0xF9F9) and 5247 (0xA2A4).
+        assert_eq!(big5_pointer('\u{2550}'), Some(18991));
+        assert_eq!(big5_code_point(5247), Some('\u{2550}'));
+    }
+
+    #[test]
+    fn test_shift_jis_pointer_skips_nec_ibm_rows() {
+        // U+2170 (small roman numeral one) is in NEC-selected IBM row 92 and
+        // in the IBM extension rows; Shift_JIS must use the latter.
+        let jis = jis0208_pointer('\u{2170}').unwrap();
+        let sjis = shift_jis_pointer('\u{2170}').unwrap();
+        assert!((8272..=8835).contains(&jis));
+        assert!(sjis >= 10716);
+    }
+}
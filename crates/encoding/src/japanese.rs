@@ -0,0 +1,457 @@
+//! Japanese multi-byte encodings: EUC-JP, ISO-2022-JP and Shift_JIS
+//! (WHATWG Encoding Standard §12).
+//!
+//! Decoders follow the spec's byte-at-a-time algorithms. Where the spec
+//! "prepends" a byte back to the input after a failed sequence, the decoders
+//! rewind the read position instead, since the prepended bytes are always the
+//! ones just read.
+
+use crate::EncodeSink;
+use crate::index;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// EUC-JP
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Decode EUC-JP bytes.
+pub(crate) fn decode_euc_jp(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len());
+    let mut lead: u8 = 0;
+    let mut jis0212 = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+        i += 1;
+
+        if lead == 0x8E && (0xA1..=0xDF).contains(&byte) {
+            // Half-width katakana.
+            lead = 0;
+            out.push(char::from_u32(0xFF61 - 0xA1 + byte as u32).unwrap_or('\u{FFFD}'));
+            continue;
+        }
+        if lead == 0x8F && (0xA1..=0xFE).contains(&byte) {
+            // Three-byte JIS X 0212 sequence.
+            jis0212 = true;
+            lead = byte;
+            continue;
+        }
+        if lead != 0 {
+            let l = lead;
+            lead = 0;
+            let mut c = None;
+            if (0xA1..=0xFE).contains(&l) && (0xA1..=0xFE).contains(&byte) {
+                let pointer = (l as usize - 0xA1) * 94 + byte as usize - 0xA1;
+                c = if jis0212 {
+                    index::jis0212_code_point(pointer)
+                } else {
+                    index::jis0208_code_point(pointer)
+                };
+            }
+            jis0212 = false;
+            match c {
+                Some(c) => out.push(c),
+                None => {
+                    if byte.is_ascii() {
+                        i -= 1;
+                    }
+                    out.push('\u{FFFD}');
+                }
+            }
+            continue;
+        }
+
+        match byte {
+            0x00..=0x7F => out.push(byte as char),
+            0x8E | 0x8F | 0xA1..=0xFE => lead = byte,
+            _ => out.push('\u{FFFD}'),
+        }
+    }
+
+    if lead != 0 {
+        out.push('\u{FFFD}');
+    }
+    out
+}
+
+/// Encode `text` as EUC-JP.
+pub(crate) fn encode_euc_jp(text: &str, sink: &mut dyn EncodeSink) {
+    for c in text.chars() {
+        let cp = c as u32;
+        match cp {
+            0x00..=0x7F => sink.push_byte(cp as u8),
+            0xA5 => sink.push_byte(0x5C),
+            0x203E => sink.push_byte(0x7E),
+            0xFF61..=0xFF9F => sink.push_bytes(&[0x8E, (cp - 0xFF61 + 0xA1) as u8]),
+            _ => {
+                let c = if c == '\u{2212}' { '\u{FF0D}' } else { c };
+                match index::jis0208_pointer(c) {
+                    Some(pointer) => {
+                        sink.push_bytes(&[(pointer / 94 + 0xA1) as u8, (pointer % 94 + 0xA1) as u8])
+                    }
+                    None => sink.unmappable(c),
+                }
+            }
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// ISO-2022-JP
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Iso2022JpState {
+    Ascii,
+    Roman,
+    Katakana,
+    LeadByte,
+    TrailByte,
+    EscapeStart,
+    Escape,
+}
+
+/// Decode ISO-2022-JP bytes.
+pub(crate) fn decode_iso_2022_jp(bytes: &[u8]) -> String {
+    use Iso2022JpState as S;
+
+    let mut out = String::with_capacity(bytes.len());
+    let mut state = S::Ascii;
+    let mut output_state = S::Ascii;
+    let mut lead: u8 = 0;
+    // Set after an escape sequence and cleared by any output, so that two
+    // consecutive escape sequences produce an error.
+    let mut output_flag = false;
+    let mut i = 0;
+
+    loop {
+        let byte = bytes.get(i).copied();
+        i += 1;
+
+        match state {
+            S::Ascii | S::Roman | S::Katakana | S::LeadByte => {
+                let Some(b) = byte else { break };
+                if b == 0x1B {
+                    state = S::EscapeStart;
+                    continue;
+                }
+                output_flag = false;
+                let c = match state {
+                    S::Ascii if b <= 0x7F && !matches!(b, 0x0E | 0x0F) => Some(b as char),
+                    S::Roman => match b {
+                        0x5C => Some('\u{A5}'),
+                        0x7E => Some('\u{203E}'),
+                        0x00..=0x7F if !matches!(b, 0x0E | 0x0F) => Some(b as char),
+                        _ => None,
+                    },
+                    S::Katakana if (0x21..=0x5F).contains(&b) => {
+                        char::from_u32(0xFF61 - 0x21 + b as u32)
+                    }
+                    S::LeadByte if (0x21..=0x7E).contains(&b) => {
+                        lead = b;
+                        state = S::TrailByte;
+                        continue;
+                    }
+                    _ => None,
+                };
+                out.push(c.unwrap_or('\u{FFFD}'));
+            }
+            S::TrailByte => match byte {
+                Some(0x1B) => {
+                    state = S::EscapeStart;
+                    out.push('\u{FFFD}');
+                }
+                Some(b @ 0x21..=0x7E) => {
+                    state = S::LeadByte;
+                    let pointer = (lead as usize - 0x21) * 94 + b as usize - 0x21;
+                    out.push(index::jis0208_code_point(pointer).unwrap_or('\u{FFFD}'));
+                }
+                Some(_) => {
+                    state = S::LeadByte;
+                    out.push('\u{FFFD}');
+                }
+                None => {
+                    state = S::LeadByte;
+                    i -= 1;
+                    out.push('\u{FFFD}');
+                }
+            },
+            S::EscapeStart => {
+                if let Some(b @ (0x24 | 0x28)) = byte {
+                    lead = b;
+                    state = S::Escape;
+                    continue;
+                }
+                i -= 1;
+                output_flag = false;
+                state = output_state;
+                out.push('\u{FFFD}');
+            }
+            S::Escape => {
+                let l = lead;
+                lead = 0;
+                let next = match (l, byte) {
+                    (0x28, Some(0x42)) => Some(S::Ascii),
+                    (0x28, Some(0x4A)) => Some(S::Roman),
+                    (0x28, Some(0x49)) => Some(S::Katakana),
+                    (0x24, Some(0x40 | 0x42)) => Some(S::LeadByte),
+                    _ => None,
+                };
+                match next {
+                    Some(next) => {
+                        state = next;
+                        output_state = next;
+                        if output_flag {
+                            out.push('\u{FFFD}');
+                        }
+                        output_flag = true;
+                    }
+                    None => {
+                        // Re-read the lead and this byte in the output state.
+                        i -= 2;
+                        output_flag = false;
+                        state = output_state;
+                        out.push('\u{FFFD}');
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Encode `text` as ISO-2022-JP, ending in the ASCII state.
+pub(crate) fn encode_iso_2022_jp(text: &str, sink: &mut dyn EncodeSink) {
+    #[derive(PartialEq)]
+    enum State {
+        Ascii,
+        Roman,
+        Jis0208,
+    }
+    const TO_ASCII: [u8; 3] = [0x1B, 0x28, 0x42];
+    const TO_ROMAN: [u8; 3] = [0x1B, 0x28, 0x4A];
+    const TO_JIS0208: [u8; 3] = [0x1B, 0x24, 0x42];
+
+    let mut state = State::Ascii;
+    let mut chars = text.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        let cp = c as u32;
+        if state != State::Jis0208 && matches!(cp, 0x0E | 0x0F | 0x1B) {
+            chars.next();
+            sink.unmappable('\u{FFFD}');
+            continue;
+        }
+        if state == State::Ascii && c.is_ascii() {
+            chars.next();
+            sink.push_byte(cp as u8);
+            continue;
+        }
+        if state == State::Roman {
+            let byte = match cp {
+                0xA5 => Some(0x5C),
+                0x203E => Some(0x7E),
+                0x5C | 0x7E => None,
+                0x00..=0x7F => Some(cp as u8),
+                _ => None,
+            };
+            if let Some(byte) = byte {
+                chars.next();
+                sink.push_byte(byte);
+                continue;
+            }
+        }
+        if c.is_ascii() {
+            state = State::Ascii;
+            sink.push_bytes(&TO_ASCII);
+            continue;
+        }
+        if cp == 0xA5 || cp == 0x203E {
+            state = State::Roman;
+            sink.push_bytes(&TO_ROMAN);
+            continue;
+        }
+
+        let mut mapped = c;
+        if cp == 0x2212 {
+            mapped = '\u{FF0D}';
+        } else if (0xFF61..=0xFF9F).contains(&cp) {
+            let full = index::ISO_2022_JP_KATAKANA[(cp - 0xFF61) as usize];
+            mapped = char::from_u32(full as u32).unwrap_or(c);
+        }
+        let Some(pointer) = index::jis0208_pointer(mapped) else {
+            if state == State::Jis0208 {
+                // Leave JIS X 0208 before reporting, so the error output is
+                // written in the ASCII state.
+                state = State::Ascii;
+                sink.push_bytes(&TO_ASCII);
+                continue;
+            }
+            chars.next();
+            sink.unmappable(c);
+            continue;
+        };
+        if state != State::Jis0208 {
+            state = State::Jis0208;
+            sink.push_bytes(&TO_JIS0208);
+            continue;
+        }
+        chars.next();
+        sink.push_bytes(&[(pointer / 94 + 0x21) as u8, (pointer % 94 + 0x21) as u8]);
+    }
+
+    if state != State::Ascii {
+        sink.push_bytes(&TO_ASCII);
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Shift_JIS
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Decode Shift_JIS bytes.
+pub(crate) fn decode_shift_jis(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len());
+    let mut lead: u8 = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+        i += 1;
+
+        if lead != 0 {
+            let l = lead as usize;
+            lead = 0;
+            let offset = if byte < 0x7F { 0x40 } else { 0x41 };
+            let lead_offset = if l < 0xA0 { 0x81 } else { 0xC1 };
+            if matches!(byte, 0x40..=0x7E | 0x80..=0xFC) {
+                let pointer = (l - lead_offset) * 188 + byte as usize - offset;
+                if (8836..=10715).contains(&pointer) {
+                    // End-user-defined area maps to the Private Use Area.
+                    out.push(char::from_u32(0xE000 - 8836 + pointer as u32).unwrap_or('\u{FFFD}'));
+                    continue;
+                }
+                if let Some(c) = index::jis0208_code_point(pointer) {
+                    out.push(c);
+                    continue;
+                }
+            }
+            if byte.is_ascii() {
+                i -= 1;
+            }
+            out.push('\u{FFFD}');
+            continue;
+        }
+
+        match byte {
+            0x00..=0x80 => out.push(byte as char),
+            0xA1..=0xDF => {
+                out.push(char::from_u32(0xFF61 - 0xA1 + byte as u32).unwrap_or('\u{FFFD}'))
+            }
+            0x81..=0x9F | 0xE0..=0xFC => lead = byte,
+            _ => out.push('\u{FFFD}'),
+        }
+    }
+
+    if lead != 0 {
+        out.push('\u{FFFD}');
+    }
+    out
+}
+
+/// Encode `text` as Shift_JIS.
+pub(crate) fn encode_shift_jis(text: &str, sink: &mut dyn EncodeSink) {
+    for c in text.chars() {
+        let cp = c as u32;
+        match cp {
+            0x00..=0x80 => sink.push_byte(cp as u8),
+            0xA5 => sink.push_byte(0x5C),
+            0x203E => sink.push_byte(0x7E),
+            0xFF61..=0xFF9F => sink.push_byte((cp - 0xFF61 + 0xA1) as u8),
+            _ => {
+                let c = if c == '\u{2212}' { '\u{FF0D}' } else { c };
+                let Some(pointer) = index::shift_jis_pointer(c) else {
+                    sink.unmappable(c);
+                    continue;
+                };
+                let lead = pointer / 188;
+                let lead_offset = if lead < 0x1F { 0x81 } else { 0xC1 };
+                let trail = pointer % 188;
+                let offset = if trail < 0x3F { 0x40 } else { 0x41 };
+                sink.push_bytes(&[(lead + lead_offset) as u8, (trail + offset) as u8]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NcrSink;
+
+    fn encode(f: fn(&str, &mut dyn EncodeSink), text: &str) -> Vec<u8> {
+        let mut sink = NcrSink::default();
+        f(text, &mut sink);
+        sink.bytes
+    }
+
+    #[test]
+    fn test_shift_jis_decode() {
+        // "日本語" + half-width katakana "ｱ"
+        let bytes = [0x93, 0xFA, 0x96, 0x7B, 0x8C, 0xEA, 0xB1];
+        assert_eq!(decode_shift_jis(&bytes), "日本語ｱ");
+    }
+
+    #[test]
+    fn test_shift_jis_invalid_trail_keeps_ascii() {
+        // A lead followed by an ASCII byte that cannot be a trail byte
+        // yields U+FFFD and then the ASCII byte itself.
+        assert_eq!(decode_shift_jis(&[0x93, 0x20, 0x41]), "\u{FFFD} A");
+        assert_eq!(decode_shift_jis(&[0x93]), "\u{FFFD}");
+    }
+
+    #[test]
+    fn test_shift_jis_round_trip() {
+        let text = "日本語のテキスト、ｶﾀｶﾅ ¥100";
+        let bytes = encode(encode_shift_jis, text);
+        assert_eq!(decode_shift_jis(&bytes), text.replace('¥', "\\"));
+    }
+
+    #[test]
+    fn test_euc_jp_decode() {
+        // "日本" in JIS X 0208, then 0x8E half-width "ｱ", then JIS X 0212 "丂".
+        let bytes = [0xC6, 0xFC, 0xCB, 0xDC, 0x8E, 0xB1, 0x8F, 0xB0, 0xA1];
+        assert_eq!(decode_euc_jp(&bytes), "日本ｱ丂");
+    }
+
+    #[test]
+    fn test_euc_jp_round_trip() {
+        let text = "こんにちは、世界";
+        let bytes = encode(encode_euc_jp, text);
+        assert_eq!(bytes[..2], [0xA4, 0xB3]);
+        assert_eq!(decode_euc_jp(&bytes), text);
+    }
+
+    #[test]
+    fn test_iso_2022_jp_decode() {
+        let bytes = b"a\x1b$BF|K\\\x1b(Bz";
+        assert_eq!(decode_iso_2022_jp(bytes), "a日本z");
+        // Roman: 0x5C is the yen sign.
+        assert_eq!(decode_iso_2022_jp(b"\x1b(J\\"), "\u{A5}");
+        // Unknown escape sequence.
+        assert_eq!(decode_iso_2022_jp(b"\x1b(Zx"), "\u{FFFD}(Zx");
+        // Two escapes without output in between is an error.
+        assert_eq!(decode_iso_2022_jp(b"\x1b(B\x1b(Bx"), "\u{FFFD}x");
+    }
+
+    #[test]
+    fn test_iso_2022_jp_encode() {
+        assert_eq!(encode(encode_iso_2022_jp, "a日本z"), b"a\x1b$BF|K\\\x1b(Bz");
+        // Half-width katakana is widened; the stream always ends in ASCII.
+        assert_eq!(encode(encode_iso_2022_jp, "ｱ"), b"\x1b$B%\"\x1b(B");
+        // Unmappable characters are written in the ASCII state.
+        assert_eq!(
+            encode(encode_iso_2022_jp, "日\u{1F600}"),
+            b"\x1b$BF|\x1b(B&#128512;"
+        );
+    }
+}
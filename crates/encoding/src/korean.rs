@@ -0,0 +1,82 @@
+//! EUC-KR (WHATWG Encoding Standard §13), i.e. windows-949 / Unified Hangul
+//! Code.
+
+use crate::EncodeSink;
+use crate::index;
+
+/// Decode EUC-KR bytes.
+pub(crate) fn decode_euc_kr(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len());
+    let mut lead: u8 = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+        i += 1;
+
+        if lead != 0 {
+            let l = lead as usize;
+            lead = 0;
+            if (0x41..=0xFE).contains(&byte) {
+                let pointer = (l - 0x81) * 190 + byte as usize - 0x41;
+                if let Some(c) = index::euc_kr_code_point(pointer) {
+                    out.push(c);
+                    continue;
+                }
+            }
+            if byte.is_ascii() {
+                i -= 1;
+            }
+            out.push('\u{FFFD}');
+            continue;
+        }
+
+        match byte {
+            0x00..=0x7F => out.push(byte as char),
+            0x81..=0xFE => lead = byte,
+            _ => out.push('\u{FFFD}'),
+        }
+    }
+
+    if lead != 0 {
+        out.push('\u{FFFD}');
+    }
+    out
+}
+
+/// Encode `text` as EUC-KR.
+pub(crate) fn encode_euc_kr(text: &str, sink: &mut dyn EncodeSink) {
+    for c in text.chars() {
+        if c.is_ascii() {
+            sink.push_byte(c as u8);
+            continue;
+        }
+        match index::euc_kr_pointer(c) {
+            Some(pointer) => {
+                sink.push_bytes(&[(pointer / 190 + 0x81) as u8, (pointer % 190 + 0x41) as u8])
+            }
+            None => sink.unmappable(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NcrSink;
+
+    #[test]
+    fn test_euc_kr_decode() {
+        // "한국어" (KS X 1001) and "똠" (UHC extension area).
+        let bytes = [0xC7, 0xD1, 0xB1, 0xB9, 0xBE, 0xEE, 0x8C, 0x63];
+        assert_eq!(decode_euc_kr(&bytes), "한국어똠");
+        assert_eq!(decode_euc_kr(&[0xC7, 0x20]), "\u{FFFD} ");
+    }
+
+    #[test]
+    fn test_euc_kr_encode() {
+        let mut sink = NcrSink::default();
+        encode_euc_kr("한국 ☃", &mut sink);
+        assert_eq!(sink.bytes, b"\xC7\xD1\xB1\xB9 &#9731;");
+    }
+}
@@ -1,85 +1,233 @@
 //! # Character Encoding Detection and Conversion
 //!
-//! Detects encoding from BOM or meta charset hints, and decodes byte streams
-//! to UTF-8 strings. Supports UTF-8, Latin-1 (ISO 8859-1), ASCII, and
-//! Windows-1252. **Zero external dependencies.**
+//! Detects encoding from BOM or meta charset hints, decodes byte streams to
+//! UTF-8 strings, and encodes strings back for form submission and URL query
+//! strings. Implements every encoding in the WHATWG Encoding Standard: UTF-8,
+//! UTF-16, the legacy single-byte encodings (windows-125x, ISO-8859-x, KOI8,
+//! …), GBK/gb18030, Big5, EUC-JP, ISO-2022-JP, Shift_JIS and EUC-KR.
+//!
+//! [`sniff_encoding`] runs the HTML encoding sniffing algorithm (BOM,
+//! `Content-Type` charset, `<meta>` prescan) and falls back to a statistical
+//! detector for unlabeled documents.
+//! **Zero external dependencies.**
 
 #![forbid(unsafe_code)]
 
-/// Supported character encoding labels.
+mod chinese;
+mod detect;
+mod index;
+mod japanese;
+mod korean;
+mod single_byte;
+mod sniff;
+
+pub use detect::{DETECTOR_SAMPLE_LEN, detect_statistically};
+pub use sniff::{
+    Confidence, PRESCAN_LEN, bom_sniff, charset_from_content_type, charset_from_meta_content,
+    meta_encoding, prescan, sniff_encoding,
+};
+
+/// Supported character encodings.
+///
+/// Apart from `Latin1` and `Ascii`, every variant is an encoding from the WHATWG
+/// Encoding Standard. Those two are strict decoders kept for callers that want
+/// them explicitly; no label maps to them, since the standard treats
+/// `iso-8859-1`, `latin1`, `ascii` and friends as windows-1252.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EncodingLabel {
     Utf8,
     Latin1,
     Ascii,
+    Ibm866,
+    Iso8859_2,
+    Iso8859_3,
+    Iso8859_4,
+    Iso8859_5,
+    Iso8859_6,
+    Iso8859_7,
+    Iso8859_8,
+    Iso8859_8I,
+    Iso8859_10,
+    Iso8859_13,
+    Iso8859_14,
+    Iso8859_15,
+    Iso8859_16,
+    Koi8R,
+    Koi8U,
+    Macintosh,
+    Windows874,
+    Windows1250,
+    Windows1251,
     Windows1252,
+    Windows1253,
+    Windows1254,
+    Windows1255,
+    Windows1256,
+    Windows1257,
+    Windows1258,
+    XMacCyrillic,
+    Gbk,
+    Gb18030,
+    Big5,
+    EucJp,
+    Iso2022Jp,
+    ShiftJis,
+    EucKr,
+    /// Decodes any non-empty input to a single U+FFFD. Used for encodings
+    /// that are unsafe to decode (ISO-2022-KR, HZ-GB-2312, …).
+    Replacement,
     Utf16Le,
     Utf16Be,
+    XUserDefined,
 }
 
 impl EncodingLabel {
-    /// Try to parse a charset string (case-insensitive) into an `EncodingLabel`.
+    /// Resolve a charset label to an encoding using the WHATWG label table.
+    ///
+    /// Matching is ASCII case-insensitive and ignores leading and trailing
+    /// ASCII whitespace.
     pub fn from_label(label: &str) -> Option<Self> {
-        let lower: String = label.chars().map(|c| c.to_ascii_lowercase()).collect();
-        let trimmed = lower.trim();
-        match trimmed {
-            "utf-8" | "utf8" => Some(Self::Utf8),
-            "iso-8859-1" | "iso8859-1" | "latin1" | "latin-1" | "iso_8859-1" => {
-                Some(Self::Latin1)
+        let lower = label
+            .trim_matches(|c| matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' '))
+            .to_ascii_lowercase();
+        let encoding = match lower.as_str() {
+            "unicode-1-1-utf-8" | "unicode11utf8" | "unicode20utf8" | "utf-8" | "utf8"
+            | "x-unicode20utf8" => Self::Utf8,
+            "866" | "cp866" | "csibm866" | "ibm866" => Self::Ibm866,
+            "csisolatin2" | "iso-8859-2" | "iso-ir-101" | "iso8859-2" | "iso88592"
+            | "iso_8859-2" | "iso_8859-2:1987" | "l2" | "latin2" => Self::Iso8859_2,
+            "csisolatin3" | "iso-8859-3" | "iso-ir-109" | "iso8859-3" | "iso88593"
+            | "iso_8859-3" | "iso_8859-3:1988" | "l3" | "latin3" => Self::Iso8859_3,
+            "csisolatin4" | "iso-8859-4" | "iso-ir-110" | "iso8859-4" | "iso88594"
+            | "iso_8859-4" | "iso_8859-4:1988" | "l4" | "latin4" => Self::Iso8859_4,
+            "csisolatincyrillic" | "cyrillic" | "iso-8859-5" | "iso-ir-144" | "iso8859-5"
+            | "iso88595" | "iso_8859-5" | "iso_8859-5:1988" => Self::Iso8859_5,
+            "arabic" | "asmo-708" | "csiso88596e" | "csiso88596i" | "csisolatinarabic"
+            | "ecma-114" | "iso-8859-6" | "iso-8859-6-e" | "iso-8859-6-i" | "iso-ir-127"
+            | "iso8859-6" | "iso88596" | "iso_8859-6" | "iso_8859-6:1987" => Self::Iso8859_6,
+            "csisolatingreek" | "ecma-118" | "elot_928" | "greek" | "greek8" | "iso-8859-7"
+            | "iso-ir-126" | "iso8859-7" | "iso88597" | "iso_8859-7" | "iso_8859-7:1987"
+            | "sun_eu_greek" => Self::Iso8859_7,
+            "csiso88598e" | "csisolatinhebrew" | "hebrew" | "iso-8859-8" | "iso-8859-8-e"
+            | "iso-ir-138" | "iso8859-8" | "iso88598" | "iso_8859-8" | "iso_8859-8:1988"
+            | "visual" => Self::Iso8859_8,
+            "csiso88598i" | "iso-8859-8-i" | "logical" => Self::Iso8859_8I,
+            "csisolatin6" | "iso-8859-10" | "iso-ir-157" | "iso8859-10" | "iso885910" | "l6"
+            | "latin6" => Self::Iso8859_10,
+            "iso-8859-13" | "iso8859-13" | "iso885913" => Self::Iso8859_13,
+            "iso-8859-14" | "iso8859-14" | "iso885914" => Self::Iso8859_14,
+            "csisolatin9" | "iso-8859-15" | "iso8859-15" | "iso885915" | "iso_8859-15" | "l9" => {
+                Self::Iso8859_15
             }
-            "ascii" | "us-ascii" => Some(Self::Ascii),
-            "windows-1252" | "cp1252" | "x-cp1252" => Some(Self::Windows1252),
-            "utf-16le" | "utf16le" => Some(Self::Utf16Le),
-            "utf-16be" | "utf16be" => Some(Self::Utf16Be),
-            _ => None,
+            "iso-8859-16" => Self::Iso8859_16,
+            "cskoi8r" | "koi" | "koi8" | "koi8-r" | "koi8_r" => Self::Koi8R,
+            "koi8-ru" | "koi8-u" => Self::Koi8U,
+            "csmacintosh" | "mac" | "macintosh" | "x-mac-roman" => Self::Macintosh,
+            "dos-874" | "iso-8859-11" | "iso8859-11" | "iso885911" | "tis-620"
+            | "windows-874" => Self::Windows874,
+            "cp1250" | "windows-1250" | "x-cp1250" => Self::Windows1250,
+            "cp1251" | "windows-1251" | "x-cp1251" => Self::Windows1251,
+            "ansi_x3.4-1968" | "ascii" | "cp1252" | "cp819" | "csisolatin1" | "ibm819"
+            | "iso-8859-1" | "iso-ir-100" | "iso8859-1" | "iso88591" | "iso_8859-1"
+            | "iso_8859-1:1987" | "l1" | "latin1" | "us-ascii" | "windows-1252"
+            | "x-cp1252" => Self::Windows1252,
+            "cp1253" | "windows-1253" | "x-cp1253" => Self::Windows1253,
+            "cp1254" | "csisolatin5" | "iso-8859-9" | "iso-ir-148" | "iso8859-9" | "iso88599"
+            | "iso_8859-9" | "iso_8859-9:1989" | "l5" | "latin5" | "windows-1254"
+            | "x-cp1254" => Self::Windows1254,
+            "cp1255" | "windows-1255" | "x-cp1255" => Self::Windows1255,
+            "cp1256" | "windows-1256" | "x-cp1256" => Self::Windows1256,
+            "cp1257" | "windows-1257" | "x-cp1257" => Self::Windows1257,
+            "cp1258" | "windows-1258" | "x-cp1258" => Self::Windows1258,
+            "x-mac-cyrillic" | "x-mac-ukrainian" => Self::XMacCyrillic,
+            "chinese" | "csgb2312" | "csiso58gb231280" | "gb2312" | "gb_2312" | "gb_2312-80"
+            | "gbk" | "iso-ir-58" | "x-gbk" => Self::Gbk,
+            "gb18030" => Self::Gb18030,
+            "big5" | "big5-hkscs" | "cn-big5" | "csbig5" | "x-x-big5" => Self::Big5,
+            "cseucpkdfmtjapanese" | "euc-jp" | "x-euc-jp" => Self::EucJp,
+            "csiso2022jp" | "iso-2022-jp" => Self::Iso2022Jp,
+            "csshiftjis" | "ms932" | "ms_kanji" | "shift-jis" | "shift_jis" | "sjis"
+            | "windows-31j" | "x-sjis" => Self::ShiftJis,
+            "cseuckr" | "csksc56011987" | "euc-kr" | "iso-ir-149" | "korean"
+            | "ks_c_5601-1987" | "ks_c_5601-1989" | "ksc5601" | "ksc_5601" | "windows-949" => {
+                Self::EucKr
+            }
+            "csiso2022kr" | "hz-gb-2312" | "iso-2022-cn" | "iso-2022-cn-ext" | "iso-2022-kr"
+            | "replacement" => Self::Replacement,
+            "unicodefffe" | "utf-16be" => Self::Utf16Be,
+            "csunicode" | "iso-10646-ucs-2" | "ucs-2" | "unicode" | "unicodefeff" | "utf-16"
+            | "utf-16le" => Self::Utf16Le,
+            "x-user-defined" => Self::XUserDefined,
+            _ => return None,
+        };
+        Some(encoding)
+    }
+
+    /// The encoding's canonical name, as exposed by `document.characterSet`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Utf8 => "UTF-8",
+            Self::Latin1 => "ISO-8859-1",
+            Self::Ascii => "US-ASCII",
+            Self::Ibm866 => "IBM866",
+            Self::Iso8859_2 => "ISO-8859-2",
+            Self::Iso8859_3 => "ISO-8859-3",
+            Self::Iso8859_4 => "ISO-8859-4",
+            Self::Iso8859_5 => "ISO-8859-5",
+            Self::Iso8859_6 => "ISO-8859-6",
+            Self::Iso8859_7 => "ISO-8859-7",
+            Self::Iso8859_8 => "ISO-8859-8",
+            Self::Iso8859_8I => "ISO-8859-8-I",
+            Self::Iso8859_10 => "ISO-8859-10",
+            Self::Iso8859_13 => "ISO-8859-13",
+            Self::Iso8859_14 => "ISO-8859-14",
+            Self::Iso8859_15 => "ISO-8859-15",
+            Self::Iso8859_16 => "ISO-8859-16",
+            Self::Koi8R => "KOI8-R",
+            Self::Koi8U => "KOI8-U",
+            Self::Macintosh => "macintosh",
+            Self::Windows874 => "windows-874",
+            Self::Windows1250 => "windows-1250",
+            Self::Windows1251 => "windows-1251",
+            Self::Windows1252 => "windows-1252",
+            Self::Windows1253 => "windows-1253",
+            Self::Windows1254 => "windows-1254",
+            Self::Windows1255 => "windows-1255",
+            Self::Windows1256 => "windows-1256",
+            Self::Windows1257 => "windows-1257",
+            Self::Windows1258 => "windows-1258",
+            Self::XMacCyrillic => "x-mac-cyrillic",
+            Self::Gbk => "GBK",
+            Self::Gb18030 => "gb18030",
+            Self::Big5 => "Big5",
+            Self::EucJp => "EUC-JP",
+            Self::Iso2022Jp => "ISO-2022-JP",
+            Self::ShiftJis => "Shift_JIS",
+            Self::EucKr => "EUC-KR",
+            Self::Replacement => "replacement",
+            Self::Utf16Le => "UTF-16LE",
+            Self::Utf16Be => "UTF-16BE",
+            Self::XUserDefined => "x-user-defined",
         }
     }
-}
 
-/// Windows-1252 to Unicode mapping for bytes 0x80–0x9F.
-/// These 32 code points differ from ISO 8859-1 / Latin-1.
-/// Index 0 corresponds to byte 0x80, index 31 to byte 0x9F.
-const WIN1252_SPECIAL: [char; 32] = [
-    '\u{20AC}', // 0x80 — Euro sign
-    '\u{FFFD}', // 0x81 — undefined → replacement
-    '\u{201A}', // 0x82 — single low-9 quotation mark
-    '\u{0192}', // 0x83 — Latin small letter f with hook
-    '\u{201E}', // 0x84 — double low-9 quotation mark
-    '\u{2026}', // 0x85 — horizontal ellipsis
-    '\u{2020}', // 0x86 — dagger
-    '\u{2021}', // 0x87 — double dagger
-    '\u{02C6}', // 0x88 — modifier letter circumflex accent
-    '\u{2030}', // 0x89 — per mille sign
-    '\u{0160}', // 0x8A — Latin capital letter S with caron
-    '\u{2039}', // 0x8B — single left-pointing angle quotation mark
-    '\u{0152}', // 0x8C — Latin capital ligature OE
-    '\u{FFFD}', // 0x8D — undefined → replacement
-    '\u{017D}', // 0x8E — Latin capital letter Z with caron
-    '\u{FFFD}', // 0x8F — undefined → replacement
-    '\u{FFFD}', // 0x90 — undefined → replacement
-    '\u{2018}', // 0x91 — left single quotation mark
-    '\u{2019}', // 0x92 — right single quotation mark
-    '\u{201C}', // 0x93 — left double quotation mark
-    '\u{201D}', // 0x94 — right double quotation mark
-    '\u{2022}', // 0x95 — bullet
-    '\u{2013}', // 0x96 — en dash
-    '\u{2014}', // 0x97 — em dash
-    '\u{02DC}', // 0x98 — small tilde
-    '\u{2122}', // 0x99 — trade mark sign
-    '\u{0161}', // 0x9A — Latin small letter s with caron
-    '\u{203A}', // 0x9B — single right-pointing angle quotation mark
-    '\u{0153}', // 0x9C — Latin small ligature oe
-    '\u{FFFD}', // 0x9D — undefined → replacement
-    '\u{017E}', // 0x9E — Latin small letter z with caron
-    '\u{0178}', // 0x9F — Latin capital letter Y with diaeresis
-];
+    /// The encoding used when encoding text for this document encoding
+    /// ("get an output encoding"): UTF-16 and replacement fall back to UTF-8.
+    pub fn output_encoding(self) -> Self {
+        match self {
+            Self::Replacement | Self::Utf16Le | Self::Utf16Be => Self::Utf8,
+            other => other,
+        }
+    }
+}
 
 /// Detect encoding from raw bytes and an optional `<meta charset="...">` hint.
 ///
 /// Priority:
 /// 1. BOM (Byte Order Mark) in the data
 /// 2. `meta_charset` hint (e.g. from HTML `<meta>` tag)
-/// 3. Heuristic: try UTF-8 validation, fall back to Windows-1252
+/// 3. Heuristic: valid UTF-8 is UTF-8, anything else goes to the statistical
+///    detector (see [`detect_statistically`])
 pub fn detect_encoding(bytes: &[u8], meta_charset: Option<&str>) -> EncodingLabel {
     // 1) Check BOM
     if bytes.len() >= 3 && bytes[0] == 0xEF && bytes[1] == 0xBB && bytes[2] == 0xBF {
@@ -106,14 +254,18 @@ pub fn detect_encoding(bytes: &[u8], meta_charset: Option<&str>) -> EncodingLabe
         return EncodingLabel::Utf8;
     }
 
-    // 4) Fall back to Windows-1252 (superset of Latin-1, most common legacy encoding)
-    EncodingLabel::Windows1252
+    // 4) Guess from byte statistics; Windows-1252 when nothing stands out
+    detect::detect_statistically(bytes)
 }
 
 /// Decode bytes to a UTF-8 `String` using the specified encoding.
 ///
-/// BOM bytes at the start are stripped when applicable.
+/// BOM bytes at the start are stripped when applicable. Malformed input is
+/// replaced with U+FFFD.
 pub fn decode_to_utf8(bytes: &[u8], encoding: EncodingLabel) -> String {
+    if let Some(index) = single_byte::index(encoding) {
+        return single_byte::decode(bytes, index);
+    }
     match encoding {
         EncodingLabel::Utf8 => {
             // Strip UTF-8 BOM if present
@@ -147,7 +299,19 @@ pub fn decode_to_utf8(bytes: &[u8], encoding: EncodingLabel) -> String {
             }
             s
         }
-        EncodingLabel::Windows1252 => decode_windows1252(bytes),
+        EncodingLabel::Gbk | EncodingLabel::Gb18030 => chinese::decode_gb18030(bytes),
+        EncodingLabel::Big5 => chinese::decode_big5(bytes),
+        EncodingLabel::EucJp => japanese::decode_euc_jp(bytes),
+        EncodingLabel::Iso2022Jp => japanese::decode_iso_2022_jp(bytes),
+        EncodingLabel::ShiftJis => japanese::decode_shift_jis(bytes),
+        EncodingLabel::EucKr => korean::decode_euc_kr(bytes),
+        EncodingLabel::Replacement => {
+            if bytes.is_empty() {
+                String::new()
+            } else {
+                "\u{FFFD}".to_string()
+            }
+        }
         EncodingLabel::Utf16Le => {
             // Strip BOM if present
             let data = if bytes.len() >= 2 && bytes[0] == 0xFF && bytes[1] == 0xFE {
@@ -165,23 +329,246 @@ pub fn decode_to_utf8(bytes: &[u8], encoding: EncodingLabel) -> String {
             };
             decode_utf16(data, true)
         }
+        EncodingLabel::XUserDefined => bytes
+            .iter()
+            .map(|&b| match b {
+                0x00..=0x7F => b as char,
+                _ => char::from_u32(0xF780 + b as u32 - 0x80).unwrap_or('\u{FFFD}'),
+            })
+            .collect(),
+        // Single-byte encodings were handled above.
+        _ => decode_utf8_lossy(bytes),
     }
 }
 
-/// Decode Windows-1252 bytes to UTF-8 string.
-fn decode_windows1252(bytes: &[u8]) -> String {
-    let mut s = String::with_capacity(bytes.len());
-    for &b in bytes {
-        if b < 0x80 {
-            s.push(b as char);
-        } else if b >= 0x80 && b <= 0x9F {
-            s.push(WIN1252_SPECIAL[(b - 0x80) as usize]);
+/// Incremental decoder for a document that arrives in pieces.
+///
+/// Each call to [`decode`](Self::decode) returns the text up to the last
+/// point where the input can be split without cutting a character in two;
+/// the remaining bytes are kept for the next call. Stateful and non-ASCII
+/// compatible encodings (ISO-2022-JP, UTF-16, replacement) are buffered
+/// until [`finish`](Self::finish).
+#[derive(Debug, Clone)]
+pub struct StreamDecoder {
+    encoding: EncodingLabel,
+    pending: Vec<u8>,
+    started: bool,
+}
+
+impl StreamDecoder {
+    pub fn new(encoding: EncodingLabel) -> Self {
+        Self {
+            encoding,
+            pending: Vec::new(),
+            started: false,
+        }
+    }
+
+    pub fn encoding(&self) -> EncodingLabel {
+        self.encoding
+    }
+
+    /// Append `bytes` and decode as much of the input as is safe.
+    pub fn decode(&mut self, bytes: &[u8]) -> String {
+        self.pending.extend_from_slice(bytes);
+        let split = match self.split_point() {
+            Some(split) if split > 0 => split,
+            _ => return String::new(),
+        };
+        let rest = self.pending.split_off(split);
+        let head = std::mem::replace(&mut self.pending, rest);
+        self.decode_piece(&head)
+    }
+
+    /// Decode whatever is left at the end of the input.
+    pub fn finish(&mut self) -> String {
+        let rest = std::mem::take(&mut self.pending);
+        self.decode_piece(&rest)
+    }
+
+    /// Length of the prefix of `pending` that ends on a character boundary.
+    fn split_point(&self) -> Option<usize> {
+        if single_byte::index(self.encoding).is_some()
+            || matches!(
+                self.encoding,
+                EncodingLabel::Latin1 | EncodingLabel::Ascii | EncodingLabel::XUserDefined
+            )
+        {
+            return Some(self.pending.len());
+        }
+        match self.encoding {
+            // ASCII bytes never occur inside a multi-byte UTF-8 sequence.
+            EncodingLabel::Utf8 => self.pending.iter().rposition(|&b| b < 0x80).map(|i| i + 1),
+            // Trail bytes of these encodings can be ASCII letters or digits,
+            // but never a newline or angle bracket.
+            EncodingLabel::Gbk
+            | EncodingLabel::Gb18030
+            | EncodingLabel::Big5
+            | EncodingLabel::EucJp
+            | EncodingLabel::ShiftJis
+            | EncodingLabel::EucKr => self
+                .pending
+                .iter()
+                .rposition(|&b| matches!(b, b'\n' | b'<' | b'>'))
+                .map(|i| i + 1),
+            _ => None,
+        }
+    }
+
+    fn decode_piece(&mut self, bytes: &[u8]) -> String {
+        if bytes.is_empty() {
+            return String::new();
+        }
+        // Only the start of the stream may carry a byte order mark.
+        let text = if self.started && self.encoding == EncodingLabel::Utf8 {
+            decode_utf8_lossy(bytes)
         } else {
-            // 0xA0–0xFF: same as Latin-1 (U+00A0–U+00FF)
-            s.push(b as char);
+            decode_to_utf8(bytes, self.encoding)
+        };
+        self.started = true;
+        text
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Encoding
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Receives encoder output. Encoders report code points the encoding cannot
+/// represent through `unmappable` rather than failing, so each caller picks
+/// its own error representation.
+pub(crate) trait EncodeSink {
+    fn push_byte(&mut self, byte: u8);
+
+    fn push_bytes(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.push_byte(b);
         }
     }
-    s
+
+    fn unmappable(&mut self, c: char);
+}
+
+/// Collects bytes, writing unmappable code points as HTML numeric character
+/// references (the Encoding Standard's "html" error mode).
+#[derive(Default)]
+pub(crate) struct NcrSink {
+    pub(crate) bytes: Vec<u8>,
+}
+
+impl EncodeSink for NcrSink {
+    fn push_byte(&mut self, byte: u8) {
+        self.bytes.push(byte);
+    }
+
+    fn unmappable(&mut self, c: char) {
+        self.bytes.extend_from_slice(format!("&#{};", c as u32).as_bytes());
+    }
+}
+
+/// Percent-encodes bytes as they are produced ("percent-encode after
+/// encoding"). Unmappable code points become a percent-encoded `&#N;`.
+struct PercentSink {
+    out: String,
+    /// Returns true for ASCII bytes that must be percent-encoded.
+    in_set: fn(u8) -> bool,
+    space_as_plus: bool,
+}
+
+impl EncodeSink for PercentSink {
+    fn push_byte(&mut self, byte: u8) {
+        if self.space_as_plus && byte == b' ' {
+            self.out.push('+');
+        } else if byte >= 0x80 || (self.in_set)(byte) {
+            self.out.push_str(&format!("%{byte:02X}"));
+        } else {
+            self.out.push(byte as char);
+        }
+    }
+
+    fn unmappable(&mut self, c: char) {
+        self.out.push_str(&format!("%26%23{}%3B", c as u32));
+    }
+}
+
+/// Run the encoder for `encoding` over `text`.
+fn encode_into(text: &str, encoding: EncodingLabel, sink: &mut dyn EncodeSink) {
+    if let Some(index) = single_byte::index(encoding) {
+        single_byte::encode(text, index, sink);
+        return;
+    }
+    match encoding.output_encoding() {
+        EncodingLabel::Gbk => chinese::encode_gb18030(text, true, sink),
+        EncodingLabel::Gb18030 => chinese::encode_gb18030(text, false, sink),
+        EncodingLabel::Big5 => chinese::encode_big5(text, sink),
+        EncodingLabel::EucJp => japanese::encode_euc_jp(text, sink),
+        EncodingLabel::Iso2022Jp => japanese::encode_iso_2022_jp(text, sink),
+        EncodingLabel::ShiftJis => japanese::encode_shift_jis(text, sink),
+        EncodingLabel::EucKr => korean::encode_euc_kr(text, sink),
+        EncodingLabel::Latin1 | EncodingLabel::Ascii | EncodingLabel::XUserDefined => {
+            for c in text.chars() {
+                let cp = c as u32;
+                match encoding {
+                    _ if c.is_ascii() => sink.push_byte(cp as u8),
+                    EncodingLabel::Latin1 if cp <= 0xFF => sink.push_byte(cp as u8),
+                    EncodingLabel::XUserDefined if (0xF780..=0xF7FF).contains(&cp) => {
+                        sink.push_byte((cp - 0xF780 + 0x80) as u8)
+                    }
+                    _ => sink.unmappable(c),
+                }
+            }
+        }
+        _ => sink.push_bytes(text.as_bytes()),
+    }
+}
+
+/// Encode `text` in `encoding`'s output encoding, writing characters it cannot
+/// represent as HTML numeric character references (`&#N;`), as form
+/// submission does.
+pub fn encode_from_utf8(text: &str, encoding: EncodingLabel) -> Vec<u8> {
+    let mut sink = NcrSink::default();
+    encode_into(text, encoding, &mut sink);
+    sink.bytes
+}
+
+/// Encode a URL query string in the document's encoding and percent-encode it
+/// with the query percent-encode set (plus `'` for special schemes).
+pub fn encode_query(text: &str, encoding: EncodingLabel, special_scheme: bool) -> String {
+    fn query_set(b: u8) -> bool {
+        b < 0x21 || b == 0x7F || matches!(b, b'"' | b'#' | b'<' | b'>')
+    }
+    fn special_query_set(b: u8) -> bool {
+        query_set(b) || b == b'\''
+    }
+    let mut sink = PercentSink {
+        out: String::with_capacity(text.len()),
+        in_set: if special_scheme { special_query_set } else { query_set },
+        space_as_plus: false,
+    };
+    encode_into(text, encoding, &mut sink);
+    sink.out
+}
+
+/// Serialize name/value pairs as `application/x-www-form-urlencoded` in the
+/// given encoding.
+pub fn serialize_form_urlencoded(pairs: &[(&str, &str)], encoding: EncodingLabel) -> String {
+    fn form_set(b: u8) -> bool {
+        !(b.is_ascii_alphanumeric() || matches!(b, b'*' | b'-' | b'.' | b'_'))
+    }
+    let mut sink = PercentSink {
+        out: String::new(),
+        in_set: form_set,
+        space_as_plus: true,
+    };
+    for (i, (name, value)) in pairs.iter().enumerate() {
+        if i > 0 {
+            sink.out.push('&');
+        }
+        encode_into(name, encoding, &mut sink);
+        sink.out.push('=');
+        encode_into(value, encoding, &mut sink);
+    }
+    sink.out
 }
 
 /// Decode UTF-16 bytes (LE or BE) to a UTF-8 string with surrogate pair handling.
@@ -318,17 +705,51 @@ mod tests {
             EncodingLabel::from_label("windows-1252"),
             Some(EncodingLabel::Windows1252)
         );
+        // The WHATWG label table maps Latin-1 and ASCII labels to windows-1252.
         assert_eq!(
             EncodingLabel::from_label("ISO-8859-1"),
-            Some(EncodingLabel::Latin1)
+            Some(EncodingLabel::Windows1252)
         );
         assert_eq!(
             EncodingLabel::from_label("us-ascii"),
-            Some(EncodingLabel::Ascii)
+            Some(EncodingLabel::Windows1252)
         );
         assert_eq!(EncodingLabel::from_label("bogus"), None);
     }
 
+    #[test]
+    fn test_encoding_label_whatwg_table() {
+        let cases = [
+            (" Shift_JIS\n", EncodingLabel::ShiftJis),
+            ("x-sjis", EncodingLabel::ShiftJis),
+            ("gb2312", EncodingLabel::Gbk),
+            ("GB18030", EncodingLabel::Gb18030),
+            ("big5-hkscs", EncodingLabel::Big5),
+            ("ks_c_5601-1987", EncodingLabel::EucKr),
+            ("csiso2022jp", EncodingLabel::Iso2022Jp),
+            ("koi8-ru", EncodingLabel::Koi8U),
+            ("latin2", EncodingLabel::Iso8859_2),
+            ("iso-8859-9", EncodingLabel::Windows1254),
+            ("tis-620", EncodingLabel::Windows874),
+            ("iso-2022-kr", EncodingLabel::Replacement),
+            ("utf-16", EncodingLabel::Utf16Le),
+        ];
+        for (label, expected) in cases {
+            assert_eq!(EncodingLabel::from_label(label), Some(expected), "{label}");
+        }
+        // Only ASCII whitespace is stripped, and labels are not prefix-matched.
+        assert_eq!(EncodingLabel::from_label("\u{A0}utf-8"), None);
+        assert_eq!(EncodingLabel::from_label("utf-8x"), None);
+    }
+
+    #[test]
+    fn test_encoding_names() {
+        assert_eq!(EncodingLabel::ShiftJis.name(), "Shift_JIS");
+        assert_eq!(EncodingLabel::from_label("latin1").unwrap().name(), "windows-1252");
+        assert_eq!(EncodingLabel::Utf16Be.output_encoding(), EncodingLabel::Utf8);
+        assert_eq!(EncodingLabel::EucKr.output_encoding(), EncodingLabel::EucKr);
+    }
+
     #[test]
     fn test_detect_utf8_bom() {
         let data = b"\xEF\xBB\xBFHello";
@@ -430,6 +851,71 @@ mod tests {
         assert_eq!(result, "TM\u{2122}");
     }
 
+    #[test]
+    fn test_decode_windows1252_c1_controls() {
+        // Bytes undefined in cp1252 map to the C1 controls, not U+FFFD.
+        let result = decode_to_utf8(&[0x81, 0x8D], EncodingLabel::Windows1252);
+        assert_eq!(result, "\u{81}\u{8D}");
+    }
+
+    #[test]
+    fn test_decode_single_byte_legacy() {
+        // "Привет" in windows-1251 and KOI8-R.
+        let cp1251 = [0xCF, 0xF0, 0xE8, 0xE2, 0xE5, 0xF2];
+        assert_eq!(decode_to_utf8(&cp1251, EncodingLabel::Windows1251), "Привет");
+        let koi8 = [0xF0, 0xD2, 0xC9, 0xD7, 0xC5, 0xD4];
+        assert_eq!(decode_to_utf8(&koi8, EncodingLabel::Koi8R), "Привет");
+        // ISO-8859-2 "ł", and an unmapped ISO-8859-3 byte.
+        assert_eq!(decode_to_utf8(&[0xB3], EncodingLabel::Iso8859_2), "ł");
+        assert_eq!(decode_to_utf8(&[0xA5], EncodingLabel::Iso8859_3), "\u{FFFD}");
+    }
+
+    #[test]
+    fn test_decode_replacement_and_user_defined() {
+        assert_eq!(decode_to_utf8(b"", EncodingLabel::Replacement), "");
+        assert_eq!(decode_to_utf8(b"abc", EncodingLabel::Replacement), "\u{FFFD}");
+        let result = decode_to_utf8(&[0x41, 0x80, 0xFF], EncodingLabel::XUserDefined);
+        assert_eq!(result, "A\u{F780}\u{F7FF}");
+    }
+
+    #[test]
+    fn test_decode_cjk_dispatch() {
+        assert_eq!(decode_to_utf8(&[0x93, 0xFA, 0x96, 0x7B], EncodingLabel::ShiftJis), "日本");
+        assert_eq!(decode_to_utf8(&[0xC7, 0xD1], EncodingLabel::EucKr), "한");
+        assert_eq!(decode_to_utf8(&[0xD6, 0xD0], EncodingLabel::Gbk), "中");
+        assert_eq!(decode_to_utf8(&[0xA4, 0xA4], EncodingLabel::Big5), "中");
+    }
+
+    #[test]
+    fn test_encode_from_utf8() {
+        let cp1251 = encode_from_utf8("Привет", EncodingLabel::Windows1251);
+        assert_eq!(cp1251, [0xCF, 0xF0, 0xE8, 0xE2, 0xE5, 0xF2]);
+        // Unmappable characters become numeric character references.
+        assert_eq!(encode_from_utf8("a€☃", EncodingLabel::Windows1252), b"a\x80&#9731;");
+        // UTF-16 documents submit as UTF-8.
+        assert_eq!(encode_from_utf8("é", EncodingLabel::Utf16Le), "é".as_bytes());
+        assert_eq!(encode_from_utf8("日本", EncodingLabel::ShiftJis), [0x93, 0xFA, 0x96, 0x7B]);
+    }
+
+    #[test]
+    fn test_encode_query() {
+        let query = encode_query("q=日本 語", EncodingLabel::EucJp, true);
+        assert_eq!(query, "q=%C6%FC%CB%DC%20%B8%EC");
+        assert_eq!(encode_query("a'b", EncodingLabel::Utf8, true), "a%27b");
+        assert_eq!(encode_query("a'b", EncodingLabel::Utf8, false), "a'b");
+        assert_eq!(encode_query("☃", EncodingLabel::Windows1252, true), "%26%239731%3B");
+    }
+
+    #[test]
+    fn test_serialize_form_urlencoded() {
+        let pairs = [("name", "Jöhn Doe"), ("q", "a&b=c~")];
+        assert_eq!(
+            serialize_form_urlencoded(&pairs, EncodingLabel::Windows1252),
+            "name=J%F6hn+Doe&q=a%26b%3Dc%7E"
+        );
+        assert_eq!(serialize_form_urlencoded(&[("k", "한")], EncodingLabel::EucKr), "k=%C7%D1");
+    }
+
     #[test]
     fn test_decode_utf16le() {
         // "Hi" in UTF-16 LE: 48 00 69 00
@@ -480,4 +966,32 @@ mod tests {
         let decoded = decode_to_utf8(bytes, encoding);
         assert_eq!(decoded, original);
     }
+
+    #[test]
+    fn test_stream_decoder_splits_on_character_boundaries() {
+        let text = "<p>h\u{e9}llo \u{1F600}</p>\n<p>\u{65e5}\u{672c}</p>";
+        let bytes = text.as_bytes();
+        let mut decoder = StreamDecoder::new(EncodingLabel::Utf8);
+        let mut out = String::new();
+        for piece in bytes.chunks(3) {
+            out.push_str(&decoder.decode(piece));
+        }
+        out.push_str(&decoder.finish());
+        assert_eq!(out, text);
+
+        // Shift_JIS trail bytes may be ASCII letters; only split at markup.
+        let sjis = encode_from_utf8("<p>\u{30bd}\u{8868}</p>", EncodingLabel::ShiftJis);
+        let mut decoder = StreamDecoder::new(EncodingLabel::ShiftJis);
+        let mut out = String::new();
+        for piece in sjis.chunks(1) {
+            out.push_str(&decoder.decode(piece));
+        }
+        out.push_str(&decoder.finish());
+        assert_eq!(out, "<p>\u{30bd}\u{8868}</p>");
+
+        // A BOM is stripped only at the start of the stream.
+        let mut decoder = StreamDecoder::new(EncodingLabel::Utf8);
+        assert_eq!(decoder.decode(b"\xEF\xBB\xBFa"), "a");
+        assert_eq!(decoder.decode(b"\xEF\xBB\xBFb"), "\u{FEFF}b");
+    }
 }
@@ -0,0 +1,422 @@
+//! Encoding sniffing for HTML documents (HTML Standard §13.2.3).
+//!
+//! The full algorithm, in priority order:
+//! 1. A byte order mark.
+//! 2. The `charset` parameter of the HTTP `Content-Type` header.
+//! 3. A prescan of the first 1024 bytes for `<meta charset>` or
+//!    `<meta http-equiv="content-type" content="…; charset=…">`.
+//! 4. Statistical detection over the document's bytes (see [`crate::detect_statistically`]).
+//!
+//! Steps 1–2 are certain; 3–4 are tentative, so a `<meta>` found later by the
+//! parser may still change the encoding (see [`meta_encoding`]).
+
+use crate::EncodingLabel;
+use crate::detect;
+
+/// How sure the sniffer is about a document's encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Confidence {
+    /// Guessed from the content; a later `<meta>` may change it.
+    Tentative,
+    /// From a BOM or the transport layer; never changes.
+    Certain,
+}
+
+/// Number of bytes examined by the `<meta>` prescan.
+pub const PRESCAN_LEN: usize = 1024;
+
+/// Determine the encoding of an HTML document.
+///
+/// `content_type` is the HTTP `Content-Type` header value, if any.
+pub fn sniff_encoding(bytes: &[u8], content_type: Option<&str>) -> (EncodingLabel, Confidence) {
+    if let Some(encoding) = bom_sniff(bytes) {
+        return (encoding, Confidence::Certain);
+    }
+    if let Some(encoding) = content_type.and_then(charset_from_content_type) {
+        return (encoding, Confidence::Certain);
+    }
+    if let Some(encoding) = prescan(bytes) {
+        return (encoding, Confidence::Tentative);
+    }
+    (detect::detect_statistically(bytes), Confidence::Tentative)
+}
+
+/// The encoding indicated by a byte order mark at the start of `bytes`.
+pub fn bom_sniff(bytes: &[u8]) -> Option<EncodingLabel> {
+    match bytes {
+        [0xEF, 0xBB, 0xBF, ..] => Some(EncodingLabel::Utf8),
+        [0xFE, 0xFF, ..] => Some(EncodingLabel::Utf16Be),
+        [0xFF, 0xFE, ..] => Some(EncodingLabel::Utf16Le),
+        _ => None,
+    }
+}
+
+/// The encoding named by the `charset` parameter of a `Content-Type` value,
+/// e.g. `text/html; charset="Shift_JIS"`.
+pub fn charset_from_content_type(value: &str) -> Option<EncodingLabel> {
+    value.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        if !name.trim().eq_ignore_ascii_case("charset") {
+            return None;
+        }
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|v| v.split('"').next())
+            .unwrap_or(value);
+        EncodingLabel::from_label(value)
+    })
+}
+
+/// The encoding a `<meta>` element declares, as the parser sees it.
+///
+/// A valid `charset` wins; otherwise the `content` of a
+/// `http-equiv="content-type"` element is searched for a charset. UTF-16
+/// declarations mean UTF-8 (the bytes being parsed as ASCII-compatible proves
+/// they are not UTF-16) and x-user-defined means windows-1252.
+pub fn meta_encoding(
+    charset: Option<&str>,
+    http_equiv: Option<&str>,
+    content: Option<&str>,
+) -> Option<EncodingLabel> {
+    let from_pragma = || match (http_equiv, content) {
+        (Some(equiv), Some(content)) if equiv.eq_ignore_ascii_case("content-type") => {
+            charset_from_meta_content(content)
+        }
+        _ => None,
+    };
+    let encoding = charset
+        .and_then(EncodingLabel::from_label)
+        .or_else(from_pragma)?;
+    Some(for_ascii_compatible_document(encoding))
+}
+
+/// Map encodings that cannot describe an ASCII-compatible document.
+fn for_ascii_compatible_document(encoding: EncodingLabel) -> EncodingLabel {
+    match encoding {
+        EncodingLabel::Utf16Le | EncodingLabel::Utf16Be => EncodingLabel::Utf8,
+        EncodingLabel::XUserDefined => EncodingLabel::Windows1252,
+        other => other,
+    }
+}
+
+/// "Extract a character encoding from a meta element": find `charset=` in a
+/// `content` attribute value.
+pub fn charset_from_meta_content(content: &str) -> Option<EncodingLabel> {
+    let bytes = content.as_bytes();
+    let mut pos = 0;
+    loop {
+        let rest = &bytes[pos..];
+        let found = rest
+            .windows(7)
+            .position(|w| w.eq_ignore_ascii_case(b"charset"))?;
+        pos += found + 7;
+        while pos < bytes.len() && is_space(bytes[pos]) {
+            pos += 1;
+        }
+        if bytes.get(pos) != Some(&b'=') {
+            continue;
+        }
+        pos += 1;
+        while pos < bytes.len() && is_space(bytes[pos]) {
+            pos += 1;
+        }
+        let value = match bytes.get(pos)? {
+            &quote @ (b'"' | b'\'') => {
+                let len = bytes[pos + 1..].iter().position(|&b| b == quote)?;
+                &bytes[pos + 1..pos + 1 + len]
+            }
+            _ => {
+                let len = bytes[pos..]
+                    .iter()
+                    .position(|&b| is_space(b) || b == b';')
+                    .unwrap_or(bytes.len() - pos);
+                &bytes[pos..pos + len]
+            }
+        };
+        return EncodingLabel::from_label(std::str::from_utf8(value).ok()?);
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Prescan
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn is_space(b: u8) -> bool {
+    matches!(b, b'\t' | b'\n' | 0x0C | b'\r' | b' ')
+}
+
+/// "Prescan a byte stream to determine its encoding" over the first
+/// [`PRESCAN_LEN`] bytes.
+pub fn prescan(bytes: &[u8]) -> Option<EncodingLabel> {
+    let input = &bytes[..bytes.len().min(PRESCAN_LEN)];
+    let mut pos = 0;
+
+    while pos < input.len() {
+        let rest = &input[pos..];
+        if rest.starts_with(b"<!--") {
+            // Skip to the end of the comment; "<!-->" closes immediately.
+            let end = input[pos + 2..]
+                .windows(3)
+                .position(|w| w == b"-->")
+                .map(|i| pos + 2 + i + 3);
+            pos = end?;
+            continue;
+        }
+        if rest.len() > 5
+            && rest[..5].eq_ignore_ascii_case(b"<meta")
+            && (is_space(rest[5]) || rest[5] == b'/')
+        {
+            pos += 5;
+            if let Some(encoding) = prescan_meta(input, &mut pos)? {
+                return Some(encoding);
+            }
+            continue;
+        }
+        let tag_start = match rest {
+            [b'<', b, ..] if b.is_ascii_alphabetic() => Some(1),
+            [b'<', b'/', b, ..] if b.is_ascii_alphabetic() => Some(2),
+            _ => None,
+        };
+        if let Some(offset) = tag_start {
+            pos += offset;
+            while pos < input.len() && !is_space(input[pos]) && input[pos] != b'>' {
+                pos += 1;
+            }
+            while get_attribute(input, &mut pos)?.is_some() {}
+            continue;
+        }
+        if rest.starts_with(b"<!") || rest.starts_with(b"</") || rest.starts_with(b"<?") {
+            pos += input[pos..].iter().position(|&b| b == b'>')? + 1;
+            continue;
+        }
+        pos += 1;
+    }
+    None
+}
+
+/// Process the attributes of a `<meta` tag whose name has been consumed.
+///
+/// Returns `None` when input runs out, `Some(None)` if the element declares
+/// no usable encoding.
+fn prescan_meta(input: &[u8], pos: &mut usize) -> Option<Option<EncodingLabel>> {
+    let mut seen: Vec<Vec<u8>> = Vec::new();
+    let mut got_pragma = false;
+    let mut need_pragma: Option<bool> = None;
+    let mut charset: Option<EncodingLabel> = None;
+
+    while let Some((name, value)) = get_attribute(input, pos)? {
+        if seen.contains(&name) {
+            continue;
+        }
+        match name.as_slice() {
+            b"http-equiv" => got_pragma |= value.eq_ignore_ascii_case(b"content-type"),
+            b"content" if charset.is_none() => {
+                if let Some(found) = std::str::from_utf8(&value)
+                    .ok()
+                    .and_then(charset_from_meta_content)
+                {
+                    charset = Some(found);
+                    need_pragma = Some(true);
+                }
+            }
+            b"charset" => {
+                charset = std::str::from_utf8(&value)
+                    .ok()
+                    .and_then(EncodingLabel::from_label);
+                need_pragma = Some(false);
+            }
+            _ => {}
+        }
+        seen.push(name);
+    }
+
+    let result = match need_pragma {
+        None => None,
+        Some(true) if !got_pragma => None,
+        _ => charset.map(for_ascii_compatible_document),
+    };
+    Some(result)
+}
+
+/// A lowercased attribute (name, value) pair read by the prescan.
+type Attribute = (Vec<u8>, Vec<u8>);
+
+/// The prescan's "get an attribute": returns `None` when input runs out and
+/// `Some(None)` at the end of the tag.
+fn get_attribute(input: &[u8], pos: &mut usize) -> Option<Option<Attribute>> {
+    let byte = |p: usize| input.get(p).copied();
+
+    while is_space(byte(*pos)?) || byte(*pos)? == b'/' {
+        *pos += 1;
+    }
+    if byte(*pos)? == b'>' {
+        return Some(None);
+    }
+
+    let mut name = Vec::new();
+    let mut value = Vec::new();
+
+    // Attribute name.
+    loop {
+        let b = byte(*pos)?;
+        if b == b'=' && !name.is_empty() {
+            *pos += 1;
+            break;
+        }
+        if is_space(b) {
+            while is_space(byte(*pos)?) {
+                *pos += 1;
+            }
+            if byte(*pos)? != b'=' {
+                return Some(Some((name, value)));
+            }
+            *pos += 1;
+            break;
+        }
+        if b == b'/' || b == b'>' {
+            return Some(Some((name, value)));
+        }
+        name.push(b.to_ascii_lowercase());
+        *pos += 1;
+    }
+
+    // Attribute value.
+    while is_space(byte(*pos)?) {
+        *pos += 1;
+    }
+    let b = byte(*pos)?;
+    if b == b'"' || b == b'\'' {
+        *pos += 1;
+        loop {
+            let c = byte(*pos)?;
+            *pos += 1;
+            if c == b {
+                return Some(Some((name, value)));
+            }
+            value.push(c.to_ascii_lowercase());
+        }
+    }
+    if b == b'>' {
+        return Some(Some((name, value)));
+    }
+    value.push(b.to_ascii_lowercase());
+    *pos += 1;
+    loop {
+        let c = byte(*pos)?;
+        if is_space(c) || c == b'>' {
+            return Some(Some((name, value)));
+        }
+        value.push(c.to_ascii_lowercase());
+        *pos += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prescan_meta_charset() {
+        let html = b"<!DOCTYPE html><html><head><meta charset=\"Shift_JIS\"><title>x</title>";
+        assert_eq!(prescan(html), Some(EncodingLabel::ShiftJis));
+        assert_eq!(
+            prescan(b"<META CHARSET=euc-kr>"),
+            Some(EncodingLabel::EucKr)
+        );
+    }
+
+    #[test]
+    fn test_prescan_http_equiv() {
+        let html = b"<meta content='text/html; charset=koi8-r' http-equiv='Content-Type'>";
+        assert_eq!(prescan(html), Some(EncodingLabel::Koi8R));
+        // A charset in `content` without the pragma is ignored.
+        assert_eq!(prescan(b"<meta content='text/html; charset=koi8-r'>"), None);
+    }
+
+    #[test]
+    fn test_prescan_skips_comments_and_attributes() {
+        let html = b"<!-- <meta charset=big5> --><div title='<meta charset=gbk>'>\
+                     <meta charset=windows-1251>";
+        assert_eq!(prescan(html), Some(EncodingLabel::Windows1251));
+        assert_eq!(
+            prescan(b"<!--><meta charset=gbk>"),
+            Some(EncodingLabel::Gbk)
+        );
+    }
+
+    #[test]
+    fn test_prescan_limits() {
+        // Only the first 1024 bytes are examined.
+        let mut html = vec![b' '; PRESCAN_LEN];
+        html.extend_from_slice(b"<meta charset=gbk>");
+        assert_eq!(prescan(&html), None);
+        // UTF-16 declarations in an ASCII-compatible document mean UTF-8.
+        assert_eq!(
+            prescan(b"<meta charset=utf-16le>"),
+            Some(EncodingLabel::Utf8)
+        );
+        assert_eq!(
+            prescan(b"<meta charset=x-user-defined>"),
+            Some(EncodingLabel::Windows1252)
+        );
+    }
+
+    #[test]
+    fn test_charset_from_content_type() {
+        let ct = "text/html; charset=\"Shift_JIS\"";
+        assert_eq!(charset_from_content_type(ct), Some(EncodingLabel::ShiftJis));
+        let ct = "text/html;Charset=ISO-8859-2 ; foo=bar";
+        assert_eq!(
+            charset_from_content_type(ct),
+            Some(EncodingLabel::Iso8859_2)
+        );
+        assert_eq!(charset_from_content_type("text/html"), None);
+    }
+
+    #[test]
+    fn test_charset_from_meta_content() {
+        let found = charset_from_meta_content("text/html; CHARSET = 'euc-jp'");
+        assert_eq!(found, Some(EncodingLabel::EucJp));
+        let found = charset_from_meta_content("charsetx; charset=gbk;");
+        assert_eq!(found, Some(EncodingLabel::Gbk));
+        assert_eq!(charset_from_meta_content("charset=\"gbk"), None);
+    }
+
+    #[test]
+    fn test_sniff_priority() {
+        let html = b"<meta charset=gbk>abc";
+        assert_eq!(
+            sniff_encoding(html, Some("text/html; charset=big5")),
+            (EncodingLabel::Big5, Confidence::Certain)
+        );
+        assert_eq!(
+            sniff_encoding(html, None),
+            (EncodingLabel::Gbk, Confidence::Tentative)
+        );
+        let mut bom = vec![0xEF, 0xBB, 0xBF];
+        bom.extend_from_slice(html);
+        assert_eq!(
+            sniff_encoding(&bom, Some("text/html; charset=big5")),
+            (EncodingLabel::Utf8, Confidence::Certain)
+        );
+    }
+
+    #[test]
+    fn test_meta_encoding() {
+        assert_eq!(
+            meta_encoding(Some("gbk"), None, None),
+            Some(EncodingLabel::Gbk)
+        );
+        let found = meta_encoding(None, Some("Content-Type"), Some("text/html; charset=big5"));
+        assert_eq!(found, Some(EncodingLabel::Big5));
+        assert_eq!(
+            meta_encoding(None, Some("refresh"), Some("charset=big5")),
+            None
+        );
+        assert_eq!(
+            meta_encoding(Some("utf-16"), None, None),
+            Some(EncodingLabel::Utf8)
+        );
+    }
+}
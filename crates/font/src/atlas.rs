@@ -186,17 +186,27 @@ pub struct AtlasEntry {
 // GlyphKey
 // ─────────────────────────────────────────────────────────────────────────────
 
-/// Key for glyph cache lookup: (glyph_id, size in 1/64th pixels).
+/// Key for glyph cache lookup: (face, glyph_id, size in 1/64th pixels).
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct GlyphKey {
+    /// The [`Face::id`](crate::face::Face::id) the glyph comes from; 0 for
+    /// the renderer's built-in font.
+    pub face: u32,
     pub glyph_id: u16,
     /// Size in 1/64 pixel units (to allow sub-pixel sizing).
     pub size_64: u32,
 }
 
 impl GlyphKey {
+    /// Key a glyph of the built-in font.
     pub fn new(glyph_id: u16, size_px: f32) -> Self {
+        Self::for_face(0, glyph_id, size_px)
+    }
+
+    /// Key a glyph of face `face`.
+    pub fn for_face(face: u32, glyph_id: u16, size_px: f32) -> Self {
         Self {
+            face,
             glyph_id,
             size_64: (size_px * 64.0) as u32,
         }
@@ -0,0 +1,788 @@
+//! Brotli decompression (RFC 7932), as used by WOFF2.
+//!
+//! A stream is a series of meta-blocks. Each compressed meta-block
+//! carries its own prefix codes, split into block types with context
+//! modeling for literals and distances; back-references can also name
+//! words of the built-in static dictionary, after one of 121 transforms.
+
+use common::ParseError;
+use image_decode::deflate::{BitReader, HuffmanTable};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Static dictionary and transforms (RFC 7932 Appendix A and B)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The static dictionary: words of 4 to 24 bytes, grouped by length.
+static DICTIONARY: &[u8; 122_784] = include_bytes!("brotli_dictionary.bin");
+
+/// Offset of the words of each length in [`DICTIONARY`].
+const DICTIONARY_OFFSETS: [u32; 25] = [
+    0, 0, 0, 0, 0, 4096, 9216, 21504, 35840, 44032, 53248, 63488, 74752, 87040, 93696, 100864,
+    104704, 106752, 108928, 113536, 115968, 118528, 119872, 121280, 122016,
+];
+
+/// log2 of the number of words of each length.
+const DICTIONARY_SIZE_BITS: [u8; 25] = [
+    0, 0, 0, 0, 10, 10, 11, 11, 10, 10, 10, 10, 10, 9, 9, 8, 7, 7, 8, 7, 7, 6, 6, 5, 5,
+];
+
+#[derive(Clone, Copy)]
+enum WordTransform {
+    Identity,
+    OmitLast(usize),
+    OmitFirst(usize),
+    UppercaseFirst,
+    UppercaseAll,
+}
+
+use WordTransform::*;
+
+/// Prefix, transform and suffix of each dictionary transform.
+const TRANSFORMS: [(&[u8], WordTransform, &[u8]); 121] = [
+    (b"", Identity, b""),
+    (b"", Identity, b" "),
+    (b" ", Identity, b" "),
+    (b"", OmitFirst(1), b""),
+    (b"", UppercaseFirst, b" "),
+    (b"", Identity, b" the "),
+    (b" ", Identity, b""),
+    (b"s ", Identity, b" "),
+    (b"", Identity, b" of "),
+    (b"", UppercaseFirst, b""),
+    (b"", Identity, b" and "),
+    (b"", OmitFirst(2), b""),
+    (b"", OmitLast(1), b""),
+    (b", ", Identity, b" "),
+    (b"", Identity, b", "),
+    (b" ", UppercaseFirst, b" "),
+    (b"", Identity, b" in "),
+    (b"", Identity, b" to "),
+    (b"e ", Identity, b" "),
+    (b"", Identity, b"\""),
+    (b"", Identity, b"."),
+    (b"", Identity, b"\">"),
+    (b"", Identity, b"\n"),
+    (b"", OmitLast(3), b""),
+    (b"", Identity, b"]"),
+    (b"", Identity, b" for "),
+    (b"", OmitFirst(3), b""),
+    (b"", OmitLast(2), b""),
+    (b"", Identity, b" a "),
+    (b"", Identity, b" that "),
+    (b" ", UppercaseFirst, b""),
+    (b"", Identity, b". "),
+    (b".", Identity, b""),
+    (b" ", Identity, b", "),
+    (b"", OmitFirst(4), b""),
+    (b"", Identity, b" with "),
+    (b"", Identity, b"'"),
+    (b"", Identity, b" from "),
+    (b"", Identity, b" by "),
+    (b"", OmitFirst(5), b""),
+    (b"", OmitFirst(6), b""),
+    (b" the ", Identity, b""),
+    (b"", OmitLast(4), b""),
+    (b"", Identity, b". The "),
+    (b"", UppercaseAll, b""),
+    (b"", Identity, b" on "),
+    (b"", Identity, b" as "),
+    (b"", Identity, b" is "),
+    (b"", OmitLast(7), b""),
+    (b"", OmitLast(1), b"ing "),
+    (b"", Identity, b"\n\t"),
+    (b"", Identity, b":"),
+    (b" ", Identity, b". "),
+    (b"", Identity, b"ed "),
+    (b"", OmitFirst(9), b""),
+    (b"", OmitFirst(7), b""),
+    (b"", OmitLast(6), b""),
+    (b"", Identity, b"("),
+    (b"", UppercaseFirst, b", "),
+    (b"", OmitLast(8), b""),
+    (b"", Identity, b" at "),
+    (b"", Identity, b"ly "),
+    (b" the ", Identity, b" of "),
+    (b"", OmitLast(5), b""),
+    (b"", OmitLast(9), b""),
+    (b" ", UppercaseFirst, b", "),
+    (b"", UppercaseFirst, b"\""),
+    (b".", Identity, b"("),
+    (b"", UppercaseAll, b" "),
+    (b"", UppercaseFirst, b"\">"),
+    (b"", Identity, b"=\""),
+    (b" ", Identity, b"."),
+    (b".com/", Identity, b""),
+    (b" the ", Identity, b" of the "),
+    (b"", UppercaseFirst, b"'"),
+    (b"", Identity, b". This "),
+    (b"", Identity, b","),
+    (b".", Identity, b" "),
+    (b"", UppercaseFirst, b"("),
+    (b"", UppercaseFirst, b"."),
+    (b"", Identity, b" not "),
+    (b" ", Identity, b"=\""),
+    (b"", Identity, b"er "),
+    (b" ", UppercaseAll, b" "),
+    (b"", Identity, b"al "),
+    (b" ", UppercaseAll, b""),
+    (b"", Identity, b"='"),
+    (b"", UppercaseAll, b"\""),
+    (b"", UppercaseFirst, b". "),
+    (b" ", Identity, b"("),
+    (b"", Identity, b"ful "),
+    (b" ", UppercaseFirst, b". "),
+    (b"", Identity, b"ive "),
+    (b"", Identity, b"less "),
+    (b"", UppercaseAll, b"'"),
+    (b"", Identity, b"est "),
+    (b" ", UppercaseFirst, b"."),
+    (b"", UppercaseAll, b"\">"),
+    (b" ", Identity, b"='"),
+    (b"", UppercaseFirst, b","),
+    (b"", Identity, b"ize "),
+    (b"", UppercaseAll, b"."),
+    (b"\xc2\xa0", Identity, b""),
+    (b" ", Identity, b","),
+    (b"", UppercaseFirst, b"=\""),
+    (b"", UppercaseAll, b"=\""),
+    (b"", Identity, b"ous "),
+    (b"", UppercaseAll, b", "),
+    (b"", UppercaseFirst, b"='"),
+    (b" ", UppercaseFirst, b","),
+    (b" ", UppercaseAll, b"=\""),
+    (b" ", UppercaseAll, b", "),
+    (b"", UppercaseAll, b","),
+    (b"", UppercaseAll, b"("),
+    (b"", UppercaseAll, b". "),
+    (b" ", UppercaseAll, b"."),
+    (b"", UppercaseAll, b"='"),
+    (b" ", UppercaseAll, b". "),
+    (b" ", UppercaseFirst, b"=\""),
+    (b" ", UppercaseAll, b"='"),
+    (b" ", UppercaseFirst, b"='"),
+];
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Context modeling (RFC 7932 section 7.1)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// UTF8 context: the contribution of the last byte.
+const UTF8_LAST: [u8; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 4, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    8, 12, 16, 12, 12, 20, 12, 16, 24, 28, 12, 12, 32, 12, 36, 12,
+    44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 32, 32, 24, 40, 28, 12,
+    12, 48, 52, 52, 52, 48, 52, 52, 52, 48, 52, 52, 52, 52, 52, 48,
+    52, 52, 52, 52, 52, 48, 52, 52, 52, 52, 52, 24, 12, 28, 12, 12,
+    12, 56, 60, 60, 60, 56, 60, 60, 60, 56, 60, 60, 60, 60, 60, 56,
+    60, 60, 60, 60, 60, 56, 60, 60, 60, 60, 60, 24, 12, 28, 12, 0,
+    0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1,
+    0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1,
+    0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1,
+    0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1,
+    2, 3, 2, 3, 2, 3, 2, 3, 2, 3, 2, 3, 2, 3, 2, 3,
+    2, 3, 2, 3, 2, 3, 2, 3, 2, 3, 2, 3, 2, 3, 2, 3,
+    2, 3, 2, 3, 2, 3, 2, 3, 2, 3, 2, 3, 2, 3, 2, 3,
+    2, 3, 2, 3, 2, 3, 2, 3, 2, 3, 2, 3, 2, 3, 2, 3,
+];
+
+/// UTF8 context: the contribution of the byte before it.
+const UTF8_SECOND_LAST: [u8; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 1, 1, 1, 1, 1, 1,
+    1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 1, 1, 1, 1, 1,
+    1, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 1, 1, 1, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+];
+
+/// The class of a byte for the signed context mode, three bits wide.
+fn signed_class(byte: u8) -> u8 {
+    match byte {
+        0 => 0,
+        1..=15 => 1,
+        16..=63 => 2,
+        64..=127 => 3,
+        128..=191 => 4,
+        192..=239 => 5,
+        240..=254 => 6,
+        255 => 7,
+    }
+}
+
+/// The literal context id for context `mode` after bytes `p2`, `p1`.
+fn literal_context(mode: u8, p1: u8, p2: u8) -> usize {
+    (match mode {
+        0 => p1 & 0x3f,
+        1 => p1 >> 2,
+        2 => UTF8_LAST[p1 as usize] | UTF8_SECOND_LAST[p2 as usize],
+        _ => (signed_class(p1) << 3) | signed_class(p2),
+    }) as usize
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Length code tables (RFC 7932 sections 5 and 6)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Base value and extra bits of each block count code.
+const BLOCK_COUNTS: [(u32, u32); 26] = [
+    (1, 2), (5, 2), (9, 2), (13, 2), (17, 3), (25, 3), (33, 3), (41, 3), (49, 4), (65, 4),
+    (81, 4), (97, 4), (113, 5), (145, 5), (177, 5), (209, 5), (241, 6), (305, 6), (369, 7),
+    (497, 8), (753, 9), (1265, 10), (2289, 11), (4337, 12), (8433, 13), (16625, 24),
+];
+
+/// Base value and extra bits of each insert length code.
+const INSERT_LENGTHS: [(u32, u32); 24] = [
+    (0, 0), (1, 0), (2, 0), (3, 0), (4, 0), (5, 0), (6, 1), (8, 1), (10, 2), (14, 2), (18, 3),
+    (26, 3), (34, 4), (50, 4), (66, 5), (98, 5), (130, 6), (194, 7), (322, 8), (578, 9),
+    (1090, 10), (2114, 12), (6210, 14), (22594, 24),
+];
+
+/// Base value and extra bits of each copy length code.
+const COPY_LENGTHS: [(u32, u32); 24] = [
+    (2, 0), (3, 0), (4, 0), (5, 0), (6, 0), (7, 0), (8, 0), (9, 0), (10, 1), (12, 1), (14, 2),
+    (18, 2), (22, 3), (30, 3), (38, 4), (54, 4), (70, 5), (102, 5), (134, 6), (198, 7),
+    (326, 8), (582, 9), (1094, 10), (2118, 24),
+];
+
+/// Insert and copy length code offsets of each 64-code cell of the
+/// insert-and-copy alphabet, and whether its distance is implicit.
+const COMMAND_CELLS: [(u32, u32, bool); 11] = [
+    (0, 0, true), (0, 8, true), (0, 0, false), (0, 8, false), (8, 0, false), (8, 8, false),
+    (0, 16, false), (16, 0, false), (8, 16, false), (16, 8, false), (16, 16, false),
+];
+
+/// Order in which code length code lengths are stored.
+const CODE_LENGTH_ORDER: [usize; 18] = [1, 2, 3, 4, 0, 5, 17, 6, 16, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+fn invalid(message: &'static str) -> ParseError {
+    ParseError::InvalidValue(message)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Prefix codes (RFC 7932 section 3)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A prefix code over some alphabet.
+enum PrefixCode {
+    /// A one-symbol code, which takes no bits.
+    Single(u16),
+    Table(HuffmanTable),
+}
+
+impl PrefixCode {
+    fn decode(&self, reader: &mut BitReader<'_>) -> Result<u32, ParseError> {
+        match self {
+            PrefixCode::Single(symbol) => Ok(*symbol as u32),
+            PrefixCode::Table(table) => table.decode(reader).map(u32::from),
+        }
+    }
+
+    /// Read a prefix code description for an alphabet of `alphabet_size`.
+    fn read(reader: &mut BitReader<'_>, alphabet_size: usize) -> Result<Self, ParseError> {
+        let mut lengths = vec![0u8; alphabet_size];
+        let hskip = reader.read_bits(2)? as usize;
+        if hskip == 1 {
+            // A simple code: up to four symbols with fixed code lengths.
+            let alphabet_bits = usize::BITS - (alphabet_size - 1).leading_zeros();
+            let count = reader.read_bits(2)? as usize + 1;
+            let mut symbols = Vec::with_capacity(count);
+            for _ in 0..count {
+                let symbol = reader.read_bits(alphabet_bits)? as usize;
+                if symbol >= alphabet_size || symbols.contains(&symbol) {
+                    return Err(invalid("Brotli: bad simple prefix code"));
+                }
+                symbols.push(symbol);
+            }
+            let code_lengths: &[u8] = match count {
+                1 => return Ok(PrefixCode::Single(symbols[0] as u16)),
+                2 => &[1, 1],
+                3 => &[1, 2, 2],
+                _ if reader.read_bits(1)? == 0 => &[2, 2, 2, 2],
+                _ => &[1, 2, 3, 3],
+            };
+            for (&symbol, &length) in symbols.iter().zip(code_lengths) {
+                lengths[symbol] = length;
+            }
+            return HuffmanTable::from_lengths(&lengths).map(PrefixCode::Table);
+        }
+
+        // A complex code: code lengths, themselves prefix coded.
+        let mut code_length_lengths = [0u8; 18];
+        let mut space = 32i32;
+        let mut nonzero = 0;
+        for &symbol in &CODE_LENGTH_ORDER[hskip..] {
+            let length = match reader.read_bits(2)? {
+                0 => 0,
+                1 => 4,
+                2 => 3,
+                _ if reader.read_bits(1)? == 0 => 2,
+                _ if reader.read_bits(1)? == 0 => 1,
+                _ => 5,
+            };
+            code_length_lengths[symbol] = length;
+            if length != 0 {
+                space -= 32 >> length;
+                nonzero += 1;
+                if space <= 0 {
+                    break;
+                }
+            }
+        }
+        if nonzero != 1 && space != 0 {
+            return Err(invalid("Brotli: bad code length code"));
+        }
+        let code_length_code = match nonzero {
+            1 => PrefixCode::Single(code_length_lengths.iter().position(|&l| l != 0).unwrap_or(0) as u16),
+            _ => PrefixCode::Table(HuffmanTable::from_lengths(&code_length_lengths)?),
+        };
+
+        let mut symbol = 0;
+        let mut previous_length = 8u8;
+        let mut repeat = 0usize;
+        let mut repeat_length = 0u8;
+        let mut space = 32768i32;
+        while symbol < alphabet_size && space > 0 {
+            let code = code_length_code.decode(reader)?;
+            if code < 16 {
+                repeat = 0;
+                lengths[symbol] = code as u8;
+                if code != 0 {
+                    previous_length = code as u8;
+                    space -= 32768 >> code;
+                }
+                symbol += 1;
+                continue;
+            }
+            // 16 repeats the previous non-zero length, 17 repeats zero;
+            // consecutive repeat codes multiply.
+            let (extra_bits, length) = if code == 16 { (2, previous_length) } else { (3, 0) };
+            if repeat_length != length {
+                repeat = 0;
+                repeat_length = length;
+            }
+            let old_repeat = repeat;
+            if repeat > 0 {
+                repeat = (repeat - 2) << extra_bits;
+            }
+            repeat += reader.read_bits(extra_bits)? as usize + 3;
+            let delta = repeat - old_repeat;
+            if symbol + delta > alphabet_size {
+                return Err(invalid("Brotli: code lengths overflow the alphabet"));
+            }
+            lengths[symbol..symbol + delta].fill(length);
+            symbol += delta;
+            if length != 0 {
+                space -= delta as i32 * (32768 >> length);
+            }
+        }
+        if space != 0 {
+            return Err(invalid("Brotli: incomplete prefix code"));
+        }
+        HuffmanTable::from_lengths(&lengths).map(PrefixCode::Table)
+    }
+}
+
+/// Read the variable-length number used for block type and tree counts.
+fn read_var_len_u8(reader: &mut BitReader<'_>) -> Result<usize, ParseError> {
+    if reader.read_bits(1)? == 0 {
+        return Ok(0);
+    }
+    let bits = reader.read_bits(3)?;
+    Ok(if bits == 0 { 1 } else { (1 << bits) + reader.read_bits(bits)? as usize })
+}
+
+fn read_block_count(reader: &mut BitReader<'_>, code: &PrefixCode) -> Result<u32, ParseError> {
+    let (base, extra) = BLOCK_COUNTS[code.decode(reader)? as usize];
+    Ok(base + reader.read_bits(extra)?)
+}
+
+/// A context map: which prefix code each (block type, context) uses.
+fn read_context_map(reader: &mut BitReader<'_>, size: usize, trees: usize) -> Result<Vec<u8>, ParseError> {
+    if trees < 2 {
+        return Ok(vec![0; size]);
+    }
+    let rle_max = if reader.read_bits(1)? == 1 { reader.read_bits(4)? as usize + 1 } else { 0 };
+    let code = PrefixCode::read(reader, trees + rle_max)?;
+    let mut map = Vec::with_capacity(size);
+    while map.len() < size {
+        let symbol = code.decode(reader)? as usize;
+        if symbol == 0 {
+            map.push(0);
+        } else if symbol <= rle_max {
+            let zeros = (1 << symbol) + reader.read_bits(symbol as u32)? as usize;
+            if map.len() + zeros > size {
+                return Err(invalid("Brotli: context map overflow"));
+            }
+            map.resize(map.len() + zeros, 0);
+        } else {
+            map.push((symbol - rle_max) as u8);
+        }
+    }
+    if reader.read_bits(1)? == 1 {
+        // Undo the move-to-front transform.
+        let mut mtf: Vec<u8> = (0..=255).collect();
+        for value in &mut map {
+            let index = *value as usize;
+            let front = mtf.remove(index);
+            mtf.insert(0, front);
+            *value = front;
+        }
+    }
+    Ok(map)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Block splitting (RFC 7932 section 6)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The block types of one category (literals, commands or distances).
+struct BlockSplit {
+    types: usize,
+    type_code: Option<PrefixCode>,
+    count_code: Option<PrefixCode>,
+    current: usize,
+    previous: usize,
+    remaining: u32,
+}
+
+impl BlockSplit {
+    fn read(reader: &mut BitReader<'_>) -> Result<Self, ParseError> {
+        let types = read_var_len_u8(reader)? + 1;
+        let mut split = BlockSplit { types, type_code: None, count_code: None, current: 0, previous: 1, remaining: 1 << 24 };
+        if types >= 2 {
+            let type_code = PrefixCode::read(reader, types + 2)?;
+            let count_code = PrefixCode::read(reader, 26)?;
+            split.remaining = read_block_count(reader, &count_code)?;
+            split.type_code = Some(type_code);
+            split.count_code = Some(count_code);
+        }
+        Ok(split)
+    }
+
+    /// Count one symbol of the category, switching block type first if
+    /// the current block is used up. Returns the block type.
+    fn next(&mut self, reader: &mut BitReader<'_>) -> Result<usize, ParseError> {
+        if self.remaining == 0 {
+            let (Some(type_code), Some(count_code)) = (&self.type_code, &self.count_code) else {
+                return Err(invalid("Brotli: block count exhausted"));
+            };
+            let next = match type_code.decode(reader)? as usize {
+                0 => self.previous,
+                1 => (self.current + 1) % self.types,
+                code => code - 2,
+            };
+            if next >= self.types {
+                return Err(invalid("Brotli: bad block type"));
+            }
+            self.previous = self.current;
+            self.current = next;
+            self.remaining = read_block_count(reader, count_code)?;
+        }
+        self.remaining = self.remaining.saturating_sub(1);
+        Ok(self.current)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// decompress
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Decompress a Brotli stream, failing if it would produce more than
+/// `max_len` bytes.
+pub fn decompress(data: &[u8], max_len: usize) -> Result<Vec<u8>, ParseError> {
+    let mut reader = BitReader::new(data);
+    let window_bits = if reader.read_bits(1)? == 0 {
+        16
+    } else {
+        match reader.read_bits(3)? {
+            0 => match reader.read_bits(3)? {
+                0 => 17,
+                1 => return Err(invalid("Brotli: large windows are not supported")),
+                n => 8 + n,
+            },
+            n => 17 + n,
+        }
+    };
+    let max_backward = (1usize << window_bits) - 16;
+
+    let mut output: Vec<u8> = Vec::new();
+    // The last four distances, most recent last.
+    let mut distances = [16usize, 15, 11, 4];
+
+    loop {
+        let is_last = reader.read_bits(1)? == 1;
+        if is_last && reader.read_bits(1)? == 1 {
+            break;
+        }
+        let nibbles = match reader.read_bits(2)? {
+            3 => 0,
+            n => n + 4,
+        };
+        if nibbles == 0 {
+            // Metadata, which is skipped.
+            if reader.read_bits(1)? != 0 {
+                return Err(invalid("Brotli: reserved bit set"));
+            }
+            let skip_bytes = reader.read_bits(2)?;
+            let skip = if skip_bytes == 0 { 0 } else { reader.read_bits(8 * skip_bytes)? as usize + 1 };
+            reader.align_to_byte();
+            for _ in 0..skip {
+                reader.read_byte()?;
+            }
+            if is_last {
+                break;
+            }
+            continue;
+        }
+        let meta_len = reader.read_bits(4 * nibbles)? as usize + 1;
+        if output.len() + meta_len > max_len {
+            return Err(invalid("Brotli: output too large"));
+        }
+        if !is_last && reader.read_bits(1)? == 1 {
+            reader.align_to_byte();
+            for _ in 0..meta_len {
+                output.push(reader.read_byte()?);
+            }
+            continue;
+        }
+
+        decode_meta_block(&mut reader, &mut output, meta_len, max_backward, &mut distances)?;
+        if is_last {
+            break;
+        }
+    }
+    Ok(output)
+}
+
+/// Decode one compressed meta-block of `meta_len` bytes onto `output`.
+fn decode_meta_block(
+    reader: &mut BitReader<'_>,
+    output: &mut Vec<u8>,
+    meta_len: usize,
+    max_backward: usize,
+    distances: &mut [usize; 4],
+) -> Result<(), ParseError> {
+    let mut literal_split = BlockSplit::read(reader)?;
+    let mut command_split = BlockSplit::read(reader)?;
+    let mut distance_split = BlockSplit::read(reader)?;
+    let postfix_bits = reader.read_bits(2)?;
+    let direct = (reader.read_bits(4)? << postfix_bits) as usize;
+    let mut context_modes = Vec::with_capacity(literal_split.types);
+    for _ in 0..literal_split.types {
+        context_modes.push(reader.read_bits(2)? as u8);
+    }
+    let literal_trees = read_var_len_u8(reader)? + 1;
+    let literal_map = read_context_map(reader, 64 * literal_split.types, literal_trees)?;
+    let distance_trees = read_var_len_u8(reader)? + 1;
+    let distance_map = read_context_map(reader, 4 * distance_split.types, distance_trees)?;
+
+    let literal_codes = (0..literal_trees).map(|_| PrefixCode::read(reader, 256)).collect::<Result<Vec<_>, _>>()?;
+    let command_codes =
+        (0..command_split.types).map(|_| PrefixCode::read(reader, 704)).collect::<Result<Vec<_>, _>>()?;
+    let distance_alphabet = 16 + direct + (48 << postfix_bits);
+    let distance_codes = (0..distance_trees)
+        .map(|_| PrefixCode::read(reader, distance_alphabet))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let end = output.len() + meta_len;
+    while output.len() < end {
+        let command_type = command_split.next(reader)?;
+        let command = command_codes[command_type].decode(reader)?;
+        let (insert_offset, copy_offset, implicit_distance) = COMMAND_CELLS[(command >> 6) as usize];
+        let (insert_base, insert_extra) = INSERT_LENGTHS[(insert_offset + ((command >> 3) & 7)) as usize];
+        let (copy_base, copy_extra) = COPY_LENGTHS[(copy_offset + (command & 7)) as usize];
+        let insert_len = (insert_base + reader.read_bits(insert_extra)?) as usize;
+        let copy_len = (copy_base + reader.read_bits(copy_extra)?) as usize;
+
+        if output.len() + insert_len > end {
+            return Err(invalid("Brotli: insert past the end of the meta-block"));
+        }
+        for _ in 0..insert_len {
+            let literal_type = literal_split.next(reader)?;
+            let p1 = output.last().copied().unwrap_or(0);
+            let p2 = output.len().checked_sub(2).map_or(0, |i| output[i]);
+            let context = literal_context(context_modes[literal_type], p1, p2);
+            let tree = literal_map[64 * literal_type + context] as usize;
+            let literal = literal_codes.get(tree).ok_or(invalid("Brotli: bad context map"))?.decode(reader)?;
+            output.push(literal as u8);
+        }
+        if output.len() == end {
+            break;
+        }
+
+        // The distance: 0 reuses the last one without recording it again.
+        let (distance, record) = if implicit_distance {
+            (distances[3], false)
+        } else {
+            let distance_type = distance_split.next(reader)?;
+            let context = copy_len.saturating_sub(2).min(3);
+            let tree = distance_map[4 * distance_type + context] as usize;
+            let code = distance_codes.get(tree).ok_or(invalid("Brotli: bad context map"))?.decode(reader)? as usize;
+            let distance = match code {
+                0 => distances[3],
+                1..=15 => {
+                    const LAST: [(usize, isize); 16] = [
+                        (3, 0), (2, 0), (1, 0), (0, 0), (3, -1), (3, 1), (3, -2), (3, 2),
+                        (3, -3), (3, 3), (2, -1), (2, 1), (2, -2), (2, 2), (2, -3), (2, 3),
+                    ];
+                    let (slot, delta) = LAST[code];
+                    let distance = distances[slot] as isize + delta;
+                    if distance <= 0 {
+                        return Err(invalid("Brotli: bad distance"));
+                    }
+                    distance as usize
+                }
+                _ if code < 16 + direct => code - 15,
+                _ => {
+                    let code = code - direct - 16;
+                    let postfix_mask = (1 << postfix_bits) - 1;
+                    let extra_bits = 1 + (code >> (postfix_bits + 1));
+                    let high = (code >> postfix_bits) & 1;
+                    let offset = ((2 + high) << extra_bits) - 4;
+                    let extra = reader.read_bits(extra_bits as u32)? as usize;
+                    ((offset + extra) << postfix_bits) + (code & postfix_mask) + direct + 1
+                }
+            };
+            (distance, code != 0)
+        };
+
+        let max_distance = max_backward.min(output.len());
+        if distance > max_distance {
+            // A static dictionary word.
+            let word = dictionary_word(distance - max_distance - 1, copy_len)?;
+            if output.len() + word.len() > end {
+                return Err(invalid("Brotli: copy past the end of the meta-block"));
+            }
+            output.extend_from_slice(&word);
+        } else {
+            if output.len() + copy_len > end {
+                return Err(invalid("Brotli: copy past the end of the meta-block"));
+            }
+            let start = output.len() - distance;
+            for i in 0..copy_len {
+                output.push(output[start + i]);
+            }
+            if record {
+                distances.rotate_left(1);
+                distances[3] = distance;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Word `word_id` of length `len` from the static dictionary, transformed.
+fn dictionary_word(word_id: usize, len: usize) -> Result<Vec<u8>, ParseError> {
+    if !(4..=24).contains(&len) {
+        return Err(invalid("Brotli: bad dictionary word length"));
+    }
+    let bits = DICTIONARY_SIZE_BITS[len];
+    let index = word_id & ((1 << bits) - 1);
+    let (prefix, transform, suffix) =
+        *TRANSFORMS.get(word_id >> bits).ok_or(invalid("Brotli: bad dictionary transform"))?;
+    let start = DICTIONARY_OFFSETS[len] as usize + index * len;
+    let word = &DICTIONARY[start..start + len];
+
+    let mut out = prefix.to_vec();
+    let body_start = out.len();
+    match transform {
+        Identity | UppercaseFirst | UppercaseAll => out.extend_from_slice(word),
+        OmitLast(n) => out.extend_from_slice(&word[..len.saturating_sub(n)]),
+        OmitFirst(n) => out.extend_from_slice(&word[n.min(len)..]),
+    }
+    let body = &mut out[body_start..];
+    match transform {
+        UppercaseFirst if !body.is_empty() => {
+            uppercase(body, 0);
+        }
+        UppercaseAll => {
+            let mut i = 0;
+            while i < body.len() {
+                i += uppercase(body, i);
+            }
+        }
+        _ => {}
+    }
+    out.extend_from_slice(suffix);
+    Ok(out)
+}
+
+/// Uppercase the UTF-8 character at `body[i]` the way Brotli does,
+/// returning its length.
+fn uppercase(body: &mut [u8], i: usize) -> usize {
+    match body[i] {
+        byte if byte < 0xc0 => {
+            if byte.is_ascii_lowercase() {
+                body[i] ^= 0x20;
+            }
+            1
+        }
+        byte if byte < 0xe0 => {
+            if let Some(next) = body.get_mut(i + 1) {
+                *next ^= 0x20;
+            }
+            2
+        }
+        _ => {
+            if let Some(third) = body.get_mut(i + 2) {
+                *third ^= 5;
+            }
+            3
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &[u8] = b"<html><head><title>Fonts</title></head><body>The quick brown fox jumps over \
+        the lazy dog. The Quick Brown Fox jumps again, and again, and AGAIN.</body></html>";
+
+    /// `TEXT` at quality 11: context modeling and dictionary references.
+    const BEST: [u8; 100] = [
+        0x1b, 0x9d, 0x00, 0x00, 0x1c, 0x09, 0xb6, 0x2d, 0x99, 0xf5, 0xe2, 0xfc, 0x24, 0x42, 0xc1, 0x6c,
+        0x76, 0xa4, 0x07, 0x14, 0x60, 0x10, 0xe4, 0x6d, 0x01, 0xb6, 0x92, 0xac, 0xa0, 0xff, 0x7c, 0x43,
+        0x0b, 0x0b, 0x37, 0x9d, 0xdc, 0xfc, 0x10, 0x28, 0xbc, 0x03, 0x07, 0x59, 0x44, 0x2d, 0xa1, 0x00,
+        0x13, 0xce, 0x6d, 0x0c, 0x3c, 0x47, 0xa9, 0x88, 0x9e, 0x35, 0xd2, 0x29, 0x1a, 0xf5, 0x85, 0x13,
+        0x8f, 0xda, 0xf1, 0x0d, 0x74, 0xc1, 0x64, 0x7f, 0x3d, 0x11, 0x87, 0x68, 0xa5, 0x43, 0xd2, 0x31,
+        0x08, 0xe9, 0xe9, 0xfe, 0x43, 0x28, 0x02, 0x01, 0x06, 0x31, 0xa5, 0x5f, 0x71, 0x17, 0xe0, 0x13,
+        0x33, 0x87, 0x32, 0x01,
+    ];
+
+    /// `TEXT` at quality 1: complex prefix codes, no context modeling.
+    const FAST: [u8; 143] = [
+        0x8b, 0x4e, 0x00, 0x00, 0x80, 0xaa, 0xaa, 0xaa, 0xea, 0xff, 0x74, 0x65, 0xb8, 0x3b, 0x98, 0x1e,
+        0x2e, 0x02, 0x02, 0x60, 0x7e, 0x30, 0x07, 0xbf, 0x38, 0x80, 0x03, 0xd8, 0xe1, 0xa2, 0xea, 0xaa,
+        0x6e, 0xa2, 0xee, 0xaa, 0x2a, 0xee, 0x66, 0x62, 0xee, 0xae, 0x0e, 0x35, 0xb9, 0xfd, 0x6f, 0x2d,
+        0x60, 0x3d, 0x7c, 0x40, 0x0c, 0x30, 0x0c, 0x30, 0xd8, 0x18, 0x73, 0x7c, 0x8d, 0x0e, 0x24, 0x56,
+        0x0b, 0x93, 0x24, 0x1f, 0x99, 0x2c, 0x5b, 0x49, 0x3c, 0x69, 0xb3, 0x8d, 0x5c, 0x70, 0xc8, 0xcd,
+        0xfe, 0x0a, 0x1a, 0x3b, 0xcf, 0x92, 0xf0, 0xde, 0xf3, 0xed, 0x89, 0xb0, 0xea, 0xb7, 0xe1, 0xae,
+        0x3f, 0x3c, 0xf6, 0xfa, 0xda, 0xa0, 0x9f, 0xb4, 0xc2, 0x24, 0xa1, 0xf8, 0x7f, 0x47, 0xd4, 0x65,
+        0xc0, 0x2c, 0x09, 0xd7, 0x83, 0x38, 0x22, 0x26, 0x6a, 0xf2, 0x8b, 0xcf, 0xed, 0x00, 0xdf, 0x22,
+        0xb4, 0x57, 0xe3, 0x69, 0x3c, 0x5f, 0x06, 0x72, 0x41, 0x63, 0x67, 0x72, 0x62, 0xb5, 0xf0,
+    ];
+
+    #[test]
+    fn decompresses_encoder_output() {
+        assert_eq!(decompress(&BEST, 1 << 16).unwrap(), TEXT);
+        assert_eq!(decompress(&FAST, 1 << 16).unwrap(), TEXT);
+    }
+
+    #[test]
+    fn rejects_oversized_and_truncated_streams() {
+        assert!(decompress(&BEST, TEXT.len() - 1).is_err());
+        assert!(decompress(&FAST[..FAST.len() / 2], 1 << 16).is_err());
+    }
+}
@@ -0,0 +1,257 @@
+//! A loaded font face.
+//!
+//! [`Face`] owns the sfnt data of one TrueType font and the tables needed
+//! to map characters to glyphs, measure them and rasterize their outlines.
+//! Each face gets a process-unique [`id`](Face::id) so glyph caches can
+//! tell faces apart.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use common::ParseError;
+
+use crate::glyph::{parse_glyph, GlyphDesc};
+use crate::rasterizer::{rasterize_outline, GlyphBitmap};
+use crate::tables::{
+    parse_cmap, get_glyph_offset, get_hmetric,
+    CmapFormat4, FontFile, HeadTable, HheaTable, TableTag,
+};
+
+/// Face ids handed out so far; 0 is left for a renderer's built-in font.
+static NEXT_FACE_ID: AtomicU32 = AtomicU32::new(1);
+
+/// A parsed TrueType font.
+pub struct Face {
+    id: u32,
+    data: Vec<u8>,
+    head: HeadTable,
+    hhea: HheaTable,
+    cmap: CmapFormat4,
+    loca_range: (usize, usize),
+    glyf_range: (usize, usize),
+    hmtx_range: (usize, usize),
+}
+
+impl core::fmt::Debug for Face {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Face").field("id", &self.id).field("len", &self.data.len()).finish()
+    }
+}
+
+impl Face {
+    /// Parse font file `data`: TrueType, or WOFF/WOFF2 wrapping it.
+    /// CFF-flavored OpenType has no `glyf` table and is rejected.
+    pub fn parse(data: &[u8]) -> Result<Self, ParseError> {
+        Self::from_sfnt(crate::woff::decode(data)?)
+    }
+
+    /// Parse plain sfnt data, taking ownership of it.
+    pub fn from_sfnt(data: Vec<u8>) -> Result<Self, ParseError> {
+        let (head, hhea, cmap, loca_range, glyf_range, hmtx_range) = {
+            let ff = FontFile::parse(&data)?;
+            let table = |tag: TableTag, missing: &'static str| ff.table_data(tag).ok_or(ParseError::InvalidValue(missing));
+            let head = HeadTable::parse(table(TableTag::HEAD, "missing head table")?)?;
+            let hhea = HheaTable::parse(table(TableTag::HHEA, "missing hhea table")?)?;
+            let cmap = parse_cmap(table(TableTag::CMAP, "missing cmap table")?)?;
+            let range = |tag: TableTag, missing: &'static str| {
+                let record = ff.find_table(tag).ok_or(ParseError::InvalidValue(missing))?;
+                let (offset, length) = (record.offset as usize, record.length as usize);
+                if offset.checked_add(length).is_none_or(|end| end > data.len()) {
+                    return Err(ParseError::LengthOutOfRange("font table"));
+                }
+                Ok((offset, length))
+            };
+            (
+                head,
+                hhea,
+                cmap,
+                range(TableTag::LOCA, "missing loca table")?,
+                range(TableTag::GLYF, "missing glyf table")?,
+                range(TableTag::HMTX, "missing hmtx table")?,
+            )
+        };
+        if head.units_per_em == 0 {
+            return Err(ParseError::InvalidValue("unitsPerEm is zero"));
+        }
+        Ok(Face {
+            id: NEXT_FACE_ID.fetch_add(1, Ordering::Relaxed),
+            data,
+            head,
+            hhea,
+            cmap,
+            loca_range,
+            glyf_range,
+            hmtx_range,
+        })
+    }
+
+    /// The face's process-unique id, never 0.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    fn loca_data(&self) -> &[u8] {
+        let (off, len) = self.loca_range;
+        &self.data[off..off + len]
+    }
+
+    fn glyf_data(&self) -> &[u8] {
+        let (off, len) = self.glyf_range;
+        &self.data[off..off + len]
+    }
+
+    fn hmtx_data(&self) -> &[u8] {
+        let (off, len) = self.hmtx_range;
+        &self.data[off..off + len]
+    }
+
+    /// The glyph for `ch`, 0 (`.notdef`) if the face has none. Only the
+    /// Basic Multilingual Plane is mapped.
+    pub fn glyph_index(&self, ch: char) -> u16 {
+        u16::try_from(ch as u32).map_or(0, |ch| self.cmap.lookup(ch))
+    }
+
+    /// Whether the face has a glyph for `ch`.
+    pub fn has_glyph(&self, ch: char) -> bool {
+        self.glyph_index(ch) != 0
+    }
+
+    /// The advance width of glyph `glyph_id` at `size_px`, in pixels.
+    pub fn advance(&self, glyph_id: u16, size_px: f32) -> f32 {
+        get_hmetric(self.hmtx_data(), glyph_id, self.hhea.num_h_metrics)
+            .map_or(0.0, |m| m.advance_width as f32 * size_px / self.head.units_per_em as f32)
+    }
+
+    /// Rasterize glyph `glyph_id` at `size_px`. Glyphs without an outline
+    /// (spaces, and composite glyphs, which aren't drawn yet) come back
+    /// empty but with their advance.
+    pub fn rasterize(&self, glyph_id: u16, size_px: f32) -> Option<GlyphBitmap> {
+        let advance = self.advance(glyph_id, size_px);
+        let empty = GlyphBitmap { width: 0, height: 0, bearing_x: 0, bearing_y: 0, advance, data: Vec::new() };
+        let (offset, next_offset) =
+            get_glyph_offset(self.loca_data(), glyph_id, self.head.index_to_loc_format).ok()?;
+        if offset == next_offset {
+            return Some(empty);
+        }
+        let glyph_data = self.glyf_data().get(offset as usize..next_offset as usize)?;
+        match parse_glyph(glyph_data).ok()? {
+            GlyphDesc::Simple(outline) => {
+                let mut bitmap = rasterize_outline(&outline, size_px, self.head.units_per_em);
+                bitmap.advance = advance;
+                Some(bitmap)
+            }
+            GlyphDesc::Composite(_) | GlyphDesc::Empty => Some(empty),
+        }
+    }
+
+    /// The line height (ascender − descender + line gap) at `size_px`.
+    pub fn line_height(&self, size_px: f32) -> f32 {
+        let scale = size_px / self.head.units_per_em as f32;
+        (self.hhea.ascender as f32 - self.hhea.descender as f32 + self.hhea.line_gap as f32) * scale
+    }
+
+    /// The ascent (baseline to top) at `size_px`.
+    pub fn ascent(&self, size_px: f32) -> f32 {
+        self.hhea.ascender as f32 * size_px / self.head.units_per_em as f32
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use common::{BufWriter, Endian};
+
+    /// A TrueType font (1000 units per em) mapping each of `glyphs` to a
+    /// square with the given advance; glyph 0 is an empty `.notdef`.
+    pub(crate) fn test_font(glyphs: &[(char, u16)]) -> Vec<u8> {
+        let mut head = vec![0u8; 54];
+        head[18..20].copy_from_slice(&1000u16.to_be_bytes());
+        head[50..52].copy_from_slice(&1i16.to_be_bytes()); // long loca
+
+        let mut hhea = vec![0u8; 36];
+        hhea[4..6].copy_from_slice(&800i16.to_be_bytes());
+        hhea[6..8].copy_from_slice(&(-200i16).to_be_bytes());
+        hhea[34..36].copy_from_slice(&(glyphs.len() as u16 + 1).to_be_bytes());
+
+        // One cmap segment per character, then the 0xFFFF terminator.
+        let mut sorted: Vec<(u16, u16)> = glyphs.iter().enumerate().map(|(i, &(ch, _))| (ch as u16, i as u16 + 1)).collect();
+        sorted.sort();
+        sorted.push((0xffff, 0));
+        let seg_count = sorted.len() as u16;
+        let mut cmap = BufWriter::new(Endian::Big);
+        cmap.u16(0);
+        cmap.u16(1);
+        cmap.u16(3);
+        cmap.u16(1);
+        cmap.u32(12);
+        cmap.u16(4);
+        cmap.u16(16 + 8 * seg_count);
+        cmap.u16(0);
+        cmap.u16(seg_count * 2);
+        cmap.bytes(&[0; 6]);
+        sorted.iter().for_each(|&(ch, _)| cmap.u16(ch));
+        cmap.u16(0);
+        sorted.iter().for_each(|&(ch, _)| cmap.u16(ch));
+        sorted.iter().for_each(|&(ch, glyph)| cmap.u16(if ch == 0xffff { 1 } else { glyph.wrapping_sub(ch) }));
+        sorted.iter().for_each(|_| cmap.u16(0));
+
+        let mut glyf = BufWriter::new(Endian::Big);
+        let mut loca = BufWriter::new(Endian::Big);
+        let mut hmtx = BufWriter::new(Endian::Big);
+        // .notdef: no outline, no advance.
+        loca.u32(0);
+        loca.u32(0);
+        hmtx.u32(0);
+        for &(ch, advance) in glyphs {
+            if ch != ' ' {
+                let size = (advance as i16 - 100).max(1);
+                glyf.u16(1);
+                for v in [50, 0, 50 + size, 700] {
+                    glyf.u16(v as u16);
+                }
+                glyf.u16(3);
+                glyf.u16(0);
+                glyf.bytes(&[1; 4]);
+                for dx in [50, size, 0, -size] {
+                    glyf.u16(dx as u16);
+                }
+                for dy in [0, 0, 700, 0] {
+                    glyf.u16(dy as u16);
+                }
+                glyf.pad4();
+            }
+            loca.u32(glyf.len() as u32);
+            hmtx.u16(advance);
+            hmtx.u16(50);
+        }
+
+        crate::woff::build_sfnt(0x0001_0000, vec![
+            (TableTag::HEAD, head),
+            (TableTag::HHEA, hhea),
+            (TableTag::CMAP, cmap.finish()),
+            (TableTag::GLYF, glyf.finish()),
+            (TableTag::LOCA, loca.finish()),
+            (TableTag::HMTX, hmtx.finish()),
+        ])
+    }
+
+    #[test]
+    fn faces_map_measure_and_rasterize() {
+        let a = Face::parse(&test_font(&[('A', 600), (' ', 250)])).unwrap();
+        let b = Face::parse(&test_font(&[('A', 600)])).unwrap();
+        assert_ne!(a.id(), b.id());
+        assert!(a.id() > 0);
+
+        assert_eq!(a.glyph_index('A'), 1);
+        assert!(a.has_glyph(' ') && !a.has_glyph('B') && !a.has_glyph('😀'));
+        assert_eq!(a.advance(1, 20.0), 12.0);
+        assert_eq!(a.advance(2, 20.0), 5.0);
+        assert_eq!((a.ascent(10.0), a.line_height(10.0)), (8.0, 10.0));
+
+        let glyph = a.rasterize(1, 20.0).unwrap();
+        assert!(glyph.width > 0 && glyph.height > 0 && glyph.data.iter().any(|&p| p > 0));
+        assert_eq!(glyph.advance, 12.0);
+        assert_eq!(a.rasterize(2, 20.0).unwrap().width, 0);
+
+        assert!(Face::parse(b"OTTO\0\0").is_err());
+    }
+}
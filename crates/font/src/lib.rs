@@ -7,9 +7,18 @@
 //! - `glyph`: Parse simple and composite glyph outlines from the `glyf` table
 //! - `rasterizer`: Scanline rasterization with quadratic Bézier flattening
 //! - `atlas`: Skyline-packed glyph atlas for GPU text rendering
+//! - `brotli`: Brotli decompression for WOFF2
+//! - `woff`, `woff2`: Unwrap web font containers into sfnt data
+//! - `face`: A loaded font with glyph lookup, metrics and rasterization
+//! - `registry`: Match text to the web fonts a document registered
 
 pub mod tables;
 pub mod glyph;
 pub mod rasterizer;
 pub mod atlas;
+pub mod brotli;
+pub mod woff;
+pub mod woff2;
+pub mod face;
+pub mod registry;
 
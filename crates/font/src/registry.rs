@@ -0,0 +1,152 @@
+//! Font matching over a document's registered faces.
+//!
+//! Pages register the faces their `@font-face` rules load; text is then
+//! matched one character at a time against the family list of its style,
+//! following the CSS Fonts rules for style and weight.
+
+use std::rc::Rc;
+
+use crate::face::Face;
+
+/// A face registered under a family, with what it covers.
+#[derive(Debug, Clone)]
+pub struct RegisteredFace {
+    pub family: String,
+    /// Weights covered, inclusive.
+    pub weight: (u16, u16),
+    /// Whether the face is italic (or oblique).
+    pub italic: bool,
+    /// Code point ranges, inclusive; empty for all.
+    pub unicode_range: Vec<(u32, u32)>,
+    pub face: Rc<Face>,
+}
+
+impl RegisteredFace {
+    fn covers(&self, ch: char) -> bool {
+        let ch = ch as u32;
+        self.unicode_range.is_empty() || self.unicode_range.iter().any(|&(start, end)| (start..=end).contains(&ch))
+    }
+
+    /// How well the face's weights fit `desired`: lower is better.
+    fn weight_distance(&self, desired: u16) -> (u8, u16) {
+        let (low, high) = self.weight;
+        if (low..=high).contains(&desired) {
+            return (0, 0);
+        }
+        let lighter = high < desired;
+        let distance = if lighter { desired - high } else { low - desired };
+        let rank = match desired {
+            // Light text looks lighter first, bold text heavier first.
+            ..400 => if lighter { 1 } else { 2 },
+            501.. => if lighter { 2 } else { 1 },
+            // In between, heavier up to 500, then lighter, then the rest.
+            _ if !lighter && low <= 500 => 1,
+            _ if lighter => 2,
+            _ => 3,
+        };
+        (rank, distance)
+    }
+}
+
+/// The faces a document has loaded.
+#[derive(Debug, Clone, Default)]
+pub struct FontRegistry {
+    faces: Vec<RegisteredFace>,
+}
+
+impl FontRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a face; of equally good matches, the last registered wins.
+    pub fn add(&mut self, face: RegisteredFace) {
+        self.faces.push(face);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.faces.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.faces.len()
+    }
+
+    /// The face to draw `ch` with, for text whose `font-family` is
+    /// `families` (comma-separated, quotes optional). Families are tried in
+    /// order; within one, faces must cover `ch` and have a glyph for it,
+    /// and the closest in style, then weight, wins. `None` leaves `ch` to
+    /// the built-in font.
+    pub fn match_face(&self, families: &str, weight: u16, italic: bool, ch: char) -> Option<&Rc<Face>> {
+        if self.faces.is_empty() {
+            return None;
+        }
+        families.split(',').find_map(|family| {
+            let family = family.trim().trim_matches(|c| c == '"' || c == '\'');
+            self.faces
+                .iter()
+                .rev()
+                .filter(|f| f.family.eq_ignore_ascii_case(family) && f.covers(ch) && f.face.has_glyph(ch))
+                .min_by_key(|f| (f.italic != italic, f.weight_distance(weight)))
+                .map(|f| &f.face)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::face::tests::test_font;
+
+    fn register(registry: &mut FontRegistry, family: &str, weight: (u16, u16), italic: bool, chars: &str) -> u32 {
+        let glyphs: Vec<(char, u16)> = chars.chars().map(|ch| (ch, 500)).collect();
+        let face = Rc::new(Face::parse(&test_font(&glyphs)).unwrap());
+        let id = face.id();
+        registry.add(RegisteredFace { family: family.into(), weight, italic, unicode_range: Vec::new(), face });
+        id
+    }
+
+    #[test]
+    fn faces_match_by_family_style_and_weight() {
+        let mut registry = FontRegistry::new();
+        let regular = register(&mut registry, "Body", (400, 400), false, "ab");
+        let bold = register(&mut registry, "Body", (700, 700), false, "ab");
+        let italic = register(&mut registry, "Body", (400, 400), true, "ab");
+        let light = register(&mut registry, "Body", (100, 300), false, "ab");
+        let fallback = register(&mut registry, "Fallback Face", (400, 400), false, "abc");
+
+        let matched = |families: &str, weight: u16, italic: bool, ch: char| {
+            registry.match_face(families, weight, italic, ch).map(|f| f.id())
+        };
+        assert_eq!(matched("Body", 400, false, 'a'), Some(regular));
+        assert_eq!(matched("body, serif", 700, false, 'a'), Some(bold));
+        assert_eq!(matched("Body", 400, true, 'a'), Some(italic));
+        assert_eq!(matched("Body", 200, false, 'a'), Some(light));
+        // 350 looks lighter first; 450 heavier up to 500, then lighter;
+        // 900 heavier, then the heaviest lighter face.
+        assert_eq!(matched("Body", 350, false, 'a'), Some(light));
+        assert_eq!(matched("Body", 450, false, 'a'), Some(regular));
+        assert_eq!(matched("Body", 900, false, 'a'), Some(bold));
+
+        // A family without the glyph falls through to the next one.
+        assert_eq!(matched("Body, 'Fallback Face'", 400, false, 'c'), Some(fallback));
+        assert_eq!(matched("Body, sans-serif", 400, false, 'c'), None);
+        assert_eq!(matched("serif", 400, false, 'a'), None);
+    }
+
+    #[test]
+    fn unicode_ranges_limit_faces() {
+        let mut registry = FontRegistry::new();
+        let glyphs = [('a', 500), ('é', 500)];
+        let face = Rc::new(Face::parse(&test_font(&glyphs)).unwrap());
+        registry.add(RegisteredFace {
+            family: "Latin".into(),
+            weight: (400, 400),
+            italic: false,
+            unicode_range: vec![(0, 0x7f)],
+            face,
+        });
+        assert!(registry.match_face("Latin", 400, false, 'a').is_some());
+        assert!(registry.match_face("Latin", 400, false, 'é').is_none());
+    }
+}
@@ -0,0 +1,219 @@
+//! Web font containers: WOFF 1.0 and (through [`crate::woff2`]) WOFF 2.0.
+//!
+//! Both wrap the tables of an ordinary TrueType/OpenType font, compressed.
+//! [`decode`] turns any supported font file back into plain sfnt data that
+//! [`FontFile`](crate::tables::FontFile) can parse.
+
+use common::{BufWriter, Cursor, Endian, ParseError};
+
+use crate::tables::TableTag;
+
+/// The container format of a font file, from its signature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FontFormat {
+    /// Plain TrueType or OpenType (sfnt) data.
+    Sfnt,
+    Woff,
+    Woff2,
+    Unknown,
+}
+
+/// The most tables an sfnt directory can hold: its `searchRange` header
+/// field (16 × the largest power of two ≤ the count) must fit in a `u16`.
+pub const MAX_TABLES: u16 = 4095;
+
+/// Upper bound on the unwrapped font size, against decompression bombs.
+pub(crate) const MAX_DECOMPRESSED: usize = 64 << 20;
+
+/// Check a WOFF or WOFF2 header's table count before trusting it.
+pub(crate) fn check_num_tables(num_tables: u16) -> Result<(), ParseError> {
+    if num_tables == 0 || num_tables > MAX_TABLES {
+        return Err(ParseError::LengthOutOfRange("font table count"));
+    }
+    Ok(())
+}
+
+/// Detect the container format of font file `data`.
+pub fn detect_format(data: &[u8]) -> FontFormat {
+    match data.get(..4) {
+        Some([0, 1, 0, 0] | b"OTTO" | b"true") => FontFormat::Sfnt,
+        Some(b"wOFF") => FontFormat::Woff,
+        Some(b"wOF2") => FontFormat::Woff2,
+        _ => FontFormat::Unknown,
+    }
+}
+
+/// The sfnt data of font file `data`, unwrapping WOFF and WOFF2.
+pub fn decode(data: &[u8]) -> Result<Vec<u8>, ParseError> {
+    match detect_format(data) {
+        FontFormat::Sfnt => Ok(data.to_vec()),
+        FontFormat::Woff => decode_woff(data),
+        FontFormat::Woff2 => crate::woff2::decode_woff2(data),
+        FontFormat::Unknown => Err(ParseError::InvalidValue("unknown font format")),
+    }
+}
+
+/// Unwrap a WOFF 1.0 file: each table is stored as is or zlib-compressed.
+pub fn decode_woff(data: &[u8]) -> Result<Vec<u8>, ParseError> {
+    let mut c = Cursor::new(data, Endian::Big);
+    if c.u32()? != u32::from_be_bytes(*b"wOFF") {
+        return Err(ParseError::InvalidValue("not a WOFF file"));
+    }
+    let flavor = c.u32()?;
+    let _length = c.u32()?;
+    let num_tables = c.u16()?;
+    check_num_tables(num_tables)?;
+    c.skip(2)?; // reserved
+    let _total_sfnt_size = c.u32()?;
+    c.skip(24)?; // version, metadata and private data blocks
+
+    let mut tables = Vec::with_capacity(num_tables as usize);
+    let mut total_len = 0usize;
+    for _ in 0..num_tables {
+        let tag = TableTag(c.bytes(4)?.try_into().map_err(|_| ParseError::UnexpectedEof)?);
+        let offset = c.u32()? as usize;
+        let compressed_len = c.u32()? as usize;
+        let original_len = c.u32()? as usize;
+        let _checksum = c.u32()?;
+        total_len = total_len.saturating_add(original_len);
+        if total_len > MAX_DECOMPRESSED {
+            return Err(ParseError::LengthOutOfRange("WOFF decompressed size"));
+        }
+        let stored = data
+            .get(offset..offset.saturating_add(compressed_len))
+            .ok_or(ParseError::LengthOutOfRange("WOFF table"))?;
+        let table = if compressed_len < original_len {
+            // zlib: skip the two-byte header; the Adler-32 trailer is ignored.
+            let deflated = stored.get(2..).ok_or(ParseError::UnexpectedEof)?;
+            image_decode::deflate::inflate_limited(deflated, original_len)?
+        } else {
+            stored.to_vec()
+        };
+        if table.len() != original_len {
+            return Err(ParseError::LengthOutOfRange("WOFF table length"));
+        }
+        tables.push((tag, table));
+    }
+    Ok(build_sfnt(flavor, tables))
+}
+
+/// Assemble an sfnt file from its flavor (`0x00010000` or `OTTO`) and
+/// tables, sorting the table directory and padding each table to four
+/// bytes. There must be 1 to [`MAX_TABLES`] tables.
+pub fn build_sfnt(flavor: u32, mut tables: Vec<(TableTag, Vec<u8>)>) -> Vec<u8> {
+    tables.sort_by_key(|(tag, _)| tag.0);
+    debug_assert!((1..=MAX_TABLES as usize).contains(&tables.len()));
+    let num_tables = tables.len() as u32;
+    let entry_selector = num_tables.max(1).ilog2();
+    let search_range = (1 << entry_selector) * 16;
+
+    let mut w = BufWriter::new(Endian::Big);
+    w.u32(flavor);
+    w.u16(num_tables as u16);
+    w.u16(search_range as u16);
+    w.u16(entry_selector as u16);
+    w.u16((num_tables * 16).saturating_sub(search_range) as u16);
+    let mut offset = 12 + 16 * tables.len();
+    for (tag, table) in &tables {
+        w.bytes(&tag.0);
+        w.u32(table_checksum(table));
+        w.u32(offset as u32);
+        w.u32(table.len() as u32);
+        offset += table.len().next_multiple_of(4);
+    }
+    for (_, table) in &tables {
+        w.bytes(table);
+        w.pad4();
+    }
+    w.finish()
+}
+
+/// The sfnt checksum of a table: the sum of its big-endian `u32`s.
+fn table_checksum(table: &[u8]) -> u32 {
+    table.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::{FontFile, HeadTable};
+
+    /// A WOFF file holding `tables`, each stored uncompressed.
+    fn woff(tables: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+        let mut w = BufWriter::new(Endian::Big);
+        w.bytes(b"wOFF");
+        w.u32(0x0001_0000);
+        w.u32(0);
+        w.u16(tables.len() as u16);
+        w.u16(0);
+        w.u32(1000);
+        w.bytes(&[0; 24]);
+        let mut offset = 44 + 20 * tables.len();
+        for (tag, data) in tables {
+            w.bytes(*tag);
+            w.u32(offset as u32);
+            w.u32(data.len() as u32);
+            w.u32(data.len() as u32);
+            w.u32(0);
+            offset += data.len().next_multiple_of(4);
+        }
+        for (_, data) in tables {
+            w.bytes(data);
+            w.pad4();
+        }
+        w.finish()
+    }
+
+    #[test]
+    fn woff_tables_come_back_as_sfnt() {
+        let mut head = vec![0u8; 54];
+        head[18..20].copy_from_slice(&2048u16.to_be_bytes()); // unitsPerEm
+        let file = woff(&[(b"name", b"abc"), (b"head", &head)]);
+        assert_eq!(detect_format(&file), FontFormat::Woff);
+
+        let sfnt = decode(&file).unwrap();
+        assert_eq!(detect_format(&sfnt), FontFormat::Sfnt);
+        let font = FontFile::parse(&sfnt).unwrap();
+        let tags: Vec<TableTag> = font.tables.iter().map(|t| t.tag).collect();
+        assert_eq!(tags, [TableTag::HEAD, TableTag::NAME]);
+        assert_eq!(font.table_data(TableTag::NAME), Some(&b"abc"[..]));
+        assert_eq!(HeadTable::parse(font.table_data(TableTag::HEAD).unwrap()).unwrap().units_per_em, 2048);
+        assert!(decode(b"GIF89a").is_err());
+    }
+
+    #[test]
+    fn woff_rejects_bad_table_counts() {
+        assert_eq!(decode(&woff(&[])), Err(ParseError::LengthOutOfRange("font table count")));
+        let mut file = woff(&[(b"name", b"abc")]);
+        file[12..14].copy_from_slice(&4096u16.to_be_bytes());
+        assert_eq!(decode(&file), Err(ParseError::LengthOutOfRange("font table count")));
+
+        let tables: Vec<_> = (0..MAX_TABLES)
+            .map(|i| (TableTag([b'a', b'a', (i >> 8) as u8, i as u8]), vec![0]))
+            .collect();
+        let sfnt = build_sfnt(0x0001_0000, tables);
+        assert_eq!(&sfnt[4..12], &[0x0f, 0xff, 0x80, 0x00, 0x00, 0x0b, 0x7f, 0xf0]);
+    }
+
+    #[test]
+    fn woff_caps_inflated_tables_at_their_length() {
+        // 259 × 'a' deflated, with a zlib header: 8 bytes in all.
+        let zlib = [0x78, 0x9c, 0x4b, 0x4c, 0x1c, 0xf1, 0x00, 0x00];
+        let mut file = woff(&[(b"name", &zlib)]);
+        file[44 + 12..44 + 16].copy_from_slice(&100u32.to_be_bytes());
+        assert_eq!(decode(&file), Err(ParseError::LengthOutOfRange("DEFLATE output")));
+
+        file[44 + 12..44 + 16].copy_from_slice(&259u32.to_be_bytes());
+        let sfnt = decode(&file).unwrap();
+        let font = FontFile::parse(&sfnt).unwrap();
+        assert_eq!(font.table_data(TableTag::NAME), Some(&[b'a'; 259][..]));
+
+        // Shorter than claimed is just as wrong.
+        file[44 + 12..44 + 16].copy_from_slice(&260u32.to_be_bytes());
+        assert_eq!(decode(&file), Err(ParseError::LengthOutOfRange("WOFF table length")));
+    }
+}
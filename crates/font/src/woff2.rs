@@ -0,0 +1,609 @@
+//! WOFF 2.0 decoding.
+//!
+//! A WOFF2 file holds one Brotli stream with every table concatenated.
+//! The `glyf`/`loca` pair is usually stored transformed: outlines are split
+//! into separate streams of contour counts, point counts, flags, packed
+//! coordinate triplets, composite records, bounding boxes and instructions,
+//! and `loca` is dropped entirely. `hmtx` may omit left side bearings that
+//! equal the glyph's `xMin`. Decoding rebuilds all three tables exactly.
+
+use common::{BufWriter, Cursor, Endian, ParseError};
+
+use crate::brotli;
+use crate::tables::TableTag;
+use crate::woff::{MAX_DECOMPRESSED, build_sfnt, check_num_tables};
+
+/// Tags for the 6-bit table index of a directory entry; 63 means the tag
+/// follows explicitly.
+const KNOWN_TAGS: [&[u8; 4]; 63] = [
+    b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post",
+    b"cvt ", b"fpgm", b"glyf", b"loca", b"prep", b"CFF ", b"VORG", b"EBDT",
+    b"EBLC", b"gasp", b"hdmx", b"kern", b"LTSH", b"PCLT", b"VDMX", b"vhea",
+    b"vmtx", b"BASE", b"GDEF", b"GPOS", b"GSUB", b"EBSC", b"JSTF", b"MATH",
+    b"CBDT", b"CBLC", b"COLR", b"CPAL", b"SVG ", b"sbix", b"acnt", b"avar",
+    b"bdat", b"bloc", b"bsln", b"cvar", b"fdsc", b"feat", b"fmtx", b"fvar",
+    b"gvar", b"hsty", b"just", b"lcar", b"mort", b"morx", b"opbd", b"prop",
+    b"trak", b"Zapf", b"Silf", b"Glat", b"Gloc", b"Feat", b"Sill",
+];
+
+/// One entry of the WOFF2 table directory.
+struct DirectoryEntry {
+    tag: TableTag,
+    orig_length: usize,
+    /// Bytes this table occupies in the decompressed stream.
+    stream_length: usize,
+    transformed: bool,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Container
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Unwrap a WOFF 2.0 file into sfnt data. Font collections are not
+/// supported.
+pub fn decode_woff2(data: &[u8]) -> Result<Vec<u8>, ParseError> {
+    let mut c = Cursor::new(data, Endian::Big);
+    if c.u32()? != u32::from_be_bytes(*b"wOF2") {
+        return Err(ParseError::InvalidValue("not a WOFF2 file"));
+    }
+    let flavor = c.u32()?;
+    if flavor == u32::from_be_bytes(*b"ttcf") {
+        return Err(ParseError::InvalidValue("WOFF2 font collections are not supported"));
+    }
+    let _length = c.u32()?;
+    let num_tables = c.u16()?;
+    check_num_tables(num_tables)?;
+    c.skip(2)?; // reserved
+    let _total_sfnt_size = c.u32()?;
+    let total_compressed_size = c.u32()? as usize;
+    c.skip(24)?; // version, metadata and private data blocks
+
+    let mut entries = Vec::with_capacity(num_tables as usize);
+    for _ in 0..num_tables {
+        let flags = c.u8()?;
+        let tag = match flags & 0x3f {
+            63 => TableTag(c.bytes(4)?.try_into().map_err(|_| ParseError::UnexpectedEof)?),
+            index => TableTag(*KNOWN_TAGS[index as usize]),
+        };
+        let version = flags >> 6;
+        // glyf and loca are transformed by default (version 0) and stored
+        // as is with version 3; every other table the other way round.
+        let transformed = if tag == TableTag::GLYF || tag == TableTag::LOCA {
+            version == 0
+        } else {
+            version != 0
+        };
+        let orig_length = read_base128(&mut c)? as usize;
+        let stream_length = if transformed { read_base128(&mut c)? as usize } else { orig_length };
+        entries.push(DirectoryEntry { tag, orig_length, stream_length, transformed });
+    }
+
+    let compressed = data
+        .get(c.position()..c.position().saturating_add(total_compressed_size))
+        .ok_or(ParseError::LengthOutOfRange("WOFF2 compressed data"))?;
+    let expected: usize = entries.iter().map(|e| e.stream_length).sum();
+    if expected > MAX_DECOMPRESSED {
+        return Err(ParseError::LengthOutOfRange("WOFF2 decompressed size"));
+    }
+    let stream = brotli::decompress(compressed, expected)?;
+    if stream.len() < expected {
+        return Err(ParseError::UnexpectedEof);
+    }
+
+    let mut raw = Vec::with_capacity(entries.len());
+    let mut offset = 0;
+    for entry in &entries {
+        raw.push(&stream[offset..offset + entry.stream_length]);
+        offset += entry.stream_length;
+    }
+    let find = |tag: TableTag| entries.iter().position(|e| e.tag == tag);
+
+    let mut tables: Vec<(TableTag, Vec<u8>)> = Vec::with_capacity(entries.len());
+    // Left side bearings of the rebuilt glyphs, for a transformed hmtx.
+    let mut x_mins = None;
+    if let Some(glyf) = find(TableTag::GLYF).filter(|&i| entries[i].transformed) {
+        let loca = find(TableTag::LOCA).ok_or(ParseError::InvalidValue("WOFF2 glyf without loca"))?;
+        let rebuilt = reconstruct_glyf(raw[glyf])?;
+        if rebuilt.loca.len() != entries[loca].orig_length {
+            return Err(ParseError::LengthOutOfRange("WOFF2 loca length"));
+        }
+        tables.push((TableTag::GLYF, rebuilt.glyf));
+        tables.push((TableTag::LOCA, rebuilt.loca));
+        x_mins = Some(rebuilt.x_mins);
+    }
+    for (entry, data) in entries.iter().zip(&raw) {
+        if tables.iter().any(|(tag, _)| *tag == entry.tag) {
+            continue;
+        }
+        let table = if !entry.transformed {
+            data.to_vec()
+        } else if entry.tag == TableTag::HMTX {
+            let x_mins = x_mins.as_deref().ok_or(ParseError::InvalidValue("WOFF2 hmtx without glyf"))?;
+            let hhea = find(TableTag::HHEA).map(|i| raw[i]).ok_or(ParseError::InvalidValue("WOFF2 hmtx without hhea"))?;
+            let num_h_metrics = hhea.get(34..36).ok_or(ParseError::UnexpectedEof)?;
+            reconstruct_hmtx(data, u16::from_be_bytes([num_h_metrics[0], num_h_metrics[1]]), x_mins)?
+        } else {
+            return Err(ParseError::InvalidValue("WOFF2: unknown table transform"));
+        };
+        if table.len() != entry.orig_length {
+            return Err(ParseError::LengthOutOfRange("WOFF2 table length"));
+        }
+        tables.push((entry.tag, table));
+    }
+    Ok(build_sfnt(flavor, tables))
+}
+
+/// A `UIntBase128`: big-endian groups of seven bits, at most five bytes.
+fn read_base128(c: &mut Cursor) -> Result<u32, ParseError> {
+    let mut value: u32 = 0;
+    for i in 0..5 {
+        let byte = c.u8()?;
+        if i == 0 && byte == 0x80 {
+            return Err(ParseError::InvalidValue("UIntBase128 leading zero"));
+        }
+        if value & 0xfe00_0000 != 0 {
+            return Err(ParseError::LengthOutOfRange("UIntBase128 overflow"));
+        }
+        value = (value << 7) | (byte & 0x7f) as u32;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(ParseError::LengthOutOfRange("UIntBase128 too long"))
+}
+
+/// A `255UInt16`: one byte, or an escape code and one or two more.
+fn read_255_u16(c: &mut Cursor) -> Result<u16, ParseError> {
+    Ok(match c.u8()? {
+        253 => c.u16()?,
+        254 => c.u8()? as u16 + 253 * 2,
+        255 => c.u8()? as u16 + 253,
+        code => code as u16,
+    })
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// glyf / loca
+// ─────────────────────────────────────────────────────────────────────────────
+
+const ON_CURVE: u8 = 0x01;
+const X_SHORT: u8 = 0x02;
+const Y_SHORT: u8 = 0x04;
+const REPEAT: u8 = 0x08;
+const X_SAME_OR_POSITIVE: u8 = 0x10;
+const Y_SAME_OR_POSITIVE: u8 = 0x20;
+const OVERLAP_SIMPLE: u8 = 0x40;
+
+const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+const WE_HAVE_A_SCALE: u16 = 0x0008;
+const MORE_COMPONENTS: u16 = 0x0020;
+const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+const WE_HAVE_INSTRUCTIONS: u16 = 0x0100;
+
+/// The rebuilt `glyf` and `loca` tables.
+struct Glyf {
+    glyf: Vec<u8>,
+    loca: Vec<u8>,
+    x_mins: Vec<i16>,
+}
+
+/// The streams of a transformed `glyf` table.
+struct GlyfStreams<'a> {
+    n_contours: Cursor<'a>,
+    n_points: Cursor<'a>,
+    flags: Cursor<'a>,
+    glyphs: Cursor<'a>,
+    composites: Cursor<'a>,
+    bbox_bitmap: &'a [u8],
+    bboxes: Cursor<'a>,
+    instructions: Cursor<'a>,
+    overlap_bitmap: Option<&'a [u8]>,
+}
+
+fn bit_set(bitmap: &[u8], index: usize) -> bool {
+    bitmap.get(index >> 3).is_some_and(|byte| byte & (0x80 >> (index & 7)) != 0)
+}
+
+fn reconstruct_glyf(data: &[u8]) -> Result<Glyf, ParseError> {
+    let mut c = Cursor::new(data, Endian::Big);
+    c.skip(2)?; // reserved
+    let option_flags = c.u16()?;
+    let num_glyphs = c.u16()? as usize;
+    let index_format = c.u16()?;
+    let mut sizes = [0usize; 7];
+    for size in &mut sizes {
+        *size = c.u32()? as usize;
+    }
+    let mut next = |len: usize| c.bytes(len).map(|b| Cursor::new(b, Endian::Big));
+    let n_contours = next(sizes[0])?;
+    let n_points = next(sizes[1])?;
+    let flags = next(sizes[2])?;
+    let glyphs = next(sizes[3])?;
+    let composites = next(sizes[4])?;
+    let mut bbox = next(sizes[5])?;
+    let instructions = next(sizes[6])?;
+    let bbox_bitmap = bbox.bytes(num_glyphs.div_ceil(32) * 4)?;
+    let overlap_bitmap = if option_flags & 1 != 0 { Some(c.bytes(num_glyphs.div_ceil(8))?) } else { None };
+    let mut s = GlyfStreams {
+        n_contours, n_points, flags, glyphs, composites,
+        bbox_bitmap, bboxes: bbox, instructions, overlap_bitmap,
+    };
+
+    let mut glyf = BufWriter::new(Endian::Big);
+    let mut loca = BufWriter::new(Endian::Big);
+    let mut x_mins = Vec::with_capacity(num_glyphs);
+    let write_offset = |loca: &mut BufWriter, offset: usize| -> Result<(), ParseError> {
+        if index_format == 0 {
+            let half = u16::try_from(offset / 2).map_err(|_| ParseError::LengthOutOfRange("short loca offset"))?;
+            loca.u16(half);
+        } else {
+            loca.u32(offset as u32);
+        }
+        Ok(())
+    };
+    for index in 0..num_glyphs {
+        write_offset(&mut loca, glyf.len())?;
+        let x_min = write_glyph(&mut s, index, &mut glyf)?;
+        x_mins.push(x_min);
+        glyf.pad4();
+    }
+    write_offset(&mut loca, glyf.len())?;
+    Ok(Glyf { glyf: glyf.finish(), loca: loca.finish(), x_mins })
+}
+
+/// Rebuild glyph `index` into `out`, returning its `xMin`.
+fn write_glyph(s: &mut GlyfStreams, index: usize, out: &mut BufWriter) -> Result<i16, ParseError> {
+    let n_contours = s.n_contours.i16()?;
+    let explicit_bbox = bit_set(s.bbox_bitmap, index);
+    let read_bbox = |s: &mut GlyfStreams| -> Result<[i16; 4], ParseError> {
+        Ok([s.bboxes.i16()?, s.bboxes.i16()?, s.bboxes.i16()?, s.bboxes.i16()?])
+    };
+
+    if n_contours == 0 {
+        if explicit_bbox {
+            return Err(ParseError::InvalidValue("WOFF2: bounding box on an empty glyph"));
+        }
+        return Ok(0);
+    }
+
+    if n_contours < 0 {
+        if !explicit_bbox {
+            return Err(ParseError::InvalidValue("WOFF2: composite glyph without bounding box"));
+        }
+        let bbox = read_bbox(s)?;
+        // Walk the component records to find where they end.
+        let start = s.composites.position();
+        let mut has_instructions = false;
+        loop {
+            let flags = s.composites.u16()?;
+            s.composites.skip(2)?; // glyph index
+            let mut len = if flags & ARG_1_AND_2_ARE_WORDS != 0 { 4 } else { 2 };
+            if flags & WE_HAVE_A_SCALE != 0 {
+                len += 2;
+            } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+                len += 4;
+            } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+                len += 8;
+            }
+            s.composites.skip(len)?;
+            has_instructions |= flags & WE_HAVE_INSTRUCTIONS != 0;
+            if flags & MORE_COMPONENTS == 0 {
+                break;
+            }
+        }
+        let records = &s.composites.buffer()[start..s.composites.position()];
+        out.u16(n_contours as u16);
+        for v in bbox {
+            out.u16(v as u16);
+        }
+        out.bytes(records);
+        if has_instructions {
+            let len = read_255_u16(&mut s.glyphs)?;
+            out.u16(len);
+            out.bytes(s.instructions.bytes(len as usize)?);
+        }
+        return Ok(bbox[0]);
+    }
+
+    let mut end_points = Vec::with_capacity(n_contours as usize);
+    let mut total: usize = 0;
+    for _ in 0..n_contours {
+        total += read_255_u16(&mut s.n_points)? as usize;
+        let end = total.checked_sub(1).and_then(|e| u16::try_from(e).ok());
+        end_points.push(end.ok_or(ParseError::LengthOutOfRange("WOFF2 contour points"))?);
+    }
+
+    let mut points = Vec::with_capacity(total);
+    let (mut x, mut y) = (0i32, 0i32);
+    for _ in 0..total {
+        let flag = s.flags.u8()?;
+        let (dx, dy) = decode_triplet(flag & 0x7f, &mut s.glyphs)?;
+        x += dx;
+        y += dy;
+        points.push((x, y, flag & 0x80 == 0));
+    }
+    let instruction_len = read_255_u16(&mut s.glyphs)?;
+
+    let bbox = if explicit_bbox {
+        read_bbox(s)?
+    } else {
+        let clamp = |v: i32| v.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        let (x_min, x_max) = points.iter().fold((i32::MAX, i32::MIN), |(lo, hi), p| (lo.min(p.0), hi.max(p.0)));
+        let (y_min, y_max) = points.iter().fold((i32::MAX, i32::MIN), |(lo, hi), p| (lo.min(p.1), hi.max(p.1)));
+        [clamp(x_min), clamp(y_min), clamp(x_max), clamp(y_max)]
+    };
+
+    out.u16(n_contours as u16);
+    for v in bbox {
+        out.u16(v as u16);
+    }
+    for end in end_points {
+        out.u16(end);
+    }
+    out.u16(instruction_len);
+    out.bytes(s.instructions.bytes(instruction_len as usize)?);
+
+    // Flags, with runs of equal flags folded into REPEAT counts.
+    let overlap = s.overlap_bitmap.is_some_and(|bitmap| bit_set(bitmap, index));
+    let mut xs = BufWriter::new(Endian::Big);
+    let mut ys = BufWriter::new(Endian::Big);
+    let mut flags: Vec<u8> = Vec::with_capacity(total);
+    let mut last_flag: Option<usize> = None;
+    let mut repeat = 0u8;
+    let (mut last_x, mut last_y) = (0i32, 0i32);
+    for (i, &(x, y, on_curve)) in points.iter().enumerate() {
+        let mut flag = if on_curve { ON_CURVE } else { 0 };
+        if i == 0 && overlap {
+            flag |= OVERLAP_SIMPLE;
+        }
+        flag |= encode_delta(x - last_x, X_SHORT, X_SAME_OR_POSITIVE, &mut xs);
+        flag |= encode_delta(y - last_y, Y_SHORT, Y_SAME_OR_POSITIVE, &mut ys);
+        (last_x, last_y) = (x, y);
+
+        match last_flag {
+            Some(at) if flags[at] & !REPEAT == flag && repeat < 255 => {
+                flags[at] |= REPEAT;
+                repeat += 1;
+            }
+            _ => {
+                if repeat > 0 {
+                    flags.push(repeat);
+                    repeat = 0;
+                }
+                flags.push(flag);
+                last_flag = Some(flags.len() - 1);
+            }
+        }
+    }
+    if repeat > 0 {
+        flags.push(repeat);
+    }
+    out.bytes(&flags);
+    out.bytes(&xs.finish());
+    out.bytes(&ys.finish());
+    Ok(bbox[0])
+}
+
+/// Write coordinate delta `d` in the smallest TrueType form, returning the
+/// flag bits that describe it.
+fn encode_delta(d: i32, short: u8, same_or_positive: u8, out: &mut BufWriter) -> u8 {
+    if d == 0 {
+        same_or_positive
+    } else if (-255..=255).contains(&d) {
+        out.u8(d.unsigned_abs() as u8);
+        short | if d > 0 { same_or_positive } else { 0 }
+    } else {
+        out.u16(d as i16 as u16);
+        0
+    }
+}
+
+/// Decode one packed point delta: `flag` (high bit already removed) picks
+/// how many bytes of `glyphs` hold it and how they split between x and y.
+fn decode_triplet(flag: u8, glyphs: &mut Cursor) -> Result<(i32, i32), ParseError> {
+    let with_sign = |flag: u8, base: i32| if flag & 1 != 0 { base } else { -base };
+    let flag_i = flag as i32;
+    Ok(if flag < 10 {
+        let b0 = glyphs.u8()? as i32;
+        (0, with_sign(flag, ((flag_i & 14) << 7) + b0))
+    } else if flag < 20 {
+        let b0 = glyphs.u8()? as i32;
+        (with_sign(flag, (((flag_i - 10) & 14) << 7) + b0), 0)
+    } else if flag < 84 {
+        let b0 = flag_i - 20;
+        let b1 = glyphs.u8()? as i32;
+        (
+            with_sign(flag, 1 + (b0 & 0x30) + (b1 >> 4)),
+            with_sign(flag >> 1, 1 + ((b0 & 0x0c) << 2) + (b1 & 0x0f)),
+        )
+    } else if flag < 120 {
+        let b0 = flag_i - 84;
+        let (b1, b2) = (glyphs.u8()? as i32, glyphs.u8()? as i32);
+        (
+            with_sign(flag, 1 + ((b0 / 12) << 8) + b1),
+            with_sign(flag >> 1, 1 + (((b0 % 12) >> 2) << 8) + b2),
+        )
+    } else if flag < 124 {
+        let (b1, b2, b3) = (glyphs.u8()? as i32, glyphs.u8()? as i32, glyphs.u8()? as i32);
+        (with_sign(flag, (b1 << 4) + (b2 >> 4)), with_sign(flag >> 1, ((b2 & 0x0f) << 8) + b3))
+    } else {
+        let (x, y) = (glyphs.u16()? as i32, glyphs.u16()? as i32);
+        (with_sign(flag, x), with_sign(flag >> 1, y))
+    })
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// hmtx
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Rebuild a transformed `hmtx`, taking omitted left side bearings from the
+/// glyphs' `xMin`.
+fn reconstruct_hmtx(data: &[u8], num_h_metrics: u16, x_mins: &[i16]) -> Result<Vec<u8>, ParseError> {
+    let mut c = Cursor::new(data, Endian::Big);
+    let flags = c.u8()?;
+    let num_h_metrics = num_h_metrics as usize;
+    if num_h_metrics == 0 || num_h_metrics > x_mins.len() {
+        return Err(ParseError::InvalidValue("WOFF2 hmtx metrics count"));
+    }
+    let mut advances = Vec::with_capacity(num_h_metrics);
+    for _ in 0..num_h_metrics {
+        advances.push(c.u16()?);
+    }
+    let mut lsbs = Vec::with_capacity(x_mins.len());
+    for (i, &x_min) in x_mins.iter().enumerate() {
+        let omitted = if i < num_h_metrics { flags & 1 != 0 } else { flags & 2 != 0 };
+        lsbs.push(if omitted { x_min } else { c.i16()? });
+    }
+
+    let mut w = BufWriter::new(Endian::Big);
+    for (i, lsb) in lsbs.into_iter().enumerate() {
+        if let Some(&advance) = advances.get(i) {
+            w.u16(advance);
+        }
+        w.u16(lsb as u16);
+    }
+    Ok(w.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::glyph::{parse_glyph, GlyphDesc};
+    use crate::tables::{get_glyph_offset, get_hmetric, FontFile};
+
+    /// Wrap `data` in an uncompressed Brotli stream.
+    fn stored_brotli(data: &[u8]) -> Vec<u8> {
+        assert!(!data.is_empty() && data.len() <= 1 << 16);
+        let mut bits: Vec<(u32, u32)> = vec![(0, 1)]; // 16-bit window
+        bits.push((0, 1)); // ISLAST = 0
+        bits.push((0, 2)); // four nibbles of length
+        bits.push(((data.len() - 1) as u32, 16));
+        bits.push((1, 1)); // ISUNCOMPRESSED
+        let mut out = Vec::new();
+        let (mut acc, mut n) = (0u64, 0);
+        for (value, count) in bits {
+            acc |= (value as u64) << n;
+            n += count;
+        }
+        while n > 0 {
+            out.push(acc as u8);
+            acc >>= 8;
+            n = n.saturating_sub(8);
+        }
+        out.extend_from_slice(data);
+        out.extend_from_slice(&[0b11]); // ISLAST, ISLASTEMPTY
+        out
+    }
+
+    fn base128(w: &mut BufWriter, v: u32) {
+        let mut groups = vec![(v & 0x7f) as u8];
+        let mut v = v >> 7;
+        while v > 0 {
+            groups.push((v & 0x7f) as u8 | 0x80);
+            v >>= 7;
+        }
+        groups.reverse();
+        w.bytes(&groups);
+    }
+
+    /// A transformed glyf holding an empty glyph and a triangle.
+    fn transformed_glyf() -> Vec<u8> {
+        // Points (0,0) on, (100,200) off and (-300,200) on: one-byte dy,
+        // two one-byte deltas, then a one-byte dx of -(256 + 144).
+        let n_contours = [0u8, 0, 0, 1];
+        let n_points = [3u8];
+        let flags = [0u8, 0x80 | 87, 12];
+        let glyphs = [0u8, 99, 199, 144, 0];
+        let mut w = BufWriter::new(Endian::Big);
+        w.u16(0);
+        w.u16(0);
+        w.u16(2); // numGlyphs
+        w.u16(0); // short loca
+        let bbox_bitmap = [0u8; 4];
+        for len in [n_contours.len(), n_points.len(), flags.len(), glyphs.len(), 0, bbox_bitmap.len(), 0] {
+            w.u32(len as u32);
+        }
+        w.bytes(&n_contours);
+        w.bytes(&n_points);
+        w.bytes(&flags);
+        w.bytes(&glyphs);
+        w.bytes(&bbox_bitmap);
+        w.finish()
+    }
+
+    #[test]
+    fn triplets_cover_every_form() {
+        let mut c = Cursor::new(&[5, 0x21, 1, 2, 0x12, 0x34, 0x01, 0x02, 0x03, 0x04], Endian::Big);
+        assert_eq!(decode_triplet(1, &mut c).unwrap(), (0, 5));
+        assert_eq!(decode_triplet(20 + 3, &mut c).unwrap(), (3, 2));
+        assert_eq!(decode_triplet(84, &mut c).unwrap(), (-2, -3));
+        assert_eq!(decode_triplet(120, &mut c).unwrap(), (-0x123, -0x401));
+        let mut c = Cursor::new(&[0x01, 0x02, 0x03, 0x04], Endian::Big);
+        assert_eq!(decode_triplet(127, &mut c).unwrap(), (0x102, 0x304));
+    }
+
+    #[test]
+    fn transformed_glyf_and_hmtx_are_rebuilt() {
+        let glyf = transformed_glyf();
+        let mut hhea = vec![0u8; 36];
+        hhea[34..36].copy_from_slice(&1u16.to_be_bytes()); // numberOfHMetrics
+        // One full metric with its lsb omitted, then one bare lsb.
+        let hmtx = [0x01u8, 0x02, 0x58, 0x00, 0x07];
+        let mut head = vec![0u8; 54];
+        head[18..20].copy_from_slice(&1000u16.to_be_bytes());
+
+        let loca_len = 6u32;
+        let hmtx_len = 4 + 2;
+        let mut stream = Vec::new();
+        stream.extend_from_slice(&head);
+        stream.extend_from_slice(&hhea);
+        stream.extend_from_slice(&hmtx);
+        stream.extend_from_slice(&glyf);
+        let compressed = stored_brotli(&stream);
+
+        let mut w = BufWriter::new(Endian::Big);
+        w.bytes(b"wOF2");
+        w.u32(0x0001_0000);
+        w.u32(0);
+        w.u16(5);
+        w.u16(0);
+        w.u32(0);
+        w.u32(compressed.len() as u32);
+        w.bytes(&[0; 24]);
+        w.u8(1); // head
+        base128(&mut w, head.len() as u32);
+        w.u8(2); // hhea
+        base128(&mut w, hhea.len() as u32);
+        w.u8(3 | 0x40); // hmtx, transformed
+        base128(&mut w, hmtx_len);
+        base128(&mut w, hmtx.len() as u32);
+        w.u8(10); // glyf, transformed
+        base128(&mut w, 0);
+        base128(&mut w, glyf.len() as u32);
+        w.u8(11); // loca, transformed
+        base128(&mut w, loca_len);
+        base128(&mut w, 0);
+        w.bytes(&compressed);
+        let file = w.finish();
+
+        let sfnt = crate::woff::decode(&file).unwrap();
+        let font = FontFile::parse(&sfnt).unwrap();
+        let loca = font.table_data(TableTag::LOCA).unwrap();
+        let glyf = font.table_data(TableTag::GLYF).unwrap();
+        assert_eq!(get_glyph_offset(loca, 0, 0).unwrap(), (0, 0));
+        let (start, end) = get_glyph_offset(loca, 1, 0).unwrap();
+        let GlyphDesc::Simple(outline) = parse_glyph(&glyf[start as usize..end as usize]).unwrap() else {
+            panic!("expected a simple glyph");
+        };
+        let points: Vec<(i32, i32, bool)> =
+            outline.contours[0].points.iter().map(|p| (p.x, p.y, p.on_curve)).collect();
+        assert_eq!(points, [(0, 0, true), (100, 200, false), (-300, 200, true)]);
+        assert_eq!((outline.x_min, outline.y_min, outline.x_max, outline.y_max), (-300, 0, 100, 200));
+
+        let hmtx = font.table_data(TableTag::HMTX).unwrap();
+        assert_eq!(get_hmetric(hmtx, 0, 1).unwrap().advance_width, 600);
+        assert_eq!(get_hmetric(hmtx, 0, 1).unwrap().left_side_bearing, 0);
+        assert_eq!(get_hmetric(hmtx, 1, 1).unwrap().left_side_bearing, 7);
+    }
+}
@@ -0,0 +1,731 @@
+//! Runs html5lib-format tests: the tokenizer suites (`tokenizer/*.test`,
+//! JSON token streams) and the tree construction suites
+//! (`tree-construction/*.dat`, DOM dumps). Each test prints its pass rate
+//! per file.
+//!
+//! `html5lib/local/` holds this crate's own cases, which must all pass.
+//! Upstream html5lib-tests at `html5lib/upstream/` is run too if present.
+//! Its cases must pass unless listed in
+//! `html5lib/expected-failures/<suite>.txt`, and a listed case that passes
+//! fails the test until it is taken off the list. Running with
+//! `HTML5LIB_BLESS=1` rewrites the lists from the current results.
+
+use crate::token::HtmlToken;
+use crate::tokenizer::{TextMode, Tokenizer};
+use crate::tree_builder::{parse_fragment_in, ParseStatus, Parser};
+use dom::node::{Namespace, NodeData, NodeId};
+use dom::Dom;
+use std::collections::BTreeSet;
+
+// ---------------------------------------------------------------------------
+// Minimal JSON reader
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number,
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn str(&self, key: &str) -> Option<&str> {
+        match self.get(key) {
+            Some(Json::Str(s)) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+struct Reader<'a> {
+    src: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn parse(src: &str) -> Json {
+        let mut reader = Reader {
+            src: src.as_bytes(),
+            pos: 0,
+        };
+        reader.value()
+    }
+
+    fn skip_ws(&mut self) {
+        while self.src.get(self.pos).is_some_and(u8::is_ascii_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, b: u8) -> bool {
+        self.skip_ws();
+        if self.src.get(self.pos) == Some(&b) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn value(&mut self) -> Json {
+        self.skip_ws();
+        match self.src[self.pos] {
+            b'{' => {
+                self.pos += 1;
+                let mut members = Vec::new();
+                while !self.eat(b'}') {
+                    self.eat(b',');
+                    self.skip_ws();
+                    let key = self.string();
+                    assert!(self.eat(b':'), "expected ':' at {}", self.pos);
+                    members.push((key, self.value()));
+                }
+                Json::Object(members)
+            }
+            b'[' => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                while !self.eat(b']') {
+                    self.eat(b',');
+                    items.push(self.value());
+                }
+                Json::Array(items)
+            }
+            b'"' => Json::Str(self.string()),
+            b'n' => self.keyword("null", Json::Null),
+            b't' => self.keyword("true", Json::Bool(true)),
+            b'f' => self.keyword("false", Json::Bool(false)),
+            _ => {
+                while self
+                    .src
+                    .get(self.pos)
+                    .is_some_and(|b| b"+-.eE0123456789".contains(b))
+                {
+                    self.pos += 1;
+                }
+                Json::Number
+            }
+        }
+    }
+
+    fn keyword(&mut self, word: &str, value: Json) -> Json {
+        assert!(self.src[self.pos..].starts_with(word.as_bytes()));
+        self.pos += word.len();
+        value
+    }
+
+    fn hex4(&mut self) -> u32 {
+        let digits = std::str::from_utf8(&self.src[self.pos..self.pos + 4]).unwrap();
+        self.pos += 4;
+        u32::from_str_radix(digits, 16).unwrap()
+    }
+
+    /// A string literal. Returns `None` for strings holding a lone
+    /// surrogate, which a Rust string cannot represent.
+    fn string_or_surrogate(&mut self) -> Option<String> {
+        assert_eq!(self.src[self.pos], b'"');
+        self.pos += 1;
+        let mut bytes = Vec::new();
+        let mut valid = true;
+        loop {
+            let b = self.src[self.pos];
+            self.pos += 1;
+            match b {
+                b'"' => break,
+                b'\\' => {
+                    let e = self.src[self.pos];
+                    self.pos += 1;
+                    let c = match e {
+                        b'n' => '\n',
+                        b't' => '\t',
+                        b'r' => '\r',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'u' => {
+                            let unit = self.hex4();
+                            let cp = if (0xD800..0xDC00).contains(&unit)
+                                && self.src[self.pos..].starts_with(b"\\u")
+                            {
+                                let save = self.pos;
+                                self.pos += 2;
+                                let low = self.hex4();
+                                if (0xDC00..0xE000).contains(&low) {
+                                    0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00)
+                                } else {
+                                    self.pos = save;
+                                    unit
+                                }
+                            } else {
+                                unit
+                            };
+                            char::from_u32(cp).unwrap_or_else(|| {
+                                valid = false;
+                                '\u{FFFD}'
+                            })
+                        }
+                        other => other as char,
+                    };
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                }
+                _ => bytes.push(b),
+            }
+        }
+        valid.then(|| String::from_utf8(bytes).unwrap())
+    }
+
+    fn string(&mut self) -> String {
+        self.string_or_surrogate()
+            .expect("lone surrogates only appear in doubleEscaped tests")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Pass rates
+// ---------------------------------------------------------------------------
+
+/// Results for one test file.
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    skipped: usize,
+    failures: Vec<Failure>,
+}
+
+/// A failed case: `case` names it within its file (`12`, or
+/// `3 (RCDATA state)` for a tokenizer test run from one of its initial
+/// states), and `detail` shows the difference.
+struct Failure {
+    case: String,
+    detail: String,
+}
+
+impl Tally {
+    fn fail(&mut self, case: String, detail: String) {
+        self.failures.push(Failure { case, detail });
+    }
+
+    fn report(&self, file: &str) {
+        let total = self.passed + self.failures.len();
+        println!(
+            "{file}: {}/{total} passed ({:.1}%), {} skipped",
+            self.passed,
+            100.0 * self.passed as f64 / total.max(1) as f64,
+            self.skipped
+        );
+    }
+}
+
+/// Run `run` over the `suite` files with the extension `ext`, reporting
+/// each file's pass rate. Fails if a local case failed, or if upstream
+/// results differ from the suite's expected failures.
+fn run_suite(suite: &str, ext: &str, run: impl Fn(&str, &mut Tally)) {
+    let root = format!("{}/html5lib", env!("CARGO_MANIFEST_DIR"));
+    let local = suite_files(&format!("{root}/local/{suite}"), ext);
+    assert!(!local.is_empty(), "no local .{ext} files for {suite}");
+    let (passed, failures) = run_files(&format!("local/{suite}"), &local, &run);
+    assert!(
+        failures.is_empty(),
+        "{}/{} failures:\n{}",
+        failures.len(),
+        passed + failures.len(),
+        failures.iter().map(|(id, detail)| format!("{id}: {detail}")).collect::<Vec<_>>().join("\n\n")
+    );
+
+    let upstream = suite_files(&format!("{root}/upstream/{suite}"), ext);
+    if upstream.is_empty() {
+        return;
+    }
+    let (passed, failures) = run_files(suite, &upstream, &run);
+    println!("upstream {suite}: {passed}/{} passed", passed + failures.len());
+
+    let list = format!("{root}/expected-failures/{suite}.txt");
+    if std::env::var_os("HTML5LIB_BLESS").is_some() {
+        let ids: Vec<&str> = failures.iter().map(|(id, _)| id.as_str()).collect();
+        std::fs::write(&list, format!("{EXPECTED_FAILURES_HEADER}{}", ids.join("\n") + "\n")).unwrap();
+        return;
+    }
+    let expected = expected_failures(&std::fs::read_to_string(&list).unwrap_or_default());
+    let (unexpected, fixed) = compare_failures(&failures, &expected);
+    assert!(
+        unexpected.is_empty() && fixed.is_empty(),
+        "upstream {suite} differs from {list}\n\n{} unexpected failures:\n{}\n\n\
+         {} expected failures now pass; take them off the list:\n{}",
+        unexpected.len(),
+        unexpected.join("\n\n"),
+        fixed.len(),
+        fixed.join("\n")
+    );
+}
+
+/// How an expected-failures list starts.
+const EXPECTED_FAILURES_HEADER: &str = "\
+# Upstream html5lib-tests cases this crate is known to fail, one
+# `<suite>/<file>#<case>` per line. See html5lib/README.md.
+";
+
+/// The case ids in an expected-failures list, skipping blank lines and
+/// `#` comments.
+fn expected_failures(list: &str) -> BTreeSet<String> {
+    list.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// The failures that are not expected (with their details), and the
+/// expected failures that did not happen.
+fn compare_failures(failures: &[(String, String)], expected: &BTreeSet<String>) -> (Vec<String>, Vec<String>) {
+    let unexpected = failures
+        .iter()
+        .filter(|(id, _)| !expected.contains(id))
+        .map(|(id, detail)| format!("{id}: {detail}"))
+        .collect();
+    let failed: BTreeSet<&str> = failures.iter().map(|(id, _)| id.as_str()).collect();
+    let fixed = expected.iter().filter(|id| !failed.contains(id.as_str())).cloned().collect();
+    (unexpected, fixed)
+}
+
+/// The files in `dir` with the extension `ext`, sorted; none if `dir`
+/// does not exist.
+fn suite_files(dir: &str, ext: &str) -> Vec<std::path::PathBuf> {
+    let mut files: Vec<_> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|e| e == ext))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Run every file, returning the number of passes and the failures as
+/// `({prefix}/{file name}#{case}, detail)`.
+fn run_files(
+    prefix: &str,
+    files: &[std::path::PathBuf],
+    run: &impl Fn(&str, &mut Tally),
+) -> (usize, Vec<(String, String)>) {
+    let mut failures = Vec::new();
+    let mut passed = 0;
+    for path in files {
+        let name = format!("{prefix}/{}", path.file_name().unwrap().to_string_lossy());
+        let mut tally = Tally::default();
+        run(&std::fs::read_to_string(path).unwrap(), &mut tally);
+        tally.report(&name);
+        passed += tally.passed;
+        failures.extend(tally.failures.into_iter().map(|f| (format!("{name}#{}", f.case), f.detail)));
+    }
+    (passed, failures)
+}
+
+#[test]
+fn expected_failures_catch_regressions_and_fixes() {
+    let list = expected_failures("# comment\n\ntree-construction/tests1.dat#3\ntree-construction/tests1.dat#7\n");
+    let failures = vec![
+        ("tree-construction/tests1.dat#3".to_string(), "still broken".to_string()),
+        ("tree-construction/tests1.dat#5".to_string(), "regressed".to_string()),
+    ];
+    let (unexpected, fixed) = compare_failures(&failures, &list);
+    assert_eq!(unexpected, ["tree-construction/tests1.dat#5: regressed"]);
+    assert_eq!(fixed, ["tree-construction/tests1.dat#7"]);
+    assert_eq!(compare_failures(&failures[..1], &list).0, Vec::<String>::new());
+}
+
+// ---------------------------------------------------------------------------
+// Tokenizer tests
+// ---------------------------------------------------------------------------
+
+/// Undo the extra escaping of `doubleEscaped` tests (`\\uXXXX` sequences
+/// in the decoded strings). Returns `None` if the text has a lone
+/// surrogate.
+fn unescape(text: &str) -> Option<String> {
+    let quoted = format!("\"{}\"", text.replace('"', "\\\""));
+    Reader {
+        src: quoted.as_bytes(),
+        pos: 0,
+    }
+    .string_or_surrogate()
+}
+
+/// A token in the html5lib output format.
+fn token_json(token: &HtmlToken) -> Json {
+    let s = |s: &str| Json::Str(s.to_string());
+    let opt = |o: &Option<String>| o.as_deref().map_or(Json::Null, s);
+    match token {
+        HtmlToken::Doctype {
+            name,
+            public_id,
+            system_id,
+            force_quirks,
+        } => Json::Array(vec![
+            s("DOCTYPE"),
+            opt(name),
+            opt(public_id),
+            opt(system_id),
+            Json::Bool(!force_quirks),
+        ]),
+        HtmlToken::StartTag {
+            name,
+            attrs,
+            self_closing,
+        } => {
+            let mut item = vec![
+                s("StartTag"),
+                s(name),
+                Json::Object(attrs.iter().map(|(n, v)| (n.clone(), s(v))).collect()),
+            ];
+            if *self_closing {
+                item.push(Json::Bool(true));
+            }
+            Json::Array(item)
+        }
+        HtmlToken::EndTag { name } => Json::Array(vec![s("EndTag"), s(name)]),
+        HtmlToken::Comment(data) => Json::Array(vec![s("Comment"), s(data)]),
+        HtmlToken::Character(c) => Json::Array(vec![s("Character"), Json::Str(c.to_string())]),
+        HtmlToken::EOF => Json::Null,
+    }
+}
+
+/// Merge adjacent Character tokens, and sort attributes so that they
+/// compare as a set.
+fn normalize_tokens(tokens: &[Json]) -> Vec<Json> {
+    let mut out: Vec<Json> = Vec::new();
+    for token in tokens {
+        let Json::Array(items) = token else {
+            continue;
+        };
+        if let [Json::Str(kind), Json::Str(text)] = items.as_slice()
+            && kind == "Character"
+            && let Some(Json::Array(last)) = out.last_mut()
+            && let [Json::Str(last_kind), Json::Str(last_text)] = last.as_mut_slice()
+            && last_kind == "Character"
+        {
+            last_text.push_str(text);
+            continue;
+        }
+        let mut items = items.clone();
+        if let Some(Json::Object(attrs)) = items.get_mut(2) {
+            attrs.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+        out.push(Json::Array(items));
+    }
+    out
+}
+
+fn text_mode(state: &str) -> Option<TextMode> {
+    Some(match state {
+        "Data state" => TextMode::Data,
+        "PLAINTEXT state" => TextMode::Plaintext,
+        "RCDATA state" => TextMode::Rcdata,
+        "RAWTEXT state" => TextMode::Rawtext,
+        "Script data state" => TextMode::ScriptData,
+        // "CDATA section state" is only reachable in foreign content.
+        _ => return None,
+    })
+}
+
+fn tokenizer_file(src: &str, tally: &mut Tally) {
+    let root = Reader::parse(src);
+    let Some(Json::Array(tests)) = root.get("tests") else {
+        return;
+    };
+    for (n, test) in tests.iter().enumerate() {
+        let description = test.str("description").unwrap_or("");
+        let double_escaped = test.get("doubleEscaped") == Some(&Json::Bool(true));
+        let mut input = test.str("input").unwrap().to_string();
+        let mut expected = match test.get("output") {
+            Some(Json::Array(output)) => output.clone(),
+            _ => Vec::new(),
+        };
+        if double_escaped {
+            let Some(unescaped) = unescape(&input) else {
+                tally.skipped += 1;
+                continue;
+            };
+            input = unescaped;
+            for token in &mut expected {
+                let Json::Array(items) = token else { continue };
+                for item in items.iter_mut() {
+                    if let Json::Str(text) = item {
+                        *text = unescape(text).unwrap_or_default();
+                    }
+                }
+            }
+        }
+        let expected = normalize_tokens(&expected);
+
+        let states = match test.get("initialStates") {
+            Some(Json::Array(states)) => states
+                .iter()
+                .filter_map(|s| match s {
+                    Json::Str(s) => Some(s.as_str()),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec!["Data state"],
+        };
+        for state in states {
+            let Some(mode) = text_mode(state) else {
+                tally.skipped += 1;
+                continue;
+            };
+            let mut tokenizer = Tokenizer::new(&input);
+            tokenizer.set_text_mode(mode, test.str("lastStartTag"));
+            let mut tokens = Vec::new();
+            loop {
+                let token = tokenizer.next_token();
+                if token == HtmlToken::EOF {
+                    break;
+                }
+                tokens.push(token_json(&token));
+            }
+            let actual = normalize_tokens(&tokens);
+            if actual == expected {
+                tally.passed += 1;
+            } else {
+                tally.fail(
+                    format!("{} ({state})", n + 1),
+                    format!("{description} {input:?}\n  expected {expected:?}\n  actual   {actual:?}"),
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn tokenizer_tests() {
+    run_suite("tokenizer", "test", tokenizer_file);
+}
+
+// ---------------------------------------------------------------------------
+// Tree construction tests
+// ---------------------------------------------------------------------------
+
+/// One `#data` block of a `.dat` file.
+#[derive(Default)]
+struct TreeTest {
+    data: String,
+    fragment_context: Option<String>,
+    scripting: bool,
+    document: String,
+}
+
+/// Split a `.dat` file into its tests. Sections run until the next line
+/// starting with `#`, except `#data` and `#document`, which may contain
+/// such lines.
+fn tree_tests(src: &str) -> Vec<TreeTest> {
+    let mut tests = Vec::new();
+    let mut test: Option<TreeTest> = None;
+    let mut section = "";
+    let mut lines: Vec<&str> = Vec::new();
+
+    let finish = |test: &mut TreeTest, section: &str, lines: &mut Vec<&str>| {
+        match section {
+            "#data" => test.data = lines.join("\n"),
+            "#document-fragment" => test.fragment_context = lines.first().map(|s| s.to_string()),
+            "#document" => {
+                while lines.last() == Some(&"") {
+                    lines.pop();
+                }
+                test.document = lines.join("\n");
+            }
+            _ => {}
+        }
+        lines.clear();
+    };
+
+    for line in src.lines() {
+        let is_header = matches!(
+            line,
+            "#data" | "#errors" | "#new-errors" | "#document-fragment" | "#script-on" | "#script-off" | "#document"
+        );
+        // A "#data" line in a document section starts the next test.
+        if is_header && (section != "#document" || line == "#data") {
+            if let Some(test) = &mut test {
+                finish(test, section, &mut lines);
+            }
+            if line == "#data" {
+                tests.extend(test.take());
+                test = Some(TreeTest {
+                    scripting: true,
+                    ..TreeTest::default()
+                });
+            }
+            if line == "#script-off"
+                && let Some(test) = &mut test
+            {
+                test.scripting = false;
+            }
+            section = match line {
+                "#script-on" | "#script-off" => "",
+                _ => line,
+            };
+            continue;
+        }
+        lines.push(line);
+    }
+    if let Some(mut test) = test {
+        finish(&mut test, section, &mut lines);
+        tests.push(test);
+    }
+    tests
+}
+
+/// Dump a tree in the html5lib format: one node per line, `| ` followed
+/// by two spaces per level of depth.
+fn dump(dom: &Dom, nodes: &[NodeId]) -> String {
+    fn node(dom: &Dom, id: NodeId, depth: usize, out: &mut Vec<String>) {
+        let indent = format!("| {}", "  ".repeat(depth));
+        let Some(n) = dom.nodes.get(id) else {
+            return;
+        };
+        match &n.data {
+            NodeData::DocumentType {
+                name,
+                public_id,
+                system_id,
+            } => {
+                if public_id.is_empty() && system_id.is_empty() {
+                    out.push(format!("{indent}<!DOCTYPE {name}>"));
+                } else {
+                    out.push(format!("{indent}<!DOCTYPE {name} \"{public_id}\" \"{system_id}\">"));
+                }
+            }
+            NodeData::Comment { data } => out.push(format!("{indent}<!-- {data} -->")),
+            NodeData::Text { data } => out.push(format!("{indent}\"{data}\"")),
+            NodeData::Element(element) => {
+                let prefix = match element.namespace {
+                    Namespace::Html => "",
+                    Namespace::Svg => "svg ",
+                    Namespace::MathMl => "math ",
+                };
+                out.push(format!("{indent}<{prefix}{}>", element.tag_name));
+                let mut attrs: Vec<_> = element
+                    .attrs
+                    .iter()
+                    .map(|attr| {
+                        let name = FOREIGN_ATTRIBUTES
+                            .iter()
+                            .find(|(qualified, ..)| {
+                                element.namespace != Namespace::Html && *qualified == attr.name
+                            })
+                            .map_or_else(|| attr.name.clone(), |(_, ns, local)| format!("{ns} {local}"));
+                        (name, &attr.value)
+                    })
+                    .collect();
+                attrs.sort();
+                for (name, value) in attrs {
+                    out.push(format!("{indent}  {name}=\"{value}\""));
+                }
+                if let Some(contents) = element.template_contents {
+                    out.push(format!("{indent}  content"));
+                    for child in dom.children(contents) {
+                        node(dom, child, depth + 2, out);
+                    }
+                }
+                for child in dom.children(id) {
+                    node(dom, child, depth + 1, out);
+                }
+            }
+            NodeData::Document { .. } | NodeData::DocumentFragment => {
+                for child in dom.children(id) {
+                    node(dom, child, depth, out);
+                }
+            }
+        }
+    }
+    let mut out = Vec::new();
+    for &id in nodes {
+        node(dom, id, 0, &mut out);
+    }
+    out.join("\n")
+}
+
+/// Attributes of SVG and MathML elements that are in the XLink, XML and
+/// XMLNS namespaces, which the dumps show as `xlink href`: `(qualified
+/// name, namespace prefix, local name)`.
+const FOREIGN_ATTRIBUTES: &[(&str, &str, &str)] = &[
+    ("xlink:actuate", "xlink", "actuate"),
+    ("xlink:arcrole", "xlink", "arcrole"),
+    ("xlink:href", "xlink", "href"),
+    ("xlink:role", "xlink", "role"),
+    ("xlink:show", "xlink", "show"),
+    ("xlink:title", "xlink", "title"),
+    ("xlink:type", "xlink", "type"),
+    ("xml:lang", "xml", "lang"),
+    ("xml:space", "xml", "space"),
+    ("xmlns", "xmlns", "xmlns"),
+    ("xmlns:xlink", "xmlns", "xlink"),
+];
+
+/// A `#document-fragment` context: `td`, or `svg path` / `math mi` for
+/// foreign elements.
+fn fragment_context(context: &str) -> (Namespace, &str) {
+    match context.split_once(' ') {
+        Some(("svg", name)) => (Namespace::Svg, name),
+        Some(("math", name)) => (Namespace::MathMl, name),
+        _ => (Namespace::Html, context),
+    }
+}
+
+fn tree_construction_file(src: &str, tally: &mut Tally) {
+    for (n, test) in tree_tests(src).into_iter().enumerate() {
+        let actual = match &test.fragment_context {
+            Some(context) => {
+                if !test.scripting {
+                    tally.skipped += 1;
+                    continue;
+                }
+                let (namespace, context) = fragment_context(context);
+                let (dom, nodes) = parse_fragment_in(namespace, context, &test.data);
+                dump(&dom, &nodes)
+            }
+            None => {
+                let mut parser = Parser::new();
+                parser.set_scripting(test.scripting);
+                parser.feed(&test.data);
+                parser.end();
+                while parser.run() != ParseStatus::Done {}
+                let dom = parser.into_dom();
+                let document = dom
+                    .nodes
+                    .iter()
+                    .find(|(_, n)| matches!(n.data, NodeData::Document { .. }))
+                    .unwrap()
+                    .0;
+                dump(&dom, &[document])
+            }
+        };
+        if actual == test.document {
+            tally.passed += 1;
+        } else {
+            let detail = format!(
+                "{:?}{}\nexpected:\n{}\nactual:\n{actual}",
+                test.data,
+                test.fragment_context
+                    .as_deref()
+                    .map(|c| format!(" in <{c}>"))
+                    .unwrap_or_default(),
+                test.document
+            );
+            tally.fail((n + 1).to_string(), detail);
+        }
+    }
+}
+
+#[test]
+fn tree_construction_tests() {
+    run_suite("tree-construction", "dat", tree_construction_file);
+}
@@ -1,15 +1,30 @@
-//! HTML parser crate — tokenizer and tree builder.
+//! HTML parser crate — tokenizer, tree builder and serializer.
 //!
 //! Parses HTML into a [`dom::Dom`] tree using a WHATWG-inspired tokenizer
-//! and tree construction algorithm. Zero external dependencies.
+//! and tree construction algorithm, with inline SVG and MathML placed in
+//! their namespaces. Documents can be parsed in one go with
+//! [`parse`] or fed piece by piece to a [`Parser`] as they download, with a
+//! [`preload::PreloadScanner`] looking ahead for subresources. Fragments are
+//! parsed in the context of an element with [`parse_fragment`], and
+//! [`serializer`] turns a tree back into markup. Zero external dependencies.
 
+pub mod entities;
+pub mod preload;
+pub mod serializer;
 pub mod token;
 pub mod tokenizer;
 pub mod tree_builder;
 
-pub use token::HtmlToken;
+#[cfg(test)]
+mod html5lib;
+
+pub use token::{HtmlToken, SourceError};
 pub use tokenizer::Tokenizer;
-pub use tree_builder::TreeBuilder;
+pub use serializer::{inner_html, outer_html};
+pub use tree_builder::{
+    insert_adjacent_html, parse_fragment, parse_fragment_in, set_inner_html, set_outer_html,
+    AdjacentPosition, FragmentError, ParseStatus, Parser, TreeBuilder,
+};
 
 /// Convenience function: parse an HTML string into a DOM tree.
 ///
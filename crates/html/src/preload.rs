@@ -0,0 +1,181 @@
+//! Speculative preload scanner.
+//!
+//! Runs a tokenizer of its own over the document as it arrives and picks
+//! out stylesheets, scripts and images, so the embedder can start fetching
+//! them before the tree builder (which may be stopped at a script) gets
+//! there. No tree is built: the scanner only tracks enough state to skip
+//! the contents of raw-text elements.
+
+use std::collections::HashSet;
+
+use crate::token::HtmlToken;
+use crate::tokenizer::Tokenizer;
+
+/// The kind of subresource a [`PreloadRequest`] is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PreloadKind {
+    /// `<link rel=stylesheet href>`
+    Style,
+    /// `<script src>`
+    Script,
+    /// `<img src>`
+    Image,
+}
+
+/// A subresource found ahead of the parser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreloadRequest {
+    pub kind: PreloadKind,
+    /// The URL as written in the attribute; resolve it against `base`.
+    pub url: String,
+    /// The document's `<base href>`, if one came before this element.
+    pub base: Option<String>,
+}
+
+/// Finds subresource URLs in a document fed to it piece by piece.
+pub struct PreloadScanner {
+    tokenizer: Tokenizer,
+    base: Option<String>,
+    seen: HashSet<(PreloadKind, String)>,
+}
+
+impl PreloadScanner {
+    pub fn new() -> Self {
+        Self {
+            tokenizer: Tokenizer::streaming(),
+            base: None,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Scan the next piece of the document, returning the requests found in
+    /// it. A URL is reported once per kind.
+    pub fn feed(&mut self, input: &str) -> Vec<PreloadRequest> {
+        self.tokenizer.feed(input);
+        self.scan()
+    }
+
+    /// Scan whatever was held back waiting for more input.
+    pub fn end(&mut self) -> Vec<PreloadRequest> {
+        self.tokenizer.end();
+        self.scan()
+    }
+
+    /// The first `<base href>` seen so far.
+    pub fn base_href(&self) -> Option<&str> {
+        self.base.as_deref()
+    }
+
+    fn scan(&mut self) -> Vec<PreloadRequest> {
+        let mut found = Vec::new();
+        while let Some(token) = self.tokenizer.try_next_token() {
+            let (name, attrs) = match token {
+                HtmlToken::StartTag { name, attrs, .. } => (name, attrs),
+                HtmlToken::EOF => break,
+                _ => continue,
+            };
+            if is_raw_text(&name) {
+                self.tokenizer.switch_to_rawtext(&name);
+            }
+            let attr = |wanted: &str| {
+                attrs
+                    .iter()
+                    .find(|(n, _)| n == wanted)
+                    .map(|(_, v)| v.trim().to_string())
+                    .filter(|v| !v.is_empty())
+            };
+            let request = match name.as_str() {
+                "base" => {
+                    if self.base.is_none() {
+                        self.base = attr("href");
+                    }
+                    None
+                }
+                "link" if attr("rel").is_some_and(|rel| {
+                    rel.split_ascii_whitespace()
+                        .any(|r| r.eq_ignore_ascii_case("stylesheet"))
+                }) =>
+                {
+                    attr("href").map(|url| (PreloadKind::Style, url))
+                }
+                "script" => attr("src").map(|url| (PreloadKind::Script, url)),
+                "img" => attr("src").map(|url| (PreloadKind::Image, url)),
+                _ => None,
+            };
+            if let Some((kind, url)) = request
+                && self.seen.insert((kind, url.clone()))
+            {
+                found.push(PreloadRequest {
+                    kind,
+                    url,
+                    base: self.base.clone(),
+                });
+            }
+        }
+        found
+    }
+}
+
+impl Default for PreloadScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Elements whose contents are not markup.
+fn is_raw_text(name: &str) -> bool {
+    matches!(
+        name,
+        "script" | "style" | "textarea" | "title" | "xmp" | "iframe" | "noembed" | "noframes"
+            | "noscript"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(requests: &[PreloadRequest]) -> Vec<(PreloadKind, &str)> {
+        requests.iter().map(|r| (r.kind, r.url.as_str())).collect()
+    }
+
+    #[test]
+    fn finds_subresources_in_pieces() {
+        let mut scanner = PreloadScanner::new();
+        let mut found = scanner.feed("<head><link rel=\"alternate stylesheet\" href=a.css><scr");
+        assert_eq!(urls(&found), vec![(PreloadKind::Style, "a.css")]);
+        found = scanner.feed("ipt src=app.js></script><link rel=icon href=f.ico></head>");
+        found.extend(scanner.feed("<body><img src=' b.png '><img src=b.png><img alt=x>"));
+        found.extend(scanner.end());
+        assert_eq!(
+            urls(&found),
+            vec![(PreloadKind::Script, "app.js"), (PreloadKind::Image, "b.png")]
+        );
+    }
+
+    #[test]
+    fn skips_raw_text_and_records_base() {
+        let mut scanner = PreloadScanner::new();
+        let found = scanner.feed(
+            "<img src=early.png><base href=/static/><base href=/ignored/>\
+             <script>var s = '<img src=fake.png>';</script>\
+             <textarea><img src=typed.png></textarea><img src=late.png>",
+        );
+        assert_eq!(scanner.base_href(), Some("/static/"));
+        assert_eq!(
+            found,
+            vec![
+                PreloadRequest {
+                    kind: PreloadKind::Image,
+                    url: "early.png".to_string(),
+                    base: None,
+                },
+                PreloadRequest {
+                    kind: PreloadKind::Image,
+                    url: "late.png".to_string(),
+                    base: Some("/static/".to_string()),
+                },
+            ]
+        );
+    }
+}
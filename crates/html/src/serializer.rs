@@ -0,0 +1,216 @@
+//! HTML serialization — turns a [`Dom`] subtree back into markup.
+//!
+//! Follows the WHATWG "serializing HTML fragments" algorithm: void elements
+//! get no end tag, the contents of raw-text elements are written verbatim,
+//! and text and attribute values are escaped. Only HTML elements can be
+//! void or raw text; an SVG `<style>` or MathML `<input>` is serialized
+//! like any other element. Attributes keep their qualified names in the
+//! DOM, so foreign attributes come out with their `xlink:`, `xml:` and
+//! `xmlns:` prefixes.
+
+use dom::node::{ElementData, Namespace, NodeData, NodeId};
+use dom::Dom;
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Serialize the children of `node` (the `innerHTML` getter).
+pub fn inner_html(dom: &Dom, node: NodeId) -> String {
+    let mut out = String::new();
+    serialize_children(dom, node, &mut out);
+    out
+}
+
+/// Serialize `node` itself, including its start and end tags (the
+/// `outerHTML` getter).
+pub fn outer_html(dom: &Dom, node: NodeId) -> String {
+    let mut out = String::new();
+    serialize_node(dom, node, &mut out);
+    out
+}
+
+/// Elements that never have contents or an end tag.
+pub fn is_void_element(tag: &str) -> bool {
+    matches!(
+        tag,
+        "area"
+            | "base"
+            | "basefont"
+            | "bgsound"
+            | "br"
+            | "col"
+            | "embed"
+            | "frame"
+            | "hr"
+            | "img"
+            | "input"
+            | "keygen"
+            | "link"
+            | "meta"
+            | "param"
+            | "source"
+            | "track"
+            | "wbr"
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Serialization
+// ---------------------------------------------------------------------------
+
+fn serialize_children(dom: &Dom, node: NodeId, out: &mut String) {
+    let mut parent = node;
+    if let Some(element) = dom.nodes.get(node).and_then(|n| n.as_element()) {
+        if is_void(element) {
+            return;
+        }
+        // A template's markup is that of its contents.
+        if let Some(contents) = element.template_contents {
+            parent = contents;
+        }
+    }
+    for child in dom.children(parent) {
+        serialize_node(dom, child, out);
+    }
+}
+
+fn serialize_node(dom: &Dom, node: NodeId, out: &mut String) {
+    let Some(n) = dom.nodes.get(node) else {
+        return;
+    };
+    match &n.data {
+        NodeData::Element(element) => {
+            out.push('<');
+            out.push_str(&element.tag_name);
+            for attr in &element.attrs {
+                out.push(' ');
+                out.push_str(&attr.name);
+                out.push_str("=\"");
+                escape(&attr.value, true, out);
+                out.push('"');
+            }
+            out.push('>');
+            if is_void(element) {
+                return;
+            }
+            serialize_children(dom, node, out);
+            out.push_str("</");
+            out.push_str(&element.tag_name);
+            out.push('>');
+        }
+        NodeData::Text { data } => {
+            let raw = n
+                .parent
+                .and_then(|p| dom.nodes.get(p))
+                .and_then(|p| p.as_element())
+                .is_some_and(|p| p.namespace == Namespace::Html && is_raw_text_parent(&p.tag_name));
+            if raw {
+                out.push_str(data);
+            } else {
+                escape(data, false, out);
+            }
+        }
+        NodeData::Comment { data } => {
+            out.push_str("<!--");
+            out.push_str(data);
+            out.push_str("-->");
+        }
+        NodeData::DocumentType { name, .. } => {
+            out.push_str("<!DOCTYPE ");
+            out.push_str(name);
+            out.push('>');
+        }
+        NodeData::Document { .. } | NodeData::DocumentFragment => {
+            serialize_children(dom, node, out)
+        }
+    }
+}
+
+/// Whether `element` is an HTML void element.
+fn is_void(element: &ElementData) -> bool {
+    element.namespace == Namespace::Html && is_void_element(&element.tag_name)
+}
+
+/// Elements whose text children are written without escaping. `noscript`
+/// is included because scripting is enabled.
+fn is_raw_text_parent(tag: &str) -> bool {
+    matches!(
+        tag,
+        "style" | "script" | "xmp" | "iframe" | "noembed" | "noframes" | "plaintext" | "noscript"
+    )
+}
+
+/// Escape `&`, U+00A0, `<` and `>`, and in attribute mode also `"`.
+fn escape(text: &str, attribute: bool, out: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '\u{a0}' => out.push_str("&nbsp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' if attribute => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree_builder::parse;
+
+    fn body(dom: &Dom) -> NodeId {
+        let document = dom
+            .nodes
+            .iter()
+            .find(|(_, n)| matches!(n.data, NodeData::Document { .. }))
+            .map(|(id, _)| id)
+            .unwrap();
+        dom.get_elements_by_tag(document, "body")[0]
+    }
+
+    #[test]
+    fn round_trips_body_markup() {
+        let markup = "<p class=\"a\">One<br>two &amp; <b>three</b></p><!-- note --><img src=\"x.png\">";
+        let dom = parse(markup);
+        assert_eq!(inner_html(&dom, body(&dom)), markup);
+    }
+
+    #[test]
+    fn escapes_text_and_attributes() {
+        let dom = parse("<p title='say \"hi\" & <wave>'>1 &lt; 2&nbsp;&gt; 0</p>");
+        let p = dom.get_elements_by_tag(body(&dom), "p")[0];
+        assert_eq!(
+            outer_html(&dom, p),
+            "<p title=\"say &quot;hi&quot; &amp; &lt;wave&gt;\">1 &lt; 2&nbsp;&gt; 0</p>"
+        );
+    }
+
+    #[test]
+    fn foreign_content_round_trips() {
+        let markup = "<svg><input></input><style>a&lt;b</style>\
+                      <a xlink:href=\"#x\" xml:lang=\"en\" xmlns:xlink=\"http://www.w3.org/1999/xlink\">\
+                      <circle r=\"1\"></circle></a></svg>\
+                      <math><mi><img></mi><mtext><br>x&amp;y</mtext><style>a&lt;b</style></math>";
+        let dom = parse(markup);
+        let serialized = inner_html(&dom, body(&dom));
+        assert_eq!(serialized, markup);
+        let reparsed = parse(&serialized);
+        assert_eq!(inner_html(&reparsed, body(&reparsed)), markup);
+    }
+
+    #[test]
+    fn raw_text_is_not_escaped() {
+        let dom = parse("<style>a > b { }</style><script>if (a < b && c) {}</script>");
+        let html = dom.nodes.get(body(&dom)).unwrap().parent.unwrap();
+        assert_eq!(
+            inner_html(&dom, html),
+            "<head><style>a > b { }</style><script>if (a < b && c) {}</script></head><body></body>"
+        );
+    }
+}
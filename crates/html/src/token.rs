@@ -1,4 +1,5 @@
-//! HTML token types produced by the tokenizer.
+//! HTML token types produced by the tokenizer, and the parse errors
+//! reported alongside them.
 
 /// A single token emitted by the HTML tokenizer.
 #[derive(Debug, Clone, PartialEq)]
@@ -27,3 +28,12 @@ pub enum HtmlToken {
     /// End of file.
     EOF,
 }
+
+/// A parse error found in the source: the spec's error code (such as
+/// `eof-in-tag`) and the character offset it was detected at, counted as by
+/// [`Tokenizer::position`](crate::Tokenizer::position).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceError {
+    pub code: &'static str,
+    pub offset: usize,
+}
@@ -1,8 +1,16 @@
-//! WHATWG-inspired HTML tokenizer state machine.
+//! WHATWG HTML tokenizer state machine.
 //!
-//! Converts a stream of characters into [`HtmlToken`]s.
+//! Converts a stream of characters into [`HtmlToken`]s. Every state of the
+//! spec's tokenization section is implemented; the comment "less-than
+//! sign" states are folded into `Comment`, which reports their
+//! `nested-comment` error itself.
+//!
+//! Parse errors are recorded with the spec's error codes when asked for
+//! (see [`Tokenizer::set_error_reporting`]); the tokenizer recovers from
+//! them the same way either way.
 
-use crate::token::HtmlToken;
+use crate::entities;
+use crate::token::{HtmlToken, SourceError};
 
 // ---------------------------------------------------------------------------
 // Tokenizer states
@@ -11,9 +19,31 @@ use crate::token::HtmlToken;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum State {
     Data,
+    Rcdata,
+    Rawtext,
+    ScriptData,
+    Plaintext,
     TagOpen,
     EndTagOpen,
     TagName,
+    RcdataLessThanSign,
+    RawtextLessThanSign,
+    ScriptDataLessThanSign,
+    /// `</` in RCDATA, RAWTEXT or script data; `text_state` says which.
+    TextEndTagOpen,
+    TextEndTagName,
+    ScriptDataEscapeStart,
+    ScriptDataEscapeStartDash,
+    ScriptDataEscaped,
+    ScriptDataEscapedDash,
+    ScriptDataEscapedDashDash,
+    ScriptDataEscapedLessThanSign,
+    ScriptDataDoubleEscapeStart,
+    ScriptDataDoubleEscaped,
+    ScriptDataDoubleEscapedDash,
+    ScriptDataDoubleEscapedDashDash,
+    ScriptDataDoubleEscapedLessThanSign,
+    ScriptDataDoubleEscapeEnd,
     BeforeAttributeName,
     AttributeName,
     AfterAttributeName,
@@ -47,14 +77,94 @@ enum State {
     DoctypeSystemIdSingleQuoted,
     AfterDoctypeSystemId,
     BogusDoctype,
+    CdataSection,
+    CdataSectionBracket,
+    CdataSectionEnd,
     CharacterReference,
     NumericCharacterReference,
     HexCharacterReferenceStart,
+    DecimalCharacterReferenceStart,
     HexCharacterReference,
     DecimalCharacterReference,
     NumericCharacterReferenceEnd,
     NamedCharacterReference,
-    RawText,
+}
+
+/// The content models the tree builder can switch the tokenizer to after a
+/// start tag (see [`Tokenizer::set_text_mode`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextMode {
+    /// Ordinary markup.
+    Data,
+    /// Text with character references but no tags (`<title>`, `<textarea>`).
+    Rcdata,
+    /// Text without references or tags (`<style>`, `<xmp>`, …).
+    Rawtext,
+    /// `<script>` contents, with the `<!--` escaping rules.
+    ScriptData,
+    /// Everything to the end of the input is text.
+    Plaintext,
+}
+
+impl TextMode {
+    /// The mode the contents of a `tag_name` element are read in, with
+    /// scripting enabled.
+    pub fn for_element(tag_name: &str) -> Self {
+        match tag_name {
+            "title" | "textarea" => TextMode::Rcdata,
+            "style" | "xmp" | "iframe" | "noembed" | "noframes" | "noscript" => TextMode::Rawtext,
+            "script" => TextMode::ScriptData,
+            "plaintext" => TextMode::Plaintext,
+            _ => TextMode::Data,
+        }
+    }
+}
+
+fn is_whitespace(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\x0C' | ' ')
+}
+
+/// Code points 0x80–0x9F in numeric references mean what they would in
+/// windows-1252.
+const WINDOWS_1252: [u32; 32] = [
+    0x20AC, 0x81, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021, 0x02C6, 0x2030, 0x0160, 0x2039,
+    0x0152, 0x8D, 0x017D, 0x8F, 0x90, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x9D, 0x017E, 0x0178,
+];
+
+/// The parse error, if any, for a numeric character reference to `code`.
+fn numeric_reference_error(code: u32) -> Option<&'static str> {
+    let is_noncharacter = (0xFDD0..=0xFDEF).contains(&code) || (code & 0xFFFE == 0xFFFE && code <= 0x10FFFF);
+    let is_control = (code < 0x20 && !matches!(code, 0x09 | 0x0A | 0x0C)) || (0x7F..=0x9F).contains(&code);
+    match code {
+        0 => Some("null-character-reference"),
+        0x110000.. => Some("character-reference-outside-unicode-range"),
+        0xD800..=0xDFFF => Some("surrogate-character-reference"),
+        _ if is_noncharacter => Some("noncharacter-character-reference"),
+        _ if is_control => Some("control-character-reference"),
+        _ => None,
+    }
+}
+
+/// The case-sensitive keyword after `<!` that opens a CDATA section.
+const CDATA_OPEN: [char; 7] = ['[', 'C', 'D', 'A', 'T', 'A', '['];
+
+/// Append `input` to `out`, turning CR LF pairs and lone CRs into LF.
+/// `after_cr` carries a CR at the end of one piece over to the next.
+fn push_normalized(out: &mut Vec<char>, input: &str, after_cr: &mut bool) {
+    for c in input.chars() {
+        match c {
+            '\r' => {
+                out.push('\n');
+                *after_cr = true;
+            }
+            '\n' if *after_cr => *after_cr = false,
+            c => {
+                out.push(c);
+                *after_cr = false;
+            }
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -66,8 +176,16 @@ enum State {
 pub struct Tokenizer {
     input: Vec<char>,
     pos: usize,
+    /// Characters already discarded from the front of `input` by `feed`,
+    /// so that `offset + pos` counts from the start of the document.
+    offset: usize,
     state: State,
     return_state: State,
+    /// The text state a `</` in RCDATA, RAWTEXT or script data returns to
+    /// when it does not start the appropriate end tag.
+    text_state: State,
+    /// Whether the last fed piece ended in CR (see [`push_normalized`]).
+    after_cr: bool,
 
     // Pending token being built
     current_tag_name: String,
@@ -76,6 +194,8 @@ pub struct Tokenizer {
     current_attrs: Vec<(String, String)>,
     current_attr_name: String,
     current_attr_value: String,
+    /// Where the current attribute's name starts, for `duplicate-attribute`.
+    current_attr_offset: usize,
 
     // Comment / doctype buffers
     current_comment: String,
@@ -84,26 +204,42 @@ pub struct Tokenizer {
     current_doctype_system_id: Option<String>,
     current_doctype_force_quirks: bool,
 
-    // Character reference
+    // Character reference, and the characters of a possible end tag in
+    // text states
     temp_buf: String,
     char_ref_code: u32,
 
-    // Raw-text end tag (for <script>, <style>, etc.)
-    rawtext_end_tag: String,
+    /// Name of the last start tag emitted; an end tag with this name is the
+    /// "appropriate end tag" that ends RCDATA, RAWTEXT and script data.
+    last_start_tag: String,
+    /// Whether `<![CDATA[` starts a CDATA section, which it only does in
+    /// SVG and MathML content; elsewhere it is a bogus comment.
+    cdata_allowed: bool,
 
     // Queue of tokens to emit (we sometimes need to emit multiple)
     pending: Vec<HtmlToken>,
     done: bool,
+
+    // Streaming: whether more input may still arrive via `feed`, and
+    // whether the last `step` stopped to wait for it.
+    input_closed: bool,
+    suspended: bool,
+
+    report_errors: bool,
+    errors: Vec<SourceError>,
 }
 
 impl Tokenizer {
     /// Create a new tokenizer for the given HTML source string.
     pub fn new(input: &str) -> Self {
-        Self {
-            input: input.chars().collect(),
+        let mut tokenizer = Self {
+            input: Vec::new(),
             pos: 0,
+            offset: 0,
             state: State::Data,
             return_state: State::Data,
+            text_state: State::Data,
+            after_cr: false,
 
             current_tag_name: String::new(),
             current_tag_is_end: false,
@@ -111,6 +247,7 @@ impl Tokenizer {
             current_attrs: Vec::new(),
             current_attr_name: String::new(),
             current_attr_value: String::new(),
+            current_attr_offset: 0,
 
             current_comment: String::new(),
             current_doctype_name: None,
@@ -121,10 +258,111 @@ impl Tokenizer {
             temp_buf: String::new(),
             char_ref_code: 0,
 
-            rawtext_end_tag: String::new(),
+            last_start_tag: String::new(),
+            cdata_allowed: false,
 
             pending: Vec::new(),
             done: false,
+
+            input_closed: true,
+            suspended: false,
+
+            report_errors: false,
+            errors: Vec::new(),
+        };
+        push_normalized(&mut tokenizer.input, input, &mut tokenizer.after_cr);
+        tokenizer
+    }
+
+    /// Create a tokenizer whose input arrives in pieces through
+    /// [`feed`](Tokenizer::feed), finished by [`end`](Tokenizer::end).
+    pub fn streaming() -> Self {
+        Self {
+            input_closed: false,
+            ..Self::new("")
+        }
+    }
+
+    /// Append input. Characters that have already been tokenized are
+    /// discarded.
+    pub fn feed(&mut self, input: &str) {
+        if self.pos > 0 {
+            self.input.drain(..self.pos);
+            self.offset += self.pos;
+            self.pos = 0;
+        }
+        push_normalized(&mut self.input, input, &mut self.after_cr);
+    }
+
+    /// Mark the end of the input; the tokenizer emits `EOF` once the
+    /// remaining input is consumed.
+    pub fn end(&mut self) {
+        self.input_closed = true;
+    }
+
+    /// Insert text at the current position, ahead of the remaining input
+    /// (used for `document.write`).
+    pub fn insert(&mut self, text: &str) {
+        let mut chars = Vec::new();
+        push_normalized(&mut chars, text, &mut false);
+        let at = self.pos;
+        self.input.splice(at..at, chars);
+    }
+
+    /// The offset, in characters from the start of the input, of the next
+    /// character to be read. Line breaks count as one character each (CR LF
+    /// is read as LF), and text added by [`insert`](Tokenizer::insert)
+    /// counts too.
+    pub fn position(&self) -> usize {
+        self.offset + self.pos
+    }
+
+    /// Start or stop recording parse errors.
+    pub fn set_error_reporting(&mut self, enabled: bool) {
+        self.report_errors = enabled;
+    }
+
+    /// The parse errors recorded since the last call, in input order.
+    pub fn take_errors(&mut self) -> Vec<SourceError> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// Whether [`end`](Tokenizer::end) has been called (always true for a
+    /// tokenizer created with [`new`](Tokenizer::new)).
+    pub fn is_input_closed(&self) -> bool {
+        self.input_closed
+    }
+
+    /// Switch to the state for the contents of a `tag_name` element that
+    /// was just started (RCDATA for `<title>`, script data for `<script>`,
+    /// and so on).
+    pub fn switch_to_rawtext(&mut self, tag_name: &str) {
+        let tag_name = tag_name.to_ascii_lowercase();
+        let mode = match TextMode::for_element(&tag_name) {
+            TextMode::Data => TextMode::Rawtext,
+            mode => mode,
+        };
+        self.set_text_mode(mode, Some(&tag_name));
+    }
+
+    /// Tell the tokenizer whether the tree builder's adjusted current node
+    /// is an SVG or MathML element, where `<![CDATA[` sections are read.
+    pub fn set_cdata_allowed(&mut self, allowed: bool) {
+        self.cdata_allowed = allowed;
+    }
+
+    /// Put the tokenizer in `mode`. `last_start_tag` overrides the name of
+    /// the last start tag, which decides the end tag that leaves the mode.
+    pub fn set_text_mode(&mut self, mode: TextMode, last_start_tag: Option<&str>) {
+        self.state = match mode {
+            TextMode::Data => State::Data,
+            TextMode::Rcdata => State::Rcdata,
+            TextMode::Rawtext => State::Rawtext,
+            TextMode::ScriptData => State::ScriptData,
+            TextMode::Plaintext => State::Plaintext,
+        };
+        if let Some(tag) = last_start_tag {
+            self.last_start_tag = tag.to_string();
         }
     }
 
@@ -144,12 +382,6 @@ impl Tokenizer {
         c
     }
 
-    fn reconsume(&mut self) {
-        if self.pos > 0 {
-            self.pos -= 1;
-        }
-    }
-
     /// Check if the upcoming characters (case-insensitive) match `s`.
     /// Does NOT consume them.
     fn lookahead_ci(&self, s: &str) -> bool {
@@ -159,23 +391,52 @@ impl Tokenizer {
         }
         for (i, &expected) in chars.iter().enumerate() {
             let actual = self.input[self.pos + i];
-            if actual.to_ascii_lowercase() != expected.to_ascii_lowercase() {
+            if !actual.eq_ignore_ascii_case(&expected) {
                 return false;
             }
         }
         true
     }
 
+    /// Record a parse error at the next character (or the end of input).
+    fn error(&mut self, code: &'static str) {
+        if self.report_errors {
+            let offset = self.position();
+            self.errors.push(SourceError { code, offset });
+        }
+    }
+
+    /// Record a parse error at the character just consumed.
+    fn error_consumed(&mut self, code: &'static str) {
+        if self.report_errors {
+            let offset = self.position().saturating_sub(1);
+            self.errors.push(SourceError { code, offset });
+        }
+    }
+
     fn consume_n(&mut self, n: usize) {
         self.pos = (self.pos + n).min(self.input.len());
     }
 
+    /// Queue characters for emission.
+    fn emit_str(&mut self, s: &str) {
+        self.pending.extend(s.chars().map(HtmlToken::Character));
+    }
+
     fn emit_current_tag(&mut self) -> HtmlToken {
+        self.finish_attr();
         if self.current_tag_is_end {
+            if !self.current_attrs.is_empty() {
+                self.error_consumed("end-tag-with-attributes");
+            }
+            if self.current_tag_self_closing {
+                self.error_consumed("end-tag-with-trailing-solidus");
+            }
             HtmlToken::EndTag {
                 name: self.current_tag_name.clone(),
             }
         } else {
+            self.last_start_tag = self.current_tag_name.clone();
             HtmlToken::StartTag {
                 name: self.current_tag_name.clone(),
                 attrs: self.current_attrs.clone(),
@@ -188,18 +449,27 @@ impl Tokenizer {
         if !self.current_attr_name.is_empty() {
             let name = std::mem::take(&mut self.current_attr_name);
             let value = std::mem::take(&mut self.current_attr_value);
-            // Only add if no duplicate attr name
-            if !self.current_attrs.iter().any(|(n, _)| *n == name) {
-                self.current_attrs.push((name, value));
+            // A duplicate attribute is dropped (per spec).
+            if self.current_attrs.iter().any(|(n, _)| *n == name) {
+                if self.report_errors {
+                    let offset = self.current_attr_offset;
+                    self.errors.push(SourceError { code: "duplicate-attribute", offset });
+                }
             } else {
-                // Drop duplicate (per spec)
+                self.current_attrs.push((name, value));
             }
         } else {
-            self.current_attr_name.clear();
             self.current_attr_value.clear();
         }
     }
 
+    fn start_new_attr(&mut self) {
+        self.finish_attr();
+        self.current_attr_offset = self.position();
+        self.current_attr_name.clear();
+        self.current_attr_value.clear();
+    }
+
     fn start_new_tag(&mut self, is_end: bool) {
         self.current_tag_name.clear();
         self.current_tag_is_end = is_end;
@@ -209,6 +479,11 @@ impl Tokenizer {
         self.current_attr_value.clear();
     }
 
+    /// Whether the end tag being read in a text state closes it.
+    fn is_appropriate_end_tag(&self) -> bool {
+        !self.last_start_tag.is_empty() && self.current_tag_name == self.last_start_tag
+    }
+
     fn emit_current_comment(&mut self) -> HtmlToken {
         HtmlToken::Comment(std::mem::take(&mut self.current_comment))
     }
@@ -218,55 +493,123 @@ impl Tokenizer {
             name: self.current_doctype_name.take(),
             public_id: self.current_doctype_public_id.take(),
             system_id: self.current_doctype_system_id.take(),
-            force_quirks: self.current_doctype_force_quirks,
+            force_quirks: std::mem::take(&mut self.current_doctype_force_quirks),
         }
     }
 
+    /// Emit the doctype with force-quirks set, at the end of the input.
+    fn emit_doctype_at_eof(&mut self) -> HtmlToken {
+        self.error("eof-in-doctype");
+        self.current_doctype_force_quirks = true;
+        self.done = true;
+        self.emit_current_doctype()
+    }
+
+    fn doctype_name(&mut self) -> &mut String {
+        self.current_doctype_name.get_or_insert_with(String::new)
+    }
+
     // -----------------------------------------------------------------------
     // Character reference helpers
     // -----------------------------------------------------------------------
 
-    /// Switch the tokenizer into raw-text mode.  All characters will be
-    /// emitted as `Character` tokens until `</tag_name>` (case-insensitive)
-    /// is encountered, at which point the end tag is emitted and the
-    /// tokenizer returns to `Data` state.
-    pub fn switch_to_rawtext(&mut self, tag_name: &str) {
-        self.rawtext_end_tag = tag_name.to_ascii_lowercase();
-        self.state = State::RawText;
+    /// Whether a character reference is being read inside an attribute
+    /// value (rather than in text).
+    fn in_attribute_value(&self) -> bool {
+        matches!(
+            self.return_state,
+            State::AttributeValueDoubleQuoted
+                | State::AttributeValueSingleQuoted
+                | State::AttributeValueUnquoted
+        )
     }
 
     fn flush_code_points_consumed_as_char_ref(&mut self) {
-        // Emit each character in temp_buf for the return state
-        // We just push them as pending Character tokens
-        for c in self.temp_buf.chars() {
-            self.pending.push(HtmlToken::Character(c));
+        // Append temp_buf to the attribute value, or emit each character
+        // as a pending Character token.
+        if self.in_attribute_value() {
+            self.current_attr_value.push_str(&self.temp_buf);
+        } else {
+            for c in self.temp_buf.chars() {
+                self.pending.push(HtmlToken::Character(c));
+            }
         }
         self.temp_buf.clear();
     }
 
+    /// Whether the current state can make progress with the input received
+    /// so far, without mistaking the end of a partial input for the end of
+    /// the document. Only consulted while the input is still open.
+    fn has_enough_input(&self) -> bool {
+        let rest = &self.input[self.pos..];
+        match self.state {
+            // Longest keyword looked ahead for is "DOCTYPE".
+            State::MarkupDeclarationOpen => rest.len() >= 7,
+            State::AfterDoctypeName => {
+                rest.len() >= 6 || rest.first().is_some_and(|c| c.is_ascii_whitespace() || *c == '>')
+            }
+            // A name is complete once followed by a non-alphanumeric (or once
+            // it is longer than any entity name).
+            State::NamedCharacterReference => {
+                rest.len() > 32 || rest.iter().any(|c| !c.is_ascii_alphanumeric())
+            }
+            State::NumericCharacterReferenceEnd => true,
+            _ => !rest.is_empty(),
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Public interface
     // -----------------------------------------------------------------------
 
     /// Return the next HTML token, or `HtmlToken::EOF` when done.
+    ///
+    /// On a streaming tokenizer whose input is still open, waiting for more
+    /// input is reported as `EOF` too; use
+    /// [`try_next_token`](Tokenizer::try_next_token) to tell the two apart.
     pub fn next_token(&mut self) -> HtmlToken {
+        self.try_next_token().unwrap_or(HtmlToken::EOF)
+    }
+
+    /// Return the next HTML token, or `None` if more input is needed before
+    /// one can be produced.
+    pub fn try_next_token(&mut self) -> Option<HtmlToken> {
         // Drain any queued tokens first
         if !self.pending.is_empty() {
-            return self.pending.remove(0);
+            return Some(self.pending.remove(0));
         }
         if self.done {
-            return HtmlToken::EOF;
+            return Some(HtmlToken::EOF);
+        }
+        let token = self.step();
+        if std::mem::take(&mut self.suspended) {
+            return None;
         }
+        Some(token)
+    }
 
+    /// Run the state machine until it produces a token. While the input is
+    /// open it stops in front of any state that lacks input, setting
+    /// `suspended` (the returned token is then meaningless); all progress
+    /// is kept in `self`, so the next call resumes where this one stopped.
+    ///
+    /// "Reconsume" in the spec is a state change without consuming: most
+    /// states `peek` and only `consume` in the branches that keep the
+    /// character.
+    fn step(&mut self) -> HtmlToken {
         loop {
             // Drain pending each iteration
             if !self.pending.is_empty() {
                 return self.pending.remove(0);
             }
+            if !self.input_closed && !self.has_enough_input() {
+                self.suspended = true;
+                return HtmlToken::EOF;
+            }
 
             match self.state {
                 // =============================================================
-                // Data state
+                // Text states
                 // =============================================================
                 State::Data => match self.consume() {
                     Some('&') => {
@@ -276,12 +619,74 @@ impl Tokenizer {
                     Some('<') => {
                         self.state = State::TagOpen;
                     }
+                    Some(c) => {
+                        // U+0000 is passed on; the tree builder drops it.
+                        if c == '\0' {
+                            self.error_consumed("unexpected-null-character");
+                        }
+                        return HtmlToken::Character(c);
+                    }
+                    None => {
+                        self.done = true;
+                        return HtmlToken::EOF;
+                    }
+                },
+
+                State::Rcdata => match self.consume() {
+                    Some('&') => {
+                        self.return_state = State::Rcdata;
+                        self.state = State::CharacterReference;
+                    }
+                    Some('<') => {
+                        self.state = State::RcdataLessThanSign;
+                    }
                     Some('\0') => {
+                        self.error_consumed("unexpected-null-character");
                         return HtmlToken::Character('\u{FFFD}');
                     }
-                    Some(c) => {
-                        return HtmlToken::Character(c);
+                    Some(c) => return HtmlToken::Character(c),
+                    None => {
+                        self.done = true;
+                        return HtmlToken::EOF;
+                    }
+                },
+
+                State::Rawtext => match self.consume() {
+                    Some('<') => {
+                        self.state = State::RawtextLessThanSign;
+                    }
+                    Some('\0') => {
+                        self.error_consumed("unexpected-null-character");
+                        return HtmlToken::Character('\u{FFFD}');
+                    }
+                    Some(c) => return HtmlToken::Character(c),
+                    None => {
+                        self.done = true;
+                        return HtmlToken::EOF;
+                    }
+                },
+
+                State::ScriptData => match self.consume() {
+                    Some('<') => {
+                        self.state = State::ScriptDataLessThanSign;
+                    }
+                    Some('\0') => {
+                        self.error_consumed("unexpected-null-character");
+                        return HtmlToken::Character('\u{FFFD}');
+                    }
+                    Some(c) => return HtmlToken::Character(c),
+                    None => {
+                        self.done = true;
+                        return HtmlToken::EOF;
+                    }
+                },
+
+                State::Plaintext => match self.consume() {
+                    Some('\0') => {
+                        self.error_consumed("unexpected-null-character");
+                        return HtmlToken::Character('\u{FFFD}');
                     }
+                    Some(c) => return HtmlToken::Character(c),
                     None => {
                         self.done = true;
                         return HtmlToken::EOF;
@@ -305,10 +710,16 @@ impl Tokenizer {
                         self.state = State::TagName;
                     }
                     Some('?') => {
+                        self.error("unexpected-question-mark-instead-of-tag-name");
                         self.current_comment.clear();
                         self.state = State::BogusComment;
                     }
-                    _ => {
+                    next => {
+                        self.error(if next.is_none() {
+                            "eof-before-tag-name"
+                        } else {
+                            "invalid-first-character-of-tag-name"
+                        });
                         self.state = State::Data;
                         return HtmlToken::Character('<');
                     }
@@ -323,16 +734,18 @@ impl Tokenizer {
                         self.state = State::TagName;
                     }
                     Some('>') => {
+                        self.error("missing-end-tag-name");
                         self.consume();
                         self.state = State::Data;
-                        // Missing end tag name – ignore
                     }
                     None => {
+                        self.error("eof-before-tag-name");
                         self.done = true;
                         self.pending.push(HtmlToken::Character('/'));
                         return HtmlToken::Character('<');
                     }
                     _ => {
+                        self.error("invalid-first-character-of-tag-name");
                         self.current_comment.clear();
                         self.state = State::BogusComment;
                     }
@@ -342,7 +755,7 @@ impl Tokenizer {
                 // Tag name
                 // =============================================================
                 State::TagName => match self.consume() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {
+                    Some(c) if is_whitespace(c) => {
                         self.state = State::BeforeAttributeName;
                     }
                     Some('/') => {
@@ -352,35 +765,273 @@ impl Tokenizer {
                         self.state = State::Data;
                         return self.emit_current_tag();
                     }
+                    Some('\0') => {
+                        self.error_consumed("unexpected-null-character");
+                        self.current_tag_name.push('\u{FFFD}');
+                    }
                     Some(c) => {
                         self.current_tag_name.push(c.to_ascii_lowercase());
                     }
                     None => {
+                        self.error("eof-in-tag");
                         self.done = true;
                         return HtmlToken::EOF;
                     }
                 },
 
+                // =============================================================
+                // End tags in RCDATA, RAWTEXT and script data
+                // =============================================================
+                State::RcdataLessThanSign => {
+                    self.text_state = State::Rcdata;
+                    if self.peek() == Some('/') {
+                        self.consume();
+                        self.state = State::TextEndTagOpen;
+                    } else {
+                        self.state = State::Rcdata;
+                        return HtmlToken::Character('<');
+                    }
+                }
+
+                State::RawtextLessThanSign => {
+                    self.text_state = State::Rawtext;
+                    if self.peek() == Some('/') {
+                        self.consume();
+                        self.state = State::TextEndTagOpen;
+                    } else {
+                        self.state = State::Rawtext;
+                        return HtmlToken::Character('<');
+                    }
+                }
+
+                State::ScriptDataLessThanSign => {
+                    self.text_state = State::ScriptData;
+                    match self.peek() {
+                        Some('/') => {
+                            self.consume();
+                            self.state = State::TextEndTagOpen;
+                        }
+                        Some('!') => {
+                            self.consume();
+                            self.state = State::ScriptDataEscapeStart;
+                            self.emit_str("<!");
+                        }
+                        _ => {
+                            self.state = State::ScriptData;
+                            return HtmlToken::Character('<');
+                        }
+                    }
+                }
+
+                State::TextEndTagOpen => match self.peek() {
+                    Some(c) if c.is_ascii_alphabetic() => {
+                        self.start_new_tag(true);
+                        self.temp_buf.clear();
+                        self.state = State::TextEndTagName;
+                    }
+                    _ => {
+                        self.state = self.text_state;
+                        self.emit_str("</");
+                    }
+                },
+
+                State::TextEndTagName => {
+                    let c = self.peek();
+                    match c {
+                        Some(c) if is_whitespace(c) && self.is_appropriate_end_tag() => {
+                            self.consume();
+                            self.state = State::BeforeAttributeName;
+                            continue;
+                        }
+                        Some('/') if self.is_appropriate_end_tag() => {
+                            self.consume();
+                            self.state = State::SelfClosingStartTag;
+                            continue;
+                        }
+                        Some('>') if self.is_appropriate_end_tag() => {
+                            self.consume();
+                            self.state = State::Data;
+                            return self.emit_current_tag();
+                        }
+                        Some(c) if c.is_ascii_alphabetic() => {
+                            self.consume();
+                            self.current_tag_name.push(c.to_ascii_lowercase());
+                            self.temp_buf.push(c);
+                            continue;
+                        }
+                        _ => {}
+                    }
+                    // Not the end tag after all: the characters are text.
+                    self.state = self.text_state;
+                    let buf = std::mem::take(&mut self.temp_buf);
+                    self.emit_str("</");
+                    self.emit_str(&buf);
+                }
+
+                // =============================================================
+                // Script data escapes (`<!--` … `-->` inside a script)
+                // =============================================================
+                State::ScriptDataEscapeStart => {
+                    if self.peek() == Some('-') {
+                        self.consume();
+                        self.state = State::ScriptDataEscapeStartDash;
+                        return HtmlToken::Character('-');
+                    }
+                    self.state = State::ScriptData;
+                }
+
+                State::ScriptDataEscapeStartDash => {
+                    if self.peek() == Some('-') {
+                        self.consume();
+                        self.state = State::ScriptDataEscapedDashDash;
+                        return HtmlToken::Character('-');
+                    }
+                    self.state = State::ScriptData;
+                }
+
+                State::ScriptDataEscaped
+                | State::ScriptDataEscapedDash
+                | State::ScriptDataEscapedDashDash => {
+                    let dashes = self.state;
+                    match self.consume() {
+                        Some('-') => {
+                            self.state = match dashes {
+                                State::ScriptDataEscaped => State::ScriptDataEscapedDash,
+                                _ => State::ScriptDataEscapedDashDash,
+                            };
+                            return HtmlToken::Character('-');
+                        }
+                        Some('<') => {
+                            self.state = State::ScriptDataEscapedLessThanSign;
+                        }
+                        Some('>') if dashes == State::ScriptDataEscapedDashDash => {
+                            self.state = State::ScriptData;
+                            return HtmlToken::Character('>');
+                        }
+                        Some(c) => {
+                            self.state = State::ScriptDataEscaped;
+                            if c == '\0' {
+                                self.error_consumed("unexpected-null-character");
+                                return HtmlToken::Character('\u{FFFD}');
+                            }
+                            return HtmlToken::Character(c);
+                        }
+                        None => {
+                            self.error("eof-in-script-html-comment-like-text");
+                            self.done = true;
+                            return HtmlToken::EOF;
+                        }
+                    }
+                }
+
+                State::ScriptDataEscapedLessThanSign => {
+                    self.text_state = State::ScriptDataEscaped;
+                    match self.peek() {
+                        Some('/') => {
+                            self.consume();
+                            self.state = State::TextEndTagOpen;
+                        }
+                        Some(c) if c.is_ascii_alphabetic() => {
+                            self.temp_buf.clear();
+                            self.state = State::ScriptDataDoubleEscapeStart;
+                            return HtmlToken::Character('<');
+                        }
+                        _ => {
+                            self.state = State::ScriptDataEscaped;
+                            return HtmlToken::Character('<');
+                        }
+                    }
+                }
+
+                State::ScriptDataDoubleEscapeStart | State::ScriptDataDoubleEscapeEnd => {
+                    let starting = self.state == State::ScriptDataDoubleEscapeStart;
+                    let (script, other) = if starting {
+                        (State::ScriptDataDoubleEscaped, State::ScriptDataEscaped)
+                    } else {
+                        (State::ScriptDataEscaped, State::ScriptDataDoubleEscaped)
+                    };
+                    match self.peek() {
+                        Some(c) if is_whitespace(c) || c == '/' || c == '>' => {
+                            self.consume();
+                            self.state = if self.temp_buf == "script" { script } else { other };
+                            return HtmlToken::Character(c);
+                        }
+                        Some(c) if c.is_ascii_alphabetic() => {
+                            self.consume();
+                            self.temp_buf.push(c.to_ascii_lowercase());
+                            return HtmlToken::Character(c);
+                        }
+                        _ => {
+                            self.state = other;
+                        }
+                    }
+                }
+
+                State::ScriptDataDoubleEscaped
+                | State::ScriptDataDoubleEscapedDash
+                | State::ScriptDataDoubleEscapedDashDash => {
+                    let dashes = self.state;
+                    match self.consume() {
+                        Some('-') => {
+                            self.state = match dashes {
+                                State::ScriptDataDoubleEscaped => State::ScriptDataDoubleEscapedDash,
+                                _ => State::ScriptDataDoubleEscapedDashDash,
+                            };
+                            return HtmlToken::Character('-');
+                        }
+                        Some('<') => {
+                            self.state = State::ScriptDataDoubleEscapedLessThanSign;
+                            return HtmlToken::Character('<');
+                        }
+                        Some('>') if dashes == State::ScriptDataDoubleEscapedDashDash => {
+                            self.state = State::ScriptData;
+                            return HtmlToken::Character('>');
+                        }
+                        Some(c) => {
+                            self.state = State::ScriptDataDoubleEscaped;
+                            if c == '\0' {
+                                self.error_consumed("unexpected-null-character");
+                                return HtmlToken::Character('\u{FFFD}');
+                            }
+                            return HtmlToken::Character(c);
+                        }
+                        None => {
+                            self.error("eof-in-script-html-comment-like-text");
+                            self.done = true;
+                            return HtmlToken::EOF;
+                        }
+                    }
+                }
+
+                State::ScriptDataDoubleEscapedLessThanSign => {
+                    if self.peek() == Some('/') {
+                        self.consume();
+                        self.temp_buf.clear();
+                        self.state = State::ScriptDataDoubleEscapeEnd;
+                        return HtmlToken::Character('/');
+                    }
+                    self.state = State::ScriptDataDoubleEscaped;
+                }
+
                 // =============================================================
                 // Before attribute name
                 // =============================================================
                 State::BeforeAttributeName => match self.peek() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {
+                    Some(c) if is_whitespace(c) => {
                         self.consume();
                     }
                     Some('/') | Some('>') | None => {
                         self.state = State::AfterAttributeName;
                     }
                     Some('=') => {
+                        self.error("unexpected-equals-sign-before-attribute-name");
+                        self.start_new_attr();
                         self.consume();
-                        self.current_attr_name.clear();
                         self.current_attr_name.push('=');
-                        self.current_attr_value.clear();
                         self.state = State::AttributeName;
                     }
                     _ => {
-                        self.current_attr_name.clear();
-                        self.current_attr_value.clear();
+                        self.start_new_attr();
                         self.state = State::AttributeName;
                     }
                 },
@@ -388,32 +1039,40 @@ impl Tokenizer {
                 // =============================================================
                 // Attribute name
                 // =============================================================
-                State::AttributeName => match self.consume() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') | Some('/') | Some('>') => {
-                        self.reconsume();
+                State::AttributeName => match self.peek() {
+                    Some(c) if is_whitespace(c) || c == '/' || c == '>' => {
+                        self.state = State::AfterAttributeName;
+                    }
+                    None => {
                         self.state = State::AfterAttributeName;
                     }
                     Some('=') => {
+                        self.consume();
                         self.state = State::BeforeAttributeValue;
                     }
+                    Some('\0') => {
+                        self.error("unexpected-null-character");
+                        self.consume();
+                        self.current_attr_name.push('\u{FFFD}');
+                    }
                     Some(c) => {
+                        if matches!(c, '"' | '\'' | '<') {
+                            self.error("unexpected-character-in-attribute-name");
+                        }
+                        self.consume();
                         self.current_attr_name.push(c.to_ascii_lowercase());
                     }
-                    None => {
-                        self.state = State::AfterAttributeName;
-                    }
                 },
 
                 // =============================================================
                 // After attribute name
                 // =============================================================
                 State::AfterAttributeName => match self.peek() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {
+                    Some(c) if is_whitespace(c) => {
                         self.consume();
                     }
                     Some('/') => {
                         self.consume();
-                        self.finish_attr();
                         self.state = State::SelfClosingStartTag;
                     }
                     Some('=') => {
@@ -422,19 +1081,16 @@ impl Tokenizer {
                     }
                     Some('>') => {
                         self.consume();
-                        self.finish_attr();
                         self.state = State::Data;
                         return self.emit_current_tag();
                     }
                     None => {
-                        self.finish_attr();
+                        self.error("eof-in-tag");
                         self.done = true;
                         return HtmlToken::EOF;
                     }
                     _ => {
-                        self.finish_attr();
-                        self.current_attr_name.clear();
-                        self.current_attr_value.clear();
+                        self.start_new_attr();
                         self.state = State::AttributeName;
                     }
                 },
@@ -443,7 +1099,7 @@ impl Tokenizer {
                 // Before attribute value
                 // =============================================================
                 State::BeforeAttributeValue => match self.peek() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {
+                    Some(c) if is_whitespace(c) => {
                         self.consume();
                     }
                     Some('"') => {
@@ -455,8 +1111,8 @@ impl Tokenizer {
                         self.state = State::AttributeValueSingleQuoted;
                     }
                     Some('>') => {
+                        self.error("missing-attribute-value");
                         self.consume();
-                        self.finish_attr();
                         self.state = State::Data;
                         return self.emit_current_tag();
                     }
@@ -466,61 +1122,35 @@ impl Tokenizer {
                 },
 
                 // =============================================================
-                // Attribute value (double-quoted)
-                // =============================================================
-                State::AttributeValueDoubleQuoted => match self.consume() {
-                    Some('"') => {
-                        self.finish_attr();
-                        self.state = State::AfterAttributeValueQuoted;
-                    }
-                    Some('&') => {
-                        self.return_state = State::AttributeValueDoubleQuoted;
-                        self.state = State::CharacterReference;
-                    }
-                    Some('\0') => {
-                        self.current_attr_value.push('\u{FFFD}');
-                    }
-                    Some(c) => {
-                        self.current_attr_value.push(c);
-                    }
-                    None => {
-                        self.finish_attr();
-                        self.done = true;
-                        return HtmlToken::EOF;
-                    }
-                },
-
-                // =============================================================
-                // Attribute value (single-quoted)
+                // Attribute values
                 // =============================================================
-                State::AttributeValueSingleQuoted => match self.consume() {
-                    Some('\'') => {
-                        self.finish_attr();
-                        self.state = State::AfterAttributeValueQuoted;
-                    }
-                    Some('&') => {
-                        self.return_state = State::AttributeValueSingleQuoted;
-                        self.state = State::CharacterReference;
-                    }
-                    Some('\0') => {
-                        self.current_attr_value.push('\u{FFFD}');
-                    }
-                    Some(c) => {
-                        self.current_attr_value.push(c);
-                    }
-                    None => {
-                        self.finish_attr();
-                        self.done = true;
-                        return HtmlToken::EOF;
+                State::AttributeValueDoubleQuoted | State::AttributeValueSingleQuoted => {
+                    let quote = if self.state == State::AttributeValueDoubleQuoted { '"' } else { '\'' };
+                    match self.consume() {
+                        Some(c) if c == quote => {
+                            self.state = State::AfterAttributeValueQuoted;
+                        }
+                        Some('&') => {
+                            self.return_state = self.state;
+                            self.state = State::CharacterReference;
+                        }
+                        Some('\0') => {
+                            self.error_consumed("unexpected-null-character");
+                            self.current_attr_value.push('\u{FFFD}');
+                        }
+                        Some(c) => {
+                            self.current_attr_value.push(c);
+                        }
+                        None => {
+                            self.error("eof-in-tag");
+                            self.done = true;
+                            return HtmlToken::EOF;
+                        }
                     }
-                },
+                }
 
-                // =============================================================
-                // Attribute value (unquoted)
-                // =============================================================
                 State::AttributeValueUnquoted => match self.consume() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {
-                        self.finish_attr();
+                    Some(c) if is_whitespace(c) => {
                         self.state = State::BeforeAttributeName;
                     }
                     Some('&') => {
@@ -528,18 +1158,21 @@ impl Tokenizer {
                         self.state = State::CharacterReference;
                     }
                     Some('>') => {
-                        self.finish_attr();
                         self.state = State::Data;
                         return self.emit_current_tag();
                     }
                     Some('\0') => {
+                        self.error_consumed("unexpected-null-character");
                         self.current_attr_value.push('\u{FFFD}');
                     }
                     Some(c) => {
+                        if matches!(c, '"' | '\'' | '<' | '=' | '`') {
+                            self.error_consumed("unexpected-character-in-unquoted-attribute-value");
+                        }
                         self.current_attr_value.push(c);
                     }
                     None => {
-                        self.finish_attr();
+                        self.error("eof-in-tag");
                         self.done = true;
                         return HtmlToken::EOF;
                     }
@@ -549,7 +1182,7 @@ impl Tokenizer {
                 // After attribute value (quoted)
                 // =============================================================
                 State::AfterAttributeValueQuoted => match self.peek() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {
+                    Some(c) if is_whitespace(c) => {
                         self.consume();
                         self.state = State::BeforeAttributeName;
                     }
@@ -563,10 +1196,12 @@ impl Tokenizer {
                         return self.emit_current_tag();
                     }
                     None => {
+                        self.error("eof-in-tag");
                         self.done = true;
                         return HtmlToken::EOF;
                     }
                     _ => {
+                        self.error("missing-whitespace-between-attributes");
                         self.state = State::BeforeAttributeName;
                     }
                 },
@@ -582,10 +1217,12 @@ impl Tokenizer {
                         return self.emit_current_tag();
                     }
                     None => {
+                        self.error("eof-in-tag");
                         self.done = true;
                         return HtmlToken::EOF;
                     }
                     _ => {
+                        self.error("unexpected-solidus-in-tag");
                         self.state = State::BeforeAttributeName;
                     }
                 },
@@ -599,6 +1236,7 @@ impl Tokenizer {
                         return self.emit_current_comment();
                     }
                     Some('\0') => {
+                        self.error_consumed("unexpected-null-character");
                         self.current_comment.push('\u{FFFD}');
                     }
                     Some(c) => {
@@ -622,12 +1260,58 @@ impl Tokenizer {
                     } else if self.lookahead_ci("DOCTYPE") {
                         self.consume_n(7);
                         self.state = State::Doctype;
+                    } else if self.cdata_allowed && self.input[self.pos..].starts_with(&CDATA_OPEN) {
+                        self.consume_n(7);
+                        self.state = State::CdataSection;
                     } else {
+                        self.error(if self.input[self.pos..].starts_with(&CDATA_OPEN) {
+                            "cdata-in-html-content"
+                        } else {
+                            "incorrectly-opened-comment"
+                        });
                         self.current_comment.clear();
                         self.state = State::BogusComment;
                     }
                 }
 
+                // =============================================================
+                // CDATA section states
+                // =============================================================
+                State::CdataSection => match self.consume() {
+                    Some(']') => self.state = State::CdataSectionBracket,
+                    Some(c) => return HtmlToken::Character(c),
+                    None => {
+                        self.error("eof-in-cdata");
+                        self.done = true;
+                        return HtmlToken::EOF;
+                    }
+                },
+
+                State::CdataSectionBracket => {
+                    if self.peek() == Some(']') {
+                        self.consume();
+                        self.state = State::CdataSectionEnd;
+                    } else {
+                        self.state = State::CdataSection;
+                        return HtmlToken::Character(']');
+                    }
+                }
+
+                State::CdataSectionEnd => match self.peek() {
+                    Some(']') => {
+                        self.consume();
+                        return HtmlToken::Character(']');
+                    }
+                    Some('>') => {
+                        self.consume();
+                        self.state = State::Data;
+                    }
+                    _ => {
+                        self.state = State::CdataSection;
+                        self.emit_str("]]");
+                    }
+                },
+
                 // =============================================================
                 // Comment states
                 // =============================================================
@@ -637,6 +1321,7 @@ impl Tokenizer {
                         self.state = State::CommentStartDash;
                     }
                     Some('>') => {
+                        self.error("abrupt-closing-of-empty-comment");
                         self.consume();
                         self.state = State::Data;
                         return self.emit_current_comment();
@@ -652,11 +1337,13 @@ impl Tokenizer {
                         self.state = State::CommentEnd;
                     }
                     Some('>') => {
+                        self.error("abrupt-closing-of-empty-comment");
                         self.consume();
                         self.state = State::Data;
                         return self.emit_current_comment();
                     }
                     None => {
+                        self.error("eof-in-comment");
                         self.done = true;
                         return self.emit_current_comment();
                     }
@@ -667,19 +1354,21 @@ impl Tokenizer {
                 },
 
                 State::Comment => match self.consume() {
-                    Some('<') => {
-                        self.current_comment.push('<');
-                    }
                     Some('-') => {
                         self.state = State::CommentEndDash;
                     }
                     Some('\0') => {
+                        self.error_consumed("unexpected-null-character");
                         self.current_comment.push('\u{FFFD}');
                     }
                     Some(c) => {
+                        if c == '<' && self.lookahead_ci("!--") {
+                            self.error_consumed("nested-comment");
+                        }
                         self.current_comment.push(c);
                     }
                     None => {
+                        self.error("eof-in-comment");
                         self.done = true;
                         return self.emit_current_comment();
                     }
@@ -691,6 +1380,7 @@ impl Tokenizer {
                         self.state = State::CommentEnd;
                     }
                     None => {
+                        self.error("eof-in-comment");
                         self.done = true;
                         return self.emit_current_comment();
                     }
@@ -715,12 +1405,12 @@ impl Tokenizer {
                         self.current_comment.push('-');
                     }
                     None => {
+                        self.error("eof-in-comment");
                         self.done = true;
                         return self.emit_current_comment();
                     }
                     _ => {
-                        self.current_comment.push('-');
-                        self.current_comment.push('-');
+                        self.current_comment.push_str("--");
                         self.state = State::Comment;
                     }
                 },
@@ -728,24 +1418,22 @@ impl Tokenizer {
                 State::CommentEndBang => match self.peek() {
                     Some('-') => {
                         self.consume();
-                        self.current_comment.push('-');
-                        self.current_comment.push('-');
-                        self.current_comment.push('!');
+                        self.current_comment.push_str("--!");
                         self.state = State::CommentEndDash;
                     }
                     Some('>') => {
+                        self.error("incorrectly-closed-comment");
                         self.consume();
                         self.state = State::Data;
                         return self.emit_current_comment();
                     }
                     None => {
+                        self.error("eof-in-comment");
                         self.done = true;
                         return self.emit_current_comment();
                     }
                     _ => {
-                        self.current_comment.push('-');
-                        self.current_comment.push('-');
-                        self.current_comment.push('!');
+                        self.current_comment.push_str("--!");
                         self.state = State::Comment;
                     }
                 },
@@ -754,48 +1442,44 @@ impl Tokenizer {
                 // DOCTYPE states
                 // =============================================================
                 State::Doctype => match self.peek() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {
+                    Some(c) if is_whitespace(c) => {
                         self.consume();
                         self.state = State::BeforeDoctypeName;
                     }
-                    Some('>') => {
-                        self.state = State::BeforeDoctypeName;
-                    }
                     None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
-                        return self.emit_current_doctype();
+                        return self.emit_doctype_at_eof();
                     }
                     _ => {
+                        self.error("missing-whitespace-before-doctype-name");
                         self.state = State::BeforeDoctypeName;
                     }
                 },
 
                 State::BeforeDoctypeName => match self.consume() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {}
+                    Some(c) if is_whitespace(c) => {}
                     Some('>') => {
+                        self.error_consumed("missing-doctype-name");
                         self.current_doctype_force_quirks = true;
                         self.state = State::Data;
                         return self.emit_current_doctype();
                     }
                     Some(c) => {
-                        self.current_doctype_name = Some(String::new());
-                        self.current_doctype_name
-                            .as_mut()
-                            .unwrap()
-                            .push(c.to_ascii_lowercase());
-                        self.current_doctype_force_quirks = false;
+                        let c = if c == '\0' {
+                            self.error_consumed("unexpected-null-character");
+                            '\u{FFFD}'
+                        } else {
+                            c.to_ascii_lowercase()
+                        };
+                        self.doctype_name().push(c);
                         self.state = State::DoctypeName;
                     }
                     None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
-                        return self.emit_current_doctype();
+                        return self.emit_doctype_at_eof();
                     }
                 },
 
                 State::DoctypeName => match self.consume() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {
+                    Some(c) if is_whitespace(c) => {
                         self.state = State::AfterDoctypeName;
                     }
                     Some('>') => {
@@ -803,297 +1487,198 @@ impl Tokenizer {
                         return self.emit_current_doctype();
                     }
                     Some(c) => {
-                        self.current_doctype_name
-                            .as_mut()
-                            .unwrap()
-                            .push(c.to_ascii_lowercase());
+                        let c = if c == '\0' {
+                            self.error_consumed("unexpected-null-character");
+                            '\u{FFFD}'
+                        } else {
+                            c.to_ascii_lowercase()
+                        };
+                        self.doctype_name().push(c);
                     }
                     None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
+                        return self.emit_doctype_at_eof();
+                    }
+                },
+
+                State::AfterDoctypeName => match self.peek() {
+                    Some(c) if is_whitespace(c) => {
+                        self.consume();
+                    }
+                    Some('>') => {
+                        self.consume();
+                        self.state = State::Data;
                         return self.emit_current_doctype();
                     }
+                    None => {
+                        return self.emit_doctype_at_eof();
+                    }
+                    _ => {
+                        if self.lookahead_ci("PUBLIC") {
+                            self.consume_n(6);
+                            self.state = State::AfterDoctypePublicKeyword;
+                        } else if self.lookahead_ci("SYSTEM") {
+                            self.consume_n(6);
+                            self.state = State::AfterDoctypeSystemKeyword;
+                        } else {
+                            self.error("invalid-character-sequence-after-doctype-name");
+                            self.current_doctype_force_quirks = true;
+                            self.state = State::BogusDoctype;
+                        }
+                    }
                 },
 
-                State::AfterDoctypeName => {
+                // The keyword states and the states before an identifier
+                // differ only in whether whitespace is required first.
+                State::AfterDoctypePublicKeyword
+                | State::BeforeDoctypePublicId
+                | State::AfterDoctypeSystemKeyword
+                | State::BeforeDoctypeSystemId => {
+                    let public = matches!(
+                        self.state,
+                        State::AfterDoctypePublicKeyword | State::BeforeDoctypePublicId
+                    );
+                    let after_keyword = matches!(
+                        self.state,
+                        State::AfterDoctypePublicKeyword | State::AfterDoctypeSystemKeyword
+                    );
                     match self.peek() {
-                        Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {
+                        Some(c) if is_whitespace(c) => {
+                            self.consume();
+                            self.state = if public {
+                                State::BeforeDoctypePublicId
+                            } else {
+                                State::BeforeDoctypeSystemId
+                            };
+                        }
+                        Some(quote @ ('"' | '\'')) => {
+                            if after_keyword {
+                                self.error(if public {
+                                    "missing-whitespace-after-doctype-public-keyword"
+                                } else {
+                                    "missing-whitespace-after-doctype-system-keyword"
+                                });
+                            }
                             self.consume();
+                            self.start_doctype_id(public, quote);
                         }
                         Some('>') => {
+                            self.error(if public {
+                                "missing-doctype-public-identifier"
+                            } else {
+                                "missing-doctype-system-identifier"
+                            });
                             self.consume();
+                            self.current_doctype_force_quirks = true;
                             self.state = State::Data;
                             return self.emit_current_doctype();
                         }
                         None => {
-                            self.current_doctype_force_quirks = true;
-                            self.done = true;
-                            return self.emit_current_doctype();
+                            return self.emit_doctype_at_eof();
                         }
                         _ => {
-                            if self.lookahead_ci("PUBLIC") {
-                                self.consume_n(6);
-                                self.state = State::AfterDoctypePublicKeyword;
-                            } else if self.lookahead_ci("SYSTEM") {
-                                self.consume_n(6);
-                                self.state = State::AfterDoctypeSystemKeyword;
+                            self.error(if public {
+                                "missing-quote-before-doctype-public-identifier"
                             } else {
-                                self.current_doctype_force_quirks = true;
-                                self.state = State::BogusDoctype;
-                            }
+                                "missing-quote-before-doctype-system-identifier"
+                            });
+                            self.current_doctype_force_quirks = true;
+                            self.state = State::BogusDoctype;
                         }
                     }
                 }
 
-                State::AfterDoctypePublicKeyword => match self.consume() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {
-                        self.state = State::BeforeDoctypePublicId;
-                    }
-                    Some('"') => {
-                        self.current_doctype_public_id = Some(String::new());
-                        self.state = State::DoctypePublicIdDoubleQuoted;
-                    }
-                    Some('\'') => {
-                        self.current_doctype_public_id = Some(String::new());
-                        self.state = State::DoctypePublicIdSingleQuoted;
-                    }
-                    Some('>') => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::Data;
-                        return self.emit_current_doctype();
-                    }
-                    None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
-                        return self.emit_current_doctype();
-                    }
-                    _ => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::BogusDoctype;
-                    }
-                },
-
-                State::BeforeDoctypePublicId => match self.consume() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {}
-                    Some('"') => {
-                        self.current_doctype_public_id = Some(String::new());
-                        self.state = State::DoctypePublicIdDoubleQuoted;
-                    }
-                    Some('\'') => {
-                        self.current_doctype_public_id = Some(String::new());
-                        self.state = State::DoctypePublicIdSingleQuoted;
-                    }
-                    Some('>') => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::Data;
-                        return self.emit_current_doctype();
-                    }
-                    None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
-                        return self.emit_current_doctype();
-                    }
-                    _ => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::BogusDoctype;
-                    }
-                },
-
-                State::DoctypePublicIdDoubleQuoted => match self.consume() {
-                    Some('"') => {
-                        self.state = State::AfterDoctypePublicId;
-                    }
-                    Some('>') => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::Data;
-                        return self.emit_current_doctype();
-                    }
-                    Some(c) => {
-                        self.current_doctype_public_id.as_mut().unwrap().push(c);
-                    }
-                    None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
-                        return self.emit_current_doctype();
-                    }
-                },
-
-                State::DoctypePublicIdSingleQuoted => match self.consume() {
-                    Some('\'') => {
-                        self.state = State::AfterDoctypePublicId;
-                    }
-                    Some('>') => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::Data;
-                        return self.emit_current_doctype();
-                    }
-                    Some(c) => {
-                        self.current_doctype_public_id.as_mut().unwrap().push(c);
-                    }
-                    None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
-                        return self.emit_current_doctype();
-                    }
-                },
-
-                State::AfterDoctypePublicId => match self.consume() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {
-                        self.state = State::BetweenDoctypePublicAndSystem;
-                    }
-                    Some('>') => {
-                        self.state = State::Data;
-                        return self.emit_current_doctype();
-                    }
-                    Some('"') => {
-                        self.current_doctype_system_id = Some(String::new());
-                        self.state = State::DoctypeSystemIdDoubleQuoted;
-                    }
-                    Some('\'') => {
-                        self.current_doctype_system_id = Some(String::new());
-                        self.state = State::DoctypeSystemIdSingleQuoted;
-                    }
-                    None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
-                        return self.emit_current_doctype();
-                    }
-                    _ => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::BogusDoctype;
-                    }
-                },
-
-                State::BetweenDoctypePublicAndSystem => match self.consume() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {}
-                    Some('>') => {
-                        self.state = State::Data;
-                        return self.emit_current_doctype();
-                    }
-                    Some('"') => {
-                        self.current_doctype_system_id = Some(String::new());
-                        self.state = State::DoctypeSystemIdDoubleQuoted;
-                    }
-                    Some('\'') => {
-                        self.current_doctype_system_id = Some(String::new());
-                        self.state = State::DoctypeSystemIdSingleQuoted;
-                    }
-                    None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
-                        return self.emit_current_doctype();
-                    }
-                    _ => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::BogusDoctype;
-                    }
-                },
-
-                State::AfterDoctypeSystemKeyword => match self.consume() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {
-                        self.state = State::BeforeDoctypeSystemId;
-                    }
-                    Some('"') => {
-                        self.current_doctype_system_id = Some(String::new());
-                        self.state = State::DoctypeSystemIdDoubleQuoted;
-                    }
-                    Some('\'') => {
-                        self.current_doctype_system_id = Some(String::new());
-                        self.state = State::DoctypeSystemIdSingleQuoted;
-                    }
-                    Some('>') => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::Data;
-                        return self.emit_current_doctype();
-                    }
-                    None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
-                        return self.emit_current_doctype();
-                    }
-                    _ => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::BogusDoctype;
-                    }
-                },
-
-                State::BeforeDoctypeSystemId => match self.consume() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {}
-                    Some('"') => {
-                        self.current_doctype_system_id = Some(String::new());
-                        self.state = State::DoctypeSystemIdDoubleQuoted;
-                    }
-                    Some('\'') => {
-                        self.current_doctype_system_id = Some(String::new());
-                        self.state = State::DoctypeSystemIdSingleQuoted;
-                    }
-                    Some('>') => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::Data;
-                        return self.emit_current_doctype();
-                    }
-                    None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
-                        return self.emit_current_doctype();
-                    }
-                    _ => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::BogusDoctype;
+                State::DoctypePublicIdDoubleQuoted
+                | State::DoctypePublicIdSingleQuoted
+                | State::DoctypeSystemIdDoubleQuoted
+                | State::DoctypeSystemIdSingleQuoted => {
+                    let (public, quote) = match self.state {
+                        State::DoctypePublicIdDoubleQuoted => (true, '"'),
+                        State::DoctypePublicIdSingleQuoted => (true, '\''),
+                        State::DoctypeSystemIdDoubleQuoted => (false, '"'),
+                        _ => (false, '\''),
+                    };
+                    match self.consume() {
+                        Some(c) if c == quote => {
+                            self.state = if public {
+                                State::AfterDoctypePublicId
+                            } else {
+                                State::AfterDoctypeSystemId
+                            };
+                        }
+                        Some('>') => {
+                            self.error_consumed(if public {
+                                "abrupt-doctype-public-identifier"
+                            } else {
+                                "abrupt-doctype-system-identifier"
+                            });
+                            self.current_doctype_force_quirks = true;
+                            self.state = State::Data;
+                            return self.emit_current_doctype();
+                        }
+                        Some(c) => {
+                            let c = if c == '\0' {
+                                self.error_consumed("unexpected-null-character");
+                                '\u{FFFD}'
+                            } else {
+                                c
+                            };
+                            let id = if public {
+                                &mut self.current_doctype_public_id
+                            } else {
+                                &mut self.current_doctype_system_id
+                            };
+                            id.get_or_insert_with(String::new).push(c);
+                        }
+                        None => {
+                            return self.emit_doctype_at_eof();
+                        }
                     }
-                },
+                }
 
-                State::DoctypeSystemIdDoubleQuoted => match self.consume() {
-                    Some('"') => {
-                        self.state = State::AfterDoctypeSystemId;
-                    }
-                    Some('>') => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::Data;
-                        return self.emit_current_doctype();
-                    }
-                    Some(c) => {
-                        self.current_doctype_system_id.as_mut().unwrap().push(c);
-                    }
-                    None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
-                        return self.emit_current_doctype();
+                State::AfterDoctypePublicId | State::BetweenDoctypePublicAndSystem => {
+                    match self.peek() {
+                        Some(c) if is_whitespace(c) => {
+                            self.consume();
+                            self.state = State::BetweenDoctypePublicAndSystem;
+                        }
+                        Some('>') => {
+                            self.consume();
+                            self.state = State::Data;
+                            return self.emit_current_doctype();
+                        }
+                        Some(quote @ ('"' | '\'')) => {
+                            if self.state == State::AfterDoctypePublicId {
+                                self.error("missing-whitespace-between-doctype-public-and-system-identifiers");
+                            }
+                            self.consume();
+                            self.start_doctype_id(false, quote);
+                        }
+                        None => {
+                            return self.emit_doctype_at_eof();
+                        }
+                        _ => {
+                            self.error("missing-quote-before-doctype-system-identifier");
+                            self.current_doctype_force_quirks = true;
+                            self.state = State::BogusDoctype;
+                        }
                     }
-                },
+                }
 
-                State::DoctypeSystemIdSingleQuoted => match self.consume() {
-                    Some('\'') => {
-                        self.state = State::AfterDoctypeSystemId;
-                    }
-                    Some('>') => {
-                        self.current_doctype_force_quirks = true;
-                        self.state = State::Data;
-                        return self.emit_current_doctype();
-                    }
-                    Some(c) => {
-                        self.current_doctype_system_id.as_mut().unwrap().push(c);
-                    }
-                    None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
-                        return self.emit_current_doctype();
+                State::AfterDoctypeSystemId => match self.peek() {
+                    Some(c) if is_whitespace(c) => {
+                        self.consume();
                     }
-                },
-
-                State::AfterDoctypeSystemId => match self.consume() {
-                    Some('\t') | Some('\n') | Some('\x0C') | Some(' ') => {}
                     Some('>') => {
+                        self.consume();
                         self.state = State::Data;
                         return self.emit_current_doctype();
                     }
                     None => {
-                        self.current_doctype_force_quirks = true;
-                        self.done = true;
-                        return self.emit_current_doctype();
+                        return self.emit_doctype_at_eof();
                     }
                     _ => {
                         // Not force quirks – go to bogus
+                        self.error("unexpected-character-after-doctype-system-identifier");
                         self.state = State::BogusDoctype;
                     }
                 },
@@ -1107,73 +1692,10 @@ impl Tokenizer {
                         self.done = true;
                         return self.emit_current_doctype();
                     }
+                    Some('\0') => self.error_consumed("unexpected-null-character"),
                     _ => {}
                 },
 
-                // =============================================================
-                // Raw text state (for <script>, <style>, etc.)
-                // =============================================================
-                State::RawText => match self.consume() {
-                    Some('<') => {
-                        if self.peek() == Some('/') {
-                            let saved_pos = self.pos;
-                            self.consume(); // consume '/'
-                            let tag_len = self.rawtext_end_tag.len();
-                            let mut matched = tag_len > 0
-                                && self.pos + tag_len <= self.input.len();
-                            if matched {
-                                for (i, expected_ch) in
-                                    self.rawtext_end_tag.chars().enumerate()
-                                {
-                                    let actual =
-                                        self.input[self.pos + i].to_ascii_lowercase();
-                                    if actual != expected_ch {
-                                        matched = false;
-                                        break;
-                                    }
-                                }
-                            }
-                            if matched {
-                                let after_pos = self.pos + tag_len;
-                                let after_ch = self.input.get(after_pos).copied();
-                                if after_ch == Some('>')
-                                    || after_ch == Some('/')
-                                    || after_ch == Some(' ')
-                                    || after_ch == Some('\t')
-                                    || after_ch == Some('\n')
-                                    || after_ch == Some('\x0C')
-                                    || after_ch.is_none()
-                                {
-                                    self.pos += tag_len;
-                                    while let Some(c) = self.peek() {
-                                        self.consume();
-                                        if c == '>' {
-                                            break;
-                                        }
-                                    }
-                                    self.state = State::Data;
-                                    let end_name = std::mem::take(&mut self.rawtext_end_tag);
-                                    return HtmlToken::EndTag {
-                                        name: end_name,
-                                    };
-                                }
-                            }
-                            self.pos = saved_pos;
-                            return HtmlToken::Character('<');
-                        } else {
-                            return HtmlToken::Character('<');
-                        }
-                    }
-                    Some(c) => {
-                        return HtmlToken::Character(c);
-                    }
-                    None => {
-                        self.state = State::Data;
-                        self.done = true;
-                        return HtmlToken::EOF;
-                    }
-                },
-
                 // =============================================================
                 // Character reference states
                 // =============================================================
@@ -1197,129 +1719,143 @@ impl Tokenizer {
                 }
 
                 State::NamedCharacterReference => {
-                    // We support a small set of named references:
-                    // &amp; &lt; &gt; &quot; &apos;
-                    let remaining = &self.input[self.pos..];
-                    let remaining_str: String = remaining.iter().collect();
-
-                    let (name, replacement) = if remaining_str.starts_with("amp;") {
-                        ("amp;", '&')
-                    } else if remaining_str.starts_with("lt;") {
-                        ("lt;", '<')
-                    } else if remaining_str.starts_with("gt;") {
-                        ("gt;", '>')
-                    } else if remaining_str.starts_with("quot;") {
-                        ("quot;", '"')
-                    } else if remaining_str.starts_with("apos;") {
-                        ("apos;", '\'')
-                    } else if remaining_str.starts_with("nbsp;") {
-                        ("nbsp;", '\u{00A0}')
-                    } else {
-                        // Unknown named ref – just flush the '&'
+                    // Longest match against the full WHATWG table.
+                    let matched = entities::longest_match(self.input[self.pos..].iter().copied());
+                    let Some((len, replacement)) = matched else {
+                        // No match: the '&' is literal, and the name is read
+                        // again as ordinary text.
+                        let name_len = self.input[self.pos..]
+                            .iter()
+                            .take_while(|c| c.is_ascii_alphanumeric())
+                            .count();
+                        if self.input.get(self.pos + name_len) == Some(&';') {
+                            self.error("unknown-named-character-reference");
+                        }
                         self.flush_code_points_consumed_as_char_ref();
                         self.state = self.return_state;
                         continue;
                     };
+                    self.temp_buf.extend(&self.input[self.pos..self.pos + len]);
+                    self.consume_n(len);
+
+                    // Legacy references without ';' are left alone inside
+                    // attribute values when followed by '=' or an
+                    // alphanumeric, so URLs like "?a=1&copy=2" survive.
+                    let ends_with_semicolon = self.temp_buf.ends_with(';');
+                    if self.in_attribute_value()
+                        && !ends_with_semicolon
+                        && self.peek().is_some_and(|c| c == '=' || c.is_ascii_alphanumeric())
+                    {
+                        self.flush_code_points_consumed_as_char_ref();
+                        self.state = self.return_state;
+                        continue;
+                    }
 
-                    self.consume_n(name.len());
-                    self.temp_buf.clear();
-
-                    // If in an attribute value, append to attr value; otherwise emit as character
-                    match self.return_state {
-                        State::AttributeValueDoubleQuoted
-                        | State::AttributeValueSingleQuoted
-                        | State::AttributeValueUnquoted => {
-                            self.current_attr_value.push(replacement);
-                        }
-                        _ => {
-                            self.pending.push(HtmlToken::Character(replacement));
-                        }
+                    if !ends_with_semicolon {
+                        self.error("missing-semicolon-after-character-reference");
                     }
+                    self.temp_buf.clear();
+                    replacement.push_to(&mut self.temp_buf);
+                    self.flush_code_points_consumed_as_char_ref();
                     self.state = self.return_state;
                 }
 
                 State::NumericCharacterReference => {
                     self.char_ref_code = 0;
                     match self.peek() {
-                        Some('x') | Some('X') => {
+                        Some(c @ ('x' | 'X')) => {
                             self.consume();
-                            self.temp_buf.push('x');
+                            self.temp_buf.push(c);
                             self.state = State::HexCharacterReferenceStart;
                         }
                         _ => {
-                            self.state = State::DecimalCharacterReference;
+                            self.state = State::DecimalCharacterReferenceStart;
                         }
                     }
                 }
 
-                State::HexCharacterReferenceStart => match self.peek() {
-                    Some(c) if c.is_ascii_hexdigit() => {
-                        self.state = State::HexCharacterReference;
-                    }
-                    _ => {
-                        self.flush_code_points_consumed_as_char_ref();
-                        self.state = self.return_state;
-                    }
-                },
-
-                State::HexCharacterReference => match self.peek() {
-                    Some(c) if c.is_ascii_hexdigit() => {
-                        self.consume();
-                        self.char_ref_code =
-                            self.char_ref_code.saturating_mul(16).saturating_add(
-                                c.to_digit(16).unwrap(),
-                            );
-                    }
-                    Some(';') => {
-                        self.consume();
-                        self.state = State::NumericCharacterReferenceEnd;
-                    }
-                    _ => {
-                        self.state = State::NumericCharacterReferenceEnd;
+                State::HexCharacterReferenceStart | State::DecimalCharacterReferenceStart => {
+                    let hex = self.state == State::HexCharacterReferenceStart;
+                    match self.peek() {
+                        Some(c) if c.is_digit(if hex { 16 } else { 10 }) => {
+                            self.state = if hex {
+                                State::HexCharacterReference
+                            } else {
+                                State::DecimalCharacterReference
+                            };
+                        }
+                        _ => {
+                            // "&#" or "&#x" with no digits stays literal.
+                            self.error("absence-of-digits-in-numeric-character-reference");
+                            self.flush_code_points_consumed_as_char_ref();
+                            self.state = self.return_state;
+                        }
                     }
-                },
+                }
 
-                State::DecimalCharacterReference => match self.peek() {
-                    Some(c) if c.is_ascii_digit() => {
-                        self.consume();
-                        self.char_ref_code =
-                            self.char_ref_code.saturating_mul(10).saturating_add(
-                                c.to_digit(10).unwrap(),
-                            );
-                    }
-                    Some(';') => {
-                        self.consume();
-                        self.state = State::NumericCharacterReferenceEnd;
-                    }
-                    _ => {
-                        self.state = State::NumericCharacterReferenceEnd;
+                State::HexCharacterReference | State::DecimalCharacterReference => {
+                    let radix = if self.state == State::HexCharacterReference { 16 } else { 10 };
+                    match self.peek() {
+                        Some(c) if c.is_digit(radix) => {
+                            self.consume();
+                            self.char_ref_code = self
+                                .char_ref_code
+                                .saturating_mul(radix)
+                                .saturating_add(c.to_digit(radix).unwrap());
+                        }
+                        Some(';') => {
+                            self.consume();
+                            self.state = State::NumericCharacterReferenceEnd;
+                        }
+                        _ => {
+                            self.error("missing-semicolon-after-character-reference");
+                            self.state = State::NumericCharacterReferenceEnd;
+                        }
                     }
-                },
+                }
 
                 State::NumericCharacterReferenceEnd => {
-                    let c = match self.char_ref_code {
+                    if let Some(code) = numeric_reference_error(self.char_ref_code) {
+                        self.error_consumed(code);
+                    }
+                    let code = match self.char_ref_code {
+                        0x80..=0x9F => WINDOWS_1252[self.char_ref_code as usize - 0x80],
+                        code => code,
+                    };
+                    // Null, surrogates and values past U+10FFFF all become
+                    // U+FFFD.
+                    let c = match code {
                         0 => '\u{FFFD}',
-                        c if c > 0x10FFFF => '\u{FFFD}',
-                        c => char::from_u32(c).unwrap_or('\u{FFFD}'),
+                        code => char::from_u32(code).unwrap_or('\u{FFFD}'),
                     };
-
                     self.temp_buf.clear();
-
-                    match self.return_state {
-                        State::AttributeValueDoubleQuoted
-                        | State::AttributeValueSingleQuoted
-                        | State::AttributeValueUnquoted => {
-                            self.current_attr_value.push(c);
-                        }
-                        _ => {
-                            self.pending.push(HtmlToken::Character(c));
-                        }
-                    }
+                    self.temp_buf.push(c);
+                    self.flush_code_points_consumed_as_char_ref();
                     self.state = self.return_state;
                 }
             }
         }
     }
+
+    /// Start a doctype public or system identifier quoted by `quote`.
+    fn start_doctype_id(&mut self, public: bool, quote: char) {
+        let double = quote == '"';
+        if public {
+            self.current_doctype_public_id = Some(String::new());
+            self.state = if double {
+                State::DoctypePublicIdDoubleQuoted
+            } else {
+                State::DoctypePublicIdSingleQuoted
+            };
+        } else {
+            self.current_doctype_system_id = Some(String::new());
+            self.state = if double {
+                State::DoctypeSystemIdDoubleQuoted
+            } else {
+                State::DoctypeSystemIdSingleQuoted
+            };
+        }
+    }
 }
 
 // ===========================================================================
@@ -1501,6 +2037,50 @@ mod tests {
         );
     }
 
+    fn text(input: &str) -> String {
+        tokenize(input)
+            .iter()
+            .map(|t| match t {
+                HtmlToken::Character(c) => *c,
+                _ => panic!("expected character"),
+            })
+            .collect()
+    }
+
+    fn href(input: &str) -> String {
+        match &tokenize(input)[0] {
+            HtmlToken::StartTag { attrs, .. } => attrs[0].1.clone(),
+            other => panic!("expected start tag, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn char_ref_full_table() {
+        assert_eq!(text("&nbsp;&copy;&mdash;&hellip;"), "\u{A0}\u{A9}\u{2014}\u{2026}");
+        assert_eq!(text("&Afr;&fjlig;"), "\u{1D504}fj");
+        assert_eq!(text("&NotEqualTilde;"), "\u{2242}\u{338}");
+        assert_eq!(text("&unknown; &"), "&unknown; &");
+    }
+
+    #[test]
+    fn char_ref_legacy_without_semicolon() {
+        assert_eq!(text("&copy 2024"), "\u{A9} 2024");
+        // Longest match: "not" is a legacy name, "notin;" is not reached.
+        assert_eq!(text("&notit;"), "\u{AC}it;");
+        assert_eq!(text("&notin;"), "\u{2209}");
+        assert_eq!(text("&ampx"), "&x");
+    }
+
+    #[test]
+    fn char_ref_attribute_special_cases() {
+        // Legacy names followed by '=' or an alphanumeric stay literal.
+        assert_eq!(href(r#"<a href="?a=1&copy=2">"#), "?a=1&copy=2");
+        assert_eq!(href(r#"<a href="?x&notit">"#), "?x&notit");
+        assert_eq!(href(r#"<a href="&copy;=">"#), "\u{A9}=");
+        assert_eq!(href(r#"<a href="&amp x">"#), "& x");
+        assert_eq!(href("<a href=&bogus;>"), "&bogus;");
+    }
+
     #[test]
     fn mixed_content() {
         let tokens = tokenize("<p>Hello</p>");
@@ -1534,4 +2114,36 @@ mod tests {
             }]
         );
     }
+
+    #[test]
+    fn reports_parse_errors_at_offsets() {
+        let mut t = Tokenizer::new("<a b=1 b=2></a x>&amp &#0;<!-- x");
+        t.set_error_reporting(true);
+        while t.next_token() != HtmlToken::EOF {}
+        let errors: Vec<_> = t.take_errors().into_iter().map(|e| (e.code, e.offset)).collect();
+        assert_eq!(
+            errors,
+            vec![
+                ("duplicate-attribute", 7),
+                ("end-tag-with-attributes", 16),
+                ("missing-semicolon-after-character-reference", 21),
+                ("null-character-reference", 25),
+                ("eof-in-comment", 32),
+            ]
+        );
+    }
+
+    #[test]
+    fn position_counts_from_document_start() {
+        let mut t = Tokenizer::streaming();
+        t.feed("ab\r\n");
+        assert_eq!(t.next_token(), HtmlToken::Character('a'));
+        assert_eq!(t.next_token(), HtmlToken::Character('b'));
+        t.feed("<p>");
+        t.end();
+        assert_eq!(t.position(), 2);
+        assert_eq!(t.next_token(), HtmlToken::Character('\n'));
+        assert!(matches!(t.next_token(), HtmlToken::StartTag { .. }));
+        assert_eq!(t.position(), 6);
+    }
 }
@@ -1,10 +1,14 @@
 //! HTML tree builder — constructs a [`Dom`] from a stream of [`HtmlToken`]s.
 //!
-//! Implements a simplified version of the WHATWG tree construction algorithm
-//! with the most important insertion modes.
-
-use crate::token::HtmlToken;
-use crate::tokenizer::Tokenizer;
+//! Implements the WHATWG tree construction algorithm: every insertion mode,
+//! the list of active formatting elements with the adoption agency
+//! algorithm, foster parenting for misnested table content, `<template>`
+//! contents, and the rules for SVG and MathML foreign content. Checked
+//! against the html5lib tree-construction tests (see `html5lib.rs`).
+
+use crate::serializer::is_void_element;
+use crate::token::{HtmlToken, SourceError};
+use crate::tokenizer::{TextMode, Tokenizer};
 use dom::node::{Attr, CompatMode, Namespace, NodeData, NodeId};
 use dom::Dom;
 
@@ -43,12 +47,38 @@ enum InsertionMode {
 // Active formatting entry
 // ---------------------------------------------------------------------------
 
+/// An entry in the list of active formatting elements. Elements keep the
+/// token they were created from, so that they can be recreated when
+/// formatting is reconstructed or an end tag is misnested.
 #[derive(Debug, Clone)]
 enum FormattingEntry {
-    Element(NodeId),
+    Element {
+        node: NodeId,
+        name: String,
+        attrs: Vec<(String, String)>,
+    },
     Marker,
 }
 
+impl FormattingEntry {
+    fn node(&self) -> Option<NodeId> {
+        match self {
+            FormattingEntry::Element { node, .. } => Some(*node),
+            FormattingEntry::Marker => None,
+        }
+    }
+}
+
+/// Which elements bound a "has an element in scope" search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scope {
+    Default,
+    ListItem,
+    Button,
+    Table,
+    Select,
+}
+
 // ---------------------------------------------------------------------------
 // Tree builder
 // ---------------------------------------------------------------------------
@@ -63,12 +93,29 @@ pub struct TreeBuilder {
     head_pointer: Option<NodeId>,
     form_pointer: Option<NodeId>,
     foster_parenting: bool,
+    frameset_ok: bool,
+    /// Whether scripting is enabled, which makes `<noscript>` raw text.
+    scripting: bool,
+    /// Set after `<pre>`, `<listing>` and `<textarea>`: a newline right
+    /// after the start tag is dropped.
+    skip_newline: bool,
     template_modes: Vec<InsertionMode>,
     document: NodeId,
+    /// Characters collected in the "in table text" mode.
     pending_text: String,
     /// When set, the parse loop should switch the tokenizer to raw-text mode
     /// for this tag name.  The field is consumed (taken) after each token.
     pub pending_rawtext_tag: Option<String>,
+    /// Set when a parser-inserted `<script>` element has just been closed.
+    /// The embedder runs it before parsing continues (see [`Parser::run`]).
+    pub pending_script: Option<NodeId>,
+    /// Namespace and tag name of the context element when parsing a
+    /// fragment (see [`parse_fragment_in`]).
+    fragment_context: Option<(Namespace, String)>,
+    report_errors: bool,
+    errors: Vec<SourceError>,
+    /// Source offset of the token being processed, stamped on its errors.
+    position: usize,
 }
 
 impl TreeBuilder {
@@ -85,25 +132,83 @@ impl TreeBuilder {
             head_pointer: None,
             form_pointer: None,
             foster_parenting: false,
+            frameset_ok: true,
+            scripting: true,
+            skip_newline: false,
             template_modes: Vec::new(),
             document,
             pending_text: String::new(),
             pending_rawtext_tag: None,
+            pending_script: None,
+            fragment_context: None,
+            report_errors: false,
+            errors: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Start or stop recording tree construction parse errors.
+    pub fn set_error_reporting(&mut self, enabled: bool) {
+        self.report_errors = enabled;
+    }
+
+    /// Set the source offset of the next token, which the errors it causes
+    /// are reported at.
+    pub fn set_position(&mut self, offset: usize) {
+        self.position = offset;
+    }
+
+    /// The parse errors recorded since the last call.
+    pub fn take_errors(&mut self) -> Vec<SourceError> {
+        std::mem::take(&mut self.errors)
+    }
+
+    fn parse_error(&mut self, code: &'static str) {
+        if self.report_errors {
+            self.errors.push(SourceError { code, offset: self.position });
         }
     }
 
+    /// Report a token the current insertion mode ignores.
+    fn unexpected(&mut self, token: &HtmlToken) {
+        self.parse_error(match token {
+            HtmlToken::Doctype { .. } => "unexpected-doctype",
+            HtmlToken::StartTag { .. } => "unexpected-start-tag",
+            HtmlToken::EndTag { .. } => "unexpected-end-tag",
+            HtmlToken::Comment(_) | HtmlToken::Character(_) => "unexpected-character",
+            HtmlToken::EOF => "unexpected-eof",
+        });
+    }
+
     // =======================================================================
     // Helpers
     // =======================================================================
 
-    /// Return the tag name of a node, or "" if it isn't an element.
-    fn tag_name(&self, node_id: NodeId) -> String {
+    /// Return the tag name of an HTML element, or "" for anything else.
+    /// The insertion modes only ever look for HTML elements by name, so an
+    /// SVG `<title>` never passes for an HTML one.
+    fn name(&self, node_id: NodeId) -> &str {
+        match self.dom.nodes.get(node_id).and_then(|n| n.as_element()) {
+            Some(e) if e.namespace == Namespace::Html => &e.tag_name,
+            _ => "",
+        }
+    }
+
+    /// Namespace and tag name of an element, in any namespace.
+    fn qualified(&self, node_id: NodeId) -> (Namespace, &str) {
         self.dom
             .nodes
             .get(node_id)
             .and_then(|n| n.as_element())
-            .map(|e| e.tag_name.clone())
-            .unwrap_or_default()
+            .map_or((Namespace::Html, ""), |e| (e.namespace, e.tag_name.as_str()))
+    }
+
+    /// Whether `node` is in the special category (see [`is_special`]).
+    fn is_special_node(&self, node: NodeId) -> bool {
+        match self.qualified(node) {
+            (Namespace::Html, name) => is_special(name),
+            (namespace, name) => is_foreign_boundary(namespace, name),
+        }
     }
 
     /// Current node = last element on the open elements stack.
@@ -111,13 +216,87 @@ impl TreeBuilder {
         self.open_elements.last().copied()
     }
 
-    /// The adjusted insertion location (simplified: just the current node).
-    fn appropriate_insert_location(&self) -> NodeId {
-        self.current_node().unwrap_or(self.document)
+    /// Whether the current node is an element with the given tag name.
+    fn current_node_is(&self, tag: &str) -> bool {
+        self.current_node().is_some_and(|id| self.name(id) == tag)
     }
 
-    /// Insert an element into the DOM and push onto the open elements stack.
-    fn insert_element(&mut self, tag: &str, attrs: &[(String, String)]) -> NodeId {
+    fn current_node_is_one_of(&self, tags: &[&str]) -> bool {
+        self.current_node().is_some_and(|id| tags.contains(&self.name(id)))
+    }
+
+    fn stack_has(&self, tag: &str) -> bool {
+        self.open_elements.iter().any(|&id| self.name(id) == tag)
+    }
+
+    fn is_quirks(&self) -> bool {
+        matches!(
+            self.dom.nodes.get(self.document).map(|n| &n.data),
+            Some(NodeData::Document {
+                compat_mode: CompatMode::Quirks
+            })
+        )
+    }
+
+    fn set_compat_mode(&mut self, mode: CompatMode) {
+        if let Some(NodeData::Document { compat_mode }) =
+            self.dom.nodes.get_mut(self.document).map(|n| &mut n.data)
+        {
+            *compat_mode = mode;
+        }
+    }
+
+    /// The appropriate place for inserting a node: a parent and the child
+    /// to insert before. Applies foster parenting and redirects insertions
+    /// into a `<template>` to its contents.
+    fn insertion_location(&self, override_target: Option<NodeId>) -> (NodeId, Option<NodeId>) {
+        let target = override_target
+            .or_else(|| self.current_node())
+            .unwrap_or(self.document);
+        let (parent, before) = if self.foster_parenting
+            && matches!(self.name(target), "table" | "tbody" | "tfoot" | "thead" | "tr")
+        {
+            let last_of = |tag: &str| self.open_elements.iter().rposition(|&id| self.name(id) == tag);
+            match (last_of("template"), last_of("table")) {
+                (Some(template), table) if table.is_none_or(|table| template > table) => {
+                    (self.open_elements[template], None)
+                }
+                (_, None) => (self.open_elements[0], None),
+                (_, Some(table)) => {
+                    let table_node = self.open_elements[table];
+                    match self.dom.nodes.get(table_node).and_then(|n| n.parent) {
+                        Some(parent) => (parent, Some(table_node)),
+                        None => (self.open_elements[table - 1], None),
+                    }
+                }
+            }
+        } else {
+            (target, None)
+        };
+        match self.template_contents(parent) {
+            Some(contents) => (contents, None),
+            None => (parent, before),
+        }
+    }
+
+    fn template_contents(&self, node: NodeId) -> Option<NodeId> {
+        self.dom
+            .nodes
+            .get(node)
+            .and_then(|n| n.as_element())
+            .and_then(|e| e.template_contents)
+    }
+
+    fn insert_at(&mut self, (parent, before): (NodeId, Option<NodeId>), node: NodeId) {
+        self.dom.insert_before(parent, node, before);
+    }
+
+    /// Create an HTML element for a token, without inserting it.
+    fn create_element(&mut self, tag: &str, attrs: &[(String, String)]) -> NodeId {
+        self.create_element_ns(Namespace::Html, tag, attrs)
+    }
+
+    fn create_element_ns(&mut self, namespace: Namespace, tag: &str, attrs: &[(String, String)]) -> NodeId {
         let dom_attrs: Vec<Attr> = attrs
             .iter()
             .map(|(n, v)| Attr {
@@ -125,37 +304,78 @@ impl TreeBuilder {
                 value: v.clone(),
             })
             .collect();
-        let node = self.dom.create_element(tag, Namespace::Html, dom_attrs);
-        let parent = self.appropriate_insert_location();
-        self.dom.append_child(parent, node);
+        let node = self.dom.create_element(tag, namespace, dom_attrs);
+        if namespace == Namespace::Html && tag == "template" {
+            let contents = self.dom.create_document_fragment();
+            if let Some(element) = self.dom.nodes.get_mut(node).and_then(|n| n.as_element_mut()) {
+                element.template_contents = Some(contents);
+            }
+        }
+        node
+    }
+
+    /// Insert an element into the DOM and push onto the open elements stack.
+    fn insert_element(&mut self, tag: &str, attrs: &[(String, String)]) -> NodeId {
+        let node = self.create_element(tag, attrs);
+        let location = self.insertion_location(None);
+        self.insert_at(location, node);
         self.open_elements.push(node);
         node
     }
 
+    /// Insert an SVG or MathML element, fixing up the case of its tag and
+    /// attribute names first. A self-closing tag is popped right away.
+    fn insert_foreign_element(
+        &mut self,
+        namespace: Namespace,
+        tag: &str,
+        attrs: &[(String, String)],
+        self_closing: bool,
+    ) {
+        let (tag, attrs) = adjust_foreign_token(namespace, tag, attrs);
+        let node = self.create_element_ns(namespace, &tag, &attrs);
+        let location = self.insertion_location(None);
+        self.insert_at(location, node);
+        if !self_closing {
+            self.open_elements.push(node);
+        }
+    }
+
+    /// Insert an element that has no contents (it is popped right away).
+    fn insert_void_element(&mut self, tag: &str, attrs: &[(String, String)]) {
+        self.insert_element(tag, attrs);
+        self.open_elements.pop();
+    }
+
     /// Insert a character (text) node, coalescing with the previous text node
     /// if possible.
     fn insert_character(&mut self, c: char) {
-        let parent = self.appropriate_insert_location();
-
-        // Try to append to existing last-child text node.
-        if let Some(last) = self.dom.nodes.get(parent).and_then(|n| n.last_child) {
-            if let Some(node) = self.dom.nodes.get_mut(last) {
-                if let NodeData::Text { ref mut data } = node.data {
-                    data.push(c);
-                    return;
-                }
-            }
+        let (parent, before) = self.insertion_location(None);
+        if matches!(
+            self.dom.nodes.get(parent).map(|n| &n.data),
+            Some(NodeData::Document { .. })
+        ) {
+            return;
+        }
+        let previous = match before {
+            Some(before) => self.dom.nodes.get(before).and_then(|n| n.prev_sibling),
+            None => self.dom.nodes.get(parent).and_then(|n| n.last_child),
+        };
+        if let Some(NodeData::Text { data }) =
+            previous.and_then(|p| self.dom.nodes.get_mut(p)).map(|n| &mut n.data)
+        {
+            data.push(c);
+            return;
         }
-
         let text = self.dom.create_text(&c.to_string());
-        self.dom.append_child(parent, text);
+        self.dom.insert_before(parent, text, before);
     }
 
     /// Insert a comment node.
     fn insert_comment(&mut self, data: &str) {
-        let parent = self.appropriate_insert_location();
+        let location = self.insertion_location(None);
         let comment = self.dom.create_comment(data);
-        self.dom.append_child(parent, comment);
+        self.insert_at(location, comment);
     }
 
     /// Insert a comment node as a child of the document.
@@ -164,159 +384,501 @@ impl TreeBuilder {
         self.dom.append_child(self.document, comment);
     }
 
-    /// Pop elements off the stack until we pop one with `tag`.
-    fn pop_until_tag(&mut self, tag: &str) {
-        while let Some(node_id) = self.open_elements.pop() {
-            if self.tag_name(node_id) == tag {
-                break;
+    /// Add the attributes of a second `<html>` or `<body>` start tag that
+    /// `node` does not already have.
+    fn add_missing_attrs(&mut self, node: NodeId, attrs: &[(String, String)]) {
+        let Some(element) = self.dom.nodes.get_mut(node).and_then(|n| n.as_element_mut()) else {
+            return;
+        };
+        for (name, value) in attrs {
+            if element.attrs.iter().any(|a| a.name == *name) {
+                continue;
             }
+            match name.as_str() {
+                "id" => element.id = Some(value.clone()),
+                "class" => element.classes = value.split_whitespace().map(String::from).collect(),
+                _ => {}
+            }
+            element.attrs.push(Attr {
+                name: name.clone(),
+                value: value.clone(),
+            });
         }
     }
 
+    /// Pop elements off the stack until we pop one with `tag`.
+    fn pop_until_tag(&mut self, tag: &str) {
+        self.pop_until_one_of(&[tag]);
+    }
+
     /// Pop elements off the stack until we pop one whose tag is in `tags`.
     fn pop_until_one_of(&mut self, tags: &[&str]) {
         while let Some(node_id) = self.open_elements.pop() {
-            if tags.contains(&self.tag_name(node_id).as_str()) {
+            if tags.contains(&self.name(node_id)) {
                 break;
             }
         }
     }
 
-    /// Check if the stack of open elements has an element with `tag` in scope.
-    fn has_in_scope(&self, tag: &str) -> bool {
-        let scope_tags = [
-            "applet", "caption", "html", "table", "td", "th", "marquee", "object", "template",
-        ];
-        for &node_id in self.open_elements.iter().rev() {
-            let name = self.tag_name(node_id);
-            if name == tag {
-                return true;
-            }
-            if scope_tags.contains(&name.as_str()) {
-                return false;
+    /// Pop until `node` has been popped.
+    fn pop_until_node(&mut self, node: NodeId) {
+        while let Some(popped) = self.open_elements.pop() {
+            if popped == node {
+                break;
             }
         }
-        false
     }
 
-    /// Check if the stack has an element with `tag` in button scope.
-    fn has_in_button_scope(&self, tag: &str) -> bool {
-        let scope_tags = [
-            "applet", "caption", "html", "table", "td", "th", "marquee", "object", "template",
-            "button",
-        ];
+    fn remove_from_stack(&mut self, node: NodeId) {
+        self.open_elements.retain(|&id| id != node);
+    }
+
+    /// Whether `name` ends the search of a `scope` check.
+    fn is_scope_boundary(scope: Scope, name: &str) -> bool {
+        let default = matches!(
+            name,
+            "applet" | "caption" | "html" | "table" | "td" | "th" | "marquee" | "object" | "template"
+        );
+        match scope {
+            Scope::Default => default,
+            Scope::ListItem => default || matches!(name, "ol" | "ul"),
+            Scope::Button => default || name == "button",
+            Scope::Table => matches!(name, "html" | "table" | "template"),
+            Scope::Select => !matches!(name, "optgroup" | "option"),
+        }
+    }
+
+    /// Whether the element `node` ends the search of a `scope` check. The
+    /// MathML text integration points, `annotation-xml` and the SVG
+    /// elements that hold HTML bound every scope but table and select
+    /// scope; select scope stops at anything but `optgroup` and `option`.
+    fn bounds_scope(&self, scope: Scope, node: NodeId) -> bool {
+        match self.qualified(node) {
+            (Namespace::Html, name) => Self::is_scope_boundary(scope, name),
+            (namespace, name) => match scope {
+                Scope::Table => false,
+                Scope::Select => true,
+                _ => is_foreign_boundary(namespace, name),
+            },
+        }
+    }
+
+    /// Check if the stack of open elements has an element whose tag is in
+    /// `tags`, in the given scope.
+    fn has_one_in_scope(&self, tags: &[&str], scope: Scope) -> bool {
         for &node_id in self.open_elements.iter().rev() {
-            let name = self.tag_name(node_id);
-            if name == tag {
+            if tags.contains(&self.name(node_id)) {
                 return true;
             }
-            if scope_tags.contains(&name.as_str()) {
+            if self.bounds_scope(scope, node_id) {
                 return false;
             }
         }
         false
     }
 
-    /// Check if the stack has an element with `tag` in list item scope.
-    fn has_in_list_item_scope(&self, tag: &str) -> bool {
-        let scope_tags = [
-            "applet", "caption", "html", "table", "td", "th", "marquee", "object", "template",
-            "ol", "ul",
-        ];
+    fn has_in_scope(&self, tag: &str, scope: Scope) -> bool {
+        self.has_one_in_scope(&[tag], scope)
+    }
+
+    /// Whether the specific element `node` is in (default) scope.
+    fn has_node_in_scope(&self, node: NodeId) -> bool {
         for &node_id in self.open_elements.iter().rev() {
-            let name = self.tag_name(node_id);
-            if name == tag {
+            if node_id == node {
                 return true;
             }
-            if scope_tags.contains(&name.as_str()) {
+            if self.bounds_scope(Scope::Default, node_id) {
                 return false;
             }
         }
         false
     }
 
-    /// Generate implied end tags (for dd, dt, li, optgroup, option, p, rb, rp, rt, rtc).
-    fn generate_implied_end_tags(&mut self) {
-        let implied = [
+    /// Generate implied end tags, except for `except_tag`.
+    fn generate_implied_end_tags_except(&mut self, except_tag: &str) {
+        while self.current_node_is_one_of(&[
             "dd", "dt", "li", "optgroup", "option", "p", "rb", "rp", "rt", "rtc",
-        ];
-        while let Some(&node_id) = self.open_elements.last() {
-            if implied.contains(&self.tag_name(node_id).as_str()) {
-                self.open_elements.pop();
-            } else {
-                break;
-            }
+        ]) && !self.current_node_is(except_tag)
+        {
+            self.open_elements.pop();
         }
     }
 
-    /// Generate implied end tags, except for `except_tag`.
-    fn generate_implied_end_tags_except(&mut self, except_tag: &str) {
-        let implied = [
-            "dd", "dt", "li", "optgroup", "option", "p", "rb", "rp", "rt", "rtc",
-        ];
-        while let Some(&node_id) = self.open_elements.last() {
-            let name = self.tag_name(node_id);
-            if name == except_tag {
-                break;
-            }
-            if implied.contains(&name.as_str()) {
-                self.open_elements.pop();
-            } else {
-                break;
-            }
+    /// Generate implied end tags (for dd, dt, li, optgroup, option, p, rb, rp, rt, rtc).
+    fn generate_implied_end_tags(&mut self) {
+        self.generate_implied_end_tags_except("");
+    }
+
+    /// The "thorough" variant, which also closes table parts.
+    fn generate_all_implied_end_tags(&mut self) {
+        while self.current_node_is_one_of(&[
+            "caption", "colgroup", "dd", "dt", "li", "optgroup", "option", "p", "rb", "rp", "rt",
+            "rtc", "tbody", "td", "tfoot", "th", "thead", "tr",
+        ]) {
+            self.open_elements.pop();
         }
     }
 
     /// Close a <p> element if one is in button scope.
     fn close_p_element(&mut self) {
-        if self.has_in_button_scope("p") {
+        if self.has_in_scope("p", Scope::Button) {
             self.generate_implied_end_tags_except("p");
             self.pop_until_tag("p");
         }
     }
 
-    /// Whether the current node is an element with the given tag name.
-    fn current_node_is(&self, tag: &str) -> bool {
-        self.current_node()
-            .map(|id| self.tag_name(id) == tag)
-            .unwrap_or(false)
+    /// Insert an element whose contents the tokenizer reads as text
+    /// (RCDATA, RAWTEXT or script data).
+    fn insert_text_element(&mut self, tag: &str, attrs: &[(String, String)]) {
+        self.insert_element(tag, attrs);
+        self.pending_rawtext_tag = Some(tag.to_string());
+        self.original_mode = self.mode;
+        self.mode = InsertionMode::Text;
     }
 
     // =======================================================================
-    // Flush pending text
+    // Active formatting elements
     // =======================================================================
 
-    fn flush_pending_text(&mut self) {
-        if self.pending_text.is_empty() {
+    fn push_formatting_element(&mut self, node: NodeId, name: &str, attrs: &[(String, String)]) {
+        // Noah's Ark: at most three identical entries after the last marker.
+        let same = |entry: &FormattingEntry| match entry {
+            FormattingEntry::Element {
+                name: other_name,
+                attrs: other_attrs,
+                ..
+            } => {
+                other_name == name
+                    && other_attrs.len() == attrs.len()
+                    && other_attrs.iter().all(|a| attrs.contains(a))
+            }
+            FormattingEntry::Marker => false,
+        };
+        let start = self.last_marker_index().map_or(0, |i| i + 1);
+        let matching: Vec<usize> = (start..self.active_formatting.len())
+            .filter(|&i| same(&self.active_formatting[i]))
+            .collect();
+        if matching.len() >= 3 {
+            self.active_formatting.remove(matching[0]);
+        }
+        self.active_formatting.push(FormattingEntry::Element {
+            node,
+            name: name.to_string(),
+            attrs: attrs.to_vec(),
+        });
+    }
+
+    fn last_marker_index(&self) -> Option<usize> {
+        self.active_formatting
+            .iter()
+            .rposition(|e| matches!(e, FormattingEntry::Marker))
+    }
+
+    fn formatting_index(&self, node: NodeId) -> Option<usize> {
+        self.active_formatting.iter().position(|e| e.node() == Some(node))
+    }
+
+    /// The last element named `tag` after the last marker.
+    fn formatting_element_named(&self, tag: &str) -> Option<NodeId> {
+        self.active_formatting
+            .iter()
+            .rev()
+            .take_while(|e| !matches!(e, FormattingEntry::Marker))
+            .find_map(|e| match e {
+                FormattingEntry::Element { node, name, .. } if name == tag => Some(*node),
+                _ => None,
+            })
+    }
+
+    fn clear_formatting_to_last_marker(&mut self) {
+        while let Some(entry) = self.active_formatting.pop() {
+            if matches!(entry, FormattingEntry::Marker) {
+                break;
+            }
+        }
+    }
+
+    /// Reopen formatting elements that were closed implicitly, e.g. the `<b>`
+    /// in `<p><b>x<p>y`.
+    fn reconstruct_active_formatting(&mut self) {
+        let is_open = |this: &Self, entry: &FormattingEntry| match entry.node() {
+            Some(node) => this.open_elements.contains(&node),
+            None => true,
+        };
+        let Some(last) = self.active_formatting.last() else {
+            return;
+        };
+        if is_open(self, last) {
             return;
         }
-        let text = std::mem::take(&mut self.pending_text);
-        for c in text.chars() {
-            self.insert_character(c);
+        let mut i = self.active_formatting.len() - 1;
+        while i > 0 && !is_open(self, &self.active_formatting[i - 1]) {
+            i -= 1;
+        }
+        for entry in i..self.active_formatting.len() {
+            let FormattingEntry::Element { name, attrs, .. } = self.active_formatting[entry].clone()
+            else {
+                continue;
+            };
+            let node = self.insert_element(&name, &attrs);
+            self.active_formatting[entry] = FormattingEntry::Element { node, name, attrs };
+        }
+    }
+
+    /// The adoption agency algorithm, run for the end tag `subject` of a
+    /// formatting element. Returns false when the end tag should instead be
+    /// handled like any other end tag.
+    fn adoption_agency(&mut self, subject: &str) -> bool {
+        if let Some(current) = self.current_node()
+            && self.name(current) == subject
+            && self.formatting_index(current).is_none()
+        {
+            self.open_elements.pop();
+            return true;
+        }
+
+        for _ in 0..8 {
+            let Some(formatting) = self.formatting_element_named(subject) else {
+                return false;
+            };
+            let Some(formatting_pos) = self.open_elements.iter().position(|&n| n == formatting)
+            else {
+                self.parse_error("unexpected-end-tag");
+                let index = self.formatting_index(formatting).unwrap();
+                self.active_formatting.remove(index);
+                return true;
+            };
+            if !self.has_node_in_scope(formatting) {
+                self.parse_error("unexpected-end-tag");
+                return true;
+            }
+            if self.current_node() != Some(formatting) {
+                self.parse_error("misnested-formatting-element");
+            }
+
+            // The furthest block is the topmost special element below the
+            // formatting element.
+            let furthest_block = self.open_elements[formatting_pos + 1..]
+                .iter()
+                .copied()
+                .find(|&n| self.is_special_node(n));
+            let Some(furthest_block) = furthest_block else {
+                self.pop_until_node(formatting);
+                let index = self.formatting_index(formatting).unwrap();
+                self.active_formatting.remove(index);
+                return true;
+            };
+
+            let common_ancestor = self.open_elements[formatting_pos - 1];
+            let mut bookmark = self.formatting_index(formatting).unwrap();
+            let mut node_pos = self.open_elements.iter().position(|&n| n == furthest_block).unwrap();
+            let mut last_node = furthest_block;
+            let mut inner = 0;
+            loop {
+                inner += 1;
+                node_pos -= 1;
+                let node = self.open_elements[node_pos];
+                if node == formatting {
+                    break;
+                }
+                let mut node_index = self.formatting_index(node);
+                if inner > 3
+                    && let Some(index) = node_index
+                {
+                    self.active_formatting.remove(index);
+                    if index < bookmark {
+                        bookmark -= 1;
+                    }
+                    node_index = None;
+                }
+                let Some(node_index) = node_index else {
+                    self.open_elements.remove(node_pos);
+                    continue;
+                };
+
+                // Replace the node with a fresh copy that adopts last_node.
+                let FormattingEntry::Element { name, attrs, .. } =
+                    self.active_formatting[node_index].clone()
+                else {
+                    unreachable!("open elements are never markers");
+                };
+                let replacement = self.create_element(&name, &attrs);
+                self.active_formatting[node_index] = FormattingEntry::Element {
+                    node: replacement,
+                    name,
+                    attrs,
+                };
+                self.open_elements[node_pos] = replacement;
+                if last_node == furthest_block {
+                    bookmark = node_index + 1;
+                }
+                self.dom.append_child(replacement, last_node);
+                last_node = replacement;
+            }
+
+            let location = self.insertion_location(Some(common_ancestor));
+            self.insert_at(location, last_node);
+
+            let formatting_index = self.formatting_index(formatting).unwrap();
+            let FormattingEntry::Element { name, attrs, .. } =
+                self.active_formatting[formatting_index].clone()
+            else {
+                unreachable!("formatting_index only finds elements");
+            };
+            let new_element = self.create_element(&name, &attrs);
+            for child in self.dom.children(furthest_block) {
+                self.dom.append_child(new_element, child);
+            }
+            self.dom.append_child(furthest_block, new_element);
+
+            self.active_formatting.remove(formatting_index);
+            if formatting_index < bookmark {
+                bookmark -= 1;
+            }
+            self.active_formatting.insert(
+                bookmark.min(self.active_formatting.len()),
+                FormattingEntry::Element {
+                    node: new_element,
+                    name,
+                    attrs,
+                },
+            );
+            self.remove_from_stack(formatting);
+            let block_pos = self.open_elements.iter().position(|&n| n == furthest_block).unwrap();
+            self.open_elements.insert(block_pos + 1, new_element);
         }
+        true
     }
 
     // =======================================================================
     // Token processing — main dispatch
     // =======================================================================
 
-    /// Process a single token in the current insertion mode.
+    /// Process a single token: in the current insertion mode, or by the
+    /// rules for foreign content inside SVG and MathML (the tree
+    /// construction dispatcher).
     pub fn process_token(&mut self, token: HtmlToken) {
-        match self.mode {
+        if std::mem::take(&mut self.skip_newline) && token == HtmlToken::Character('\n') {
+            return;
+        }
+        if self.is_foreign_token(&token) {
+            self.handle_foreign_content(token);
+        } else {
+            if let HtmlToken::StartTag { name, self_closing: true, .. } = &token
+                && !is_void_element(name)
+            {
+                self.parse_error("non-void-html-element-start-tag-with-trailing-solidus");
+            }
+            self.process_in(self.mode, token);
+        }
+    }
+
+    /// The adjusted current node: the context element while a fragment has
+    /// only its root open, otherwise the current node. The context element
+    /// has no node of its own.
+    fn adjusted_current_node(&self) -> Option<(Namespace, &str, Option<NodeId>)> {
+        if let (Some((namespace, name)), 1) = (&self.fragment_context, self.open_elements.len()) {
+            return Some((*namespace, name.as_str(), None));
+        }
+        let node = self.current_node()?;
+        let (namespace, name) = self.qualified(node);
+        Some((namespace, name, Some(node)))
+    }
+
+    /// Whether the tokenizer should read `<![CDATA[` as a CDATA section,
+    /// which it only does inside SVG and MathML.
+    pub fn in_foreign_content(&self) -> bool {
+        self.adjusted_current_node()
+            .is_some_and(|(namespace, ..)| namespace != Namespace::Html)
+    }
+
+    /// Whether an element starts an island of HTML in foreign content:
+    /// `<annotation-xml>` with an HTML encoding, or SVG `<foreignObject>`,
+    /// `<desc>` and `<title>`.
+    fn is_html_integration_point(&self, namespace: Namespace, name: &str, node: Option<NodeId>) -> bool {
+        match namespace {
+            Namespace::MathMl => {
+                name == "annotation-xml"
+                    && node
+                        .and_then(|n| self.dom.nodes.get(n))
+                        .and_then(|n| n.as_element())
+                        .and_then(|e| e.attrs.iter().find(|a| a.name == "encoding"))
+                        .is_some_and(|a| {
+                            a.value.eq_ignore_ascii_case("text/html")
+                                || a.value.eq_ignore_ascii_case("application/xhtml+xml")
+                        })
+            }
+            Namespace::Svg => matches!(name, "foreignObject" | "desc" | "title"),
+            Namespace::Html => false,
+        }
+    }
+
+    /// The dispatcher: whether `token` is handled by the rules for foreign
+    /// content rather than by the current insertion mode.
+    fn is_foreign_token(&self, token: &HtmlToken) -> bool {
+        let Some((namespace, name, node)) = self.adjusted_current_node() else {
+            return false;
+        };
+        if namespace == Namespace::Html || *token == HtmlToken::EOF {
+            return false;
+        }
+        let start_tag = match token {
+            HtmlToken::StartTag { name, .. } => Some(name.as_str()),
+            _ => None,
+        };
+        let is_character = matches!(token, HtmlToken::Character(_));
+        let text_integration_point = namespace == Namespace::MathMl
+            && matches!(name, "mi" | "mo" | "mn" | "ms" | "mtext");
+        if text_integration_point
+            && (is_character || start_tag.is_some_and(|t| t != "mglyph" && t != "malignmark"))
+        {
+            return false;
+        }
+        if namespace == Namespace::MathMl && name == "annotation-xml" && start_tag == Some("svg") {
+            return false;
+        }
+        if self.is_html_integration_point(namespace, name, node) && (is_character || start_tag.is_some()) {
+            return false;
+        }
+        true
+    }
+
+    /// Process a token using the rules for `mode` (which may differ from
+    /// the current insertion mode).
+    fn process_in(&mut self, mode: InsertionMode, token: HtmlToken) {
+        match mode {
             InsertionMode::Initial => self.handle_initial(token),
             InsertionMode::BeforeHtml => self.handle_before_html(token),
             InsertionMode::BeforeHead => self.handle_before_head(token),
             InsertionMode::InHead => self.handle_in_head(token),
+            InsertionMode::InHeadNoscript => self.handle_in_head_noscript(token),
             InsertionMode::AfterHead => self.handle_after_head(token),
             InsertionMode::InBody => self.handle_in_body(token),
             InsertionMode::Text => self.handle_text(token),
+            InsertionMode::InTable => self.handle_in_table(token),
+            InsertionMode::InTableText => self.handle_in_table_text(token),
+            InsertionMode::InCaption => self.handle_in_caption(token),
+            InsertionMode::InColumnGroup => self.handle_in_column_group(token),
+            InsertionMode::InTableBody => self.handle_in_table_body(token),
+            InsertionMode::InRow => self.handle_in_row(token),
+            InsertionMode::InCell => self.handle_in_cell(token),
+            InsertionMode::InSelect => self.handle_in_select(token),
+            InsertionMode::InSelectInTable => self.handle_in_select_in_table(token),
+            InsertionMode::InTemplate => self.handle_in_template(token),
             InsertionMode::AfterBody => self.handle_after_body(token),
+            InsertionMode::InFrameset => self.handle_in_frameset(token),
+            InsertionMode::AfterFrameset => self.handle_after_frameset(token),
             InsertionMode::AfterAfterBody => self.handle_after_after_body(token),
-            // For modes we haven't fully implemented, fall back to InBody
-            _ => self.handle_in_body(token),
+            InsertionMode::AfterAfterFrameset => self.handle_after_after_frameset(token),
         }
     }
 
+    /// Switch to `mode` and process the token again there.
+    fn reprocess_in(&mut self, mode: InsertionMode, token: HtmlToken) {
+        self.mode = mode;
+        self.process_in(mode, token);
+    }
+
     // =======================================================================
     // Initial
     // =======================================================================
@@ -339,35 +901,25 @@ impl TreeBuilder {
                 let pub_id = public_id.as_deref().unwrap_or("");
                 let sys_id = system_id.as_deref().unwrap_or("");
 
+                let legacy_compat = sys_id == "about:legacy-compat";
+                if n != "html" || public_id.is_some() || (system_id.is_some() && !legacy_compat) {
+                    self.parse_error("unknown-doctype");
+                }
                 let doctype = self.dom.create_doctype(n, pub_id, sys_id);
                 self.dom.append_child(self.document, doctype);
 
-                // Set quirks mode
-                if force_quirks || n != "html" {
-                    if let Some(doc_node) = self.dom.nodes.get_mut(self.document) {
-                        if let NodeData::Document {
-                            ref mut compat_mode,
-                        } = doc_node.data
-                        {
-                            *compat_mode = CompatMode::Quirks;
-                        }
-                    }
-                }
-
+                let mode = doctype_compat_mode(
+                    force_quirks || n != "html",
+                    public_id.as_deref(),
+                    system_id.as_deref(),
+                );
+                self.set_compat_mode(mode);
                 self.mode = InsertionMode::BeforeHtml;
             }
             _ => {
-                // Quirks mode
-                if let Some(doc_node) = self.dom.nodes.get_mut(self.document) {
-                    if let NodeData::Document {
-                        ref mut compat_mode,
-                    } = doc_node.data
-                    {
-                        *compat_mode = CompatMode::Quirks;
-                    }
-                }
-                self.mode = InsertionMode::BeforeHtml;
-                self.process_token(token);
+                self.parse_error("missing-doctype");
+                self.set_compat_mode(CompatMode::Quirks);
+                self.reprocess_in(InsertionMode::BeforeHtml, token);
             }
         }
     }
@@ -377,152 +929,168 @@ impl TreeBuilder {
     // =======================================================================
 
     fn handle_before_html(&mut self, token: HtmlToken) {
-        match token {
+        match &token {
             HtmlToken::Character(c) if c.is_ascii_whitespace() => {
                 // Ignore
             }
             HtmlToken::Comment(data) => {
-                self.insert_comment_on_document(&data);
+                self.insert_comment_on_document(data);
             }
             HtmlToken::Doctype { .. } => {
-                // Ignore
+                self.unexpected(&token);
             }
-            HtmlToken::StartTag { ref name, ref attrs, .. } if name == "html" => {
-                let html = self.insert_html_element_for(name, attrs);
-                // Don't push again, insert_element already pushed
-                let _ = html;
+            HtmlToken::StartTag { name, attrs, .. } if name == "html" => {
+                let html = self.create_element(name, attrs);
+                self.dom.append_child(self.document, html);
+                self.open_elements.push(html);
                 self.mode = InsertionMode::BeforeHead;
             }
-            HtmlToken::EndTag { ref name }
-                if name != "head" && name != "body" && name != "html" && name != "br" =>
-            {
-                // Ignore
+            HtmlToken::EndTag { name } if !matches!(name.as_str(), "head" | "body" | "html" | "br") => {
+                self.unexpected(&token);
             }
             _ => {
                 // Create html element implicitly
-                let html = self
-                    .dom
-                    .create_element("html", Namespace::Html, Vec::new());
+                let html = self.create_element("html", &[]);
                 self.dom.append_child(self.document, html);
                 self.open_elements.push(html);
-                self.mode = InsertionMode::BeforeHead;
-                self.process_token(token);
+                self.reprocess_in(InsertionMode::BeforeHead, token);
             }
         }
     }
 
-    /// Helper used by BeforeHtml to insert the html element with the token's attrs.
-    fn insert_html_element_for(&mut self, tag: &str, attrs: &[(String, String)]) -> NodeId {
-        self.insert_element(tag, attrs)
-    }
-
     // =======================================================================
     // Before Head
     // =======================================================================
 
     fn handle_before_head(&mut self, token: HtmlToken) {
-        match token {
+        match &token {
             HtmlToken::Character(c) if c.is_ascii_whitespace() => {
                 // Ignore
             }
             HtmlToken::Comment(data) => {
-                self.insert_comment(&data);
+                self.insert_comment(data);
             }
             HtmlToken::Doctype { .. } => {
-                // Ignore
+                self.unexpected(&token);
             }
-            HtmlToken::StartTag { ref name, ref attrs, .. } if name == "html" => {
-                // Merge attrs onto existing html element
-                self.merge_attrs_onto_first(attrs);
+            HtmlToken::StartTag { name, .. } if name == "html" => {
+                self.handle_in_body(token);
             }
-            HtmlToken::StartTag { ref name, ref attrs, .. } if name == "head" => {
+            HtmlToken::StartTag { name, attrs, .. } if name == "head" => {
                 let head = self.insert_element(name, attrs);
                 self.head_pointer = Some(head);
                 self.mode = InsertionMode::InHead;
             }
-            HtmlToken::EndTag { ref name }
-                if name != "head" && name != "body" && name != "html" && name != "br" =>
-            {
-                // Ignore
+            HtmlToken::EndTag { name } if !matches!(name.as_str(), "head" | "body" | "html" | "br") => {
+                self.unexpected(&token);
             }
             _ => {
                 let head = self.insert_element("head", &[]);
                 self.head_pointer = Some(head);
-                self.mode = InsertionMode::InHead;
-                self.process_token(token);
+                self.reprocess_in(InsertionMode::InHead, token);
             }
         }
     }
 
-    fn merge_attrs_onto_first(&mut self, _attrs: &[(String, String)]) {
-        // Simplified: we don't merge attributes onto the existing html element
-    }
-
     // =======================================================================
     // In Head
     // =======================================================================
 
     fn handle_in_head(&mut self, token: HtmlToken) {
-        match token {
+        match &token {
             HtmlToken::Character(c) if c.is_ascii_whitespace() => {
-                self.insert_character(c);
+                self.insert_character(*c);
             }
             HtmlToken::Comment(data) => {
-                self.insert_comment(&data);
+                self.insert_comment(data);
             }
             HtmlToken::Doctype { .. } => {
-                // Ignore
-            }
-            HtmlToken::StartTag { ref name, ref attrs, .. } if name == "html" => {
-                self.merge_attrs_onto_first(attrs);
+                self.unexpected(&token);
             }
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                self_closing,
-            } if name == "meta" || name == "base" || name == "basefont" || name == "bgsound" || name == "link" => {
-                self.insert_element(name, attrs);
-                self.open_elements.pop(); // void element
-                let _ = self_closing;
+            HtmlToken::StartTag { name, attrs, .. } => match name.as_str() {
+                "html" => self.handle_in_body(token),
+                "base" | "basefont" | "bgsound" | "link" | "meta" => {
+                    self.insert_void_element(name, attrs);
+                }
+                "title" | "noframes" | "style" | "script" => {
+                    self.insert_text_element(name, attrs);
+                }
+                "noscript" if self.scripting => {
+                    self.insert_text_element(name, attrs);
+                }
+                "noscript" => {
+                    self.insert_element(name, attrs);
+                    self.mode = InsertionMode::InHeadNoscript;
+                }
+                "template" => {
+                    self.insert_element(name, attrs);
+                    self.active_formatting.push(FormattingEntry::Marker);
+                    self.frameset_ok = false;
+                    self.mode = InsertionMode::InTemplate;
+                    self.template_modes.push(InsertionMode::InTemplate);
+                }
+                "head" => {
+                    self.unexpected(&token);
+                }
+                _ => self.leave_head(token),
+            },
+            HtmlToken::EndTag { name } => match name.as_str() {
+                "head" => {
+                    self.open_elements.pop();
+                    self.mode = InsertionMode::AfterHead;
+                }
+                "body" | "html" | "br" => self.leave_head(token),
+                "template" => {
+                    if !self.stack_has("template") {
+                        return;
+                    }
+                    self.generate_all_implied_end_tags();
+                    self.pop_until_tag("template");
+                    self.clear_formatting_to_last_marker();
+                    self.template_modes.pop();
+                    self.reset_insertion_mode();
+                }
+                _ => {
+                    self.unexpected(&token);
+                }
+            },
+            _ => self.leave_head(token),
+        }
+    }
+
+    /// In head, anything else: close the head and go on after it.
+    fn leave_head(&mut self, token: HtmlToken) {
+        self.open_elements.pop(); // pop head
+        self.reprocess_in(InsertionMode::AfterHead, token);
+    }
+
+    // =======================================================================
+    // In Head Noscript (scripting disabled)
+    // =======================================================================
+
+    fn handle_in_head_noscript(&mut self, token: HtmlToken) {
+        match &token {
+            HtmlToken::Doctype { .. } => {}
+            HtmlToken::StartTag { name, .. } if name == "html" => self.handle_in_body(token),
+            HtmlToken::EndTag { name } if name == "noscript" => {
+                self.open_elements.pop();
+                self.mode = InsertionMode::InHead;
             }
-            HtmlToken::StartTag { ref name, ref attrs, .. }
-                if name == "title" =>
+            HtmlToken::Character(c) if c.is_ascii_whitespace() => self.handle_in_head(token),
+            HtmlToken::Comment(_) => self.handle_in_head(token),
+            HtmlToken::StartTag { name, .. }
+                if matches!(
+                    name.as_str(),
+                    "basefont" | "bgsound" | "link" | "meta" | "noframes" | "style"
+                ) =>
             {
-                self.insert_element(name, attrs);
-                self.original_mode = self.mode;
-                self.mode = InsertionMode::Text;
-                self.pending_rawtext_tag = Some(name.clone());
-            }
-            HtmlToken::StartTag { ref name, ref attrs, .. }
-                if name == "noscript" || name == "noframes" || name == "style" =>
-            {
-                self.insert_element(name, attrs);
-                self.original_mode = self.mode;
-                self.mode = InsertionMode::Text;
-                self.pending_rawtext_tag = Some(name.clone());
-            }
-            HtmlToken::StartTag { ref name, ref attrs, .. } if name == "script" => {
-                self.insert_element(name, attrs);
-                self.original_mode = self.mode;
-                self.mode = InsertionMode::Text;
-                self.pending_rawtext_tag = Some(name.clone());
-            }
-            HtmlToken::EndTag { ref name } if name == "head" => {
-                self.open_elements.pop();
-                self.mode = InsertionMode::AfterHead;
-            }
-            HtmlToken::EndTag { ref name }
-                if name != "body" && name != "html" && name != "br" =>
-            {
-                // Ignore
-            }
-            HtmlToken::StartTag { ref name, .. } if name == "head" => {
-                // Ignore
+                self.handle_in_head(token)
             }
+            HtmlToken::StartTag { name, .. } if matches!(name.as_str(), "head" | "noscript") => {}
+            HtmlToken::EndTag { name } if name != "br" => {}
             _ => {
-                self.open_elements.pop(); // pop head
-                self.mode = InsertionMode::AfterHead;
-                self.process_token(token);
+                self.open_elements.pop();
+                self.reprocess_in(InsertionMode::InHead, token);
             }
         }
     }
@@ -532,569 +1100,1150 @@ impl TreeBuilder {
     // =======================================================================
 
     fn handle_after_head(&mut self, token: HtmlToken) {
-        match token {
+        match &token {
             HtmlToken::Character(c) if c.is_ascii_whitespace() => {
-                self.insert_character(c);
+                self.insert_character(*c);
             }
             HtmlToken::Comment(data) => {
-                self.insert_comment(&data);
+                self.insert_comment(data);
             }
             HtmlToken::Doctype { .. } => {
-                // Ignore
-            }
-            HtmlToken::StartTag { ref name, ref attrs, .. } if name == "html" => {
-                self.merge_attrs_onto_first(attrs);
-            }
-            HtmlToken::StartTag { ref name, ref attrs, .. } if name == "body" => {
-                self.insert_element(name, attrs);
-                self.mode = InsertionMode::InBody;
+                self.unexpected(&token);
             }
-            HtmlToken::StartTag { ref name, ref attrs, .. } if name == "frameset" => {
-                self.insert_element(name, attrs);
-                self.mode = InsertionMode::InFrameset;
-            }
-            HtmlToken::StartTag { ref name, .. }
-                if matches!(
-                    name.as_str(),
-                    "base" | "basefont" | "bgsound" | "link" | "meta" | "noframes"
-                        | "script" | "style" | "template" | "title"
-                ) =>
-            {
-                // Push head back, process in InHead, then remove head
-                if let Some(head) = self.head_pointer {
+            HtmlToken::StartTag { name, attrs, .. } => match name.as_str() {
+                "html" => self.handle_in_body(token),
+                "body" => {
+                    self.insert_element(name, attrs);
+                    self.frameset_ok = false;
+                    self.mode = InsertionMode::InBody;
+                }
+                "frameset" => {
+                    self.insert_element(name, attrs);
+                    self.mode = InsertionMode::InFrameset;
+                }
+                "base" | "basefont" | "bgsound" | "link" | "meta" | "noframes" | "script"
+                | "style" | "template" | "title" => {
+                    // Push head back, process in InHead, then remove head
+                    let head = self.head_pointer.expect("after head implies a head");
                     self.open_elements.push(head);
                     self.handle_in_head(token);
-                    self.open_elements.retain(|&id| id != head);
+                    self.remove_from_stack(head);
                 }
-            }
-            HtmlToken::EndTag { ref name }
-                if name != "body" && name != "html" && name != "br" =>
-            {
-                // Ignore
-            }
-            HtmlToken::StartTag { ref name, .. } if name == "head" => {
-                // Ignore
-            }
-            _ => {
-                self.insert_element("body", &[]);
-                self.mode = InsertionMode::InBody;
-                self.process_token(token);
-            }
+                "head" => {
+                    self.unexpected(&token);
+                }
+                _ => self.start_body(token),
+            },
+            HtmlToken::EndTag { name } => match name.as_str() {
+                "template" => self.handle_in_head(token),
+                "body" | "html" | "br" => self.start_body(token),
+                _ => {
+                    self.unexpected(&token);
+                }
+            },
+            _ => self.start_body(token),
         }
     }
 
+    /// After head, anything else: open an implied `<body>`.
+    fn start_body(&mut self, token: HtmlToken) {
+        self.insert_element("body", &[]);
+        self.reprocess_in(InsertionMode::InBody, token);
+    }
+
     // =======================================================================
     // In Body
     // =======================================================================
 
     fn handle_in_body(&mut self, token: HtmlToken) {
-        match token {
+        match &token {
             HtmlToken::Character('\0') => {
                 // Ignore null
             }
             HtmlToken::Character(c) => {
-                self.insert_character(c);
+                self.reconstruct_active_formatting();
+                self.insert_character(*c);
+                if !c.is_ascii_whitespace() {
+                    self.frameset_ok = false;
+                }
             }
             HtmlToken::Comment(data) => {
-                self.insert_comment(&data);
+                self.insert_comment(data);
             }
             HtmlToken::Doctype { .. } => {
-                // Ignore
+                self.unexpected(&token);
             }
-
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if name == "html" => {
-                self.merge_attrs_onto_first(attrs);
+            HtmlToken::StartTag { name, attrs, .. } => self.in_body_start_tag(name, attrs, &token),
+            HtmlToken::EndTag { name } => self.in_body_end_tag(name, &token),
+            HtmlToken::EOF => {
+                if !self.template_modes.is_empty() {
+                    self.handle_in_template(token);
+                    return;
+                }
+                const MAY_BE_OPEN: [&str; 16] = [
+                    "dd", "dt", "li", "optgroup", "option", "p", "rb", "rp", "rt", "rtc", "tbody",
+                    "td", "tfoot", "th", "thead", "tr",
+                ];
+                let unclosed = self.open_elements.iter().any(|&n| {
+                    let name = self.name(n);
+                    !MAY_BE_OPEN.contains(&name) && name != "body" && name != "html"
+                });
+                if unclosed {
+                    self.parse_error("expected-closing-tag-but-got-eof");
+                }
+                // Stop parsing.
             }
+        }
+    }
 
-            // Void / self-closing elements
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if matches!(
-                name.as_str(),
-                "area" | "br" | "embed" | "img" | "input" | "keygen" | "wbr"
-            ) => {
+    fn in_body_start_tag(&mut self, name: &str, attrs: &[(String, String)], token: &HtmlToken) {
+        match name {
+            "html" => {
+                if !self.stack_has("template") {
+                    let html = self.open_elements[0];
+                    self.add_missing_attrs(html, attrs);
+                }
+            }
+            "base" | "basefont" | "bgsound" | "link" | "meta" | "noframes" | "script" | "style"
+            | "template" | "title" => self.handle_in_head(token.clone()),
+            "body" => {
+                let Some(&body) = self.open_elements.get(1) else {
+                    return;
+                };
+                if self.name(body) != "body" || self.stack_has("template") {
+                    return;
+                }
+                self.frameset_ok = false;
+                self.add_missing_attrs(body, attrs);
+            }
+            "frameset" => {
+                let Some(&body) = self.open_elements.get(1) else {
+                    return;
+                };
+                if self.name(body) != "body" || !self.frameset_ok {
+                    return;
+                }
+                if let Some(parent) = self.dom.nodes.get(body).and_then(|n| n.parent) {
+                    self.dom.remove_child(parent, body);
+                }
+                self.open_elements.truncate(1);
                 self.insert_element(name, attrs);
-                self.open_elements.pop(); // void element
+                self.mode = InsertionMode::InFrameset;
             }
-
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if name == "hr" => {
+            "address" | "article" | "aside" | "blockquote" | "center" | "details" | "dialog"
+            | "dir" | "div" | "dl" | "fieldset" | "figcaption" | "figure" | "footer" | "header"
+            | "hgroup" | "main" | "menu" | "nav" | "ol" | "p" | "search" | "section"
+            | "summary" | "ul" => {
                 self.close_p_element();
                 self.insert_element(name, attrs);
-                self.open_elements.pop(); // void
             }
-
-            // Headings
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if matches!(name.as_str(), "h1" | "h2" | "h3" | "h4" | "h5" | "h6") => {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
                 self.close_p_element();
-                // If current node is a heading, pop it (no nested headings)
-                if let Some(cur) = self.current_node() {
-                    let cur_name = self.tag_name(cur);
-                    if matches!(
-                        cur_name.as_str(),
-                        "h1" | "h2" | "h3" | "h4" | "h5" | "h6"
-                    ) {
-                        self.open_elements.pop();
-                    }
+                // No nested headings
+                if self.current_node_is_one_of(&["h1", "h2", "h3", "h4", "h5", "h6"]) {
+                    self.open_elements.pop();
                 }
                 self.insert_element(name, attrs);
             }
-
-            HtmlToken::EndTag { ref name }
-                if matches!(name.as_str(), "h1" | "h2" | "h3" | "h4" | "h5" | "h6") =>
-            {
-                if self.has_in_scope(name) {
-                    self.generate_implied_end_tags();
-                    self.pop_until_one_of(&["h1", "h2", "h3", "h4", "h5", "h6"]);
-                }
-            }
-
-            // Block-level elements
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if matches!(
-                name.as_str(),
-                "address"
-                    | "article"
-                    | "aside"
-                    | "blockquote"
-                    | "center"
-                    | "details"
-                    | "dialog"
-                    | "dir"
-                    | "div"
-                    | "dl"
-                    | "fieldset"
-                    | "figcaption"
-                    | "figure"
-                    | "footer"
-                    | "header"
-                    | "hgroup"
-                    | "main"
-                    | "menu"
-                    | "nav"
-                    | "ol"
-                    | "p"
-                    | "search"
-                    | "section"
-                    | "summary"
-                    | "ul"
-            ) => {
+            "pre" | "listing" => {
                 self.close_p_element();
                 self.insert_element(name, attrs);
+                self.skip_newline = true;
+                self.frameset_ok = false;
             }
-
-            HtmlToken::EndTag { ref name }
-                if matches!(
-                    name.as_str(),
-                    "address"
-                        | "article"
-                        | "aside"
-                        | "blockquote"
-                        | "center"
-                        | "details"
-                        | "dialog"
-                        | "dir"
-                        | "div"
-                        | "dl"
-                        | "fieldset"
-                        | "figcaption"
-                        | "figure"
-                        | "footer"
-                        | "header"
-                        | "hgroup"
-                        | "main"
-                        | "menu"
-                        | "nav"
-                        | "ol"
-                        | "search"
-                        | "section"
-                        | "summary"
-                        | "ul"
-                ) =>
-            {
-                if self.has_in_scope(name) {
-                    self.generate_implied_end_tags();
-                    self.pop_until_tag(name);
-                }
-            }
-
-            // <p> end tag
-            HtmlToken::EndTag { ref name } if name == "p" => {
-                if !self.has_in_button_scope("p") {
-                    // Act as if <p> was seen
-                    self.insert_element("p", &[]);
+            "form" => {
+                let in_template = self.stack_has("template");
+                if self.form_pointer.is_some() && !in_template {
+                    return;
                 }
                 self.close_p_element();
+                let form = self.insert_element(name, attrs);
+                if !in_template {
+                    self.form_pointer = Some(form);
+                }
             }
-
-            // <li>
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if name == "li" => {
-                // Close any open <li> in list item scope
+            "li" | "dd" | "dt" => {
+                self.frameset_ok = false;
+                let closes: &[&str] = if name == "li" { &["li"] } else { &["dd", "dt"] };
                 for i in (0..self.open_elements.len()).rev() {
-                    let n = self.tag_name(self.open_elements[i]);
-                    if n == "li" {
-                        self.generate_implied_end_tags_except("li");
-                        self.pop_until_tag("li");
+                    let node = self.open_elements[i];
+                    let node_name = self.name(node).to_string();
+                    if closes.contains(&node_name.as_str()) {
+                        self.generate_implied_end_tags_except(&node_name);
+                        self.pop_until_tag(&node_name);
                         break;
                     }
-                    if is_special(&n)
-                        && !matches!(n.as_str(), "address" | "div" | "p")
-                    {
+                    if self.is_special_node(node) && !matches!(node_name.as_str(), "address" | "div" | "p") {
                         break;
                     }
                 }
                 self.close_p_element();
                 self.insert_element(name, attrs);
             }
-
-            HtmlToken::EndTag { ref name } if name == "li" => {
-                if self.has_in_list_item_scope("li") {
-                    self.generate_implied_end_tags_except("li");
-                    self.pop_until_tag("li");
+            "plaintext" => {
+                self.close_p_element();
+                self.insert_element(name, attrs);
+                self.pending_rawtext_tag = Some(name.to_string());
+            }
+            "button" => {
+                if self.has_in_scope("button", Scope::Default) {
+                    self.generate_implied_end_tags();
+                    self.pop_until_tag("button");
                 }
+                self.reconstruct_active_formatting();
+                self.insert_element(name, attrs);
+                self.frameset_ok = false;
             }
-
-            // <dd> / <dt>
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if name == "dd" || name == "dt" => {
-                for i in (0..self.open_elements.len()).rev() {
-                    let n = self.tag_name(self.open_elements[i]);
-                    if n == "dd" || n == "dt" {
-                        self.generate_implied_end_tags_except(&n);
-                        self.pop_until_tag(&n);
-                        break;
-                    }
-                    if is_special(&n)
-                        && !matches!(n.as_str(), "address" | "div" | "p")
-                    {
-                        break;
+            "a" => {
+                if let Some(a) = self.formatting_element_named("a") {
+                    self.adoption_agency("a");
+                    if let Some(index) = self.formatting_index(a) {
+                        self.active_formatting.remove(index);
                     }
+                    self.remove_from_stack(a);
                 }
-                self.close_p_element();
+                self.reconstruct_active_formatting();
+                let node = self.insert_element(name, attrs);
+                self.push_formatting_element(node, name, attrs);
+            }
+            "b" | "big" | "code" | "em" | "font" | "i" | "s" | "small" | "strike" | "strong"
+            | "tt" | "u" => {
+                self.reconstruct_active_formatting();
+                let node = self.insert_element(name, attrs);
+                self.push_formatting_element(node, name, attrs);
+            }
+            "nobr" => {
+                self.reconstruct_active_formatting();
+                if self.has_in_scope("nobr", Scope::Default) {
+                    self.adoption_agency("nobr");
+                    self.reconstruct_active_formatting();
+                }
+                let node = self.insert_element(name, attrs);
+                self.push_formatting_element(node, name, attrs);
+            }
+            "applet" | "marquee" | "object" => {
+                self.reconstruct_active_formatting();
                 self.insert_element(name, attrs);
+                self.active_formatting.push(FormattingEntry::Marker);
+                self.frameset_ok = false;
             }
-
-            HtmlToken::EndTag { ref name } if name == "dd" || name == "dt" => {
-                if self.has_in_scope(name) {
-                    self.generate_implied_end_tags_except(name);
-                    self.pop_until_tag(name);
+            "table" => {
+                if !self.is_quirks() {
+                    self.close_p_element();
                 }
+                self.insert_element(name, attrs);
+                self.frameset_ok = false;
+                self.mode = InsertionMode::InTable;
             }
-
-            // <pre>, <listing>
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if name == "pre" || name == "listing" => {
+            "area" | "br" | "embed" | "img" | "keygen" | "wbr" => {
+                self.reconstruct_active_formatting();
+                self.insert_void_element(name, attrs);
+                self.frameset_ok = false;
+            }
+            "input" => {
+                self.reconstruct_active_formatting();
+                self.insert_void_element(name, attrs);
+                if !is_hidden_input(attrs) {
+                    self.frameset_ok = false;
+                }
+            }
+            "param" | "source" | "track" => {
+                self.insert_void_element(name, attrs);
+            }
+            "hr" => {
                 self.close_p_element();
+                self.insert_void_element(name, attrs);
+                self.frameset_ok = false;
+            }
+            "image" => {
+                // Parse error: treated as <img>.
+                self.parse_error("unexpected-start-tag");
+                self.in_body_start_tag("img", attrs, token);
+            }
+            "textarea" => {
+                self.insert_text_element(name, attrs);
+                self.skip_newline = true;
+                self.frameset_ok = false;
+            }
+            "xmp" => {
+                self.close_p_element();
+                self.reconstruct_active_formatting();
+                self.frameset_ok = false;
+                self.insert_text_element(name, attrs);
+            }
+            "iframe" => {
+                self.frameset_ok = false;
+                self.insert_text_element(name, attrs);
+            }
+            "noembed" => self.insert_text_element(name, attrs),
+            "noscript" if self.scripting => self.insert_text_element(name, attrs),
+            "select" => {
+                self.reconstruct_active_formatting();
+                self.insert_element(name, attrs);
+                self.frameset_ok = false;
+                self.mode = match self.mode {
+                    InsertionMode::InTable
+                    | InsertionMode::InCaption
+                    | InsertionMode::InTableBody
+                    | InsertionMode::InRow
+                    | InsertionMode::InCell => InsertionMode::InSelectInTable,
+                    _ => InsertionMode::InSelect,
+                };
+            }
+            "optgroup" | "option" => {
+                if self.current_node_is("option") {
+                    self.open_elements.pop();
+                }
+                self.reconstruct_active_formatting();
                 self.insert_element(name, attrs);
             }
-
-            HtmlToken::EndTag { ref name } if name == "pre" || name == "listing" => {
-                if self.has_in_scope(name) {
+            "rb" | "rtc" => {
+                if self.has_in_scope("ruby", Scope::Default) {
                     self.generate_implied_end_tags();
-                    self.pop_until_tag(name);
                 }
+                self.insert_element(name, attrs);
             }
-
-            // <form>
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if name == "form" => {
-                if self.form_pointer.is_some() {
-                    // Ignore
-                } else {
-                    self.close_p_element();
-                    let form = self.insert_element(name, attrs);
-                    self.form_pointer = Some(form);
+            "rp" | "rt" => {
+                if self.has_in_scope("ruby", Scope::Default) {
+                    self.generate_implied_end_tags_except("rtc");
                 }
+                self.insert_element(name, attrs);
+            }
+            "math" | "svg" => {
+                self.reconstruct_active_formatting();
+                let namespace = if name == "svg" { Namespace::Svg } else { Namespace::MathMl };
+                let self_closing = matches!(token, HtmlToken::StartTag { self_closing: true, .. });
+                self.insert_foreign_element(namespace, name, attrs, self_closing);
+            }
+            "caption" | "col" | "colgroup" | "frame" | "head" | "tbody" | "td" | "tfoot" | "th"
+            | "thead" | "tr" => {
+                self.unexpected(token);
+            }
+            _ => {
+                self.reconstruct_active_formatting();
+                self.insert_element(name, attrs);
             }
+        }
+    }
 
-            HtmlToken::EndTag { ref name } if name == "form" => {
-                if let Some(_form_id) = self.form_pointer {
-                    self.form_pointer = None;
-                    if self.has_in_scope("form") {
+    fn in_body_end_tag(&mut self, name: &str, token: &HtmlToken) {
+        match name {
+            "template" => self.handle_in_head(token.clone()),
+            "body" | "html" => {
+                if !self.has_in_scope("body", Scope::Default) {
+                    return;
+                }
+                self.mode = InsertionMode::AfterBody;
+                if name == "html" {
+                    self.handle_after_body(token.clone());
+                }
+            }
+            "address" | "article" | "aside" | "blockquote" | "button" | "center" | "details"
+            | "dialog" | "dir" | "div" | "dl" | "fieldset" | "figcaption" | "figure" | "footer"
+            | "header" | "hgroup" | "listing" | "main" | "menu" | "nav" | "ol" | "pre"
+            | "search" | "section" | "summary" | "ul" => {
+                if self.has_in_scope(name, Scope::Default) {
+                    self.generate_implied_end_tags();
+                    self.pop_until_tag(name);
+                }
+            }
+            "form" => {
+                if self.stack_has("template") {
+                    if self.has_in_scope("form", Scope::Default) {
                         self.generate_implied_end_tags();
                         self.pop_until_tag("form");
                     }
+                    return;
+                }
+                let Some(form) = self.form_pointer.take() else {
+                    return;
+                };
+                if self.has_node_in_scope(form) {
+                    self.generate_implied_end_tags();
+                    self.remove_from_stack(form);
                 }
             }
-
-            // <table>
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if name == "table" => {
+            "p" => {
+                if !self.has_in_scope("p", Scope::Button) {
+                    self.parse_error("unexpected-end-tag");
+                    self.insert_element("p", &[]);
+                }
                 self.close_p_element();
-                self.insert_element(name, attrs);
-                self.mode = InsertionMode::InTable;
             }
+            "li" => {
+                if self.has_in_scope("li", Scope::ListItem) {
+                    self.generate_implied_end_tags_except("li");
+                    self.pop_until_tag("li");
+                }
+            }
+            "dd" | "dt" => {
+                if self.has_in_scope(name, Scope::Default) {
+                    self.generate_implied_end_tags_except(name);
+                    self.pop_until_tag(name);
+                }
+            }
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let headings = ["h1", "h2", "h3", "h4", "h5", "h6"];
+                if self.has_one_in_scope(&headings, Scope::Default) {
+                    self.generate_implied_end_tags();
+                    self.pop_until_one_of(&headings);
+                }
+            }
+            "a" | "b" | "big" | "code" | "em" | "font" | "i" | "nobr" | "s" | "small"
+            | "strike" | "strong" | "tt" | "u" => {
+                if !self.adoption_agency(name) {
+                    self.any_other_end_tag(name);
+                }
+            }
+            "applet" | "marquee" | "object" => {
+                if self.has_in_scope(name, Scope::Default) {
+                    self.generate_implied_end_tags();
+                    self.pop_until_tag(name);
+                    self.clear_formatting_to_last_marker();
+                }
+            }
+            "br" => {
+                // Parse error: treated as <br>.
+                self.parse_error("unexpected-end-tag");
+                self.in_body_start_tag("br", &[], token);
+            }
+            _ => self.any_other_end_tag(name),
+        }
+    }
 
-            HtmlToken::EndTag { ref name } if name == "table" => {
-                if self.has_in_scope("table") {
-                    self.pop_until_tag("table");
-                    self.reset_insertion_mode();
+    /// In body, any other end tag: close the nearest open element with that
+    /// name, unless a special element is in the way.
+    fn any_other_end_tag(&mut self, name: &str) {
+        for i in (0..self.open_elements.len()).rev() {
+            let node = self.open_elements[i];
+            if self.name(node) == name {
+                self.generate_implied_end_tags_except(name);
+                if self.current_node() != Some(node) {
+                    self.parse_error("end-tag-with-unclosed-elements");
                 }
+                self.pop_until_node(node);
+                return;
             }
+            if self.is_special_node(node) {
+                self.parse_error("unexpected-end-tag");
+                return;
+            }
+        }
+    }
 
-            // Formatting elements: a, b, big, code, em, font, i, s, small, strike, strong, tt, u
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if matches!(
-                name.as_str(),
-                "a" | "b" | "big" | "code" | "em" | "font" | "i" | "s" | "small" | "strike"
-                    | "strong" | "tt" | "u"
-            ) => {
-                // Simplified: just insert the element
-                // Full spec would do adoption agency algorithm
-                if name == "a" {
-                    // Close any existing <a> in scope
-                    // Simplified version
+    // =======================================================================
+    // Text
+    // =======================================================================
+
+    fn handle_text(&mut self, token: HtmlToken) {
+        match token {
+            HtmlToken::Character(c) => {
+                self.insert_character(c);
+            }
+            HtmlToken::EOF => {
+                self.parse_error("expected-closing-tag-but-got-eof");
+                self.open_elements.pop();
+                self.mode = self.original_mode;
+                self.process_token(token);
+            }
+            HtmlToken::EndTag { .. } => {
+                if let Some(node) = self.open_elements.pop()
+                    && self.name(node) == "script"
+                {
+                    self.pending_script = Some(node);
                 }
-                self.insert_element(name, attrs);
+                self.mode = self.original_mode;
             }
+            _ => {
+                self.open_elements.pop();
+                self.mode = self.original_mode;
+                self.process_token(token);
+            }
+        }
+    }
 
-            HtmlToken::EndTag { ref name }
+    // =======================================================================
+    // In Table
+    // =======================================================================
+
+    fn handle_in_table(&mut self, token: HtmlToken) {
+        match &token {
+            HtmlToken::Character(_)
+                if self.current_node_is_one_of(&["table", "tbody", "template", "tfoot", "thead", "tr"]) =>
+            {
+                self.pending_text.clear();
+                self.original_mode = self.mode;
+                self.reprocess_in(InsertionMode::InTableText, token);
+            }
+            HtmlToken::Comment(data) => self.insert_comment(data),
+            HtmlToken::Doctype { .. } => {}
+            HtmlToken::StartTag { name, attrs, .. } => match name.as_str() {
+                "caption" => {
+                    self.clear_stack_back_to(&["table", "template", "html"]);
+                    self.active_formatting.push(FormattingEntry::Marker);
+                    self.insert_element(name, attrs);
+                    self.mode = InsertionMode::InCaption;
+                }
+                "colgroup" => {
+                    self.clear_stack_back_to(&["table", "template", "html"]);
+                    self.insert_element(name, attrs);
+                    self.mode = InsertionMode::InColumnGroup;
+                }
+                "col" => {
+                    self.clear_stack_back_to(&["table", "template", "html"]);
+                    self.insert_element("colgroup", &[]);
+                    self.reprocess_in(InsertionMode::InColumnGroup, token);
+                }
+                "tbody" | "tfoot" | "thead" => {
+                    self.clear_stack_back_to(&["table", "template", "html"]);
+                    self.insert_element(name, attrs);
+                    self.mode = InsertionMode::InTableBody;
+                }
+                "td" | "th" | "tr" => {
+                    self.clear_stack_back_to(&["table", "template", "html"]);
+                    self.insert_element("tbody", &[]);
+                    self.reprocess_in(InsertionMode::InTableBody, token);
+                }
+                "table" => {
+                    if self.has_in_scope("table", Scope::Table) {
+                        self.pop_until_tag("table");
+                        self.reset_insertion_mode();
+                        self.process_token(token);
+                    }
+                }
+                "style" | "script" | "template" => self.handle_in_head(token),
+                "input" if is_hidden_input(attrs) => {
+                    self.insert_void_element(name, attrs);
+                }
+                "form" => {
+                    if self.stack_has("template") || self.form_pointer.is_some() {
+                        return;
+                    }
+                    let form = self.insert_element(name, attrs);
+                    self.form_pointer = Some(form);
+                    self.open_elements.pop();
+                }
+                _ => self.foster_parent(token),
+            },
+            HtmlToken::EndTag { name } => match name.as_str() {
+                "table" => {
+                    if self.has_in_scope("table", Scope::Table) {
+                        self.pop_until_tag("table");
+                        self.reset_insertion_mode();
+                    }
+                }
+                "body" | "caption" | "col" | "colgroup" | "html" | "tbody" | "td" | "tfoot"
+                | "th" | "thead" | "tr" => {
+                    self.unexpected(&token);
+                }
+                "template" => self.handle_in_head(token),
+                _ => self.foster_parent(token),
+            },
+            HtmlToken::EOF => self.handle_in_body(token),
+            HtmlToken::Character(_) => self.foster_parent(token),
+        }
+    }
+
+    /// In table, anything else: process the token as in body, but with
+    /// content that would land inside the table moved in front of it.
+    fn foster_parent(&mut self, token: HtmlToken) {
+        self.parse_error("foster-parented-content");
+        self.foster_parenting = true;
+        self.handle_in_body(token);
+        self.foster_parenting = false;
+    }
+
+    fn clear_stack_back_to(&mut self, tags: &[&str]) {
+        while !self.current_node_is_one_of(tags) && self.open_elements.len() > 1 {
+            self.open_elements.pop();
+        }
+    }
+
+    // =======================================================================
+    // In Table Text
+    // =======================================================================
+
+    fn handle_in_table_text(&mut self, token: HtmlToken) {
+        match token {
+            HtmlToken::Character('\0') => {}
+            HtmlToken::Character(c) => self.pending_text.push(c),
+            _ => {
+                let text = std::mem::take(&mut self.pending_text);
+                if text.chars().all(|c| c.is_ascii_whitespace()) {
+                    for c in text.chars() {
+                        self.insert_character(c);
+                    }
+                } else {
+                    for c in text.chars() {
+                        self.foster_parent(HtmlToken::Character(c));
+                    }
+                }
+                self.reprocess_in(self.original_mode, token);
+            }
+        }
+    }
+
+    // =======================================================================
+    // In Caption
+    // =======================================================================
+
+    fn handle_in_caption(&mut self, token: HtmlToken) {
+        let closes_caption = match &token {
+            HtmlToken::EndTag { name } if name == "caption" => Some(false),
+            HtmlToken::StartTag { name, .. }
                 if matches!(
                     name.as_str(),
-                    "a" | "b" | "big" | "code" | "em" | "font" | "i" | "s" | "small"
-                        | "strike" | "strong" | "tt" | "u"
+                    "caption" | "col" | "colgroup" | "tbody" | "td" | "tfoot" | "th" | "thead" | "tr"
                 ) =>
             {
-                // Simplified adoption agency: just pop until we find the tag
-                if self.has_in_scope(name) {
-                    self.generate_implied_end_tags();
-                    self.pop_until_tag(name);
+                Some(true)
+            }
+            HtmlToken::EndTag { name } if name == "table" => Some(true),
+            HtmlToken::EndTag { name }
+                if matches!(
+                    name.as_str(),
+                    "body" | "col" | "colgroup" | "html" | "tbody" | "td" | "tfoot" | "th" | "thead" | "tr"
+                ) =>
+            {
+                return;
+            }
+            _ => None,
+        };
+        let Some(reprocess) = closes_caption else {
+            self.handle_in_body(token);
+            return;
+        };
+        if !self.has_in_scope("caption", Scope::Table) {
+            return;
+        }
+        self.generate_implied_end_tags();
+        self.pop_until_tag("caption");
+        self.clear_formatting_to_last_marker();
+        self.mode = InsertionMode::InTable;
+        if reprocess {
+            self.process_token(token);
+        }
+    }
+
+    // =======================================================================
+    // In Column Group
+    // =======================================================================
+
+    fn handle_in_column_group(&mut self, token: HtmlToken) {
+        match &token {
+            HtmlToken::Character(c) if c.is_ascii_whitespace() => self.insert_character(*c),
+            HtmlToken::Comment(data) => self.insert_comment(data),
+            HtmlToken::Doctype { .. } => {}
+            HtmlToken::StartTag { name, .. } if name == "html" => self.handle_in_body(token),
+            HtmlToken::StartTag { name, attrs, .. } if name == "col" => {
+                self.insert_void_element(name, attrs);
+            }
+            HtmlToken::EndTag { name } if name == "colgroup" => {
+                if self.current_node_is("colgroup") {
+                    self.open_elements.pop();
+                    self.mode = InsertionMode::InTable;
                 }
             }
+            HtmlToken::EndTag { name } if name == "col" => {}
+            HtmlToken::StartTag { name, .. } | HtmlToken::EndTag { name } if name == "template" => {
+                self.handle_in_head(token);
+            }
+            HtmlToken::EOF => self.handle_in_body(token),
+            _ => {
+                if self.current_node_is("colgroup") {
+                    self.open_elements.pop();
+                    self.reprocess_in(InsertionMode::InTable, token);
+                }
+            }
+        }
+    }
 
-            // <span> and other ordinary inline elements
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if matches!(
-                name.as_str(),
-                "span" | "label" | "abbr" | "cite" | "dfn" | "kbd" | "mark"
-                    | "q" | "samp" | "sub" | "sup" | "var" | "time" | "data"
-                    | "ruby" | "rb" | "rp" | "rt" | "rtc" | "bdi" | "bdo"
-            ) => {
+    // =======================================================================
+    // In Table Body
+    // =======================================================================
+
+    fn handle_in_table_body(&mut self, token: HtmlToken) {
+        const CONTEXT: &[&str] = &["tbody", "tfoot", "thead", "template", "html"];
+        match &token {
+            HtmlToken::StartTag { name, attrs, .. } if name == "tr" => {
+                self.clear_stack_back_to(CONTEXT);
                 self.insert_element(name, attrs);
+                self.mode = InsertionMode::InRow;
             }
-
-            HtmlToken::EndTag { ref name }
+            HtmlToken::StartTag { name, .. } if matches!(name.as_str(), "th" | "td") => {
+                self.clear_stack_back_to(CONTEXT);
+                self.insert_element("tr", &[]);
+                self.reprocess_in(InsertionMode::InRow, token);
+            }
+            HtmlToken::EndTag { name } if matches!(name.as_str(), "tbody" | "tfoot" | "thead") => {
+                if self.has_in_scope(name, Scope::Table) {
+                    self.clear_stack_back_to(CONTEXT);
+                    self.open_elements.pop();
+                    self.mode = InsertionMode::InTable;
+                }
+            }
+            HtmlToken::StartTag { name, .. }
                 if matches!(
                     name.as_str(),
-                    "span" | "label" | "abbr" | "cite" | "dfn" | "kbd" | "mark"
-                        | "q" | "samp" | "sub" | "sup" | "var" | "time" | "data"
-                        | "ruby" | "rb" | "rp" | "rt" | "rtc" | "bdi" | "bdo"
+                    "caption" | "col" | "colgroup" | "tbody" | "tfoot" | "thead"
                 ) =>
             {
-                if self.has_in_scope(name) {
-                    self.generate_implied_end_tags();
-                    self.pop_until_tag(name);
-                }
+                self.close_table_body(token);
             }
+            HtmlToken::EndTag { name } if name == "table" => self.close_table_body(token),
+            HtmlToken::EndTag { name }
+                if matches!(
+                    name.as_str(),
+                    "body" | "caption" | "col" | "colgroup" | "html" | "td" | "th" | "tr"
+                ) => {}
+            _ => self.handle_in_table(token),
+        }
+    }
 
-            // <button>
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if name == "button" => {
-                if self.has_in_scope("button") {
-                    self.generate_implied_end_tags();
-                    self.pop_until_tag("button");
-                }
+    fn close_table_body(&mut self, token: HtmlToken) {
+        if !self.has_one_in_scope(&["tbody", "thead", "tfoot"], Scope::Table) {
+            return;
+        }
+        self.clear_stack_back_to(&["tbody", "tfoot", "thead", "template", "html"]);
+        self.open_elements.pop();
+        self.reprocess_in(InsertionMode::InTable, token);
+    }
+
+    // =======================================================================
+    // In Row
+    // =======================================================================
+
+    fn handle_in_row(&mut self, token: HtmlToken) {
+        const CONTEXT: &[&str] = &["tr", "template", "html"];
+        match &token {
+            HtmlToken::StartTag { name, attrs, .. } if matches!(name.as_str(), "th" | "td") => {
+                self.clear_stack_back_to(CONTEXT);
                 self.insert_element(name, attrs);
+                self.mode = InsertionMode::InCell;
+                self.active_formatting.push(FormattingEntry::Marker);
+            }
+            HtmlToken::EndTag { name } if name == "tr" => {
+                if self.has_in_scope("tr", Scope::Table) {
+                    self.clear_stack_back_to(CONTEXT);
+                    self.open_elements.pop();
+                    self.mode = InsertionMode::InTableBody;
+                }
+            }
+            HtmlToken::StartTag { name, .. }
+                if matches!(
+                    name.as_str(),
+                    "caption" | "col" | "colgroup" | "tbody" | "tfoot" | "thead" | "tr"
+                ) =>
+            {
+                self.close_row(token);
             }
+            HtmlToken::EndTag { name } if name == "table" => self.close_row(token),
+            HtmlToken::EndTag { name } if matches!(name.as_str(), "tbody" | "tfoot" | "thead") => {
+                if self.has_in_scope(name, Scope::Table) {
+                    self.close_row(token);
+                }
+            }
+            HtmlToken::EndTag { name }
+                if matches!(
+                    name.as_str(),
+                    "body" | "caption" | "col" | "colgroup" | "html" | "td" | "th"
+                ) => {}
+            _ => self.handle_in_table(token),
+        }
+    }
 
-            HtmlToken::EndTag { ref name } if name == "button" => {
-                if self.has_in_scope("button") {
+    fn close_row(&mut self, token: HtmlToken) {
+        if !self.has_in_scope("tr", Scope::Table) {
+            return;
+        }
+        self.clear_stack_back_to(&["tr", "template", "html"]);
+        self.open_elements.pop();
+        self.reprocess_in(InsertionMode::InTableBody, token);
+    }
+
+    // =======================================================================
+    // In Cell
+    // =======================================================================
+
+    fn handle_in_cell(&mut self, token: HtmlToken) {
+        match &token {
+            HtmlToken::EndTag { name } if matches!(name.as_str(), "td" | "th") => {
+                if self.has_in_scope(name, Scope::Table) {
                     self.generate_implied_end_tags();
-                    self.pop_until_tag("button");
+                    self.pop_until_tag(name);
+                    self.clear_formatting_to_last_marker();
+                    self.mode = InsertionMode::InRow;
                 }
             }
-
-            // <body> end tag
-            HtmlToken::EndTag { ref name } if name == "body" => {
-                if self.has_in_scope("body") {
-                    self.mode = InsertionMode::AfterBody;
+            HtmlToken::StartTag { name, .. }
+                if matches!(
+                    name.as_str(),
+                    "caption" | "col" | "colgroup" | "tbody" | "td" | "tfoot" | "th" | "thead" | "tr"
+                ) =>
+            {
+                if self.has_one_in_scope(&["td", "th"], Scope::Table) {
+                    self.close_cell();
+                    self.process_token(token);
                 }
             }
-
-            // <html> end tag
-            HtmlToken::EndTag { ref name } if name == "html" => {
-                if self.has_in_scope("body") {
-                    self.mode = InsertionMode::AfterBody;
-                    self.process_token(HtmlToken::EndTag {
-                        name: name.clone(),
-                    });
+            HtmlToken::EndTag { name }
+                if matches!(name.as_str(), "body" | "caption" | "col" | "colgroup" | "html") => {}
+            HtmlToken::EndTag { name }
+                if matches!(name.as_str(), "table" | "tbody" | "tfoot" | "thead" | "tr") =>
+            {
+                if self.has_in_scope(name, Scope::Table) {
+                    self.close_cell();
+                    self.process_token(token);
                 }
             }
+            _ => self.handle_in_body(token),
+        }
+    }
 
-            // <script>, <style>, <textarea> in body — raw text elements
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                ..
-            } if name == "script" || name == "style" || name == "textarea" => {
-                self.insert_element(name, attrs);
-                self.original_mode = self.mode;
-                self.mode = InsertionMode::Text;
-                self.pending_rawtext_tag = Some(name.clone());
-            }
+    fn close_cell(&mut self) {
+        self.generate_implied_end_tags();
+        self.pop_until_one_of(&["td", "th"]);
+        self.clear_formatting_to_last_marker();
+        self.mode = InsertionMode::InRow;
+    }
 
-            // EOF
-            HtmlToken::EOF => {
-                // Stop
-            }
+    // =======================================================================
+    // In Select
+    // =======================================================================
 
-            // Any other start tag — generic element
-            HtmlToken::StartTag {
-                ref name,
-                ref attrs,
-                self_closing,
-            } => {
-                self.insert_element(name, attrs);
-                if self_closing {
+    fn handle_in_select(&mut self, token: HtmlToken) {
+        match &token {
+            HtmlToken::Character('\0') => {}
+            HtmlToken::Character(c) => self.insert_character(*c),
+            HtmlToken::Comment(data) => self.insert_comment(data),
+            HtmlToken::Doctype { .. } => {}
+            HtmlToken::StartTag { name, attrs, .. } => match name.as_str() {
+                "html" => self.handle_in_body(token),
+                "option" => {
+                    if self.current_node_is("option") {
+                        self.open_elements.pop();
+                    }
+                    self.insert_element(name, attrs);
+                }
+                "optgroup" => {
+                    if self.current_node_is("option") {
+                        self.open_elements.pop();
+                    }
+                    if self.current_node_is("optgroup") {
+                        self.open_elements.pop();
+                    }
+                    self.insert_element(name, attrs);
+                }
+                "select" => self.close_select(),
+                "input" | "keygen" | "textarea" if self.has_in_scope("select", Scope::Select) => {
+                    self.close_select();
+                    self.process_token(token);
+                }
+                "script" | "template" => self.handle_in_head(token),
+                _ => {
+                    self.unexpected(&token);
+                }
+            },
+            HtmlToken::EndTag { name } => match name.as_str() {
+                "optgroup" => {
+                    let len = self.open_elements.len();
+                    if self.current_node_is("option")
+                        && len >= 2
+                        && self.name(self.open_elements[len - 2]) == "optgroup"
+                    {
+                        self.open_elements.pop();
+                    }
+                    if self.current_node_is("optgroup") {
+                        self.open_elements.pop();
+                    }
+                }
+                "option" if self.current_node_is("option") => {
                     self.open_elements.pop();
                 }
+                "select" => self.close_select(),
+                "template" => self.handle_in_head(token),
+                _ => {
+                    self.unexpected(&token);
+                }
+            },
+            HtmlToken::EOF => self.handle_in_body(token),
+        }
+    }
+
+    fn close_select(&mut self) {
+        if self.has_in_scope("select", Scope::Select) {
+            self.pop_until_tag("select");
+            self.reset_insertion_mode();
+        }
+    }
+
+    // =======================================================================
+    // In Select In Table
+    // =======================================================================
+
+    fn handle_in_select_in_table(&mut self, token: HtmlToken) {
+        const TABLE_TAGS: &[&str] = &["caption", "table", "tbody", "tfoot", "thead", "tr", "td", "th"];
+        match &token {
+            HtmlToken::StartTag { name, .. } if TABLE_TAGS.contains(&name.as_str()) => {
+                self.pop_until_tag("select");
+                self.reset_insertion_mode();
+                self.process_token(token);
+            }
+            HtmlToken::EndTag { name } if TABLE_TAGS.contains(&name.as_str()) => {
+                if self.has_in_scope(name, Scope::Table) {
+                    self.pop_until_tag("select");
+                    self.reset_insertion_mode();
+                    self.process_token(token);
+                }
             }
+            _ => self.handle_in_select(token),
+        }
+    }
 
-            // Any other end tag
-            HtmlToken::EndTag { ref name } => {
-                // Walk the stack backwards
-                for i in (0..self.open_elements.len()).rev() {
-                    let node_id = self.open_elements[i];
-                    if self.tag_name(node_id) == *name {
-                        self.generate_implied_end_tags_except(name);
-                        while self.open_elements.len() > i {
-                            self.open_elements.pop();
-                        }
-                        break;
-                    }
-                    if is_special(&self.tag_name(node_id)) {
-                        break;
+    // =======================================================================
+    // In Template
+    // =======================================================================
+
+    fn handle_in_template(&mut self, token: HtmlToken) {
+        match &token {
+            HtmlToken::Character(_) | HtmlToken::Comment(_) | HtmlToken::Doctype { .. } => {
+                self.handle_in_body(token);
+            }
+            HtmlToken::StartTag { name, .. } => {
+                let mode = match name.as_str() {
+                    "base" | "basefont" | "bgsound" | "link" | "meta" | "noframes" | "script"
+                    | "style" | "template" | "title" => {
+                        self.handle_in_head(token);
+                        return;
                     }
+                    "caption" | "colgroup" | "tbody" | "tfoot" | "thead" => InsertionMode::InTable,
+                    "col" => InsertionMode::InColumnGroup,
+                    "tr" => InsertionMode::InTableBody,
+                    "td" | "th" => InsertionMode::InRow,
+                    _ => InsertionMode::InBody,
+                };
+                self.template_modes.pop();
+                self.template_modes.push(mode);
+                self.reprocess_in(mode, token);
+            }
+            HtmlToken::EndTag { name } => {
+                if name == "template" {
+                    self.handle_in_head(token);
+                }
+            }
+            HtmlToken::EOF => {
+                if !self.stack_has("template") {
+                    return;
                 }
+                self.pop_until_tag("template");
+                self.clear_formatting_to_last_marker();
+                self.template_modes.pop();
+                self.reset_insertion_mode();
+                self.process_token(token);
             }
         }
     }
 
     // =======================================================================
-    // Text
+    // After Body
     // =======================================================================
 
-    fn handle_text(&mut self, token: HtmlToken) {
-        match token {
-            HtmlToken::Character(c) => {
-                self.insert_character(c);
+    fn handle_after_body(&mut self, token: HtmlToken) {
+        match &token {
+            HtmlToken::Character(c) if c.is_ascii_whitespace() => {
+                self.handle_in_body(token);
+            }
+            HtmlToken::Comment(data) => {
+                // Append to html element
+                let comment = self.dom.create_comment(data);
+                self.dom.append_child(self.open_elements[0], comment);
+            }
+            HtmlToken::Doctype { .. } => {
+                self.unexpected(&token);
+            }
+            HtmlToken::StartTag { name, .. } if name == "html" => {
+                self.handle_in_body(token);
+            }
+            HtmlToken::EndTag { name } if name == "html" => {
+                if self.fragment_context.is_none() {
+                    self.mode = InsertionMode::AfterAfterBody;
+                }
             }
             HtmlToken::EOF => {
+                // Stop
+            }
+            _ => {
+                self.reprocess_in(InsertionMode::InBody, token);
+            }
+        }
+    }
+
+    // =======================================================================
+    // In Frameset / After Frameset
+    // =======================================================================
+
+    fn handle_in_frameset(&mut self, token: HtmlToken) {
+        match &token {
+            HtmlToken::Character(c) if c.is_ascii_whitespace() => self.insert_character(*c),
+            HtmlToken::Comment(data) => self.insert_comment(data),
+            HtmlToken::StartTag { name, attrs, .. } => match name.as_str() {
+                "html" => self.handle_in_body(token),
+                "frameset" => {
+                    self.insert_element(name, attrs);
+                }
+                "frame" => self.insert_void_element(name, attrs),
+                "noframes" => self.handle_in_head(token),
+                _ => {}
+            },
+            HtmlToken::EndTag { name } if name == "frameset" => {
+                if self.open_elements.len() == 1 {
+                    return;
+                }
                 self.open_elements.pop();
-                self.mode = self.original_mode;
-                self.process_token(token);
+                if self.fragment_context.is_none() && !self.current_node_is("frameset") {
+                    self.mode = InsertionMode::AfterFrameset;
+                }
+            }
+            _ => {
+                // Ignore (EOF stops parsing)
+                if token != HtmlToken::EOF {
+                    self.unexpected(&token);
+                }
             }
-            HtmlToken::EndTag { .. } => {
-                self.open_elements.pop();
-                self.mode = self.original_mode;
+        }
+    }
+
+    fn handle_after_frameset(&mut self, token: HtmlToken) {
+        match &token {
+            HtmlToken::Character(c) if c.is_ascii_whitespace() => self.insert_character(*c),
+            HtmlToken::Comment(data) => self.insert_comment(data),
+            HtmlToken::StartTag { name, .. } if name == "html" => self.handle_in_body(token),
+            HtmlToken::StartTag { name, .. } if name == "noframes" => self.handle_in_head(token),
+            HtmlToken::EndTag { name } if name == "html" => {
+                self.mode = InsertionMode::AfterAfterFrameset;
             }
             _ => {
-                self.open_elements.pop();
-                self.mode = self.original_mode;
-                self.process_token(token);
+                // Ignore (EOF stops parsing)
+                if token != HtmlToken::EOF {
+                    self.unexpected(&token);
+                }
             }
         }
     }
 
     // =======================================================================
-    // After Body
+    // After After Body / After After Frameset
     // =======================================================================
 
-    fn handle_after_body(&mut self, token: HtmlToken) {
-        match token {
-            HtmlToken::Character(c) if c.is_ascii_whitespace() => {
-                self.handle_in_body(HtmlToken::Character(c));
-            }
+    fn handle_after_after_body(&mut self, token: HtmlToken) {
+        match &token {
             HtmlToken::Comment(data) => {
-                // Append to html element
-                if let Some(&html_id) = self.open_elements.first() {
-                    let comment = self.dom.create_comment(&data);
-                    self.dom.append_child(html_id, comment);
-                }
+                self.insert_comment_on_document(data);
             }
             HtmlToken::Doctype { .. } => {
-                // Ignore
+                self.handle_in_body(token);
             }
-            HtmlToken::StartTag { ref name, .. } if name == "html" => {
+            HtmlToken::Character(c) if c.is_ascii_whitespace() => {
                 self.handle_in_body(token);
             }
-            HtmlToken::EndTag { ref name } if name == "html" => {
-                self.mode = InsertionMode::AfterAfterBody;
+            HtmlToken::StartTag { name, .. } if name == "html" => {
+                self.handle_in_body(token);
             }
             HtmlToken::EOF => {
                 // Stop
             }
             _ => {
-                self.mode = InsertionMode::InBody;
-                self.process_token(token);
+                self.reprocess_in(InsertionMode::InBody, token);
+            }
+        }
+    }
+
+    fn handle_after_after_frameset(&mut self, token: HtmlToken) {
+        match &token {
+            HtmlToken::Comment(data) => self.insert_comment_on_document(data),
+            HtmlToken::Doctype { .. } => self.handle_in_body(token),
+            HtmlToken::Character(c) if c.is_ascii_whitespace() => self.handle_in_body(token),
+            HtmlToken::StartTag { name, .. } if name == "html" => self.handle_in_body(token),
+            HtmlToken::StartTag { name, .. } if name == "noframes" => self.handle_in_head(token),
+            _ => {
+                // Ignore (EOF stops parsing)
+                if token != HtmlToken::EOF {
+                    self.unexpected(&token);
+                }
             }
         }
     }
 
     // =======================================================================
-    // After After Body
+    // Foreign content
     // =======================================================================
 
-    fn handle_after_after_body(&mut self, token: HtmlToken) {
-        match token {
-            HtmlToken::Comment(data) => {
-                self.insert_comment_on_document(&data);
-            }
-            HtmlToken::Doctype { .. } => {
-                // Ignore
+    fn handle_foreign_content(&mut self, token: HtmlToken) {
+        match &token {
+            HtmlToken::Character('\0') => self.insert_character('\u{FFFD}'),
+            HtmlToken::Character(c) => {
+                self.insert_character(*c);
+                if !c.is_ascii_whitespace() {
+                    self.frameset_ok = false;
+                }
             }
-            HtmlToken::Character(c) if c.is_ascii_whitespace() => {
-                self.handle_in_body(HtmlToken::Character(c));
+            HtmlToken::Comment(data) => self.insert_comment(data),
+            HtmlToken::Doctype { .. } => {}
+            HtmlToken::StartTag { name, attrs, .. } if breaks_out_of_foreign_content(name, attrs) => {
+                self.leave_foreign_content(token)
             }
-            HtmlToken::EOF => {
-                // Stop
+            HtmlToken::EndTag { name } if name == "br" || name == "p" => self.leave_foreign_content(token),
+            HtmlToken::StartTag {
+                name,
+                attrs,
+                self_closing,
+            } => {
+                let namespace = self
+                    .adjusted_current_node()
+                    .map_or(Namespace::Html, |(namespace, ..)| namespace);
+                self.insert_foreign_element(namespace, name, attrs, *self_closing);
+            }
+            HtmlToken::EndTag { name } => {
+                // Close the nearest foreign element with this name (ASCII
+                // case-insensitively); an HTML element in the way hands the
+                // token back to the insertion mode.
+                for i in (0..self.open_elements.len()).rev() {
+                    let node = self.open_elements[i];
+                    let (namespace, tag) = self.qualified(node);
+                    if i < self.open_elements.len() - 1 && namespace == Namespace::Html {
+                        self.process_in(self.mode, token);
+                        return;
+                    }
+                    if i == 0 {
+                        return;
+                    }
+                    if tag.eq_ignore_ascii_case(name) {
+                        self.pop_until_node(node);
+                        return;
+                    }
+                }
             }
-            _ => {
-                self.mode = InsertionMode::InBody;
-                self.process_token(token);
+            HtmlToken::EOF => {}
+        }
+    }
+
+    /// An HTML tag in foreign content closes the SVG or MathML elements
+    /// back to the nearest HTML element or integration point, and is then
+    /// handled by the insertion mode.
+    fn leave_foreign_content(&mut self, token: HtmlToken) {
+        while let Some(node) = self.current_node() {
+            let (namespace, name) = self.qualified(node);
+            let text_integration_point = namespace == Namespace::MathMl
+                && matches!(name, "mi" | "mo" | "mn" | "ms" | "mtext");
+            if namespace == Namespace::Html
+                || text_integration_point
+                || self.is_html_integration_point(namespace, name, Some(node))
+            {
+                break;
             }
+            self.open_elements.pop();
         }
+        self.process_in(self.mode, token);
     }
 
     // =======================================================================
@@ -1104,76 +2253,162 @@ impl TreeBuilder {
     fn reset_insertion_mode(&mut self) {
         for i in (0..self.open_elements.len()).rev() {
             let node_id = self.open_elements[i];
-            let name = self.tag_name(node_id);
             let last = i == 0;
-
-            match name.as_str() {
+            // In a fragment, the bottom of the stack stands in for the
+            // context element.
+            let name = match &self.fragment_context {
+                Some((Namespace::Html, context)) if last => context.clone(),
+                Some(_) if last => String::new(),
+                _ => self.name(node_id).to_string(),
+            };
+
+            self.mode = match name.as_str() {
                 "select" => {
-                    self.mode = InsertionMode::InSelect;
-                    return;
-                }
-                "td" | "th" if !last => {
-                    self.mode = InsertionMode::InCell;
-                    return;
-                }
-                "tr" => {
-                    self.mode = InsertionMode::InRow;
-                    return;
-                }
-                "tbody" | "thead" | "tfoot" => {
-                    self.mode = InsertionMode::InTableBody;
-                    return;
-                }
-                "caption" => {
-                    self.mode = InsertionMode::InCaption;
-                    return;
-                }
-                "colgroup" => {
-                    self.mode = InsertionMode::InColumnGroup;
-                    return;
-                }
-                "table" => {
-                    self.mode = InsertionMode::InTable;
-                    return;
-                }
-                "template" => {
-                    self.mode = *self.template_modes.last().unwrap_or(&InsertionMode::InBody);
-                    return;
-                }
-                "head" if !last => {
-                    self.mode = InsertionMode::InHead;
-                    return;
-                }
-                "body" => {
-                    self.mode = InsertionMode::InBody;
-                    return;
-                }
-                "frameset" => {
-                    self.mode = InsertionMode::InFrameset;
-                    return;
+                    // A select inside a table (not inside a template in the
+                    // table) is in select in table mode.
+                    let in_table = self.open_elements[..i]
+                        .iter()
+                        .rev()
+                        .map(|&n| self.name(n))
+                        .take_while(|&n| n != "template")
+                        .any(|n| n == "table");
+                    if in_table && !last {
+                        InsertionMode::InSelectInTable
+                    } else {
+                        InsertionMode::InSelect
+                    }
                 }
+                "td" | "th" if !last => InsertionMode::InCell,
+                "tr" => InsertionMode::InRow,
+                "tbody" | "thead" | "tfoot" => InsertionMode::InTableBody,
+                "caption" => InsertionMode::InCaption,
+                "colgroup" => InsertionMode::InColumnGroup,
+                "table" => InsertionMode::InTable,
+                "template" => *self.template_modes.last().unwrap_or(&InsertionMode::InBody),
+                "head" if !last => InsertionMode::InHead,
+                "body" => InsertionMode::InBody,
+                "frameset" => InsertionMode::InFrameset,
                 "html" => {
                     if self.head_pointer.is_none() {
-                        self.mode = InsertionMode::BeforeHead;
+                        InsertionMode::BeforeHead
                     } else {
-                        self.mode = InsertionMode::AfterHead;
-                    }
-                    return;
-                }
-                _ => {
-                    if last {
-                        self.mode = InsertionMode::InBody;
-                        return;
+                        InsertionMode::AfterHead
                     }
                 }
-            }
+                _ if last => InsertionMode::InBody,
+                _ => continue,
+            };
+            return;
         }
         self.mode = InsertionMode::InBody;
     }
 }
 
+impl Default for TreeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether an `<input>` start tag has `type=hidden`.
+fn is_hidden_input(attrs: &[(String, String)]) -> bool {
+    attrs
+        .iter()
+        .any(|(name, value)| name == "type" && value.eq_ignore_ascii_case("hidden"))
+}
+
+// ===========================================================================
+// Quirks mode
+// ===========================================================================
+
+/// Public identifier prefixes that put a document in quirks mode.
+const QUIRKS_PUBLIC_PREFIXES: &[&str] = &[
+    "+//silmaril//dtd html pro v0r11 19970101//",
+    "-//as//dtd html 3.0 aswedit + extensions//",
+    "-//advasoft ltd//dtd html 3.0 aswedit + extensions//",
+    "-//ietf//dtd html 2.0 level 1//",
+    "-//ietf//dtd html 2.0 level 2//",
+    "-//ietf//dtd html 2.0 strict level 1//",
+    "-//ietf//dtd html 2.0 strict level 2//",
+    "-//ietf//dtd html 2.0 strict//",
+    "-//ietf//dtd html 2.0//",
+    "-//ietf//dtd html 2.1e//",
+    "-//ietf//dtd html 3.0//",
+    "-//ietf//dtd html 3.2 final//",
+    "-//ietf//dtd html 3.2//",
+    "-//ietf//dtd html 3//",
+    "-//ietf//dtd html level 0//",
+    "-//ietf//dtd html level 1//",
+    "-//ietf//dtd html level 2//",
+    "-//ietf//dtd html level 3//",
+    "-//ietf//dtd html strict level 0//",
+    "-//ietf//dtd html strict level 1//",
+    "-//ietf//dtd html strict level 2//",
+    "-//ietf//dtd html strict level 3//",
+    "-//ietf//dtd html strict//",
+    "-//ietf//dtd html//",
+    "-//metrius//dtd metrius presentational//",
+    "-//microsoft//dtd internet explorer 2.0 html strict//",
+    "-//microsoft//dtd internet explorer 2.0 html//",
+    "-//microsoft//dtd internet explorer 2.0 tables//",
+    "-//microsoft//dtd internet explorer 3.0 html strict//",
+    "-//microsoft//dtd internet explorer 3.0 html//",
+    "-//microsoft//dtd internet explorer 3.0 tables//",
+    "-//netscape comm. corp.//dtd html//",
+    "-//netscape comm. corp.//dtd strict html//",
+    "-//o'reilly and associates//dtd html 2.0//",
+    "-//o'reilly and associates//dtd html extended 1.0//",
+    "-//o'reilly and associates//dtd html extended relaxed 1.0//",
+    "-//sq//dtd html 2.0 hotmetal + extensions//",
+    "-//softquad software//dtd hotmetal pro 6.0::19990601::extensions to html 4.0//",
+    "-//softquad//dtd hotmetal pro 4.0::19971010::extensions to html 4.0//",
+    "-//spyglass//dtd html 2.0 extended//",
+    "-//sun microsystems corp.//dtd hotjava html//",
+    "-//sun microsystems corp.//dtd hotjava strict html//",
+    "-//w3c//dtd html 3 1995-03-24//",
+    "-//w3c//dtd html 3.2 draft//",
+    "-//w3c//dtd html 3.2 final//",
+    "-//w3c//dtd html 3.2//",
+    "-//w3c//dtd html 3.2s draft//",
+    "-//w3c//dtd html 4.0 frameset//",
+    "-//w3c//dtd html 4.0 transitional//",
+    "-//w3c//dtd html experimental 19960712//",
+    "-//w3c//dtd html experimental 970421//",
+    "-//w3c//dtd w3 html//",
+    "-//w3o//dtd w3 html 3.0//",
+    "-//webtechs//dtd mozilla html 2.0//",
+    "-//webtechs//dtd mozilla html//",
+];
+
+/// The document mode a doctype selects (WHATWG "initial" insertion mode).
+/// Identifiers compare ASCII case-insensitively.
+fn doctype_compat_mode(quirky_name: bool, public_id: Option<&str>, system_id: Option<&str>) -> CompatMode {
+    let public = public_id.unwrap_or("").to_ascii_lowercase();
+    let system = system_id.map(str::to_ascii_lowercase);
+    let html401 = public.starts_with("-//w3c//dtd html 4.01 frameset//")
+        || public.starts_with("-//w3c//dtd html 4.01 transitional//");
+    if quirky_name
+        || matches!(
+            public.as_str(),
+            "-//w3o//dtd w3 html strict 3.0//en//" | "-/w3c/dtd html 4.0 transitional/en" | "html"
+        )
+        || system.as_deref() == Some("http://www.ibm.com/data/dtd/v11/ibmxhtml1-transitional.dtd")
+        || QUIRKS_PUBLIC_PREFIXES.iter().any(|prefix| public.starts_with(prefix))
+        || (system.is_none() && html401)
+    {
+        CompatMode::Quirks
+    } else if public.starts_with("-//w3c//dtd xhtml 1.0 frameset//")
+        || public.starts_with("-//w3c//dtd xhtml 1.0 transitional//")
+        || (system.is_some() && html401)
+    {
+        CompatMode::LimitedQuirks
+    } else {
+        CompatMode::NoQuirks
+    }
+}
+
 // ===========================================================================
-// Special elements set (simplified)
+// Special elements set
 // ===========================================================================
 
 fn is_special(tag: &str) -> bool {
@@ -1265,29 +2500,498 @@ fn is_special(tag: &str) -> bool {
     )
 }
 
+/// The SVG and MathML elements that are special and bound the default,
+/// list item and button scopes.
+fn is_foreign_boundary(namespace: Namespace, name: &str) -> bool {
+    match namespace {
+        Namespace::MathMl => matches!(name, "mi" | "mo" | "mn" | "ms" | "mtext" | "annotation-xml"),
+        Namespace::Svg => matches!(name, "foreignObject" | "desc" | "title"),
+        Namespace::Html => false,
+    }
+}
+
+// ===========================================================================
+// Foreign content
+// ===========================================================================
+
+/// Whether an HTML start tag closes the SVG or MathML elements it appears
+/// in (`<p>` inside `<svg>`, say). `<font>` only does with presentational
+/// attributes.
+fn breaks_out_of_foreign_content(name: &str, attrs: &[(String, String)]) -> bool {
+    match name {
+        "b" | "big" | "blockquote" | "body" | "br" | "center" | "code" | "dd" | "div" | "dl"
+        | "dt" | "em" | "embed" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "head" | "hr" | "i"
+        | "img" | "li" | "listing" | "menu" | "meta" | "nobr" | "ol" | "p" | "pre" | "ruby"
+        | "s" | "small" | "span" | "strong" | "strike" | "sub" | "sup" | "table" | "tt" | "u"
+        | "ul" | "var" => true,
+        "font" => attrs
+            .iter()
+            .any(|(name, _)| matches!(name.as_str(), "color" | "face" | "size")),
+        _ => false,
+    }
+}
+
+/// SVG tag names the tokenizer has lowercased, in their proper case.
+const SVG_TAG_NAMES: &[(&str, &str)] = &[
+    ("altglyph", "altGlyph"),
+    ("altglyphdef", "altGlyphDef"),
+    ("altglyphitem", "altGlyphItem"),
+    ("animatecolor", "animateColor"),
+    ("animatemotion", "animateMotion"),
+    ("animatetransform", "animateTransform"),
+    ("clippath", "clipPath"),
+    ("feblend", "feBlend"),
+    ("fecolormatrix", "feColorMatrix"),
+    ("fecomponenttransfer", "feComponentTransfer"),
+    ("fecomposite", "feComposite"),
+    ("feconvolvematrix", "feConvolveMatrix"),
+    ("fediffuselighting", "feDiffuseLighting"),
+    ("fedisplacementmap", "feDisplacementMap"),
+    ("fedistantlight", "feDistantLight"),
+    ("fedropshadow", "feDropShadow"),
+    ("feflood", "feFlood"),
+    ("fefunca", "feFuncA"),
+    ("fefuncb", "feFuncB"),
+    ("fefuncg", "feFuncG"),
+    ("fefuncr", "feFuncR"),
+    ("fegaussianblur", "feGaussianBlur"),
+    ("feimage", "feImage"),
+    ("femerge", "feMerge"),
+    ("femergenode", "feMergeNode"),
+    ("femorphology", "feMorphology"),
+    ("feoffset", "feOffset"),
+    ("fepointlight", "fePointLight"),
+    ("fespecularlighting", "feSpecularLighting"),
+    ("fespotlight", "feSpotLight"),
+    ("fetile", "feTile"),
+    ("feturbulence", "feTurbulence"),
+    ("foreignobject", "foreignObject"),
+    ("glyphref", "glyphRef"),
+    ("lineargradient", "linearGradient"),
+    ("radialgradient", "radialGradient"),
+    ("textpath", "textPath"),
+];
+
+/// SVG attribute names the tokenizer has lowercased, in their proper case.
+const SVG_ATTRIBUTE_NAMES: &[(&str, &str)] = &[
+    ("attributename", "attributeName"),
+    ("attributetype", "attributeType"),
+    ("basefrequency", "baseFrequency"),
+    ("baseprofile", "baseProfile"),
+    ("calcmode", "calcMode"),
+    ("clippathunits", "clipPathUnits"),
+    ("diffuseconstant", "diffuseConstant"),
+    ("edgemode", "edgeMode"),
+    ("filterunits", "filterUnits"),
+    ("glyphref", "glyphRef"),
+    ("gradienttransform", "gradientTransform"),
+    ("gradientunits", "gradientUnits"),
+    ("kernelmatrix", "kernelMatrix"),
+    ("kernelunitlength", "kernelUnitLength"),
+    ("keypoints", "keyPoints"),
+    ("keysplines", "keySplines"),
+    ("keytimes", "keyTimes"),
+    ("lengthadjust", "lengthAdjust"),
+    ("limitingconeangle", "limitingConeAngle"),
+    ("markerheight", "markerHeight"),
+    ("markerunits", "markerUnits"),
+    ("markerwidth", "markerWidth"),
+    ("maskcontentunits", "maskContentUnits"),
+    ("maskunits", "maskUnits"),
+    ("numoctaves", "numOctaves"),
+    ("pathlength", "pathLength"),
+    ("patterncontentunits", "patternContentUnits"),
+    ("patterntransform", "patternTransform"),
+    ("patternunits", "patternUnits"),
+    ("pointsatx", "pointsAtX"),
+    ("pointsaty", "pointsAtY"),
+    ("pointsatz", "pointsAtZ"),
+    ("preservealpha", "preserveAlpha"),
+    ("preserveaspectratio", "preserveAspectRatio"),
+    ("primitiveunits", "primitiveUnits"),
+    ("refx", "refX"),
+    ("refy", "refY"),
+    ("repeatcount", "repeatCount"),
+    ("repeatdur", "repeatDur"),
+    ("requiredextensions", "requiredExtensions"),
+    ("requiredfeatures", "requiredFeatures"),
+    ("specularconstant", "specularConstant"),
+    ("specularexponent", "specularExponent"),
+    ("spreadmethod", "spreadMethod"),
+    ("startoffset", "startOffset"),
+    ("stddeviation", "stdDeviation"),
+    ("stitchtiles", "stitchTiles"),
+    ("surfacescale", "surfaceScale"),
+    ("systemlanguage", "systemLanguage"),
+    ("tablevalues", "tableValues"),
+    ("targetx", "targetX"),
+    ("targety", "targetY"),
+    ("textlength", "textLength"),
+    ("viewbox", "viewBox"),
+    ("viewtarget", "viewTarget"),
+    ("xchannelselector", "xChannelSelector"),
+    ("ychannelselector", "yChannelSelector"),
+    ("zoomandpan", "zoomAndPan"),
+];
+
+/// Restore the case of an SVG or MathML start tag's name and attributes,
+/// which the tokenizer lowercased. Attributes in the XLink, XML and XMLNS
+/// namespaces keep their qualified names (`xlink:href`).
+fn adjust_foreign_token(
+    namespace: Namespace,
+    tag: &str,
+    attrs: &[(String, String)],
+) -> (String, Vec<(String, String)>) {
+    let lookup = |table: &[(&str, &'static str)], name: &str| {
+        table.iter().find(|(lower, _)| *lower == name).map(|(_, fixed)| *fixed)
+    };
+    let attrs = attrs
+        .iter()
+        .map(|(name, value)| {
+            let fixed = match namespace {
+                Namespace::Svg => lookup(SVG_ATTRIBUTE_NAMES, name),
+                Namespace::MathMl => (name == "definitionurl").then_some("definitionURL"),
+                Namespace::Html => None,
+            };
+            (fixed.map_or_else(|| name.clone(), String::from), value.clone())
+        })
+        .collect();
+    let tag = match namespace {
+        Namespace::Svg => lookup(SVG_TAG_NAMES, tag).unwrap_or(tag),
+        _ => tag,
+    };
+    (tag.to_string(), attrs)
+}
+
 // ===========================================================================
 // Public convenience function
 // ===========================================================================
 
 /// Parse an HTML string into a DOM tree.
+///
+/// Scripts are not run; use [`Parser`] for that.
 pub fn parse(input: &str) -> Dom {
-    let mut tokenizer = Tokenizer::new(input);
+    let mut parser = Parser::new();
+    parser.feed(input);
+    parser.end();
+    while parser.run() != ParseStatus::Done {}
+    parser.into_dom()
+}
+
+// ===========================================================================
+// Fragment parsing
+// ===========================================================================
+
+/// Parse `markup` as the contents of a `context` element (the HTML fragment
+/// parsing algorithm), as for an `innerHTML` assignment.
+///
+/// Returns the DOM the fragment was built in and the top-level nodes of the
+/// fragment; copy them into a document with [`Dom::import_node`]. Scripts in
+/// the fragment are not run.
+pub fn parse_fragment(context: &str, markup: &str) -> (Dom, Vec<NodeId>) {
+    parse_fragment_in(Namespace::Html, context, markup)
+}
+
+/// [`parse_fragment`] for a context element in any namespace, e.g. the
+/// contents of an SVG `<g>`.
+pub fn parse_fragment_in(namespace: Namespace, context: &str, markup: &str) -> (Dom, Vec<NodeId>) {
+    let mut tokenizer = Tokenizer::new(markup);
+    // The tokenizer starts in the state the context element's contents
+    // would be read in. No start tag has been emitted, so no end tag can
+    // leave that state.
+    if namespace == Namespace::Html {
+        tokenizer.set_text_mode(TextMode::for_element(context), None);
+    }
+
     let mut builder = TreeBuilder::new();
+    let root = builder.dom.create_html_element("html");
+    builder.dom.append_child(builder.document, root);
+    builder.open_elements.push(root);
+    if namespace == Namespace::Html && context == "template" {
+        builder.template_modes.push(InsertionMode::InTemplate);
+    }
+    builder.fragment_context = Some((namespace, context.to_string()));
+    builder.reset_insertion_mode();
+    tokenizer.set_cdata_allowed(builder.in_foreign_content());
+
+    let mut parser = Parser {
+        tokenizer,
+        builder,
+        done: false,
+    };
+    while parser.run() != ParseStatus::Done {}
+    let dom = parser.into_dom();
+    let nodes = dom.children(root);
+    (dom, nodes)
+}
+
+/// Where [`insert_adjacent_html`] puts the new nodes, relative to the
+/// element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdjacentPosition {
+    BeforeBegin,
+    AfterBegin,
+    BeforeEnd,
+    AfterEnd,
+}
+
+impl AdjacentPosition {
+    /// Parse a position keyword (`"beforebegin"` etc., ASCII
+    /// case-insensitive).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "beforebegin" => Some(Self::BeforeBegin),
+            "afterbegin" => Some(Self::AfterBegin),
+            "beforeend" => Some(Self::BeforeEnd),
+            "afterend" => Some(Self::AfterEnd),
+            _ => None,
+        }
+    }
+}
+
+/// Why markup could not be inserted next to an element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FragmentError {
+    /// The new nodes would become children of the document, or of nothing.
+    NoModificationAllowed,
+}
+
+impl std::fmt::Display for FragmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FragmentError::NoModificationAllowed => {
+                write!(f, "NoModificationAllowedError: the element has no parent element")
+            }
+        }
+    }
+}
+
+/// Replace the children of `element` with the nodes parsed from `markup`
+/// (the `innerHTML` setter).
+pub fn set_inner_html(dom: &mut Dom, element: NodeId, markup: &str) {
+    let nodes = parse_into(dom, fragment_context(dom, element), markup);
+    // A template's children live in its contents fragment.
+    let target = dom
+        .nodes
+        .get(element)
+        .and_then(|n| n.as_element())
+        .and_then(|e| e.template_contents)
+        .unwrap_or(element);
+    for child in dom.children(target) {
+        dom.remove_child(target, child);
+    }
+    for node in nodes {
+        dom.append_child(target, node);
+    }
+    dom.mark_dirty_style(element);
+}
+
+/// Replace `element` with the nodes parsed from `markup` (the `outerHTML`
+/// setter). Does nothing if `element` has no parent.
+pub fn set_outer_html(dom: &mut Dom, element: NodeId, markup: &str) -> Result<(), FragmentError> {
+    let Some(parent) = dom.nodes.get(element).and_then(|n| n.parent) else {
+        return Ok(());
+    };
+    if is_document(dom, parent) {
+        return Err(FragmentError::NoModificationAllowed);
+    }
+    for node in parse_into(dom, fragment_context(dom, parent), markup) {
+        dom.insert_before(parent, node, Some(element));
+    }
+    dom.remove_child(parent, element);
+    dom.mark_dirty_style(parent);
+    Ok(())
+}
+
+/// Parse `markup` and insert the nodes at `position` relative to `element`
+/// (`insertAdjacentHTML`).
+pub fn insert_adjacent_html(
+    dom: &mut Dom,
+    element: NodeId,
+    position: AdjacentPosition,
+    markup: &str,
+) -> Result<(), FragmentError> {
+    let (parent, reference) = match position {
+        AdjacentPosition::BeforeBegin | AdjacentPosition::AfterEnd => {
+            let Some(node) = dom.nodes.get(element) else {
+                return Ok(());
+            };
+            let parent = node
+                .parent
+                .filter(|&p| !is_document(dom, p))
+                .ok_or(FragmentError::NoModificationAllowed)?;
+            let reference = if position == AdjacentPosition::BeforeBegin {
+                Some(element)
+            } else {
+                node.next_sibling
+            };
+            (parent, reference)
+        }
+        AdjacentPosition::AfterBegin => {
+            (element, dom.nodes.get(element).and_then(|n| n.first_child))
+        }
+        AdjacentPosition::BeforeEnd => (element, None),
+    };
+    let context = match fragment_context(dom, parent) {
+        (Namespace::Html, context) if context == "html" => (Namespace::Html, "body".to_string()),
+        context => context,
+    };
+    for node in parse_into(dom, context, markup) {
+        dom.insert_before(parent, node, reference);
+    }
+    dom.mark_dirty_style(parent);
+    Ok(())
+}
+
+/// The context element (namespace and tag) for parsing markup that will
+/// become children of `parent`. Anything that is not an element is treated
+/// as `<body>`.
+fn fragment_context(dom: &Dom, parent: NodeId) -> (Namespace, String) {
+    dom.nodes
+        .get(parent)
+        .and_then(|n| n.as_element())
+        .map(|e| (e.namespace, e.tag_name.clone()))
+        .unwrap_or_else(|| (Namespace::Html, "body".to_string()))
+}
+
+fn is_document(dom: &Dom, node: NodeId) -> bool {
+    matches!(
+        dom.nodes.get(node).map(|n| &n.data),
+        Some(NodeData::Document { .. })
+    )
+}
+
+/// Parse a fragment and copy its nodes into `dom`, detached.
+fn parse_into(dom: &mut Dom, (namespace, context): (Namespace, String), markup: &str) -> Vec<NodeId> {
+    let (fragment, nodes) = parse_fragment_in(namespace, &context, markup);
+    nodes
+        .into_iter()
+        .map(|node| dom.import_node(&fragment, node))
+        .collect()
+}
+
+// ===========================================================================
+// Incremental parser
+// ===========================================================================
+
+/// Why [`Parser::run`] returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseStatus {
+    /// Everything fed so far has been parsed; call [`Parser::feed`] or
+    /// [`Parser::end`], then `run` again.
+    NeedInput,
+    /// A parser-inserted `<script>` element was just closed. The embedder
+    /// runs it (possibly calling [`Parser::document_write`]) and then calls
+    /// `run` again.
+    Script(NodeId),
+    /// The whole input has been parsed.
+    Done,
+}
+
+/// An HTML parser that accepts the document in pieces as it downloads, so
+/// the partial tree can be rendered before the end arrives.
+///
+/// ```
+/// use html::tree_builder::{ParseStatus, Parser};
+///
+/// let mut parser = Parser::new();
+/// parser.feed("<p>Hel");
+/// assert_eq!(parser.run(), ParseStatus::NeedInput);
+/// parser.feed("lo</p>");
+/// parser.end();
+/// assert_eq!(parser.run(), ParseStatus::Done);
+/// ```
+pub struct Parser {
+    tokenizer: Tokenizer,
+    builder: TreeBuilder,
+    done: bool,
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        Self {
+            tokenizer: Tokenizer::streaming(),
+            builder: TreeBuilder::new(),
+            done: false,
+        }
+    }
+
+    /// Parse as for a browsing context with scripting disabled, where
+    /// `<noscript>` contents are markup rather than raw text.
+    pub fn set_scripting(&mut self, enabled: bool) {
+        self.builder.scripting = enabled;
+    }
 
-    loop {
-        let token = tokenizer.next_token();
-        let is_eof = token == HtmlToken::EOF;
-        builder.process_token(token);
-        // If the tree builder wants raw-text mode, switch the tokenizer.
-        if let Some(tag) = builder.pending_rawtext_tag.take() {
-            tokenizer.switch_to_rawtext(&tag);
+    /// Append the next piece of the document.
+    pub fn feed(&mut self, input: &str) {
+        self.tokenizer.feed(input);
+    }
+
+    /// Mark the end of the document.
+    pub fn end(&mut self) {
+        self.tokenizer.end();
+    }
+
+    /// Parse as far as the input allows.
+    pub fn run(&mut self) -> ParseStatus {
+        if self.done {
+            return ParseStatus::Done;
         }
-        if is_eof {
-            break;
+        loop {
+            let Some(token) = self.tokenizer.try_next_token() else {
+                return ParseStatus::NeedInput;
+            };
+            let is_eof = token == HtmlToken::EOF;
+            self.builder.process_token(token);
+            // If the tree builder wants raw-text mode, switch the tokenizer.
+            if let Some(tag) = self.builder.pending_rawtext_tag.take() {
+                self.tokenizer.switch_to_rawtext(&tag);
+            }
+            self.tokenizer.set_cdata_allowed(self.builder.in_foreign_content());
+            if is_eof {
+                self.done = true;
+                return ParseStatus::Done;
+            }
+            if let Some(script) = self.builder.pending_script.take() {
+                return ParseStatus::Script(script);
+            }
+        }
+    }
+
+    /// `document.write` from a parser-inserted script: `text` is parsed next,
+    /// ahead of the rest of the document. Ignored once parsing is done
+    /// (writing to a closed document would replace it, which is not
+    /// supported).
+    pub fn document_write(&mut self, text: &str) {
+        if !self.done {
+            self.tokenizer.insert(text);
         }
     }
 
-    builder.dom
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// The tree built so far.
+    pub fn dom(&self) -> &Dom {
+        &self.builder.dom
+    }
+
+    /// The tree built so far, for scripts to modify while the parser is
+    /// stopped at them.
+    pub fn dom_mut(&mut self) -> &mut Dom {
+        &mut self.builder.dom
+    }
+
+    pub fn into_dom(self) -> Dom {
+        self.builder.dom
+    }
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // ===========================================================================
@@ -1677,4 +3381,193 @@ mod tests {
         let divs = dom.get_elements_by_tag(doc_id, "div");
         assert_eq!(divs.len(), 3);
     }
+
+    /// Helper: the tree as nested `tag[text]` outline, for comparing parses.
+    fn outline(dom: &Dom, id: NodeId) -> String {
+        let mut out = match &dom.nodes.get(id).unwrap().data {
+            NodeData::Text { data } => format!("{data:?}"),
+            _ => tag(dom, id),
+        };
+        let children = dom.children(id);
+        if !children.is_empty() {
+            let inner: Vec<String> = children.iter().map(|&c| outline(dom, c)).collect();
+            out.push_str(&format!("({})", inner.join(" ")));
+        }
+        out
+    }
+
+    fn document(dom: &Dom) -> NodeId {
+        dom.nodes
+            .iter()
+            .find(|(_, n)| matches!(n.data, NodeData::Document { .. }))
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn incremental_parse_matches_whole_parse() {
+        let html = "<!DOCTYPE html><html><head><title>T &amp; U</title>\
+                    <script>if (a < b) { x = '</scr' + 'ipt>'; }</script></head>\
+                    <body><!-- note --><p class=\"a\">caf&eacute; &notit; &#x41;</p>\
+                    <textarea>a<b></textarea></body></html>";
+        let whole = parse(html);
+
+        let mut parser = Parser::new();
+        for c in html.chars() {
+            parser.feed(&c.to_string());
+            while let ParseStatus::Script(_) = parser.run() {}
+        }
+        parser.end();
+        while parser.run() != ParseStatus::Done {}
+        let streamed = parser.into_dom();
+
+        assert_eq!(
+            outline(&streamed, document(&streamed)),
+            outline(&whole, document(&whole))
+        );
+    }
+
+    #[test]
+    fn incremental_parse_pauses_for_scripts() {
+        let mut parser = Parser::new();
+        parser.feed("<body><p>before</p><script>document.write('x')</scr");
+        assert_eq!(parser.run(), ParseStatus::NeedInput);
+        let paragraphs = parser.dom().get_elements_by_tag(document(parser.dom()), "p");
+        assert_eq!(paragraphs.len(), 1, "content before the script is available");
+
+        parser.feed("ipt><p>after</p>");
+        let ParseStatus::Script(script) = parser.run() else {
+            panic!("expected the parser to stop at the script");
+        };
+        assert_eq!(tag(parser.dom(), script), "script");
+        parser.document_write("<p>written</p>");
+        parser.end();
+        assert_eq!(parser.run(), ParseStatus::Done);
+
+        let dom = parser.into_dom();
+        let texts: Vec<String> = dom
+            .get_elements_by_tag(document(&dom), "p")
+            .iter()
+            .map(|&p| text(&dom, dom.children(p)[0]))
+            .collect();
+        assert_eq!(texts, vec!["before", "written", "after"]);
+    }
+
+    #[test]
+    fn fragment_parsing_uses_the_context_element() {
+        let outline_all = |context: &str, markup: &str| {
+            let (dom, nodes) = parse_fragment(context, markup);
+            nodes.iter().map(|&n| outline(&dom, n)).collect::<Vec<_>>().join(" ")
+        };
+        assert_eq!(outline_all("div", "<p>a</p>b<br>"), "p(\"a\") \"b\" br");
+        // Body-level content does not get an html/head/body wrapper.
+        assert_eq!(outline_all("body", "<title>x</title>"), "title(\"x\")");
+        assert_eq!(outline_all("ul", "<li>1<li>2"), "li(\"1\") li(\"2\")");
+        assert_eq!(outline_all("textarea", "<b>&amp;</b>"), "\"<b>&</b>\"");
+        assert_eq!(outline_all("html", "<p>x"), "head body(p(\"x\"))");
+    }
+
+    #[test]
+    fn rcdata_fragments_decode_references_but_not_tags() {
+        let outline_all = |context: &str, markup: &str| {
+            let (dom, nodes) = parse_fragment(context, markup);
+            nodes.iter().map(|&n| outline(&dom, n)).collect::<Vec<_>>().join(" ")
+        };
+        assert_eq!(outline_all("title", "a &lt;b&gt; &amp c"), "\"a <b> & c\"");
+        // No start tag opened the context, so its end tag is text too.
+        assert_eq!(outline_all("textarea", "x</textarea>y"), "\"x</textarea>y\"");
+        // Raw text elements keep references as written.
+        assert_eq!(outline_all("style", "&amp;"), "\"&amp;\"");
+
+        // innerHTML round-trips: RCDATA text is escaped on the way out.
+        let mut dom = parse("<textarea></textarea>");
+        let textarea = dom.get_elements_by_tag(document(&dom), "textarea")[0];
+        set_inner_html(&mut dom, textarea, "<b>&amp;</b>");
+        assert_eq!(text(&dom, dom.children(textarea)[0]), "<b>&</b>");
+        let markup = crate::serializer::inner_html(&dom, textarea);
+        assert_eq!(markup, "&lt;b&gt;&amp;&lt;/b&gt;");
+        set_inner_html(&mut dom, textarea, &markup);
+        assert_eq!(text(&dom, dom.children(textarea)[0]), "<b>&</b>");
+    }
+
+    #[test]
+    fn inner_and_outer_html_setters() {
+        let mut dom = parse("<div id=a><p>old</p></div><span>s</span>");
+        let body = dom.get_elements_by_tag(document(&dom), "body")[0];
+        let div = dom.get_element_by_id(body, "a").unwrap();
+
+        set_inner_html(&mut dom, div, "<i>new</i> text");
+        assert_eq!(outline(&dom, body), "body(div(i(\"new\") \" text\") span(\"s\"))");
+
+        insert_adjacent_html(&mut dom, div, AdjacentPosition::AfterBegin, "<b>1</b>").unwrap();
+        insert_adjacent_html(&mut dom, div, AdjacentPosition::BeforeEnd, "<b>2</b>").unwrap();
+        insert_adjacent_html(&mut dom, div, AdjacentPosition::BeforeBegin, "<hr>").unwrap();
+        insert_adjacent_html(&mut dom, div, AdjacentPosition::AfterEnd, "<hr>").unwrap();
+        assert_eq!(
+            outline(&dom, body),
+            "body(hr div(b(\"1\") i(\"new\") \" text\" b(\"2\")) hr span(\"s\"))"
+        );
+
+        set_outer_html(&mut dom, div, "<em>x</em><em>y</em>").unwrap();
+        assert_eq!(outline(&dom, body), "body(hr em(\"x\") em(\"y\") hr span(\"s\"))");
+
+        let html = dom.nodes.get(body).unwrap().parent.unwrap();
+        assert_eq!(
+            set_outer_html(&mut dom, html, "<p>"),
+            Err(FragmentError::NoModificationAllowed)
+        );
+        assert_eq!(AdjacentPosition::parse("BeforeEnd"), Some(AdjacentPosition::BeforeEnd));
+        assert_eq!(AdjacentPosition::parse("inside"), None);
+    }
+
+    #[test]
+    fn template_children_live_in_its_contents() {
+        let mut dom = parse("<template><p>a</p></template>");
+        let template = dom.get_elements_by_tag(document(&dom), "template")[0];
+        assert!(dom.children(template).is_empty());
+        assert_eq!(crate::inner_html(&dom, template), "<p>a</p>");
+
+        set_inner_html(&mut dom, template, "<td>b</td>");
+        assert!(dom.children(template).is_empty());
+        assert_eq!(crate::inner_html(&dom, template), "<td>b</td>");
+    }
+
+    #[test]
+    fn inner_html_of_svg_parses_svg() {
+        let mut dom = parse("<svg><g></g></svg>");
+        let g = dom.get_elements_by_tag(document(&dom), "g")[0];
+        set_inner_html(&mut dom, g, "<lineargradient/><circle r=2/>");
+        let children = dom.children(g);
+        let element = |id| dom.nodes.get(id).and_then(|n| n.as_element()).unwrap();
+        assert_eq!(element(children[0]).tag_name, "linearGradient");
+        assert!(children.iter().all(|&c| element(c).namespace == Namespace::Svg));
+    }
+
+    #[test]
+    fn reports_tree_construction_errors() {
+        let mut tokenizer = Tokenizer::new("<p><b><i>x</b></i></span><div/>");
+        let mut builder = TreeBuilder::new();
+        builder.set_error_reporting(true);
+        loop {
+            builder.set_position(tokenizer.position());
+            let token = tokenizer.next_token();
+            let eof = token == HtmlToken::EOF;
+            builder.process_token(token);
+            if eof {
+                break;
+            }
+        }
+        let errors: Vec<_> = builder.take_errors().into_iter().map(|e| (e.code, e.offset)).collect();
+        assert_eq!(
+            errors,
+            vec![
+                ("missing-doctype", 0),
+                ("misnested-formatting-element", 10),
+                ("unexpected-end-tag", 14),
+                ("unexpected-end-tag", 18),
+                ("non-void-html-element-start-tag-with-trailing-solidus", 25),
+                ("expected-closing-tag-but-got-eof", 31),
+            ]
+        );
+    }
 }
@@ -0,0 +1,622 @@
+//! # HTTP Authentication (RFC 9110 §11, RFC 7617, RFC 7616)
+//!
+//! Parses `WWW-Authenticate` challenges and builds `Authorization` header
+//! values for the Basic and Digest schemes. Digest supports the `MD5`,
+//! `MD5-sess`, `SHA-256` and `SHA-256-sess` algorithms with `qop=auth`.
+
+use crypto::{md5, sha256};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Types
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// An authentication scheme we know how to answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthScheme {
+    Basic,
+    Digest,
+    /// Any other scheme (Bearer, Negotiate, ...). Parsed but not answerable.
+    Other,
+}
+
+/// Hash algorithm of a Digest challenge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigestAlgorithm {
+    Md5,
+    Md5Sess,
+    Sha256,
+    Sha256Sess,
+}
+
+impl DigestAlgorithm {
+    /// Parse the `algorithm` parameter (case-insensitive). A missing parameter
+    /// means MD5 per RFC 7616 §3.3.
+    pub fn from_param(value: Option<&str>) -> Option<Self> {
+        let Some(value) = value else {
+            return Some(Self::Md5);
+        };
+        match value.to_ascii_uppercase().as_str() {
+            "MD5" => Some(Self::Md5),
+            "MD5-SESS" => Some(Self::Md5Sess),
+            "SHA-256" => Some(Self::Sha256),
+            "SHA-256-SESS" => Some(Self::Sha256Sess),
+            _ => None,
+        }
+    }
+
+    /// The token used in the `algorithm` parameter of the response.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Md5 => "MD5",
+            Self::Md5Sess => "MD5-sess",
+            Self::Sha256 => "SHA-256",
+            Self::Sha256Sess => "SHA-256-sess",
+        }
+    }
+
+    fn is_session(self) -> bool {
+        matches!(self, Self::Md5Sess | Self::Sha256Sess)
+    }
+
+    /// Hash `data` and return the lowercase hex digest.
+    fn hex_digest(self, data: &str) -> String {
+        match self {
+            Self::Md5 | Self::Md5Sess => to_hex(&md5(data.as_bytes())),
+            Self::Sha256 | Self::Sha256Sess => to_hex(&sha256(data.as_bytes())),
+        }
+    }
+}
+
+/// A single parsed challenge from a `WWW-Authenticate` header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthChallenge {
+    pub scheme: AuthScheme,
+    /// The scheme name as sent by the server.
+    pub scheme_name: String,
+    /// Auth parameters with lowercased names, in order.
+    pub params: Vec<(String, String)>,
+}
+
+impl AuthChallenge {
+    /// Look up a parameter by (case-insensitive) name.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// The protection space name. Empty if the server didn't send one.
+    pub fn realm(&self) -> &str {
+        self.param("realm").unwrap_or("")
+    }
+
+    /// Digest algorithm, or `None` for non-Digest or unsupported algorithms.
+    pub fn digest_algorithm(&self) -> Option<DigestAlgorithm> {
+        if self.scheme != AuthScheme::Digest {
+            return None;
+        }
+        DigestAlgorithm::from_param(self.param("algorithm"))
+    }
+
+    /// Whether the server marked the previous nonce as stale (RFC 7616 §3.3),
+    /// meaning the credentials were right and only the nonce must be refreshed.
+    pub fn is_stale(&self) -> bool {
+        self.param("stale")
+            .map(|v| v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false)
+    }
+
+    /// Whether we are able to produce an `Authorization` for this challenge.
+    pub fn is_supported(&self) -> bool {
+        match self.scheme {
+            AuthScheme::Basic => true,
+            AuthScheme::Digest => {
+                self.param("nonce").is_some() && self.digest_algorithm().is_some() && {
+                    // qop absent (RFC 2069 compatibility) or offering "auth".
+                    self.param("qop").is_none_or(qop_offers_auth)
+                }
+            }
+            AuthScheme::Other => false,
+        }
+    }
+}
+
+/// A username / password pair.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Credentials {
+    pub username: String,
+    pub password: String,
+}
+
+impl Credentials {
+    pub fn new(username: &str, password: &str) -> Self {
+        Self {
+            username: username.to_string(),
+            password: password.to_string(),
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Challenge parsing
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Parse one `WWW-Authenticate` header value into its challenges.
+///
+/// A single header may carry several comma-separated challenges, e.g.
+/// `Digest realm="x", nonce="y", Basic realm="x"`. The parser tells a new
+/// challenge apart from another auth-param by the absence of `=` after the
+/// token.
+pub fn parse_www_authenticate(value: &str) -> Vec<AuthChallenge> {
+    let mut challenges: Vec<AuthChallenge> = Vec::new();
+    let mut p = HeaderCursor::new(value);
+
+    loop {
+        p.skip_separators();
+        if p.at_end() {
+            break;
+        }
+
+        let token = p.token();
+        if token.is_empty() {
+            // Garbage: skip to the next separator and keep going.
+            p.skip_junk();
+            continue;
+        }
+
+        p.skip_spaces();
+        if p.peek() == Some(b'=') && !challenges.is_empty() {
+            // auth-param for the current challenge.
+            p.bump();
+            p.skip_spaces();
+            let v = if p.peek() == Some(b'"') {
+                p.quoted_string()
+            } else {
+                p.token().to_string()
+            };
+            if let Some(current) = challenges.last_mut() {
+                current.params.push((token.to_ascii_lowercase(), v));
+            }
+            continue;
+        }
+
+        // A new challenge begins with this scheme token.
+        let scheme = match token.to_ascii_lowercase().as_str() {
+            "basic" => AuthScheme::Basic,
+            "digest" => AuthScheme::Digest,
+            _ => AuthScheme::Other,
+        };
+        challenges.push(AuthChallenge {
+            scheme,
+            scheme_name: token.to_string(),
+            params: Vec::new(),
+        });
+
+        // token68 form (e.g. `Negotiate abc==`) — store it under "token68".
+        p.skip_spaces();
+        if p.looks_like_token68() {
+            let t = p.token68();
+            if let Some(current) = challenges.last_mut() {
+                current.params.push(("token68".to_string(), t.to_string()));
+            }
+        }
+    }
+
+    challenges
+}
+
+/// Collect challenges from every `WWW-Authenticate` header in `headers`.
+pub fn challenges_from_headers(headers: &[(String, String)]) -> Vec<AuthChallenge> {
+    headers
+        .iter()
+        .filter(|(k, _)| k.eq_ignore_ascii_case("www-authenticate"))
+        .flat_map(|(_, v)| parse_www_authenticate(v))
+        .collect()
+}
+
+/// Pick the strongest challenge we can answer: Digest SHA-256 first, then
+/// Digest MD5, then Basic.
+pub fn select_challenge(challenges: &[AuthChallenge]) -> Option<&AuthChallenge> {
+    fn rank(c: &AuthChallenge) -> u8 {
+        match (c.scheme, c.digest_algorithm()) {
+            (AuthScheme::Digest, Some(DigestAlgorithm::Sha256 | DigestAlgorithm::Sha256Sess)) => 3,
+            (AuthScheme::Digest, Some(_)) => 2,
+            (AuthScheme::Basic, _) => 1,
+            _ => 0,
+        }
+    }
+    challenges
+        .iter()
+        .filter(|c| c.is_supported())
+        .max_by_key(|c| rank(c))
+}
+
+/// Byte cursor over a header value.
+struct HeaderCursor<'a> {
+    bytes: &'a [u8],
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> HeaderCursor<'a> {
+    fn new(src: &'a str) -> Self {
+        Self { bytes: src.as_bytes(), src, pos: 0 }
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    /// Step over one character, all of its bytes, so that `pos` stays on a
+    /// char boundary of `src`.
+    fn bump(&mut self) {
+        self.pos += self.src[self.pos..].chars().next().map_or(1, char::len_utf8);
+    }
+
+    /// Skip characters up to the next space, tab or comma.
+    fn skip_junk(&mut self) {
+        while !self.at_end() && !matches!(self.peek(), Some(b' ' | b'\t' | b',')) {
+            self.bump();
+        }
+    }
+
+    fn skip_spaces(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t')) {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b',')) {
+            self.pos += 1;
+        }
+    }
+
+    /// Read an RFC 9110 token.
+    fn token(&mut self) -> &'a str {
+        let start = self.pos;
+        while let Some(b) = self.peek() {
+            if is_tchar(b) {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        &self.src[start..self.pos]
+    }
+
+    /// Whether a token68 (rather than auth-params) follows the scheme:
+    /// `abc`, `abc==` followed by end or comma, as opposed to `name=value`.
+    fn looks_like_token68(&self) -> bool {
+        let mut i = self.pos;
+        while let Some(&b) = self.bytes.get(i) {
+            if is_token68_char(b) {
+                i += 1;
+            } else {
+                break;
+            }
+        }
+        if i == self.pos {
+            return false;
+        }
+        while self.bytes.get(i) == Some(&b'=') {
+            i += 1;
+        }
+        while matches!(self.bytes.get(i), Some(b' ' | b'\t')) {
+            i += 1;
+        }
+        matches!(self.bytes.get(i), None | Some(b','))
+    }
+
+    fn token68(&mut self) -> &'a str {
+        let start = self.pos;
+        while let Some(b) = self.peek() {
+            if is_token68_char(b) || b == b'=' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        &self.src[start..self.pos]
+    }
+
+    /// Read a quoted-string (cursor on the opening quote), unescaping `\x`.
+    fn quoted_string(&mut self) -> String {
+        self.bump();
+        let mut out = Vec::new();
+        while let Some(b) = self.peek() {
+            self.pos += 1;
+            match b {
+                b'"' => break,
+                b'\\' => {
+                    if let Some(next) = self.peek() {
+                        out.push(next);
+                        self.pos += 1;
+                    }
+                }
+                _ => out.push(b),
+            }
+        }
+        String::from_utf8_lossy(&out).into_owned()
+    }
+}
+
+fn is_tchar(b: u8) -> bool {
+    b.is_ascii_alphanumeric()
+        || matches!(
+            b,
+            b'!' | b'#' | b'$' | b'%' | b'&' | b'\'' | b'*' | b'+' | b'-' | b'.' | b'^' | b'_'
+                | b'`' | b'|' | b'~'
+        )
+}
+
+fn is_token68_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~' | b'+' | b'/')
+}
+
+fn qop_offers_auth(qop: &str) -> bool {
+    qop.split(',').any(|q| q.trim().eq_ignore_ascii_case("auth"))
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Authorization header construction
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Build a Basic `Authorization` header value (RFC 7617).
+pub fn basic_authorization(creds: &Credentials) -> String {
+    let raw = format!("{}:{}", creds.username, creds.password);
+    format!("Basic {}", base64_encode(raw.as_bytes()))
+}
+
+/// Build a Digest `Authorization` header value (RFC 7616 §3.4).
+///
+/// * `uri` — the request-target exactly as sent on the request line.
+/// * `cnonce` — a fresh client nonce (only used when the server offers qop).
+/// * `nc` — nonce count: how many requests have used this server nonce,
+///   starting at 1.
+///
+/// Returns `None` if the challenge isn't a supported Digest challenge.
+pub fn digest_authorization(
+    challenge: &AuthChallenge,
+    creds: &Credentials,
+    method: &str,
+    uri: &str,
+    cnonce: &str,
+    nc: u32,
+) -> Option<String> {
+    if challenge.scheme != AuthScheme::Digest || !challenge.is_supported() {
+        return None;
+    }
+    let algorithm = challenge.digest_algorithm()?;
+    let realm = challenge.realm();
+    let nonce = challenge.param("nonce")?;
+    let use_qop = challenge.param("qop").is_some();
+
+    let mut ha1 = algorithm.hex_digest(&format!("{}:{}:{}", creds.username, realm, creds.password));
+    if algorithm.is_session() {
+        ha1 = algorithm.hex_digest(&format!("{ha1}:{nonce}:{cnonce}"));
+    }
+    let ha2 = algorithm.hex_digest(&format!("{method}:{uri}"));
+    let nc_str = format!("{nc:08x}");
+    let response = if use_qop {
+        algorithm.hex_digest(&format!("{ha1}:{nonce}:{nc_str}:{cnonce}:auth:{ha2}"))
+    } else {
+        algorithm.hex_digest(&format!("{ha1}:{nonce}:{ha2}"))
+    };
+
+    let mut out = format!(
+        "Digest username=\"{}\", realm=\"{}\", uri=\"{}\", algorithm={}, nonce=\"{}\"",
+        quote_escape(&creds.username),
+        quote_escape(realm),
+        quote_escape(uri),
+        algorithm.as_str(),
+        quote_escape(nonce),
+    );
+    if use_qop {
+        out.push_str(&format!(", nc={nc_str}, cnonce=\"{}\", qop=auth", quote_escape(cnonce)));
+    }
+    out.push_str(&format!(", response=\"{response}\""));
+    if let Some(opaque) = challenge.param("opaque") {
+        out.push_str(&format!(", opaque=\"{}\"", quote_escape(opaque)));
+    }
+    Some(out)
+}
+
+/// Build the `Authorization` value for whichever scheme `challenge` uses.
+pub fn authorization_for(
+    challenge: &AuthChallenge,
+    creds: &Credentials,
+    method: &str,
+    uri: &str,
+    cnonce: &str,
+    nc: u32,
+) -> Option<String> {
+    match challenge.scheme {
+        AuthScheme::Basic => Some(basic_authorization(creds)),
+        AuthScheme::Digest => digest_authorization(challenge, creds, method, uri, cnonce, nc),
+        AuthScheme::Other => None,
+    }
+}
+
+fn quote_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for &b in bytes {
+        s.push(HEX[(b >> 4) as usize] as char);
+        s.push(HEX[(b & 0xf) as usize] as char);
+    }
+    s
+}
+
+/// Standard base64 with padding (RFC 4648 §4).
+fn base64_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+        out.push(ALPHABET[(n >> 18) as usize & 63] as char);
+        out.push(ALPHABET[(n >> 12) as usize & 63] as char);
+        out.push(if chunk.len() > 1 { ALPHABET[(n >> 6) as usize & 63] as char } else { '=' });
+        out.push(if chunk.len() > 2 { ALPHABET[n as usize & 63] as char } else { '=' });
+    }
+    out
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_basic() {
+        let c = parse_www_authenticate(r#"Basic realm="Secure Area", charset="UTF-8""#);
+        assert_eq!(c.len(), 1);
+        assert_eq!(c[0].scheme, AuthScheme::Basic);
+        assert_eq!(c[0].realm(), "Secure Area");
+        assert_eq!(c[0].param("CHARSET"), Some("UTF-8"));
+    }
+
+    #[test]
+    fn test_parse_multiple_challenges() {
+        let c = parse_www_authenticate(
+            r#"Digest realm="http-auth@example.org", qop="auth, auth-int", algorithm=SHA-256, nonce="7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v", opaque="FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS", Basic realm="fallback""#,
+        );
+        assert_eq!(c.len(), 2);
+        assert_eq!(c[0].scheme, AuthScheme::Digest);
+        assert_eq!(c[0].digest_algorithm(), Some(DigestAlgorithm::Sha256));
+        assert_eq!(c[0].param("qop"), Some("auth, auth-int"));
+        assert_eq!(c[1].scheme, AuthScheme::Basic);
+        assert_eq!(c[1].realm(), "fallback");
+    }
+
+    #[test]
+    fn test_parse_token68_and_escapes() {
+        let c = parse_www_authenticate(r#"Negotiate abc+/d==, Basic realm="a \"quoted\" realm""#);
+        assert_eq!(c.len(), 2);
+        assert_eq!(c[0].scheme, AuthScheme::Other);
+        assert_eq!(c[0].param("token68"), Some("abc+/d=="));
+        assert_eq!(c[1].realm(), r#"a "quoted" realm"#);
+    }
+
+    #[test]
+    fn test_parse_non_ascii() {
+        let c = parse_www_authenticate(r#"Basic realm="Zürich""#);
+        assert_eq!(c[0].realm(), "Zürich");
+
+        // An unquoted value ends at the first non-token character.
+        let c = parse_www_authenticate("Basic realm=Zürich");
+        assert_eq!(c.len(), 1);
+        assert_eq!(c[0].realm(), "Z");
+
+        let c = parse_www_authenticate(r#"Basic realm="a", ☃☃ ünï, Digest realm="b", nonce="n""#);
+        assert_eq!(c.len(), 2);
+        assert_eq!(c[0].realm(), "a");
+        assert_eq!(c[1].scheme, AuthScheme::Digest);
+        assert_eq!(c[1].realm(), "b");
+    }
+
+    #[test]
+    fn test_select_prefers_strongest() {
+        let mut all = parse_www_authenticate(r#"Basic realm="r""#);
+        all.extend(parse_www_authenticate(r#"Digest realm="r", nonce="n", algorithm=MD5"#));
+        all.extend(parse_www_authenticate(r#"Digest realm="r", nonce="n", algorithm=SHA-256"#));
+        all.extend(parse_www_authenticate(r#"Bearer realm="r""#));
+        let best = select_challenge(&all).unwrap();
+        assert_eq!(best.digest_algorithm(), Some(DigestAlgorithm::Sha256));
+
+        let only_unsupported = parse_www_authenticate(r#"Digest realm="r", nonce="n", qop="auth-int""#);
+        assert!(select_challenge(&only_unsupported).is_none());
+    }
+
+    #[test]
+    fn test_basic_authorization() {
+        // RFC 7617 §2 example.
+        let creds = Credentials::new("Aladdin", "open sesame");
+        assert_eq!(basic_authorization(&creds), "Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==");
+        assert_eq!(base64_encode(b"a"), "YQ==");
+        assert_eq!(base64_encode(b"ab"), "YWI=");
+    }
+
+    #[test]
+    fn test_digest_rfc7616_md5() {
+        // RFC 7616 §3.9.1, MD5 variant.
+        let challenge = &parse_www_authenticate(
+            r#"Digest realm="http-auth@example.org", qop="auth, auth-int", algorithm=MD5, nonce="7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v", opaque="FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS""#,
+        )[0];
+        let creds = Credentials::new("Mufasa", "Circle of Life");
+        let header = digest_authorization(
+            challenge,
+            &creds,
+            "GET",
+            "/dir/index.html",
+            "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ",
+            1,
+        )
+        .unwrap();
+        assert!(header.contains(r#"response="8ca523f5e9506fed4657c9700eebdbec""#), "{header}");
+        assert!(header.contains("nc=00000001"));
+        assert!(header.contains(r#"opaque="FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS""#));
+    }
+
+    #[test]
+    fn test_digest_rfc7616_sha256() {
+        // RFC 7616 §3.9.1, SHA-256 variant.
+        let challenge = &parse_www_authenticate(
+            r#"Digest realm="http-auth@example.org", qop="auth, auth-int", algorithm=SHA-256, nonce="7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v", opaque="FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS""#,
+        )[0];
+        let creds = Credentials::new("Mufasa", "Circle of Life");
+        let header = authorization_for(
+            challenge,
+            &creds,
+            "GET",
+            "/dir/index.html",
+            "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ",
+            1,
+        )
+        .unwrap();
+        assert!(
+            header.contains(r#"response="753927fa0e85d155564e2e272a28d1802ca10daf4496794697cf8db5856cb6c1""#),
+            "{header}"
+        );
+        assert!(header.contains("algorithm=SHA-256"));
+    }
+
+    #[test]
+    fn test_digest_rfc2069_without_qop() {
+        // RFC 2617 §3.5 example, computed without qop (RFC 2069 mode).
+        let challenge = &parse_www_authenticate(
+            r#"Digest realm="testrealm@host.com", nonce="dcd98b7102dd2f0e8b11d0f600bfb0c093""#,
+        )[0];
+        let creds = Credentials::new("Mufasa", "Circle Of Life");
+        let header =
+            digest_authorization(challenge, &creds, "GET", "/dir/index.html", "unused", 1).unwrap();
+        assert!(header.contains(r#"response="670fd8c2df070c60b045671b8b24ff02""#), "{header}");
+        assert!(!header.contains("cnonce"));
+    }
+
+    #[test]
+    fn test_stale_flag() {
+        let c = &parse_www_authenticate(r#"Digest realm="r", nonce="n2", stale=TRUE"#)[0];
+        assert!(c.is_stale());
+    }
+}
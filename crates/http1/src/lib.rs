@@ -2,10 +2,15 @@
 //!
 //! Builds HTTP/1.1 request messages and parses response messages using a
 //! state-machine approach. Supports Content-Length, chunked transfer encoding,
-//! and read-until-close body modes. **Zero external crate dependencies.**
+//! and read-until-close body modes. The [`auth`] module handles Basic and
+//! Digest authentication challenges, and [`link`] parses `Link` headers.
+//! **Zero external crate dependencies.**
 
 #![forbid(unsafe_code)]
 
+pub mod auth;
+pub mod link;
+
 use core::fmt;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -317,6 +322,32 @@ impl HttpResponseParser {
         })
     }
 
+    /// The response as received so far, for consumers that process it
+    /// while it is still arriving. The body holds the decoded bytes past
+    /// the first `from`.
+    ///
+    /// Returns `None` until the headers are complete. Chunked bodies only
+    /// include chunks that have fully arrived.
+    pub fn partial_response(&self, from: usize) -> Option<HttpResponse> {
+        let header_end = find_header_end(&self.buf)?;
+        let header_str = std::str::from_utf8(&self.buf[..header_end]).ok()?;
+        let (version, status, reason, headers) = parse_headers(header_str).ok()?;
+        let body = &self.buf[header_end + 4..];
+        let body = match determine_body_mode(&headers, status) {
+            BodyMode::None => Vec::new(),
+            BodyMode::ContentLength(len) => body[..body.len().min(len)].get(from..)?.to_vec(),
+            BodyMode::UntilClose => body.get(from..)?.to_vec(),
+            BodyMode::Chunked => decode_chunked_prefix(body, from),
+        };
+        Some(HttpResponse {
+            version,
+            status,
+            reason,
+            headers,
+            body,
+        })
+    }
+
     /// Clear the internal buffer.
     pub fn reset(&mut self) {
         self.buf.clear();
@@ -476,6 +507,30 @@ fn decode_chunked(data: &[u8]) -> Result<Option<(Vec<u8>, usize)>, HttpError> {
     }
 }
 
+/// The data of the complete chunks at the start of `data`, skipping the
+/// first `from` bytes of decoded body.
+fn decode_chunked_prefix(data: &[u8], from: usize) -> Vec<u8> {
+    let mut body = Vec::new();
+    let mut decoded = 0;
+    let mut offset = 0;
+    while let Some(line_end) = find_crlf(data, offset) {
+        let size = std::str::from_utf8(&data[offset..line_end])
+            .ok()
+            .and_then(|line| usize::from_str_radix(line.split(';').next()?.trim(), 16).ok());
+        let start = line_end + 2;
+        let Some(size) = size.filter(|&size| size > 0 && start + size + 2 <= data.len()) else {
+            break;
+        };
+        let chunk = &data[start..start + size];
+        if decoded + size > from {
+            body.extend_from_slice(&chunk[from.saturating_sub(decoded)..]);
+        }
+        decoded += size;
+        offset = start + size + 2;
+    }
+    body
+}
+
 fn find_crlf(data: &[u8], start: usize) -> Option<usize> {
     if data.len() < start + 2 {
         return None;
@@ -563,6 +618,26 @@ mod tests {
         assert_eq!(resp.body, b"Hello");
     }
 
+    #[test]
+    fn test_partial_response() {
+        let body = |parser: &HttpResponseParser, from| {
+            parser.partial_response(from).map(|resp| (resp.status, resp.body))
+        };
+        let mut parser = HttpResponseParser::new();
+        parser.feed(b"HTTP/1.1 200 OK\r\nContent-Length: 11\r\n");
+        assert_eq!(body(&parser, 0), None);
+        parser.feed(b"\r\nHello");
+        assert_eq!(body(&parser, 0), Some((200, b"Hello".to_vec())));
+        assert_eq!(body(&parser, 3), Some((200, b"lo".to_vec())));
+        assert_eq!(parser.partial_response(0).unwrap().header("content-length"), Some("11"));
+
+        let mut parser = HttpResponseParser::new();
+        parser.feed(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nHello\r\n6\r\n Wor");
+        assert_eq!(body(&parser, 0), Some((200, b"Hello".to_vec())));
+        parser.feed(b"ld\r\n0\r\n");
+        assert_eq!(body(&parser, 2), Some((200, b"llo World".to_vec())));
+    }
+
     #[test]
     fn test_parse_response_incomplete() {
         let raw = b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\nPartial";
@@ -0,0 +1,208 @@
+//! # Link Header (RFC 8288)
+//!
+//! Parses `Link` response headers into their target URIs and parameters,
+//! e.g. `</style.css>; rel=preload; as=style, </app.js>; rel="preload"`.
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Types
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// One link from a `Link` header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    /// The URI reference between `<` and `>`, unresolved.
+    pub target: String,
+    /// Parameters with lowercased names, in order, quotes removed.
+    pub params: Vec<(String, String)>,
+}
+
+impl Link {
+    /// Value of the first parameter named `name` (lowercase).
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+    }
+
+    /// Whether the space-separated `rel` parameter contains `relation`
+    /// (ASCII case-insensitive).
+    pub fn has_rel(&self, relation: &str) -> bool {
+        self.param("rel")
+            .is_some_and(|rel| rel.split_ascii_whitespace().any(|r| r.eq_ignore_ascii_case(relation)))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Parsing
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Parse one `Link` header value. Malformed links are skipped.
+pub fn parse_link(value: &str) -> Vec<Link> {
+    let mut links = Vec::new();
+    let mut cursor = Cursor::new(value);
+    loop {
+        cursor.skip_whitespace_and(',');
+        if cursor.at_end() {
+            break;
+        }
+        match cursor.link() {
+            Some(link) => links.push(link),
+            None => cursor.skip_past_comma(),
+        }
+    }
+    links
+}
+
+/// Collect links from every `Link` header in `headers`.
+pub fn links_from_headers(headers: &[(String, String)]) -> Vec<Link> {
+    headers
+        .iter()
+        .filter(|(k, _)| k.eq_ignore_ascii_case("link"))
+        .flat_map(|(_, v)| parse_link(v))
+        .collect()
+}
+
+/// Byte cursor over a header value.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { bytes: text.as_bytes(), text, pos: 0 }
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_whitespace_and(&mut self, extra: char) {
+        while let Some(b) = self.peek() {
+            if b == b' ' || b == b'\t' || b == extra as u8 {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Skip the rest of a malformed link, up to the next top-level comma.
+    fn skip_past_comma(&mut self) {
+        let mut quoted = false;
+        while let Some(b) = self.peek() {
+            self.pos += 1;
+            match b {
+                b'"' => quoted = !quoted,
+                b'\\' if quoted => self.pos += 1,
+                b',' if !quoted => return,
+                _ => {}
+            }
+        }
+    }
+
+    /// `<target>` followed by `; name[=value]` parameters.
+    fn link(&mut self) -> Option<Link> {
+        if self.peek() != Some(b'<') {
+            return None;
+        }
+        let start = self.pos + 1;
+        let end = start + self.text[start..].find('>')?;
+        let target = self.text[start..end].trim().to_string();
+        self.pos = end + 1;
+
+        let mut params = Vec::new();
+        loop {
+            self.skip_whitespace_and(' ');
+            match self.peek() {
+                Some(b';') => self.pos += 1,
+                Some(b',') | None => break,
+                Some(_) => return None,
+            }
+            self.skip_whitespace_and(' ');
+            let name = self.token().to_ascii_lowercase();
+            if name.is_empty() {
+                continue;
+            }
+            self.skip_whitespace_and(' ');
+            let value = if self.peek() == Some(b'=') {
+                self.pos += 1;
+                self.skip_whitespace_and(' ');
+                if self.peek() == Some(b'"') { self.quoted_string() } else { self.token().to_string() }
+            } else {
+                String::new()
+            };
+            params.push((name, value));
+        }
+        Some(Link { target, params })
+    }
+
+    fn token(&mut self) -> &'a str {
+        let start = self.pos;
+        while let Some(b) = self.peek() {
+            if matches!(b, b';' | b',' | b'=' | b' ' | b'\t' | b'"') {
+                break;
+            }
+            self.pos += 1;
+        }
+        &self.text[start..self.pos]
+    }
+
+    fn quoted_string(&mut self) -> String {
+        let mut out = String::new();
+        self.pos += 1;
+        let mut chars = self.text[self.pos..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return out;
+                }
+                '\\' => {
+                    if let Some((_, escaped)) = chars.next() {
+                        out.push(escaped);
+                    }
+                }
+                _ => out.push(c),
+            }
+        }
+        self.pos = self.bytes.len();
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_targets_and_params() {
+        let links = parse_link(r#"</style.css>; rel=preload; as=style, <https://cdn.example/app.js>; rel="preload modulepreload"; crossorigin"#);
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].target, "/style.css");
+        assert_eq!(links[0].param("as"), Some("style"));
+        assert!(links[0].has_rel("PRELOAD"));
+        assert_eq!(links[1].target, "https://cdn.example/app.js");
+        assert!(links[1].has_rel("modulepreload"));
+        assert_eq!(links[1].param("crossorigin"), Some(""));
+    }
+
+    #[test]
+    fn skips_malformed_links() {
+        let links = parse_link(r#"nope; rel="a,b", <ok>; title="say \"hi\", ok"; rel=next"#);
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].param("title"), Some(r#"say "hi", ok"#));
+        assert!(links[0].has_rel("next"));
+
+        let headers = vec![
+            ("Link".to_string(), "<a.css>; rel=stylesheet".to_string()),
+            ("Content-Type".to_string(), "text/html".to_string()),
+            ("link".to_string(), "<b.css>; rel=stylesheet".to_string()),
+        ];
+        let targets: Vec<_> = links_from_headers(&headers).into_iter().map(|l| l.target).collect();
+        assert_eq!(targets, ["a.css", "b.css"]);
+    }
+}
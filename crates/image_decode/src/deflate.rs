@@ -234,6 +234,12 @@ fn build_fixed_dist_table() -> HuffmanTable {
 
 /// Decompress DEFLATE-compressed data (RFC 1951).
 pub fn inflate(compressed: &[u8]) -> Result<Vec<u8>, ParseError> {
+    inflate_limited(compressed, usize::MAX)
+}
+
+/// [`inflate`], failing as soon as the output would exceed `max_len`
+/// bytes. Guards against decompression bombs when the real size is known.
+pub fn inflate_limited(compressed: &[u8], max_len: usize) -> Result<Vec<u8>, ParseError> {
     let mut reader = BitReader::new(compressed);
     let mut output = Vec::new();
 
@@ -250,6 +256,9 @@ pub fn inflate(compressed: &[u8]) -> Result<Vec<u8>, ParseError> {
                 if len != !nlen {
                     return Err(ParseError::InvalidValue("DEFLATE stored block length mismatch"));
                 }
+                if len as usize > max_len - output.len() {
+                    return Err(OUTPUT_TOO_LONG);
+                }
                 for _ in 0..len {
                     output.push(reader.read_byte()?);
                 }
@@ -258,12 +267,12 @@ pub fn inflate(compressed: &[u8]) -> Result<Vec<u8>, ParseError> {
                 // Fixed Huffman codes
                 let lit_table = build_fixed_lit_table();
                 let dist_table = build_fixed_dist_table();
-                inflate_block(&mut reader, &lit_table, &dist_table, &mut output)?;
+                inflate_block(&mut reader, &lit_table, &dist_table, &mut output, max_len)?;
             }
             2 => {
                 // Dynamic Huffman codes
                 let (lit_table, dist_table) = decode_dynamic_tables(&mut reader)?;
-                inflate_block(&mut reader, &lit_table, &dist_table, &mut output)?;
+                inflate_block(&mut reader, &lit_table, &dist_table, &mut output, max_len)?;
             }
             _ => {
                 return Err(ParseError::InvalidValue("DEFLATE reserved block type 3"));
@@ -278,18 +287,24 @@ pub fn inflate(compressed: &[u8]) -> Result<Vec<u8>, ParseError> {
     Ok(output)
 }
 
+const OUTPUT_TOO_LONG: ParseError = ParseError::LengthOutOfRange("DEFLATE output");
+
 /// Decompress a single Huffman-coded block.
 fn inflate_block(
     reader: &mut BitReader<'_>,
     lit_table: &HuffmanTable,
     dist_table: &HuffmanTable,
     output: &mut Vec<u8>,
+    max_len: usize,
 ) -> Result<(), ParseError> {
     loop {
         let sym = lit_table.decode(reader)?;
 
         if sym < 256 {
             // Literal byte
+            if output.len() == max_len {
+                return Err(OUTPUT_TOO_LONG);
+            }
             output.push(sym as u8);
         } else if sym == 256 {
             // End of block
@@ -313,6 +328,9 @@ fn inflate_block(
             if distance > output.len() {
                 return Err(ParseError::InvalidValue("DEFLATE distance exceeds output buffer"));
             }
+            if length > max_len - output.len() {
+                return Err(OUTPUT_TOO_LONG);
+            }
 
             // Copy from back-reference
             let start = output.len() - distance;
@@ -473,4 +491,17 @@ mod tests {
         let result = inflate(&data).unwrap();
         assert!(result.is_empty());
     }
+
+    #[test]
+    fn inflate_limited_stops_at_the_limit() {
+        // 259 × 'a' from zlib: a few literals, then a distance-1 copy.
+        let data = [0x4b, 0x4c, 0x1c, 0xf1, 0x00, 0x00];
+        assert_eq!(inflate(&data).unwrap(), vec![b'a'; 259]);
+        assert_eq!(inflate_limited(&data, 259).unwrap().len(), 259);
+        assert!(inflate_limited(&data, 258).is_err());
+        assert!(inflate_limited(&data, 0).is_err());
+
+        let stored = [0x01, 0x05, 0x00, 0xFA, 0xFF, b'h', b'e', b'l', b'l', b'o'];
+        assert!(inflate_limited(&stored, 4).is_err());
+    }
 }
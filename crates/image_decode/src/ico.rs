@@ -0,0 +1,251 @@
+//! ICO (and CUR) container decoder.
+//!
+//! An icon file holds several images of the same picture at different
+//! sizes and depths. Each entry is either a complete PNG file or a
+//! headerless BMP ("DIB") whose height counts twice: the colour rows are
+//! followed by a 1-bit transparency mask. [`select_entry`] picks the entry
+//! to use for a given display size.
+
+use super::Image;
+use common::ParseError;
+
+/// One image listed in the icon directory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IconEntry {
+    /// Width in pixels (a 0 in the directory means 256).
+    pub width: u32,
+    pub height: u32,
+    /// Bits per pixel, when the directory states it (0 otherwise).
+    pub bit_count: u16,
+    offset: usize,
+    size: usize,
+}
+
+/// Read the directory of an ICO or CUR file.
+pub fn parse_directory(data: &[u8]) -> Result<Vec<IconEntry>, ParseError> {
+    if data.len() < 6 {
+        return Err(ParseError::UnexpectedEof);
+    }
+    let kind = le_u16(data, 2)?;
+    if le_u16(data, 0)? != 0 || (kind != 1 && kind != 2) {
+        return Err(ParseError::InvalidValue("ICO: bad header"));
+    }
+    let count = le_u16(data, 4)? as usize;
+    let mut entries = Vec::with_capacity(count);
+    for i in 0..count {
+        let at = 6 + i * 16;
+        let entry = data.get(at..at + 16).ok_or(ParseError::UnexpectedEof)?;
+        let dimension = |b: u8| if b == 0 { 256 } else { b as u32 };
+        let offset = le_u32(entry, 12)? as usize;
+        let size = le_u32(entry, 8)? as usize;
+        if offset.checked_add(size).is_none_or(|end| end > data.len()) {
+            continue;
+        }
+        entries.push(IconEntry {
+            width: dimension(entry[0]),
+            height: dimension(entry[1]),
+            // For cursors these two fields hold the hotspot instead.
+            bit_count: if kind == 1 { le_u16(entry, 6)? } else { 0 },
+            offset,
+            size,
+        });
+    }
+    if entries.is_empty() {
+        return Err(ParseError::InvalidValue("ICO: no images"));
+    }
+    Ok(entries)
+}
+
+/// The entry to draw at `size`×`size` pixels: the smallest one at least
+/// that big, or failing that the biggest. Deeper colour breaks ties.
+pub fn select_entry(entries: &[IconEntry], size: u32) -> Option<&IconEntry> {
+    let big_enough = entries
+        .iter()
+        .filter(|e| e.width.min(e.height) >= size)
+        .min_by_key(|e| (e.width.min(e.height), std::cmp::Reverse(e.bit_count)));
+    big_enough.or_else(|| entries.iter().max_by_key(|e| (e.width.min(e.height), e.bit_count)))
+}
+
+/// Decode the largest image of an icon file.
+pub fn decode_ico(data: &[u8]) -> Result<Image, ParseError> {
+    decode_ico_for_size(data, u32::MAX)
+}
+
+/// Decode the image of an icon file best suited to `size`×`size` pixels
+/// (see [`select_entry`]). It is not scaled.
+pub fn decode_ico_for_size(data: &[u8], size: u32) -> Result<Image, ParseError> {
+    let entries = parse_directory(data)?;
+    let entry = select_entry(&entries, size).ok_or(ParseError::InvalidValue("ICO: no images"))?;
+    decode_entry(data, entry)
+}
+
+/// Decode one entry of the icon file `data`.
+pub fn decode_entry(data: &[u8], entry: &IconEntry) -> Result<Image, ParseError> {
+    let bytes = &data[entry.offset..entry.offset + entry.size];
+    if bytes.starts_with(&[137, 80, 78, 71, 13, 10, 26, 10]) {
+        super::png::decode_png(bytes)
+    } else {
+        decode_dib(bytes)
+    }
+}
+
+/// Decode an icon's BMP image: a `BITMAPINFOHEADER`, an optional palette,
+/// the colour rows and the AND mask, all bottom-up.
+fn decode_dib(data: &[u8]) -> Result<Image, ParseError> {
+    let header_size = le_u32(data, 0)? as usize;
+    if header_size < 40 {
+        return Err(ParseError::InvalidValue("ICO: unsupported bitmap header"));
+    }
+    let width = le_u32(data, 4)?;
+    let height = (le_u32(data, 8)? as i32).unsigned_abs() / 2;
+    let bit_count = le_u16(data, 14)? as u32;
+    if le_u32(data, 16)? != 0 {
+        return Err(ParseError::InvalidValue("ICO: compressed bitmaps unsupported"));
+    }
+    if width == 0 || height == 0 || width > 1024 || height > 1024 {
+        return Err(ParseError::InvalidValue("ICO: bad bitmap size"));
+    }
+    if !matches!(bit_count, 1 | 4 | 8 | 24 | 32) {
+        return Err(ParseError::InvalidValue("ICO: unsupported bit depth"));
+    }
+
+    let palette_len = if bit_count <= 8 {
+        match le_u32(data, 32)? {
+            0 => 1 << bit_count,
+            n => n.min(256),
+        }
+    } else {
+        0
+    } as usize;
+    let palette_at = header_size;
+    let palette = data
+        .get(palette_at..palette_at + palette_len * 4)
+        .ok_or(ParseError::UnexpectedEof)?;
+
+    let stride = (width * bit_count).div_ceil(32) as usize * 4;
+    let pixels_at = palette_at + palette_len * 4;
+    let mask_stride = width.div_ceil(32) as usize * 4;
+    let mask_at = pixels_at + stride * height as usize;
+    let pixels = data.get(pixels_at..mask_at).ok_or(ParseError::UnexpectedEof)?;
+    // Some 32-bit icons leave the mask out, relying on the alpha channel.
+    let mask = data.get(mask_at..mask_at + mask_stride * height as usize);
+
+    let mut img = Image::new(width, height);
+    let mut any_alpha = false;
+    for row in 0..height as usize {
+        let y = height - 1 - row as u32;
+        let line = &pixels[row * stride..(row + 1) * stride];
+        for x in 0..width as usize {
+            let rgba = match bit_count {
+                32 => {
+                    let p = &line[x * 4..x * 4 + 4];
+                    any_alpha |= p[3] != 0;
+                    [p[2], p[1], p[0], p[3]]
+                }
+                24 => {
+                    let p = &line[x * 3..x * 3 + 3];
+                    [p[2], p[1], p[0], 255]
+                }
+                _ => {
+                    let bit = x * bit_count as usize;
+                    let shift = 8 - bit_count as usize - bit % 8;
+                    let index = (line[bit / 8] >> shift) as usize & ((1 << bit_count) - 1);
+                    match palette.get(index * 4..index * 4 + 4) {
+                        Some(c) => [c[2], c[1], c[0], 255],
+                        None => [0, 0, 0, 255],
+                    }
+                }
+            };
+            img.set_pixel(x as u32, y, rgba);
+        }
+    }
+
+    // 32-bit entries carry alpha; for the rest (and 32-bit ones whose
+    // alpha is all zero) a set mask bit means transparent.
+    if bit_count != 32 || !any_alpha {
+        for row in 0..height as usize {
+            let y = height - 1 - row as u32;
+            for x in 0..width {
+                let transparent = match mask {
+                    Some(mask) => mask[row * mask_stride + x as usize / 8] & (0x80 >> (x % 8)) != 0,
+                    None => false,
+                };
+                let mut rgba = img.get_pixel(x, y);
+                rgba[3] = if transparent { 0 } else { 255 };
+                img.set_pixel(x, y, rgba);
+            }
+        }
+    }
+    Ok(img)
+}
+
+fn le_u16(data: &[u8], off: usize) -> Result<u16, ParseError> {
+    let b = data.get(off..off + 2).ok_or(ParseError::UnexpectedEof)?;
+    Ok(u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn le_u32(data: &[u8], off: usize) -> Result<u32, ParseError> {
+    let b = data.get(off..off + 4).ok_or(ParseError::UnexpectedEof)?;
+    Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An icon file with the given `(width, bit_count, image)` entries.
+    fn ico(images: &[(u8, u16, Vec<u8>)]) -> Vec<u8> {
+        let mut out = vec![0, 0, 1, 0];
+        out.extend_from_slice(&(images.len() as u16).to_le_bytes());
+        let mut offset = 6 + 16 * images.len();
+        for (size, bits, image) in images {
+            out.extend_from_slice(&[*size, *size, 0, 0, 1, 0]);
+            out.extend_from_slice(&bits.to_le_bytes());
+            out.extend_from_slice(&(image.len() as u32).to_le_bytes());
+            out.extend_from_slice(&(offset as u32).to_le_bytes());
+            offset += image.len();
+        }
+        for (_, _, image) in images {
+            out.extend_from_slice(image);
+        }
+        out
+    }
+
+    /// A 2×2, 8-bit paletted DIB: red except a transparent top-left pixel.
+    fn paletted_dib() -> Vec<u8> {
+        let mut dib = Vec::new();
+        dib.extend_from_slice(&40u32.to_le_bytes());
+        dib.extend_from_slice(&2u32.to_le_bytes());
+        dib.extend_from_slice(&4u32.to_le_bytes()); // doubled height
+        dib.extend_from_slice(&1u16.to_le_bytes());
+        dib.extend_from_slice(&8u16.to_le_bytes());
+        dib.extend_from_slice(&[0; 16]);
+        dib.extend_from_slice(&2u32.to_le_bytes()); // colours used
+        dib.extend_from_slice(&[0; 4]);
+        dib.extend_from_slice(&[0, 0, 0, 0, 0, 0, 255, 0]); // black, red (BGRX)
+        dib.extend_from_slice(&[1, 1, 0, 0, 1, 1, 0, 0]); // rows, bottom-up
+        dib.extend_from_slice(&[0, 0, 0, 0, 0x80, 0, 0, 0]); // mask, bottom-up
+        dib
+    }
+
+    #[test]
+    fn decodes_paletted_bitmap_with_mask() {
+        let img = decode_ico(&ico(&[(2, 8, paletted_dib())])).unwrap();
+        assert_eq!((img.width, img.height), (2, 2));
+        assert_eq!(img.get_pixel(0, 0)[3], 0);
+        assert_eq!(img.get_pixel(1, 0), [255, 0, 0, 255]);
+        assert_eq!(img.get_pixel(0, 1), [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn selects_best_size() {
+        let data = ico(&[(16, 32, vec![0; 4]), (32, 8, vec![0; 4]), (32, 32, vec![0; 4]), (0, 32, vec![0; 4])]);
+        let entries = parse_directory(&data).unwrap();
+        assert_eq!(entries[3].width, 256);
+        let pick = |size| select_entry(&entries, size).map(|e| (e.width, e.bit_count));
+        assert_eq!(pick(16), Some((16, 32)));
+        assert_eq!(pick(20), Some((32, 32)));
+        assert_eq!(pick(512), Some((256, 32)));
+        assert!(parse_directory(b"\0\0\x03\0\0\0").is_err());
+    }
+}
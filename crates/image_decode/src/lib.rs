@@ -6,8 +6,10 @@
 //! - `deflate`: DEFLATE decompression (RFC 1951)
 //! - `png`: PNG decoder (chunk parsing, filters, RGBA8 output)
 //! - `jpeg`: Baseline JPEG decoder (Huffman, IDCT, YCbCr→RGB)
+//! - `ico`: ICO/CUR containers of PNG and BMP images, with size selection
 
 pub mod deflate;
+pub mod ico;
 pub mod png;
 pub mod jpeg;
 pub mod webp;
@@ -54,6 +56,40 @@ impl Image {
     pub fn is_empty(&self) -> bool {
         self.width == 0 || self.height == 0
     }
+
+    /// A copy scaled to `width × height`. Each output pixel averages the
+    /// source pixels it covers, weighted by alpha, so downscaled icons stay
+    /// smooth and transparent edges don't darken.
+    pub fn resized(&self, width: u32, height: u32) -> Image {
+        let mut out = Image::new(width, height);
+        if self.is_empty() || width == 0 || height == 0 {
+            return out;
+        }
+        for y in 0..height {
+            let y0 = (y as u64 * self.height as u64 / height as u64) as u32;
+            let y1 = (((y + 1) as u64 * self.height as u64).div_ceil(height as u64) as u32).max(y0 + 1);
+            for x in 0..width {
+                let x0 = (x as u64 * self.width as u64 / width as u64) as u32;
+                let x1 = (((x + 1) as u64 * self.width as u64).div_ceil(width as u64) as u32).max(x0 + 1);
+                let mut sum = [0u64; 4];
+                for sy in y0..y1.min(self.height) {
+                    for sx in x0..x1.min(self.width) {
+                        let [r, g, b, a] = self.get_pixel(sx, sy);
+                        let a = a as u64;
+                        sum[0] += r as u64 * a;
+                        sum[1] += g as u64 * a;
+                        sum[2] += b as u64 * a;
+                        sum[3] += a;
+                    }
+                }
+                let count = ((y1.min(self.height) - y0) * (x1.min(self.width) - x0)) as u64;
+                let channel = |i: usize| sum[i].checked_div(sum[3]).unwrap_or(0) as u8;
+                let rgba = [channel(0), channel(1), channel(2), (sum[3] / count) as u8];
+                out.set_pixel(x, y, rgba);
+            }
+        }
+        out
+    }
 }
 
 /// Detect image format from the first few bytes.
@@ -64,6 +100,7 @@ pub enum ImageFormat {
     WebP,
     Gif,
     Bmp,
+    Ico,
     Svg,
     Unknown,
 }
@@ -80,17 +117,53 @@ pub fn detect_format(data: &[u8]) -> ImageFormat {
         ImageFormat::Gif
     } else if data.len() >= 2 && data[0] == b'B' && data[1] == b'M' {
         ImageFormat::Bmp
+    } else if data.len() >= 6 && matches!(data[..4], [0, 0, 1 | 2, 0]) && data[4..6] != [0, 0] {
+        ImageFormat::Ico
+    } else if looks_like_svg(data) {
+        ImageFormat::Svg
     } else {
-        // SVG detection (XML/text-based)
-        let prefix = std::str::from_utf8(&data[..data.len().min(256)]).unwrap_or("");
-        let trimmed = prefix.trim_start();
-        if trimmed.starts_with("<svg")
-            || trimmed.starts_with("<?xml")
-            || trimmed.starts_with("<!DOCTYPE svg")
+        ImageFormat::Unknown
+    }
+}
+
+/// Sniff an SVG document: skip a byte order mark, whitespace, the XML
+/// declaration, processing instructions, comments and the doctype, then
+/// expect an `<svg` start tag (optionally prefixed, as in `<svg:svg`).
+///
+/// Works on bytes so that a multi-byte character in a leading comment can't
+/// make the check fail.
+fn looks_like_svg(data: &[u8]) -> bool {
+    let mut rest = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+    loop {
+        let start = rest.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(rest.len());
+        rest = &rest[start..];
+        let skip_past = |rest: &[u8], open: &[u8], close: &[u8]| -> Option<usize> {
+            if !rest.starts_with(open) {
+                return None;
+            }
+            let body = &rest[open.len()..];
+            let end = body.windows(close.len()).position(|w| w == close)?;
+            Some(open.len() + end + close.len())
+        };
+        if let Some(n) = skip_past(rest, b"<?", b"?>")
+            .or_else(|| skip_past(rest, b"<!--", b"-->"))
+            .or_else(|| skip_past(rest, b"<!DOCTYPE", b">"))
         {
-            return ImageFormat::Svg;
+            rest = &rest[n..];
+            continue;
         }
-        ImageFormat::Unknown
+        // A prolog cut off by a short read still says this is XML.
+        if rest.starts_with(b"<?xml") || rest.starts_with(b"<!--") {
+            return true;
+        }
+        let tag = rest.strip_prefix(b"<").unwrap_or(b"");
+        let name_end = tag
+            .iter()
+            .position(|b| b.is_ascii_whitespace() || *b == b'>' || *b == b'/')
+            .unwrap_or(tag.len());
+        let name = &tag[..name_end];
+        let local = name.rsplit(|b| *b == b':').next().unwrap_or(name);
+        return local == b"svg" && name_end > 0;
     }
 }
 
@@ -102,6 +175,7 @@ pub fn decode(data: &[u8]) -> Result<Image, common::ParseError> {
         ImageFormat::WebP => webp::decode_webp(data),
         ImageFormat::Gif => webp::decode_gif(data),
         ImageFormat::Bmp => webp::decode_bmp(data),
+        ImageFormat::Ico => ico::decode_ico(data),
         ImageFormat::Svg => decode_svg(data),
         ImageFormat::Unknown => Err(common::ParseError::InvalidValue("unknown image format")),
     }
@@ -111,6 +185,10 @@ pub fn decode(data: &[u8]) -> Result<Image, common::ParseError> {
 // SVG decoder (placeholder rendering with correct dimensions)
 // ─────────────────────────────────────────────────────────────────────────────
 
+// Real SVG rendering lives in the `svg` crate, which needs the HTML parser
+// and the framebuffer; callers check `detect_format` first and only fall
+// back to this box when they can't render the document.
+
 fn decode_svg(data: &[u8]) -> Result<Image, common::ParseError> {
     let text = std::str::from_utf8(data)
         .map_err(|_| common::ParseError::InvalidValue("SVG: invalid UTF-8"))?;
@@ -257,6 +335,25 @@ mod tests {
         assert_eq!(detect_format(&bmp_header), ImageFormat::Bmp);
     }
 
+    #[test]
+    fn detect_ico() {
+        assert_eq!(detect_format(&[0, 0, 1, 0, 1, 0]), ImageFormat::Ico);
+        assert_eq!(detect_format(&[0, 0, 2, 0, 3, 0]), ImageFormat::Ico);
+        assert_eq!(detect_format(&[0, 0, 1, 0, 0, 0]), ImageFormat::Unknown);
+    }
+
+    #[test]
+    fn resize_averages_with_alpha() {
+        let mut img = Image::new(2, 2);
+        img.set_pixel(0, 0, [255, 0, 0, 255]);
+        img.set_pixel(1, 0, [0, 0, 255, 255]);
+        let small = img.resized(1, 1);
+        assert_eq!(small.get_pixel(0, 0), [127, 0, 127, 127]);
+        let big = img.resized(4, 4);
+        assert_eq!(big.get_pixel(3, 0), [0, 0, 255, 255]);
+        assert_eq!(big.get_pixel(0, 3)[3], 0);
+    }
+
     #[test]
     fn detect_svg() {
         let svg = b"<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"100\" height=\"100\"></svg>";
@@ -269,6 +366,20 @@ mod tests {
         assert_eq!(detect_format(svg), ImageFormat::Svg);
     }
 
+    #[test]
+    fn detect_svg_after_bom_comment_and_doctype() {
+        let mut svg = b"\xEF\xBB\xBF<?xml version=\"1.0\"?>\n<!-- Generator: \xC3\xA9diteur -->".to_vec();
+        svg.extend_from_slice(&[b' '; 300]);
+        svg.extend_from_slice(b"<!DOCTYPE svg PUBLIC \"-//W3C//DTD SVG 1.1//EN\" \"x.dtd\">\n<svg:svg/>");
+        assert_eq!(detect_format(&svg), ImageFormat::Svg);
+    }
+
+    #[test]
+    fn detect_html_is_not_svg() {
+        assert_eq!(detect_format(b"<!DOCTYPE html><html><svg></svg></html>"), ImageFormat::Unknown);
+        assert_eq!(detect_format(b"<svgx>"), ImageFormat::Unknown);
+    }
+
     #[test]
     fn detect_unknown() {
         let garbage = [0x00, 0x01, 0x02, 0x03];
@@ -0,0 +1,755 @@
+//! # Web Crypto
+//!
+//! The `crypto` global: `getRandomValues` and `randomUUID`, both drawing on
+//! the engine-wide ChaCha20 DRBG in the `crypto` crate, and `crypto.subtle`
+//! with SHA-256 digests, HMAC, AES-GCM and HKDF. Subtle methods return
+//! promise ids in a [`PromiseRuntime`]; results settle immediately, so
+//! reactions still run as microtasks.
+
+use crate::promise::{PromiseRuntime, PromiseValue};
+use crypto::{AesGcm, HkdfSha256, ct_eq, hmac_sha256, sha256};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Errors
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A `DOMException` raised by the `crypto` methods.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CryptoError {
+    /// `TypeMismatchError`: the array is not an integer typed array.
+    TypeMismatch,
+    /// `QuotaExceededError`: more than [`MAX_RANDOM_BYTES`] requested.
+    QuotaExceeded,
+    /// `NotSupportedError`: unknown algorithm, hash or key format.
+    NotSupported,
+    /// `SyntaxError`: bad key usages.
+    Syntax,
+    /// `InvalidAccessError`: the key does not allow the operation.
+    InvalidAccess,
+    /// `DataError`: malformed key data.
+    Data,
+    /// `OperationError`: the operation itself failed (e.g. a bad tag).
+    Operation,
+}
+
+impl CryptoError {
+    /// The `DOMException` name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TypeMismatch => "TypeMismatchError",
+            Self::QuotaExceeded => "QuotaExceededError",
+            Self::NotSupported => "NotSupportedError",
+            Self::Syntax => "SyntaxError",
+            Self::InvalidAccess => "InvalidAccessError",
+            Self::Data => "DataError",
+            Self::Operation => "OperationError",
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// getRandomValues / randomUUID
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The largest `getRandomValues` request, in bytes.
+pub const MAX_RANDOM_BYTES: usize = 65536;
+
+/// Whether `getRandomValues` accepts a typed array of this kind: integer
+/// arrays only, not `Float32Array`/`Float64Array` or `DataView`.
+pub fn accepts_array_type(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "Int8Array"
+            | "Uint8Array"
+            | "Uint8ClampedArray"
+            | "Int16Array"
+            | "Uint16Array"
+            | "Int32Array"
+            | "Uint32Array"
+            | "BigInt64Array"
+            | "BigUint64Array"
+    )
+}
+
+/// `crypto.getRandomValues(array)`: fill the bytes backing an integer typed
+/// array of kind `type_name`.
+pub fn get_random_values(type_name: &str, bytes: &mut [u8]) -> Result<(), CryptoError> {
+    if !accepts_array_type(type_name) {
+        return Err(CryptoError::TypeMismatch);
+    }
+    if bytes.len() > MAX_RANDOM_BYTES {
+        return Err(CryptoError::QuotaExceeded);
+    }
+    crypto::fill_random(bytes);
+    Ok(())
+}
+
+/// `crypto.randomUUID()`: a version 4 UUID in lowercase hex.
+pub fn random_uuid() -> String {
+    let mut b: [u8; 16] = crypto::random_bytes();
+    b[6] = (b[6] & 0x0f) | 0x40; // version 4
+    b[8] = (b[8] & 0x3f) | 0x80; // variant 10
+    let hex: String = b.iter().map(|x| format!("{:02x}", x)).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// crypto.subtle: algorithms and keys
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// An algorithm dictionary as passed from script (`{name: "AES-GCM", iv}`).
+/// Only the members the named algorithm uses are read; `BufferSource`
+/// members hold the bytes of the `ArrayBuffer` or `Uint8Array`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AlgorithmParams {
+    pub name: String,
+    /// `hash` for HMAC and HKDF.
+    pub hash: Option<String>,
+    /// Key length in bits (`generateKey`, HMAC `importKey`).
+    pub length: Option<usize>,
+    pub iv: Option<Vec<u8>>,
+    pub additional_data: Option<Vec<u8>>,
+    /// AES-GCM tag length in bits; only 128 is supported.
+    pub tag_length: Option<usize>,
+    pub salt: Option<Vec<u8>>,
+    pub info: Option<Vec<u8>>,
+}
+
+impl AlgorithmParams {
+    /// An algorithm given by name alone, e.g. `"SHA-256"`.
+    pub fn named(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Self::default()
+        }
+    }
+}
+
+/// The algorithms `crypto.subtle` implements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyAlgorithm {
+    Hmac,
+    AesGcm,
+    Hkdf,
+}
+
+impl KeyAlgorithm {
+    /// Normalize an algorithm name (ASCII case-insensitive).
+    fn from_name(name: &str) -> Result<Self, CryptoError> {
+        match name.to_ascii_uppercase().as_str() {
+            "HMAC" => Ok(Self::Hmac),
+            "AES-GCM" => Ok(Self::AesGcm),
+            "HKDF" => Ok(Self::Hkdf),
+            _ => Err(CryptoError::NotSupported),
+        }
+    }
+
+    /// The registered algorithm name, as reported by `key.algorithm.name`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Hmac => "HMAC",
+            Self::AesGcm => "AES-GCM",
+            Self::Hkdf => "HKDF",
+        }
+    }
+
+    fn allowed_usages(self) -> &'static [&'static str] {
+        match self {
+            Self::Hmac => &["sign", "verify"],
+            Self::AesGcm => &["encrypt", "decrypt", "wrapKey", "unwrapKey"],
+            Self::Hkdf => &["deriveBits", "deriveKey"],
+        }
+    }
+}
+
+/// A `CryptoKey`. All supported algorithms use secret keys.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CryptoKey {
+    pub algorithm: KeyAlgorithm,
+    pub extractable: bool,
+    pub usages: Vec<String>,
+    material: Vec<u8>,
+}
+
+impl CryptoKey {
+    /// `key.type`.
+    pub fn key_type(&self) -> &'static str {
+        "secret"
+    }
+
+    /// The key length in bits, as reported by `key.algorithm.length`.
+    pub fn length_bits(&self) -> usize {
+        self.material.len() * 8
+    }
+}
+
+/// Only SHA-256 is available in the `crypto` crate.
+fn check_hash(hash: Option<&str>) -> Result<(), CryptoError> {
+    match hash {
+        Some(h) if h.eq_ignore_ascii_case("SHA-256") => Ok(()),
+        Some(_) => Err(CryptoError::NotSupported),
+        None => Err(CryptoError::Syntax),
+    }
+}
+
+fn check_aes_length(len: usize) -> Result<(), CryptoError> {
+    match len {
+        16 | 32 => Ok(()),
+        // AES-192 is valid WebCrypto but not implemented.
+        24 => Err(CryptoError::NotSupported),
+        _ => Err(CryptoError::Data),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// crypto.subtle: operations
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The `crypto.subtle` object. Owns the keys that script holds as
+/// `CryptoKey` handles ([`PromiseValue::CryptoKey`]).
+#[derive(Debug, Default)]
+pub struct SubtleCrypto {
+    keys: Vec<CryptoKey>,
+}
+
+impl SubtleCrypto {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Look up a key handle.
+    pub fn key(&self, handle: usize) -> Option<&CryptoKey> {
+        self.keys.get(handle)
+    }
+
+    /// Create a promise settled with the outcome of `op`.
+    fn settle(rt: &mut PromiseRuntime, result: Result<PromiseValue, CryptoError>) -> usize {
+        let id = rt.create_promise();
+        match result {
+            Ok(value) => rt.resolve(id, value),
+            Err(e) => rt.reject(id, PromiseValue::Str(e.name().to_string())),
+        }
+        id
+    }
+
+    fn add_key(&mut self, key: CryptoKey) -> PromiseValue {
+        self.keys.push(key);
+        PromiseValue::CryptoKey(self.keys.len() - 1)
+    }
+
+    /// Fetch a key and check it is for `algorithm` and allows `usage`.
+    fn usable_key(
+        &self,
+        handle: usize,
+        algorithm: &str,
+        usage: &str,
+    ) -> Result<&CryptoKey, CryptoError> {
+        let algorithm = KeyAlgorithm::from_name(algorithm)?;
+        let key = self.keys.get(handle).ok_or(CryptoError::InvalidAccess)?;
+        if key.algorithm != algorithm || !key.usages.iter().any(|u| u == usage) {
+            return Err(CryptoError::InvalidAccess);
+        }
+        Ok(key)
+    }
+
+    /// `digest(algorithm, data)`.
+    pub fn digest(&self, rt: &mut PromiseRuntime, algorithm: &str, data: &[u8]) -> usize {
+        let result =
+            check_hash(Some(algorithm)).map(|()| PromiseValue::ArrayBuffer(sha256(data).to_vec()));
+        Self::settle(rt, result)
+    }
+
+    /// `importKey("raw", keyData, algorithm, extractable, usages)`.
+    pub fn import_key(
+        &mut self,
+        rt: &mut PromiseRuntime,
+        format: &str,
+        key_data: &[u8],
+        algorithm: &AlgorithmParams,
+        extractable: bool,
+        usages: &[&str],
+    ) -> usize {
+        let result = self.import_raw(format, key_data, algorithm, extractable, usages);
+        Self::settle(rt, result)
+    }
+
+    fn import_raw(
+        &mut self,
+        format: &str,
+        key_data: &[u8],
+        params: &AlgorithmParams,
+        extractable: bool,
+        usages: &[&str],
+    ) -> Result<PromiseValue, CryptoError> {
+        let algorithm = KeyAlgorithm::from_name(&params.name)?;
+        if format != "raw" {
+            return Err(CryptoError::NotSupported);
+        }
+        if usages.is_empty()
+            || usages
+                .iter()
+                .any(|u| !algorithm.allowed_usages().contains(u))
+        {
+            return Err(CryptoError::Syntax);
+        }
+        match algorithm {
+            KeyAlgorithm::Hmac => {
+                check_hash(params.hash.as_deref())?;
+                if key_data.is_empty() || params.length.is_some_and(|l| l != key_data.len() * 8) {
+                    return Err(CryptoError::Data);
+                }
+            }
+            KeyAlgorithm::AesGcm => check_aes_length(key_data.len())?,
+            KeyAlgorithm::Hkdf => {
+                if extractable {
+                    return Err(CryptoError::Syntax);
+                }
+            }
+        }
+        Ok(self.add_key(CryptoKey {
+            algorithm,
+            extractable,
+            usages: usages.iter().map(|u| u.to_string()).collect(),
+            material: key_data.to_vec(),
+        }))
+    }
+
+    /// `exportKey("raw", key)`.
+    pub fn export_key(&self, rt: &mut PromiseRuntime, format: &str, key: usize) -> usize {
+        let result = if format != "raw" {
+            Err(CryptoError::NotSupported)
+        } else {
+            match self.keys.get(key) {
+                Some(k) if k.extractable => Ok(PromiseValue::ArrayBuffer(k.material.clone())),
+                _ => Err(CryptoError::InvalidAccess),
+            }
+        };
+        Self::settle(rt, result)
+    }
+
+    /// `generateKey(algorithm, extractable, usages)` for HMAC and AES-GCM.
+    pub fn generate_key(
+        &mut self,
+        rt: &mut PromiseRuntime,
+        algorithm: &AlgorithmParams,
+        extractable: bool,
+        usages: &[&str],
+    ) -> usize {
+        let result = self.generate(algorithm, extractable, usages);
+        Self::settle(rt, result)
+    }
+
+    fn generate(
+        &mut self,
+        algorithm: &AlgorithmParams,
+        extractable: bool,
+        usages: &[&str],
+    ) -> Result<PromiseValue, CryptoError> {
+        let bits = match KeyAlgorithm::from_name(&algorithm.name)? {
+            KeyAlgorithm::Hmac => {
+                check_hash(algorithm.hash.as_deref())?;
+                // Default length is the hash's block size.
+                let bits = algorithm.length.unwrap_or(512);
+                if bits == 0 || !bits.is_multiple_of(8) {
+                    return Err(CryptoError::Operation);
+                }
+                bits
+            }
+            KeyAlgorithm::AesGcm => match algorithm.length {
+                Some(bits @ (128 | 256)) => bits,
+                Some(192) => return Err(CryptoError::NotSupported),
+                _ => return Err(CryptoError::Operation),
+            },
+            KeyAlgorithm::Hkdf => return Err(CryptoError::NotSupported),
+        };
+        self.import_generated(algorithm, bits / 8, extractable, usages)
+    }
+
+    fn import_generated(
+        &mut self,
+        algorithm: &AlgorithmParams,
+        len: usize,
+        extractable: bool,
+        usages: &[&str],
+    ) -> Result<PromiseValue, CryptoError> {
+        let mut material = vec![0u8; len];
+        crypto::fill_random(&mut material);
+        let params = AlgorithmParams {
+            length: None,
+            ..algorithm.clone()
+        };
+        self.import_raw("raw", &material, &params, extractable, usages)
+    }
+
+    /// `sign({name: "HMAC"}, key, data)`.
+    pub fn sign(&self, rt: &mut PromiseRuntime, algorithm: &str, key: usize, data: &[u8]) -> usize {
+        let result = self
+            .usable_key(key, algorithm, "sign")
+            .map(|k| PromiseValue::ArrayBuffer(hmac_sha256(&k.material, data).to_vec()));
+        Self::settle(rt, result)
+    }
+
+    /// `verify({name: "HMAC"}, key, signature, data)`; resolves to a boolean.
+    pub fn verify(
+        &self,
+        rt: &mut PromiseRuntime,
+        algorithm: &str,
+        key: usize,
+        signature: &[u8],
+        data: &[u8],
+    ) -> usize {
+        let result = self
+            .usable_key(key, algorithm, "verify")
+            .map(|k| PromiseValue::Bool(ct_eq(&hmac_sha256(&k.material, data), signature)));
+        Self::settle(rt, result)
+    }
+
+    /// `encrypt({name: "AES-GCM", iv, additionalData}, key, data)`;
+    /// resolves to the ciphertext followed by the 16-byte tag.
+    pub fn encrypt(
+        &self,
+        rt: &mut PromiseRuntime,
+        params: &AlgorithmParams,
+        key: usize,
+        data: &[u8],
+    ) -> usize {
+        let result = self
+            .gcm_setup(params, key, "encrypt")
+            .map(|(gcm, iv, aad)| {
+                let (mut ciphertext, tag) = gcm.seal(&iv, aad, data);
+                ciphertext.extend_from_slice(&tag);
+                PromiseValue::ArrayBuffer(ciphertext)
+            });
+        Self::settle(rt, result)
+    }
+
+    /// `decrypt({name: "AES-GCM", iv, additionalData}, key, data)`; rejects
+    /// with `OperationError` if the tag does not verify.
+    pub fn decrypt(
+        &self,
+        rt: &mut PromiseRuntime,
+        params: &AlgorithmParams,
+        key: usize,
+        data: &[u8],
+    ) -> usize {
+        let result = self
+            .gcm_setup(params, key, "decrypt")
+            .and_then(|(gcm, iv, aad)| {
+                if data.len() < 16 {
+                    return Err(CryptoError::Operation);
+                }
+                let (ciphertext, tag) = data.split_at(data.len() - 16);
+                let tag: &[u8; 16] = tag.try_into().unwrap();
+                gcm.open(&iv, aad, ciphertext, tag)
+                    .map(PromiseValue::ArrayBuffer)
+                    .map_err(|()| CryptoError::Operation)
+            });
+        Self::settle(rt, result)
+    }
+
+    fn gcm_setup<'p>(
+        &self,
+        params: &'p AlgorithmParams,
+        key: usize,
+        usage: &str,
+    ) -> Result<(AesGcm, [u8; 12], &'p [u8]), CryptoError> {
+        let key = self.usable_key(key, &params.name, usage)?;
+        match params.tag_length {
+            None | Some(128) => {}
+            Some(32 | 64 | 96 | 104 | 112 | 120) => return Err(CryptoError::NotSupported),
+            Some(_) => return Err(CryptoError::Operation),
+        }
+        // Only the recommended 96-bit IV is implemented.
+        let iv: [u8; 12] = match params.iv.as_deref() {
+            Some(iv) if iv.len() == 12 => iv.try_into().unwrap(),
+            Some(iv) if !iv.is_empty() => return Err(CryptoError::NotSupported),
+            _ => return Err(CryptoError::Operation),
+        };
+        let aad = params.additional_data.as_deref().unwrap_or(&[]);
+        Ok((AesGcm::new(&key.material), iv, aad))
+    }
+
+    /// `deriveBits({name: "HKDF", hash, salt, info}, key, length)`.
+    pub fn derive_bits(
+        &self,
+        rt: &mut PromiseRuntime,
+        params: &AlgorithmParams,
+        key: usize,
+        length: usize,
+    ) -> usize {
+        let result = self
+            .usable_key(key, &params.name, "deriveBits")
+            .and_then(|k| {
+                check_hash(params.hash.as_deref())?;
+                // HKDF-Expand produces at most 255 hash-lengths of output.
+                if length == 0 || !length.is_multiple_of(8) || length / 8 > 255 * 32 {
+                    return Err(CryptoError::Operation);
+                }
+                let salt = params.salt.as_deref().unwrap_or(&[]);
+                let info = params.info.as_deref().unwrap_or(&[]);
+                let okm = HkdfSha256::extract(salt, &k.material).expand(info, length / 8);
+                Ok(PromiseValue::ArrayBuffer(okm))
+            });
+        Self::settle(rt, result)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::promise::PromiseState;
+
+    #[test]
+    fn test_get_random_values() {
+        let mut a = [0u8; 64];
+        let mut b = [0u8; 64];
+        get_random_values("Uint32Array", &mut a).unwrap();
+        get_random_values("Uint8Array", &mut b).unwrap();
+        assert_ne!(a, b);
+
+        let mut f = [0u8; 8];
+        assert_eq!(
+            get_random_values("Float64Array", &mut f),
+            Err(CryptoError::TypeMismatch)
+        );
+        let mut big = vec![0u8; MAX_RANDOM_BYTES + 1];
+        let err = get_random_values("Uint8Array", &mut big).unwrap_err();
+        assert_eq!(err.name(), "QuotaExceededError");
+    }
+
+    #[test]
+    fn test_random_uuid() {
+        let uuid = random_uuid();
+        assert_eq!(uuid.len(), 36);
+        assert_eq!(&uuid[14..15], "4");
+        assert!(matches!(&uuid[19..20], "8" | "9" | "a" | "b"));
+        assert_ne!(uuid, random_uuid());
+    }
+
+    fn settled(rt: &PromiseRuntime, id: usize) -> PromiseValue {
+        match rt.state(id) {
+            PromiseState::Fulfilled(v) => v.clone(),
+            other => panic!("promise not fulfilled: {:?}", other),
+        }
+    }
+
+    fn rejection(rt: &PromiseRuntime, id: usize) -> String {
+        match rt.state(id) {
+            PromiseState::Rejected(PromiseValue::Str(name)) => name.clone(),
+            other => panic!("promise not rejected: {:?}", other),
+        }
+    }
+
+    fn bytes(value: PromiseValue) -> Vec<u8> {
+        match value {
+            PromiseValue::ArrayBuffer(b) => b,
+            other => panic!("expected an ArrayBuffer, got {:?}", other),
+        }
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    fn import(
+        subtle: &mut SubtleCrypto,
+        rt: &mut PromiseRuntime,
+        data: &[u8],
+        alg: AlgorithmParams,
+        usages: &[&str],
+    ) -> usize {
+        let p = subtle.import_key(rt, "raw", data, &alg, true, usages);
+        match settled(rt, p) {
+            PromiseValue::CryptoKey(k) => k,
+            other => panic!("expected a key, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_subtle_digest() {
+        let mut rt = PromiseRuntime::new();
+        let subtle = SubtleCrypto::new();
+        let p = subtle.digest(&mut rt, "SHA-256", b"abc");
+        assert_eq!(
+            hex(&bytes(settled(&rt, p))),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        let p = subtle.digest(&mut rt, "SHA-1", b"abc");
+        assert_eq!(rejection(&rt, p), "NotSupportedError");
+    }
+
+    #[test]
+    fn test_subtle_hmac_sign_verify() {
+        let mut rt = PromiseRuntime::new();
+        let mut subtle = SubtleCrypto::new();
+        let alg = AlgorithmParams {
+            hash: Some("SHA-256".into()),
+            ..AlgorithmParams::named("HMAC")
+        };
+        // RFC 4231 test case 2.
+        let key = import(&mut subtle, &mut rt, b"Jefe", alg, &["sign", "verify"]);
+        let data = b"what do ya want for nothing?";
+        let p = subtle.sign(&mut rt, "HMAC", key, data);
+        let sig = bytes(settled(&rt, p));
+        assert_eq!(
+            hex(&sig),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+
+        let p = subtle.verify(&mut rt, "HMAC", key, &sig, data);
+        assert_eq!(settled(&rt, p), PromiseValue::Bool(true));
+        let p = subtle.verify(&mut rt, "HMAC", key, &sig, b"tampered");
+        assert_eq!(settled(&rt, p), PromiseValue::Bool(false));
+
+        // Key usages are enforced.
+        let alg = AlgorithmParams {
+            hash: Some("SHA-256".into()),
+            ..AlgorithmParams::named("hmac")
+        };
+        let verify_only = import(&mut subtle, &mut rt, b"k", alg, &["verify"]);
+        let p = subtle.sign(&mut rt, "HMAC", verify_only, data);
+        assert_eq!(rejection(&rt, p), "InvalidAccessError");
+    }
+
+    #[test]
+    fn test_subtle_aes_gcm_round_trip() {
+        let mut rt = PromiseRuntime::new();
+        let mut subtle = SubtleCrypto::new();
+        let key = import(
+            &mut subtle,
+            &mut rt,
+            &[0u8; 16],
+            AlgorithmParams::named("AES-GCM"),
+            &["encrypt", "decrypt"],
+        );
+        let params = AlgorithmParams {
+            iv: Some(vec![0u8; 12]),
+            ..AlgorithmParams::named("AES-GCM")
+        };
+        // GCM spec test case 2: zero key, zero IV, one zero block.
+        let p = subtle.encrypt(&mut rt, &params, key, &[0u8; 16]);
+        let ct = bytes(settled(&rt, p));
+        assert_eq!(
+            hex(&ct),
+            "0388dace60b6a392f328c2b971b2fe78ab6e47d42cec13bdf53a67b21257bddf"
+        );
+
+        let p = subtle.decrypt(&mut rt, &params, key, &ct);
+
+        let pt = bytes(settled(&rt, p));
+        assert_eq!(pt, vec![0u8; 16]);
+
+        let mut bad = ct.clone();
+        bad[0] ^= 1;
+        let p = subtle.decrypt(&mut rt, &params, key, &bad);
+        assert_eq!(rejection(&rt, p), "OperationError");
+    }
+
+    #[test]
+    fn test_subtle_import_export_and_generate() {
+        let mut rt = PromiseRuntime::new();
+        let mut subtle = SubtleCrypto::new();
+        let p = subtle.import_key(
+            &mut rt,
+            "raw",
+            &[1; 15],
+            &AlgorithmParams::named("AES-GCM"),
+            true,
+            &["encrypt"],
+        );
+        assert_eq!(rejection(&rt, p), "DataError");
+        let p = subtle.import_key(
+            &mut rt,
+            "raw",
+            &[1; 16],
+            &AlgorithmParams::named("AES-GCM"),
+            true,
+            &["sign"],
+        );
+        assert_eq!(rejection(&rt, p), "SyntaxError");
+        let p = subtle.import_key(
+            &mut rt,
+            "jwk",
+            &[1; 16],
+            &AlgorithmParams::named("AES-GCM"),
+            true,
+            &["encrypt"],
+        );
+        assert_eq!(rejection(&rt, p), "NotSupportedError");
+
+        let key = import(
+            &mut subtle,
+            &mut rt,
+            &[9; 32],
+            AlgorithmParams::named("AES-GCM"),
+            &["encrypt"],
+        );
+        let p = subtle.export_key(&mut rt, "raw", key);
+        assert_eq!(bytes(settled(&rt, p)), vec![9; 32]);
+        assert_eq!(subtle.key(key).unwrap().length_bits(), 256);
+
+        let alg = AlgorithmParams {
+            length: Some(256),
+            ..AlgorithmParams::named("AES-GCM")
+        };
+        let p = subtle.generate_key(&mut rt, &alg, false, &["encrypt", "decrypt"]);
+        let PromiseValue::CryptoKey(generated) = settled(&rt, p) else {
+            panic!()
+        };
+        let p = subtle.export_key(&mut rt, "raw", generated);
+        assert_eq!(rejection(&rt, p), "InvalidAccessError");
+    }
+
+    #[test]
+    fn test_subtle_hkdf_derive_bits() {
+        let mut rt = PromiseRuntime::new();
+        let mut subtle = SubtleCrypto::new();
+        // RFC 5869 test case 1.
+        let p = subtle.import_key(
+            &mut rt,
+            "raw",
+            &[0x0b; 22],
+            &AlgorithmParams::named("HKDF"),
+            false,
+            &["deriveBits"],
+        );
+        let PromiseValue::CryptoKey(key) = settled(&rt, p) else {
+            panic!()
+        };
+        let params = AlgorithmParams {
+            hash: Some("SHA-256".into()),
+            salt: Some((0x00..=0x0c).collect()),
+            info: Some((0xf0..=0xf9).collect()),
+            ..AlgorithmParams::named("HKDF")
+        };
+        let p = subtle.derive_bits(&mut rt, &params, key, 42 * 8);
+        let okm = bytes(settled(&rt, p));
+        assert_eq!(
+            hex(&okm),
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+        );
+    }
+
+    #[test]
+    fn test_subtle_results_reach_reactions() {
+        let mut rt = PromiseRuntime::new();
+        let subtle = SubtleCrypto::new();
+        let p = subtle.digest(&mut rt, "sha-256", b"");
+        let cb = rt.register_callback("onDigest".into());
+        rt.then(p, Some(cb), None);
+        let invoked = rt.drain_microtasks();
+        assert_eq!(invoked.len(), 1);
+        assert!(matches!(&invoked[0].1, PromiseValue::ArrayBuffer(b) if b.len() == 32));
+    }
+}
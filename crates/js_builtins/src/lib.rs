@@ -1,13 +1,16 @@
 //! # JS Builtins Crate
 //!
 //! JavaScript built-in objects and functions for the browser engine.
-//! Provides Math, console, parseInt/parseFloat, isNaN/isFinite, and JSON.
+//! Provides Math, console, parseInt/parseFloat, isNaN/isFinite, JSON,
+//! URL/URLSearchParams, and `crypto` (random values and `crypto.subtle`).
 //! **Zero external dependencies.**
 
 #![forbid(unsafe_code)]
 
 pub mod canvas;
+pub mod crypto;
 pub mod promise;
+pub mod url;
 
 use std::collections::HashMap;
 
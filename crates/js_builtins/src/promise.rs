@@ -20,6 +20,10 @@ pub enum PromiseValue {
     Undefined,
     /// Reference to another promise by index.
     PromiseRef(usize),
+    /// An `ArrayBuffer` (e.g. a `crypto.subtle` result).
+    ArrayBuffer(Vec<u8>),
+    /// A `CryptoKey`, by index into its `SubtleCrypto` key store.
+    CryptoKey(usize),
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -0,0 +1,307 @@
+//! # URL and URLSearchParams
+//!
+//! Script-facing models of the `URL` and `URLSearchParams` interfaces,
+//! backed by `url_parser`. A `URL`'s search params stay in sync with its
+//! query in both directions.
+
+use url_parser::{Url, parse_query_string, serialize_query_string};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// URLSearchParams
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// An ordered list of name-value pairs (`URLSearchParams`).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UrlSearchParams {
+    list: Vec<(String, String)>,
+}
+
+impl UrlSearchParams {
+    /// `new URLSearchParams(init)` with a string; a leading `?` is ignored.
+    pub fn new(init: &str) -> Self {
+        let init = init.strip_prefix('?').unwrap_or(init);
+        Self {
+            list: parse_query_string(init),
+        }
+    }
+
+    /// `new URLSearchParams(init)` with a sequence of pairs.
+    pub fn from_pairs<K: Into<String>, V: Into<String>>(
+        pairs: impl IntoIterator<Item = (K, V)>,
+    ) -> Self {
+        Self {
+            list: pairs
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        }
+    }
+
+    /// `size`: the number of pairs.
+    pub fn size(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn append(&mut self, name: &str, value: &str) {
+        self.list.push((name.to_string(), value.to_string()));
+    }
+
+    /// Remove all pairs named `name`, or only those also matching `value`.
+    pub fn delete(&mut self, name: &str, value: Option<&str>) {
+        self.list
+            .retain(|(n, v)| n != name || value.is_some_and(|value| v != value));
+    }
+
+    /// The value of the first pair named `name`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.list
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn get_all(&self, name: &str) -> Vec<&str> {
+        self.list
+            .iter()
+            .filter(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+            .collect()
+    }
+
+    pub fn has(&self, name: &str, value: Option<&str>) -> bool {
+        self.list
+            .iter()
+            .any(|(n, v)| n == name && value.is_none_or(|value| v == value))
+    }
+
+    /// Replace the first pair named `name` and drop the others, or append.
+    pub fn set(&mut self, name: &str, value: &str) {
+        match self.list.iter().position(|(n, _)| n == name) {
+            Some(i) => {
+                self.list[i].1 = value.to_string();
+                let mut index = 0;
+                self.list.retain(|(n, _)| {
+                    index += 1;
+                    index - 1 <= i || n != name
+                });
+            }
+            None => self.append(name, value),
+        }
+    }
+
+    /// Stable sort by name, comparing UTF-16 code units like JavaScript.
+    pub fn sort(&mut self) {
+        self.list
+            .sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+    }
+
+    pub fn entries(&self) -> &[(String, String)] {
+        &self.list
+    }
+}
+
+impl std::fmt::Display for UrlSearchParams {
+    /// `toString()`: the `application/x-www-form-urlencoded` serialization.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&serialize_query_string(&self.list))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// URL
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A `URL` object.
+#[derive(Clone, Debug)]
+pub struct JsUrl {
+    url: Url,
+    search_params: UrlSearchParams,
+}
+
+impl JsUrl {
+    /// `new URL(url, base)`; the error becomes a `TypeError`.
+    pub fn new(input: &str, base: Option<&str>) -> Result<Self, String> {
+        let base = base
+            .map(Url::parse)
+            .transpose()
+            .map_err(|e| format!("Invalid base URL: {e}"))?;
+        let url = Url::parse_with_base(input, base.as_ref())
+            .map_err(|e| format!("Invalid URL '{input}': {e}"))?;
+        Ok(Self::from_url(url))
+    }
+
+    /// `URL.canParse(url, base)`.
+    pub fn can_parse(input: &str, base: Option<&str>) -> bool {
+        Self::new(input, base).is_ok()
+    }
+
+    pub fn from_url(url: Url) -> Self {
+        let search_params = UrlSearchParams::new(url.query.as_deref().unwrap_or(""));
+        Self { url, search_params }
+    }
+
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    pub fn href(&self) -> String {
+        self.url.href()
+    }
+
+    pub fn origin(&self) -> String {
+        self.url.origin()
+    }
+
+    pub fn protocol(&self) -> String {
+        self.url.protocol()
+    }
+
+    pub fn username(&self) -> &str {
+        &self.url.username
+    }
+
+    pub fn password(&self) -> &str {
+        &self.url.password
+    }
+
+    pub fn host(&self) -> String {
+        self.url.host_with_port()
+    }
+
+    pub fn hostname(&self) -> &str {
+        &self.url.host
+    }
+
+    pub fn port(&self) -> String {
+        self.url.port.map(|p| p.to_string()).unwrap_or_default()
+    }
+
+    pub fn pathname(&self) -> &str {
+        &self.url.path
+    }
+
+    pub fn search(&self) -> String {
+        self.url.search()
+    }
+
+    pub fn hash(&self) -> String {
+        self.url.hash()
+    }
+
+    /// The `searchParams` object.
+    pub fn search_params(&self) -> &UrlSearchParams {
+        &self.search_params
+    }
+
+    /// Mutate `searchParams` and write the result back to the query; an
+    /// empty list removes the query.
+    pub fn update_search_params(&mut self, f: impl FnOnce(&mut UrlSearchParams)) {
+        f(&mut self.search_params);
+        let query = self.search_params.to_string();
+        self.url.query = (!query.is_empty()).then_some(query);
+    }
+
+    /// The `href` setter throws a `TypeError` on invalid input.
+    pub fn set_href(&mut self, value: &str) -> Result<(), String> {
+        let url = Url::parse(value).map_err(|e| format!("Invalid URL '{value}': {e}"))?;
+        *self = Self::from_url(url);
+        Ok(())
+    }
+
+    pub fn set_protocol(&mut self, value: &str) {
+        self.url.set_protocol(value);
+    }
+
+    pub fn set_username(&mut self, value: &str) {
+        self.url.set_username(value);
+    }
+
+    pub fn set_password(&mut self, value: &str) {
+        self.url.set_password(value);
+    }
+
+    pub fn set_host(&mut self, value: &str) {
+        self.url.set_host(value);
+    }
+
+    pub fn set_hostname(&mut self, value: &str) {
+        self.url.set_hostname(value);
+    }
+
+    pub fn set_port(&mut self, value: &str) {
+        self.url.set_port(value);
+    }
+
+    pub fn set_pathname(&mut self, value: &str) {
+        self.url.set_pathname(value);
+    }
+
+    pub fn set_search(&mut self, value: &str) {
+        self.url.set_search(value);
+        self.search_params = UrlSearchParams::new(self.url.query.as_deref().unwrap_or(""));
+    }
+
+    pub fn set_hash(&mut self, value: &str) {
+        self.url.set_hash(value);
+    }
+}
+
+impl std::fmt::Display for JsUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.url.fmt(f)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_params_methods() {
+        let mut params = UrlSearchParams::new("?b=2&a=1&b=3");
+        assert_eq!(params.get("b"), Some("2"));
+        assert_eq!(params.get_all("b"), vec!["2", "3"]);
+        assert!(params.has("a", Some("1")));
+        assert!(!params.has("a", Some("2")));
+
+        params.set("b", "x y");
+        assert_eq!(params.to_string(), "b=x+y&a=1");
+        params.append("c", "&");
+        params.sort();
+        assert_eq!(params.to_string(), "a=1&b=x+y&c=%26");
+        params.delete("a", None);
+        assert_eq!(params.size(), 2);
+    }
+
+    #[test]
+    fn test_url_accessors() {
+        let url = JsUrl::new("../x?q=1#h", Some("https://user@example.com:8080/a/b")).unwrap();
+        assert_eq!(url.href(), "https://user@example.com:8080/x?q=1#h");
+        assert_eq!(url.host(), "example.com:8080");
+        assert_eq!(url.port(), "8080");
+        assert_eq!(url.search(), "?q=1");
+        assert_eq!(url.search_params().get("q"), Some("1"));
+        assert!(JsUrl::new("not a url", None).is_err());
+        assert!(!JsUrl::can_parse("http://[::1", None));
+    }
+
+    #[test]
+    fn test_search_params_sync() {
+        let mut url = JsUrl::new("http://example.com/?a=1", None).unwrap();
+        url.update_search_params(|p| p.append("b", "2 3"));
+        assert_eq!(url.href(), "http://example.com/?a=1&b=2+3");
+
+        url.set_search("?c=4");
+        assert_eq!(
+            url.search_params().entries(),
+            &[("c".to_string(), "4".to_string())]
+        );
+
+        url.update_search_params(|p| p.delete("c", None));
+        assert_eq!(url.href(), "http://example.com/");
+    }
+}
@@ -70,6 +70,9 @@ pub enum OpCode {
     CallMethod { dst: u16, obj: u16, name: u32, argc: u16, argv: u16 },
     New { dst: u16, callee: u16, argc: u16, argv: u16 },
     Return { src: u16 },
+    /// `await src`: the VM hands the value to the embedder's `__await`
+    /// native, if any, to unwrap a promise.
+    Await { dst: u16, src: u16 },
 
     // Exception handling
     Throw { src: u16 },
@@ -798,6 +801,35 @@ impl Compiler {
             }
 
             Expr::Call { callee, arguments } => {
+                // `obj.name(...)` passes `obj` along as the receiver.
+                if let Expr::Member { object, property, computed: false } = callee.as_ref()
+                    && let Expr::Ident(prop_name) = property.as_ref()
+                {
+                    let obj = self.compile_expr(object)?;
+                    let name = self.add_string_constant(prop_name);
+                    let argv = self.next_reg;
+                    for arg in arguments {
+                        let _r = self.compile_expr(arg)?;
+                    }
+                    let dst = self.alloc_reg();
+                    self.emit(OpCode::CallMethod {
+                        dst,
+                        obj,
+                        name,
+                        argc: arguments.len() as u16,
+                        argv,
+                    });
+                    for _ in 0..arguments.len() {
+                        self.free_reg();
+                    }
+                    self.free_reg(); // dst
+                    self.free_reg(); // obj
+                    let final_dst = self.alloc_reg();
+                    if final_dst != dst {
+                        self.emit(OpCode::Move { dst: final_dst, src: dst });
+                    }
+                    return Ok(final_dst);
+                }
                 let callee_reg = self.compile_expr(callee)?;
                 let argv = self.next_reg;
                 for arg in arguments {
@@ -849,20 +881,20 @@ impl Compiler {
             }
 
             Expr::Member { object, property, computed } => {
+                // The value replaces the object in its register, so the
+                // result stays on top of the register stack.
                 let obj = self.compile_expr(object)?;
-                let dst = self.alloc_reg();
                 if *computed {
                     let key = self.compile_expr(property)?;
-                    self.emit(OpCode::GetElem { dst, obj, key });
+                    self.emit(OpCode::GetElem { dst: obj, obj, key });
                     self.free_reg(); // key
                 } else {
                     if let Expr::Ident(prop_name) = property.as_ref() {
                         let name_idx = self.add_string_constant(prop_name);
-                        self.emit(OpCode::GetProp { dst, obj, name: name_idx });
+                        self.emit(OpCode::GetProp { dst: obj, obj, name: name_idx });
                     }
                 }
-                self.free_reg(); // obj
-                Ok(dst)
+                Ok(obj)
             }
 
             Expr::Array(elements) => {
@@ -977,7 +1009,11 @@ impl Compiler {
 
             Expr::Spread(inner) => self.compile_expr(inner),
 
-            Expr::Await(inner) => self.compile_expr(inner),
+            Expr::Await(inner) => {
+                let reg = self.compile_expr(inner)?;
+                self.emit(OpCode::Await { dst: reg, src: reg });
+                Ok(reg)
+            }
 
             Expr::Yield { argument, .. } => {
                 if let Some(arg) = argument {
@@ -1259,6 +1295,26 @@ mod tests {
         assert!(has_call);
     }
 
+    #[test]
+    fn test_method_call_keeps_receiver_register() {
+        // obj.method(1): the argument must not overwrite the receiver.
+        let stmts = vec![Stmt::Expr(Expr::Call {
+            callee: Box::new(Expr::Member {
+                object: Box::new(Expr::Ident("obj".into())),
+                property: Box::new(Expr::Ident("method".into())),
+                computed: false,
+            }),
+            arguments: vec![Expr::Number(1.0)],
+        })];
+        let proto = compile(&stmts);
+        let call = proto.code.iter().find_map(|op| match op {
+            OpCode::CallMethod { obj, argv, .. } => Some((*obj, *argv)),
+            _ => None,
+        });
+        let (obj, argv) = call.unwrap();
+        assert!(argv > obj, "receiver r{obj} overlaps arguments at r{argv}");
+    }
+
     #[test]
     fn test_register_count() {
         let stmts = vec![
@@ -202,6 +202,9 @@ pub struct Lexer {
     pub col: usize,
     /// Depth of `${...}` template nesting; when > 0 and we see `}`, resume template scanning.
     template_depth: usize,
+    /// Position, line and column where the last token started, for
+    /// [`reread_slash_as_regexp`](Lexer::reread_slash_as_regexp).
+    token_start: (usize, usize, usize),
 }
 
 impl Lexer {
@@ -212,6 +215,7 @@ impl Lexer {
             line: 1,
             col: 1,
             template_depth: 0,
+            token_start: (0, 1, 1),
         }
     }
 
@@ -652,6 +656,7 @@ impl Lexer {
     /// followed by a division operator.
     pub fn next_token(&mut self) -> Result<JsToken, LexError> {
         self.skip_whitespace_and_comments();
+        self.token_start = (self.pos, self.line, self.col);
 
         let c = match self.peek() {
             Some(c) => c,
@@ -894,9 +899,17 @@ impl Lexer {
         }
     }
 
-    /// Convenience: re-interpret the last `/` or `/ ` token as the start of a regexp.
+    /// The offset, in characters, of the next character to be read. A
+    /// token's text runs from the position before `next_token` to the one
+    /// after, including the whitespace and comments in front of it.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Convenience: re-interpret the last `/` or `/=` token as the start of a regexp.
     /// The parser calls this when it expects a regexp (i.e., `/` is not division).
     pub fn reread_slash_as_regexp(&mut self) -> Result<JsToken, LexError> {
+        (self.pos, self.line, self.col) = self.token_start;
         self.read_regexp()
     }
 
@@ -1298,6 +1311,20 @@ mod tests {
         assert_eq!(tokens[12], JsToken::Eof);
     }
 
+    #[test]
+    fn test_reread_slash_as_regexp() {
+        let mut lexer = Lexer::new("x = /a\\/b/g;");
+        lexer.next_token().unwrap();
+        lexer.next_token().unwrap();
+        assert_eq!(lexer.next_token().unwrap(), JsToken::Slash);
+        assert_eq!(
+            lexer.reread_slash_as_regexp().unwrap(),
+            JsToken::RegExp { pattern: "a\\/b".into(), flags: "g".into() }
+        );
+        assert_eq!(lexer.next_token().unwrap(), JsToken::Semicolon);
+        assert_eq!(lexer.position(), 12);
+    }
+
     #[test]
     fn test_eof() {
         let tokens = lex("");
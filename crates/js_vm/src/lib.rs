@@ -1,6 +1,7 @@
 // crates/js_vm/src/lib.rs
 // JavaScript stack-based VM — zero external crates
 
+use std::any::Any;
 use std::collections::HashMap;
 use js_bytecode::{OpCode, FunctionProto, Constant};
 use js_gc::{Heap, GcRef, GcObject};
@@ -125,6 +126,30 @@ impl PartialEq for Value {
     fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
 }
 
+fn from_gc_value(v: &js_gc::Value) -> Value {
+    match v {
+        js_gc::Value::Number(n) => Value::number(*n),
+        js_gc::Value::HeapRef(r) => Value::ptr(r.0),
+        js_gc::Value::Boolean(b) => Value::boolean(*b),
+        js_gc::Value::Null => Value::null(),
+        js_gc::Value::Undefined => Value::undefined(),
+    }
+}
+
+fn to_gc_value(v: Value) -> js_gc::Value {
+    if v.is_number() {
+        js_gc::Value::Number(v.as_f64())
+    } else if v.is_ptr() {
+        js_gc::Value::HeapRef(v.as_gc_ref())
+    } else if v.is_boolean() {
+        js_gc::Value::Boolean(v.as_bool())
+    } else if v.is_null() {
+        js_gc::Value::Null
+    } else {
+        js_gc::Value::Undefined
+    }
+}
+
 // ═══════════════════════════════════════════════════════════
 //  CallFrame
 // ═══════════════════════════════════════════════════════════
@@ -186,6 +211,11 @@ pub struct VM {
     pub output: Vec<String>,
     try_stack: Vec<TryFrame>,
     natives: HashMap<String, NativeFn>,
+    /// The receiver of the native being called: `obj` in `obj.method()` or
+    /// in an accessor on `obj`, `undefined` for a plain call.
+    pub this: Value,
+    /// Embedder state for natives to use (e.g. the document's DOM).
+    pub host: Option<Box<dyn Any>>,
 }
 
 impl VM {
@@ -201,6 +231,8 @@ impl VM {
             output: Vec::new(),
             try_stack: Vec::new(),
             natives: HashMap::new(),
+            this: Value::undefined(),
+            host: None,
         }
     }
 
@@ -209,14 +241,182 @@ impl VM {
     }
 
     pub fn set_global(&mut self, name: &str, val: Value) {
-        if let Some(GcObject::Object(map)) = self.heap.get_mut(self.global_object) {
-            let key_ref = self.heap.alloc_string(name.to_string());
-            // Store as number-encoded or ptr
-            // For simplicity, store in a side map
+        let gc_val = if val.is_number() {
+            js_gc::Value::Number(val.as_f64())
+        } else if val.is_ptr() {
+            js_gc::Value::HeapRef(val.as_gc_ref())
+        } else if val.is_boolean() {
+            js_gc::Value::Boolean(val.as_bool())
+        } else if val.is_null() {
+            js_gc::Value::Null
+        } else {
+            js_gc::Value::Undefined
+        };
+        self.set_global_internal(name, gc_val);
+    }
+
+    /// A value that calls the native registered as `name` when invoked, for
+    /// use as a property of host objects (e.g. `document.write`).
+    pub fn native_function_value(&mut self, name: &str) -> Value {
+        let r = self.heap.alloc_string(format!("__native_{}", name));
+        Value::ptr(r.0)
+    }
+
+    /// Make `name` on `obj` an accessor property: reading it calls the
+    /// native `getter` and assigning to it calls `setter` with the new
+    /// value (or is ignored without one). Both run with `this` set to the
+    /// object.
+    pub fn define_accessor(&mut self, obj: GcRef, name: &str, getter: &str, setter: Option<&str>) {
+        let getter = self.native_function_value(getter);
+        let setter = setter.map(|s| self.native_function_value(s));
+        if let Some(GcObject::Object(map)) = self.heap.get_mut(obj) {
+            map.insert(format!("__get_{}", name), to_gc_value(getter));
+            if let Some(setter) = setter {
+                map.insert(format!("__set_{}", name), to_gc_value(setter));
+            }
+        }
+    }
+
+    /// A new array holding `items`.
+    pub fn array_value(&mut self, items: &[Value]) -> Value {
+        let items = items.iter().map(|&v| to_gc_value(v)).collect();
+        Value::ptr(self.heap.allocate(GcObject::Array(items)).0)
+    }
+
+    /// The elements of `value`, if it is an array.
+    pub fn array_items(&self, value: Value) -> Option<Vec<Value>> {
+        if !value.is_ptr() {
+            return None;
+        }
+        match self.heap.get(value.as_gc_ref()) {
+            Some(GcObject::Array(items)) => Some(items.iter().map(from_gc_value).collect()),
+            _ => None,
         }
-        // Use a simpler approach: store globals in the object
-        let _ = name;
-        let _ = val;
+    }
+
+    /// Replace the element `index` of the array `value`.
+    pub fn set_array_item(&mut self, value: Value, index: usize, item: Value) {
+        if value.is_ptr()
+            && let Some(GcObject::Array(items)) = self.heap.get_mut(value.as_gc_ref())
+            && index < items.len()
+        {
+            items[index] = to_gc_value(item);
+        }
+    }
+
+    /// `obj[name]`, calling the getter if `name` is an accessor.
+    pub fn get_property(&mut self, obj: Value, name: &str) -> Result<Value, VmError> {
+        if !obj.is_ptr() {
+            return Ok(Value::undefined());
+        }
+        let (value, getter) = match self.heap.get(obj.as_gc_ref()) {
+            Some(GcObject::Object(map)) => (
+                map.get(name).map(from_gc_value),
+                map.get(&format!("__get_{}", name)).map(from_gc_value),
+            ),
+            _ => (None, None),
+        };
+        if let Some(value) = value {
+            return Ok(value);
+        }
+        match getter.and_then(|g| self.call_native(g, obj, &[])) {
+            Some(result) => result,
+            None => Ok(Value::undefined()),
+        }
+    }
+
+    /// `obj[name] = val`, calling the setter if `name` is an accessor.
+    pub fn set_property(&mut self, obj: Value, name: &str, val: Value) -> Result<(), VmError> {
+        if !obj.is_ptr() {
+            return Ok(());
+        }
+        let (is_accessor, setter) = match self.heap.get(obj.as_gc_ref()) {
+            Some(GcObject::Object(map)) => (
+                map.contains_key(&format!("__get_{}", name)),
+                map.get(&format!("__set_{}", name)).map(from_gc_value),
+            ),
+            _ => return Ok(()),
+        };
+        if let Some(setter) = setter {
+            if let Some(result) = self.call_native(setter, obj, &[val]) {
+                result?;
+            }
+        } else if !is_accessor
+            && let Some(GcObject::Object(map)) = self.heap.get_mut(obj.as_gc_ref())
+        {
+            map.insert(name.to_string(), to_gc_value(val));
+        }
+        Ok(())
+    }
+
+    /// Whether `value` can be called: a native or a script function.
+    pub fn is_callable(&self, value: Value) -> bool {
+        if !value.is_ptr() {
+            return false;
+        }
+        match self.heap.get(value.as_gc_ref()) {
+            Some(GcObject::String(s)) => s
+                .strip_prefix("__native_")
+                .is_some_and(|name| self.natives.contains_key(name)),
+            Some(GcObject::Function(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// Call `callee` with `args` and return its result, for natives that
+    /// take callbacks (e.g. promise reactions). A script function runs to
+    /// completion on top of the current frames.
+    pub fn call_function(&mut self, callee: Value, this: Value, args: &[Value]) -> Result<Value, VmError> {
+        if let Some(result) = self.call_native(callee, this, args) {
+            return result;
+        }
+        let func_index = match callee.is_ptr().then(|| self.heap.get(callee.as_gc_ref())).flatten() {
+            Some(GcObject::Function(data)) => data.func_index as usize,
+            _ => {
+                return Err(VmError {
+                    message: "TypeError: not a function".into(),
+                });
+            }
+        };
+        let base = self.frames.last().map_or(0, |frame| {
+            frame.base_reg + self.protos[frame.func_proto_idx].num_regs as usize + 1
+        });
+        let needed = base + (self.protos[func_index].num_regs as usize).max(args.len()) + 16;
+        if self.regs.len() < needed {
+            self.regs.resize(needed, Value::undefined());
+        }
+        let params = self.protos[func_index].num_params as usize;
+        for i in 0..params {
+            self.regs[base + i] = args.get(i).copied().unwrap_or(Value::undefined());
+        }
+        let depth = self.frames.len();
+        self.frames.push(CallFrame {
+            func_proto_idx: func_index,
+            ip: 0,
+            base_reg: base,
+        });
+        let outer = std::mem::replace(&mut self.this, this);
+        let result = self.run_until(depth);
+        self.this = outer;
+        // An error leaves the callee's frames behind.
+        self.frames.truncate(depth);
+        result
+    }
+
+    /// Call `callee` if it is a registered native, with `this` as the
+    /// receiver. `None` if it is not a native.
+    fn call_native(&mut self, callee: Value, this: Value, args: &[Value]) -> Option<Result<Value, VmError>> {
+        if !callee.is_ptr() {
+            return None;
+        }
+        let func = match self.heap.get(callee.as_gc_ref()) {
+            Some(GcObject::String(s)) => *self.natives.get(s.strip_prefix("__native_")?)?,
+            _ => return None,
+        };
+        let outer = std::mem::replace(&mut self.this, this);
+        let result = func(self, args);
+        self.this = outer;
+        Some(result)
     }
 
     pub fn get_global_value(&self, name: &str) -> Value {
@@ -289,12 +489,14 @@ impl VM {
             self.regs.resize(needed, Value::undefined());
         }
 
-        self.run()
+        self.run_until(0)
     }
 
-    fn run(&mut self) -> Result<Value, VmError> {
+    /// Run until only `depth` frames are left, returning the value the
+    /// last one popped returned.
+    fn run_until(&mut self, depth: usize) -> Result<Value, VmError> {
         loop {
-            if self.frames.is_empty() {
+            if self.frames.len() <= depth {
                 return Ok(Value::undefined());
             }
 
@@ -479,26 +681,8 @@ impl VM {
                     let name_c = &self.protos[proto_idx].constants[name as usize];
                     if let Constant::String(prop_name) = name_c {
                         let prop_name = prop_name.clone();
-                        if obj_val.is_ptr() {
-                            if let Some(GcObject::Object(map)) = self.heap.get(obj_val.as_gc_ref()) {
-                                if let Some(v) = map.get(&prop_name) {
-                                    let val = match v {
-                                        js_gc::Value::Number(n) => Value::number(*n),
-                                        js_gc::Value::HeapRef(r) => Value::ptr(r.0),
-                                        js_gc::Value::Boolean(b) => Value::boolean(*b),
-                                        js_gc::Value::Null => Value::null(),
-                                        js_gc::Value::Undefined => Value::undefined(),
-                                    };
-                                    self.set_reg(dst, base, val);
-                                } else {
-                                    self.set_reg(dst, base, Value::undefined());
-                                }
-                            } else {
-                                self.set_reg(dst, base, Value::undefined());
-                            }
-                        } else {
-                            self.set_reg(dst, base, Value::undefined());
-                        }
+                        let val = self.get_property(obj_val, &prop_name)?;
+                        self.set_reg(dst, base, val);
                     }
                 }
                 OpCode::SetProp { obj, name, val } => {
@@ -507,22 +691,7 @@ impl VM {
                     let name_c = &self.protos[proto_idx].constants[name as usize];
                     if let Constant::String(prop_name) = name_c {
                         let prop_name = prop_name.clone();
-                        if obj_val.is_ptr() {
-                            let gc_val = if v.is_number() {
-                                js_gc::Value::Number(v.as_f64())
-                            } else if v.is_boolean() {
-                                js_gc::Value::Boolean(v.as_bool())
-                            } else if v.is_null() {
-                                js_gc::Value::Null
-                            } else if v.is_ptr() {
-                                js_gc::Value::HeapRef(v.as_gc_ref())
-                            } else {
-                                js_gc::Value::Undefined
-                            };
-                            if let Some(GcObject::Object(map)) = self.heap.get_mut(obj_val.as_gc_ref()) {
-                                map.insert(prop_name, gc_val);
-                            }
-                        }
+                        self.set_property(obj_val, &prop_name, v)?;
                     }
                 }
                 OpCode::GetElem { dst, obj, key } => {
@@ -628,34 +797,47 @@ impl VM {
                         args.push(self.reg(argv + i, base));
                     }
 
-                    // Check if it's a closure in protos
-                    if callee_val.is_ptr() {
-                        if let Some(GcObject::String(s)) = self.heap.get(callee_val.as_gc_ref()) {
-                            if let Some(native_name) = s.strip_prefix("__native_") {
-                                let native_name = native_name.to_string();
-                                if let Some(func) = self.natives.get(&native_name).copied() {
-                                    let result = func(self, &args)?;
-                                    self.set_reg(dst, base, result);
-                                    continue;
-                                }
-                            }
-                        }
+                    if let Some(result) = self.call_native(callee_val, Value::undefined(), &args) {
+                        let result = result?;
+                        self.set_reg(dst, base, result);
+                        continue;
                     }
                     // For now, return undefined for unknown calls
                     self.set_reg(dst, base, Value::undefined());
                 }
-                OpCode::CallMethod { dst, obj: _, name: _, argc: _, argv: _ } => {
-                    self.set_reg(dst, base, Value::undefined());
+                OpCode::CallMethod { dst, obj, name, argc, argv } => {
+                    let obj_val = self.reg(obj, base);
+                    let args: Vec<Value> = (0..argc).map(|i| self.reg(argv + i, base)).collect();
+                    let result = match &self.protos[proto_idx].constants[name as usize] {
+                        Constant::String(method) => {
+                            let method = method.clone();
+                            let callee = self.get_property(obj_val, &method)?;
+                            match self.call_native(callee, obj_val, &args) {
+                                Some(result) => result?,
+                                None => Value::undefined(),
+                            }
+                        }
+                        _ => Value::undefined(),
+                    };
+                    self.set_reg(dst, base, result);
                 }
-                OpCode::New { dst, callee: _, argc: _, argv: _ } => {
-                    let r = self.heap.alloc_object();
-                    self.set_reg(dst, base, Value::ptr(r.0));
+                OpCode::New { dst, callee, argc, argv } => {
+                    // A native constructor runs with the new object as
+                    // `this`, and may return another object instead.
+                    let callee_val = self.reg(callee, base);
+                    let args: Vec<Value> = (0..argc).map(|i| self.reg(argv + i, base)).collect();
+                    let obj = Value::ptr(self.heap.alloc_object().0);
+                    let result = match self.call_native(callee_val, obj, &args) {
+                        Some(result) => Some(result?).filter(|r| r.is_ptr()).unwrap_or(obj),
+                        None => obj,
+                    };
+                    self.set_reg(dst, base, result);
                 }
 
                 OpCode::Return { src } => {
                     let val = self.reg(src, base);
                     self.frames.pop();
-                    if self.frames.is_empty() {
+                    if self.frames.len() <= depth {
                         return Ok(val);
                     }
                     // Set return value in caller's frame
@@ -665,7 +847,10 @@ impl VM {
                 // Exception handling
                 OpCode::Throw { src } => {
                     let _val = self.reg(src, base);
-                    if let Some(try_frame) = self.try_stack.pop() {
+                    // A try block outside a running callback does not
+                    // catch what the callback throws.
+                    let catchable = self.try_stack.last().is_some_and(|t| t.frame_depth > depth);
+                    if let Some(try_frame) = self.try_stack.pop_if(|_| catchable) {
                         // Unwind to catch
                         while self.frames.len() > try_frame.frame_depth {
                             self.frames.pop();
@@ -687,6 +872,14 @@ impl VM {
                 OpCode::PopTry => {
                     self.try_stack.pop();
                 }
+                OpCode::Await { dst, src } => {
+                    let val = self.reg(src, base);
+                    let val = match self.natives.get("__await").copied() {
+                        Some(unwrap) => unwrap(self, &[val])?,
+                        None => val,
+                    };
+                    self.set_reg(dst, base, val);
+                }
 
                 // Object/Array creation
                 OpCode::CreateObject { dst } => {
@@ -1036,4 +1229,180 @@ mod tests {
         let result = vm.execute(proto).unwrap();
         assert_eq!(result.as_f64(), 15.0);
     }
+
+    #[test]
+    fn test_host_object_method() {
+        fn write(vm: &mut VM, args: &[Value]) -> Result<Value, VmError> {
+            let s = args[0].to_string_val(&mut vm.heap);
+            let text = vm.get_string(s).unwrap_or_default().to_string();
+            vm.output.push(text);
+            Ok(Value::undefined())
+        }
+
+        // document.write("hi")
+        let proto = make_proto(
+            vec![
+                OpCode::GetGlobal { dst: 0, name: 0 },
+                OpCode::GetProp { dst: 1, obj: 0, name: 1 },
+                OpCode::LoadConst { dst: 2, idx: 2 },
+                OpCode::Call { dst: 3, callee: 1, argc: 1, argv: 2 },
+                OpCode::Return { src: 3 },
+            ],
+            vec![
+                Constant::String("document".into()),
+                Constant::String("write".into()),
+                Constant::String("hi".into()),
+            ],
+            4,
+        );
+        let mut vm = VM::new();
+        vm.register_native("document.write", write);
+        let document = vm.heap.alloc_object();
+        let method = vm.native_function_value("document.write");
+        if let Some(GcObject::Object(map)) = vm.heap.get_mut(document) {
+            map.insert("write".into(), js_gc::Value::HeapRef(method.as_gc_ref()));
+        }
+        vm.set_global("document", Value::ptr(document.0));
+        vm.execute(proto).unwrap();
+        assert_eq!(vm.output, vec!["hi".to_string()]);
+    }
+
+    #[test]
+    fn test_accessors_and_method_receiver() {
+        // The getter reports how often the setter ran; `bump` adds to `n`
+        // on its receiver.
+        fn get_count(vm: &mut VM, _args: &[Value]) -> Result<Value, VmError> {
+            let n = vm.get_property(vm.this, "n")?;
+            Ok(Value::number(n.as_f64() * 10.0))
+        }
+        fn set_count(vm: &mut VM, args: &[Value]) -> Result<Value, VmError> {
+            vm.set_property(vm.this, "n", args[0])?;
+            Ok(Value::undefined())
+        }
+        fn bump(vm: &mut VM, args: &[Value]) -> Result<Value, VmError> {
+            let n = vm.get_property(vm.this, "n")?.as_f64() + args[0].as_f64();
+            vm.set_property(vm.this, "n", Value::number(n))?;
+            Ok(Value::number(n))
+        }
+
+        // obj.count = 2; obj.bump(3); return obj.count
+        let proto = make_proto(
+            vec![
+                OpCode::GetGlobal { dst: 0, name: 0 },
+                OpCode::LoadConst { dst: 1, idx: 2 },
+                OpCode::SetProp { obj: 0, name: 1, val: 1 },
+                OpCode::LoadConst { dst: 1, idx: 4 },
+                OpCode::CallMethod { dst: 2, obj: 0, name: 3, argc: 1, argv: 1 },
+                OpCode::GetProp { dst: 3, obj: 0, name: 1 },
+                OpCode::Return { src: 3 },
+            ],
+            vec![
+                Constant::String("obj".into()),
+                Constant::String("count".into()),
+                Constant::Number(2.0),
+                Constant::String("bump".into()),
+                Constant::Number(3.0),
+            ],
+            4,
+        );
+        let mut vm = VM::new();
+        vm.register_native("get_count", get_count);
+        vm.register_native("set_count", set_count);
+        vm.register_native("bump", bump);
+        let obj = vm.heap.alloc_object();
+        vm.define_accessor(obj, "count", "get_count", Some("set_count"));
+        let method = vm.native_function_value("bump");
+        vm.set_property(Value::ptr(obj.0), "bump", method).unwrap();
+        vm.set_global("obj", Value::ptr(obj.0));
+        let result = vm.execute(proto).unwrap();
+        assert_eq!(result.as_f64(), 50.0);
+    }
+
+    #[test]
+    fn test_new_calls_native_constructors() {
+        // The constructor fills in `this`, or returns an array instead.
+        fn point(vm: &mut VM, args: &[Value]) -> Result<Value, VmError> {
+            if args.is_empty() {
+                return Ok(vm.array_value(&[Value::number(7.0)]));
+            }
+            vm.set_property(vm.this, "x", args[0])?;
+            Ok(Value::undefined())
+        }
+
+        // return [new Point(5).x, new Point()]
+        let proto = make_proto(
+            vec![
+                OpCode::GetGlobal { dst: 0, name: 0 },
+                OpCode::LoadConst { dst: 1, idx: 1 },
+                OpCode::New { dst: 2, callee: 0, argc: 1, argv: 1 },
+                OpCode::GetProp { dst: 2, obj: 2, name: 2 },
+                OpCode::New { dst: 3, callee: 0, argc: 0, argv: 1 },
+                OpCode::CreateArray { dst: 4, len: 0 },
+                OpCode::LoadConst { dst: 5, idx: 3 },
+                OpCode::SetElem { obj: 4, key: 5, val: 2 },
+                OpCode::LoadConst { dst: 5, idx: 4 },
+                OpCode::SetElem { obj: 4, key: 5, val: 3 },
+                OpCode::Return { src: 4 },
+            ],
+            vec![
+                Constant::String("Point".into()),
+                Constant::Number(5.0),
+                Constant::String("x".into()),
+                Constant::Number(0.0),
+                Constant::Number(1.0),
+            ],
+            6,
+        );
+        let mut vm = VM::new();
+        vm.register_native("Point", point);
+        let result = vm.execute(proto).unwrap();
+        let items = vm.array_items(result).unwrap();
+        assert_eq!(items[0].as_f64(), 5.0);
+        assert_eq!(vm.array_items(items[1]).unwrap()[0].as_f64(), 7.0);
+    }
+
+    #[test]
+    fn test_natives_call_script_functions() {
+        // apply(function (a) { return a * a; }, 21), then await it.
+        fn apply(vm: &mut VM, args: &[Value]) -> Result<Value, VmError> {
+            vm.call_function(args[0], Value::undefined(), &args[1..])
+        }
+        fn add_one(_vm: &mut VM, args: &[Value]) -> Result<Value, VmError> {
+            Ok(Value::number(args[0].as_f64() + 1.0))
+        }
+        let mut double = make_proto(
+            vec![
+                OpCode::Mul { dst: 1, a: 0, b: 0 },
+                OpCode::Return { src: 1 },
+            ],
+            vec![],
+            2,
+        );
+        double.num_params = 1;
+        let proto = make_proto(
+            vec![
+                OpCode::GetGlobal { dst: 0, name: 0 },
+                OpCode::CreateClosure { dst: 1, func_idx: 1 },
+                OpCode::LoadConst { dst: 2, idx: 2 },
+                OpCode::Call { dst: 3, callee: 0, argc: 2, argv: 1 },
+                OpCode::Await { dst: 3, src: 3 },
+                OpCode::Return { src: 3 },
+            ],
+            vec![
+                Constant::String("apply".into()),
+                Constant::Function(Box::new(double)),
+                Constant::Number(21.0),
+            ],
+            4,
+        );
+        let mut vm = VM::new();
+        vm.register_native("apply", apply);
+        vm.register_native("__await", add_one);
+        assert_eq!(vm.execute(proto).unwrap().as_f64(), 442.0);
+        assert!(vm.frames.is_empty());
+
+        let not_a_function = Value::number(1.0);
+        assert!(!vm.is_callable(not_a_function));
+        assert!(vm.call_function(not_a_function, Value::undefined(), &[]).is_err());
+    }
 }
@@ -244,8 +244,13 @@ pub fn layout_block(tree: &mut LayoutTree, box_id: LayoutBoxId, containing_width
         }
     }
 
-    let contain_layout_flag = tree.get(box_id).map(|b| b.computed_style.contain_layout).unwrap_or(false);
-    if contain_layout_flag && specified_height.is_none() {
+    // Size containment: the height doesn't depend on the content, so
+    // size container queries can't feed back into it.
+    let contain_size = tree
+        .get(box_id)
+        .map(|b| b.computed_style.contain_layout || b.computed_style.container_type == style::ContainerType::Size)
+        .unwrap_or(false);
+    if contain_size && specified_height.is_none() {
         content_height = 0.0;
     }
 
@@ -1,10 +1,11 @@
 //! Build a layout tree from the DOM tree and computed styles.
 //!
 //! Walks the DOM in tree order, skipping `display: none` elements, and creates
-//! the corresponding layout boxes. Text nodes become `TextRun` boxes.
+//! the corresponding layout boxes. Text nodes become `TextRun` boxes. SVG
+//! elements get a single box; their contents are painted, not laid out.
 
 use std::collections::HashMap;
-use dom::{Dom, NodeId, NodeData};
+use dom::{Dom, Namespace, NodeId, NodeData};
 use style::{ComputedStyle, Display};
 use crate::tree::{LayoutBox, LayoutBoxId, LayoutBoxKind, LayoutTree};
 
@@ -72,8 +73,14 @@ fn build_box(
                 }
             }
 
-            // Recursively build children.
-            let child_ids = dom.children(node_id);
+            // Recursively build children. SVG elements and iframes are
+            // replaced content: an SVG subtree is painted by the SVG
+            // renderer, and an iframe shows its own document instead of
+            // its (raw text) children.
+            let is_replaced = node.as_element().is_some_and(|e| {
+                e.namespace == Namespace::Svg || (e.namespace == Namespace::Html && e.tag_name == "iframe")
+            });
+            let child_ids = if is_replaced { Vec::new() } else { dom.children(node_id) };
             let mut child_boxes: Vec<LayoutBoxId> = before_boxes;
 
             for child_node_id in child_ids {
@@ -389,6 +396,48 @@ mod tests {
         assert_eq!(children.len(), 1);
     }
 
+    #[test]
+    fn svg_contents_are_not_laid_out() {
+        let mut dom = Dom::new();
+        let doc = dom.create_document();
+        let body = dom.create_html_element("body");
+        let svg = dom.create_element("svg", Namespace::Svg, Vec::new());
+        let text = dom.create_element("text", Namespace::Svg, Vec::new());
+        let label = dom.create_text("Label");
+
+        dom.append_child(doc, body);
+        dom.append_child(body, svg);
+        dom.append_child(svg, text);
+        dom.append_child(text, label);
+
+        let styles = make_test_styles(&dom, body);
+        let layout_tree = build_layout_tree(&dom, doc, &styles);
+        let root_id = layout_tree.root.unwrap();
+        let svg_box = layout_tree.children(root_id)[0];
+        assert_eq!(layout_tree.get(svg_box).unwrap().node, Some(svg));
+        assert!(layout_tree.children(svg_box).is_empty());
+    }
+
+    #[test]
+    fn iframe_fallback_text_is_not_laid_out() {
+        let mut dom = Dom::new();
+        let doc = dom.create_document();
+        let body = dom.create_html_element("body");
+        let iframe = dom.create_html_element("iframe");
+        let fallback = dom.create_text("Your browser does not support iframes");
+
+        dom.append_child(doc, body);
+        dom.append_child(body, iframe);
+        dom.append_child(iframe, fallback);
+
+        let styles = make_test_styles(&dom, body);
+        let layout_tree = build_layout_tree(&dom, doc, &styles);
+        let root_id = layout_tree.root.unwrap();
+        let iframe_box = layout_tree.children(root_id)[0];
+        assert_eq!(layout_tree.get(iframe_box).unwrap().node, Some(iframe));
+        assert!(layout_tree.children(iframe_box).is_empty());
+    }
+
     #[test]
     fn text_node_creates_text_run() {
         let mut dom = Dom::new();
@@ -9,6 +9,7 @@
 //! 6. Determine cross sizes and align (align-items).
 
 use style::{FlexDirection, FlexWrap, JustifyContent, AlignItems};
+use crate::text::text_advance;
 use crate::tree::{LayoutBoxId, LayoutTree};
 use crate::block::layout_block;
 
@@ -59,7 +60,7 @@ pub fn layout_flex(tree: &mut LayoutTree, container_id: LayoutBoxId, available_w
                 if let Some(cb) = tree.get(cc) {
                     w += cb.computed_style.width.unwrap_or_else(|| {
                         cb.text.as_ref()
-                            .map(|t| t.chars().count() as f32 * cb.computed_style.font_size_px * 0.6)
+                            .map(|t| text_advance(&tree.fonts, &cb.computed_style, t))
                             .unwrap_or(0.0)
                     });
                 }
@@ -4,6 +4,7 @@ use common::Rect;
 use style::TextAlign;
 use crate::tree::{LayoutBoxId, LayoutBoxKind, LayoutTree};
 use crate::block::layout_block;
+use crate::text::glyph_advance;
 
 /// A single item positioned on a line.
 #[derive(Debug, Clone)]
@@ -295,7 +296,8 @@ pub fn layout_inline_content(
 
 /// Measure the width and height of an inline box.
 ///
-/// For text runs, we estimate width based on character count * average char width.
+/// For text runs, we sum the advances of the characters: a web font's where
+/// one matches, else an average character width.
 /// For inline elements, we sum children or use specified width.
 fn measure_inline_box(tree: &LayoutTree, box_id: LayoutBoxId, _available_width: f32) -> (f32, f32) {
     let b = match tree.get(box_id) {
@@ -309,7 +311,6 @@ fn measure_inline_box(tree: &LayoutTree, box_id: LayoutBoxId, _available_width:
     match b.kind {
         LayoutBoxKind::TextRun => {
             let text = b.text.as_deref().unwrap_or("");
-            let avg_char_width = font_size * 0.6;
             let letter_sp = b.computed_style.letter_spacing;
             let word_sp = b.computed_style.word_spacing;
 
@@ -323,15 +324,16 @@ fn measure_inline_box(tree: &LayoutTree, box_id: LayoutBoxId, _available_width:
                 return (font_size, char_count * (line_height + letter_sp));
             }
 
-            let tab_width = b.computed_style.tab_size * avg_char_width;
+            let style = &b.computed_style;
+            let tab_width = style.tab_size * glyph_advance(&tree.fonts, style, ' ').1;
             let mut width = 0.0f32;
             for ch in text.chars() {
                 if ch == '\t' {
                     width += tab_width;
                 } else if ch == ' ' {
-                    width += avg_char_width + word_sp + letter_sp;
+                    width += glyph_advance(&tree.fonts, style, ch).1 + word_sp + letter_sp;
                 } else {
-                    width += avg_char_width + letter_sp;
+                    width += glyph_advance(&tree.fonts, style, ch).1 + letter_sp;
                 }
             }
             (width, line_height)
@@ -11,6 +11,7 @@ pub mod inline;
 pub mod flex;
 pub mod grid;
 pub mod build;
+pub mod text;
 
 pub use geometry::{BoxModel, compute_box_model};
 pub use tree::{LayoutBoxId, LayoutBoxKind, LayoutBox, LayoutTree};
@@ -19,3 +20,4 @@ pub use inline::{LineBox, LineItem, layout_inline_content};
 pub use flex::layout_flex;
 pub use grid::layout_grid;
 pub use build::build_layout_tree;
+pub use text::{glyph_advance, text_advance};
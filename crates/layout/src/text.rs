@@ -0,0 +1,31 @@
+//! Text measurement.
+//!
+//! Characters a registered web font covers take that face's advances;
+//! everything else keeps the built-in estimate of 0.6em per character.
+
+use std::rc::Rc;
+
+use font::face::Face;
+use font::registry::FontRegistry;
+use style::{ComputedStyle, FontStyle};
+
+/// Advance of a character without a web font, as a fraction of the font size.
+pub const DEFAULT_ADVANCE_EM: f32 = 0.6;
+
+/// The web font face `ch` is drawn with in `style`, if any, and its advance.
+pub fn glyph_advance<'a>(fonts: &'a FontRegistry, style: &ComputedStyle, ch: char) -> (Option<&'a Rc<Face>>, f32) {
+    let size = style.font_size_px;
+    let italic = style.font_style != FontStyle::Normal;
+    match fonts.match_face(&style.font_family, style.font_weight, italic, ch) {
+        Some(face) => (Some(face), face.advance(face.glyph_index(ch), size)),
+        None => (None, size * DEFAULT_ADVANCE_EM),
+    }
+}
+
+/// The summed advances of `text` in `style`, without letter or word spacing.
+pub fn text_advance(fonts: &FontRegistry, style: &ComputedStyle, text: &str) -> f32 {
+    if fonts.is_empty() {
+        return text.chars().count() as f32 * style.font_size_px * DEFAULT_ADVANCE_EM;
+    }
+    text.chars().map(|ch| glyph_advance(fonts, style, ch).1).sum()
+}
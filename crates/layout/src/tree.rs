@@ -1,7 +1,10 @@
 //! Layout tree — the box tree that mirrors (but is not identical to) the DOM tree.
 
+use std::rc::Rc;
+
 use arena::{Arena, GenIndex};
 use dom::NodeId;
+use font::registry::FontRegistry;
 use style::ComputedStyle;
 
 use crate::geometry::BoxModel;
@@ -108,6 +111,9 @@ pub struct LayoutTree {
 
     /// The root box of the layout tree.
     pub root: Option<LayoutBoxId>,
+
+    /// The web fonts text is measured (and later drawn) with.
+    pub fonts: Rc<FontRegistry>,
 }
 
 impl LayoutTree {
@@ -116,6 +122,7 @@ impl LayoutTree {
         Self {
             boxes: Arena::new(),
             root: None,
+            fonts: Rc::default(),
         }
     }
 
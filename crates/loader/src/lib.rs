@@ -133,7 +133,7 @@ impl ResourceLoader {
     /// Detect content type from magic bytes and URL extension.
     ///
     /// Performs simple content sniffing:
-    /// 1. Check magic bytes (PNG, JPEG, GIF, WOFF2, WOFF, PDF).
+    /// 1. Check magic bytes (PNG, JPEG, GIF, ICO, WOFF2, WOFF, PDF).
     /// 2. Fall back to URL file extension.
     /// 3. Default to `application/octet-stream`.
     pub fn detect_content_type(data: &[u8], url: &str) -> String {
@@ -167,6 +167,10 @@ impl ResourceLoader {
             if data.starts_with(b"%PDF") {
                 return "application/pdf".to_string();
             }
+            // ICO
+            if data.starts_with(&[0, 0, 1, 0]) {
+                return "image/x-icon".to_string();
+            }
         }
         // Check for HTML-like content
         if data.len() >= 15 {
@@ -394,6 +398,12 @@ mod tests {
         assert_eq!(ResourceLoader::detect_content_type(data, "anim.bin"), "image/gif");
     }
 
+    #[test]
+    fn detect_ico() {
+        let data = [0x00, 0x00, 0x01, 0x00, 0x01, 0x00];
+        assert_eq!(ResourceLoader::detect_content_type(&data, "favicon"), "image/x-icon");
+    }
+
     #[test]
     fn detect_woff2() {
         let data = b"wOF2\x00\x00";
@@ -4,24 +4,15 @@
 //! and renders text into a `Framebuffer` using alpha-blended bitmaps.
 
 use font::atlas::{AtlasEntry, GlyphAtlas, GlyphKey};
-use font::glyph::{parse_glyph, GlyphDesc};
-use font::rasterizer::{rasterize_outline, GlyphBitmap};
-use font::tables::{
-    parse_cmap, get_glyph_offset, get_hmetric,
-    CmapFormat4, FontFile, HeadTable, HheaTable, TableTag,
-};
+use font::face::Face;
 
 use crate::rasterizer::Framebuffer;
 
 /// A font engine that loads a TrueType font and renders text.
+///
+/// Glyphs of web fonts share the engine's atlas, keyed by their face.
 pub struct FontEngine {
-    font_data: Vec<u8>,
-    head: HeadTable,
-    hhea: HheaTable,
-    cmap: CmapFormat4,
-    loca_range: (usize, usize),
-    glyf_range: (usize, usize),
-    hmtx_range: (usize, usize),
+    face: Face,
     atlas: GlyphAtlas,
 }
 
@@ -45,50 +36,9 @@ impl FontEngine {
     pub fn load(path: &str) -> Result<Self, String> {
         let font_data =
             std::fs::read(path).map_err(|e| format!("failed to read font '{}': {}", path, e))?;
-
-        // Parse the font file to extract table metadata. We copy out all the
-        // data we need so that the borrow of `font_data` is released before
-        // we move it into the struct.
-        let (head, hhea, cmap, loca_range, glyf_range, hmtx_range) = {
-            let ff = FontFile::parse(&font_data)
-                .map_err(|e| format!("failed to parse font: {:?}", e))?;
-
-            let head_data = ff
-                .table_data(TableTag::HEAD)
-                .ok_or("missing head table")?;
-            let head =
-                HeadTable::parse(head_data).map_err(|e| format!("head parse error: {:?}", e))?;
-
-            let hhea_data = ff
-                .table_data(TableTag::HHEA)
-                .ok_or("missing hhea table")?;
-            let hhea =
-                HheaTable::parse(hhea_data).map_err(|e| format!("hhea parse error: {:?}", e))?;
-
-            let cmap_data = ff
-                .table_data(TableTag::CMAP)
-                .ok_or("missing cmap table")?;
-            let cmap = parse_cmap(cmap_data).map_err(|e| format!("cmap parse error: {:?}", e))?;
-
-            let loca_rec = ff.find_table(TableTag::LOCA).ok_or("missing loca table")?;
-            let glyf_rec = ff.find_table(TableTag::GLYF).ok_or("missing glyf table")?;
-            let hmtx_rec = ff.find_table(TableTag::HMTX).ok_or("missing hmtx table")?;
-
-            let loca_range = (loca_rec.offset as usize, loca_rec.length as usize);
-            let glyf_range = (glyf_rec.offset as usize, glyf_rec.length as usize);
-            let hmtx_range = (hmtx_rec.offset as usize, hmtx_rec.length as usize);
-
-            (head, hhea, cmap, loca_range, glyf_range, hmtx_range)
-        };
-
+        let face = Face::from_sfnt(font_data).map_err(|e| format!("failed to parse font: {:?}", e))?;
         Ok(FontEngine {
-            font_data,
-            head,
-            hhea,
-            cmap,
-            loca_range,
-            glyf_range,
-            hmtx_range,
+            face,
             atlas: GlyphAtlas::new(1024, 1024),
         })
     }
@@ -122,24 +72,9 @@ impl FontEngine {
         Err("no suitable system font found".into())
     }
 
-    fn loca_data(&self) -> &[u8] {
-        let (off, len) = self.loca_range;
-        &self.font_data[off..off + len]
-    }
-
-    fn glyf_data(&self) -> &[u8] {
-        let (off, len) = self.glyf_range;
-        &self.font_data[off..off + len]
-    }
-
-    fn hmtx_data(&self) -> &[u8] {
-        let (off, len) = self.hmtx_range;
-        &self.font_data[off..off + len]
-    }
-
     /// Look up a Unicode codepoint in the cmap table.
     pub fn cmap_lookup(&self, ch: char) -> u16 {
-        self.cmap.lookup(ch as u16)
+        self.face.glyph_index(ch)
     }
 
     /// Look up a cached glyph entry in the atlas.
@@ -149,55 +84,25 @@ impl FontEngine {
 
     /// Rasterize and cache a glyph, returning its atlas entry.
     pub fn get_glyph(&mut self, ch: char, size_px: f32) -> Option<AtlasEntry> {
-        let glyph_id = self.cmap.lookup(ch as u16);
+        let glyph_id = self.face.glyph_index(ch);
         let key = GlyphKey::new(glyph_id, size_px);
-
         if let Some(entry) = self.atlas.get(key) {
             return Some(*entry);
         }
+        let bitmap = self.face.rasterize(glyph_id, size_px)?;
+        self.atlas.insert(key, &bitmap)
+    }
 
-        let scale = size_px / self.head.units_per_em as f32;
-
-        let hmetric = get_hmetric(self.hmtx_data(), glyph_id, self.hhea.num_h_metrics).ok()?;
-        let advance = hmetric.advance_width as f32 * scale;
-
-        let (offset, next_offset) =
-            get_glyph_offset(self.loca_data(), glyph_id, self.head.index_to_loc_format).ok()?;
-
-        if offset == next_offset {
-            let bitmap = GlyphBitmap {
-                width: 0,
-                height: 0,
-                bearing_x: 0,
-                bearing_y: 0,
-                advance,
-                data: Vec::new(),
-            };
-            return self.atlas.insert(key, &bitmap);
-        }
-
-        let glyf = self.glyf_data();
-        let glyph_data = glyf.get(offset as usize..next_offset as usize)?;
-        let desc = parse_glyph(glyph_data).ok()?;
-
-        match desc {
-            GlyphDesc::Simple(outline) => {
-                let mut bitmap = rasterize_outline(&outline, size_px, self.head.units_per_em);
-                bitmap.advance = advance;
-                self.atlas.insert(key, &bitmap)
-            }
-            GlyphDesc::Composite(_) | GlyphDesc::Empty => {
-                let bitmap = GlyphBitmap {
-                    width: 0,
-                    height: 0,
-                    bearing_x: 0,
-                    bearing_y: 0,
-                    advance,
-                    data: Vec::new(),
-                };
-                self.atlas.insert(key, &bitmap)
-            }
+    /// Rasterize and cache a glyph of web font `face`, returning its key
+    /// and atlas entry.
+    pub fn get_face_glyph(&mut self, face: &Face, ch: char, size_px: f32) -> Option<(GlyphKey, AtlasEntry)> {
+        let glyph_id = face.glyph_index(ch);
+        let key = GlyphKey::for_face(face.id(), glyph_id, size_px);
+        if let Some(entry) = self.atlas.get(key) {
+            return Some((key, *entry));
         }
+        let bitmap = face.rasterize(glyph_id, size_px)?;
+        Some((key, self.atlas.insert(key, &bitmap)?))
     }
 
     /// Blit a single cached glyph from the atlas onto the framebuffer.
@@ -286,7 +191,7 @@ impl FontEngine {
                 }
             }
 
-            let glyph_id = self.cmap.lookup(ch as u16);
+            let glyph_id = self.face.glyph_index(ch);
             let key = GlyphKey::new(glyph_id, size_px);
 
             if let Some(entry) = self.atlas.get(key).copied() {
@@ -316,14 +221,11 @@ impl FontEngine {
 
     /// Return the line height (ascender − descender + line_gap) in pixels.
     pub fn line_height(&self, size_px: f32) -> f32 {
-        let scale = size_px / self.head.units_per_em as f32;
-        (self.hhea.ascender as f32 - self.hhea.descender as f32 + self.hhea.line_gap as f32)
-            * scale
+        self.face.line_height(size_px)
     }
 
     /// Return the ascent (baseline to top) in pixels.
     pub fn ascent(&self, size_px: f32) -> f32 {
-        let scale = size_px / self.head.units_per_em as f32;
-        self.hhea.ascender as f32 * scale
+        self.face.ascent(size_px)
     }
 }
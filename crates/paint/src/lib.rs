@@ -9,8 +9,12 @@
 pub mod rasterizer;
 pub mod font_engine;
 
+use std::rc::Rc;
+
 use common::{Color, Rect};
-use layout::{LayoutTree, LayoutBoxId, LayoutBoxKind, LayoutBox};
+use font::face::Face;
+use font::registry::FontRegistry;
+use layout::{LayoutTree, LayoutBoxId, LayoutBoxKind, LayoutBox, glyph_advance};
 use style::{Display, Position, BorderStyle, Overflow, Visibility};
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -23,6 +27,8 @@ pub struct PositionedGlyph {
     pub glyph_id: u16,
     pub x: f32,
     pub y: f32,
+    /// The web font to draw the glyph with; `None` for the built-in font.
+    pub face: Option<Rc<Face>>,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -218,7 +224,7 @@ fn paint_layout_box(tree: &LayoutTree, box_id: LayoutBoxId, list: &mut DisplayLi
     match layout_box.kind {
         LayoutBoxKind::TextRun => {
             if is_visible {
-                paint_text(layout_box, list);
+                paint_text(&tree.fonts, layout_box, list);
             }
         }
         _ => {
@@ -521,7 +527,7 @@ fn paint_borders(layout_box: &LayoutBox, list: &mut DisplayList) {
 }
 
 /// Paint a text run.
-fn paint_text(layout_box: &LayoutBox, list: &mut DisplayList) {
+fn paint_text(fonts: &FontRegistry, layout_box: &LayoutBox, list: &mut DisplayList) {
     let text = match &layout_box.text {
         Some(t) => t.clone(),
         None => return,
@@ -562,10 +568,11 @@ fn paint_text(layout_box: &LayoutBox, list: &mut DisplayList) {
         style.color
     };
 
-    // Generate simple glyph positions (one per character, evenly spaced).
+    // Position one glyph per character, advancing by the web font's
+    // advances where one matches and evenly otherwise.
     let font_size = style.font_size_px;
-    let avg_char_width = font_size * 0.6;
     let mut glyphs = Vec::with_capacity(text.len());
+    let mut advances = Vec::with_capacity(text.len());
     let mut x_offset = 0.0f32;
     let y_offset = font_size; // baseline approximation
 
@@ -581,6 +588,7 @@ fn paint_text(layout_box: &LayoutBox, list: &mut DisplayList) {
                 glyph_id: ch as u16,
                 x: content_box.x + font_size * 0.2,
                 y: content_box.y + vert_y + font_size,
+                face: glyph_advance(fonts, style, ch).0.cloned(),
             });
             vert_y += style.line_height_px;
         }
@@ -588,20 +596,24 @@ fn paint_text(layout_box: &LayoutBox, list: &mut DisplayList) {
     } else {
         let extra_letter = style.letter_spacing;
         let extra_word = style.word_spacing;
-        let tab_width = style.tab_size * avg_char_width;
+        let tab_width = style.tab_size * glyph_advance(fonts, style, ' ').1;
         for ch in text.chars() {
+            let (face, advance) = glyph_advance(fonts, style, ch);
             glyphs.push(PositionedGlyph {
                 glyph_id: ch as u16,
                 x: content_box.x + x_offset,
                 y: content_box.y + y_offset,
+                face: face.cloned(),
             });
-            if ch == '\t' {
-                x_offset += tab_width;
+            let advance = if ch == '\t' {
+                tab_width
             } else if ch == ' ' {
-                x_offset += avg_char_width + extra_letter + extra_word;
+                advance + extra_letter + extra_word
             } else {
-                x_offset += avg_char_width + extra_letter;
-            }
+                advance + extra_letter
+            };
+            advances.push(advance);
+            x_offset += advance;
         }
     }
 
@@ -611,23 +623,24 @@ fn paint_text(layout_box: &LayoutBox, list: &mut DisplayList) {
         && x_offset > content_box.w
         && content_box.w > 0.0;
     if needs_ellipsis {
-        let ellipsis_width = avg_char_width;
+        let (ellipsis_face, ellipsis_width) = glyph_advance(fonts, style, '\u{2026}');
         let max_width = (content_box.w - ellipsis_width).max(0.0);
         let mut truncated = Vec::new();
         let mut trunc_x = 0.0f32;
         let mut char_count = 0usize;
-        for g in &glyphs {
-            if trunc_x + avg_char_width > max_width {
+        for (g, advance) in glyphs.iter().zip(&advances) {
+            if trunc_x + advance > max_width {
                 break;
             }
             truncated.push(g.clone());
-            trunc_x += avg_char_width;
+            trunc_x += advance;
             char_count += 1;
         }
         truncated.push(PositionedGlyph {
             glyph_id: '\u{2026}' as u16,
             x: content_box.x + trunc_x,
             y: content_box.y + y_offset,
+            face: ellipsis_face.cloned(),
         });
         glyphs = truncated;
         let mut s: String = display_text.chars().take(char_count).collect();
@@ -642,6 +655,7 @@ fn paint_text(layout_box: &LayoutBox, list: &mut DisplayList) {
                 glyph_id: g.glyph_id,
                 x: g.x + shadow.offset_x,
                 y: g.y + shadow.offset_y,
+                face: g.face.clone(),
             }
         }).collect();
         list.push(DisplayItem::TextRun {
@@ -730,6 +744,7 @@ fn paint_list_marker(tree: &LayoutTree, box_id: LayoutBoxId, layout_box: &Layout
                     glyph_id: 0x2022,
                     x: marker_x,
                     y: content_box.y + font_size,
+                    face: None,
                 }],
             });
         }
@@ -744,6 +759,7 @@ fn paint_list_marker(tree: &LayoutTree, box_id: LayoutBoxId, layout_box: &Layout
                     glyph_id: 0x25E6,
                     x: marker_x,
                     y: content_box.y + font_size,
+                    face: None,
                 }],
             });
         }
@@ -758,6 +774,7 @@ fn paint_list_marker(tree: &LayoutTree, box_id: LayoutBoxId, layout_box: &Layout
                     glyph_id: 0x25AA,
                     x: marker_x,
                     y: content_box.y + font_size,
+                    face: None,
                 }],
             });
         }
@@ -774,6 +791,7 @@ fn paint_list_marker(tree: &LayoutTree, box_id: LayoutBoxId, layout_box: &Layout
                     glyph_id: ch as u16,
                     x: text_x + gx,
                     y: content_box.y + font_size,
+                    face: None,
                 });
                 gx += char_w;
             }
@@ -35,7 +35,7 @@ fn apply_opacity(c: &Color, opacity: f32) -> Color {
 
 /// Alpha-blend `src` over `dst` using the standard "over" compositing operator.
 ///
-/// Both values are in ARGB `0xAARRGGBB` format.
+/// Both values are in ARGB `0xAARRGGBB` format (not premultiplied).
 #[inline]
 fn blend_argb(dst: u32, src: u32) -> u32 {
     let sa = (src >> 24) & 0xFF;
@@ -47,6 +47,10 @@ fn blend_argb(dst: u32, src: u32) -> u32 {
     }
 
     let inv_sa = 255 - sa;
+    let da = (dst >> 24) & 0xFF;
+    if da < 255 {
+        return blend_argb_translucent(dst, src, da);
+    }
 
     let sr = (src >> 16) & 0xFF;
     let sg = (src >> 8) & 0xFF;
@@ -55,14 +59,31 @@ fn blend_argb(dst: u32, src: u32) -> u32 {
     let dr = (dst >> 16) & 0xFF;
     let dg = (dst >> 8) & 0xFF;
     let db = dst & 0xFF;
-    let da = (dst >> 24) & 0xFF;
 
     let out_r = (sr * sa + dr * inv_sa) / 255;
     let out_g = (sg * sa + dg * inv_sa) / 255;
     let out_b = (sb * sa + db * inv_sa) / 255;
-    let out_a = sa + (da * inv_sa) / 255;
 
-    (out_a << 24) | (out_r << 16) | (out_g << 8) | out_b
+    (0xFF << 24) | (out_r << 16) | (out_g << 8) | out_b
+}
+
+/// "Over" onto a destination that is itself translucent, as when drawing
+/// into a transparent offscreen buffer: the destination colour is weighted
+/// by its own alpha so transparent pixels don't darken the edges.
+fn blend_argb_translucent(dst: u32, src: u32, da: u32) -> u32 {
+    let sa = (src >> 24) & 0xFF;
+    let dw = da * (255 - sa); // destination weight, scaled by 255
+    let out_a255 = sa * 255 + dw;
+    if out_a255 == 0 {
+        return 0;
+    }
+    let channel = |shift: u32| {
+        let s = (src >> shift) & 0xFF;
+        let d = (dst >> shift) & 0xFF;
+        (s * sa * 255 + d * dw + out_a255 / 2) / out_a255
+    };
+    let out_a = (out_a255 + 127) / 255;
+    (out_a << 24) | (channel(16) << 16) | (channel(8) << 8) | channel(0)
 }
 
 /// Intersect two rectangles. Returns `Rect::ZERO` if no overlap.
@@ -380,6 +401,15 @@ impl Framebuffer {
         }
     }
 
+    /// Copy the pixels out as RGBA8, the layout the image store uses.
+    pub fn to_rgba(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.pixels.len() * 4);
+        for &p in &self.pixels {
+            out.extend_from_slice(&[(p >> 16) as u8, (p >> 8) as u8, p as u8, (p >> 24) as u8]);
+        }
+        out
+    }
+
     /// Get the raw pixel data as a byte slice (for X11 PutImage).
     pub fn as_bytes(&self) -> &[u8] {
         let ptr = self.pixels.as_ptr() as *const u8;
@@ -461,13 +491,7 @@ pub fn rasterize_display_list_with_font(
     scroll_y: f32,
     font_engine: &mut FontEngine,
 ) {
-    for item in list {
-        if let DisplayItem::TextRun { text, font_size, .. } = item {
-            for ch in text.chars() {
-                font_engine.get_glyph(ch, *font_size);
-            }
-        }
-    }
+    cache_glyphs(list, font_engine);
 
     let viewport = Rect::new(0.0, 0.0, fb.width as f32, fb.height as f32);
     let mut state = RasterState {
@@ -493,13 +517,7 @@ pub fn rasterize_display_list_with_font_and_images(
     images: &ImageStore,
 ) {
     // Pre-cache all text glyphs so the render pass only needs &FontEngine.
-    for item in list {
-        if let DisplayItem::TextRun { text, font_size, .. } = item {
-            for ch in text.chars() {
-                font_engine.get_glyph(ch, *font_size);
-            }
-        }
-    }
+    cache_glyphs(list, font_engine);
 
     let viewport = Rect::new(0.0, 0.0, fb.width as f32, fb.height as f32);
     let mut state = RasterState {
@@ -516,6 +534,26 @@ pub fn rasterize_display_list_with_font_and_images(
     }
 }
 
+/// Rasterize the glyphs of every text run in `list` into the font atlas,
+/// from their web font where they have one.
+fn cache_glyphs(list: &[DisplayItem], font_engine: &mut FontEngine) {
+    for item in list {
+        if let DisplayItem::TextRun { font_size, glyphs, .. } = item {
+            for glyph in glyphs {
+                let ch = char::from_u32(glyph.glyph_id as u32).unwrap_or('\0');
+                match &glyph.face {
+                    Some(face) => {
+                        font_engine.get_face_glyph(face, ch, *font_size);
+                    }
+                    None => {
+                        font_engine.get_glyph(ch, *font_size);
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Rasterize a single display item.
 fn rasterize_item(
     fb: &mut Framebuffer,
@@ -578,8 +616,10 @@ fn rasterize_item(
                     let gy = glyph.y - state.scroll_y + t_ty;
 
                     let codepoint = char::from_u32(glyph.glyph_id as u32).unwrap_or('\0');
-                    let glyph_id = fe.cmap_lookup(codepoint);
-                    let key = font::atlas::GlyphKey::new(glyph_id, *font_size);
+                    let key = match &glyph.face {
+                        Some(face) => font::atlas::GlyphKey::for_face(face.id(), face.glyph_index(codepoint), *font_size),
+                        None => font::atlas::GlyphKey::new(fe.cmap_lookup(codepoint), *font_size),
+                    };
 
                     if let Some(entry) = fe.atlas_get(key) {
                         if entry.w > 0 && entry.h > 0 {
@@ -1007,6 +1047,16 @@ mod tests {
         assert!(b > 100 && b < 160, "b={b}");
     }
 
+    #[test]
+    fn blend_argb_onto_transparent_keeps_source_color() {
+        let result = blend_argb(0x0000_0000, 0x80FF_0000);
+        assert_eq!(result, 0x80FF_0000);
+        // Two half-covered layers accumulate alpha without darkening.
+        let twice = blend_argb(result, 0x80FF_0000);
+        assert_eq!(twice & 0x00FF_FFFF, 0x00FF_0000);
+        assert!((190..=194).contains(&(twice >> 24)), "a={}", twice >> 24);
+    }
+
     #[test]
     fn color_to_argb_works() {
         let c = Color::rgba(0x12, 0x34, 0x56, 0x78);
@@ -1137,8 +1187,8 @@ mod tests {
             color: Color::BLACK,
             font_size: 16.0,
             glyphs: vec![
-                PositionedGlyph { glyph_id: b'H' as u16, x: 0.0, y: 16.0 },
-                PositionedGlyph { glyph_id: b'i' as u16, x: 9.6, y: 16.0 },
+                PositionedGlyph { glyph_id: b'H' as u16, x: 0.0, y: 16.0, face: None },
+                PositionedGlyph { glyph_id: b'i' as u16, x: 9.6, y: 16.0, face: None },
             ],
         }];
 
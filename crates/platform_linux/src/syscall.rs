@@ -1,6 +1,7 @@
 //! Raw Linux syscall wrappers.
 //!
-//! Thin wrappers around libc functions for socket I/O, epoll, and dynamic loading.
+//! Thin wrappers around libc functions for socket I/O, epoll, dynamic loading,
+//! and kernel randomness.
 //! No external crates — uses `extern "C"` FFI directly.
 
 use core::ffi::c_void;
@@ -118,6 +119,9 @@ unsafe extern "C" {
     pub fn dlclose(handle: *mut c_void) -> i32;
     pub fn dlerror() -> *const u8;
 
+    // Randomness
+    pub fn getrandom(buf: *mut u8, buflen: usize, flags: u32) -> isize;
+
     // errno
     pub fn __errno_location() -> *mut i32;
 }
@@ -188,6 +192,27 @@ pub fn write_all(fd: i32, buf: &[u8]) -> Result<(), i32> {
     Ok(())
 }
 
+/// Fill `buf` from the kernel CSPRNG with `getrandom(2)`.
+///
+/// Blocks only until the entropy pool is first initialized. Interrupted and
+/// short reads are retried.
+pub fn fill_random(buf: &mut [u8]) -> Result<(), i32> {
+    let mut offset = 0;
+    while offset < buf.len() {
+        let n = unsafe { getrandom(buf[offset..].as_mut_ptr(), buf.len() - offset, 0) };
+        if n < 0 {
+            let e = errno();
+            if e == 4 {
+                // EINTR
+                continue;
+            }
+            return Err(e);
+        }
+        offset += n as usize;
+    }
+    Ok(())
+}
+
 /// Create a Unix domain stream socket.
 pub fn unix_stream_socket() -> Result<i32, i32> {
     let fd = unsafe { socket(AF_UNIX, SOCK_STREAM, 0) };
@@ -255,6 +280,16 @@ mod tests {
         assert_eq!(data, 0);
     }
 
+    #[test]
+    fn fill_random_returns_fresh_bytes() {
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+        fill_random(&mut a).unwrap();
+        fill_random(&mut b).unwrap();
+        assert_ne!(a, b);
+        assert_ne!(a, [0u8; 32]);
+    }
+
     #[test]
     fn constants_are_correct() {
         assert_eq!(AF_UNIX, 1);
@@ -1,6 +1,17 @@
 //! CSS Animations — keyframe-based animation types and evaluation engine.
 
+use std::collections::HashMap;
+
 use common::Color;
+use css::{Declaration, Device, KeyframesRule, Stylesheet};
+use dom::{Dom, NodeId};
+
+use crate::cascade::{
+    apply_declaration, is_inherited_property, parse_timing_function, resolve_css_values,
+    resolve_property_percentages, resolve_remaining_calcs, strip_vendor_prefix, ResolveContext, StyleOrigin,
+};
+use crate::computed::{ComputedStyle, Display, TransformFunction};
+use crate::supports::supports_declaration;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // AnimationDirection
@@ -106,6 +117,8 @@ pub enum AnimatableValue {
     Number(f64),
     Length(f32),
     Color(Color),
+    Transform(Vec<TransformFunction>),
+    /// No value to blend, like `auto`.
     None,
 }
 
@@ -118,6 +131,9 @@ pub enum AnimatableValue {
 pub struct KeyframeStop {
     /// Progress offset in the range `0.0..=1.0`.
     pub offset: f32,
+    /// The keyframe's `animation-timing-function`, easing the way to the
+    /// next keyframe.
+    pub timing: Option<TimingFunction>,
     /// Property–value pairs that apply at this stop.
     pub properties: Vec<(String, AnimatableValue)>,
 }
@@ -127,7 +143,7 @@ pub struct KeyframeStop {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// A named `@keyframes` animation definition.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct KeyframeAnimation {
     pub name: String,
     pub keyframes: Vec<KeyframeStop>,
@@ -379,6 +395,862 @@ impl AnimationEngine {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Animated properties
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// What a change to an animated property makes stale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Invalidation {
+    /// Nothing changed.
+    None,
+    /// Boxes keep their geometry, but the display list must be rebuilt.
+    Paint,
+    /// Boxes must be laid out again.
+    Layout,
+}
+
+/// The properties animations interpolate, and what changing each one
+/// invalidates. Keyframes can't animate other properties yet.
+const ANIMATABLE_PROPERTIES: &[(&str, Invalidation)] = &[
+    ("opacity", Invalidation::Paint),
+    ("color", Invalidation::Paint),
+    ("background-color", Invalidation::Paint),
+    ("border-top-color", Invalidation::Paint),
+    ("border-right-color", Invalidation::Paint),
+    ("border-bottom-color", Invalidation::Paint),
+    ("border-left-color", Invalidation::Paint),
+    ("outline-color", Invalidation::Paint),
+    ("outline-width", Invalidation::Paint),
+    ("outline-offset", Invalidation::Paint),
+    ("transform", Invalidation::Paint),
+    ("width", Invalidation::Layout),
+    ("height", Invalidation::Layout),
+    ("min-width", Invalidation::Layout),
+    ("min-height", Invalidation::Layout),
+    ("max-width", Invalidation::Layout),
+    ("max-height", Invalidation::Layout),
+    ("margin-top", Invalidation::Layout),
+    ("margin-right", Invalidation::Layout),
+    ("margin-bottom", Invalidation::Layout),
+    ("margin-left", Invalidation::Layout),
+    ("padding-top", Invalidation::Layout),
+    ("padding-right", Invalidation::Layout),
+    ("padding-bottom", Invalidation::Layout),
+    ("padding-left", Invalidation::Layout),
+    ("border-top-width", Invalidation::Layout),
+    ("border-right-width", Invalidation::Layout),
+    ("border-bottom-width", Invalidation::Layout),
+    ("border-left-width", Invalidation::Layout),
+    ("top", Invalidation::Layout),
+    ("right", Invalidation::Layout),
+    ("bottom", Invalidation::Layout),
+    ("left", Invalidation::Layout),
+    ("font-size", Invalidation::Layout),
+    ("line-height", Invalidation::Layout),
+    ("letter-spacing", Invalidation::Layout),
+    ("word-spacing", Invalidation::Layout),
+    ("text-indent", Invalidation::Layout),
+    ("gap", Invalidation::Layout),
+];
+
+/// What a change to `property` invalidates; `None` if it isn't animatable.
+pub fn property_invalidation(property: &str) -> Invalidation {
+    ANIMATABLE_PROPERTIES
+        .iter()
+        .find(|(name, _)| *name == property)
+        .map_or(Invalidation::None, |&(_, invalidation)| invalidation)
+}
+
+/// Copy the values of the properties whose changes only need a repaint
+/// from `from` to `to`, as when animated styles reach boxes already laid
+/// out.
+pub fn copy_paint_properties(from: &ComputedStyle, to: &mut ComputedStyle) {
+    for &(property, invalidation) in ANIMATABLE_PROPERTIES {
+        if invalidation == Invalidation::Paint {
+            set_animated_value(to, property, &animated_value(from, property));
+        }
+    }
+}
+
+/// The animatable longhands a declaration of `property` sets.
+fn animatable_longhands(property: &str) -> Vec<&'static str> {
+    const SIDES: [&str; 4] = ["top", "right", "bottom", "left"];
+    let sided = |prefix: &str, suffix: &str| -> Vec<&'static str> {
+        SIDES
+            .iter()
+            .filter_map(|side| {
+                let name = format!("{prefix}{side}{suffix}");
+                ANIMATABLE_PROPERTIES.iter().find(|(p, _)| *p == name).map(|(p, _)| *p)
+            })
+            .collect()
+    };
+    match property {
+        "margin" => sided("margin-", ""),
+        "padding" => sided("padding-", ""),
+        "inset" => sided("", ""),
+        "border-color" => sided("border-", "-color"),
+        "border-width" => sided("border-", "-width"),
+        "border" => [sided("border-", "-width"), sided("border-", "-color")].concat(),
+        "border-top" | "border-right" | "border-bottom" | "border-left" => {
+            let width = format!("{property}-width");
+            let color = format!("{property}-color");
+            ANIMATABLE_PROPERTIES.iter().map(|(p, _)| *p).filter(|p| **p == width || **p == color).collect()
+        }
+        "outline" => vec!["outline-color", "outline-width"],
+        "background" => vec!["background-color"],
+        "grid-gap" => vec!["gap"],
+        _ => ANIMATABLE_PROPERTIES.iter().map(|(p, _)| *p).filter(|p| *p == property).collect(),
+    }
+}
+
+fn length_or_auto(value: Option<f32>) -> AnimatableValue {
+    value.map_or(AnimatableValue::None, AnimatableValue::Length)
+}
+
+/// The value of animatable `property` in `style`.
+pub fn animated_value(style: &ComputedStyle, property: &str) -> AnimatableValue {
+    match property {
+        "opacity" => AnimatableValue::Number(style.opacity as f64),
+        "color" => AnimatableValue::Color(style.color),
+        "background-color" => AnimatableValue::Color(style.background_color),
+        "border-top-color" => AnimatableValue::Color(style.border.top.color),
+        "border-right-color" => AnimatableValue::Color(style.border.right.color),
+        "border-bottom-color" => AnimatableValue::Color(style.border.bottom.color),
+        "border-left-color" => AnimatableValue::Color(style.border.left.color),
+        "outline-color" => AnimatableValue::Color(style.outline_color),
+        "outline-width" => AnimatableValue::Length(style.outline_width),
+        "outline-offset" => AnimatableValue::Length(style.outline_offset),
+        "transform" => AnimatableValue::Transform(style.transform.clone()),
+        "width" => length_or_auto(style.width),
+        "height" => length_or_auto(style.height),
+        "min-width" => length_or_auto(style.min_width),
+        "min-height" => length_or_auto(style.min_height),
+        "max-width" => length_or_auto(style.max_width),
+        "max-height" => length_or_auto(style.max_height),
+        "margin-top" => AnimatableValue::Length(style.margin.top),
+        "margin-right" => AnimatableValue::Length(style.margin.right),
+        "margin-bottom" => AnimatableValue::Length(style.margin.bottom),
+        "margin-left" => AnimatableValue::Length(style.margin.left),
+        "padding-top" => AnimatableValue::Length(style.padding.top),
+        "padding-right" => AnimatableValue::Length(style.padding.right),
+        "padding-bottom" => AnimatableValue::Length(style.padding.bottom),
+        "padding-left" => AnimatableValue::Length(style.padding.left),
+        "border-top-width" => AnimatableValue::Length(style.border.top.width),
+        "border-right-width" => AnimatableValue::Length(style.border.right.width),
+        "border-bottom-width" => AnimatableValue::Length(style.border.bottom.width),
+        "border-left-width" => AnimatableValue::Length(style.border.left.width),
+        "top" => length_or_auto(style.top),
+        "right" => length_or_auto(style.right),
+        "bottom" => length_or_auto(style.bottom),
+        "left" => length_or_auto(style.left),
+        "font-size" => AnimatableValue::Length(style.font_size_px),
+        "line-height" => AnimatableValue::Length(style.line_height_px),
+        "letter-spacing" => AnimatableValue::Length(style.letter_spacing),
+        "word-spacing" => AnimatableValue::Length(style.word_spacing),
+        "text-indent" => AnimatableValue::Length(style.text_indent),
+        "gap" => AnimatableValue::Length(style.gap),
+        _ => AnimatableValue::None,
+    }
+}
+
+/// Set animatable `property` of `style` to `value`, which came from
+/// [`animated_value`] for the same property.
+pub fn set_animated_value(style: &mut ComputedStyle, property: &str, value: &AnimatableValue) {
+    let length = || match value {
+        AnimatableValue::Length(px) => Some(*px),
+        _ => None,
+    };
+    match (property, value) {
+        ("opacity", AnimatableValue::Number(n)) => style.opacity = (*n as f32).clamp(0.0, 1.0),
+        ("color", AnimatableValue::Color(c)) => style.color = *c,
+        ("background-color", AnimatableValue::Color(c)) => style.background_color = *c,
+        ("border-top-color", AnimatableValue::Color(c)) => style.border.top.color = *c,
+        ("border-right-color", AnimatableValue::Color(c)) => style.border.right.color = *c,
+        ("border-bottom-color", AnimatableValue::Color(c)) => style.border.bottom.color = *c,
+        ("border-left-color", AnimatableValue::Color(c)) => style.border.left.color = *c,
+        ("outline-color", AnimatableValue::Color(c)) => style.outline_color = *c,
+        ("transform", AnimatableValue::Transform(t)) => style.transform = t.clone(),
+        ("width", _) => style.width = length(),
+        ("height", _) => style.height = length(),
+        ("min-width", _) => style.min_width = length(),
+        ("min-height", _) => style.min_height = length(),
+        ("max-width", _) => style.max_width = length(),
+        ("max-height", _) => style.max_height = length(),
+        ("top", _) => style.top = length(),
+        ("right", _) => style.right = length(),
+        ("bottom", _) => style.bottom = length(),
+        ("left", _) => style.left = length(),
+        (_, AnimatableValue::Length(px)) => {
+            let px = *px;
+            match property {
+                "outline-width" => style.outline_width = px.max(0.0),
+                "outline-offset" => style.outline_offset = px,
+                "margin-top" => style.margin.top = px,
+                "margin-right" => style.margin.right = px,
+                "margin-bottom" => style.margin.bottom = px,
+                "margin-left" => style.margin.left = px,
+                "padding-top" => style.padding.top = px.max(0.0),
+                "padding-right" => style.padding.right = px.max(0.0),
+                "padding-bottom" => style.padding.bottom = px.max(0.0),
+                "padding-left" => style.padding.left = px.max(0.0),
+                "border-top-width" => style.border.top.width = px.max(0.0),
+                "border-right-width" => style.border.right.width = px.max(0.0),
+                "border-bottom-width" => style.border.bottom.width = px.max(0.0),
+                "border-left-width" => style.border.left.width = px.max(0.0),
+                "font-size" => style.font_size_px = px.max(0.0),
+                "line-height" => style.line_height_px = px.max(0.0),
+                "letter-spacing" => style.letter_spacing = px,
+                "word-spacing" => style.word_spacing = px,
+                "text-indent" => style.text_indent = px,
+                "gap" => style.gap = px.max(0.0),
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Interpolate between two values of a property at `t`. Values that
+/// can't be blended (`auto`, transform lists that don't line up) flip
+/// halfway through.
+pub fn interpolate_value(from: &AnimatableValue, to: &AnimatableValue, t: f32) -> AnimatableValue {
+    let blended = match (from, to) {
+        (AnimatableValue::Number(a), AnimatableValue::Number(b)) => Some(AnimatableValue::Number(a + (b - a) * t as f64)),
+        (AnimatableValue::Length(a), AnimatableValue::Length(b)) => Some(AnimatableValue::Length(interpolate_f32(*a, *b, t))),
+        (AnimatableValue::Color(a), AnimatableValue::Color(b)) => Some(AnimatableValue::Color(interpolate_color(*a, *b, t))),
+        (AnimatableValue::Transform(a), AnimatableValue::Transform(b)) => {
+            interpolate_transforms(a, b, t).map(AnimatableValue::Transform)
+        }
+        _ => None,
+    };
+    blended.unwrap_or_else(|| if t < 0.5 { from.clone() } else { to.clone() })
+}
+
+/// The identity transform function of the same kind as `f`.
+fn identity_like(f: &TransformFunction) -> TransformFunction {
+    match f {
+        TransformFunction::Translate(..) => TransformFunction::Translate(0.0, 0.0),
+        TransformFunction::TranslateX(_) => TransformFunction::TranslateX(0.0),
+        TransformFunction::TranslateY(_) => TransformFunction::TranslateY(0.0),
+        TransformFunction::Scale(..) => TransformFunction::Scale(1.0, 1.0),
+        TransformFunction::ScaleX(_) => TransformFunction::ScaleX(1.0),
+        TransformFunction::ScaleY(_) => TransformFunction::ScaleY(1.0),
+        TransformFunction::Rotate(_) => TransformFunction::Rotate(0.0),
+        TransformFunction::SkewX(_) => TransformFunction::SkewX(0.0),
+        TransformFunction::SkewY(_) => TransformFunction::SkewY(0.0),
+        TransformFunction::Matrix(..) => TransformFunction::Matrix(1.0, 0.0, 0.0, 1.0, 0.0, 0.0),
+    }
+}
+
+/// Blend two transform lists function by function, padding the shorter
+/// with identities. `None` if the functions differ in kind.
+fn interpolate_transforms(a: &[TransformFunction], b: &[TransformFunction], t: f32) -> Option<Vec<TransformFunction>> {
+    use TransformFunction as F;
+    let lerp = |x: f32, y: f32| interpolate_f32(x, y, t);
+    (0..a.len().max(b.len()))
+        .map(|i| {
+            let (from, to) = match (a.get(i), b.get(i)) {
+                (Some(from), Some(to)) => (from.clone(), to.clone()),
+                (Some(from), None) => (from.clone(), identity_like(from)),
+                (None, Some(to)) => (identity_like(to), to.clone()),
+                (None, None) => unreachable!(),
+            };
+            Some(match (from, to) {
+                (F::Translate(x1, y1), F::Translate(x2, y2)) => F::Translate(lerp(x1, x2), lerp(y1, y2)),
+                (F::TranslateX(x1), F::TranslateX(x2)) => F::TranslateX(lerp(x1, x2)),
+                (F::TranslateY(y1), F::TranslateY(y2)) => F::TranslateY(lerp(y1, y2)),
+                (F::Scale(x1, y1), F::Scale(x2, y2)) => F::Scale(lerp(x1, x2), lerp(y1, y2)),
+                (F::ScaleX(x1), F::ScaleX(x2)) => F::ScaleX(lerp(x1, x2)),
+                (F::ScaleY(y1), F::ScaleY(y2)) => F::ScaleY(lerp(y1, y2)),
+                (F::Rotate(r1), F::Rotate(r2)) => F::Rotate(lerp(r1, r2)),
+                (F::SkewX(s1), F::SkewX(s2)) => F::SkewX(lerp(s1, s2)),
+                (F::SkewY(s1), F::SkewY(s2)) => F::SkewY(lerp(s1, s2)),
+                (F::Matrix(a1, b1, c1, d1, e1, f1), F::Matrix(a2, b2, c2, d2, e2, f2)) => F::Matrix(
+                    lerp(a1, a2),
+                    lerp(b1, b2),
+                    lerp(c1, c2),
+                    lerp(d1, d2),
+                    lerp(e1, e2),
+                    lerp(f1, f2),
+                ),
+                _ => return None,
+            })
+        })
+        .collect()
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Keyframes
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The `@keyframes` rules of `stylesheets` that apply to `device`, by
+/// name; of rules sharing a name, the last wins.
+pub fn keyframes_by_name<'a>(
+    stylesheets: &'a [(Stylesheet, StyleOrigin)],
+    device: &Device,
+) -> HashMap<&'a str, &'a KeyframesRule> {
+    let mut keyframes = HashMap::new();
+    for (sheet, _) in stylesheets {
+        for rule in &sheet.keyframes {
+            if rule.media.iter().all(|m| m.matches(device)) && rule.supports.iter().all(|s| s.matches(supports_declaration)) {
+                keyframes.insert(rule.name.as_str(), rule);
+            }
+        }
+    }
+    keyframes
+}
+
+impl KeyframeAnimation {
+    /// The keyframes of `rule` for an element whose unanimated style is
+    /// `base`: each keyframe's declarations are computed on top of it, the
+    /// way the cascade would, and keyframes at the same offset merge.
+    pub fn compute(
+        rule: &KeyframesRule,
+        base: &ComputedStyle,
+        parent: Option<&ComputedStyle>,
+        ctx: &ResolveContext,
+    ) -> Self {
+        let mut stops: Vec<KeyframeStop> = Vec::new();
+        for keyframe in &rule.keyframes {
+            let mut style = base.clone();
+            let mut timing = None;
+            let mut longhands = Vec::new();
+            for decl in &keyframe.declarations {
+                let name = strip_vendor_prefix(&decl.name);
+                if name == "animation-timing-function" {
+                    timing = parse_timing_function(&decl.value).or(timing);
+                    continue;
+                }
+                if name.starts_with("animation") || name.starts_with("--") {
+                    continue;
+                }
+                let values = resolve_css_values(&decl.value, ctx);
+                let values = resolve_property_percentages(&decl.name, &values, ctx);
+                let values = resolve_remaining_calcs(&values, ctx);
+                let resolved = Declaration { name: decl.name.clone(), value: values, important: false };
+                apply_declaration(&mut style, &resolved, parent);
+                longhands.extend(animatable_longhands(&name));
+            }
+            let properties: Vec<(String, AnimatableValue)> =
+                longhands.iter().map(|p| (p.to_string(), animated_value(&style, p))).collect();
+            match stops.last_mut() {
+                Some(last) if last.offset == keyframe.offset => {
+                    for (property, value) in properties {
+                        match last.properties.iter_mut().find(|(p, _)| *p == property) {
+                            Some(existing) => existing.1 = value,
+                            None => last.properties.push((property, value)),
+                        }
+                    }
+                    last.timing = timing.or(last.timing.take());
+                }
+                _ => stops.push(KeyframeStop { offset: keyframe.offset, timing, properties }),
+            }
+        }
+        KeyframeAnimation { name: rule.name.clone(), keyframes: stops }
+    }
+
+    /// Every property some keyframe sets, once each.
+    pub fn properties(&self) -> Vec<&str> {
+        let mut properties: Vec<&str> = Vec::new();
+        for (property, _) in self.keyframes.iter().flat_map(|k| &k.properties) {
+            if !properties.contains(&property.as_str()) {
+                properties.push(property);
+            }
+        }
+        properties
+    }
+
+    /// The value of each property at iteration progress `progress`. A
+    /// property blends between the keyframes that set it on either side,
+    /// eased by the earlier one's timing function, or `timing` if it has
+    /// none; where no keyframe sets it at 0% or 100%, `base` stands in.
+    pub fn sample(&self, progress: f32, timing: &TimingFunction, base: &ComputedStyle) -> Vec<(String, AnimatableValue)> {
+        self.properties()
+            .into_iter()
+            .map(|property| {
+                let value_at = |stop: &KeyframeStop| {
+                    stop.properties.iter().find(|(p, _)| p == property).map(|(_, v)| v.clone())
+                };
+                let from = self
+                    .keyframes
+                    .iter()
+                    .rev()
+                    .filter(|k| k.offset <= progress)
+                    .find_map(|k| Some((k.offset, value_at(k)?, k.timing.as_ref())))
+                    .unwrap_or_else(|| (0.0, animated_value(base, property), None));
+                let to = self
+                    .keyframes
+                    .iter()
+                    .filter(|k| k.offset > from.0 && k.offset >= progress)
+                    .find_map(|k| Some((k.offset, value_at(k)?)))
+                    .unwrap_or_else(|| (1.0, animated_value(base, property)));
+                let span = to.0 - from.0;
+                let local = if span > 0.0 { (progress - from.0) / span } else { 0.0 };
+                let eased = evaluate_timing(from.2.unwrap_or(timing), local);
+                (property.to_string(), interpolate_value(&from.1, &to.1, eased))
+            })
+            .collect()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// DocumentAnimations
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// An animation running on an element.
+#[derive(Debug, Clone)]
+pub struct ElementAnimation {
+    pub node: NodeId,
+    pub state: AnimationState,
+    pub keyframes: KeyframeAnimation,
+    /// Whether `animationstart` has fired.
+    started: bool,
+    /// Whether `animationend` has fired.
+    ended: bool,
+}
+
+impl ElementAnimation {
+    /// The values the animation gives its properties now, over `base`;
+    /// `None` while it has no effect (in its delay or after it ends,
+    /// unless filling).
+    fn current_values(&self, base: &ComputedStyle) -> Option<Vec<(String, AnimatableValue)>> {
+        let state = &self.state;
+        let progress = if state.elapsed_ms < state.delay_ms {
+            if !matches!(state.fill_mode, AnimationFillMode::Backwards | AnimationFillMode::Both) {
+                return None;
+            }
+            match state.direction {
+                AnimationDirection::Reverse | AnimationDirection::AlternateReverse => 1.0,
+                AnimationDirection::Normal | AnimationDirection::Alternate => 0.0,
+            }
+        } else if state.is_finished() {
+            if !matches!(state.fill_mode, AnimationFillMode::Forwards | AnimationFillMode::Both) {
+                return None;
+            }
+            state.progress()
+        } else {
+            state.progress()
+        };
+        Some(self.keyframes.sample(progress as f32, &state.timing, base))
+    }
+}
+
+/// A transition running on an element.
+#[derive(Debug, Clone)]
+pub struct ElementTransition {
+    pub node: NodeId,
+    pub property: String,
+    pub from: AnimatableValue,
+    pub to: AnimatableValue,
+    pub duration_ms: f64,
+    pub delay_ms: f64,
+    pub timing: TimingFunction,
+    /// Time since the transition started, including its delay.
+    pub elapsed_ms: f64,
+    /// The value a transition back has to return to: `from`, unless this
+    /// transition itself reverses one, whose end it is.
+    reversing_adjusted_start: AnimatableValue,
+    /// How much of its duration the transition runs: less than 1 when it
+    /// reverses one that hadn't finished, so going back takes as long as
+    /// getting there did.
+    reversing_shortening_factor: f64,
+    /// Whether `transitionend` has fired.
+    ended: bool,
+}
+
+impl ElementTransition {
+    /// The eased progress; the start value holds through the delay.
+    fn output_progress(&self) -> f32 {
+        let active_ms = self.elapsed_ms - self.delay_ms;
+        let progress = if active_ms < 0.0 {
+            0.0
+        } else if self.duration_ms > 0.0 {
+            (active_ms / self.duration_ms).min(1.0)
+        } else {
+            1.0
+        };
+        evaluate_timing(&self.timing, progress as f32)
+    }
+
+    fn current_value(&self) -> AnimatableValue {
+        interpolate_value(&self.from, &self.to, self.output_progress())
+    }
+
+    fn is_finished(&self) -> bool {
+        self.elapsed_ms >= self.delay_ms + self.duration_ms
+    }
+}
+
+/// Which animation or transition event fired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationEventKind {
+    Start,
+    End,
+    TransitionEnd,
+}
+
+impl AnimationEventKind {
+    /// The DOM event type.
+    pub fn event_type(self) -> &'static str {
+        match self {
+            AnimationEventKind::Start => "animationstart",
+            AnimationEventKind::End => "animationend",
+            AnimationEventKind::TransitionEnd => "transitionend",
+        }
+    }
+}
+
+/// An `animationstart`, `animationend` or `transitionend` event for an
+/// element.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationEvent {
+    pub kind: AnimationEventKind,
+    pub node: NodeId,
+    /// The animation's name, or the property that transitioned.
+    pub name: String,
+    /// Seconds the animation or transition had run, not counting its delay.
+    pub elapsed_time: f64,
+}
+
+/// The CSS animations and transitions of one document. Each pass of the
+/// cascade starts, updates and cancels them; the frame loop advances them
+/// and writes their values over the cascade's styles.
+#[derive(Debug, Clone, Default)]
+pub struct DocumentAnimations {
+    /// In document order, each element's in `animation-name` order.
+    animations: Vec<ElementAnimation>,
+    /// In document order, each element's in property table order.
+    transitions: Vec<ElementTransition>,
+    /// The elements with animations or transitions, in document order.
+    nodes: Vec<NodeId>,
+    /// The unanimated styles of those elements, and of the elements under
+    /// them that may inherit animated values.
+    base: HashMap<NodeId, ComputedStyle>,
+}
+
+impl DocumentAnimations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn animations(&self) -> &[ElementAnimation] {
+        &self.animations
+    }
+
+    pub fn transitions(&self) -> &[ElementTransition] {
+        &self.transitions
+    }
+
+    /// Whether anything still needs frames: an animation not paused that
+    /// hasn't ended, or a transition that hasn't.
+    pub fn is_active(&self) -> bool {
+        self.animations.iter().any(|a| !a.ended && a.state.play_state == AnimationPlayState::Running)
+            || self.transitions.iter().any(|t| !t.ended)
+    }
+
+    /// Match the animations and transitions to the unanimated `styles` the
+    /// cascade just computed for the document under `root`.
+    ///
+    /// An element keeps the timeline of an animation it still names;
+    /// others start from zero, and animations no longer named are dropped.
+    /// A property whose value differs from its value in `before`, the
+    /// styles of the previous pass as last drawn, transitions from there
+    /// if the element's `transition-*` properties say so. Elements that
+    /// aren't rendered, or weren't, don't animate or transition.
+    pub fn update(
+        &mut self,
+        dom: &Dom,
+        root: NodeId,
+        before: &HashMap<NodeId, ComputedStyle>,
+        styles: &HashMap<NodeId, ComputedStyle>,
+        keyframes: &HashMap<&str, &KeyframesRule>,
+        ctx: &ResolveContext,
+    ) {
+        let mut previous = std::mem::take(&mut self.animations);
+        let mut previous_transitions: Vec<ElementTransition> =
+            std::mem::take(&mut self.transitions).into_iter().filter(|t| !t.ended).collect();
+        self.nodes.clear();
+        self.base.clear();
+        let rendered = |styles: &HashMap<NodeId, ComputedStyle>, node: NodeId| {
+            let hidden = |id: &NodeId| styles.get(id).is_some_and(|s| s.display == Display::None);
+            styles.contains_key(&node) && !hidden(&node) && !dom.ancestors(node).iter().any(hidden)
+        };
+        for node in dom.descendants(root) {
+            if !dom.nodes.get(node).is_some_and(|n| n.is_element()) {
+                continue;
+            }
+            let Some(style) = styles.get(&node).filter(|s| !s.animation_names.is_empty() || has_transitions(s)) else {
+                continue;
+            };
+            if !rendered(styles, node) {
+                continue;
+            }
+
+            let parent = dom.nodes.get(node).and_then(|n| n.parent).and_then(|p| styles.get(&p));
+            let first = self.animations.len();
+            for (i, name) in style.animation_names.iter().enumerate() {
+                let Some(rule) = keyframes.get(name.as_str()) else {
+                    continue;
+                };
+                let state = animation_state(style, i);
+                let animation = match previous.iter().position(|a| a.node == node && a.state.animation_name == *name) {
+                    Some(at) => {
+                        let kept = previous.remove(at);
+                        ElementAnimation {
+                            state: AnimationState { elapsed_ms: kept.state.elapsed_ms, iteration: kept.state.iteration, ..state },
+                            ..kept
+                        }
+                    }
+                    None => ElementAnimation {
+                        node,
+                        state,
+                        keyframes: KeyframeAnimation::default(),
+                        started: false,
+                        ended: false,
+                    },
+                };
+                self.animations.push(ElementAnimation {
+                    keyframes: KeyframeAnimation::compute(rule, style, parent, ctx),
+                    ..animation
+                });
+            }
+
+            // Animations override transitions of the properties they animate.
+            let animated: Vec<String> =
+                self.animations[first..].iter().flat_map(|a| a.keyframes.properties()).map(String::from).collect();
+            let first_transition = self.transitions.len();
+            let before_style = before.get(&node).filter(|_| rendered(before, node));
+            for &(property, _) in ANIMATABLE_PROPERTIES {
+                let mut running = previous_transitions
+                    .iter()
+                    .position(|t| t.node == node && t.property == property)
+                    .map(|at| previous_transitions.remove(at));
+                let Some(before_style) = before_style.filter(|_| !animated.iter().any(|p| p == property)) else {
+                    continue;
+                };
+                let value = animated_value(style, property);
+                if let Some(running) = running.take_if(|t| t.to == value) {
+                    self.transitions.push(running);
+                    continue;
+                }
+                let current = animated_value(before_style, property);
+                let Some((duration_ms, delay_ms, timing)) = transition_settings(style, property) else {
+                    continue;
+                };
+                if current == value || !can_interpolate(&current, &value) {
+                    continue;
+                }
+                // Going back to where a running transition started takes as
+                // long as it took to get here.
+                let (reversing_adjusted_start, factor) = match running {
+                    Some(t) if t.reversing_adjusted_start == value => {
+                        let progress = t.output_progress() as f64;
+                        let factor = progress * t.reversing_shortening_factor + (1.0 - t.reversing_shortening_factor);
+                        (t.to, factor.clamp(0.0, 1.0))
+                    }
+                    _ => (current.clone(), 1.0),
+                };
+                self.transitions.push(ElementTransition {
+                    node,
+                    property: property.to_string(),
+                    from: current,
+                    to: value,
+                    duration_ms: duration_ms * factor,
+                    delay_ms: if delay_ms < 0.0 { delay_ms * factor } else { delay_ms },
+                    timing,
+                    elapsed_ms: 0.0,
+                    reversing_adjusted_start,
+                    reversing_shortening_factor: factor,
+                    ended: false,
+                });
+            }
+
+            let transitioned = self.transitions[first_transition..].iter().map(|t| t.property.as_str());
+            if first == self.animations.len() && first_transition == self.transitions.len() {
+                continue;
+            }
+            let inherits = animated.iter().map(String::as_str).chain(transitioned).any(is_inherited_property);
+            self.nodes.push(node);
+            self.base.insert(node, style.clone());
+            if inherits {
+                for descendant in dom.descendants(node) {
+                    if let Some(style) = styles.get(&descendant) {
+                        self.base.insert(descendant, style.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Advance running animations and transitions by `delta_ms`, returning
+    /// the events that fired, in order.
+    pub fn tick(&mut self, delta_ms: f64) -> Vec<AnimationEvent> {
+        let mut events = Vec::new();
+        for animation in &mut self.animations {
+            let state = &mut animation.state;
+            if state.play_state == AnimationPlayState::Running {
+                state.elapsed_ms += delta_ms;
+                if state.duration_ms > 0.0 && state.elapsed_ms > state.delay_ms {
+                    state.iteration = ((state.elapsed_ms - state.delay_ms) / state.duration_ms) as u32;
+                }
+            }
+            let event = |kind, elapsed_time| AnimationEvent {
+                kind,
+                node: animation.node,
+                name: state.animation_name.clone(),
+                elapsed_time,
+            };
+            if !animation.started && state.elapsed_ms >= state.delay_ms {
+                animation.started = true;
+                events.push(event(AnimationEventKind::Start, (-state.delay_ms).max(0.0) / 1000.0));
+            }
+            if animation.started && !animation.ended && state.is_finished() {
+                animation.ended = true;
+                events.push(event(AnimationEventKind::End, state.duration_ms * state.iteration_count / 1000.0));
+            }
+        }
+        for transition in self.transitions.iter_mut().filter(|t| !t.ended) {
+            transition.elapsed_ms += delta_ms;
+            if transition.is_finished() {
+                transition.ended = true;
+                events.push(AnimationEvent {
+                    kind: AnimationEventKind::TransitionEnd,
+                    node: transition.node,
+                    name: transition.property.clone(),
+                    elapsed_time: transition.duration_ms / 1000.0,
+                });
+            }
+        }
+        events
+    }
+
+    /// Write the current values of the animations and transitions over
+    /// `styles`, which hold the document's styles as of the last call (or
+    /// the unanimated ones). Transitions win over animations, and elements
+    /// that inherit an animated value get it too. Returns what the changes
+    /// invalidate.
+    pub fn apply(&self, dom: &Dom, styles: &mut HashMap<NodeId, ComputedStyle>) -> Invalidation {
+        // New styles, with the properties animations touched in each.
+        let mut updated: HashMap<NodeId, (ComputedStyle, Vec<&str>)> = HashMap::new();
+        let mut order: Vec<NodeId> = Vec::new();
+        for &node in &self.nodes {
+            let Some(base) = self.base.get(&node) else {
+                continue;
+            };
+            let (mut style, mut touched) = updated.remove(&node).unwrap_or_else(|| (base.clone(), Vec::new()));
+            let mut own = Vec::new();
+            for animation in self.animations.iter().filter(|a| a.node == node) {
+                own.extend(animation.keyframes.properties());
+                for (property, value) in animation.current_values(base).unwrap_or_default() {
+                    set_animated_value(&mut style, &property, &value);
+                }
+            }
+            for transition in self.transitions.iter().filter(|t| t.node == node) {
+                own.push(transition.property.as_str());
+                set_animated_value(&mut style, &transition.property, &transition.current_value());
+            }
+            own.sort_unstable();
+            own.dedup();
+            for &property in own.iter().filter(|p| is_inherited_property(p)) {
+                let base_value = animated_value(base, property);
+                let value = animated_value(&style, property);
+                for descendant in dom.descendants(node) {
+                    let Some(descendant_base) = self.base.get(&descendant) else {
+                        continue;
+                    };
+                    // Only elements that inherited the base value inherit the animated one.
+                    if animated_value(descendant_base, property) != base_value {
+                        continue;
+                    }
+                    let entry = updated.entry(descendant).or_insert_with(|| {
+                        order.push(descendant);
+                        (descendant_base.clone(), Vec::new())
+                    });
+                    set_animated_value(&mut entry.0, property, &value);
+                    entry.1.push(property);
+                }
+            }
+            touched.extend(own);
+            if !order.contains(&node) {
+                order.push(node);
+            }
+            updated.insert(node, (style, touched));
+        }
+
+        let mut invalidation = Invalidation::None;
+        for node in order {
+            let Some((style, touched)) = updated.remove(&node) else {
+                continue;
+            };
+            if let Some(old) = styles.get(&node) {
+                for property in touched {
+                    if animated_value(old, property) != animated_value(&style, property) {
+                        invalidation = invalidation.max(property_invalidation(property));
+                    }
+                }
+            }
+            styles.insert(node, style);
+        }
+        invalidation
+    }
+}
+
+/// The `index`th item of one of a style's comma-separated lists, which
+/// repeat to cover as many items as needed.
+fn repeating<T: Clone>(list: &[T], index: usize, fallback: T) -> T {
+    if list.is_empty() { fallback } else { list[index % list.len()].clone() }
+}
+
+/// The state of the `index`th animation `style` names, not yet started.
+fn animation_state(style: &ComputedStyle, index: usize) -> AnimationState {
+    AnimationState {
+        animation_name: style.animation_names[index].clone(),
+        duration_ms: repeating(&style.animation_durations_ms, index, 0.0),
+        delay_ms: repeating(&style.animation_delays_ms, index, 0.0),
+        iteration_count: repeating(&style.animation_iteration_counts, index, 1.0),
+        direction: repeating(&style.animation_directions, index, AnimationDirection::Normal),
+        fill_mode: repeating(&style.animation_fill_modes, index, AnimationFillMode::None),
+        timing: repeating(&style.animation_timing_functions, index, TimingFunction::Ease),
+        play_state: repeating(&style.animation_play_states, index, AnimationPlayState::Running),
+        elapsed_ms: 0.0,
+        iteration: 0,
+    }
+}
+
+/// Whether `style` transitions any property over some time.
+fn has_transitions(style: &ComputedStyle) -> bool {
+    style.transition_properties.iter().enumerate().any(|(i, property)| {
+        property != "none"
+            && repeating(&style.transition_durations_ms, i, 0.0) + repeating(&style.transition_delays_ms, i, 0.0) > 0.0
+    })
+}
+
+/// The duration, delay and timing function with which `style` transitions
+/// `property`, from the last `transition-property` item naming it (or a
+/// shorthand of it, or `all`). `None` if it doesn't, or over no time.
+fn transition_settings(style: &ComputedStyle, property: &str) -> Option<(f64, f64, TimingFunction)> {
+    let index = style
+        .transition_properties
+        .iter()
+        .rposition(|name| name == "all" || animatable_longhands(name).contains(&property))?;
+    let duration_ms = repeating(&style.transition_durations_ms, index, 0.0);
+    let delay_ms = repeating(&style.transition_delays_ms, index, 0.0);
+    let timing = repeating(&style.transition_timing_functions, index, TimingFunction::Ease);
+    (duration_ms + delay_ms > 0.0).then_some((duration_ms, delay_ms, timing))
+}
+
+/// Whether [`interpolate_value`] blends `a` into `b` rather than flipping.
+fn can_interpolate(a: &AnimatableValue, b: &AnimatableValue) -> bool {
+    match (a, b) {
+        (AnimatableValue::Number(_), AnimatableValue::Number(_))
+        | (AnimatableValue::Length(_), AnimatableValue::Length(_))
+        | (AnimatableValue::Color(_), AnimatableValue::Color(_)) => true,
+        (AnimatableValue::Transform(a), AnimatableValue::Transform(b)) => interpolate_transforms(a, b, 0.0).is_some(),
+        _ => false,
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -538,4 +1410,172 @@ mod tests {
         assert!((interpolate_f32(-10.0, 10.0, 0.25) - (-5.0)).abs() < 1e-6);
         assert!((interpolate_f32(3.0, 3.0, 0.7) - 3.0).abs() < 1e-6);
     }
+
+    // -- DocumentAnimations --
+
+    /// A `<div>` in a body, styled by `css`, with its animations started.
+    fn animated_div(css: &str) -> (Dom, NodeId, HashMap<NodeId, ComputedStyle>, DocumentAnimations) {
+        let mut dom = Dom::new();
+        let doc = dom.create_document();
+        let body = dom.create_html_element("body");
+        let div = dom.create_html_element("div");
+        let text = dom.create_text("moving");
+        dom.append_child(doc, body);
+        dom.append_child(body, div);
+        dom.append_child(div, text);
+
+        let sheets = vec![(css::parse_stylesheet(css), StyleOrigin::Author)];
+        let mut ctx = ResolveContext::new(800.0, 600.0);
+        let mut styles = HashMap::new();
+        styles.insert(doc, ComputedStyle::default());
+        for node in dom.descendants(doc) {
+            let parent = dom.nodes.get(node).and_then(|n| n.parent).and_then(|p| styles.get(&p)).cloned();
+            let style = if node == text {
+                parent.unwrap_or_default()
+            } else {
+                let matched = crate::cascade::collect_matching_rules(&dom, node, &sheets);
+                crate::cascade::resolve_style(&dom, node, &matched, parent.as_ref(), &mut ctx)
+            };
+            styles.insert(node, style);
+        }
+        let device = Device::screen(800.0, 600.0);
+        let mut animations = DocumentAnimations::new();
+        animations.update(&dom, doc, &HashMap::new(), &styles, &keyframes_by_name(&sheets, &device), &ctx);
+        animations.apply(&dom, &mut styles);
+        (dom, div, styles, animations)
+    }
+
+    #[test]
+    fn keyframes_are_sampled_with_per_keyframe_timing() {
+        let (dom, div, mut styles, mut animations) = animated_div(
+            "div { opacity: 0.5; width: 100px; animation: grow 1s linear; }
+             @keyframes grow { from { width: 0; animation-timing-function: steps(2) } 50% { width: 200px } }",
+        );
+        assert_eq!(styles[&div].width, Some(0.0));
+
+        animations.tick(200.0);
+        assert_eq!(animations.apply(&dom, &mut styles), Invalidation::None, "steps(2) holds the first step");
+        animations.tick(300.0);
+        assert_eq!(animations.apply(&dom, &mut styles), Invalidation::Layout);
+        assert_eq!(styles[&div].width, Some(200.0));
+        // From 50% on, the implicit 100% keyframe is the element's own style.
+        animations.tick(250.0);
+        animations.apply(&dom, &mut styles);
+        assert_eq!(styles[&div].width, Some(150.0));
+        assert_eq!(styles[&div].opacity, 0.5);
+
+        animations.tick(250.0);
+        animations.apply(&dom, &mut styles);
+        assert_eq!(styles[&div].width, Some(100.0));
+        assert!(!animations.is_active());
+    }
+
+    #[test]
+    fn fill_modes_and_events() {
+        let (dom, div, mut styles, mut animations) = animated_div(
+            "div { animation: fade 1s linear 500ms both; }
+             @keyframes fade { from { opacity: 0.2 } to { opacity: 0.8 } }",
+        );
+        // Filling backwards through the delay.
+        assert_eq!(styles[&div].opacity, 0.2);
+        assert!(animations.tick(400.0).is_empty());
+
+        let events = animations.tick(100.0);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, AnimationEventKind::Start);
+        assert_eq!(events[0].name, "fade");
+        assert_eq!(events[0].node, div);
+
+        animations.tick(500.0);
+        assert_eq!(animations.apply(&dom, &mut styles), Invalidation::Paint);
+        assert!((styles[&div].opacity - 0.5).abs() < 1e-6);
+
+        let events = animations.tick(600.0);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind.event_type(), "animationend");
+        assert_eq!(events[0].elapsed_time, 1.0);
+        // Filling forwards once done.
+        animations.apply(&dom, &mut styles);
+        assert_eq!(styles[&div].opacity, 0.8);
+        assert!(animations.tick(16.0).is_empty());
+    }
+
+    #[test]
+    fn inherited_values_reach_descendants() {
+        let (dom, div, mut styles, mut animations) = animated_div(
+            "div { color: black; animation: flash 1s linear; }
+             @keyframes flash { to { color: white } }",
+        );
+        let text = dom.children(div)[0];
+        animations.tick(500.0);
+        assert_eq!(animations.apply(&dom, &mut styles), Invalidation::Paint);
+        assert_eq!(styles[&div].color, Color::rgb(128, 128, 128));
+        assert_eq!(styles[&text].color, Color::rgb(128, 128, 128));
+    }
+
+    #[test]
+    fn transforms_interpolate_function_by_function() {
+        let from = AnimatableValue::Transform(vec![TransformFunction::TranslateX(0.0)]);
+        let to = AnimatableValue::Transform(vec![TransformFunction::TranslateX(100.0), TransformFunction::Rotate(90.0)]);
+        assert_eq!(
+            interpolate_value(&from, &to, 0.5),
+            AnimatableValue::Transform(vec![TransformFunction::TranslateX(50.0), TransformFunction::Rotate(45.0)]),
+        );
+        let scale = AnimatableValue::Transform(vec![TransformFunction::Scale(2.0, 2.0)]);
+        assert_eq!(interpolate_value(&from, &scale, 0.4), from);
+        assert_eq!(interpolate_value(&from, &scale, 0.6), scale);
+    }
+
+    // -- Transitions --
+
+    /// Restyle the element whose styles `styles` holds as `update` would
+    /// after a cascade pass gave it `css`.
+    fn restyle(
+        dom: &Dom,
+        node: NodeId,
+        css: &str,
+        styles: &mut HashMap<NodeId, ComputedStyle>,
+        animations: &mut DocumentAnimations,
+    ) -> Invalidation {
+        let sheets = vec![(css::parse_stylesheet(css), StyleOrigin::Author)];
+        let mut ctx = ResolveContext::new(800.0, 600.0);
+        let matched = crate::cascade::collect_matching_rules(dom, node, &sheets);
+        let mut after = styles.clone();
+        after.insert(node, crate::cascade::resolve_style(dom, node, &matched, None, &mut ctx));
+        let root = dom.nodes.get(node).and_then(|n| n.parent).unwrap();
+        animations.update(dom, root, styles, &after, &HashMap::new(), &ctx);
+        let invalidation = animations.apply(dom, &mut after);
+        *styles = after;
+        invalidation
+    }
+
+    #[test]
+    fn transitions_start_interrupt_and_end() {
+        let transition = "transition: opacity 1s linear, width 2s linear 1s;";
+        let (dom, div, mut styles, mut animations) = animated_div(&format!("div {{ opacity: 0; width: 0; {transition} }}"));
+        assert!(animations.transitions().is_empty());
+
+        restyle(&dom, div, &format!("div {{ opacity: 1; width: 100px; {transition} }}"), &mut styles, &mut animations);
+        assert_eq!(animations.transitions().len(), 2);
+        assert_eq!(styles[&div].opacity, 0.0);
+        animations.tick(500.0);
+        assert_eq!(animations.apply(&dom, &mut styles), Invalidation::Paint, "width waits out its delay");
+        assert_eq!(styles[&div].opacity, 0.5);
+
+        // Interrupted, opacity heads for its new value from where it is.
+        restyle(&dom, div, &format!("div {{ opacity: 0.75; width: 100px; {transition} }}"), &mut styles, &mut animations);
+        assert_eq!(styles[&div].opacity, 0.5);
+        let events = animations.tick(1000.0);
+        assert_eq!(events.len(), 1);
+        assert_eq!((events[0].kind.event_type(), events[0].name.as_str()), ("transitionend", "opacity"));
+        assert_eq!(animations.apply(&dom, &mut styles), Invalidation::Layout);
+        assert_eq!(styles[&div].opacity, 0.75);
+        assert_eq!(styles[&div].width, Some(25.0));
+
+        // Values that can't blend, and properties not listed, change at once.
+        restyle(&dom, div, &format!("div {{ opacity: 0.75; width: auto; color: red; {transition} }}"), &mut styles, &mut animations);
+        assert_eq!(styles[&div].width, None);
+        assert_eq!(styles[&div].color, Color::rgb(255, 0, 0));
+        assert!(!animations.is_active());
+    }
 }
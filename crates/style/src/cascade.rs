@@ -6,13 +6,17 @@
 use std::collections::HashMap;
 
 use css::{
-    Declaration, Specificity, Stylesheet, compute_specificity,
+    ContainerQuery, Declaration, Device, Specificity, Stylesheet, compute_specificity,
     CssValue, CssColor, LengthUnit,
 };
 use dom::{Dom, NodeId};
 
 use crate::computed::*;
+use crate::animation::{
+    AnimationDirection, AnimationFillMode, AnimationPlayState, StepPosition, TimingFunction,
+};
 use crate::matching::matches_selector;
+use crate::supports::supports_declaration;
 use common::{Color, Edges};
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -20,7 +24,7 @@ use common::{Color, Edges};
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// The origin of a CSS rule (determines cascade priority).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum StyleOrigin {
     UserAgent,
     User,
@@ -36,10 +40,25 @@ pub enum StyleOrigin {
 pub struct MatchedRule {
     pub specificity: Specificity,
     pub origin: StyleOrigin,
+    /// Where the rule's cascade layer ranks within its origin; higher
+    /// wins for normal declarations and loses for `!important` ones.
+    pub layer: usize,
     pub source_order: usize,
     pub declarations: Vec<Declaration>,
 }
 
+/// A query container for `@container` rules: an element whose
+/// `container-type` isn't `normal`, measured after layout.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryContainer {
+    /// Its `container-name`s.
+    pub names: Vec<String>,
+    /// The width of its content box; `None` unless it contains that axis.
+    pub width: Option<f32>,
+    /// The height of its content box; `None` unless `container-type: size`.
+    pub height: Option<f32>,
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // ResolveContext — viewport dimensions + custom properties for var() resolution
 // ─────────────────────────────────────────────────────────────────────────────
@@ -251,17 +270,53 @@ pub fn resolve_remaining_calcs(values: &[CssValue], ctx: &ResolveContext) -> Vec
 // Collect matching rules
 // ─────────────────────────────────────────────────────────────────────────────
 
-/// Collect all rules from the given stylesheets that match `node_id`.
+/// Collect all rules from the given stylesheets that match `node_id`,
+/// evaluating `@media` rules for a default desktop screen.
 pub fn collect_matching_rules(
     dom: &Dom,
     node_id: NodeId,
     stylesheets: &[(Stylesheet, StyleOrigin)],
+) -> Vec<MatchedRule> {
+    collect_matching_rules_for_device(dom, node_id, stylesheets, &Device::default())
+}
+
+/// Collect the rules that match `node_id`, whose media queries match
+/// `device` and whose `@supports` conditions hold. Rules in `@container`
+/// rules don't match, as there are no query containers before layout.
+pub fn collect_matching_rules_for_device(
+    dom: &Dom,
+    node_id: NodeId,
+    stylesheets: &[(Stylesheet, StyleOrigin)],
+    device: &Device,
+) -> Vec<MatchedRule> {
+    collect_matching_rules_in(dom, node_id, stylesheets, device, &HashMap::new())
+}
+
+/// [`collect_matching_rules_for_device`], testing the queries of
+/// `@container` rules against `containers`, the query containers of the
+/// last layout by element.
+pub fn collect_matching_rules_in(
+    dom: &Dom,
+    node_id: NodeId,
+    stylesheets: &[(Stylesheet, StyleOrigin)],
+    device: &Device,
+    containers: &HashMap<NodeId, QueryContainer>,
 ) -> Vec<MatchedRule> {
     let mut matched = Vec::new();
     let mut source_order = 0usize;
+    let layer_ranks = match stylesheets.iter().any(|(sheet, _)| !sheet.layers.is_empty()) {
+        true => layer_ranks(stylesheets),
+        false => HashMap::new(),
+    };
 
     for (stylesheet, origin) in stylesheets {
         for rule in &stylesheet.rules {
+            if !rule.applies_to(device)
+                || !rule.supports.iter().all(|s| s.matches(supports_declaration))
+                || !rule.containers.iter().all(|q| container_query_matches(dom, node_id, q, containers))
+            {
+                continue;
+            }
             // Check if any selector in the rule's selector list matches.
             let mut best_spec: Option<Specificity> = None;
             for selector in &rule.selectors {
@@ -279,6 +334,10 @@ pub fn collect_matching_rules(
                 matched.push(MatchedRule {
                     specificity,
                     origin: *origin,
+                    layer: match layer_ranks.is_empty() {
+                        true => 0,
+                        false => layer_ranks.get(&(*origin, rule.layer.clone())).copied().unwrap_or(0),
+                    },
                     source_order,
                     declarations: rule.declarations.clone(),
                 });
@@ -290,6 +349,83 @@ pub fn collect_matching_rules(
     matched
 }
 
+/// Where each cascade layer of `stylesheets` ranks within its origin.
+/// Layers declared earlier rank lower, a layer's sublayers rank below
+/// the rules directly in it, and unlayered rules (the empty path) rank
+/// highest.
+fn layer_ranks(stylesheets: &[(Stylesheet, StyleOrigin)]) -> HashMap<(StyleOrigin, Vec<String>), usize> {
+    // Each origin's layer tree, as (path, children) nodes with the
+    // unlayered root first.
+    type LayerTree = Vec<(Vec<String>, Vec<usize>)>;
+    let mut trees: Vec<(StyleOrigin, LayerTree)> = Vec::new();
+    for (stylesheet, origin) in stylesheets {
+        let index = match trees.iter().position(|(o, _)| o == origin) {
+            Some(index) => index,
+            None => {
+                trees.push((*origin, vec![(Vec::new(), Vec::new())]));
+                trees.len() - 1
+            }
+        };
+        let tree = &mut trees[index].1;
+        for path in &stylesheet.layers {
+            let mut node = 0;
+            for depth in 1..=path.len() {
+                let prefix = &path[..depth];
+                node = match tree[node].1.iter().copied().find(|&child| tree[child].0 == prefix) {
+                    Some(child) => child,
+                    None => {
+                        tree.push((prefix.to_vec(), Vec::new()));
+                        let child = tree.len() - 1;
+                        tree[node].1.push(child);
+                        child
+                    }
+                };
+            }
+        }
+    }
+
+    fn rank_post_order(
+        tree: &LayerTree,
+        node: usize,
+        origin: StyleOrigin,
+        ranks: &mut HashMap<(StyleOrigin, Vec<String>), usize>,
+    ) {
+        for &child in &tree[node].1 {
+            rank_post_order(tree, child, origin, ranks);
+        }
+        let rank = ranks.len();
+        ranks.insert((origin, tree[node].0.clone()), rank);
+    }
+    let mut ranks = HashMap::new();
+    for (origin, tree) in &trees {
+        rank_post_order(tree, 0, *origin, &mut ranks);
+    }
+    ranks
+}
+
+/// Whether `query` holds for the nearest ancestor of `node_id` in
+/// `containers` that has its name and contains the axes it tests.
+fn container_query_matches(
+    dom: &Dom,
+    node_id: NodeId,
+    query: &ContainerQuery,
+    containers: &HashMap<NodeId, QueryContainer>,
+) -> bool {
+    if containers.is_empty() {
+        return false;
+    }
+    let (needs_width, needs_height) = query.axes();
+    dom.ancestors(node_id)
+        .into_iter()
+        .filter_map(|ancestor| containers.get(&ancestor))
+        .find(|container| {
+            query.name.as_ref().is_none_or(|name| container.names.contains(name))
+                && (!needs_width || container.width.is_some())
+                && (!needs_height || container.height.is_some())
+        })
+        .is_some_and(|container| query.matches(container.width, container.height))
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Resolve style
 // ─────────────────────────────────────────────────────────────────────────────
@@ -305,7 +441,9 @@ pub fn collect_matching_rules(
 ///   5. User !important
 ///   6. User-agent !important
 ///
-/// Within each level: higher specificity wins, then later source order wins.
+/// Within each level, rules in later cascade layers win for normal
+/// declarations and lose for `!important` ones (unlayered rules count as
+/// the last layer); then higher specificity wins, then later source order.
 pub fn resolve_style(
     _dom: &Dom,
     _node_id: NodeId,
@@ -320,12 +458,12 @@ pub fn resolve_style(
     };
 
     // Separate declarations into normal and important, then sort.
-    let mut normal_decls: Vec<(&Declaration, Specificity, StyleOrigin, usize)> = Vec::new();
-    let mut important_decls: Vec<(&Declaration, Specificity, StyleOrigin, usize)> = Vec::new();
+    let mut normal_decls: Vec<(&Declaration, Specificity, StyleOrigin, usize, usize)> = Vec::new();
+    let mut important_decls: Vec<(&Declaration, Specificity, StyleOrigin, usize, usize)> = Vec::new();
 
     for rule in matched_rules {
         for decl in &rule.declarations {
-            let entry = (decl, rule.specificity, rule.origin, rule.source_order);
+            let entry = (decl, rule.specificity, rule.origin, rule.source_order, rule.layer);
             if decl.important {
                 important_decls.push(entry);
             } else {
@@ -334,26 +472,28 @@ pub fn resolve_style(
         }
     }
 
-    // Sort normal declarations: origin (UA < User < Author), then specificity, then source order.
+    // Sort normal declarations: origin (UA < User < Author), then layer, then specificity, then source order.
     normal_decls.sort_by(|a, b| {
         a.2.cmp(&b.2)
+            .then(a.4.cmp(&b.4))
             .then(a.1.cmp(&b.1))
             .then(a.3.cmp(&b.3))
     });
 
-    // Sort important declarations: origin reversed (Author < User < UA), then specificity, then source order.
+    // Sort important declarations: origin and layer reversed (Author < User < UA), then specificity, then source order.
     important_decls.sort_by(|a, b| {
         // For !important, origin priority is reversed
         let origin_a = important_origin_rank(a.2);
         let origin_b = important_origin_rank(b.2);
         origin_a
             .cmp(&origin_b)
+            .then(b.4.cmp(&a.4))
             .then(a.1.cmp(&b.1))
             .then(a.3.cmp(&b.3))
     });
 
     // First pass: collect custom properties (--*) from all declarations.
-    for (decl, _, _, _) in normal_decls.iter().chain(important_decls.iter()) {
+    for (decl, _, _, _, _) in normal_decls.iter().chain(important_decls.iter()) {
         if decl.name.starts_with("--") {
             let resolved = resolve_css_values(&decl.value, ctx);
             ctx.custom_properties.insert(decl.name.clone(), resolved);
@@ -361,7 +501,7 @@ pub fn resolve_style(
     }
 
     // Second pass: apply declarations with var()/viewport units resolved.
-    for (decl, _, _, _) in &normal_decls {
+    for (decl, _, _, _, _) in &normal_decls {
         if decl.name.starts_with("--") {
             continue;
         }
@@ -375,7 +515,7 @@ pub fn resolve_style(
         };
         apply_declaration(&mut style, &resolved_decl, parent_style);
     }
-    for (decl, _, _, _) in &important_decls {
+    for (decl, _, _, _, _) in &important_decls {
         if decl.name.starts_with("--") {
             continue;
         }
@@ -1930,7 +2070,9 @@ pub fn apply_declaration(
             }
         }
         "hyphens" => {
-            if let Some(kw) = first_keyword_or_none(&decl.value) {
+            if matches!(decl.value.first(), Some(CssValue::Auto)) {
+                style.hyphens = true;
+            } else if let Some(kw) = first_keyword_or_none(&decl.value) {
                 style.hyphens = kw == "auto";
             }
         }
@@ -2264,6 +2406,29 @@ pub fn apply_declaration(
                 }
             }
         }
+        "container-type" => {
+            if let Some(container_type) = parse_container_type(&decl.value) {
+                style.container_type = container_type;
+            }
+        }
+        "container-name" => {
+            if let Some(names) = parse_container_names(&decl.value) {
+                style.container_name = names;
+            }
+        }
+        "container" => {
+            // `<container-name> [ / <container-type> ]?`
+            let mut parts = decl.value.split(|v| matches!(v, CssValue::Keyword(k) if k == "/"));
+            let names = parts.next().and_then(parse_container_names);
+            let container_type = match parts.next() {
+                Some(values) => parse_container_type(values),
+                None => Some(ContainerType::Normal),
+            };
+            if let (Some(names), Some(container_type), None) = (names, container_type, parts.next()) {
+                style.container_name = names;
+                style.container_type = container_type;
+            }
+        }
 
         "mix-blend-mode" => {
             if let Some(kw) = first_keyword(&decl.value) {
@@ -2332,81 +2497,129 @@ pub fn apply_declaration(
         }
 
         "transition" => {
-            for v in &decl.value {
-                match v {
-                    CssValue::Length(val, unit) => {
-                        let ms = resolve_time_to_ms(*val, unit);
-                        if style.transition_duration_ms == 0.0 { style.transition_duration_ms = ms; }
-                    }
-                    CssValue::Number(n) if *n > 0.0 => {
-                        if style.transition_duration_ms == 0.0 {
-                            style.transition_duration_ms = (*n as f32) * 1000.0;
-                        }
-                    }
-                    CssValue::Keyword(kw) if !matches!(kw.as_str(), "ease" | "linear" | "ease-in" | "ease-out" | "ease-in-out" | "all" | "none") => {
-                        if style.transition_property.is_none() {
-                            style.transition_property = Some(kw.clone());
-                        }
-                    }
-                    _ => {}
-                }
+            if let Some(transitions) = comma_separated(&decl.value).map(parse_single_transition).collect::<Option<Vec<_>>>() {
+                style.transition_properties = transitions.iter().map(|t| t.property.clone()).collect();
+                style.transition_durations_ms = transitions.iter().map(|t| t.duration_ms).collect();
+                style.transition_timing_functions = transitions.iter().map(|t| t.timing.clone()).collect();
+                style.transition_delays_ms = transitions.iter().map(|t| t.delay_ms).collect();
             }
         }
         "transition-property" => {
-            if let Some(kw) = first_keyword_or_none(&decl.value) {
-                style.transition_property = if kw == "none" || kw == "all" { None } else { Some(kw.to_string()) };
+            let properties = comma_separated(&decl.value).map(|item| match item {
+                [CssValue::None] => Some("none".to_string()),
+                [CssValue::Keyword(name)] => Some(strip_vendor_prefix(name)),
+                _ => None,
+            });
+            if let Some(properties) = properties.collect() {
+                style.transition_properties = properties;
             }
         }
         "transition-duration" => {
-            for v in &decl.value {
-                match v {
-                    CssValue::Length(val, unit) => { style.transition_duration_ms = resolve_time_to_ms(*val, unit); break; }
-                    CssValue::Number(n) => { style.transition_duration_ms = (*n as f32) * 1000.0; break; }
-                    _ => {}
-                }
+            let durations = comma_separated(&decl.value).map(|item| match item {
+                [time] => parse_time_ms(time).filter(|ms| *ms >= 0.0),
+                _ => None,
+            });
+            if let Some(durations) = durations.collect() {
+                style.transition_durations_ms = durations;
+            }
+        }
+        "transition-timing-function" => {
+            if let Some(timings) = comma_separated(&decl.value).map(parse_timing_function).collect() {
+                style.transition_timing_functions = timings;
+            }
+        }
+        "transition-delay" => {
+            let delays = comma_separated(&decl.value).map(|item| match item {
+                [time] => parse_time_ms(time),
+                _ => None,
+            });
+            if let Some(delays) = delays.collect() {
+                style.transition_delays_ms = delays;
             }
         }
-        "transition-timing-function" | "transition-delay" => {}
 
         "animation" => {
-            for v in &decl.value {
-                match v {
-                    CssValue::Length(val, unit) => {
-                        let ms = resolve_time_to_ms(*val, unit);
-                        if style.animation_duration_ms == 0.0 { style.animation_duration_ms = ms; }
-                    }
-                    CssValue::Number(n) if *n > 0.0 => {
-                        if style.animation_duration_ms == 0.0 {
-                            style.animation_duration_ms = (*n as f32) * 1000.0;
-                        }
-                    }
-                    CssValue::Keyword(kw) if !matches!(kw.as_str(), "ease" | "linear" | "ease-in" | "ease-out" | "ease-in-out" | "none" | "normal" | "reverse" | "alternate" | "alternate-reverse" | "infinite" | "forwards" | "backwards" | "both" | "running" | "paused") => {
-                        if style.animation_name.is_none() {
-                            style.animation_name = Some(kw.clone());
-                        }
-                    }
-                    _ => {}
-                }
+            if let Some(animations) = comma_separated(&decl.value).map(parse_single_animation).collect::<Option<Vec<_>>>() {
+                style.animation_names = animations.iter().map(|a| a.name.clone()).collect();
+                style.animation_durations_ms = animations.iter().map(|a| a.duration_ms).collect();
+                style.animation_timing_functions = animations.iter().map(|a| a.timing.clone()).collect();
+                style.animation_delays_ms = animations.iter().map(|a| a.delay_ms).collect();
+                style.animation_iteration_counts = animations.iter().map(|a| a.iteration_count).collect();
+                style.animation_directions = animations.iter().map(|a| a.direction).collect();
+                style.animation_fill_modes = animations.iter().map(|a| a.fill_mode).collect();
+                style.animation_play_states = animations.iter().map(|a| a.play_state).collect();
             }
         }
         "animation-name" => {
-            if let Some(kw) = first_keyword_or_none(&decl.value) {
-                style.animation_name = if kw == "none" { None } else { Some(kw.to_string()) };
-            } else if let Some(CssValue::String(s)) = decl.value.first() {
-                style.animation_name = Some(s.clone());
+            let names = comma_separated(&decl.value).map(|item| match item {
+                [CssValue::None] => Some("none".to_string()),
+                [CssValue::Keyword(name) | CssValue::String(name)] => Some(name.clone()),
+                _ => None,
+            });
+            if let Some(names) = names.collect() {
+                style.animation_names = names;
             }
         }
         "animation-duration" => {
-            for v in &decl.value {
-                match v {
-                    CssValue::Length(val, unit) => { style.animation_duration_ms = resolve_time_to_ms(*val, unit); break; }
-                    CssValue::Number(n) => { style.animation_duration_ms = (*n as f32) * 1000.0; break; }
-                    _ => {}
-                }
+            let durations = comma_separated(&decl.value).map(|item| match item {
+                [time] => parse_time_ms(time).filter(|ms| *ms >= 0.0),
+                _ => None,
+            });
+            if let Some(durations) = durations.collect() {
+                style.animation_durations_ms = durations;
+            }
+        }
+        "animation-timing-function" => {
+            if let Some(timings) = comma_separated(&decl.value).map(parse_timing_function).collect() {
+                style.animation_timing_functions = timings;
+            }
+        }
+        "animation-delay" => {
+            let delays = comma_separated(&decl.value).map(|item| match item {
+                [time] => parse_time_ms(time),
+                _ => None,
+            });
+            if let Some(delays) = delays.collect() {
+                style.animation_delays_ms = delays;
+            }
+        }
+        "animation-iteration-count" => {
+            let counts = comma_separated(&decl.value).map(|item| match item {
+                [count] => parse_iteration_count(count),
+                _ => None,
+            });
+            if let Some(counts) = counts.collect() {
+                style.animation_iteration_counts = counts;
+            }
+        }
+        "animation-direction" => {
+            let directions = comma_separated(&decl.value).map(|item| match item {
+                [CssValue::Keyword(kw)] => parse_animation_direction(kw),
+                _ => None,
+            });
+            if let Some(directions) = directions.collect() {
+                style.animation_directions = directions;
+            }
+        }
+        "animation-fill-mode" => {
+            let fill_modes = comma_separated(&decl.value).map(|item| match item {
+                [CssValue::None] => Some(AnimationFillMode::None),
+                [CssValue::Keyword(kw)] => parse_animation_fill_mode(kw),
+                _ => None,
+            });
+            if let Some(fill_modes) = fill_modes.collect() {
+                style.animation_fill_modes = fill_modes;
+            }
+        }
+        "animation-play-state" => {
+            let play_states = comma_separated(&decl.value).map(|item| match item {
+                [CssValue::Keyword(kw)] => parse_animation_play_state(kw),
+                _ => None,
+            });
+            if let Some(play_states) = play_states.collect() {
+                style.animation_play_states = play_states;
             }
         }
-        "animation-timing-function" | "animation-delay" | "animation-iteration-count"
-        | "animation-direction" | "animation-fill-mode" | "animation-play-state" => {}
 
         "counter-reset" | "counter-increment" | "counter-set" => {}
 
@@ -2434,7 +2647,7 @@ pub fn apply_declaration(
         "text-fill-color" | "text-stroke" | "text-stroke-width" | "text-stroke-color" => {}
 
         "scrollbar-width" | "scrollbar-color" | "scrollbar-gutter" => {}
-        "content-visibility" | "container-type" | "container-name" | "container" => {}
+        "content-visibility" => {}
         "text-wrap" | "text-wrap-mode" | "text-wrap-style" => {}
         "white-space-collapse" => {}
         "color-interpolation" | "color-interpolation-filters" => {}
@@ -2484,7 +2697,7 @@ pub fn apply_declaration(
 // Inherit / Initial helpers
 // ─────────────────────────────────────────────────────────────────────────────
 
-fn strip_vendor_prefix(name: &str) -> String {
+pub(crate) fn strip_vendor_prefix(name: &str) -> String {
     for prefix in &["-webkit-", "-moz-", "-ms-", "-o-"] {
         if let Some(stripped) = name.strip_prefix(prefix) {
             return stripped.to_string();
@@ -2493,7 +2706,7 @@ fn strip_vendor_prefix(name: &str) -> String {
     name.to_string()
 }
 
-fn is_inherited_property(name: &str) -> bool {
+pub(crate) fn is_inherited_property(name: &str) -> bool {
     matches!(
         name,
         "color"
@@ -2635,15 +2848,43 @@ fn apply_initial(style: &mut ComputedStyle, prop: &str) {
         "column-width" => style.column_width = def.column_width,
         "will-change" => style.will_change = def.will_change,
         "contain" => { style.contain_layout = def.contain_layout; style.contain_paint = def.contain_paint; }
+        "container-type" => style.container_type = def.container_type,
+        "container-name" => style.container_name = def.container_name.clone(),
+        "container" => { style.container_type = def.container_type; style.container_name = def.container_name.clone(); }
         "mix-blend-mode" => style.mix_blend_mode = def.mix_blend_mode,
         "isolation" => style.isolation = def.isolation,
         "scroll-behavior" => style.scroll_behavior = def.scroll_behavior,
         "accent-color" => style.accent_color = def.accent_color,
         "caret-color" => style.caret_color = def.caret_color,
         "color-scheme" => style.color_scheme = def.color_scheme,
-        "transition" | "transition-duration" | "transition-property" => { style.transition_duration_ms = def.transition_duration_ms; style.transition_property = def.transition_property.clone(); }
-        "animation" | "animation-duration" => style.animation_duration_ms = def.animation_duration_ms,
-        "animation-name" => style.animation_name = def.animation_name.clone(),
+        "transition" => {
+            style.transition_properties = def.transition_properties;
+            style.transition_durations_ms = def.transition_durations_ms;
+            style.transition_timing_functions = def.transition_timing_functions;
+            style.transition_delays_ms = def.transition_delays_ms;
+        }
+        "transition-property" => style.transition_properties = def.transition_properties,
+        "transition-duration" => style.transition_durations_ms = def.transition_durations_ms,
+        "transition-timing-function" => style.transition_timing_functions = def.transition_timing_functions,
+        "transition-delay" => style.transition_delays_ms = def.transition_delays_ms,
+        "animation" => {
+            style.animation_names = def.animation_names;
+            style.animation_durations_ms = def.animation_durations_ms;
+            style.animation_timing_functions = def.animation_timing_functions;
+            style.animation_delays_ms = def.animation_delays_ms;
+            style.animation_iteration_counts = def.animation_iteration_counts;
+            style.animation_directions = def.animation_directions;
+            style.animation_fill_modes = def.animation_fill_modes;
+            style.animation_play_states = def.animation_play_states;
+        }
+        "animation-name" => style.animation_names = def.animation_names,
+        "animation-duration" => style.animation_durations_ms = def.animation_durations_ms,
+        "animation-timing-function" => style.animation_timing_functions = def.animation_timing_functions,
+        "animation-delay" => style.animation_delays_ms = def.animation_delays_ms,
+        "animation-iteration-count" => style.animation_iteration_counts = def.animation_iteration_counts,
+        "animation-direction" => style.animation_directions = def.animation_directions,
+        "animation-fill-mode" => style.animation_fill_modes = def.animation_fill_modes,
+        "animation-play-state" => style.animation_play_states = def.animation_play_states,
         _ => {}
     }
 }
@@ -2749,6 +2990,7 @@ fn resolve_length(value: f64, unit: &LengthUnit, parent_font_size: f32) -> f32 {
         LengthUnit::Ex => value as f32 * parent_font_size * 0.5,
         LengthUnit::Percent => value as f32, // caller must handle percentage context
         LengthUnit::Fr => value as f32, // fr units only meaningful in grid track context
+        LengthUnit::S | LengthUnit::Ms => value as f32, // times are not lengths
     }
 }
 
@@ -2802,6 +3044,7 @@ fn first_length_or_none(values: &[CssValue], parent_font_size: f32) -> Option<f3
     None
 }
 
+/// The family list of a `font-family` value, names separated by `", "`.
 fn collect_font_families(values: &[CssValue]) -> String {
     let mut families: Vec<String> = Vec::new();
     let mut current = String::new();
@@ -2814,6 +3057,12 @@ fn collect_font_families(values: &[CssValue]) -> String {
                 }
                 families.push(s.clone());
             }
+            CssValue::Keyword(kw) if kw == "," => {
+                if !current.is_empty() {
+                    families.push(current.clone());
+                    current.clear();
+                }
+            }
             CssValue::Keyword(kw) => {
                 if current.is_empty() {
                     current = kw.clone();
@@ -2833,11 +3082,7 @@ fn collect_font_families(values: &[CssValue]) -> String {
     if !current.is_empty() {
         families.push(current);
     }
-    if families.is_empty() {
-        String::new()
-    } else {
-        families[0].clone()
-    }
+    families.join(", ")
 }
 
 fn collect_lengths(values: &[CssValue], parent_font_size: f32) -> Vec<f32> {
@@ -3161,13 +3406,236 @@ fn parse_filter_list(values: &[CssValue], font_size: f32, current_color: Color)
     filters
 }
 
-fn resolve_time_to_ms(val: f64, unit: &LengthUnit) -> f32 {
-    match unit {
-        LengthUnit::Px => val as f32,
-        _ => (val * 1000.0) as f32,
+/// The items of a comma-separated list value.
+fn comma_separated(values: &[CssValue]) -> impl Iterator<Item = &[CssValue]> {
+    values.split(|v| matches!(v, CssValue::Keyword(kw) if kw == ","))
+}
+
+/// A `container-type` keyword.
+fn parse_container_type(values: &[CssValue]) -> Option<ContainerType> {
+    match values {
+        [CssValue::Keyword(kw)] => match kw.as_str() {
+            "normal" => Some(ContainerType::Normal),
+            "size" => Some(ContainerType::Size),
+            "inline-size" => Some(ContainerType::InlineSize),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `none` or a list of `container-name` idents.
+fn parse_container_names(values: &[CssValue]) -> Option<Vec<String>> {
+    match values {
+        [CssValue::None] => Some(Vec::new()),
+        [] => None,
+        names => names
+            .iter()
+            .map(|v| match v {
+                CssValue::Keyword(kw) if !matches!(kw.as_str(), "and" | "or" | "not" | "/" | ",") => Some(kw.clone()),
+                _ => None,
+            })
+            .collect(),
+    }
+}
+
+/// A `<time>` in milliseconds; a bare `0` counts too.
+fn parse_time_ms(value: &CssValue) -> Option<f64> {
+    match value {
+        CssValue::Length(val, LengthUnit::S) => Some(val * 1000.0),
+        CssValue::Length(val, LengthUnit::Ms) => Some(*val),
+        CssValue::Number(n) if *n == 0.0 => Some(0.0),
+        _ => None,
+    }
+}
+
+/// An easing function: a keyword, `cubic-bezier()` or `steps()`.
+pub(crate) fn parse_timing_function(item: &[CssValue]) -> Option<TimingFunction> {
+    match item {
+        [CssValue::Keyword(kw)] => match kw.as_str() {
+            "linear" => Some(TimingFunction::Linear),
+            "ease" => Some(TimingFunction::Ease),
+            "ease-in" => Some(TimingFunction::EaseIn),
+            "ease-out" => Some(TimingFunction::EaseOut),
+            "ease-in-out" => Some(TimingFunction::EaseInOut),
+            "step-start" => Some(TimingFunction::Steps(1, StepPosition::Start)),
+            "step-end" => Some(TimingFunction::Steps(1, StepPosition::End)),
+            _ => None,
+        },
+        [CssValue::Function { name, args }] => {
+            let args: Vec<&CssValue> = args.iter().filter(|a| !matches!(a, CssValue::Keyword(kw) if kw == ",")).collect();
+            match (name.to_ascii_lowercase().as_str(), args.as_slice()) {
+                (
+                    "cubic-bezier",
+                    [CssValue::Number(x1), CssValue::Number(y1), CssValue::Number(x2), CssValue::Number(y2)],
+                ) if (0.0..=1.0).contains(x1) && (0.0..=1.0).contains(x2) => {
+                    Some(TimingFunction::CubicBezier(*x1 as f32, *y1 as f32, *x2 as f32, *y2 as f32))
+                }
+                ("steps", [CssValue::Number(n), rest @ ..]) if *n >= 1.0 && n.fract() == 0.0 => {
+                    let position = match rest {
+                        [] => StepPosition::End,
+                        [CssValue::Keyword(kw)] => match kw.as_str() {
+                            "start" | "jump-start" => StepPosition::Start,
+                            "end" | "jump-end" => StepPosition::End,
+                            _ => return None,
+                        },
+                        _ => return None,
+                    };
+                    Some(TimingFunction::Steps(*n as u32, position))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn parse_iteration_count(value: &CssValue) -> Option<f64> {
+    match value {
+        CssValue::Keyword(kw) if kw == "infinite" => Some(f64::INFINITY),
+        CssValue::Number(n) if *n >= 0.0 => Some(*n),
+        _ => None,
     }
 }
 
+fn parse_animation_direction(kw: &str) -> Option<AnimationDirection> {
+    match kw {
+        "normal" => Some(AnimationDirection::Normal),
+        "reverse" => Some(AnimationDirection::Reverse),
+        "alternate" => Some(AnimationDirection::Alternate),
+        "alternate-reverse" => Some(AnimationDirection::AlternateReverse),
+        _ => None,
+    }
+}
+
+fn parse_animation_fill_mode(kw: &str) -> Option<AnimationFillMode> {
+    match kw {
+        "forwards" => Some(AnimationFillMode::Forwards),
+        "backwards" => Some(AnimationFillMode::Backwards),
+        "both" => Some(AnimationFillMode::Both),
+        _ => None,
+    }
+}
+
+fn parse_animation_play_state(kw: &str) -> Option<AnimationPlayState> {
+    match kw {
+        "running" => Some(AnimationPlayState::Running),
+        "paused" => Some(AnimationPlayState::Paused),
+        _ => None,
+    }
+}
+
+/// One animation of the `animation` shorthand.
+struct SingleAnimation {
+    name: String,
+    duration_ms: f64,
+    timing: TimingFunction,
+    delay_ms: f64,
+    iteration_count: f64,
+    direction: AnimationDirection,
+    fill_mode: AnimationFillMode,
+    play_state: AnimationPlayState,
+}
+
+/// Parse one comma-separated item of the `animation` shorthand. The first
+/// time is the duration and the second the delay; a keyword goes to the
+/// first longhand that takes it and isn't set yet, and what is left is
+/// the name.
+fn parse_single_animation(item: &[CssValue]) -> Option<SingleAnimation> {
+    let mut name = None;
+    let mut times = Vec::new();
+    let mut timing = None;
+    let mut iteration_count = None;
+    let mut direction = None;
+    let mut fill_mode = None;
+    let mut play_state = None;
+    for value in item {
+        if let Some(ms) = parse_time_ms(value) {
+            times.push(ms);
+        } else if timing.is_none() && let Some(t) = parse_timing_function(std::slice::from_ref(value)) {
+            timing = Some(t);
+        } else if iteration_count.is_none() && let Some(count) = parse_iteration_count(value) {
+            iteration_count = Some(count);
+        } else if let CssValue::Keyword(kw) = value
+            && let Some(d) = direction.is_none().then(|| parse_animation_direction(kw)).flatten()
+        {
+            direction = Some(d);
+        } else if let CssValue::Keyword(kw) = value
+            && let Some(f) = fill_mode.is_none().then(|| parse_animation_fill_mode(kw)).flatten()
+        {
+            fill_mode = Some(f);
+        } else if let CssValue::Keyword(kw) = value
+            && let Some(p) = play_state.is_none().then(|| parse_animation_play_state(kw)).flatten()
+        {
+            play_state = Some(p);
+        } else if name.is_none() {
+            name = Some(match value {
+                CssValue::None => "none".to_string(),
+                CssValue::Keyword(kw) | CssValue::String(kw) => kw.clone(),
+                _ => return None,
+            });
+        } else if *value == CssValue::None && fill_mode.is_none() {
+            fill_mode = Some(AnimationFillMode::None);
+        } else {
+            return None;
+        }
+    }
+    if times.len() > 2 || times.first().is_some_and(|ms| *ms < 0.0) {
+        return None;
+    }
+    Some(SingleAnimation {
+        name: name.unwrap_or_else(|| "none".to_string()),
+        duration_ms: times.first().copied().unwrap_or(0.0),
+        timing: timing.unwrap_or(TimingFunction::Ease),
+        delay_ms: times.get(1).copied().unwrap_or(0.0),
+        iteration_count: iteration_count.unwrap_or(1.0),
+        direction: direction.unwrap_or(AnimationDirection::Normal),
+        fill_mode: fill_mode.unwrap_or(AnimationFillMode::None),
+        play_state: play_state.unwrap_or(AnimationPlayState::Running),
+    })
+}
+
+/// One comma-separated item of the `transition` shorthand.
+struct SingleTransition {
+    property: String,
+    duration_ms: f64,
+    timing: TimingFunction,
+    delay_ms: f64,
+}
+
+/// Parse one comma-separated item of the `transition` shorthand. As with
+/// `animation`, the first time is the duration and the second the delay;
+/// the one other keyword is the property.
+fn parse_single_transition(item: &[CssValue]) -> Option<SingleTransition> {
+    let mut property = None;
+    let mut times = Vec::new();
+    let mut timing = None;
+    for value in item {
+        if let Some(ms) = parse_time_ms(value) {
+            times.push(ms);
+        } else if timing.is_none() && let Some(t) = parse_timing_function(std::slice::from_ref(value)) {
+            timing = Some(t);
+        } else if property.is_none() {
+            property = Some(match value {
+                CssValue::None => "none".to_string(),
+                CssValue::Keyword(kw) => strip_vendor_prefix(kw),
+                _ => return None,
+            });
+        } else {
+            return None;
+        }
+    }
+    if times.len() > 2 || times.first().is_some_and(|ms| *ms < 0.0) {
+        return None;
+    }
+    Some(SingleTransition {
+        property: property.unwrap_or_else(|| "all".to_string()),
+        duration_ms: times.first().copied().unwrap_or(0.0),
+        timing: timing.unwrap_or(TimingFunction::Ease),
+        delay_ms: times.get(1).copied().unwrap_or(0.0),
+    })
+}
+
 fn parse_grid_track_list(values: &[CssValue], parent_font_size: f32) -> Vec<GridTrackSize> {
     let mut tracks = Vec::new();
     for v in values {
@@ -3317,6 +3785,48 @@ mod tests {
         assert_eq!(style.display, Display::Block);
     }
 
+    #[test]
+    fn media_rules_follow_the_device() {
+        let (dom, div, _) = build_dom_and_style("");
+        let ss = parse_stylesheet(
+            "div { color: red; } @media (max-width: 600px) { div { color: blue; } } @media print { div { color: gray; } }",
+        );
+        let sheets = vec![(ss, StyleOrigin::Author)];
+        let color = |device: &Device| {
+            let matched = collect_matching_rules_for_device(&dom, div, &sheets, device);
+            resolve_style(&dom, div, &matched, None, &mut default_ctx()).color
+        };
+        assert_eq!(color(&Device::screen(1024.0, 768.0)), Color::rgb(255, 0, 0));
+        assert_eq!(color(&Device::screen(400.0, 768.0)), Color::rgb(0, 0, 255));
+    }
+
+    #[test]
+    fn supports_rules_take_the_fallback_for_missing_features() {
+        let (dom, div, _) = build_dom_and_style("");
+        let ss = parse_stylesheet(
+            "@supports (display: grid) { div { display: grid; } }
+             @supports not (display: grid) { div { display: block; } }
+             @supports (clip-path: circle(50%)) { div { color: red; } }
+             @supports not selector(:has(p)) { div { opacity: 0.5; } }",
+        );
+        let sheets = vec![(ss, StyleOrigin::Author)];
+        let matched = collect_matching_rules(&dom, div, &sheets);
+        let style = resolve_style(&dom, div, &matched, None, &mut default_ctx());
+        assert_eq!(style.display, Display::Grid);
+        assert_eq!(style.color, ComputedStyle::default().color);
+        assert_eq!(style.opacity, 0.5);
+    }
+
+    #[test]
+    fn font_family_keeps_the_whole_list() {
+        let (dom, div, _) = build_dom_and_style("");
+        let ss = parse_stylesheet(r#"div { font-family: "Open Sans", Helvetica Neue, sans-serif; }"#);
+        let sheets = vec![(ss, StyleOrigin::Author)];
+        let matched = collect_matching_rules(&dom, div, &sheets);
+        let style = resolve_style(&dom, div, &matched, None, &mut default_ctx());
+        assert_eq!(style.font_family, "Open Sans, helvetica neue, sans-serif");
+    }
+
     #[test]
     fn resolve_color_and_font_size() {
         let (dom, div, _) = build_dom_and_style("");
@@ -3408,6 +3918,85 @@ mod tests {
         assert_eq!(style.color, Color::rgb(0, 0, 255));
     }
 
+    #[test]
+    fn cascade_layers_order_before_specificity() {
+        let (dom, div, _) = build_dom_and_style("");
+        let ss = parse_stylesheet(
+            "@layer base, theme;
+             @layer theme {
+                 div { color: blue; }
+                 @layer dark { div { opacity: 0.5; } }
+                 div { opacity: 0.25; }
+                 #main { width: 20px; }
+             }
+             @layer base { #main { color: red; } }
+             @layer { #main { font-size: 30px; } }
+             @layer base { div { font-size: 20px; } }
+             div { width: 10px; }",
+        );
+        let sheets = vec![(ss, StyleOrigin::Author)];
+        let matched = collect_matching_rules(&dom, div, &sheets);
+        let style = resolve_style(&dom, div, &matched, None, &mut default_ctx());
+        assert_eq!(style.color, Color::rgb(0, 0, 255), "later layers win over specificity");
+        assert_eq!(style.opacity, 0.25, "a layer's own rules win over its sublayers");
+        assert_eq!(style.width, Some(10.0), "unlayered rules win over layered ones");
+        assert_eq!(style.font_size_px, 30.0, "anonymous layers order where they appear");
+    }
+
+    #[test]
+    fn important_declarations_invert_layer_order() {
+        let (dom, div, _) = build_dom_and_style("");
+        let ss = parse_stylesheet(
+            "@layer base { div { display: block !important; color: red !important; } }
+             @layer theme { div { display: flex !important; } }
+             div { display: grid !important; color: blue; }
+             #main { color: green; }",
+        );
+        let ua = parse_stylesheet("div { display: inline-block !important; }");
+        let sheets = vec![(ss, StyleOrigin::Author)];
+        let matched = collect_matching_rules(&dom, div, &sheets);
+        let style = resolve_style(&dom, div, &matched, None, &mut default_ctx());
+        assert_eq!(style.display, Display::Block);
+        assert_eq!(style.color, Color::rgb(255, 0, 0));
+
+        let sheets = vec![(ua, StyleOrigin::UserAgent), sheets.into_iter().next().unwrap()];
+        let matched = collect_matching_rules(&dom, div, &sheets);
+        let style = resolve_style(&dom, div, &matched, None, &mut default_ctx());
+        assert_eq!(style.display, Display::InlineBlock, "origins still come first");
+    }
+
+    #[test]
+    fn container_queries_test_the_nearest_fitting_container() {
+        let (dom, div, p) = build_dom_and_style("");
+        let body = dom.nodes.get(div).unwrap().parent.unwrap();
+        let ss = parse_stylesheet(
+            "div { container: card / inline-size; }
+             @container (min-width: 500px) { p { color: red; } }
+             @container page (min-width: 500px) { p { opacity: 0.5; } }
+             @container (min-height: 10px) { p { display: block; } }
+             @container card (width < 500px) { @container page (width > 500px) { p { font-size: 30px; } } }",
+        );
+        let sheets = vec![(ss, StyleOrigin::Author)];
+        let matched = collect_matching_rules(&dom, div, &sheets);
+        let style = resolve_style(&dom, div, &matched, None, &mut default_ctx());
+        assert_eq!((style.container_name.as_slice(), style.container_type), (&["card".to_string()][..], ContainerType::InlineSize));
+
+        let containers = HashMap::from([
+            (body, QueryContainer { names: vec!["page".into()], width: Some(1000.0), height: None }),
+            (div, QueryContainer { names: vec!["card".into()], width: Some(300.0), height: None }),
+        ]);
+        let matched = collect_matching_rules_in(&dom, p, &sheets, &Device::default(), &containers);
+        let style = resolve_style(&dom, p, &matched, None, &mut default_ctx());
+        let default = ComputedStyle::default();
+        assert_eq!(style.color, default.color, "the nearest container is too narrow");
+        assert_eq!(style.opacity, 0.5, "a named query skips to its container");
+        assert_eq!(style.display, default.display, "no container has a height");
+        assert_eq!(style.font_size_px, 30.0);
+
+        let matched = collect_matching_rules(&dom, p, &sheets);
+        assert_eq!(matched.len(), 0, "container rules need a layout");
+    }
+
     #[test]
     fn resolve_opacity() {
         let (dom, div, _) = build_dom_and_style("");
@@ -3518,4 +4107,40 @@ mod tests {
         // 200% of inherited 16px = 32px
         assert_eq!(style.font_size_px, 32.0);
     }
+
+    #[test]
+    fn animation_shorthand_and_longhands() {
+        let (dom, div, _) = build_dom_and_style("");
+        let ss = parse_stylesheet(
+            "div { animation: 2s ease-in 500ms infinite alternate both slide, fade 300ms steps(4, start) paused; \
+             animation-delay: -1s; animation-fill-mode: forwards; }",
+        );
+        let sheets = vec![(ss, StyleOrigin::Author)];
+        let matched = collect_matching_rules(&dom, div, &sheets);
+        let style = resolve_style(&dom, div, &matched, None, &mut default_ctx());
+        assert_eq!(style.animation_names, ["slide", "fade"]);
+        assert_eq!(style.animation_durations_ms, [2000.0, 300.0]);
+        assert_eq!(style.animation_timing_functions, [TimingFunction::EaseIn, TimingFunction::Steps(4, StepPosition::Start)]);
+        assert_eq!(style.animation_iteration_counts, [f64::INFINITY, 1.0]);
+        assert_eq!(style.animation_directions, [AnimationDirection::Alternate, AnimationDirection::Normal]);
+        assert_eq!(style.animation_play_states, [AnimationPlayState::Running, AnimationPlayState::Paused]);
+        // Longhands after the shorthand replace its lists.
+        assert_eq!(style.animation_delays_ms, [-1000.0]);
+        assert_eq!(style.animation_fill_modes, [AnimationFillMode::Forwards]);
+    }
+
+    #[test]
+    fn transition_shorthand_and_longhands() {
+        let (dom, div, _) = build_dom_and_style("");
+        let ss = parse_stylesheet(
+            "div { transition: opacity 200ms, -webkit-transform 1s ease-out 50ms, 2s; transition-delay: 0s, 1s; }",
+        );
+        let sheets = vec![(ss, StyleOrigin::Author)];
+        let matched = collect_matching_rules(&dom, div, &sheets);
+        let style = resolve_style(&dom, div, &matched, None, &mut default_ctx());
+        assert_eq!(style.transition_properties, ["opacity", "transform", "all"]);
+        assert_eq!(style.transition_durations_ms, [200.0, 1000.0, 2000.0]);
+        assert_eq!(style.transition_timing_functions, [TimingFunction::Ease, TimingFunction::EaseOut, TimingFunction::Ease]);
+        assert_eq!(style.transition_delays_ms, [0.0, 1000.0]);
+    }
 }
@@ -2,6 +2,8 @@
 
 use common::{Color, Edges};
 
+use crate::animation::{AnimationDirection, AnimationFillMode, AnimationPlayState, TimingFunction};
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Display
 // ─────────────────────────────────────────────────────────────────────────────
@@ -850,6 +852,24 @@ impl Default for Isolation {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// ContainerType
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Which axes of an element `@container` queries can measure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerType {
+    Normal,
+    Size,
+    InlineSize,
+}
+
+impl Default for ContainerType {
+    fn default() -> Self {
+        ContainerType::Normal
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // ScrollBehavior
 // ─────────────────────────────────────────────────────────────────────────────
@@ -1141,6 +1161,8 @@ pub struct ComputedStyle {
     pub will_change: bool,
     pub contain_layout: bool,
     pub contain_paint: bool,
+    pub container_type: ContainerType,
+    pub container_name: Vec<String>,
 
     // -- Blending --
     pub mix_blend_mode: MixBlendMode,
@@ -1154,11 +1176,23 @@ pub struct ComputedStyle {
     pub caret_color: Option<Color>,
     pub color_scheme: ColorScheme,
 
-    // -- Transition/Animation (stored for future use) --
-    pub transition_duration_ms: f32,
-    pub transition_property: Option<String>,
-    pub animation_name: Option<String>,
-    pub animation_duration_ms: f32,
+    // -- Transition: the longhands' comma-separated lists; there is one
+    // transition per property, the other lists repeating to match --
+    pub transition_properties: Vec<String>,
+    pub transition_durations_ms: Vec<f64>,
+    pub transition_timing_functions: Vec<TimingFunction>,
+    pub transition_delays_ms: Vec<f64>,
+
+    // -- Animation: the longhands' comma-separated lists; there is one
+    // animation per name, the other lists repeating to match --
+    pub animation_names: Vec<String>,
+    pub animation_durations_ms: Vec<f64>,
+    pub animation_timing_functions: Vec<TimingFunction>,
+    pub animation_delays_ms: Vec<f64>,
+    pub animation_iteration_counts: Vec<f64>,
+    pub animation_directions: Vec<AnimationDirection>,
+    pub animation_fill_modes: Vec<AnimationFillMode>,
+    pub animation_play_states: Vec<AnimationPlayState>,
 }
 
 impl Default for ComputedStyle {
@@ -1302,6 +1336,8 @@ impl Default for ComputedStyle {
             will_change: false,
             contain_layout: false,
             contain_paint: false,
+            container_type: ContainerType::Normal,
+            container_name: Vec::new(),
 
             mix_blend_mode: MixBlendMode::Normal,
             isolation: Isolation::Auto,
@@ -1312,10 +1348,19 @@ impl Default for ComputedStyle {
             caret_color: None,
             color_scheme: ColorScheme::Normal,
 
-            transition_duration_ms: 0.0,
-            transition_property: None,
-            animation_name: None,
-            animation_duration_ms: 0.0,
+            transition_properties: vec!["all".to_string()],
+            transition_durations_ms: vec![0.0],
+            transition_timing_functions: vec![TimingFunction::Ease],
+            transition_delays_ms: vec![0.0],
+
+            animation_names: Vec::new(),
+            animation_durations_ms: vec![0.0],
+            animation_timing_functions: vec![TimingFunction::Ease],
+            animation_delays_ms: vec![0.0],
+            animation_iteration_counts: vec![1.0],
+            animation_directions: vec![AnimationDirection::Normal],
+            animation_fill_modes: vec![AnimationFillMode::None],
+            animation_play_states: vec![AnimationPlayState::Running],
         }
     }
 }
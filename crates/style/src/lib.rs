@@ -7,11 +7,12 @@ pub mod animation;
 pub mod cascade;
 pub mod computed;
 pub mod matching;
+pub mod supports;
 
 pub use computed::*;
 pub use cascade::{
-    MatchedRule, StyleOrigin, ResolveContext,
-    collect_matching_rules, resolve_style,
+    MatchedRule, QueryContainer, StyleOrigin, ResolveContext,
+    collect_matching_rules, collect_matching_rules_for_device, collect_matching_rules_in, resolve_style,
     apply_declaration, resolve_css_values, resolve_property_percentages,
     resolve_remaining_calcs,
 };
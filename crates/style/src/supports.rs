@@ -0,0 +1,299 @@
+//! Declaration support for `@supports`, answered by the cascade itself.
+//!
+//! [`PROPERTIES`] lists every property [`apply_declaration`] computes,
+//! each with a sample value that differs from its initial value. A
+//! declaration is supported when applying it changes a style — either the
+//! default style or one holding the sample, so that initial values (like
+//! `display: inline`) count too. Properties `apply_declaration` accepts
+//! but ignores (`clip-path`, `mask`, …) are left out, so pages take their
+//! fallbacks instead.
+
+use css::{CssTokenizer, CssValue, Declaration, parse_declaration_block};
+
+use crate::cascade::{
+    ResolveContext, apply_declaration, resolve_css_values, resolve_property_percentages,
+    resolve_remaining_calcs, strip_vendor_prefix,
+};
+use crate::computed::ComputedStyle;
+
+/// Supported properties and a non-initial sample value for each.
+pub const PROPERTIES: &[(&str, &str)] = &[
+    ("display", "block"),
+    ("position", "relative"),
+    ("float", "left"),
+    ("color", "red"),
+    ("background-color", "red"),
+    ("background", "red"),
+    ("font-size", "20px"),
+    ("font-weight", "bold"),
+    ("font-family", "monospace"),
+    ("font-style", "italic"),
+    ("font", "bold 20px monospace"),
+    ("line-height", "3"),
+    ("text-align", "center"),
+    ("margin", "4px"),
+    ("margin-top", "4px"),
+    ("margin-right", "4px"),
+    ("margin-bottom", "4px"),
+    ("margin-left", "4px"),
+    ("margin-inline", "4px"),
+    ("margin-block", "4px"),
+    ("margin-inline-start", "4px"),
+    ("margin-inline-end", "4px"),
+    ("margin-block-start", "4px"),
+    ("margin-block-end", "4px"),
+    ("padding", "4px"),
+    ("padding-top", "4px"),
+    ("padding-right", "4px"),
+    ("padding-bottom", "4px"),
+    ("padding-left", "4px"),
+    ("padding-inline", "4px"),
+    ("padding-block", "4px"),
+    ("padding-inline-start", "4px"),
+    ("padding-inline-end", "4px"),
+    ("padding-block-start", "4px"),
+    ("padding-block-end", "4px"),
+    ("border", "1px solid red"),
+    ("border-width", "4px"),
+    ("border-style", "solid"),
+    ("border-color", "red"),
+    ("border-top", "1px solid red"),
+    ("border-right", "1px solid red"),
+    ("border-bottom", "1px solid red"),
+    ("border-left", "1px solid red"),
+    ("border-top-width", "4px"),
+    ("border-right-width", "4px"),
+    ("border-bottom-width", "4px"),
+    ("border-left-width", "4px"),
+    ("border-top-style", "solid"),
+    ("border-right-style", "solid"),
+    ("border-bottom-style", "solid"),
+    ("border-left-style", "solid"),
+    ("border-top-color", "red"),
+    ("border-right-color", "red"),
+    ("border-bottom-color", "red"),
+    ("border-left-color", "red"),
+    ("border-inline-width", "4px"),
+    ("border-block-width", "4px"),
+    ("border-radius", "4px"),
+    ("border-top-left-radius", "4px"),
+    ("border-top-right-radius", "4px"),
+    ("border-bottom-right-radius", "4px"),
+    ("border-bottom-left-radius", "4px"),
+    ("border-collapse", "collapse"),
+    ("border-spacing", "4px"),
+    ("width", "10px"),
+    ("height", "10px"),
+    ("min-width", "10px"),
+    ("min-height", "10px"),
+    ("max-width", "10px"),
+    ("max-height", "10px"),
+    ("inline-size", "10px"),
+    ("block-size", "10px"),
+    ("min-inline-size", "10px"),
+    ("min-block-size", "10px"),
+    ("max-inline-size", "10px"),
+    ("max-block-size", "10px"),
+    ("box-sizing", "border-box"),
+    ("aspect-ratio", "16 / 9"),
+    ("top", "4px"),
+    ("right", "4px"),
+    ("bottom", "4px"),
+    ("left", "4px"),
+    ("inset", "4px"),
+    ("z-index", "1"),
+    ("flex", "1"),
+    ("flex-direction", "column"),
+    ("flex-wrap", "wrap"),
+    ("flex-flow", "column wrap"),
+    ("flex-grow", "1"),
+    ("flex-shrink", "2"),
+    ("flex-basis", "10px"),
+    ("order", "1"),
+    ("justify-content", "center"),
+    ("align-items", "center"),
+    ("align-self", "center"),
+    ("align-content", "center"),
+    ("place-items", "center"),
+    ("place-content", "center"),
+    ("gap", "4px"),
+    ("row-gap", "4px"),
+    ("column-gap", "4px"),
+    ("grid-gap", "4px"),
+    ("grid-row-gap", "4px"),
+    ("grid-column-gap", "4px"),
+    ("grid-template-columns", "1fr 1fr"),
+    ("grid-template-rows", "1fr 1fr"),
+    ("grid-auto-flow", "column"),
+    ("box-pack", "center"),
+    ("box-align", "center"),
+    ("box-orient", "vertical"),
+    ("box-direction", "reverse"),
+    ("box-flex", "1"),
+    ("overflow", "hidden"),
+    ("overflow-x", "hidden"),
+    ("overflow-y", "hidden"),
+    ("opacity", "0.5"),
+    ("visibility", "hidden"),
+    ("text-decoration", "underline"),
+    ("text-decoration-line", "underline"),
+    ("white-space", "nowrap"),
+    ("text-transform", "uppercase"),
+    ("letter-spacing", "2px"),
+    ("word-spacing", "2px"),
+    ("vertical-align", "middle"),
+    ("text-indent", "10px"),
+    ("text-overflow", "ellipsis"),
+    ("text-shadow", "1px 1px red"),
+    ("list-style", "square"),
+    ("list-style-type", "square"),
+    ("word-break", "break-all"),
+    ("overflow-wrap", "break-word"),
+    ("word-wrap", "break-word"),
+    ("tab-size", "4"),
+    ("hyphens", "auto"),
+    ("direction", "rtl"),
+    ("writing-mode", "vertical-rl"),
+    ("unicode-bidi", "isolate"),
+    ("cursor", "pointer"),
+    ("pointer-events", "none"),
+    ("user-select", "none"),
+    ("resize", "both"),
+    ("background-image", "linear-gradient(red, blue)"),
+    ("background-repeat", "no-repeat"),
+    ("background-size", "cover"),
+    ("background-position", "right bottom"),
+    ("box-shadow", "1px 1px red"),
+    ("outline", "1px solid red"),
+    ("outline-width", "4px"),
+    ("outline-style", "solid"),
+    ("outline-color", "red"),
+    ("outline-offset", "2px"),
+    ("object-fit", "cover"),
+    ("object-position", "right bottom"),
+    ("table-layout", "fixed"),
+    ("caption-side", "bottom"),
+    ("empty-cells", "hide"),
+    ("content", "\"x\""),
+    ("transform", "rotate(45deg)"),
+    ("transform-origin", "left top"),
+    ("filter", "blur(2px)"),
+    ("backdrop-filter", "blur(2px)"),
+    ("column-count", "2"),
+    ("column-width", "100px"),
+    ("columns", "2"),
+    ("will-change", "transform"),
+    ("contain", "paint"),
+    ("container-type", "size"),
+    ("container-name", "card"),
+    ("container", "card / size"),
+    ("mix-blend-mode", "multiply"),
+    ("isolation", "isolate"),
+    ("scroll-behavior", "smooth"),
+    ("accent-color", "red"),
+    ("caret-color", "red"),
+    ("color-scheme", "dark"),
+    ("transition", "opacity 1s"),
+    ("transition-property", "opacity"),
+    ("transition-duration", "1s"),
+    ("transition-timing-function", "linear"),
+    ("transition-delay", "1s"),
+    ("animation", "spin 1s"),
+    ("animation-name", "spin"),
+    ("animation-duration", "1s"),
+    ("animation-timing-function", "linear"),
+    ("animation-delay", "1s"),
+    ("animation-iteration-count", "infinite"),
+    ("animation-direction", "alternate"),
+    ("animation-fill-mode", "both"),
+    ("animation-play-state", "paused"),
+];
+
+/// Whether the style engine understands `decl`, for `@supports`.
+pub fn supports_declaration(decl: &Declaration) -> bool {
+    if decl.name.starts_with("--") {
+        return true;
+    }
+    let name = strip_vendor_prefix(&decl.name);
+    let Some((_, sample)) = PROPERTIES.iter().find(|(property, _)| *property == name) else {
+        return false;
+    };
+    match decl.value.as_slice() {
+        [] => return false,
+        // CSS-wide keywords are valid for every property, and a `var()`
+        // can't be checked until it is substituted.
+        [CssValue::Inherit | CssValue::Initial | CssValue::Unset] => return true,
+        values if values.iter().any(contains_var) => return true,
+        _ => {}
+    }
+
+    let default = ComputedStyle::default();
+    if applied(&default, decl) != default {
+        return true;
+    }
+    let sample = parse_declaration_block(&CssTokenizer::new(&format!("{name}: {sample}")).tokenize_all());
+    let mut sampled = default;
+    for sample in &sample {
+        sampled = applied(&sampled, sample);
+    }
+    applied(&sampled, decl) != sampled
+}
+
+/// `style` with `decl` applied, its values resolved as the cascade would.
+fn applied(style: &ComputedStyle, decl: &Declaration) -> ComputedStyle {
+    let ctx = ResolveContext::new(1280.0, 800.0);
+    let values = resolve_css_values(&decl.value, &ctx);
+    let values = resolve_property_percentages(&decl.name, &values, &ctx);
+    let values = resolve_remaining_calcs(&values, &ctx);
+    let resolved = Declaration { name: decl.name.clone(), value: values, important: false };
+    let mut style = style.clone();
+    apply_declaration(&mut style, &resolved, None);
+    style
+}
+
+fn contains_var(value: &CssValue) -> bool {
+    match value {
+        CssValue::Function { name, args } => name.eq_ignore_ascii_case("var") || args.iter().any(contains_var),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn supports(css: &str) -> bool {
+        let decls = parse_declaration_block(&CssTokenizer::new(css).tokenize_all());
+        supports_declaration(&decls[0])
+    }
+
+    #[test]
+    fn test_every_sample_changes_the_default_style() {
+        let default = ComputedStyle::default();
+        let unchanged: Vec<&str> = PROPERTIES
+            .iter()
+            .filter(|(name, sample)| {
+                let decls = parse_declaration_block(&CssTokenizer::new(&format!("{name}: {sample}")).tokenize_all());
+                decls.iter().fold(default.clone(), |style, decl| applied(&style, decl)) == default
+            })
+            .map(|(name, _)| *name)
+            .collect();
+        assert!(unchanged.is_empty(), "samples with no effect: {unchanged:?}");
+    }
+
+    #[test]
+    fn test_values_the_cascade_understands() {
+        assert!(supports("display: grid"));
+        assert!(supports("display: inline"));
+        assert!(supports("position: sticky"));
+        assert!(supports("-webkit-box-shadow: 0 1px 2px black"));
+        assert!(supports("gap: calc(1rem + 2px)"));
+        assert!(supports("color: var(--accent)"));
+        assert!(supports("--anything: at all"));
+        assert!(supports("margin: initial"));
+        assert!(!supports("display: subgrid"));
+        assert!(!supports("position: -webkit-sticky-ish"));
+        assert!(!supports("clip-path: circle(50%)"));
+        assert!(!supports("no-such-property: 1"));
+    }
+}
@@ -0,0 +1,245 @@
+//! # SVG
+//!
+//! Renders SVG documents and inline `<svg>` subtrees into a
+//! [`paint::rasterizer::Framebuffer`]. **Zero external crates.**
+//!
+//! - `path`: Path data parsing, basic-shape outlines, curve flattening
+//! - `transform`: Affine transforms, `viewBox` and `preserveAspectRatio`
+//! - `props`: Presentation attributes, `<style>` rules and the `style` attribute
+//! - `raster`: Anti-aliased filling, stroking, solid and gradient paint
+//! - `render`: The element walk (shapes, `g`, `use`, nested `svg`, `text`)
+//!
+//! Standalone SVG files are parsed with the HTML parser, whose foreign
+//! content rules produce the same SVG-namespace tree as inline markup.
+
+pub mod path;
+pub mod props;
+pub mod raster;
+mod render;
+pub mod transform;
+
+use common::{Color, ParseError, Rect};
+use dom::{Dom, Namespace, NodeId};
+use paint::font_engine::FontEngine;
+use paint::rasterizer::Framebuffer;
+
+use crate::props::parse_length;
+use crate::transform::{ViewBox, parse_view_box};
+
+/// The size of a replaced element with no size of its own (CSS 2 §10.3.2).
+pub const DEFAULT_SIZE: (f32, f32) = (300.0, 150.0);
+
+/// Largest dimension an SVG image is rasterized at.
+const MAX_DIMENSION: u32 = 4096;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Sizing
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The size an outermost `<svg>` asks for through its attributes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IntrinsicSize {
+    /// An absolute `width` attribute (percentages don't count).
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub view_box: Option<ViewBox>,
+}
+
+impl IntrinsicSize {
+    /// Width over height, from the `viewBox` or both dimensions.
+    pub fn ratio(&self) -> Option<f32> {
+        match (self.width, self.height, self.view_box) {
+            (Some(w), Some(h), _) if h > 0.0 => Some(w / h),
+            (_, _, Some(vb)) => Some(vb.w / vb.h),
+            _ => None,
+        }
+    }
+
+    /// A concrete size: the specified dimensions, with a missing one derived
+    /// from the aspect ratio, and `default` for whatever remains.
+    pub fn resolve(&self, default: (f32, f32)) -> (f32, f32) {
+        match (self.width, self.height, self.ratio()) {
+            (Some(w), Some(h), _) => (w, h),
+            (Some(w), None, Some(r)) => (w, w / r),
+            (None, Some(h), Some(r)) => (h * r, h),
+            (Some(w), None, None) => (w, default.1),
+            (None, Some(h), None) => (default.0, h),
+            (None, None, _) => default,
+        }
+    }
+}
+
+/// Read the sizing attributes of an `<svg>` element.
+pub fn intrinsic_size(dom: &Dom, svg: NodeId) -> IntrinsicSize {
+    let absolute = |name: &str| {
+        render::attr(dom, svg, name)
+            .filter(|v| !v.trim_end().ends_with('%'))
+            .and_then(|v| parse_length(v, 0.0))
+            .filter(|v| *v >= 0.0)
+    };
+    IntrinsicSize {
+        width: absolute("width"),
+        height: absolute("height"),
+        view_box: render::attr(dom, svg, "viewBox").and_then(parse_view_box),
+    }
+}
+
+/// True for an `<svg>` element that is not inside another SVG element —
+/// the ones that are laid out and painted as replaced content.
+pub fn is_outermost_svg(dom: &Dom, node: NodeId) -> bool {
+    let Some(n) = dom.nodes.get(node) else { return false };
+    let is_svg = n
+        .as_element()
+        .is_some_and(|e| e.namespace == Namespace::Svg && e.tag_name == "svg");
+    let parent_is_svg = n
+        .parent
+        .and_then(|p| dom.nodes.get(p))
+        .and_then(|p| p.as_element())
+        .is_some_and(|e| e.namespace == Namespace::Svg);
+    is_svg && !parent_is_svg
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Rendering
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Paint the `<svg>` element `svg` into `fb`, fitting its viewport to `rect`
+/// (device pixels). `color` is the CSS `color` that `currentColor` refers
+/// to; text is skipped without a font engine.
+pub fn render(dom: &Dom, svg: NodeId, fb: &mut Framebuffer, rect: Rect, color: Color, font: Option<&mut FontEngine>) {
+    let mut renderer = render::Renderer::new(dom, svg, font);
+    renderer.render_root(fb, svg, rect, color);
+}
+
+/// A parsed standalone SVG file, such as an `<img>` source.
+pub struct SvgDocument {
+    pub dom: Dom,
+    /// The root `<svg>` element.
+    pub root: NodeId,
+}
+
+impl SvgDocument {
+    /// Parse an SVG file. Fails if it contains no `<svg>` element.
+    pub fn parse(data: &[u8]) -> Result<Self, ParseError> {
+        let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+        let text = String::from_utf8_lossy(data);
+        let dom = html::parse(&text);
+        let root = dom
+            .nodes
+            .iter()
+            .find(|(_, n)| n.as_element().is_some_and(|e| e.namespace == Namespace::Svg && e.tag_name == "svg"))
+            .map(|(id, _)| id)
+            .ok_or(ParseError::InvalidValue("SVG: no <svg> element"))?;
+        Ok(Self { dom, root })
+    }
+
+    /// The natural size in pixels. A `viewBox` alone gives its own size, so
+    /// icons declared only by `viewBox="0 0 24 24"` come out at 24×24.
+    pub fn natural_size(&self) -> (u32, u32) {
+        let intrinsic = intrinsic_size(&self.dom, self.root);
+        let default = intrinsic.view_box.map_or(DEFAULT_SIZE, |vb| (vb.w, vb.h));
+        let (w, h) = intrinsic.resolve(default);
+        (clamp_dimension(w), clamp_dimension(h))
+    }
+
+    /// Rasterize onto a transparent framebuffer of `width × height`.
+    pub fn rasterize(&self, width: u32, height: u32, font: Option<&mut FontEngine>) -> Framebuffer {
+        let (width, height) = (width.clamp(1, MAX_DIMENSION), height.clamp(1, MAX_DIMENSION));
+        let mut fb = Framebuffer::new(width, height);
+        fb.clear(0);
+        let rect = Rect::new(0.0, 0.0, width as f32, height as f32);
+        render(&self.dom, self.root, &mut fb, rect, Color::BLACK, font);
+        fb
+    }
+}
+
+fn clamp_dimension(v: f32) -> u32 {
+    (v.round() as u32).clamp(1, MAX_DIMENSION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pixel(fb: &Framebuffer, x: u32, y: u32) -> u32 {
+        fb.pixels[(y * fb.width + x) as usize]
+    }
+
+    #[test]
+    fn natural_size_from_attributes_and_view_box() {
+        let doc = SvgDocument::parse(br#"<svg width="40" viewBox="0 0 20 10"/>"#).unwrap();
+        assert_eq!(doc.natural_size(), (40, 20));
+        let doc = SvgDocument::parse(br#"<svg viewBox="0 0 24 24"></svg>"#).unwrap();
+        assert_eq!(doc.natural_size(), (24, 24));
+        let doc = SvgDocument::parse(br#"<svg width="100%"></svg>"#).unwrap();
+        assert_eq!(doc.natural_size(), (300, 150));
+        assert!(SvgDocument::parse(b"<p>not svg</p>").is_err());
+    }
+
+    #[test]
+    fn renders_shapes_through_view_box() {
+        let doc = SvgDocument::parse(
+            br##"<?xml version="1.0"?>
+            <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 10 10">
+              <rect width="5" height="10" fill="#ff0000"/>
+              <circle cx="7.5" cy="5" r="2" fill="blue"/>
+            </svg>"##,
+        )
+        .unwrap();
+        let fb = doc.rasterize(20, 20, None);
+        assert_eq!(pixel(&fb, 2, 10), 0xFFFF_0000);
+        assert_eq!(pixel(&fb, 15, 10), 0xFF00_00FF);
+        assert_eq!(pixel(&fb, 15, 1), 0);
+    }
+
+    #[test]
+    fn use_references_symbol_with_current_color() {
+        let doc = SvgDocument::parse(
+            br##"<svg width="10" height="10" style="color: lime">
+              <defs><symbol id="s" viewBox="0 0 1 1"><path d="M0 0H1V1H0z" fill="currentColor"/></symbol></defs>
+              <use href="#s" width="10" height="10"/>
+            </svg>"##,
+        )
+        .unwrap();
+        let fb = doc.rasterize(10, 10, None);
+        assert_eq!(pixel(&fb, 5, 5), 0xFF00_FF00);
+    }
+
+    #[test]
+    fn gradient_fill_in_bounding_box_units() {
+        let doc = SvgDocument::parse(
+            br##"<svg width="100" height="10">
+              <linearGradient id="g"><stop offset="0" stop-color="black"/><stop offset="1" stop-color="white"/></linearGradient>
+              <rect x="0" y="0" width="100" height="10" fill="url(#g)"/>
+            </svg>"##,
+        )
+        .unwrap();
+        let fb = doc.rasterize(100, 10, None);
+        let left = pixel(&fb, 1, 5) & 0xFF;
+        let mid = pixel(&fb, 50, 5) & 0xFF;
+        let right = pixel(&fb, 98, 5) & 0xFF;
+        assert!(left < 10 && (120..=135).contains(&mid) && right > 245, "{left} {mid} {right}");
+    }
+
+    #[test]
+    fn strokes_and_group_transforms() {
+        let doc = SvgDocument::parse(
+            br##"<svg width="20" height="20">
+              <g transform="translate(10 0)"><line x1="0" y1="0" x2="0" y2="20" stroke="black" stroke-width="4"/></g>
+            </svg>"##,
+        )
+        .unwrap();
+        let fb = doc.rasterize(20, 20, None);
+        assert_eq!(pixel(&fb, 9, 10), 0xFF00_0000);
+        assert_eq!(pixel(&fb, 5, 10), 0);
+    }
+
+    #[test]
+    fn outermost_svg_detection() {
+        let dom = html::parse("<svg><svg></svg></svg>");
+        let doc = dom.nodes.iter().next().map(|(id, _)| id).unwrap();
+        let svgs = dom.get_elements_by_tag(doc, "svg");
+        assert!(is_outermost_svg(&dom, svgs[0]));
+        assert!(!is_outermost_svg(&dom, svgs[1]));
+    }
+}
@@ -0,0 +1,613 @@
+//! Path geometry: the `d` attribute grammar, basic-shape outlines, and
+//! flattening curves into polylines for the rasterizer.
+
+use common::Vec2;
+
+use crate::transform::Transform;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Number scanning
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Scans SVG numbers out of attribute text, skipping separators.
+///
+/// Handles the compact forms path data allows, such as `1.5.5` (two
+/// numbers) and `10-5` (a sign starts a new number).
+pub struct NumberScanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> NumberScanner<'a> {
+    pub fn new(s: &'a str) -> Self {
+        Self { bytes: s.as_bytes(), pos: 0 }
+    }
+
+    fn skip_separators(&mut self) {
+        while self.pos < self.bytes.len()
+            && (self.bytes[self.pos].is_ascii_whitespace() || self.bytes[self.pos] == b',')
+        {
+            self.pos += 1;
+        }
+    }
+
+    /// True once only separators remain.
+    pub fn at_end(&mut self) -> bool {
+        self.skip_separators();
+        self.pos >= self.bytes.len()
+    }
+
+    /// The next non-separator byte, without consuming it.
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_separators();
+        self.bytes.get(self.pos).copied()
+    }
+
+    /// Consume the next number, or return `None` (consuming nothing) if the
+    /// input does not continue with one.
+    pub fn number(&mut self) -> Option<f32> {
+        self.skip_separators();
+        let b = self.bytes;
+        let start = self.pos;
+        let mut i = start;
+        if i < b.len() && (b[i] == b'+' || b[i] == b'-') {
+            i += 1;
+        }
+        let int_start = i;
+        while i < b.len() && b[i].is_ascii_digit() {
+            i += 1;
+        }
+        let mut digits = i > int_start;
+        if i < b.len() && b[i] == b'.' {
+            let frac_start = i + 1;
+            let mut j = frac_start;
+            while j < b.len() && b[j].is_ascii_digit() {
+                j += 1;
+            }
+            if j > frac_start {
+                digits = true;
+                i = j;
+            } else if digits {
+                i = j;
+            }
+        }
+        if !digits {
+            return None;
+        }
+        if i < b.len() && (b[i] == b'e' || b[i] == b'E') {
+            let mut j = i + 1;
+            if j < b.len() && (b[j] == b'+' || b[j] == b'-') {
+                j += 1;
+            }
+            let exp_start = j;
+            while j < b.len() && b[j].is_ascii_digit() {
+                j += 1;
+            }
+            if j > exp_start {
+                i = j;
+            }
+        }
+        let text = std::str::from_utf8(&b[start..i]).ok()?;
+        let value = text.parse::<f32>().ok()?;
+        self.pos = i;
+        Some(value)
+    }
+
+    /// Consume an arc flag, which is a single `0` or `1` that need not be
+    /// separated from what follows.
+    fn flag(&mut self) -> Option<bool> {
+        match self.peek()? {
+            b'0' => {
+                self.pos += 1;
+                Some(false)
+            }
+            b'1' => {
+                self.pos += 1;
+                Some(true)
+            }
+            _ => None,
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Path
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// One absolute path segment.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Segment {
+    MoveTo(Vec2),
+    LineTo(Vec2),
+    QuadTo(Vec2, Vec2),
+    CubicTo(Vec2, Vec2, Vec2),
+    Close,
+}
+
+/// A sequence of absolute segments in user space.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path {
+    pub segments: Vec<Segment>,
+}
+
+/// A flattened subpath in device space.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polyline {
+    pub points: Vec<Vec2>,
+    pub closed: bool,
+}
+
+/// Cubic Bézier control-point distance for approximating a quarter circle.
+const KAPPA: f32 = 0.552_284_8;
+
+impl Path {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    pub fn move_to(&mut self, x: f32, y: f32) {
+        self.segments.push(Segment::MoveTo(Vec2::new(x, y)));
+    }
+
+    pub fn line_to(&mut self, x: f32, y: f32) {
+        self.segments.push(Segment::LineTo(Vec2::new(x, y)));
+    }
+
+    pub fn cubic_to(&mut self, c1: Vec2, c2: Vec2, p: Vec2) {
+        self.segments.push(Segment::CubicTo(c1, c2, p));
+    }
+
+    pub fn close(&mut self) {
+        self.segments.push(Segment::Close);
+    }
+
+    /// A rectangle, with corners rounded by `rx` × `ry` when both are positive.
+    pub fn rect(x: f32, y: f32, w: f32, h: f32, rx: f32, ry: f32) -> Path {
+        let mut p = Path::new();
+        let rx = rx.min(w / 2.0);
+        let ry = ry.min(h / 2.0);
+        if rx <= 0.0 || ry <= 0.0 {
+            p.move_to(x, y);
+            p.line_to(x + w, y);
+            p.line_to(x + w, y + h);
+            p.line_to(x, y + h);
+            p.close();
+            return p;
+        }
+        let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+        let (r, b) = (x + w, y + h);
+        p.move_to(x + rx, y);
+        p.line_to(r - rx, y);
+        p.cubic_to(Vec2::new(r - rx + kx, y), Vec2::new(r, y + ry - ky), Vec2::new(r, y + ry));
+        p.line_to(r, b - ry);
+        p.cubic_to(Vec2::new(r, b - ry + ky), Vec2::new(r - rx + kx, b), Vec2::new(r - rx, b));
+        p.line_to(x + rx, b);
+        p.cubic_to(Vec2::new(x + rx - kx, b), Vec2::new(x, b - ry + ky), Vec2::new(x, b - ry));
+        p.line_to(x, y + ry);
+        p.cubic_to(Vec2::new(x, y + ry - ky), Vec2::new(x + rx - kx, y), Vec2::new(x + rx, y));
+        p.close();
+        p
+    }
+
+    /// An ellipse centred on `(cx, cy)`, drawn clockwise from the right.
+    pub fn ellipse(cx: f32, cy: f32, rx: f32, ry: f32) -> Path {
+        let mut p = Path::new();
+        let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+        p.move_to(cx + rx, cy);
+        p.cubic_to(Vec2::new(cx + rx, cy + ky), Vec2::new(cx + kx, cy + ry), Vec2::new(cx, cy + ry));
+        p.cubic_to(Vec2::new(cx - kx, cy + ry), Vec2::new(cx - rx, cy + ky), Vec2::new(cx - rx, cy));
+        p.cubic_to(Vec2::new(cx - rx, cy - ky), Vec2::new(cx - kx, cy - ry), Vec2::new(cx, cy - ry));
+        p.cubic_to(Vec2::new(cx + kx, cy - ry), Vec2::new(cx + rx, cy - ky), Vec2::new(cx + rx, cy));
+        p.close();
+        p
+    }
+
+    /// A polyline or polygon from a `points` attribute.
+    pub fn polyline(points: &str, close: bool) -> Path {
+        let mut scanner = NumberScanner::new(points);
+        let mut p = Path::new();
+        while let (Some(x), Some(y)) = (scanner.number(), scanner.number()) {
+            if p.is_empty() {
+                p.move_to(x, y);
+            } else {
+                p.line_to(x, y);
+            }
+        }
+        if close && !p.is_empty() {
+            p.close();
+        }
+        p
+    }
+
+    /// The axis-aligned bounding box `(min, max)` of the segments' points,
+    /// including curve control points (a slight over-estimate for curves).
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        let mut acc: Option<(Vec2, Vec2)> = None;
+        let mut add = |p: Vec2| {
+            acc = Some(match acc {
+                Some((lo, hi)) => (lo.min(p), hi.max(p)),
+                None => (p, p),
+            });
+        };
+        for seg in &self.segments {
+            match *seg {
+                Segment::MoveTo(p) | Segment::LineTo(p) => add(p),
+                Segment::QuadTo(c, p) => {
+                    add(c);
+                    add(p);
+                }
+                Segment::CubicTo(c1, c2, p) => {
+                    add(c1);
+                    add(c2);
+                    add(p);
+                }
+                Segment::Close => {}
+            }
+        }
+        acc
+    }
+
+    /// Transform the path into device space and flatten its curves.
+    ///
+    /// `tolerance` is the maximum deviation in device pixels.
+    pub fn flatten(&self, transform: &Transform, tolerance: f32) -> Vec<Polyline> {
+        let mut out: Vec<Polyline> = Vec::new();
+        let mut current: Vec<Vec2> = Vec::new();
+        let mut start = Vec2::ZERO;
+        let mut last = Vec2::ZERO;
+        let finish = |current: &mut Vec<Vec2>, out: &mut Vec<Polyline>, closed: bool| {
+            if current.len() > 1 {
+                out.push(Polyline { points: std::mem::take(current), closed });
+            } else {
+                current.clear();
+            }
+        };
+        for seg in &self.segments {
+            match *seg {
+                Segment::MoveTo(p) => {
+                    finish(&mut current, &mut out, false);
+                    start = transform.apply(p);
+                    last = start;
+                    current.push(start);
+                }
+                Segment::LineTo(p) => {
+                    if current.is_empty() {
+                        current.push(last);
+                    }
+                    last = transform.apply(p);
+                    current.push(last);
+                }
+                Segment::QuadTo(c, p) => {
+                    if current.is_empty() {
+                        current.push(last);
+                    }
+                    let (c, p) = (transform.apply(c), transform.apply(p));
+                    flatten_cubic(last, last.lerp(c, 2.0 / 3.0), p.lerp(c, 2.0 / 3.0), p, tolerance, &mut current);
+                    last = p;
+                }
+                Segment::CubicTo(c1, c2, p) => {
+                    if current.is_empty() {
+                        current.push(last);
+                    }
+                    let (c1, c2, p) = (transform.apply(c1), transform.apply(c2), transform.apply(p));
+                    flatten_cubic(last, c1, c2, p, tolerance, &mut current);
+                    last = p;
+                }
+                Segment::Close => {
+                    // A lone "M x y Z" still needs its point for square and
+                    // round caps, so keep single-point closed subpaths.
+                    if current.len() == 1 {
+                        current.push(current[0]);
+                    }
+                    finish(&mut current, &mut out, true);
+                    last = start;
+                }
+            }
+        }
+        finish(&mut current, &mut out, false);
+        out
+    }
+}
+
+/// Flatten a cubic Bézier by uniform subdivision, with the step count chosen
+/// from the control polygon's deviation. Emits every point except `p0`.
+fn flatten_cubic(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, tolerance: f32, out: &mut Vec<Vec2>) {
+    let dd1 = (p0 - p1 * 2.0 + p2).length();
+    let dd2 = (p1 - p2 * 2.0 + p3).length();
+    let dd = dd1.max(dd2);
+    // Standard bound: n ≥ sqrt(3·dd / (4·tol)) keeps the error under tol.
+    let n = ((0.75 * dd / tolerance.max(0.01)).sqrt().ceil() as usize).clamp(1, 256);
+    for i in 1..=n {
+        let t = i as f32 / n as f32;
+        let mt = 1.0 - t;
+        let p = p0 * (mt * mt * mt) + p1 * (3.0 * mt * mt * t) + p2 * (3.0 * mt * t * t) + p3 * (t * t * t);
+        out.push(p);
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Path data parsing
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Parse SVG path data (the `d` attribute).
+///
+/// Relative commands, implicit repeats, smooth curves and elliptical arcs are
+/// resolved into absolute [`Segment`]s. On an error, the path up to the error
+/// is returned, as SVG's error handling requires.
+pub fn parse_path_data(d: &str) -> Path {
+    let mut path = Path::new();
+    let mut s = NumberScanner::new(d);
+    let mut cur = Vec2::ZERO;
+    let mut start = Vec2::ZERO;
+    // The reflected control point for S/T, with the command kind it came from.
+    let mut last_cubic_ctrl: Option<Vec2> = None;
+    let mut last_quad_ctrl: Option<Vec2> = None;
+    let mut command: Option<u8> = None;
+
+    while let Some(next) = s.peek() {
+        let cmd = if next.is_ascii_alphabetic() {
+            s.pos += 1;
+            next
+        } else {
+            // Implicit repetition; a repeated moveto becomes a lineto.
+            match command {
+                Some(b'M') => b'L',
+                Some(b'm') => b'l',
+                Some(b'Z') | Some(b'z') | None => break,
+                Some(c) => c,
+            }
+        };
+        if path.is_empty() && cmd != b'M' && cmd != b'm' {
+            break;
+        }
+        let rel = cmd.is_ascii_lowercase();
+        let base = if rel { cur } else { Vec2::ZERO };
+        let point = |s: &mut NumberScanner| -> Option<Vec2> {
+            let x = s.number()?;
+            let y = s.number()?;
+            Some(Vec2::new(x, y) + base)
+        };
+        let ok = (|| -> Option<()> {
+            let mut cubic_ctrl = None;
+            let mut quad_ctrl = None;
+            match cmd.to_ascii_uppercase() {
+                b'M' => {
+                    let p = point(&mut s)?;
+                    path.segments.push(Segment::MoveTo(p));
+                    cur = p;
+                    start = p;
+                }
+                b'L' => {
+                    let p = point(&mut s)?;
+                    path.segments.push(Segment::LineTo(p));
+                    cur = p;
+                }
+                b'H' => {
+                    let x = s.number()? + if rel { cur.x } else { 0.0 };
+                    cur = Vec2::new(x, cur.y);
+                    path.segments.push(Segment::LineTo(cur));
+                }
+                b'V' => {
+                    let y = s.number()? + if rel { cur.y } else { 0.0 };
+                    cur = Vec2::new(cur.x, y);
+                    path.segments.push(Segment::LineTo(cur));
+                }
+                b'C' => {
+                    let c1 = point(&mut s)?;
+                    let c2 = point(&mut s)?;
+                    let p = point(&mut s)?;
+                    path.segments.push(Segment::CubicTo(c1, c2, p));
+                    cubic_ctrl = Some(c2);
+                    cur = p;
+                }
+                b'S' => {
+                    let c1 = match last_cubic_ctrl {
+                        Some(c) => cur * 2.0 - c,
+                        None => cur,
+                    };
+                    let c2 = point(&mut s)?;
+                    let p = point(&mut s)?;
+                    path.segments.push(Segment::CubicTo(c1, c2, p));
+                    cubic_ctrl = Some(c2);
+                    cur = p;
+                }
+                b'Q' => {
+                    let c = point(&mut s)?;
+                    let p = point(&mut s)?;
+                    path.segments.push(Segment::QuadTo(c, p));
+                    quad_ctrl = Some(c);
+                    cur = p;
+                }
+                b'T' => {
+                    let c = match last_quad_ctrl {
+                        Some(c) => cur * 2.0 - c,
+                        None => cur,
+                    };
+                    let p = point(&mut s)?;
+                    path.segments.push(Segment::QuadTo(c, p));
+                    quad_ctrl = Some(c);
+                    cur = p;
+                }
+                b'A' => {
+                    let rx = s.number()?;
+                    let ry = s.number()?;
+                    let rotation = s.number()?;
+                    let large_arc = s.flag()?;
+                    let sweep = s.flag()?;
+                    let p = point(&mut s)?;
+                    arc_to(&mut path, cur, rx, ry, rotation, large_arc, sweep, p);
+                    cur = p;
+                }
+                b'Z' => {
+                    path.segments.push(Segment::Close);
+                    cur = start;
+                }
+                _ => return None,
+            }
+            last_cubic_ctrl = cubic_ctrl;
+            last_quad_ctrl = quad_ctrl;
+            Some(())
+        })();
+        if ok.is_none() {
+            break;
+        }
+        command = Some(cmd);
+    }
+    path
+}
+
+/// Append an elliptical arc from `from` to `to` as cubic Béziers, using the
+/// endpoint-to-centre conversion from SVG 1.1 Appendix F.6.
+#[allow(clippy::too_many_arguments)]
+fn arc_to(
+    path: &mut Path,
+    from: Vec2,
+    rx: f32,
+    ry: f32,
+    rotation: f32,
+    large_arc: bool,
+    sweep: bool,
+    to: Vec2,
+) {
+    if from == to {
+        return;
+    }
+    let mut rx = rx.abs();
+    let mut ry = ry.abs();
+    if rx == 0.0 || ry == 0.0 {
+        path.segments.push(Segment::LineTo(to));
+        return;
+    }
+    let (sin_phi, cos_phi) = rotation.to_radians().sin_cos();
+    let dx = (from.x - to.x) / 2.0;
+    let dy = (from.y - to.y) / 2.0;
+    let x1p = cos_phi * dx + sin_phi * dy;
+    let y1p = -sin_phi * dx + cos_phi * dy;
+
+    // Scale up radii that are too small to span the endpoints.
+    let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+    if lambda > 1.0 {
+        let s = lambda.sqrt();
+        rx *= s;
+        ry *= s;
+    }
+
+    let num = rx * rx * ry * ry - rx * rx * y1p * y1p - ry * ry * x1p * x1p;
+    let den = rx * rx * y1p * y1p + ry * ry * x1p * x1p;
+    let mut coef = if den == 0.0 { 0.0 } else { (num / den).max(0.0).sqrt() };
+    if large_arc == sweep {
+        coef = -coef;
+    }
+    let cxp = coef * rx * y1p / ry;
+    let cyp = -coef * ry * x1p / rx;
+    let cx = cos_phi * cxp - sin_phi * cyp + (from.x + to.x) / 2.0;
+    let cy = sin_phi * cxp + cos_phi * cyp + (from.y + to.y) / 2.0;
+
+    let angle = |ux: f32, uy: f32, vx: f32, vy: f32| {
+        let a = (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+        if a.is_nan() { 0.0 } else { a }
+    };
+    let ux = (x1p - cxp) / rx;
+    let uy = (y1p - cyp) / ry;
+    let vx = (-x1p - cxp) / rx;
+    let vy = (-y1p - cyp) / ry;
+    let theta1 = angle(1.0, 0.0, ux, uy);
+    let mut delta = angle(ux, uy, vx, vy);
+    if !sweep && delta > 0.0 {
+        delta -= std::f32::consts::TAU;
+    } else if sweep && delta < 0.0 {
+        delta += std::f32::consts::TAU;
+    }
+
+    // One cubic per quarter turn (or less).
+    let pieces = (delta.abs() / std::f32::consts::FRAC_PI_2).ceil().max(1.0) as usize;
+    let step = delta / pieces as f32;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+    let point_at = |t: f32| {
+        let (s, c) = t.sin_cos();
+        Vec2::new(
+            cx + rx * c * cos_phi - ry * s * sin_phi,
+            cy + rx * c * sin_phi + ry * s * cos_phi,
+        )
+    };
+    let deriv_at = |t: f32| {
+        let (s, c) = t.sin_cos();
+        Vec2::new(
+            -rx * s * cos_phi - ry * c * sin_phi,
+            -rx * s * sin_phi + ry * c * cos_phi,
+        )
+    };
+    let mut t = theta1;
+    let mut p0 = from;
+    for i in 0..pieces {
+        let t2 = t + step;
+        let p3 = if i + 1 == pieces { to } else { point_at(t2) };
+        let c1 = p0 + deriv_at(t) * k;
+        let c2 = p3 - deriv_at(t2) * k;
+        path.segments.push(Segment::CubicTo(c1, c2, p3));
+        p0 = p3;
+        t = t2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact_numbers() {
+        let mut s = NumberScanner::new("1.5.5-2e1,3");
+        assert_eq!(s.number(), Some(1.5));
+        assert_eq!(s.number(), Some(0.5));
+        assert_eq!(s.number(), Some(-20.0));
+        assert_eq!(s.number(), Some(3.0));
+        assert!(s.at_end());
+    }
+
+    #[test]
+    fn relative_commands_and_implicit_lineto() {
+        let p = parse_path_data("m10 10 5 0 0 5h-5z");
+        assert_eq!(
+            p.segments,
+            vec![
+                Segment::MoveTo(Vec2::new(10.0, 10.0)),
+                Segment::LineTo(Vec2::new(15.0, 10.0)),
+                Segment::LineTo(Vec2::new(15.0, 15.0)),
+                Segment::LineTo(Vec2::new(10.0, 15.0)),
+                Segment::Close,
+            ]
+        );
+    }
+
+    #[test]
+    fn error_keeps_valid_prefix() {
+        let p = parse_path_data("M0 0 L10 10 L20 x");
+        assert_eq!(p.segments.len(), 2);
+        assert!(parse_path_data("L10 10").is_empty());
+    }
+
+    #[test]
+    fn arc_with_compact_flags_ends_at_target() {
+        let p = parse_path_data("M0 0a10 10 0 1110 10");
+        match p.segments.last() {
+            Some(Segment::CubicTo(_, _, end)) => {
+                assert!((end.x - 10.0).abs() < 1e-3 && (end.y - 10.0).abs() < 1e-3);
+            }
+            other => panic!("expected a cubic, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn flattened_circle_stays_on_radius() {
+        let lines = Path::ellipse(0.0, 0.0, 50.0, 50.0).flatten(&Transform::IDENTITY, 0.1);
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].closed);
+        for p in &lines[0].points {
+            assert!((p.length() - 50.0).abs() < 0.5, "{p:?}");
+        }
+    }
+}
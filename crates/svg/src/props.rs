@@ -0,0 +1,371 @@
+//! SVG presentation properties and their cascade.
+//!
+//! Each element starts from its parent's inherited properties, then applies
+//! presentation attributes, matching `<style>` rules, and finally its own
+//! `style` attribute — the same precedence browsers use.
+
+use common::Color;
+use css::{CssColor, CssTokenizer, CssValue, Declaration, LengthUnit};
+use dom::{Dom, NodeId};
+
+use crate::raster::{FillRule, LineCap, LineJoin};
+
+/// What a fill or stroke refers to before paint servers are resolved.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PaintRef {
+    None,
+    Color(Color),
+    CurrentColor,
+    /// A `url(#id)` reference to a paint server, with the fallback used if
+    /// the reference does not resolve.
+    Url(String, Box<PaintRef>),
+}
+
+/// `text-anchor` alignment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextAnchor {
+    Start,
+    Middle,
+    End,
+}
+
+/// The computed presentation properties of one element.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Props {
+    // Inherited.
+    pub fill: PaintRef,
+    pub fill_opacity: f32,
+    pub fill_rule: FillRule,
+    pub stroke: PaintRef,
+    pub stroke_opacity: f32,
+    pub stroke_width: f32,
+    pub line_cap: LineCap,
+    pub line_join: LineJoin,
+    pub miter_limit: f32,
+    pub color: Color,
+    pub font_size: f32,
+    pub text_anchor: TextAnchor,
+    pub visible: bool,
+    // Not inherited.
+    pub opacity: f32,
+    pub display: bool,
+    pub stop_color: Color,
+    pub stop_opacity: f32,
+}
+
+impl Props {
+    /// The initial values, with `color` taken from the embedding context.
+    pub fn initial(color: Color) -> Self {
+        Self {
+            fill: PaintRef::Color(Color::BLACK),
+            fill_opacity: 1.0,
+            fill_rule: FillRule::NonZero,
+            stroke: PaintRef::None,
+            stroke_opacity: 1.0,
+            stroke_width: 1.0,
+            line_cap: LineCap::Butt,
+            line_join: LineJoin::Miter,
+            miter_limit: 4.0,
+            color,
+            font_size: 16.0,
+            text_anchor: TextAnchor::Start,
+            visible: true,
+            opacity: 1.0,
+            display: true,
+            stop_color: Color::BLACK,
+            stop_opacity: 1.0,
+        }
+    }
+
+    /// A child's starting point: inherited properties kept, the rest reset.
+    pub fn inherit(&self) -> Self {
+        Self {
+            opacity: 1.0,
+            display: true,
+            stop_color: Color::BLACK,
+            stop_opacity: 1.0,
+            ..self.clone()
+        }
+    }
+}
+
+/// The presentation attributes this renderer understands; they double as
+/// the CSS property names.
+const PROPERTIES: &[&str] = &[
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "stroke",
+    "stroke-opacity",
+    "stroke-width",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "color",
+    "font-size",
+    "text-anchor",
+    "visibility",
+    "opacity",
+    "display",
+    "stop-color",
+    "stop-opacity",
+];
+
+/// Compute the properties of `node` given its parent's.
+pub fn compute(
+    dom: &Dom,
+    node: NodeId,
+    parent: &Props,
+    sheets: &[(css::Stylesheet, style::StyleOrigin)],
+) -> Props {
+    let mut props = parent.inherit();
+    let Some(elem) = dom.nodes.get(node).and_then(|n| n.as_element()) else {
+        return props;
+    };
+
+    for attr in &elem.attrs {
+        if PROPERTIES.contains(&attr.name.as_str()) {
+            let tokens = CssTokenizer::new(&attr.value).tokenize_all();
+            let values = css::value::parse_value_from_tokens(&tokens);
+            apply(&mut props, &attr.name, &values, parent);
+        }
+    }
+
+    if !sheets.is_empty() {
+        let mut matched = style::collect_matching_rules(dom, node, sheets);
+        matched.sort_by_key(|m| (m.specificity, m.source_order));
+        let mut important: Vec<&Declaration> = Vec::new();
+        for rule in &matched {
+            for decl in &rule.declarations {
+                if decl.important {
+                    important.push(decl);
+                } else {
+                    apply(&mut props, &decl.name, &decl.value, parent);
+                }
+            }
+        }
+        for decl in important {
+            apply(&mut props, &decl.name, &decl.value, parent);
+        }
+    }
+
+    if let Some(style_attr) = elem.attrs.iter().find(|a| a.name == "style") {
+        let tokens = CssTokenizer::new(&style_attr.value).tokenize_all();
+        for decl in css::parse_declaration_block(&tokens) {
+            apply(&mut props, &decl.name, &decl.value, parent);
+        }
+    }
+    props
+}
+
+/// Apply one declaration. Unknown properties and invalid values are ignored.
+fn apply(props: &mut Props, name: &str, values: &[CssValue], parent: &Props) {
+    let Some(first) = values.first() else { return };
+    if *first == CssValue::Inherit {
+        match name {
+            "opacity" => props.opacity = parent.opacity,
+            "display" => props.display = parent.display,
+            "stop-color" => props.stop_color = parent.stop_color,
+            "stop-opacity" => props.stop_opacity = parent.stop_opacity,
+            _ => {}
+        }
+        return;
+    }
+    match name {
+        "fill" => {
+            if let Some(p) = paint_ref(values) {
+                props.fill = p;
+            }
+        }
+        "stroke" => {
+            if let Some(p) = paint_ref(values) {
+                props.stroke = p;
+            }
+        }
+        "fill-opacity" => set_opt(&mut props.fill_opacity, alpha_value(first)),
+        "stroke-opacity" => set_opt(&mut props.stroke_opacity, alpha_value(first)),
+        "opacity" => set_opt(&mut props.opacity, alpha_value(first)),
+        "stop-opacity" => set_opt(&mut props.stop_opacity, alpha_value(first)),
+        "stroke-width" => {
+            if let Some(w) = length_value(first, props.font_size)
+                && w >= 0.0
+            {
+                props.stroke_width = w;
+            }
+        }
+        "stroke-miterlimit" => {
+            if let CssValue::Number(n) = first
+                && *n >= 1.0
+            {
+                props.miter_limit = *n as f32;
+            }
+        }
+        "font-size" => {
+            if let Some(size) = length_value(first, parent.font_size) {
+                props.font_size = size.max(0.0);
+            }
+        }
+        "color" => {
+            if let CssValue::Color(c) = first {
+                props.color = to_color(*c);
+            }
+        }
+        "stop-color" => match first {
+            CssValue::Color(c) => props.stop_color = to_color(*c),
+            CssValue::Keyword(k) if k == "currentcolor" => props.stop_color = props.color,
+            _ => {}
+        },
+        _ => {
+            let CssValue::Keyword(k) = first else {
+                if name == "display" && *first == CssValue::None {
+                    props.display = false;
+                }
+                return;
+            };
+            match (name, k.as_str()) {
+                ("fill-rule", "nonzero") => props.fill_rule = FillRule::NonZero,
+                ("fill-rule", "evenodd") => props.fill_rule = FillRule::EvenOdd,
+                ("stroke-linecap", "butt") => props.line_cap = LineCap::Butt,
+                ("stroke-linecap", "round") => props.line_cap = LineCap::Round,
+                ("stroke-linecap", "square") => props.line_cap = LineCap::Square,
+                ("stroke-linejoin", "miter" | "miter-clip" | "arcs") => props.line_join = LineJoin::Miter,
+                ("stroke-linejoin", "round") => props.line_join = LineJoin::Round,
+                ("stroke-linejoin", "bevel") => props.line_join = LineJoin::Bevel,
+                ("text-anchor", "start") => props.text_anchor = TextAnchor::Start,
+                ("text-anchor", "middle") => props.text_anchor = TextAnchor::Middle,
+                ("text-anchor", "end") => props.text_anchor = TextAnchor::End,
+                ("visibility", "visible") => props.visible = true,
+                ("visibility", "hidden" | "collapse") => props.visible = false,
+                ("display", _) => props.display = true,
+                _ => {}
+            }
+        }
+    }
+}
+
+fn set_opt(slot: &mut f32, value: Option<f32>) {
+    if let Some(v) = value {
+        *slot = v;
+    }
+}
+
+fn to_color(c: CssColor) -> Color {
+    Color::rgba(c.r, c.g, c.b, c.a)
+}
+
+fn paint_ref(values: &[CssValue]) -> Option<PaintRef> {
+    let simple = |v: &CssValue| match v {
+        CssValue::None => Some(PaintRef::None),
+        CssValue::Color(c) => Some(PaintRef::Color(to_color(*c))),
+        CssValue::Keyword(k) if k == "currentcolor" => Some(PaintRef::CurrentColor),
+        _ => None,
+    };
+    match values.first()? {
+        CssValue::Url(url) => {
+            let fallback = values.get(1).and_then(simple).unwrap_or(PaintRef::None);
+            let id = url.trim().strip_prefix('#')?.to_string();
+            Some(PaintRef::Url(id, Box::new(fallback)))
+        }
+        v => simple(v),
+    }
+}
+
+/// An opacity: a number or a percentage, clamped to `[0, 1]`.
+fn alpha_value(v: &CssValue) -> Option<f32> {
+    match v {
+        CssValue::Number(n) => Some((*n as f32).clamp(0.0, 1.0)),
+        CssValue::Percentage(p) => Some((*p as f32 / 100.0).clamp(0.0, 1.0)),
+        _ => None,
+    }
+}
+
+/// A length in user units. Percentages are not supported here and resolve
+/// to `None`.
+fn length_value(v: &CssValue, font_size: f32) -> Option<f32> {
+    match v {
+        CssValue::Number(n) => Some(*n as f32),
+        CssValue::Length(n, unit) => {
+            let n = *n as f32;
+            Some(match unit {
+                LengthUnit::Em | LengthUnit::Rem => n * font_size,
+                LengthUnit::Ex | LengthUnit::Ch => n * font_size / 2.0,
+                LengthUnit::Pt => n * 4.0 / 3.0,
+                _ => n,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Parse an SVG length attribute (`10`, `10px`, `2em`, `50%`).
+///
+/// Percentages resolve against `reference`.
+pub fn parse_length(s: &str, reference: f32) -> Option<f32> {
+    let s = s.trim();
+    let num_end = s
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E')))
+        .unwrap_or(s.len());
+    // Back off a trailing `e`/`E` that begins a unit such as `em` or `ex`.
+    let num_end = if num_end > 0 && matches!(s.as_bytes()[num_end - 1], b'e' | b'E') {
+        num_end - 1
+    } else {
+        num_end
+    };
+    let value: f32 = s[..num_end].parse().ok()?;
+    let factor = match s[num_end..].trim() {
+        "" | "px" => 1.0,
+        "%" => reference / 100.0,
+        "em" => 16.0,
+        "ex" => 8.0,
+        "pt" => 4.0 / 3.0,
+        "pc" => 16.0,
+        "in" => 96.0,
+        "cm" => 96.0 / 2.54,
+        "mm" => 96.0 / 25.4,
+        _ => return None,
+    };
+    Some(value * factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(markup: &str) -> (Dom, NodeId) {
+        let dom = html::parse(markup);
+        let doc = dom.nodes.iter().next().map(|(id, _)| id).unwrap();
+        let rect = dom.get_elements_by_tag(doc, "rect")[0];
+        (dom, rect)
+    }
+
+    #[test]
+    fn style_attribute_beats_presentation_attribute() {
+        let (dom, rect) = build(r#"<svg><rect fill="red" style="fill: blue; stroke: url(#g) green"/></svg>"#);
+        let props = compute(&dom, rect, &Props::initial(Color::BLACK), &[]);
+        assert_eq!(props.fill, PaintRef::Color(Color::rgb(0, 0, 255)));
+        assert_eq!(
+            props.stroke,
+            PaintRef::Url("g".into(), Box::new(PaintRef::Color(Color::rgb(0, 128, 0))))
+        );
+    }
+
+    #[test]
+    fn stylesheet_rules_apply_between() {
+        let (dom, rect) = build(r#"<svg><rect class="a" fill="red" stroke="red" style="stroke: none"/></svg>"#);
+        let sheet = css::parse_stylesheet(".a { fill: currentColor; stroke: blue; stroke-width: 3 }");
+        let props = compute(&dom, rect, &Props::initial(Color::BLACK), &[(sheet, style::StyleOrigin::Author)]);
+        assert_eq!(props.fill, PaintRef::CurrentColor);
+        assert_eq!(props.stroke, PaintRef::None);
+        assert_eq!(props.stroke_width, 3.0);
+    }
+
+    #[test]
+    fn lengths_with_units() {
+        assert_eq!(parse_length("10", 0.0), Some(10.0));
+        assert_eq!(parse_length("1in", 0.0), Some(96.0));
+        assert_eq!(parse_length("2em", 0.0), Some(32.0));
+        assert_eq!(parse_length("50%", 300.0), Some(150.0));
+        assert_eq!(parse_length("1e1", 0.0), Some(10.0));
+        assert_eq!(parse_length("auto", 0.0), None);
+    }
+}
@@ -0,0 +1,489 @@
+//! Anti-aliased polygon filling, stroking, and paint servers.
+//!
+//! Filling samples each pixel row at several sub-scanlines and accumulates
+//! exact horizontal span coverage, which is enough for crisp icon edges
+//! without an analytic area rasterizer. Strokes are converted into filled
+//! outlines that are all wound the same way, so the nonzero rule unions
+//! their overlaps.
+
+use common::{Color, Vec2};
+use paint::rasterizer::Framebuffer;
+
+use crate::path::Polyline;
+use crate::transform::Transform;
+
+/// Vertical samples per pixel row.
+const SUBSAMPLES: usize = 5;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Paint servers
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// How a gradient continues outside its `[0, 1]` range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Spread {
+    Pad,
+    Reflect,
+    Repeat,
+}
+
+/// The geometry of a gradient, in its own coordinate space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientKind {
+    Linear { x1: f32, y1: f32, x2: f32, y2: f32 },
+    Radial { cx: f32, cy: f32, r: f32, fx: f32, fy: f32 },
+}
+
+/// A resolved gradient ready for sampling.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    pub kind: GradientKind,
+    /// Offset/colour pairs, offsets non-decreasing within `[0, 1]`.
+    pub stops: Vec<(f32, Color)>,
+    pub spread: Spread,
+    /// Maps device pixels back into the gradient's coordinate space.
+    pub device_to_gradient: Transform,
+}
+
+/// What a fill or stroke is painted with.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Source {
+    Solid(Color),
+    Gradient(Gradient),
+}
+
+impl Gradient {
+    fn color_at(&self, device: Vec2) -> Color {
+        let p = self.device_to_gradient.apply(device);
+        let t = match self.kind {
+            GradientKind::Linear { x1, y1, x2, y2 } => {
+                let (dx, dy) = (x2 - x1, y2 - y1);
+                let len_sq = dx * dx + dy * dy;
+                if len_sq == 0.0 {
+                    1.0
+                } else {
+                    ((p.x - x1) * dx + (p.y - y1) * dy) / len_sq
+                }
+            }
+            GradientKind::Radial { cx, cy, r, fx, fy } => radial_offset(p, cx, cy, r, fx, fy),
+        };
+        let t = match self.spread {
+            Spread::Pad => t.clamp(0.0, 1.0),
+            Spread::Repeat => t - t.floor(),
+            Spread::Reflect => {
+                let m = t.rem_euclid(2.0);
+                if m > 1.0 { 2.0 - m } else { m }
+            }
+        };
+        interpolate(&self.stops, t)
+    }
+}
+
+/// The gradient offset of `p` for a radial gradient with focal point `f`:
+/// the fraction of the way from the focus to the circle along the ray
+/// through `p`.
+fn radial_offset(p: Vec2, cx: f32, cy: f32, r: f32, fx: f32, fy: f32) -> f32 {
+    if r <= 0.0 {
+        return 1.0;
+    }
+    let (dx, dy) = (p.x - fx, p.y - fy);
+    let (ox, oy) = (fx - cx, fy - cy);
+    // Solve |f + s·d − c| = r for s; t = 1/s.
+    let a = dx * dx + dy * dy;
+    if a == 0.0 {
+        return 0.0;
+    }
+    let b = 2.0 * (dx * ox + dy * oy);
+    let c = ox * ox + oy * oy - r * r;
+    let disc = b * b - 4.0 * a * c;
+    if disc < 0.0 {
+        return 1.0;
+    }
+    let s = (-b + disc.sqrt()) / (2.0 * a);
+    if s <= 0.0 { 1.0 } else { 1.0 / s }
+}
+
+fn interpolate(stops: &[(f32, Color)], t: f32) -> Color {
+    let Some(&(first_offset, first)) = stops.first() else {
+        return Color::TRANSPARENT;
+    };
+    if t <= first_offset {
+        return first;
+    }
+    for pair in stops.windows(2) {
+        let ((o0, c0), (o1, c1)) = (pair[0], pair[1]);
+        if t <= o1 {
+            let f = if o1 > o0 { (t - o0) / (o1 - o0) } else { 1.0 };
+            let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * f).round() as u8;
+            return Color::rgba(lerp(c0.r, c1.r), lerp(c0.g, c1.g), lerp(c0.b, c1.b), lerp(c0.a, c1.a));
+        }
+    }
+    stops[stops.len() - 1].1
+}
+
+impl Source {
+    fn argb_at(&self, x: i32, y: i32, alpha: f32) -> u32 {
+        let c = match self {
+            Source::Solid(c) => *c,
+            Source::Gradient(g) => g.color_at(Vec2::new(x as f32 + 0.5, y as f32 + 0.5)),
+        };
+        let a = (c.a as f32 * alpha).round().clamp(0.0, 255.0) as u32;
+        (a << 24) | ((c.r as u32) << 16) | ((c.g as u32) << 8) | c.b as u32
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Filling
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The winding rule for deciding which regions are inside a shape.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillRule {
+    NonZero,
+    EvenOdd,
+}
+
+/// Device-space clip rectangle `(x0, y0, x1, y1)`, exclusive on the right.
+pub type Clip = (i32, i32, i32, i32);
+
+/// Fill the region enclosed by `polys` (each implicitly closed) into `fb`.
+pub fn fill(fb: &mut Framebuffer, polys: &[Vec<Vec2>], rule: FillRule, source: &Source, opacity: f32, clip: Clip) {
+    let mut edges: Vec<(Vec2, Vec2, i32)> = Vec::new();
+    let (mut min_y, mut max_y) = (f32::MAX, f32::MIN);
+    let (mut min_x, mut max_x) = (f32::MAX, f32::MIN);
+    for poly in polys {
+        if poly.len() < 2 {
+            continue;
+        }
+        for i in 0..poly.len() {
+            let a = poly[i];
+            let b = poly[(i + 1) % poly.len()];
+            if !(a.x.is_finite() && a.y.is_finite() && b.x.is_finite() && b.y.is_finite()) {
+                return;
+            }
+            min_x = min_x.min(a.x);
+            max_x = max_x.max(a.x);
+            if a.y == b.y {
+                continue;
+            }
+            let (top, bottom, dir) = if a.y < b.y { (a, b, 1) } else { (b, a, -1) };
+            min_y = min_y.min(top.y);
+            max_y = max_y.max(bottom.y);
+            edges.push((top, bottom, dir));
+        }
+    }
+    if edges.is_empty() {
+        return;
+    }
+
+    let (cx0, cy0, cx1, cy1) = clip;
+    let cx0 = cx0.max(0);
+    let cy0 = cy0.max(0);
+    let cx1 = cx1.min(fb.width as i32);
+    let cy1 = cy1.min(fb.height as i32);
+    let row_x0 = (min_x.floor() as i32).max(cx0);
+    let row_x1 = (max_x.ceil() as i32 + 1).min(cx1);
+    let y_start = (min_y.floor() as i32).max(cy0);
+    let y_end = (max_y.ceil() as i32).min(cy1);
+    if row_x0 >= row_x1 || y_start >= y_end {
+        return;
+    }
+
+    let width = (row_x1 - row_x0) as usize;
+    let mut coverage = vec![0.0f32; width + 1];
+    let mut crossings: Vec<(f32, i32)> = Vec::new();
+    let sample_weight = 1.0 / SUBSAMPLES as f32;
+
+    for y in y_start..y_end {
+        coverage.iter_mut().for_each(|c| *c = 0.0);
+        let mut any = false;
+        for s in 0..SUBSAMPLES {
+            let sy = y as f32 + (s as f32 + 0.5) * sample_weight;
+            crossings.clear();
+            for &(top, bottom, dir) in &edges {
+                if sy >= top.y && sy < bottom.y {
+                    let t = (sy - top.y) / (bottom.y - top.y);
+                    crossings.push((top.x + (bottom.x - top.x) * t, dir));
+                }
+            }
+            if crossings.len() < 2 {
+                continue;
+            }
+            crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let mut winding = 0;
+            for i in 0..crossings.len() - 1 {
+                winding += crossings[i].1;
+                let inside = match rule {
+                    FillRule::NonZero => winding != 0,
+                    FillRule::EvenOdd => winding % 2 != 0,
+                };
+                if inside {
+                    let x0 = crossings[i].0.max(row_x0 as f32) - row_x0 as f32;
+                    let x1 = crossings[i + 1].0.min(row_x1 as f32) - row_x0 as f32;
+                    if x1 > x0 {
+                        add_span(&mut coverage, x0, x1, sample_weight);
+                        any = true;
+                    }
+                }
+            }
+        }
+        if !any {
+            continue;
+        }
+        for (i, &c) in coverage.iter().take(width).enumerate() {
+            if c <= 0.002 {
+                continue;
+            }
+            let x = row_x0 + i as i32;
+            let argb = source.argb_at(x, y, c.min(1.0) * opacity);
+            fb.blend_pixel(x, y, argb);
+        }
+    }
+}
+
+/// Add horizontal coverage for the span `[x0, x1)`, splitting partial pixels
+/// at either end.
+fn add_span(coverage: &mut [f32], x0: f32, x1: f32, weight: f32) {
+    let i0 = x0.floor() as usize;
+    let i1 = x1.floor() as usize;
+    if i0 >= coverage.len() {
+        return;
+    }
+    if i0 == i1 {
+        coverage[i0] += (x1 - x0) * weight;
+        return;
+    }
+    coverage[i0] += (i0 as f32 + 1.0 - x0) * weight;
+    let end = i1.min(coverage.len());
+    for c in coverage.iter_mut().take(end).skip(i0 + 1) {
+        *c += weight;
+    }
+    if i1 < coverage.len() {
+        coverage[i1] += (x1 - i1 as f32) * weight;
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Stroking
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineJoin {
+    Miter,
+    Round,
+    Bevel,
+}
+
+/// Stroke geometry in device pixels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub cap: LineCap,
+    pub join: LineJoin,
+    pub miter_limit: f32,
+}
+
+/// Convert device-space polylines into closed outlines covering their
+/// stroke, to be filled with [`FillRule::NonZero`].
+pub fn stroke_outlines(lines: &[Polyline], style: &StrokeStyle) -> Vec<Vec<Vec2>> {
+    let hw = style.width / 2.0;
+    let mut out: Vec<Vec<Vec2>> = Vec::new();
+    if hw <= 0.0 {
+        return out;
+    }
+    for line in lines {
+        let mut pts: Vec<Vec2> = Vec::with_capacity(line.points.len());
+        for &p in &line.points {
+            if pts.last().is_none_or(|&q: &Vec2| (p - q).length_sq() > 1e-8) {
+                pts.push(p);
+            }
+        }
+        if line.closed && pts.len() > 2 && (pts[0] - pts[pts.len() - 1]).length_sq() <= 1e-8 {
+            pts.pop();
+        }
+        if pts.len() == 1 {
+            // A zero-length subpath only shows its caps.
+            let p = pts[0];
+            match style.cap {
+                LineCap::Round => push_wound(&mut out, circle(p, hw)),
+                LineCap::Square => push_wound(
+                    &mut out,
+                    vec![
+                        Vec2::new(p.x - hw, p.y - hw),
+                        Vec2::new(p.x + hw, p.y - hw),
+                        Vec2::new(p.x + hw, p.y + hw),
+                        Vec2::new(p.x - hw, p.y + hw),
+                    ],
+                ),
+                LineCap::Butt => {}
+            }
+            continue;
+        }
+        let n = pts.len();
+        let segments = if line.closed { n } else { n - 1 };
+        for i in 0..segments {
+            let a = pts[i];
+            let b = pts[(i + 1) % n];
+            let dir = (b - a).normalize();
+            let normal = Vec2::new(-dir.y, dir.x) * hw;
+            let (mut a, mut b) = (a, b);
+            if !line.closed && style.cap == LineCap::Square {
+                if i == 0 {
+                    a = a - dir * hw;
+                }
+                if i == segments - 1 {
+                    b = b + dir * hw;
+                }
+            }
+            push_wound(&mut out, vec![a + normal, b + normal, b - normal, a - normal]);
+        }
+        // Joins at interior vertices (and every vertex of a closed path).
+        let joins: Vec<usize> = if line.closed { (0..n).collect() } else { (1..n - 1).collect() };
+        for i in joins {
+            let prev = pts[(i + n - 1) % n];
+            let p = pts[i];
+            let next = pts[(i + 1) % n];
+            join(&mut out, prev, p, next, hw, style);
+        }
+        if !line.closed && style.cap == LineCap::Round {
+            push_wound(&mut out, circle(pts[0], hw));
+            push_wound(&mut out, circle(pts[n - 1], hw));
+        }
+    }
+    out
+}
+
+fn join(out: &mut Vec<Vec<Vec2>>, prev: Vec2, p: Vec2, next: Vec2, hw: f32, style: &StrokeStyle) {
+    let d0 = (p - prev).normalize();
+    let d1 = (next - p).normalize();
+    let cross = d0.x * d1.y - d0.y * d1.x;
+    if cross.abs() < 1e-6 && d0.dot(d1) > 0.0 {
+        return; // Collinear: the segment quads already meet.
+    }
+    if style.join == LineJoin::Round {
+        push_wound(out, circle(p, hw));
+        return;
+    }
+    // The outer side of the turn is opposite the direction of rotation.
+    let side = if cross > 0.0 { -1.0 } else { 1.0 };
+    let n0 = Vec2::new(-d0.y, d0.x) * (hw * side);
+    let n1 = Vec2::new(-d1.y, d1.x) * (hw * side);
+    let mut poly = vec![p, p + n0, p + n1];
+    if style.join == LineJoin::Miter {
+        let cos_theta = d0.dot(d1).clamp(-1.0, 1.0);
+        // miter length / stroke width = 1 / sin(θ/2) for the interior angle
+        // θ, and sin(θ/2) = cos(φ/2) for the turning angle φ.
+        let half_angle = ((1.0 + cos_theta) / 2.0).sqrt();
+        let ratio = if half_angle > 1e-6 { 1.0 / half_angle } else { f32::MAX };
+        if ratio <= style.miter_limit {
+            let bisector = (n0 + n1).normalize();
+            let tip = p + bisector * (hw * ratio);
+            poly = vec![p, p + n0, tip, p + n1];
+        }
+    }
+    push_wound(out, poly);
+}
+
+fn circle(c: Vec2, r: f32) -> Vec<Vec2> {
+    let steps = ((r * 2.0).ceil() as usize).clamp(8, 64);
+    (0..steps)
+        .map(|i| {
+            let (s, co) = (i as f32 / steps as f32 * std::f32::consts::TAU).sin_cos();
+            Vec2::new(c.x + r * co, c.y + r * s)
+        })
+        .collect()
+}
+
+/// Push `poly` with positive signed area, so overlapping pieces never cancel
+/// under the nonzero rule.
+fn push_wound(out: &mut Vec<Vec<Vec2>>, mut poly: Vec<Vec2>) {
+    let mut area = 0.0;
+    for i in 0..poly.len() {
+        let a = poly[i];
+        let b = poly[(i + 1) % poly.len()];
+        area += a.x * b.y - b.x * a.y;
+    }
+    if area < 0.0 {
+        poly.reverse();
+    }
+    if area != 0.0 {
+        out.push(poly);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FULL: Clip = (0, 0, i32::MAX, i32::MAX);
+
+    fn square(x: f32, y: f32, s: f32) -> Vec<Vec2> {
+        vec![Vec2::new(x, y), Vec2::new(x + s, y), Vec2::new(x + s, y + s), Vec2::new(x, y + s)]
+    }
+
+    #[test]
+    fn fill_covers_whole_and_partial_pixels() {
+        let mut fb = Framebuffer::new(10, 10);
+        fb.clear(0);
+        let red = Source::Solid(Color::rgb(255, 0, 0));
+        fill(&mut fb, &[square(2.0, 2.0, 3.5)], FillRule::NonZero, &red, 1.0, FULL);
+        assert_eq!(fb.pixels[3 * 10 + 3], 0xFFFF_0000);
+        let edge = fb.pixels[3 * 10 + 5] >> 24;
+        assert!((100..=155).contains(&edge), "half-covered alpha {edge}");
+        assert_eq!(fb.pixels[3 * 10 + 7], 0);
+    }
+
+    #[test]
+    fn even_odd_leaves_hole() {
+        let mut fb = Framebuffer::new(10, 10);
+        fb.clear(0);
+        let polys = [square(0.0, 0.0, 9.0), square(3.0, 3.0, 3.0)];
+        let black = Source::Solid(Color::BLACK);
+        fill(&mut fb, &polys, FillRule::EvenOdd, &black, 1.0, FULL);
+        assert_eq!(fb.pixels[4 * 10 + 4], 0);
+        assert_eq!(fb.pixels[10 + 1], 0xFF00_0000);
+        fill(&mut fb, &polys, FillRule::NonZero, &black, 1.0, FULL);
+        assert_eq!(fb.pixels[4 * 10 + 4], 0xFF00_0000);
+    }
+
+    #[test]
+    fn linear_gradient_interpolates() {
+        let g = Gradient {
+            kind: GradientKind::Linear { x1: 0.0, y1: 0.0, x2: 10.0, y2: 0.0 },
+            stops: vec![(0.0, Color::rgb(0, 0, 0)), (1.0, Color::rgb(200, 0, 0))],
+            spread: Spread::Pad,
+            device_to_gradient: Transform::IDENTITY,
+        };
+        assert_eq!(g.color_at(Vec2::new(5.0, 3.0)).r, 100);
+        assert_eq!(g.color_at(Vec2::new(-5.0, 3.0)).r, 0);
+        let repeat = Gradient { spread: Spread::Repeat, ..g };
+        assert_eq!(repeat.color_at(Vec2::new(15.0, 0.0)).r, 100);
+    }
+
+    #[test]
+    fn radial_offset_from_centre_focus() {
+        assert!((radial_offset(Vec2::new(5.0, 0.0), 0.0, 0.0, 10.0, 0.0, 0.0) - 0.5).abs() < 1e-4);
+        assert!((radial_offset(Vec2::new(0.0, 10.0), 0.0, 0.0, 10.0, 0.0, 0.0) - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn stroke_outlines_cover_line() {
+        let line = Polyline { points: vec![Vec2::new(1.0, 5.0), Vec2::new(9.0, 5.0)], closed: false };
+        let style = StrokeStyle { width: 2.0, cap: LineCap::Butt, join: LineJoin::Miter, miter_limit: 4.0 };
+        let mut fb = Framebuffer::new(10, 10);
+        fb.clear(0);
+        let outlines = stroke_outlines(&[line], &style);
+        fill(&mut fb, &outlines, FillRule::NonZero, &Source::Solid(Color::BLACK), 1.0, FULL);
+        assert_eq!(fb.pixels[4 * 10 + 5], 0xFF00_0000);
+        assert_eq!(fb.pixels[5 * 10 + 5], 0xFF00_0000);
+        assert_eq!(fb.pixels[2 * 10 + 5], 0);
+        assert_eq!(fb.pixels[5 * 10], 0);
+    }
+}
@@ -0,0 +1,576 @@
+//! Rendering an SVG element tree into a framebuffer.
+//!
+//! The walk carries the current user-to-device transform, the inherited
+//! presentation properties, and the accumulated group opacity. Group
+//! opacity is approximated by multiplying it into each descendant's fill
+//! and stroke alpha rather than compositing an offscreen layer.
+
+use common::{Color, Rect, Vec2};
+use dom::{Dom, Namespace, NodeData, NodeId};
+use paint::font_engine::FontEngine;
+use paint::rasterizer::Framebuffer;
+
+use crate::path::{Path, parse_path_data};
+use crate::props::{self, PaintRef, Props, TextAnchor, parse_length};
+use crate::raster::{self, Clip, FillRule, Gradient, GradientKind, Source, Spread, StrokeStyle};
+use crate::transform::{
+    PreserveAspectRatio, Transform, parse_number_list, parse_transform, parse_view_box, view_box_transform,
+};
+
+/// Maximum device-space deviation when flattening curves.
+const TOLERANCE: f32 = 0.2;
+
+/// Limit on nested `<use>` expansion, which also stops reference cycles.
+const MAX_USE_DEPTH: u32 = 8;
+
+pub(crate) struct Renderer<'a> {
+    pub dom: &'a Dom,
+    pub document: NodeId,
+    pub sheets: Vec<(css::Stylesheet, style::StyleOrigin)>,
+    pub font: Option<&'a mut FontEngine>,
+    use_depth: u32,
+}
+
+/// Per-element state threaded through the walk.
+#[derive(Clone, Copy)]
+struct Frame {
+    transform: Transform,
+    /// The nearest viewport's size in user units, for percentage lengths.
+    viewport: (f32, f32),
+    opacity: f32,
+    clip: Clip,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(dom: &'a Dom, svg: NodeId, font: Option<&'a mut FontEngine>) -> Self {
+        let document = dom.ancestors(svg).pop().unwrap_or(svg);
+        // Styles from every <style> in the document: an SVG file's own, or
+        // the page's when the SVG is inline.
+        let sheets = dom
+            .get_elements_by_tag(document, "style")
+            .into_iter()
+            .map(|id| {
+                let text: String = dom
+                    .children(id)
+                    .into_iter()
+                    .filter_map(|c| match &dom.nodes.get(c)?.data {
+                        NodeData::Text { data } => Some(data.as_str()),
+                        _ => None,
+                    })
+                    .collect();
+                (css::parse_stylesheet(&text), style::StyleOrigin::Author)
+            })
+            .collect();
+        Self { dom, document, sheets, font, use_depth: 0 }
+    }
+
+    /// Render the outermost `<svg>` element into the device rectangle
+    /// `rect`, clipped to it.
+    pub fn render_root(&mut self, fb: &mut Framebuffer, svg: NodeId, rect: Rect, color: Color) {
+        let Rect { x, y, w: width, h: height } = rect;
+        let parent = Props::initial(color);
+        let props = props::compute(self.dom, svg, &parent, &self.sheets);
+        if !props.display || width <= 0.0 || height <= 0.0 {
+            return;
+        }
+        let clip = (
+            x.floor() as i32,
+            y.floor() as i32,
+            (x + width).ceil() as i32,
+            (y + height).ceil() as i32,
+        );
+        let (transform, viewport) = self.viewport_transform(svg, width, height);
+        let frame = Frame {
+            transform: Transform::translate(x, y).then(&transform),
+            viewport,
+            opacity: props.opacity,
+            clip,
+        };
+        self.children(fb, svg, &props, frame);
+    }
+
+    /// The `viewBox` mapping for a viewport element of the given size, and
+    /// the size its children see for percentages.
+    fn viewport_transform(&self, node: NodeId, width: f32, height: f32) -> (Transform, (f32, f32)) {
+        match attr(self.dom, node, "viewBox").and_then(parse_view_box) {
+            Some(vb) => {
+                let par = attr(self.dom, node, "preserveAspectRatio")
+                    .map(PreserveAspectRatio::parse)
+                    .unwrap_or_default();
+                (view_box_transform(&vb, par, width, height), (vb.w, vb.h))
+            }
+            None => (Transform::IDENTITY, (width, height)),
+        }
+    }
+
+    fn children(&mut self, fb: &mut Framebuffer, node: NodeId, props: &Props, frame: Frame) {
+        for child in self.dom.children(node) {
+            self.element(fb, child, props, frame);
+        }
+    }
+
+    fn element(&mut self, fb: &mut Framebuffer, node: NodeId, parent: &Props, frame: Frame) {
+        let Some(elem) = self.dom.nodes.get(node).and_then(|n| n.as_element()) else {
+            return;
+        };
+        if elem.namespace != Namespace::Svg {
+            return;
+        }
+        let tag = elem.tag_name.as_str();
+        if matches!(
+            tag,
+            "defs" | "symbol" | "linearGradient" | "radialGradient" | "stop" | "clipPath" | "mask"
+                | "pattern" | "marker" | "filter" | "style" | "script" | "title" | "desc"
+                | "metadata" | "foreignObject"
+        ) {
+            return;
+        }
+        let props = props::compute(self.dom, node, parent, &self.sheets);
+        if !props.display {
+            return;
+        }
+        let mut frame = frame;
+        frame.opacity *= props.opacity;
+        if frame.opacity <= 0.0 {
+            return;
+        }
+        if let Some(t) = attr(self.dom, node, "transform").and_then(parse_transform) {
+            frame.transform = frame.transform.then(&t);
+        }
+        let (vw, vh) = frame.viewport;
+        let diag = ((vw * vw + vh * vh) / 2.0).sqrt();
+        let dom = self.dom;
+        let len_x = |name: &str, default: f32| length(dom, node, name, vw, default);
+        let len_y = |name: &str, default: f32| length(dom, node, name, vh, default);
+        let len_d = |name: &str, default: f32| length(dom, node, name, diag, default);
+
+        match tag {
+            "g" | "a" => self.children(fb, node, &props, frame),
+            "switch" => {
+                // Conditional processing attributes are not evaluated, so
+                // the first element child wins.
+                if let Some(child) = self.dom.children(node).into_iter().find(|&c| {
+                    self.dom.nodes.get(c).is_some_and(|n| n.as_element().is_some())
+                }) {
+                    self.element(fb, child, &props, frame);
+                }
+            }
+            "svg" => {
+                let (x, y) = (len_x("x", 0.0), len_y("y", 0.0));
+                let (w, h) = (len_x("width", vw), len_y("height", vh));
+                self.nested_viewport(fb, node, false, &props, frame, x, y, w, h);
+            }
+            "use" => {
+                if self.use_depth >= MAX_USE_DEPTH {
+                    return;
+                }
+                let Some(target) = href(self.dom, node).and_then(|id| self.dom.get_element_by_id(self.document, id))
+                else {
+                    return;
+                };
+                let (x, y) = (len_x("x", 0.0), len_y("y", 0.0));
+                frame.transform = frame.transform.then(&Transform::translate(x, y));
+                self.use_depth += 1;
+                match tag_name(self.dom, target) {
+                    Some("symbol") | Some("svg") => {
+                        let w = length(self.dom, node, "width", vw, length(self.dom, target, "width", vw, vw));
+                        let h = length(self.dom, node, "height", vh, length(self.dom, target, "height", vh, vh));
+                        self.nested_viewport(fb, target, true, &props, frame, 0.0, 0.0, w, h);
+                    }
+                    _ => self.element(fb, target, &props, frame),
+                }
+                self.use_depth -= 1;
+            }
+            "rect" => {
+                let (w, h) = (len_x("width", 0.0), len_y("height", 0.0));
+                if w <= 0.0 || h <= 0.0 {
+                    return;
+                }
+                let rx = attr(self.dom, node, "rx").and_then(|v| parse_length(v, vw));
+                let ry = attr(self.dom, node, "ry").and_then(|v| parse_length(v, vh));
+                let (rx, ry) = match (rx, ry) {
+                    (Some(rx), Some(ry)) => (rx, ry),
+                    (Some(r), None) | (None, Some(r)) => (r, r),
+                    (None, None) => (0.0, 0.0),
+                };
+                let path = Path::rect(len_x("x", 0.0), len_y("y", 0.0), w, h, rx.max(0.0), ry.max(0.0));
+                self.draw(fb, &path, &props, frame);
+            }
+            "circle" => {
+                let r = len_d("r", 0.0);
+                if r > 0.0 {
+                    self.draw(fb, &Path::ellipse(len_x("cx", 0.0), len_y("cy", 0.0), r, r), &props, frame);
+                }
+            }
+            "ellipse" => {
+                let rx = attr(self.dom, node, "rx").and_then(|v| parse_length(v, vw));
+                let ry = attr(self.dom, node, "ry").and_then(|v| parse_length(v, vh));
+                // SVG 2: an `auto` or missing radius takes the other's value.
+                let (rx, ry) = match (rx, ry) {
+                    (Some(rx), Some(ry)) => (rx, ry),
+                    (Some(r), None) | (None, Some(r)) => (r, r),
+                    (None, None) => return,
+                };
+                if rx > 0.0 && ry > 0.0 {
+                    self.draw(fb, &Path::ellipse(len_x("cx", 0.0), len_y("cy", 0.0), rx, ry), &props, frame);
+                }
+            }
+            "line" => {
+                let mut path = Path::new();
+                path.move_to(len_x("x1", 0.0), len_y("y1", 0.0));
+                path.line_to(len_x("x2", 0.0), len_y("y2", 0.0));
+                // Lines have no interior, so only the stroke is drawn.
+                let props = Props { fill: PaintRef::None, ..props };
+                self.draw(fb, &path, &props, frame);
+            }
+            "polyline" | "polygon" => {
+                let points = attr(self.dom, node, "points").unwrap_or("");
+                self.draw(fb, &Path::polyline(points, tag == "polygon"), &props, frame);
+            }
+            "path" => {
+                let d = attr(self.dom, node, "d").unwrap_or("");
+                self.draw(fb, &parse_path_data(d), &props, frame);
+            }
+            "text" => self.text(fb, node, &props, frame),
+            _ => {}
+        }
+    }
+
+    /// Establish a new viewport of `w × h` at `(x, y)` for `content`'s
+    /// children. `props` are `content`'s own unless it was reached through
+    /// `<use>`, in which case they are the referencing element's.
+    #[allow(clippy::too_many_arguments)]
+    fn nested_viewport(
+        &mut self,
+        fb: &mut Framebuffer,
+        content: NodeId,
+        referenced: bool,
+        props: &Props,
+        frame: Frame,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+    ) {
+        if w <= 0.0 || h <= 0.0 {
+            return;
+        }
+        let (view, viewport) = self.viewport_transform(content, w, h);
+        let transform = frame.transform.then(&Transform::translate(x, y));
+        let mut clip = frame.clip;
+        // Overflow clipping is only applied when the viewport stays an
+        // axis-aligned rectangle in device space.
+        if transform.b == 0.0 && transform.c == 0.0 {
+            let p0 = transform.apply(Vec2::ZERO);
+            let p1 = transform.apply(Vec2::new(w, h));
+            clip = (
+                clip.0.max(p0.x.min(p1.x).floor() as i32),
+                clip.1.max(p0.y.min(p1.y).floor() as i32),
+                clip.2.min(p0.x.max(p1.x).ceil() as i32),
+                clip.3.min(p0.y.max(p1.y).ceil() as i32),
+            );
+        }
+        let inner = Frame { transform: transform.then(&view), viewport, opacity: frame.opacity, clip };
+        let props = if referenced {
+            props::compute(self.dom, content, props, &self.sheets)
+        } else {
+            props.clone()
+        };
+        self.children(fb, content, &props, inner);
+    }
+
+    /// Fill and stroke a path.
+    fn draw(&mut self, fb: &mut Framebuffer, path: &Path, props: &Props, frame: Frame) {
+        if !props.visible || path.is_empty() {
+            return;
+        }
+        let bounds = path.bounds();
+        let lines = path.flatten(&frame.transform, TOLERANCE);
+        if let Some(source) = self.resolve_paint(&props.fill, props, bounds, &frame) {
+            let polys: Vec<Vec<Vec2>> = lines.iter().map(|l| l.points.clone()).collect();
+            let opacity = props.fill_opacity * frame.opacity;
+            raster::fill(fb, &polys, props.fill_rule, &source, opacity, frame.clip);
+        }
+        if let Some(source) = self.resolve_paint(&props.stroke, props, bounds, &frame) {
+            let style = StrokeStyle {
+                width: props.stroke_width * frame.transform.mean_scale(),
+                cap: props.line_cap,
+                join: props.line_join,
+                miter_limit: props.miter_limit,
+            };
+            let outlines = raster::stroke_outlines(&lines, &style);
+            let opacity = props.stroke_opacity * frame.opacity;
+            raster::fill(fb, &outlines, FillRule::NonZero, &source, opacity, frame.clip);
+        }
+    }
+
+    /// Turn a fill or stroke reference into something the rasterizer can
+    /// sample, or `None` if nothing should be painted.
+    fn resolve_paint(
+        &self,
+        paint: &PaintRef,
+        props: &Props,
+        bounds: Option<(Vec2, Vec2)>,
+        frame: &Frame,
+    ) -> Option<Source> {
+        match paint {
+            PaintRef::None => None,
+            PaintRef::Color(c) => Some(Source::Solid(*c)),
+            PaintRef::CurrentColor => Some(Source::Solid(props.color)),
+            PaintRef::Url(id, fallback) => {
+                let server = self.dom.get_element_by_id(self.document, id);
+                match server.and_then(|s| self.gradient(s, props, bounds, frame)) {
+                    Some(source) => Some(source),
+                    None if server.is_some() => None,
+                    None => self.resolve_paint(fallback, props, bounds, frame),
+                }
+            }
+        }
+    }
+
+    /// Build a gradient paint from a `linearGradient` / `radialGradient`,
+    /// following `href` templates for unset attributes and stops.
+    fn gradient(&self, node: NodeId, props: &Props, bounds: Option<(Vec2, Vec2)>, frame: &Frame) -> Option<Source> {
+        let tag = tag_name(self.dom, node)?;
+        if tag != "linearGradient" && tag != "radialGradient" {
+            return None;
+        }
+        let mut chain = vec![node];
+        while chain.len() < MAX_USE_DEPTH as usize {
+            let last = *chain.last()?;
+            match href(self.dom, last).and_then(|id| self.dom.get_element_by_id(self.document, id)) {
+                Some(next) if !chain.contains(&next) => chain.push(next),
+                _ => break,
+            }
+        }
+        let get = |name: &str| chain.iter().find_map(|&n| attr(self.dom, n, name));
+
+        let stop_parent = chain
+            .iter()
+            .copied()
+            .find(|&n| self.dom.children(n).into_iter().any(|c| tag_name(self.dom, c) == Some("stop")))?;
+        let mut stops: Vec<(f32, Color)> = Vec::new();
+        for stop in self.dom.children(stop_parent) {
+            if tag_name(self.dom, stop) != Some("stop") {
+                continue;
+            }
+            let stop_props = props::compute(self.dom, stop, props, &self.sheets);
+            let offset = attr(self.dom, stop, "offset")
+                .and_then(|v| match v.trim().strip_suffix('%') {
+                    Some(pct) => pct.trim().parse::<f32>().ok().map(|p| p / 100.0),
+                    None => v.trim().parse::<f32>().ok(),
+                })
+                .unwrap_or(0.0)
+                .clamp(0.0, 1.0);
+            let offset = stops.last().map_or(offset, |&(prev, _)| offset.max(prev));
+            let c = stop_props.stop_color;
+            let alpha = (c.a as f32 * stop_props.stop_opacity).round() as u8;
+            stops.push((offset, Color::rgba(c.r, c.g, c.b, alpha)));
+        }
+        if stops.len() == 1 {
+            return Some(Source::Solid(stops[0].1));
+        }
+
+        let user_space = get("gradientUnits") == Some("userSpaceOnUse");
+        let units = if user_space {
+            Transform::IDENTITY
+        } else {
+            let (lo, hi) = bounds?;
+            let (w, h) = (hi.x - lo.x, hi.y - lo.y);
+            if w <= 0.0 || h <= 0.0 {
+                return None;
+            }
+            Transform::new(w, 0.0, 0.0, h, lo.x, lo.y)
+        };
+        let (vw, vh) = frame.viewport;
+        // Bounding-box units are fractions; percentages mean the same there.
+        let coord = |name: &str, reference: f32, default: &str| -> f32 {
+            let v = get(name).unwrap_or(default);
+            if user_space {
+                parse_length(v, reference).unwrap_or(0.0)
+            } else {
+                match v.trim().strip_suffix('%') {
+                    Some(p) => p.trim().parse::<f32>().map(|p| p / 100.0).unwrap_or(0.0),
+                    None => v.trim().parse::<f32>().unwrap_or(0.0),
+                }
+            }
+        };
+        let kind = if tag == "linearGradient" {
+            GradientKind::Linear {
+                x1: coord("x1", vw, "0%"),
+                y1: coord("y1", vh, "0%"),
+                x2: coord("x2", vw, "100%"),
+                y2: coord("y2", vh, "0%"),
+            }
+        } else {
+            let diag = ((vw * vw + vh * vh) / 2.0).sqrt();
+            let cx = coord("cx", vw, "50%");
+            let cy = coord("cy", vh, "50%");
+            let fx = if get("fx").is_some() { coord("fx", vw, "50%") } else { cx };
+            let fy = if get("fy").is_some() { coord("fy", vh, "50%") } else { cy };
+            GradientKind::Radial { cx, cy, r: coord("r", diag, "50%"), fx, fy }
+        };
+        let spread = match get("spreadMethod") {
+            Some("reflect") => Spread::Reflect,
+            Some("repeat") => Spread::Repeat,
+            _ => Spread::Pad,
+        };
+        let gradient_transform = get("gradientTransform").and_then(parse_transform).unwrap_or_default();
+        let to_device = frame.transform.then(&units).then(&gradient_transform);
+        if stops.is_empty() {
+            return None;
+        }
+        Some(Source::Gradient(Gradient { kind, stops, spread, device_to_gradient: to_device.invert()? }))
+    }
+
+    /// Draw a `<text>` element and its `<tspan>` children along a single
+    /// baseline. Glyphs are placed upright at the transformed pen position,
+    /// so rotated or skewed text is not supported.
+    fn text(&mut self, fb: &mut Framebuffer, node: NodeId, props: &Props, frame: Frame) {
+        if self.font.is_none() {
+            return;
+        }
+        let (vw, vh) = frame.viewport;
+        let mut pen = Vec2::new(
+            first_coordinate(self.dom, node, "x", vw).unwrap_or(0.0),
+            first_coordinate(self.dom, node, "y", vh).unwrap_or(0.0),
+        );
+        let mut at_start = true;
+        self.text_run(fb, node, props, frame, &mut pen, &mut at_start);
+    }
+
+    fn text_run(
+        &mut self,
+        fb: &mut Framebuffer,
+        node: NodeId,
+        props: &Props,
+        frame: Frame,
+        pen: &mut Vec2,
+        at_start: &mut bool,
+    ) {
+        let (vw, vh) = frame.viewport;
+        for child in self.dom.children(node) {
+            let Some(n) = self.dom.nodes.get(child) else { continue };
+            match &n.data {
+                NodeData::Text { data } => {
+                    let mut text = collapse_whitespace(data);
+                    if *at_start {
+                        text = text.trim_start().to_string();
+                    }
+                    if text.is_empty() {
+                        continue;
+                    }
+                    *at_start = false;
+                    self.draw_text(fb, &text, props, frame, pen);
+                }
+                NodeData::Element(e) if e.tag_name == "tspan" || e.tag_name == "a" => {
+                    let tspan_props = props::compute(self.dom, child, props, &self.sheets);
+                    if !tspan_props.display {
+                        continue;
+                    }
+                    if let Some(x) = first_coordinate(self.dom, child, "x", vw) {
+                        pen.x = x;
+                    }
+                    if let Some(y) = first_coordinate(self.dom, child, "y", vh) {
+                        pen.y = y;
+                    }
+                    pen.x += first_coordinate(self.dom, child, "dx", vw).unwrap_or(0.0);
+                    pen.y += first_coordinate(self.dom, child, "dy", vh).unwrap_or(0.0);
+                    let mut inner = frame;
+                    inner.opacity *= tspan_props.opacity;
+                    self.text_run(fb, child, &tspan_props, inner, pen, at_start);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn draw_text(&mut self, fb: &mut Framebuffer, text: &str, props: &Props, frame: Frame, pen: &mut Vec2) {
+        let scale = frame.transform.mean_scale();
+        let size = props.font_size * scale;
+        let Some(font) = self.font.as_deref_mut() else { return };
+        if size < 1.0 {
+            return;
+        }
+        let width = font.measure_text(text, size);
+        let offset = match props.text_anchor {
+            TextAnchor::Start => 0.0,
+            TextAnchor::Middle => width / 2.0,
+            TextAnchor::End => width,
+        };
+        let origin = frame.transform.apply(*pen);
+        let fill = match &props.fill {
+            PaintRef::Url(_, fallback) => fallback.as_ref().clone(),
+            other => other.clone(),
+        };
+        let color = match fill {
+            PaintRef::Color(c) => Some(c),
+            PaintRef::CurrentColor => Some(props.color),
+            _ => None,
+        };
+        if let (Some(c), true) = (color, props.visible) {
+            let a = (c.a as f32 * props.fill_opacity * frame.opacity).round().clamp(0.0, 255.0) as u32;
+            let argb = (a << 24) | ((c.r as u32) << 16) | ((c.g as u32) << 8) | c.b as u32;
+            let x = (origin.x - offset).round() as i32;
+            font.draw_text(fb, text, x, origin.y.round() as i32, size, argb, None);
+        }
+        // An anchored chunk ends `width - offset` past the pen.
+        if scale > 0.0 {
+            pen.x += (width - offset) / scale;
+        }
+    }
+}
+
+/// An attribute value, if present.
+pub(crate) fn attr<'d>(dom: &'d Dom, node: NodeId, name: &str) -> Option<&'d str> {
+    dom.nodes
+        .get(node)?
+        .as_element()?
+        .attrs
+        .iter()
+        .find(|a| a.name == name)
+        .map(|a| a.value.as_str())
+}
+
+fn tag_name(dom: &Dom, node: NodeId) -> Option<&str> {
+    let elem = dom.nodes.get(node)?.as_element()?;
+    (elem.namespace == Namespace::Svg).then_some(elem.tag_name.as_str())
+}
+
+/// The fragment id of an `href` or `xlink:href` reference.
+fn href(dom: &Dom, node: NodeId) -> Option<&str> {
+    attr(dom, node, "href")
+        .or_else(|| attr(dom, node, "xlink:href"))
+        .and_then(|v| v.trim().strip_prefix('#'))
+}
+
+fn length(dom: &Dom, node: NodeId, name: &str, reference: f32, default: f32) -> f32 {
+    attr(dom, node, name).and_then(|v| parse_length(v, reference)).unwrap_or(default)
+}
+
+/// The first value of a coordinate-list attribute such as `<text x="…">`.
+fn first_coordinate(dom: &Dom, node: NodeId, name: &str, reference: f32) -> Option<f32> {
+    let value = attr(dom, node, name)?;
+    let first = value.split(|c: char| c.is_whitespace() || c == ',').find(|s| !s.is_empty())?;
+    parse_length(first, reference).or_else(|| parse_number_list(first)?.first().copied())
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut space = false;
+    for ch in s.chars() {
+        if ch == '\n' || ch == '\r' {
+            continue;
+        }
+        if ch.is_whitespace() {
+            if !space {
+                out.push(' ');
+            }
+            space = true;
+        } else {
+            out.push(ch);
+            space = false;
+        }
+    }
+    out
+}
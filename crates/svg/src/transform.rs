@@ -0,0 +1,281 @@
+//! Affine transforms, the `transform` attribute, and the `viewBox` /
+//! `preserveAspectRatio` viewport mapping.
+
+use common::Vec2;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Transform
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A 2D affine transform `[a c e; b d f; 0 0 1]`, as in the SVG `matrix()`
+/// function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub e: f32,
+    pub f: f32,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform {
+    pub const IDENTITY: Self = Self { a: 1.0, b: 0.0, c: 0.0, d: 1.0, e: 0.0, f: 0.0 };
+
+    pub const fn new(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) -> Self {
+        Self { a, b, c, d, e, f }
+    }
+
+    pub const fn translate(tx: f32, ty: f32) -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, tx, ty)
+    }
+
+    pub const fn scale(sx: f32, sy: f32) -> Self {
+        Self::new(sx, 0.0, 0.0, sy, 0.0, 0.0)
+    }
+
+    /// Rotation by `degrees` (clockwise in the y-down coordinate system).
+    pub fn rotate(degrees: f32) -> Self {
+        let (s, c) = degrees.to_radians().sin_cos();
+        Self::new(c, s, -s, c, 0.0, 0.0)
+    }
+
+    pub fn skew_x(degrees: f32) -> Self {
+        Self::new(1.0, 0.0, degrees.to_radians().tan(), 1.0, 0.0, 0.0)
+    }
+
+    pub fn skew_y(degrees: f32) -> Self {
+        Self::new(1.0, degrees.to_radians().tan(), 0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// `self × other`: apply `other` first, then `self`.
+    pub fn then(&self, other: &Transform) -> Transform {
+        Transform {
+            a: self.a * other.a + self.c * other.b,
+            b: self.b * other.a + self.d * other.b,
+            c: self.a * other.c + self.c * other.d,
+            d: self.b * other.c + self.d * other.d,
+            e: self.a * other.e + self.c * other.f + self.e,
+            f: self.b * other.e + self.d * other.f + self.f,
+        }
+    }
+
+    /// Map a point through the transform.
+    pub fn apply(&self, p: Vec2) -> Vec2 {
+        Vec2::new(
+            self.a * p.x + self.c * p.y + self.e,
+            self.b * p.x + self.d * p.y + self.f,
+        )
+    }
+
+    /// The inverse transform, or `None` if the matrix is singular.
+    pub fn invert(&self) -> Option<Transform> {
+        let det = self.a * self.d - self.b * self.c;
+        if det.abs() < 1e-12 {
+            return None;
+        }
+        let inv = 1.0 / det;
+        Some(Transform {
+            a: self.d * inv,
+            b: -self.b * inv,
+            c: -self.c * inv,
+            d: self.a * inv,
+            e: (self.c * self.f - self.d * self.e) * inv,
+            f: (self.b * self.e - self.a * self.f) * inv,
+        })
+    }
+
+    /// The average scale factor, used to scale stroke widths and flattening
+    /// tolerances.
+    pub fn mean_scale(&self) -> f32 {
+        let det = (self.a * self.d - self.b * self.c).abs();
+        det.sqrt()
+    }
+}
+
+/// Parse a `transform` attribute such as
+/// `translate(10 20) rotate(45, 5, 5) scale(2)`.
+///
+/// Returns `None` for malformed input, in which case SVG says the attribute
+/// is ignored.
+pub fn parse_transform(s: &str) -> Option<Transform> {
+    let mut result = Transform::IDENTITY;
+    let mut rest = s.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+    while !rest.is_empty() {
+        let open = rest.find('(')?;
+        let name = rest[..open].trim();
+        let close = rest[open..].find(')')? + open;
+        let args = parse_number_list(&rest[open + 1..close])?;
+        let t = match (name, args.as_slice()) {
+            ("matrix", [a, b, c, d, e, f]) => Transform::new(*a, *b, *c, *d, *e, *f),
+            ("translate", [tx]) => Transform::translate(*tx, 0.0),
+            ("translate", [tx, ty]) => Transform::translate(*tx, *ty),
+            ("scale", [s]) => Transform::scale(*s, *s),
+            ("scale", [sx, sy]) => Transform::scale(*sx, *sy),
+            ("rotate", [deg]) => Transform::rotate(*deg),
+            ("rotate", [deg, cx, cy]) => Transform::translate(*cx, *cy)
+                .then(&Transform::rotate(*deg))
+                .then(&Transform::translate(-cx, -cy)),
+            ("skewX", [deg]) => Transform::skew_x(*deg),
+            ("skewY", [deg]) => Transform::skew_y(*deg),
+            _ => return None,
+        };
+        result = result.then(&t);
+        rest = rest[close + 1..].trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+    }
+    Some(result)
+}
+
+/// Parse a whitespace- and/or comma-separated list of numbers.
+pub fn parse_number_list(s: &str) -> Option<Vec<f32>> {
+    let mut out = Vec::new();
+    let mut scanner = crate::path::NumberScanner::new(s);
+    while let Some(n) = scanner.number() {
+        out.push(n);
+    }
+    if scanner.at_end() { Some(out) } else { None }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// viewBox / preserveAspectRatio
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A parsed `viewBox` attribute: `min-x min-y width height`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewBox {
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+}
+
+/// Parse a `viewBox`. A zero or negative size disables it, per spec.
+pub fn parse_view_box(s: &str) -> Option<ViewBox> {
+    match parse_number_list(s)?.as_slice() {
+        [x, y, w, h] if *w > 0.0 && *h > 0.0 => Some(ViewBox { x: *x, y: *y, w: *w, h: *h }),
+        _ => None,
+    }
+}
+
+/// Alignment along one axis for `preserveAspectRatio`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Align {
+    Min,
+    Mid,
+    Max,
+}
+
+/// A parsed `preserveAspectRatio` attribute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PreserveAspectRatio {
+    /// `None` for `none`: scale each axis independently.
+    pub align: Option<(Align, Align)>,
+    /// `slice` rather than `meet`.
+    pub slice: bool,
+}
+
+impl Default for PreserveAspectRatio {
+    /// `xMidYMid meet`.
+    fn default() -> Self {
+        Self { align: Some((Align::Mid, Align::Mid)), slice: false }
+    }
+}
+
+impl PreserveAspectRatio {
+    pub fn parse(s: &str) -> Self {
+        let mut words = s.split_whitespace();
+        let mut first = words.next().unwrap_or("");
+        if first == "defer" {
+            first = words.next().unwrap_or("");
+        }
+        let align = match first {
+            "none" => None,
+            _ if first.len() == 8 && first.starts_with('x') && &first[4..5] == "Y" => {
+                let axis = |s: &str| match s {
+                    "Min" => Some(Align::Min),
+                    "Mid" => Some(Align::Mid),
+                    "Max" => Some(Align::Max),
+                    _ => None,
+                };
+                match (axis(&first[1..4]), axis(&first[5..8])) {
+                    (Some(x), Some(y)) => Some((x, y)),
+                    _ => return Self::default(),
+                }
+            }
+            _ => return Self::default(),
+        };
+        let slice = words.next() == Some("slice");
+        Self { align, slice }
+    }
+}
+
+/// The transform that maps `view_box` into a viewport of `width × height`
+/// at the origin.
+pub fn view_box_transform(
+    view_box: &ViewBox,
+    par: PreserveAspectRatio,
+    width: f32,
+    height: f32,
+) -> Transform {
+    let sx = width / view_box.w;
+    let sy = height / view_box.h;
+    let Some((align_x, align_y)) = par.align else {
+        return Transform::scale(sx, sy).then(&Transform::translate(-view_box.x, -view_box.y));
+    };
+    let s = if par.slice { sx.max(sy) } else { sx.min(sy) };
+    let offset = |align: Align, room: f32| match align {
+        Align::Min => 0.0,
+        Align::Mid => room / 2.0,
+        Align::Max => room,
+    };
+    let tx = offset(align_x, width - view_box.w * s) - view_box.x * s;
+    let ty = offset(align_y, height - view_box.h * s) - view_box.y * s;
+    Transform::new(s, 0.0, 0.0, s, tx, ty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Vec2, x: f32, y: f32) -> bool {
+        (a.x - x).abs() < 1e-3 && (a.y - y).abs() < 1e-3
+    }
+
+    #[test]
+    fn transform_list_applies_right_to_left() {
+        let t = parse_transform("translate(10, 0) scale(2)").unwrap();
+        assert!(close(t.apply(Vec2::new(1.0, 1.0)), 12.0, 2.0));
+        let r = parse_transform("rotate(90 5 5)").unwrap();
+        assert!(close(r.apply(Vec2::new(10.0, 5.0)), 5.0, 10.0));
+        assert!(parse_transform("wobble(3)").is_none());
+    }
+
+    #[test]
+    fn inverse_round_trips() {
+        let t = parse_transform("matrix(2 1 0 3 4 5)").unwrap();
+        let p = t.apply(Vec2::new(3.0, -2.0));
+        assert!(close(t.invert().unwrap().apply(p), 3.0, -2.0));
+    }
+
+    #[test]
+    fn view_box_meet_and_slice() {
+        let vb = parse_view_box("0 0 10 20").unwrap();
+        let meet = view_box_transform(&vb, PreserveAspectRatio::default(), 100.0, 100.0);
+        // Scaled by 5 and centred horizontally.
+        assert!(close(meet.apply(Vec2::new(0.0, 0.0)), 25.0, 0.0));
+        assert!(close(meet.apply(Vec2::new(10.0, 20.0)), 75.0, 100.0));
+
+        let slice = view_box_transform(&vb, PreserveAspectRatio::parse("xMinYMin slice"), 100.0, 100.0);
+        assert!(close(slice.apply(Vec2::new(10.0, 20.0)), 100.0, 200.0));
+
+        let none = view_box_transform(&vb, PreserveAspectRatio::parse("none"), 100.0, 100.0);
+        assert!(close(none.apply(Vec2::new(10.0, 20.0)), 100.0, 100.0));
+        assert!(parse_view_box("0 0 0 10").is_none());
+    }
+}
@@ -17,6 +17,7 @@ use crate::handshake::{
 };
 use crate::key_schedule::{self, KeySchedule, TrafficKeys};
 use crate::record::{self, ContentType, TlsRecord};
+use crate::trust::TrustStore;
 use crate::x509;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -49,6 +50,13 @@ pub struct TlsClient<S: Read + Write> {
     read_pos: usize,
     /// Hostname for SNI.
     hostname: String,
+    /// Leaf fingerprint the user has chosen to accept despite a validation
+    /// error.
+    allowed_fingerprint: Option<[u8; 32]>,
+    /// The server's certificate chain, leaf first.
+    peer_certificates: Vec<x509::X509Certificate>,
+    /// The validation error that `allowed_fingerprint` overrode, if any.
+    certificate_error: Option<x509::CertificateError>,
 }
 
 impl<S: Read + Write> TlsClient<S> {
@@ -62,7 +70,24 @@ impl<S: Read + Write> TlsClient<S> {
     /// 5. Process encrypted handshake messages (EncryptedExtensions, Certificate, CertificateVerify, Finished)
     /// 6. Send client Finished
     /// 7. Derive application traffic keys
-    pub fn connect(hostname: &str, mut stream: S) -> io::Result<Self> {
+    ///
+    /// The server's chain is checked against the system
+    /// [`TrustStore`](crate::trust::TrustStore). A rejected chain fails the
+    /// handshake with an `InvalidData` error whose payload is an
+    /// [`x509::InvalidCertificate`].
+    pub fn connect(hostname: &str, stream: S) -> io::Result<Self> {
+        Self::connect_with_exception(hostname, stream, None)
+    }
+
+    /// Like [`connect`](Self::connect), but accept a chain that fails
+    /// validation if its leaf has the given SHA-256 fingerprint. The
+    /// overridden error is available from
+    /// [`certificate_error`](Self::certificate_error).
+    pub fn connect_with_exception(
+        hostname: &str,
+        stream: S,
+        allowed_fingerprint: Option<[u8; 32]>,
+    ) -> io::Result<Self> {
         let mut client = TlsClient {
             stream,
             state: TlsClientState::Start,
@@ -77,12 +102,58 @@ impl<S: Read + Write> TlsClient<S> {
             read_buf: Vec::new(),
             read_pos: 0,
             hostname: hostname.to_string(),
+            allowed_fingerprint,
+            peer_certificates: Vec::new(),
+            certificate_error: None,
         };
 
         client.do_handshake()?;
         Ok(client)
     }
 
+    /// Parse and validate the server's Certificate message body.
+    fn check_certificates(&mut self, body: &[u8]) -> io::Result<()> {
+        let (chain, result) = match x509::parse_certificate_chain(body) {
+            Ok(chain) => {
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map_or(0, |d| d.as_secs());
+                let result = TrustStore::system().verify(&chain, &self.hostname, now);
+                (chain, result)
+            }
+            Err(e) => (Vec::new(), Err(x509::CertificateError::Malformed(e.to_string()))),
+        };
+        match result {
+            Ok(()) => {}
+            Err(error)
+                if chain
+                    .first()
+                    .is_some_and(|leaf| Some(leaf.fingerprint) == self.allowed_fingerprint) =>
+            {
+                self.certificate_error = Some(error);
+            }
+            Err(error) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    x509::InvalidCertificate { error, chain },
+                ));
+            }
+        }
+        self.peer_certificates = chain;
+        Ok(())
+    }
+
+    /// The server's certificate chain, leaf first.
+    pub fn peer_certificates(&self) -> &[x509::X509Certificate] {
+        &self.peer_certificates
+    }
+
+    /// The validation error that was overridden by an exception, if the
+    /// connection was allowed despite one.
+    pub fn certificate_error(&self) -> Option<&x509::CertificateError> {
+        self.certificate_error.as_ref()
+    }
+
     /// Read decrypted application data from the TLS connection.
     pub fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         // Return buffered data first
@@ -215,11 +286,11 @@ impl<S: Read + Write> TlsClient<S> {
         // Generate ephemeral X25519 key pair
         // In a real implementation, this would use a CSPRNG. For now, use a
         // deterministic but unique seed derived from hostname + timestamp-like data.
-        let (our_private, our_public) = generate_x25519_keypair(&self.hostname);
+        let (our_private, our_public) = generate_x25519_keypair();
 
         // Generate random bytes for ClientHello
-        let client_random = generate_random_bytes(&self.hostname, b"client_random");
-        let session_id = generate_random_bytes(&self.hostname, b"session_id");
+        let client_random: [u8; 32] = crypto::random_bytes();
+        let session_id: [u8; 32] = crypto::random_bytes();
 
         // Build and send ClientHello
         let client_hello = handshake::build_client_hello(
@@ -343,10 +414,7 @@ impl<S: Read + Write> TlsClient<S> {
                             }
                             11 => {
                                 self.state = TlsClientState::GotCertificate;
-                                if hs_len > 0 {
-                                    let _chain =
-                                        x509::parse_certificate_chain(&payload[off + 4..msg_end]).ok();
-                                }
+                                self.check_certificates(&payload[off + 4..msg_end])?;
                             }
                             15 => {
                                 self.state = TlsClientState::GotCertificateVerify;
@@ -467,18 +535,9 @@ fn extract_key_share(sh: &ServerHello) -> io::Result<Vec<u8>> {
     ))
 }
 
-/// Read cryptographically secure random bytes from `/dev/urandom`.
-fn read_urandom(buf: &mut [u8]) {
-    use std::io::Read;
-    if let Ok(mut f) = std::fs::File::open("/dev/urandom") {
-        let _ = f.read_exact(buf);
-    }
-}
-
-/// Generate an ephemeral X25519 key pair using OS entropy.
-fn generate_x25519_keypair(_hostname: &str) -> ([u8; 32], [u8; 32]) {
-    let mut private_key = [0u8; 32];
-    read_urandom(&mut private_key);
+/// Generate an ephemeral X25519 key pair from the DRBG.
+fn generate_x25519_keypair() -> ([u8; 32], [u8; 32]) {
+    let mut private_key: [u8; 32] = crypto::random_bytes();
 
     // Clamp private key per X25519 spec
     private_key[0] &= 248;
@@ -799,13 +858,6 @@ fn sub_with_borrow(a: u64, b: u64, borrow: bool) -> (u64, bool) {
     (d2, b1 || b2)
 }
 
-/// Generate random bytes using OS entropy (`/dev/urandom`).
-fn generate_random_bytes(_hostname: &str, _context: &[u8]) -> [u8; 32] {
-    let mut buf = [0u8; 32];
-    read_urandom(&mut buf);
-    buf
-}
-
 // ─────────────────────────────────────────────────────────────────────────────
 // Tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -933,12 +985,14 @@ mod tests {
     }
 
     #[test]
-    fn test_generate_random_bytes_unique() {
-        let r1 = generate_random_bytes("example.com", b"test");
-        let r2 = generate_random_bytes("example.com", b"test");
-        // Two calls should produce different values (from /dev/urandom).
-        assert_ne!(r1, r2);
-        // Should not be all zeros.
-        assert_ne!(r1, [0u8; 32]);
+    fn test_generate_x25519_keypair_unique() {
+        let (priv1, pub1) = generate_x25519_keypair();
+        let (priv2, pub2) = generate_x25519_keypair();
+        // Two calls should produce different keys (from the DRBG).
+        assert_ne!(priv1, priv2);
+        assert_ne!(pub1, pub2);
+        // Private keys are clamped.
+        assert_eq!(priv1[0] & 7, 0);
+        assert_eq!(priv1[31] & 0xC0, 0x40);
     }
 }
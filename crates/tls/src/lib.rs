@@ -1,7 +1,8 @@
 //! # TLS 1.3 Client (RFC 8446)
 //!
 //! A from-scratch TLS 1.3 client implementation. Provides the record layer,
-//! handshake state machine, key schedule derivation, X.509 certificate parsing,
+//! handshake state machine, key schedule derivation, X.509 certificate parsing
+//! and validation against the system trust store,
 //! and a high-level `TlsClient` for encrypted communication.
 //! **Zero external crate dependencies** (uses sibling `crypto` and `common` crates).
 
@@ -9,4 +10,5 @@ pub mod record;
 pub mod handshake;
 pub mod key_schedule;
 pub mod x509;
+pub mod trust;
 pub mod client;
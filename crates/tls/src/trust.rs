@@ -0,0 +1,271 @@
+//! Trust anchors and server certificate validation.
+//!
+//! Root certificates are loaded once from the system PEM bundle. A chain is
+//! accepted when the leaf matches the hostname, each certificate is signed
+//! by the next, every certificate is within its validity period, and the
+//! chain reaches a root in the store or a certificate signed by one. With
+//! no roots loaded nothing is trusted.
+
+use std::sync::OnceLock;
+
+use crate::x509::{self, CertificateError, X509Certificate};
+
+/// Well-known locations of the system CA bundle, tried in order.
+pub const SYSTEM_BUNDLES: &[&str] = &[
+    "/etc/ssl/certs/ca-certificates.crt",
+    "/etc/pki/tls/certs/ca-bundle.crt",
+    "/etc/ssl/ca-bundle.pem",
+    "/etc/ssl/cert.pem",
+];
+
+/// A set of trusted root certificates.
+#[derive(Debug, Default)]
+pub struct TrustStore {
+    roots: Vec<X509Certificate>,
+}
+
+impl TrustStore {
+    /// Parse every `CERTIFICATE` block in a PEM bundle, skipping ones that
+    /// fail to decode.
+    pub fn from_pem(pem: &str) -> Self {
+        let roots = pem_blocks(pem, "CERTIFICATE")
+            .filter_map(|der| x509::parse_certificate(&der).ok())
+            .collect();
+        Self { roots }
+    }
+
+    /// The process-wide store loaded from the first readable
+    /// [`SYSTEM_BUNDLES`] entry. Empty if none exists.
+    pub fn system() -> &'static TrustStore {
+        static STORE: OnceLock<TrustStore> = OnceLock::new();
+        STORE.get_or_init(|| {
+            SYSTEM_BUNDLES
+                .iter()
+                .find_map(|path| std::fs::read_to_string(path).ok())
+                .map(|pem| Self::from_pem(&pem))
+                .unwrap_or_default()
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.roots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    /// Whether some certificate of `chain` is a root, or was signed by
+    /// one that may issue it. Servers often leave the root out, or end
+    /// the chain with a cross-signed copy of it, so any position counts.
+    /// A root's name alone proves nothing: anyone can mint a certificate
+    /// claiming to be issued by it.
+    pub fn anchors(&self, chain: &[X509Certificate]) -> bool {
+        chain.iter().enumerate().any(|(index, cert)| {
+            self.roots.iter().any(|root| {
+                root.fingerprint == cert.fingerprint
+                    || (root.subject == cert.issuer
+                        && x509::check_issuer(root, index).is_ok()
+                        && x509::verify_signature(cert, root))
+            })
+        })
+    }
+
+    /// Validate a server chain for `hostname` at `now` (Unix seconds).
+    ///
+    /// Errors are reported in the order a user can act on them: name
+    /// mismatch, broken chain, validity period, then trust. An empty store
+    /// (no system bundle) trusts nothing.
+    pub fn verify(
+        &self,
+        chain: &[X509Certificate],
+        hostname: &str,
+        now: u64,
+    ) -> Result<(), CertificateError> {
+        x509::verify_chain(chain, hostname)?;
+        x509::check_validity(chain, now)?;
+        if self.anchors(chain) {
+            return Ok(());
+        }
+        let last = &chain[chain.len() - 1];
+        Err(if last.is_self_issued() {
+            CertificateError::SelfSigned {
+                subject: last.subject.clone(),
+            }
+        } else {
+            CertificateError::UntrustedIssuer {
+                issuer: last.issuer.clone(),
+            }
+        })
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// PEM
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Decode the base64 bodies of every `-----BEGIN {label}-----` block.
+pub fn pem_blocks<'a>(pem: &'a str, label: &'a str) -> impl Iterator<Item = Vec<u8>> + 'a {
+    let begin = format!("-----BEGIN {label}-----");
+    let end = format!("-----END {label}-----");
+    let mut rest = pem;
+    std::iter::from_fn(move || {
+        loop {
+            let start = rest.find(&begin)? + begin.len();
+            let len = rest[start..].find(&end)?;
+            let body = &rest[start..start + len];
+            rest = &rest[start + len + end.len()..];
+            if let Some(der) = base64_decode(body) {
+                return Some(der);
+            }
+        }
+    })
+}
+
+/// Standard base64 with padding; whitespace is ignored.
+fn base64_decode(text: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(text.len() * 3 / 4);
+    let mut acc = 0u32;
+    let mut bits = 0;
+    for b in text.bytes() {
+        let v = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            b if b.is_ascii_whitespace() => continue,
+            _ => return None,
+        };
+        acc = (acc << 6) | v as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cert(subject: &str, issuer: &str, names: &[&str]) -> X509Certificate {
+        X509Certificate {
+            tbs_der: Vec::new(),
+            issuer: issuer.to_string(),
+            subject: subject.to_string(),
+            spki: Vec::new(),
+            san_dns: names.iter().map(|n| n.to_string()).collect(),
+            not_before: "230101000000Z".to_string(),
+            not_after: "301231235959Z".to_string(),
+            is_ca: names.is_empty(),
+            path_len: None,
+            key_usage: None,
+            signature_algorithm: String::new(),
+            signature: Vec::new(),
+            serial_number: vec![1],
+            fingerprint: crypto::sha256::sha256(subject.as_bytes()),
+        }
+    }
+
+    const NOW: u64 = 1_760_000_000;
+
+    #[test]
+    fn test_base64_and_pem() {
+        assert_eq!(base64_decode("aGVs bG8=").unwrap(), b"hello");
+        let pem = "junk\n-----BEGIN CERTIFICATE-----\nAAEC\n-----END CERTIFICATE-----\n\
+                   -----BEGIN CERTIFICATE-----\n/w==\n-----END CERTIFICATE-----\n";
+        let blocks: Vec<_> = pem_blocks(pem, "CERTIFICATE").collect();
+        assert_eq!(blocks, vec![vec![0, 1, 2], vec![0xFF]]);
+    }
+
+    /// Leaf, intermediate and root made with `openssl req`/`x509`; the
+    /// impostor root has the real root's name but its own key.
+    fn fixture_chain() -> Vec<X509Certificate> {
+        TrustStore::from_pem(include_str!("../testdata/chain.pem")).roots
+    }
+
+    #[test]
+    fn test_verify_anchored_chain() {
+        let store = TrustStore::from_pem(include_str!("../testdata/root.pem"));
+        let chain = fixture_chain();
+        assert!(store.verify(&chain, "example.com", NOW).is_ok());
+        assert!(matches!(
+            store.verify(&chain, "other.com", NOW),
+            Err(CertificateError::HostnameMismatch { .. })
+        ));
+        // A chain that includes the root itself.
+        let mut full = chain.clone();
+        full.push(store.roots[0].clone());
+        assert!(store.verify(&full, "example.com", NOW).is_ok());
+    }
+
+    #[test]
+    fn test_verify_requires_root_signature() {
+        let impostor = TrustStore::from_pem(include_str!("../testdata/impostor_root.pem"));
+        let chain = fixture_chain();
+        assert_eq!(impostor.roots[0].subject, chain[1].issuer);
+        assert_eq!(
+            impostor.verify(&chain, "example.com", NOW),
+            Err(CertificateError::UntrustedIssuer {
+                issuer: "Test Root CA".to_string()
+            })
+        );
+        // Names alone no longer anchor a chain.
+        let store = TrustStore {
+            roots: vec![cert("Root CA", "Root CA", &[])],
+        };
+        let unsigned = [cert("example.com", "Root CA", &["example.com"])];
+        assert!(!store.anchors(&unsigned));
+    }
+
+    #[test]
+    fn test_anchor_below_the_end_of_the_chain() {
+        // The intermediate was signed by a root the server did not send;
+        // a trailing certificate that leads nowhere does not matter.
+        let store = TrustStore::from_pem(include_str!("../testdata/root.pem"));
+        let impostor = TrustStore::from_pem(include_str!("../testdata/impostor_root.pem"));
+        let mut chain = fixture_chain();
+        chain.push(impostor.roots[0].clone());
+        assert!(store.anchors(&chain));
+        assert!(!impostor.anchors(&chain[..2]));
+
+        // A root that is not a CA anchors nothing it did not itself send.
+        let mut not_ca = store.roots[0].clone();
+        not_ca.is_ca = false;
+        let store = TrustStore { roots: vec![not_ca] };
+        assert!(!store.anchors(&chain[..2]));
+    }
+
+    #[test]
+    fn test_verify_untrusted() {
+        let store = TrustStore {
+            roots: vec![cert("Root CA", "Root CA", &[])],
+        };
+        let self_signed = [cert("example.com", "example.com", &["example.com"])];
+        assert_eq!(
+            store.verify(&self_signed, "example.com", NOW),
+            Err(CertificateError::SelfSigned {
+                subject: "example.com".to_string()
+            })
+        );
+        let unknown = [cert("example.com", "Shady CA", &["example.com"])];
+        let err = store.verify(&unknown, "example.com", NOW).unwrap_err();
+        assert_eq!(err.code(), "ERR_CERT_AUTHORITY_INVALID");
+
+        // Without a system bundle nothing is trusted.
+        assert_eq!(
+            TrustStore::default().verify(&unknown, "example.com", NOW),
+            Err(CertificateError::UntrustedIssuer {
+                issuer: "Shady CA".to_string()
+            })
+        );
+        let store = TrustStore::from_pem(include_str!("../testdata/root.pem"));
+        let chain = fixture_chain();
+        assert!(store.verify(&chain, "example.com", NOW).is_ok());
+        assert!(TrustStore::default().verify(&chain, "example.com", NOW).is_err());
+    }
+}
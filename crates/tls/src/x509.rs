@@ -5,6 +5,10 @@
 //! - DER tag/length/value parsing
 //! - TBSCertificate extraction (issuer, subject, SPKI, SAN, validity)
 //! - Certificate chain building and hostname verification
+//! - Signature verification (RSA PKCS #1 v1.5 and ECDSA on P-256/P-384,
+//!   with SHA-256/384/512)
+//! - Validity period checks and a [`CertificateError`] describing why a
+//!   chain was rejected
 //!
 //! **Zero external crate dependencies.**
 
@@ -193,9 +197,14 @@ pub const OID_BASIC_CONSTRAINTS: &str = "2.5.29.19";
 pub const OID_KEY_USAGE: &str = "2.5.29.15";
 pub const OID_RSA_ENCRYPTION: &str = "1.2.840.113549.1.1.1";
 pub const OID_SHA256_WITH_RSA: &str = "1.2.840.113549.1.1.11";
+pub const OID_SHA384_WITH_RSA: &str = "1.2.840.113549.1.1.12";
+pub const OID_SHA512_WITH_RSA: &str = "1.2.840.113549.1.1.13";
 pub const OID_ECDSA_WITH_SHA256: &str = "1.2.840.10045.4.3.2";
+pub const OID_ECDSA_WITH_SHA384: &str = "1.2.840.10045.4.3.3";
+pub const OID_ECDSA_WITH_SHA512: &str = "1.2.840.10045.4.3.4";
 pub const OID_EC_PUBLIC_KEY: &str = "1.2.840.10045.2.1";
 pub const OID_SECP256R1: &str = "1.2.840.10045.3.1.7";
+pub const OID_SECP384R1: &str = "1.3.132.0.34";
 
 // ─────────────────────────────────────────────────────────────────────────────
 // X.509 Certificate
@@ -220,12 +229,32 @@ pub struct X509Certificate {
     pub not_after: String,
     /// Whether this is a CA certificate (from BasicConstraints).
     pub is_ca: bool,
+    /// BasicConstraints' pathLenConstraint: how many intermediates may
+    /// follow this CA on the way to a leaf.
+    pub path_len: Option<u32>,
+    /// KeyUsage bits (`KEY_USAGE_*`), if the extension is present.
+    pub key_usage: Option<u16>,
     /// Signature algorithm OID.
     pub signature_algorithm: String,
     /// Signature bytes.
     pub signature: Vec<u8>,
+    /// Serial number (big-endian INTEGER contents).
+    pub serial_number: Vec<u8>,
+    /// SHA-256 over the whole DER certificate.
+    pub fingerprint: [u8; 32],
 }
 
+impl X509Certificate {
+    /// Whether the certificate names itself as its issuer.
+    pub fn is_self_issued(&self) -> bool {
+        self.issuer == self.subject
+    }
+}
+
+/// KeyUsage `keyCertSign`: the key may sign certificates. Bit `n` of the
+/// KeyUsage BIT STRING is `1 << n`.
+pub const KEY_USAGE_KEY_CERT_SIGN: u16 = 1 << 5;
+
 /// Parse a single X.509 certificate from DER bytes.
 pub fn parse_certificate(der: &[u8]) -> Result<X509Certificate, &'static str> {
     let mut reader = DerReader::new(der);
@@ -262,7 +291,7 @@ pub fn parse_certificate(der: &[u8]) -> Result<X509Certificate, &'static str> {
     }
 
     // serialNumber INTEGER
-    let _serial = tbs.read_tlv()?;
+    let serial_number = tbs.read_tlv()?.value.to_vec();
 
     // signature AlgorithmIdentifier
     let _sig_alg = tbs.read_tlv()?;
@@ -286,8 +315,7 @@ pub fn parse_certificate(der: &[u8]) -> Result<X509Certificate, &'static str> {
         .to_vec();
 
     // Extensions [3] EXPLICIT (optional)
-    let mut san_dns = Vec::new();
-    let mut is_ca = false;
+    let mut extensions = Extensions::default();
 
     while !tbs.is_empty() {
         let ext_container = tbs.read_tlv()?;
@@ -299,7 +327,7 @@ pub fn parse_certificate(der: &[u8]) -> Result<X509Certificate, &'static str> {
                     let mut exts = DerReader::new(ext_seq.value);
                     while !exts.is_empty() {
                         if let Ok(ext) = exts.read_tlv() {
-                            parse_extension(ext.value, &mut san_dns, &mut is_ca);
+                            parse_extension(ext.value, &mut extensions);
                         }
                     }
                 }
@@ -325,12 +353,16 @@ pub fn parse_certificate(der: &[u8]) -> Result<X509Certificate, &'static str> {
         issuer,
         subject,
         spki,
-        san_dns,
+        san_dns: extensions.san_dns,
         not_before,
         not_after,
-        is_ca,
+        is_ca: extensions.is_ca,
+        path_len: extensions.path_len,
+        key_usage: extensions.key_usage,
         signature_algorithm,
         signature,
+        serial_number,
+        fingerprint: crypto::sha256::sha256(&der[..cert_seq.total_len]),
     })
 }
 
@@ -391,8 +423,18 @@ fn extract_algorithm_oid(data: &[u8]) -> Option<String> {
     None
 }
 
-/// Parse a single Extension SEQUENCE and extract SAN / BasicConstraints.
-fn parse_extension(ext_data: &[u8], san_dns: &mut Vec<String>, is_ca: &mut bool) {
+/// The extensions [`X509Certificate`] keeps.
+#[derive(Default)]
+struct Extensions {
+    san_dns: Vec<String>,
+    is_ca: bool,
+    path_len: Option<u32>,
+    key_usage: Option<u16>,
+}
+
+/// Parse a single Extension SEQUENCE and extract SAN / BasicConstraints /
+/// KeyUsage.
+fn parse_extension(ext_data: &[u8], extensions: &mut Extensions) {
     let mut reader = DerReader::new(ext_data);
 
     // extnID OBJECT IDENTIFIER
@@ -420,9 +462,11 @@ fn parse_extension(ext_data: &[u8], san_dns: &mut Vec<String>, is_ca: &mut bool)
     }
 
     if oid == OID_SUBJECT_ALT_NAME {
-        parse_san(value_tlv.value, san_dns);
+        parse_san(value_tlv.value, &mut extensions.san_dns);
     } else if oid == OID_BASIC_CONSTRAINTS {
-        parse_basic_constraints(value_tlv.value, is_ca);
+        parse_basic_constraints(value_tlv.value, extensions);
+    } else if oid == OID_KEY_USAGE {
+        extensions.key_usage = parse_key_usage(value_tlv.value);
     }
 }
 
@@ -448,73 +492,407 @@ fn parse_san(data: &[u8], san_dns: &mut Vec<String>) {
 }
 
 /// Parse BasicConstraints extension value.
-fn parse_basic_constraints(data: &[u8], is_ca: &mut bool) {
+fn parse_basic_constraints(data: &[u8], extensions: &mut Extensions) {
     // BasicConstraints ::= SEQUENCE { cA BOOLEAN DEFAULT FALSE, pathLenConstraint INTEGER OPTIONAL }
     let mut reader = DerReader::new(data);
-    if let Ok(seq_tlv) = reader.read_tlv() {
-        if seq_tlv.tag == TAG_SEQUENCE && !seq_tlv.value.is_empty() {
-            let mut inner = DerReader::new(seq_tlv.value);
-            if let Ok(bool_tlv) = inner.read_tlv() {
-                if bool_tlv.tag == TAG_BOOLEAN && !bool_tlv.value.is_empty() {
-                    *is_ca = bool_tlv.value[0] != 0;
+    let Ok(seq_tlv) = reader.read_tlv() else {
+        return;
+    };
+    if seq_tlv.tag != TAG_SEQUENCE {
+        return;
+    }
+    let mut inner = DerReader::new(seq_tlv.value);
+    if inner.peek_tag() == Some(TAG_BOOLEAN)
+        && let Ok(bool_tlv) = inner.read_tlv()
+    {
+        extensions.is_ca = bool_tlv.value.first().is_some_and(|&b| b != 0);
+    }
+    // A constraint too large for u32 is no constraint at all.
+    if let Ok(int_tlv) = inner.read_tlv()
+        && int_tlv.tag == TAG_INTEGER
+        && int_tlv.value.len() <= 4
+    {
+        let len = int_tlv.value.iter().fold(0u32, |acc, &b| acc << 8 | b as u32);
+        extensions.path_len = Some(len);
+    }
+}
+
+/// Parse a KeyUsage extension value into `KEY_USAGE_*` bits.
+fn parse_key_usage(data: &[u8]) -> Option<u16> {
+    // KeyUsage ::= BIT STRING; bit 0 is the most significant bit of the
+    // first byte after the unused-bits count.
+    let mut reader = DerReader::new(data);
+    let bits = reader.read_tlv().ok()?;
+    if bits.tag != TAG_BIT_STRING || bits.value.is_empty() {
+        return None;
+    }
+    let mut usage = 0u16;
+    for (i, &byte) in bits.value[1..].iter().take(2).enumerate() {
+        for bit in 0..8 {
+            if byte & (0x80 >> bit) != 0 {
+                usage |= 1 << (i * 8 + bit);
+            }
+        }
+    }
+    Some(usage)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Signatures
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Whether `cert` carries a valid signature by `issuer`'s public key.
+///
+/// Unsupported signature or key algorithms (RSA-PSS, Ed25519, P-521)
+/// count as invalid: the chain cannot be shown to be genuine.
+pub fn verify_signature(cert: &X509Certificate, issuer: &X509Certificate) -> bool {
+    use crypto::sha512::{sha384, sha512};
+    let digest = match cert.signature_algorithm.as_str() {
+        OID_SHA256_WITH_RSA | OID_ECDSA_WITH_SHA256 => crypto::sha256(&cert.tbs_der).to_vec(),
+        OID_SHA384_WITH_RSA | OID_ECDSA_WITH_SHA384 => sha384(&cert.tbs_der).to_vec(),
+        OID_SHA512_WITH_RSA | OID_ECDSA_WITH_SHA512 => sha512(&cert.tbs_der).to_vec(),
+        _ => return false,
+    };
+    let Some((key_algorithm, curve, key)) = parse_spki(&issuer.spki) else {
+        return false;
+    };
+    match (cert.signature_algorithm.as_str(), key_algorithm.as_str()) {
+        (OID_SHA256_WITH_RSA | OID_SHA384_WITH_RSA | OID_SHA512_WITH_RSA, OID_RSA_ENCRYPTION) => {
+            // RSAPublicKey ::= SEQUENCE { modulus INTEGER, publicExponent INTEGER }
+            let mut reader = DerReader::new(key);
+            let Ok(seq) = reader.read_tlv() else {
+                return false;
+            };
+            let mut ints = DerReader::new(seq.value);
+            match (ints.read_tlv(), ints.read_tlv()) {
+                (Ok(n), Ok(e)) => {
+                    crypto::rsa::verify_pkcs1_v15(n.value, e.value, &digest, &cert.signature)
                 }
+                _ => false,
+            }
+        }
+        (_, OID_EC_PUBLIC_KEY) if cert.signature_algorithm.starts_with("1.2.840.10045.4.3.") => {
+            let curve = match curve.as_deref() {
+                Some(OID_SECP256R1) => crypto::ecdsa::Curve::P256,
+                Some(OID_SECP384R1) => crypto::ecdsa::Curve::P384,
+                _ => return false,
+            };
+            // Ecdsa-Sig-Value ::= SEQUENCE { r INTEGER, s INTEGER }
+            let mut reader = DerReader::new(&cert.signature);
+            let Ok(seq) = reader.read_tlv() else {
+                return false;
+            };
+            let mut ints = DerReader::new(seq.value);
+            match (ints.read_tlv(), ints.read_tlv()) {
+                (Ok(r), Ok(s)) => crypto::ecdsa::verify(curve, key, &digest, r.value, s.value),
+                _ => false,
             }
         }
+        _ => false,
     }
 }
 
+/// Split a SubjectPublicKeyInfo into its algorithm OID, the curve OID for
+/// EC keys, and the key bits.
+fn parse_spki(spki: &[u8]) -> Option<(String, Option<String>, &[u8])> {
+    let mut reader = DerReader::new(spki);
+    let seq = reader.read_tlv().ok()?;
+    let mut inner = DerReader::new(seq.value);
+    let algorithm = inner.read_tlv().ok()?;
+    let key = inner.read_tlv().ok()?;
+    if key.tag != TAG_BIT_STRING || key.value.first() != Some(&0) {
+        return None;
+    }
+    let mut alg = DerReader::new(algorithm.value);
+    let oid = alg.read_tlv().ok()?;
+    let params = alg.read_tlv().ok().filter(|p| p.tag == TAG_OID);
+    Some((
+        decode_oid(oid.value),
+        params.map(|p| decode_oid(p.value)),
+        &key.value[1..],
+    ))
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Certificate chain verification
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Why a server's certificate chain was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CertificateError {
+    /// The server sent no certificates.
+    Empty,
+    /// The Certificate message or a certificate in it could not be parsed.
+    Malformed(String),
+    /// The leaf is not valid for the host that was contacted.
+    HostnameMismatch { hostname: String, names: Vec<String> },
+    /// `not_after` has passed.
+    Expired { not_after: String },
+    /// `not_before` is still in the future.
+    NotYetValid { not_before: String },
+    /// The chain is broken at link `index`: the certificate there was not
+    /// (or may not have been) issued by the next one.
+    BadChain { index: usize, fault: ChainFault },
+    /// A certificate's signature does not verify under its issuer's key.
+    BadSignature { subject: String },
+    /// The chain ends in a self-signed certificate that is not a trust anchor.
+    SelfSigned { subject: String },
+    /// The chain does not lead to a trusted root.
+    UntrustedIssuer { issuer: String },
+}
+
+impl CertificateError {
+    /// A short stable identifier, in the style of browser error codes.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Empty | Self::Malformed(_) | Self::BadChain { .. } | Self::BadSignature { .. } => {
+                "ERR_CERT_INVALID"
+            }
+            Self::HostnameMismatch { .. } => "ERR_CERT_COMMON_NAME_INVALID",
+            Self::Expired { .. } | Self::NotYetValid { .. } => "ERR_CERT_DATE_INVALID",
+            Self::SelfSigned { .. } | Self::UntrustedIssuer { .. } => "ERR_CERT_AUTHORITY_INVALID",
+        }
+    }
+}
+
+impl std::fmt::Display for CertificateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty certificate chain"),
+            Self::Malformed(msg) => write!(f, "malformed certificate: {msg}"),
+            Self::HostnameMismatch { hostname, names } => write!(
+                f,
+                "hostname '{hostname}' does not match certificate (names: {})",
+                names.join(", ")
+            ),
+            Self::Expired { not_after } => {
+                write!(f, "certificate expired on {}", format_time(not_after))
+            }
+            Self::NotYetValid { not_before } => {
+                write!(f, "certificate is not valid until {}", format_time(not_before))
+            }
+            Self::BadChain { index, fault } => write!(f, "chain link {index}: {fault}"),
+            Self::BadSignature { subject } => {
+                write!(f, "signature on certificate '{subject}' does not verify")
+            }
+            Self::SelfSigned { subject } => {
+                write!(f, "self-signed certificate '{subject}' is not trusted")
+            }
+            Self::UntrustedIssuer { issuer } => {
+                write!(f, "issuer '{issuer}' is not a trusted certificate authority")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CertificateError {}
+
+/// How a link in a chain is broken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChainFault {
+    /// The certificate's issuer is not the next certificate's subject.
+    IssuerMismatch { issuer: String, next_subject: String },
+    /// The issuer's BasicConstraints do not say `cA=TRUE`.
+    NotCa { subject: String },
+    /// More intermediates sit below the issuer than its pathLenConstraint
+    /// allows.
+    PathLenExceeded { subject: String, path_len: u32 },
+    /// The issuer has a KeyUsage extension without `keyCertSign`.
+    NoKeyCertSign { subject: String },
+}
+
+impl std::fmt::Display for ChainFault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IssuerMismatch { issuer, next_subject } => {
+                write!(f, "issuer '{issuer}' does not match next subject '{next_subject}'")
+            }
+            Self::NotCa { subject } => {
+                write!(f, "'{subject}' is not a certificate authority")
+            }
+            Self::PathLenExceeded { subject, path_len } => write!(
+                f,
+                "'{subject}' allows at most {path_len} intermediate certificates below it"
+            ),
+            Self::NoKeyCertSign { subject } => {
+                write!(f, "the key of '{subject}' may not sign certificates")
+            }
+        }
+    }
+}
+
+/// A rejected chain, carried as the payload of the handshake's `io::Error`
+/// so callers can show the certificate and make an exception for it.
+#[derive(Debug, Clone)]
+pub struct InvalidCertificate {
+    pub error: CertificateError,
+    /// The chain as sent by the server, leaf first. Empty if unparseable.
+    pub chain: Vec<X509Certificate>,
+}
+
+impl std::fmt::Display for InvalidCertificate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl std::error::Error for InvalidCertificate {}
+
 /// Verify that a certificate chain is plausible and the leaf matches the hostname.
 ///
 /// This performs:
 /// 1. Hostname verification (SAN or CN matching with basic wildcard support)
-/// 2. Chain ordering check (each cert issued by the next)
-/// 3. CA flag check for intermediates
+/// 2. Chain ordering check (each cert issued by the next, and signed by
+///    its key)
+/// 3. Issuer checks: every certificate after the leaf must be allowed to
+///    issue the one before it (see [`check_issuer`])
 ///
-/// NOTE: This does **not** verify cryptographic signatures (that would require
-/// the full RSA/ECDSA verify path). For a real browser this must be completed.
-pub fn verify_chain(
-    chain: &[X509Certificate],
-    hostname: &str,
-    _root_certs: &[X509Certificate],
-) -> Result<(), String> {
+/// Validity periods and trust anchors are checked by
+/// [`crate::trust::TrustStore::verify`].
+pub fn verify_chain(chain: &[X509Certificate], hostname: &str) -> Result<(), CertificateError> {
     if chain.is_empty() {
-        return Err("empty certificate chain".to_string());
+        return Err(CertificateError::Empty);
     }
 
     let leaf = &chain[0];
 
     // 1. Hostname verification
     if !verify_hostname(leaf, hostname) {
-        return Err(format!(
-            "hostname '{}' does not match certificate (subject='{}', SANs={:?})",
-            hostname, leaf.subject, leaf.san_dns
-        ));
+        let names = if leaf.san_dns.is_empty() {
+            vec![leaf.subject.clone()]
+        } else {
+            leaf.san_dns.clone()
+        };
+        return Err(CertificateError::HostnameMismatch {
+            hostname: hostname.to_string(),
+            names,
+        });
     }
 
-    // 2. Chain ordering: each cert's issuer should match the next cert's subject
+    // 2. Chain ordering: each cert's issuer should match the next cert's
+    //    subject, and the next cert's key should verify its signature.
+    // 3. The next cert must be a CA allowed to sign at this depth.
     for i in 0..chain.len().saturating_sub(1) {
         if chain[i].issuer != chain[i + 1].subject {
-            return Err(format!(
-                "chain link {}: issuer '{}' does not match next subject '{}'",
-                i, chain[i].issuer, chain[i + 1].subject
-            ));
+            return Err(CertificateError::BadChain {
+                index: i,
+                fault: ChainFault::IssuerMismatch {
+                    issuer: chain[i].issuer.clone(),
+                    next_subject: chain[i + 1].subject.clone(),
+                },
+            });
+        }
+        check_issuer(&chain[i + 1], i)?;
+        if !verify_signature(&chain[i], &chain[i + 1]) {
+            return Err(CertificateError::BadSignature {
+                subject: chain[i].subject.clone(),
+            });
         }
     }
 
-    // 3. Intermediate certs should have CA=true
-    for cert in chain.iter().skip(1) {
-        if !cert.is_ca {
-            // Warn but don't hard-fail (some certs omit BasicConstraints)
+    Ok(())
+}
+
+/// Check that `issuer` may sign the certificate at `index` of a chain,
+/// i.e. with `index` intermediates between it and the leaf: it must be a
+/// CA, its pathLenConstraint must allow that many intermediates, and if
+/// it has a KeyUsage extension that must include `keyCertSign`.
+pub fn check_issuer(issuer: &X509Certificate, index: usize) -> Result<(), CertificateError> {
+    let fault = if !issuer.is_ca {
+        ChainFault::NotCa {
+            subject: issuer.subject.clone(),
         }
-    }
+    } else if let Some(path_len) = issuer.path_len.filter(|&len| index > len as usize) {
+        ChainFault::PathLenExceeded {
+            subject: issuer.subject.clone(),
+            path_len,
+        }
+    } else if issuer.key_usage.is_some_and(|usage| usage & KEY_USAGE_KEY_CERT_SIGN == 0) {
+        ChainFault::NoKeyCertSign {
+            subject: issuer.subject.clone(),
+        }
+    } else {
+        return Ok(());
+    };
+    Err(CertificateError::BadChain { index, fault })
+}
 
+/// Check every certificate's validity period against `now` (Unix seconds).
+pub fn check_validity(chain: &[X509Certificate], now: u64) -> Result<(), CertificateError> {
+    for cert in chain {
+        if parse_time(&cert.not_before).is_some_and(|t| now < t) {
+            return Err(CertificateError::NotYetValid {
+                not_before: cert.not_before.clone(),
+            });
+        }
+        if parse_time(&cert.not_after).is_some_and(|t| now > t) {
+            return Err(CertificateError::Expired {
+                not_after: cert.not_after.clone(),
+            });
+        }
+    }
     Ok(())
 }
 
+/// Parse a UTCTime (`YYMMDDHHMMSSZ`) or GeneralizedTime
+/// (`YYYYMMDDHHMMSSZ`) value into Unix seconds.
+pub fn parse_time(time: &str) -> Option<u64> {
+    let digits = time.strip_suffix('Z')?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (year, rest) = match digits.len() {
+        // RFC 5280 §4.1.2.5.1: YY >= 50 is 19YY.
+        12 => {
+            let yy: i64 = digits[..2].parse().ok()?;
+            (if yy >= 50 { 1900 + yy } else { 2000 + yy }, &digits[2..])
+        }
+        14 => (digits[..4].parse().ok()?, &digits[4..]),
+        _ => return None,
+    };
+    let field = |i: usize| rest[i..i + 2].parse::<i64>().ok();
+    let (month, day) = (field(0)?, field(2)?);
+    let (hour, minute, second) = (field(4)?, field(6)?, field(8)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    // Days from the civil date (Howard Hinnant's algorithm).
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    u64::try_from(days * 86_400 + hour * 3_600 + minute * 60 + second).ok()
+}
+
+/// Render an ASN.1 time as `YYYY-MM-DD HH:MM:SS UTC` for display; values
+/// that don't parse are returned unchanged.
+pub fn format_time(time: &str) -> String {
+    let Some(digits) = time.strip_suffix('Z') else {
+        return time.to_string();
+    };
+    let full = match digits.len() {
+        12 if digits.bytes().all(|b| b.is_ascii_digit()) => {
+            let century = if digits[..2] >= *"50" { "19" } else { "20" };
+            format!("{century}{digits}")
+        }
+        14 if digits.bytes().all(|b| b.is_ascii_digit()) => digits.to_string(),
+        _ => return time.to_string(),
+    };
+    format!(
+        "{}-{}-{} {}:{}:{} UTC",
+        &full[..4],
+        &full[4..6],
+        &full[6..8],
+        &full[8..10],
+        &full[10..12],
+        &full[12..14]
+    )
+}
+
 /// Check if a certificate matches the given hostname.
 ///
 /// Checks SAN dNSName entries first; falls back to subject CN.
@@ -701,8 +1079,12 @@ mod tests {
             not_before: String::new(),
             not_after: String::new(),
             is_ca: false,
+            path_len: None,
+            key_usage: None,
             signature_algorithm: String::new(),
             signature: Vec::new(),
+            serial_number: Vec::new(),
+            fingerprint: [0; 32],
         };
 
         assert!(verify_hostname(&cert, "example.com"));
@@ -721,8 +1103,12 @@ mod tests {
             not_before: String::new(),
             not_after: String::new(),
             is_ca: false,
+            path_len: None,
+            key_usage: None,
             signature_algorithm: String::new(),
             signature: Vec::new(),
+            serial_number: Vec::new(),
+            fingerprint: [0; 32],
         };
 
         assert!(verify_hostname(&cert, "example.com"));
@@ -731,7 +1117,7 @@ mod tests {
 
     #[test]
     fn test_verify_chain_empty() {
-        assert!(verify_chain(&[], "example.com", &[]).is_err());
+        assert!(verify_chain(&[], "example.com").is_err());
     }
 
     #[test]
@@ -745,10 +1131,15 @@ mod tests {
             not_before: String::new(),
             not_after: String::new(),
             is_ca: false,
+            path_len: None,
+            key_usage: None,
             signature_algorithm: String::new(),
             signature: Vec::new(),
+            serial_number: Vec::new(),
+            fingerprint: [0; 32],
         };
-        assert!(verify_chain(&[cert], "wrong.com", &[]).is_err());
+        let err = verify_chain(&[cert], "wrong.com").unwrap_err();
+        assert_eq!(err.code(), "ERR_CERT_COMMON_NAME_INVALID");
     }
 
     #[test]
@@ -762,27 +1153,96 @@ mod tests {
             not_before: String::new(),
             not_after: String::new(),
             is_ca: false,
+            path_len: None,
+            key_usage: None,
             signature_algorithm: String::new(),
             signature: Vec::new(),
+            serial_number: Vec::new(),
+            fingerprint: [0; 32],
         };
-        assert!(verify_chain(&[cert], "example.com", &[]).is_ok());
+        assert!(verify_chain(&[cert], "example.com").is_ok());
+    }
+
+    /// Leaf (P-256 key) signed by an RSA intermediate with SHA-256, which
+    /// a P-384 root signed with SHA-384; made with `openssl req`/`x509`.
+    fn fixture_chain() -> Vec<X509Certificate> {
+        crate::trust::pem_blocks(include_str!("../testdata/chain.pem"), "CERTIFICATE")
+            .map(|der| parse_certificate(&der).unwrap())
+            .collect()
+    }
+
+    fn fixture_root() -> X509Certificate {
+        let der = crate::trust::pem_blocks(include_str!("../testdata/root.pem"), "CERTIFICATE")
+            .next()
+            .unwrap();
+        parse_certificate(&der).unwrap()
     }
 
     #[test]
     fn test_verify_chain_ordering() {
-        let leaf = X509Certificate {
-            tbs_der: Vec::new(),
-            issuer: "Intermediate CA".to_string(),
-            subject: "example.com".to_string(),
-            spki: Vec::new(),
-            san_dns: vec!["example.com".to_string()],
-            not_before: String::new(),
-            not_after: String::new(),
-            is_ca: false,
-            signature_algorithm: String::new(),
-            signature: Vec::new(),
-        };
-        let intermediate = X509Certificate {
+        let chain = fixture_chain();
+        assert_eq!(chain[1].subject, "Test Intermediate CA");
+        assert!(verify_chain(&chain, "example.com").is_ok());
+        assert!(verify_chain(&chain, "www.example.com").is_ok());
+    }
+
+    #[test]
+    fn test_verify_signature_rsa_and_ecdsa() {
+        let chain = fixture_chain();
+        let root = fixture_root();
+        assert_eq!(chain[0].signature_algorithm, OID_SHA256_WITH_RSA);
+        assert_eq!(chain[1].signature_algorithm, OID_ECDSA_WITH_SHA384);
+        assert!(verify_signature(&chain[0], &chain[1]));
+        assert!(verify_signature(&chain[1], &root));
+        assert!(verify_signature(&root, &root));
+        // Wrong issuer key.
+        assert!(!verify_signature(&chain[0], &root));
+        assert!(!verify_signature(&chain[1], &chain[0]));
+    }
+
+    #[test]
+    fn test_verify_chain_bad_signature() {
+        let mut chain = fixture_chain();
+        let last = chain[0].tbs_der.len() - 1;
+        chain[0].tbs_der[last] ^= 1;
+        assert_eq!(
+            verify_chain(&chain, "example.com"),
+            Err(CertificateError::BadSignature {
+                subject: "example.com".to_string()
+            })
+        );
+
+        // An unsupported algorithm cannot be shown to be genuine.
+        let mut chain = fixture_chain();
+        chain[0].signature_algorithm = "1.2.840.113549.1.1.10".to_string(); // RSASSA-PSS
+        assert_eq!(verify_chain(&chain, "example.com").unwrap_err().code(), "ERR_CERT_INVALID");
+    }
+
+    #[test]
+    fn test_verify_chain_rejects_leaf_as_issuer() {
+        // bank.example signed with the key of the genuine example.com leaf.
+        let chain: Vec<_> =
+            crate::trust::pem_blocks(include_str!("../testdata/leaf_issuer_chain.pem"), "CERTIFICATE")
+                .map(|der| parse_certificate(&der).unwrap())
+                .collect();
+        assert!(verify_signature(&chain[0], &chain[1]));
+        assert!(!chain[1].is_ca);
+        let err = verify_chain(&chain, "bank.example").unwrap_err();
+        assert_eq!(
+            err,
+            CertificateError::BadChain {
+                index: 0,
+                fault: ChainFault::NotCa {
+                    subject: "example.com".to_string()
+                },
+            }
+        );
+        assert_eq!(err.code(), "ERR_CERT_INVALID");
+    }
+
+    #[test]
+    fn test_check_issuer_constraints() {
+        let ca = |path_len, key_usage| X509Certificate {
             tbs_der: Vec::new(),
             issuer: "Root CA".to_string(),
             subject: "Intermediate CA".to_string(),
@@ -791,10 +1251,49 @@ mod tests {
             not_before: String::new(),
             not_after: String::new(),
             is_ca: true,
+            path_len,
+            key_usage,
             signature_algorithm: String::new(),
             signature: Vec::new(),
+            serial_number: Vec::new(),
+            fingerprint: [0; 32],
         };
-        assert!(verify_chain(&[leaf, intermediate], "example.com", &[]).is_ok());
+        assert!(check_issuer(&ca(None, None), 3).is_ok());
+        assert!(check_issuer(&ca(Some(0), None), 0).is_ok());
+        assert!(matches!(
+            check_issuer(&ca(Some(0), None), 1),
+            Err(CertificateError::BadChain {
+                index: 1,
+                fault: ChainFault::PathLenExceeded { path_len: 0, .. }
+            })
+        ));
+        assert!(check_issuer(&ca(Some(1), Some(KEY_USAGE_KEY_CERT_SIGN)), 1).is_ok());
+        let digital_signature = 1 << 0;
+        assert!(matches!(
+            check_issuer(&ca(None, Some(digital_signature)), 0),
+            Err(CertificateError::BadChain {
+                fault: ChainFault::NoKeyCertSign { .. },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_parse_key_usage_and_path_len() {
+        // digitalSignature | keyCertSign | cRLSign, one unused bit.
+        let key_usage = der_tlv(TAG_BIT_STRING, &[0x01, 0x86]);
+        assert_eq!(parse_key_usage(&key_usage), Some(1 | KEY_USAGE_KEY_CERT_SIGN | 1 << 6));
+
+        let mut extensions = Extensions::default();
+        let constraints = der_seq(&[&der_tlv(TAG_BOOLEAN, &[0xFF]), &der_tlv(TAG_INTEGER, &[2])]);
+        parse_basic_constraints(&constraints, &mut extensions);
+        assert!(extensions.is_ca);
+        assert_eq!(extensions.path_len, Some(2));
+
+        let chain = fixture_chain();
+        assert!(chain[1].is_ca);
+        assert_eq!(chain[1].path_len, None);
+        assert!(!chain[0].is_ca);
     }
 
     #[test]
@@ -808,8 +1307,12 @@ mod tests {
             not_before: String::new(),
             not_after: String::new(),
             is_ca: false,
+            path_len: None,
+            key_usage: None,
             signature_algorithm: String::new(),
             signature: Vec::new(),
+            serial_number: Vec::new(),
+            fingerprint: [0; 32],
         };
         let intermediate = X509Certificate {
             tbs_der: Vec::new(),
@@ -820,10 +1323,52 @@ mod tests {
             not_before: String::new(),
             not_after: String::new(),
             is_ca: true,
+            path_len: None,
+            key_usage: None,
+            signature_algorithm: String::new(),
+            signature: Vec::new(),
+            serial_number: Vec::new(),
+            fingerprint: [0; 32],
+        };
+        assert!(verify_chain(&[leaf, intermediate], "example.com").is_err());
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(parse_time("700101000000Z"), Some(0));
+        assert_eq!(parse_time("20000301000000Z"), Some(951_868_800));
+        assert_eq!(parse_time("251231235959Z"), Some(1_767_225_599));
+        assert_eq!(parse_time("2512312359Z"), None);
+        assert_eq!(format_time("251231235959Z"), "2025-12-31 23:59:59 UTC");
+    }
+
+    #[test]
+    fn test_check_validity() {
+        let cert = X509Certificate {
+            tbs_der: Vec::new(),
+            issuer: "CA".to_string(),
+            subject: "example.com".to_string(),
+            spki: Vec::new(),
+            san_dns: Vec::new(),
+            not_before: "230101000000Z".to_string(),
+            not_after: "251231235959Z".to_string(),
+            is_ca: false,
+            path_len: None,
+            key_usage: None,
             signature_algorithm: String::new(),
             signature: Vec::new(),
+            serial_number: Vec::new(),
+            fingerprint: [0; 32],
         };
-        assert!(verify_chain(&[leaf, intermediate], "example.com", &[]).is_err());
+        let chain = [cert];
+        assert!(check_validity(&chain, 1_700_000_000).is_ok());
+        let expired = check_validity(&chain, 1_800_000_000).unwrap_err();
+        assert_eq!(expired.code(), "ERR_CERT_DATE_INVALID");
+        assert!(expired.to_string().contains("2025-12-31"));
+        assert!(matches!(
+            check_validity(&chain, 1_600_000_000),
+            Err(CertificateError::NotYetValid { .. })
+        ));
     }
 
     // Build a minimal self-signed DER cert for testing the parser
@@ -887,6 +1432,9 @@ mod tests {
         assert_eq!(cert.not_after, "251231235959Z");
         assert!(!cert.spki.is_empty());
         assert_eq!(cert.signature, &[0xDE, 0xAD]);
+        assert_eq!(cert.serial_number, &[0x01]);
+        assert_eq!(cert.fingerprint, crypto::sha256::sha256(&cert_der));
+        assert!(cert.is_self_issued());
     }
 
     // Helper: build a DER TLV
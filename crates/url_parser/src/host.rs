@@ -0,0 +1,384 @@
+//! Host parsing and serialization (URL Standard §3).
+//!
+//! Domains go through IDNA, IPv4 addresses accept the legacy number forms
+//! (`0x7f.1`, `2130706433`), and IPv6 addresses are serialized in their
+//! canonical compressed form.
+
+use core::fmt;
+
+use crate::{UrlError, idna, in_c0_control_set, percent_decode_bytes, utf8_percent_encode};
+
+/// A parsed host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Host {
+    /// An ASCII domain, after IDNA processing.
+    Domain(String),
+    Ipv4(u32),
+    Ipv6([u16; 8]),
+    /// A host of a non-special URL, kept percent-encoded as written.
+    Opaque(String),
+    /// The empty host (`file:///`, `foo://`).
+    Empty,
+}
+
+impl fmt::Display for Host {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Domain(s) | Self::Opaque(s) => f.write_str(s),
+            Self::Ipv4(addr) => {
+                let [a, b, c, d] = addr.to_be_bytes();
+                write!(f, "{a}.{b}.{c}.{d}")
+            }
+            Self::Ipv6(pieces) => {
+                f.write_str("[")?;
+                write_ipv6(f, pieces)?;
+                f.write_str("]")
+            }
+            Self::Empty => Ok(()),
+        }
+    }
+}
+
+/// Forbidden host code points: not allowed in any host.
+fn is_forbidden_host(c: char) -> bool {
+    matches!(
+        c,
+        '\0' | '\t'
+            | '\n'
+            | '\r'
+            | ' '
+            | '#'
+            | '/'
+            | ':'
+            | '<'
+            | '>'
+            | '?'
+            | '@'
+            | '['
+            | '\\'
+            | ']'
+            | '^'
+            | '|'
+    )
+}
+
+/// Forbidden domain code points: additionally excluded from domains.
+fn is_forbidden_domain(c: char) -> bool {
+    is_forbidden_host(c) || c.is_ascii_control() || c == '%'
+}
+
+/// The host parser. `is_opaque` is set for non-special URLs.
+pub fn parse_host(input: &str, is_opaque: bool) -> Result<Host, UrlError> {
+    if let Some(rest) = input.strip_prefix('[') {
+        let inner = rest.strip_suffix(']').ok_or(UrlError::InvalidIpv6)?;
+        return parse_ipv6(inner)
+            .map(Host::Ipv6)
+            .ok_or(UrlError::InvalidIpv6);
+    }
+    if is_opaque {
+        if input.chars().any(|c| c != '%' && is_forbidden_host(c)) {
+            return Err(UrlError::InvalidHost);
+        }
+        return Ok(Host::Opaque(utf8_percent_encode(input, in_c0_control_set)));
+    }
+    if input.is_empty() {
+        return Err(UrlError::InvalidHost);
+    }
+    let domain = String::from_utf8_lossy(&percent_decode_bytes(input)).into_owned();
+    let ascii = domain_to_ascii(&domain)?;
+    if ends_in_number(&ascii) {
+        return parse_ipv4(&ascii).map(Host::Ipv4);
+    }
+    Ok(Host::Domain(ascii))
+}
+
+/// Domain to ASCII: IDNA `ToASCII`, skipped for plain ASCII domains with no
+/// `xn--` labels.
+fn domain_to_ascii(domain: &str) -> Result<String, UrlError> {
+    let needs_idna = !domain.is_ascii()
+        || domain
+            .split('.')
+            .any(|l| l.len() >= 4 && l[..4].eq_ignore_ascii_case("xn--"));
+    let ascii = if needs_idna {
+        idna::to_ascii(domain).ok_or(UrlError::InvalidHost)?
+    } else {
+        domain.to_ascii_lowercase()
+    };
+    if ascii.is_empty() || ascii.chars().any(is_forbidden_domain) {
+        return Err(UrlError::InvalidHost);
+    }
+    Ok(ascii)
+}
+
+/// Whether the last label looks numeric, which makes the host an IPv4
+/// address (or invalid).
+fn ends_in_number(domain: &str) -> bool {
+    let mut labels: Vec<&str> = domain.split('.').collect();
+    if labels.last() == Some(&"") {
+        if labels.len() == 1 {
+            return false;
+        }
+        labels.pop();
+    }
+    let last = labels.last().copied().unwrap_or("");
+    if !last.is_empty() && last.bytes().all(|b| b.is_ascii_digit()) {
+        return true;
+    }
+    parse_ipv4_number(last).is_some()
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// IPv4
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Parse a decimal, octal (`0` prefix) or hex (`0x` prefix) IPv4 part.
+/// Values that overflow `u64` saturate; they are out of range anyway.
+fn parse_ipv4_number(input: &str) -> Option<u64> {
+    if input.is_empty() {
+        return None;
+    }
+    let (digits, radix) = if let Some(hex) = input
+        .strip_prefix("0x")
+        .or_else(|| input.strip_prefix("0X"))
+    {
+        (hex, 16)
+    } else if input.len() > 1 && input.starts_with('0') {
+        (&input[1..], 8)
+    } else {
+        (input, 10)
+    };
+    let mut n: u64 = 0;
+    for c in digits.chars() {
+        let d = c.to_digit(radix)? as u64;
+        n = n.saturating_mul(radix as u64).saturating_add(d);
+    }
+    Some(n)
+}
+
+/// The IPv4 parser: one to four dot-separated numbers, the last filling the
+/// remaining bytes.
+fn parse_ipv4(input: &str) -> Result<u32, UrlError> {
+    let mut parts: Vec<&str> = input.split('.').collect();
+    if parts.last() == Some(&"") && parts.len() > 1 {
+        parts.pop();
+    }
+    if parts.len() > 4 {
+        return Err(UrlError::InvalidIpv4);
+    }
+    let numbers = parts
+        .iter()
+        .map(|p| parse_ipv4_number(p))
+        .collect::<Option<Vec<u64>>>()
+        .ok_or(UrlError::InvalidIpv4)?;
+    let (last, init) = numbers.split_last().ok_or(UrlError::InvalidIpv4)?;
+    if init.iter().any(|&n| n > 255) || *last >= 256u64.pow(5 - numbers.len() as u32) {
+        return Err(UrlError::InvalidIpv4);
+    }
+    let mut ipv4 = *last;
+    for (i, &n) in init.iter().enumerate() {
+        ipv4 += n << (8 * (3 - i));
+    }
+    Ok(ipv4 as u32)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// IPv6
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The IPv6 parser, including `::` compression and a trailing dotted IPv4
+/// part.
+fn parse_ipv6(input: &str) -> Option<[u16; 8]> {
+    let c: Vec<char> = input.chars().collect();
+    let mut address = [0u16; 8];
+    let mut piece_index = 0;
+    let mut compress: Option<usize> = None;
+    let mut p = 0;
+
+    if c.first() == Some(&':') {
+        if c.get(1) != Some(&':') {
+            return None;
+        }
+        p += 2;
+        piece_index += 1;
+        compress = Some(piece_index);
+    }
+    while p < c.len() {
+        if piece_index == 8 {
+            return None;
+        }
+        if c[p] == ':' {
+            if compress.is_some() {
+                return None;
+            }
+            p += 1;
+            piece_index += 1;
+            compress = Some(piece_index);
+            continue;
+        }
+        let mut value: u32 = 0;
+        let mut length = 0;
+        while length < 4 && p < c.len() && c[p].is_ascii_hexdigit() {
+            value = value * 0x10 + c[p].to_digit(16)?;
+            p += 1;
+            length += 1;
+        }
+        if p < c.len() && c[p] == '.' {
+            if length == 0 {
+                return None;
+            }
+            p -= length;
+            if piece_index > 6 {
+                return None;
+            }
+            let mut numbers_seen = 0;
+            while p < c.len() {
+                let mut ipv4_piece: Option<u32> = None;
+                if numbers_seen > 0 {
+                    if c[p] == '.' && numbers_seen < 4 {
+                        p += 1;
+                    } else {
+                        return None;
+                    }
+                }
+                if p >= c.len() || !c[p].is_ascii_digit() {
+                    return None;
+                }
+                while p < c.len() && c[p].is_ascii_digit() {
+                    let number = c[p].to_digit(10)?;
+                    ipv4_piece = match ipv4_piece {
+                        None => Some(number),
+                        Some(0) => return None,
+                        Some(v) => Some(v * 10 + number),
+                    };
+                    if ipv4_piece > Some(255) {
+                        return None;
+                    }
+                    p += 1;
+                }
+                address[piece_index] = (address[piece_index] << 8) | ipv4_piece? as u16;
+                numbers_seen += 1;
+                if numbers_seen == 2 || numbers_seen == 4 {
+                    piece_index += 1;
+                }
+            }
+            if numbers_seen != 4 {
+                return None;
+            }
+            break;
+        } else if p < c.len() && c[p] == ':' {
+            p += 1;
+            if p >= c.len() {
+                return None;
+            }
+        } else if p < c.len() {
+            return None;
+        }
+        address[piece_index] = value as u16;
+        piece_index += 1;
+    }
+    match compress {
+        Some(compress) => {
+            let mut swaps = piece_index - compress;
+            piece_index = 7;
+            while piece_index != 0 && swaps > 0 {
+                address.swap(piece_index, compress + swaps - 1);
+                piece_index -= 1;
+                swaps -= 1;
+            }
+        }
+        None if piece_index != 8 => return None,
+        None => {}
+    }
+    Some(address)
+}
+
+/// Serialize an IPv6 address, compressing the first longest run of two or
+/// more zero pieces.
+fn write_ipv6(f: &mut fmt::Formatter<'_>, pieces: &[u16; 8]) -> fmt::Result {
+    let mut compress: Option<(usize, usize)> = None;
+    let mut i = 0;
+    while i < 8 {
+        if pieces[i] != 0 {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < 8 && pieces[i] == 0 {
+            i += 1;
+        }
+        if i - start > 1 && compress.is_none_or(|(s, e)| i - start > e - s) {
+            compress = Some((start, i));
+        }
+    }
+    let mut i = 0;
+    while i < 8 {
+        if let Some((start, end)) = compress
+            && i == start
+        {
+            f.write_str(if i == 0 { "::" } else { ":" })?;
+            i = end;
+            continue;
+        }
+        write!(f, "{:x}", pieces[i])?;
+        if i < 7 {
+            f.write_str(":")?;
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn host(input: &str) -> Result<String, UrlError> {
+        parse_host(input, false).map(|h| h.to_string())
+    }
+
+    #[test]
+    fn test_ipv4_number_forms() {
+        assert_eq!(host("0x7f.1").as_deref(), Ok("127.0.0.1"));
+        assert_eq!(host("2130706433").as_deref(), Ok("127.0.0.1"));
+        assert_eq!(host("0300.0250.0.01").as_deref(), Ok("192.168.0.1"));
+        assert_eq!(host("192.168.0.1.").as_deref(), Ok("192.168.0.1"));
+        assert_eq!(host("256.0.0.1"), Err(UrlError::InvalidIpv4));
+        assert_eq!(host("1.2.3.4.5"), Err(UrlError::InvalidIpv4));
+        assert_eq!(host("foo.09"), Err(UrlError::InvalidIpv4));
+        assert_eq!(host("foo.0x"), Err(UrlError::InvalidIpv4));
+    }
+
+    #[test]
+    fn test_ipv6() {
+        assert_eq!(host("[::1]").as_deref(), Ok("[::1]"));
+        assert_eq!(host("[0:0:0:0:0:0:0:1]").as_deref(), Ok("[::1]"));
+        assert_eq!(
+            host("[2001:DB8:0:0:1:0:0:1]").as_deref(),
+            Ok("[2001:db8::1:0:0:1]")
+        );
+        assert_eq!(
+            host("[::ffff:192.168.0.1]").as_deref(),
+            Ok("[::ffff:c0a8:1]")
+        );
+        assert_eq!(
+            host("[1:2:3:4:5:6:7:8]").as_deref(),
+            Ok("[1:2:3:4:5:6:7:8]")
+        );
+        assert_eq!(host("[1::2::3]"), Err(UrlError::InvalidIpv6));
+        assert_eq!(host("[::1"), Err(UrlError::InvalidIpv6));
+        assert_eq!(host("[::1.2.3]"), Err(UrlError::InvalidIpv6));
+    }
+
+    #[test]
+    fn test_domains() {
+        assert_eq!(host("EXAMPLE.com").as_deref(), Ok("example.com"));
+        assert_eq!(host("b%C3%BCcher.de").as_deref(), Ok("xn--bcher-kva.de"));
+        assert_eq!(host("exa mple.com"), Err(UrlError::InvalidHost));
+        assert_eq!(host("a%25b"), Err(UrlError::InvalidHost));
+        assert_eq!(
+            parse_host("ex%41mple", true),
+            Ok(Host::Opaque("ex%41mple".into()))
+        );
+        assert_eq!(parse_host("a b", true), Err(UrlError::InvalidHost));
+    }
+}
@@ -0,0 +1,553 @@
+//! # IDNA
+//!
+//! UTS #46 processing and Punycode (RFC 3492), configured the way the URL
+//! Standard's *domain to ASCII* uses them: non-transitional processing,
+//! `CheckBidi` and `CheckJoiners` on, `CheckHyphens`, `UseSTD3ASCIIRules` and
+//! `VerifyDnsLength` off.
+
+mod tables;
+
+use tables::{
+    BIDI_CLASS, COMBINING_CLASS, COMPOSITION, DECOMPOSITION, JOINING_TYPE, MAPPING, MARK,
+};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Table types
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// UTS #46 status of a code point range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mapping {
+    Valid,
+    Ignored,
+    Disallowed,
+    /// Every code point in the range maps to this string.
+    Mapped(&'static str),
+    /// The range maps one-to-one onto a range starting at this code point.
+    Shifted(u32),
+}
+
+/// The bidi classes RFC 5893 distinguishes; everything else is `Other`.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BidiClass {
+    L,
+    R,
+    AL,
+    AN,
+    EN,
+    ES,
+    CS,
+    ET,
+    ON,
+    BN,
+    NSM,
+    Other,
+}
+
+/// Joining types relevant to the ContextJ rule for ZERO WIDTH NON-JOINER.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum JoiningType {
+    D,
+    L,
+    R,
+    T,
+}
+
+fn mapping(c: char) -> (u32, Mapping) {
+    let cp = c as u32;
+    let i = MAPPING.partition_point(|&(start, _)| start <= cp) - 1;
+    MAPPING[i]
+}
+
+fn in_ranges<T: Copy>(table: &[(u32, u32, T)], cp: u32) -> Option<T> {
+    let i = table.partition_point(|&(_, last, _)| last < cp);
+    table
+        .get(i)
+        .filter(|&&(first, _, _)| first <= cp)
+        .map(|&(_, _, v)| v)
+}
+
+fn combining_class(c: char) -> u8 {
+    in_ranges(&COMBINING_CLASS, c as u32).unwrap_or(0)
+}
+
+fn bidi_class(c: char) -> BidiClass {
+    in_ranges(&BIDI_CLASS, c as u32).unwrap_or(BidiClass::L)
+}
+
+fn joining_type(c: char) -> Option<JoiningType> {
+    in_ranges(&JOINING_TYPE, c as u32)
+}
+
+fn is_mark(c: char) -> bool {
+    let cp = c as u32;
+    let i = MARK.partition_point(|&(_, last)| last < cp);
+    MARK.get(i).is_some_and(|&(first, _)| first <= cp)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Domain to ASCII / Unicode
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// UTS #46 `ToASCII`. Returns `None` if processing records an error.
+///
+/// Labels that are not ASCII after mapping and normalization are
+/// Punycode-encoded with an `xn--` prefix. Labels are not length-checked.
+pub fn to_ascii(domain: &str) -> Option<String> {
+    let labels = process(domain)?;
+    let mut out = String::with_capacity(domain.len());
+    for (i, label) in labels.iter().enumerate() {
+        if i > 0 {
+            out.push('.');
+        }
+        if label.is_ascii() {
+            out.push_str(label);
+        } else {
+            out.push_str("xn--");
+            out.push_str(&punycode_encode(label)?);
+        }
+    }
+    Some(out)
+}
+
+/// UTS #46 `ToUnicode`: map, normalize and decode `xn--` labels. Returns
+/// `None` if processing records an error.
+pub fn to_unicode(domain: &str) -> Option<String> {
+    Some(process(domain)?.join("."))
+}
+
+/// UTS #46 Processing (§4): map, normalize, break into labels, convert and
+/// validate. Returns the Unicode labels, or `None` on any error.
+fn process(domain: &str) -> Option<Vec<String>> {
+    let mut ok = true;
+    let mut mapped = String::with_capacity(domain.len());
+    for c in domain.chars() {
+        match mapping(c) {
+            (_, Mapping::Valid) => mapped.push(c),
+            (_, Mapping::Ignored) => {}
+            (_, Mapping::Disallowed) => {
+                ok = false;
+                mapped.push(c);
+            }
+            (_, Mapping::Mapped(s)) => mapped.push_str(s),
+            (start, Mapping::Shifted(base)) => {
+                mapped.extend(char::from_u32(base + (c as u32 - start)));
+            }
+        }
+    }
+    let normalized = nfc(&mapped);
+
+    let mut labels = Vec::new();
+    for label in normalized.split('.') {
+        if let Some(encoded) = label.strip_prefix("xn--") {
+            // Already lowercase: mapping folded ASCII case.
+            let decoded = label.is_ascii().then(|| punycode_decode(encoded)).flatten();
+            match decoded {
+                Some(d) if !d.is_empty() && !d.is_ascii() => {
+                    ok &= is_valid_label(&d, true);
+                    labels.push(d);
+                }
+                _ => {
+                    ok = false;
+                    labels.push(label.to_string());
+                }
+            }
+        } else {
+            ok &= is_valid_label(label, false);
+            labels.push(label.to_string());
+        }
+    }
+
+    let bidi_domain = labels.iter().any(|l| {
+        l.chars()
+            .any(|c| matches!(bidi_class(c), BidiClass::R | BidiClass::AL | BidiClass::AN))
+    });
+    if bidi_domain {
+        ok &= labels
+            .iter()
+            .all(|l| l.is_empty() || satisfies_bidi_rule(l));
+    }
+    ok.then_some(labels)
+}
+
+/// UTS #46 §4.1 Validity Criteria (with `CheckHyphens=false`).
+fn is_valid_label(label: &str, from_punycode: bool) -> bool {
+    if label.is_empty() {
+        return true;
+    }
+    if from_punycode && nfc(label) != label {
+        return false;
+    }
+    if label.starts_with("xn--") || label.contains('.') {
+        return false;
+    }
+    if label.chars().next().is_some_and(is_mark) {
+        return false;
+    }
+    // Mapped labels only contain valid code points by construction, but
+    // decoded Punycode can contain anything.
+    if from_punycode && !label.chars().all(|c| mapping(c).1 == Mapping::Valid) {
+        return false;
+    }
+    satisfies_context_j(label)
+}
+
+/// RFC 5892 Appendix A.1 and A.2: ZERO WIDTH (NON-)JOINER is only allowed
+/// after a virama, or (for ZWNJ) between joining letters.
+fn satisfies_context_j(label: &str) -> bool {
+    const VIRAMA: u8 = 9;
+    let chars: Vec<char> = label.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        if c != '\u{200C}' && c != '\u{200D}' {
+            continue;
+        }
+        if i > 0 && combining_class(chars[i - 1]) == VIRAMA {
+            continue;
+        }
+        if c == '\u{200D}' {
+            return false;
+        }
+        let before = chars[..i].iter().rev();
+        let after = chars[i + 1..].iter();
+        if !joins_towards(before, JoiningType::L) || !joins_towards(after, JoiningType::R) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Whether the first non-transparent character of `chars` has joining type
+/// `side` or D.
+fn joins_towards<'a>(chars: impl Iterator<Item = &'a char>, side: JoiningType) -> bool {
+    chars
+        .map(|&c| joining_type(c))
+        .find(|&t| t != Some(JoiningType::T))
+        .flatten()
+        .is_some_and(|t| t == side || t == JoiningType::D)
+}
+
+/// RFC 5893 §2, the Bidi Rule.
+fn satisfies_bidi_rule(label: &str) -> bool {
+    use BidiClass::*;
+    let classes: Vec<BidiClass> = label.chars().map(bidi_class).collect();
+    let last = classes.iter().rev().find(|&&c| c != NSM).copied();
+    match classes[0] {
+        R | AL => {
+            let allowed = |c| matches!(c, R | AL | AN | EN | ES | CS | ET | ON | BN | NSM);
+            classes.iter().all(|&c| allowed(c))
+                && matches!(last, Some(R | AL | EN | AN))
+                && !(classes.contains(&EN) && classes.contains(&AN))
+        }
+        L => {
+            let allowed = |c| matches!(c, L | EN | ES | CS | ET | ON | BN | NSM);
+            classes.iter().all(|&c| allowed(c)) && matches!(last, Some(L | EN))
+        }
+        _ => false,
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Normalization (NFC)
+// ─────────────────────────────────────────────────────────────────────────────
+
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+fn decompose(c: char, out: &mut Vec<char>) {
+    let cp = c as u32;
+    if (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&cp) {
+        let s = cp - HANGUL_S_BASE;
+        out.extend(char::from_u32(HANGUL_L_BASE + s / HANGUL_N_COUNT));
+        out.extend(char::from_u32(
+            HANGUL_V_BASE + (s % HANGUL_N_COUNT) / HANGUL_T_COUNT,
+        ));
+        if !s.is_multiple_of(HANGUL_T_COUNT) {
+            out.extend(char::from_u32(HANGUL_T_BASE + s % HANGUL_T_COUNT));
+        }
+        return;
+    }
+    match DECOMPOSITION.binary_search_by_key(&cp, |&(c, _, _)| c) {
+        Ok(i) => {
+            let (_, first, second) = DECOMPOSITION[i];
+            decompose(char::from_u32(first).unwrap_or(c), out);
+            if second != 0 {
+                decompose(char::from_u32(second).unwrap_or(c), out);
+            }
+        }
+        Err(_) => out.push(c),
+    }
+}
+
+fn compose_pair(a: char, b: char) -> Option<char> {
+    let (a, b) = (a as u32, b as u32);
+    if (HANGUL_L_BASE..HANGUL_L_BASE + HANGUL_L_COUNT).contains(&a)
+        && (HANGUL_V_BASE..HANGUL_V_BASE + HANGUL_V_COUNT).contains(&b)
+    {
+        let lv = (a - HANGUL_L_BASE) * HANGUL_N_COUNT + (b - HANGUL_V_BASE) * HANGUL_T_COUNT;
+        return char::from_u32(HANGUL_S_BASE + lv);
+    }
+    if (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&a)
+        && (a - HANGUL_S_BASE).is_multiple_of(HANGUL_T_COUNT)
+        && (HANGUL_T_BASE + 1..HANGUL_T_BASE + HANGUL_T_COUNT).contains(&b)
+    {
+        return char::from_u32(a + (b - HANGUL_T_BASE));
+    }
+    COMPOSITION
+        .binary_search_by_key(&(a, b), |&(x, y, _)| (x, y))
+        .ok()
+        .and_then(|i| char::from_u32(COMPOSITION[i].2))
+}
+
+/// Unicode Normalization Form C.
+pub(crate) fn nfc(s: &str) -> String {
+    if s.is_ascii() {
+        return s.to_string();
+    }
+    let mut chars = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        decompose(c, &mut chars);
+    }
+    // Canonical ordering: stable-sort each run of non-starters.
+    let mut i = 0;
+    while i < chars.len() {
+        if combining_class(chars[i]) == 0 {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < chars.len() && combining_class(chars[i]) != 0 {
+            i += 1;
+        }
+        chars[start..i].sort_by_key(|&c| combining_class(c));
+    }
+    // Canonical composition.
+    let mut out: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter: Option<usize> = None;
+    let mut last_class = 0u8;
+    for c in chars {
+        let class = combining_class(c);
+        if let Some(si) = starter {
+            // Blocked unless nothing sits between the starter and `c`, or
+            // everything that does has a lower combining class.
+            let adjacent = out.len() - 1 == si;
+            if (adjacent || last_class < class)
+                && let Some(composed) = compose_pair(out[si], c)
+            {
+                out[si] = composed;
+                continue;
+            }
+        }
+        if class == 0 {
+            starter = Some(out.len());
+        }
+        last_class = class;
+        out.push(c);
+    }
+    out.into_iter().collect()
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Punycode (RFC 3492)
+// ─────────────────────────────────────────────────────────────────────────────
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+
+fn adapt(mut delta: u32, num_points: u32, first_time: bool) -> u32 {
+    delta /= if first_time { DAMP } else { 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (((BASE - T_MIN + 1) * delta) / (delta + SKEW))
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        T_MIN
+    } else if k >= bias + T_MAX {
+        T_MAX
+    } else {
+        k - bias
+    }
+}
+
+fn encode_digit(d: u32) -> char {
+    (if d < 26 {
+        b'a' + d as u8
+    } else {
+        b'0' + (d - 26) as u8
+    }) as char
+}
+
+fn decode_digit(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32),
+        'A'..='Z' => Some(c as u32 - 'A' as u32),
+        '0'..='9' => Some(c as u32 - '0' as u32 + 26),
+        _ => None,
+    }
+}
+
+/// Decode a Punycode string (without the `xn--` prefix).
+pub fn punycode_decode(input: &str) -> Option<String> {
+    let (basic, extended) = match input.rfind('-') {
+        Some(i) => (&input[..i], &input[i + 1..]),
+        None => ("", input),
+    };
+    if !basic.is_ascii() {
+        return None;
+    }
+    let mut output: Vec<char> = basic.chars().collect();
+    let (mut n, mut i, mut bias) = (INITIAL_N, 0u32, INITIAL_BIAS);
+    let mut digits = extended.chars().peekable();
+    while digits.peek().is_some() {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = BASE;
+        loop {
+            let digit = decode_digit(digits.next()?)?;
+            i = i.checked_add(digit.checked_mul(w)?)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(BASE - t)?;
+            k += BASE;
+        }
+        let len = output.len() as u32 + 1;
+        bias = adapt(i - old_i, len, old_i == 0);
+        n = n.checked_add(i / len)?;
+        i %= len;
+        output.insert(i as usize, char::from_u32(n)?);
+        i += 1;
+    }
+    Some(output.into_iter().collect())
+}
+
+/// Encode a string as Punycode (without the `xn--` prefix).
+pub fn punycode_encode(input: &str) -> Option<String> {
+    let chars: Vec<u32> = input.chars().map(|c| c as u32).collect();
+    let mut output: String = input.chars().filter(char::is_ascii).collect();
+    let basic_len = output.len() as u32;
+    let mut handled = basic_len;
+    if basic_len > 0 {
+        output.push('-');
+    }
+    let (mut n, mut delta, mut bias) = (INITIAL_N, 0u32, INITIAL_BIAS);
+    while (handled as usize) < chars.len() {
+        let m = chars.iter().copied().filter(|&c| c >= n).min()?;
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+        for &c in &chars {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q));
+                bias = adapt(delta, handled + 1, handled == basic_len);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punycode_round_trip() {
+        assert_eq!(punycode_encode("bücher").as_deref(), Some("bcher-kva"));
+        assert_eq!(punycode_decode("bcher-kva").as_deref(), Some("bücher"));
+        assert_eq!(punycode_encode("ü").as_deref(), Some("tda"));
+        assert_eq!(
+            punycode_encode("他们为什么不说中文").as_deref(),
+            Some("ihqwcrb4cv8a8dqg056pqjye")
+        );
+        assert_eq!(
+            punycode_decode("ihqwcrb4cv8a8dqg056pqjye").as_deref(),
+            Some("他们为什么不说中文")
+        );
+        assert_eq!(punycode_decode("9999999999a"), None);
+    }
+
+    #[test]
+    fn test_nfc() {
+        assert_eq!(nfc("e\u{301}"), "é");
+        assert_eq!(nfc("\u{1100}\u{1161}\u{11A8}"), "각");
+        // Reordering puts the cedilla (class 202) before the acute (230).
+        assert_eq!(nfc("c\u{301}\u{327}"), "\u{1E09}");
+        // Singletons and excluded composites decompose and stay decomposed.
+        assert_eq!(nfc("\u{212B}"), "Å");
+        assert_eq!(nfc("\u{0958}"), "\u{915}\u{93C}");
+    }
+
+    #[test]
+    fn test_to_ascii() {
+        assert_eq!(
+            to_ascii("Bücher.example").as_deref(),
+            Some("xn--bcher-kva.example")
+        );
+        assert_eq!(
+            to_ascii("ＥＸＡＭＰＬＥ．com").as_deref(),
+            Some("example.com")
+        );
+        assert_eq!(to_ascii("faß.de").as_deref(), Some("xn--fa-hia.de"));
+        assert_eq!(to_ascii("a\u{AD}b").as_deref(), Some("ab"));
+        assert_eq!(to_ascii("xn--bcher-kva").as_deref(), Some("xn--bcher-kva"));
+        // Invalid Punycode, ASCII-only Punycode, and disallowed code points.
+        assert_eq!(to_ascii("xn--a.com"), None);
+        assert_eq!(to_ascii("xn--ab-.com"), None);
+        assert_eq!(to_ascii("a\u{FFFD}b"), None);
+    }
+
+    #[test]
+    fn test_context_and_bidi_rules() {
+        // ZWJ needs a preceding virama.
+        assert_eq!(to_ascii("a\u{200D}b"), None);
+        assert!(to_ascii("\u{915}\u{94D}\u{200D}").is_some());
+        // ZWNJ is also allowed between joining Arabic letters.
+        assert!(to_ascii("\u{628}\u{200C}\u{628}").is_some());
+        // A label starting with a combining mark is invalid.
+        assert_eq!(to_ascii("\u{301}a"), None);
+        // Hebrew label followed by an LTR label ending in a digit is fine,
+        // mixing directions inside a label is not.
+        assert!(to_ascii("\u{5D0}\u{5D1}.a1").is_some());
+        assert_eq!(to_ascii("\u{5D0}a"), None);
+        assert_eq!(
+            to_unicode("xn--bcher-kva.example").as_deref(),
+            Some("bücher.example")
+        );
+    }
+}
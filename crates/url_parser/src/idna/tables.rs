@@ -0,0 +1,3998 @@
+//! Unicode data for IDNA processing (UTS #46).
+//!
+//! The mapping table is the non-transitional UTS #46 IDNA Mapping Table
+//! with `UseSTD3ASCIIRules=false` folded in: deviations and
+//! `disallowed_STD3_valid` code points are valid, `disallowed_STD3_mapped`
+//! ones are mapped. The normalization, bidi, mark and joining-type tables
+//! come from the Unicode Character Database.
+//!
+//! Generated from the Unicode data files; do not edit by hand.
+
+use super::Mapping::{Disallowed, Ignored, Mapped, Shifted, Valid};
+use super::{BidiClass, JoiningType, Mapping};
+
+/// `(first code point, status)`, sorted; each entry runs until the next.
+#[rustfmt::skip]
+pub(super) static MAPPING: [(u32, Mapping); 5874] = [
+    (0x0000, Valid), (0x0041, Shifted(0x0061)), (0x005B, Valid), (0x0080, Disallowed),
+    (0x00A0, Mapped(" ")), (0x00A1, Valid), (0x00A8, Mapped(" \u{308}")), (0x00A9, Valid),
+    (0x00AA, Mapped("a")), (0x00AB, Valid), (0x00AD, Ignored), (0x00AE, Valid),
+    (0x00AF, Mapped(" \u{304}")), (0x00B0, Valid), (0x00B2, Shifted(0x0032)),
+    (0x00B4, Mapped(" \u{301}")), (0x00B5, Mapped("\u{3BC}")), (0x00B6, Valid),
+    (0x00B8, Mapped(" \u{327}")), (0x00B9, Mapped("1")), (0x00BA, Mapped("o")), (0x00BB, Valid),
+    (0x00BC, Mapped("1\u{2044}4")), (0x00BD, Mapped("1\u{2044}2")), (0x00BE, Mapped("3\u{2044}4")),
+    (0x00BF, Valid), (0x00C0, Shifted(0x00E0)), (0x00D7, Valid), (0x00D8, Shifted(0x00F8)),
+    (0x00DF, Valid), (0x0100, Mapped("\u{101}")), (0x0101, Valid), (0x0102, Mapped("\u{103}")),
+    (0x0103, Valid), (0x0104, Mapped("\u{105}")), (0x0105, Valid), (0x0106, Mapped("\u{107}")),
+    (0x0107, Valid), (0x0108, Mapped("\u{109}")), (0x0109, Valid), (0x010A, Mapped("\u{10B}")),
+    (0x010B, Valid), (0x010C, Mapped("\u{10D}")), (0x010D, Valid), (0x010E, Mapped("\u{10F}")),
+    (0x010F, Valid), (0x0110, Mapped("\u{111}")), (0x0111, Valid), (0x0112, Mapped("\u{113}")),
+    (0x0113, Valid), (0x0114, Mapped("\u{115}")), (0x0115, Valid), (0x0116, Mapped("\u{117}")),
+    (0x0117, Valid), (0x0118, Mapped("\u{119}")), (0x0119, Valid), (0x011A, Mapped("\u{11B}")),
+    (0x011B, Valid), (0x011C, Mapped("\u{11D}")), (0x011D, Valid), (0x011E, Mapped("\u{11F}")),
+    (0x011F, Valid), (0x0120, Mapped("\u{121}")), (0x0121, Valid), (0x0122, Mapped("\u{123}")),
+    (0x0123, Valid), (0x0124, Mapped("\u{125}")), (0x0125, Valid), (0x0126, Mapped("\u{127}")),
+    (0x0127, Valid), (0x0128, Mapped("\u{129}")), (0x0129, Valid), (0x012A, Mapped("\u{12B}")),
+    (0x012B, Valid), (0x012C, Mapped("\u{12D}")), (0x012D, Valid), (0x012E, Mapped("\u{12F}")),
+    (0x012F, Valid), (0x0130, Mapped("i\u{307}")), (0x0131, Valid), (0x0132, Mapped("ij")),
+    (0x0134, Mapped("\u{135}")), (0x0135, Valid), (0x0136, Mapped("\u{137}")), (0x0137, Valid),
+    (0x0139, Mapped("\u{13A}")), (0x013A, Valid), (0x013B, Mapped("\u{13C}")), (0x013C, Valid),
+    (0x013D, Mapped("\u{13E}")), (0x013E, Valid), (0x013F, Mapped("l\u{B7}")),
+    (0x0141, Mapped("\u{142}")), (0x0142, Valid), (0x0143, Mapped("\u{144}")), (0x0144, Valid),
+    (0x0145, Mapped("\u{146}")), (0x0146, Valid), (0x0147, Mapped("\u{148}")), (0x0148, Valid),
+    (0x0149, Mapped("\u{2BC}n")), (0x014A, Mapped("\u{14B}")), (0x014B, Valid),
+    (0x014C, Mapped("\u{14D}")), (0x014D, Valid), (0x014E, Mapped("\u{14F}")), (0x014F, Valid),
+    (0x0150, Mapped("\u{151}")), (0x0151, Valid), (0x0152, Mapped("\u{153}")), (0x0153, Valid),
+    (0x0154, Mapped("\u{155}")), (0x0155, Valid), (0x0156, Mapped("\u{157}")), (0x0157, Valid),
+    (0x0158, Mapped("\u{159}")), (0x0159, Valid), (0x015A, Mapped("\u{15B}")), (0x015B, Valid),
+    (0x015C, Mapped("\u{15D}")), (0x015D, Valid), (0x015E, Mapped("\u{15F}")), (0x015F, Valid),
+    (0x0160, Mapped("\u{161}")), (0x0161, Valid), (0x0162, Mapped("\u{163}")), (0x0163, Valid),
+    (0x0164, Mapped("\u{165}")), (0x0165, Valid), (0x0166, Mapped("\u{167}")), (0x0167, Valid),
+    (0x0168, Mapped("\u{169}")), (0x0169, Valid), (0x016A, Mapped("\u{16B}")), (0x016B, Valid),
+    (0x016C, Mapped("\u{16D}")), (0x016D, Valid), (0x016E, Mapped("\u{16F}")), (0x016F, Valid),
+    (0x0170, Mapped("\u{171}")), (0x0171, Valid), (0x0172, Mapped("\u{173}")), (0x0173, Valid),
+    (0x0174, Mapped("\u{175}")), (0x0175, Valid), (0x0176, Mapped("\u{177}")), (0x0177, Valid),
+    (0x0178, Mapped("\u{FF}")), (0x0179, Mapped("\u{17A}")), (0x017A, Valid),
+    (0x017B, Mapped("\u{17C}")), (0x017C, Valid), (0x017D, Mapped("\u{17E}")), (0x017E, Valid),
+    (0x017F, Mapped("s")), (0x0180, Valid), (0x0181, Mapped("\u{253}")),
+    (0x0182, Mapped("\u{183}")), (0x0183, Valid), (0x0184, Mapped("\u{185}")), (0x0185, Valid),
+    (0x0186, Mapped("\u{254}")), (0x0187, Mapped("\u{188}")), (0x0188, Valid),
+    (0x0189, Shifted(0x0256)), (0x018B, Mapped("\u{18C}")), (0x018C, Valid),
+    (0x018E, Mapped("\u{1DD}")), (0x018F, Mapped("\u{259}")), (0x0190, Mapped("\u{25B}")),
+    (0x0191, Mapped("\u{192}")), (0x0192, Valid), (0x0193, Mapped("\u{260}")),
+    (0x0194, Mapped("\u{263}")), (0x0195, Valid), (0x0196, Mapped("\u{269}")),
+    (0x0197, Mapped("\u{268}")), (0x0198, Mapped("\u{199}")), (0x0199, Valid),
+    (0x019C, Mapped("\u{26F}")), (0x019D, Mapped("\u{272}")), (0x019E, Valid),
+    (0x019F, Mapped("\u{275}")), (0x01A0, Mapped("\u{1A1}")), (0x01A1, Valid),
+    (0x01A2, Mapped("\u{1A3}")), (0x01A3, Valid), (0x01A4, Mapped("\u{1A5}")), (0x01A5, Valid),
+    (0x01A6, Mapped("\u{280}")), (0x01A7, Mapped("\u{1A8}")), (0x01A8, Valid),
+    (0x01A9, Mapped("\u{283}")), (0x01AA, Valid), (0x01AC, Mapped("\u{1AD}")), (0x01AD, Valid),
+    (0x01AE, Mapped("\u{288}")), (0x01AF, Mapped("\u{1B0}")), (0x01B0, Valid),
+    (0x01B1, Shifted(0x028A)), (0x01B3, Mapped("\u{1B4}")), (0x01B4, Valid),
+    (0x01B5, Mapped("\u{1B6}")), (0x01B6, Valid), (0x01B7, Mapped("\u{292}")),
+    (0x01B8, Mapped("\u{1B9}")), (0x01B9, Valid), (0x01BC, Mapped("\u{1BD}")), (0x01BD, Valid),
+    (0x01C4, Mapped("d\u{17E}")), (0x01C7, Mapped("lj")), (0x01CA, Mapped("nj")),
+    (0x01CD, Mapped("\u{1CE}")), (0x01CE, Valid), (0x01CF, Mapped("\u{1D0}")), (0x01D0, Valid),
+    (0x01D1, Mapped("\u{1D2}")), (0x01D2, Valid), (0x01D3, Mapped("\u{1D4}")), (0x01D4, Valid),
+    (0x01D5, Mapped("\u{1D6}")), (0x01D6, Valid), (0x01D7, Mapped("\u{1D8}")), (0x01D8, Valid),
+    (0x01D9, Mapped("\u{1DA}")), (0x01DA, Valid), (0x01DB, Mapped("\u{1DC}")), (0x01DC, Valid),
+    (0x01DE, Mapped("\u{1DF}")), (0x01DF, Valid), (0x01E0, Mapped("\u{1E1}")), (0x01E1, Valid),
+    (0x01E2, Mapped("\u{1E3}")), (0x01E3, Valid), (0x01E4, Mapped("\u{1E5}")), (0x01E5, Valid),
+    (0x01E6, Mapped("\u{1E7}")), (0x01E7, Valid), (0x01E8, Mapped("\u{1E9}")), (0x01E9, Valid),
+    (0x01EA, Mapped("\u{1EB}")), (0x01EB, Valid), (0x01EC, Mapped("\u{1ED}")), (0x01ED, Valid),
+    (0x01EE, Mapped("\u{1EF}")), (0x01EF, Valid), (0x01F1, Mapped("dz")),
+    (0x01F4, Mapped("\u{1F5}")), (0x01F5, Valid), (0x01F6, Mapped("\u{195}")),
+    (0x01F7, Mapped("\u{1BF}")), (0x01F8, Mapped("\u{1F9}")), (0x01F9, Valid),
+    (0x01FA, Mapped("\u{1FB}")), (0x01FB, Valid), (0x01FC, Mapped("\u{1FD}")), (0x01FD, Valid),
+    (0x01FE, Mapped("\u{1FF}")), (0x01FF, Valid), (0x0200, Mapped("\u{201}")), (0x0201, Valid),
+    (0x0202, Mapped("\u{203}")), (0x0203, Valid), (0x0204, Mapped("\u{205}")), (0x0205, Valid),
+    (0x0206, Mapped("\u{207}")), (0x0207, Valid), (0x0208, Mapped("\u{209}")), (0x0209, Valid),
+    (0x020A, Mapped("\u{20B}")), (0x020B, Valid), (0x020C, Mapped("\u{20D}")), (0x020D, Valid),
+    (0x020E, Mapped("\u{20F}")), (0x020F, Valid), (0x0210, Mapped("\u{211}")), (0x0211, Valid),
+    (0x0212, Mapped("\u{213}")), (0x0213, Valid), (0x0214, Mapped("\u{215}")), (0x0215, Valid),
+    (0x0216, Mapped("\u{217}")), (0x0217, Valid), (0x0218, Mapped("\u{219}")), (0x0219, Valid),
+    (0x021A, Mapped("\u{21B}")), (0x021B, Valid), (0x021C, Mapped("\u{21D}")), (0x021D, Valid),
+    (0x021E, Mapped("\u{21F}")), (0x021F, Valid), (0x0220, Mapped("\u{19E}")), (0x0221, Valid),
+    (0x0222, Mapped("\u{223}")), (0x0223, Valid), (0x0224, Mapped("\u{225}")), (0x0225, Valid),
+    (0x0226, Mapped("\u{227}")), (0x0227, Valid), (0x0228, Mapped("\u{229}")), (0x0229, Valid),
+    (0x022A, Mapped("\u{22B}")), (0x022B, Valid), (0x022C, Mapped("\u{22D}")), (0x022D, Valid),
+    (0x022E, Mapped("\u{22F}")), (0x022F, Valid), (0x0230, Mapped("\u{231}")), (0x0231, Valid),
+    (0x0232, Mapped("\u{233}")), (0x0233, Valid), (0x023A, Mapped("\u{2C65}")),
+    (0x023B, Mapped("\u{23C}")), (0x023C, Valid), (0x023D, Mapped("\u{19A}")),
+    (0x023E, Mapped("\u{2C66}")), (0x023F, Valid), (0x0241, Mapped("\u{242}")), (0x0242, Valid),
+    (0x0243, Mapped("\u{180}")), (0x0244, Mapped("\u{289}")), (0x0245, Mapped("\u{28C}")),
+    (0x0246, Mapped("\u{247}")), (0x0247, Valid), (0x0248, Mapped("\u{249}")), (0x0249, Valid),
+    (0x024A, Mapped("\u{24B}")), (0x024B, Valid), (0x024C, Mapped("\u{24D}")), (0x024D, Valid),
+    (0x024E, Mapped("\u{24F}")), (0x024F, Valid), (0x02B0, Mapped("h")),
+    (0x02B1, Mapped("\u{266}")), (0x02B2, Mapped("j")), (0x02B3, Mapped("r")),
+    (0x02B4, Mapped("\u{279}")), (0x02B5, Mapped("\u{27B}")), (0x02B6, Mapped("\u{281}")),
+    (0x02B7, Mapped("w")), (0x02B8, Mapped("y")), (0x02B9, Valid), (0x02D8, Mapped(" \u{306}")),
+    (0x02D9, Mapped(" \u{307}")), (0x02DA, Mapped(" \u{30A}")), (0x02DB, Mapped(" \u{328}")),
+    (0x02DC, Mapped(" \u{303}")), (0x02DD, Mapped(" \u{30B}")), (0x02DE, Valid),
+    (0x02E0, Mapped("\u{263}")), (0x02E1, Mapped("l")), (0x02E2, Mapped("s")),
+    (0x02E3, Mapped("x")), (0x02E4, Mapped("\u{295}")), (0x02E5, Valid), (0x0340, Shifted(0x0300)),
+    (0x0342, Valid), (0x0343, Mapped("\u{313}")), (0x0344, Mapped("\u{308}\u{301}")),
+    (0x0345, Mapped("\u{3B9}")), (0x0346, Valid), (0x034F, Ignored), (0x0350, Valid),
+    (0x0370, Mapped("\u{371}")), (0x0371, Valid), (0x0372, Mapped("\u{373}")), (0x0373, Valid),
+    (0x0374, Mapped("\u{2B9}")), (0x0375, Valid), (0x0376, Mapped("\u{377}")), (0x0377, Valid),
+    (0x0378, Disallowed), (0x037A, Mapped(" \u{3B9}")), (0x037B, Valid), (0x037E, Mapped(";")),
+    (0x037F, Mapped("\u{3F3}")), (0x0380, Disallowed), (0x0384, Mapped(" \u{301}")),
+    (0x0385, Mapped(" \u{308}\u{301}")), (0x0386, Mapped("\u{3AC}")), (0x0387, Mapped("\u{B7}")),
+    (0x0388, Shifted(0x03AD)), (0x038B, Disallowed), (0x038C, Mapped("\u{3CC}")),
+    (0x038D, Disallowed), (0x038E, Shifted(0x03CD)), (0x0390, Valid), (0x0391, Shifted(0x03B1)),
+    (0x03A2, Disallowed), (0x03A3, Shifted(0x03C3)), (0x03AC, Valid), (0x03CF, Mapped("\u{3D7}")),
+    (0x03D0, Mapped("\u{3B2}")), (0x03D1, Mapped("\u{3B8}")), (0x03D2, Mapped("\u{3C5}")),
+    (0x03D3, Mapped("\u{3CD}")), (0x03D4, Mapped("\u{3CB}")), (0x03D5, Mapped("\u{3C6}")),
+    (0x03D6, Mapped("\u{3C0}")), (0x03D7, Valid), (0x03D8, Mapped("\u{3D9}")), (0x03D9, Valid),
+    (0x03DA, Mapped("\u{3DB}")), (0x03DB, Valid), (0x03DC, Mapped("\u{3DD}")), (0x03DD, Valid),
+    (0x03DE, Mapped("\u{3DF}")), (0x03DF, Valid), (0x03E0, Mapped("\u{3E1}")), (0x03E1, Valid),
+    (0x03E2, Mapped("\u{3E3}")), (0x03E3, Valid), (0x03E4, Mapped("\u{3E5}")), (0x03E5, Valid),
+    (0x03E6, Mapped("\u{3E7}")), (0x03E7, Valid), (0x03E8, Mapped("\u{3E9}")), (0x03E9, Valid),
+    (0x03EA, Mapped("\u{3EB}")), (0x03EB, Valid), (0x03EC, Mapped("\u{3ED}")), (0x03ED, Valid),
+    (0x03EE, Mapped("\u{3EF}")), (0x03EF, Valid), (0x03F0, Mapped("\u{3BA}")),
+    (0x03F1, Mapped("\u{3C1}")), (0x03F2, Mapped("\u{3C3}")), (0x03F3, Valid),
+    (0x03F4, Mapped("\u{3B8}")), (0x03F5, Mapped("\u{3B5}")), (0x03F6, Valid),
+    (0x03F7, Mapped("\u{3F8}")), (0x03F8, Valid), (0x03F9, Mapped("\u{3C3}")),
+    (0x03FA, Mapped("\u{3FB}")), (0x03FB, Valid), (0x03FD, Shifted(0x037B)),
+    (0x0400, Shifted(0x0450)), (0x0410, Shifted(0x0430)), (0x0430, Valid),
+    (0x0460, Mapped("\u{461}")), (0x0461, Valid), (0x0462, Mapped("\u{463}")), (0x0463, Valid),
+    (0x0464, Mapped("\u{465}")), (0x0465, Valid), (0x0466, Mapped("\u{467}")), (0x0467, Valid),
+    (0x0468, Mapped("\u{469}")), (0x0469, Valid), (0x046A, Mapped("\u{46B}")), (0x046B, Valid),
+    (0x046C, Mapped("\u{46D}")), (0x046D, Valid), (0x046E, Mapped("\u{46F}")), (0x046F, Valid),
+    (0x0470, Mapped("\u{471}")), (0x0471, Valid), (0x0472, Mapped("\u{473}")), (0x0473, Valid),
+    (0x0474, Mapped("\u{475}")), (0x0475, Valid), (0x0476, Mapped("\u{477}")), (0x0477, Valid),
+    (0x0478, Mapped("\u{479}")), (0x0479, Valid), (0x047A, Mapped("\u{47B}")), (0x047B, Valid),
+    (0x047C, Mapped("\u{47D}")), (0x047D, Valid), (0x047E, Mapped("\u{47F}")), (0x047F, Valid),
+    (0x0480, Mapped("\u{481}")), (0x0481, Valid), (0x048A, Mapped("\u{48B}")), (0x048B, Valid),
+    (0x048C, Mapped("\u{48D}")), (0x048D, Valid), (0x048E, Mapped("\u{48F}")), (0x048F, Valid),
+    (0x0490, Mapped("\u{491}")), (0x0491, Valid), (0x0492, Mapped("\u{493}")), (0x0493, Valid),
+    (0x0494, Mapped("\u{495}")), (0x0495, Valid), (0x0496, Mapped("\u{497}")), (0x0497, Valid),
+    (0x0498, Mapped("\u{499}")), (0x0499, Valid), (0x049A, Mapped("\u{49B}")), (0x049B, Valid),
+    (0x049C, Mapped("\u{49D}")), (0x049D, Valid), (0x049E, Mapped("\u{49F}")), (0x049F, Valid),
+    (0x04A0, Mapped("\u{4A1}")), (0x04A1, Valid), (0x04A2, Mapped("\u{4A3}")), (0x04A3, Valid),
+    (0x04A4, Mapped("\u{4A5}")), (0x04A5, Valid), (0x04A6, Mapped("\u{4A7}")), (0x04A7, Valid),
+    (0x04A8, Mapped("\u{4A9}")), (0x04A9, Valid), (0x04AA, Mapped("\u{4AB}")), (0x04AB, Valid),
+    (0x04AC, Mapped("\u{4AD}")), (0x04AD, Valid), (0x04AE, Mapped("\u{4AF}")), (0x04AF, Valid),
+    (0x04B0, Mapped("\u{4B1}")), (0x04B1, Valid), (0x04B2, Mapped("\u{4B3}")), (0x04B3, Valid),
+    (0x04B4, Mapped("\u{4B5}")), (0x04B5, Valid), (0x04B6, Mapped("\u{4B7}")), (0x04B7, Valid),
+    (0x04B8, Mapped("\u{4B9}")), (0x04B9, Valid), (0x04BA, Mapped("\u{4BB}")), (0x04BB, Valid),
+    (0x04BC, Mapped("\u{4BD}")), (0x04BD, Valid), (0x04BE, Mapped("\u{4BF}")), (0x04BF, Valid),
+    (0x04C0, Disallowed), (0x04C1, Mapped("\u{4C2}")), (0x04C2, Valid), (0x04C3, Mapped("\u{4C4}")),
+    (0x04C4, Valid), (0x04C5, Mapped("\u{4C6}")), (0x04C6, Valid), (0x04C7, Mapped("\u{4C8}")),
+    (0x04C8, Valid), (0x04C9, Mapped("\u{4CA}")), (0x04CA, Valid), (0x04CB, Mapped("\u{4CC}")),
+    (0x04CC, Valid), (0x04CD, Mapped("\u{4CE}")), (0x04CE, Valid), (0x04D0, Mapped("\u{4D1}")),
+    (0x04D1, Valid), (0x04D2, Mapped("\u{4D3}")), (0x04D3, Valid), (0x04D4, Mapped("\u{4D5}")),
+    (0x04D5, Valid), (0x04D6, Mapped("\u{4D7}")), (0x04D7, Valid), (0x04D8, Mapped("\u{4D9}")),
+    (0x04D9, Valid), (0x04DA, Mapped("\u{4DB}")), (0x04DB, Valid), (0x04DC, Mapped("\u{4DD}")),
+    (0x04DD, Valid), (0x04DE, Mapped("\u{4DF}")), (0x04DF, Valid), (0x04E0, Mapped("\u{4E1}")),
+    (0x04E1, Valid), (0x04E2, Mapped("\u{4E3}")), (0x04E3, Valid), (0x04E4, Mapped("\u{4E5}")),
+    (0x04E5, Valid), (0x04E6, Mapped("\u{4E7}")), (0x04E7, Valid), (0x04E8, Mapped("\u{4E9}")),
+    (0x04E9, Valid), (0x04EA, Mapped("\u{4EB}")), (0x04EB, Valid), (0x04EC, Mapped("\u{4ED}")),
+    (0x04ED, Valid), (0x04EE, Mapped("\u{4EF}")), (0x04EF, Valid), (0x04F0, Mapped("\u{4F1}")),
+    (0x04F1, Valid), (0x04F2, Mapped("\u{4F3}")), (0x04F3, Valid), (0x04F4, Mapped("\u{4F5}")),
+    (0x04F5, Valid), (0x04F6, Mapped("\u{4F7}")), (0x04F7, Valid), (0x04F8, Mapped("\u{4F9}")),
+    (0x04F9, Valid), (0x04FA, Mapped("\u{4FB}")), (0x04FB, Valid), (0x04FC, Mapped("\u{4FD}")),
+    (0x04FD, Valid), (0x04FE, Mapped("\u{4FF}")), (0x04FF, Valid), (0x0500, Mapped("\u{501}")),
+    (0x0501, Valid), (0x0502, Mapped("\u{503}")), (0x0503, Valid), (0x0504, Mapped("\u{505}")),
+    (0x0505, Valid), (0x0506, Mapped("\u{507}")), (0x0507, Valid), (0x0508, Mapped("\u{509}")),
+    (0x0509, Valid), (0x050A, Mapped("\u{50B}")), (0x050B, Valid), (0x050C, Mapped("\u{50D}")),
+    (0x050D, Valid), (0x050E, Mapped("\u{50F}")), (0x050F, Valid), (0x0510, Mapped("\u{511}")),
+    (0x0511, Valid), (0x0512, Mapped("\u{513}")), (0x0513, Valid), (0x0514, Mapped("\u{515}")),
+    (0x0515, Valid), (0x0516, Mapped("\u{517}")), (0x0517, Valid), (0x0518, Mapped("\u{519}")),
+    (0x0519, Valid), (0x051A, Mapped("\u{51B}")), (0x051B, Valid), (0x051C, Mapped("\u{51D}")),
+    (0x051D, Valid), (0x051E, Mapped("\u{51F}")), (0x051F, Valid), (0x0520, Mapped("\u{521}")),
+    (0x0521, Valid), (0x0522, Mapped("\u{523}")), (0x0523, Valid), (0x0524, Mapped("\u{525}")),
+    (0x0525, Valid), (0x0526, Mapped("\u{527}")), (0x0527, Valid), (0x0528, Mapped("\u{529}")),
+    (0x0529, Valid), (0x052A, Mapped("\u{52B}")), (0x052B, Valid), (0x052C, Mapped("\u{52D}")),
+    (0x052D, Valid), (0x052E, Mapped("\u{52F}")), (0x052F, Valid), (0x0530, Disallowed),
+    (0x0531, Shifted(0x0561)), (0x0557, Disallowed), (0x0559, Valid),
+    (0x0587, Mapped("\u{565}\u{582}")), (0x0588, Valid), (0x058B, Disallowed), (0x058D, Valid),
+    (0x0590, Disallowed), (0x0591, Valid), (0x05C8, Disallowed), (0x05D0, Valid),
+    (0x05EB, Disallowed), (0x05EF, Valid), (0x05F5, Disallowed), (0x0606, Valid),
+    (0x061C, Disallowed), (0x061E, Valid), (0x0675, Mapped("\u{627}\u{674}")),
+    (0x0676, Mapped("\u{648}\u{674}")), (0x0677, Mapped("\u{6C7}\u{674}")),
+    (0x0678, Mapped("\u{64A}\u{674}")), (0x0679, Valid), (0x06DD, Disallowed), (0x06DE, Valid),
+    (0x070E, Disallowed), (0x0710, Valid), (0x074B, Disallowed), (0x074D, Valid),
+    (0x07B2, Disallowed), (0x07C0, Valid), (0x07FB, Disallowed), (0x07FD, Valid),
+    (0x082E, Disallowed), (0x0830, Valid), (0x083F, Disallowed), (0x0840, Valid),
+    (0x085C, Disallowed), (0x085E, Valid), (0x085F, Disallowed), (0x0860, Valid),
+    (0x086B, Disallowed), (0x08A0, Valid), (0x08B5, Disallowed), (0x08B6, Valid),
+    (0x08C8, Disallowed), (0x08D3, Valid), (0x08E2, Disallowed), (0x08E3, Valid),
+    (0x0958, Mapped("\u{915}\u{93C}")), (0x0959, Mapped("\u{916}\u{93C}")),
+    (0x095A, Mapped("\u{917}\u{93C}")), (0x095B, Mapped("\u{91C}\u{93C}")),
+    (0x095C, Mapped("\u{921}\u{93C}")), (0x095D, Mapped("\u{922}\u{93C}")),
+    (0x095E, Mapped("\u{92B}\u{93C}")), (0x095F, Mapped("\u{92F}\u{93C}")), (0x0960, Valid),
+    (0x0984, Disallowed), (0x0985, Valid), (0x098D, Disallowed), (0x098F, Valid),
+    (0x0991, Disallowed), (0x0993, Valid), (0x09A9, Disallowed), (0x09AA, Valid),
+    (0x09B1, Disallowed), (0x09B2, Valid), (0x09B3, Disallowed), (0x09B6, Valid),
+    (0x09BA, Disallowed), (0x09BC, Valid), (0x09C5, Disallowed), (0x09C7, Valid),
+    (0x09C9, Disallowed), (0x09CB, Valid), (0x09CF, Disallowed), (0x09D7, Valid),
+    (0x09D8, Disallowed), (0x09DC, Mapped("\u{9A1}\u{9BC}")), (0x09DD, Mapped("\u{9A2}\u{9BC}")),
+    (0x09DE, Disallowed), (0x09DF, Mapped("\u{9AF}\u{9BC}")), (0x09E0, Valid), (0x09E4, Disallowed),
+    (0x09E6, Valid), (0x09FF, Disallowed), (0x0A01, Valid), (0x0A04, Disallowed), (0x0A05, Valid),
+    (0x0A0B, Disallowed), (0x0A0F, Valid), (0x0A11, Disallowed), (0x0A13, Valid),
+    (0x0A29, Disallowed), (0x0A2A, Valid), (0x0A31, Disallowed), (0x0A32, Valid),
+    (0x0A33, Mapped("\u{A32}\u{A3C}")), (0x0A34, Disallowed), (0x0A35, Valid),
+    (0x0A36, Mapped("\u{A38}\u{A3C}")), (0x0A37, Disallowed), (0x0A38, Valid), (0x0A3A, Disallowed),
+    (0x0A3C, Valid), (0x0A3D, Disallowed), (0x0A3E, Valid), (0x0A43, Disallowed), (0x0A47, Valid),
+    (0x0A49, Disallowed), (0x0A4B, Valid), (0x0A4E, Disallowed), (0x0A51, Valid),
+    (0x0A52, Disallowed), (0x0A59, Mapped("\u{A16}\u{A3C}")), (0x0A5A, Mapped("\u{A17}\u{A3C}")),
+    (0x0A5B, Mapped("\u{A1C}\u{A3C}")), (0x0A5C, Valid), (0x0A5D, Disallowed),
+    (0x0A5E, Mapped("\u{A2B}\u{A3C}")), (0x0A5F, Disallowed), (0x0A66, Valid), (0x0A77, Disallowed),
+    (0x0A81, Valid), (0x0A84, Disallowed), (0x0A85, Valid), (0x0A8E, Disallowed), (0x0A8F, Valid),
+    (0x0A92, Disallowed), (0x0A93, Valid), (0x0AA9, Disallowed), (0x0AAA, Valid),
+    (0x0AB1, Disallowed), (0x0AB2, Valid), (0x0AB4, Disallowed), (0x0AB5, Valid),
+    (0x0ABA, Disallowed), (0x0ABC, Valid), (0x0AC6, Disallowed), (0x0AC7, Valid),
+    (0x0ACA, Disallowed), (0x0ACB, Valid), (0x0ACE, Disallowed), (0x0AD0, Valid),
+    (0x0AD1, Disallowed), (0x0AE0, Valid), (0x0AE4, Disallowed), (0x0AE6, Valid),
+    (0x0AF2, Disallowed), (0x0AF9, Valid), (0x0B00, Disallowed), (0x0B01, Valid),
+    (0x0B04, Disallowed), (0x0B05, Valid), (0x0B0D, Disallowed), (0x0B0F, Valid),
+    (0x0B11, Disallowed), (0x0B13, Valid), (0x0B29, Disallowed), (0x0B2A, Valid),
+    (0x0B31, Disallowed), (0x0B32, Valid), (0x0B34, Disallowed), (0x0B35, Valid),
+    (0x0B3A, Disallowed), (0x0B3C, Valid), (0x0B45, Disallowed), (0x0B47, Valid),
+    (0x0B49, Disallowed), (0x0B4B, Valid), (0x0B4E, Disallowed), (0x0B55, Valid),
+    (0x0B58, Disallowed), (0x0B5C, Mapped("\u{B21}\u{B3C}")), (0x0B5D, Mapped("\u{B22}\u{B3C}")),
+    (0x0B5E, Disallowed), (0x0B5F, Valid), (0x0B64, Disallowed), (0x0B66, Valid),
+    (0x0B78, Disallowed), (0x0B82, Valid), (0x0B84, Disallowed), (0x0B85, Valid),
+    (0x0B8B, Disallowed), (0x0B8E, Valid), (0x0B91, Disallowed), (0x0B92, Valid),
+    (0x0B96, Disallowed), (0x0B99, Valid), (0x0B9B, Disallowed), (0x0B9C, Valid),
+    (0x0B9D, Disallowed), (0x0B9E, Valid), (0x0BA0, Disallowed), (0x0BA3, Valid),
+    (0x0BA5, Disallowed), (0x0BA8, Valid), (0x0BAB, Disallowed), (0x0BAE, Valid),
+    (0x0BBA, Disallowed), (0x0BBE, Valid), (0x0BC3, Disallowed), (0x0BC6, Valid),
+    (0x0BC9, Disallowed), (0x0BCA, Valid), (0x0BCE, Disallowed), (0x0BD0, Valid),
+    (0x0BD1, Disallowed), (0x0BD7, Valid), (0x0BD8, Disallowed), (0x0BE6, Valid),
+    (0x0BFB, Disallowed), (0x0C00, Valid), (0x0C0D, Disallowed), (0x0C0E, Valid),
+    (0x0C11, Disallowed), (0x0C12, Valid), (0x0C29, Disallowed), (0x0C2A, Valid),
+    (0x0C3A, Disallowed), (0x0C3D, Valid), (0x0C45, Disallowed), (0x0C46, Valid),
+    (0x0C49, Disallowed), (0x0C4A, Valid), (0x0C4E, Disallowed), (0x0C55, Valid),
+    (0x0C57, Disallowed), (0x0C58, Valid), (0x0C5B, Disallowed), (0x0C60, Valid),
+    (0x0C64, Disallowed), (0x0C66, Valid), (0x0C70, Disallowed), (0x0C77, Valid),
+    (0x0C8D, Disallowed), (0x0C8E, Valid), (0x0C91, Disallowed), (0x0C92, Valid),
+    (0x0CA9, Disallowed), (0x0CAA, Valid), (0x0CB4, Disallowed), (0x0CB5, Valid),
+    (0x0CBA, Disallowed), (0x0CBC, Valid), (0x0CC5, Disallowed), (0x0CC6, Valid),
+    (0x0CC9, Disallowed), (0x0CCA, Valid), (0x0CCE, Disallowed), (0x0CD5, Valid),
+    (0x0CD7, Disallowed), (0x0CDE, Valid), (0x0CDF, Disallowed), (0x0CE0, Valid),
+    (0x0CE4, Disallowed), (0x0CE6, Valid), (0x0CF0, Disallowed), (0x0CF1, Valid),
+    (0x0CF3, Disallowed), (0x0D00, Valid), (0x0D0D, Disallowed), (0x0D0E, Valid),
+    (0x0D11, Disallowed), (0x0D12, Valid), (0x0D45, Disallowed), (0x0D46, Valid),
+    (0x0D49, Disallowed), (0x0D4A, Valid), (0x0D50, Disallowed), (0x0D54, Valid),
+    (0x0D64, Disallowed), (0x0D66, Valid), (0x0D80, Disallowed), (0x0D81, Valid),
+    (0x0D84, Disallowed), (0x0D85, Valid), (0x0D97, Disallowed), (0x0D9A, Valid),
+    (0x0DB2, Disallowed), (0x0DB3, Valid), (0x0DBC, Disallowed), (0x0DBD, Valid),
+    (0x0DBE, Disallowed), (0x0DC0, Valid), (0x0DC7, Disallowed), (0x0DCA, Valid),
+    (0x0DCB, Disallowed), (0x0DCF, Valid), (0x0DD5, Disallowed), (0x0DD6, Valid),
+    (0x0DD7, Disallowed), (0x0DD8, Valid), (0x0DE0, Disallowed), (0x0DE6, Valid),
+    (0x0DF0, Disallowed), (0x0DF2, Valid), (0x0DF5, Disallowed), (0x0E01, Valid),
+    (0x0E33, Mapped("\u{E4D}\u{E32}")), (0x0E34, Valid), (0x0E3B, Disallowed), (0x0E3F, Valid),
+    (0x0E5C, Disallowed), (0x0E81, Valid), (0x0E83, Disallowed), (0x0E84, Valid),
+    (0x0E85, Disallowed), (0x0E86, Valid), (0x0E8B, Disallowed), (0x0E8C, Valid),
+    (0x0EA4, Disallowed), (0x0EA5, Valid), (0x0EA6, Disallowed), (0x0EA7, Valid),
+    (0x0EB3, Mapped("\u{ECD}\u{EB2}")), (0x0EB4, Valid), (0x0EBE, Disallowed), (0x0EC0, Valid),
+    (0x0EC5, Disallowed), (0x0EC6, Valid), (0x0EC7, Disallowed), (0x0EC8, Valid),
+    (0x0ECE, Disallowed), (0x0ED0, Valid), (0x0EDA, Disallowed), (0x0EDC, Mapped("\u{EAB}\u{E99}")),
+    (0x0EDD, Mapped("\u{EAB}\u{EA1}")), (0x0EDE, Valid), (0x0EE0, Disallowed), (0x0F00, Valid),
+    (0x0F0C, Mapped("\u{F0B}")), (0x0F0D, Valid), (0x0F43, Mapped("\u{F42}\u{FB7}")),
+    (0x0F44, Valid), (0x0F48, Disallowed), (0x0F49, Valid), (0x0F4D, Mapped("\u{F4C}\u{FB7}")),
+    (0x0F4E, Valid), (0x0F52, Mapped("\u{F51}\u{FB7}")), (0x0F53, Valid),
+    (0x0F57, Mapped("\u{F56}\u{FB7}")), (0x0F58, Valid), (0x0F5C, Mapped("\u{F5B}\u{FB7}")),
+    (0x0F5D, Valid), (0x0F69, Mapped("\u{F40}\u{FB5}")), (0x0F6A, Valid), (0x0F6D, Disallowed),
+    (0x0F71, Valid), (0x0F73, Mapped("\u{F71}\u{F72}")), (0x0F74, Valid),
+    (0x0F75, Mapped("\u{F71}\u{F74}")), (0x0F76, Mapped("\u{FB2}\u{F80}")),
+    (0x0F77, Mapped("\u{FB2}\u{F71}\u{F80}")), (0x0F78, Mapped("\u{FB3}\u{F80}")),
+    (0x0F79, Mapped("\u{FB3}\u{F71}\u{F80}")), (0x0F7A, Valid), (0x0F81, Mapped("\u{F71}\u{F80}")),
+    (0x0F82, Valid), (0x0F93, Mapped("\u{F92}\u{FB7}")), (0x0F94, Valid), (0x0F98, Disallowed),
+    (0x0F99, Valid), (0x0F9D, Mapped("\u{F9C}\u{FB7}")), (0x0F9E, Valid),
+    (0x0FA2, Mapped("\u{FA1}\u{FB7}")), (0x0FA3, Valid), (0x0FA7, Mapped("\u{FA6}\u{FB7}")),
+    (0x0FA8, Valid), (0x0FAC, Mapped("\u{FAB}\u{FB7}")), (0x0FAD, Valid),
+    (0x0FB9, Mapped("\u{F90}\u{FB5}")), (0x0FBA, Valid), (0x0FBD, Disallowed), (0x0FBE, Valid),
+    (0x0FCD, Disallowed), (0x0FCE, Valid), (0x0FDB, Disallowed), (0x1000, Valid),
+    (0x10A0, Disallowed), (0x10C7, Mapped("\u{2D27}")), (0x10C8, Disallowed),
+    (0x10CD, Mapped("\u{2D2D}")), (0x10CE, Disallowed), (0x10D0, Valid),
+    (0x10FC, Mapped("\u{10DC}")), (0x10FD, Valid), (0x115F, Disallowed), (0x1161, Valid),
+    (0x1249, Disallowed), (0x124A, Valid), (0x124E, Disallowed), (0x1250, Valid),
+    (0x1257, Disallowed), (0x1258, Valid), (0x1259, Disallowed), (0x125A, Valid),
+    (0x125E, Disallowed), (0x1260, Valid), (0x1289, Disallowed), (0x128A, Valid),
+    (0x128E, Disallowed), (0x1290, Valid), (0x12B1, Disallowed), (0x12B2, Valid),
+    (0x12B6, Disallowed), (0x12B8, Valid), (0x12BF, Disallowed), (0x12C0, Valid),
+    (0x12C1, Disallowed), (0x12C2, Valid), (0x12C6, Disallowed), (0x12C8, Valid),
+    (0x12D7, Disallowed), (0x12D8, Valid), (0x1311, Disallowed), (0x1312, Valid),
+    (0x1316, Disallowed), (0x1318, Valid), (0x135B, Disallowed), (0x135D, Valid),
+    (0x137D, Disallowed), (0x1380, Valid), (0x139A, Disallowed), (0x13A0, Valid),
+    (0x13F6, Disallowed), (0x13F8, Shifted(0x13F0)), (0x13FE, Disallowed), (0x1400, Valid),
+    (0x1680, Disallowed), (0x1681, Valid), (0x169D, Disallowed), (0x16A0, Valid),
+    (0x16F9, Disallowed), (0x1700, Valid), (0x170D, Disallowed), (0x170E, Valid),
+    (0x1715, Disallowed), (0x1720, Valid), (0x1737, Disallowed), (0x1740, Valid),
+    (0x1754, Disallowed), (0x1760, Valid), (0x176D, Disallowed), (0x176E, Valid),
+    (0x1771, Disallowed), (0x1772, Valid), (0x1774, Disallowed), (0x1780, Valid),
+    (0x17B4, Disallowed), (0x17B6, Valid), (0x17DE, Disallowed), (0x17E0, Valid),
+    (0x17EA, Disallowed), (0x17F0, Valid), (0x17FA, Disallowed), (0x1800, Valid),
+    (0x1806, Disallowed), (0x1807, Valid), (0x180B, Ignored), (0x180E, Disallowed), (0x1810, Valid),
+    (0x181A, Disallowed), (0x1820, Valid), (0x1879, Disallowed), (0x1880, Valid),
+    (0x18AB, Disallowed), (0x18B0, Valid), (0x18F6, Disallowed), (0x1900, Valid),
+    (0x191F, Disallowed), (0x1920, Valid), (0x192C, Disallowed), (0x1930, Valid),
+    (0x193C, Disallowed), (0x1940, Valid), (0x1941, Disallowed), (0x1944, Valid),
+    (0x196E, Disallowed), (0x1970, Valid), (0x1975, Disallowed), (0x1980, Valid),
+    (0x19AC, Disallowed), (0x19B0, Valid), (0x19CA, Disallowed), (0x19D0, Valid),
+    (0x19DB, Disallowed), (0x19DE, Valid), (0x1A1C, Disallowed), (0x1A1E, Valid),
+    (0x1A5F, Disallowed), (0x1A60, Valid), (0x1A7D, Disallowed), (0x1A7F, Valid),
+    (0x1A8A, Disallowed), (0x1A90, Valid), (0x1A9A, Disallowed), (0x1AA0, Valid),
+    (0x1AAE, Disallowed), (0x1AB0, Valid), (0x1AC1, Disallowed), (0x1B00, Valid),
+    (0x1B4C, Disallowed), (0x1B50, Valid), (0x1B7D, Disallowed), (0x1B80, Valid),
+    (0x1BF4, Disallowed), (0x1BFC, Valid), (0x1C38, Disallowed), (0x1C3B, Valid),
+    (0x1C4A, Disallowed), (0x1C4D, Valid), (0x1C80, Mapped("\u{432}")), (0x1C81, Mapped("\u{434}")),
+    (0x1C82, Mapped("\u{43E}")), (0x1C83, Shifted(0x0441)), (0x1C85, Mapped("\u{442}")),
+    (0x1C86, Mapped("\u{44A}")), (0x1C87, Mapped("\u{463}")), (0x1C88, Mapped("\u{A64B}")),
+    (0x1C89, Disallowed), (0x1C90, Shifted(0x10D0)), (0x1CBB, Disallowed),
+    (0x1CBD, Shifted(0x10FD)), (0x1CC0, Valid), (0x1CC8, Disallowed), (0x1CD0, Valid),
+    (0x1CFB, Disallowed), (0x1D00, Valid), (0x1D2C, Mapped("a")), (0x1D2D, Mapped("\u{E6}")),
+    (0x1D2E, Mapped("b")), (0x1D2F, Valid), (0x1D30, Shifted(0x0064)), (0x1D32, Mapped("\u{1DD}")),
+    (0x1D33, Shifted(0x0067)), (0x1D3B, Valid), (0x1D3C, Mapped("o")), (0x1D3D, Mapped("\u{223}")),
+    (0x1D3E, Mapped("p")), (0x1D3F, Mapped("r")), (0x1D40, Shifted(0x0074)), (0x1D42, Mapped("w")),
+    (0x1D43, Mapped("a")), (0x1D44, Shifted(0x0250)), (0x1D46, Mapped("\u{1D02}")),
+    (0x1D47, Mapped("b")), (0x1D48, Shifted(0x0064)), (0x1D4A, Mapped("\u{259}")),
+    (0x1D4B, Shifted(0x025B)), (0x1D4D, Mapped("g")), (0x1D4E, Valid), (0x1D4F, Mapped("k")),
+    (0x1D50, Mapped("m")), (0x1D51, Mapped("\u{14B}")), (0x1D52, Mapped("o")),
+    (0x1D53, Mapped("\u{254}")), (0x1D54, Shifted(0x1D16)), (0x1D56, Mapped("p")),
+    (0x1D57, Shifted(0x0074)), (0x1D59, Mapped("\u{1D1D}")), (0x1D5A, Mapped("\u{26F}")),
+    (0x1D5B, Mapped("v")), (0x1D5C, Mapped("\u{1D25}")), (0x1D5D, Shifted(0x03B2)),
+    (0x1D60, Shifted(0x03C6)), (0x1D62, Mapped("i")), (0x1D63, Mapped("r")),
+    (0x1D64, Shifted(0x0075)), (0x1D66, Shifted(0x03B2)), (0x1D68, Mapped("\u{3C1}")),
+    (0x1D69, Shifted(0x03C6)), (0x1D6B, Valid), (0x1D78, Mapped("\u{43D}")), (0x1D79, Valid),
+    (0x1D9B, Mapped("\u{252}")), (0x1D9C, Mapped("c")), (0x1D9D, Mapped("\u{255}")),
+    (0x1D9E, Mapped("\u{F0}")), (0x1D9F, Mapped("\u{25C}")), (0x1DA0, Mapped("f")),
+    (0x1DA1, Mapped("\u{25F}")), (0x1DA2, Mapped("\u{261}")), (0x1DA3, Mapped("\u{265}")),
+    (0x1DA4, Shifted(0x0268)), (0x1DA7, Mapped("\u{1D7B}")), (0x1DA8, Mapped("\u{29D}")),
+    (0x1DA9, Mapped("\u{26D}")), (0x1DAA, Mapped("\u{1D85}")), (0x1DAB, Mapped("\u{29F}")),
+    (0x1DAC, Mapped("\u{271}")), (0x1DAD, Mapped("\u{270}")), (0x1DAE, Shifted(0x0272)),
+    (0x1DB2, Mapped("\u{278}")), (0x1DB3, Shifted(0x0282)), (0x1DB5, Mapped("\u{1AB}")),
+    (0x1DB6, Shifted(0x0289)), (0x1DB8, Mapped("\u{1D1C}")), (0x1DB9, Shifted(0x028B)),
+    (0x1DBB, Mapped("z")), (0x1DBC, Shifted(0x0290)), (0x1DBF, Mapped("\u{3B8}")), (0x1DC0, Valid),
+    (0x1DFA, Disallowed), (0x1DFB, Valid), (0x1E00, Mapped("\u{1E01}")), (0x1E01, Valid),
+    (0x1E02, Mapped("\u{1E03}")), (0x1E03, Valid), (0x1E04, Mapped("\u{1E05}")), (0x1E05, Valid),
+    (0x1E06, Mapped("\u{1E07}")), (0x1E07, Valid), (0x1E08, Mapped("\u{1E09}")), (0x1E09, Valid),
+    (0x1E0A, Mapped("\u{1E0B}")), (0x1E0B, Valid), (0x1E0C, Mapped("\u{1E0D}")), (0x1E0D, Valid),
+    (0x1E0E, Mapped("\u{1E0F}")), (0x1E0F, Valid), (0x1E10, Mapped("\u{1E11}")), (0x1E11, Valid),
+    (0x1E12, Mapped("\u{1E13}")), (0x1E13, Valid), (0x1E14, Mapped("\u{1E15}")), (0x1E15, Valid),
+    (0x1E16, Mapped("\u{1E17}")), (0x1E17, Valid), (0x1E18, Mapped("\u{1E19}")), (0x1E19, Valid),
+    (0x1E1A, Mapped("\u{1E1B}")), (0x1E1B, Valid), (0x1E1C, Mapped("\u{1E1D}")), (0x1E1D, Valid),
+    (0x1E1E, Mapped("\u{1E1F}")), (0x1E1F, Valid), (0x1E20, Mapped("\u{1E21}")), (0x1E21, Valid),
+    (0x1E22, Mapped("\u{1E23}")), (0x1E23, Valid), (0x1E24, Mapped("\u{1E25}")), (0x1E25, Valid),
+    (0x1E26, Mapped("\u{1E27}")), (0x1E27, Valid), (0x1E28, Mapped("\u{1E29}")), (0x1E29, Valid),
+    (0x1E2A, Mapped("\u{1E2B}")), (0x1E2B, Valid), (0x1E2C, Mapped("\u{1E2D}")), (0x1E2D, Valid),
+    (0x1E2E, Mapped("\u{1E2F}")), (0x1E2F, Valid), (0x1E30, Mapped("\u{1E31}")), (0x1E31, Valid),
+    (0x1E32, Mapped("\u{1E33}")), (0x1E33, Valid), (0x1E34, Mapped("\u{1E35}")), (0x1E35, Valid),
+    (0x1E36, Mapped("\u{1E37}")), (0x1E37, Valid), (0x1E38, Mapped("\u{1E39}")), (0x1E39, Valid),
+    (0x1E3A, Mapped("\u{1E3B}")), (0x1E3B, Valid), (0x1E3C, Mapped("\u{1E3D}")), (0x1E3D, Valid),
+    (0x1E3E, Mapped("\u{1E3F}")), (0x1E3F, Valid), (0x1E40, Mapped("\u{1E41}")), (0x1E41, Valid),
+    (0x1E42, Mapped("\u{1E43}")), (0x1E43, Valid), (0x1E44, Mapped("\u{1E45}")), (0x1E45, Valid),
+    (0x1E46, Mapped("\u{1E47}")), (0x1E47, Valid), (0x1E48, Mapped("\u{1E49}")), (0x1E49, Valid),
+    (0x1E4A, Mapped("\u{1E4B}")), (0x1E4B, Valid), (0x1E4C, Mapped("\u{1E4D}")), (0x1E4D, Valid),
+    (0x1E4E, Mapped("\u{1E4F}")), (0x1E4F, Valid), (0x1E50, Mapped("\u{1E51}")), (0x1E51, Valid),
+    (0x1E52, Mapped("\u{1E53}")), (0x1E53, Valid), (0x1E54, Mapped("\u{1E55}")), (0x1E55, Valid),
+    (0x1E56, Mapped("\u{1E57}")), (0x1E57, Valid), (0x1E58, Mapped("\u{1E59}")), (0x1E59, Valid),
+    (0x1E5A, Mapped("\u{1E5B}")), (0x1E5B, Valid), (0x1E5C, Mapped("\u{1E5D}")), (0x1E5D, Valid),
+    (0x1E5E, Mapped("\u{1E5F}")), (0x1E5F, Valid), (0x1E60, Mapped("\u{1E61}")), (0x1E61, Valid),
+    (0x1E62, Mapped("\u{1E63}")), (0x1E63, Valid), (0x1E64, Mapped("\u{1E65}")), (0x1E65, Valid),
+    (0x1E66, Mapped("\u{1E67}")), (0x1E67, Valid), (0x1E68, Mapped("\u{1E69}")), (0x1E69, Valid),
+    (0x1E6A, Mapped("\u{1E6B}")), (0x1E6B, Valid), (0x1E6C, Mapped("\u{1E6D}")), (0x1E6D, Valid),
+    (0x1E6E, Mapped("\u{1E6F}")), (0x1E6F, Valid), (0x1E70, Mapped("\u{1E71}")), (0x1E71, Valid),
+    (0x1E72, Mapped("\u{1E73}")), (0x1E73, Valid), (0x1E74, Mapped("\u{1E75}")), (0x1E75, Valid),
+    (0x1E76, Mapped("\u{1E77}")), (0x1E77, Valid), (0x1E78, Mapped("\u{1E79}")), (0x1E79, Valid),
+    (0x1E7A, Mapped("\u{1E7B}")), (0x1E7B, Valid), (0x1E7C, Mapped("\u{1E7D}")), (0x1E7D, Valid),
+    (0x1E7E, Mapped("\u{1E7F}")), (0x1E7F, Valid), (0x1E80, Mapped("\u{1E81}")), (0x1E81, Valid),
+    (0x1E82, Mapped("\u{1E83}")), (0x1E83, Valid), (0x1E84, Mapped("\u{1E85}")), (0x1E85, Valid),
+    (0x1E86, Mapped("\u{1E87}")), (0x1E87, Valid), (0x1E88, Mapped("\u{1E89}")), (0x1E89, Valid),
+    (0x1E8A, Mapped("\u{1E8B}")), (0x1E8B, Valid), (0x1E8C, Mapped("\u{1E8D}")), (0x1E8D, Valid),
+    (0x1E8E, Mapped("\u{1E8F}")), (0x1E8F, Valid), (0x1E90, Mapped("\u{1E91}")), (0x1E91, Valid),
+    (0x1E92, Mapped("\u{1E93}")), (0x1E93, Valid), (0x1E94, Mapped("\u{1E95}")), (0x1E95, Valid),
+    (0x1E9A, Mapped("a\u{2BE}")), (0x1E9B, Mapped("\u{1E61}")), (0x1E9C, Valid),
+    (0x1E9E, Mapped("ss")), (0x1E9F, Valid), (0x1EA0, Mapped("\u{1EA1}")), (0x1EA1, Valid),
+    (0x1EA2, Mapped("\u{1EA3}")), (0x1EA3, Valid), (0x1EA4, Mapped("\u{1EA5}")), (0x1EA5, Valid),
+    (0x1EA6, Mapped("\u{1EA7}")), (0x1EA7, Valid), (0x1EA8, Mapped("\u{1EA9}")), (0x1EA9, Valid),
+    (0x1EAA, Mapped("\u{1EAB}")), (0x1EAB, Valid), (0x1EAC, Mapped("\u{1EAD}")), (0x1EAD, Valid),
+    (0x1EAE, Mapped("\u{1EAF}")), (0x1EAF, Valid), (0x1EB0, Mapped("\u{1EB1}")), (0x1EB1, Valid),
+    (0x1EB2, Mapped("\u{1EB3}")), (0x1EB3, Valid), (0x1EB4, Mapped("\u{1EB5}")), (0x1EB5, Valid),
+    (0x1EB6, Mapped("\u{1EB7}")), (0x1EB7, Valid), (0x1EB8, Mapped("\u{1EB9}")), (0x1EB9, Valid),
+    (0x1EBA, Mapped("\u{1EBB}")), (0x1EBB, Valid), (0x1EBC, Mapped("\u{1EBD}")), (0x1EBD, Valid),
+    (0x1EBE, Mapped("\u{1EBF}")), (0x1EBF, Valid), (0x1EC0, Mapped("\u{1EC1}")), (0x1EC1, Valid),
+    (0x1EC2, Mapped("\u{1EC3}")), (0x1EC3, Valid), (0x1EC4, Mapped("\u{1EC5}")), (0x1EC5, Valid),
+    (0x1EC6, Mapped("\u{1EC7}")), (0x1EC7, Valid), (0x1EC8, Mapped("\u{1EC9}")), (0x1EC9, Valid),
+    (0x1ECA, Mapped("\u{1ECB}")), (0x1ECB, Valid), (0x1ECC, Mapped("\u{1ECD}")), (0x1ECD, Valid),
+    (0x1ECE, Mapped("\u{1ECF}")), (0x1ECF, Valid), (0x1ED0, Mapped("\u{1ED1}")), (0x1ED1, Valid),
+    (0x1ED2, Mapped("\u{1ED3}")), (0x1ED3, Valid), (0x1ED4, Mapped("\u{1ED5}")), (0x1ED5, Valid),
+    (0x1ED6, Mapped("\u{1ED7}")), (0x1ED7, Valid), (0x1ED8, Mapped("\u{1ED9}")), (0x1ED9, Valid),
+    (0x1EDA, Mapped("\u{1EDB}")), (0x1EDB, Valid), (0x1EDC, Mapped("\u{1EDD}")), (0x1EDD, Valid),
+    (0x1EDE, Mapped("\u{1EDF}")), (0x1EDF, Valid), (0x1EE0, Mapped("\u{1EE1}")), (0x1EE1, Valid),
+    (0x1EE2, Mapped("\u{1EE3}")), (0x1EE3, Valid), (0x1EE4, Mapped("\u{1EE5}")), (0x1EE5, Valid),
+    (0x1EE6, Mapped("\u{1EE7}")), (0x1EE7, Valid), (0x1EE8, Mapped("\u{1EE9}")), (0x1EE9, Valid),
+    (0x1EEA, Mapped("\u{1EEB}")), (0x1EEB, Valid), (0x1EEC, Mapped("\u{1EED}")), (0x1EED, Valid),
+    (0x1EEE, Mapped("\u{1EEF}")), (0x1EEF, Valid), (0x1EF0, Mapped("\u{1EF1}")), (0x1EF1, Valid),
+    (0x1EF2, Mapped("\u{1EF3}")), (0x1EF3, Valid), (0x1EF4, Mapped("\u{1EF5}")), (0x1EF5, Valid),
+    (0x1EF6, Mapped("\u{1EF7}")), (0x1EF7, Valid), (0x1EF8, Mapped("\u{1EF9}")), (0x1EF9, Valid),
+    (0x1EFA, Mapped("\u{1EFB}")), (0x1EFB, Valid), (0x1EFC, Mapped("\u{1EFD}")), (0x1EFD, Valid),
+    (0x1EFE, Mapped("\u{1EFF}")), (0x1EFF, Valid), (0x1F08, Shifted(0x1F00)), (0x1F10, Valid),
+    (0x1F16, Disallowed), (0x1F18, Shifted(0x1F10)), (0x1F1E, Disallowed), (0x1F20, Valid),
+    (0x1F28, Shifted(0x1F20)), (0x1F30, Valid), (0x1F38, Shifted(0x1F30)), (0x1F40, Valid),
+    (0x1F46, Disallowed), (0x1F48, Shifted(0x1F40)), (0x1F4E, Disallowed), (0x1F50, Valid),
+    (0x1F58, Disallowed), (0x1F59, Mapped("\u{1F51}")), (0x1F5A, Disallowed),
+    (0x1F5B, Mapped("\u{1F53}")), (0x1F5C, Disallowed), (0x1F5D, Mapped("\u{1F55}")),
+    (0x1F5E, Disallowed), (0x1F5F, Mapped("\u{1F57}")), (0x1F60, Valid), (0x1F68, Shifted(0x1F60)),
+    (0x1F70, Valid), (0x1F71, Mapped("\u{3AC}")), (0x1F72, Valid), (0x1F73, Mapped("\u{3AD}")),
+    (0x1F74, Valid), (0x1F75, Mapped("\u{3AE}")), (0x1F76, Valid), (0x1F77, Mapped("\u{3AF}")),
+    (0x1F78, Valid), (0x1F79, Mapped("\u{3CC}")), (0x1F7A, Valid), (0x1F7B, Mapped("\u{3CD}")),
+    (0x1F7C, Valid), (0x1F7D, Mapped("\u{3CE}")), (0x1F7E, Disallowed),
+    (0x1F80, Mapped("\u{1F00}\u{3B9}")), (0x1F81, Mapped("\u{1F01}\u{3B9}")),
+    (0x1F82, Mapped("\u{1F02}\u{3B9}")), (0x1F83, Mapped("\u{1F03}\u{3B9}")),
+    (0x1F84, Mapped("\u{1F04}\u{3B9}")), (0x1F85, Mapped("\u{1F05}\u{3B9}")),
+    (0x1F86, Mapped("\u{1F06}\u{3B9}")), (0x1F87, Mapped("\u{1F07}\u{3B9}")),
+    (0x1F88, Mapped("\u{1F00}\u{3B9}")), (0x1F89, Mapped("\u{1F01}\u{3B9}")),
+    (0x1F8A, Mapped("\u{1F02}\u{3B9}")), (0x1F8B, Mapped("\u{1F03}\u{3B9}")),
+    (0x1F8C, Mapped("\u{1F04}\u{3B9}")), (0x1F8D, Mapped("\u{1F05}\u{3B9}")),
+    (0x1F8E, Mapped("\u{1F06}\u{3B9}")), (0x1F8F, Mapped("\u{1F07}\u{3B9}")),
+    (0x1F90, Mapped("\u{1F20}\u{3B9}")), (0x1F91, Mapped("\u{1F21}\u{3B9}")),
+    (0x1F92, Mapped("\u{1F22}\u{3B9}")), (0x1F93, Mapped("\u{1F23}\u{3B9}")),
+    (0x1F94, Mapped("\u{1F24}\u{3B9}")), (0x1F95, Mapped("\u{1F25}\u{3B9}")),
+    (0x1F96, Mapped("\u{1F26}\u{3B9}")), (0x1F97, Mapped("\u{1F27}\u{3B9}")),
+    (0x1F98, Mapped("\u{1F20}\u{3B9}")), (0x1F99, Mapped("\u{1F21}\u{3B9}")),
+    (0x1F9A, Mapped("\u{1F22}\u{3B9}")), (0x1F9B, Mapped("\u{1F23}\u{3B9}")),
+    (0x1F9C, Mapped("\u{1F24}\u{3B9}")), (0x1F9D, Mapped("\u{1F25}\u{3B9}")),
+    (0x1F9E, Mapped("\u{1F26}\u{3B9}")), (0x1F9F, Mapped("\u{1F27}\u{3B9}")),
+    (0x1FA0, Mapped("\u{1F60}\u{3B9}")), (0x1FA1, Mapped("\u{1F61}\u{3B9}")),
+    (0x1FA2, Mapped("\u{1F62}\u{3B9}")), (0x1FA3, Mapped("\u{1F63}\u{3B9}")),
+    (0x1FA4, Mapped("\u{1F64}\u{3B9}")), (0x1FA5, Mapped("\u{1F65}\u{3B9}")),
+    (0x1FA6, Mapped("\u{1F66}\u{3B9}")), (0x1FA7, Mapped("\u{1F67}\u{3B9}")),
+    (0x1FA8, Mapped("\u{1F60}\u{3B9}")), (0x1FA9, Mapped("\u{1F61}\u{3B9}")),
+    (0x1FAA, Mapped("\u{1F62}\u{3B9}")), (0x1FAB, Mapped("\u{1F63}\u{3B9}")),
+    (0x1FAC, Mapped("\u{1F64}\u{3B9}")), (0x1FAD, Mapped("\u{1F65}\u{3B9}")),
+    (0x1FAE, Mapped("\u{1F66}\u{3B9}")), (0x1FAF, Mapped("\u{1F67}\u{3B9}")), (0x1FB0, Valid),
+    (0x1FB2, Mapped("\u{1F70}\u{3B9}")), (0x1FB3, Mapped("\u{3B1}\u{3B9}")),
+    (0x1FB4, Mapped("\u{3AC}\u{3B9}")), (0x1FB5, Disallowed), (0x1FB6, Valid),
+    (0x1FB7, Mapped("\u{1FB6}\u{3B9}")), (0x1FB8, Shifted(0x1FB0)), (0x1FBA, Mapped("\u{1F70}")),
+    (0x1FBB, Mapped("\u{3AC}")), (0x1FBC, Mapped("\u{3B1}\u{3B9}")), (0x1FBD, Mapped(" \u{313}")),
+    (0x1FBE, Mapped("\u{3B9}")), (0x1FBF, Mapped(" \u{313}")), (0x1FC0, Mapped(" \u{342}")),
+    (0x1FC1, Mapped(" \u{308}\u{342}")), (0x1FC2, Mapped("\u{1F74}\u{3B9}")),
+    (0x1FC3, Mapped("\u{3B7}\u{3B9}")), (0x1FC4, Mapped("\u{3AE}\u{3B9}")), (0x1FC5, Disallowed),
+    (0x1FC6, Valid), (0x1FC7, Mapped("\u{1FC6}\u{3B9}")), (0x1FC8, Mapped("\u{1F72}")),
+    (0x1FC9, Mapped("\u{3AD}")), (0x1FCA, Mapped("\u{1F74}")), (0x1FCB, Mapped("\u{3AE}")),
+    (0x1FCC, Mapped("\u{3B7}\u{3B9}")), (0x1FCD, Mapped(" \u{313}\u{300}")),
+    (0x1FCE, Mapped(" \u{313}\u{301}")), (0x1FCF, Mapped(" \u{313}\u{342}")), (0x1FD0, Valid),
+    (0x1FD3, Mapped("\u{390}")), (0x1FD4, Disallowed), (0x1FD6, Valid), (0x1FD8, Shifted(0x1FD0)),
+    (0x1FDA, Mapped("\u{1F76}")), (0x1FDB, Mapped("\u{3AF}")), (0x1FDC, Disallowed),
+    (0x1FDD, Mapped(" \u{314}\u{300}")), (0x1FDE, Mapped(" \u{314}\u{301}")),
+    (0x1FDF, Mapped(" \u{314}\u{342}")), (0x1FE0, Valid), (0x1FE3, Mapped("\u{3B0}")),
+    (0x1FE4, Valid), (0x1FE8, Shifted(0x1FE0)), (0x1FEA, Mapped("\u{1F7A}")),
+    (0x1FEB, Mapped("\u{3CD}")), (0x1FEC, Mapped("\u{1FE5}")), (0x1FED, Mapped(" \u{308}\u{300}")),
+    (0x1FEE, Mapped(" \u{308}\u{301}")), (0x1FEF, Mapped("`")), (0x1FF0, Disallowed),
+    (0x1FF2, Mapped("\u{1F7C}\u{3B9}")), (0x1FF3, Mapped("\u{3C9}\u{3B9}")),
+    (0x1FF4, Mapped("\u{3CE}\u{3B9}")), (0x1FF5, Disallowed), (0x1FF6, Valid),
+    (0x1FF7, Mapped("\u{1FF6}\u{3B9}")), (0x1FF8, Mapped("\u{1F78}")), (0x1FF9, Mapped("\u{3CC}")),
+    (0x1FFA, Mapped("\u{1F7C}")), (0x1FFB, Mapped("\u{3CE}")), (0x1FFC, Mapped("\u{3C9}\u{3B9}")),
+    (0x1FFD, Mapped(" \u{301}")), (0x1FFE, Mapped(" \u{314}")), (0x1FFF, Disallowed),
+    (0x2000, Mapped(" ")), (0x200B, Ignored), (0x200C, Valid), (0x200E, Disallowed),
+    (0x2010, Valid), (0x2011, Mapped("\u{2010}")), (0x2012, Valid), (0x2017, Mapped(" \u{333}")),
+    (0x2018, Valid), (0x2024, Disallowed), (0x2027, Valid), (0x2028, Disallowed),
+    (0x202F, Mapped(" ")), (0x2030, Valid), (0x2033, Mapped("\u{2032}\u{2032}")),
+    (0x2034, Mapped("\u{2032}\u{2032}\u{2032}")), (0x2035, Valid),
+    (0x2036, Mapped("\u{2035}\u{2035}")), (0x2037, Mapped("\u{2035}\u{2035}\u{2035}")),
+    (0x2038, Valid), (0x203C, Mapped("!!")), (0x203D, Valid), (0x203E, Mapped(" \u{305}")),
+    (0x203F, Valid), (0x2047, Mapped("??")), (0x2048, Mapped("?!")), (0x2049, Mapped("!?")),
+    (0x204A, Valid), (0x2057, Mapped("\u{2032}\u{2032}\u{2032}\u{2032}")), (0x2058, Valid),
+    (0x205F, Mapped(" ")), (0x2060, Ignored), (0x2061, Disallowed), (0x2064, Ignored),
+    (0x2065, Disallowed), (0x2070, Mapped("0")), (0x2071, Mapped("i")), (0x2072, Disallowed),
+    (0x2074, Shifted(0x0034)), (0x207A, Mapped("+")), (0x207B, Mapped("\u{2212}")),
+    (0x207C, Mapped("=")), (0x207D, Shifted(0x0028)), (0x207F, Mapped("n")),
+    (0x2080, Shifted(0x0030)), (0x208A, Mapped("+")), (0x208B, Mapped("\u{2212}")),
+    (0x208C, Mapped("=")), (0x208D, Shifted(0x0028)), (0x208F, Disallowed), (0x2090, Mapped("a")),
+    (0x2091, Mapped("e")), (0x2092, Mapped("o")), (0x2093, Mapped("x")),
+    (0x2094, Mapped("\u{259}")), (0x2095, Mapped("h")), (0x2096, Shifted(0x006B)),
+    (0x209A, Mapped("p")), (0x209B, Shifted(0x0073)), (0x209D, Disallowed), (0x20A0, Valid),
+    (0x20A8, Mapped("rs")), (0x20A9, Valid), (0x20C0, Disallowed), (0x20D0, Valid),
+    (0x20F1, Disallowed), (0x2100, Mapped("a/c")), (0x2101, Mapped("a/s")), (0x2102, Mapped("c")),
+    (0x2103, Mapped("\u{B0}c")), (0x2104, Valid), (0x2105, Mapped("c/o")), (0x2106, Mapped("c/u")),
+    (0x2107, Mapped("\u{25B}")), (0x2108, Valid), (0x2109, Mapped("\u{B0}f")),
+    (0x210A, Shifted(0x0067)), (0x210C, Mapped("h")), (0x210F, Mapped("\u{127}")),
+    (0x2110, Mapped("i")), (0x2112, Mapped("l")), (0x2114, Valid), (0x2115, Mapped("n")),
+    (0x2116, Mapped("no")), (0x2117, Valid), (0x2119, Shifted(0x0070)), (0x211C, Mapped("r")),
+    (0x211E, Valid), (0x2120, Mapped("sm")), (0x2121, Mapped("tel")), (0x2122, Mapped("tm")),
+    (0x2123, Valid), (0x2124, Mapped("z")), (0x2125, Valid), (0x2126, Mapped("\u{3C9}")),
+    (0x2127, Valid), (0x2128, Mapped("z")), (0x2129, Valid), (0x212A, Mapped("k")),
+    (0x212B, Mapped("\u{E5}")), (0x212C, Shifted(0x0062)), (0x212E, Valid), (0x212F, Mapped("e")),
+    (0x2131, Mapped("f")), (0x2132, Disallowed), (0x2133, Mapped("m")), (0x2134, Mapped("o")),
+    (0x2135, Shifted(0x05D0)), (0x2139, Mapped("i")), (0x213A, Valid), (0x213B, Mapped("fax")),
+    (0x213C, Mapped("\u{3C0}")), (0x213D, Mapped("\u{3B3}")), (0x213F, Mapped("\u{3C0}")),
+    (0x2140, Mapped("\u{2211}")), (0x2141, Valid), (0x2145, Mapped("d")), (0x2147, Mapped("e")),
+    (0x2148, Shifted(0x0069)), (0x214A, Valid), (0x2150, Mapped("1\u{2044}7")),
+    (0x2151, Mapped("1\u{2044}9")), (0x2152, Mapped("1\u{2044}10")), (0x2153, Mapped("1\u{2044}3")),
+    (0x2154, Mapped("2\u{2044}3")), (0x2155, Mapped("1\u{2044}5")), (0x2156, Mapped("2\u{2044}5")),
+    (0x2157, Mapped("3\u{2044}5")), (0x2158, Mapped("4\u{2044}5")), (0x2159, Mapped("1\u{2044}6")),
+    (0x215A, Mapped("5\u{2044}6")), (0x215B, Mapped("1\u{2044}8")), (0x215C, Mapped("3\u{2044}8")),
+    (0x215D, Mapped("5\u{2044}8")), (0x215E, Mapped("7\u{2044}8")), (0x215F, Mapped("1\u{2044}")),
+    (0x2160, Mapped("i")), (0x2161, Mapped("ii")), (0x2162, Mapped("iii")), (0x2163, Mapped("iv")),
+    (0x2164, Mapped("v")), (0x2165, Mapped("vi")), (0x2166, Mapped("vii")),
+    (0x2167, Mapped("viii")), (0x2168, Mapped("ix")), (0x2169, Mapped("x")), (0x216A, Mapped("xi")),
+    (0x216B, Mapped("xii")), (0x216C, Mapped("l")), (0x216D, Shifted(0x0063)),
+    (0x216F, Mapped("m")), (0x2170, Mapped("i")), (0x2171, Mapped("ii")), (0x2172, Mapped("iii")),
+    (0x2173, Mapped("iv")), (0x2174, Mapped("v")), (0x2175, Mapped("vi")), (0x2176, Mapped("vii")),
+    (0x2177, Mapped("viii")), (0x2178, Mapped("ix")), (0x2179, Mapped("x")), (0x217A, Mapped("xi")),
+    (0x217B, Mapped("xii")), (0x217C, Mapped("l")), (0x217D, Shifted(0x0063)),
+    (0x217F, Mapped("m")), (0x2180, Valid), (0x2183, Disallowed), (0x2184, Valid),
+    (0x2189, Mapped("0\u{2044}3")), (0x218A, Valid), (0x218C, Disallowed), (0x2190, Valid),
+    (0x222C, Mapped("\u{222B}\u{222B}")), (0x222D, Mapped("\u{222B}\u{222B}\u{222B}")),
+    (0x222E, Valid), (0x222F, Mapped("\u{222E}\u{222E}")),
+    (0x2230, Mapped("\u{222E}\u{222E}\u{222E}")), (0x2231, Valid), (0x2329, Shifted(0x3008)),
+    (0x232B, Valid), (0x2427, Disallowed), (0x2440, Valid), (0x244B, Disallowed),
+    (0x2460, Shifted(0x0031)), (0x2469, Mapped("10")), (0x246A, Mapped("11")),
+    (0x246B, Mapped("12")), (0x246C, Mapped("13")), (0x246D, Mapped("14")), (0x246E, Mapped("15")),
+    (0x246F, Mapped("16")), (0x2470, Mapped("17")), (0x2471, Mapped("18")), (0x2472, Mapped("19")),
+    (0x2473, Mapped("20")), (0x2474, Mapped("(1)")), (0x2475, Mapped("(2)")),
+    (0x2476, Mapped("(3)")), (0x2477, Mapped("(4)")), (0x2478, Mapped("(5)")),
+    (0x2479, Mapped("(6)")), (0x247A, Mapped("(7)")), (0x247B, Mapped("(8)")),
+    (0x247C, Mapped("(9)")), (0x247D, Mapped("(10)")), (0x247E, Mapped("(11)")),
+    (0x247F, Mapped("(12)")), (0x2480, Mapped("(13)")), (0x2481, Mapped("(14)")),
+    (0x2482, Mapped("(15)")), (0x2483, Mapped("(16)")), (0x2484, Mapped("(17)")),
+    (0x2485, Mapped("(18)")), (0x2486, Mapped("(19)")), (0x2487, Mapped("(20)")),
+    (0x2488, Disallowed), (0x249C, Mapped("(a)")), (0x249D, Mapped("(b)")), (0x249E, Mapped("(c)")),
+    (0x249F, Mapped("(d)")), (0x24A0, Mapped("(e)")), (0x24A1, Mapped("(f)")),
+    (0x24A2, Mapped("(g)")), (0x24A3, Mapped("(h)")), (0x24A4, Mapped("(i)")),
+    (0x24A5, Mapped("(j)")), (0x24A6, Mapped("(k)")), (0x24A7, Mapped("(l)")),
+    (0x24A8, Mapped("(m)")), (0x24A9, Mapped("(n)")), (0x24AA, Mapped("(o)")),
+    (0x24AB, Mapped("(p)")), (0x24AC, Mapped("(q)")), (0x24AD, Mapped("(r)")),
+    (0x24AE, Mapped("(s)")), (0x24AF, Mapped("(t)")), (0x24B0, Mapped("(u)")),
+    (0x24B1, Mapped("(v)")), (0x24B2, Mapped("(w)")), (0x24B3, Mapped("(x)")),
+    (0x24B4, Mapped("(y)")), (0x24B5, Mapped("(z)")), (0x24B6, Shifted(0x0061)),
+    (0x24D0, Shifted(0x0061)), (0x24EA, Mapped("0")), (0x24EB, Valid),
+    (0x2A0C, Mapped("\u{222B}\u{222B}\u{222B}\u{222B}")), (0x2A0D, Valid), (0x2A74, Mapped("::=")),
+    (0x2A75, Mapped("==")), (0x2A76, Mapped("===")), (0x2A77, Valid),
+    (0x2ADC, Mapped("\u{2ADD}\u{338}")), (0x2ADD, Valid), (0x2B74, Disallowed), (0x2B76, Valid),
+    (0x2B96, Disallowed), (0x2B97, Valid), (0x2C00, Shifted(0x2C30)), (0x2C2F, Disallowed),
+    (0x2C30, Valid), (0x2C5F, Disallowed), (0x2C60, Mapped("\u{2C61}")), (0x2C61, Valid),
+    (0x2C62, Mapped("\u{26B}")), (0x2C63, Mapped("\u{1D7D}")), (0x2C64, Mapped("\u{27D}")),
+    (0x2C65, Valid), (0x2C67, Mapped("\u{2C68}")), (0x2C68, Valid), (0x2C69, Mapped("\u{2C6A}")),
+    (0x2C6A, Valid), (0x2C6B, Mapped("\u{2C6C}")), (0x2C6C, Valid), (0x2C6D, Mapped("\u{251}")),
+    (0x2C6E, Mapped("\u{271}")), (0x2C6F, Mapped("\u{250}")), (0x2C70, Mapped("\u{252}")),
+    (0x2C71, Valid), (0x2C72, Mapped("\u{2C73}")), (0x2C73, Valid), (0x2C75, Mapped("\u{2C76}")),
+    (0x2C76, Valid), (0x2C7C, Mapped("j")), (0x2C7D, Mapped("v")), (0x2C7E, Shifted(0x023F)),
+    (0x2C80, Mapped("\u{2C81}")), (0x2C81, Valid), (0x2C82, Mapped("\u{2C83}")), (0x2C83, Valid),
+    (0x2C84, Mapped("\u{2C85}")), (0x2C85, Valid), (0x2C86, Mapped("\u{2C87}")), (0x2C87, Valid),
+    (0x2C88, Mapped("\u{2C89}")), (0x2C89, Valid), (0x2C8A, Mapped("\u{2C8B}")), (0x2C8B, Valid),
+    (0x2C8C, Mapped("\u{2C8D}")), (0x2C8D, Valid), (0x2C8E, Mapped("\u{2C8F}")), (0x2C8F, Valid),
+    (0x2C90, Mapped("\u{2C91}")), (0x2C91, Valid), (0x2C92, Mapped("\u{2C93}")), (0x2C93, Valid),
+    (0x2C94, Mapped("\u{2C95}")), (0x2C95, Valid), (0x2C96, Mapped("\u{2C97}")), (0x2C97, Valid),
+    (0x2C98, Mapped("\u{2C99}")), (0x2C99, Valid), (0x2C9A, Mapped("\u{2C9B}")), (0x2C9B, Valid),
+    (0x2C9C, Mapped("\u{2C9D}")), (0x2C9D, Valid), (0x2C9E, Mapped("\u{2C9F}")), (0x2C9F, Valid),
+    (0x2CA0, Mapped("\u{2CA1}")), (0x2CA1, Valid), (0x2CA2, Mapped("\u{2CA3}")), (0x2CA3, Valid),
+    (0x2CA4, Mapped("\u{2CA5}")), (0x2CA5, Valid), (0x2CA6, Mapped("\u{2CA7}")), (0x2CA7, Valid),
+    (0x2CA8, Mapped("\u{2CA9}")), (0x2CA9, Valid), (0x2CAA, Mapped("\u{2CAB}")), (0x2CAB, Valid),
+    (0x2CAC, Mapped("\u{2CAD}")), (0x2CAD, Valid), (0x2CAE, Mapped("\u{2CAF}")), (0x2CAF, Valid),
+    (0x2CB0, Mapped("\u{2CB1}")), (0x2CB1, Valid), (0x2CB2, Mapped("\u{2CB3}")), (0x2CB3, Valid),
+    (0x2CB4, Mapped("\u{2CB5}")), (0x2CB5, Valid), (0x2CB6, Mapped("\u{2CB7}")), (0x2CB7, Valid),
+    (0x2CB8, Mapped("\u{2CB9}")), (0x2CB9, Valid), (0x2CBA, Mapped("\u{2CBB}")), (0x2CBB, Valid),
+    (0x2CBC, Mapped("\u{2CBD}")), (0x2CBD, Valid), (0x2CBE, Mapped("\u{2CBF}")), (0x2CBF, Valid),
+    (0x2CC0, Mapped("\u{2CC1}")), (0x2CC1, Valid), (0x2CC2, Mapped("\u{2CC3}")), (0x2CC3, Valid),
+    (0x2CC4, Mapped("\u{2CC5}")), (0x2CC5, Valid), (0x2CC6, Mapped("\u{2CC7}")), (0x2CC7, Valid),
+    (0x2CC8, Mapped("\u{2CC9}")), (0x2CC9, Valid), (0x2CCA, Mapped("\u{2CCB}")), (0x2CCB, Valid),
+    (0x2CCC, Mapped("\u{2CCD}")), (0x2CCD, Valid), (0x2CCE, Mapped("\u{2CCF}")), (0x2CCF, Valid),
+    (0x2CD0, Mapped("\u{2CD1}")), (0x2CD1, Valid), (0x2CD2, Mapped("\u{2CD3}")), (0x2CD3, Valid),
+    (0x2CD4, Mapped("\u{2CD5}")), (0x2CD5, Valid), (0x2CD6, Mapped("\u{2CD7}")), (0x2CD7, Valid),
+    (0x2CD8, Mapped("\u{2CD9}")), (0x2CD9, Valid), (0x2CDA, Mapped("\u{2CDB}")), (0x2CDB, Valid),
+    (0x2CDC, Mapped("\u{2CDD}")), (0x2CDD, Valid), (0x2CDE, Mapped("\u{2CDF}")), (0x2CDF, Valid),
+    (0x2CE0, Mapped("\u{2CE1}")), (0x2CE1, Valid), (0x2CE2, Mapped("\u{2CE3}")), (0x2CE3, Valid),
+    (0x2CEB, Mapped("\u{2CEC}")), (0x2CEC, Valid), (0x2CED, Mapped("\u{2CEE}")), (0x2CEE, Valid),
+    (0x2CF2, Mapped("\u{2CF3}")), (0x2CF3, Valid), (0x2CF4, Disallowed), (0x2CF9, Valid),
+    (0x2D26, Disallowed), (0x2D27, Valid), (0x2D28, Disallowed), (0x2D2D, Valid),
+    (0x2D2E, Disallowed), (0x2D30, Valid), (0x2D68, Disallowed), (0x2D6F, Mapped("\u{2D61}")),
+    (0x2D70, Valid), (0x2D71, Disallowed), (0x2D7F, Valid), (0x2D97, Disallowed), (0x2DA0, Valid),
+    (0x2DA7, Disallowed), (0x2DA8, Valid), (0x2DAF, Disallowed), (0x2DB0, Valid),
+    (0x2DB7, Disallowed), (0x2DB8, Valid), (0x2DBF, Disallowed), (0x2DC0, Valid),
+    (0x2DC7, Disallowed), (0x2DC8, Valid), (0x2DCF, Disallowed), (0x2DD0, Valid),
+    (0x2DD7, Disallowed), (0x2DD8, Valid), (0x2DDF, Disallowed), (0x2DE0, Valid),
+    (0x2E53, Disallowed), (0x2E80, Valid), (0x2E9A, Disallowed), (0x2E9B, Valid),
+    (0x2E9F, Mapped("\u{6BCD}")), (0x2EA0, Valid), (0x2EF3, Mapped("\u{9F9F}")),
+    (0x2EF4, Disallowed), (0x2F00, Mapped("\u{4E00}")), (0x2F01, Mapped("\u{4E28}")),
+    (0x2F02, Mapped("\u{4E36}")), (0x2F03, Mapped("\u{4E3F}")), (0x2F04, Mapped("\u{4E59}")),
+    (0x2F05, Mapped("\u{4E85}")), (0x2F06, Mapped("\u{4E8C}")), (0x2F07, Mapped("\u{4EA0}")),
+    (0x2F08, Mapped("\u{4EBA}")), (0x2F09, Mapped("\u{513F}")), (0x2F0A, Mapped("\u{5165}")),
+    (0x2F0B, Mapped("\u{516B}")), (0x2F0C, Mapped("\u{5182}")), (0x2F0D, Mapped("\u{5196}")),
+    (0x2F0E, Mapped("\u{51AB}")), (0x2F0F, Mapped("\u{51E0}")), (0x2F10, Mapped("\u{51F5}")),
+    (0x2F11, Mapped("\u{5200}")), (0x2F12, Mapped("\u{529B}")), (0x2F13, Mapped("\u{52F9}")),
+    (0x2F14, Mapped("\u{5315}")), (0x2F15, Mapped("\u{531A}")), (0x2F16, Mapped("\u{5338}")),
+    (0x2F17, Mapped("\u{5341}")), (0x2F18, Mapped("\u{535C}")), (0x2F19, Mapped("\u{5369}")),
+    (0x2F1A, Mapped("\u{5382}")), (0x2F1B, Mapped("\u{53B6}")), (0x2F1C, Mapped("\u{53C8}")),
+    (0x2F1D, Mapped("\u{53E3}")), (0x2F1E, Mapped("\u{56D7}")), (0x2F1F, Mapped("\u{571F}")),
+    (0x2F20, Mapped("\u{58EB}")), (0x2F21, Mapped("\u{5902}")), (0x2F22, Mapped("\u{590A}")),
+    (0x2F23, Mapped("\u{5915}")), (0x2F24, Mapped("\u{5927}")), (0x2F25, Mapped("\u{5973}")),
+    (0x2F26, Mapped("\u{5B50}")), (0x2F27, Mapped("\u{5B80}")), (0x2F28, Mapped("\u{5BF8}")),
+    (0x2F29, Mapped("\u{5C0F}")), (0x2F2A, Mapped("\u{5C22}")), (0x2F2B, Mapped("\u{5C38}")),
+    (0x2F2C, Mapped("\u{5C6E}")), (0x2F2D, Mapped("\u{5C71}")), (0x2F2E, Mapped("\u{5DDB}")),
+    (0x2F2F, Mapped("\u{5DE5}")), (0x2F30, Mapped("\u{5DF1}")), (0x2F31, Mapped("\u{5DFE}")),
+    (0x2F32, Mapped("\u{5E72}")), (0x2F33, Mapped("\u{5E7A}")), (0x2F34, Mapped("\u{5E7F}")),
+    (0x2F35, Mapped("\u{5EF4}")), (0x2F36, Mapped("\u{5EFE}")), (0x2F37, Mapped("\u{5F0B}")),
+    (0x2F38, Mapped("\u{5F13}")), (0x2F39, Mapped("\u{5F50}")), (0x2F3A, Mapped("\u{5F61}")),
+    (0x2F3B, Mapped("\u{5F73}")), (0x2F3C, Mapped("\u{5FC3}")), (0x2F3D, Mapped("\u{6208}")),
+    (0x2F3E, Mapped("\u{6236}")), (0x2F3F, Mapped("\u{624B}")), (0x2F40, Mapped("\u{652F}")),
+    (0x2F41, Mapped("\u{6534}")), (0x2F42, Mapped("\u{6587}")), (0x2F43, Mapped("\u{6597}")),
+    (0x2F44, Mapped("\u{65A4}")), (0x2F45, Mapped("\u{65B9}")), (0x2F46, Mapped("\u{65E0}")),
+    (0x2F47, Mapped("\u{65E5}")), (0x2F48, Mapped("\u{66F0}")), (0x2F49, Mapped("\u{6708}")),
+    (0x2F4A, Mapped("\u{6728}")), (0x2F4B, Mapped("\u{6B20}")), (0x2F4C, Mapped("\u{6B62}")),
+    (0x2F4D, Mapped("\u{6B79}")), (0x2F4E, Mapped("\u{6BB3}")), (0x2F4F, Mapped("\u{6BCB}")),
+    (0x2F50, Mapped("\u{6BD4}")), (0x2F51, Mapped("\u{6BDB}")), (0x2F52, Mapped("\u{6C0F}")),
+    (0x2F53, Mapped("\u{6C14}")), (0x2F54, Mapped("\u{6C34}")), (0x2F55, Mapped("\u{706B}")),
+    (0x2F56, Mapped("\u{722A}")), (0x2F57, Mapped("\u{7236}")), (0x2F58, Mapped("\u{723B}")),
+    (0x2F59, Mapped("\u{723F}")), (0x2F5A, Mapped("\u{7247}")), (0x2F5B, Mapped("\u{7259}")),
+    (0x2F5C, Mapped("\u{725B}")), (0x2F5D, Mapped("\u{72AC}")), (0x2F5E, Mapped("\u{7384}")),
+    (0x2F5F, Mapped("\u{7389}")), (0x2F60, Mapped("\u{74DC}")), (0x2F61, Mapped("\u{74E6}")),
+    (0x2F62, Mapped("\u{7518}")), (0x2F63, Mapped("\u{751F}")), (0x2F64, Mapped("\u{7528}")),
+    (0x2F65, Mapped("\u{7530}")), (0x2F66, Mapped("\u{758B}")), (0x2F67, Mapped("\u{7592}")),
+    (0x2F68, Mapped("\u{7676}")), (0x2F69, Mapped("\u{767D}")), (0x2F6A, Mapped("\u{76AE}")),
+    (0x2F6B, Mapped("\u{76BF}")), (0x2F6C, Mapped("\u{76EE}")), (0x2F6D, Mapped("\u{77DB}")),
+    (0x2F6E, Mapped("\u{77E2}")), (0x2F6F, Mapped("\u{77F3}")), (0x2F70, Mapped("\u{793A}")),
+    (0x2F71, Mapped("\u{79B8}")), (0x2F72, Mapped("\u{79BE}")), (0x2F73, Mapped("\u{7A74}")),
+    (0x2F74, Mapped("\u{7ACB}")), (0x2F75, Mapped("\u{7AF9}")), (0x2F76, Mapped("\u{7C73}")),
+    (0x2F77, Mapped("\u{7CF8}")), (0x2F78, Mapped("\u{7F36}")), (0x2F79, Mapped("\u{7F51}")),
+    (0x2F7A, Mapped("\u{7F8A}")), (0x2F7B, Mapped("\u{7FBD}")), (0x2F7C, Mapped("\u{8001}")),
+    (0x2F7D, Mapped("\u{800C}")), (0x2F7E, Mapped("\u{8012}")), (0x2F7F, Mapped("\u{8033}")),
+    (0x2F80, Mapped("\u{807F}")), (0x2F81, Mapped("\u{8089}")), (0x2F82, Mapped("\u{81E3}")),
+    (0x2F83, Mapped("\u{81EA}")), (0x2F84, Mapped("\u{81F3}")), (0x2F85, Mapped("\u{81FC}")),
+    (0x2F86, Mapped("\u{820C}")), (0x2F87, Mapped("\u{821B}")), (0x2F88, Mapped("\u{821F}")),
+    (0x2F89, Mapped("\u{826E}")), (0x2F8A, Mapped("\u{8272}")), (0x2F8B, Mapped("\u{8278}")),
+    (0x2F8C, Mapped("\u{864D}")), (0x2F8D, Mapped("\u{866B}")), (0x2F8E, Mapped("\u{8840}")),
+    (0x2F8F, Mapped("\u{884C}")), (0x2F90, Mapped("\u{8863}")), (0x2F91, Mapped("\u{897E}")),
+    (0x2F92, Mapped("\u{898B}")), (0x2F93, Mapped("\u{89D2}")), (0x2F94, Mapped("\u{8A00}")),
+    (0x2F95, Mapped("\u{8C37}")), (0x2F96, Mapped("\u{8C46}")), (0x2F97, Mapped("\u{8C55}")),
+    (0x2F98, Mapped("\u{8C78}")), (0x2F99, Mapped("\u{8C9D}")), (0x2F9A, Mapped("\u{8D64}")),
+    (0x2F9B, Mapped("\u{8D70}")), (0x2F9C, Mapped("\u{8DB3}")), (0x2F9D, Mapped("\u{8EAB}")),
+    (0x2F9E, Mapped("\u{8ECA}")), (0x2F9F, Mapped("\u{8F9B}")), (0x2FA0, Mapped("\u{8FB0}")),
+    (0x2FA1, Mapped("\u{8FB5}")), (0x2FA2, Mapped("\u{9091}")), (0x2FA3, Mapped("\u{9149}")),
+    (0x2FA4, Mapped("\u{91C6}")), (0x2FA5, Mapped("\u{91CC}")), (0x2FA6, Mapped("\u{91D1}")),
+    (0x2FA7, Mapped("\u{9577}")), (0x2FA8, Mapped("\u{9580}")), (0x2FA9, Mapped("\u{961C}")),
+    (0x2FAA, Mapped("\u{96B6}")), (0x2FAB, Mapped("\u{96B9}")), (0x2FAC, Mapped("\u{96E8}")),
+    (0x2FAD, Mapped("\u{9751}")), (0x2FAE, Mapped("\u{975E}")), (0x2FAF, Mapped("\u{9762}")),
+    (0x2FB0, Mapped("\u{9769}")), (0x2FB1, Mapped("\u{97CB}")), (0x2FB2, Mapped("\u{97ED}")),
+    (0x2FB3, Mapped("\u{97F3}")), (0x2FB4, Mapped("\u{9801}")), (0x2FB5, Mapped("\u{98A8}")),
+    (0x2FB6, Mapped("\u{98DB}")), (0x2FB7, Mapped("\u{98DF}")), (0x2FB8, Mapped("\u{9996}")),
+    (0x2FB9, Mapped("\u{9999}")), (0x2FBA, Mapped("\u{99AC}")), (0x2FBB, Mapped("\u{9AA8}")),
+    (0x2FBC, Mapped("\u{9AD8}")), (0x2FBD, Mapped("\u{9ADF}")), (0x2FBE, Mapped("\u{9B25}")),
+    (0x2FBF, Mapped("\u{9B2F}")), (0x2FC0, Mapped("\u{9B32}")), (0x2FC1, Mapped("\u{9B3C}")),
+    (0x2FC2, Mapped("\u{9B5A}")), (0x2FC3, Mapped("\u{9CE5}")), (0x2FC4, Mapped("\u{9E75}")),
+    (0x2FC5, Mapped("\u{9E7F}")), (0x2FC6, Mapped("\u{9EA5}")), (0x2FC7, Mapped("\u{9EBB}")),
+    (0x2FC8, Mapped("\u{9EC3}")), (0x2FC9, Mapped("\u{9ECD}")), (0x2FCA, Mapped("\u{9ED1}")),
+    (0x2FCB, Mapped("\u{9EF9}")), (0x2FCC, Mapped("\u{9EFD}")), (0x2FCD, Mapped("\u{9F0E}")),
+    (0x2FCE, Mapped("\u{9F13}")), (0x2FCF, Mapped("\u{9F20}")), (0x2FD0, Mapped("\u{9F3B}")),
+    (0x2FD1, Mapped("\u{9F4A}")), (0x2FD2, Mapped("\u{9F52}")), (0x2FD3, Mapped("\u{9F8D}")),
+    (0x2FD4, Mapped("\u{9F9C}")), (0x2FD5, Mapped("\u{9FA0}")), (0x2FD6, Disallowed),
+    (0x3000, Mapped(" ")), (0x3001, Valid), (0x3002, Mapped(".")), (0x3003, Valid),
+    (0x3036, Mapped("\u{3012}")), (0x3037, Valid), (0x3038, Mapped("\u{5341}")),
+    (0x3039, Shifted(0x5344)), (0x303B, Valid), (0x3040, Disallowed), (0x3041, Valid),
+    (0x3097, Disallowed), (0x3099, Valid), (0x309B, Mapped(" \u{3099}")),
+    (0x309C, Mapped(" \u{309A}")), (0x309D, Valid), (0x309F, Mapped("\u{3088}\u{308A}")),
+    (0x30A0, Valid), (0x30FF, Mapped("\u{30B3}\u{30C8}")), (0x3100, Disallowed), (0x3105, Valid),
+    (0x3130, Disallowed), (0x3131, Shifted(0x1100)), (0x3133, Mapped("\u{11AA}")),
+    (0x3134, Mapped("\u{1102}")), (0x3135, Shifted(0x11AC)), (0x3137, Shifted(0x1103)),
+    (0x313A, Shifted(0x11B0)), (0x3140, Mapped("\u{111A}")), (0x3141, Shifted(0x1106)),
+    (0x3144, Mapped("\u{1121}")), (0x3145, Shifted(0x1109)), (0x314F, Shifted(0x1161)),
+    (0x3164, Disallowed), (0x3165, Shifted(0x1114)), (0x3167, Shifted(0x11C7)),
+    (0x3169, Mapped("\u{11CC}")), (0x316A, Mapped("\u{11CE}")), (0x316B, Mapped("\u{11D3}")),
+    (0x316C, Mapped("\u{11D7}")), (0x316D, Mapped("\u{11D9}")), (0x316E, Mapped("\u{111C}")),
+    (0x316F, Mapped("\u{11DD}")), (0x3170, Mapped("\u{11DF}")), (0x3171, Shifted(0x111D)),
+    (0x3173, Mapped("\u{1120}")), (0x3174, Shifted(0x1122)), (0x3176, Mapped("\u{1127}")),
+    (0x3177, Mapped("\u{1129}")), (0x3178, Shifted(0x112B)), (0x317D, Mapped("\u{1132}")),
+    (0x317E, Mapped("\u{1136}")), (0x317F, Mapped("\u{1140}")), (0x3180, Mapped("\u{1147}")),
+    (0x3181, Mapped("\u{114C}")), (0x3182, Shifted(0x11F1)), (0x3184, Shifted(0x1157)),
+    (0x3187, Shifted(0x1184)), (0x3189, Mapped("\u{1188}")), (0x318A, Shifted(0x1191)),
+    (0x318C, Mapped("\u{1194}")), (0x318D, Mapped("\u{119E}")), (0x318E, Mapped("\u{11A1}")),
+    (0x318F, Disallowed), (0x3190, Valid), (0x3192, Mapped("\u{4E00}")),
+    (0x3193, Mapped("\u{4E8C}")), (0x3194, Mapped("\u{4E09}")), (0x3195, Mapped("\u{56DB}")),
+    (0x3196, Mapped("\u{4E0A}")), (0x3197, Mapped("\u{4E2D}")), (0x3198, Mapped("\u{4E0B}")),
+    (0x3199, Mapped("\u{7532}")), (0x319A, Mapped("\u{4E59}")), (0x319B, Mapped("\u{4E19}")),
+    (0x319C, Mapped("\u{4E01}")), (0x319D, Mapped("\u{5929}")), (0x319E, Mapped("\u{5730}")),
+    (0x319F, Mapped("\u{4EBA}")), (0x31A0, Valid), (0x31E4, Disallowed), (0x31F0, Valid),
+    (0x3200, Mapped("(\u{1100})")), (0x3201, Mapped("(\u{1102})")), (0x3202, Mapped("(\u{1103})")),
+    (0x3203, Mapped("(\u{1105})")), (0x3204, Mapped("(\u{1106})")), (0x3205, Mapped("(\u{1107})")),
+    (0x3206, Mapped("(\u{1109})")), (0x3207, Mapped("(\u{110B})")), (0x3208, Mapped("(\u{110C})")),
+    (0x3209, Mapped("(\u{110E})")), (0x320A, Mapped("(\u{110F})")), (0x320B, Mapped("(\u{1110})")),
+    (0x320C, Mapped("(\u{1111})")), (0x320D, Mapped("(\u{1112})")), (0x320E, Mapped("(\u{AC00})")),
+    (0x320F, Mapped("(\u{B098})")), (0x3210, Mapped("(\u{B2E4})")), (0x3211, Mapped("(\u{B77C})")),
+    (0x3212, Mapped("(\u{B9C8})")), (0x3213, Mapped("(\u{BC14})")), (0x3214, Mapped("(\u{C0AC})")),
+    (0x3215, Mapped("(\u{C544})")), (0x3216, Mapped("(\u{C790})")), (0x3217, Mapped("(\u{CC28})")),
+    (0x3218, Mapped("(\u{CE74})")), (0x3219, Mapped("(\u{D0C0})")), (0x321A, Mapped("(\u{D30C})")),
+    (0x321B, Mapped("(\u{D558})")), (0x321C, Mapped("(\u{C8FC})")),
+    (0x321D, Mapped("(\u{C624}\u{C804})")), (0x321E, Mapped("(\u{C624}\u{D6C4})")),
+    (0x321F, Disallowed), (0x3220, Mapped("(\u{4E00})")), (0x3221, Mapped("(\u{4E8C})")),
+    (0x3222, Mapped("(\u{4E09})")), (0x3223, Mapped("(\u{56DB})")), (0x3224, Mapped("(\u{4E94})")),
+    (0x3225, Mapped("(\u{516D})")), (0x3226, Mapped("(\u{4E03})")), (0x3227, Mapped("(\u{516B})")),
+    (0x3228, Mapped("(\u{4E5D})")), (0x3229, Mapped("(\u{5341})")), (0x322A, Mapped("(\u{6708})")),
+    (0x322B, Mapped("(\u{706B})")), (0x322C, Mapped("(\u{6C34})")), (0x322D, Mapped("(\u{6728})")),
+    (0x322E, Mapped("(\u{91D1})")), (0x322F, Mapped("(\u{571F})")), (0x3230, Mapped("(\u{65E5})")),
+    (0x3231, Mapped("(\u{682A})")), (0x3232, Mapped("(\u{6709})")), (0x3233, Mapped("(\u{793E})")),
+    (0x3234, Mapped("(\u{540D})")), (0x3235, Mapped("(\u{7279})")), (0x3236, Mapped("(\u{8CA1})")),
+    (0x3237, Mapped("(\u{795D})")), (0x3238, Mapped("(\u{52B4})")), (0x3239, Mapped("(\u{4EE3})")),
+    (0x323A, Mapped("(\u{547C})")), (0x323B, Mapped("(\u{5B66})")), (0x323C, Mapped("(\u{76E3})")),
+    (0x323D, Mapped("(\u{4F01})")), (0x323E, Mapped("(\u{8CC7})")), (0x323F, Mapped("(\u{5354})")),
+    (0x3240, Mapped("(\u{796D})")), (0x3241, Mapped("(\u{4F11})")), (0x3242, Mapped("(\u{81EA})")),
+    (0x3243, Mapped("(\u{81F3})")), (0x3244, Mapped("\u{554F}")), (0x3245, Mapped("\u{5E7C}")),
+    (0x3246, Mapped("\u{6587}")), (0x3247, Mapped("\u{7B8F}")), (0x3248, Valid),
+    (0x3250, Mapped("pte")), (0x3251, Mapped("21")), (0x3252, Mapped("22")), (0x3253, Mapped("23")),
+    (0x3254, Mapped("24")), (0x3255, Mapped("25")), (0x3256, Mapped("26")), (0x3257, Mapped("27")),
+    (0x3258, Mapped("28")), (0x3259, Mapped("29")), (0x325A, Mapped("30")), (0x325B, Mapped("31")),
+    (0x325C, Mapped("32")), (0x325D, Mapped("33")), (0x325E, Mapped("34")), (0x325F, Mapped("35")),
+    (0x3260, Mapped("\u{1100}")), (0x3261, Shifted(0x1102)), (0x3263, Shifted(0x1105)),
+    (0x3266, Mapped("\u{1109}")), (0x3267, Shifted(0x110B)), (0x3269, Shifted(0x110E)),
+    (0x326E, Mapped("\u{AC00}")), (0x326F, Mapped("\u{B098}")), (0x3270, Mapped("\u{B2E4}")),
+    (0x3271, Mapped("\u{B77C}")), (0x3272, Mapped("\u{B9C8}")), (0x3273, Mapped("\u{BC14}")),
+    (0x3274, Mapped("\u{C0AC}")), (0x3275, Mapped("\u{C544}")), (0x3276, Mapped("\u{C790}")),
+    (0x3277, Mapped("\u{CC28}")), (0x3278, Mapped("\u{CE74}")), (0x3279, Mapped("\u{D0C0}")),
+    (0x327A, Mapped("\u{D30C}")), (0x327B, Mapped("\u{D558}")),
+    (0x327C, Mapped("\u{CC38}\u{ACE0}")), (0x327D, Mapped("\u{C8FC}\u{C758}")),
+    (0x327E, Mapped("\u{C6B0}")), (0x327F, Valid), (0x3280, Mapped("\u{4E00}")),
+    (0x3281, Mapped("\u{4E8C}")), (0x3282, Mapped("\u{4E09}")), (0x3283, Mapped("\u{56DB}")),
+    (0x3284, Mapped("\u{4E94}")), (0x3285, Mapped("\u{516D}")), (0x3286, Mapped("\u{4E03}")),
+    (0x3287, Mapped("\u{516B}")), (0x3288, Mapped("\u{4E5D}")), (0x3289, Mapped("\u{5341}")),
+    (0x328A, Mapped("\u{6708}")), (0x328B, Mapped("\u{706B}")), (0x328C, Mapped("\u{6C34}")),
+    (0x328D, Mapped("\u{6728}")), (0x328E, Mapped("\u{91D1}")), (0x328F, Mapped("\u{571F}")),
+    (0x3290, Mapped("\u{65E5}")), (0x3291, Mapped("\u{682A}")), (0x3292, Mapped("\u{6709}")),
+    (0x3293, Mapped("\u{793E}")), (0x3294, Mapped("\u{540D}")), (0x3295, Mapped("\u{7279}")),
+    (0x3296, Mapped("\u{8CA1}")), (0x3297, Mapped("\u{795D}")), (0x3298, Mapped("\u{52B4}")),
+    (0x3299, Mapped("\u{79D8}")), (0x329A, Mapped("\u{7537}")), (0x329B, Mapped("\u{5973}")),
+    (0x329C, Mapped("\u{9069}")), (0x329D, Mapped("\u{512A}")), (0x329E, Mapped("\u{5370}")),
+    (0x329F, Mapped("\u{6CE8}")), (0x32A0, Mapped("\u{9805}")), (0x32A1, Mapped("\u{4F11}")),
+    (0x32A2, Mapped("\u{5199}")), (0x32A3, Mapped("\u{6B63}")), (0x32A4, Mapped("\u{4E0A}")),
+    (0x32A5, Mapped("\u{4E2D}")), (0x32A6, Mapped("\u{4E0B}")), (0x32A7, Mapped("\u{5DE6}")),
+    (0x32A8, Mapped("\u{53F3}")), (0x32A9, Mapped("\u{533B}")), (0x32AA, Mapped("\u{5B97}")),
+    (0x32AB, Mapped("\u{5B66}")), (0x32AC, Mapped("\u{76E3}")), (0x32AD, Mapped("\u{4F01}")),
+    (0x32AE, Mapped("\u{8CC7}")), (0x32AF, Mapped("\u{5354}")), (0x32B0, Mapped("\u{591C}")),
+    (0x32B1, Mapped("36")), (0x32B2, Mapped("37")), (0x32B3, Mapped("38")), (0x32B4, Mapped("39")),
+    (0x32B5, Mapped("40")), (0x32B6, Mapped("41")), (0x32B7, Mapped("42")), (0x32B8, Mapped("43")),
+    (0x32B9, Mapped("44")), (0x32BA, Mapped("45")), (0x32BB, Mapped("46")), (0x32BC, Mapped("47")),
+    (0x32BD, Mapped("48")), (0x32BE, Mapped("49")), (0x32BF, Mapped("50")),
+    (0x32C0, Mapped("1\u{6708}")), (0x32C1, Mapped("2\u{6708}")), (0x32C2, Mapped("3\u{6708}")),
+    (0x32C3, Mapped("4\u{6708}")), (0x32C4, Mapped("5\u{6708}")), (0x32C5, Mapped("6\u{6708}")),
+    (0x32C6, Mapped("7\u{6708}")), (0x32C7, Mapped("8\u{6708}")), (0x32C8, Mapped("9\u{6708}")),
+    (0x32C9, Mapped("10\u{6708}")), (0x32CA, Mapped("11\u{6708}")), (0x32CB, Mapped("12\u{6708}")),
+    (0x32CC, Mapped("hg")), (0x32CD, Mapped("erg")), (0x32CE, Mapped("ev")),
+    (0x32CF, Mapped("ltd")), (0x32D0, Mapped("\u{30A2}")), (0x32D1, Mapped("\u{30A4}")),
+    (0x32D2, Mapped("\u{30A6}")), (0x32D3, Mapped("\u{30A8}")), (0x32D4, Shifted(0x30AA)),
+    (0x32D6, Mapped("\u{30AD}")), (0x32D7, Mapped("\u{30AF}")), (0x32D8, Mapped("\u{30B1}")),
+    (0x32D9, Mapped("\u{30B3}")), (0x32DA, Mapped("\u{30B5}")), (0x32DB, Mapped("\u{30B7}")),
+    (0x32DC, Mapped("\u{30B9}")), (0x32DD, Mapped("\u{30BB}")), (0x32DE, Mapped("\u{30BD}")),
+    (0x32DF, Mapped("\u{30BF}")), (0x32E0, Mapped("\u{30C1}")), (0x32E1, Mapped("\u{30C4}")),
+    (0x32E2, Mapped("\u{30C6}")), (0x32E3, Mapped("\u{30C8}")), (0x32E4, Shifted(0x30CA)),
+    (0x32EA, Mapped("\u{30D2}")), (0x32EB, Mapped("\u{30D5}")), (0x32EC, Mapped("\u{30D8}")),
+    (0x32ED, Mapped("\u{30DB}")), (0x32EE, Shifted(0x30DE)), (0x32F3, Mapped("\u{30E4}")),
+    (0x32F4, Mapped("\u{30E6}")), (0x32F5, Shifted(0x30E8)), (0x32FB, Shifted(0x30EF)),
+    (0x32FF, Mapped("\u{4EE4}\u{548C}")), (0x3300, Mapped("\u{30A2}\u{30D1}\u{30FC}\u{30C8}")),
+    (0x3301, Mapped("\u{30A2}\u{30EB}\u{30D5}\u{30A1}")),
+    (0x3302, Mapped("\u{30A2}\u{30F3}\u{30DA}\u{30A2}")),
+    (0x3303, Mapped("\u{30A2}\u{30FC}\u{30EB}")),
+    (0x3304, Mapped("\u{30A4}\u{30CB}\u{30F3}\u{30B0}")),
+    (0x3305, Mapped("\u{30A4}\u{30F3}\u{30C1}")), (0x3306, Mapped("\u{30A6}\u{30A9}\u{30F3}")),
+    (0x3307, Mapped("\u{30A8}\u{30B9}\u{30AF}\u{30FC}\u{30C9}")),
+    (0x3308, Mapped("\u{30A8}\u{30FC}\u{30AB}\u{30FC}")),
+    (0x3309, Mapped("\u{30AA}\u{30F3}\u{30B9}")), (0x330A, Mapped("\u{30AA}\u{30FC}\u{30E0}")),
+    (0x330B, Mapped("\u{30AB}\u{30A4}\u{30EA}")),
+    (0x330C, Mapped("\u{30AB}\u{30E9}\u{30C3}\u{30C8}")),
+    (0x330D, Mapped("\u{30AB}\u{30ED}\u{30EA}\u{30FC}")),
+    (0x330E, Mapped("\u{30AC}\u{30ED}\u{30F3}")), (0x330F, Mapped("\u{30AC}\u{30F3}\u{30DE}")),
+    (0x3310, Mapped("\u{30AE}\u{30AC}")), (0x3311, Mapped("\u{30AE}\u{30CB}\u{30FC}")),
+    (0x3312, Mapped("\u{30AD}\u{30E5}\u{30EA}\u{30FC}")),
+    (0x3313, Mapped("\u{30AE}\u{30EB}\u{30C0}\u{30FC}")), (0x3314, Mapped("\u{30AD}\u{30ED}")),
+    (0x3315, Mapped("\u{30AD}\u{30ED}\u{30B0}\u{30E9}\u{30E0}")),
+    (0x3316, Mapped("\u{30AD}\u{30ED}\u{30E1}\u{30FC}\u{30C8}\u{30EB}")),
+    (0x3317, Mapped("\u{30AD}\u{30ED}\u{30EF}\u{30C3}\u{30C8}")),
+    (0x3318, Mapped("\u{30B0}\u{30E9}\u{30E0}")),
+    (0x3319, Mapped("\u{30B0}\u{30E9}\u{30E0}\u{30C8}\u{30F3}")),
+    (0x331A, Mapped("\u{30AF}\u{30EB}\u{30BC}\u{30A4}\u{30ED}")),
+    (0x331B, Mapped("\u{30AF}\u{30ED}\u{30FC}\u{30CD}")),
+    (0x331C, Mapped("\u{30B1}\u{30FC}\u{30B9}")), (0x331D, Mapped("\u{30B3}\u{30EB}\u{30CA}")),
+    (0x331E, Mapped("\u{30B3}\u{30FC}\u{30DD}")),
+    (0x331F, Mapped("\u{30B5}\u{30A4}\u{30AF}\u{30EB}")),
+    (0x3320, Mapped("\u{30B5}\u{30F3}\u{30C1}\u{30FC}\u{30E0}")),
+    (0x3321, Mapped("\u{30B7}\u{30EA}\u{30F3}\u{30B0}")),
+    (0x3322, Mapped("\u{30BB}\u{30F3}\u{30C1}")), (0x3323, Mapped("\u{30BB}\u{30F3}\u{30C8}")),
+    (0x3324, Mapped("\u{30C0}\u{30FC}\u{30B9}")), (0x3325, Mapped("\u{30C7}\u{30B7}")),
+    (0x3326, Mapped("\u{30C9}\u{30EB}")), (0x3327, Mapped("\u{30C8}\u{30F3}")),
+    (0x3328, Mapped("\u{30CA}\u{30CE}")), (0x3329, Mapped("\u{30CE}\u{30C3}\u{30C8}")),
+    (0x332A, Mapped("\u{30CF}\u{30A4}\u{30C4}")),
+    (0x332B, Mapped("\u{30D1}\u{30FC}\u{30BB}\u{30F3}\u{30C8}")),
+    (0x332C, Mapped("\u{30D1}\u{30FC}\u{30C4}")),
+    (0x332D, Mapped("\u{30D0}\u{30FC}\u{30EC}\u{30EB}")),
+    (0x332E, Mapped("\u{30D4}\u{30A2}\u{30B9}\u{30C8}\u{30EB}")),
+    (0x332F, Mapped("\u{30D4}\u{30AF}\u{30EB}")), (0x3330, Mapped("\u{30D4}\u{30B3}")),
+    (0x3331, Mapped("\u{30D3}\u{30EB}")),
+    (0x3332, Mapped("\u{30D5}\u{30A1}\u{30E9}\u{30C3}\u{30C9}")),
+    (0x3333, Mapped("\u{30D5}\u{30A3}\u{30FC}\u{30C8}")),
+    (0x3334, Mapped("\u{30D6}\u{30C3}\u{30B7}\u{30A7}\u{30EB}")),
+    (0x3335, Mapped("\u{30D5}\u{30E9}\u{30F3}")),
+    (0x3336, Mapped("\u{30D8}\u{30AF}\u{30BF}\u{30FC}\u{30EB}")),
+    (0x3337, Mapped("\u{30DA}\u{30BD}")), (0x3338, Mapped("\u{30DA}\u{30CB}\u{30D2}")),
+    (0x3339, Mapped("\u{30D8}\u{30EB}\u{30C4}")), (0x333A, Mapped("\u{30DA}\u{30F3}\u{30B9}")),
+    (0x333B, Mapped("\u{30DA}\u{30FC}\u{30B8}")), (0x333C, Mapped("\u{30D9}\u{30FC}\u{30BF}")),
+    (0x333D, Mapped("\u{30DD}\u{30A4}\u{30F3}\u{30C8}")),
+    (0x333E, Mapped("\u{30DC}\u{30EB}\u{30C8}")), (0x333F, Mapped("\u{30DB}\u{30F3}")),
+    (0x3340, Mapped("\u{30DD}\u{30F3}\u{30C9}")), (0x3341, Mapped("\u{30DB}\u{30FC}\u{30EB}")),
+    (0x3342, Mapped("\u{30DB}\u{30FC}\u{30F3}")),
+    (0x3343, Mapped("\u{30DE}\u{30A4}\u{30AF}\u{30ED}")),
+    (0x3344, Mapped("\u{30DE}\u{30A4}\u{30EB}")), (0x3345, Mapped("\u{30DE}\u{30C3}\u{30CF}")),
+    (0x3346, Mapped("\u{30DE}\u{30EB}\u{30AF}")),
+    (0x3347, Mapped("\u{30DE}\u{30F3}\u{30B7}\u{30E7}\u{30F3}")),
+    (0x3348, Mapped("\u{30DF}\u{30AF}\u{30ED}\u{30F3}")), (0x3349, Mapped("\u{30DF}\u{30EA}")),
+    (0x334A, Mapped("\u{30DF}\u{30EA}\u{30D0}\u{30FC}\u{30EB}")),
+    (0x334B, Mapped("\u{30E1}\u{30AC}")), (0x334C, Mapped("\u{30E1}\u{30AC}\u{30C8}\u{30F3}")),
+    (0x334D, Mapped("\u{30E1}\u{30FC}\u{30C8}\u{30EB}")),
+    (0x334E, Mapped("\u{30E4}\u{30FC}\u{30C9}")), (0x334F, Mapped("\u{30E4}\u{30FC}\u{30EB}")),
+    (0x3350, Mapped("\u{30E6}\u{30A2}\u{30F3}")),
+    (0x3351, Mapped("\u{30EA}\u{30C3}\u{30C8}\u{30EB}")), (0x3352, Mapped("\u{30EA}\u{30E9}")),
+    (0x3353, Mapped("\u{30EB}\u{30D4}\u{30FC}")),
+    (0x3354, Mapped("\u{30EB}\u{30FC}\u{30D6}\u{30EB}")), (0x3355, Mapped("\u{30EC}\u{30E0}")),
+    (0x3356, Mapped("\u{30EC}\u{30F3}\u{30C8}\u{30B2}\u{30F3}")),
+    (0x3357, Mapped("\u{30EF}\u{30C3}\u{30C8}")), (0x3358, Mapped("0\u{70B9}")),
+    (0x3359, Mapped("1\u{70B9}")), (0x335A, Mapped("2\u{70B9}")), (0x335B, Mapped("3\u{70B9}")),
+    (0x335C, Mapped("4\u{70B9}")), (0x335D, Mapped("5\u{70B9}")), (0x335E, Mapped("6\u{70B9}")),
+    (0x335F, Mapped("7\u{70B9}")), (0x3360, Mapped("8\u{70B9}")), (0x3361, Mapped("9\u{70B9}")),
+    (0x3362, Mapped("10\u{70B9}")), (0x3363, Mapped("11\u{70B9}")), (0x3364, Mapped("12\u{70B9}")),
+    (0x3365, Mapped("13\u{70B9}")), (0x3366, Mapped("14\u{70B9}")), (0x3367, Mapped("15\u{70B9}")),
+    (0x3368, Mapped("16\u{70B9}")), (0x3369, Mapped("17\u{70B9}")), (0x336A, Mapped("18\u{70B9}")),
+    (0x336B, Mapped("19\u{70B9}")), (0x336C, Mapped("20\u{70B9}")), (0x336D, Mapped("21\u{70B9}")),
+    (0x336E, Mapped("22\u{70B9}")), (0x336F, Mapped("23\u{70B9}")), (0x3370, Mapped("24\u{70B9}")),
+    (0x3371, Mapped("hpa")), (0x3372, Mapped("da")), (0x3373, Mapped("au")),
+    (0x3374, Mapped("bar")), (0x3375, Mapped("ov")), (0x3376, Mapped("pc")), (0x3377, Mapped("dm")),
+    (0x3378, Mapped("dm2")), (0x3379, Mapped("dm3")), (0x337A, Mapped("iu")),
+    (0x337B, Mapped("\u{5E73}\u{6210}")), (0x337C, Mapped("\u{662D}\u{548C}")),
+    (0x337D, Mapped("\u{5927}\u{6B63}")), (0x337E, Mapped("\u{660E}\u{6CBB}")),
+    (0x337F, Mapped("\u{682A}\u{5F0F}\u{4F1A}\u{793E}")), (0x3380, Mapped("pa")),
+    (0x3381, Mapped("na")), (0x3382, Mapped("\u{3BC}a")), (0x3383, Mapped("ma")),
+    (0x3384, Mapped("ka")), (0x3385, Mapped("kb")), (0x3386, Mapped("mb")), (0x3387, Mapped("gb")),
+    (0x3388, Mapped("cal")), (0x3389, Mapped("kcal")), (0x338A, Mapped("pf")),
+    (0x338B, Mapped("nf")), (0x338C, Mapped("\u{3BC}f")), (0x338D, Mapped("\u{3BC}g")),
+    (0x338E, Mapped("mg")), (0x338F, Mapped("kg")), (0x3390, Mapped("hz")), (0x3391, Mapped("khz")),
+    (0x3392, Mapped("mhz")), (0x3393, Mapped("ghz")), (0x3394, Mapped("thz")),
+    (0x3395, Mapped("\u{3BC}l")), (0x3396, Mapped("ml")), (0x3397, Mapped("dl")),
+    (0x3398, Mapped("kl")), (0x3399, Mapped("fm")), (0x339A, Mapped("nm")),
+    (0x339B, Mapped("\u{3BC}m")), (0x339C, Mapped("mm")), (0x339D, Mapped("cm")),
+    (0x339E, Mapped("km")), (0x339F, Mapped("mm2")), (0x33A0, Mapped("cm2")),
+    (0x33A1, Mapped("m2")), (0x33A2, Mapped("km2")), (0x33A3, Mapped("mm3")),
+    (0x33A4, Mapped("cm3")), (0x33A5, Mapped("m3")), (0x33A6, Mapped("km3")),
+    (0x33A7, Mapped("m\u{2215}s")), (0x33A8, Mapped("m\u{2215}s2")), (0x33A9, Mapped("pa")),
+    (0x33AA, Mapped("kpa")), (0x33AB, Mapped("mpa")), (0x33AC, Mapped("gpa")),
+    (0x33AD, Mapped("rad")), (0x33AE, Mapped("rad\u{2215}s")), (0x33AF, Mapped("rad\u{2215}s2")),
+    (0x33B0, Mapped("ps")), (0x33B1, Mapped("ns")), (0x33B2, Mapped("\u{3BC}s")),
+    (0x33B3, Mapped("ms")), (0x33B4, Mapped("pv")), (0x33B5, Mapped("nv")),
+    (0x33B6, Mapped("\u{3BC}v")), (0x33B7, Mapped("mv")), (0x33B8, Mapped("kv")),
+    (0x33B9, Mapped("mv")), (0x33BA, Mapped("pw")), (0x33BB, Mapped("nw")),
+    (0x33BC, Mapped("\u{3BC}w")), (0x33BD, Mapped("mw")), (0x33BE, Mapped("kw")),
+    (0x33BF, Mapped("mw")), (0x33C0, Mapped("k\u{3C9}")), (0x33C1, Mapped("m\u{3C9}")),
+    (0x33C2, Disallowed), (0x33C3, Mapped("bq")), (0x33C4, Mapped("cc")), (0x33C5, Mapped("cd")),
+    (0x33C6, Mapped("c\u{2215}kg")), (0x33C7, Disallowed), (0x33C8, Mapped("db")),
+    (0x33C9, Mapped("gy")), (0x33CA, Mapped("ha")), (0x33CB, Mapped("hp")), (0x33CC, Mapped("in")),
+    (0x33CD, Mapped("kk")), (0x33CE, Mapped("km")), (0x33CF, Mapped("kt")), (0x33D0, Mapped("lm")),
+    (0x33D1, Mapped("ln")), (0x33D2, Mapped("log")), (0x33D3, Mapped("lx")), (0x33D4, Mapped("mb")),
+    (0x33D5, Mapped("mil")), (0x33D6, Mapped("mol")), (0x33D7, Mapped("ph")), (0x33D8, Disallowed),
+    (0x33D9, Mapped("ppm")), (0x33DA, Mapped("pr")), (0x33DB, Mapped("sr")), (0x33DC, Mapped("sv")),
+    (0x33DD, Mapped("wb")), (0x33DE, Mapped("v\u{2215}m")), (0x33DF, Mapped("a\u{2215}m")),
+    (0x33E0, Mapped("1\u{65E5}")), (0x33E1, Mapped("2\u{65E5}")), (0x33E2, Mapped("3\u{65E5}")),
+    (0x33E3, Mapped("4\u{65E5}")), (0x33E4, Mapped("5\u{65E5}")), (0x33E5, Mapped("6\u{65E5}")),
+    (0x33E6, Mapped("7\u{65E5}")), (0x33E7, Mapped("8\u{65E5}")), (0x33E8, Mapped("9\u{65E5}")),
+    (0x33E9, Mapped("10\u{65E5}")), (0x33EA, Mapped("11\u{65E5}")), (0x33EB, Mapped("12\u{65E5}")),
+    (0x33EC, Mapped("13\u{65E5}")), (0x33ED, Mapped("14\u{65E5}")), (0x33EE, Mapped("15\u{65E5}")),
+    (0x33EF, Mapped("16\u{65E5}")), (0x33F0, Mapped("17\u{65E5}")), (0x33F1, Mapped("18\u{65E5}")),
+    (0x33F2, Mapped("19\u{65E5}")), (0x33F3, Mapped("20\u{65E5}")), (0x33F4, Mapped("21\u{65E5}")),
+    (0x33F5, Mapped("22\u{65E5}")), (0x33F6, Mapped("23\u{65E5}")), (0x33F7, Mapped("24\u{65E5}")),
+    (0x33F8, Mapped("25\u{65E5}")), (0x33F9, Mapped("26\u{65E5}")), (0x33FA, Mapped("27\u{65E5}")),
+    (0x33FB, Mapped("28\u{65E5}")), (0x33FC, Mapped("29\u{65E5}")), (0x33FD, Mapped("30\u{65E5}")),
+    (0x33FE, Mapped("31\u{65E5}")), (0x33FF, Mapped("gal")), (0x3400, Valid), (0x9FFD, Disallowed),
+    (0xA000, Valid), (0xA48D, Disallowed), (0xA490, Valid), (0xA4C7, Disallowed), (0xA4D0, Valid),
+    (0xA62C, Disallowed), (0xA640, Mapped("\u{A641}")), (0xA641, Valid),
+    (0xA642, Mapped("\u{A643}")), (0xA643, Valid), (0xA644, Mapped("\u{A645}")), (0xA645, Valid),
+    (0xA646, Mapped("\u{A647}")), (0xA647, Valid), (0xA648, Mapped("\u{A649}")), (0xA649, Valid),
+    (0xA64A, Mapped("\u{A64B}")), (0xA64B, Valid), (0xA64C, Mapped("\u{A64D}")), (0xA64D, Valid),
+    (0xA64E, Mapped("\u{A64F}")), (0xA64F, Valid), (0xA650, Mapped("\u{A651}")), (0xA651, Valid),
+    (0xA652, Mapped("\u{A653}")), (0xA653, Valid), (0xA654, Mapped("\u{A655}")), (0xA655, Valid),
+    (0xA656, Mapped("\u{A657}")), (0xA657, Valid), (0xA658, Mapped("\u{A659}")), (0xA659, Valid),
+    (0xA65A, Mapped("\u{A65B}")), (0xA65B, Valid), (0xA65C, Mapped("\u{A65D}")), (0xA65D, Valid),
+    (0xA65E, Mapped("\u{A65F}")), (0xA65F, Valid), (0xA660, Mapped("\u{A661}")), (0xA661, Valid),
+    (0xA662, Mapped("\u{A663}")), (0xA663, Valid), (0xA664, Mapped("\u{A665}")), (0xA665, Valid),
+    (0xA666, Mapped("\u{A667}")), (0xA667, Valid), (0xA668, Mapped("\u{A669}")), (0xA669, Valid),
+    (0xA66A, Mapped("\u{A66B}")), (0xA66B, Valid), (0xA66C, Mapped("\u{A66D}")), (0xA66D, Valid),
+    (0xA680, Mapped("\u{A681}")), (0xA681, Valid), (0xA682, Mapped("\u{A683}")), (0xA683, Valid),
+    (0xA684, Mapped("\u{A685}")), (0xA685, Valid), (0xA686, Mapped("\u{A687}")), (0xA687, Valid),
+    (0xA688, Mapped("\u{A689}")), (0xA689, Valid), (0xA68A, Mapped("\u{A68B}")), (0xA68B, Valid),
+    (0xA68C, Mapped("\u{A68D}")), (0xA68D, Valid), (0xA68E, Mapped("\u{A68F}")), (0xA68F, Valid),
+    (0xA690, Mapped("\u{A691}")), (0xA691, Valid), (0xA692, Mapped("\u{A693}")), (0xA693, Valid),
+    (0xA694, Mapped("\u{A695}")), (0xA695, Valid), (0xA696, Mapped("\u{A697}")), (0xA697, Valid),
+    (0xA698, Mapped("\u{A699}")), (0xA699, Valid), (0xA69A, Mapped("\u{A69B}")), (0xA69B, Valid),
+    (0xA69C, Mapped("\u{44A}")), (0xA69D, Mapped("\u{44C}")), (0xA69E, Valid), (0xA6F8, Disallowed),
+    (0xA700, Valid), (0xA722, Mapped("\u{A723}")), (0xA723, Valid), (0xA724, Mapped("\u{A725}")),
+    (0xA725, Valid), (0xA726, Mapped("\u{A727}")), (0xA727, Valid), (0xA728, Mapped("\u{A729}")),
+    (0xA729, Valid), (0xA72A, Mapped("\u{A72B}")), (0xA72B, Valid), (0xA72C, Mapped("\u{A72D}")),
+    (0xA72D, Valid), (0xA72E, Mapped("\u{A72F}")), (0xA72F, Valid), (0xA732, Mapped("\u{A733}")),
+    (0xA733, Valid), (0xA734, Mapped("\u{A735}")), (0xA735, Valid), (0xA736, Mapped("\u{A737}")),
+    (0xA737, Valid), (0xA738, Mapped("\u{A739}")), (0xA739, Valid), (0xA73A, Mapped("\u{A73B}")),
+    (0xA73B, Valid), (0xA73C, Mapped("\u{A73D}")), (0xA73D, Valid), (0xA73E, Mapped("\u{A73F}")),
+    (0xA73F, Valid), (0xA740, Mapped("\u{A741}")), (0xA741, Valid), (0xA742, Mapped("\u{A743}")),
+    (0xA743, Valid), (0xA744, Mapped("\u{A745}")), (0xA745, Valid), (0xA746, Mapped("\u{A747}")),
+    (0xA747, Valid), (0xA748, Mapped("\u{A749}")), (0xA749, Valid), (0xA74A, Mapped("\u{A74B}")),
+    (0xA74B, Valid), (0xA74C, Mapped("\u{A74D}")), (0xA74D, Valid), (0xA74E, Mapped("\u{A74F}")),
+    (0xA74F, Valid), (0xA750, Mapped("\u{A751}")), (0xA751, Valid), (0xA752, Mapped("\u{A753}")),
+    (0xA753, Valid), (0xA754, Mapped("\u{A755}")), (0xA755, Valid), (0xA756, Mapped("\u{A757}")),
+    (0xA757, Valid), (0xA758, Mapped("\u{A759}")), (0xA759, Valid), (0xA75A, Mapped("\u{A75B}")),
+    (0xA75B, Valid), (0xA75C, Mapped("\u{A75D}")), (0xA75D, Valid), (0xA75E, Mapped("\u{A75F}")),
+    (0xA75F, Valid), (0xA760, Mapped("\u{A761}")), (0xA761, Valid), (0xA762, Mapped("\u{A763}")),
+    (0xA763, Valid), (0xA764, Mapped("\u{A765}")), (0xA765, Valid), (0xA766, Mapped("\u{A767}")),
+    (0xA767, Valid), (0xA768, Mapped("\u{A769}")), (0xA769, Valid), (0xA76A, Mapped("\u{A76B}")),
+    (0xA76B, Valid), (0xA76C, Mapped("\u{A76D}")), (0xA76D, Valid), (0xA76E, Mapped("\u{A76F}")),
+    (0xA76F, Valid), (0xA770, Mapped("\u{A76F}")), (0xA771, Valid), (0xA779, Mapped("\u{A77A}")),
+    (0xA77A, Valid), (0xA77B, Mapped("\u{A77C}")), (0xA77C, Valid), (0xA77D, Mapped("\u{1D79}")),
+    (0xA77E, Mapped("\u{A77F}")), (0xA77F, Valid), (0xA780, Mapped("\u{A781}")), (0xA781, Valid),
+    (0xA782, Mapped("\u{A783}")), (0xA783, Valid), (0xA784, Mapped("\u{A785}")), (0xA785, Valid),
+    (0xA786, Mapped("\u{A787}")), (0xA787, Valid), (0xA78B, Mapped("\u{A78C}")), (0xA78C, Valid),
+    (0xA78D, Mapped("\u{265}")), (0xA78E, Valid), (0xA790, Mapped("\u{A791}")), (0xA791, Valid),
+    (0xA792, Mapped("\u{A793}")), (0xA793, Valid), (0xA796, Mapped("\u{A797}")), (0xA797, Valid),
+    (0xA798, Mapped("\u{A799}")), (0xA799, Valid), (0xA79A, Mapped("\u{A79B}")), (0xA79B, Valid),
+    (0xA79C, Mapped("\u{A79D}")), (0xA79D, Valid), (0xA79E, Mapped("\u{A79F}")), (0xA79F, Valid),
+    (0xA7A0, Mapped("\u{A7A1}")), (0xA7A1, Valid), (0xA7A2, Mapped("\u{A7A3}")), (0xA7A3, Valid),
+    (0xA7A4, Mapped("\u{A7A5}")), (0xA7A5, Valid), (0xA7A6, Mapped("\u{A7A7}")), (0xA7A7, Valid),
+    (0xA7A8, Mapped("\u{A7A9}")), (0xA7A9, Valid), (0xA7AA, Mapped("\u{266}")),
+    (0xA7AB, Mapped("\u{25C}")), (0xA7AC, Mapped("\u{261}")), (0xA7AD, Mapped("\u{26C}")),
+    (0xA7AE, Mapped("\u{26A}")), (0xA7AF, Valid), (0xA7B0, Mapped("\u{29E}")),
+    (0xA7B1, Mapped("\u{287}")), (0xA7B2, Mapped("\u{29D}")), (0xA7B3, Mapped("\u{AB53}")),
+    (0xA7B4, Mapped("\u{A7B5}")), (0xA7B5, Valid), (0xA7B6, Mapped("\u{A7B7}")), (0xA7B7, Valid),
+    (0xA7B8, Mapped("\u{A7B9}")), (0xA7B9, Valid), (0xA7BA, Mapped("\u{A7BB}")), (0xA7BB, Valid),
+    (0xA7BC, Mapped("\u{A7BD}")), (0xA7BD, Valid), (0xA7BE, Mapped("\u{A7BF}")), (0xA7BF, Valid),
+    (0xA7C0, Disallowed), (0xA7C2, Mapped("\u{A7C3}")), (0xA7C3, Valid),
+    (0xA7C4, Mapped("\u{A794}")), (0xA7C5, Mapped("\u{282}")), (0xA7C6, Mapped("\u{1D8E}")),
+    (0xA7C7, Mapped("\u{A7C8}")), (0xA7C8, Valid), (0xA7C9, Mapped("\u{A7CA}")), (0xA7CA, Valid),
+    (0xA7CB, Disallowed), (0xA7F5, Mapped("\u{A7F6}")), (0xA7F6, Valid),
+    (0xA7F8, Mapped("\u{127}")), (0xA7F9, Mapped("\u{153}")), (0xA7FA, Valid), (0xA82D, Disallowed),
+    (0xA830, Valid), (0xA83A, Disallowed), (0xA840, Valid), (0xA878, Disallowed), (0xA880, Valid),
+    (0xA8C6, Disallowed), (0xA8CE, Valid), (0xA8DA, Disallowed), (0xA8E0, Valid),
+    (0xA954, Disallowed), (0xA95F, Valid), (0xA97D, Disallowed), (0xA980, Valid),
+    (0xA9CE, Disallowed), (0xA9CF, Valid), (0xA9DA, Disallowed), (0xA9DE, Valid),
+    (0xA9FF, Disallowed), (0xAA00, Valid), (0xAA37, Disallowed), (0xAA40, Valid),
+    (0xAA4E, Disallowed), (0xAA50, Valid), (0xAA5A, Disallowed), (0xAA5C, Valid),
+    (0xAAC3, Disallowed), (0xAADB, Valid), (0xAAF7, Disallowed), (0xAB01, Valid),
+    (0xAB07, Disallowed), (0xAB09, Valid), (0xAB0F, Disallowed), (0xAB11, Valid),
+    (0xAB17, Disallowed), (0xAB20, Valid), (0xAB27, Disallowed), (0xAB28, Valid),
+    (0xAB2F, Disallowed), (0xAB30, Valid), (0xAB5C, Mapped("\u{A727}")),
+    (0xAB5D, Mapped("\u{AB37}")), (0xAB5E, Mapped("\u{26B}")), (0xAB5F, Mapped("\u{AB52}")),
+    (0xAB60, Valid), (0xAB69, Mapped("\u{28D}")), (0xAB6A, Valid), (0xAB6C, Disallowed),
+    (0xAB70, Shifted(0x13A0)), (0xABC0, Valid), (0xABEE, Disallowed), (0xABF0, Valid),
+    (0xABFA, Disallowed), (0xAC00, Valid), (0xD7A4, Disallowed), (0xD7B0, Valid),
+    (0xD7C7, Disallowed), (0xD7CB, Valid), (0xD7FC, Disallowed), (0xF900, Mapped("\u{8C48}")),
+    (0xF901, Mapped("\u{66F4}")), (0xF902, Mapped("\u{8ECA}")), (0xF903, Mapped("\u{8CC8}")),
+    (0xF904, Mapped("\u{6ED1}")), (0xF905, Mapped("\u{4E32}")), (0xF906, Mapped("\u{53E5}")),
+    (0xF907, Mapped("\u{9F9C}")), (0xF909, Mapped("\u{5951}")), (0xF90A, Mapped("\u{91D1}")),
+    (0xF90B, Mapped("\u{5587}")), (0xF90C, Mapped("\u{5948}")), (0xF90D, Mapped("\u{61F6}")),
+    (0xF90E, Mapped("\u{7669}")), (0xF90F, Mapped("\u{7F85}")), (0xF910, Mapped("\u{863F}")),
+    (0xF911, Mapped("\u{87BA}")), (0xF912, Mapped("\u{88F8}")), (0xF913, Mapped("\u{908F}")),
+    (0xF914, Mapped("\u{6A02}")), (0xF915, Mapped("\u{6D1B}")), (0xF916, Mapped("\u{70D9}")),
+    (0xF917, Mapped("\u{73DE}")), (0xF918, Mapped("\u{843D}")), (0xF919, Mapped("\u{916A}")),
+    (0xF91A, Mapped("\u{99F1}")), (0xF91B, Mapped("\u{4E82}")), (0xF91C, Mapped("\u{5375}")),
+    (0xF91D, Mapped("\u{6B04}")), (0xF91E, Mapped("\u{721B}")), (0xF91F, Mapped("\u{862D}")),
+    (0xF920, Mapped("\u{9E1E}")), (0xF921, Mapped("\u{5D50}")), (0xF922, Mapped("\u{6FEB}")),
+    (0xF923, Mapped("\u{85CD}")), (0xF924, Mapped("\u{8964}")), (0xF925, Mapped("\u{62C9}")),
+    (0xF926, Mapped("\u{81D8}")), (0xF927, Mapped("\u{881F}")), (0xF928, Mapped("\u{5ECA}")),
+    (0xF929, Mapped("\u{6717}")), (0xF92A, Mapped("\u{6D6A}")), (0xF92B, Mapped("\u{72FC}")),
+    (0xF92C, Mapped("\u{90CE}")), (0xF92D, Mapped("\u{4F86}")), (0xF92E, Mapped("\u{51B7}")),
+    (0xF92F, Mapped("\u{52DE}")), (0xF930, Mapped("\u{64C4}")), (0xF931, Mapped("\u{6AD3}")),
+    (0xF932, Mapped("\u{7210}")), (0xF933, Mapped("\u{76E7}")), (0xF934, Mapped("\u{8001}")),
+    (0xF935, Mapped("\u{8606}")), (0xF936, Mapped("\u{865C}")), (0xF937, Mapped("\u{8DEF}")),
+    (0xF938, Mapped("\u{9732}")), (0xF939, Mapped("\u{9B6F}")), (0xF93A, Mapped("\u{9DFA}")),
+    (0xF93B, Mapped("\u{788C}")), (0xF93C, Mapped("\u{797F}")), (0xF93D, Mapped("\u{7DA0}")),
+    (0xF93E, Mapped("\u{83C9}")), (0xF93F, Mapped("\u{9304}")), (0xF940, Mapped("\u{9E7F}")),
+    (0xF941, Mapped("\u{8AD6}")), (0xF942, Mapped("\u{58DF}")), (0xF943, Mapped("\u{5F04}")),
+    (0xF944, Mapped("\u{7C60}")), (0xF945, Mapped("\u{807E}")), (0xF946, Mapped("\u{7262}")),
+    (0xF947, Mapped("\u{78CA}")), (0xF948, Mapped("\u{8CC2}")), (0xF949, Mapped("\u{96F7}")),
+    (0xF94A, Mapped("\u{58D8}")), (0xF94B, Mapped("\u{5C62}")), (0xF94C, Mapped("\u{6A13}")),
+    (0xF94D, Mapped("\u{6DDA}")), (0xF94E, Mapped("\u{6F0F}")), (0xF94F, Mapped("\u{7D2F}")),
+    (0xF950, Mapped("\u{7E37}")), (0xF951, Mapped("\u{964B}")), (0xF952, Mapped("\u{52D2}")),
+    (0xF953, Mapped("\u{808B}")), (0xF954, Mapped("\u{51DC}")), (0xF955, Mapped("\u{51CC}")),
+    (0xF956, Mapped("\u{7A1C}")), (0xF957, Mapped("\u{7DBE}")), (0xF958, Mapped("\u{83F1}")),
+    (0xF959, Mapped("\u{9675}")), (0xF95A, Mapped("\u{8B80}")), (0xF95B, Mapped("\u{62CF}")),
+    (0xF95C, Mapped("\u{6A02}")), (0xF95D, Mapped("\u{8AFE}")), (0xF95E, Mapped("\u{4E39}")),
+    (0xF95F, Mapped("\u{5BE7}")), (0xF960, Mapped("\u{6012}")), (0xF961, Mapped("\u{7387}")),
+    (0xF962, Mapped("\u{7570}")), (0xF963, Mapped("\u{5317}")), (0xF964, Mapped("\u{78FB}")),
+    (0xF965, Mapped("\u{4FBF}")), (0xF966, Mapped("\u{5FA9}")), (0xF967, Mapped("\u{4E0D}")),
+    (0xF968, Mapped("\u{6CCC}")), (0xF969, Mapped("\u{6578}")), (0xF96A, Mapped("\u{7D22}")),
+    (0xF96B, Mapped("\u{53C3}")), (0xF96C, Mapped("\u{585E}")), (0xF96D, Mapped("\u{7701}")),
+    (0xF96E, Mapped("\u{8449}")), (0xF96F, Mapped("\u{8AAA}")), (0xF970, Mapped("\u{6BBA}")),
+    (0xF971, Mapped("\u{8FB0}")), (0xF972, Mapped("\u{6C88}")), (0xF973, Mapped("\u{62FE}")),
+    (0xF974, Mapped("\u{82E5}")), (0xF975, Mapped("\u{63A0}")), (0xF976, Mapped("\u{7565}")),
+    (0xF977, Mapped("\u{4EAE}")), (0xF978, Mapped("\u{5169}")), (0xF979, Mapped("\u{51C9}")),
+    (0xF97A, Mapped("\u{6881}")), (0xF97B, Mapped("\u{7CE7}")), (0xF97C, Mapped("\u{826F}")),
+    (0xF97D, Mapped("\u{8AD2}")), (0xF97E, Mapped("\u{91CF}")), (0xF97F, Mapped("\u{52F5}")),
+    (0xF980, Mapped("\u{5442}")), (0xF981, Mapped("\u{5973}")), (0xF982, Mapped("\u{5EEC}")),
+    (0xF983, Mapped("\u{65C5}")), (0xF984, Mapped("\u{6FFE}")), (0xF985, Mapped("\u{792A}")),
+    (0xF986, Mapped("\u{95AD}")), (0xF987, Mapped("\u{9A6A}")), (0xF988, Mapped("\u{9E97}")),
+    (0xF989, Mapped("\u{9ECE}")), (0xF98A, Mapped("\u{529B}")), (0xF98B, Mapped("\u{66C6}")),
+    (0xF98C, Mapped("\u{6B77}")), (0xF98D, Mapped("\u{8F62}")), (0xF98E, Mapped("\u{5E74}")),
+    (0xF98F, Mapped("\u{6190}")), (0xF990, Mapped("\u{6200}")), (0xF991, Mapped("\u{649A}")),
+    (0xF992, Mapped("\u{6F23}")), (0xF993, Mapped("\u{7149}")), (0xF994, Mapped("\u{7489}")),
+    (0xF995, Mapped("\u{79CA}")), (0xF996, Mapped("\u{7DF4}")), (0xF997, Mapped("\u{806F}")),
+    (0xF998, Mapped("\u{8F26}")), (0xF999, Mapped("\u{84EE}")), (0xF99A, Mapped("\u{9023}")),
+    (0xF99B, Mapped("\u{934A}")), (0xF99C, Mapped("\u{5217}")), (0xF99D, Mapped("\u{52A3}")),
+    (0xF99E, Mapped("\u{54BD}")), (0xF99F, Mapped("\u{70C8}")), (0xF9A0, Mapped("\u{88C2}")),
+    (0xF9A1, Mapped("\u{8AAA}")), (0xF9A2, Mapped("\u{5EC9}")), (0xF9A3, Mapped("\u{5FF5}")),
+    (0xF9A4, Mapped("\u{637B}")), (0xF9A5, Mapped("\u{6BAE}")), (0xF9A6, Mapped("\u{7C3E}")),
+    (0xF9A7, Mapped("\u{7375}")), (0xF9A8, Mapped("\u{4EE4}")), (0xF9A9, Mapped("\u{56F9}")),
+    (0xF9AA, Mapped("\u{5BE7}")), (0xF9AB, Mapped("\u{5DBA}")), (0xF9AC, Mapped("\u{601C}")),
+    (0xF9AD, Mapped("\u{73B2}")), (0xF9AE, Mapped("\u{7469}")), (0xF9AF, Mapped("\u{7F9A}")),
+    (0xF9B0, Mapped("\u{8046}")), (0xF9B1, Mapped("\u{9234}")), (0xF9B2, Mapped("\u{96F6}")),
+    (0xF9B3, Mapped("\u{9748}")), (0xF9B4, Mapped("\u{9818}")), (0xF9B5, Mapped("\u{4F8B}")),
+    (0xF9B6, Mapped("\u{79AE}")), (0xF9B7, Mapped("\u{91B4}")), (0xF9B8, Mapped("\u{96B8}")),
+    (0xF9B9, Mapped("\u{60E1}")), (0xF9BA, Mapped("\u{4E86}")), (0xF9BB, Mapped("\u{50DA}")),
+    (0xF9BC, Mapped("\u{5BEE}")), (0xF9BD, Mapped("\u{5C3F}")), (0xF9BE, Mapped("\u{6599}")),
+    (0xF9BF, Mapped("\u{6A02}")), (0xF9C0, Mapped("\u{71CE}")), (0xF9C1, Mapped("\u{7642}")),
+    (0xF9C2, Mapped("\u{84FC}")), (0xF9C3, Mapped("\u{907C}")), (0xF9C4, Mapped("\u{9F8D}")),
+    (0xF9C5, Mapped("\u{6688}")), (0xF9C6, Mapped("\u{962E}")), (0xF9C7, Mapped("\u{5289}")),
+    (0xF9C8, Mapped("\u{677B}")), (0xF9C9, Mapped("\u{67F3}")), (0xF9CA, Mapped("\u{6D41}")),
+    (0xF9CB, Mapped("\u{6E9C}")), (0xF9CC, Mapped("\u{7409}")), (0xF9CD, Mapped("\u{7559}")),
+    (0xF9CE, Mapped("\u{786B}")), (0xF9CF, Mapped("\u{7D10}")), (0xF9D0, Mapped("\u{985E}")),
+    (0xF9D1, Mapped("\u{516D}")), (0xF9D2, Mapped("\u{622E}")), (0xF9D3, Mapped("\u{9678}")),
+    (0xF9D4, Mapped("\u{502B}")), (0xF9D5, Mapped("\u{5D19}")), (0xF9D6, Mapped("\u{6DEA}")),
+    (0xF9D7, Mapped("\u{8F2A}")), (0xF9D8, Mapped("\u{5F8B}")), (0xF9D9, Mapped("\u{6144}")),
+    (0xF9DA, Mapped("\u{6817}")), (0xF9DB, Mapped("\u{7387}")), (0xF9DC, Mapped("\u{9686}")),
+    (0xF9DD, Mapped("\u{5229}")), (0xF9DE, Mapped("\u{540F}")), (0xF9DF, Mapped("\u{5C65}")),
+    (0xF9E0, Mapped("\u{6613}")), (0xF9E1, Mapped("\u{674E}")), (0xF9E2, Mapped("\u{68A8}")),
+    (0xF9E3, Mapped("\u{6CE5}")), (0xF9E4, Mapped("\u{7406}")), (0xF9E5, Mapped("\u{75E2}")),
+    (0xF9E6, Mapped("\u{7F79}")), (0xF9E7, Mapped("\u{88CF}")), (0xF9E8, Mapped("\u{88E1}")),
+    (0xF9E9, Mapped("\u{91CC}")), (0xF9EA, Mapped("\u{96E2}")), (0xF9EB, Mapped("\u{533F}")),
+    (0xF9EC, Mapped("\u{6EBA}")), (0xF9ED, Mapped("\u{541D}")), (0xF9EE, Mapped("\u{71D0}")),
+    (0xF9EF, Mapped("\u{7498}")), (0xF9F0, Mapped("\u{85FA}")), (0xF9F1, Mapped("\u{96A3}")),
+    (0xF9F2, Mapped("\u{9C57}")), (0xF9F3, Mapped("\u{9E9F}")), (0xF9F4, Mapped("\u{6797}")),
+    (0xF9F5, Mapped("\u{6DCB}")), (0xF9F6, Mapped("\u{81E8}")), (0xF9F7, Mapped("\u{7ACB}")),
+    (0xF9F8, Mapped("\u{7B20}")), (0xF9F9, Mapped("\u{7C92}")), (0xF9FA, Mapped("\u{72C0}")),
+    (0xF9FB, Mapped("\u{7099}")), (0xF9FC, Mapped("\u{8B58}")), (0xF9FD, Mapped("\u{4EC0}")),
+    (0xF9FE, Mapped("\u{8336}")), (0xF9FF, Mapped("\u{523A}")), (0xFA00, Mapped("\u{5207}")),
+    (0xFA01, Mapped("\u{5EA6}")), (0xFA02, Mapped("\u{62D3}")), (0xFA03, Mapped("\u{7CD6}")),
+    (0xFA04, Mapped("\u{5B85}")), (0xFA05, Mapped("\u{6D1E}")), (0xFA06, Mapped("\u{66B4}")),
+    (0xFA07, Mapped("\u{8F3B}")), (0xFA08, Mapped("\u{884C}")), (0xFA09, Mapped("\u{964D}")),
+    (0xFA0A, Mapped("\u{898B}")), (0xFA0B, Mapped("\u{5ED3}")), (0xFA0C, Mapped("\u{5140}")),
+    (0xFA0D, Mapped("\u{55C0}")), (0xFA0E, Valid), (0xFA10, Mapped("\u{585A}")), (0xFA11, Valid),
+    (0xFA12, Mapped("\u{6674}")), (0xFA13, Valid), (0xFA15, Mapped("\u{51DE}")),
+    (0xFA16, Mapped("\u{732A}")), (0xFA17, Mapped("\u{76CA}")), (0xFA18, Mapped("\u{793C}")),
+    (0xFA19, Mapped("\u{795E}")), (0xFA1A, Mapped("\u{7965}")), (0xFA1B, Mapped("\u{798F}")),
+    (0xFA1C, Mapped("\u{9756}")), (0xFA1D, Mapped("\u{7CBE}")), (0xFA1E, Mapped("\u{7FBD}")),
+    (0xFA1F, Valid), (0xFA20, Mapped("\u{8612}")), (0xFA21, Valid), (0xFA22, Mapped("\u{8AF8}")),
+    (0xFA23, Valid), (0xFA25, Mapped("\u{9038}")), (0xFA26, Mapped("\u{90FD}")), (0xFA27, Valid),
+    (0xFA2A, Mapped("\u{98EF}")), (0xFA2B, Mapped("\u{98FC}")), (0xFA2C, Mapped("\u{9928}")),
+    (0xFA2D, Mapped("\u{9DB4}")), (0xFA2E, Mapped("\u{90DE}")), (0xFA2F, Mapped("\u{96B7}")),
+    (0xFA30, Mapped("\u{4FAE}")), (0xFA31, Mapped("\u{50E7}")), (0xFA32, Mapped("\u{514D}")),
+    (0xFA33, Mapped("\u{52C9}")), (0xFA34, Mapped("\u{52E4}")), (0xFA35, Mapped("\u{5351}")),
+    (0xFA36, Mapped("\u{559D}")), (0xFA37, Mapped("\u{5606}")), (0xFA38, Mapped("\u{5668}")),
+    (0xFA39, Mapped("\u{5840}")), (0xFA3A, Mapped("\u{58A8}")), (0xFA3B, Mapped("\u{5C64}")),
+    (0xFA3C, Mapped("\u{5C6E}")), (0xFA3D, Mapped("\u{6094}")), (0xFA3E, Mapped("\u{6168}")),
+    (0xFA3F, Mapped("\u{618E}")), (0xFA40, Mapped("\u{61F2}")), (0xFA41, Mapped("\u{654F}")),
+    (0xFA42, Mapped("\u{65E2}")), (0xFA43, Mapped("\u{6691}")), (0xFA44, Mapped("\u{6885}")),
+    (0xFA45, Mapped("\u{6D77}")), (0xFA46, Mapped("\u{6E1A}")), (0xFA47, Mapped("\u{6F22}")),
+    (0xFA48, Mapped("\u{716E}")), (0xFA49, Mapped("\u{722B}")), (0xFA4A, Mapped("\u{7422}")),
+    (0xFA4B, Mapped("\u{7891}")), (0xFA4C, Mapped("\u{793E}")), (0xFA4D, Mapped("\u{7949}")),
+    (0xFA4E, Mapped("\u{7948}")), (0xFA4F, Mapped("\u{7950}")), (0xFA50, Mapped("\u{7956}")),
+    (0xFA51, Mapped("\u{795D}")), (0xFA52, Shifted(0x798D)), (0xFA54, Mapped("\u{7A40}")),
+    (0xFA55, Mapped("\u{7A81}")), (0xFA56, Mapped("\u{7BC0}")), (0xFA57, Mapped("\u{7DF4}")),
+    (0xFA58, Mapped("\u{7E09}")), (0xFA59, Mapped("\u{7E41}")), (0xFA5A, Mapped("\u{7F72}")),
+    (0xFA5B, Mapped("\u{8005}")), (0xFA5C, Mapped("\u{81ED}")), (0xFA5D, Mapped("\u{8279}")),
+    (0xFA5F, Mapped("\u{8457}")), (0xFA60, Mapped("\u{8910}")), (0xFA61, Mapped("\u{8996}")),
+    (0xFA62, Mapped("\u{8B01}")), (0xFA63, Mapped("\u{8B39}")), (0xFA64, Mapped("\u{8CD3}")),
+    (0xFA65, Mapped("\u{8D08}")), (0xFA66, Mapped("\u{8FB6}")), (0xFA67, Mapped("\u{9038}")),
+    (0xFA68, Mapped("\u{96E3}")), (0xFA69, Mapped("\u{97FF}")), (0xFA6A, Mapped("\u{983B}")),
+    (0xFA6B, Mapped("\u{6075}")), (0xFA6C, Mapped("\u{242EE}")), (0xFA6D, Mapped("\u{8218}")),
+    (0xFA6E, Disallowed), (0xFA70, Mapped("\u{4E26}")), (0xFA71, Mapped("\u{51B5}")),
+    (0xFA72, Mapped("\u{5168}")), (0xFA73, Mapped("\u{4F80}")), (0xFA74, Mapped("\u{5145}")),
+    (0xFA75, Mapped("\u{5180}")), (0xFA76, Mapped("\u{52C7}")), (0xFA77, Mapped("\u{52FA}")),
+    (0xFA78, Mapped("\u{559D}")), (0xFA79, Mapped("\u{5555}")), (0xFA7A, Mapped("\u{5599}")),
+    (0xFA7B, Mapped("\u{55E2}")), (0xFA7C, Mapped("\u{585A}")), (0xFA7D, Mapped("\u{58B3}")),
+    (0xFA7E, Mapped("\u{5944}")), (0xFA7F, Mapped("\u{5954}")), (0xFA80, Mapped("\u{5A62}")),
+    (0xFA81, Mapped("\u{5B28}")), (0xFA82, Mapped("\u{5ED2}")), (0xFA83, Mapped("\u{5ED9}")),
+    (0xFA84, Mapped("\u{5F69}")), (0xFA85, Mapped("\u{5FAD}")), (0xFA86, Mapped("\u{60D8}")),
+    (0xFA87, Mapped("\u{614E}")), (0xFA88, Mapped("\u{6108}")), (0xFA89, Mapped("\u{618E}")),
+    (0xFA8A, Mapped("\u{6160}")), (0xFA8B, Mapped("\u{61F2}")), (0xFA8C, Mapped("\u{6234}")),
+    (0xFA8D, Mapped("\u{63C4}")), (0xFA8E, Mapped("\u{641C}")), (0xFA8F, Mapped("\u{6452}")),
+    (0xFA90, Mapped("\u{6556}")), (0xFA91, Mapped("\u{6674}")), (0xFA92, Mapped("\u{6717}")),
+    (0xFA93, Mapped("\u{671B}")), (0xFA94, Mapped("\u{6756}")), (0xFA95, Mapped("\u{6B79}")),
+    (0xFA96, Mapped("\u{6BBA}")), (0xFA97, Mapped("\u{6D41}")), (0xFA98, Mapped("\u{6EDB}")),
+    (0xFA99, Mapped("\u{6ECB}")), (0xFA9A, Mapped("\u{6F22}")), (0xFA9B, Mapped("\u{701E}")),
+    (0xFA9C, Mapped("\u{716E}")), (0xFA9D, Mapped("\u{77A7}")), (0xFA9E, Mapped("\u{7235}")),
+    (0xFA9F, Mapped("\u{72AF}")), (0xFAA0, Mapped("\u{732A}")), (0xFAA1, Mapped("\u{7471}")),
+    (0xFAA2, Mapped("\u{7506}")), (0xFAA3, Mapped("\u{753B}")), (0xFAA4, Mapped("\u{761D}")),
+    (0xFAA5, Mapped("\u{761F}")), (0xFAA6, Mapped("\u{76CA}")), (0xFAA7, Mapped("\u{76DB}")),
+    (0xFAA8, Mapped("\u{76F4}")), (0xFAA9, Mapped("\u{774A}")), (0xFAAA, Mapped("\u{7740}")),
+    (0xFAAB, Mapped("\u{78CC}")), (0xFAAC, Mapped("\u{7AB1}")), (0xFAAD, Mapped("\u{7BC0}")),
+    (0xFAAE, Mapped("\u{7C7B}")), (0xFAAF, Mapped("\u{7D5B}")), (0xFAB0, Mapped("\u{7DF4}")),
+    (0xFAB1, Mapped("\u{7F3E}")), (0xFAB2, Mapped("\u{8005}")), (0xFAB3, Mapped("\u{8352}")),
+    (0xFAB4, Mapped("\u{83EF}")), (0xFAB5, Mapped("\u{8779}")), (0xFAB6, Mapped("\u{8941}")),
+    (0xFAB7, Mapped("\u{8986}")), (0xFAB8, Mapped("\u{8996}")), (0xFAB9, Mapped("\u{8ABF}")),
+    (0xFABA, Mapped("\u{8AF8}")), (0xFABB, Mapped("\u{8ACB}")), (0xFABC, Mapped("\u{8B01}")),
+    (0xFABD, Mapped("\u{8AFE}")), (0xFABE, Mapped("\u{8AED}")), (0xFABF, Mapped("\u{8B39}")),
+    (0xFAC0, Mapped("\u{8B8A}")), (0xFAC1, Mapped("\u{8D08}")), (0xFAC2, Mapped("\u{8F38}")),
+    (0xFAC3, Mapped("\u{9072}")), (0xFAC4, Mapped("\u{9199}")), (0xFAC5, Mapped("\u{9276}")),
+    (0xFAC6, Mapped("\u{967C}")), (0xFAC7, Mapped("\u{96E3}")), (0xFAC8, Mapped("\u{9756}")),
+    (0xFAC9, Mapped("\u{97DB}")), (0xFACA, Mapped("\u{97FF}")), (0xFACB, Mapped("\u{980B}")),
+    (0xFACC, Mapped("\u{983B}")), (0xFACD, Mapped("\u{9B12}")), (0xFACE, Mapped("\u{9F9C}")),
+    (0xFACF, Mapped("\u{2284A}")), (0xFAD0, Mapped("\u{22844}")), (0xFAD1, Mapped("\u{233D5}")),
+    (0xFAD2, Mapped("\u{3B9D}")), (0xFAD3, Mapped("\u{4018}")), (0xFAD4, Mapped("\u{4039}")),
+    (0xFAD5, Mapped("\u{25249}")), (0xFAD6, Mapped("\u{25CD0}")), (0xFAD7, Mapped("\u{27ED3}")),
+    (0xFAD8, Mapped("\u{9F43}")), (0xFAD9, Mapped("\u{9F8E}")), (0xFADA, Disallowed),
+    (0xFB00, Mapped("ff")), (0xFB01, Mapped("fi")), (0xFB02, Mapped("fl")), (0xFB03, Mapped("ffi")),
+    (0xFB04, Mapped("ffl")), (0xFB05, Mapped("st")), (0xFB07, Disallowed),
+    (0xFB13, Mapped("\u{574}\u{576}")), (0xFB14, Mapped("\u{574}\u{565}")),
+    (0xFB15, Mapped("\u{574}\u{56B}")), (0xFB16, Mapped("\u{57E}\u{576}")),
+    (0xFB17, Mapped("\u{574}\u{56D}")), (0xFB18, Disallowed), (0xFB1D, Mapped("\u{5D9}\u{5B4}")),
+    (0xFB1E, Valid), (0xFB1F, Mapped("\u{5F2}\u{5B7}")), (0xFB20, Mapped("\u{5E2}")),
+    (0xFB21, Mapped("\u{5D0}")), (0xFB22, Shifted(0x05D3)), (0xFB24, Shifted(0x05DB)),
+    (0xFB27, Mapped("\u{5E8}")), (0xFB28, Mapped("\u{5EA}")), (0xFB29, Mapped("+")),
+    (0xFB2A, Mapped("\u{5E9}\u{5C1}")), (0xFB2B, Mapped("\u{5E9}\u{5C2}")),
+    (0xFB2C, Mapped("\u{5E9}\u{5BC}\u{5C1}")), (0xFB2D, Mapped("\u{5E9}\u{5BC}\u{5C2}")),
+    (0xFB2E, Mapped("\u{5D0}\u{5B7}")), (0xFB2F, Mapped("\u{5D0}\u{5B8}")),
+    (0xFB30, Mapped("\u{5D0}\u{5BC}")), (0xFB31, Mapped("\u{5D1}\u{5BC}")),
+    (0xFB32, Mapped("\u{5D2}\u{5BC}")), (0xFB33, Mapped("\u{5D3}\u{5BC}")),
+    (0xFB34, Mapped("\u{5D4}\u{5BC}")), (0xFB35, Mapped("\u{5D5}\u{5BC}")),
+    (0xFB36, Mapped("\u{5D6}\u{5BC}")), (0xFB37, Disallowed), (0xFB38, Mapped("\u{5D8}\u{5BC}")),
+    (0xFB39, Mapped("\u{5D9}\u{5BC}")), (0xFB3A, Mapped("\u{5DA}\u{5BC}")),
+    (0xFB3B, Mapped("\u{5DB}\u{5BC}")), (0xFB3C, Mapped("\u{5DC}\u{5BC}")), (0xFB3D, Disallowed),
+    (0xFB3E, Mapped("\u{5DE}\u{5BC}")), (0xFB3F, Disallowed), (0xFB40, Mapped("\u{5E0}\u{5BC}")),
+    (0xFB41, Mapped("\u{5E1}\u{5BC}")), (0xFB42, Disallowed), (0xFB43, Mapped("\u{5E3}\u{5BC}")),
+    (0xFB44, Mapped("\u{5E4}\u{5BC}")), (0xFB45, Disallowed), (0xFB46, Mapped("\u{5E6}\u{5BC}")),
+    (0xFB47, Mapped("\u{5E7}\u{5BC}")), (0xFB48, Mapped("\u{5E8}\u{5BC}")),
+    (0xFB49, Mapped("\u{5E9}\u{5BC}")), (0xFB4A, Mapped("\u{5EA}\u{5BC}")),
+    (0xFB4B, Mapped("\u{5D5}\u{5B9}")), (0xFB4C, Mapped("\u{5D1}\u{5BF}")),
+    (0xFB4D, Mapped("\u{5DB}\u{5BF}")), (0xFB4E, Mapped("\u{5E4}\u{5BF}")),
+    (0xFB4F, Mapped("\u{5D0}\u{5DC}")), (0xFB50, Mapped("\u{671}")), (0xFB52, Mapped("\u{67B}")),
+    (0xFB56, Mapped("\u{67E}")), (0xFB5A, Mapped("\u{680}")), (0xFB5E, Mapped("\u{67A}")),
+    (0xFB62, Mapped("\u{67F}")), (0xFB66, Mapped("\u{679}")), (0xFB6A, Mapped("\u{6A4}")),
+    (0xFB6E, Mapped("\u{6A6}")), (0xFB72, Mapped("\u{684}")), (0xFB76, Mapped("\u{683}")),
+    (0xFB7A, Mapped("\u{686}")), (0xFB7E, Mapped("\u{687}")), (0xFB82, Mapped("\u{68D}")),
+    (0xFB84, Mapped("\u{68C}")), (0xFB86, Mapped("\u{68E}")), (0xFB88, Mapped("\u{688}")),
+    (0xFB8A, Mapped("\u{698}")), (0xFB8C, Mapped("\u{691}")), (0xFB8E, Mapped("\u{6A9}")),
+    (0xFB92, Mapped("\u{6AF}")), (0xFB96, Mapped("\u{6B3}")), (0xFB9A, Mapped("\u{6B1}")),
+    (0xFB9E, Mapped("\u{6BA}")), (0xFBA0, Mapped("\u{6BB}")), (0xFBA4, Mapped("\u{6C0}")),
+    (0xFBA6, Mapped("\u{6C1}")), (0xFBAA, Mapped("\u{6BE}")), (0xFBAE, Mapped("\u{6D2}")),
+    (0xFBB0, Mapped("\u{6D3}")), (0xFBB2, Valid), (0xFBC2, Disallowed), (0xFBD3, Mapped("\u{6AD}")),
+    (0xFBD7, Mapped("\u{6C7}")), (0xFBD9, Mapped("\u{6C6}")), (0xFBDB, Mapped("\u{6C8}")),
+    (0xFBDD, Mapped("\u{6C7}\u{674}")), (0xFBDE, Mapped("\u{6CB}")), (0xFBE0, Mapped("\u{6C5}")),
+    (0xFBE2, Mapped("\u{6C9}")), (0xFBE4, Mapped("\u{6D0}")), (0xFBE8, Mapped("\u{649}")),
+    (0xFBEA, Mapped("\u{626}\u{627}")), (0xFBEC, Mapped("\u{626}\u{6D5}")),
+    (0xFBEE, Mapped("\u{626}\u{648}")), (0xFBF0, Mapped("\u{626}\u{6C7}")),
+    (0xFBF2, Mapped("\u{626}\u{6C6}")), (0xFBF4, Mapped("\u{626}\u{6C8}")),
+    (0xFBF6, Mapped("\u{626}\u{6D0}")), (0xFBF9, Mapped("\u{626}\u{649}")),
+    (0xFBFC, Mapped("\u{6CC}")), (0xFC00, Mapped("\u{626}\u{62C}")),
+    (0xFC01, Mapped("\u{626}\u{62D}")), (0xFC02, Mapped("\u{626}\u{645}")),
+    (0xFC03, Mapped("\u{626}\u{649}")), (0xFC04, Mapped("\u{626}\u{64A}")),
+    (0xFC05, Mapped("\u{628}\u{62C}")), (0xFC06, Mapped("\u{628}\u{62D}")),
+    (0xFC07, Mapped("\u{628}\u{62E}")), (0xFC08, Mapped("\u{628}\u{645}")),
+    (0xFC09, Mapped("\u{628}\u{649}")), (0xFC0A, Mapped("\u{628}\u{64A}")),
+    (0xFC0B, Mapped("\u{62A}\u{62C}")), (0xFC0C, Mapped("\u{62A}\u{62D}")),
+    (0xFC0D, Mapped("\u{62A}\u{62E}")), (0xFC0E, Mapped("\u{62A}\u{645}")),
+    (0xFC0F, Mapped("\u{62A}\u{649}")), (0xFC10, Mapped("\u{62A}\u{64A}")),
+    (0xFC11, Mapped("\u{62B}\u{62C}")), (0xFC12, Mapped("\u{62B}\u{645}")),
+    (0xFC13, Mapped("\u{62B}\u{649}")), (0xFC14, Mapped("\u{62B}\u{64A}")),
+    (0xFC15, Mapped("\u{62C}\u{62D}")), (0xFC16, Mapped("\u{62C}\u{645}")),
+    (0xFC17, Mapped("\u{62D}\u{62C}")), (0xFC18, Mapped("\u{62D}\u{645}")),
+    (0xFC19, Mapped("\u{62E}\u{62C}")), (0xFC1A, Mapped("\u{62E}\u{62D}")),
+    (0xFC1B, Mapped("\u{62E}\u{645}")), (0xFC1C, Mapped("\u{633}\u{62C}")),
+    (0xFC1D, Mapped("\u{633}\u{62D}")), (0xFC1E, Mapped("\u{633}\u{62E}")),
+    (0xFC1F, Mapped("\u{633}\u{645}")), (0xFC20, Mapped("\u{635}\u{62D}")),
+    (0xFC21, Mapped("\u{635}\u{645}")), (0xFC22, Mapped("\u{636}\u{62C}")),
+    (0xFC23, Mapped("\u{636}\u{62D}")), (0xFC24, Mapped("\u{636}\u{62E}")),
+    (0xFC25, Mapped("\u{636}\u{645}")), (0xFC26, Mapped("\u{637}\u{62D}")),
+    (0xFC27, Mapped("\u{637}\u{645}")), (0xFC28, Mapped("\u{638}\u{645}")),
+    (0xFC29, Mapped("\u{639}\u{62C}")), (0xFC2A, Mapped("\u{639}\u{645}")),
+    (0xFC2B, Mapped("\u{63A}\u{62C}")), (0xFC2C, Mapped("\u{63A}\u{645}")),
+    (0xFC2D, Mapped("\u{641}\u{62C}")), (0xFC2E, Mapped("\u{641}\u{62D}")),
+    (0xFC2F, Mapped("\u{641}\u{62E}")), (0xFC30, Mapped("\u{641}\u{645}")),
+    (0xFC31, Mapped("\u{641}\u{649}")), (0xFC32, Mapped("\u{641}\u{64A}")),
+    (0xFC33, Mapped("\u{642}\u{62D}")), (0xFC34, Mapped("\u{642}\u{645}")),
+    (0xFC35, Mapped("\u{642}\u{649}")), (0xFC36, Mapped("\u{642}\u{64A}")),
+    (0xFC37, Mapped("\u{643}\u{627}")), (0xFC38, Mapped("\u{643}\u{62C}")),
+    (0xFC39, Mapped("\u{643}\u{62D}")), (0xFC3A, Mapped("\u{643}\u{62E}")),
+    (0xFC3B, Mapped("\u{643}\u{644}")), (0xFC3C, Mapped("\u{643}\u{645}")),
+    (0xFC3D, Mapped("\u{643}\u{649}")), (0xFC3E, Mapped("\u{643}\u{64A}")),
+    (0xFC3F, Mapped("\u{644}\u{62C}")), (0xFC40, Mapped("\u{644}\u{62D}")),
+    (0xFC41, Mapped("\u{644}\u{62E}")), (0xFC42, Mapped("\u{644}\u{645}")),
+    (0xFC43, Mapped("\u{644}\u{649}")), (0xFC44, Mapped("\u{644}\u{64A}")),
+    (0xFC45, Mapped("\u{645}\u{62C}")), (0xFC46, Mapped("\u{645}\u{62D}")),
+    (0xFC47, Mapped("\u{645}\u{62E}")), (0xFC48, Mapped("\u{645}\u{645}")),
+    (0xFC49, Mapped("\u{645}\u{649}")), (0xFC4A, Mapped("\u{645}\u{64A}")),
+    (0xFC4B, Mapped("\u{646}\u{62C}")), (0xFC4C, Mapped("\u{646}\u{62D}")),
+    (0xFC4D, Mapped("\u{646}\u{62E}")), (0xFC4E, Mapped("\u{646}\u{645}")),
+    (0xFC4F, Mapped("\u{646}\u{649}")), (0xFC50, Mapped("\u{646}\u{64A}")),
+    (0xFC51, Mapped("\u{647}\u{62C}")), (0xFC52, Mapped("\u{647}\u{645}")),
+    (0xFC53, Mapped("\u{647}\u{649}")), (0xFC54, Mapped("\u{647}\u{64A}")),
+    (0xFC55, Mapped("\u{64A}\u{62C}")), (0xFC56, Mapped("\u{64A}\u{62D}")),
+    (0xFC57, Mapped("\u{64A}\u{62E}")), (0xFC58, Mapped("\u{64A}\u{645}")),
+    (0xFC59, Mapped("\u{64A}\u{649}")), (0xFC5A, Mapped("\u{64A}\u{64A}")),
+    (0xFC5B, Mapped("\u{630}\u{670}")), (0xFC5C, Mapped("\u{631}\u{670}")),
+    (0xFC5D, Mapped("\u{649}\u{670}")), (0xFC5E, Mapped(" \u{64C}\u{651}")),
+    (0xFC5F, Mapped(" \u{64D}\u{651}")), (0xFC60, Mapped(" \u{64E}\u{651}")),
+    (0xFC61, Mapped(" \u{64F}\u{651}")), (0xFC62, Mapped(" \u{650}\u{651}")),
+    (0xFC63, Mapped(" \u{651}\u{670}")), (0xFC64, Mapped("\u{626}\u{631}")),
+    (0xFC65, Mapped("\u{626}\u{632}")), (0xFC66, Mapped("\u{626}\u{645}")),
+    (0xFC67, Mapped("\u{626}\u{646}")), (0xFC68, Mapped("\u{626}\u{649}")),
+    (0xFC69, Mapped("\u{626}\u{64A}")), (0xFC6A, Mapped("\u{628}\u{631}")),
+    (0xFC6B, Mapped("\u{628}\u{632}")), (0xFC6C, Mapped("\u{628}\u{645}")),
+    (0xFC6D, Mapped("\u{628}\u{646}")), (0xFC6E, Mapped("\u{628}\u{649}")),
+    (0xFC6F, Mapped("\u{628}\u{64A}")), (0xFC70, Mapped("\u{62A}\u{631}")),
+    (0xFC71, Mapped("\u{62A}\u{632}")), (0xFC72, Mapped("\u{62A}\u{645}")),
+    (0xFC73, Mapped("\u{62A}\u{646}")), (0xFC74, Mapped("\u{62A}\u{649}")),
+    (0xFC75, Mapped("\u{62A}\u{64A}")), (0xFC76, Mapped("\u{62B}\u{631}")),
+    (0xFC77, Mapped("\u{62B}\u{632}")), (0xFC78, Mapped("\u{62B}\u{645}")),
+    (0xFC79, Mapped("\u{62B}\u{646}")), (0xFC7A, Mapped("\u{62B}\u{649}")),
+    (0xFC7B, Mapped("\u{62B}\u{64A}")), (0xFC7C, Mapped("\u{641}\u{649}")),
+    (0xFC7D, Mapped("\u{641}\u{64A}")), (0xFC7E, Mapped("\u{642}\u{649}")),
+    (0xFC7F, Mapped("\u{642}\u{64A}")), (0xFC80, Mapped("\u{643}\u{627}")),
+    (0xFC81, Mapped("\u{643}\u{644}")), (0xFC82, Mapped("\u{643}\u{645}")),
+    (0xFC83, Mapped("\u{643}\u{649}")), (0xFC84, Mapped("\u{643}\u{64A}")),
+    (0xFC85, Mapped("\u{644}\u{645}")), (0xFC86, Mapped("\u{644}\u{649}")),
+    (0xFC87, Mapped("\u{644}\u{64A}")), (0xFC88, Mapped("\u{645}\u{627}")),
+    (0xFC89, Mapped("\u{645}\u{645}")), (0xFC8A, Mapped("\u{646}\u{631}")),
+    (0xFC8B, Mapped("\u{646}\u{632}")), (0xFC8C, Mapped("\u{646}\u{645}")),
+    (0xFC8D, Mapped("\u{646}\u{646}")), (0xFC8E, Mapped("\u{646}\u{649}")),
+    (0xFC8F, Mapped("\u{646}\u{64A}")), (0xFC90, Mapped("\u{649}\u{670}")),
+    (0xFC91, Mapped("\u{64A}\u{631}")), (0xFC92, Mapped("\u{64A}\u{632}")),
+    (0xFC93, Mapped("\u{64A}\u{645}")), (0xFC94, Mapped("\u{64A}\u{646}")),
+    (0xFC95, Mapped("\u{64A}\u{649}")), (0xFC96, Mapped("\u{64A}\u{64A}")),
+    (0xFC97, Mapped("\u{626}\u{62C}")), (0xFC98, Mapped("\u{626}\u{62D}")),
+    (0xFC99, Mapped("\u{626}\u{62E}")), (0xFC9A, Mapped("\u{626}\u{645}")),
+    (0xFC9B, Mapped("\u{626}\u{647}")), (0xFC9C, Mapped("\u{628}\u{62C}")),
+    (0xFC9D, Mapped("\u{628}\u{62D}")), (0xFC9E, Mapped("\u{628}\u{62E}")),
+    (0xFC9F, Mapped("\u{628}\u{645}")), (0xFCA0, Mapped("\u{628}\u{647}")),
+    (0xFCA1, Mapped("\u{62A}\u{62C}")), (0xFCA2, Mapped("\u{62A}\u{62D}")),
+    (0xFCA3, Mapped("\u{62A}\u{62E}")), (0xFCA4, Mapped("\u{62A}\u{645}")),
+    (0xFCA5, Mapped("\u{62A}\u{647}")), (0xFCA6, Mapped("\u{62B}\u{645}")),
+    (0xFCA7, Mapped("\u{62C}\u{62D}")), (0xFCA8, Mapped("\u{62C}\u{645}")),
+    (0xFCA9, Mapped("\u{62D}\u{62C}")), (0xFCAA, Mapped("\u{62D}\u{645}")),
+    (0xFCAB, Mapped("\u{62E}\u{62C}")), (0xFCAC, Mapped("\u{62E}\u{645}")),
+    (0xFCAD, Mapped("\u{633}\u{62C}")), (0xFCAE, Mapped("\u{633}\u{62D}")),
+    (0xFCAF, Mapped("\u{633}\u{62E}")), (0xFCB0, Mapped("\u{633}\u{645}")),
+    (0xFCB1, Mapped("\u{635}\u{62D}")), (0xFCB2, Mapped("\u{635}\u{62E}")),
+    (0xFCB3, Mapped("\u{635}\u{645}")), (0xFCB4, Mapped("\u{636}\u{62C}")),
+    (0xFCB5, Mapped("\u{636}\u{62D}")), (0xFCB6, Mapped("\u{636}\u{62E}")),
+    (0xFCB7, Mapped("\u{636}\u{645}")), (0xFCB8, Mapped("\u{637}\u{62D}")),
+    (0xFCB9, Mapped("\u{638}\u{645}")), (0xFCBA, Mapped("\u{639}\u{62C}")),
+    (0xFCBB, Mapped("\u{639}\u{645}")), (0xFCBC, Mapped("\u{63A}\u{62C}")),
+    (0xFCBD, Mapped("\u{63A}\u{645}")), (0xFCBE, Mapped("\u{641}\u{62C}")),
+    (0xFCBF, Mapped("\u{641}\u{62D}")), (0xFCC0, Mapped("\u{641}\u{62E}")),
+    (0xFCC1, Mapped("\u{641}\u{645}")), (0xFCC2, Mapped("\u{642}\u{62D}")),
+    (0xFCC3, Mapped("\u{642}\u{645}")), (0xFCC4, Mapped("\u{643}\u{62C}")),
+    (0xFCC5, Mapped("\u{643}\u{62D}")), (0xFCC6, Mapped("\u{643}\u{62E}")),
+    (0xFCC7, Mapped("\u{643}\u{644}")), (0xFCC8, Mapped("\u{643}\u{645}")),
+    (0xFCC9, Mapped("\u{644}\u{62C}")), (0xFCCA, Mapped("\u{644}\u{62D}")),
+    (0xFCCB, Mapped("\u{644}\u{62E}")), (0xFCCC, Mapped("\u{644}\u{645}")),
+    (0xFCCD, Mapped("\u{644}\u{647}")), (0xFCCE, Mapped("\u{645}\u{62C}")),
+    (0xFCCF, Mapped("\u{645}\u{62D}")), (0xFCD0, Mapped("\u{645}\u{62E}")),
+    (0xFCD1, Mapped("\u{645}\u{645}")), (0xFCD2, Mapped("\u{646}\u{62C}")),
+    (0xFCD3, Mapped("\u{646}\u{62D}")), (0xFCD4, Mapped("\u{646}\u{62E}")),
+    (0xFCD5, Mapped("\u{646}\u{645}")), (0xFCD6, Mapped("\u{646}\u{647}")),
+    (0xFCD7, Mapped("\u{647}\u{62C}")), (0xFCD8, Mapped("\u{647}\u{645}")),
+    (0xFCD9, Mapped("\u{647}\u{670}")), (0xFCDA, Mapped("\u{64A}\u{62C}")),
+    (0xFCDB, Mapped("\u{64A}\u{62D}")), (0xFCDC, Mapped("\u{64A}\u{62E}")),
+    (0xFCDD, Mapped("\u{64A}\u{645}")), (0xFCDE, Mapped("\u{64A}\u{647}")),
+    (0xFCDF, Mapped("\u{626}\u{645}")), (0xFCE0, Mapped("\u{626}\u{647}")),
+    (0xFCE1, Mapped("\u{628}\u{645}")), (0xFCE2, Mapped("\u{628}\u{647}")),
+    (0xFCE3, Mapped("\u{62A}\u{645}")), (0xFCE4, Mapped("\u{62A}\u{647}")),
+    (0xFCE5, Mapped("\u{62B}\u{645}")), (0xFCE6, Mapped("\u{62B}\u{647}")),
+    (0xFCE7, Mapped("\u{633}\u{645}")), (0xFCE8, Mapped("\u{633}\u{647}")),
+    (0xFCE9, Mapped("\u{634}\u{645}")), (0xFCEA, Mapped("\u{634}\u{647}")),
+    (0xFCEB, Mapped("\u{643}\u{644}")), (0xFCEC, Mapped("\u{643}\u{645}")),
+    (0xFCED, Mapped("\u{644}\u{645}")), (0xFCEE, Mapped("\u{646}\u{645}")),
+    (0xFCEF, Mapped("\u{646}\u{647}")), (0xFCF0, Mapped("\u{64A}\u{645}")),
+    (0xFCF1, Mapped("\u{64A}\u{647}")), (0xFCF2, Mapped("\u{640}\u{64E}\u{651}")),
+    (0xFCF3, Mapped("\u{640}\u{64F}\u{651}")), (0xFCF4, Mapped("\u{640}\u{650}\u{651}")),
+    (0xFCF5, Mapped("\u{637}\u{649}")), (0xFCF6, Mapped("\u{637}\u{64A}")),
+    (0xFCF7, Mapped("\u{639}\u{649}")), (0xFCF8, Mapped("\u{639}\u{64A}")),
+    (0xFCF9, Mapped("\u{63A}\u{649}")), (0xFCFA, Mapped("\u{63A}\u{64A}")),
+    (0xFCFB, Mapped("\u{633}\u{649}")), (0xFCFC, Mapped("\u{633}\u{64A}")),
+    (0xFCFD, Mapped("\u{634}\u{649}")), (0xFCFE, Mapped("\u{634}\u{64A}")),
+    (0xFCFF, Mapped("\u{62D}\u{649}")), (0xFD00, Mapped("\u{62D}\u{64A}")),
+    (0xFD01, Mapped("\u{62C}\u{649}")), (0xFD02, Mapped("\u{62C}\u{64A}")),
+    (0xFD03, Mapped("\u{62E}\u{649}")), (0xFD04, Mapped("\u{62E}\u{64A}")),
+    (0xFD05, Mapped("\u{635}\u{649}")), (0xFD06, Mapped("\u{635}\u{64A}")),
+    (0xFD07, Mapped("\u{636}\u{649}")), (0xFD08, Mapped("\u{636}\u{64A}")),
+    (0xFD09, Mapped("\u{634}\u{62C}")), (0xFD0A, Mapped("\u{634}\u{62D}")),
+    (0xFD0B, Mapped("\u{634}\u{62E}")), (0xFD0C, Mapped("\u{634}\u{645}")),
+    (0xFD0D, Mapped("\u{634}\u{631}")), (0xFD0E, Mapped("\u{633}\u{631}")),
+    (0xFD0F, Mapped("\u{635}\u{631}")), (0xFD10, Mapped("\u{636}\u{631}")),
+    (0xFD11, Mapped("\u{637}\u{649}")), (0xFD12, Mapped("\u{637}\u{64A}")),
+    (0xFD13, Mapped("\u{639}\u{649}")), (0xFD14, Mapped("\u{639}\u{64A}")),
+    (0xFD15, Mapped("\u{63A}\u{649}")), (0xFD16, Mapped("\u{63A}\u{64A}")),
+    (0xFD17, Mapped("\u{633}\u{649}")), (0xFD18, Mapped("\u{633}\u{64A}")),
+    (0xFD19, Mapped("\u{634}\u{649}")), (0xFD1A, Mapped("\u{634}\u{64A}")),
+    (0xFD1B, Mapped("\u{62D}\u{649}")), (0xFD1C, Mapped("\u{62D}\u{64A}")),
+    (0xFD1D, Mapped("\u{62C}\u{649}")), (0xFD1E, Mapped("\u{62C}\u{64A}")),
+    (0xFD1F, Mapped("\u{62E}\u{649}")), (0xFD20, Mapped("\u{62E}\u{64A}")),
+    (0xFD21, Mapped("\u{635}\u{649}")), (0xFD22, Mapped("\u{635}\u{64A}")),
+    (0xFD23, Mapped("\u{636}\u{649}")), (0xFD24, Mapped("\u{636}\u{64A}")),
+    (0xFD25, Mapped("\u{634}\u{62C}")), (0xFD26, Mapped("\u{634}\u{62D}")),
+    (0xFD27, Mapped("\u{634}\u{62E}")), (0xFD28, Mapped("\u{634}\u{645}")),
+    (0xFD29, Mapped("\u{634}\u{631}")), (0xFD2A, Mapped("\u{633}\u{631}")),
+    (0xFD2B, Mapped("\u{635}\u{631}")), (0xFD2C, Mapped("\u{636}\u{631}")),
+    (0xFD2D, Mapped("\u{634}\u{62C}")), (0xFD2E, Mapped("\u{634}\u{62D}")),
+    (0xFD2F, Mapped("\u{634}\u{62E}")), (0xFD30, Mapped("\u{634}\u{645}")),
+    (0xFD31, Mapped("\u{633}\u{647}")), (0xFD32, Mapped("\u{634}\u{647}")),
+    (0xFD33, Mapped("\u{637}\u{645}")), (0xFD34, Mapped("\u{633}\u{62C}")),
+    (0xFD35, Mapped("\u{633}\u{62D}")), (0xFD36, Mapped("\u{633}\u{62E}")),
+    (0xFD37, Mapped("\u{634}\u{62C}")), (0xFD38, Mapped("\u{634}\u{62D}")),
+    (0xFD39, Mapped("\u{634}\u{62E}")), (0xFD3A, Mapped("\u{637}\u{645}")),
+    (0xFD3B, Mapped("\u{638}\u{645}")), (0xFD3C, Mapped("\u{627}\u{64B}")), (0xFD3E, Valid),
+    (0xFD40, Disallowed), (0xFD50, Mapped("\u{62A}\u{62C}\u{645}")),
+    (0xFD51, Mapped("\u{62A}\u{62D}\u{62C}")), (0xFD53, Mapped("\u{62A}\u{62D}\u{645}")),
+    (0xFD54, Mapped("\u{62A}\u{62E}\u{645}")), (0xFD55, Mapped("\u{62A}\u{645}\u{62C}")),
+    (0xFD56, Mapped("\u{62A}\u{645}\u{62D}")), (0xFD57, Mapped("\u{62A}\u{645}\u{62E}")),
+    (0xFD58, Mapped("\u{62C}\u{645}\u{62D}")), (0xFD5A, Mapped("\u{62D}\u{645}\u{64A}")),
+    (0xFD5B, Mapped("\u{62D}\u{645}\u{649}")), (0xFD5C, Mapped("\u{633}\u{62D}\u{62C}")),
+    (0xFD5D, Mapped("\u{633}\u{62C}\u{62D}")), (0xFD5E, Mapped("\u{633}\u{62C}\u{649}")),
+    (0xFD5F, Mapped("\u{633}\u{645}\u{62D}")), (0xFD61, Mapped("\u{633}\u{645}\u{62C}")),
+    (0xFD62, Mapped("\u{633}\u{645}\u{645}")), (0xFD64, Mapped("\u{635}\u{62D}\u{62D}")),
+    (0xFD66, Mapped("\u{635}\u{645}\u{645}")), (0xFD67, Mapped("\u{634}\u{62D}\u{645}")),
+    (0xFD69, Mapped("\u{634}\u{62C}\u{64A}")), (0xFD6A, Mapped("\u{634}\u{645}\u{62E}")),
+    (0xFD6C, Mapped("\u{634}\u{645}\u{645}")), (0xFD6E, Mapped("\u{636}\u{62D}\u{649}")),
+    (0xFD6F, Mapped("\u{636}\u{62E}\u{645}")), (0xFD71, Mapped("\u{637}\u{645}\u{62D}")),
+    (0xFD73, Mapped("\u{637}\u{645}\u{645}")), (0xFD74, Mapped("\u{637}\u{645}\u{64A}")),
+    (0xFD75, Mapped("\u{639}\u{62C}\u{645}")), (0xFD76, Mapped("\u{639}\u{645}\u{645}")),
+    (0xFD78, Mapped("\u{639}\u{645}\u{649}")), (0xFD79, Mapped("\u{63A}\u{645}\u{645}")),
+    (0xFD7A, Mapped("\u{63A}\u{645}\u{64A}")), (0xFD7B, Mapped("\u{63A}\u{645}\u{649}")),
+    (0xFD7C, Mapped("\u{641}\u{62E}\u{645}")), (0xFD7E, Mapped("\u{642}\u{645}\u{62D}")),
+    (0xFD7F, Mapped("\u{642}\u{645}\u{645}")), (0xFD80, Mapped("\u{644}\u{62D}\u{645}")),
+    (0xFD81, Mapped("\u{644}\u{62D}\u{64A}")), (0xFD82, Mapped("\u{644}\u{62D}\u{649}")),
+    (0xFD83, Mapped("\u{644}\u{62C}\u{62C}")), (0xFD85, Mapped("\u{644}\u{62E}\u{645}")),
+    (0xFD87, Mapped("\u{644}\u{645}\u{62D}")), (0xFD89, Mapped("\u{645}\u{62D}\u{62C}")),
+    (0xFD8A, Mapped("\u{645}\u{62D}\u{645}")), (0xFD8B, Mapped("\u{645}\u{62D}\u{64A}")),
+    (0xFD8C, Mapped("\u{645}\u{62C}\u{62D}")), (0xFD8D, Mapped("\u{645}\u{62C}\u{645}")),
+    (0xFD8E, Mapped("\u{645}\u{62E}\u{62C}")), (0xFD8F, Mapped("\u{645}\u{62E}\u{645}")),
+    (0xFD90, Disallowed), (0xFD92, Mapped("\u{645}\u{62C}\u{62E}")),
+    (0xFD93, Mapped("\u{647}\u{645}\u{62C}")), (0xFD94, Mapped("\u{647}\u{645}\u{645}")),
+    (0xFD95, Mapped("\u{646}\u{62D}\u{645}")), (0xFD96, Mapped("\u{646}\u{62D}\u{649}")),
+    (0xFD97, Mapped("\u{646}\u{62C}\u{645}")), (0xFD99, Mapped("\u{646}\u{62C}\u{649}")),
+    (0xFD9A, Mapped("\u{646}\u{645}\u{64A}")), (0xFD9B, Mapped("\u{646}\u{645}\u{649}")),
+    (0xFD9C, Mapped("\u{64A}\u{645}\u{645}")), (0xFD9E, Mapped("\u{628}\u{62E}\u{64A}")),
+    (0xFD9F, Mapped("\u{62A}\u{62C}\u{64A}")), (0xFDA0, Mapped("\u{62A}\u{62C}\u{649}")),
+    (0xFDA1, Mapped("\u{62A}\u{62E}\u{64A}")), (0xFDA2, Mapped("\u{62A}\u{62E}\u{649}")),
+    (0xFDA3, Mapped("\u{62A}\u{645}\u{64A}")), (0xFDA4, Mapped("\u{62A}\u{645}\u{649}")),
+    (0xFDA5, Mapped("\u{62C}\u{645}\u{64A}")), (0xFDA6, Mapped("\u{62C}\u{62D}\u{649}")),
+    (0xFDA7, Mapped("\u{62C}\u{645}\u{649}")), (0xFDA8, Mapped("\u{633}\u{62E}\u{649}")),
+    (0xFDA9, Mapped("\u{635}\u{62D}\u{64A}")), (0xFDAA, Mapped("\u{634}\u{62D}\u{64A}")),
+    (0xFDAB, Mapped("\u{636}\u{62D}\u{64A}")), (0xFDAC, Mapped("\u{644}\u{62C}\u{64A}")),
+    (0xFDAD, Mapped("\u{644}\u{645}\u{64A}")), (0xFDAE, Mapped("\u{64A}\u{62D}\u{64A}")),
+    (0xFDAF, Mapped("\u{64A}\u{62C}\u{64A}")), (0xFDB0, Mapped("\u{64A}\u{645}\u{64A}")),
+    (0xFDB1, Mapped("\u{645}\u{645}\u{64A}")), (0xFDB2, Mapped("\u{642}\u{645}\u{64A}")),
+    (0xFDB3, Mapped("\u{646}\u{62D}\u{64A}")), (0xFDB4, Mapped("\u{642}\u{645}\u{62D}")),
+    (0xFDB5, Mapped("\u{644}\u{62D}\u{645}")), (0xFDB6, Mapped("\u{639}\u{645}\u{64A}")),
+    (0xFDB7, Mapped("\u{643}\u{645}\u{64A}")), (0xFDB8, Mapped("\u{646}\u{62C}\u{62D}")),
+    (0xFDB9, Mapped("\u{645}\u{62E}\u{64A}")), (0xFDBA, Mapped("\u{644}\u{62C}\u{645}")),
+    (0xFDBB, Mapped("\u{643}\u{645}\u{645}")), (0xFDBC, Mapped("\u{644}\u{62C}\u{645}")),
+    (0xFDBD, Mapped("\u{646}\u{62C}\u{62D}")), (0xFDBE, Mapped("\u{62C}\u{62D}\u{64A}")),
+    (0xFDBF, Mapped("\u{62D}\u{62C}\u{64A}")), (0xFDC0, Mapped("\u{645}\u{62C}\u{64A}")),
+    (0xFDC1, Mapped("\u{641}\u{645}\u{64A}")), (0xFDC2, Mapped("\u{628}\u{62D}\u{64A}")),
+    (0xFDC3, Mapped("\u{643}\u{645}\u{645}")), (0xFDC4, Mapped("\u{639}\u{62C}\u{645}")),
+    (0xFDC5, Mapped("\u{635}\u{645}\u{645}")), (0xFDC6, Mapped("\u{633}\u{62E}\u{64A}")),
+    (0xFDC7, Mapped("\u{646}\u{62C}\u{64A}")), (0xFDC8, Disallowed),
+    (0xFDF0, Mapped("\u{635}\u{644}\u{6D2}")), (0xFDF1, Mapped("\u{642}\u{644}\u{6D2}")),
+    (0xFDF2, Mapped("\u{627}\u{644}\u{644}\u{647}")),
+    (0xFDF3, Mapped("\u{627}\u{643}\u{628}\u{631}")),
+    (0xFDF4, Mapped("\u{645}\u{62D}\u{645}\u{62F}")),
+    (0xFDF5, Mapped("\u{635}\u{644}\u{639}\u{645}")),
+    (0xFDF6, Mapped("\u{631}\u{633}\u{648}\u{644}")),
+    (0xFDF7, Mapped("\u{639}\u{644}\u{64A}\u{647}")),
+    (0xFDF8, Mapped("\u{648}\u{633}\u{644}\u{645}")), (0xFDF9, Mapped("\u{635}\u{644}\u{649}")),
+    (0xFDFA, Mapped("\u{635}\u{644}\u{649} \u{627}\u{644}\u{644}\u{647} \u{639}\u{644}\u{64A}\u{647} \u{648}\u{633}\u{644}\u{645}")),
+    (0xFDFB, Mapped("\u{62C}\u{644} \u{62C}\u{644}\u{627}\u{644}\u{647}")),
+    (0xFDFC, Mapped("\u{631}\u{6CC}\u{627}\u{644}")), (0xFDFD, Valid), (0xFDFE, Disallowed),
+    (0xFE00, Ignored), (0xFE10, Mapped(",")), (0xFE11, Mapped("\u{3001}")), (0xFE12, Disallowed),
+    (0xFE13, Shifted(0x003A)), (0xFE15, Mapped("!")), (0xFE16, Mapped("?")),
+    (0xFE17, Shifted(0x3016)), (0xFE19, Disallowed), (0xFE20, Valid), (0xFE30, Disallowed),
+    (0xFE31, Mapped("\u{2014}")), (0xFE32, Mapped("\u{2013}")), (0xFE33, Mapped("_")),
+    (0xFE35, Shifted(0x0028)), (0xFE37, Mapped("{")), (0xFE38, Mapped("}")),
+    (0xFE39, Shifted(0x3014)), (0xFE3B, Shifted(0x3010)), (0xFE3D, Shifted(0x300A)),
+    (0xFE3F, Shifted(0x3008)), (0xFE41, Shifted(0x300C)), (0xFE45, Valid), (0xFE47, Mapped("[")),
+    (0xFE48, Mapped("]")), (0xFE49, Mapped(" \u{305}")), (0xFE4D, Mapped("_")),
+    (0xFE50, Mapped(",")), (0xFE51, Mapped("\u{3001}")), (0xFE52, Disallowed),
+    (0xFE54, Mapped(";")), (0xFE55, Mapped(":")), (0xFE56, Mapped("?")), (0xFE57, Mapped("!")),
+    (0xFE58, Mapped("\u{2014}")), (0xFE59, Shifted(0x0028)), (0xFE5B, Mapped("{")),
+    (0xFE5C, Mapped("}")), (0xFE5D, Shifted(0x3014)), (0xFE5F, Mapped("#")), (0xFE60, Mapped("&")),
+    (0xFE61, Shifted(0x002A)), (0xFE63, Mapped("-")), (0xFE64, Mapped("<")), (0xFE65, Mapped(">")),
+    (0xFE66, Mapped("=")), (0xFE67, Disallowed), (0xFE68, Mapped("\\")), (0xFE69, Shifted(0x0024)),
+    (0xFE6B, Mapped("@")), (0xFE6C, Disallowed), (0xFE70, Mapped(" \u{64B}")),
+    (0xFE71, Mapped("\u{640}\u{64B}")), (0xFE72, Mapped(" \u{64C}")), (0xFE73, Valid),
+    (0xFE74, Mapped(" \u{64D}")), (0xFE75, Disallowed), (0xFE76, Mapped(" \u{64E}")),
+    (0xFE77, Mapped("\u{640}\u{64E}")), (0xFE78, Mapped(" \u{64F}")),
+    (0xFE79, Mapped("\u{640}\u{64F}")), (0xFE7A, Mapped(" \u{650}")),
+    (0xFE7B, Mapped("\u{640}\u{650}")), (0xFE7C, Mapped(" \u{651}")),
+    (0xFE7D, Mapped("\u{640}\u{651}")), (0xFE7E, Mapped(" \u{652}")),
+    (0xFE7F, Mapped("\u{640}\u{652}")), (0xFE80, Shifted(0x0621)), (0xFE82, Shifted(0x0622)),
+    (0xFE84, Shifted(0x0623)), (0xFE86, Shifted(0x0624)), (0xFE88, Shifted(0x0625)),
+    (0xFE8A, Mapped("\u{626}")), (0xFE8D, Mapped("\u{627}")), (0xFE8F, Mapped("\u{628}")),
+    (0xFE93, Mapped("\u{629}")), (0xFE95, Mapped("\u{62A}")), (0xFE99, Mapped("\u{62B}")),
+    (0xFE9D, Mapped("\u{62C}")), (0xFEA1, Mapped("\u{62D}")), (0xFEA5, Mapped("\u{62E}")),
+    (0xFEA9, Mapped("\u{62F}")), (0xFEAB, Mapped("\u{630}")), (0xFEAD, Mapped("\u{631}")),
+    (0xFEAF, Mapped("\u{632}")), (0xFEB1, Mapped("\u{633}")), (0xFEB5, Mapped("\u{634}")),
+    (0xFEB9, Mapped("\u{635}")), (0xFEBD, Mapped("\u{636}")), (0xFEC1, Mapped("\u{637}")),
+    (0xFEC5, Mapped("\u{638}")), (0xFEC9, Mapped("\u{639}")), (0xFECD, Mapped("\u{63A}")),
+    (0xFED1, Mapped("\u{641}")), (0xFED5, Mapped("\u{642}")), (0xFED9, Mapped("\u{643}")),
+    (0xFEDD, Mapped("\u{644}")), (0xFEE1, Mapped("\u{645}")), (0xFEE5, Mapped("\u{646}")),
+    (0xFEE9, Mapped("\u{647}")), (0xFEED, Mapped("\u{648}")), (0xFEEF, Mapped("\u{649}")),
+    (0xFEF1, Mapped("\u{64A}")), (0xFEF5, Mapped("\u{644}\u{622}")),
+    (0xFEF7, Mapped("\u{644}\u{623}")), (0xFEF9, Mapped("\u{644}\u{625}")),
+    (0xFEFB, Mapped("\u{644}\u{627}")), (0xFEFD, Disallowed), (0xFEFF, Ignored),
+    (0xFF00, Disallowed), (0xFF01, Shifted(0x0021)), (0xFF21, Shifted(0x0061)),
+    (0xFF3B, Shifted(0x005B)), (0xFF5F, Shifted(0x2985)), (0xFF61, Mapped(".")),
+    (0xFF62, Shifted(0x300C)), (0xFF64, Mapped("\u{3001}")), (0xFF65, Mapped("\u{30FB}")),
+    (0xFF66, Mapped("\u{30F2}")), (0xFF67, Mapped("\u{30A1}")), (0xFF68, Mapped("\u{30A3}")),
+    (0xFF69, Mapped("\u{30A5}")), (0xFF6A, Mapped("\u{30A7}")), (0xFF6B, Mapped("\u{30A9}")),
+    (0xFF6C, Mapped("\u{30E3}")), (0xFF6D, Mapped("\u{30E5}")), (0xFF6E, Mapped("\u{30E7}")),
+    (0xFF6F, Mapped("\u{30C3}")), (0xFF70, Mapped("\u{30FC}")), (0xFF71, Mapped("\u{30A2}")),
+    (0xFF72, Mapped("\u{30A4}")), (0xFF73, Mapped("\u{30A6}")), (0xFF74, Mapped("\u{30A8}")),
+    (0xFF75, Shifted(0x30AA)), (0xFF77, Mapped("\u{30AD}")), (0xFF78, Mapped("\u{30AF}")),
+    (0xFF79, Mapped("\u{30B1}")), (0xFF7A, Mapped("\u{30B3}")), (0xFF7B, Mapped("\u{30B5}")),
+    (0xFF7C, Mapped("\u{30B7}")), (0xFF7D, Mapped("\u{30B9}")), (0xFF7E, Mapped("\u{30BB}")),
+    (0xFF7F, Mapped("\u{30BD}")), (0xFF80, Mapped("\u{30BF}")), (0xFF81, Mapped("\u{30C1}")),
+    (0xFF82, Mapped("\u{30C4}")), (0xFF83, Mapped("\u{30C6}")), (0xFF84, Mapped("\u{30C8}")),
+    (0xFF85, Shifted(0x30CA)), (0xFF8B, Mapped("\u{30D2}")), (0xFF8C, Mapped("\u{30D5}")),
+    (0xFF8D, Mapped("\u{30D8}")), (0xFF8E, Mapped("\u{30DB}")), (0xFF8F, Shifted(0x30DE)),
+    (0xFF94, Mapped("\u{30E4}")), (0xFF95, Mapped("\u{30E6}")), (0xFF96, Shifted(0x30E8)),
+    (0xFF9C, Mapped("\u{30EF}")), (0xFF9D, Mapped("\u{30F3}")), (0xFF9E, Shifted(0x3099)),
+    (0xFFA0, Disallowed), (0xFFA1, Shifted(0x1100)), (0xFFA3, Mapped("\u{11AA}")),
+    (0xFFA4, Mapped("\u{1102}")), (0xFFA5, Shifted(0x11AC)), (0xFFA7, Shifted(0x1103)),
+    (0xFFAA, Shifted(0x11B0)), (0xFFB0, Mapped("\u{111A}")), (0xFFB1, Shifted(0x1106)),
+    (0xFFB4, Mapped("\u{1121}")), (0xFFB5, Shifted(0x1109)), (0xFFBF, Disallowed),
+    (0xFFC2, Shifted(0x1161)), (0xFFC8, Disallowed), (0xFFCA, Shifted(0x1167)),
+    (0xFFD0, Disallowed), (0xFFD2, Shifted(0x116D)), (0xFFD8, Disallowed),
+    (0xFFDA, Shifted(0x1173)), (0xFFDD, Disallowed), (0xFFE0, Shifted(0x00A2)),
+    (0xFFE2, Mapped("\u{AC}")), (0xFFE3, Mapped(" \u{304}")), (0xFFE4, Mapped("\u{A6}")),
+    (0xFFE5, Mapped("\u{A5}")), (0xFFE6, Mapped("\u{20A9}")), (0xFFE7, Disallowed),
+    (0xFFE8, Mapped("\u{2502}")), (0xFFE9, Shifted(0x2190)), (0xFFED, Mapped("\u{25A0}")),
+    (0xFFEE, Mapped("\u{25CB}")), (0xFFEF, Disallowed), (0x10000, Valid), (0x1000C, Disallowed),
+    (0x1000D, Valid), (0x10027, Disallowed), (0x10028, Valid), (0x1003B, Disallowed),
+    (0x1003C, Valid), (0x1003E, Disallowed), (0x1003F, Valid), (0x1004E, Disallowed),
+    (0x10050, Valid), (0x1005E, Disallowed), (0x10080, Valid), (0x100FB, Disallowed),
+    (0x10100, Valid), (0x10103, Disallowed), (0x10107, Valid), (0x10134, Disallowed),
+    (0x10137, Valid), (0x1018F, Disallowed), (0x10190, Valid), (0x1019D, Disallowed),
+    (0x101A0, Valid), (0x101A1, Disallowed), (0x101D0, Valid), (0x101FE, Disallowed),
+    (0x10280, Valid), (0x1029D, Disallowed), (0x102A0, Valid), (0x102D1, Disallowed),
+    (0x102E0, Valid), (0x102FC, Disallowed), (0x10300, Valid), (0x10324, Disallowed),
+    (0x1032D, Valid), (0x1034B, Disallowed), (0x10350, Valid), (0x1037B, Disallowed),
+    (0x10380, Valid), (0x1039E, Disallowed), (0x1039F, Valid), (0x103C4, Disallowed),
+    (0x103C8, Valid), (0x103D6, Disallowed), (0x10400, Shifted(0x10428)), (0x10428, Valid),
+    (0x1049E, Disallowed), (0x104A0, Valid), (0x104AA, Disallowed), (0x104B0, Shifted(0x104D8)),
+    (0x104D4, Disallowed), (0x104D8, Valid), (0x104FC, Disallowed), (0x10500, Valid),
+    (0x10528, Disallowed), (0x10530, Valid), (0x10564, Disallowed), (0x1056F, Valid),
+    (0x10570, Disallowed), (0x10600, Valid), (0x10737, Disallowed), (0x10740, Valid),
+    (0x10756, Disallowed), (0x10760, Valid), (0x10768, Disallowed), (0x10800, Valid),
+    (0x10806, Disallowed), (0x10808, Valid), (0x10809, Disallowed), (0x1080A, Valid),
+    (0x10836, Disallowed), (0x10837, Valid), (0x10839, Disallowed), (0x1083C, Valid),
+    (0x1083D, Disallowed), (0x1083F, Valid), (0x10856, Disallowed), (0x10857, Valid),
+    (0x1089F, Disallowed), (0x108A7, Valid), (0x108B0, Disallowed), (0x108E0, Valid),
+    (0x108F3, Disallowed), (0x108F4, Valid), (0x108F6, Disallowed), (0x108FB, Valid),
+    (0x1091C, Disallowed), (0x1091F, Valid), (0x1093A, Disallowed), (0x1093F, Valid),
+    (0x10940, Disallowed), (0x10980, Valid), (0x109B8, Disallowed), (0x109BC, Valid),
+    (0x109D0, Disallowed), (0x109D2, Valid), (0x10A04, Disallowed), (0x10A05, Valid),
+    (0x10A07, Disallowed), (0x10A0C, Valid), (0x10A14, Disallowed), (0x10A15, Valid),
+    (0x10A18, Disallowed), (0x10A19, Valid), (0x10A36, Disallowed), (0x10A38, Valid),
+    (0x10A3B, Disallowed), (0x10A3F, Valid), (0x10A49, Disallowed), (0x10A50, Valid),
+    (0x10A59, Disallowed), (0x10A60, Valid), (0x10AA0, Disallowed), (0x10AC0, Valid),
+    (0x10AE7, Disallowed), (0x10AEB, Valid), (0x10AF7, Disallowed), (0x10B00, Valid),
+    (0x10B36, Disallowed), (0x10B39, Valid), (0x10B56, Disallowed), (0x10B58, Valid),
+    (0x10B73, Disallowed), (0x10B78, Valid), (0x10B92, Disallowed), (0x10B99, Valid),
+    (0x10B9D, Disallowed), (0x10BA9, Valid), (0x10BB0, Disallowed), (0x10C00, Valid),
+    (0x10C49, Disallowed), (0x10C80, Shifted(0x10CC0)), (0x10CB3, Disallowed), (0x10CC0, Valid),
+    (0x10CF3, Disallowed), (0x10CFA, Valid), (0x10D28, Disallowed), (0x10D30, Valid),
+    (0x10D3A, Disallowed), (0x10E60, Valid), (0x10E7F, Disallowed), (0x10E80, Valid),
+    (0x10EAA, Disallowed), (0x10EAB, Valid), (0x10EAE, Disallowed), (0x10EB0, Valid),
+    (0x10EB2, Disallowed), (0x10F00, Valid), (0x10F28, Disallowed), (0x10F30, Valid),
+    (0x10F5A, Disallowed), (0x10FB0, Valid), (0x10FCC, Disallowed), (0x10FE0, Valid),
+    (0x10FF7, Disallowed), (0x11000, Valid), (0x1104E, Disallowed), (0x11052, Valid),
+    (0x11070, Disallowed), (0x1107F, Valid), (0x110BD, Disallowed), (0x110BE, Valid),
+    (0x110C2, Disallowed), (0x110D0, Valid), (0x110E9, Disallowed), (0x110F0, Valid),
+    (0x110FA, Disallowed), (0x11100, Valid), (0x11135, Disallowed), (0x11136, Valid),
+    (0x11148, Disallowed), (0x11150, Valid), (0x11177, Disallowed), (0x11180, Valid),
+    (0x111E0, Disallowed), (0x111E1, Valid), (0x111F5, Disallowed), (0x11200, Valid),
+    (0x11212, Disallowed), (0x11213, Valid), (0x1123F, Disallowed), (0x11280, Valid),
+    (0x11287, Disallowed), (0x11288, Valid), (0x11289, Disallowed), (0x1128A, Valid),
+    (0x1128E, Disallowed), (0x1128F, Valid), (0x1129E, Disallowed), (0x1129F, Valid),
+    (0x112AA, Disallowed), (0x112B0, Valid), (0x112EB, Disallowed), (0x112F0, Valid),
+    (0x112FA, Disallowed), (0x11300, Valid), (0x11304, Disallowed), (0x11305, Valid),
+    (0x1130D, Disallowed), (0x1130F, Valid), (0x11311, Disallowed), (0x11313, Valid),
+    (0x11329, Disallowed), (0x1132A, Valid), (0x11331, Disallowed), (0x11332, Valid),
+    (0x11334, Disallowed), (0x11335, Valid), (0x1133A, Disallowed), (0x1133B, Valid),
+    (0x11345, Disallowed), (0x11347, Valid), (0x11349, Disallowed), (0x1134B, Valid),
+    (0x1134E, Disallowed), (0x11350, Valid), (0x11351, Disallowed), (0x11357, Valid),
+    (0x11358, Disallowed), (0x1135D, Valid), (0x11364, Disallowed), (0x11366, Valid),
+    (0x1136D, Disallowed), (0x11370, Valid), (0x11375, Disallowed), (0x11400, Valid),
+    (0x1145C, Disallowed), (0x1145D, Valid), (0x11462, Disallowed), (0x11480, Valid),
+    (0x114C8, Disallowed), (0x114D0, Valid), (0x114DA, Disallowed), (0x11580, Valid),
+    (0x115B6, Disallowed), (0x115B8, Valid), (0x115DE, Disallowed), (0x11600, Valid),
+    (0x11645, Disallowed), (0x11650, Valid), (0x1165A, Disallowed), (0x11660, Valid),
+    (0x1166D, Disallowed), (0x11680, Valid), (0x116B9, Disallowed), (0x116C0, Valid),
+    (0x116CA, Disallowed), (0x11700, Valid), (0x1171B, Disallowed), (0x1171D, Valid),
+    (0x1172C, Disallowed), (0x11730, Valid), (0x11740, Disallowed), (0x11800, Valid),
+    (0x1183C, Disallowed), (0x118A0, Shifted(0x118C0)), (0x118C0, Valid), (0x118F3, Disallowed),
+    (0x118FF, Valid), (0x11907, Disallowed), (0x11909, Valid), (0x1190A, Disallowed),
+    (0x1190C, Valid), (0x11914, Disallowed), (0x11915, Valid), (0x11917, Disallowed),
+    (0x11918, Valid), (0x11936, Disallowed), (0x11937, Valid), (0x11939, Disallowed),
+    (0x1193B, Valid), (0x11947, Disallowed), (0x11950, Valid), (0x1195A, Disallowed),
+    (0x119A0, Valid), (0x119A8, Disallowed), (0x119AA, Valid), (0x119D8, Disallowed),
+    (0x119DA, Valid), (0x119E5, Disallowed), (0x11A00, Valid), (0x11A48, Disallowed),
+    (0x11A50, Valid), (0x11AA3, Disallowed), (0x11AC0, Valid), (0x11AF9, Disallowed),
+    (0x11C00, Valid), (0x11C09, Disallowed), (0x11C0A, Valid), (0x11C37, Disallowed),
+    (0x11C38, Valid), (0x11C46, Disallowed), (0x11C50, Valid), (0x11C6D, Disallowed),
+    (0x11C70, Valid), (0x11C90, Disallowed), (0x11C92, Valid), (0x11CA8, Disallowed),
+    (0x11CA9, Valid), (0x11CB7, Disallowed), (0x11D00, Valid), (0x11D07, Disallowed),
+    (0x11D08, Valid), (0x11D0A, Disallowed), (0x11D0B, Valid), (0x11D37, Disallowed),
+    (0x11D3A, Valid), (0x11D3B, Disallowed), (0x11D3C, Valid), (0x11D3E, Disallowed),
+    (0x11D3F, Valid), (0x11D48, Disallowed), (0x11D50, Valid), (0x11D5A, Disallowed),
+    (0x11D60, Valid), (0x11D66, Disallowed), (0x11D67, Valid), (0x11D69, Disallowed),
+    (0x11D6A, Valid), (0x11D8F, Disallowed), (0x11D90, Valid), (0x11D92, Disallowed),
+    (0x11D93, Valid), (0x11D99, Disallowed), (0x11DA0, Valid), (0x11DAA, Disallowed),
+    (0x11EE0, Valid), (0x11EF9, Disallowed), (0x11FB0, Valid), (0x11FB1, Disallowed),
+    (0x11FC0, Valid), (0x11FF2, Disallowed), (0x11FFF, Valid), (0x1239A, Disallowed),
+    (0x12400, Valid), (0x1246F, Disallowed), (0x12470, Valid), (0x12475, Disallowed),
+    (0x12480, Valid), (0x12544, Disallowed), (0x13000, Valid), (0x1342F, Disallowed),
+    (0x14400, Valid), (0x14647, Disallowed), (0x16800, Valid), (0x16A39, Disallowed),
+    (0x16A40, Valid), (0x16A5F, Disallowed), (0x16A60, Valid), (0x16A6A, Disallowed),
+    (0x16A6E, Valid), (0x16A70, Disallowed), (0x16AD0, Valid), (0x16AEE, Disallowed),
+    (0x16AF0, Valid), (0x16AF6, Disallowed), (0x16B00, Valid), (0x16B46, Disallowed),
+    (0x16B50, Valid), (0x16B5A, Disallowed), (0x16B5B, Valid), (0x16B62, Disallowed),
+    (0x16B63, Valid), (0x16B78, Disallowed), (0x16B7D, Valid), (0x16B90, Disallowed),
+    (0x16E40, Shifted(0x16E60)), (0x16E60, Valid), (0x16E9B, Disallowed), (0x16F00, Valid),
+    (0x16F4B, Disallowed), (0x16F4F, Valid), (0x16F88, Disallowed), (0x16F8F, Valid),
+    (0x16FA0, Disallowed), (0x16FE0, Valid), (0x16FE5, Disallowed), (0x16FF0, Valid),
+    (0x16FF2, Disallowed), (0x17000, Valid), (0x187F8, Disallowed), (0x18800, Valid),
+    (0x18CD6, Disallowed), (0x18D00, Valid), (0x18D09, Disallowed), (0x1B000, Valid),
+    (0x1B11F, Disallowed), (0x1B150, Valid), (0x1B153, Disallowed), (0x1B164, Valid),
+    (0x1B168, Disallowed), (0x1B170, Valid), (0x1B2FC, Disallowed), (0x1BC00, Valid),
+    (0x1BC6B, Disallowed), (0x1BC70, Valid), (0x1BC7D, Disallowed), (0x1BC80, Valid),
+    (0x1BC89, Disallowed), (0x1BC90, Valid), (0x1BC9A, Disallowed), (0x1BC9C, Valid),
+    (0x1BCA0, Ignored), (0x1BCA4, Disallowed), (0x1D000, Valid), (0x1D0F6, Disallowed),
+    (0x1D100, Valid), (0x1D127, Disallowed), (0x1D129, Valid),
+    (0x1D15E, Mapped("\u{1D157}\u{1D165}")), (0x1D15F, Mapped("\u{1D158}\u{1D165}")),
+    (0x1D160, Mapped("\u{1D158}\u{1D165}\u{1D16E}")),
+    (0x1D161, Mapped("\u{1D158}\u{1D165}\u{1D16F}")),
+    (0x1D162, Mapped("\u{1D158}\u{1D165}\u{1D170}")),
+    (0x1D163, Mapped("\u{1D158}\u{1D165}\u{1D171}")),
+    (0x1D164, Mapped("\u{1D158}\u{1D165}\u{1D172}")), (0x1D165, Valid), (0x1D173, Disallowed),
+    (0x1D17B, Valid), (0x1D1BB, Mapped("\u{1D1B9}\u{1D165}")),
+    (0x1D1BC, Mapped("\u{1D1BA}\u{1D165}")), (0x1D1BD, Mapped("\u{1D1B9}\u{1D165}\u{1D16E}")),
+    (0x1D1BE, Mapped("\u{1D1BA}\u{1D165}\u{1D16E}")),
+    (0x1D1BF, Mapped("\u{1D1B9}\u{1D165}\u{1D16F}")),
+    (0x1D1C0, Mapped("\u{1D1BA}\u{1D165}\u{1D16F}")), (0x1D1C1, Valid), (0x1D1E9, Disallowed),
+    (0x1D200, Valid), (0x1D246, Disallowed), (0x1D2E0, Valid), (0x1D2F4, Disallowed),
+    (0x1D300, Valid), (0x1D357, Disallowed), (0x1D360, Valid), (0x1D379, Disallowed),
+    (0x1D400, Shifted(0x0061)), (0x1D41A, Shifted(0x0061)), (0x1D434, Shifted(0x0061)),
+    (0x1D44E, Shifted(0x0061)), (0x1D455, Disallowed), (0x1D456, Shifted(0x0069)),
+    (0x1D468, Shifted(0x0061)), (0x1D482, Shifted(0x0061)), (0x1D49C, Mapped("a")),
+    (0x1D49D, Disallowed), (0x1D49E, Shifted(0x0063)), (0x1D4A0, Disallowed),
+    (0x1D4A2, Mapped("g")), (0x1D4A3, Disallowed), (0x1D4A5, Shifted(0x006A)),
+    (0x1D4A7, Disallowed), (0x1D4A9, Shifted(0x006E)), (0x1D4AD, Disallowed),
+    (0x1D4AE, Shifted(0x0073)), (0x1D4B6, Shifted(0x0061)), (0x1D4BA, Disallowed),
+    (0x1D4BB, Mapped("f")), (0x1D4BC, Disallowed), (0x1D4BD, Shifted(0x0068)),
+    (0x1D4C4, Disallowed), (0x1D4C5, Shifted(0x0070)), (0x1D4D0, Shifted(0x0061)),
+    (0x1D4EA, Shifted(0x0061)), (0x1D504, Shifted(0x0061)), (0x1D506, Disallowed),
+    (0x1D507, Shifted(0x0064)), (0x1D50B, Disallowed), (0x1D50D, Shifted(0x006A)),
+    (0x1D515, Disallowed), (0x1D516, Shifted(0x0073)), (0x1D51D, Disallowed),
+    (0x1D51E, Shifted(0x0061)), (0x1D538, Shifted(0x0061)), (0x1D53A, Disallowed),
+    (0x1D53B, Shifted(0x0064)), (0x1D53F, Disallowed), (0x1D540, Shifted(0x0069)),
+    (0x1D545, Disallowed), (0x1D546, Mapped("o")), (0x1D547, Disallowed),
+    (0x1D54A, Shifted(0x0073)), (0x1D551, Disallowed), (0x1D552, Shifted(0x0061)),
+    (0x1D56C, Shifted(0x0061)), (0x1D586, Shifted(0x0061)), (0x1D5A0, Shifted(0x0061)),
+    (0x1D5BA, Shifted(0x0061)), (0x1D5D4, Shifted(0x0061)), (0x1D5EE, Shifted(0x0061)),
+    (0x1D608, Shifted(0x0061)), (0x1D622, Shifted(0x0061)), (0x1D63C, Shifted(0x0061)),
+    (0x1D656, Shifted(0x0061)), (0x1D670, Shifted(0x0061)), (0x1D68A, Shifted(0x0061)),
+    (0x1D6A4, Mapped("\u{131}")), (0x1D6A5, Mapped("\u{237}")), (0x1D6A6, Disallowed),
+    (0x1D6A8, Shifted(0x03B1)), (0x1D6B9, Mapped("\u{3B8}")), (0x1D6BA, Shifted(0x03C3)),
+    (0x1D6C1, Mapped("\u{2207}")), (0x1D6C2, Shifted(0x03B1)), (0x1D6D3, Mapped("\u{3C3}")),
+    (0x1D6D5, Shifted(0x03C4)), (0x1D6DB, Mapped("\u{2202}")), (0x1D6DC, Mapped("\u{3B5}")),
+    (0x1D6DD, Mapped("\u{3B8}")), (0x1D6DE, Mapped("\u{3BA}")), (0x1D6DF, Mapped("\u{3C6}")),
+    (0x1D6E0, Mapped("\u{3C1}")), (0x1D6E1, Mapped("\u{3C0}")), (0x1D6E2, Shifted(0x03B1)),
+    (0x1D6F3, Mapped("\u{3B8}")), (0x1D6F4, Shifted(0x03C3)), (0x1D6FB, Mapped("\u{2207}")),
+    (0x1D6FC, Shifted(0x03B1)), (0x1D70D, Mapped("\u{3C3}")), (0x1D70F, Shifted(0x03C4)),
+    (0x1D715, Mapped("\u{2202}")), (0x1D716, Mapped("\u{3B5}")), (0x1D717, Mapped("\u{3B8}")),
+    (0x1D718, Mapped("\u{3BA}")), (0x1D719, Mapped("\u{3C6}")), (0x1D71A, Mapped("\u{3C1}")),
+    (0x1D71B, Mapped("\u{3C0}")), (0x1D71C, Shifted(0x03B1)), (0x1D72D, Mapped("\u{3B8}")),
+    (0x1D72E, Shifted(0x03C3)), (0x1D735, Mapped("\u{2207}")), (0x1D736, Shifted(0x03B1)),
+    (0x1D747, Mapped("\u{3C3}")), (0x1D749, Shifted(0x03C4)), (0x1D74F, Mapped("\u{2202}")),
+    (0x1D750, Mapped("\u{3B5}")), (0x1D751, Mapped("\u{3B8}")), (0x1D752, Mapped("\u{3BA}")),
+    (0x1D753, Mapped("\u{3C6}")), (0x1D754, Mapped("\u{3C1}")), (0x1D755, Mapped("\u{3C0}")),
+    (0x1D756, Shifted(0x03B1)), (0x1D767, Mapped("\u{3B8}")), (0x1D768, Shifted(0x03C3)),
+    (0x1D76F, Mapped("\u{2207}")), (0x1D770, Shifted(0x03B1)), (0x1D781, Mapped("\u{3C3}")),
+    (0x1D783, Shifted(0x03C4)), (0x1D789, Mapped("\u{2202}")), (0x1D78A, Mapped("\u{3B5}")),
+    (0x1D78B, Mapped("\u{3B8}")), (0x1D78C, Mapped("\u{3BA}")), (0x1D78D, Mapped("\u{3C6}")),
+    (0x1D78E, Mapped("\u{3C1}")), (0x1D78F, Mapped("\u{3C0}")), (0x1D790, Shifted(0x03B1)),
+    (0x1D7A1, Mapped("\u{3B8}")), (0x1D7A2, Shifted(0x03C3)), (0x1D7A9, Mapped("\u{2207}")),
+    (0x1D7AA, Shifted(0x03B1)), (0x1D7BB, Mapped("\u{3C3}")), (0x1D7BD, Shifted(0x03C4)),
+    (0x1D7C3, Mapped("\u{2202}")), (0x1D7C4, Mapped("\u{3B5}")), (0x1D7C5, Mapped("\u{3B8}")),
+    (0x1D7C6, Mapped("\u{3BA}")), (0x1D7C7, Mapped("\u{3C6}")), (0x1D7C8, Mapped("\u{3C1}")),
+    (0x1D7C9, Mapped("\u{3C0}")), (0x1D7CA, Mapped("\u{3DD}")), (0x1D7CC, Disallowed),
+    (0x1D7CE, Shifted(0x0030)), (0x1D7D8, Shifted(0x0030)), (0x1D7E2, Shifted(0x0030)),
+    (0x1D7EC, Shifted(0x0030)), (0x1D7F6, Shifted(0x0030)), (0x1D800, Valid), (0x1DA8C, Disallowed),
+    (0x1DA9B, Valid), (0x1DAA0, Disallowed), (0x1DAA1, Valid), (0x1DAB0, Disallowed),
+    (0x1E000, Valid), (0x1E007, Disallowed), (0x1E008, Valid), (0x1E019, Disallowed),
+    (0x1E01B, Valid), (0x1E022, Disallowed), (0x1E023, Valid), (0x1E025, Disallowed),
+    (0x1E026, Valid), (0x1E02B, Disallowed), (0x1E100, Valid), (0x1E12D, Disallowed),
+    (0x1E130, Valid), (0x1E13E, Disallowed), (0x1E140, Valid), (0x1E14A, Disallowed),
+    (0x1E14E, Valid), (0x1E150, Disallowed), (0x1E2C0, Valid), (0x1E2FA, Disallowed),
+    (0x1E2FF, Valid), (0x1E300, Disallowed), (0x1E800, Valid), (0x1E8C5, Disallowed),
+    (0x1E8C7, Valid), (0x1E8D7, Disallowed), (0x1E900, Shifted(0x1E922)), (0x1E922, Valid),
+    (0x1E94C, Disallowed), (0x1E950, Valid), (0x1E95A, Disallowed), (0x1E95E, Valid),
+    (0x1E960, Disallowed), (0x1EC71, Valid), (0x1ECB5, Disallowed), (0x1ED01, Valid),
+    (0x1ED3E, Disallowed), (0x1EE00, Shifted(0x0627)), (0x1EE02, Mapped("\u{62C}")),
+    (0x1EE03, Mapped("\u{62F}")), (0x1EE04, Disallowed), (0x1EE05, Mapped("\u{648}")),
+    (0x1EE06, Mapped("\u{632}")), (0x1EE07, Mapped("\u{62D}")), (0x1EE08, Mapped("\u{637}")),
+    (0x1EE09, Mapped("\u{64A}")), (0x1EE0A, Shifted(0x0643)), (0x1EE0E, Mapped("\u{633}")),
+    (0x1EE0F, Mapped("\u{639}")), (0x1EE10, Mapped("\u{641}")), (0x1EE11, Mapped("\u{635}")),
+    (0x1EE12, Mapped("\u{642}")), (0x1EE13, Mapped("\u{631}")), (0x1EE14, Mapped("\u{634}")),
+    (0x1EE15, Shifted(0x062A)), (0x1EE17, Mapped("\u{62E}")), (0x1EE18, Mapped("\u{630}")),
+    (0x1EE19, Mapped("\u{636}")), (0x1EE1A, Mapped("\u{638}")), (0x1EE1B, Mapped("\u{63A}")),
+    (0x1EE1C, Mapped("\u{66E}")), (0x1EE1D, Mapped("\u{6BA}")), (0x1EE1E, Mapped("\u{6A1}")),
+    (0x1EE1F, Mapped("\u{66F}")), (0x1EE20, Disallowed), (0x1EE21, Mapped("\u{628}")),
+    (0x1EE22, Mapped("\u{62C}")), (0x1EE23, Disallowed), (0x1EE24, Mapped("\u{647}")),
+    (0x1EE25, Disallowed), (0x1EE27, Mapped("\u{62D}")), (0x1EE28, Disallowed),
+    (0x1EE29, Mapped("\u{64A}")), (0x1EE2A, Shifted(0x0643)), (0x1EE2E, Mapped("\u{633}")),
+    (0x1EE2F, Mapped("\u{639}")), (0x1EE30, Mapped("\u{641}")), (0x1EE31, Mapped("\u{635}")),
+    (0x1EE32, Mapped("\u{642}")), (0x1EE33, Disallowed), (0x1EE34, Mapped("\u{634}")),
+    (0x1EE35, Shifted(0x062A)), (0x1EE37, Mapped("\u{62E}")), (0x1EE38, Disallowed),
+    (0x1EE39, Mapped("\u{636}")), (0x1EE3A, Disallowed), (0x1EE3B, Mapped("\u{63A}")),
+    (0x1EE3C, Disallowed), (0x1EE42, Mapped("\u{62C}")), (0x1EE43, Disallowed),
+    (0x1EE47, Mapped("\u{62D}")), (0x1EE48, Disallowed), (0x1EE49, Mapped("\u{64A}")),
+    (0x1EE4A, Disallowed), (0x1EE4B, Mapped("\u{644}")), (0x1EE4C, Disallowed),
+    (0x1EE4D, Mapped("\u{646}")), (0x1EE4E, Mapped("\u{633}")), (0x1EE4F, Mapped("\u{639}")),
+    (0x1EE50, Disallowed), (0x1EE51, Mapped("\u{635}")), (0x1EE52, Mapped("\u{642}")),
+    (0x1EE53, Disallowed), (0x1EE54, Mapped("\u{634}")), (0x1EE55, Disallowed),
+    (0x1EE57, Mapped("\u{62E}")), (0x1EE58, Disallowed), (0x1EE59, Mapped("\u{636}")),
+    (0x1EE5A, Disallowed), (0x1EE5B, Mapped("\u{63A}")), (0x1EE5C, Disallowed),
+    (0x1EE5D, Mapped("\u{6BA}")), (0x1EE5E, Disallowed), (0x1EE5F, Mapped("\u{66F}")),
+    (0x1EE60, Disallowed), (0x1EE61, Mapped("\u{628}")), (0x1EE62, Mapped("\u{62C}")),
+    (0x1EE63, Disallowed), (0x1EE64, Mapped("\u{647}")), (0x1EE65, Disallowed),
+    (0x1EE67, Mapped("\u{62D}")), (0x1EE68, Mapped("\u{637}")), (0x1EE69, Mapped("\u{64A}")),
+    (0x1EE6A, Mapped("\u{643}")), (0x1EE6B, Disallowed), (0x1EE6C, Shifted(0x0645)),
+    (0x1EE6E, Mapped("\u{633}")), (0x1EE6F, Mapped("\u{639}")), (0x1EE70, Mapped("\u{641}")),
+    (0x1EE71, Mapped("\u{635}")), (0x1EE72, Mapped("\u{642}")), (0x1EE73, Disallowed),
+    (0x1EE74, Mapped("\u{634}")), (0x1EE75, Shifted(0x062A)), (0x1EE77, Mapped("\u{62E}")),
+    (0x1EE78, Disallowed), (0x1EE79, Mapped("\u{636}")), (0x1EE7A, Mapped("\u{638}")),
+    (0x1EE7B, Mapped("\u{63A}")), (0x1EE7C, Mapped("\u{66E}")), (0x1EE7D, Disallowed),
+    (0x1EE7E, Mapped("\u{6A1}")), (0x1EE7F, Disallowed), (0x1EE80, Shifted(0x0627)),
+    (0x1EE82, Mapped("\u{62C}")), (0x1EE83, Mapped("\u{62F}")), (0x1EE84, Shifted(0x0647)),
+    (0x1EE86, Mapped("\u{632}")), (0x1EE87, Mapped("\u{62D}")), (0x1EE88, Mapped("\u{637}")),
+    (0x1EE89, Mapped("\u{64A}")), (0x1EE8A, Disallowed), (0x1EE8B, Shifted(0x0644)),
+    (0x1EE8E, Mapped("\u{633}")), (0x1EE8F, Mapped("\u{639}")), (0x1EE90, Mapped("\u{641}")),
+    (0x1EE91, Mapped("\u{635}")), (0x1EE92, Mapped("\u{642}")), (0x1EE93, Mapped("\u{631}")),
+    (0x1EE94, Mapped("\u{634}")), (0x1EE95, Shifted(0x062A)), (0x1EE97, Mapped("\u{62E}")),
+    (0x1EE98, Mapped("\u{630}")), (0x1EE99, Mapped("\u{636}")), (0x1EE9A, Mapped("\u{638}")),
+    (0x1EE9B, Mapped("\u{63A}")), (0x1EE9C, Disallowed), (0x1EEA1, Mapped("\u{628}")),
+    (0x1EEA2, Mapped("\u{62C}")), (0x1EEA3, Mapped("\u{62F}")), (0x1EEA4, Disallowed),
+    (0x1EEA5, Mapped("\u{648}")), (0x1EEA6, Mapped("\u{632}")), (0x1EEA7, Mapped("\u{62D}")),
+    (0x1EEA8, Mapped("\u{637}")), (0x1EEA9, Mapped("\u{64A}")), (0x1EEAA, Disallowed),
+    (0x1EEAB, Shifted(0x0644)), (0x1EEAE, Mapped("\u{633}")), (0x1EEAF, Mapped("\u{639}")),
+    (0x1EEB0, Mapped("\u{641}")), (0x1EEB1, Mapped("\u{635}")), (0x1EEB2, Mapped("\u{642}")),
+    (0x1EEB3, Mapped("\u{631}")), (0x1EEB4, Mapped("\u{634}")), (0x1EEB5, Shifted(0x062A)),
+    (0x1EEB7, Mapped("\u{62E}")), (0x1EEB8, Mapped("\u{630}")), (0x1EEB9, Mapped("\u{636}")),
+    (0x1EEBA, Mapped("\u{638}")), (0x1EEBB, Mapped("\u{63A}")), (0x1EEBC, Disallowed),
+    (0x1EEF0, Valid), (0x1EEF2, Disallowed), (0x1F000, Valid), (0x1F02C, Disallowed),
+    (0x1F030, Valid), (0x1F094, Disallowed), (0x1F0A0, Valid), (0x1F0AF, Disallowed),
+    (0x1F0B1, Valid), (0x1F0C0, Disallowed), (0x1F0C1, Valid), (0x1F0D0, Disallowed),
+    (0x1F0D1, Valid), (0x1F0F6, Disallowed), (0x1F101, Mapped("0,")), (0x1F102, Mapped("1,")),
+    (0x1F103, Mapped("2,")), (0x1F104, Mapped("3,")), (0x1F105, Mapped("4,")),
+    (0x1F106, Mapped("5,")), (0x1F107, Mapped("6,")), (0x1F108, Mapped("7,")),
+    (0x1F109, Mapped("8,")), (0x1F10A, Mapped("9,")), (0x1F10B, Valid), (0x1F110, Mapped("(a)")),
+    (0x1F111, Mapped("(b)")), (0x1F112, Mapped("(c)")), (0x1F113, Mapped("(d)")),
+    (0x1F114, Mapped("(e)")), (0x1F115, Mapped("(f)")), (0x1F116, Mapped("(g)")),
+    (0x1F117, Mapped("(h)")), (0x1F118, Mapped("(i)")), (0x1F119, Mapped("(j)")),
+    (0x1F11A, Mapped("(k)")), (0x1F11B, Mapped("(l)")), (0x1F11C, Mapped("(m)")),
+    (0x1F11D, Mapped("(n)")), (0x1F11E, Mapped("(o)")), (0x1F11F, Mapped("(p)")),
+    (0x1F120, Mapped("(q)")), (0x1F121, Mapped("(r)")), (0x1F122, Mapped("(s)")),
+    (0x1F123, Mapped("(t)")), (0x1F124, Mapped("(u)")), (0x1F125, Mapped("(v)")),
+    (0x1F126, Mapped("(w)")), (0x1F127, Mapped("(x)")), (0x1F128, Mapped("(y)")),
+    (0x1F129, Mapped("(z)")), (0x1F12A, Mapped("\u{3014}s\u{3015}")), (0x1F12B, Mapped("c")),
+    (0x1F12C, Mapped("r")), (0x1F12D, Mapped("cd")), (0x1F12E, Mapped("wz")), (0x1F12F, Valid),
+    (0x1F130, Shifted(0x0061)), (0x1F14A, Mapped("hv")), (0x1F14B, Mapped("mv")),
+    (0x1F14C, Mapped("sd")), (0x1F14D, Mapped("ss")), (0x1F14E, Mapped("ppv")),
+    (0x1F14F, Mapped("wc")), (0x1F150, Valid), (0x1F16A, Mapped("mc")), (0x1F16B, Mapped("md")),
+    (0x1F16C, Mapped("mr")), (0x1F16D, Valid), (0x1F190, Mapped("dj")), (0x1F191, Valid),
+    (0x1F1AE, Disallowed), (0x1F1E6, Valid), (0x1F200, Mapped("\u{307B}\u{304B}")),
+    (0x1F201, Mapped("\u{30B3}\u{30B3}")), (0x1F202, Mapped("\u{30B5}")), (0x1F203, Disallowed),
+    (0x1F210, Mapped("\u{624B}")), (0x1F211, Mapped("\u{5B57}")), (0x1F212, Mapped("\u{53CC}")),
+    (0x1F213, Mapped("\u{30C7}")), (0x1F214, Mapped("\u{4E8C}")), (0x1F215, Mapped("\u{591A}")),
+    (0x1F216, Mapped("\u{89E3}")), (0x1F217, Mapped("\u{5929}")), (0x1F218, Mapped("\u{4EA4}")),
+    (0x1F219, Mapped("\u{6620}")), (0x1F21A, Mapped("\u{7121}")), (0x1F21B, Mapped("\u{6599}")),
+    (0x1F21C, Mapped("\u{524D}")), (0x1F21D, Mapped("\u{5F8C}")), (0x1F21E, Mapped("\u{518D}")),
+    (0x1F21F, Mapped("\u{65B0}")), (0x1F220, Mapped("\u{521D}")), (0x1F221, Mapped("\u{7D42}")),
+    (0x1F222, Mapped("\u{751F}")), (0x1F223, Mapped("\u{8CA9}")), (0x1F224, Mapped("\u{58F0}")),
+    (0x1F225, Mapped("\u{5439}")), (0x1F226, Mapped("\u{6F14}")), (0x1F227, Mapped("\u{6295}")),
+    (0x1F228, Mapped("\u{6355}")), (0x1F229, Mapped("\u{4E00}")), (0x1F22A, Mapped("\u{4E09}")),
+    (0x1F22B, Mapped("\u{904A}")), (0x1F22C, Mapped("\u{5DE6}")), (0x1F22D, Mapped("\u{4E2D}")),
+    (0x1F22E, Mapped("\u{53F3}")), (0x1F22F, Mapped("\u{6307}")), (0x1F230, Mapped("\u{8D70}")),
+    (0x1F231, Mapped("\u{6253}")), (0x1F232, Mapped("\u{7981}")), (0x1F233, Mapped("\u{7A7A}")),
+    (0x1F234, Mapped("\u{5408}")), (0x1F235, Mapped("\u{6E80}")), (0x1F236, Mapped("\u{6709}")),
+    (0x1F237, Mapped("\u{6708}")), (0x1F238, Mapped("\u{7533}")), (0x1F239, Mapped("\u{5272}")),
+    (0x1F23A, Mapped("\u{55B6}")), (0x1F23B, Mapped("\u{914D}")), (0x1F23C, Disallowed),
+    (0x1F240, Mapped("\u{3014}\u{672C}\u{3015}")), (0x1F241, Mapped("\u{3014}\u{4E09}\u{3015}")),
+    (0x1F242, Mapped("\u{3014}\u{4E8C}\u{3015}")), (0x1F243, Mapped("\u{3014}\u{5B89}\u{3015}")),
+    (0x1F244, Mapped("\u{3014}\u{70B9}\u{3015}")), (0x1F245, Mapped("\u{3014}\u{6253}\u{3015}")),
+    (0x1F246, Mapped("\u{3014}\u{76D7}\u{3015}")), (0x1F247, Mapped("\u{3014}\u{52DD}\u{3015}")),
+    (0x1F248, Mapped("\u{3014}\u{6557}\u{3015}")), (0x1F249, Disallowed),
+    (0x1F250, Mapped("\u{5F97}")), (0x1F251, Mapped("\u{53EF}")), (0x1F252, Disallowed),
+    (0x1F260, Valid), (0x1F266, Disallowed), (0x1F300, Valid), (0x1F6D8, Disallowed),
+    (0x1F6E0, Valid), (0x1F6ED, Disallowed), (0x1F6F0, Valid), (0x1F6FD, Disallowed),
+    (0x1F700, Valid), (0x1F774, Disallowed), (0x1F780, Valid), (0x1F7D9, Disallowed),
+    (0x1F7E0, Valid), (0x1F7EC, Disallowed), (0x1F800, Valid), (0x1F80C, Disallowed),
+    (0x1F810, Valid), (0x1F848, Disallowed), (0x1F850, Valid), (0x1F85A, Disallowed),
+    (0x1F860, Valid), (0x1F888, Disallowed), (0x1F890, Valid), (0x1F8AE, Disallowed),
+    (0x1F8B0, Valid), (0x1F8B2, Disallowed), (0x1F900, Valid), (0x1F979, Disallowed),
+    (0x1F97A, Valid), (0x1F9CC, Disallowed), (0x1F9CD, Valid), (0x1FA54, Disallowed),
+    (0x1FA60, Valid), (0x1FA6E, Disallowed), (0x1FA70, Valid), (0x1FA75, Disallowed),
+    (0x1FA78, Valid), (0x1FA7B, Disallowed), (0x1FA80, Valid), (0x1FA87, Disallowed),
+    (0x1FA90, Valid), (0x1FAA9, Disallowed), (0x1FAB0, Valid), (0x1FAB7, Disallowed),
+    (0x1FAC0, Valid), (0x1FAC3, Disallowed), (0x1FAD0, Valid), (0x1FAD7, Disallowed),
+    (0x1FB00, Valid), (0x1FB93, Disallowed), (0x1FB94, Valid), (0x1FBCB, Disallowed),
+    (0x1FBF0, Shifted(0x0030)), (0x1FBFA, Disallowed), (0x20000, Valid), (0x2A6DE, Disallowed),
+    (0x2A700, Valid), (0x2B735, Disallowed), (0x2B740, Valid), (0x2B81E, Disallowed),
+    (0x2B820, Valid), (0x2CEA2, Disallowed), (0x2CEB0, Valid), (0x2EBE1, Disallowed),
+    (0x2F800, Mapped("\u{4E3D}")), (0x2F801, Mapped("\u{4E38}")), (0x2F802, Mapped("\u{4E41}")),
+    (0x2F803, Mapped("\u{20122}")), (0x2F804, Mapped("\u{4F60}")), (0x2F805, Mapped("\u{4FAE}")),
+    (0x2F806, Mapped("\u{4FBB}")), (0x2F807, Mapped("\u{5002}")), (0x2F808, Mapped("\u{507A}")),
+    (0x2F809, Mapped("\u{5099}")), (0x2F80A, Mapped("\u{50E7}")), (0x2F80B, Mapped("\u{50CF}")),
+    (0x2F80C, Mapped("\u{349E}")), (0x2F80D, Mapped("\u{2063A}")), (0x2F80E, Mapped("\u{514D}")),
+    (0x2F80F, Mapped("\u{5154}")), (0x2F810, Mapped("\u{5164}")), (0x2F811, Mapped("\u{5177}")),
+    (0x2F812, Mapped("\u{2051C}")), (0x2F813, Mapped("\u{34B9}")), (0x2F814, Mapped("\u{5167}")),
+    (0x2F815, Mapped("\u{518D}")), (0x2F816, Mapped("\u{2054B}")), (0x2F817, Mapped("\u{5197}")),
+    (0x2F818, Mapped("\u{51A4}")), (0x2F819, Mapped("\u{4ECC}")), (0x2F81A, Mapped("\u{51AC}")),
+    (0x2F81B, Mapped("\u{51B5}")), (0x2F81C, Mapped("\u{291DF}")), (0x2F81D, Mapped("\u{51F5}")),
+    (0x2F81E, Mapped("\u{5203}")), (0x2F81F, Mapped("\u{34DF}")), (0x2F820, Mapped("\u{523B}")),
+    (0x2F821, Mapped("\u{5246}")), (0x2F822, Mapped("\u{5272}")), (0x2F823, Mapped("\u{5277}")),
+    (0x2F824, Mapped("\u{3515}")), (0x2F825, Mapped("\u{52C7}")), (0x2F826, Mapped("\u{52C9}")),
+    (0x2F827, Mapped("\u{52E4}")), (0x2F828, Mapped("\u{52FA}")), (0x2F829, Shifted(0x5305)),
+    (0x2F82B, Mapped("\u{5317}")), (0x2F82C, Mapped("\u{5349}")), (0x2F82D, Mapped("\u{5351}")),
+    (0x2F82E, Mapped("\u{535A}")), (0x2F82F, Mapped("\u{5373}")), (0x2F830, Mapped("\u{537D}")),
+    (0x2F831, Mapped("\u{537F}")), (0x2F834, Mapped("\u{20A2C}")), (0x2F835, Mapped("\u{7070}")),
+    (0x2F836, Mapped("\u{53CA}")), (0x2F837, Mapped("\u{53DF}")), (0x2F838, Mapped("\u{20B63}")),
+    (0x2F839, Mapped("\u{53EB}")), (0x2F83A, Mapped("\u{53F1}")), (0x2F83B, Mapped("\u{5406}")),
+    (0x2F83C, Mapped("\u{549E}")), (0x2F83D, Mapped("\u{5438}")), (0x2F83E, Mapped("\u{5448}")),
+    (0x2F83F, Mapped("\u{5468}")), (0x2F840, Mapped("\u{54A2}")), (0x2F841, Mapped("\u{54F6}")),
+    (0x2F842, Mapped("\u{5510}")), (0x2F843, Mapped("\u{5553}")), (0x2F844, Mapped("\u{5563}")),
+    (0x2F845, Mapped("\u{5584}")), (0x2F847, Mapped("\u{5599}")), (0x2F848, Mapped("\u{55AB}")),
+    (0x2F849, Mapped("\u{55B3}")), (0x2F84A, Mapped("\u{55C2}")), (0x2F84B, Mapped("\u{5716}")),
+    (0x2F84C, Mapped("\u{5606}")), (0x2F84D, Mapped("\u{5717}")), (0x2F84E, Mapped("\u{5651}")),
+    (0x2F84F, Mapped("\u{5674}")), (0x2F850, Mapped("\u{5207}")), (0x2F851, Mapped("\u{58EE}")),
+    (0x2F852, Mapped("\u{57CE}")), (0x2F853, Mapped("\u{57F4}")), (0x2F854, Mapped("\u{580D}")),
+    (0x2F855, Mapped("\u{578B}")), (0x2F856, Mapped("\u{5832}")), (0x2F857, Mapped("\u{5831}")),
+    (0x2F858, Mapped("\u{58AC}")), (0x2F859, Mapped("\u{214E4}")), (0x2F85A, Mapped("\u{58F2}")),
+    (0x2F85B, Mapped("\u{58F7}")), (0x2F85C, Mapped("\u{5906}")), (0x2F85D, Mapped("\u{591A}")),
+    (0x2F85E, Mapped("\u{5922}")), (0x2F85F, Mapped("\u{5962}")), (0x2F860, Mapped("\u{216A8}")),
+    (0x2F861, Mapped("\u{216EA}")), (0x2F862, Mapped("\u{59EC}")), (0x2F863, Mapped("\u{5A1B}")),
+    (0x2F864, Mapped("\u{5A27}")), (0x2F865, Mapped("\u{59D8}")), (0x2F866, Mapped("\u{5A66}")),
+    (0x2F867, Mapped("\u{36EE}")), (0x2F868, Disallowed), (0x2F869, Mapped("\u{5B08}")),
+    (0x2F86A, Mapped("\u{5B3E}")), (0x2F86C, Mapped("\u{219C8}")), (0x2F86D, Mapped("\u{5BC3}")),
+    (0x2F86E, Mapped("\u{5BD8}")), (0x2F86F, Mapped("\u{5BE7}")), (0x2F870, Mapped("\u{5BF3}")),
+    (0x2F871, Mapped("\u{21B18}")), (0x2F872, Mapped("\u{5BFF}")), (0x2F873, Mapped("\u{5C06}")),
+    (0x2F874, Disallowed), (0x2F875, Mapped("\u{5C22}")), (0x2F876, Mapped("\u{3781}")),
+    (0x2F877, Mapped("\u{5C60}")), (0x2F878, Mapped("\u{5C6E}")), (0x2F879, Mapped("\u{5CC0}")),
+    (0x2F87A, Mapped("\u{5C8D}")), (0x2F87B, Mapped("\u{21DE4}")), (0x2F87C, Mapped("\u{5D43}")),
+    (0x2F87D, Mapped("\u{21DE6}")), (0x2F87E, Mapped("\u{5D6E}")), (0x2F87F, Mapped("\u{5D6B}")),
+    (0x2F880, Mapped("\u{5D7C}")), (0x2F881, Shifted(0x5DE1)), (0x2F883, Mapped("\u{382F}")),
+    (0x2F884, Mapped("\u{5DFD}")), (0x2F885, Mapped("\u{5E28}")), (0x2F886, Mapped("\u{5E3D}")),
+    (0x2F887, Mapped("\u{5E69}")), (0x2F888, Mapped("\u{3862}")), (0x2F889, Mapped("\u{22183}")),
+    (0x2F88A, Mapped("\u{387C}")), (0x2F88B, Mapped("\u{5EB0}")), (0x2F88C, Mapped("\u{5EB3}")),
+    (0x2F88D, Mapped("\u{5EB6}")), (0x2F88E, Mapped("\u{5ECA}")), (0x2F88F, Mapped("\u{2A392}")),
+    (0x2F890, Mapped("\u{5EFE}")), (0x2F891, Mapped("\u{22331}")), (0x2F893, Mapped("\u{8201}")),
+    (0x2F894, Mapped("\u{5F22}")), (0x2F896, Mapped("\u{38C7}")), (0x2F897, Mapped("\u{232B8}")),
+    (0x2F898, Mapped("\u{261DA}")), (0x2F899, Mapped("\u{5F62}")), (0x2F89A, Mapped("\u{5F6B}")),
+    (0x2F89B, Mapped("\u{38E3}")), (0x2F89C, Mapped("\u{5F9A}")), (0x2F89D, Mapped("\u{5FCD}")),
+    (0x2F89E, Mapped("\u{5FD7}")), (0x2F89F, Mapped("\u{5FF9}")), (0x2F8A0, Mapped("\u{6081}")),
+    (0x2F8A1, Mapped("\u{393A}")), (0x2F8A2, Mapped("\u{391C}")), (0x2F8A3, Mapped("\u{6094}")),
+    (0x2F8A4, Mapped("\u{226D4}")), (0x2F8A5, Mapped("\u{60C7}")), (0x2F8A6, Mapped("\u{6148}")),
+    (0x2F8A7, Mapped("\u{614C}")), (0x2F8A8, Mapped("\u{614E}")), (0x2F8A9, Mapped("\u{614C}")),
+    (0x2F8AA, Mapped("\u{617A}")), (0x2F8AB, Mapped("\u{618E}")), (0x2F8AC, Mapped("\u{61B2}")),
+    (0x2F8AD, Mapped("\u{61A4}")), (0x2F8AE, Mapped("\u{61AF}")), (0x2F8AF, Mapped("\u{61DE}")),
+    (0x2F8B0, Mapped("\u{61F2}")), (0x2F8B1, Mapped("\u{61F6}")), (0x2F8B2, Mapped("\u{6210}")),
+    (0x2F8B3, Mapped("\u{621B}")), (0x2F8B4, Mapped("\u{625D}")), (0x2F8B5, Mapped("\u{62B1}")),
+    (0x2F8B6, Mapped("\u{62D4}")), (0x2F8B7, Mapped("\u{6350}")), (0x2F8B8, Mapped("\u{22B0C}")),
+    (0x2F8B9, Mapped("\u{633D}")), (0x2F8BA, Mapped("\u{62FC}")), (0x2F8BB, Mapped("\u{6368}")),
+    (0x2F8BC, Mapped("\u{6383}")), (0x2F8BD, Mapped("\u{63E4}")), (0x2F8BE, Mapped("\u{22BF1}")),
+    (0x2F8BF, Mapped("\u{6422}")), (0x2F8C0, Mapped("\u{63C5}")), (0x2F8C1, Mapped("\u{63A9}")),
+    (0x2F8C2, Mapped("\u{3A2E}")), (0x2F8C3, Mapped("\u{6469}")), (0x2F8C4, Mapped("\u{647E}")),
+    (0x2F8C5, Mapped("\u{649D}")), (0x2F8C6, Mapped("\u{6477}")), (0x2F8C7, Mapped("\u{3A6C}")),
+    (0x2F8C8, Mapped("\u{654F}")), (0x2F8C9, Mapped("\u{656C}")), (0x2F8CA, Mapped("\u{2300A}")),
+    (0x2F8CB, Mapped("\u{65E3}")), (0x2F8CC, Mapped("\u{66F8}")), (0x2F8CD, Mapped("\u{6649}")),
+    (0x2F8CE, Mapped("\u{3B19}")), (0x2F8CF, Mapped("\u{6691}")), (0x2F8D0, Mapped("\u{3B08}")),
+    (0x2F8D1, Mapped("\u{3AE4}")), (0x2F8D2, Mapped("\u{5192}")), (0x2F8D3, Mapped("\u{5195}")),
+    (0x2F8D4, Mapped("\u{6700}")), (0x2F8D5, Mapped("\u{669C}")), (0x2F8D6, Mapped("\u{80AD}")),
+    (0x2F8D7, Mapped("\u{43D9}")), (0x2F8D8, Mapped("\u{6717}")), (0x2F8D9, Mapped("\u{671B}")),
+    (0x2F8DA, Mapped("\u{6721}")), (0x2F8DB, Mapped("\u{675E}")), (0x2F8DC, Mapped("\u{6753}")),
+    (0x2F8DD, Mapped("\u{233C3}")), (0x2F8DE, Mapped("\u{3B49}")), (0x2F8DF, Mapped("\u{67FA}")),
+    (0x2F8E0, Mapped("\u{6785}")), (0x2F8E1, Mapped("\u{6852}")), (0x2F8E2, Mapped("\u{6885}")),
+    (0x2F8E3, Mapped("\u{2346D}")), (0x2F8E4, Mapped("\u{688E}")), (0x2F8E5, Mapped("\u{681F}")),
+    (0x2F8E6, Mapped("\u{6914}")), (0x2F8E7, Mapped("\u{3B9D}")), (0x2F8E8, Mapped("\u{6942}")),
+    (0x2F8E9, Mapped("\u{69A3}")), (0x2F8EA, Mapped("\u{69EA}")), (0x2F8EB, Mapped("\u{6AA8}")),
+    (0x2F8EC, Mapped("\u{236A3}")), (0x2F8ED, Mapped("\u{6ADB}")), (0x2F8EE, Mapped("\u{3C18}")),
+    (0x2F8EF, Mapped("\u{6B21}")), (0x2F8F0, Mapped("\u{238A7}")), (0x2F8F1, Mapped("\u{6B54}")),
+    (0x2F8F2, Mapped("\u{3C4E}")), (0x2F8F3, Mapped("\u{6B72}")), (0x2F8F4, Mapped("\u{6B9F}")),
+    (0x2F8F5, Shifted(0x6BBA)), (0x2F8F7, Mapped("\u{23A8D}")), (0x2F8F8, Mapped("\u{21D0B}")),
+    (0x2F8F9, Mapped("\u{23AFA}")), (0x2F8FA, Mapped("\u{6C4E}")), (0x2F8FB, Mapped("\u{23CBC}")),
+    (0x2F8FC, Mapped("\u{6CBF}")), (0x2F8FD, Mapped("\u{6CCD}")), (0x2F8FE, Mapped("\u{6C67}")),
+    (0x2F8FF, Mapped("\u{6D16}")), (0x2F900, Mapped("\u{6D3E}")), (0x2F901, Mapped("\u{6D77}")),
+    (0x2F902, Mapped("\u{6D41}")), (0x2F903, Mapped("\u{6D69}")), (0x2F904, Mapped("\u{6D78}")),
+    (0x2F905, Mapped("\u{6D85}")), (0x2F906, Mapped("\u{23D1E}")), (0x2F907, Mapped("\u{6D34}")),
+    (0x2F908, Mapped("\u{6E2F}")), (0x2F909, Mapped("\u{6E6E}")), (0x2F90A, Mapped("\u{3D33}")),
+    (0x2F90B, Mapped("\u{6ECB}")), (0x2F90C, Mapped("\u{6EC7}")), (0x2F90D, Mapped("\u{23ED1}")),
+    (0x2F90E, Mapped("\u{6DF9}")), (0x2F90F, Mapped("\u{6F6E}")), (0x2F910, Mapped("\u{23F5E}")),
+    (0x2F911, Mapped("\u{23F8E}")), (0x2F912, Mapped("\u{6FC6}")), (0x2F913, Mapped("\u{7039}")),
+    (0x2F914, Mapped("\u{701E}")), (0x2F915, Mapped("\u{701B}")), (0x2F916, Mapped("\u{3D96}")),
+    (0x2F917, Mapped("\u{704A}")), (0x2F918, Mapped("\u{707D}")), (0x2F919, Mapped("\u{7077}")),
+    (0x2F91A, Mapped("\u{70AD}")), (0x2F91B, Mapped("\u{20525}")), (0x2F91C, Mapped("\u{7145}")),
+    (0x2F91D, Mapped("\u{24263}")), (0x2F91E, Mapped("\u{719C}")), (0x2F91F, Disallowed),
+    (0x2F920, Mapped("\u{7228}")), (0x2F921, Mapped("\u{7235}")), (0x2F922, Mapped("\u{7250}")),
+    (0x2F923, Mapped("\u{24608}")), (0x2F924, Mapped("\u{7280}")), (0x2F925, Mapped("\u{7295}")),
+    (0x2F926, Mapped("\u{24735}")), (0x2F927, Mapped("\u{24814}")), (0x2F928, Mapped("\u{737A}")),
+    (0x2F929, Mapped("\u{738B}")), (0x2F92A, Mapped("\u{3EAC}")), (0x2F92B, Mapped("\u{73A5}")),
+    (0x2F92C, Mapped("\u{3EB8}")), (0x2F92E, Mapped("\u{7447}")), (0x2F92F, Mapped("\u{745C}")),
+    (0x2F930, Mapped("\u{7471}")), (0x2F931, Mapped("\u{7485}")), (0x2F932, Mapped("\u{74CA}")),
+    (0x2F933, Mapped("\u{3F1B}")), (0x2F934, Mapped("\u{7524}")), (0x2F935, Mapped("\u{24C36}")),
+    (0x2F936, Mapped("\u{753E}")), (0x2F937, Mapped("\u{24C92}")), (0x2F938, Mapped("\u{7570}")),
+    (0x2F939, Mapped("\u{2219F}")), (0x2F93A, Mapped("\u{7610}")), (0x2F93B, Mapped("\u{24FA1}")),
+    (0x2F93C, Mapped("\u{24FB8}")), (0x2F93D, Mapped("\u{25044}")), (0x2F93E, Mapped("\u{3FFC}")),
+    (0x2F93F, Mapped("\u{4008}")), (0x2F940, Mapped("\u{76F4}")), (0x2F941, Mapped("\u{250F3}")),
+    (0x2F942, Mapped("\u{250F2}")), (0x2F943, Mapped("\u{25119}")), (0x2F944, Mapped("\u{25133}")),
+    (0x2F945, Shifted(0x771E)), (0x2F947, Mapped("\u{771F}")), (0x2F948, Mapped("\u{774A}")),
+    (0x2F949, Mapped("\u{4039}")), (0x2F94A, Mapped("\u{778B}")), (0x2F94B, Mapped("\u{4046}")),
+    (0x2F94C, Mapped("\u{4096}")), (0x2F94D, Mapped("\u{2541D}")), (0x2F94E, Mapped("\u{784E}")),
+    (0x2F94F, Mapped("\u{788C}")), (0x2F950, Mapped("\u{78CC}")), (0x2F951, Mapped("\u{40E3}")),
+    (0x2F952, Mapped("\u{25626}")), (0x2F953, Mapped("\u{7956}")), (0x2F954, Mapped("\u{2569A}")),
+    (0x2F955, Mapped("\u{256C5}")), (0x2F956, Mapped("\u{798F}")), (0x2F957, Mapped("\u{79EB}")),
+    (0x2F958, Mapped("\u{412F}")), (0x2F959, Mapped("\u{7A40}")), (0x2F95A, Mapped("\u{7A4A}")),
+    (0x2F95B, Mapped("\u{7A4F}")), (0x2F95C, Mapped("\u{2597C}")), (0x2F95D, Mapped("\u{25AA7}")),
+    (0x2F95F, Disallowed), (0x2F960, Mapped("\u{4202}")), (0x2F961, Mapped("\u{25BAB}")),
+    (0x2F962, Mapped("\u{7BC6}")), (0x2F963, Mapped("\u{7BC9}")), (0x2F964, Mapped("\u{4227}")),
+    (0x2F965, Mapped("\u{25C80}")), (0x2F966, Mapped("\u{7CD2}")), (0x2F967, Mapped("\u{42A0}")),
+    (0x2F968, Mapped("\u{7CE8}")), (0x2F969, Mapped("\u{7CE3}")), (0x2F96A, Mapped("\u{7D00}")),
+    (0x2F96B, Mapped("\u{25F86}")), (0x2F96C, Mapped("\u{7D63}")), (0x2F96D, Mapped("\u{4301}")),
+    (0x2F96E, Mapped("\u{7DC7}")), (0x2F96F, Mapped("\u{7E02}")), (0x2F970, Mapped("\u{7E45}")),
+    (0x2F971, Mapped("\u{4334}")), (0x2F972, Mapped("\u{26228}")), (0x2F973, Mapped("\u{26247}")),
+    (0x2F974, Mapped("\u{4359}")), (0x2F975, Mapped("\u{262D9}")), (0x2F976, Mapped("\u{7F7A}")),
+    (0x2F977, Mapped("\u{2633E}")), (0x2F978, Mapped("\u{7F95}")), (0x2F979, Mapped("\u{7FFA}")),
+    (0x2F97A, Mapped("\u{8005}")), (0x2F97B, Mapped("\u{264DA}")), (0x2F97C, Mapped("\u{26523}")),
+    (0x2F97D, Mapped("\u{8060}")), (0x2F97E, Mapped("\u{265A8}")), (0x2F97F, Mapped("\u{8070}")),
+    (0x2F980, Mapped("\u{2335F}")), (0x2F981, Mapped("\u{43D5}")), (0x2F982, Mapped("\u{80B2}")),
+    (0x2F983, Mapped("\u{8103}")), (0x2F984, Mapped("\u{440B}")), (0x2F985, Mapped("\u{813E}")),
+    (0x2F986, Mapped("\u{5AB5}")), (0x2F987, Mapped("\u{267A7}")), (0x2F988, Mapped("\u{267B5}")),
+    (0x2F989, Mapped("\u{23393}")), (0x2F98A, Mapped("\u{2339C}")), (0x2F98B, Mapped("\u{8201}")),
+    (0x2F98C, Mapped("\u{8204}")), (0x2F98D, Mapped("\u{8F9E}")), (0x2F98E, Mapped("\u{446B}")),
+    (0x2F98F, Mapped("\u{8291}")), (0x2F990, Mapped("\u{828B}")), (0x2F991, Mapped("\u{829D}")),
+    (0x2F992, Mapped("\u{52B3}")), (0x2F993, Mapped("\u{82B1}")), (0x2F994, Mapped("\u{82B3}")),
+    (0x2F995, Mapped("\u{82BD}")), (0x2F996, Mapped("\u{82E6}")), (0x2F997, Mapped("\u{26B3C}")),
+    (0x2F998, Mapped("\u{82E5}")), (0x2F999, Mapped("\u{831D}")), (0x2F99A, Mapped("\u{8363}")),
+    (0x2F99B, Mapped("\u{83AD}")), (0x2F99C, Mapped("\u{8323}")), (0x2F99D, Mapped("\u{83BD}")),
+    (0x2F99E, Mapped("\u{83E7}")), (0x2F99F, Mapped("\u{8457}")), (0x2F9A0, Mapped("\u{8353}")),
+    (0x2F9A1, Mapped("\u{83CA}")), (0x2F9A2, Mapped("\u{83CC}")), (0x2F9A3, Mapped("\u{83DC}")),
+    (0x2F9A4, Mapped("\u{26C36}")), (0x2F9A5, Mapped("\u{26D6B}")), (0x2F9A6, Mapped("\u{26CD5}")),
+    (0x2F9A7, Mapped("\u{452B}")), (0x2F9A8, Mapped("\u{84F1}")), (0x2F9A9, Mapped("\u{84F3}")),
+    (0x2F9AA, Mapped("\u{8516}")), (0x2F9AB, Mapped("\u{273CA}")), (0x2F9AC, Mapped("\u{8564}")),
+    (0x2F9AD, Mapped("\u{26F2C}")), (0x2F9AE, Mapped("\u{455D}")), (0x2F9AF, Mapped("\u{4561}")),
+    (0x2F9B0, Mapped("\u{26FB1}")), (0x2F9B1, Mapped("\u{270D2}")), (0x2F9B2, Mapped("\u{456B}")),
+    (0x2F9B3, Mapped("\u{8650}")), (0x2F9B4, Mapped("\u{865C}")), (0x2F9B5, Mapped("\u{8667}")),
+    (0x2F9B6, Mapped("\u{8669}")), (0x2F9B7, Mapped("\u{86A9}")), (0x2F9B8, Mapped("\u{8688}")),
+    (0x2F9B9, Mapped("\u{870E}")), (0x2F9BA, Mapped("\u{86E2}")), (0x2F9BB, Mapped("\u{8779}")),
+    (0x2F9BC, Mapped("\u{8728}")), (0x2F9BD, Mapped("\u{876B}")), (0x2F9BE, Mapped("\u{8786}")),
+    (0x2F9BF, Disallowed), (0x2F9C0, Mapped("\u{87E1}")), (0x2F9C1, Mapped("\u{8801}")),
+    (0x2F9C2, Mapped("\u{45F9}")), (0x2F9C3, Mapped("\u{8860}")), (0x2F9C4, Mapped("\u{8863}")),
+    (0x2F9C5, Mapped("\u{27667}")), (0x2F9C6, Mapped("\u{88D7}")), (0x2F9C7, Mapped("\u{88DE}")),
+    (0x2F9C8, Mapped("\u{4635}")), (0x2F9C9, Mapped("\u{88FA}")), (0x2F9CA, Mapped("\u{34BB}")),
+    (0x2F9CB, Mapped("\u{278AE}")), (0x2F9CC, Mapped("\u{27966}")), (0x2F9CD, Mapped("\u{46BE}")),
+    (0x2F9CE, Mapped("\u{46C7}")), (0x2F9CF, Mapped("\u{8AA0}")), (0x2F9D0, Mapped("\u{8AED}")),
+    (0x2F9D1, Mapped("\u{8B8A}")), (0x2F9D2, Mapped("\u{8C55}")), (0x2F9D3, Mapped("\u{27CA8}")),
+    (0x2F9D4, Mapped("\u{8CAB}")), (0x2F9D5, Mapped("\u{8CC1}")), (0x2F9D6, Mapped("\u{8D1B}")),
+    (0x2F9D7, Mapped("\u{8D77}")), (0x2F9D8, Mapped("\u{27F2F}")), (0x2F9D9, Mapped("\u{20804}")),
+    (0x2F9DA, Mapped("\u{8DCB}")), (0x2F9DB, Mapped("\u{8DBC}")), (0x2F9DC, Mapped("\u{8DF0}")),
+    (0x2F9DD, Mapped("\u{208DE}")), (0x2F9DE, Mapped("\u{8ED4}")), (0x2F9DF, Mapped("\u{8F38}")),
+    (0x2F9E0, Mapped("\u{285D2}")), (0x2F9E1, Mapped("\u{285ED}")), (0x2F9E2, Mapped("\u{9094}")),
+    (0x2F9E3, Mapped("\u{90F1}")), (0x2F9E4, Mapped("\u{9111}")), (0x2F9E5, Mapped("\u{2872E}")),
+    (0x2F9E6, Mapped("\u{911B}")), (0x2F9E7, Mapped("\u{9238}")), (0x2F9E8, Shifted(0x92D7)),
+    (0x2F9EA, Mapped("\u{927C}")), (0x2F9EB, Mapped("\u{93F9}")), (0x2F9EC, Mapped("\u{9415}")),
+    (0x2F9ED, Mapped("\u{28BFA}")), (0x2F9EE, Mapped("\u{958B}")), (0x2F9EF, Mapped("\u{4995}")),
+    (0x2F9F0, Mapped("\u{95B7}")), (0x2F9F1, Mapped("\u{28D77}")), (0x2F9F2, Mapped("\u{49E6}")),
+    (0x2F9F3, Mapped("\u{96C3}")), (0x2F9F4, Mapped("\u{5DB2}")), (0x2F9F5, Mapped("\u{9723}")),
+    (0x2F9F6, Mapped("\u{29145}")), (0x2F9F7, Mapped("\u{2921A}")), (0x2F9F8, Mapped("\u{4A6E}")),
+    (0x2F9F9, Mapped("\u{4A76}")), (0x2F9FA, Mapped("\u{97E0}")), (0x2F9FB, Mapped("\u{2940A}")),
+    (0x2F9FC, Mapped("\u{4AB2}")), (0x2F9FD, Mapped("\u{29496}")), (0x2F9FE, Mapped("\u{980B}")),
+    (0x2FA00, Mapped("\u{9829}")), (0x2FA01, Mapped("\u{295B6}")), (0x2FA02, Mapped("\u{98E2}")),
+    (0x2FA03, Mapped("\u{4B33}")), (0x2FA04, Mapped("\u{9929}")), (0x2FA05, Mapped("\u{99A7}")),
+    (0x2FA06, Mapped("\u{99C2}")), (0x2FA07, Mapped("\u{99FE}")), (0x2FA08, Mapped("\u{4BCE}")),
+    (0x2FA09, Mapped("\u{29B30}")), (0x2FA0A, Mapped("\u{9B12}")), (0x2FA0B, Mapped("\u{9C40}")),
+    (0x2FA0C, Mapped("\u{9CFD}")), (0x2FA0D, Mapped("\u{4CCE}")), (0x2FA0E, Mapped("\u{4CED}")),
+    (0x2FA0F, Mapped("\u{9D67}")), (0x2FA10, Mapped("\u{2A0CE}")), (0x2FA11, Mapped("\u{4CF8}")),
+    (0x2FA12, Mapped("\u{2A105}")), (0x2FA13, Mapped("\u{2A20E}")), (0x2FA14, Mapped("\u{2A291}")),
+    (0x2FA15, Mapped("\u{9EBB}")), (0x2FA16, Mapped("\u{4D56}")), (0x2FA17, Mapped("\u{9EF9}")),
+    (0x2FA18, Mapped("\u{9EFE}")), (0x2FA19, Mapped("\u{9F05}")), (0x2FA1A, Mapped("\u{9F0F}")),
+    (0x2FA1B, Mapped("\u{9F16}")), (0x2FA1C, Mapped("\u{9F3B}")), (0x2FA1D, Mapped("\u{2A600}")),
+    (0x2FA1E, Disallowed), (0x30000, Valid), (0x3134B, Disallowed), (0xE0100, Ignored),
+    (0xE01F0, Disallowed),
+];
+
+/// Canonical combining classes: `(first, last, class)`.
+#[rustfmt::skip]
+pub(super) static COMBINING_CLASS: [(u32, u32, u8); 382] = [
+    (0x0300, 0x0314, 230), (0x0315, 0x0315, 232), (0x0316, 0x0319, 220), (0x031A, 0x031A, 232),
+    (0x031B, 0x031B, 216), (0x031C, 0x0320, 220), (0x0321, 0x0322, 202), (0x0323, 0x0326, 220),
+    (0x0327, 0x0328, 202), (0x0329, 0x0333, 220), (0x0334, 0x0338, 1), (0x0339, 0x033C, 220),
+    (0x033D, 0x0344, 230), (0x0345, 0x0345, 240), (0x0346, 0x0346, 230), (0x0347, 0x0349, 220),
+    (0x034A, 0x034C, 230), (0x034D, 0x034E, 220), (0x0350, 0x0352, 230), (0x0353, 0x0356, 220),
+    (0x0357, 0x0357, 230), (0x0358, 0x0358, 232), (0x0359, 0x035A, 220), (0x035B, 0x035B, 230),
+    (0x035C, 0x035C, 233), (0x035D, 0x035E, 234), (0x035F, 0x035F, 233), (0x0360, 0x0361, 234),
+    (0x0362, 0x0362, 233), (0x0363, 0x036F, 230), (0x0483, 0x0487, 230), (0x0591, 0x0591, 220),
+    (0x0592, 0x0595, 230), (0x0596, 0x0596, 220), (0x0597, 0x0599, 230), (0x059A, 0x059A, 222),
+    (0x059B, 0x059B, 220), (0x059C, 0x05A1, 230), (0x05A2, 0x05A7, 220), (0x05A8, 0x05A9, 230),
+    (0x05AA, 0x05AA, 220), (0x05AB, 0x05AC, 230), (0x05AD, 0x05AD, 222), (0x05AE, 0x05AE, 228),
+    (0x05AF, 0x05AF, 230), (0x05B0, 0x05B0, 10), (0x05B1, 0x05B1, 11), (0x05B2, 0x05B2, 12),
+    (0x05B3, 0x05B3, 13), (0x05B4, 0x05B4, 14), (0x05B5, 0x05B5, 15), (0x05B6, 0x05B6, 16),
+    (0x05B7, 0x05B7, 17), (0x05B8, 0x05B8, 18), (0x05B9, 0x05BA, 19), (0x05BB, 0x05BB, 20),
+    (0x05BC, 0x05BC, 21), (0x05BD, 0x05BD, 22), (0x05BF, 0x05BF, 23), (0x05C1, 0x05C1, 24),
+    (0x05C2, 0x05C2, 25), (0x05C4, 0x05C4, 230), (0x05C5, 0x05C5, 220), (0x05C7, 0x05C7, 18),
+    (0x0610, 0x0617, 230), (0x0618, 0x0618, 30), (0x0619, 0x0619, 31), (0x061A, 0x061A, 32),
+    (0x064B, 0x064B, 27), (0x064C, 0x064C, 28), (0x064D, 0x064D, 29), (0x064E, 0x064E, 30),
+    (0x064F, 0x064F, 31), (0x0650, 0x0650, 32), (0x0651, 0x0651, 33), (0x0652, 0x0652, 34),
+    (0x0653, 0x0654, 230), (0x0655, 0x0656, 220), (0x0657, 0x065B, 230), (0x065C, 0x065C, 220),
+    (0x065D, 0x065E, 230), (0x065F, 0x065F, 220), (0x0670, 0x0670, 35), (0x06D6, 0x06DC, 230),
+    (0x06DF, 0x06E2, 230), (0x06E3, 0x06E3, 220), (0x06E4, 0x06E4, 230), (0x06E7, 0x06E8, 230),
+    (0x06EA, 0x06EA, 220), (0x06EB, 0x06EC, 230), (0x06ED, 0x06ED, 220), (0x0711, 0x0711, 36),
+    (0x0730, 0x0730, 230), (0x0731, 0x0731, 220), (0x0732, 0x0733, 230), (0x0734, 0x0734, 220),
+    (0x0735, 0x0736, 230), (0x0737, 0x0739, 220), (0x073A, 0x073A, 230), (0x073B, 0x073C, 220),
+    (0x073D, 0x073D, 230), (0x073E, 0x073E, 220), (0x073F, 0x0741, 230), (0x0742, 0x0742, 220),
+    (0x0743, 0x0743, 230), (0x0744, 0x0744, 220), (0x0745, 0x0745, 230), (0x0746, 0x0746, 220),
+    (0x0747, 0x0747, 230), (0x0748, 0x0748, 220), (0x0749, 0x074A, 230), (0x07EB, 0x07F1, 230),
+    (0x07F2, 0x07F2, 220), (0x07F3, 0x07F3, 230), (0x07FD, 0x07FD, 220), (0x0816, 0x0819, 230),
+    (0x081B, 0x0823, 230), (0x0825, 0x0827, 230), (0x0829, 0x082D, 230), (0x0859, 0x085B, 220),
+    (0x0898, 0x0898, 230), (0x0899, 0x089B, 220), (0x089C, 0x089F, 230), (0x08CA, 0x08CE, 230),
+    (0x08CF, 0x08D3, 220), (0x08D4, 0x08E1, 230), (0x08E3, 0x08E3, 220), (0x08E4, 0x08E5, 230),
+    (0x08E6, 0x08E6, 220), (0x08E7, 0x08E8, 230), (0x08E9, 0x08E9, 220), (0x08EA, 0x08EC, 230),
+    (0x08ED, 0x08EF, 220), (0x08F0, 0x08F0, 27), (0x08F1, 0x08F1, 28), (0x08F2, 0x08F2, 29),
+    (0x08F3, 0x08F5, 230), (0x08F6, 0x08F6, 220), (0x08F7, 0x08F8, 230), (0x08F9, 0x08FA, 220),
+    (0x08FB, 0x08FF, 230), (0x093C, 0x093C, 7), (0x094D, 0x094D, 9), (0x0951, 0x0951, 230),
+    (0x0952, 0x0952, 220), (0x0953, 0x0954, 230), (0x09BC, 0x09BC, 7), (0x09CD, 0x09CD, 9),
+    (0x09FE, 0x09FE, 230), (0x0A3C, 0x0A3C, 7), (0x0A4D, 0x0A4D, 9), (0x0ABC, 0x0ABC, 7),
+    (0x0ACD, 0x0ACD, 9), (0x0B3C, 0x0B3C, 7), (0x0B4D, 0x0B4D, 9), (0x0BCD, 0x0BCD, 9),
+    (0x0C3C, 0x0C3C, 7), (0x0C4D, 0x0C4D, 9), (0x0C55, 0x0C55, 84), (0x0C56, 0x0C56, 91),
+    (0x0CBC, 0x0CBC, 7), (0x0CCD, 0x0CCD, 9), (0x0D3B, 0x0D3C, 9), (0x0D4D, 0x0D4D, 9),
+    (0x0DCA, 0x0DCA, 9), (0x0E38, 0x0E39, 103), (0x0E3A, 0x0E3A, 9), (0x0E48, 0x0E4B, 107),
+    (0x0EB8, 0x0EB9, 118), (0x0EBA, 0x0EBA, 9), (0x0EC8, 0x0ECB, 122), (0x0F18, 0x0F19, 220),
+    (0x0F35, 0x0F35, 220), (0x0F37, 0x0F37, 220), (0x0F39, 0x0F39, 216), (0x0F71, 0x0F71, 129),
+    (0x0F72, 0x0F72, 130), (0x0F74, 0x0F74, 132), (0x0F7A, 0x0F7D, 130), (0x0F80, 0x0F80, 130),
+    (0x0F82, 0x0F83, 230), (0x0F84, 0x0F84, 9), (0x0F86, 0x0F87, 230), (0x0FC6, 0x0FC6, 220),
+    (0x1037, 0x1037, 7), (0x1039, 0x103A, 9), (0x108D, 0x108D, 220), (0x135D, 0x135F, 230),
+    (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17D2, 0x17D2, 9), (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228), (0x1939, 0x1939, 222), (0x193A, 0x193A, 230), (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230), (0x1A18, 0x1A18, 220), (0x1A60, 0x1A60, 9), (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220), (0x1AB0, 0x1AB4, 230), (0x1AB5, 0x1ABA, 220), (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220), (0x1ABF, 0x1AC0, 220), (0x1AC1, 0x1AC2, 230), (0x1AC3, 0x1AC4, 220),
+    (0x1AC5, 0x1AC9, 230), (0x1ACA, 0x1ACA, 220), (0x1ACB, 0x1ACE, 230), (0x1B34, 0x1B34, 7),
+    (0x1B44, 0x1B44, 9), (0x1B6B, 0x1B6B, 230), (0x1B6C, 0x1B6C, 220), (0x1B6D, 0x1B73, 230),
+    (0x1BAA, 0x1BAB, 9), (0x1BE6, 0x1BE6, 7), (0x1BF2, 0x1BF3, 9), (0x1C37, 0x1C37, 7),
+    (0x1CD0, 0x1CD2, 230), (0x1CD4, 0x1CD4, 1), (0x1CD5, 0x1CD9, 220), (0x1CDA, 0x1CDB, 230),
+    (0x1CDC, 0x1CDF, 220), (0x1CE0, 0x1CE0, 230), (0x1CE2, 0x1CE8, 1), (0x1CED, 0x1CED, 220),
+    (0x1CF4, 0x1CF4, 230), (0x1CF8, 0x1CF9, 230), (0x1DC0, 0x1DC1, 230), (0x1DC2, 0x1DC2, 220),
+    (0x1DC3, 0x1DC9, 230), (0x1DCA, 0x1DCA, 220), (0x1DCB, 0x1DCC, 230), (0x1DCD, 0x1DCD, 234),
+    (0x1DCE, 0x1DCE, 214), (0x1DCF, 0x1DCF, 220), (0x1DD0, 0x1DD0, 202), (0x1DD1, 0x1DF5, 230),
+    (0x1DF6, 0x1DF6, 232), (0x1DF7, 0x1DF8, 228), (0x1DF9, 0x1DF9, 220), (0x1DFA, 0x1DFA, 218),
+    (0x1DFB, 0x1DFB, 230), (0x1DFC, 0x1DFC, 233), (0x1DFD, 0x1DFD, 220), (0x1DFE, 0x1DFE, 230),
+    (0x1DFF, 0x1DFF, 220), (0x20D0, 0x20D1, 230), (0x20D2, 0x20D3, 1), (0x20D4, 0x20D7, 230),
+    (0x20D8, 0x20DA, 1), (0x20DB, 0x20DC, 230), (0x20E1, 0x20E1, 230), (0x20E5, 0x20E6, 1),
+    (0x20E7, 0x20E7, 230), (0x20E8, 0x20E8, 220), (0x20E9, 0x20E9, 230), (0x20EA, 0x20EB, 1),
+    (0x20EC, 0x20EF, 220), (0x20F0, 0x20F0, 230), (0x2CEF, 0x2CF1, 230), (0x2D7F, 0x2D7F, 9),
+    (0x2DE0, 0x2DFF, 230), (0x302A, 0x302A, 218), (0x302B, 0x302B, 228), (0x302C, 0x302C, 232),
+    (0x302D, 0x302D, 222), (0x302E, 0x302F, 224), (0x3099, 0x309A, 8), (0xA66F, 0xA66F, 230),
+    (0xA674, 0xA67D, 230), (0xA69E, 0xA69F, 230), (0xA6F0, 0xA6F1, 230), (0xA806, 0xA806, 9),
+    (0xA82C, 0xA82C, 9), (0xA8C4, 0xA8C4, 9), (0xA8E0, 0xA8F1, 230), (0xA92B, 0xA92D, 220),
+    (0xA953, 0xA953, 9), (0xA9B3, 0xA9B3, 7), (0xA9C0, 0xA9C0, 9), (0xAAB0, 0xAAB0, 230),
+    (0xAAB2, 0xAAB3, 230), (0xAAB4, 0xAAB4, 220), (0xAAB7, 0xAAB8, 230), (0xAABE, 0xAABF, 230),
+    (0xAAC1, 0xAAC1, 230), (0xAAF6, 0xAAF6, 9), (0xABED, 0xABED, 9), (0xFB1E, 0xFB1E, 26),
+    (0xFE20, 0xFE26, 230), (0xFE27, 0xFE2D, 220), (0xFE2E, 0xFE2F, 230), (0x101FD, 0x101FD, 220),
+    (0x102E0, 0x102E0, 220), (0x10376, 0x1037A, 230), (0x10A0D, 0x10A0D, 220),
+    (0x10A0F, 0x10A0F, 230), (0x10A38, 0x10A38, 230), (0x10A39, 0x10A39, 1),
+    (0x10A3A, 0x10A3A, 220), (0x10A3F, 0x10A3F, 9), (0x10AE5, 0x10AE5, 230),
+    (0x10AE6, 0x10AE6, 220), (0x10D24, 0x10D27, 230), (0x10EAB, 0x10EAC, 230),
+    (0x10F46, 0x10F47, 220), (0x10F48, 0x10F4A, 230), (0x10F4B, 0x10F4B, 220),
+    (0x10F4C, 0x10F4C, 230), (0x10F4D, 0x10F50, 220), (0x10F82, 0x10F82, 230),
+    (0x10F83, 0x10F83, 220), (0x10F84, 0x10F84, 230), (0x10F85, 0x10F85, 220),
+    (0x11046, 0x11046, 9), (0x11070, 0x11070, 9), (0x1107F, 0x1107F, 9), (0x110B9, 0x110B9, 9),
+    (0x110BA, 0x110BA, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9), (0x11173, 0x11173, 7),
+    (0x111C0, 0x111C0, 9), (0x111CA, 0x111CA, 7), (0x11235, 0x11235, 9), (0x11236, 0x11236, 7),
+    (0x112E9, 0x112E9, 7), (0x112EA, 0x112EA, 9), (0x1133B, 0x1133C, 7), (0x1134D, 0x1134D, 9),
+    (0x11366, 0x1136C, 230), (0x11370, 0x11374, 230), (0x11442, 0x11442, 9), (0x11446, 0x11446, 7),
+    (0x1145E, 0x1145E, 230), (0x114C2, 0x114C2, 9), (0x114C3, 0x114C3, 7), (0x115BF, 0x115BF, 9),
+    (0x115C0, 0x115C0, 7), (0x1163F, 0x1163F, 9), (0x116B6, 0x116B6, 9), (0x116B7, 0x116B7, 7),
+    (0x1172B, 0x1172B, 9), (0x11839, 0x11839, 9), (0x1183A, 0x1183A, 7), (0x1193D, 0x1193E, 9),
+    (0x11943, 0x11943, 7), (0x119E0, 0x119E0, 9), (0x11A34, 0x11A34, 9), (0x11A47, 0x11A47, 9),
+    (0x11A99, 0x11A99, 9), (0x11C3F, 0x11C3F, 9), (0x11D42, 0x11D42, 7), (0x11D44, 0x11D45, 9),
+    (0x11D97, 0x11D97, 9), (0x16AF0, 0x16AF4, 1), (0x16B30, 0x16B36, 230), (0x16FF0, 0x16FF1, 6),
+    (0x1BC9E, 0x1BC9E, 1), (0x1D165, 0x1D166, 216), (0x1D167, 0x1D169, 1), (0x1D16D, 0x1D16D, 226),
+    (0x1D16E, 0x1D172, 216), (0x1D17B, 0x1D182, 220), (0x1D185, 0x1D189, 230),
+    (0x1D18A, 0x1D18B, 220), (0x1D1AA, 0x1D1AD, 230), (0x1D242, 0x1D244, 230),
+    (0x1E000, 0x1E006, 230), (0x1E008, 0x1E018, 230), (0x1E01B, 0x1E021, 230),
+    (0x1E023, 0x1E024, 230), (0x1E026, 0x1E02A, 230), (0x1E130, 0x1E136, 230),
+    (0x1E2AE, 0x1E2AE, 230), (0x1E2EC, 0x1E2EF, 230), (0x1E8D0, 0x1E8D6, 220),
+    (0x1E944, 0x1E949, 230), (0x1E94A, 0x1E94A, 7),
+];
+
+/// Canonical decompositions, one level deep: `(code point, first, second)`;
+/// `second` is 0 for singletons. Hangul syllables are algorithmic.
+#[rustfmt::skip]
+pub(super) static DECOMPOSITION: [(u32, u32, u32); 2061] = [
+    (0x00C0, 0x0041, 0x0300), (0x00C1, 0x0041, 0x0301), (0x00C2, 0x0041, 0x0302),
+    (0x00C3, 0x0041, 0x0303), (0x00C4, 0x0041, 0x0308), (0x00C5, 0x0041, 0x030A),
+    (0x00C7, 0x0043, 0x0327), (0x00C8, 0x0045, 0x0300), (0x00C9, 0x0045, 0x0301),
+    (0x00CA, 0x0045, 0x0302), (0x00CB, 0x0045, 0x0308), (0x00CC, 0x0049, 0x0300),
+    (0x00CD, 0x0049, 0x0301), (0x00CE, 0x0049, 0x0302), (0x00CF, 0x0049, 0x0308),
+    (0x00D1, 0x004E, 0x0303), (0x00D2, 0x004F, 0x0300), (0x00D3, 0x004F, 0x0301),
+    (0x00D4, 0x004F, 0x0302), (0x00D5, 0x004F, 0x0303), (0x00D6, 0x004F, 0x0308),
+    (0x00D9, 0x0055, 0x0300), (0x00DA, 0x0055, 0x0301), (0x00DB, 0x0055, 0x0302),
+    (0x00DC, 0x0055, 0x0308), (0x00DD, 0x0059, 0x0301), (0x00E0, 0x0061, 0x0300),
+    (0x00E1, 0x0061, 0x0301), (0x00E2, 0x0061, 0x0302), (0x00E3, 0x0061, 0x0303),
+    (0x00E4, 0x0061, 0x0308), (0x00E5, 0x0061, 0x030A), (0x00E7, 0x0063, 0x0327),
+    (0x00E8, 0x0065, 0x0300), (0x00E9, 0x0065, 0x0301), (0x00EA, 0x0065, 0x0302),
+    (0x00EB, 0x0065, 0x0308), (0x00EC, 0x0069, 0x0300), (0x00ED, 0x0069, 0x0301),
+    (0x00EE, 0x0069, 0x0302), (0x00EF, 0x0069, 0x0308), (0x00F1, 0x006E, 0x0303),
+    (0x00F2, 0x006F, 0x0300), (0x00F3, 0x006F, 0x0301), (0x00F4, 0x006F, 0x0302),
+    (0x00F5, 0x006F, 0x0303), (0x00F6, 0x006F, 0x0308), (0x00F9, 0x0075, 0x0300),
+    (0x00FA, 0x0075, 0x0301), (0x00FB, 0x0075, 0x0302), (0x00FC, 0x0075, 0x0308),
+    (0x00FD, 0x0079, 0x0301), (0x00FF, 0x0079, 0x0308), (0x0100, 0x0041, 0x0304),
+    (0x0101, 0x0061, 0x0304), (0x0102, 0x0041, 0x0306), (0x0103, 0x0061, 0x0306),
+    (0x0104, 0x0041, 0x0328), (0x0105, 0x0061, 0x0328), (0x0106, 0x0043, 0x0301),
+    (0x0107, 0x0063, 0x0301), (0x0108, 0x0043, 0x0302), (0x0109, 0x0063, 0x0302),
+    (0x010A, 0x0043, 0x0307), (0x010B, 0x0063, 0x0307), (0x010C, 0x0043, 0x030C),
+    (0x010D, 0x0063, 0x030C), (0x010E, 0x0044, 0x030C), (0x010F, 0x0064, 0x030C),
+    (0x0112, 0x0045, 0x0304), (0x0113, 0x0065, 0x0304), (0x0114, 0x0045, 0x0306),
+    (0x0115, 0x0065, 0x0306), (0x0116, 0x0045, 0x0307), (0x0117, 0x0065, 0x0307),
+    (0x0118, 0x0045, 0x0328), (0x0119, 0x0065, 0x0328), (0x011A, 0x0045, 0x030C),
+    (0x011B, 0x0065, 0x030C), (0x011C, 0x0047, 0x0302), (0x011D, 0x0067, 0x0302),
+    (0x011E, 0x0047, 0x0306), (0x011F, 0x0067, 0x0306), (0x0120, 0x0047, 0x0307),
+    (0x0121, 0x0067, 0x0307), (0x0122, 0x0047, 0x0327), (0x0123, 0x0067, 0x0327),
+    (0x0124, 0x0048, 0x0302), (0x0125, 0x0068, 0x0302), (0x0128, 0x0049, 0x0303),
+    (0x0129, 0x0069, 0x0303), (0x012A, 0x0049, 0x0304), (0x012B, 0x0069, 0x0304),
+    (0x012C, 0x0049, 0x0306), (0x012D, 0x0069, 0x0306), (0x012E, 0x0049, 0x0328),
+    (0x012F, 0x0069, 0x0328), (0x0130, 0x0049, 0x0307), (0x0134, 0x004A, 0x0302),
+    (0x0135, 0x006A, 0x0302), (0x0136, 0x004B, 0x0327), (0x0137, 0x006B, 0x0327),
+    (0x0139, 0x004C, 0x0301), (0x013A, 0x006C, 0x0301), (0x013B, 0x004C, 0x0327),
+    (0x013C, 0x006C, 0x0327), (0x013D, 0x004C, 0x030C), (0x013E, 0x006C, 0x030C),
+    (0x0143, 0x004E, 0x0301), (0x0144, 0x006E, 0x0301), (0x0145, 0x004E, 0x0327),
+    (0x0146, 0x006E, 0x0327), (0x0147, 0x004E, 0x030C), (0x0148, 0x006E, 0x030C),
+    (0x014C, 0x004F, 0x0304), (0x014D, 0x006F, 0x0304), (0x014E, 0x004F, 0x0306),
+    (0x014F, 0x006F, 0x0306), (0x0150, 0x004F, 0x030B), (0x0151, 0x006F, 0x030B),
+    (0x0154, 0x0052, 0x0301), (0x0155, 0x0072, 0x0301), (0x0156, 0x0052, 0x0327),
+    (0x0157, 0x0072, 0x0327), (0x0158, 0x0052, 0x030C), (0x0159, 0x0072, 0x030C),
+    (0x015A, 0x0053, 0x0301), (0x015B, 0x0073, 0x0301), (0x015C, 0x0053, 0x0302),
+    (0x015D, 0x0073, 0x0302), (0x015E, 0x0053, 0x0327), (0x015F, 0x0073, 0x0327),
+    (0x0160, 0x0053, 0x030C), (0x0161, 0x0073, 0x030C), (0x0162, 0x0054, 0x0327),
+    (0x0163, 0x0074, 0x0327), (0x0164, 0x0054, 0x030C), (0x0165, 0x0074, 0x030C),
+    (0x0168, 0x0055, 0x0303), (0x0169, 0x0075, 0x0303), (0x016A, 0x0055, 0x0304),
+    (0x016B, 0x0075, 0x0304), (0x016C, 0x0055, 0x0306), (0x016D, 0x0075, 0x0306),
+    (0x016E, 0x0055, 0x030A), (0x016F, 0x0075, 0x030A), (0x0170, 0x0055, 0x030B),
+    (0x0171, 0x0075, 0x030B), (0x0172, 0x0055, 0x0328), (0x0173, 0x0075, 0x0328),
+    (0x0174, 0x0057, 0x0302), (0x0175, 0x0077, 0x0302), (0x0176, 0x0059, 0x0302),
+    (0x0177, 0x0079, 0x0302), (0x0178, 0x0059, 0x0308), (0x0179, 0x005A, 0x0301),
+    (0x017A, 0x007A, 0x0301), (0x017B, 0x005A, 0x0307), (0x017C, 0x007A, 0x0307),
+    (0x017D, 0x005A, 0x030C), (0x017E, 0x007A, 0x030C), (0x01A0, 0x004F, 0x031B),
+    (0x01A1, 0x006F, 0x031B), (0x01AF, 0x0055, 0x031B), (0x01B0, 0x0075, 0x031B),
+    (0x01CD, 0x0041, 0x030C), (0x01CE, 0x0061, 0x030C), (0x01CF, 0x0049, 0x030C),
+    (0x01D0, 0x0069, 0x030C), (0x01D1, 0x004F, 0x030C), (0x01D2, 0x006F, 0x030C),
+    (0x01D3, 0x0055, 0x030C), (0x01D4, 0x0075, 0x030C), (0x01D5, 0x00DC, 0x0304),
+    (0x01D6, 0x00FC, 0x0304), (0x01D7, 0x00DC, 0x0301), (0x01D8, 0x00FC, 0x0301),
+    (0x01D9, 0x00DC, 0x030C), (0x01DA, 0x00FC, 0x030C), (0x01DB, 0x00DC, 0x0300),
+    (0x01DC, 0x00FC, 0x0300), (0x01DE, 0x00C4, 0x0304), (0x01DF, 0x00E4, 0x0304),
+    (0x01E0, 0x0226, 0x0304), (0x01E1, 0x0227, 0x0304), (0x01E2, 0x00C6, 0x0304),
+    (0x01E3, 0x00E6, 0x0304), (0x01E6, 0x0047, 0x030C), (0x01E7, 0x0067, 0x030C),
+    (0x01E8, 0x004B, 0x030C), (0x01E9, 0x006B, 0x030C), (0x01EA, 0x004F, 0x0328),
+    (0x01EB, 0x006F, 0x0328), (0x01EC, 0x01EA, 0x0304), (0x01ED, 0x01EB, 0x0304),
+    (0x01EE, 0x01B7, 0x030C), (0x01EF, 0x0292, 0x030C), (0x01F0, 0x006A, 0x030C),
+    (0x01F4, 0x0047, 0x0301), (0x01F5, 0x0067, 0x0301), (0x01F8, 0x004E, 0x0300),
+    (0x01F9, 0x006E, 0x0300), (0x01FA, 0x00C5, 0x0301), (0x01FB, 0x00E5, 0x0301),
+    (0x01FC, 0x00C6, 0x0301), (0x01FD, 0x00E6, 0x0301), (0x01FE, 0x00D8, 0x0301),
+    (0x01FF, 0x00F8, 0x0301), (0x0200, 0x0041, 0x030F), (0x0201, 0x0061, 0x030F),
+    (0x0202, 0x0041, 0x0311), (0x0203, 0x0061, 0x0311), (0x0204, 0x0045, 0x030F),
+    (0x0205, 0x0065, 0x030F), (0x0206, 0x0045, 0x0311), (0x0207, 0x0065, 0x0311),
+    (0x0208, 0x0049, 0x030F), (0x0209, 0x0069, 0x030F), (0x020A, 0x0049, 0x0311),
+    (0x020B, 0x0069, 0x0311), (0x020C, 0x004F, 0x030F), (0x020D, 0x006F, 0x030F),
+    (0x020E, 0x004F, 0x0311), (0x020F, 0x006F, 0x0311), (0x0210, 0x0052, 0x030F),
+    (0x0211, 0x0072, 0x030F), (0x0212, 0x0052, 0x0311), (0x0213, 0x0072, 0x0311),
+    (0x0214, 0x0055, 0x030F), (0x0215, 0x0075, 0x030F), (0x0216, 0x0055, 0x0311),
+    (0x0217, 0x0075, 0x0311), (0x0218, 0x0053, 0x0326), (0x0219, 0x0073, 0x0326),
+    (0x021A, 0x0054, 0x0326), (0x021B, 0x0074, 0x0326), (0x021E, 0x0048, 0x030C),
+    (0x021F, 0x0068, 0x030C), (0x0226, 0x0041, 0x0307), (0x0227, 0x0061, 0x0307),
+    (0x0228, 0x0045, 0x0327), (0x0229, 0x0065, 0x0327), (0x022A, 0x00D6, 0x0304),
+    (0x022B, 0x00F6, 0x0304), (0x022C, 0x00D5, 0x0304), (0x022D, 0x00F5, 0x0304),
+    (0x022E, 0x004F, 0x0307), (0x022F, 0x006F, 0x0307), (0x0230, 0x022E, 0x0304),
+    (0x0231, 0x022F, 0x0304), (0x0232, 0x0059, 0x0304), (0x0233, 0x0079, 0x0304),
+    (0x0340, 0x0300, 0x0000), (0x0341, 0x0301, 0x0000), (0x0343, 0x0313, 0x0000),
+    (0x0344, 0x0308, 0x0301), (0x0374, 0x02B9, 0x0000), (0x037E, 0x003B, 0x0000),
+    (0x0385, 0x00A8, 0x0301), (0x0386, 0x0391, 0x0301), (0x0387, 0x00B7, 0x0000),
+    (0x0388, 0x0395, 0x0301), (0x0389, 0x0397, 0x0301), (0x038A, 0x0399, 0x0301),
+    (0x038C, 0x039F, 0x0301), (0x038E, 0x03A5, 0x0301), (0x038F, 0x03A9, 0x0301),
+    (0x0390, 0x03CA, 0x0301), (0x03AA, 0x0399, 0x0308), (0x03AB, 0x03A5, 0x0308),
+    (0x03AC, 0x03B1, 0x0301), (0x03AD, 0x03B5, 0x0301), (0x03AE, 0x03B7, 0x0301),
+    (0x03AF, 0x03B9, 0x0301), (0x03B0, 0x03CB, 0x0301), (0x03CA, 0x03B9, 0x0308),
+    (0x03CB, 0x03C5, 0x0308), (0x03CC, 0x03BF, 0x0301), (0x03CD, 0x03C5, 0x0301),
+    (0x03CE, 0x03C9, 0x0301), (0x03D3, 0x03D2, 0x0301), (0x03D4, 0x03D2, 0x0308),
+    (0x0400, 0x0415, 0x0300), (0x0401, 0x0415, 0x0308), (0x0403, 0x0413, 0x0301),
+    (0x0407, 0x0406, 0x0308), (0x040C, 0x041A, 0x0301), (0x040D, 0x0418, 0x0300),
+    (0x040E, 0x0423, 0x0306), (0x0419, 0x0418, 0x0306), (0x0439, 0x0438, 0x0306),
+    (0x0450, 0x0435, 0x0300), (0x0451, 0x0435, 0x0308), (0x0453, 0x0433, 0x0301),
+    (0x0457, 0x0456, 0x0308), (0x045C, 0x043A, 0x0301), (0x045D, 0x0438, 0x0300),
+    (0x045E, 0x0443, 0x0306), (0x0476, 0x0474, 0x030F), (0x0477, 0x0475, 0x030F),
+    (0x04C1, 0x0416, 0x0306), (0x04C2, 0x0436, 0x0306), (0x04D0, 0x0410, 0x0306),
+    (0x04D1, 0x0430, 0x0306), (0x04D2, 0x0410, 0x0308), (0x04D3, 0x0430, 0x0308),
+    (0x04D6, 0x0415, 0x0306), (0x04D7, 0x0435, 0x0306), (0x04DA, 0x04D8, 0x0308),
+    (0x04DB, 0x04D9, 0x0308), (0x04DC, 0x0416, 0x0308), (0x04DD, 0x0436, 0x0308),
+    (0x04DE, 0x0417, 0x0308), (0x04DF, 0x0437, 0x0308), (0x04E2, 0x0418, 0x0304),
+    (0x04E3, 0x0438, 0x0304), (0x04E4, 0x0418, 0x0308), (0x04E5, 0x0438, 0x0308),
+    (0x04E6, 0x041E, 0x0308), (0x04E7, 0x043E, 0x0308), (0x04EA, 0x04E8, 0x0308),
+    (0x04EB, 0x04E9, 0x0308), (0x04EC, 0x042D, 0x0308), (0x04ED, 0x044D, 0x0308),
+    (0x04EE, 0x0423, 0x0304), (0x04EF, 0x0443, 0x0304), (0x04F0, 0x0423, 0x0308),
+    (0x04F1, 0x0443, 0x0308), (0x04F2, 0x0423, 0x030B), (0x04F3, 0x0443, 0x030B),
+    (0x04F4, 0x0427, 0x0308), (0x04F5, 0x0447, 0x0308), (0x04F8, 0x042B, 0x0308),
+    (0x04F9, 0x044B, 0x0308), (0x0622, 0x0627, 0x0653), (0x0623, 0x0627, 0x0654),
+    (0x0624, 0x0648, 0x0654), (0x0625, 0x0627, 0x0655), (0x0626, 0x064A, 0x0654),
+    (0x06C0, 0x06D5, 0x0654), (0x06C2, 0x06C1, 0x0654), (0x06D3, 0x06D2, 0x0654),
+    (0x0929, 0x0928, 0x093C), (0x0931, 0x0930, 0x093C), (0x0934, 0x0933, 0x093C),
+    (0x0958, 0x0915, 0x093C), (0x0959, 0x0916, 0x093C), (0x095A, 0x0917, 0x093C),
+    (0x095B, 0x091C, 0x093C), (0x095C, 0x0921, 0x093C), (0x095D, 0x0922, 0x093C),
+    (0x095E, 0x092B, 0x093C), (0x095F, 0x092F, 0x093C), (0x09CB, 0x09C7, 0x09BE),
+    (0x09CC, 0x09C7, 0x09D7), (0x09DC, 0x09A1, 0x09BC), (0x09DD, 0x09A2, 0x09BC),
+    (0x09DF, 0x09AF, 0x09BC), (0x0A33, 0x0A32, 0x0A3C), (0x0A36, 0x0A38, 0x0A3C),
+    (0x0A59, 0x0A16, 0x0A3C), (0x0A5A, 0x0A17, 0x0A3C), (0x0A5B, 0x0A1C, 0x0A3C),
+    (0x0A5E, 0x0A2B, 0x0A3C), (0x0B48, 0x0B47, 0x0B56), (0x0B4B, 0x0B47, 0x0B3E),
+    (0x0B4C, 0x0B47, 0x0B57), (0x0B5C, 0x0B21, 0x0B3C), (0x0B5D, 0x0B22, 0x0B3C),
+    (0x0B94, 0x0B92, 0x0BD7), (0x0BCA, 0x0BC6, 0x0BBE), (0x0BCB, 0x0BC7, 0x0BBE),
+    (0x0BCC, 0x0BC6, 0x0BD7), (0x0C48, 0x0C46, 0x0C56), (0x0CC0, 0x0CBF, 0x0CD5),
+    (0x0CC7, 0x0CC6, 0x0CD5), (0x0CC8, 0x0CC6, 0x0CD6), (0x0CCA, 0x0CC6, 0x0CC2),
+    (0x0CCB, 0x0CCA, 0x0CD5), (0x0D4A, 0x0D46, 0x0D3E), (0x0D4B, 0x0D47, 0x0D3E),
+    (0x0D4C, 0x0D46, 0x0D57), (0x0DDA, 0x0DD9, 0x0DCA), (0x0DDC, 0x0DD9, 0x0DCF),
+    (0x0DDD, 0x0DDC, 0x0DCA), (0x0DDE, 0x0DD9, 0x0DDF), (0x0F43, 0x0F42, 0x0FB7),
+    (0x0F4D, 0x0F4C, 0x0FB7), (0x0F52, 0x0F51, 0x0FB7), (0x0F57, 0x0F56, 0x0FB7),
+    (0x0F5C, 0x0F5B, 0x0FB7), (0x0F69, 0x0F40, 0x0FB5), (0x0F73, 0x0F71, 0x0F72),
+    (0x0F75, 0x0F71, 0x0F74), (0x0F76, 0x0FB2, 0x0F80), (0x0F78, 0x0FB3, 0x0F80),
+    (0x0F81, 0x0F71, 0x0F80), (0x0F93, 0x0F92, 0x0FB7), (0x0F9D, 0x0F9C, 0x0FB7),
+    (0x0FA2, 0x0FA1, 0x0FB7), (0x0FA7, 0x0FA6, 0x0FB7), (0x0FAC, 0x0FAB, 0x0FB7),
+    (0x0FB9, 0x0F90, 0x0FB5), (0x1026, 0x1025, 0x102E), (0x1B06, 0x1B05, 0x1B35),
+    (0x1B08, 0x1B07, 0x1B35), (0x1B0A, 0x1B09, 0x1B35), (0x1B0C, 0x1B0B, 0x1B35),
+    (0x1B0E, 0x1B0D, 0x1B35), (0x1B12, 0x1B11, 0x1B35), (0x1B3B, 0x1B3A, 0x1B35),
+    (0x1B3D, 0x1B3C, 0x1B35), (0x1B40, 0x1B3E, 0x1B35), (0x1B41, 0x1B3F, 0x1B35),
+    (0x1B43, 0x1B42, 0x1B35), (0x1E00, 0x0041, 0x0325), (0x1E01, 0x0061, 0x0325),
+    (0x1E02, 0x0042, 0x0307), (0x1E03, 0x0062, 0x0307), (0x1E04, 0x0042, 0x0323),
+    (0x1E05, 0x0062, 0x0323), (0x1E06, 0x0042, 0x0331), (0x1E07, 0x0062, 0x0331),
+    (0x1E08, 0x00C7, 0x0301), (0x1E09, 0x00E7, 0x0301), (0x1E0A, 0x0044, 0x0307),
+    (0x1E0B, 0x0064, 0x0307), (0x1E0C, 0x0044, 0x0323), (0x1E0D, 0x0064, 0x0323),
+    (0x1E0E, 0x0044, 0x0331), (0x1E0F, 0x0064, 0x0331), (0x1E10, 0x0044, 0x0327),
+    (0x1E11, 0x0064, 0x0327), (0x1E12, 0x0044, 0x032D), (0x1E13, 0x0064, 0x032D),
+    (0x1E14, 0x0112, 0x0300), (0x1E15, 0x0113, 0x0300), (0x1E16, 0x0112, 0x0301),
+    (0x1E17, 0x0113, 0x0301), (0x1E18, 0x0045, 0x032D), (0x1E19, 0x0065, 0x032D),
+    (0x1E1A, 0x0045, 0x0330), (0x1E1B, 0x0065, 0x0330), (0x1E1C, 0x0228, 0x0306),
+    (0x1E1D, 0x0229, 0x0306), (0x1E1E, 0x0046, 0x0307), (0x1E1F, 0x0066, 0x0307),
+    (0x1E20, 0x0047, 0x0304), (0x1E21, 0x0067, 0x0304), (0x1E22, 0x0048, 0x0307),
+    (0x1E23, 0x0068, 0x0307), (0x1E24, 0x0048, 0x0323), (0x1E25, 0x0068, 0x0323),
+    (0x1E26, 0x0048, 0x0308), (0x1E27, 0x0068, 0x0308), (0x1E28, 0x0048, 0x0327),
+    (0x1E29, 0x0068, 0x0327), (0x1E2A, 0x0048, 0x032E), (0x1E2B, 0x0068, 0x032E),
+    (0x1E2C, 0x0049, 0x0330), (0x1E2D, 0x0069, 0x0330), (0x1E2E, 0x00CF, 0x0301),
+    (0x1E2F, 0x00EF, 0x0301), (0x1E30, 0x004B, 0x0301), (0x1E31, 0x006B, 0x0301),
+    (0x1E32, 0x004B, 0x0323), (0x1E33, 0x006B, 0x0323), (0x1E34, 0x004B, 0x0331),
+    (0x1E35, 0x006B, 0x0331), (0x1E36, 0x004C, 0x0323), (0x1E37, 0x006C, 0x0323),
+    (0x1E38, 0x1E36, 0x0304), (0x1E39, 0x1E37, 0x0304), (0x1E3A, 0x004C, 0x0331),
+    (0x1E3B, 0x006C, 0x0331), (0x1E3C, 0x004C, 0x032D), (0x1E3D, 0x006C, 0x032D),
+    (0x1E3E, 0x004D, 0x0301), (0x1E3F, 0x006D, 0x0301), (0x1E40, 0x004D, 0x0307),
+    (0x1E41, 0x006D, 0x0307), (0x1E42, 0x004D, 0x0323), (0x1E43, 0x006D, 0x0323),
+    (0x1E44, 0x004E, 0x0307), (0x1E45, 0x006E, 0x0307), (0x1E46, 0x004E, 0x0323),
+    (0x1E47, 0x006E, 0x0323), (0x1E48, 0x004E, 0x0331), (0x1E49, 0x006E, 0x0331),
+    (0x1E4A, 0x004E, 0x032D), (0x1E4B, 0x006E, 0x032D), (0x1E4C, 0x00D5, 0x0301),
+    (0x1E4D, 0x00F5, 0x0301), (0x1E4E, 0x00D5, 0x0308), (0x1E4F, 0x00F5, 0x0308),
+    (0x1E50, 0x014C, 0x0300), (0x1E51, 0x014D, 0x0300), (0x1E52, 0x014C, 0x0301),
+    (0x1E53, 0x014D, 0x0301), (0x1E54, 0x0050, 0x0301), (0x1E55, 0x0070, 0x0301),
+    (0x1E56, 0x0050, 0x0307), (0x1E57, 0x0070, 0x0307), (0x1E58, 0x0052, 0x0307),
+    (0x1E59, 0x0072, 0x0307), (0x1E5A, 0x0052, 0x0323), (0x1E5B, 0x0072, 0x0323),
+    (0x1E5C, 0x1E5A, 0x0304), (0x1E5D, 0x1E5B, 0x0304), (0x1E5E, 0x0052, 0x0331),
+    (0x1E5F, 0x0072, 0x0331), (0x1E60, 0x0053, 0x0307), (0x1E61, 0x0073, 0x0307),
+    (0x1E62, 0x0053, 0x0323), (0x1E63, 0x0073, 0x0323), (0x1E64, 0x015A, 0x0307),
+    (0x1E65, 0x015B, 0x0307), (0x1E66, 0x0160, 0x0307), (0x1E67, 0x0161, 0x0307),
+    (0x1E68, 0x1E62, 0x0307), (0x1E69, 0x1E63, 0x0307), (0x1E6A, 0x0054, 0x0307),
+    (0x1E6B, 0x0074, 0x0307), (0x1E6C, 0x0054, 0x0323), (0x1E6D, 0x0074, 0x0323),
+    (0x1E6E, 0x0054, 0x0331), (0x1E6F, 0x0074, 0x0331), (0x1E70, 0x0054, 0x032D),
+    (0x1E71, 0x0074, 0x032D), (0x1E72, 0x0055, 0x0324), (0x1E73, 0x0075, 0x0324),
+    (0x1E74, 0x0055, 0x0330), (0x1E75, 0x0075, 0x0330), (0x1E76, 0x0055, 0x032D),
+    (0x1E77, 0x0075, 0x032D), (0x1E78, 0x0168, 0x0301), (0x1E79, 0x0169, 0x0301),
+    (0x1E7A, 0x016A, 0x0308), (0x1E7B, 0x016B, 0x0308), (0x1E7C, 0x0056, 0x0303),
+    (0x1E7D, 0x0076, 0x0303), (0x1E7E, 0x0056, 0x0323), (0x1E7F, 0x0076, 0x0323),
+    (0x1E80, 0x0057, 0x0300), (0x1E81, 0x0077, 0x0300), (0x1E82, 0x0057, 0x0301),
+    (0x1E83, 0x0077, 0x0301), (0x1E84, 0x0057, 0x0308), (0x1E85, 0x0077, 0x0308),
+    (0x1E86, 0x0057, 0x0307), (0x1E87, 0x0077, 0x0307), (0x1E88, 0x0057, 0x0323),
+    (0x1E89, 0x0077, 0x0323), (0x1E8A, 0x0058, 0x0307), (0x1E8B, 0x0078, 0x0307),
+    (0x1E8C, 0x0058, 0x0308), (0x1E8D, 0x0078, 0x0308), (0x1E8E, 0x0059, 0x0307),
+    (0x1E8F, 0x0079, 0x0307), (0x1E90, 0x005A, 0x0302), (0x1E91, 0x007A, 0x0302),
+    (0x1E92, 0x005A, 0x0323), (0x1E93, 0x007A, 0x0323), (0x1E94, 0x005A, 0x0331),
+    (0x1E95, 0x007A, 0x0331), (0x1E96, 0x0068, 0x0331), (0x1E97, 0x0074, 0x0308),
+    (0x1E98, 0x0077, 0x030A), (0x1E99, 0x0079, 0x030A), (0x1E9B, 0x017F, 0x0307),
+    (0x1EA0, 0x0041, 0x0323), (0x1EA1, 0x0061, 0x0323), (0x1EA2, 0x0041, 0x0309),
+    (0x1EA3, 0x0061, 0x0309), (0x1EA4, 0x00C2, 0x0301), (0x1EA5, 0x00E2, 0x0301),
+    (0x1EA6, 0x00C2, 0x0300), (0x1EA7, 0x00E2, 0x0300), (0x1EA8, 0x00C2, 0x0309),
+    (0x1EA9, 0x00E2, 0x0309), (0x1EAA, 0x00C2, 0x0303), (0x1EAB, 0x00E2, 0x0303),
+    (0x1EAC, 0x1EA0, 0x0302), (0x1EAD, 0x1EA1, 0x0302), (0x1EAE, 0x0102, 0x0301),
+    (0x1EAF, 0x0103, 0x0301), (0x1EB0, 0x0102, 0x0300), (0x1EB1, 0x0103, 0x0300),
+    (0x1EB2, 0x0102, 0x0309), (0x1EB3, 0x0103, 0x0309), (0x1EB4, 0x0102, 0x0303),
+    (0x1EB5, 0x0103, 0x0303), (0x1EB6, 0x1EA0, 0x0306), (0x1EB7, 0x1EA1, 0x0306),
+    (0x1EB8, 0x0045, 0x0323), (0x1EB9, 0x0065, 0x0323), (0x1EBA, 0x0045, 0x0309),
+    (0x1EBB, 0x0065, 0x0309), (0x1EBC, 0x0045, 0x0303), (0x1EBD, 0x0065, 0x0303),
+    (0x1EBE, 0x00CA, 0x0301), (0x1EBF, 0x00EA, 0x0301), (0x1EC0, 0x00CA, 0x0300),
+    (0x1EC1, 0x00EA, 0x0300), (0x1EC2, 0x00CA, 0x0309), (0x1EC3, 0x00EA, 0x0309),
+    (0x1EC4, 0x00CA, 0x0303), (0x1EC5, 0x00EA, 0x0303), (0x1EC6, 0x1EB8, 0x0302),
+    (0x1EC7, 0x1EB9, 0x0302), (0x1EC8, 0x0049, 0x0309), (0x1EC9, 0x0069, 0x0309),
+    (0x1ECA, 0x0049, 0x0323), (0x1ECB, 0x0069, 0x0323), (0x1ECC, 0x004F, 0x0323),
+    (0x1ECD, 0x006F, 0x0323), (0x1ECE, 0x004F, 0x0309), (0x1ECF, 0x006F, 0x0309),
+    (0x1ED0, 0x00D4, 0x0301), (0x1ED1, 0x00F4, 0x0301), (0x1ED2, 0x00D4, 0x0300),
+    (0x1ED3, 0x00F4, 0x0300), (0x1ED4, 0x00D4, 0x0309), (0x1ED5, 0x00F4, 0x0309),
+    (0x1ED6, 0x00D4, 0x0303), (0x1ED7, 0x00F4, 0x0303), (0x1ED8, 0x1ECC, 0x0302),
+    (0x1ED9, 0x1ECD, 0x0302), (0x1EDA, 0x01A0, 0x0301), (0x1EDB, 0x01A1, 0x0301),
+    (0x1EDC, 0x01A0, 0x0300), (0x1EDD, 0x01A1, 0x0300), (0x1EDE, 0x01A0, 0x0309),
+    (0x1EDF, 0x01A1, 0x0309), (0x1EE0, 0x01A0, 0x0303), (0x1EE1, 0x01A1, 0x0303),
+    (0x1EE2, 0x01A0, 0x0323), (0x1EE3, 0x01A1, 0x0323), (0x1EE4, 0x0055, 0x0323),
+    (0x1EE5, 0x0075, 0x0323), (0x1EE6, 0x0055, 0x0309), (0x1EE7, 0x0075, 0x0309),
+    (0x1EE8, 0x01AF, 0x0301), (0x1EE9, 0x01B0, 0x0301), (0x1EEA, 0x01AF, 0x0300),
+    (0x1EEB, 0x01B0, 0x0300), (0x1EEC, 0x01AF, 0x0309), (0x1EED, 0x01B0, 0x0309),
+    (0x1EEE, 0x01AF, 0x0303), (0x1EEF, 0x01B0, 0x0303), (0x1EF0, 0x01AF, 0x0323),
+    (0x1EF1, 0x01B0, 0x0323), (0x1EF2, 0x0059, 0x0300), (0x1EF3, 0x0079, 0x0300),
+    (0x1EF4, 0x0059, 0x0323), (0x1EF5, 0x0079, 0x0323), (0x1EF6, 0x0059, 0x0309),
+    (0x1EF7, 0x0079, 0x0309), (0x1EF8, 0x0059, 0x0303), (0x1EF9, 0x0079, 0x0303),
+    (0x1F00, 0x03B1, 0x0313), (0x1F01, 0x03B1, 0x0314), (0x1F02, 0x1F00, 0x0300),
+    (0x1F03, 0x1F01, 0x0300), (0x1F04, 0x1F00, 0x0301), (0x1F05, 0x1F01, 0x0301),
+    (0x1F06, 0x1F00, 0x0342), (0x1F07, 0x1F01, 0x0342), (0x1F08, 0x0391, 0x0313),
+    (0x1F09, 0x0391, 0x0314), (0x1F0A, 0x1F08, 0x0300), (0x1F0B, 0x1F09, 0x0300),
+    (0x1F0C, 0x1F08, 0x0301), (0x1F0D, 0x1F09, 0x0301), (0x1F0E, 0x1F08, 0x0342),
+    (0x1F0F, 0x1F09, 0x0342), (0x1F10, 0x03B5, 0x0313), (0x1F11, 0x03B5, 0x0314),
+    (0x1F12, 0x1F10, 0x0300), (0x1F13, 0x1F11, 0x0300), (0x1F14, 0x1F10, 0x0301),
+    (0x1F15, 0x1F11, 0x0301), (0x1F18, 0x0395, 0x0313), (0x1F19, 0x0395, 0x0314),
+    (0x1F1A, 0x1F18, 0x0300), (0x1F1B, 0x1F19, 0x0300), (0x1F1C, 0x1F18, 0x0301),
+    (0x1F1D, 0x1F19, 0x0301), (0x1F20, 0x03B7, 0x0313), (0x1F21, 0x03B7, 0x0314),
+    (0x1F22, 0x1F20, 0x0300), (0x1F23, 0x1F21, 0x0300), (0x1F24, 0x1F20, 0x0301),
+    (0x1F25, 0x1F21, 0x0301), (0x1F26, 0x1F20, 0x0342), (0x1F27, 0x1F21, 0x0342),
+    (0x1F28, 0x0397, 0x0313), (0x1F29, 0x0397, 0x0314), (0x1F2A, 0x1F28, 0x0300),
+    (0x1F2B, 0x1F29, 0x0300), (0x1F2C, 0x1F28, 0x0301), (0x1F2D, 0x1F29, 0x0301),
+    (0x1F2E, 0x1F28, 0x0342), (0x1F2F, 0x1F29, 0x0342), (0x1F30, 0x03B9, 0x0313),
+    (0x1F31, 0x03B9, 0x0314), (0x1F32, 0x1F30, 0x0300), (0x1F33, 0x1F31, 0x0300),
+    (0x1F34, 0x1F30, 0x0301), (0x1F35, 0x1F31, 0x0301), (0x1F36, 0x1F30, 0x0342),
+    (0x1F37, 0x1F31, 0x0342), (0x1F38, 0x0399, 0x0313), (0x1F39, 0x0399, 0x0314),
+    (0x1F3A, 0x1F38, 0x0300), (0x1F3B, 0x1F39, 0x0300), (0x1F3C, 0x1F38, 0x0301),
+    (0x1F3D, 0x1F39, 0x0301), (0x1F3E, 0x1F38, 0x0342), (0x1F3F, 0x1F39, 0x0342),
+    (0x1F40, 0x03BF, 0x0313), (0x1F41, 0x03BF, 0x0314), (0x1F42, 0x1F40, 0x0300),
+    (0x1F43, 0x1F41, 0x0300), (0x1F44, 0x1F40, 0x0301), (0x1F45, 0x1F41, 0x0301),
+    (0x1F48, 0x039F, 0x0313), (0x1F49, 0x039F, 0x0314), (0x1F4A, 0x1F48, 0x0300),
+    (0x1F4B, 0x1F49, 0x0300), (0x1F4C, 0x1F48, 0x0301), (0x1F4D, 0x1F49, 0x0301),
+    (0x1F50, 0x03C5, 0x0313), (0x1F51, 0x03C5, 0x0314), (0x1F52, 0x1F50, 0x0300),
+    (0x1F53, 0x1F51, 0x0300), (0x1F54, 0x1F50, 0x0301), (0x1F55, 0x1F51, 0x0301),
+    (0x1F56, 0x1F50, 0x0342), (0x1F57, 0x1F51, 0x0342), (0x1F59, 0x03A5, 0x0314),
+    (0x1F5B, 0x1F59, 0x0300), (0x1F5D, 0x1F59, 0x0301), (0x1F5F, 0x1F59, 0x0342),
+    (0x1F60, 0x03C9, 0x0313), (0x1F61, 0x03C9, 0x0314), (0x1F62, 0x1F60, 0x0300),
+    (0x1F63, 0x1F61, 0x0300), (0x1F64, 0x1F60, 0x0301), (0x1F65, 0x1F61, 0x0301),
+    (0x1F66, 0x1F60, 0x0342), (0x1F67, 0x1F61, 0x0342), (0x1F68, 0x03A9, 0x0313),
+    (0x1F69, 0x03A9, 0x0314), (0x1F6A, 0x1F68, 0x0300), (0x1F6B, 0x1F69, 0x0300),
+    (0x1F6C, 0x1F68, 0x0301), (0x1F6D, 0x1F69, 0x0301), (0x1F6E, 0x1F68, 0x0342),
+    (0x1F6F, 0x1F69, 0x0342), (0x1F70, 0x03B1, 0x0300), (0x1F71, 0x03AC, 0x0000),
+    (0x1F72, 0x03B5, 0x0300), (0x1F73, 0x03AD, 0x0000), (0x1F74, 0x03B7, 0x0300),
+    (0x1F75, 0x03AE, 0x0000), (0x1F76, 0x03B9, 0x0300), (0x1F77, 0x03AF, 0x0000),
+    (0x1F78, 0x03BF, 0x0300), (0x1F79, 0x03CC, 0x0000), (0x1F7A, 0x03C5, 0x0300),
+    (0x1F7B, 0x03CD, 0x0000), (0x1F7C, 0x03C9, 0x0300), (0x1F7D, 0x03CE, 0x0000),
+    (0x1F80, 0x1F00, 0x0345), (0x1F81, 0x1F01, 0x0345), (0x1F82, 0x1F02, 0x0345),
+    (0x1F83, 0x1F03, 0x0345), (0x1F84, 0x1F04, 0x0345), (0x1F85, 0x1F05, 0x0345),
+    (0x1F86, 0x1F06, 0x0345), (0x1F87, 0x1F07, 0x0345), (0x1F88, 0x1F08, 0x0345),
+    (0x1F89, 0x1F09, 0x0345), (0x1F8A, 0x1F0A, 0x0345), (0x1F8B, 0x1F0B, 0x0345),
+    (0x1F8C, 0x1F0C, 0x0345), (0x1F8D, 0x1F0D, 0x0345), (0x1F8E, 0x1F0E, 0x0345),
+    (0x1F8F, 0x1F0F, 0x0345), (0x1F90, 0x1F20, 0x0345), (0x1F91, 0x1F21, 0x0345),
+    (0x1F92, 0x1F22, 0x0345), (0x1F93, 0x1F23, 0x0345), (0x1F94, 0x1F24, 0x0345),
+    (0x1F95, 0x1F25, 0x0345), (0x1F96, 0x1F26, 0x0345), (0x1F97, 0x1F27, 0x0345),
+    (0x1F98, 0x1F28, 0x0345), (0x1F99, 0x1F29, 0x0345), (0x1F9A, 0x1F2A, 0x0345),
+    (0x1F9B, 0x1F2B, 0x0345), (0x1F9C, 0x1F2C, 0x0345), (0x1F9D, 0x1F2D, 0x0345),
+    (0x1F9E, 0x1F2E, 0x0345), (0x1F9F, 0x1F2F, 0x0345), (0x1FA0, 0x1F60, 0x0345),
+    (0x1FA1, 0x1F61, 0x0345), (0x1FA2, 0x1F62, 0x0345), (0x1FA3, 0x1F63, 0x0345),
+    (0x1FA4, 0x1F64, 0x0345), (0x1FA5, 0x1F65, 0x0345), (0x1FA6, 0x1F66, 0x0345),
+    (0x1FA7, 0x1F67, 0x0345), (0x1FA8, 0x1F68, 0x0345), (0x1FA9, 0x1F69, 0x0345),
+    (0x1FAA, 0x1F6A, 0x0345), (0x1FAB, 0x1F6B, 0x0345), (0x1FAC, 0x1F6C, 0x0345),
+    (0x1FAD, 0x1F6D, 0x0345), (0x1FAE, 0x1F6E, 0x0345), (0x1FAF, 0x1F6F, 0x0345),
+    (0x1FB0, 0x03B1, 0x0306), (0x1FB1, 0x03B1, 0x0304), (0x1FB2, 0x1F70, 0x0345),
+    (0x1FB3, 0x03B1, 0x0345), (0x1FB4, 0x03AC, 0x0345), (0x1FB6, 0x03B1, 0x0342),
+    (0x1FB7, 0x1FB6, 0x0345), (0x1FB8, 0x0391, 0x0306), (0x1FB9, 0x0391, 0x0304),
+    (0x1FBA, 0x0391, 0x0300), (0x1FBB, 0x0386, 0x0000), (0x1FBC, 0x0391, 0x0345),
+    (0x1FBE, 0x03B9, 0x0000), (0x1FC1, 0x00A8, 0x0342), (0x1FC2, 0x1F74, 0x0345),
+    (0x1FC3, 0x03B7, 0x0345), (0x1FC4, 0x03AE, 0x0345), (0x1FC6, 0x03B7, 0x0342),
+    (0x1FC7, 0x1FC6, 0x0345), (0x1FC8, 0x0395, 0x0300), (0x1FC9, 0x0388, 0x0000),
+    (0x1FCA, 0x0397, 0x0300), (0x1FCB, 0x0389, 0x0000), (0x1FCC, 0x0397, 0x0345),
+    (0x1FCD, 0x1FBF, 0x0300), (0x1FCE, 0x1FBF, 0x0301), (0x1FCF, 0x1FBF, 0x0342),
+    (0x1FD0, 0x03B9, 0x0306), (0x1FD1, 0x03B9, 0x0304), (0x1FD2, 0x03CA, 0x0300),
+    (0x1FD3, 0x0390, 0x0000), (0x1FD6, 0x03B9, 0x0342), (0x1FD7, 0x03CA, 0x0342),
+    (0x1FD8, 0x0399, 0x0306), (0x1FD9, 0x0399, 0x0304), (0x1FDA, 0x0399, 0x0300),
+    (0x1FDB, 0x038A, 0x0000), (0x1FDD, 0x1FFE, 0x0300), (0x1FDE, 0x1FFE, 0x0301),
+    (0x1FDF, 0x1FFE, 0x0342), (0x1FE0, 0x03C5, 0x0306), (0x1FE1, 0x03C5, 0x0304),
+    (0x1FE2, 0x03CB, 0x0300), (0x1FE3, 0x03B0, 0x0000), (0x1FE4, 0x03C1, 0x0313),
+    (0x1FE5, 0x03C1, 0x0314), (0x1FE6, 0x03C5, 0x0342), (0x1FE7, 0x03CB, 0x0342),
+    (0x1FE8, 0x03A5, 0x0306), (0x1FE9, 0x03A5, 0x0304), (0x1FEA, 0x03A5, 0x0300),
+    (0x1FEB, 0x038E, 0x0000), (0x1FEC, 0x03A1, 0x0314), (0x1FED, 0x00A8, 0x0300),
+    (0x1FEE, 0x0385, 0x0000), (0x1FEF, 0x0060, 0x0000), (0x1FF2, 0x1F7C, 0x0345),
+    (0x1FF3, 0x03C9, 0x0345), (0x1FF4, 0x03CE, 0x0345), (0x1FF6, 0x03C9, 0x0342),
+    (0x1FF7, 0x1FF6, 0x0345), (0x1FF8, 0x039F, 0x0300), (0x1FF9, 0x038C, 0x0000),
+    (0x1FFA, 0x03A9, 0x0300), (0x1FFB, 0x038F, 0x0000), (0x1FFC, 0x03A9, 0x0345),
+    (0x1FFD, 0x00B4, 0x0000), (0x2000, 0x2002, 0x0000), (0x2001, 0x2003, 0x0000),
+    (0x2126, 0x03A9, 0x0000), (0x212A, 0x004B, 0x0000), (0x212B, 0x00C5, 0x0000),
+    (0x219A, 0x2190, 0x0338), (0x219B, 0x2192, 0x0338), (0x21AE, 0x2194, 0x0338),
+    (0x21CD, 0x21D0, 0x0338), (0x21CE, 0x21D4, 0x0338), (0x21CF, 0x21D2, 0x0338),
+    (0x2204, 0x2203, 0x0338), (0x2209, 0x2208, 0x0338), (0x220C, 0x220B, 0x0338),
+    (0x2224, 0x2223, 0x0338), (0x2226, 0x2225, 0x0338), (0x2241, 0x223C, 0x0338),
+    (0x2244, 0x2243, 0x0338), (0x2247, 0x2245, 0x0338), (0x2249, 0x2248, 0x0338),
+    (0x2260, 0x003D, 0x0338), (0x2262, 0x2261, 0x0338), (0x226D, 0x224D, 0x0338),
+    (0x226E, 0x003C, 0x0338), (0x226F, 0x003E, 0x0338), (0x2270, 0x2264, 0x0338),
+    (0x2271, 0x2265, 0x0338), (0x2274, 0x2272, 0x0338), (0x2275, 0x2273, 0x0338),
+    (0x2278, 0x2276, 0x0338), (0x2279, 0x2277, 0x0338), (0x2280, 0x227A, 0x0338),
+    (0x2281, 0x227B, 0x0338), (0x2284, 0x2282, 0x0338), (0x2285, 0x2283, 0x0338),
+    (0x2288, 0x2286, 0x0338), (0x2289, 0x2287, 0x0338), (0x22AC, 0x22A2, 0x0338),
+    (0x22AD, 0x22A8, 0x0338), (0x22AE, 0x22A9, 0x0338), (0x22AF, 0x22AB, 0x0338),
+    (0x22E0, 0x227C, 0x0338), (0x22E1, 0x227D, 0x0338), (0x22E2, 0x2291, 0x0338),
+    (0x22E3, 0x2292, 0x0338), (0x22EA, 0x22B2, 0x0338), (0x22EB, 0x22B3, 0x0338),
+    (0x22EC, 0x22B4, 0x0338), (0x22ED, 0x22B5, 0x0338), (0x2329, 0x3008, 0x0000),
+    (0x232A, 0x3009, 0x0000), (0x2ADC, 0x2ADD, 0x0338), (0x304C, 0x304B, 0x3099),
+    (0x304E, 0x304D, 0x3099), (0x3050, 0x304F, 0x3099), (0x3052, 0x3051, 0x3099),
+    (0x3054, 0x3053, 0x3099), (0x3056, 0x3055, 0x3099), (0x3058, 0x3057, 0x3099),
+    (0x305A, 0x3059, 0x3099), (0x305C, 0x305B, 0x3099), (0x305E, 0x305D, 0x3099),
+    (0x3060, 0x305F, 0x3099), (0x3062, 0x3061, 0x3099), (0x3065, 0x3064, 0x3099),
+    (0x3067, 0x3066, 0x3099), (0x3069, 0x3068, 0x3099), (0x3070, 0x306F, 0x3099),
+    (0x3071, 0x306F, 0x309A), (0x3073, 0x3072, 0x3099), (0x3074, 0x3072, 0x309A),
+    (0x3076, 0x3075, 0x3099), (0x3077, 0x3075, 0x309A), (0x3079, 0x3078, 0x3099),
+    (0x307A, 0x3078, 0x309A), (0x307C, 0x307B, 0x3099), (0x307D, 0x307B, 0x309A),
+    (0x3094, 0x3046, 0x3099), (0x309E, 0x309D, 0x3099), (0x30AC, 0x30AB, 0x3099),
+    (0x30AE, 0x30AD, 0x3099), (0x30B0, 0x30AF, 0x3099), (0x30B2, 0x30B1, 0x3099),
+    (0x30B4, 0x30B3, 0x3099), (0x30B6, 0x30B5, 0x3099), (0x30B8, 0x30B7, 0x3099),
+    (0x30BA, 0x30B9, 0x3099), (0x30BC, 0x30BB, 0x3099), (0x30BE, 0x30BD, 0x3099),
+    (0x30C0, 0x30BF, 0x3099), (0x30C2, 0x30C1, 0x3099), (0x30C5, 0x30C4, 0x3099),
+    (0x30C7, 0x30C6, 0x3099), (0x30C9, 0x30C8, 0x3099), (0x30D0, 0x30CF, 0x3099),
+    (0x30D1, 0x30CF, 0x309A), (0x30D3, 0x30D2, 0x3099), (0x30D4, 0x30D2, 0x309A),
+    (0x30D6, 0x30D5, 0x3099), (0x30D7, 0x30D5, 0x309A), (0x30D9, 0x30D8, 0x3099),
+    (0x30DA, 0x30D8, 0x309A), (0x30DC, 0x30DB, 0x3099), (0x30DD, 0x30DB, 0x309A),
+    (0x30F4, 0x30A6, 0x3099), (0x30F7, 0x30EF, 0x3099), (0x30F8, 0x30F0, 0x3099),
+    (0x30F9, 0x30F1, 0x3099), (0x30FA, 0x30F2, 0x3099), (0x30FE, 0x30FD, 0x3099),
+    (0xF900, 0x8C48, 0x0000), (0xF901, 0x66F4, 0x0000), (0xF902, 0x8ECA, 0x0000),
+    (0xF903, 0x8CC8, 0x0000), (0xF904, 0x6ED1, 0x0000), (0xF905, 0x4E32, 0x0000),
+    (0xF906, 0x53E5, 0x0000), (0xF907, 0x9F9C, 0x0000), (0xF908, 0x9F9C, 0x0000),
+    (0xF909, 0x5951, 0x0000), (0xF90A, 0x91D1, 0x0000), (0xF90B, 0x5587, 0x0000),
+    (0xF90C, 0x5948, 0x0000), (0xF90D, 0x61F6, 0x0000), (0xF90E, 0x7669, 0x0000),
+    (0xF90F, 0x7F85, 0x0000), (0xF910, 0x863F, 0x0000), (0xF911, 0x87BA, 0x0000),
+    (0xF912, 0x88F8, 0x0000), (0xF913, 0x908F, 0x0000), (0xF914, 0x6A02, 0x0000),
+    (0xF915, 0x6D1B, 0x0000), (0xF916, 0x70D9, 0x0000), (0xF917, 0x73DE, 0x0000),
+    (0xF918, 0x843D, 0x0000), (0xF919, 0x916A, 0x0000), (0xF91A, 0x99F1, 0x0000),
+    (0xF91B, 0x4E82, 0x0000), (0xF91C, 0x5375, 0x0000), (0xF91D, 0x6B04, 0x0000),
+    (0xF91E, 0x721B, 0x0000), (0xF91F, 0x862D, 0x0000), (0xF920, 0x9E1E, 0x0000),
+    (0xF921, 0x5D50, 0x0000), (0xF922, 0x6FEB, 0x0000), (0xF923, 0x85CD, 0x0000),
+    (0xF924, 0x8964, 0x0000), (0xF925, 0x62C9, 0x0000), (0xF926, 0x81D8, 0x0000),
+    (0xF927, 0x881F, 0x0000), (0xF928, 0x5ECA, 0x0000), (0xF929, 0x6717, 0x0000),
+    (0xF92A, 0x6D6A, 0x0000), (0xF92B, 0x72FC, 0x0000), (0xF92C, 0x90CE, 0x0000),
+    (0xF92D, 0x4F86, 0x0000), (0xF92E, 0x51B7, 0x0000), (0xF92F, 0x52DE, 0x0000),
+    (0xF930, 0x64C4, 0x0000), (0xF931, 0x6AD3, 0x0000), (0xF932, 0x7210, 0x0000),
+    (0xF933, 0x76E7, 0x0000), (0xF934, 0x8001, 0x0000), (0xF935, 0x8606, 0x0000),
+    (0xF936, 0x865C, 0x0000), (0xF937, 0x8DEF, 0x0000), (0xF938, 0x9732, 0x0000),
+    (0xF939, 0x9B6F, 0x0000), (0xF93A, 0x9DFA, 0x0000), (0xF93B, 0x788C, 0x0000),
+    (0xF93C, 0x797F, 0x0000), (0xF93D, 0x7DA0, 0x0000), (0xF93E, 0x83C9, 0x0000),
+    (0xF93F, 0x9304, 0x0000), (0xF940, 0x9E7F, 0x0000), (0xF941, 0x8AD6, 0x0000),
+    (0xF942, 0x58DF, 0x0000), (0xF943, 0x5F04, 0x0000), (0xF944, 0x7C60, 0x0000),
+    (0xF945, 0x807E, 0x0000), (0xF946, 0x7262, 0x0000), (0xF947, 0x78CA, 0x0000),
+    (0xF948, 0x8CC2, 0x0000), (0xF949, 0x96F7, 0x0000), (0xF94A, 0x58D8, 0x0000),
+    (0xF94B, 0x5C62, 0x0000), (0xF94C, 0x6A13, 0x0000), (0xF94D, 0x6DDA, 0x0000),
+    (0xF94E, 0x6F0F, 0x0000), (0xF94F, 0x7D2F, 0x0000), (0xF950, 0x7E37, 0x0000),
+    (0xF951, 0x964B, 0x0000), (0xF952, 0x52D2, 0x0000), (0xF953, 0x808B, 0x0000),
+    (0xF954, 0x51DC, 0x0000), (0xF955, 0x51CC, 0x0000), (0xF956, 0x7A1C, 0x0000),
+    (0xF957, 0x7DBE, 0x0000), (0xF958, 0x83F1, 0x0000), (0xF959, 0x9675, 0x0000),
+    (0xF95A, 0x8B80, 0x0000), (0xF95B, 0x62CF, 0x0000), (0xF95C, 0x6A02, 0x0000),
+    (0xF95D, 0x8AFE, 0x0000), (0xF95E, 0x4E39, 0x0000), (0xF95F, 0x5BE7, 0x0000),
+    (0xF960, 0x6012, 0x0000), (0xF961, 0x7387, 0x0000), (0xF962, 0x7570, 0x0000),
+    (0xF963, 0x5317, 0x0000), (0xF964, 0x78FB, 0x0000), (0xF965, 0x4FBF, 0x0000),
+    (0xF966, 0x5FA9, 0x0000), (0xF967, 0x4E0D, 0x0000), (0xF968, 0x6CCC, 0x0000),
+    (0xF969, 0x6578, 0x0000), (0xF96A, 0x7D22, 0x0000), (0xF96B, 0x53C3, 0x0000),
+    (0xF96C, 0x585E, 0x0000), (0xF96D, 0x7701, 0x0000), (0xF96E, 0x8449, 0x0000),
+    (0xF96F, 0x8AAA, 0x0000), (0xF970, 0x6BBA, 0x0000), (0xF971, 0x8FB0, 0x0000),
+    (0xF972, 0x6C88, 0x0000), (0xF973, 0x62FE, 0x0000), (0xF974, 0x82E5, 0x0000),
+    (0xF975, 0x63A0, 0x0000), (0xF976, 0x7565, 0x0000), (0xF977, 0x4EAE, 0x0000),
+    (0xF978, 0x5169, 0x0000), (0xF979, 0x51C9, 0x0000), (0xF97A, 0x6881, 0x0000),
+    (0xF97B, 0x7CE7, 0x0000), (0xF97C, 0x826F, 0x0000), (0xF97D, 0x8AD2, 0x0000),
+    (0xF97E, 0x91CF, 0x0000), (0xF97F, 0x52F5, 0x0000), (0xF980, 0x5442, 0x0000),
+    (0xF981, 0x5973, 0x0000), (0xF982, 0x5EEC, 0x0000), (0xF983, 0x65C5, 0x0000),
+    (0xF984, 0x6FFE, 0x0000), (0xF985, 0x792A, 0x0000), (0xF986, 0x95AD, 0x0000),
+    (0xF987, 0x9A6A, 0x0000), (0xF988, 0x9E97, 0x0000), (0xF989, 0x9ECE, 0x0000),
+    (0xF98A, 0x529B, 0x0000), (0xF98B, 0x66C6, 0x0000), (0xF98C, 0x6B77, 0x0000),
+    (0xF98D, 0x8F62, 0x0000), (0xF98E, 0x5E74, 0x0000), (0xF98F, 0x6190, 0x0000),
+    (0xF990, 0x6200, 0x0000), (0xF991, 0x649A, 0x0000), (0xF992, 0x6F23, 0x0000),
+    (0xF993, 0x7149, 0x0000), (0xF994, 0x7489, 0x0000), (0xF995, 0x79CA, 0x0000),
+    (0xF996, 0x7DF4, 0x0000), (0xF997, 0x806F, 0x0000), (0xF998, 0x8F26, 0x0000),
+    (0xF999, 0x84EE, 0x0000), (0xF99A, 0x9023, 0x0000), (0xF99B, 0x934A, 0x0000),
+    (0xF99C, 0x5217, 0x0000), (0xF99D, 0x52A3, 0x0000), (0xF99E, 0x54BD, 0x0000),
+    (0xF99F, 0x70C8, 0x0000), (0xF9A0, 0x88C2, 0x0000), (0xF9A1, 0x8AAA, 0x0000),
+    (0xF9A2, 0x5EC9, 0x0000), (0xF9A3, 0x5FF5, 0x0000), (0xF9A4, 0x637B, 0x0000),
+    (0xF9A5, 0x6BAE, 0x0000), (0xF9A6, 0x7C3E, 0x0000), (0xF9A7, 0x7375, 0x0000),
+    (0xF9A8, 0x4EE4, 0x0000), (0xF9A9, 0x56F9, 0x0000), (0xF9AA, 0x5BE7, 0x0000),
+    (0xF9AB, 0x5DBA, 0x0000), (0xF9AC, 0x601C, 0x0000), (0xF9AD, 0x73B2, 0x0000),
+    (0xF9AE, 0x7469, 0x0000), (0xF9AF, 0x7F9A, 0x0000), (0xF9B0, 0x8046, 0x0000),
+    (0xF9B1, 0x9234, 0x0000), (0xF9B2, 0x96F6, 0x0000), (0xF9B3, 0x9748, 0x0000),
+    (0xF9B4, 0x9818, 0x0000), (0xF9B5, 0x4F8B, 0x0000), (0xF9B6, 0x79AE, 0x0000),
+    (0xF9B7, 0x91B4, 0x0000), (0xF9B8, 0x96B8, 0x0000), (0xF9B9, 0x60E1, 0x0000),
+    (0xF9BA, 0x4E86, 0x0000), (0xF9BB, 0x50DA, 0x0000), (0xF9BC, 0x5BEE, 0x0000),
+    (0xF9BD, 0x5C3F, 0x0000), (0xF9BE, 0x6599, 0x0000), (0xF9BF, 0x6A02, 0x0000),
+    (0xF9C0, 0x71CE, 0x0000), (0xF9C1, 0x7642, 0x0000), (0xF9C2, 0x84FC, 0x0000),
+    (0xF9C3, 0x907C, 0x0000), (0xF9C4, 0x9F8D, 0x0000), (0xF9C5, 0x6688, 0x0000),
+    (0xF9C6, 0x962E, 0x0000), (0xF9C7, 0x5289, 0x0000), (0xF9C8, 0x677B, 0x0000),
+    (0xF9C9, 0x67F3, 0x0000), (0xF9CA, 0x6D41, 0x0000), (0xF9CB, 0x6E9C, 0x0000),
+    (0xF9CC, 0x7409, 0x0000), (0xF9CD, 0x7559, 0x0000), (0xF9CE, 0x786B, 0x0000),
+    (0xF9CF, 0x7D10, 0x0000), (0xF9D0, 0x985E, 0x0000), (0xF9D1, 0x516D, 0x0000),
+    (0xF9D2, 0x622E, 0x0000), (0xF9D3, 0x9678, 0x0000), (0xF9D4, 0x502B, 0x0000),
+    (0xF9D5, 0x5D19, 0x0000), (0xF9D6, 0x6DEA, 0x0000), (0xF9D7, 0x8F2A, 0x0000),
+    (0xF9D8, 0x5F8B, 0x0000), (0xF9D9, 0x6144, 0x0000), (0xF9DA, 0x6817, 0x0000),
+    (0xF9DB, 0x7387, 0x0000), (0xF9DC, 0x9686, 0x0000), (0xF9DD, 0x5229, 0x0000),
+    (0xF9DE, 0x540F, 0x0000), (0xF9DF, 0x5C65, 0x0000), (0xF9E0, 0x6613, 0x0000),
+    (0xF9E1, 0x674E, 0x0000), (0xF9E2, 0x68A8, 0x0000), (0xF9E3, 0x6CE5, 0x0000),
+    (0xF9E4, 0x7406, 0x0000), (0xF9E5, 0x75E2, 0x0000), (0xF9E6, 0x7F79, 0x0000),
+    (0xF9E7, 0x88CF, 0x0000), (0xF9E8, 0x88E1, 0x0000), (0xF9E9, 0x91CC, 0x0000),
+    (0xF9EA, 0x96E2, 0x0000), (0xF9EB, 0x533F, 0x0000), (0xF9EC, 0x6EBA, 0x0000),
+    (0xF9ED, 0x541D, 0x0000), (0xF9EE, 0x71D0, 0x0000), (0xF9EF, 0x7498, 0x0000),
+    (0xF9F0, 0x85FA, 0x0000), (0xF9F1, 0x96A3, 0x0000), (0xF9F2, 0x9C57, 0x0000),
+    (0xF9F3, 0x9E9F, 0x0000), (0xF9F4, 0x6797, 0x0000), (0xF9F5, 0x6DCB, 0x0000),
+    (0xF9F6, 0x81E8, 0x0000), (0xF9F7, 0x7ACB, 0x0000), (0xF9F8, 0x7B20, 0x0000),
+    (0xF9F9, 0x7C92, 0x0000), (0xF9FA, 0x72C0, 0x0000), (0xF9FB, 0x7099, 0x0000),
+    (0xF9FC, 0x8B58, 0x0000), (0xF9FD, 0x4EC0, 0x0000), (0xF9FE, 0x8336, 0x0000),
+    (0xF9FF, 0x523A, 0x0000), (0xFA00, 0x5207, 0x0000), (0xFA01, 0x5EA6, 0x0000),
+    (0xFA02, 0x62D3, 0x0000), (0xFA03, 0x7CD6, 0x0000), (0xFA04, 0x5B85, 0x0000),
+    (0xFA05, 0x6D1E, 0x0000), (0xFA06, 0x66B4, 0x0000), (0xFA07, 0x8F3B, 0x0000),
+    (0xFA08, 0x884C, 0x0000), (0xFA09, 0x964D, 0x0000), (0xFA0A, 0x898B, 0x0000),
+    (0xFA0B, 0x5ED3, 0x0000), (0xFA0C, 0x5140, 0x0000), (0xFA0D, 0x55C0, 0x0000),
+    (0xFA10, 0x585A, 0x0000), (0xFA12, 0x6674, 0x0000), (0xFA15, 0x51DE, 0x0000),
+    (0xFA16, 0x732A, 0x0000), (0xFA17, 0x76CA, 0x0000), (0xFA18, 0x793C, 0x0000),
+    (0xFA19, 0x795E, 0x0000), (0xFA1A, 0x7965, 0x0000), (0xFA1B, 0x798F, 0x0000),
+    (0xFA1C, 0x9756, 0x0000), (0xFA1D, 0x7CBE, 0x0000), (0xFA1E, 0x7FBD, 0x0000),
+    (0xFA20, 0x8612, 0x0000), (0xFA22, 0x8AF8, 0x0000), (0xFA25, 0x9038, 0x0000),
+    (0xFA26, 0x90FD, 0x0000), (0xFA2A, 0x98EF, 0x0000), (0xFA2B, 0x98FC, 0x0000),
+    (0xFA2C, 0x9928, 0x0000), (0xFA2D, 0x9DB4, 0x0000), (0xFA2E, 0x90DE, 0x0000),
+    (0xFA2F, 0x96B7, 0x0000), (0xFA30, 0x4FAE, 0x0000), (0xFA31, 0x50E7, 0x0000),
+    (0xFA32, 0x514D, 0x0000), (0xFA33, 0x52C9, 0x0000), (0xFA34, 0x52E4, 0x0000),
+    (0xFA35, 0x5351, 0x0000), (0xFA36, 0x559D, 0x0000), (0xFA37, 0x5606, 0x0000),
+    (0xFA38, 0x5668, 0x0000), (0xFA39, 0x5840, 0x0000), (0xFA3A, 0x58A8, 0x0000),
+    (0xFA3B, 0x5C64, 0x0000), (0xFA3C, 0x5C6E, 0x0000), (0xFA3D, 0x6094, 0x0000),
+    (0xFA3E, 0x6168, 0x0000), (0xFA3F, 0x618E, 0x0000), (0xFA40, 0x61F2, 0x0000),
+    (0xFA41, 0x654F, 0x0000), (0xFA42, 0x65E2, 0x0000), (0xFA43, 0x6691, 0x0000),
+    (0xFA44, 0x6885, 0x0000), (0xFA45, 0x6D77, 0x0000), (0xFA46, 0x6E1A, 0x0000),
+    (0xFA47, 0x6F22, 0x0000), (0xFA48, 0x716E, 0x0000), (0xFA49, 0x722B, 0x0000),
+    (0xFA4A, 0x7422, 0x0000), (0xFA4B, 0x7891, 0x0000), (0xFA4C, 0x793E, 0x0000),
+    (0xFA4D, 0x7949, 0x0000), (0xFA4E, 0x7948, 0x0000), (0xFA4F, 0x7950, 0x0000),
+    (0xFA50, 0x7956, 0x0000), (0xFA51, 0x795D, 0x0000), (0xFA52, 0x798D, 0x0000),
+    (0xFA53, 0x798E, 0x0000), (0xFA54, 0x7A40, 0x0000), (0xFA55, 0x7A81, 0x0000),
+    (0xFA56, 0x7BC0, 0x0000), (0xFA57, 0x7DF4, 0x0000), (0xFA58, 0x7E09, 0x0000),
+    (0xFA59, 0x7E41, 0x0000), (0xFA5A, 0x7F72, 0x0000), (0xFA5B, 0x8005, 0x0000),
+    (0xFA5C, 0x81ED, 0x0000), (0xFA5D, 0x8279, 0x0000), (0xFA5E, 0x8279, 0x0000),
+    (0xFA5F, 0x8457, 0x0000), (0xFA60, 0x8910, 0x0000), (0xFA61, 0x8996, 0x0000),
+    (0xFA62, 0x8B01, 0x0000), (0xFA63, 0x8B39, 0x0000), (0xFA64, 0x8CD3, 0x0000),
+    (0xFA65, 0x8D08, 0x0000), (0xFA66, 0x8FB6, 0x0000), (0xFA67, 0x9038, 0x0000),
+    (0xFA68, 0x96E3, 0x0000), (0xFA69, 0x97FF, 0x0000), (0xFA6A, 0x983B, 0x0000),
+    (0xFA6B, 0x6075, 0x0000), (0xFA6C, 0x242EE, 0x0000), (0xFA6D, 0x8218, 0x0000),
+    (0xFA70, 0x4E26, 0x0000), (0xFA71, 0x51B5, 0x0000), (0xFA72, 0x5168, 0x0000),
+    (0xFA73, 0x4F80, 0x0000), (0xFA74, 0x5145, 0x0000), (0xFA75, 0x5180, 0x0000),
+    (0xFA76, 0x52C7, 0x0000), (0xFA77, 0x52FA, 0x0000), (0xFA78, 0x559D, 0x0000),
+    (0xFA79, 0x5555, 0x0000), (0xFA7A, 0x5599, 0x0000), (0xFA7B, 0x55E2, 0x0000),
+    (0xFA7C, 0x585A, 0x0000), (0xFA7D, 0x58B3, 0x0000), (0xFA7E, 0x5944, 0x0000),
+    (0xFA7F, 0x5954, 0x0000), (0xFA80, 0x5A62, 0x0000), (0xFA81, 0x5B28, 0x0000),
+    (0xFA82, 0x5ED2, 0x0000), (0xFA83, 0x5ED9, 0x0000), (0xFA84, 0x5F69, 0x0000),
+    (0xFA85, 0x5FAD, 0x0000), (0xFA86, 0x60D8, 0x0000), (0xFA87, 0x614E, 0x0000),
+    (0xFA88, 0x6108, 0x0000), (0xFA89, 0x618E, 0x0000), (0xFA8A, 0x6160, 0x0000),
+    (0xFA8B, 0x61F2, 0x0000), (0xFA8C, 0x6234, 0x0000), (0xFA8D, 0x63C4, 0x0000),
+    (0xFA8E, 0x641C, 0x0000), (0xFA8F, 0x6452, 0x0000), (0xFA90, 0x6556, 0x0000),
+    (0xFA91, 0x6674, 0x0000), (0xFA92, 0x6717, 0x0000), (0xFA93, 0x671B, 0x0000),
+    (0xFA94, 0x6756, 0x0000), (0xFA95, 0x6B79, 0x0000), (0xFA96, 0x6BBA, 0x0000),
+    (0xFA97, 0x6D41, 0x0000), (0xFA98, 0x6EDB, 0x0000), (0xFA99, 0x6ECB, 0x0000),
+    (0xFA9A, 0x6F22, 0x0000), (0xFA9B, 0x701E, 0x0000), (0xFA9C, 0x716E, 0x0000),
+    (0xFA9D, 0x77A7, 0x0000), (0xFA9E, 0x7235, 0x0000), (0xFA9F, 0x72AF, 0x0000),
+    (0xFAA0, 0x732A, 0x0000), (0xFAA1, 0x7471, 0x0000), (0xFAA2, 0x7506, 0x0000),
+    (0xFAA3, 0x753B, 0x0000), (0xFAA4, 0x761D, 0x0000), (0xFAA5, 0x761F, 0x0000),
+    (0xFAA6, 0x76CA, 0x0000), (0xFAA7, 0x76DB, 0x0000), (0xFAA8, 0x76F4, 0x0000),
+    (0xFAA9, 0x774A, 0x0000), (0xFAAA, 0x7740, 0x0000), (0xFAAB, 0x78CC, 0x0000),
+    (0xFAAC, 0x7AB1, 0x0000), (0xFAAD, 0x7BC0, 0x0000), (0xFAAE, 0x7C7B, 0x0000),
+    (0xFAAF, 0x7D5B, 0x0000), (0xFAB0, 0x7DF4, 0x0000), (0xFAB1, 0x7F3E, 0x0000),
+    (0xFAB2, 0x8005, 0x0000), (0xFAB3, 0x8352, 0x0000), (0xFAB4, 0x83EF, 0x0000),
+    (0xFAB5, 0x8779, 0x0000), (0xFAB6, 0x8941, 0x0000), (0xFAB7, 0x8986, 0x0000),
+    (0xFAB8, 0x8996, 0x0000), (0xFAB9, 0x8ABF, 0x0000), (0xFABA, 0x8AF8, 0x0000),
+    (0xFABB, 0x8ACB, 0x0000), (0xFABC, 0x8B01, 0x0000), (0xFABD, 0x8AFE, 0x0000),
+    (0xFABE, 0x8AED, 0x0000), (0xFABF, 0x8B39, 0x0000), (0xFAC0, 0x8B8A, 0x0000),
+    (0xFAC1, 0x8D08, 0x0000), (0xFAC2, 0x8F38, 0x0000), (0xFAC3, 0x9072, 0x0000),
+    (0xFAC4, 0x9199, 0x0000), (0xFAC5, 0x9276, 0x0000), (0xFAC6, 0x967C, 0x0000),
+    (0xFAC7, 0x96E3, 0x0000), (0xFAC8, 0x9756, 0x0000), (0xFAC9, 0x97DB, 0x0000),
+    (0xFACA, 0x97FF, 0x0000), (0xFACB, 0x980B, 0x0000), (0xFACC, 0x983B, 0x0000),
+    (0xFACD, 0x9B12, 0x0000), (0xFACE, 0x9F9C, 0x0000), (0xFACF, 0x2284A, 0x0000),
+    (0xFAD0, 0x22844, 0x0000), (0xFAD1, 0x233D5, 0x0000), (0xFAD2, 0x3B9D, 0x0000),
+    (0xFAD3, 0x4018, 0x0000), (0xFAD4, 0x4039, 0x0000), (0xFAD5, 0x25249, 0x0000),
+    (0xFAD6, 0x25CD0, 0x0000), (0xFAD7, 0x27ED3, 0x0000), (0xFAD8, 0x9F43, 0x0000),
+    (0xFAD9, 0x9F8E, 0x0000), (0xFB1D, 0x05D9, 0x05B4), (0xFB1F, 0x05F2, 0x05B7),
+    (0xFB2A, 0x05E9, 0x05C1), (0xFB2B, 0x05E9, 0x05C2), (0xFB2C, 0xFB49, 0x05C1),
+    (0xFB2D, 0xFB49, 0x05C2), (0xFB2E, 0x05D0, 0x05B7), (0xFB2F, 0x05D0, 0x05B8),
+    (0xFB30, 0x05D0, 0x05BC), (0xFB31, 0x05D1, 0x05BC), (0xFB32, 0x05D2, 0x05BC),
+    (0xFB33, 0x05D3, 0x05BC), (0xFB34, 0x05D4, 0x05BC), (0xFB35, 0x05D5, 0x05BC),
+    (0xFB36, 0x05D6, 0x05BC), (0xFB38, 0x05D8, 0x05BC), (0xFB39, 0x05D9, 0x05BC),
+    (0xFB3A, 0x05DA, 0x05BC), (0xFB3B, 0x05DB, 0x05BC), (0xFB3C, 0x05DC, 0x05BC),
+    (0xFB3E, 0x05DE, 0x05BC), (0xFB40, 0x05E0, 0x05BC), (0xFB41, 0x05E1, 0x05BC),
+    (0xFB43, 0x05E3, 0x05BC), (0xFB44, 0x05E4, 0x05BC), (0xFB46, 0x05E6, 0x05BC),
+    (0xFB47, 0x05E7, 0x05BC), (0xFB48, 0x05E8, 0x05BC), (0xFB49, 0x05E9, 0x05BC),
+    (0xFB4A, 0x05EA, 0x05BC), (0xFB4B, 0x05D5, 0x05B9), (0xFB4C, 0x05D1, 0x05BF),
+    (0xFB4D, 0x05DB, 0x05BF), (0xFB4E, 0x05E4, 0x05BF), (0x1109A, 0x11099, 0x110BA),
+    (0x1109C, 0x1109B, 0x110BA), (0x110AB, 0x110A5, 0x110BA), (0x1112E, 0x11131, 0x11127),
+    (0x1112F, 0x11132, 0x11127), (0x1134B, 0x11347, 0x1133E), (0x1134C, 0x11347, 0x11357),
+    (0x114BB, 0x114B9, 0x114BA), (0x114BC, 0x114B9, 0x114B0), (0x114BE, 0x114B9, 0x114BD),
+    (0x115BA, 0x115B8, 0x115AF), (0x115BB, 0x115B9, 0x115AF), (0x11938, 0x11935, 0x11930),
+    (0x1D15E, 0x1D157, 0x1D165), (0x1D15F, 0x1D158, 0x1D165), (0x1D160, 0x1D15F, 0x1D16E),
+    (0x1D161, 0x1D15F, 0x1D16F), (0x1D162, 0x1D15F, 0x1D170), (0x1D163, 0x1D15F, 0x1D171),
+    (0x1D164, 0x1D15F, 0x1D172), (0x1D1BB, 0x1D1B9, 0x1D165), (0x1D1BC, 0x1D1BA, 0x1D165),
+    (0x1D1BD, 0x1D1BB, 0x1D16E), (0x1D1BE, 0x1D1BC, 0x1D16E), (0x1D1BF, 0x1D1BB, 0x1D16F),
+    (0x1D1C0, 0x1D1BC, 0x1D16F), (0x2F800, 0x4E3D, 0x0000), (0x2F801, 0x4E38, 0x0000),
+    (0x2F802, 0x4E41, 0x0000), (0x2F803, 0x20122, 0x0000), (0x2F804, 0x4F60, 0x0000),
+    (0x2F805, 0x4FAE, 0x0000), (0x2F806, 0x4FBB, 0x0000), (0x2F807, 0x5002, 0x0000),
+    (0x2F808, 0x507A, 0x0000), (0x2F809, 0x5099, 0x0000), (0x2F80A, 0x50E7, 0x0000),
+    (0x2F80B, 0x50CF, 0x0000), (0x2F80C, 0x349E, 0x0000), (0x2F80D, 0x2063A, 0x0000),
+    (0x2F80E, 0x514D, 0x0000), (0x2F80F, 0x5154, 0x0000), (0x2F810, 0x5164, 0x0000),
+    (0x2F811, 0x5177, 0x0000), (0x2F812, 0x2051C, 0x0000), (0x2F813, 0x34B9, 0x0000),
+    (0x2F814, 0x5167, 0x0000), (0x2F815, 0x518D, 0x0000), (0x2F816, 0x2054B, 0x0000),
+    (0x2F817, 0x5197, 0x0000), (0x2F818, 0x51A4, 0x0000), (0x2F819, 0x4ECC, 0x0000),
+    (0x2F81A, 0x51AC, 0x0000), (0x2F81B, 0x51B5, 0x0000), (0x2F81C, 0x291DF, 0x0000),
+    (0x2F81D, 0x51F5, 0x0000), (0x2F81E, 0x5203, 0x0000), (0x2F81F, 0x34DF, 0x0000),
+    (0x2F820, 0x523B, 0x0000), (0x2F821, 0x5246, 0x0000), (0x2F822, 0x5272, 0x0000),
+    (0x2F823, 0x5277, 0x0000), (0x2F824, 0x3515, 0x0000), (0x2F825, 0x52C7, 0x0000),
+    (0x2F826, 0x52C9, 0x0000), (0x2F827, 0x52E4, 0x0000), (0x2F828, 0x52FA, 0x0000),
+    (0x2F829, 0x5305, 0x0000), (0x2F82A, 0x5306, 0x0000), (0x2F82B, 0x5317, 0x0000),
+    (0x2F82C, 0x5349, 0x0000), (0x2F82D, 0x5351, 0x0000), (0x2F82E, 0x535A, 0x0000),
+    (0x2F82F, 0x5373, 0x0000), (0x2F830, 0x537D, 0x0000), (0x2F831, 0x537F, 0x0000),
+    (0x2F832, 0x537F, 0x0000), (0x2F833, 0x537F, 0x0000), (0x2F834, 0x20A2C, 0x0000),
+    (0x2F835, 0x7070, 0x0000), (0x2F836, 0x53CA, 0x0000), (0x2F837, 0x53DF, 0x0000),
+    (0x2F838, 0x20B63, 0x0000), (0x2F839, 0x53EB, 0x0000), (0x2F83A, 0x53F1, 0x0000),
+    (0x2F83B, 0x5406, 0x0000), (0x2F83C, 0x549E, 0x0000), (0x2F83D, 0x5438, 0x0000),
+    (0x2F83E, 0x5448, 0x0000), (0x2F83F, 0x5468, 0x0000), (0x2F840, 0x54A2, 0x0000),
+    (0x2F841, 0x54F6, 0x0000), (0x2F842, 0x5510, 0x0000), (0x2F843, 0x5553, 0x0000),
+    (0x2F844, 0x5563, 0x0000), (0x2F845, 0x5584, 0x0000), (0x2F846, 0x5584, 0x0000),
+    (0x2F847, 0x5599, 0x0000), (0x2F848, 0x55AB, 0x0000), (0x2F849, 0x55B3, 0x0000),
+    (0x2F84A, 0x55C2, 0x0000), (0x2F84B, 0x5716, 0x0000), (0x2F84C, 0x5606, 0x0000),
+    (0x2F84D, 0x5717, 0x0000), (0x2F84E, 0x5651, 0x0000), (0x2F84F, 0x5674, 0x0000),
+    (0x2F850, 0x5207, 0x0000), (0x2F851, 0x58EE, 0x0000), (0x2F852, 0x57CE, 0x0000),
+    (0x2F853, 0x57F4, 0x0000), (0x2F854, 0x580D, 0x0000), (0x2F855, 0x578B, 0x0000),
+    (0x2F856, 0x5832, 0x0000), (0x2F857, 0x5831, 0x0000), (0x2F858, 0x58AC, 0x0000),
+    (0x2F859, 0x214E4, 0x0000), (0x2F85A, 0x58F2, 0x0000), (0x2F85B, 0x58F7, 0x0000),
+    (0x2F85C, 0x5906, 0x0000), (0x2F85D, 0x591A, 0x0000), (0x2F85E, 0x5922, 0x0000),
+    (0x2F85F, 0x5962, 0x0000), (0x2F860, 0x216A8, 0x0000), (0x2F861, 0x216EA, 0x0000),
+    (0x2F862, 0x59EC, 0x0000), (0x2F863, 0x5A1B, 0x0000), (0x2F864, 0x5A27, 0x0000),
+    (0x2F865, 0x59D8, 0x0000), (0x2F866, 0x5A66, 0x0000), (0x2F867, 0x36EE, 0x0000),
+    (0x2F868, 0x36FC, 0x0000), (0x2F869, 0x5B08, 0x0000), (0x2F86A, 0x5B3E, 0x0000),
+    (0x2F86B, 0x5B3E, 0x0000), (0x2F86C, 0x219C8, 0x0000), (0x2F86D, 0x5BC3, 0x0000),
+    (0x2F86E, 0x5BD8, 0x0000), (0x2F86F, 0x5BE7, 0x0000), (0x2F870, 0x5BF3, 0x0000),
+    (0x2F871, 0x21B18, 0x0000), (0x2F872, 0x5BFF, 0x0000), (0x2F873, 0x5C06, 0x0000),
+    (0x2F874, 0x5F53, 0x0000), (0x2F875, 0x5C22, 0x0000), (0x2F876, 0x3781, 0x0000),
+    (0x2F877, 0x5C60, 0x0000), (0x2F878, 0x5C6E, 0x0000), (0x2F879, 0x5CC0, 0x0000),
+    (0x2F87A, 0x5C8D, 0x0000), (0x2F87B, 0x21DE4, 0x0000), (0x2F87C, 0x5D43, 0x0000),
+    (0x2F87D, 0x21DE6, 0x0000), (0x2F87E, 0x5D6E, 0x0000), (0x2F87F, 0x5D6B, 0x0000),
+    (0x2F880, 0x5D7C, 0x0000), (0x2F881, 0x5DE1, 0x0000), (0x2F882, 0x5DE2, 0x0000),
+    (0x2F883, 0x382F, 0x0000), (0x2F884, 0x5DFD, 0x0000), (0x2F885, 0x5E28, 0x0000),
+    (0x2F886, 0x5E3D, 0x0000), (0x2F887, 0x5E69, 0x0000), (0x2F888, 0x3862, 0x0000),
+    (0x2F889, 0x22183, 0x0000), (0x2F88A, 0x387C, 0x0000), (0x2F88B, 0x5EB0, 0x0000),
+    (0x2F88C, 0x5EB3, 0x0000), (0x2F88D, 0x5EB6, 0x0000), (0x2F88E, 0x5ECA, 0x0000),
+    (0x2F88F, 0x2A392, 0x0000), (0x2F890, 0x5EFE, 0x0000), (0x2F891, 0x22331, 0x0000),
+    (0x2F892, 0x22331, 0x0000), (0x2F893, 0x8201, 0x0000), (0x2F894, 0x5F22, 0x0000),
+    (0x2F895, 0x5F22, 0x0000), (0x2F896, 0x38C7, 0x0000), (0x2F897, 0x232B8, 0x0000),
+    (0x2F898, 0x261DA, 0x0000), (0x2F899, 0x5F62, 0x0000), (0x2F89A, 0x5F6B, 0x0000),
+    (0x2F89B, 0x38E3, 0x0000), (0x2F89C, 0x5F9A, 0x0000), (0x2F89D, 0x5FCD, 0x0000),
+    (0x2F89E, 0x5FD7, 0x0000), (0x2F89F, 0x5FF9, 0x0000), (0x2F8A0, 0x6081, 0x0000),
+    (0x2F8A1, 0x393A, 0x0000), (0x2F8A2, 0x391C, 0x0000), (0x2F8A3, 0x6094, 0x0000),
+    (0x2F8A4, 0x226D4, 0x0000), (0x2F8A5, 0x60C7, 0x0000), (0x2F8A6, 0x6148, 0x0000),
+    (0x2F8A7, 0x614C, 0x0000), (0x2F8A8, 0x614E, 0x0000), (0x2F8A9, 0x614C, 0x0000),
+    (0x2F8AA, 0x617A, 0x0000), (0x2F8AB, 0x618E, 0x0000), (0x2F8AC, 0x61B2, 0x0000),
+    (0x2F8AD, 0x61A4, 0x0000), (0x2F8AE, 0x61AF, 0x0000), (0x2F8AF, 0x61DE, 0x0000),
+    (0x2F8B0, 0x61F2, 0x0000), (0x2F8B1, 0x61F6, 0x0000), (0x2F8B2, 0x6210, 0x0000),
+    (0x2F8B3, 0x621B, 0x0000), (0x2F8B4, 0x625D, 0x0000), (0x2F8B5, 0x62B1, 0x0000),
+    (0x2F8B6, 0x62D4, 0x0000), (0x2F8B7, 0x6350, 0x0000), (0x2F8B8, 0x22B0C, 0x0000),
+    (0x2F8B9, 0x633D, 0x0000), (0x2F8BA, 0x62FC, 0x0000), (0x2F8BB, 0x6368, 0x0000),
+    (0x2F8BC, 0x6383, 0x0000), (0x2F8BD, 0x63E4, 0x0000), (0x2F8BE, 0x22BF1, 0x0000),
+    (0x2F8BF, 0x6422, 0x0000), (0x2F8C0, 0x63C5, 0x0000), (0x2F8C1, 0x63A9, 0x0000),
+    (0x2F8C2, 0x3A2E, 0x0000), (0x2F8C3, 0x6469, 0x0000), (0x2F8C4, 0x647E, 0x0000),
+    (0x2F8C5, 0x649D, 0x0000), (0x2F8C6, 0x6477, 0x0000), (0x2F8C7, 0x3A6C, 0x0000),
+    (0x2F8C8, 0x654F, 0x0000), (0x2F8C9, 0x656C, 0x0000), (0x2F8CA, 0x2300A, 0x0000),
+    (0x2F8CB, 0x65E3, 0x0000), (0x2F8CC, 0x66F8, 0x0000), (0x2F8CD, 0x6649, 0x0000),
+    (0x2F8CE, 0x3B19, 0x0000), (0x2F8CF, 0x6691, 0x0000), (0x2F8D0, 0x3B08, 0x0000),
+    (0x2F8D1, 0x3AE4, 0x0000), (0x2F8D2, 0x5192, 0x0000), (0x2F8D3, 0x5195, 0x0000),
+    (0x2F8D4, 0x6700, 0x0000), (0x2F8D5, 0x669C, 0x0000), (0x2F8D6, 0x80AD, 0x0000),
+    (0x2F8D7, 0x43D9, 0x0000), (0x2F8D8, 0x6717, 0x0000), (0x2F8D9, 0x671B, 0x0000),
+    (0x2F8DA, 0x6721, 0x0000), (0x2F8DB, 0x675E, 0x0000), (0x2F8DC, 0x6753, 0x0000),
+    (0x2F8DD, 0x233C3, 0x0000), (0x2F8DE, 0x3B49, 0x0000), (0x2F8DF, 0x67FA, 0x0000),
+    (0x2F8E0, 0x6785, 0x0000), (0x2F8E1, 0x6852, 0x0000), (0x2F8E2, 0x6885, 0x0000),
+    (0x2F8E3, 0x2346D, 0x0000), (0x2F8E4, 0x688E, 0x0000), (0x2F8E5, 0x681F, 0x0000),
+    (0x2F8E6, 0x6914, 0x0000), (0x2F8E7, 0x3B9D, 0x0000), (0x2F8E8, 0x6942, 0x0000),
+    (0x2F8E9, 0x69A3, 0x0000), (0x2F8EA, 0x69EA, 0x0000), (0x2F8EB, 0x6AA8, 0x0000),
+    (0x2F8EC, 0x236A3, 0x0000), (0x2F8ED, 0x6ADB, 0x0000), (0x2F8EE, 0x3C18, 0x0000),
+    (0x2F8EF, 0x6B21, 0x0000), (0x2F8F0, 0x238A7, 0x0000), (0x2F8F1, 0x6B54, 0x0000),
+    (0x2F8F2, 0x3C4E, 0x0000), (0x2F8F3, 0x6B72, 0x0000), (0x2F8F4, 0x6B9F, 0x0000),
+    (0x2F8F5, 0x6BBA, 0x0000), (0x2F8F6, 0x6BBB, 0x0000), (0x2F8F7, 0x23A8D, 0x0000),
+    (0x2F8F8, 0x21D0B, 0x0000), (0x2F8F9, 0x23AFA, 0x0000), (0x2F8FA, 0x6C4E, 0x0000),
+    (0x2F8FB, 0x23CBC, 0x0000), (0x2F8FC, 0x6CBF, 0x0000), (0x2F8FD, 0x6CCD, 0x0000),
+    (0x2F8FE, 0x6C67, 0x0000), (0x2F8FF, 0x6D16, 0x0000), (0x2F900, 0x6D3E, 0x0000),
+    (0x2F901, 0x6D77, 0x0000), (0x2F902, 0x6D41, 0x0000), (0x2F903, 0x6D69, 0x0000),
+    (0x2F904, 0x6D78, 0x0000), (0x2F905, 0x6D85, 0x0000), (0x2F906, 0x23D1E, 0x0000),
+    (0x2F907, 0x6D34, 0x0000), (0x2F908, 0x6E2F, 0x0000), (0x2F909, 0x6E6E, 0x0000),
+    (0x2F90A, 0x3D33, 0x0000), (0x2F90B, 0x6ECB, 0x0000), (0x2F90C, 0x6EC7, 0x0000),
+    (0x2F90D, 0x23ED1, 0x0000), (0x2F90E, 0x6DF9, 0x0000), (0x2F90F, 0x6F6E, 0x0000),
+    (0x2F910, 0x23F5E, 0x0000), (0x2F911, 0x23F8E, 0x0000), (0x2F912, 0x6FC6, 0x0000),
+    (0x2F913, 0x7039, 0x0000), (0x2F914, 0x701E, 0x0000), (0x2F915, 0x701B, 0x0000),
+    (0x2F916, 0x3D96, 0x0000), (0x2F917, 0x704A, 0x0000), (0x2F918, 0x707D, 0x0000),
+    (0x2F919, 0x7077, 0x0000), (0x2F91A, 0x70AD, 0x0000), (0x2F91B, 0x20525, 0x0000),
+    (0x2F91C, 0x7145, 0x0000), (0x2F91D, 0x24263, 0x0000), (0x2F91E, 0x719C, 0x0000),
+    (0x2F91F, 0x243AB, 0x0000), (0x2F920, 0x7228, 0x0000), (0x2F921, 0x7235, 0x0000),
+    (0x2F922, 0x7250, 0x0000), (0x2F923, 0x24608, 0x0000), (0x2F924, 0x7280, 0x0000),
+    (0x2F925, 0x7295, 0x0000), (0x2F926, 0x24735, 0x0000), (0x2F927, 0x24814, 0x0000),
+    (0x2F928, 0x737A, 0x0000), (0x2F929, 0x738B, 0x0000), (0x2F92A, 0x3EAC, 0x0000),
+    (0x2F92B, 0x73A5, 0x0000), (0x2F92C, 0x3EB8, 0x0000), (0x2F92D, 0x3EB8, 0x0000),
+    (0x2F92E, 0x7447, 0x0000), (0x2F92F, 0x745C, 0x0000), (0x2F930, 0x7471, 0x0000),
+    (0x2F931, 0x7485, 0x0000), (0x2F932, 0x74CA, 0x0000), (0x2F933, 0x3F1B, 0x0000),
+    (0x2F934, 0x7524, 0x0000), (0x2F935, 0x24C36, 0x0000), (0x2F936, 0x753E, 0x0000),
+    (0x2F937, 0x24C92, 0x0000), (0x2F938, 0x7570, 0x0000), (0x2F939, 0x2219F, 0x0000),
+    (0x2F93A, 0x7610, 0x0000), (0x2F93B, 0x24FA1, 0x0000), (0x2F93C, 0x24FB8, 0x0000),
+    (0x2F93D, 0x25044, 0x0000), (0x2F93E, 0x3FFC, 0x0000), (0x2F93F, 0x4008, 0x0000),
+    (0x2F940, 0x76F4, 0x0000), (0x2F941, 0x250F3, 0x0000), (0x2F942, 0x250F2, 0x0000),
+    (0x2F943, 0x25119, 0x0000), (0x2F944, 0x25133, 0x0000), (0x2F945, 0x771E, 0x0000),
+    (0x2F946, 0x771F, 0x0000), (0x2F947, 0x771F, 0x0000), (0x2F948, 0x774A, 0x0000),
+    (0x2F949, 0x4039, 0x0000), (0x2F94A, 0x778B, 0x0000), (0x2F94B, 0x4046, 0x0000),
+    (0x2F94C, 0x4096, 0x0000), (0x2F94D, 0x2541D, 0x0000), (0x2F94E, 0x784E, 0x0000),
+    (0x2F94F, 0x788C, 0x0000), (0x2F950, 0x78CC, 0x0000), (0x2F951, 0x40E3, 0x0000),
+    (0x2F952, 0x25626, 0x0000), (0x2F953, 0x7956, 0x0000), (0x2F954, 0x2569A, 0x0000),
+    (0x2F955, 0x256C5, 0x0000), (0x2F956, 0x798F, 0x0000), (0x2F957, 0x79EB, 0x0000),
+    (0x2F958, 0x412F, 0x0000), (0x2F959, 0x7A40, 0x0000), (0x2F95A, 0x7A4A, 0x0000),
+    (0x2F95B, 0x7A4F, 0x0000), (0x2F95C, 0x2597C, 0x0000), (0x2F95D, 0x25AA7, 0x0000),
+    (0x2F95E, 0x25AA7, 0x0000), (0x2F95F, 0x7AEE, 0x0000), (0x2F960, 0x4202, 0x0000),
+    (0x2F961, 0x25BAB, 0x0000), (0x2F962, 0x7BC6, 0x0000), (0x2F963, 0x7BC9, 0x0000),
+    (0x2F964, 0x4227, 0x0000), (0x2F965, 0x25C80, 0x0000), (0x2F966, 0x7CD2, 0x0000),
+    (0x2F967, 0x42A0, 0x0000), (0x2F968, 0x7CE8, 0x0000), (0x2F969, 0x7CE3, 0x0000),
+    (0x2F96A, 0x7D00, 0x0000), (0x2F96B, 0x25F86, 0x0000), (0x2F96C, 0x7D63, 0x0000),
+    (0x2F96D, 0x4301, 0x0000), (0x2F96E, 0x7DC7, 0x0000), (0x2F96F, 0x7E02, 0x0000),
+    (0x2F970, 0x7E45, 0x0000), (0x2F971, 0x4334, 0x0000), (0x2F972, 0x26228, 0x0000),
+    (0x2F973, 0x26247, 0x0000), (0x2F974, 0x4359, 0x0000), (0x2F975, 0x262D9, 0x0000),
+    (0x2F976, 0x7F7A, 0x0000), (0x2F977, 0x2633E, 0x0000), (0x2F978, 0x7F95, 0x0000),
+    (0x2F979, 0x7FFA, 0x0000), (0x2F97A, 0x8005, 0x0000), (0x2F97B, 0x264DA, 0x0000),
+    (0x2F97C, 0x26523, 0x0000), (0x2F97D, 0x8060, 0x0000), (0x2F97E, 0x265A8, 0x0000),
+    (0x2F97F, 0x8070, 0x0000), (0x2F980, 0x2335F, 0x0000), (0x2F981, 0x43D5, 0x0000),
+    (0x2F982, 0x80B2, 0x0000), (0x2F983, 0x8103, 0x0000), (0x2F984, 0x440B, 0x0000),
+    (0x2F985, 0x813E, 0x0000), (0x2F986, 0x5AB5, 0x0000), (0x2F987, 0x267A7, 0x0000),
+    (0x2F988, 0x267B5, 0x0000), (0x2F989, 0x23393, 0x0000), (0x2F98A, 0x2339C, 0x0000),
+    (0x2F98B, 0x8201, 0x0000), (0x2F98C, 0x8204, 0x0000), (0x2F98D, 0x8F9E, 0x0000),
+    (0x2F98E, 0x446B, 0x0000), (0x2F98F, 0x8291, 0x0000), (0x2F990, 0x828B, 0x0000),
+    (0x2F991, 0x829D, 0x0000), (0x2F992, 0x52B3, 0x0000), (0x2F993, 0x82B1, 0x0000),
+    (0x2F994, 0x82B3, 0x0000), (0x2F995, 0x82BD, 0x0000), (0x2F996, 0x82E6, 0x0000),
+    (0x2F997, 0x26B3C, 0x0000), (0x2F998, 0x82E5, 0x0000), (0x2F999, 0x831D, 0x0000),
+    (0x2F99A, 0x8363, 0x0000), (0x2F99B, 0x83AD, 0x0000), (0x2F99C, 0x8323, 0x0000),
+    (0x2F99D, 0x83BD, 0x0000), (0x2F99E, 0x83E7, 0x0000), (0x2F99F, 0x8457, 0x0000),
+    (0x2F9A0, 0x8353, 0x0000), (0x2F9A1, 0x83CA, 0x0000), (0x2F9A2, 0x83CC, 0x0000),
+    (0x2F9A3, 0x83DC, 0x0000), (0x2F9A4, 0x26C36, 0x0000), (0x2F9A5, 0x26D6B, 0x0000),
+    (0x2F9A6, 0x26CD5, 0x0000), (0x2F9A7, 0x452B, 0x0000), (0x2F9A8, 0x84F1, 0x0000),
+    (0x2F9A9, 0x84F3, 0x0000), (0x2F9AA, 0x8516, 0x0000), (0x2F9AB, 0x273CA, 0x0000),
+    (0x2F9AC, 0x8564, 0x0000), (0x2F9AD, 0x26F2C, 0x0000), (0x2F9AE, 0x455D, 0x0000),
+    (0x2F9AF, 0x4561, 0x0000), (0x2F9B0, 0x26FB1, 0x0000), (0x2F9B1, 0x270D2, 0x0000),
+    (0x2F9B2, 0x456B, 0x0000), (0x2F9B3, 0x8650, 0x0000), (0x2F9B4, 0x865C, 0x0000),
+    (0x2F9B5, 0x8667, 0x0000), (0x2F9B6, 0x8669, 0x0000), (0x2F9B7, 0x86A9, 0x0000),
+    (0x2F9B8, 0x8688, 0x0000), (0x2F9B9, 0x870E, 0x0000), (0x2F9BA, 0x86E2, 0x0000),
+    (0x2F9BB, 0x8779, 0x0000), (0x2F9BC, 0x8728, 0x0000), (0x2F9BD, 0x876B, 0x0000),
+    (0x2F9BE, 0x8786, 0x0000), (0x2F9BF, 0x45D7, 0x0000), (0x2F9C0, 0x87E1, 0x0000),
+    (0x2F9C1, 0x8801, 0x0000), (0x2F9C2, 0x45F9, 0x0000), (0x2F9C3, 0x8860, 0x0000),
+    (0x2F9C4, 0x8863, 0x0000), (0x2F9C5, 0x27667, 0x0000), (0x2F9C6, 0x88D7, 0x0000),
+    (0x2F9C7, 0x88DE, 0x0000), (0x2F9C8, 0x4635, 0x0000), (0x2F9C9, 0x88FA, 0x0000),
+    (0x2F9CA, 0x34BB, 0x0000), (0x2F9CB, 0x278AE, 0x0000), (0x2F9CC, 0x27966, 0x0000),
+    (0x2F9CD, 0x46BE, 0x0000), (0x2F9CE, 0x46C7, 0x0000), (0x2F9CF, 0x8AA0, 0x0000),
+    (0x2F9D0, 0x8AED, 0x0000), (0x2F9D1, 0x8B8A, 0x0000), (0x2F9D2, 0x8C55, 0x0000),
+    (0x2F9D3, 0x27CA8, 0x0000), (0x2F9D4, 0x8CAB, 0x0000), (0x2F9D5, 0x8CC1, 0x0000),
+    (0x2F9D6, 0x8D1B, 0x0000), (0x2F9D7, 0x8D77, 0x0000), (0x2F9D8, 0x27F2F, 0x0000),
+    (0x2F9D9, 0x20804, 0x0000), (0x2F9DA, 0x8DCB, 0x0000), (0x2F9DB, 0x8DBC, 0x0000),
+    (0x2F9DC, 0x8DF0, 0x0000), (0x2F9DD, 0x208DE, 0x0000), (0x2F9DE, 0x8ED4, 0x0000),
+    (0x2F9DF, 0x8F38, 0x0000), (0x2F9E0, 0x285D2, 0x0000), (0x2F9E1, 0x285ED, 0x0000),
+    (0x2F9E2, 0x9094, 0x0000), (0x2F9E3, 0x90F1, 0x0000), (0x2F9E4, 0x9111, 0x0000),
+    (0x2F9E5, 0x2872E, 0x0000), (0x2F9E6, 0x911B, 0x0000), (0x2F9E7, 0x9238, 0x0000),
+    (0x2F9E8, 0x92D7, 0x0000), (0x2F9E9, 0x92D8, 0x0000), (0x2F9EA, 0x927C, 0x0000),
+    (0x2F9EB, 0x93F9, 0x0000), (0x2F9EC, 0x9415, 0x0000), (0x2F9ED, 0x28BFA, 0x0000),
+    (0x2F9EE, 0x958B, 0x0000), (0x2F9EF, 0x4995, 0x0000), (0x2F9F0, 0x95B7, 0x0000),
+    (0x2F9F1, 0x28D77, 0x0000), (0x2F9F2, 0x49E6, 0x0000), (0x2F9F3, 0x96C3, 0x0000),
+    (0x2F9F4, 0x5DB2, 0x0000), (0x2F9F5, 0x9723, 0x0000), (0x2F9F6, 0x29145, 0x0000),
+    (0x2F9F7, 0x2921A, 0x0000), (0x2F9F8, 0x4A6E, 0x0000), (0x2F9F9, 0x4A76, 0x0000),
+    (0x2F9FA, 0x97E0, 0x0000), (0x2F9FB, 0x2940A, 0x0000), (0x2F9FC, 0x4AB2, 0x0000),
+    (0x2F9FD, 0x29496, 0x0000), (0x2F9FE, 0x980B, 0x0000), (0x2F9FF, 0x980B, 0x0000),
+    (0x2FA00, 0x9829, 0x0000), (0x2FA01, 0x295B6, 0x0000), (0x2FA02, 0x98E2, 0x0000),
+    (0x2FA03, 0x4B33, 0x0000), (0x2FA04, 0x9929, 0x0000), (0x2FA05, 0x99A7, 0x0000),
+    (0x2FA06, 0x99C2, 0x0000), (0x2FA07, 0x99FE, 0x0000), (0x2FA08, 0x4BCE, 0x0000),
+    (0x2FA09, 0x29B30, 0x0000), (0x2FA0A, 0x9B12, 0x0000), (0x2FA0B, 0x9C40, 0x0000),
+    (0x2FA0C, 0x9CFD, 0x0000), (0x2FA0D, 0x4CCE, 0x0000), (0x2FA0E, 0x4CED, 0x0000),
+    (0x2FA0F, 0x9D67, 0x0000), (0x2FA10, 0x2A0CE, 0x0000), (0x2FA11, 0x4CF8, 0x0000),
+    (0x2FA12, 0x2A105, 0x0000), (0x2FA13, 0x2A20E, 0x0000), (0x2FA14, 0x2A291, 0x0000),
+    (0x2FA15, 0x9EBB, 0x0000), (0x2FA16, 0x4D56, 0x0000), (0x2FA17, 0x9EF9, 0x0000),
+    (0x2FA18, 0x9EFE, 0x0000), (0x2FA19, 0x9F05, 0x0000), (0x2FA1A, 0x9F0F, 0x0000),
+    (0x2FA1B, 0x9F16, 0x0000), (0x2FA1C, 0x9F3B, 0x0000), (0x2FA1D, 0x2A600, 0x0000),
+];
+
+/// Primary composites: `(first, second, composite)`, sorted by pair.
+#[rustfmt::skip]
+pub(super) static COMPOSITION: [(u32, u32, u32); 941] = [
+    (0x003C, 0x0338, 0x226E), (0x003D, 0x0338, 0x2260), (0x003E, 0x0338, 0x226F),
+    (0x0041, 0x0300, 0x00C0), (0x0041, 0x0301, 0x00C1), (0x0041, 0x0302, 0x00C2),
+    (0x0041, 0x0303, 0x00C3), (0x0041, 0x0304, 0x0100), (0x0041, 0x0306, 0x0102),
+    (0x0041, 0x0307, 0x0226), (0x0041, 0x0308, 0x00C4), (0x0041, 0x0309, 0x1EA2),
+    (0x0041, 0x030A, 0x00C5), (0x0041, 0x030C, 0x01CD), (0x0041, 0x030F, 0x0200),
+    (0x0041, 0x0311, 0x0202), (0x0041, 0x0323, 0x1EA0), (0x0041, 0x0325, 0x1E00),
+    (0x0041, 0x0328, 0x0104), (0x0042, 0x0307, 0x1E02), (0x0042, 0x0323, 0x1E04),
+    (0x0042, 0x0331, 0x1E06), (0x0043, 0x0301, 0x0106), (0x0043, 0x0302, 0x0108),
+    (0x0043, 0x0307, 0x010A), (0x0043, 0x030C, 0x010C), (0x0043, 0x0327, 0x00C7),
+    (0x0044, 0x0307, 0x1E0A), (0x0044, 0x030C, 0x010E), (0x0044, 0x0323, 0x1E0C),
+    (0x0044, 0x0327, 0x1E10), (0x0044, 0x032D, 0x1E12), (0x0044, 0x0331, 0x1E0E),
+    (0x0045, 0x0300, 0x00C8), (0x0045, 0x0301, 0x00C9), (0x0045, 0x0302, 0x00CA),
+    (0x0045, 0x0303, 0x1EBC), (0x0045, 0x0304, 0x0112), (0x0045, 0x0306, 0x0114),
+    (0x0045, 0x0307, 0x0116), (0x0045, 0x0308, 0x00CB), (0x0045, 0x0309, 0x1EBA),
+    (0x0045, 0x030C, 0x011A), (0x0045, 0x030F, 0x0204), (0x0045, 0x0311, 0x0206),
+    (0x0045, 0x0323, 0x1EB8), (0x0045, 0x0327, 0x0228), (0x0045, 0x0328, 0x0118),
+    (0x0045, 0x032D, 0x1E18), (0x0045, 0x0330, 0x1E1A), (0x0046, 0x0307, 0x1E1E),
+    (0x0047, 0x0301, 0x01F4), (0x0047, 0x0302, 0x011C), (0x0047, 0x0304, 0x1E20),
+    (0x0047, 0x0306, 0x011E), (0x0047, 0x0307, 0x0120), (0x0047, 0x030C, 0x01E6),
+    (0x0047, 0x0327, 0x0122), (0x0048, 0x0302, 0x0124), (0x0048, 0x0307, 0x1E22),
+    (0x0048, 0x0308, 0x1E26), (0x0048, 0x030C, 0x021E), (0x0048, 0x0323, 0x1E24),
+    (0x0048, 0x0327, 0x1E28), (0x0048, 0x032E, 0x1E2A), (0x0049, 0x0300, 0x00CC),
+    (0x0049, 0x0301, 0x00CD), (0x0049, 0x0302, 0x00CE), (0x0049, 0x0303, 0x0128),
+    (0x0049, 0x0304, 0x012A), (0x0049, 0x0306, 0x012C), (0x0049, 0x0307, 0x0130),
+    (0x0049, 0x0308, 0x00CF), (0x0049, 0x0309, 0x1EC8), (0x0049, 0x030C, 0x01CF),
+    (0x0049, 0x030F, 0x0208), (0x0049, 0x0311, 0x020A), (0x0049, 0x0323, 0x1ECA),
+    (0x0049, 0x0328, 0x012E), (0x0049, 0x0330, 0x1E2C), (0x004A, 0x0302, 0x0134),
+    (0x004B, 0x0301, 0x1E30), (0x004B, 0x030C, 0x01E8), (0x004B, 0x0323, 0x1E32),
+    (0x004B, 0x0327, 0x0136), (0x004B, 0x0331, 0x1E34), (0x004C, 0x0301, 0x0139),
+    (0x004C, 0x030C, 0x013D), (0x004C, 0x0323, 0x1E36), (0x004C, 0x0327, 0x013B),
+    (0x004C, 0x032D, 0x1E3C), (0x004C, 0x0331, 0x1E3A), (0x004D, 0x0301, 0x1E3E),
+    (0x004D, 0x0307, 0x1E40), (0x004D, 0x0323, 0x1E42), (0x004E, 0x0300, 0x01F8),
+    (0x004E, 0x0301, 0x0143), (0x004E, 0x0303, 0x00D1), (0x004E, 0x0307, 0x1E44),
+    (0x004E, 0x030C, 0x0147), (0x004E, 0x0323, 0x1E46), (0x004E, 0x0327, 0x0145),
+    (0x004E, 0x032D, 0x1E4A), (0x004E, 0x0331, 0x1E48), (0x004F, 0x0300, 0x00D2),
+    (0x004F, 0x0301, 0x00D3), (0x004F, 0x0302, 0x00D4), (0x004F, 0x0303, 0x00D5),
+    (0x004F, 0x0304, 0x014C), (0x004F, 0x0306, 0x014E), (0x004F, 0x0307, 0x022E),
+    (0x004F, 0x0308, 0x00D6), (0x004F, 0x0309, 0x1ECE), (0x004F, 0x030B, 0x0150),
+    (0x004F, 0x030C, 0x01D1), (0x004F, 0x030F, 0x020C), (0x004F, 0x0311, 0x020E),
+    (0x004F, 0x031B, 0x01A0), (0x004F, 0x0323, 0x1ECC), (0x004F, 0x0328, 0x01EA),
+    (0x0050, 0x0301, 0x1E54), (0x0050, 0x0307, 0x1E56), (0x0052, 0x0301, 0x0154),
+    (0x0052, 0x0307, 0x1E58), (0x0052, 0x030C, 0x0158), (0x0052, 0x030F, 0x0210),
+    (0x0052, 0x0311, 0x0212), (0x0052, 0x0323, 0x1E5A), (0x0052, 0x0327, 0x0156),
+    (0x0052, 0x0331, 0x1E5E), (0x0053, 0x0301, 0x015A), (0x0053, 0x0302, 0x015C),
+    (0x0053, 0x0307, 0x1E60), (0x0053, 0x030C, 0x0160), (0x0053, 0x0323, 0x1E62),
+    (0x0053, 0x0326, 0x0218), (0x0053, 0x0327, 0x015E), (0x0054, 0x0307, 0x1E6A),
+    (0x0054, 0x030C, 0x0164), (0x0054, 0x0323, 0x1E6C), (0x0054, 0x0326, 0x021A),
+    (0x0054, 0x0327, 0x0162), (0x0054, 0x032D, 0x1E70), (0x0054, 0x0331, 0x1E6E),
+    (0x0055, 0x0300, 0x00D9), (0x0055, 0x0301, 0x00DA), (0x0055, 0x0302, 0x00DB),
+    (0x0055, 0x0303, 0x0168), (0x0055, 0x0304, 0x016A), (0x0055, 0x0306, 0x016C),
+    (0x0055, 0x0308, 0x00DC), (0x0055, 0x0309, 0x1EE6), (0x0055, 0x030A, 0x016E),
+    (0x0055, 0x030B, 0x0170), (0x0055, 0x030C, 0x01D3), (0x0055, 0x030F, 0x0214),
+    (0x0055, 0x0311, 0x0216), (0x0055, 0x031B, 0x01AF), (0x0055, 0x0323, 0x1EE4),
+    (0x0055, 0x0324, 0x1E72), (0x0055, 0x0328, 0x0172), (0x0055, 0x032D, 0x1E76),
+    (0x0055, 0x0330, 0x1E74), (0x0056, 0x0303, 0x1E7C), (0x0056, 0x0323, 0x1E7E),
+    (0x0057, 0x0300, 0x1E80), (0x0057, 0x0301, 0x1E82), (0x0057, 0x0302, 0x0174),
+    (0x0057, 0x0307, 0x1E86), (0x0057, 0x0308, 0x1E84), (0x0057, 0x0323, 0x1E88),
+    (0x0058, 0x0307, 0x1E8A), (0x0058, 0x0308, 0x1E8C), (0x0059, 0x0300, 0x1EF2),
+    (0x0059, 0x0301, 0x00DD), (0x0059, 0x0302, 0x0176), (0x0059, 0x0303, 0x1EF8),
+    (0x0059, 0x0304, 0x0232), (0x0059, 0x0307, 0x1E8E), (0x0059, 0x0308, 0x0178),
+    (0x0059, 0x0309, 0x1EF6), (0x0059, 0x0323, 0x1EF4), (0x005A, 0x0301, 0x0179),
+    (0x005A, 0x0302, 0x1E90), (0x005A, 0x0307, 0x017B), (0x005A, 0x030C, 0x017D),
+    (0x005A, 0x0323, 0x1E92), (0x005A, 0x0331, 0x1E94), (0x0061, 0x0300, 0x00E0),
+    (0x0061, 0x0301, 0x00E1), (0x0061, 0x0302, 0x00E2), (0x0061, 0x0303, 0x00E3),
+    (0x0061, 0x0304, 0x0101), (0x0061, 0x0306, 0x0103), (0x0061, 0x0307, 0x0227),
+    (0x0061, 0x0308, 0x00E4), (0x0061, 0x0309, 0x1EA3), (0x0061, 0x030A, 0x00E5),
+    (0x0061, 0x030C, 0x01CE), (0x0061, 0x030F, 0x0201), (0x0061, 0x0311, 0x0203),
+    (0x0061, 0x0323, 0x1EA1), (0x0061, 0x0325, 0x1E01), (0x0061, 0x0328, 0x0105),
+    (0x0062, 0x0307, 0x1E03), (0x0062, 0x0323, 0x1E05), (0x0062, 0x0331, 0x1E07),
+    (0x0063, 0x0301, 0x0107), (0x0063, 0x0302, 0x0109), (0x0063, 0x0307, 0x010B),
+    (0x0063, 0x030C, 0x010D), (0x0063, 0x0327, 0x00E7), (0x0064, 0x0307, 0x1E0B),
+    (0x0064, 0x030C, 0x010F), (0x0064, 0x0323, 0x1E0D), (0x0064, 0x0327, 0x1E11),
+    (0x0064, 0x032D, 0x1E13), (0x0064, 0x0331, 0x1E0F), (0x0065, 0x0300, 0x00E8),
+    (0x0065, 0x0301, 0x00E9), (0x0065, 0x0302, 0x00EA), (0x0065, 0x0303, 0x1EBD),
+    (0x0065, 0x0304, 0x0113), (0x0065, 0x0306, 0x0115), (0x0065, 0x0307, 0x0117),
+    (0x0065, 0x0308, 0x00EB), (0x0065, 0x0309, 0x1EBB), (0x0065, 0x030C, 0x011B),
+    (0x0065, 0x030F, 0x0205), (0x0065, 0x0311, 0x0207), (0x0065, 0x0323, 0x1EB9),
+    (0x0065, 0x0327, 0x0229), (0x0065, 0x0328, 0x0119), (0x0065, 0x032D, 0x1E19),
+    (0x0065, 0x0330, 0x1E1B), (0x0066, 0x0307, 0x1E1F), (0x0067, 0x0301, 0x01F5),
+    (0x0067, 0x0302, 0x011D), (0x0067, 0x0304, 0x1E21), (0x0067, 0x0306, 0x011F),
+    (0x0067, 0x0307, 0x0121), (0x0067, 0x030C, 0x01E7), (0x0067, 0x0327, 0x0123),
+    (0x0068, 0x0302, 0x0125), (0x0068, 0x0307, 0x1E23), (0x0068, 0x0308, 0x1E27),
+    (0x0068, 0x030C, 0x021F), (0x0068, 0x0323, 0x1E25), (0x0068, 0x0327, 0x1E29),
+    (0x0068, 0x032E, 0x1E2B), (0x0068, 0x0331, 0x1E96), (0x0069, 0x0300, 0x00EC),
+    (0x0069, 0x0301, 0x00ED), (0x0069, 0x0302, 0x00EE), (0x0069, 0x0303, 0x0129),
+    (0x0069, 0x0304, 0x012B), (0x0069, 0x0306, 0x012D), (0x0069, 0x0308, 0x00EF),
+    (0x0069, 0x0309, 0x1EC9), (0x0069, 0x030C, 0x01D0), (0x0069, 0x030F, 0x0209),
+    (0x0069, 0x0311, 0x020B), (0x0069, 0x0323, 0x1ECB), (0x0069, 0x0328, 0x012F),
+    (0x0069, 0x0330, 0x1E2D), (0x006A, 0x0302, 0x0135), (0x006A, 0x030C, 0x01F0),
+    (0x006B, 0x0301, 0x1E31), (0x006B, 0x030C, 0x01E9), (0x006B, 0x0323, 0x1E33),
+    (0x006B, 0x0327, 0x0137), (0x006B, 0x0331, 0x1E35), (0x006C, 0x0301, 0x013A),
+    (0x006C, 0x030C, 0x013E), (0x006C, 0x0323, 0x1E37), (0x006C, 0x0327, 0x013C),
+    (0x006C, 0x032D, 0x1E3D), (0x006C, 0x0331, 0x1E3B), (0x006D, 0x0301, 0x1E3F),
+    (0x006D, 0x0307, 0x1E41), (0x006D, 0x0323, 0x1E43), (0x006E, 0x0300, 0x01F9),
+    (0x006E, 0x0301, 0x0144), (0x006E, 0x0303, 0x00F1), (0x006E, 0x0307, 0x1E45),
+    (0x006E, 0x030C, 0x0148), (0x006E, 0x0323, 0x1E47), (0x006E, 0x0327, 0x0146),
+    (0x006E, 0x032D, 0x1E4B), (0x006E, 0x0331, 0x1E49), (0x006F, 0x0300, 0x00F2),
+    (0x006F, 0x0301, 0x00F3), (0x006F, 0x0302, 0x00F4), (0x006F, 0x0303, 0x00F5),
+    (0x006F, 0x0304, 0x014D), (0x006F, 0x0306, 0x014F), (0x006F, 0x0307, 0x022F),
+    (0x006F, 0x0308, 0x00F6), (0x006F, 0x0309, 0x1ECF), (0x006F, 0x030B, 0x0151),
+    (0x006F, 0x030C, 0x01D2), (0x006F, 0x030F, 0x020D), (0x006F, 0x0311, 0x020F),
+    (0x006F, 0x031B, 0x01A1), (0x006F, 0x0323, 0x1ECD), (0x006F, 0x0328, 0x01EB),
+    (0x0070, 0x0301, 0x1E55), (0x0070, 0x0307, 0x1E57), (0x0072, 0x0301, 0x0155),
+    (0x0072, 0x0307, 0x1E59), (0x0072, 0x030C, 0x0159), (0x0072, 0x030F, 0x0211),
+    (0x0072, 0x0311, 0x0213), (0x0072, 0x0323, 0x1E5B), (0x0072, 0x0327, 0x0157),
+    (0x0072, 0x0331, 0x1E5F), (0x0073, 0x0301, 0x015B), (0x0073, 0x0302, 0x015D),
+    (0x0073, 0x0307, 0x1E61), (0x0073, 0x030C, 0x0161), (0x0073, 0x0323, 0x1E63),
+    (0x0073, 0x0326, 0x0219), (0x0073, 0x0327, 0x015F), (0x0074, 0x0307, 0x1E6B),
+    (0x0074, 0x0308, 0x1E97), (0x0074, 0x030C, 0x0165), (0x0074, 0x0323, 0x1E6D),
+    (0x0074, 0x0326, 0x021B), (0x0074, 0x0327, 0x0163), (0x0074, 0x032D, 0x1E71),
+    (0x0074, 0x0331, 0x1E6F), (0x0075, 0x0300, 0x00F9), (0x0075, 0x0301, 0x00FA),
+    (0x0075, 0x0302, 0x00FB), (0x0075, 0x0303, 0x0169), (0x0075, 0x0304, 0x016B),
+    (0x0075, 0x0306, 0x016D), (0x0075, 0x0308, 0x00FC), (0x0075, 0x0309, 0x1EE7),
+    (0x0075, 0x030A, 0x016F), (0x0075, 0x030B, 0x0171), (0x0075, 0x030C, 0x01D4),
+    (0x0075, 0x030F, 0x0215), (0x0075, 0x0311, 0x0217), (0x0075, 0x031B, 0x01B0),
+    (0x0075, 0x0323, 0x1EE5), (0x0075, 0x0324, 0x1E73), (0x0075, 0x0328, 0x0173),
+    (0x0075, 0x032D, 0x1E77), (0x0075, 0x0330, 0x1E75), (0x0076, 0x0303, 0x1E7D),
+    (0x0076, 0x0323, 0x1E7F), (0x0077, 0x0300, 0x1E81), (0x0077, 0x0301, 0x1E83),
+    (0x0077, 0x0302, 0x0175), (0x0077, 0x0307, 0x1E87), (0x0077, 0x0308, 0x1E85),
+    (0x0077, 0x030A, 0x1E98), (0x0077, 0x0323, 0x1E89), (0x0078, 0x0307, 0x1E8B),
+    (0x0078, 0x0308, 0x1E8D), (0x0079, 0x0300, 0x1EF3), (0x0079, 0x0301, 0x00FD),
+    (0x0079, 0x0302, 0x0177), (0x0079, 0x0303, 0x1EF9), (0x0079, 0x0304, 0x0233),
+    (0x0079, 0x0307, 0x1E8F), (0x0079, 0x0308, 0x00FF), (0x0079, 0x0309, 0x1EF7),
+    (0x0079, 0x030A, 0x1E99), (0x0079, 0x0323, 0x1EF5), (0x007A, 0x0301, 0x017A),
+    (0x007A, 0x0302, 0x1E91), (0x007A, 0x0307, 0x017C), (0x007A, 0x030C, 0x017E),
+    (0x007A, 0x0323, 0x1E93), (0x007A, 0x0331, 0x1E95), (0x00A8, 0x0300, 0x1FED),
+    (0x00A8, 0x0301, 0x0385), (0x00A8, 0x0342, 0x1FC1), (0x00C2, 0x0300, 0x1EA6),
+    (0x00C2, 0x0301, 0x1EA4), (0x00C2, 0x0303, 0x1EAA), (0x00C2, 0x0309, 0x1EA8),
+    (0x00C4, 0x0304, 0x01DE), (0x00C5, 0x0301, 0x01FA), (0x00C6, 0x0301, 0x01FC),
+    (0x00C6, 0x0304, 0x01E2), (0x00C7, 0x0301, 0x1E08), (0x00CA, 0x0300, 0x1EC0),
+    (0x00CA, 0x0301, 0x1EBE), (0x00CA, 0x0303, 0x1EC4), (0x00CA, 0x0309, 0x1EC2),
+    (0x00CF, 0x0301, 0x1E2E), (0x00D4, 0x0300, 0x1ED2), (0x00D4, 0x0301, 0x1ED0),
+    (0x00D4, 0x0303, 0x1ED6), (0x00D4, 0x0309, 0x1ED4), (0x00D5, 0x0301, 0x1E4C),
+    (0x00D5, 0x0304, 0x022C), (0x00D5, 0x0308, 0x1E4E), (0x00D6, 0x0304, 0x022A),
+    (0x00D8, 0x0301, 0x01FE), (0x00DC, 0x0300, 0x01DB), (0x00DC, 0x0301, 0x01D7),
+    (0x00DC, 0x0304, 0x01D5), (0x00DC, 0x030C, 0x01D9), (0x00E2, 0x0300, 0x1EA7),
+    (0x00E2, 0x0301, 0x1EA5), (0x00E2, 0x0303, 0x1EAB), (0x00E2, 0x0309, 0x1EA9),
+    (0x00E4, 0x0304, 0x01DF), (0x00E5, 0x0301, 0x01FB), (0x00E6, 0x0301, 0x01FD),
+    (0x00E6, 0x0304, 0x01E3), (0x00E7, 0x0301, 0x1E09), (0x00EA, 0x0300, 0x1EC1),
+    (0x00EA, 0x0301, 0x1EBF), (0x00EA, 0x0303, 0x1EC5), (0x00EA, 0x0309, 0x1EC3),
+    (0x00EF, 0x0301, 0x1E2F), (0x00F4, 0x0300, 0x1ED3), (0x00F4, 0x0301, 0x1ED1),
+    (0x00F4, 0x0303, 0x1ED7), (0x00F4, 0x0309, 0x1ED5), (0x00F5, 0x0301, 0x1E4D),
+    (0x00F5, 0x0304, 0x022D), (0x00F5, 0x0308, 0x1E4F), (0x00F6, 0x0304, 0x022B),
+    (0x00F8, 0x0301, 0x01FF), (0x00FC, 0x0300, 0x01DC), (0x00FC, 0x0301, 0x01D8),
+    (0x00FC, 0x0304, 0x01D6), (0x00FC, 0x030C, 0x01DA), (0x0102, 0x0300, 0x1EB0),
+    (0x0102, 0x0301, 0x1EAE), (0x0102, 0x0303, 0x1EB4), (0x0102, 0x0309, 0x1EB2),
+    (0x0103, 0x0300, 0x1EB1), (0x0103, 0x0301, 0x1EAF), (0x0103, 0x0303, 0x1EB5),
+    (0x0103, 0x0309, 0x1EB3), (0x0112, 0x0300, 0x1E14), (0x0112, 0x0301, 0x1E16),
+    (0x0113, 0x0300, 0x1E15), (0x0113, 0x0301, 0x1E17), (0x014C, 0x0300, 0x1E50),
+    (0x014C, 0x0301, 0x1E52), (0x014D, 0x0300, 0x1E51), (0x014D, 0x0301, 0x1E53),
+    (0x015A, 0x0307, 0x1E64), (0x015B, 0x0307, 0x1E65), (0x0160, 0x0307, 0x1E66),
+    (0x0161, 0x0307, 0x1E67), (0x0168, 0x0301, 0x1E78), (0x0169, 0x0301, 0x1E79),
+    (0x016A, 0x0308, 0x1E7A), (0x016B, 0x0308, 0x1E7B), (0x017F, 0x0307, 0x1E9B),
+    (0x01A0, 0x0300, 0x1EDC), (0x01A0, 0x0301, 0x1EDA), (0x01A0, 0x0303, 0x1EE0),
+    (0x01A0, 0x0309, 0x1EDE), (0x01A0, 0x0323, 0x1EE2), (0x01A1, 0x0300, 0x1EDD),
+    (0x01A1, 0x0301, 0x1EDB), (0x01A1, 0x0303, 0x1EE1), (0x01A1, 0x0309, 0x1EDF),
+    (0x01A1, 0x0323, 0x1EE3), (0x01AF, 0x0300, 0x1EEA), (0x01AF, 0x0301, 0x1EE8),
+    (0x01AF, 0x0303, 0x1EEE), (0x01AF, 0x0309, 0x1EEC), (0x01AF, 0x0323, 0x1EF0),
+    (0x01B0, 0x0300, 0x1EEB), (0x01B0, 0x0301, 0x1EE9), (0x01B0, 0x0303, 0x1EEF),
+    (0x01B0, 0x0309, 0x1EED), (0x01B0, 0x0323, 0x1EF1), (0x01B7, 0x030C, 0x01EE),
+    (0x01EA, 0x0304, 0x01EC), (0x01EB, 0x0304, 0x01ED), (0x0226, 0x0304, 0x01E0),
+    (0x0227, 0x0304, 0x01E1), (0x0228, 0x0306, 0x1E1C), (0x0229, 0x0306, 0x1E1D),
+    (0x022E, 0x0304, 0x0230), (0x022F, 0x0304, 0x0231), (0x0292, 0x030C, 0x01EF),
+    (0x0391, 0x0300, 0x1FBA), (0x0391, 0x0301, 0x0386), (0x0391, 0x0304, 0x1FB9),
+    (0x0391, 0x0306, 0x1FB8), (0x0391, 0x0313, 0x1F08), (0x0391, 0x0314, 0x1F09),
+    (0x0391, 0x0345, 0x1FBC), (0x0395, 0x0300, 0x1FC8), (0x0395, 0x0301, 0x0388),
+    (0x0395, 0x0313, 0x1F18), (0x0395, 0x0314, 0x1F19), (0x0397, 0x0300, 0x1FCA),
+    (0x0397, 0x0301, 0x0389), (0x0397, 0x0313, 0x1F28), (0x0397, 0x0314, 0x1F29),
+    (0x0397, 0x0345, 0x1FCC), (0x0399, 0x0300, 0x1FDA), (0x0399, 0x0301, 0x038A),
+    (0x0399, 0x0304, 0x1FD9), (0x0399, 0x0306, 0x1FD8), (0x0399, 0x0308, 0x03AA),
+    (0x0399, 0x0313, 0x1F38), (0x0399, 0x0314, 0x1F39), (0x039F, 0x0300, 0x1FF8),
+    (0x039F, 0x0301, 0x038C), (0x039F, 0x0313, 0x1F48), (0x039F, 0x0314, 0x1F49),
+    (0x03A1, 0x0314, 0x1FEC), (0x03A5, 0x0300, 0x1FEA), (0x03A5, 0x0301, 0x038E),
+    (0x03A5, 0x0304, 0x1FE9), (0x03A5, 0x0306, 0x1FE8), (0x03A5, 0x0308, 0x03AB),
+    (0x03A5, 0x0314, 0x1F59), (0x03A9, 0x0300, 0x1FFA), (0x03A9, 0x0301, 0x038F),
+    (0x03A9, 0x0313, 0x1F68), (0x03A9, 0x0314, 0x1F69), (0x03A9, 0x0345, 0x1FFC),
+    (0x03AC, 0x0345, 0x1FB4), (0x03AE, 0x0345, 0x1FC4), (0x03B1, 0x0300, 0x1F70),
+    (0x03B1, 0x0301, 0x03AC), (0x03B1, 0x0304, 0x1FB1), (0x03B1, 0x0306, 0x1FB0),
+    (0x03B1, 0x0313, 0x1F00), (0x03B1, 0x0314, 0x1F01), (0x03B1, 0x0342, 0x1FB6),
+    (0x03B1, 0x0345, 0x1FB3), (0x03B5, 0x0300, 0x1F72), (0x03B5, 0x0301, 0x03AD),
+    (0x03B5, 0x0313, 0x1F10), (0x03B5, 0x0314, 0x1F11), (0x03B7, 0x0300, 0x1F74),
+    (0x03B7, 0x0301, 0x03AE), (0x03B7, 0x0313, 0x1F20), (0x03B7, 0x0314, 0x1F21),
+    (0x03B7, 0x0342, 0x1FC6), (0x03B7, 0x0345, 0x1FC3), (0x03B9, 0x0300, 0x1F76),
+    (0x03B9, 0x0301, 0x03AF), (0x03B9, 0x0304, 0x1FD1), (0x03B9, 0x0306, 0x1FD0),
+    (0x03B9, 0x0308, 0x03CA), (0x03B9, 0x0313, 0x1F30), (0x03B9, 0x0314, 0x1F31),
+    (0x03B9, 0x0342, 0x1FD6), (0x03BF, 0x0300, 0x1F78), (0x03BF, 0x0301, 0x03CC),
+    (0x03BF, 0x0313, 0x1F40), (0x03BF, 0x0314, 0x1F41), (0x03C1, 0x0313, 0x1FE4),
+    (0x03C1, 0x0314, 0x1FE5), (0x03C5, 0x0300, 0x1F7A), (0x03C5, 0x0301, 0x03CD),
+    (0x03C5, 0x0304, 0x1FE1), (0x03C5, 0x0306, 0x1FE0), (0x03C5, 0x0308, 0x03CB),
+    (0x03C5, 0x0313, 0x1F50), (0x03C5, 0x0314, 0x1F51), (0x03C5, 0x0342, 0x1FE6),
+    (0x03C9, 0x0300, 0x1F7C), (0x03C9, 0x0301, 0x03CE), (0x03C9, 0x0313, 0x1F60),
+    (0x03C9, 0x0314, 0x1F61), (0x03C9, 0x0342, 0x1FF6), (0x03C9, 0x0345, 0x1FF3),
+    (0x03CA, 0x0300, 0x1FD2), (0x03CA, 0x0301, 0x0390), (0x03CA, 0x0342, 0x1FD7),
+    (0x03CB, 0x0300, 0x1FE2), (0x03CB, 0x0301, 0x03B0), (0x03CB, 0x0342, 0x1FE7),
+    (0x03CE, 0x0345, 0x1FF4), (0x03D2, 0x0301, 0x03D3), (0x03D2, 0x0308, 0x03D4),
+    (0x0406, 0x0308, 0x0407), (0x0410, 0x0306, 0x04D0), (0x0410, 0x0308, 0x04D2),
+    (0x0413, 0x0301, 0x0403), (0x0415, 0x0300, 0x0400), (0x0415, 0x0306, 0x04D6),
+    (0x0415, 0x0308, 0x0401), (0x0416, 0x0306, 0x04C1), (0x0416, 0x0308, 0x04DC),
+    (0x0417, 0x0308, 0x04DE), (0x0418, 0x0300, 0x040D), (0x0418, 0x0304, 0x04E2),
+    (0x0418, 0x0306, 0x0419), (0x0418, 0x0308, 0x04E4), (0x041A, 0x0301, 0x040C),
+    (0x041E, 0x0308, 0x04E6), (0x0423, 0x0304, 0x04EE), (0x0423, 0x0306, 0x040E),
+    (0x0423, 0x0308, 0x04F0), (0x0423, 0x030B, 0x04F2), (0x0427, 0x0308, 0x04F4),
+    (0x042B, 0x0308, 0x04F8), (0x042D, 0x0308, 0x04EC), (0x0430, 0x0306, 0x04D1),
+    (0x0430, 0x0308, 0x04D3), (0x0433, 0x0301, 0x0453), (0x0435, 0x0300, 0x0450),
+    (0x0435, 0x0306, 0x04D7), (0x0435, 0x0308, 0x0451), (0x0436, 0x0306, 0x04C2),
+    (0x0436, 0x0308, 0x04DD), (0x0437, 0x0308, 0x04DF), (0x0438, 0x0300, 0x045D),
+    (0x0438, 0x0304, 0x04E3), (0x0438, 0x0306, 0x0439), (0x0438, 0x0308, 0x04E5),
+    (0x043A, 0x0301, 0x045C), (0x043E, 0x0308, 0x04E7), (0x0443, 0x0304, 0x04EF),
+    (0x0443, 0x0306, 0x045E), (0x0443, 0x0308, 0x04F1), (0x0443, 0x030B, 0x04F3),
+    (0x0447, 0x0308, 0x04F5), (0x044B, 0x0308, 0x04F9), (0x044D, 0x0308, 0x04ED),
+    (0x0456, 0x0308, 0x0457), (0x0474, 0x030F, 0x0476), (0x0475, 0x030F, 0x0477),
+    (0x04D8, 0x0308, 0x04DA), (0x04D9, 0x0308, 0x04DB), (0x04E8, 0x0308, 0x04EA),
+    (0x04E9, 0x0308, 0x04EB), (0x0627, 0x0653, 0x0622), (0x0627, 0x0654, 0x0623),
+    (0x0627, 0x0655, 0x0625), (0x0648, 0x0654, 0x0624), (0x064A, 0x0654, 0x0626),
+    (0x06C1, 0x0654, 0x06C2), (0x06D2, 0x0654, 0x06D3), (0x06D5, 0x0654, 0x06C0),
+    (0x0928, 0x093C, 0x0929), (0x0930, 0x093C, 0x0931), (0x0933, 0x093C, 0x0934),
+    (0x09C7, 0x09BE, 0x09CB), (0x09C7, 0x09D7, 0x09CC), (0x0B47, 0x0B3E, 0x0B4B),
+    (0x0B47, 0x0B56, 0x0B48), (0x0B47, 0x0B57, 0x0B4C), (0x0B92, 0x0BD7, 0x0B94),
+    (0x0BC6, 0x0BBE, 0x0BCA), (0x0BC6, 0x0BD7, 0x0BCC), (0x0BC7, 0x0BBE, 0x0BCB),
+    (0x0C46, 0x0C56, 0x0C48), (0x0CBF, 0x0CD5, 0x0CC0), (0x0CC6, 0x0CC2, 0x0CCA),
+    (0x0CC6, 0x0CD5, 0x0CC7), (0x0CC6, 0x0CD6, 0x0CC8), (0x0CCA, 0x0CD5, 0x0CCB),
+    (0x0D46, 0x0D3E, 0x0D4A), (0x0D46, 0x0D57, 0x0D4C), (0x0D47, 0x0D3E, 0x0D4B),
+    (0x0DD9, 0x0DCA, 0x0DDA), (0x0DD9, 0x0DCF, 0x0DDC), (0x0DD9, 0x0DDF, 0x0DDE),
+    (0x0DDC, 0x0DCA, 0x0DDD), (0x1025, 0x102E, 0x1026), (0x1B05, 0x1B35, 0x1B06),
+    (0x1B07, 0x1B35, 0x1B08), (0x1B09, 0x1B35, 0x1B0A), (0x1B0B, 0x1B35, 0x1B0C),
+    (0x1B0D, 0x1B35, 0x1B0E), (0x1B11, 0x1B35, 0x1B12), (0x1B3A, 0x1B35, 0x1B3B),
+    (0x1B3C, 0x1B35, 0x1B3D), (0x1B3E, 0x1B35, 0x1B40), (0x1B3F, 0x1B35, 0x1B41),
+    (0x1B42, 0x1B35, 0x1B43), (0x1E36, 0x0304, 0x1E38), (0x1E37, 0x0304, 0x1E39),
+    (0x1E5A, 0x0304, 0x1E5C), (0x1E5B, 0x0304, 0x1E5D), (0x1E62, 0x0307, 0x1E68),
+    (0x1E63, 0x0307, 0x1E69), (0x1EA0, 0x0302, 0x1EAC), (0x1EA0, 0x0306, 0x1EB6),
+    (0x1EA1, 0x0302, 0x1EAD), (0x1EA1, 0x0306, 0x1EB7), (0x1EB8, 0x0302, 0x1EC6),
+    (0x1EB9, 0x0302, 0x1EC7), (0x1ECC, 0x0302, 0x1ED8), (0x1ECD, 0x0302, 0x1ED9),
+    (0x1F00, 0x0300, 0x1F02), (0x1F00, 0x0301, 0x1F04), (0x1F00, 0x0342, 0x1F06),
+    (0x1F00, 0x0345, 0x1F80), (0x1F01, 0x0300, 0x1F03), (0x1F01, 0x0301, 0x1F05),
+    (0x1F01, 0x0342, 0x1F07), (0x1F01, 0x0345, 0x1F81), (0x1F02, 0x0345, 0x1F82),
+    (0x1F03, 0x0345, 0x1F83), (0x1F04, 0x0345, 0x1F84), (0x1F05, 0x0345, 0x1F85),
+    (0x1F06, 0x0345, 0x1F86), (0x1F07, 0x0345, 0x1F87), (0x1F08, 0x0300, 0x1F0A),
+    (0x1F08, 0x0301, 0x1F0C), (0x1F08, 0x0342, 0x1F0E), (0x1F08, 0x0345, 0x1F88),
+    (0x1F09, 0x0300, 0x1F0B), (0x1F09, 0x0301, 0x1F0D), (0x1F09, 0x0342, 0x1F0F),
+    (0x1F09, 0x0345, 0x1F89), (0x1F0A, 0x0345, 0x1F8A), (0x1F0B, 0x0345, 0x1F8B),
+    (0x1F0C, 0x0345, 0x1F8C), (0x1F0D, 0x0345, 0x1F8D), (0x1F0E, 0x0345, 0x1F8E),
+    (0x1F0F, 0x0345, 0x1F8F), (0x1F10, 0x0300, 0x1F12), (0x1F10, 0x0301, 0x1F14),
+    (0x1F11, 0x0300, 0x1F13), (0x1F11, 0x0301, 0x1F15), (0x1F18, 0x0300, 0x1F1A),
+    (0x1F18, 0x0301, 0x1F1C), (0x1F19, 0x0300, 0x1F1B), (0x1F19, 0x0301, 0x1F1D),
+    (0x1F20, 0x0300, 0x1F22), (0x1F20, 0x0301, 0x1F24), (0x1F20, 0x0342, 0x1F26),
+    (0x1F20, 0x0345, 0x1F90), (0x1F21, 0x0300, 0x1F23), (0x1F21, 0x0301, 0x1F25),
+    (0x1F21, 0x0342, 0x1F27), (0x1F21, 0x0345, 0x1F91), (0x1F22, 0x0345, 0x1F92),
+    (0x1F23, 0x0345, 0x1F93), (0x1F24, 0x0345, 0x1F94), (0x1F25, 0x0345, 0x1F95),
+    (0x1F26, 0x0345, 0x1F96), (0x1F27, 0x0345, 0x1F97), (0x1F28, 0x0300, 0x1F2A),
+    (0x1F28, 0x0301, 0x1F2C), (0x1F28, 0x0342, 0x1F2E), (0x1F28, 0x0345, 0x1F98),
+    (0x1F29, 0x0300, 0x1F2B), (0x1F29, 0x0301, 0x1F2D), (0x1F29, 0x0342, 0x1F2F),
+    (0x1F29, 0x0345, 0x1F99), (0x1F2A, 0x0345, 0x1F9A), (0x1F2B, 0x0345, 0x1F9B),
+    (0x1F2C, 0x0345, 0x1F9C), (0x1F2D, 0x0345, 0x1F9D), (0x1F2E, 0x0345, 0x1F9E),
+    (0x1F2F, 0x0345, 0x1F9F), (0x1F30, 0x0300, 0x1F32), (0x1F30, 0x0301, 0x1F34),
+    (0x1F30, 0x0342, 0x1F36), (0x1F31, 0x0300, 0x1F33), (0x1F31, 0x0301, 0x1F35),
+    (0x1F31, 0x0342, 0x1F37), (0x1F38, 0x0300, 0x1F3A), (0x1F38, 0x0301, 0x1F3C),
+    (0x1F38, 0x0342, 0x1F3E), (0x1F39, 0x0300, 0x1F3B), (0x1F39, 0x0301, 0x1F3D),
+    (0x1F39, 0x0342, 0x1F3F), (0x1F40, 0x0300, 0x1F42), (0x1F40, 0x0301, 0x1F44),
+    (0x1F41, 0x0300, 0x1F43), (0x1F41, 0x0301, 0x1F45), (0x1F48, 0x0300, 0x1F4A),
+    (0x1F48, 0x0301, 0x1F4C), (0x1F49, 0x0300, 0x1F4B), (0x1F49, 0x0301, 0x1F4D),
+    (0x1F50, 0x0300, 0x1F52), (0x1F50, 0x0301, 0x1F54), (0x1F50, 0x0342, 0x1F56),
+    (0x1F51, 0x0300, 0x1F53), (0x1F51, 0x0301, 0x1F55), (0x1F51, 0x0342, 0x1F57),
+    (0x1F59, 0x0300, 0x1F5B), (0x1F59, 0x0301, 0x1F5D), (0x1F59, 0x0342, 0x1F5F),
+    (0x1F60, 0x0300, 0x1F62), (0x1F60, 0x0301, 0x1F64), (0x1F60, 0x0342, 0x1F66),
+    (0x1F60, 0x0345, 0x1FA0), (0x1F61, 0x0300, 0x1F63), (0x1F61, 0x0301, 0x1F65),
+    (0x1F61, 0x0342, 0x1F67), (0x1F61, 0x0345, 0x1FA1), (0x1F62, 0x0345, 0x1FA2),
+    (0x1F63, 0x0345, 0x1FA3), (0x1F64, 0x0345, 0x1FA4), (0x1F65, 0x0345, 0x1FA5),
+    (0x1F66, 0x0345, 0x1FA6), (0x1F67, 0x0345, 0x1FA7), (0x1F68, 0x0300, 0x1F6A),
+    (0x1F68, 0x0301, 0x1F6C), (0x1F68, 0x0342, 0x1F6E), (0x1F68, 0x0345, 0x1FA8),
+    (0x1F69, 0x0300, 0x1F6B), (0x1F69, 0x0301, 0x1F6D), (0x1F69, 0x0342, 0x1F6F),
+    (0x1F69, 0x0345, 0x1FA9), (0x1F6A, 0x0345, 0x1FAA), (0x1F6B, 0x0345, 0x1FAB),
+    (0x1F6C, 0x0345, 0x1FAC), (0x1F6D, 0x0345, 0x1FAD), (0x1F6E, 0x0345, 0x1FAE),
+    (0x1F6F, 0x0345, 0x1FAF), (0x1F70, 0x0345, 0x1FB2), (0x1F74, 0x0345, 0x1FC2),
+    (0x1F7C, 0x0345, 0x1FF2), (0x1FB6, 0x0345, 0x1FB7), (0x1FBF, 0x0300, 0x1FCD),
+    (0x1FBF, 0x0301, 0x1FCE), (0x1FBF, 0x0342, 0x1FCF), (0x1FC6, 0x0345, 0x1FC7),
+    (0x1FF6, 0x0345, 0x1FF7), (0x1FFE, 0x0300, 0x1FDD), (0x1FFE, 0x0301, 0x1FDE),
+    (0x1FFE, 0x0342, 0x1FDF), (0x2190, 0x0338, 0x219A), (0x2192, 0x0338, 0x219B),
+    (0x2194, 0x0338, 0x21AE), (0x21D0, 0x0338, 0x21CD), (0x21D2, 0x0338, 0x21CF),
+    (0x21D4, 0x0338, 0x21CE), (0x2203, 0x0338, 0x2204), (0x2208, 0x0338, 0x2209),
+    (0x220B, 0x0338, 0x220C), (0x2223, 0x0338, 0x2224), (0x2225, 0x0338, 0x2226),
+    (0x223C, 0x0338, 0x2241), (0x2243, 0x0338, 0x2244), (0x2245, 0x0338, 0x2247),
+    (0x2248, 0x0338, 0x2249), (0x224D, 0x0338, 0x226D), (0x2261, 0x0338, 0x2262),
+    (0x2264, 0x0338, 0x2270), (0x2265, 0x0338, 0x2271), (0x2272, 0x0338, 0x2274),
+    (0x2273, 0x0338, 0x2275), (0x2276, 0x0338, 0x2278), (0x2277, 0x0338, 0x2279),
+    (0x227A, 0x0338, 0x2280), (0x227B, 0x0338, 0x2281), (0x227C, 0x0338, 0x22E0),
+    (0x227D, 0x0338, 0x22E1), (0x2282, 0x0338, 0x2284), (0x2283, 0x0338, 0x2285),
+    (0x2286, 0x0338, 0x2288), (0x2287, 0x0338, 0x2289), (0x2291, 0x0338, 0x22E2),
+    (0x2292, 0x0338, 0x22E3), (0x22A2, 0x0338, 0x22AC), (0x22A8, 0x0338, 0x22AD),
+    (0x22A9, 0x0338, 0x22AE), (0x22AB, 0x0338, 0x22AF), (0x22B2, 0x0338, 0x22EA),
+    (0x22B3, 0x0338, 0x22EB), (0x22B4, 0x0338, 0x22EC), (0x22B5, 0x0338, 0x22ED),
+    (0x3046, 0x3099, 0x3094), (0x304B, 0x3099, 0x304C), (0x304D, 0x3099, 0x304E),
+    (0x304F, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052), (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058), (0x3059, 0x3099, 0x305A),
+    (0x305B, 0x3099, 0x305C), (0x305D, 0x3099, 0x305E), (0x305F, 0x3099, 0x3060),
+    (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065), (0x3066, 0x3099, 0x3067),
+    (0x3068, 0x3099, 0x3069), (0x306F, 0x3099, 0x3070), (0x306F, 0x309A, 0x3071),
+    (0x3072, 0x3099, 0x3073), (0x3072, 0x309A, 0x3074), (0x3075, 0x3099, 0x3076),
+    (0x3075, 0x309A, 0x3077), (0x3078, 0x3099, 0x3079), (0x3078, 0x309A, 0x307A),
+    (0x307B, 0x3099, 0x307C), (0x307B, 0x309A, 0x307D), (0x309D, 0x3099, 0x309E),
+    (0x30A6, 0x3099, 0x30F4), (0x30AB, 0x3099, 0x30AC), (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0), (0x30B1, 0x3099, 0x30B2), (0x30B3, 0x3099, 0x30B4),
+    (0x30B5, 0x3099, 0x30B6), (0x30B7, 0x3099, 0x30B8), (0x30B9, 0x3099, 0x30BA),
+    (0x30BB, 0x3099, 0x30BC), (0x30BD, 0x3099, 0x30BE), (0x30BF, 0x3099, 0x30C0),
+    (0x30C1, 0x3099, 0x30C2), (0x30C4, 0x3099, 0x30C5), (0x30C6, 0x3099, 0x30C7),
+    (0x30C8, 0x3099, 0x30C9), (0x30CF, 0x3099, 0x30D0), (0x30CF, 0x309A, 0x30D1),
+    (0x30D2, 0x3099, 0x30D3), (0x30D2, 0x309A, 0x30D4), (0x30D5, 0x3099, 0x30D6),
+    (0x30D5, 0x309A, 0x30D7), (0x30D8, 0x3099, 0x30D9), (0x30D8, 0x309A, 0x30DA),
+    (0x30DB, 0x3099, 0x30DC), (0x30DB, 0x309A, 0x30DD), (0x30EF, 0x3099, 0x30F7),
+    (0x30F0, 0x3099, 0x30F8), (0x30F1, 0x3099, 0x30F9), (0x30F2, 0x3099, 0x30FA),
+    (0x30FD, 0x3099, 0x30FE), (0x11099, 0x110BA, 0x1109A), (0x1109B, 0x110BA, 0x1109C),
+    (0x110A5, 0x110BA, 0x110AB), (0x11131, 0x11127, 0x1112E), (0x11132, 0x11127, 0x1112F),
+    (0x11347, 0x1133E, 0x1134B), (0x11347, 0x11357, 0x1134C), (0x114B9, 0x114B0, 0x114BC),
+    (0x114B9, 0x114BA, 0x114BB), (0x114B9, 0x114BD, 0x114BE), (0x115B8, 0x115AF, 0x115BA),
+    (0x115B9, 0x115AF, 0x115BB), (0x11935, 0x11930, 0x11938),
+];
+
+/// Bidi classes: `(first, last, class)`.
+#[rustfmt::skip]
+pub(super) static BIDI_CLASS: [(u32, u32, BidiClass); 1318] = [
+    (0x0000, 0x0008, BidiClass::BN), (0x0009, 0x000D, BidiClass::Other),
+    (0x000E, 0x001B, BidiClass::BN), (0x001C, 0x0020, BidiClass::Other),
+    (0x0021, 0x0022, BidiClass::ON), (0x0023, 0x0025, BidiClass::ET),
+    (0x0026, 0x002A, BidiClass::ON), (0x002B, 0x002B, BidiClass::ES),
+    (0x002C, 0x002C, BidiClass::CS), (0x002D, 0x002D, BidiClass::ES),
+    (0x002E, 0x002F, BidiClass::CS), (0x0030, 0x0039, BidiClass::EN),
+    (0x003A, 0x003A, BidiClass::CS), (0x003B, 0x0040, BidiClass::ON),
+    (0x0041, 0x005A, BidiClass::L), (0x005B, 0x0060, BidiClass::ON), (0x0061, 0x007A, BidiClass::L),
+    (0x007B, 0x007E, BidiClass::ON), (0x007F, 0x0084, BidiClass::BN),
+    (0x0085, 0x0085, BidiClass::Other), (0x0086, 0x009F, BidiClass::BN),
+    (0x00A0, 0x00A0, BidiClass::CS), (0x00A1, 0x00A1, BidiClass::ON),
+    (0x00A2, 0x00A5, BidiClass::ET), (0x00A6, 0x00A9, BidiClass::ON),
+    (0x00AA, 0x00AA, BidiClass::L), (0x00AB, 0x00AC, BidiClass::ON),
+    (0x00AD, 0x00AD, BidiClass::BN), (0x00AE, 0x00AF, BidiClass::ON),
+    (0x00B0, 0x00B1, BidiClass::ET), (0x00B2, 0x00B3, BidiClass::EN),
+    (0x00B4, 0x00B4, BidiClass::ON), (0x00B5, 0x00B5, BidiClass::L),
+    (0x00B6, 0x00B8, BidiClass::ON), (0x00B9, 0x00B9, BidiClass::EN),
+    (0x00BA, 0x00BA, BidiClass::L), (0x00BB, 0x00BF, BidiClass::ON), (0x00C0, 0x00D6, BidiClass::L),
+    (0x00D7, 0x00D7, BidiClass::ON), (0x00D8, 0x00F6, BidiClass::L),
+    (0x00F7, 0x00F7, BidiClass::ON), (0x00F8, 0x02B8, BidiClass::L),
+    (0x02B9, 0x02BA, BidiClass::ON), (0x02BB, 0x02C1, BidiClass::L),
+    (0x02C2, 0x02CF, BidiClass::ON), (0x02D0, 0x02D1, BidiClass::L),
+    (0x02D2, 0x02DF, BidiClass::ON), (0x02E0, 0x02E4, BidiClass::L),
+    (0x02E5, 0x02ED, BidiClass::ON), (0x02EE, 0x02EE, BidiClass::L),
+    (0x02EF, 0x02FF, BidiClass::ON), (0x0300, 0x036F, BidiClass::NSM),
+    (0x0370, 0x0373, BidiClass::L), (0x0374, 0x0375, BidiClass::ON), (0x0376, 0x037D, BidiClass::L),
+    (0x037E, 0x037E, BidiClass::ON), (0x037F, 0x0383, BidiClass::L),
+    (0x0384, 0x0385, BidiClass::ON), (0x0386, 0x0386, BidiClass::L),
+    (0x0387, 0x0387, BidiClass::ON), (0x0388, 0x03F5, BidiClass::L),
+    (0x03F6, 0x03F6, BidiClass::ON), (0x03F7, 0x0482, BidiClass::L),
+    (0x0483, 0x0489, BidiClass::NSM), (0x048A, 0x0589, BidiClass::L),
+    (0x058A, 0x058A, BidiClass::ON), (0x058B, 0x058C, BidiClass::L),
+    (0x058D, 0x058E, BidiClass::ON), (0x058F, 0x058F, BidiClass::ET),
+    (0x0590, 0x0590, BidiClass::L), (0x0591, 0x05BD, BidiClass::NSM),
+    (0x05BE, 0x05BE, BidiClass::R), (0x05BF, 0x05BF, BidiClass::NSM),
+    (0x05C0, 0x05C0, BidiClass::R), (0x05C1, 0x05C2, BidiClass::NSM),
+    (0x05C3, 0x05C3, BidiClass::R), (0x05C4, 0x05C5, BidiClass::NSM),
+    (0x05C6, 0x05C6, BidiClass::R), (0x05C7, 0x05C7, BidiClass::NSM),
+    (0x05C8, 0x05CF, BidiClass::L), (0x05D0, 0x05EA, BidiClass::R), (0x05EB, 0x05EE, BidiClass::L),
+    (0x05EF, 0x05F4, BidiClass::R), (0x05F5, 0x05FF, BidiClass::L), (0x0600, 0x0605, BidiClass::AN),
+    (0x0606, 0x0607, BidiClass::ON), (0x0608, 0x0608, BidiClass::AL),
+    (0x0609, 0x060A, BidiClass::ET), (0x060B, 0x060B, BidiClass::AL),
+    (0x060C, 0x060C, BidiClass::CS), (0x060D, 0x060D, BidiClass::AL),
+    (0x060E, 0x060F, BidiClass::ON), (0x0610, 0x061A, BidiClass::NSM),
+    (0x061B, 0x064A, BidiClass::AL), (0x064B, 0x065F, BidiClass::NSM),
+    (0x0660, 0x0669, BidiClass::AN), (0x066A, 0x066A, BidiClass::ET),
+    (0x066B, 0x066C, BidiClass::AN), (0x066D, 0x066F, BidiClass::AL),
+    (0x0670, 0x0670, BidiClass::NSM), (0x0671, 0x06D5, BidiClass::AL),
+    (0x06D6, 0x06DC, BidiClass::NSM), (0x06DD, 0x06DD, BidiClass::AN),
+    (0x06DE, 0x06DE, BidiClass::ON), (0x06DF, 0x06E4, BidiClass::NSM),
+    (0x06E5, 0x06E6, BidiClass::AL), (0x06E7, 0x06E8, BidiClass::NSM),
+    (0x06E9, 0x06E9, BidiClass::ON), (0x06EA, 0x06ED, BidiClass::NSM),
+    (0x06EE, 0x06EF, BidiClass::AL), (0x06F0, 0x06F9, BidiClass::EN),
+    (0x06FA, 0x070D, BidiClass::AL), (0x070E, 0x070E, BidiClass::L),
+    (0x070F, 0x0710, BidiClass::AL), (0x0711, 0x0711, BidiClass::NSM),
+    (0x0712, 0x072F, BidiClass::AL), (0x0730, 0x074A, BidiClass::NSM),
+    (0x074B, 0x074C, BidiClass::L), (0x074D, 0x07A5, BidiClass::AL),
+    (0x07A6, 0x07B0, BidiClass::NSM), (0x07B1, 0x07B1, BidiClass::AL),
+    (0x07B2, 0x07BF, BidiClass::L), (0x07C0, 0x07EA, BidiClass::R),
+    (0x07EB, 0x07F3, BidiClass::NSM), (0x07F4, 0x07F5, BidiClass::R),
+    (0x07F6, 0x07F9, BidiClass::ON), (0x07FA, 0x07FA, BidiClass::R), (0x07FB, 0x07FC, BidiClass::L),
+    (0x07FD, 0x07FD, BidiClass::NSM), (0x07FE, 0x0815, BidiClass::R),
+    (0x0816, 0x0819, BidiClass::NSM), (0x081A, 0x081A, BidiClass::R),
+    (0x081B, 0x0823, BidiClass::NSM), (0x0824, 0x0824, BidiClass::R),
+    (0x0825, 0x0827, BidiClass::NSM), (0x0828, 0x0828, BidiClass::R),
+    (0x0829, 0x082D, BidiClass::NSM), (0x082E, 0x082F, BidiClass::L),
+    (0x0830, 0x083E, BidiClass::R), (0x083F, 0x083F, BidiClass::L), (0x0840, 0x0858, BidiClass::R),
+    (0x0859, 0x085B, BidiClass::NSM), (0x085C, 0x085D, BidiClass::L),
+    (0x085E, 0x085E, BidiClass::R), (0x085F, 0x085F, BidiClass::L), (0x0860, 0x086A, BidiClass::AL),
+    (0x086B, 0x086F, BidiClass::L), (0x0870, 0x088E, BidiClass::AL), (0x088F, 0x088F, BidiClass::L),
+    (0x0890, 0x0891, BidiClass::AN), (0x0892, 0x0897, BidiClass::L),
+    (0x0898, 0x089F, BidiClass::NSM), (0x08A0, 0x08C9, BidiClass::AL),
+    (0x08CA, 0x08E1, BidiClass::NSM), (0x08E2, 0x08E2, BidiClass::AN),
+    (0x08E3, 0x0902, BidiClass::NSM), (0x0903, 0x0939, BidiClass::L),
+    (0x093A, 0x093A, BidiClass::NSM), (0x093B, 0x093B, BidiClass::L),
+    (0x093C, 0x093C, BidiClass::NSM), (0x093D, 0x0940, BidiClass::L),
+    (0x0941, 0x0948, BidiClass::NSM), (0x0949, 0x094C, BidiClass::L),
+    (0x094D, 0x094D, BidiClass::NSM), (0x094E, 0x0950, BidiClass::L),
+    (0x0951, 0x0957, BidiClass::NSM), (0x0958, 0x0961, BidiClass::L),
+    (0x0962, 0x0963, BidiClass::NSM), (0x0964, 0x0980, BidiClass::L),
+    (0x0981, 0x0981, BidiClass::NSM), (0x0982, 0x09BB, BidiClass::L),
+    (0x09BC, 0x09BC, BidiClass::NSM), (0x09BD, 0x09C0, BidiClass::L),
+    (0x09C1, 0x09C4, BidiClass::NSM), (0x09C5, 0x09CC, BidiClass::L),
+    (0x09CD, 0x09CD, BidiClass::NSM), (0x09CE, 0x09E1, BidiClass::L),
+    (0x09E2, 0x09E3, BidiClass::NSM), (0x09E4, 0x09F1, BidiClass::L),
+    (0x09F2, 0x09F3, BidiClass::ET), (0x09F4, 0x09FA, BidiClass::L),
+    (0x09FB, 0x09FB, BidiClass::ET), (0x09FC, 0x09FD, BidiClass::L),
+    (0x09FE, 0x09FE, BidiClass::NSM), (0x09FF, 0x0A00, BidiClass::L),
+    (0x0A01, 0x0A02, BidiClass::NSM), (0x0A03, 0x0A3B, BidiClass::L),
+    (0x0A3C, 0x0A3C, BidiClass::NSM), (0x0A3D, 0x0A40, BidiClass::L),
+    (0x0A41, 0x0A42, BidiClass::NSM), (0x0A43, 0x0A46, BidiClass::L),
+    (0x0A47, 0x0A48, BidiClass::NSM), (0x0A49, 0x0A4A, BidiClass::L),
+    (0x0A4B, 0x0A4D, BidiClass::NSM), (0x0A4E, 0x0A50, BidiClass::L),
+    (0x0A51, 0x0A51, BidiClass::NSM), (0x0A52, 0x0A6F, BidiClass::L),
+    (0x0A70, 0x0A71, BidiClass::NSM), (0x0A72, 0x0A74, BidiClass::L),
+    (0x0A75, 0x0A75, BidiClass::NSM), (0x0A76, 0x0A80, BidiClass::L),
+    (0x0A81, 0x0A82, BidiClass::NSM), (0x0A83, 0x0ABB, BidiClass::L),
+    (0x0ABC, 0x0ABC, BidiClass::NSM), (0x0ABD, 0x0AC0, BidiClass::L),
+    (0x0AC1, 0x0AC5, BidiClass::NSM), (0x0AC6, 0x0AC6, BidiClass::L),
+    (0x0AC7, 0x0AC8, BidiClass::NSM), (0x0AC9, 0x0ACC, BidiClass::L),
+    (0x0ACD, 0x0ACD, BidiClass::NSM), (0x0ACE, 0x0AE1, BidiClass::L),
+    (0x0AE2, 0x0AE3, BidiClass::NSM), (0x0AE4, 0x0AF0, BidiClass::L),
+    (0x0AF1, 0x0AF1, BidiClass::ET), (0x0AF2, 0x0AF9, BidiClass::L),
+    (0x0AFA, 0x0AFF, BidiClass::NSM), (0x0B00, 0x0B00, BidiClass::L),
+    (0x0B01, 0x0B01, BidiClass::NSM), (0x0B02, 0x0B3B, BidiClass::L),
+    (0x0B3C, 0x0B3C, BidiClass::NSM), (0x0B3D, 0x0B3E, BidiClass::L),
+    (0x0B3F, 0x0B3F, BidiClass::NSM), (0x0B40, 0x0B40, BidiClass::L),
+    (0x0B41, 0x0B44, BidiClass::NSM), (0x0B45, 0x0B4C, BidiClass::L),
+    (0x0B4D, 0x0B4D, BidiClass::NSM), (0x0B4E, 0x0B54, BidiClass::L),
+    (0x0B55, 0x0B56, BidiClass::NSM), (0x0B57, 0x0B61, BidiClass::L),
+    (0x0B62, 0x0B63, BidiClass::NSM), (0x0B64, 0x0B81, BidiClass::L),
+    (0x0B82, 0x0B82, BidiClass::NSM), (0x0B83, 0x0BBF, BidiClass::L),
+    (0x0BC0, 0x0BC0, BidiClass::NSM), (0x0BC1, 0x0BCC, BidiClass::L),
+    (0x0BCD, 0x0BCD, BidiClass::NSM), (0x0BCE, 0x0BF2, BidiClass::L),
+    (0x0BF3, 0x0BF8, BidiClass::ON), (0x0BF9, 0x0BF9, BidiClass::ET),
+    (0x0BFA, 0x0BFA, BidiClass::ON), (0x0BFB, 0x0BFF, BidiClass::L),
+    (0x0C00, 0x0C00, BidiClass::NSM), (0x0C01, 0x0C03, BidiClass::L),
+    (0x0C04, 0x0C04, BidiClass::NSM), (0x0C05, 0x0C3B, BidiClass::L),
+    (0x0C3C, 0x0C3C, BidiClass::NSM), (0x0C3D, 0x0C3D, BidiClass::L),
+    (0x0C3E, 0x0C40, BidiClass::NSM), (0x0C41, 0x0C45, BidiClass::L),
+    (0x0C46, 0x0C48, BidiClass::NSM), (0x0C49, 0x0C49, BidiClass::L),
+    (0x0C4A, 0x0C4D, BidiClass::NSM), (0x0C4E, 0x0C54, BidiClass::L),
+    (0x0C55, 0x0C56, BidiClass::NSM), (0x0C57, 0x0C61, BidiClass::L),
+    (0x0C62, 0x0C63, BidiClass::NSM), (0x0C64, 0x0C77, BidiClass::L),
+    (0x0C78, 0x0C7E, BidiClass::ON), (0x0C7F, 0x0C80, BidiClass::L),
+    (0x0C81, 0x0C81, BidiClass::NSM), (0x0C82, 0x0CBB, BidiClass::L),
+    (0x0CBC, 0x0CBC, BidiClass::NSM), (0x0CBD, 0x0CCB, BidiClass::L),
+    (0x0CCC, 0x0CCD, BidiClass::NSM), (0x0CCE, 0x0CE1, BidiClass::L),
+    (0x0CE2, 0x0CE3, BidiClass::NSM), (0x0CE4, 0x0CFF, BidiClass::L),
+    (0x0D00, 0x0D01, BidiClass::NSM), (0x0D02, 0x0D3A, BidiClass::L),
+    (0x0D3B, 0x0D3C, BidiClass::NSM), (0x0D3D, 0x0D40, BidiClass::L),
+    (0x0D41, 0x0D44, BidiClass::NSM), (0x0D45, 0x0D4C, BidiClass::L),
+    (0x0D4D, 0x0D4D, BidiClass::NSM), (0x0D4E, 0x0D61, BidiClass::L),
+    (0x0D62, 0x0D63, BidiClass::NSM), (0x0D64, 0x0D80, BidiClass::L),
+    (0x0D81, 0x0D81, BidiClass::NSM), (0x0D82, 0x0DC9, BidiClass::L),
+    (0x0DCA, 0x0DCA, BidiClass::NSM), (0x0DCB, 0x0DD1, BidiClass::L),
+    (0x0DD2, 0x0DD4, BidiClass::NSM), (0x0DD5, 0x0DD5, BidiClass::L),
+    (0x0DD6, 0x0DD6, BidiClass::NSM), (0x0DD7, 0x0E30, BidiClass::L),
+    (0x0E31, 0x0E31, BidiClass::NSM), (0x0E32, 0x0E33, BidiClass::L),
+    (0x0E34, 0x0E3A, BidiClass::NSM), (0x0E3B, 0x0E3E, BidiClass::L),
+    (0x0E3F, 0x0E3F, BidiClass::ET), (0x0E40, 0x0E46, BidiClass::L),
+    (0x0E47, 0x0E4E, BidiClass::NSM), (0x0E4F, 0x0EB0, BidiClass::L),
+    (0x0EB1, 0x0EB1, BidiClass::NSM), (0x0EB2, 0x0EB3, BidiClass::L),
+    (0x0EB4, 0x0EBC, BidiClass::NSM), (0x0EBD, 0x0EC7, BidiClass::L),
+    (0x0EC8, 0x0ECD, BidiClass::NSM), (0x0ECE, 0x0F17, BidiClass::L),
+    (0x0F18, 0x0F19, BidiClass::NSM), (0x0F1A, 0x0F34, BidiClass::L),
+    (0x0F35, 0x0F35, BidiClass::NSM), (0x0F36, 0x0F36, BidiClass::L),
+    (0x0F37, 0x0F37, BidiClass::NSM), (0x0F38, 0x0F38, BidiClass::L),
+    (0x0F39, 0x0F39, BidiClass::NSM), (0x0F3A, 0x0F3D, BidiClass::ON),
+    (0x0F3E, 0x0F70, BidiClass::L), (0x0F71, 0x0F7E, BidiClass::NSM),
+    (0x0F7F, 0x0F7F, BidiClass::L), (0x0F80, 0x0F84, BidiClass::NSM),
+    (0x0F85, 0x0F85, BidiClass::L), (0x0F86, 0x0F87, BidiClass::NSM),
+    (0x0F88, 0x0F8C, BidiClass::L), (0x0F8D, 0x0F97, BidiClass::NSM),
+    (0x0F98, 0x0F98, BidiClass::L), (0x0F99, 0x0FBC, BidiClass::NSM),
+    (0x0FBD, 0x0FC5, BidiClass::L), (0x0FC6, 0x0FC6, BidiClass::NSM),
+    (0x0FC7, 0x102C, BidiClass::L), (0x102D, 0x1030, BidiClass::NSM),
+    (0x1031, 0x1031, BidiClass::L), (0x1032, 0x1037, BidiClass::NSM),
+    (0x1038, 0x1038, BidiClass::L), (0x1039, 0x103A, BidiClass::NSM),
+    (0x103B, 0x103C, BidiClass::L), (0x103D, 0x103E, BidiClass::NSM),
+    (0x103F, 0x1057, BidiClass::L), (0x1058, 0x1059, BidiClass::NSM),
+    (0x105A, 0x105D, BidiClass::L), (0x105E, 0x1060, BidiClass::NSM),
+    (0x1061, 0x1070, BidiClass::L), (0x1071, 0x1074, BidiClass::NSM),
+    (0x1075, 0x1081, BidiClass::L), (0x1082, 0x1082, BidiClass::NSM),
+    (0x1083, 0x1084, BidiClass::L), (0x1085, 0x1086, BidiClass::NSM),
+    (0x1087, 0x108C, BidiClass::L), (0x108D, 0x108D, BidiClass::NSM),
+    (0x108E, 0x109C, BidiClass::L), (0x109D, 0x109D, BidiClass::NSM),
+    (0x109E, 0x135C, BidiClass::L), (0x135D, 0x135F, BidiClass::NSM),
+    (0x1360, 0x138F, BidiClass::L), (0x1390, 0x1399, BidiClass::ON), (0x139A, 0x13FF, BidiClass::L),
+    (0x1400, 0x1400, BidiClass::ON), (0x1401, 0x167F, BidiClass::L),
+    (0x1680, 0x1680, BidiClass::Other), (0x1681, 0x169A, BidiClass::L),
+    (0x169B, 0x169C, BidiClass::ON), (0x169D, 0x1711, BidiClass::L),
+    (0x1712, 0x1714, BidiClass::NSM), (0x1715, 0x1731, BidiClass::L),
+    (0x1732, 0x1733, BidiClass::NSM), (0x1734, 0x1751, BidiClass::L),
+    (0x1752, 0x1753, BidiClass::NSM), (0x1754, 0x1771, BidiClass::L),
+    (0x1772, 0x1773, BidiClass::NSM), (0x1774, 0x17B3, BidiClass::L),
+    (0x17B4, 0x17B5, BidiClass::NSM), (0x17B6, 0x17B6, BidiClass::L),
+    (0x17B7, 0x17BD, BidiClass::NSM), (0x17BE, 0x17C5, BidiClass::L),
+    (0x17C6, 0x17C6, BidiClass::NSM), (0x17C7, 0x17C8, BidiClass::L),
+    (0x17C9, 0x17D3, BidiClass::NSM), (0x17D4, 0x17DA, BidiClass::L),
+    (0x17DB, 0x17DB, BidiClass::ET), (0x17DC, 0x17DC, BidiClass::L),
+    (0x17DD, 0x17DD, BidiClass::NSM), (0x17DE, 0x17EF, BidiClass::L),
+    (0x17F0, 0x17F9, BidiClass::ON), (0x17FA, 0x17FF, BidiClass::L),
+    (0x1800, 0x180A, BidiClass::ON), (0x180B, 0x180D, BidiClass::NSM),
+    (0x180E, 0x180E, BidiClass::BN), (0x180F, 0x180F, BidiClass::NSM),
+    (0x1810, 0x1884, BidiClass::L), (0x1885, 0x1886, BidiClass::NSM),
+    (0x1887, 0x18A8, BidiClass::L), (0x18A9, 0x18A9, BidiClass::NSM),
+    (0x18AA, 0x191F, BidiClass::L), (0x1920, 0x1922, BidiClass::NSM),
+    (0x1923, 0x1926, BidiClass::L), (0x1927, 0x1928, BidiClass::NSM),
+    (0x1929, 0x1931, BidiClass::L), (0x1932, 0x1932, BidiClass::NSM),
+    (0x1933, 0x1938, BidiClass::L), (0x1939, 0x193B, BidiClass::NSM),
+    (0x193C, 0x193F, BidiClass::L), (0x1940, 0x1940, BidiClass::ON), (0x1941, 0x1943, BidiClass::L),
+    (0x1944, 0x1945, BidiClass::ON), (0x1946, 0x19DD, BidiClass::L),
+    (0x19DE, 0x19FF, BidiClass::ON), (0x1A00, 0x1A16, BidiClass::L),
+    (0x1A17, 0x1A18, BidiClass::NSM), (0x1A19, 0x1A1A, BidiClass::L),
+    (0x1A1B, 0x1A1B, BidiClass::NSM), (0x1A1C, 0x1A55, BidiClass::L),
+    (0x1A56, 0x1A56, BidiClass::NSM), (0x1A57, 0x1A57, BidiClass::L),
+    (0x1A58, 0x1A5E, BidiClass::NSM), (0x1A5F, 0x1A5F, BidiClass::L),
+    (0x1A60, 0x1A60, BidiClass::NSM), (0x1A61, 0x1A61, BidiClass::L),
+    (0x1A62, 0x1A62, BidiClass::NSM), (0x1A63, 0x1A64, BidiClass::L),
+    (0x1A65, 0x1A6C, BidiClass::NSM), (0x1A6D, 0x1A72, BidiClass::L),
+    (0x1A73, 0x1A7C, BidiClass::NSM), (0x1A7D, 0x1A7E, BidiClass::L),
+    (0x1A7F, 0x1A7F, BidiClass::NSM), (0x1A80, 0x1AAF, BidiClass::L),
+    (0x1AB0, 0x1ACE, BidiClass::NSM), (0x1ACF, 0x1AFF, BidiClass::L),
+    (0x1B00, 0x1B03, BidiClass::NSM), (0x1B04, 0x1B33, BidiClass::L),
+    (0x1B34, 0x1B34, BidiClass::NSM), (0x1B35, 0x1B35, BidiClass::L),
+    (0x1B36, 0x1B3A, BidiClass::NSM), (0x1B3B, 0x1B3B, BidiClass::L),
+    (0x1B3C, 0x1B3C, BidiClass::NSM), (0x1B3D, 0x1B41, BidiClass::L),
+    (0x1B42, 0x1B42, BidiClass::NSM), (0x1B43, 0x1B6A, BidiClass::L),
+    (0x1B6B, 0x1B73, BidiClass::NSM), (0x1B74, 0x1B7F, BidiClass::L),
+    (0x1B80, 0x1B81, BidiClass::NSM), (0x1B82, 0x1BA1, BidiClass::L),
+    (0x1BA2, 0x1BA5, BidiClass::NSM), (0x1BA6, 0x1BA7, BidiClass::L),
+    (0x1BA8, 0x1BA9, BidiClass::NSM), (0x1BAA, 0x1BAA, BidiClass::L),
+    (0x1BAB, 0x1BAD, BidiClass::NSM), (0x1BAE, 0x1BE5, BidiClass::L),
+    (0x1BE6, 0x1BE6, BidiClass::NSM), (0x1BE7, 0x1BE7, BidiClass::L),
+    (0x1BE8, 0x1BE9, BidiClass::NSM), (0x1BEA, 0x1BEC, BidiClass::L),
+    (0x1BED, 0x1BED, BidiClass::NSM), (0x1BEE, 0x1BEE, BidiClass::L),
+    (0x1BEF, 0x1BF1, BidiClass::NSM), (0x1BF2, 0x1C2B, BidiClass::L),
+    (0x1C2C, 0x1C33, BidiClass::NSM), (0x1C34, 0x1C35, BidiClass::L),
+    (0x1C36, 0x1C37, BidiClass::NSM), (0x1C38, 0x1CCF, BidiClass::L),
+    (0x1CD0, 0x1CD2, BidiClass::NSM), (0x1CD3, 0x1CD3, BidiClass::L),
+    (0x1CD4, 0x1CE0, BidiClass::NSM), (0x1CE1, 0x1CE1, BidiClass::L),
+    (0x1CE2, 0x1CE8, BidiClass::NSM), (0x1CE9, 0x1CEC, BidiClass::L),
+    (0x1CED, 0x1CED, BidiClass::NSM), (0x1CEE, 0x1CF3, BidiClass::L),
+    (0x1CF4, 0x1CF4, BidiClass::NSM), (0x1CF5, 0x1CF7, BidiClass::L),
+    (0x1CF8, 0x1CF9, BidiClass::NSM), (0x1CFA, 0x1DBF, BidiClass::L),
+    (0x1DC0, 0x1DFF, BidiClass::NSM), (0x1E00, 0x1FBC, BidiClass::L),
+    (0x1FBD, 0x1FBD, BidiClass::ON), (0x1FBE, 0x1FBE, BidiClass::L),
+    (0x1FBF, 0x1FC1, BidiClass::ON), (0x1FC2, 0x1FCC, BidiClass::L),
+    (0x1FCD, 0x1FCF, BidiClass::ON), (0x1FD0, 0x1FDC, BidiClass::L),
+    (0x1FDD, 0x1FDF, BidiClass::ON), (0x1FE0, 0x1FEC, BidiClass::L),
+    (0x1FED, 0x1FEF, BidiClass::ON), (0x1FF0, 0x1FFC, BidiClass::L),
+    (0x1FFD, 0x1FFE, BidiClass::ON), (0x1FFF, 0x1FFF, BidiClass::L),
+    (0x2000, 0x200A, BidiClass::Other), (0x200B, 0x200D, BidiClass::BN),
+    (0x200E, 0x200E, BidiClass::L), (0x200F, 0x200F, BidiClass::R), (0x2010, 0x2027, BidiClass::ON),
+    (0x2028, 0x202E, BidiClass::Other), (0x202F, 0x202F, BidiClass::CS),
+    (0x2030, 0x2034, BidiClass::ET), (0x2035, 0x2043, BidiClass::ON),
+    (0x2044, 0x2044, BidiClass::CS), (0x2045, 0x205E, BidiClass::ON),
+    (0x205F, 0x205F, BidiClass::Other), (0x2060, 0x2064, BidiClass::BN),
+    (0x2065, 0x2065, BidiClass::L), (0x2066, 0x2069, BidiClass::Other),
+    (0x206A, 0x206F, BidiClass::BN), (0x2070, 0x2070, BidiClass::EN),
+    (0x2071, 0x2073, BidiClass::L), (0x2074, 0x2079, BidiClass::EN),
+    (0x207A, 0x207B, BidiClass::ES), (0x207C, 0x207E, BidiClass::ON),
+    (0x207F, 0x207F, BidiClass::L), (0x2080, 0x2089, BidiClass::EN),
+    (0x208A, 0x208B, BidiClass::ES), (0x208C, 0x208E, BidiClass::ON),
+    (0x208F, 0x209F, BidiClass::L), (0x20A0, 0x20C0, BidiClass::ET), (0x20C1, 0x20CF, BidiClass::L),
+    (0x20D0, 0x20F0, BidiClass::NSM), (0x20F1, 0x20FF, BidiClass::L),
+    (0x2100, 0x2101, BidiClass::ON), (0x2102, 0x2102, BidiClass::L),
+    (0x2103, 0x2106, BidiClass::ON), (0x2107, 0x2107, BidiClass::L),
+    (0x2108, 0x2109, BidiClass::ON), (0x210A, 0x2113, BidiClass::L),
+    (0x2114, 0x2114, BidiClass::ON), (0x2115, 0x2115, BidiClass::L),
+    (0x2116, 0x2118, BidiClass::ON), (0x2119, 0x211D, BidiClass::L),
+    (0x211E, 0x2123, BidiClass::ON), (0x2124, 0x2124, BidiClass::L),
+    (0x2125, 0x2125, BidiClass::ON), (0x2126, 0x2126, BidiClass::L),
+    (0x2127, 0x2127, BidiClass::ON), (0x2128, 0x2128, BidiClass::L),
+    (0x2129, 0x2129, BidiClass::ON), (0x212A, 0x212D, BidiClass::L),
+    (0x212E, 0x212E, BidiClass::ET), (0x212F, 0x2139, BidiClass::L),
+    (0x213A, 0x213B, BidiClass::ON), (0x213C, 0x213F, BidiClass::L),
+    (0x2140, 0x2144, BidiClass::ON), (0x2145, 0x2149, BidiClass::L),
+    (0x214A, 0x214D, BidiClass::ON), (0x214E, 0x214F, BidiClass::L),
+    (0x2150, 0x215F, BidiClass::ON), (0x2160, 0x2188, BidiClass::L),
+    (0x2189, 0x218B, BidiClass::ON), (0x218C, 0x218F, BidiClass::L),
+    (0x2190, 0x2211, BidiClass::ON), (0x2212, 0x2212, BidiClass::ES),
+    (0x2213, 0x2213, BidiClass::ET), (0x2214, 0x2335, BidiClass::ON),
+    (0x2336, 0x237A, BidiClass::L), (0x237B, 0x2394, BidiClass::ON), (0x2395, 0x2395, BidiClass::L),
+    (0x2396, 0x2426, BidiClass::ON), (0x2427, 0x243F, BidiClass::L),
+    (0x2440, 0x244A, BidiClass::ON), (0x244B, 0x245F, BidiClass::L),
+    (0x2460, 0x2487, BidiClass::ON), (0x2488, 0x249B, BidiClass::EN),
+    (0x249C, 0x24E9, BidiClass::L), (0x24EA, 0x26AB, BidiClass::ON), (0x26AC, 0x26AC, BidiClass::L),
+    (0x26AD, 0x27FF, BidiClass::ON), (0x2800, 0x28FF, BidiClass::L),
+    (0x2900, 0x2B73, BidiClass::ON), (0x2B74, 0x2B75, BidiClass::L),
+    (0x2B76, 0x2B95, BidiClass::ON), (0x2B96, 0x2B96, BidiClass::L),
+    (0x2B97, 0x2BFF, BidiClass::ON), (0x2C00, 0x2CE4, BidiClass::L),
+    (0x2CE5, 0x2CEA, BidiClass::ON), (0x2CEB, 0x2CEE, BidiClass::L),
+    (0x2CEF, 0x2CF1, BidiClass::NSM), (0x2CF2, 0x2CF8, BidiClass::L),
+    (0x2CF9, 0x2CFF, BidiClass::ON), (0x2D00, 0x2D7E, BidiClass::L),
+    (0x2D7F, 0x2D7F, BidiClass::NSM), (0x2D80, 0x2DDF, BidiClass::L),
+    (0x2DE0, 0x2DFF, BidiClass::NSM), (0x2E00, 0x2E5D, BidiClass::ON),
+    (0x2E5E, 0x2E7F, BidiClass::L), (0x2E80, 0x2E99, BidiClass::ON), (0x2E9A, 0x2E9A, BidiClass::L),
+    (0x2E9B, 0x2EF3, BidiClass::ON), (0x2EF4, 0x2EFF, BidiClass::L),
+    (0x2F00, 0x2FD5, BidiClass::ON), (0x2FD6, 0x2FEF, BidiClass::L),
+    (0x2FF0, 0x2FFB, BidiClass::ON), (0x2FFC, 0x2FFF, BidiClass::L),
+    (0x3000, 0x3000, BidiClass::Other), (0x3001, 0x3004, BidiClass::ON),
+    (0x3005, 0x3007, BidiClass::L), (0x3008, 0x3020, BidiClass::ON), (0x3021, 0x3029, BidiClass::L),
+    (0x302A, 0x302D, BidiClass::NSM), (0x302E, 0x302F, BidiClass::L),
+    (0x3030, 0x3030, BidiClass::ON), (0x3031, 0x3035, BidiClass::L),
+    (0x3036, 0x3037, BidiClass::ON), (0x3038, 0x303C, BidiClass::L),
+    (0x303D, 0x303F, BidiClass::ON), (0x3040, 0x3098, BidiClass::L),
+    (0x3099, 0x309A, BidiClass::NSM), (0x309B, 0x309C, BidiClass::ON),
+    (0x309D, 0x309F, BidiClass::L), (0x30A0, 0x30A0, BidiClass::ON), (0x30A1, 0x30FA, BidiClass::L),
+    (0x30FB, 0x30FB, BidiClass::ON), (0x30FC, 0x31BF, BidiClass::L),
+    (0x31C0, 0x31E3, BidiClass::ON), (0x31E4, 0x321C, BidiClass::L),
+    (0x321D, 0x321E, BidiClass::ON), (0x321F, 0x324F, BidiClass::L),
+    (0x3250, 0x325F, BidiClass::ON), (0x3260, 0x327B, BidiClass::L),
+    (0x327C, 0x327E, BidiClass::ON), (0x327F, 0x32B0, BidiClass::L),
+    (0x32B1, 0x32BF, BidiClass::ON), (0x32C0, 0x32CB, BidiClass::L),
+    (0x32CC, 0x32CF, BidiClass::ON), (0x32D0, 0x3376, BidiClass::L),
+    (0x3377, 0x337A, BidiClass::ON), (0x337B, 0x33DD, BidiClass::L),
+    (0x33DE, 0x33DF, BidiClass::ON), (0x33E0, 0x33FE, BidiClass::L),
+    (0x33FF, 0x33FF, BidiClass::ON), (0x3400, 0x4DBF, BidiClass::L),
+    (0x4DC0, 0x4DFF, BidiClass::ON), (0x4E00, 0xA48F, BidiClass::L),
+    (0xA490, 0xA4C6, BidiClass::ON), (0xA4C7, 0xA60C, BidiClass::L),
+    (0xA60D, 0xA60F, BidiClass::ON), (0xA610, 0xA66E, BidiClass::L),
+    (0xA66F, 0xA672, BidiClass::NSM), (0xA673, 0xA673, BidiClass::ON),
+    (0xA674, 0xA67D, BidiClass::NSM), (0xA67E, 0xA67F, BidiClass::ON),
+    (0xA680, 0xA69D, BidiClass::L), (0xA69E, 0xA69F, BidiClass::NSM),
+    (0xA6A0, 0xA6EF, BidiClass::L), (0xA6F0, 0xA6F1, BidiClass::NSM),
+    (0xA6F2, 0xA6FF, BidiClass::L), (0xA700, 0xA721, BidiClass::ON), (0xA722, 0xA787, BidiClass::L),
+    (0xA788, 0xA788, BidiClass::ON), (0xA789, 0xA801, BidiClass::L),
+    (0xA802, 0xA802, BidiClass::NSM), (0xA803, 0xA805, BidiClass::L),
+    (0xA806, 0xA806, BidiClass::NSM), (0xA807, 0xA80A, BidiClass::L),
+    (0xA80B, 0xA80B, BidiClass::NSM), (0xA80C, 0xA824, BidiClass::L),
+    (0xA825, 0xA826, BidiClass::NSM), (0xA827, 0xA827, BidiClass::L),
+    (0xA828, 0xA82B, BidiClass::ON), (0xA82C, 0xA82C, BidiClass::NSM),
+    (0xA82D, 0xA837, BidiClass::L), (0xA838, 0xA839, BidiClass::ET), (0xA83A, 0xA873, BidiClass::L),
+    (0xA874, 0xA877, BidiClass::ON), (0xA878, 0xA8C3, BidiClass::L),
+    (0xA8C4, 0xA8C5, BidiClass::NSM), (0xA8C6, 0xA8DF, BidiClass::L),
+    (0xA8E0, 0xA8F1, BidiClass::NSM), (0xA8F2, 0xA8FE, BidiClass::L),
+    (0xA8FF, 0xA8FF, BidiClass::NSM), (0xA900, 0xA925, BidiClass::L),
+    (0xA926, 0xA92D, BidiClass::NSM), (0xA92E, 0xA946, BidiClass::L),
+    (0xA947, 0xA951, BidiClass::NSM), (0xA952, 0xA97F, BidiClass::L),
+    (0xA980, 0xA982, BidiClass::NSM), (0xA983, 0xA9B2, BidiClass::L),
+    (0xA9B3, 0xA9B3, BidiClass::NSM), (0xA9B4, 0xA9B5, BidiClass::L),
+    (0xA9B6, 0xA9B9, BidiClass::NSM), (0xA9BA, 0xA9BB, BidiClass::L),
+    (0xA9BC, 0xA9BD, BidiClass::NSM), (0xA9BE, 0xA9E4, BidiClass::L),
+    (0xA9E5, 0xA9E5, BidiClass::NSM), (0xA9E6, 0xAA28, BidiClass::L),
+    (0xAA29, 0xAA2E, BidiClass::NSM), (0xAA2F, 0xAA30, BidiClass::L),
+    (0xAA31, 0xAA32, BidiClass::NSM), (0xAA33, 0xAA34, BidiClass::L),
+    (0xAA35, 0xAA36, BidiClass::NSM), (0xAA37, 0xAA42, BidiClass::L),
+    (0xAA43, 0xAA43, BidiClass::NSM), (0xAA44, 0xAA4B, BidiClass::L),
+    (0xAA4C, 0xAA4C, BidiClass::NSM), (0xAA4D, 0xAA7B, BidiClass::L),
+    (0xAA7C, 0xAA7C, BidiClass::NSM), (0xAA7D, 0xAAAF, BidiClass::L),
+    (0xAAB0, 0xAAB0, BidiClass::NSM), (0xAAB1, 0xAAB1, BidiClass::L),
+    (0xAAB2, 0xAAB4, BidiClass::NSM), (0xAAB5, 0xAAB6, BidiClass::L),
+    (0xAAB7, 0xAAB8, BidiClass::NSM), (0xAAB9, 0xAABD, BidiClass::L),
+    (0xAABE, 0xAABF, BidiClass::NSM), (0xAAC0, 0xAAC0, BidiClass::L),
+    (0xAAC1, 0xAAC1, BidiClass::NSM), (0xAAC2, 0xAAEB, BidiClass::L),
+    (0xAAEC, 0xAAED, BidiClass::NSM), (0xAAEE, 0xAAF5, BidiClass::L),
+    (0xAAF6, 0xAAF6, BidiClass::NSM), (0xAAF7, 0xAB69, BidiClass::L),
+    (0xAB6A, 0xAB6B, BidiClass::ON), (0xAB6C, 0xABE4, BidiClass::L),
+    (0xABE5, 0xABE5, BidiClass::NSM), (0xABE6, 0xABE7, BidiClass::L),
+    (0xABE8, 0xABE8, BidiClass::NSM), (0xABE9, 0xABEC, BidiClass::L),
+    (0xABED, 0xABED, BidiClass::NSM), (0xABEE, 0xFB1C, BidiClass::L),
+    (0xFB1D, 0xFB1D, BidiClass::R), (0xFB1E, 0xFB1E, BidiClass::NSM),
+    (0xFB1F, 0xFB28, BidiClass::R), (0xFB29, 0xFB29, BidiClass::ES), (0xFB2A, 0xFB36, BidiClass::R),
+    (0xFB37, 0xFB37, BidiClass::L), (0xFB38, 0xFB3C, BidiClass::R), (0xFB3D, 0xFB3D, BidiClass::L),
+    (0xFB3E, 0xFB3E, BidiClass::R), (0xFB3F, 0xFB3F, BidiClass::L), (0xFB40, 0xFB41, BidiClass::R),
+    (0xFB42, 0xFB42, BidiClass::L), (0xFB43, 0xFB44, BidiClass::R), (0xFB45, 0xFB45, BidiClass::L),
+    (0xFB46, 0xFB4F, BidiClass::R), (0xFB50, 0xFBC2, BidiClass::AL), (0xFBC3, 0xFBD2, BidiClass::L),
+    (0xFBD3, 0xFD3D, BidiClass::AL), (0xFD3E, 0xFD4F, BidiClass::ON),
+    (0xFD50, 0xFD8F, BidiClass::AL), (0xFD90, 0xFD91, BidiClass::L),
+    (0xFD92, 0xFDC7, BidiClass::AL), (0xFDC8, 0xFDCE, BidiClass::L),
+    (0xFDCF, 0xFDCF, BidiClass::ON), (0xFDD0, 0xFDEF, BidiClass::L),
+    (0xFDF0, 0xFDFC, BidiClass::AL), (0xFDFD, 0xFDFF, BidiClass::ON),
+    (0xFE00, 0xFE0F, BidiClass::NSM), (0xFE10, 0xFE19, BidiClass::ON),
+    (0xFE1A, 0xFE1F, BidiClass::L), (0xFE20, 0xFE2F, BidiClass::NSM),
+    (0xFE30, 0xFE4F, BidiClass::ON), (0xFE50, 0xFE50, BidiClass::CS),
+    (0xFE51, 0xFE51, BidiClass::ON), (0xFE52, 0xFE52, BidiClass::CS),
+    (0xFE53, 0xFE53, BidiClass::L), (0xFE54, 0xFE54, BidiClass::ON),
+    (0xFE55, 0xFE55, BidiClass::CS), (0xFE56, 0xFE5E, BidiClass::ON),
+    (0xFE5F, 0xFE5F, BidiClass::ET), (0xFE60, 0xFE61, BidiClass::ON),
+    (0xFE62, 0xFE63, BidiClass::ES), (0xFE64, 0xFE66, BidiClass::ON),
+    (0xFE67, 0xFE67, BidiClass::L), (0xFE68, 0xFE68, BidiClass::ON),
+    (0xFE69, 0xFE6A, BidiClass::ET), (0xFE6B, 0xFE6B, BidiClass::ON),
+    (0xFE6C, 0xFE6F, BidiClass::L), (0xFE70, 0xFE74, BidiClass::AL), (0xFE75, 0xFE75, BidiClass::L),
+    (0xFE76, 0xFEFC, BidiClass::AL), (0xFEFD, 0xFEFE, BidiClass::L),
+    (0xFEFF, 0xFEFF, BidiClass::BN), (0xFF00, 0xFF00, BidiClass::L),
+    (0xFF01, 0xFF02, BidiClass::ON), (0xFF03, 0xFF05, BidiClass::ET),
+    (0xFF06, 0xFF0A, BidiClass::ON), (0xFF0B, 0xFF0B, BidiClass::ES),
+    (0xFF0C, 0xFF0C, BidiClass::CS), (0xFF0D, 0xFF0D, BidiClass::ES),
+    (0xFF0E, 0xFF0F, BidiClass::CS), (0xFF10, 0xFF19, BidiClass::EN),
+    (0xFF1A, 0xFF1A, BidiClass::CS), (0xFF1B, 0xFF20, BidiClass::ON),
+    (0xFF21, 0xFF3A, BidiClass::L), (0xFF3B, 0xFF40, BidiClass::ON), (0xFF41, 0xFF5A, BidiClass::L),
+    (0xFF5B, 0xFF65, BidiClass::ON), (0xFF66, 0xFFDF, BidiClass::L),
+    (0xFFE0, 0xFFE1, BidiClass::ET), (0xFFE2, 0xFFE4, BidiClass::ON),
+    (0xFFE5, 0xFFE6, BidiClass::ET), (0xFFE7, 0xFFE7, BidiClass::L),
+    (0xFFE8, 0xFFEE, BidiClass::ON), (0xFFEF, 0xFFF8, BidiClass::L),
+    (0xFFF9, 0xFFFD, BidiClass::ON), (0xFFFE, 0x10100, BidiClass::L),
+    (0x10101, 0x10101, BidiClass::ON), (0x10102, 0x1013F, BidiClass::L),
+    (0x10140, 0x1018C, BidiClass::ON), (0x1018D, 0x1018F, BidiClass::L),
+    (0x10190, 0x1019C, BidiClass::ON), (0x1019D, 0x1019F, BidiClass::L),
+    (0x101A0, 0x101A0, BidiClass::ON), (0x101A1, 0x101FC, BidiClass::L),
+    (0x101FD, 0x101FD, BidiClass::NSM), (0x101FE, 0x102DF, BidiClass::L),
+    (0x102E0, 0x102E0, BidiClass::NSM), (0x102E1, 0x102FB, BidiClass::EN),
+    (0x102FC, 0x10375, BidiClass::L), (0x10376, 0x1037A, BidiClass::NSM),
+    (0x1037B, 0x107FF, BidiClass::L), (0x10800, 0x10805, BidiClass::R),
+    (0x10806, 0x10807, BidiClass::L), (0x10808, 0x10808, BidiClass::R),
+    (0x10809, 0x10809, BidiClass::L), (0x1080A, 0x10835, BidiClass::R),
+    (0x10836, 0x10836, BidiClass::L), (0x10837, 0x10838, BidiClass::R),
+    (0x10839, 0x1083B, BidiClass::L), (0x1083C, 0x1083C, BidiClass::R),
+    (0x1083D, 0x1083E, BidiClass::L), (0x1083F, 0x10855, BidiClass::R),
+    (0x10856, 0x10856, BidiClass::L), (0x10857, 0x1089E, BidiClass::R),
+    (0x1089F, 0x108A6, BidiClass::L), (0x108A7, 0x108AF, BidiClass::R),
+    (0x108B0, 0x108DF, BidiClass::L), (0x108E0, 0x108F2, BidiClass::R),
+    (0x108F3, 0x108F3, BidiClass::L), (0x108F4, 0x108F5, BidiClass::R),
+    (0x108F6, 0x108FA, BidiClass::L), (0x108FB, 0x1091B, BidiClass::R),
+    (0x1091C, 0x1091E, BidiClass::L), (0x1091F, 0x1091F, BidiClass::ON),
+    (0x10920, 0x10939, BidiClass::R), (0x1093A, 0x1093E, BidiClass::L),
+    (0x1093F, 0x1093F, BidiClass::R), (0x10940, 0x1097F, BidiClass::L),
+    (0x10980, 0x109B7, BidiClass::R), (0x109B8, 0x109BB, BidiClass::L),
+    (0x109BC, 0x109CF, BidiClass::R), (0x109D0, 0x109D1, BidiClass::L),
+    (0x109D2, 0x10A00, BidiClass::R), (0x10A01, 0x10A03, BidiClass::NSM),
+    (0x10A04, 0x10A04, BidiClass::L), (0x10A05, 0x10A06, BidiClass::NSM),
+    (0x10A07, 0x10A0B, BidiClass::L), (0x10A0C, 0x10A0F, BidiClass::NSM),
+    (0x10A10, 0x10A13, BidiClass::R), (0x10A14, 0x10A14, BidiClass::L),
+    (0x10A15, 0x10A17, BidiClass::R), (0x10A18, 0x10A18, BidiClass::L),
+    (0x10A19, 0x10A35, BidiClass::R), (0x10A36, 0x10A37, BidiClass::L),
+    (0x10A38, 0x10A3A, BidiClass::NSM), (0x10A3B, 0x10A3E, BidiClass::L),
+    (0x10A3F, 0x10A3F, BidiClass::NSM), (0x10A40, 0x10A48, BidiClass::R),
+    (0x10A49, 0x10A4F, BidiClass::L), (0x10A50, 0x10A58, BidiClass::R),
+    (0x10A59, 0x10A5F, BidiClass::L), (0x10A60, 0x10A9F, BidiClass::R),
+    (0x10AA0, 0x10ABF, BidiClass::L), (0x10AC0, 0x10AE4, BidiClass::R),
+    (0x10AE5, 0x10AE6, BidiClass::NSM), (0x10AE7, 0x10AEA, BidiClass::L),
+    (0x10AEB, 0x10AF6, BidiClass::R), (0x10AF7, 0x10AFF, BidiClass::L),
+    (0x10B00, 0x10B35, BidiClass::R), (0x10B36, 0x10B38, BidiClass::L),
+    (0x10B39, 0x10B3F, BidiClass::ON), (0x10B40, 0x10B55, BidiClass::R),
+    (0x10B56, 0x10B57, BidiClass::L), (0x10B58, 0x10B72, BidiClass::R),
+    (0x10B73, 0x10B77, BidiClass::L), (0x10B78, 0x10B91, BidiClass::R),
+    (0x10B92, 0x10B98, BidiClass::L), (0x10B99, 0x10B9C, BidiClass::R),
+    (0x10B9D, 0x10BA8, BidiClass::L), (0x10BA9, 0x10BAF, BidiClass::R),
+    (0x10BB0, 0x10BFF, BidiClass::L), (0x10C00, 0x10C48, BidiClass::R),
+    (0x10C49, 0x10C7F, BidiClass::L), (0x10C80, 0x10CB2, BidiClass::R),
+    (0x10CB3, 0x10CBF, BidiClass::L), (0x10CC0, 0x10CF2, BidiClass::R),
+    (0x10CF3, 0x10CF9, BidiClass::L), (0x10CFA, 0x10CFF, BidiClass::R),
+    (0x10D00, 0x10D23, BidiClass::AL), (0x10D24, 0x10D27, BidiClass::NSM),
+    (0x10D28, 0x10D2F, BidiClass::L), (0x10D30, 0x10D39, BidiClass::AN),
+    (0x10D3A, 0x10E5F, BidiClass::L), (0x10E60, 0x10E7E, BidiClass::AN),
+    (0x10E7F, 0x10E7F, BidiClass::L), (0x10E80, 0x10EA9, BidiClass::R),
+    (0x10EAA, 0x10EAA, BidiClass::L), (0x10EAB, 0x10EAC, BidiClass::NSM),
+    (0x10EAD, 0x10EAD, BidiClass::R), (0x10EAE, 0x10EAF, BidiClass::L),
+    (0x10EB0, 0x10EB1, BidiClass::R), (0x10EB2, 0x10EFF, BidiClass::L),
+    (0x10F00, 0x10F27, BidiClass::R), (0x10F28, 0x10F2F, BidiClass::L),
+    (0x10F30, 0x10F45, BidiClass::AL), (0x10F46, 0x10F50, BidiClass::NSM),
+    (0x10F51, 0x10F59, BidiClass::AL), (0x10F5A, 0x10F6F, BidiClass::L),
+    (0x10F70, 0x10F81, BidiClass::R), (0x10F82, 0x10F85, BidiClass::NSM),
+    (0x10F86, 0x10F89, BidiClass::R), (0x10F8A, 0x10FAF, BidiClass::L),
+    (0x10FB0, 0x10FCB, BidiClass::R), (0x10FCC, 0x10FDF, BidiClass::L),
+    (0x10FE0, 0x10FF6, BidiClass::R), (0x10FF7, 0x11000, BidiClass::L),
+    (0x11001, 0x11001, BidiClass::NSM), (0x11002, 0x11037, BidiClass::L),
+    (0x11038, 0x11046, BidiClass::NSM), (0x11047, 0x11051, BidiClass::L),
+    (0x11052, 0x11065, BidiClass::ON), (0x11066, 0x1106F, BidiClass::L),
+    (0x11070, 0x11070, BidiClass::NSM), (0x11071, 0x11072, BidiClass::L),
+    (0x11073, 0x11074, BidiClass::NSM), (0x11075, 0x1107E, BidiClass::L),
+    (0x1107F, 0x11081, BidiClass::NSM), (0x11082, 0x110B2, BidiClass::L),
+    (0x110B3, 0x110B6, BidiClass::NSM), (0x110B7, 0x110B8, BidiClass::L),
+    (0x110B9, 0x110BA, BidiClass::NSM), (0x110BB, 0x110C1, BidiClass::L),
+    (0x110C2, 0x110C2, BidiClass::NSM), (0x110C3, 0x110FF, BidiClass::L),
+    (0x11100, 0x11102, BidiClass::NSM), (0x11103, 0x11126, BidiClass::L),
+    (0x11127, 0x1112B, BidiClass::NSM), (0x1112C, 0x1112C, BidiClass::L),
+    (0x1112D, 0x11134, BidiClass::NSM), (0x11135, 0x11172, BidiClass::L),
+    (0x11173, 0x11173, BidiClass::NSM), (0x11174, 0x1117F, BidiClass::L),
+    (0x11180, 0x11181, BidiClass::NSM), (0x11182, 0x111B5, BidiClass::L),
+    (0x111B6, 0x111BE, BidiClass::NSM), (0x111BF, 0x111C8, BidiClass::L),
+    (0x111C9, 0x111CC, BidiClass::NSM), (0x111CD, 0x111CE, BidiClass::L),
+    (0x111CF, 0x111CF, BidiClass::NSM), (0x111D0, 0x1122E, BidiClass::L),
+    (0x1122F, 0x11231, BidiClass::NSM), (0x11232, 0x11233, BidiClass::L),
+    (0x11234, 0x11234, BidiClass::NSM), (0x11235, 0x11235, BidiClass::L),
+    (0x11236, 0x11237, BidiClass::NSM), (0x11238, 0x1123D, BidiClass::L),
+    (0x1123E, 0x1123E, BidiClass::NSM), (0x1123F, 0x112DE, BidiClass::L),
+    (0x112DF, 0x112DF, BidiClass::NSM), (0x112E0, 0x112E2, BidiClass::L),
+    (0x112E3, 0x112EA, BidiClass::NSM), (0x112EB, 0x112FF, BidiClass::L),
+    (0x11300, 0x11301, BidiClass::NSM), (0x11302, 0x1133A, BidiClass::L),
+    (0x1133B, 0x1133C, BidiClass::NSM), (0x1133D, 0x1133F, BidiClass::L),
+    (0x11340, 0x11340, BidiClass::NSM), (0x11341, 0x11365, BidiClass::L),
+    (0x11366, 0x1136C, BidiClass::NSM), (0x1136D, 0x1136F, BidiClass::L),
+    (0x11370, 0x11374, BidiClass::NSM), (0x11375, 0x11437, BidiClass::L),
+    (0x11438, 0x1143F, BidiClass::NSM), (0x11440, 0x11441, BidiClass::L),
+    (0x11442, 0x11444, BidiClass::NSM), (0x11445, 0x11445, BidiClass::L),
+    (0x11446, 0x11446, BidiClass::NSM), (0x11447, 0x1145D, BidiClass::L),
+    (0x1145E, 0x1145E, BidiClass::NSM), (0x1145F, 0x114B2, BidiClass::L),
+    (0x114B3, 0x114B8, BidiClass::NSM), (0x114B9, 0x114B9, BidiClass::L),
+    (0x114BA, 0x114BA, BidiClass::NSM), (0x114BB, 0x114BE, BidiClass::L),
+    (0x114BF, 0x114C0, BidiClass::NSM), (0x114C1, 0x114C1, BidiClass::L),
+    (0x114C2, 0x114C3, BidiClass::NSM), (0x114C4, 0x115B1, BidiClass::L),
+    (0x115B2, 0x115B5, BidiClass::NSM), (0x115B6, 0x115BB, BidiClass::L),
+    (0x115BC, 0x115BD, BidiClass::NSM), (0x115BE, 0x115BE, BidiClass::L),
+    (0x115BF, 0x115C0, BidiClass::NSM), (0x115C1, 0x115DB, BidiClass::L),
+    (0x115DC, 0x115DD, BidiClass::NSM), (0x115DE, 0x11632, BidiClass::L),
+    (0x11633, 0x1163A, BidiClass::NSM), (0x1163B, 0x1163C, BidiClass::L),
+    (0x1163D, 0x1163D, BidiClass::NSM), (0x1163E, 0x1163E, BidiClass::L),
+    (0x1163F, 0x11640, BidiClass::NSM), (0x11641, 0x1165F, BidiClass::L),
+    (0x11660, 0x1166C, BidiClass::ON), (0x1166D, 0x116AA, BidiClass::L),
+    (0x116AB, 0x116AB, BidiClass::NSM), (0x116AC, 0x116AC, BidiClass::L),
+    (0x116AD, 0x116AD, BidiClass::NSM), (0x116AE, 0x116AF, BidiClass::L),
+    (0x116B0, 0x116B5, BidiClass::NSM), (0x116B6, 0x116B6, BidiClass::L),
+    (0x116B7, 0x116B7, BidiClass::NSM), (0x116B8, 0x1171C, BidiClass::L),
+    (0x1171D, 0x1171F, BidiClass::NSM), (0x11720, 0x11721, BidiClass::L),
+    (0x11722, 0x11725, BidiClass::NSM), (0x11726, 0x11726, BidiClass::L),
+    (0x11727, 0x1172B, BidiClass::NSM), (0x1172C, 0x1182E, BidiClass::L),
+    (0x1182F, 0x11837, BidiClass::NSM), (0x11838, 0x11838, BidiClass::L),
+    (0x11839, 0x1183A, BidiClass::NSM), (0x1183B, 0x1193A, BidiClass::L),
+    (0x1193B, 0x1193C, BidiClass::NSM), (0x1193D, 0x1193D, BidiClass::L),
+    (0x1193E, 0x1193E, BidiClass::NSM), (0x1193F, 0x11942, BidiClass::L),
+    (0x11943, 0x11943, BidiClass::NSM), (0x11944, 0x119D3, BidiClass::L),
+    (0x119D4, 0x119D7, BidiClass::NSM), (0x119D8, 0x119D9, BidiClass::L),
+    (0x119DA, 0x119DB, BidiClass::NSM), (0x119DC, 0x119DF, BidiClass::L),
+    (0x119E0, 0x119E0, BidiClass::NSM), (0x119E1, 0x11A00, BidiClass::L),
+    (0x11A01, 0x11A06, BidiClass::NSM), (0x11A07, 0x11A08, BidiClass::L),
+    (0x11A09, 0x11A0A, BidiClass::NSM), (0x11A0B, 0x11A32, BidiClass::L),
+    (0x11A33, 0x11A38, BidiClass::NSM), (0x11A39, 0x11A3A, BidiClass::L),
+    (0x11A3B, 0x11A3E, BidiClass::NSM), (0x11A3F, 0x11A46, BidiClass::L),
+    (0x11A47, 0x11A47, BidiClass::NSM), (0x11A48, 0x11A50, BidiClass::L),
+    (0x11A51, 0x11A56, BidiClass::NSM), (0x11A57, 0x11A58, BidiClass::L),
+    (0x11A59, 0x11A5B, BidiClass::NSM), (0x11A5C, 0x11A89, BidiClass::L),
+    (0x11A8A, 0x11A96, BidiClass::NSM), (0x11A97, 0x11A97, BidiClass::L),
+    (0x11A98, 0x11A99, BidiClass::NSM), (0x11A9A, 0x11C2F, BidiClass::L),
+    (0x11C30, 0x11C36, BidiClass::NSM), (0x11C37, 0x11C37, BidiClass::L),
+    (0x11C38, 0x11C3D, BidiClass::NSM), (0x11C3E, 0x11C91, BidiClass::L),
+    (0x11C92, 0x11CA7, BidiClass::NSM), (0x11CA8, 0x11CA9, BidiClass::L),
+    (0x11CAA, 0x11CB0, BidiClass::NSM), (0x11CB1, 0x11CB1, BidiClass::L),
+    (0x11CB2, 0x11CB3, BidiClass::NSM), (0x11CB4, 0x11CB4, BidiClass::L),
+    (0x11CB5, 0x11CB6, BidiClass::NSM), (0x11CB7, 0x11D30, BidiClass::L),
+    (0x11D31, 0x11D36, BidiClass::NSM), (0x11D37, 0x11D39, BidiClass::L),
+    (0x11D3A, 0x11D3A, BidiClass::NSM), (0x11D3B, 0x11D3B, BidiClass::L),
+    (0x11D3C, 0x11D3D, BidiClass::NSM), (0x11D3E, 0x11D3E, BidiClass::L),
+    (0x11D3F, 0x11D45, BidiClass::NSM), (0x11D46, 0x11D46, BidiClass::L),
+    (0x11D47, 0x11D47, BidiClass::NSM), (0x11D48, 0x11D8F, BidiClass::L),
+    (0x11D90, 0x11D91, BidiClass::NSM), (0x11D92, 0x11D94, BidiClass::L),
+    (0x11D95, 0x11D95, BidiClass::NSM), (0x11D96, 0x11D96, BidiClass::L),
+    (0x11D97, 0x11D97, BidiClass::NSM), (0x11D98, 0x11EF2, BidiClass::L),
+    (0x11EF3, 0x11EF4, BidiClass::NSM), (0x11EF5, 0x11FD4, BidiClass::L),
+    (0x11FD5, 0x11FDC, BidiClass::ON), (0x11FDD, 0x11FE0, BidiClass::ET),
+    (0x11FE1, 0x11FF1, BidiClass::ON), (0x11FF2, 0x16AEF, BidiClass::L),
+    (0x16AF0, 0x16AF4, BidiClass::NSM), (0x16AF5, 0x16B2F, BidiClass::L),
+    (0x16B30, 0x16B36, BidiClass::NSM), (0x16B37, 0x16F4E, BidiClass::L),
+    (0x16F4F, 0x16F4F, BidiClass::NSM), (0x16F50, 0x16F8E, BidiClass::L),
+    (0x16F8F, 0x16F92, BidiClass::NSM), (0x16F93, 0x16FE1, BidiClass::L),
+    (0x16FE2, 0x16FE2, BidiClass::ON), (0x16FE3, 0x16FE3, BidiClass::L),
+    (0x16FE4, 0x16FE4, BidiClass::NSM), (0x16FE5, 0x1BC9C, BidiClass::L),
+    (0x1BC9D, 0x1BC9E, BidiClass::NSM), (0x1BC9F, 0x1BC9F, BidiClass::L),
+    (0x1BCA0, 0x1BCA3, BidiClass::BN), (0x1BCA4, 0x1CEFF, BidiClass::L),
+    (0x1CF00, 0x1CF2D, BidiClass::NSM), (0x1CF2E, 0x1CF2F, BidiClass::L),
+    (0x1CF30, 0x1CF46, BidiClass::NSM), (0x1CF47, 0x1D166, BidiClass::L),
+    (0x1D167, 0x1D169, BidiClass::NSM), (0x1D16A, 0x1D172, BidiClass::L),
+    (0x1D173, 0x1D17A, BidiClass::BN), (0x1D17B, 0x1D182, BidiClass::NSM),
+    (0x1D183, 0x1D184, BidiClass::L), (0x1D185, 0x1D18B, BidiClass::NSM),
+    (0x1D18C, 0x1D1A9, BidiClass::L), (0x1D1AA, 0x1D1AD, BidiClass::NSM),
+    (0x1D1AE, 0x1D1E8, BidiClass::L), (0x1D1E9, 0x1D1EA, BidiClass::ON),
+    (0x1D1EB, 0x1D1FF, BidiClass::L), (0x1D200, 0x1D241, BidiClass::ON),
+    (0x1D242, 0x1D244, BidiClass::NSM), (0x1D245, 0x1D245, BidiClass::ON),
+    (0x1D246, 0x1D2FF, BidiClass::L), (0x1D300, 0x1D356, BidiClass::ON),
+    (0x1D357, 0x1D6DA, BidiClass::L), (0x1D6DB, 0x1D6DB, BidiClass::ON),
+    (0x1D6DC, 0x1D714, BidiClass::L), (0x1D715, 0x1D715, BidiClass::ON),
+    (0x1D716, 0x1D74E, BidiClass::L), (0x1D74F, 0x1D74F, BidiClass::ON),
+    (0x1D750, 0x1D788, BidiClass::L), (0x1D789, 0x1D789, BidiClass::ON),
+    (0x1D78A, 0x1D7C2, BidiClass::L), (0x1D7C3, 0x1D7C3, BidiClass::ON),
+    (0x1D7C4, 0x1D7CD, BidiClass::L), (0x1D7CE, 0x1D7FF, BidiClass::EN),
+    (0x1D800, 0x1D9FF, BidiClass::L), (0x1DA00, 0x1DA36, BidiClass::NSM),
+    (0x1DA37, 0x1DA3A, BidiClass::L), (0x1DA3B, 0x1DA6C, BidiClass::NSM),
+    (0x1DA6D, 0x1DA74, BidiClass::L), (0x1DA75, 0x1DA75, BidiClass::NSM),
+    (0x1DA76, 0x1DA83, BidiClass::L), (0x1DA84, 0x1DA84, BidiClass::NSM),
+    (0x1DA85, 0x1DA9A, BidiClass::L), (0x1DA9B, 0x1DA9F, BidiClass::NSM),
+    (0x1DAA0, 0x1DAA0, BidiClass::L), (0x1DAA1, 0x1DAAF, BidiClass::NSM),
+    (0x1DAB0, 0x1DFFF, BidiClass::L), (0x1E000, 0x1E006, BidiClass::NSM),
+    (0x1E007, 0x1E007, BidiClass::L), (0x1E008, 0x1E018, BidiClass::NSM),
+    (0x1E019, 0x1E01A, BidiClass::L), (0x1E01B, 0x1E021, BidiClass::NSM),
+    (0x1E022, 0x1E022, BidiClass::L), (0x1E023, 0x1E024, BidiClass::NSM),
+    (0x1E025, 0x1E025, BidiClass::L), (0x1E026, 0x1E02A, BidiClass::NSM),
+    (0x1E02B, 0x1E12F, BidiClass::L), (0x1E130, 0x1E136, BidiClass::NSM),
+    (0x1E137, 0x1E2AD, BidiClass::L), (0x1E2AE, 0x1E2AE, BidiClass::NSM),
+    (0x1E2AF, 0x1E2EB, BidiClass::L), (0x1E2EC, 0x1E2EF, BidiClass::NSM),
+    (0x1E2F0, 0x1E2FE, BidiClass::L), (0x1E2FF, 0x1E2FF, BidiClass::ET),
+    (0x1E300, 0x1E7FF, BidiClass::L), (0x1E800, 0x1E8C4, BidiClass::R),
+    (0x1E8C5, 0x1E8C6, BidiClass::L), (0x1E8C7, 0x1E8CF, BidiClass::R),
+    (0x1E8D0, 0x1E8D6, BidiClass::NSM), (0x1E8D7, 0x1E8FF, BidiClass::L),
+    (0x1E900, 0x1E943, BidiClass::R), (0x1E944, 0x1E94A, BidiClass::NSM),
+    (0x1E94B, 0x1E94B, BidiClass::R), (0x1E94C, 0x1E94F, BidiClass::L),
+    (0x1E950, 0x1E959, BidiClass::R), (0x1E95A, 0x1E95D, BidiClass::L),
+    (0x1E95E, 0x1E95F, BidiClass::R), (0x1E960, 0x1EC70, BidiClass::L),
+    (0x1EC71, 0x1ECB4, BidiClass::AL), (0x1ECB5, 0x1ED00, BidiClass::L),
+    (0x1ED01, 0x1ED3D, BidiClass::AL), (0x1ED3E, 0x1EDFF, BidiClass::L),
+    (0x1EE00, 0x1EE03, BidiClass::AL), (0x1EE04, 0x1EE04, BidiClass::L),
+    (0x1EE05, 0x1EE1F, BidiClass::AL), (0x1EE20, 0x1EE20, BidiClass::L),
+    (0x1EE21, 0x1EE22, BidiClass::AL), (0x1EE23, 0x1EE23, BidiClass::L),
+    (0x1EE24, 0x1EE24, BidiClass::AL), (0x1EE25, 0x1EE26, BidiClass::L),
+    (0x1EE27, 0x1EE27, BidiClass::AL), (0x1EE28, 0x1EE28, BidiClass::L),
+    (0x1EE29, 0x1EE32, BidiClass::AL), (0x1EE33, 0x1EE33, BidiClass::L),
+    (0x1EE34, 0x1EE37, BidiClass::AL), (0x1EE38, 0x1EE38, BidiClass::L),
+    (0x1EE39, 0x1EE39, BidiClass::AL), (0x1EE3A, 0x1EE3A, BidiClass::L),
+    (0x1EE3B, 0x1EE3B, BidiClass::AL), (0x1EE3C, 0x1EE41, BidiClass::L),
+    (0x1EE42, 0x1EE42, BidiClass::AL), (0x1EE43, 0x1EE46, BidiClass::L),
+    (0x1EE47, 0x1EE47, BidiClass::AL), (0x1EE48, 0x1EE48, BidiClass::L),
+    (0x1EE49, 0x1EE49, BidiClass::AL), (0x1EE4A, 0x1EE4A, BidiClass::L),
+    (0x1EE4B, 0x1EE4B, BidiClass::AL), (0x1EE4C, 0x1EE4C, BidiClass::L),
+    (0x1EE4D, 0x1EE4F, BidiClass::AL), (0x1EE50, 0x1EE50, BidiClass::L),
+    (0x1EE51, 0x1EE52, BidiClass::AL), (0x1EE53, 0x1EE53, BidiClass::L),
+    (0x1EE54, 0x1EE54, BidiClass::AL), (0x1EE55, 0x1EE56, BidiClass::L),
+    (0x1EE57, 0x1EE57, BidiClass::AL), (0x1EE58, 0x1EE58, BidiClass::L),
+    (0x1EE59, 0x1EE59, BidiClass::AL), (0x1EE5A, 0x1EE5A, BidiClass::L),
+    (0x1EE5B, 0x1EE5B, BidiClass::AL), (0x1EE5C, 0x1EE5C, BidiClass::L),
+    (0x1EE5D, 0x1EE5D, BidiClass::AL), (0x1EE5E, 0x1EE5E, BidiClass::L),
+    (0x1EE5F, 0x1EE5F, BidiClass::AL), (0x1EE60, 0x1EE60, BidiClass::L),
+    (0x1EE61, 0x1EE62, BidiClass::AL), (0x1EE63, 0x1EE63, BidiClass::L),
+    (0x1EE64, 0x1EE64, BidiClass::AL), (0x1EE65, 0x1EE66, BidiClass::L),
+    (0x1EE67, 0x1EE6A, BidiClass::AL), (0x1EE6B, 0x1EE6B, BidiClass::L),
+    (0x1EE6C, 0x1EE72, BidiClass::AL), (0x1EE73, 0x1EE73, BidiClass::L),
+    (0x1EE74, 0x1EE77, BidiClass::AL), (0x1EE78, 0x1EE78, BidiClass::L),
+    (0x1EE79, 0x1EE7C, BidiClass::AL), (0x1EE7D, 0x1EE7D, BidiClass::L),
+    (0x1EE7E, 0x1EE7E, BidiClass::AL), (0x1EE7F, 0x1EE7F, BidiClass::L),
+    (0x1EE80, 0x1EE89, BidiClass::AL), (0x1EE8A, 0x1EE8A, BidiClass::L),
+    (0x1EE8B, 0x1EE9B, BidiClass::AL), (0x1EE9C, 0x1EEA0, BidiClass::L),
+    (0x1EEA1, 0x1EEA3, BidiClass::AL), (0x1EEA4, 0x1EEA4, BidiClass::L),
+    (0x1EEA5, 0x1EEA9, BidiClass::AL), (0x1EEAA, 0x1EEAA, BidiClass::L),
+    (0x1EEAB, 0x1EEBB, BidiClass::AL), (0x1EEBC, 0x1EEEF, BidiClass::L),
+    (0x1EEF0, 0x1EEF1, BidiClass::ON), (0x1EEF2, 0x1EFFF, BidiClass::L),
+    (0x1F000, 0x1F02B, BidiClass::ON), (0x1F02C, 0x1F02F, BidiClass::L),
+    (0x1F030, 0x1F093, BidiClass::ON), (0x1F094, 0x1F09F, BidiClass::L),
+    (0x1F0A0, 0x1F0AE, BidiClass::ON), (0x1F0AF, 0x1F0B0, BidiClass::L),
+    (0x1F0B1, 0x1F0BF, BidiClass::ON), (0x1F0C0, 0x1F0C0, BidiClass::L),
+    (0x1F0C1, 0x1F0CF, BidiClass::ON), (0x1F0D0, 0x1F0D0, BidiClass::L),
+    (0x1F0D1, 0x1F0F5, BidiClass::ON), (0x1F0F6, 0x1F0FF, BidiClass::L),
+    (0x1F100, 0x1F10A, BidiClass::EN), (0x1F10B, 0x1F10F, BidiClass::ON),
+    (0x1F110, 0x1F12E, BidiClass::L), (0x1F12F, 0x1F12F, BidiClass::ON),
+    (0x1F130, 0x1F169, BidiClass::L), (0x1F16A, 0x1F16F, BidiClass::ON),
+    (0x1F170, 0x1F1AC, BidiClass::L), (0x1F1AD, 0x1F1AD, BidiClass::ON),
+    (0x1F1AE, 0x1F25F, BidiClass::L), (0x1F260, 0x1F265, BidiClass::ON),
+    (0x1F266, 0x1F2FF, BidiClass::L), (0x1F300, 0x1F6D7, BidiClass::ON),
+    (0x1F6D8, 0x1F6DC, BidiClass::L), (0x1F6DD, 0x1F6EC, BidiClass::ON),
+    (0x1F6ED, 0x1F6EF, BidiClass::L), (0x1F6F0, 0x1F6FC, BidiClass::ON),
+    (0x1F6FD, 0x1F6FF, BidiClass::L), (0x1F700, 0x1F773, BidiClass::ON),
+    (0x1F774, 0x1F77F, BidiClass::L), (0x1F780, 0x1F7D8, BidiClass::ON),
+    (0x1F7D9, 0x1F7DF, BidiClass::L), (0x1F7E0, 0x1F7EB, BidiClass::ON),
+    (0x1F7EC, 0x1F7EF, BidiClass::L), (0x1F7F0, 0x1F7F0, BidiClass::ON),
+    (0x1F7F1, 0x1F7FF, BidiClass::L), (0x1F800, 0x1F80B, BidiClass::ON),
+    (0x1F80C, 0x1F80F, BidiClass::L), (0x1F810, 0x1F847, BidiClass::ON),
+    (0x1F848, 0x1F84F, BidiClass::L), (0x1F850, 0x1F859, BidiClass::ON),
+    (0x1F85A, 0x1F85F, BidiClass::L), (0x1F860, 0x1F887, BidiClass::ON),
+    (0x1F888, 0x1F88F, BidiClass::L), (0x1F890, 0x1F8AD, BidiClass::ON),
+    (0x1F8AE, 0x1F8AF, BidiClass::L), (0x1F8B0, 0x1F8B1, BidiClass::ON),
+    (0x1F8B2, 0x1F8FF, BidiClass::L), (0x1F900, 0x1FA53, BidiClass::ON),
+    (0x1FA54, 0x1FA5F, BidiClass::L), (0x1FA60, 0x1FA6D, BidiClass::ON),
+    (0x1FA6E, 0x1FA6F, BidiClass::L), (0x1FA70, 0x1FA74, BidiClass::ON),
+    (0x1FA75, 0x1FA77, BidiClass::L), (0x1FA78, 0x1FA7C, BidiClass::ON),
+    (0x1FA7D, 0x1FA7F, BidiClass::L), (0x1FA80, 0x1FA86, BidiClass::ON),
+    (0x1FA87, 0x1FA8F, BidiClass::L), (0x1FA90, 0x1FAAC, BidiClass::ON),
+    (0x1FAAD, 0x1FAAF, BidiClass::L), (0x1FAB0, 0x1FABA, BidiClass::ON),
+    (0x1FABB, 0x1FABF, BidiClass::L), (0x1FAC0, 0x1FAC5, BidiClass::ON),
+    (0x1FAC6, 0x1FACF, BidiClass::L), (0x1FAD0, 0x1FAD9, BidiClass::ON),
+    (0x1FADA, 0x1FADF, BidiClass::L), (0x1FAE0, 0x1FAE7, BidiClass::ON),
+    (0x1FAE8, 0x1FAEF, BidiClass::L), (0x1FAF0, 0x1FAF6, BidiClass::ON),
+    (0x1FAF7, 0x1FAFF, BidiClass::L), (0x1FB00, 0x1FB92, BidiClass::ON),
+    (0x1FB93, 0x1FB93, BidiClass::L), (0x1FB94, 0x1FBCA, BidiClass::ON),
+    (0x1FBCB, 0x1FBEF, BidiClass::L), (0x1FBF0, 0x1FBF9, BidiClass::EN),
+    (0x1FBFA, 0xE0000, BidiClass::L), (0xE0001, 0xE0001, BidiClass::BN),
+    (0xE0002, 0xE001F, BidiClass::L), (0xE0020, 0xE007F, BidiClass::BN),
+    (0xE0080, 0xE00FF, BidiClass::L), (0xE0100, 0xE01EF, BidiClass::NSM),
+    (0xE01F0, 0x10FFFF, BidiClass::L),
+];
+
+/// Code points with General_Category=Mark: `(first, last)`.
+#[rustfmt::skip]
+pub(super) static MARK: [(u32, u32); 299] = [
+    (0x0300, 0x036F), (0x0483, 0x0489), (0x0591, 0x05BD), (0x05BF, 0x05BF), (0x05C1, 0x05C2),
+    (0x05C4, 0x05C5), (0x05C7, 0x05C7), (0x0610, 0x061A), (0x064B, 0x065F), (0x0670, 0x0670),
+    (0x06D6, 0x06DC), (0x06DF, 0x06E4), (0x06E7, 0x06E8), (0x06EA, 0x06ED), (0x0711, 0x0711),
+    (0x0730, 0x074A), (0x07A6, 0x07B0), (0x07EB, 0x07F3), (0x07FD, 0x07FD), (0x0816, 0x0819),
+    (0x081B, 0x0823), (0x0825, 0x0827), (0x0829, 0x082D), (0x0859, 0x085B), (0x0898, 0x089F),
+    (0x08CA, 0x08E1), (0x08E3, 0x0903), (0x093A, 0x093C), (0x093E, 0x094F), (0x0951, 0x0957),
+    (0x0962, 0x0963), (0x0981, 0x0983), (0x09BC, 0x09BC), (0x09BE, 0x09C4), (0x09C7, 0x09C8),
+    (0x09CB, 0x09CD), (0x09D7, 0x09D7), (0x09E2, 0x09E3), (0x09FE, 0x09FE), (0x0A01, 0x0A03),
+    (0x0A3C, 0x0A3C), (0x0A3E, 0x0A42), (0x0A47, 0x0A48), (0x0A4B, 0x0A4D), (0x0A51, 0x0A51),
+    (0x0A70, 0x0A71), (0x0A75, 0x0A75), (0x0A81, 0x0A83), (0x0ABC, 0x0ABC), (0x0ABE, 0x0AC5),
+    (0x0AC7, 0x0AC9), (0x0ACB, 0x0ACD), (0x0AE2, 0x0AE3), (0x0AFA, 0x0AFF), (0x0B01, 0x0B03),
+    (0x0B3C, 0x0B3C), (0x0B3E, 0x0B44), (0x0B47, 0x0B48), (0x0B4B, 0x0B4D), (0x0B55, 0x0B57),
+    (0x0B62, 0x0B63), (0x0B82, 0x0B82), (0x0BBE, 0x0BC2), (0x0BC6, 0x0BC8), (0x0BCA, 0x0BCD),
+    (0x0BD7, 0x0BD7), (0x0C00, 0x0C04), (0x0C3C, 0x0C3C), (0x0C3E, 0x0C44), (0x0C46, 0x0C48),
+    (0x0C4A, 0x0C4D), (0x0C55, 0x0C56), (0x0C62, 0x0C63), (0x0C81, 0x0C83), (0x0CBC, 0x0CBC),
+    (0x0CBE, 0x0CC4), (0x0CC6, 0x0CC8), (0x0CCA, 0x0CCD), (0x0CD5, 0x0CD6), (0x0CE2, 0x0CE3),
+    (0x0D00, 0x0D03), (0x0D3B, 0x0D3C), (0x0D3E, 0x0D44), (0x0D46, 0x0D48), (0x0D4A, 0x0D4D),
+    (0x0D57, 0x0D57), (0x0D62, 0x0D63), (0x0D81, 0x0D83), (0x0DCA, 0x0DCA), (0x0DCF, 0x0DD4),
+    (0x0DD6, 0x0DD6), (0x0DD8, 0x0DDF), (0x0DF2, 0x0DF3), (0x0E31, 0x0E31), (0x0E34, 0x0E3A),
+    (0x0E47, 0x0E4E), (0x0EB1, 0x0EB1), (0x0EB4, 0x0EBC), (0x0EC8, 0x0ECD), (0x0F18, 0x0F19),
+    (0x0F35, 0x0F35), (0x0F37, 0x0F37), (0x0F39, 0x0F39), (0x0F3E, 0x0F3F), (0x0F71, 0x0F84),
+    (0x0F86, 0x0F87), (0x0F8D, 0x0F97), (0x0F99, 0x0FBC), (0x0FC6, 0x0FC6), (0x102B, 0x103E),
+    (0x1056, 0x1059), (0x105E, 0x1060), (0x1062, 0x1064), (0x1067, 0x106D), (0x1071, 0x1074),
+    (0x1082, 0x108D), (0x108F, 0x108F), (0x109A, 0x109D), (0x135D, 0x135F), (0x1712, 0x1715),
+    (0x1732, 0x1734), (0x1752, 0x1753), (0x1772, 0x1773), (0x17B4, 0x17D3), (0x17DD, 0x17DD),
+    (0x180B, 0x180D), (0x180F, 0x180F), (0x1885, 0x1886), (0x18A9, 0x18A9), (0x1920, 0x192B),
+    (0x1930, 0x193B), (0x1A17, 0x1A1B), (0x1A55, 0x1A5E), (0x1A60, 0x1A7C), (0x1A7F, 0x1A7F),
+    (0x1AB0, 0x1ACE), (0x1B00, 0x1B04), (0x1B34, 0x1B44), (0x1B6B, 0x1B73), (0x1B80, 0x1B82),
+    (0x1BA1, 0x1BAD), (0x1BE6, 0x1BF3), (0x1C24, 0x1C37), (0x1CD0, 0x1CD2), (0x1CD4, 0x1CE8),
+    (0x1CED, 0x1CED), (0x1CF4, 0x1CF4), (0x1CF7, 0x1CF9), (0x1DC0, 0x1DFF), (0x20D0, 0x20F0),
+    (0x2CEF, 0x2CF1), (0x2D7F, 0x2D7F), (0x2DE0, 0x2DFF), (0x302A, 0x302F), (0x3099, 0x309A),
+    (0xA66F, 0xA672), (0xA674, 0xA67D), (0xA69E, 0xA69F), (0xA6F0, 0xA6F1), (0xA802, 0xA802),
+    (0xA806, 0xA806), (0xA80B, 0xA80B), (0xA823, 0xA827), (0xA82C, 0xA82C), (0xA880, 0xA881),
+    (0xA8B4, 0xA8C5), (0xA8E0, 0xA8F1), (0xA8FF, 0xA8FF), (0xA926, 0xA92D), (0xA947, 0xA953),
+    (0xA980, 0xA983), (0xA9B3, 0xA9C0), (0xA9E5, 0xA9E5), (0xAA29, 0xAA36), (0xAA43, 0xAA43),
+    (0xAA4C, 0xAA4D), (0xAA7B, 0xAA7D), (0xAAB0, 0xAAB0), (0xAAB2, 0xAAB4), (0xAAB7, 0xAAB8),
+    (0xAABE, 0xAABF), (0xAAC1, 0xAAC1), (0xAAEB, 0xAAEF), (0xAAF5, 0xAAF6), (0xABE3, 0xABEA),
+    (0xABEC, 0xABED), (0xFB1E, 0xFB1E), (0xFE00, 0xFE0F), (0xFE20, 0xFE2F), (0x101FD, 0x101FD),
+    (0x102E0, 0x102E0), (0x10376, 0x1037A), (0x10A01, 0x10A03), (0x10A05, 0x10A06),
+    (0x10A0C, 0x10A0F), (0x10A38, 0x10A3A), (0x10A3F, 0x10A3F), (0x10AE5, 0x10AE6),
+    (0x10D24, 0x10D27), (0x10EAB, 0x10EAC), (0x10F46, 0x10F50), (0x10F82, 0x10F85),
+    (0x11000, 0x11002), (0x11038, 0x11046), (0x11070, 0x11070), (0x11073, 0x11074),
+    (0x1107F, 0x11082), (0x110B0, 0x110BA), (0x110C2, 0x110C2), (0x11100, 0x11102),
+    (0x11127, 0x11134), (0x11145, 0x11146), (0x11173, 0x11173), (0x11180, 0x11182),
+    (0x111B3, 0x111C0), (0x111C9, 0x111CC), (0x111CE, 0x111CF), (0x1122C, 0x11237),
+    (0x1123E, 0x1123E), (0x112DF, 0x112EA), (0x11300, 0x11303), (0x1133B, 0x1133C),
+    (0x1133E, 0x11344), (0x11347, 0x11348), (0x1134B, 0x1134D), (0x11357, 0x11357),
+    (0x11362, 0x11363), (0x11366, 0x1136C), (0x11370, 0x11374), (0x11435, 0x11446),
+    (0x1145E, 0x1145E), (0x114B0, 0x114C3), (0x115AF, 0x115B5), (0x115B8, 0x115C0),
+    (0x115DC, 0x115DD), (0x11630, 0x11640), (0x116AB, 0x116B7), (0x1171D, 0x1172B),
+    (0x1182C, 0x1183A), (0x11930, 0x11935), (0x11937, 0x11938), (0x1193B, 0x1193E),
+    (0x11940, 0x11940), (0x11942, 0x11943), (0x119D1, 0x119D7), (0x119DA, 0x119E0),
+    (0x119E4, 0x119E4), (0x11A01, 0x11A0A), (0x11A33, 0x11A39), (0x11A3B, 0x11A3E),
+    (0x11A47, 0x11A47), (0x11A51, 0x11A5B), (0x11A8A, 0x11A99), (0x11C2F, 0x11C36),
+    (0x11C38, 0x11C3F), (0x11C92, 0x11CA7), (0x11CA9, 0x11CB6), (0x11D31, 0x11D36),
+    (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D), (0x11D3F, 0x11D45), (0x11D47, 0x11D47),
+    (0x11D8A, 0x11D8E), (0x11D90, 0x11D91), (0x11D93, 0x11D97), (0x11EF3, 0x11EF6),
+    (0x16AF0, 0x16AF4), (0x16B30, 0x16B36), (0x16F4F, 0x16F4F), (0x16F51, 0x16F87),
+    (0x16F8F, 0x16F92), (0x16FE4, 0x16FE4), (0x16FF0, 0x16FF1), (0x1BC9D, 0x1BC9E),
+    (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46), (0x1D165, 0x1D169), (0x1D16D, 0x1D172),
+    (0x1D17B, 0x1D182), (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD), (0x1D242, 0x1D244),
+    (0x1DA00, 0x1DA36), (0x1DA3B, 0x1DA6C), (0x1DA75, 0x1DA75), (0x1DA84, 0x1DA84),
+    (0x1DA9B, 0x1DA9F), (0x1DAA1, 0x1DAAF), (0x1E000, 0x1E006), (0x1E008, 0x1E018),
+    (0x1E01B, 0x1E021), (0x1E023, 0x1E024), (0x1E026, 0x1E02A), (0x1E130, 0x1E136),
+    (0x1E2AE, 0x1E2AE), (0x1E2EC, 0x1E2EF), (0x1E8D0, 0x1E8D6), (0x1E944, 0x1E94A),
+    (0xE0100, 0xE01EF),
+];
+
+/// Joining types other than U and C: `(first, last, type)`.
+#[rustfmt::skip]
+pub(super) static JOINING_TYPE: [(u32, u32, JoiningType); 501] = [
+    (0x00AD, 0x00AD, JoiningType::T), (0x0300, 0x036F, JoiningType::T),
+    (0x0483, 0x0489, JoiningType::T), (0x0591, 0x05BD, JoiningType::T),
+    (0x05BF, 0x05BF, JoiningType::T), (0x05C1, 0x05C2, JoiningType::T),
+    (0x05C4, 0x05C5, JoiningType::T), (0x05C7, 0x05C7, JoiningType::T),
+    (0x0610, 0x061A, JoiningType::T), (0x061C, 0x061C, JoiningType::T),
+    (0x0620, 0x0620, JoiningType::D), (0x0622, 0x0625, JoiningType::R),
+    (0x0626, 0x0626, JoiningType::D), (0x0627, 0x0627, JoiningType::R),
+    (0x0628, 0x0628, JoiningType::D), (0x0629, 0x0629, JoiningType::R),
+    (0x062A, 0x062E, JoiningType::D), (0x062F, 0x0632, JoiningType::R),
+    (0x0633, 0x063F, JoiningType::D), (0x0641, 0x0647, JoiningType::D),
+    (0x0648, 0x0648, JoiningType::R), (0x0649, 0x064A, JoiningType::D),
+    (0x064B, 0x065F, JoiningType::T), (0x066E, 0x066F, JoiningType::D),
+    (0x0670, 0x0670, JoiningType::T), (0x0671, 0x0673, JoiningType::R),
+    (0x0675, 0x0677, JoiningType::R), (0x0678, 0x0687, JoiningType::D),
+    (0x0688, 0x0699, JoiningType::R), (0x069A, 0x06BF, JoiningType::D),
+    (0x06C0, 0x06C0, JoiningType::R), (0x06C1, 0x06C2, JoiningType::D),
+    (0x06C3, 0x06CB, JoiningType::R), (0x06CC, 0x06CC, JoiningType::D),
+    (0x06CD, 0x06CD, JoiningType::R), (0x06CE, 0x06CE, JoiningType::D),
+    (0x06CF, 0x06CF, JoiningType::R), (0x06D0, 0x06D1, JoiningType::D),
+    (0x06D2, 0x06D3, JoiningType::R), (0x06D5, 0x06D5, JoiningType::R),
+    (0x06D6, 0x06DC, JoiningType::T), (0x06DF, 0x06E4, JoiningType::T),
+    (0x06E7, 0x06E8, JoiningType::T), (0x06EA, 0x06ED, JoiningType::T),
+    (0x06EE, 0x06EF, JoiningType::R), (0x06FA, 0x06FC, JoiningType::D),
+    (0x06FF, 0x06FF, JoiningType::D), (0x070F, 0x070F, JoiningType::T),
+    (0x0710, 0x0710, JoiningType::R), (0x0711, 0x0711, JoiningType::T),
+    (0x0712, 0x0714, JoiningType::D), (0x0715, 0x0719, JoiningType::R),
+    (0x071A, 0x071D, JoiningType::D), (0x071E, 0x071E, JoiningType::R),
+    (0x071F, 0x0727, JoiningType::D), (0x0728, 0x0728, JoiningType::R),
+    (0x0729, 0x0729, JoiningType::D), (0x072A, 0x072A, JoiningType::R),
+    (0x072B, 0x072B, JoiningType::D), (0x072C, 0x072C, JoiningType::R),
+    (0x072D, 0x072E, JoiningType::D), (0x072F, 0x072F, JoiningType::R),
+    (0x0730, 0x074A, JoiningType::T), (0x074D, 0x074D, JoiningType::R),
+    (0x074E, 0x0758, JoiningType::D), (0x0759, 0x075B, JoiningType::R),
+    (0x075C, 0x076A, JoiningType::D), (0x076B, 0x076C, JoiningType::R),
+    (0x076D, 0x0770, JoiningType::D), (0x0771, 0x0771, JoiningType::R),
+    (0x0772, 0x0772, JoiningType::D), (0x0773, 0x0774, JoiningType::R),
+    (0x0775, 0x0777, JoiningType::D), (0x0778, 0x0779, JoiningType::R),
+    (0x077A, 0x077F, JoiningType::D), (0x07A6, 0x07B0, JoiningType::T),
+    (0x07CA, 0x07EA, JoiningType::D), (0x07EB, 0x07F3, JoiningType::T),
+    (0x07FD, 0x07FD, JoiningType::T), (0x0816, 0x0819, JoiningType::T),
+    (0x081B, 0x0823, JoiningType::T), (0x0825, 0x0827, JoiningType::T),
+    (0x0829, 0x082D, JoiningType::T), (0x0840, 0x0840, JoiningType::R),
+    (0x0841, 0x0845, JoiningType::D), (0x0846, 0x0847, JoiningType::R),
+    (0x0848, 0x0848, JoiningType::D), (0x0849, 0x0849, JoiningType::R),
+    (0x084A, 0x0853, JoiningType::D), (0x0854, 0x0854, JoiningType::R),
+    (0x0855, 0x0855, JoiningType::D), (0x0856, 0x0858, JoiningType::R),
+    (0x0859, 0x085B, JoiningType::T), (0x0860, 0x0860, JoiningType::D),
+    (0x0862, 0x0865, JoiningType::D), (0x0867, 0x0867, JoiningType::R),
+    (0x0868, 0x0868, JoiningType::D), (0x0869, 0x086A, JoiningType::R),
+    (0x0870, 0x0882, JoiningType::R), (0x0886, 0x0886, JoiningType::D),
+    (0x0889, 0x088D, JoiningType::D), (0x088E, 0x088E, JoiningType::R),
+    (0x0898, 0x089F, JoiningType::T), (0x08A0, 0x08A9, JoiningType::D),
+    (0x08AA, 0x08AC, JoiningType::R), (0x08AE, 0x08AE, JoiningType::R),
+    (0x08AF, 0x08B0, JoiningType::D), (0x08B1, 0x08B2, JoiningType::R),
+    (0x08B3, 0x08B8, JoiningType::D), (0x08B9, 0x08B9, JoiningType::R),
+    (0x08BA, 0x08C8, JoiningType::D), (0x08CA, 0x08E1, JoiningType::T),
+    (0x08E3, 0x0902, JoiningType::T), (0x093A, 0x093A, JoiningType::T),
+    (0x093C, 0x093C, JoiningType::T), (0x0941, 0x0948, JoiningType::T),
+    (0x094D, 0x094D, JoiningType::T), (0x0951, 0x0957, JoiningType::T),
+    (0x0962, 0x0963, JoiningType::T), (0x0981, 0x0981, JoiningType::T),
+    (0x09BC, 0x09BC, JoiningType::T), (0x09C1, 0x09C4, JoiningType::T),
+    (0x09CD, 0x09CD, JoiningType::T), (0x09E2, 0x09E3, JoiningType::T),
+    (0x09FE, 0x09FE, JoiningType::T), (0x0A01, 0x0A02, JoiningType::T),
+    (0x0A3C, 0x0A3C, JoiningType::T), (0x0A41, 0x0A42, JoiningType::T),
+    (0x0A47, 0x0A48, JoiningType::T), (0x0A4B, 0x0A4D, JoiningType::T),
+    (0x0A51, 0x0A51, JoiningType::T), (0x0A70, 0x0A71, JoiningType::T),
+    (0x0A75, 0x0A75, JoiningType::T), (0x0A81, 0x0A82, JoiningType::T),
+    (0x0ABC, 0x0ABC, JoiningType::T), (0x0AC1, 0x0AC5, JoiningType::T),
+    (0x0AC7, 0x0AC8, JoiningType::T), (0x0ACD, 0x0ACD, JoiningType::T),
+    (0x0AE2, 0x0AE3, JoiningType::T), (0x0AFA, 0x0AFF, JoiningType::T),
+    (0x0B01, 0x0B01, JoiningType::T), (0x0B3C, 0x0B3C, JoiningType::T),
+    (0x0B3F, 0x0B3F, JoiningType::T), (0x0B41, 0x0B44, JoiningType::T),
+    (0x0B4D, 0x0B4D, JoiningType::T), (0x0B55, 0x0B56, JoiningType::T),
+    (0x0B62, 0x0B63, JoiningType::T), (0x0B82, 0x0B82, JoiningType::T),
+    (0x0BC0, 0x0BC0, JoiningType::T), (0x0BCD, 0x0BCD, JoiningType::T),
+    (0x0C00, 0x0C00, JoiningType::T), (0x0C04, 0x0C04, JoiningType::T),
+    (0x0C3C, 0x0C3C, JoiningType::T), (0x0C3E, 0x0C40, JoiningType::T),
+    (0x0C46, 0x0C48, JoiningType::T), (0x0C4A, 0x0C4D, JoiningType::T),
+    (0x0C55, 0x0C56, JoiningType::T), (0x0C62, 0x0C63, JoiningType::T),
+    (0x0C81, 0x0C81, JoiningType::T), (0x0CBC, 0x0CBC, JoiningType::T),
+    (0x0CBF, 0x0CBF, JoiningType::T), (0x0CC6, 0x0CC6, JoiningType::T),
+    (0x0CCC, 0x0CCD, JoiningType::T), (0x0CE2, 0x0CE3, JoiningType::T),
+    (0x0D00, 0x0D01, JoiningType::T), (0x0D3B, 0x0D3C, JoiningType::T),
+    (0x0D41, 0x0D44, JoiningType::T), (0x0D4D, 0x0D4D, JoiningType::T),
+    (0x0D62, 0x0D63, JoiningType::T), (0x0D81, 0x0D81, JoiningType::T),
+    (0x0DCA, 0x0DCA, JoiningType::T), (0x0DD2, 0x0DD4, JoiningType::T),
+    (0x0DD6, 0x0DD6, JoiningType::T), (0x0E31, 0x0E31, JoiningType::T),
+    (0x0E34, 0x0E3A, JoiningType::T), (0x0E47, 0x0E4E, JoiningType::T),
+    (0x0EB1, 0x0EB1, JoiningType::T), (0x0EB4, 0x0EBC, JoiningType::T),
+    (0x0EC8, 0x0ECE, JoiningType::T), (0x0F18, 0x0F19, JoiningType::T),
+    (0x0F35, 0x0F35, JoiningType::T), (0x0F37, 0x0F37, JoiningType::T),
+    (0x0F39, 0x0F39, JoiningType::T), (0x0F71, 0x0F7E, JoiningType::T),
+    (0x0F80, 0x0F84, JoiningType::T), (0x0F86, 0x0F87, JoiningType::T),
+    (0x0F8D, 0x0F97, JoiningType::T), (0x0F99, 0x0FBC, JoiningType::T),
+    (0x0FC6, 0x0FC6, JoiningType::T), (0x102D, 0x1030, JoiningType::T),
+    (0x1032, 0x1037, JoiningType::T), (0x1039, 0x103A, JoiningType::T),
+    (0x103D, 0x103E, JoiningType::T), (0x1058, 0x1059, JoiningType::T),
+    (0x105E, 0x1060, JoiningType::T), (0x1071, 0x1074, JoiningType::T),
+    (0x1082, 0x1082, JoiningType::T), (0x1085, 0x1086, JoiningType::T),
+    (0x108D, 0x108D, JoiningType::T), (0x109D, 0x109D, JoiningType::T),
+    (0x135D, 0x135F, JoiningType::T), (0x1712, 0x1714, JoiningType::T),
+    (0x1732, 0x1733, JoiningType::T), (0x1752, 0x1753, JoiningType::T),
+    (0x1772, 0x1773, JoiningType::T), (0x17B4, 0x17B5, JoiningType::T),
+    (0x17B7, 0x17BD, JoiningType::T), (0x17C6, 0x17C6, JoiningType::T),
+    (0x17C9, 0x17D3, JoiningType::T), (0x17DD, 0x17DD, JoiningType::T),
+    (0x1807, 0x1807, JoiningType::D), (0x180B, 0x180D, JoiningType::T),
+    (0x180F, 0x180F, JoiningType::T), (0x1820, 0x1878, JoiningType::D),
+    (0x1885, 0x1886, JoiningType::T), (0x1887, 0x18A8, JoiningType::D),
+    (0x18A9, 0x18A9, JoiningType::T), (0x18AA, 0x18AA, JoiningType::D),
+    (0x1920, 0x1922, JoiningType::T), (0x1927, 0x1928, JoiningType::T),
+    (0x1932, 0x1932, JoiningType::T), (0x1939, 0x193B, JoiningType::T),
+    (0x1A17, 0x1A18, JoiningType::T), (0x1A1B, 0x1A1B, JoiningType::T),
+    (0x1A56, 0x1A56, JoiningType::T), (0x1A58, 0x1A5E, JoiningType::T),
+    (0x1A60, 0x1A60, JoiningType::T), (0x1A62, 0x1A62, JoiningType::T),
+    (0x1A65, 0x1A6C, JoiningType::T), (0x1A73, 0x1A7C, JoiningType::T),
+    (0x1A7F, 0x1A7F, JoiningType::T), (0x1AB0, 0x1ACE, JoiningType::T),
+    (0x1B00, 0x1B03, JoiningType::T), (0x1B34, 0x1B34, JoiningType::T),
+    (0x1B36, 0x1B3A, JoiningType::T), (0x1B3C, 0x1B3C, JoiningType::T),
+    (0x1B42, 0x1B42, JoiningType::T), (0x1B6B, 0x1B73, JoiningType::T),
+    (0x1B80, 0x1B81, JoiningType::T), (0x1BA2, 0x1BA5, JoiningType::T),
+    (0x1BA8, 0x1BA9, JoiningType::T), (0x1BAB, 0x1BAD, JoiningType::T),
+    (0x1BE6, 0x1BE6, JoiningType::T), (0x1BE8, 0x1BE9, JoiningType::T),
+    (0x1BED, 0x1BED, JoiningType::T), (0x1BEF, 0x1BF1, JoiningType::T),
+    (0x1C2C, 0x1C33, JoiningType::T), (0x1C36, 0x1C37, JoiningType::T),
+    (0x1CD0, 0x1CD2, JoiningType::T), (0x1CD4, 0x1CE0, JoiningType::T),
+    (0x1CE2, 0x1CE8, JoiningType::T), (0x1CED, 0x1CED, JoiningType::T),
+    (0x1CF4, 0x1CF4, JoiningType::T), (0x1CF8, 0x1CF9, JoiningType::T),
+    (0x1DC0, 0x1DFF, JoiningType::T), (0x200B, 0x200B, JoiningType::T),
+    (0x200E, 0x200F, JoiningType::T), (0x202A, 0x202E, JoiningType::T),
+    (0x2060, 0x2064, JoiningType::T), (0x206A, 0x206F, JoiningType::T),
+    (0x20D0, 0x20F0, JoiningType::T), (0x2CEF, 0x2CF1, JoiningType::T),
+    (0x2D7F, 0x2D7F, JoiningType::T), (0x2DE0, 0x2DFF, JoiningType::T),
+    (0x302A, 0x302D, JoiningType::T), (0x3099, 0x309A, JoiningType::T),
+    (0xA66F, 0xA672, JoiningType::T), (0xA674, 0xA67D, JoiningType::T),
+    (0xA69E, 0xA69F, JoiningType::T), (0xA6F0, 0xA6F1, JoiningType::T),
+    (0xA802, 0xA802, JoiningType::T), (0xA806, 0xA806, JoiningType::T),
+    (0xA80B, 0xA80B, JoiningType::T), (0xA825, 0xA826, JoiningType::T),
+    (0xA82C, 0xA82C, JoiningType::T), (0xA840, 0xA871, JoiningType::D),
+    (0xA872, 0xA872, JoiningType::L), (0xA8C4, 0xA8C5, JoiningType::T),
+    (0xA8E0, 0xA8F1, JoiningType::T), (0xA8FF, 0xA8FF, JoiningType::T),
+    (0xA926, 0xA92D, JoiningType::T), (0xA947, 0xA951, JoiningType::T),
+    (0xA980, 0xA982, JoiningType::T), (0xA9B3, 0xA9B3, JoiningType::T),
+    (0xA9B6, 0xA9B9, JoiningType::T), (0xA9BC, 0xA9BD, JoiningType::T),
+    (0xA9E5, 0xA9E5, JoiningType::T), (0xAA29, 0xAA2E, JoiningType::T),
+    (0xAA31, 0xAA32, JoiningType::T), (0xAA35, 0xAA36, JoiningType::T),
+    (0xAA43, 0xAA43, JoiningType::T), (0xAA4C, 0xAA4C, JoiningType::T),
+    (0xAA7C, 0xAA7C, JoiningType::T), (0xAAB0, 0xAAB0, JoiningType::T),
+    (0xAAB2, 0xAAB4, JoiningType::T), (0xAAB7, 0xAAB8, JoiningType::T),
+    (0xAABE, 0xAABF, JoiningType::T), (0xAAC1, 0xAAC1, JoiningType::T),
+    (0xAAEC, 0xAAED, JoiningType::T), (0xAAF6, 0xAAF6, JoiningType::T),
+    (0xABE5, 0xABE5, JoiningType::T), (0xABE8, 0xABE8, JoiningType::T),
+    (0xABED, 0xABED, JoiningType::T), (0xFB1E, 0xFB1E, JoiningType::T),
+    (0xFE00, 0xFE0F, JoiningType::T), (0xFE20, 0xFE2F, JoiningType::T),
+    (0xFEFF, 0xFEFF, JoiningType::T), (0xFFF9, 0xFFFB, JoiningType::T),
+    (0x101FD, 0x101FD, JoiningType::T), (0x102E0, 0x102E0, JoiningType::T),
+    (0x10376, 0x1037A, JoiningType::T), (0x10A01, 0x10A03, JoiningType::T),
+    (0x10A05, 0x10A06, JoiningType::T), (0x10A0C, 0x10A0F, JoiningType::T),
+    (0x10A38, 0x10A3A, JoiningType::T), (0x10A3F, 0x10A3F, JoiningType::T),
+    (0x10AC0, 0x10AC4, JoiningType::D), (0x10AC5, 0x10AC5, JoiningType::R),
+    (0x10AC7, 0x10AC7, JoiningType::R), (0x10AC9, 0x10ACA, JoiningType::R),
+    (0x10ACD, 0x10ACD, JoiningType::L), (0x10ACE, 0x10AD2, JoiningType::R),
+    (0x10AD3, 0x10AD6, JoiningType::D), (0x10AD7, 0x10AD7, JoiningType::L),
+    (0x10AD8, 0x10ADC, JoiningType::D), (0x10ADD, 0x10ADD, JoiningType::R),
+    (0x10ADE, 0x10AE0, JoiningType::D), (0x10AE1, 0x10AE1, JoiningType::R),
+    (0x10AE4, 0x10AE4, JoiningType::R), (0x10AE5, 0x10AE6, JoiningType::T),
+    (0x10AEB, 0x10AEE, JoiningType::D), (0x10AEF, 0x10AEF, JoiningType::R),
+    (0x10B80, 0x10B80, JoiningType::D), (0x10B81, 0x10B81, JoiningType::R),
+    (0x10B82, 0x10B82, JoiningType::D), (0x10B83, 0x10B85, JoiningType::R),
+    (0x10B86, 0x10B88, JoiningType::D), (0x10B89, 0x10B89, JoiningType::R),
+    (0x10B8A, 0x10B8B, JoiningType::D), (0x10B8C, 0x10B8C, JoiningType::R),
+    (0x10B8D, 0x10B8D, JoiningType::D), (0x10B8E, 0x10B8F, JoiningType::R),
+    (0x10B90, 0x10B90, JoiningType::D), (0x10B91, 0x10B91, JoiningType::R),
+    (0x10BA9, 0x10BAC, JoiningType::R), (0x10BAD, 0x10BAE, JoiningType::D),
+    (0x10D00, 0x10D00, JoiningType::L), (0x10D01, 0x10D21, JoiningType::D),
+    (0x10D22, 0x10D22, JoiningType::R), (0x10D23, 0x10D23, JoiningType::D),
+    (0x10D24, 0x10D27, JoiningType::T), (0x10EAB, 0x10EAC, JoiningType::T),
+    (0x10EFD, 0x10EFF, JoiningType::T), (0x10F30, 0x10F32, JoiningType::D),
+    (0x10F33, 0x10F33, JoiningType::R), (0x10F34, 0x10F44, JoiningType::D),
+    (0x10F46, 0x10F50, JoiningType::T), (0x10F51, 0x10F53, JoiningType::D),
+    (0x10F54, 0x10F54, JoiningType::R), (0x10F70, 0x10F73, JoiningType::D),
+    (0x10F74, 0x10F75, JoiningType::R), (0x10F76, 0x10F81, JoiningType::D),
+    (0x10F82, 0x10F85, JoiningType::T), (0x10FB0, 0x10FB0, JoiningType::D),
+    (0x10FB2, 0x10FB3, JoiningType::D), (0x10FB4, 0x10FB6, JoiningType::R),
+    (0x10FB8, 0x10FB8, JoiningType::D), (0x10FB9, 0x10FBA, JoiningType::R),
+    (0x10FBB, 0x10FBC, JoiningType::D), (0x10FBD, 0x10FBD, JoiningType::R),
+    (0x10FBE, 0x10FBF, JoiningType::D), (0x10FC1, 0x10FC1, JoiningType::D),
+    (0x10FC2, 0x10FC3, JoiningType::R), (0x10FC4, 0x10FC4, JoiningType::D),
+    (0x10FC9, 0x10FC9, JoiningType::R), (0x10FCA, 0x10FCA, JoiningType::D),
+    (0x10FCB, 0x10FCB, JoiningType::L), (0x11001, 0x11001, JoiningType::T),
+    (0x11038, 0x11046, JoiningType::T), (0x11070, 0x11070, JoiningType::T),
+    (0x11073, 0x11074, JoiningType::T), (0x1107F, 0x11081, JoiningType::T),
+    (0x110B3, 0x110B6, JoiningType::T), (0x110B9, 0x110BA, JoiningType::T),
+    (0x110C2, 0x110C2, JoiningType::T), (0x11100, 0x11102, JoiningType::T),
+    (0x11127, 0x1112B, JoiningType::T), (0x1112D, 0x11134, JoiningType::T),
+    (0x11173, 0x11173, JoiningType::T), (0x11180, 0x11181, JoiningType::T),
+    (0x111B6, 0x111BE, JoiningType::T), (0x111C9, 0x111CC, JoiningType::T),
+    (0x111CF, 0x111CF, JoiningType::T), (0x1122F, 0x11231, JoiningType::T),
+    (0x11234, 0x11234, JoiningType::T), (0x11236, 0x11237, JoiningType::T),
+    (0x1123E, 0x1123E, JoiningType::T), (0x11241, 0x11241, JoiningType::T),
+    (0x112DF, 0x112DF, JoiningType::T), (0x112E3, 0x112EA, JoiningType::T),
+    (0x11300, 0x11301, JoiningType::T), (0x1133B, 0x1133C, JoiningType::T),
+    (0x11340, 0x11340, JoiningType::T), (0x11366, 0x1136C, JoiningType::T),
+    (0x11370, 0x11374, JoiningType::T), (0x11438, 0x1143F, JoiningType::T),
+    (0x11442, 0x11444, JoiningType::T), (0x11446, 0x11446, JoiningType::T),
+    (0x1145E, 0x1145E, JoiningType::T), (0x114B3, 0x114B8, JoiningType::T),
+    (0x114BA, 0x114BA, JoiningType::T), (0x114BF, 0x114C0, JoiningType::T),
+    (0x114C2, 0x114C3, JoiningType::T), (0x115B2, 0x115B5, JoiningType::T),
+    (0x115BC, 0x115BD, JoiningType::T), (0x115BF, 0x115C0, JoiningType::T),
+    (0x115DC, 0x115DD, JoiningType::T), (0x11633, 0x1163A, JoiningType::T),
+    (0x1163D, 0x1163D, JoiningType::T), (0x1163F, 0x11640, JoiningType::T),
+    (0x116AB, 0x116AB, JoiningType::T), (0x116AD, 0x116AD, JoiningType::T),
+    (0x116B0, 0x116B5, JoiningType::T), (0x116B7, 0x116B7, JoiningType::T),
+    (0x1171D, 0x1171F, JoiningType::T), (0x11722, 0x11725, JoiningType::T),
+    (0x11727, 0x1172B, JoiningType::T), (0x1182F, 0x11837, JoiningType::T),
+    (0x11839, 0x1183A, JoiningType::T), (0x1193B, 0x1193C, JoiningType::T),
+    (0x1193E, 0x1193E, JoiningType::T), (0x11943, 0x11943, JoiningType::T),
+    (0x119D4, 0x119D7, JoiningType::T), (0x119DA, 0x119DB, JoiningType::T),
+    (0x119E0, 0x119E0, JoiningType::T), (0x11A01, 0x11A0A, JoiningType::T),
+    (0x11A33, 0x11A38, JoiningType::T), (0x11A3B, 0x11A3E, JoiningType::T),
+    (0x11A47, 0x11A47, JoiningType::T), (0x11A51, 0x11A56, JoiningType::T),
+    (0x11A59, 0x11A5B, JoiningType::T), (0x11A8A, 0x11A96, JoiningType::T),
+    (0x11A98, 0x11A99, JoiningType::T), (0x11C30, 0x11C36, JoiningType::T),
+    (0x11C38, 0x11C3D, JoiningType::T), (0x11C3F, 0x11C3F, JoiningType::T),
+    (0x11C92, 0x11CA7, JoiningType::T), (0x11CAA, 0x11CB0, JoiningType::T),
+    (0x11CB2, 0x11CB3, JoiningType::T), (0x11CB5, 0x11CB6, JoiningType::T),
+    (0x11D31, 0x11D36, JoiningType::T), (0x11D3A, 0x11D3A, JoiningType::T),
+    (0x11D3C, 0x11D3D, JoiningType::T), (0x11D3F, 0x11D45, JoiningType::T),
+    (0x11D47, 0x11D47, JoiningType::T), (0x11D90, 0x11D91, JoiningType::T),
+    (0x11D95, 0x11D95, JoiningType::T), (0x11D97, 0x11D97, JoiningType::T),
+    (0x11EF3, 0x11EF4, JoiningType::T), (0x11F00, 0x11F01, JoiningType::T),
+    (0x11F36, 0x11F3A, JoiningType::T), (0x11F40, 0x11F40, JoiningType::T),
+    (0x11F42, 0x11F42, JoiningType::T), (0x13430, 0x13440, JoiningType::T),
+    (0x13447, 0x13455, JoiningType::T), (0x16AF0, 0x16AF4, JoiningType::T),
+    (0x16B30, 0x16B36, JoiningType::T), (0x16F4F, 0x16F4F, JoiningType::T),
+    (0x16F8F, 0x16F92, JoiningType::T), (0x16FE4, 0x16FE4, JoiningType::T),
+    (0x1BC9D, 0x1BC9E, JoiningType::T), (0x1BCA0, 0x1BCA3, JoiningType::T),
+    (0x1CF00, 0x1CF2D, JoiningType::T), (0x1CF30, 0x1CF46, JoiningType::T),
+    (0x1D167, 0x1D169, JoiningType::T), (0x1D173, 0x1D182, JoiningType::T),
+    (0x1D185, 0x1D18B, JoiningType::T), (0x1D1AA, 0x1D1AD, JoiningType::T),
+    (0x1D242, 0x1D244, JoiningType::T), (0x1DA00, 0x1DA36, JoiningType::T),
+    (0x1DA3B, 0x1DA6C, JoiningType::T), (0x1DA75, 0x1DA75, JoiningType::T),
+    (0x1DA84, 0x1DA84, JoiningType::T), (0x1DA9B, 0x1DA9F, JoiningType::T),
+    (0x1DAA1, 0x1DAAF, JoiningType::T), (0x1E000, 0x1E006, JoiningType::T),
+    (0x1E008, 0x1E018, JoiningType::T), (0x1E01B, 0x1E021, JoiningType::T),
+    (0x1E023, 0x1E024, JoiningType::T), (0x1E026, 0x1E02A, JoiningType::T),
+    (0x1E08F, 0x1E08F, JoiningType::T), (0x1E130, 0x1E136, JoiningType::T),
+    (0x1E2AE, 0x1E2AE, JoiningType::T), (0x1E2EC, 0x1E2EF, JoiningType::T),
+    (0x1E4EC, 0x1E4EF, JoiningType::T), (0x1E8D0, 0x1E8D6, JoiningType::T),
+    (0x1E900, 0x1E943, JoiningType::D), (0x1E944, 0x1E94B, JoiningType::T),
+    (0xE0001, 0xE0001, JoiningType::T), (0xE0020, 0xE007F, JoiningType::T),
+    (0xE0100, 0xE01EF, JoiningType::T),
+];
@@ -1,14 +1,24 @@
 //! # WHATWG URL Parser
 //!
-//! Parses URLs according to a simplified subset of the WHATWG URL Standard.
-//! Supports http/https schemes with default ports, host parsing (domain + IPv4),
-//! path normalization, query/fragment parsing, and percent-encoding.
+//! Parses URLs according to the WHATWG URL Standard: special and non-special
+//! schemes, relative resolution against a base, `file:` quirks, opaque paths
+//! (`mailto:`, `data:`), IDNA for domains (UTS #46 + Punycode), IPv4 number
+//! forms and IPv6 addresses. The setters of the URL API run the same state
+//! machine with a state override. Also provides percent-encoding and
+//! `application/x-www-form-urlencoded` helpers.
 //! **Zero external dependencies.**
 
 #![forbid(unsafe_code)]
 
+mod host;
+pub mod idna;
+#[cfg(test)]
+mod wpt;
+
 use core::fmt;
 
+pub use host::{Host, parse_host};
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Error
 // ─────────────────────────────────────────────────────────────────────────────
@@ -21,6 +31,7 @@ pub enum UrlError {
     InvalidHost,
     InvalidPort,
     InvalidIpv4,
+    InvalidIpv6,
     InvalidPath,
     InvalidPercentEncoding,
     Custom(String),
@@ -35,6 +46,7 @@ impl fmt::Display for UrlError {
             Self::InvalidHost => write!(f, "invalid host"),
             Self::InvalidPort => write!(f, "invalid port"),
             Self::InvalidIpv4 => write!(f, "invalid IPv4 address"),
+            Self::InvalidIpv6 => write!(f, "invalid IPv6 address"),
             Self::InvalidPath => write!(f, "invalid path"),
             Self::InvalidPercentEncoding => write!(f, "invalid percent encoding"),
             Self::Custom(msg) => write!(f, "{msg}"),
@@ -47,207 +59,258 @@ impl fmt::Display for UrlError {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// A parsed URL.
+///
+/// Components are stored serialized, exactly as they appear in `href`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Url {
     pub scheme: String,
     pub username: String,
     pub password: String,
+    /// The serialized host: a domain, a dotted IPv4 address, a bracketed
+    /// IPv6 address or an opaque host. Empty when there is no host.
     pub host: String,
+    /// Whether the URL has a host, possibly empty (`file:///x`, `foo://`),
+    /// as opposed to none at all (`mailto:x`, `foo:/x`).
+    pub has_host: bool,
+    /// The port, or `None` if absent or the scheme's default.
     pub port: Option<u16>,
+    /// The serialized path. Hierarchical paths are empty or start with `/`;
+    /// anything else is an opaque path (`mailto:`, `data:`).
     pub path: String,
     pub query: Option<String>,
     pub fragment: Option<String>,
 }
 
 impl Url {
-    /// Parse a URL string into a `Url` struct.
+    /// Parse an absolute URL string.
     pub fn parse(input: &str) -> Result<Self, UrlError> {
-        let input = input.trim();
-        if input.is_empty() {
+        Self::parse_with_base(input, None)
+    }
+
+    /// Parse `input`, resolving it against `base` if it is relative.
+    pub fn parse_with_base(input: &str, base: Option<&Url>) -> Result<Self, UrlError> {
+        if input.trim().is_empty() && base.is_none() {
             return Err(UrlError::EmptyInput);
         }
+        let base = base.map(Record::from_url);
+        let mut record = Record::default();
+        Parser::new(input, base.as_ref(), &mut record, None).run()?;
+        Ok(record.into_url())
+    }
 
-        // 1) Extract scheme
-        let (scheme, rest) = parse_scheme(input)?;
+    /// Resolve a relative URL against this base URL.
+    pub fn join(&self, relative: &str) -> Result<Url, UrlError> {
+        Self::parse_with_base(relative, Some(self))
+    }
 
-        // 2) Expect "//" (the ':' was already consumed by parse_scheme)
-        let rest = rest
-            .strip_prefix("//")
-            .ok_or(UrlError::InvalidScheme)?;
+    /// Whether the scheme is one of the special schemes (http, https, ws,
+    /// wss, ftp, file), which get hierarchical paths and domain hosts.
+    pub fn is_special(&self) -> bool {
+        is_special_scheme(&self.scheme)
+    }
 
-        // 3) Parse authority: [user[:password]@]host[:port]
-        let (authority, rest) = split_authority(rest);
+    /// Whether the path is opaque, i.e. not a `/`-separated list.
+    pub fn has_opaque_path(&self) -> bool {
+        !self.has_host && !self.path.starts_with('/')
+    }
 
-        let (userinfo, hostport) = if let Some(at_pos) = authority.rfind('@') {
-            (&authority[..at_pos], &authority[at_pos + 1..])
-        } else {
-            ("", authority)
-        };
+    /// Return the effective port (explicit or default for scheme).
+    pub fn effective_port(&self) -> Option<u16> {
+        self.port.or_else(|| default_port(&self.scheme))
+    }
 
-        let (username, password) = if !userinfo.is_empty() {
-            if let Some(colon) = userinfo.find(':') {
-                (
-                    percent_decode(&userinfo[..colon]),
-                    percent_decode(&userinfo[colon + 1..]),
-                )
-            } else {
-                (percent_decode(userinfo), String::new())
+    /// Return the serialized origin: `scheme://host[:port]` for http(s),
+    /// ws(s) and ftp, the inner URL's origin for `blob:`, and `"null"` (an
+    /// opaque origin) for everything else.
+    pub fn origin(&self) -> String {
+        match self.scheme.as_str() {
+            "http" | "https" | "ws" | "wss" | "ftp" => {
+                let mut s = format!("{}://{}", self.scheme, self.host);
+                if let Some(p) = self.port {
+                    s.push(':');
+                    s.push_str(&p.to_string());
+                }
+                s
             }
-        } else {
-            (String::new(), String::new())
-        };
+            "blob" => match Url::parse(&self.path) {
+                Ok(inner) if inner.scheme == "http" || inner.scheme == "https" => inner.origin(),
+                _ => "null".to_string(),
+            },
+            _ => "null".to_string(),
+        }
+    }
 
-        let (host, port) = parse_host_port(hostport, &scheme)?;
+    // ── URL API getters ──────────────────────────────────────────────────
 
-        // 4) Parse path, query, fragment
-        let (path_str, rest) = split_at_char(rest, '?');
-        let (query_str, fragment_str) = if let Some(r) = rest {
-            let (q, f) = split_at_char(r, '#');
-            (Some(q.to_string()), f.map(|s| s.to_string()))
-        } else {
-            // Check if path itself has a fragment
-            let (p, f) = split_at_char(path_str, '#');
-            if f.is_some() {
-                // Re-parse: path had a # but no ?
-                let path = normalize_path(p);
-                return Ok(Url {
-                    scheme,
-                    username,
-                    password,
-                    host,
-                    port,
-                    path,
-                    query: None,
-                    fragment: f.map(|s| s.to_string()),
-                });
-            }
-            (None, None)
-        };
+    /// `href`: the full serialization.
+    pub fn href(&self) -> String {
+        self.to_string()
+    }
 
-        let path = normalize_path(path_str);
+    /// `protocol`: the scheme followed by `:`.
+    pub fn protocol(&self) -> String {
+        format!("{}:", self.scheme)
+    }
 
-        Ok(Url {
-            scheme,
-            username,
-            password,
-            host,
-            port,
-            path,
-            query: query_str,
-            fragment: fragment_str,
-        })
+    /// `host`: the host and, if present, the port.
+    pub fn host_with_port(&self) -> String {
+        match self.port {
+            Some(p) => format!("{}:{}", self.host, p),
+            None => self.host.clone(),
+        }
     }
 
-    /// Return the effective port (explicit or default for scheme).
-    pub fn effective_port(&self) -> Option<u16> {
-        self.port.or_else(|| default_port(&self.scheme))
+    /// `search`: `?` and the query, or empty if the query is null or empty.
+    pub fn search(&self) -> String {
+        match self.query.as_deref() {
+            Some(q) if !q.is_empty() => format!("?{q}"),
+            _ => String::new(),
+        }
     }
 
-    /// Return the origin: `scheme://host[:port]`
-    pub fn origin(&self) -> String {
-        let mut s = format!("{}://{}", self.scheme, self.host);
-        if let Some(p) = self.port {
-            if Some(p) != default_port(&self.scheme) {
-                s.push(':');
-                s.push_str(&p.to_string());
-            }
+    /// `hash`: `#` and the fragment, or empty if the fragment is null or
+    /// empty.
+    pub fn hash(&self) -> String {
+        match self.fragment.as_deref() {
+            Some(f) if !f.is_empty() => format!("#{f}"),
+            _ => String::new(),
         }
-        s
     }
 
-    /// Resolve a relative URL against this base URL.
-    pub fn join(&self, relative: &str) -> Result<Url, UrlError> {
-        let relative = relative.trim();
-        if relative.is_empty() {
-            return Ok(self.clone());
+    // ── URL API setters ──────────────────────────────────────────────────
+    //
+    // Setters re-run the parser on this URL from the relevant state. Invalid
+    // input is ignored, though like the standard, components set before the
+    // failure are kept.
+
+    /// `href` setter: replaces the URL if `value` parses.
+    pub fn set_href(&mut self, value: &str) -> Result<(), UrlError> {
+        *self = Url::parse(value)?;
+        Ok(())
+    }
+
+    /// `protocol` setter.
+    pub fn set_protocol(&mut self, value: &str) {
+        self.run_override(&format!("{value}:"), State::SchemeStart);
+    }
+
+    /// `username` setter.
+    pub fn set_username(&mut self, value: &str) {
+        if self.cannot_have_credentials_or_port() {
+            return;
         }
+        self.username = utf8_percent_encode(value, in_userinfo_set);
+    }
 
-        // Absolute URL?
-        if relative.contains("://") {
-            return Url::parse(relative);
+    /// `password` setter.
+    pub fn set_password(&mut self, value: &str) {
+        if self.cannot_have_credentials_or_port() {
+            return;
         }
+        self.password = utf8_percent_encode(value, in_userinfo_set);
+    }
 
-        // Protocol-relative
-        if relative.starts_with("//") {
-            let full = format!("{}:{}", self.scheme, relative);
-            return Url::parse(&full);
+    /// `host` setter: host and optional port.
+    pub fn set_host(&mut self, value: &str) {
+        if !self.has_opaque_path() {
+            self.run_override(value, State::Host);
         }
+    }
 
-        // Absolute path
-        if relative.starts_with('/') {
-            let mut url = self.clone();
-            let (path_str, rest) = split_at_char(relative, '?');
-            url.path = normalize_path(path_str);
-            if let Some(r) = rest {
-                let (q, f) = split_at_char(r, '#');
-                url.query = Some(q.to_string());
-                url.fragment = f.map(|s| s.to_string());
-            } else {
-                let (p, f) = split_at_char(path_str, '#');
-                url.path = normalize_path(p);
-                url.query = None;
-                url.fragment = f.map(|s| s.to_string());
-            }
-            return Ok(url);
+    /// `hostname` setter: host only.
+    pub fn set_hostname(&mut self, value: &str) {
+        if !self.has_opaque_path() {
+            self.run_override(value, State::Hostname);
         }
+    }
 
-        // Query-only relative
-        if relative.starts_with('?') {
-            let mut url = self.clone();
-            let (q, f) = split_at_char(&relative[1..], '#');
-            url.query = Some(q.to_string());
-            url.fragment = f.map(|s| s.to_string());
-            return Ok(url);
+    /// `port` setter; the empty string removes the port.
+    pub fn set_port(&mut self, value: &str) {
+        if self.cannot_have_credentials_or_port() {
+            return;
+        }
+        if value.is_empty() {
+            self.port = None;
+        } else {
+            self.run_override(value, State::Port);
         }
+    }
 
-        // Fragment-only relative
-        if relative.starts_with('#') {
-            let mut url = self.clone();
-            url.fragment = Some(relative[1..].to_string());
-            return Ok(url);
+    /// `pathname` setter.
+    pub fn set_pathname(&mut self, value: &str) {
+        if self.has_opaque_path() {
+            return;
         }
+        let mut record = Record::from_url(self);
+        record.path.clear();
+        let _ = Parser::new(value, None, &mut record, Some(State::PathStart)).run();
+        *self = record.into_url();
+    }
 
-        // Relative path: merge with base
-        let mut url = self.clone();
-        let base_dir = if let Some(last_slash) = self.path.rfind('/') {
-            &self.path[..=last_slash]
-        } else {
-            "/"
-        };
-        let merged = format!("{}{}", base_dir, relative);
-        let (path_str, rest) = split_at_char(&merged, '?');
-        if let Some(r) = rest {
-            let (q, f) = split_at_char(r, '#');
-            url.path = normalize_path(path_str);
-            url.query = Some(q.to_string());
-            url.fragment = f.map(|s| s.to_string());
-        } else {
-            let (p, f) = split_at_char(path_str, '#');
-            url.path = normalize_path(p);
-            url.query = None;
-            url.fragment = f.map(|s| s.to_string());
+    /// `search` setter; the empty string removes the query.
+    pub fn set_search(&mut self, value: &str) {
+        if value.is_empty() {
+            self.query = None;
+            self.strip_trailing_spaces_from_opaque_path();
+            return;
+        }
+        let input = value.strip_prefix('?').unwrap_or(value);
+        self.query = Some(String::new());
+        self.run_override(input, State::Query);
+    }
+
+    /// `hash` setter; the empty string removes the fragment.
+    pub fn set_hash(&mut self, value: &str) {
+        if value.is_empty() {
+            self.fragment = None;
+            self.strip_trailing_spaces_from_opaque_path();
+            return;
         }
-        Ok(url)
+        let input = value.strip_prefix('#').unwrap_or(value);
+        self.fragment = Some(String::new());
+        self.run_override(input, State::Fragment);
+    }
+
+    fn cannot_have_credentials_or_port(&self) -> bool {
+        self.host.is_empty() || self.scheme == "file"
+    }
+
+    fn strip_trailing_spaces_from_opaque_path(&mut self) {
+        if self.has_opaque_path() && self.fragment.is_none() && self.query.is_none() {
+            let len = self.path.trim_end_matches(' ').len();
+            self.path.truncate(len);
+        }
+    }
+
+    fn run_override(&mut self, input: &str, state: State) {
+        let mut record = Record::from_url(self);
+        // Failure only means the rest of the input was rejected.
+        let _ = Parser::new(input, None, &mut record, Some(state)).run();
+        *self = record.into_url();
     }
 }
 
 impl fmt::Display for Url {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}://", self.scheme)?;
-        if !self.username.is_empty() {
-            write!(f, "{}", percent_encode_userinfo(&self.username))?;
-            if !self.password.is_empty() {
-                write!(f, ":{}", percent_encode_userinfo(&self.password))?;
+        write!(f, "{}:", self.scheme)?;
+        if self.has_host {
+            f.write_str("//")?;
+            if !self.username.is_empty() || !self.password.is_empty() {
+                f.write_str(&self.username)?;
+                if !self.password.is_empty() {
+                    write!(f, ":{}", self.password)?;
+                }
+                f.write_str("@")?;
             }
-            write!(f, "@")?;
-        }
-        write!(f, "{}", self.host)?;
-        if let Some(p) = self.port {
-            if Some(p) != default_port(&self.scheme) {
+            f.write_str(&self.host)?;
+            if let Some(p) = self.port {
                 write!(f, ":{}", p)?;
             }
+        } else if self.path.starts_with("//") {
+            // Keep `scheme:/.//path` from reparsing as `scheme://path`.
+            f.write_str("/.")?;
         }
-        write!(f, "{}", self.path)?;
+        f.write_str(&self.path)?;
         if let Some(ref q) = self.query {
             write!(f, "?{}", q)?;
         }
@@ -259,129 +322,635 @@ impl fmt::Display for Url {
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Parsing helpers
+// Parser
 // ─────────────────────────────────────────────────────────────────────────────
 
-fn parse_scheme(input: &str) -> Result<(String, &str), UrlError> {
-    // Scheme = ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )
-    let mut end = 0;
-    for (i, c) in input.char_indices() {
-        if i == 0 {
-            if !c.is_ascii_alphabetic() {
-                return Err(UrlError::MissingScheme);
+/// A URL record while it is being parsed: the host is still structured and
+/// the path is still a list of segments.
+#[derive(Debug, Clone, Default)]
+struct Record {
+    scheme: String,
+    username: String,
+    password: String,
+    host: Option<Host>,
+    port: Option<u16>,
+    path: Vec<String>,
+    /// Set instead of `path` for URLs with an opaque path.
+    opaque_path: Option<String>,
+    query: Option<String>,
+    fragment: Option<String>,
+}
+
+impl Record {
+    fn from_url(url: &Url) -> Self {
+        let host = url.has_host.then(|| {
+            if url.host.is_empty() {
+                Host::Empty
+            } else {
+                Host::Opaque(url.host.clone())
             }
-        } else if c == ':' {
-            end = i;
-            break;
-        } else if !c.is_ascii_alphanumeric() && c != '+' && c != '-' && c != '.' {
-            return Err(UrlError::InvalidScheme);
+        });
+        let (path, opaque_path) = if url.has_opaque_path() {
+            (Vec::new(), Some(url.path.clone()))
+        } else if url.path.is_empty() {
+            (Vec::new(), None)
+        } else {
+            (url.path[1..].split('/').map(str::to_string).collect(), None)
+        };
+        Self {
+            scheme: url.scheme.clone(),
+            username: url.username.clone(),
+            password: url.password.clone(),
+            host,
+            port: url.port,
+            path,
+            opaque_path,
+            query: url.query.clone(),
+            fragment: url.fragment.clone(),
         }
     }
-    if end == 0 {
-        return Err(UrlError::MissingScheme);
-    }
-    let scheme = input[..end].to_ascii_lowercase();
-    Ok((scheme, &input[end + 1..]))
-}
 
-fn split_authority(input: &str) -> (&str, &str) {
-    // Authority ends at '/', '?', '#', or end of string
-    for (i, c) in input.char_indices() {
-        if c == '/' || c == '?' || c == '#' {
-            return (&input[..i], &input[i..]);
+    fn into_url(self) -> Url {
+        let path = match self.opaque_path {
+            Some(p) => p,
+            None => self.path.iter().map(|s| format!("/{s}")).collect(),
+        };
+        Url {
+            scheme: self.scheme,
+            username: self.username,
+            password: self.password,
+            host: self.host.as_ref().map(Host::to_string).unwrap_or_default(),
+            has_host: self.host.is_some(),
+            port: self.port,
+            path,
+            query: self.query,
+            fragment: self.fragment,
         }
     }
-    (input, "")
-}
 
-fn parse_host_port(input: &str, scheme: &str) -> Result<(String, Option<u16>), UrlError> {
-    if input.is_empty() {
-        return Err(UrlError::InvalidHost);
+    fn is_special(&self) -> bool {
+        is_special_scheme(&self.scheme)
     }
 
-    // Check for IPv4 with port: last colon separates host and port
-    // But only if host doesn't start with '[' (IPv6 bracket notation not fully supported)
-    let (host_str, port_str) = if let Some(colon) = input.rfind(':') {
-        let potential_port = &input[colon + 1..];
-        // Only treat as port if all digits
-        if !potential_port.is_empty() && potential_port.chars().all(|c| c.is_ascii_digit()) {
-            (&input[..colon], Some(potential_port))
-        } else {
-            (input, None)
-        }
-    } else {
-        (input, None)
-    };
-
-    let host = host_str.to_ascii_lowercase();
-    if host.is_empty() {
-        return Err(UrlError::InvalidHost);
+    fn has_credentials(&self) -> bool {
+        !self.username.is_empty() || !self.password.is_empty()
     }
 
-    // Validate host (domain name or IPv4)
-    validate_host(&host)?;
-
-    let port = if let Some(ps) = port_str {
-        let p: u16 = ps.parse().map_err(|_| UrlError::InvalidPort)?;
-        // Omit if it's the default port
-        if Some(p) == default_port(scheme) {
-            None
-        } else {
-            Some(p)
+    fn shorten_path(&mut self) {
+        if self.scheme == "file"
+            && self.path.len() == 1
+            && is_normalized_windows_drive_letter(&self.path[0])
+        {
+            return;
         }
-    } else {
-        None
-    };
+        self.path.pop();
+    }
+}
 
-    Ok((host, port))
+/// States of the basic URL parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    SchemeStart,
+    Scheme,
+    NoScheme,
+    SpecialRelativeOrAuthority,
+    PathOrAuthority,
+    Relative,
+    RelativeSlash,
+    SpecialAuthoritySlashes,
+    SpecialAuthorityIgnoreSlashes,
+    Authority,
+    Host,
+    Hostname,
+    Port,
+    File,
+    FileSlash,
+    FileHost,
+    PathStart,
+    Path,
+    OpaquePath,
+    Query,
+    Fragment,
 }
 
-fn validate_host(host: &str) -> Result<(), UrlError> {
-    if host.is_empty() {
-        return Err(UrlError::InvalidHost);
-    }
+/// The basic URL parser (URL Standard §4.4).
+struct Parser<'a> {
+    input: Vec<char>,
+    base: Option<&'a Record>,
+    url: &'a mut Record,
+    state_override: Option<State>,
+}
 
-    // Try as IPv4
-    if host.chars().next().unwrap_or(' ').is_ascii_digit() {
-        // Looks like IPv4 — validate
-        if parse_ipv4(host).is_some() {
-            return Ok(());
+impl<'a> Parser<'a> {
+    fn new(
+        input: &str,
+        base: Option<&'a Record>,
+        url: &'a mut Record,
+        state_override: Option<State>,
+    ) -> Self {
+        let input = if state_override.is_none() {
+            input.trim_matches(|c: char| c <= ' ')
+        } else {
+            input
+        };
+        let input = input
+            .chars()
+            .filter(|&c| !matches!(c, '\t' | '\n' | '\r'))
+            .collect();
+        Self {
+            input,
+            base,
+            url,
+            state_override,
         }
-        // Could be a domain starting with a digit; allow it
     }
 
-    // Domain name validation: labels separated by '.'
-    for label in host.split('.') {
-        if label.is_empty() {
-            // Trailing dot is OK (FQDN), but not double dots
-            continue;
-        }
-        if label.len() > 63 {
-            return Err(UrlError::InvalidHost);
-        }
-        for c in label.chars() {
-            if !c.is_ascii_alphanumeric() && c != '-' && c != '_' {
-                return Err(UrlError::InvalidHost);
+    /// Whether the input from `p` on starts with `s`.
+    fn remaining_starts_with(&self, p: usize, s: &str) -> bool {
+        s.chars()
+            .enumerate()
+            .all(|(i, c)| self.input.get(p + i) == Some(&c))
+    }
+
+    fn run(self) -> Result<(), UrlError> {
+        let mut state = self.state_override.unwrap_or(State::SchemeStart);
+        let mut buffer = String::new();
+        let mut at_sign_seen = false;
+        let mut inside_brackets = false;
+        let mut password_token_seen = false;
+        // `p` may step one before the start; the loop pre-increments.
+        let mut p: isize = 0;
+
+        loop {
+            let c = (p >= 0)
+                .then(|| self.input.get(p as usize).copied())
+                .flatten();
+            let next = (p + 1) as usize;
+            let special = self.url.is_special();
+            match state {
+                State::SchemeStart => {
+                    if let Some(c) = c.filter(char::is_ascii_alphabetic) {
+                        buffer.push(c.to_ascii_lowercase());
+                        state = State::Scheme;
+                    } else if self.state_override.is_none() {
+                        state = State::NoScheme;
+                        p -= 1;
+                    } else {
+                        return Err(UrlError::InvalidScheme);
+                    }
+                }
+                State::Scheme => match c {
+                    Some(c) if c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.') => {
+                        buffer.push(c.to_ascii_lowercase());
+                    }
+                    Some(':') => {
+                        if self.state_override.is_some() {
+                            let url = &self.url;
+                            if url.is_special() != is_special_scheme(&buffer)
+                                || ((url.has_credentials() || url.port.is_some())
+                                    && buffer == "file")
+                                || (url.scheme == "file" && url.host == Some(Host::Empty))
+                            {
+                                return Ok(());
+                            }
+                        }
+                        self.url.scheme = std::mem::take(&mut buffer);
+                        if self.state_override.is_some() {
+                            if self.url.port == default_port(&self.url.scheme) {
+                                self.url.port = None;
+                            }
+                            return Ok(());
+                        }
+                        if self.url.scheme == "file" {
+                            state = State::File;
+                        } else if self.url.is_special()
+                            && self.base.is_some_and(|b| b.scheme == self.url.scheme)
+                        {
+                            state = State::SpecialRelativeOrAuthority;
+                        } else if self.url.is_special() {
+                            state = State::SpecialAuthoritySlashes;
+                        } else if self.remaining_starts_with(next, "/") {
+                            state = State::PathOrAuthority;
+                            p += 1;
+                        } else {
+                            self.url.opaque_path = Some(String::new());
+                            state = State::OpaquePath;
+                        }
+                    }
+                    _ if self.state_override.is_none() => {
+                        buffer.clear();
+                        state = State::NoScheme;
+                        p = -1;
+                    }
+                    _ => return Err(UrlError::InvalidScheme),
+                },
+                State::NoScheme => {
+                    let base = self.base.ok_or(UrlError::MissingScheme)?;
+                    if base.opaque_path.is_some() {
+                        if c != Some('#') {
+                            return Err(UrlError::MissingScheme);
+                        }
+                        self.url.scheme = base.scheme.clone();
+                        self.url.opaque_path = base.opaque_path.clone();
+                        self.url.query = base.query.clone();
+                        self.url.fragment = Some(String::new());
+                        state = State::Fragment;
+                    } else if base.scheme != "file" {
+                        state = State::Relative;
+                        p -= 1;
+                    } else {
+                        state = State::File;
+                        p -= 1;
+                    }
+                }
+                State::SpecialRelativeOrAuthority => {
+                    if c == Some('/') && self.remaining_starts_with(next, "/") {
+                        state = State::SpecialAuthorityIgnoreSlashes;
+                        p += 1;
+                    } else {
+                        state = State::Relative;
+                        p -= 1;
+                    }
+                }
+                State::PathOrAuthority => {
+                    if c == Some('/') {
+                        state = State::Authority;
+                    } else {
+                        state = State::Path;
+                        p -= 1;
+                    }
+                }
+                State::Relative => {
+                    let base = self.base.ok_or(UrlError::MissingScheme)?;
+                    self.url.scheme = base.scheme.clone();
+                    let special = self.url.is_special();
+                    if c == Some('/') || (special && c == Some('\\')) {
+                        state = State::RelativeSlash;
+                    } else {
+                        self.url.username = base.username.clone();
+                        self.url.password = base.password.clone();
+                        self.url.host = base.host.clone();
+                        self.url.port = base.port;
+                        self.url.path = base.path.clone();
+                        self.url.query = base.query.clone();
+                        match c {
+                            Some('?') => {
+                                self.url.query = Some(String::new());
+                                state = State::Query;
+                            }
+                            Some('#') => {
+                                self.url.fragment = Some(String::new());
+                                state = State::Fragment;
+                            }
+                            Some(_) => {
+                                self.url.query = None;
+                                self.url.shorten_path();
+                                state = State::Path;
+                                p -= 1;
+                            }
+                            None => {}
+                        }
+                    }
+                }
+                State::RelativeSlash => {
+                    if special && matches!(c, Some('/' | '\\')) {
+                        state = State::SpecialAuthorityIgnoreSlashes;
+                    } else if c == Some('/') {
+                        state = State::Authority;
+                    } else {
+                        let base = self.base.ok_or(UrlError::MissingScheme)?;
+                        self.url.username = base.username.clone();
+                        self.url.password = base.password.clone();
+                        self.url.host = base.host.clone();
+                        self.url.port = base.port;
+                        state = State::Path;
+                        p -= 1;
+                    }
+                }
+                State::SpecialAuthoritySlashes => {
+                    if c == Some('/') && self.remaining_starts_with(next, "/") {
+                        p += 1;
+                    } else {
+                        p -= 1;
+                    }
+                    state = State::SpecialAuthorityIgnoreSlashes;
+                }
+                State::SpecialAuthorityIgnoreSlashes => {
+                    if !matches!(c, Some('/' | '\\')) {
+                        state = State::Authority;
+                        p -= 1;
+                    }
+                }
+                State::Authority => match c {
+                    Some('@') => {
+                        if at_sign_seen {
+                            buffer.insert_str(0, "%40");
+                        }
+                        at_sign_seen = true;
+                        for bc in buffer.chars() {
+                            if bc == ':' && !password_token_seen {
+                                password_token_seen = true;
+                                continue;
+                            }
+                            let target = if password_token_seen {
+                                &mut self.url.password
+                            } else {
+                                &mut self.url.username
+                            };
+                            percent_encode_char(bc, in_userinfo_set, target);
+                        }
+                        buffer.clear();
+                    }
+                    None | Some('/' | '?' | '#') => {
+                        if at_sign_seen && buffer.is_empty() {
+                            return Err(UrlError::InvalidHost);
+                        }
+                        p -= buffer.chars().count() as isize + 1;
+                        buffer.clear();
+                        state = State::Host;
+                    }
+                    Some('\\') if special => {
+                        if at_sign_seen && buffer.is_empty() {
+                            return Err(UrlError::InvalidHost);
+                        }
+                        p -= buffer.chars().count() as isize + 1;
+                        buffer.clear();
+                        state = State::Host;
+                    }
+                    Some(c) => buffer.push(c),
+                },
+                State::Host | State::Hostname => {
+                    let at_end =
+                        matches!(c, None | Some('/' | '?' | '#')) || (special && c == Some('\\'));
+                    if self.state_override.is_some() && self.url.scheme == "file" {
+                        p -= 1;
+                        state = State::FileHost;
+                    } else if c == Some(':') && !inside_brackets {
+                        if buffer.is_empty() {
+                            return Err(UrlError::InvalidHost);
+                        }
+                        if self.state_override == Some(State::Hostname) {
+                            return Err(UrlError::InvalidHost);
+                        }
+                        self.url.host = Some(parse_host(&buffer, !special)?);
+                        buffer.clear();
+                        state = State::Port;
+                    } else if at_end {
+                        p -= 1;
+                        if special && buffer.is_empty() {
+                            return Err(UrlError::InvalidHost);
+                        }
+                        if self.state_override.is_some()
+                            && buffer.is_empty()
+                            && (self.url.has_credentials() || self.url.port.is_some())
+                        {
+                            return Err(UrlError::InvalidHost);
+                        }
+                        self.url.host = Some(parse_host(&buffer, !special)?);
+                        buffer.clear();
+                        state = State::PathStart;
+                        if self.state_override.is_some() {
+                            return Ok(());
+                        }
+                    } else if let Some(c) = c {
+                        if c == '[' {
+                            inside_brackets = true;
+                        } else if c == ']' {
+                            inside_brackets = false;
+                        }
+                        buffer.push(c);
+                    }
+                }
+                State::Port => {
+                    if let Some(d) = c.filter(char::is_ascii_digit) {
+                        buffer.push(d);
+                    } else if matches!(c, None | Some('/' | '?' | '#'))
+                        || (special && c == Some('\\'))
+                        || self.state_override.is_some()
+                    {
+                        if !buffer.is_empty() {
+                            let digits = buffer.trim_start_matches('0');
+                            let port: u16 = if digits.is_empty() {
+                                0
+                            } else if digits.len() > 5 {
+                                return Err(UrlError::InvalidPort);
+                            } else {
+                                digits.parse().map_err(|_| UrlError::InvalidPort)?
+                            };
+                            self.url.port =
+                                (Some(port) != default_port(&self.url.scheme)).then_some(port);
+                            buffer.clear();
+                        }
+                        if self.state_override.is_some() {
+                            return Ok(());
+                        }
+                        state = State::PathStart;
+                        p -= 1;
+                    } else {
+                        return Err(UrlError::InvalidPort);
+                    }
+                }
+                State::File => {
+                    self.url.scheme = "file".to_string();
+                    self.url.host = Some(Host::Empty);
+                    if matches!(c, Some('/' | '\\')) {
+                        state = State::FileSlash;
+                    } else if let Some(base) = self.base.filter(|b| b.scheme == "file") {
+                        self.url.host = base.host.clone();
+                        self.url.path = base.path.clone();
+                        self.url.query = base.query.clone();
+                        match c {
+                            Some('?') => {
+                                self.url.query = Some(String::new());
+                                state = State::Query;
+                            }
+                            Some('#') => {
+                                self.url.fragment = Some(String::new());
+                                state = State::Fragment;
+                            }
+                            Some(_) => {
+                                self.url.query = None;
+                                if starts_with_windows_drive_letter(&self.input[p as usize..]) {
+                                    self.url.path.clear();
+                                } else {
+                                    self.url.shorten_path();
+                                }
+                                state = State::Path;
+                                p -= 1;
+                            }
+                            None => {}
+                        }
+                    } else {
+                        state = State::Path;
+                        p -= 1;
+                    }
+                }
+                State::FileSlash => {
+                    if matches!(c, Some('/' | '\\')) {
+                        state = State::FileHost;
+                    } else {
+                        if let Some(base) = self.base.filter(|b| b.scheme == "file") {
+                            self.url.host = base.host.clone();
+                            let rest = &self.input[p.max(0) as usize..];
+                            if !starts_with_windows_drive_letter(rest)
+                                && base
+                                    .path
+                                    .first()
+                                    .is_some_and(|s| is_normalized_windows_drive_letter(s))
+                            {
+                                self.url.path.push(base.path[0].clone());
+                            }
+                        }
+                        state = State::Path;
+                        p -= 1;
+                    }
+                }
+                State::FileHost => {
+                    if matches!(c, None | Some('/' | '\\' | '?' | '#')) {
+                        p -= 1;
+                        if self.state_override.is_none() && is_windows_drive_letter(&buffer) {
+                            // The "host" is really a drive letter: keep it in
+                            // the buffer as the first path segment.
+                            state = State::Path;
+                        } else if buffer.is_empty() {
+                            self.url.host = Some(Host::Empty);
+                            if self.state_override.is_some() {
+                                return Ok(());
+                            }
+                            state = State::PathStart;
+                        } else {
+                            let mut host = parse_host(&buffer, !special)?;
+                            if host == Host::Domain("localhost".to_string()) {
+                                host = Host::Empty;
+                            }
+                            self.url.host = Some(host);
+                            if self.state_override.is_some() {
+                                return Ok(());
+                            }
+                            buffer.clear();
+                            state = State::PathStart;
+                        }
+                    } else if let Some(c) = c {
+                        buffer.push(c);
+                    }
+                }
+                State::PathStart => {
+                    if special {
+                        state = State::Path;
+                        if !matches!(c, Some('/' | '\\')) {
+                            p -= 1;
+                        }
+                    } else if self.state_override.is_none() && c == Some('?') {
+                        self.url.query = Some(String::new());
+                        state = State::Query;
+                    } else if self.state_override.is_none() && c == Some('#') {
+                        self.url.fragment = Some(String::new());
+                        state = State::Fragment;
+                    } else if c.is_some() {
+                        state = State::Path;
+                        if c != Some('/') {
+                            p -= 1;
+                        }
+                    } else if self.state_override.is_some() && self.url.host.is_none() {
+                        self.url.path.push(String::new());
+                    }
+                }
+                State::Path => {
+                    let slash = c == Some('/') || (special && c == Some('\\'));
+                    if c.is_none()
+                        || slash
+                        || (self.state_override.is_none() && matches!(c, Some('?' | '#')))
+                    {
+                        if is_double_dot_segment(&buffer) {
+                            self.url.shorten_path();
+                            if !slash {
+                                self.url.path.push(String::new());
+                            }
+                        } else if is_single_dot_segment(&buffer) {
+                            if !slash {
+                                self.url.path.push(String::new());
+                            }
+                        } else {
+                            if self.url.scheme == "file"
+                                && self.url.path.is_empty()
+                                && is_windows_drive_letter(&buffer)
+                            {
+                                buffer.replace_range(1.., ":");
+                            }
+                            self.url.path.push(std::mem::take(&mut buffer));
+                        }
+                        buffer.clear();
+                        match c {
+                            Some('?') if self.state_override.is_none() => {
+                                self.url.query = Some(String::new());
+                                state = State::Query;
+                            }
+                            Some('#') if self.state_override.is_none() => {
+                                self.url.fragment = Some(String::new());
+                                state = State::Fragment;
+                            }
+                            _ => {}
+                        }
+                    } else if let Some(c) = c {
+                        percent_encode_char(c, in_path_set, &mut buffer);
+                    }
+                }
+                State::OpaquePath => {
+                    let path = self.url.opaque_path.get_or_insert_with(String::new);
+                    match c {
+                        Some('?') => {
+                            self.url.query = Some(String::new());
+                            state = State::Query;
+                        }
+                        Some('#') => {
+                            self.url.fragment = Some(String::new());
+                            state = State::Fragment;
+                        }
+                        Some(c) => percent_encode_char(c, in_c0_control_set, path),
+                        None => {}
+                    }
+                }
+                State::Query => {
+                    if (self.state_override.is_none() && c == Some('#')) || c.is_none() {
+                        let set = if special {
+                            in_special_query_set
+                        } else {
+                            in_query_set
+                        };
+                        let query = self.url.query.get_or_insert_with(String::new);
+                        for bc in buffer.chars() {
+                            percent_encode_char(bc, set, query);
+                        }
+                        buffer.clear();
+                        if c == Some('#') {
+                            self.url.fragment = Some(String::new());
+                            state = State::Fragment;
+                        }
+                    } else if let Some(c) = c {
+                        buffer.push(c);
+                    }
+                }
+                State::Fragment => {
+                    if let Some(c) = c {
+                        let fragment = self.url.fragment.get_or_insert_with(String::new);
+                        percent_encode_char(c, in_fragment_set, fragment);
+                    }
+                }
+            }
+            if p >= 0 && p as usize >= self.input.len() {
+                return Ok(());
             }
+            p += 1;
         }
     }
-    Ok(())
 }
 
-fn parse_ipv4(s: &str) -> Option<[u8; 4]> {
-    let parts: Vec<&str> = s.split('.').collect();
-    if parts.len() != 4 {
-        return None;
-    }
-    let mut octets = [0u8; 4];
-    for (i, part) in parts.iter().enumerate() {
-        let n: u16 = part.parse().ok()?;
-        if n > 255 {
-            return None;
-        }
-        octets[i] = n as u8;
-    }
-    Some(octets)
+// ─────────────────────────────────────────────────────────────────────────────
+// Parsing helpers
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn is_special_scheme(scheme: &str) -> bool {
+    matches!(scheme, "http" | "https" | "ws" | "wss" | "ftp" | "file")
 }
 
 fn default_port(scheme: &str) -> Option<u16> {
@@ -395,69 +964,135 @@ fn default_port(scheme: &str) -> Option<u16> {
     }
 }
 
-fn split_at_char<'a>(s: &'a str, ch: char) -> (&'a str, Option<&'a str>) {
-    if let Some(pos) = s.find(ch) {
-        (&s[..pos], Some(&s[pos + 1..]))
-    } else {
-        (s, None)
-    }
+fn is_single_dot_segment(s: &str) -> bool {
+    s == "." || s.eq_ignore_ascii_case("%2e")
 }
 
-/// Normalize a URL path by resolving `.` and `..` segments.
-fn normalize_path(path: &str) -> String {
-    if path.is_empty() {
-        return "/".to_string();
-    }
+fn is_double_dot_segment(s: &str) -> bool {
+    matches!(
+        s.to_ascii_lowercase().as_str(),
+        ".." | ".%2e" | "%2e." | "%2e%2e"
+    )
+}
 
-    let path = if path.starts_with('/') { path } else { path };
-    let mut segments: Vec<&str> = Vec::new();
+/// An ASCII letter followed by `:` or `|`.
+fn is_windows_drive_letter(s: &str) -> bool {
+    let b = s.as_bytes();
+    b.len() == 2 && b[0].is_ascii_alphabetic() && (b[1] == b':' || b[1] == b'|')
+}
 
-    for segment in path.split('/') {
-        match segment {
-            "." | "" => {
-                // Current directory or empty (double slash) — skip (keep leading empty)
-                if segments.is_empty() {
-                    segments.push("");
-                }
-            }
-            ".." => {
-                if segments.len() > 1 {
-                    segments.pop();
-                }
-            }
-            s => segments.push(s),
-        }
-    }
+/// An ASCII letter followed by `:`.
+fn is_normalized_windows_drive_letter(s: &str) -> bool {
+    is_windows_drive_letter(s) && s.as_bytes()[1] == b':'
+}
 
-    let result = segments.join("/");
-    if result.is_empty() || !result.starts_with('/') {
-        format!("/{}", result)
-    } else {
-        result
-    }
+/// A drive letter followed by the end of input or a path/query/fragment
+/// delimiter.
+fn starts_with_windows_drive_letter(c: &[char]) -> bool {
+    c.len() >= 2
+        && c[0].is_ascii_alphabetic()
+        && matches!(c[1], ':' | '|')
+        && (c.len() == 2 || matches!(c[2], '/' | '\\' | '?' | '#'))
+}
+
+/// Resolve `.` and `..` segments in an absolute path, the way the parser
+/// does for special URLs.
+pub fn normalize_path(path: &str) -> String {
+    let mut record = Record {
+        scheme: "http".to_string(),
+        ..Record::default()
+    };
+    let _ = Parser::new(path, None, &mut record, Some(State::PathStart)).run();
+    record.into_url().path
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Percent encoding / decoding
 // ─────────────────────────────────────────────────────────────────────────────
 
-/// Decode percent-encoded sequences in a string.
-pub fn percent_decode(input: &str) -> String {
+/// C0 controls and everything above `~`.
+fn in_c0_control_set(b: u8) -> bool {
+    !(0x20..=0x7E).contains(&b)
+}
+
+fn in_fragment_set(b: u8) -> bool {
+    in_c0_control_set(b) || matches!(b, b' ' | b'"' | b'<' | b'>' | b'`')
+}
+
+fn in_query_set(b: u8) -> bool {
+    in_c0_control_set(b) || matches!(b, b' ' | b'"' | b'#' | b'<' | b'>')
+}
+
+fn in_special_query_set(b: u8) -> bool {
+    in_query_set(b) || b == b'\''
+}
+
+fn in_path_set(b: u8) -> bool {
+    in_query_set(b) || matches!(b, b'?' | b'`' | b'{' | b'}')
+}
+
+fn in_userinfo_set(b: u8) -> bool {
+    in_path_set(b) || matches!(b, b'/' | b':' | b';' | b'=' | b'@' | b'['..=b'^' | b'|')
+}
+
+fn in_component_set(b: u8) -> bool {
+    in_userinfo_set(b) || matches!(b, b'$'..=b'&' | b'+' | b',')
+}
+
+fn in_form_urlencoded_set(b: u8) -> bool {
+    in_component_set(b) || matches!(b, b'!' | b'\''..=b')' | b'~')
+}
+
+/// UTF-8 percent-encode `c` if any of its bytes is in `in_set`.
+fn percent_encode_char(c: char, in_set: fn(u8) -> bool, out: &mut String) {
+    let mut utf8 = [0u8; 4];
+    for &b in c.encode_utf8(&mut utf8).as_bytes() {
+        if in_set(b) {
+            push_percent_encoded(b, out);
+        } else {
+            out.push(b as char);
+        }
+    }
+}
+
+fn push_percent_encoded(b: u8, out: &mut String) {
+    out.push('%');
+    out.push(HEX_UPPER[(b >> 4) as usize] as char);
+    out.push(HEX_UPPER[(b & 0xF) as usize] as char);
+}
+
+/// UTF-8 percent-encode every character of `input` using `in_set`.
+fn utf8_percent_encode(input: &str, in_set: fn(u8) -> bool) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        percent_encode_char(c, in_set, &mut out);
+    }
+    out
+}
+
+/// Percent-decode to bytes; `%` not followed by two hex digits is kept.
+fn percent_decode_bytes(input: &str) -> Vec<u8> {
     let bytes = input.as_bytes();
     let mut out = Vec::with_capacity(bytes.len());
     let mut i = 0;
     while i < bytes.len() {
-        if bytes[i] == b'%' && i + 2 < bytes.len() {
-            if let (Some(hi), Some(lo)) = (hex_val(bytes[i + 1]), hex_val(bytes[i + 2])) {
-                out.push(hi << 4 | lo);
-                i += 3;
-                continue;
-            }
+        if bytes[i] == b'%'
+            && i + 2 < bytes.len()
+            && let (Some(hi), Some(lo)) = (hex_val(bytes[i + 1]), hex_val(bytes[i + 2]))
+        {
+            out.push(hi << 4 | lo);
+            i += 3;
+            continue;
         }
         out.push(bytes[i]);
         i += 1;
     }
-    String::from_utf8_lossy(&out).into_owned()
+    out
+}
+
+/// Decode percent-encoded sequences in a string.
+pub fn percent_decode(input: &str) -> String {
+    String::from_utf8_lossy(&percent_decode_bytes(input)).into_owned()
 }
 
 /// Percent-encode a string for use in the path component.
@@ -474,24 +1109,7 @@ pub fn percent_encode(input: &str) -> String {
         {
             out.push(b as char);
         } else {
-            out.push('%');
-            out.push(HEX_UPPER[(b >> 4) as usize] as char);
-            out.push(HEX_UPPER[(b & 0xF) as usize] as char);
-        }
-    }
-    out
-}
-
-/// Percent-encode for userinfo component (more restrictive).
-fn percent_encode_userinfo(input: &str) -> String {
-    let mut out = String::with_capacity(input.len());
-    for &b in input.as_bytes() {
-        if b.is_ascii_alphanumeric() || b == b'-' || b == b'.' || b == b'_' || b == b'~' {
-            out.push(b as char);
-        } else {
-            out.push('%');
-            out.push(HEX_UPPER[(b >> 4) as usize] as char);
-            out.push(HEX_UPPER[(b & 0xF) as usize] as char);
+            push_percent_encoded(b, &mut out);
         }
     }
     out
@@ -508,27 +1126,51 @@ fn hex_val(b: u8) -> Option<u8> {
     }
 }
 
-/// Parse query string into key-value pairs.
+/// Parse query string into key-value pairs
+/// (`application/x-www-form-urlencoded` parsing: `+` is a space).
 pub fn parse_query_string(query: &str) -> Vec<(String, String)> {
     if query.is_empty() {
         return Vec::new();
     }
+    let decode = |s: &str| percent_decode(&s.replace('+', " "));
     query
         .split('&')
         .filter(|s| !s.is_empty())
         .map(|pair| {
             if let Some(eq) = pair.find('=') {
-                (
-                    percent_decode(&pair[..eq]),
-                    percent_decode(&pair[eq + 1..]),
-                )
+                (decode(&pair[..eq]), decode(&pair[eq + 1..]))
             } else {
-                (percent_decode(pair), String::new())
+                (decode(pair), String::new())
             }
         })
         .collect()
 }
 
+/// Serialize key-value pairs as `application/x-www-form-urlencoded`, the
+/// inverse of [`parse_query_string`].
+pub fn serialize_query_string<K: AsRef<str>, V: AsRef<str>>(pairs: &[(K, V)]) -> String {
+    let mut out = String::new();
+    for (i, (name, value)) in pairs.iter().enumerate() {
+        if i > 0 {
+            out.push('&');
+        }
+        form_urlencode(name.as_ref(), &mut out);
+        out.push('=');
+        form_urlencode(value.as_ref(), &mut out);
+    }
+    out
+}
+
+fn form_urlencode(s: &str, out: &mut String) {
+    for c in s.chars() {
+        if c == ' ' {
+            out.push('+');
+        } else {
+            percent_encode_char(c, in_form_urlencoded_set, out);
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -743,11 +1385,70 @@ mod tests {
         .unwrap();
         assert_eq!(url.scheme, "https");
         assert_eq!(url.username, "user");
-        assert_eq!(url.password, "p@ss");
+        assert_eq!(url.password, "p%40ss");
         assert_eq!(url.host, "www.example.com");
         assert_eq!(url.port, Some(8443));
         assert_eq!(url.path, "/api/v1/search");
         assert_eq!(url.query, Some("q=rust%20lang&page=1".to_string()));
         assert_eq!(url.fragment, Some("results".to_string()));
     }
+
+    #[test]
+    fn test_join_dot_segments_and_scheme_relative() {
+        let base = Url::parse("http://example.com/a/b/c").unwrap();
+        assert_eq!(
+            base.join("../d").unwrap().to_string(),
+            "http://example.com/a/d"
+        );
+        assert_eq!(
+            base.join("//other.org/x").unwrap().to_string(),
+            "http://other.org/x"
+        );
+        assert_eq!(
+            base.join("").unwrap().to_string(),
+            "http://example.com/a/b/c"
+        );
+    }
+
+    #[test]
+    fn test_opaque_and_non_special() {
+        let url = Url::parse("mailto:someone@example.com").unwrap();
+        assert!(url.has_opaque_path());
+        assert_eq!(url.path, "someone@example.com");
+        assert_eq!(url.origin(), "null");
+
+        let url = Url::parse("foo://host/p").unwrap();
+        assert!(!url.is_special());
+        assert_eq!(url.host, "host");
+        assert_eq!(
+            Url::parse("file:///tmp/x").unwrap().to_string(),
+            "file:///tmp/x"
+        );
+    }
+
+    #[test]
+    fn test_setters() {
+        let mut url = Url::parse("http://example.com/path?q#f").unwrap();
+        url.set_protocol("https");
+        url.set_host("other.org:8443");
+        url.set_pathname("/a b");
+        url.set_search("x=1");
+        url.set_hash("");
+        assert_eq!(url.to_string(), "https://other.org:8443/a%20b?x=1");
+
+        url.set_port("443");
+        assert_eq!(url.port, None);
+        url.set_protocol("mailto");
+        assert_eq!(url.scheme, "https");
+    }
+
+    #[test]
+    fn test_serialize_query_string() {
+        let pairs = [("a b", "c&d"), ("é", "~*")];
+        let encoded = serialize_query_string(&pairs);
+        assert_eq!(encoded, "a+b=c%26d&%C3%A9=%7E*");
+        let decoded = parse_query_string(&encoded);
+        assert_eq!(decoded[0], ("a b".to_string(), "c&d".to_string()));
+        assert_eq!(decoded[1], ("é".to_string(), "~*".to_string()));
+    }
 }
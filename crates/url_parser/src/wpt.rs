@@ -0,0 +1,295 @@
+//! Runs the web-platform-tests URL suites vendored under `wpt/`:
+//! `urltestdata.json` (parsing against an optional base) and
+//! `setters_tests.json` (the URL API setters).
+
+use super::Url;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Minimal JSON reader
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number,
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn str(&self, key: &str) -> Option<&str> {
+        match self.get(key) {
+            Some(Json::Str(s)) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+struct Reader<'a> {
+    src: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn parse(src: &str) -> Json {
+        let mut reader = Reader {
+            src: src.as_bytes(),
+            pos: 0,
+        };
+        reader.value()
+    }
+
+    fn skip_ws(&mut self) {
+        while self.src.get(self.pos).is_some_and(u8::is_ascii_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, b: u8) -> bool {
+        self.skip_ws();
+        if self.src.get(self.pos) == Some(&b) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn value(&mut self) -> Json {
+        self.skip_ws();
+        match self.src[self.pos] {
+            b'{' => {
+                self.pos += 1;
+                let mut members = Vec::new();
+                while !self.eat(b'}') {
+                    self.eat(b',');
+                    self.skip_ws();
+                    let key = self.string();
+                    assert!(self.eat(b':'), "expected ':' at {}", self.pos);
+                    members.push((key, self.value()));
+                }
+                Json::Object(members)
+            }
+            b'[' => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                while !self.eat(b']') {
+                    self.eat(b',');
+                    items.push(self.value());
+                }
+                Json::Array(items)
+            }
+            b'"' => Json::Str(self.string()),
+            b'n' => self.keyword("null", Json::Null),
+            b't' => self.keyword("true", Json::Bool(true)),
+            b'f' => self.keyword("false", Json::Bool(false)),
+            _ => {
+                while self
+                    .src
+                    .get(self.pos)
+                    .is_some_and(|b| b"+-.eE0123456789".contains(b))
+                {
+                    self.pos += 1;
+                }
+                Json::Number
+            }
+        }
+    }
+
+    fn keyword(&mut self, word: &str, value: Json) -> Json {
+        assert!(self.src[self.pos..].starts_with(word.as_bytes()));
+        self.pos += word.len();
+        value
+    }
+
+    fn hex4(&mut self) -> u32 {
+        let digits = std::str::from_utf8(&self.src[self.pos..self.pos + 4]).unwrap();
+        self.pos += 4;
+        u32::from_str_radix(digits, 16).unwrap()
+    }
+
+    /// A string literal. Lone surrogates become U+FFFD, as they do when a
+    /// JavaScript string is converted to a USVString.
+    fn string(&mut self) -> String {
+        assert_eq!(self.src[self.pos], b'"');
+        self.pos += 1;
+        let mut bytes = Vec::new();
+        loop {
+            let b = self.src[self.pos];
+            self.pos += 1;
+            match b {
+                b'"' => break,
+                b'\\' => {
+                    let e = self.src[self.pos];
+                    self.pos += 1;
+                    let c = match e {
+                        b'n' => '\n',
+                        b't' => '\t',
+                        b'r' => '\r',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'u' => {
+                            let unit = self.hex4();
+                            let cp = if (0xD800..0xDC00).contains(&unit)
+                                && self.src[self.pos..].starts_with(b"\\u")
+                            {
+                                let save = self.pos;
+                                self.pos += 2;
+                                let low = self.hex4();
+                                if (0xDC00..0xE000).contains(&low) {
+                                    0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00)
+                                } else {
+                                    self.pos = save;
+                                    0xFFFD
+                                }
+                            } else {
+                                unit
+                            };
+                            char::from_u32(cp).unwrap_or('\u{FFFD}')
+                        }
+                        other => other as char,
+                    };
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                }
+                _ => bytes.push(b),
+            }
+        }
+        String::from_utf8(bytes).unwrap()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Runners
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The URL API attribute `name` of `url`.
+fn attribute(url: &Url, name: &str) -> String {
+    match name {
+        "href" => url.href(),
+        "origin" => url.origin(),
+        "protocol" => url.protocol(),
+        "username" => url.username.clone(),
+        "password" => url.password.clone(),
+        "host" => url.host_with_port(),
+        "hostname" => url.host.clone(),
+        "port" => url.port.map(|p| p.to_string()).unwrap_or_default(),
+        "pathname" => url.path.clone(),
+        "search" => url.search(),
+        "hash" => url.hash(),
+        _ => panic!("unknown attribute {name}"),
+    }
+}
+
+const ATTRIBUTES: [&str; 11] = [
+    "href", "origin", "protocol", "username", "password", "host", "hostname", "port", "pathname",
+    "search", "hash",
+];
+
+#[test]
+fn urltestdata() {
+    let Json::Array(cases) = Reader::parse(include_str!("../wpt/urltestdata.json")) else {
+        panic!("expected an array");
+    };
+    let mut failures = Vec::new();
+    let mut count = 0;
+    for case in cases.iter().filter(|c| matches!(c, Json::Object(_))) {
+        count += 1;
+        let input = case.str("input").unwrap();
+        let base = match case.str("base") {
+            Some(base) => match Url::parse(base) {
+                Ok(base) => Some(base),
+                Err(e) => {
+                    failures.push(format!("base {base:?} of {input:?}: {e}"));
+                    continue;
+                }
+            },
+            None => None,
+        };
+        let result = Url::parse_with_base(input, base.as_ref());
+        let expect_failure = matches!(case.get("failure"), Some(Json::Bool(true)));
+        match (result, expect_failure) {
+            (Ok(url), true) => failures.push(format!("{input:?} parsed as {url} but should fail")),
+            (Err(e), false) => failures.push(format!("{input:?} failed: {e}")),
+            (Ok(url), false) => {
+                for name in ATTRIBUTES {
+                    let Some(expected) = case.str(name) else {
+                        continue;
+                    };
+                    let actual = attribute(&url, name);
+                    if actual != expected {
+                        failures.push(format!("{input:?} {name}: {actual:?} != {expected:?}"));
+                    }
+                }
+            }
+            (Err(_), true) => {}
+        }
+    }
+    assert!(count > 600, "only {count} test cases");
+    assert!(
+        failures.is_empty(),
+        "{}/{count} failures:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn setters_tests() {
+    let Json::Object(groups) = Reader::parse(include_str!("../wpt/setters_tests.json")) else {
+        panic!("expected an object");
+    };
+    let mut failures = Vec::new();
+    for (setter, cases) in groups.iter().filter(|(name, _)| name != "comment") {
+        let Json::Array(cases) = cases else { continue };
+        for case in cases {
+            let href = case.str("href").unwrap();
+            let value = case.str("new_value").unwrap();
+            let mut url = Url::parse(href).unwrap();
+            match setter.as_str() {
+                "href" => {
+                    let _ = url.set_href(value);
+                }
+                "protocol" => url.set_protocol(value),
+                "username" => url.set_username(value),
+                "password" => url.set_password(value),
+                "host" => url.set_host(value),
+                "hostname" => url.set_hostname(value),
+                "port" => url.set_port(value),
+                "pathname" => url.set_pathname(value),
+                "search" => url.set_search(value),
+                "hash" => url.set_hash(value),
+                _ => panic!("unknown setter {setter}"),
+            }
+            let Some(Json::Object(expected)) = case.get("expected") else {
+                continue;
+            };
+            for (name, expected) in expected {
+                let Json::Str(expected) = expected else {
+                    continue;
+                };
+                let actual = attribute(&url, name);
+                if &actual != expected {
+                    failures.push(format!(
+                        "{href:?}.{setter} = {value:?}: {name} {actual:?} != {expected:?}"
+                    ));
+                }
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} failures:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,1277 @@
+//! Host objects that expose documents to page scripts.
+//!
+//! Scripts see `window` and `document` globals and element objects with
+//! `innerHTML`, `outerHTML` and `insertAdjacentHTML`. The natives reach the
+//! trees through `vm.host`, which holds a [`ScriptHost`] while a script
+//! runs (see [`crate::browser::run_js_in`]). Window, document and element
+//! objects record the window they belong to in `__window`; an element also
+//! records which node it stands for in `__node_index` / `__node_generation`.
+//!
+//! A frame's `window.parent` and `window.top`, and an `<iframe>`'s
+//! `contentWindow`, reach the other windows of the frame tree. Their
+//! documents are only handed to scripts of the same origin; anything else
+//! is a `SecurityError`.
+//!
+//! `URL` and `URLSearchParams` objects record which of the host's
+//! [`JsUrl`]s or [`UrlSearchParams`] they stand for in `__url` /
+//! `__params`; a URL's `searchParams` shares its `__url`.
+//!
+//! The VM has no typed arrays, so plain arrays of numbers stand in for
+//! byte buffers: `new Uint8Array(n)` makes an array of `n` zeros, which
+//! `crypto.getRandomValues` fills with bytes.
+//!
+//! `crypto.subtle` methods return promises: objects with `then` and
+//! `catch` that record `__state`, `__result` and the `__reactions` waiting
+//! on them. They are fulfilled with an `ArrayBuffer` as a byte array, or a
+//! `CryptoKey` as an object whose `__key` indexes [`ScriptHost::subtle`],
+//! and rejected with a `DOMException` as a `{name, message}` object.
+//! Reactions run as microtasks, queued in the `__microtasks` global until
+//! [`run_microtasks`] drains it. `await` drains the queue at once, as the
+//! VM cannot suspend a script.
+
+use arena::GenIndex;
+use dom::{Dom, NodeId};
+use js_builtins::crypto::{AlgorithmParams, CryptoError, SubtleCrypto};
+use js_builtins::promise::{PromiseRuntime, PromiseState, PromiseValue};
+use js_builtins::url::{JsUrl, UrlSearchParams};
+use js_gc::GcObject;
+use js_vm::{NativeFn, Value, VmError, VM};
+
+use crate::browser::DOC_ROOT;
+use crate::frames::Sandbox;
+
+type NativeResult = Result<Value, VmError>;
+
+/// The documents a running script can reach.
+#[derive(Default)]
+pub struct ScriptHost {
+    /// The script's own window first, then any others it may reach.
+    pub windows: Vec<Window>,
+    /// What the script's `URL` objects hold.
+    pub urls: Vec<JsUrl>,
+    /// What the script's own `URLSearchParams` objects hold.
+    pub search_params: Vec<UrlSearchParams>,
+    /// The keys behind the script's `CryptoKey` objects.
+    pub subtle: SubtleCrypto,
+    /// Where `crypto.subtle` settles its results.
+    pub promises: PromiseRuntime,
+}
+
+/// A browsing context as scripts see it.
+pub struct Window {
+    pub dom: Dom,
+    /// `scheme://host[:port]`, or `None` for an opaque origin, which is
+    /// same-origin with nothing but itself.
+    pub origin: Option<String>,
+    /// Index of the parent window, for a frame.
+    pub parent: Option<usize>,
+    /// The `<iframe>` in the parent's document that shows this window.
+    pub frame_element: Option<NodeId>,
+}
+
+impl ScriptHost {
+    /// A host for a top-level document.
+    pub fn new(dom: Dom, origin: Option<String>) -> Self {
+        Self {
+            windows: vec![Window {
+                dom,
+                origin,
+                parent: None,
+                frame_element: None,
+            }],
+            ..Self::default()
+        }
+    }
+
+    /// A host for a document shown by the `<iframe>` `frame_element` of
+    /// `ancestors[0]`. The ancestors go from the parent up to the top, each
+    /// shown by an `<iframe>` in the next, and keep their order after the
+    /// frame's own window.
+    pub fn for_frame(dom: Dom, origin: Option<String>, frame_element: NodeId, mut ancestors: Vec<Window>) -> Self {
+        let count = ancestors.len();
+        for (i, window) in ancestors.iter_mut().enumerate() {
+            window.parent = (i + 1 < count).then_some(i + 2);
+        }
+        let mut host = Self::new(dom, origin);
+        host.windows[0].parent = Some(1);
+        host.windows[0].frame_element = Some(frame_element);
+        host.windows.extend(ancestors);
+        host
+    }
+
+    /// Documents that scripts created for the `<iframe>`s of the script's
+    /// own document by touching their `contentWindow`, keyed by element.
+    pub fn take_frame_documents(&mut self) -> Vec<(NodeId, Dom)> {
+        let mut documents = Vec::new();
+        for window in self.windows.iter_mut().skip(1) {
+            if window.parent == Some(0)
+                && let Some(element) = window.frame_element
+            {
+                documents.push((element, std::mem::take(&mut window.dom)));
+            }
+        }
+        documents
+    }
+
+    fn is_same_origin(&self, a: usize, b: usize) -> bool {
+        a == b
+            || matches!(
+                (&self.windows[a].origin, &self.windows[b].origin),
+                (Some(x), Some(y)) if x == y
+            )
+    }
+}
+
+/// Define the `window`, `self`, `parent`, `top` and `document` globals.
+/// `document` has `write`, `writeln`, `getElementById`, `body` and
+/// `documentElement`; `window` has `document`, `parent`, `top`, `self` and
+/// `frameElement`. Call with `vm.host` already set.
+pub fn install_document(vm: &mut VM) {
+    vm.register_native("document.write", write);
+    vm.register_native("document.writeln", writeln);
+    vm.register_native("document.getElementById", get_element_by_id);
+    vm.register_native("document.body", body);
+    vm.register_native("document.documentElement", document_element);
+    vm.register_native("window.document", window_document);
+    vm.register_native("window.parent", window_parent);
+    vm.register_native("window.top", window_top);
+    vm.register_native("window.frameElement", frame_element);
+    vm.register_native("element.innerHTML", get_inner_html);
+    vm.register_native("element.innerHTML=", set_inner_html);
+    vm.register_native("element.outerHTML", get_outer_html);
+    vm.register_native("element.outerHTML=", set_outer_html);
+    vm.register_native("element.insertAdjacentHTML", insert_adjacent_html);
+    vm.register_native("element.contentWindow", content_window);
+    vm.register_native("element.contentDocument", content_document);
+
+    let window = window_value(vm, 0);
+    vm.set_global("window", window);
+    vm.set_global("self", window);
+    for (name, getter) in [("parent", window_parent as js_vm::NativeFn), ("top", window_top)] {
+        let previous = std::mem::replace(&mut vm.this, window);
+        if let Ok(value) = getter(vm, &[]) {
+            vm.set_global(name, value);
+        }
+        vm.this = previous;
+    }
+    let document = document_value(vm, 0);
+    vm.set_global("document", document);
+}
+
+/// Define the `crypto` global and the `Uint8Array` constructor.
+pub fn install_crypto(vm: &mut VM) {
+    vm.register_native("Uint8Array", new_uint8_array);
+    vm.register_native("crypto.getRandomValues", get_random_values);
+    vm.register_native("crypto.randomUUID", random_uuid);
+
+    let crypto = Value::ptr(vm.heap.alloc_object().0);
+    for method in ["getRandomValues", "randomUUID"] {
+        let native = vm.native_function_value(&format!("crypto.{method}"));
+        let _ = vm.set_property(crypto, method, native);
+    }
+
+    vm.register_native("promise.then", promise_then);
+    vm.register_native("promise.catch", promise_catch);
+    vm.register_native("__await", await_value);
+
+    let subtle = Value::ptr(vm.heap.alloc_object().0);
+    for (i, (method, _)) in SUBTLE_METHODS.iter().enumerate() {
+        let name = format!("subtle.{method}");
+        vm.register_native(&name, SUBTLE_PROMISES[i]);
+        let native = vm.native_function_value(&name);
+        let _ = vm.set_property(subtle, method, native);
+    }
+    let _ = vm.set_property(crypto, "subtle", subtle);
+    vm.set_global("crypto", crypto);
+}
+
+/// Define the `URL` and `URLSearchParams` constructors.
+pub fn install_url(vm: &mut VM) {
+    vm.register_native("URL", new_url);
+    vm.register_native("URLSearchParams", new_search_params);
+    for (i, attribute) in URL_ATTRIBUTES.iter().enumerate() {
+        vm.register_native(&format!("url.{attribute}"), URL_GETTERS[i]);
+        vm.register_native(&format!("url.{attribute}="), URL_SETTERS[i]);
+    }
+    vm.register_native("url.toString", url_href);
+    vm.register_native("params.size", params_size);
+    for (method, native) in PARAMS_METHODS {
+        vm.register_native(&format!("params.{method}"), *native);
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// window
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The object for window `index`, the same one each time it is asked for.
+fn window_value(vm: &mut VM, index: usize) -> Value {
+    let key = format!("__window_{index}");
+    let cached = vm.get_global_value(&key);
+    if cached.is_ptr() {
+        return cached;
+    }
+    let obj = vm.heap.alloc_object();
+    let value = Value::ptr(obj.0);
+    let _ = vm.set_property(value, "__window", Value::number(index as f64));
+    let _ = vm.set_property(value, "self", value);
+    let _ = vm.set_property(value, "window", value);
+    for getter in ["document", "parent", "top", "frameElement"] {
+        vm.define_accessor(obj, getter, &format!("window.{getter}"), None);
+    }
+    vm.set_global(&key, value);
+    value
+}
+
+fn window_document(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    let window = this_window(vm);
+    check_same_origin(vm, window)?;
+    Ok(document_value(vm, window))
+}
+
+/// A top-level window is its own parent.
+fn window_parent(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    let window = this_window(vm);
+    let parent = host(vm)?.windows[window].parent.unwrap_or(window);
+    Ok(window_value(vm, parent))
+}
+
+fn window_top(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    let mut window = this_window(vm);
+    let host = host(vm)?;
+    while let Some(parent) = host.windows[window].parent {
+        window = parent;
+    }
+    Ok(window_value(vm, window))
+}
+
+/// The `<iframe>` showing the window, or `null` at the top and when the
+/// parent is cross-origin.
+fn frame_element(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    let window = this_window(vm);
+    let host = host(vm)?;
+    let (Some(parent), Some(element)) = (host.windows[window].parent, host.windows[window].frame_element) else {
+        return Ok(Value::null());
+    };
+    if !host.is_same_origin(0, parent) {
+        return Ok(Value::null());
+    }
+    Ok(element_value(vm, parent, element))
+}
+
+/// Refuse access to window `target`'s contents from a script of another
+/// origin.
+fn check_same_origin(vm: &mut VM, target: usize) -> Result<(), VmError> {
+    let host = host(vm)?;
+    if host.is_same_origin(0, target) {
+        return Ok(());
+    }
+    let origin = host.windows[0].origin.as_deref().unwrap_or("null");
+    Err(VmError {
+        message: format!("SecurityError: Blocked a frame with origin \"{origin}\" from accessing a cross-origin frame."),
+    })
+}
+
+/// The window the receiver of the current native belongs to; the
+/// script's own when it doesn't say.
+fn this_window(vm: &mut VM) -> usize {
+    let this = vm.this;
+    match vm.get_property(this, "__window") {
+        Ok(index) if index.is_number() => index.as_f64() as usize,
+        _ => 0,
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// document
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The `document` object of window `index`, the same one each time.
+fn document_value(vm: &mut VM, index: usize) -> Value {
+    let key = format!("__document_{index}");
+    let cached = vm.get_global_value(&key);
+    if cached.is_ptr() {
+        return cached;
+    }
+    let document = vm.heap.alloc_object();
+    let value = Value::ptr(document.0);
+    let _ = vm.set_property(value, "__window", Value::number(index as f64));
+    for method in ["write", "writeln", "getElementById"] {
+        let method_value = vm.native_function_value(&format!("document.{method}"));
+        let _ = vm.set_property(value, method, method_value);
+    }
+    for getter in ["body", "documentElement"] {
+        vm.define_accessor(document, getter, &format!("document.{getter}"), None);
+    }
+    vm.set_global(&key, value);
+    value
+}
+
+/// `document.write`: append the arguments to `vm.output`, which the parser
+/// inserts after the running script. Another window's document is not
+/// being parsed, so the markup goes at the end of its `<body>` instead.
+fn write(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let window = this_window(vm);
+    let text: String = args.iter().map(|&arg| js_string(vm, arg)).collect();
+    if window == 0 {
+        vm.output.push(text);
+        return Ok(Value::undefined());
+    }
+    let dom = dom(vm, window)?;
+    if let Some(&body) = dom.get_elements_by_tag(DOC_ROOT, "body").first() {
+        html::insert_adjacent_html(dom, body, html::AdjacentPosition::BeforeEnd, &text).map_err(|e| VmError {
+            message: e.to_string(),
+        })?;
+    }
+    Ok(Value::undefined())
+}
+
+fn writeln(vm: &mut VM, args: &[Value]) -> NativeResult {
+    write(vm, args)?;
+    let newline = string_value(vm, "\n".to_string());
+    write(vm, &[newline])
+}
+
+fn get_element_by_id(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let window = this_window(vm);
+    let id = args.first().map(|&arg| js_string(vm, arg)).unwrap_or_default();
+    let found = dom(vm, window)?.get_element_by_id(DOC_ROOT, &id);
+    Ok(found.map_or(Value::null(), |node| element_value(vm, window, node)))
+}
+
+fn body(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    let window = this_window(vm);
+    let found = dom(vm, window)?.get_elements_by_tag(DOC_ROOT, "body").first().copied();
+    Ok(found.map_or(Value::null(), |node| element_value(vm, window, node)))
+}
+
+fn document_element(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    let window = this_window(vm);
+    let dom = dom(vm, window)?;
+    let found = dom
+        .children(DOC_ROOT)
+        .into_iter()
+        .find(|&n| dom.nodes.get(n).is_some_and(|node| node.is_element()));
+    Ok(found.map_or(Value::null(), |node| element_value(vm, window, node)))
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Elements
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A new object standing for the element `node` of window `window`.
+fn element_value(vm: &mut VM, window: usize, node: NodeId) -> Value {
+    let obj = vm.heap.alloc_object();
+    let value = Value::ptr(obj.0);
+    let _ = vm.set_property(value, "__window", Value::number(window as f64));
+    let _ = vm.set_property(value, "__node_index", Value::number(node.index as f64));
+    let _ = vm.set_property(value, "__node_generation", Value::number(node.generation as f64));
+    for property in ["innerHTML", "outerHTML"] {
+        let getter = format!("element.{property}");
+        let setter = format!("element.{property}=");
+        vm.define_accessor(obj, property, &getter, Some(&setter));
+    }
+    let method = vm.native_function_value("element.insertAdjacentHTML");
+    let _ = vm.set_property(value, "insertAdjacentHTML", method);
+    let is_iframe = host(vm)
+        .ok()
+        .and_then(|host| host.windows[window].dom.nodes.get(node))
+        .and_then(|n| n.as_element())
+        .is_some_and(|e| e.tag_name == "iframe");
+    if is_iframe {
+        for getter in ["contentWindow", "contentDocument"] {
+            vm.define_accessor(obj, getter, &format!("element.{getter}"), None);
+        }
+    }
+    value
+}
+
+/// The window and node that the receiver of the current native stands for.
+fn this_node(vm: &mut VM) -> Result<(usize, NodeId), VmError> {
+    let this = vm.this;
+    let index = vm.get_property(this, "__node_index")?;
+    let generation = vm.get_property(this, "__node_generation")?;
+    if !index.is_number() || !generation.is_number() {
+        return Err(type_error("receiver is not an element"));
+    }
+    let node = GenIndex {
+        index: index.as_f64() as u32,
+        generation: generation.as_f64() as u32,
+    };
+    Ok((this_window(vm), node))
+}
+
+fn get_inner_html(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    let (window, node) = this_node(vm)?;
+    let markup = html::inner_html(dom(vm, window)?, node);
+    Ok(string_value(vm, markup))
+}
+
+fn set_inner_html(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let (window, node) = this_node(vm)?;
+    let markup = markup_arg(vm, args);
+    html::set_inner_html(dom(vm, window)?, node, &markup);
+    Ok(Value::undefined())
+}
+
+fn get_outer_html(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    let (window, node) = this_node(vm)?;
+    let markup = html::outer_html(dom(vm, window)?, node);
+    Ok(string_value(vm, markup))
+}
+
+fn set_outer_html(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let (window, node) = this_node(vm)?;
+    let markup = markup_arg(vm, args);
+    html::set_outer_html(dom(vm, window)?, node, &markup).map_err(|e| VmError {
+        message: e.to_string(),
+    })?;
+    Ok(Value::undefined())
+}
+
+fn insert_adjacent_html(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let (window, node) = this_node(vm)?;
+    let position = args.first().map(|&arg| js_string(vm, arg)).unwrap_or_default();
+    let Some(position) = html::AdjacentPosition::parse(&position) else {
+        return Err(VmError {
+            message: format!("SyntaxError: '{position}' is not a valid position"),
+        });
+    };
+    let markup = markup_arg(vm, &args[1.min(args.len())..]);
+    html::insert_adjacent_html(dom(vm, window)?, node, position, &markup).map_err(|e| VmError {
+        message: e.to_string(),
+    })?;
+    Ok(Value::undefined())
+}
+
+/// An `<iframe>`'s window. One whose document hasn't loaded yet gets an
+/// empty `about:blank` document, as in a browser, which a `src` replaces
+/// once the frame loads.
+fn content_window(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    let (window, node) = this_node(vm)?;
+    let host = host(vm)?;
+    let existing = host
+        .windows
+        .iter()
+        .position(|w| w.parent == Some(window) && w.frame_element == Some(node));
+    let index = match existing {
+        Some(index) => index,
+        None => {
+            let sandbox = Sandbox::of(&host.windows[window].dom, node);
+            let origin = match sandbox {
+                Some(sandbox) if !sandbox.allow_same_origin => None,
+                _ => host.windows[window].origin.clone(),
+            };
+            host.windows.push(Window {
+                dom: html::parse(""),
+                origin,
+                parent: Some(window),
+                frame_element: Some(node),
+            });
+            host.windows.len() - 1
+        }
+    };
+    Ok(window_value(vm, index))
+}
+
+/// An `<iframe>`'s document, or `null` when it is cross-origin.
+fn content_document(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let window = content_window(vm, args)?;
+    let index = vm.get_property(window, "__window")?.as_f64() as usize;
+    if !host(vm)?.is_same_origin(0, index) {
+        return Ok(Value::null());
+    }
+    Ok(document_value(vm, index))
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// URL
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The attributes of a `URL`, in the order of [`URL_GETTERS`] and
+/// [`URL_SETTERS`]. `origin` is read-only.
+const URL_ATTRIBUTES: [&str; 11] = [
+    "href", "origin", "protocol", "username", "password", "host", "hostname", "port", "pathname", "search", "hash",
+];
+
+const URL_GETTERS: [NativeFn; 11] = [
+    url_attribute::<0>,
+    url_attribute::<1>,
+    url_attribute::<2>,
+    url_attribute::<3>,
+    url_attribute::<4>,
+    url_attribute::<5>,
+    url_attribute::<6>,
+    url_attribute::<7>,
+    url_attribute::<8>,
+    url_attribute::<9>,
+    url_attribute::<10>,
+];
+
+const URL_SETTERS: [NativeFn; 11] = [
+    set_url_attribute::<0>,
+    set_url_attribute::<1>,
+    set_url_attribute::<2>,
+    set_url_attribute::<3>,
+    set_url_attribute::<4>,
+    set_url_attribute::<5>,
+    set_url_attribute::<6>,
+    set_url_attribute::<7>,
+    set_url_attribute::<8>,
+    set_url_attribute::<9>,
+    set_url_attribute::<10>,
+];
+
+/// `new URL(url, base)`; an invalid URL is a `TypeError`.
+fn new_url(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let input = args.first().map(|&arg| js_string(vm, arg)).unwrap_or_default();
+    let base = optional_string_arg(vm, args, 1);
+    let url = JsUrl::new(&input, base.as_deref()).map_err(|e| type_error(&e))?;
+    let host = host(vm)?;
+    host.urls.push(url);
+    let index = host.urls.len() - 1;
+
+    let this = vm.this;
+    let _ = vm.set_property(this, "__url", Value::number(index as f64));
+    for attribute in URL_ATTRIBUTES {
+        let setter = (attribute != "origin").then(|| format!("url.{attribute}="));
+        vm.define_accessor(this.as_gc_ref(), attribute, &format!("url.{attribute}"), setter.as_deref());
+    }
+    for method in ["toString", "toJSON"] {
+        let native = vm.native_function_value("url.toString");
+        let _ = vm.set_property(this, method, native);
+    }
+    let params = search_params_value(vm, "__url", index);
+    let _ = vm.set_property(this, "searchParams", params);
+    Ok(this)
+}
+
+/// The [`JsUrl`] the receiver of the current native stands for.
+fn this_url(vm: &mut VM) -> Result<&mut JsUrl, VmError> {
+    let this = vm.this;
+    let index = vm.get_property(this, "__url")?;
+    if !index.is_number() {
+        return Err(type_error("receiver is not a URL"));
+    }
+    host(vm)?
+        .urls
+        .get_mut(index.as_f64() as usize)
+        .ok_or_else(|| type_error("receiver is not a URL"))
+}
+
+fn url_attribute<const I: usize>(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    let url = this_url(vm)?;
+    let value = match URL_ATTRIBUTES[I] {
+        "href" => url.href(),
+        "origin" => url.origin(),
+        "protocol" => url.protocol(),
+        "username" => url.username().to_string(),
+        "password" => url.password().to_string(),
+        "host" => url.host(),
+        "hostname" => url.hostname().to_string(),
+        "port" => url.port(),
+        "pathname" => url.pathname().to_string(),
+        "search" => url.search(),
+        _ => url.hash(),
+    };
+    Ok(string_value(vm, value))
+}
+
+fn url_href(vm: &mut VM, args: &[Value]) -> NativeResult {
+    url_attribute::<0>(vm, args)
+}
+
+fn set_url_attribute<const I: usize>(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let value = args.first().map(|&arg| js_string(vm, arg)).unwrap_or_default();
+    let url = this_url(vm)?;
+    match URL_ATTRIBUTES[I] {
+        "href" => url.set_href(&value).map_err(|e| type_error(&e))?,
+        "protocol" => url.set_protocol(&value),
+        "username" => url.set_username(&value),
+        "password" => url.set_password(&value),
+        "host" => url.set_host(&value),
+        "hostname" => url.set_hostname(&value),
+        "port" => url.set_port(&value),
+        "pathname" => url.set_pathname(&value),
+        "search" => url.set_search(&value),
+        "hash" => url.set_hash(&value),
+        _ => {}
+    }
+    Ok(Value::undefined())
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// URLSearchParams
+// ─────────────────────────────────────────────────────────────────────────────
+
+const PARAMS_METHODS: &[(&str, NativeFn)] = &[
+    ("append", params_append),
+    ("delete", params_delete),
+    ("get", params_get),
+    ("getAll", params_get_all),
+    ("has", params_has),
+    ("set", params_set),
+    ("sort", params_sort),
+    ("toString", params_to_string),
+];
+
+/// `new URLSearchParams(init)`, from a query string.
+fn new_search_params(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let init = optional_string_arg(vm, args, 0).unwrap_or_default();
+    let host = host(vm)?;
+    host.search_params.push(UrlSearchParams::new(&init));
+    let index = host.search_params.len() - 1;
+    Ok(search_params_value(vm, "__params", index))
+}
+
+/// A `URLSearchParams` object for the params `key` (`__url` or
+/// `__params`) `index` names.
+fn search_params_value(vm: &mut VM, key: &str, index: usize) -> Value {
+    let obj = vm.heap.alloc_object();
+    let value = Value::ptr(obj.0);
+    let _ = vm.set_property(value, key, Value::number(index as f64));
+    vm.define_accessor(obj, "size", "params.size", None);
+    for (method, _) in PARAMS_METHODS {
+        let native = vm.native_function_value(&format!("params.{method}"));
+        let _ = vm.set_property(value, method, native);
+    }
+    value
+}
+
+/// Run `f` on the params the receiver stands for. Changing a URL's
+/// `searchParams` rewrites its query.
+fn with_this_params<T>(vm: &mut VM, f: impl FnOnce(&mut UrlSearchParams) -> T) -> Result<T, VmError> {
+    let this = vm.this;
+    let url = vm.get_property(this, "__url")?;
+    let params = vm.get_property(this, "__params")?;
+    let host = host(vm)?;
+    if url.is_number()
+        && let Some(url) = host.urls.get_mut(url.as_f64() as usize)
+    {
+        let mut result = None;
+        url.update_search_params(|params| result = Some(f(params)));
+        return Ok(result.expect("update_search_params runs its closure"));
+    }
+    if params.is_number()
+        && let Some(params) = host.search_params.get_mut(params.as_f64() as usize)
+    {
+        return Ok(f(params));
+    }
+    Err(type_error("receiver is not a URLSearchParams"))
+}
+
+fn params_size(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    let size = with_this_params(vm, |params| params.size())?;
+    Ok(Value::number(size as f64))
+}
+
+fn params_append(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let (name, value) = (string_arg(vm, args, 0), string_arg(vm, args, 1));
+    with_this_params(vm, |params| params.append(&name, &value))?;
+    Ok(Value::undefined())
+}
+
+fn params_delete(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let (name, value) = (string_arg(vm, args, 0), optional_string_arg(vm, args, 1));
+    with_this_params(vm, |params| params.delete(&name, value.as_deref()))?;
+    Ok(Value::undefined())
+}
+
+fn params_get(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let name = string_arg(vm, args, 0);
+    match with_this_params(vm, |params| params.get(&name).map(str::to_string))? {
+        Some(value) => Ok(string_value(vm, value)),
+        None => Ok(Value::null()),
+    }
+}
+
+fn params_get_all(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let name = string_arg(vm, args, 0);
+    let values: Vec<String> =
+        with_this_params(vm, |params| params.get_all(&name).into_iter().map(str::to_string).collect())?;
+    let values: Vec<Value> = values.into_iter().map(|v| string_value(vm, v)).collect();
+    Ok(vm.array_value(&values))
+}
+
+fn params_has(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let (name, value) = (string_arg(vm, args, 0), optional_string_arg(vm, args, 1));
+    let has = with_this_params(vm, |params| params.has(&name, value.as_deref()))?;
+    Ok(Value::boolean(has))
+}
+
+fn params_set(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let (name, value) = (string_arg(vm, args, 0), string_arg(vm, args, 1));
+    with_this_params(vm, |params| params.set(&name, &value))?;
+    Ok(Value::undefined())
+}
+
+fn params_sort(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    with_this_params(vm, |params| params.sort())?;
+    Ok(Value::undefined())
+}
+
+fn params_to_string(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    let query = with_this_params(vm, |params| params.to_string())?;
+    Ok(string_value(vm, query))
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// crypto
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// `new Uint8Array(length)` or `new Uint8Array(array)`: an array of bytes.
+fn new_uint8_array(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let bytes = match args.first() {
+        Some(&arg) if arg.is_number() => vec![0; arg.as_f64().max(0.0) as usize],
+        Some(&arg) => bytes_arg(vm, arg).unwrap_or_default(),
+        None => Vec::new(),
+    };
+    Ok(bytes_value(vm, &bytes))
+}
+
+/// `crypto.getRandomValues(array)`: fill `array` with random bytes and
+/// return it.
+fn get_random_values(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let array = args.first().copied().unwrap_or(Value::undefined());
+    let Some(items) = vm.array_items(array) else {
+        return Err(crypto_error(CryptoError::TypeMismatch));
+    };
+    let mut bytes = vec![0; items.len()];
+    js_builtins::crypto::get_random_values("Uint8Array", &mut bytes).map_err(crypto_error)?;
+    for (i, byte) in bytes.into_iter().enumerate() {
+        vm.set_array_item(array, i, Value::number(byte as f64));
+    }
+    Ok(array)
+}
+
+fn random_uuid(vm: &mut VM, _args: &[Value]) -> NativeResult {
+    Ok(string_value(vm, js_builtins::crypto::random_uuid()))
+}
+
+/// The bytes of a byte array, each element taken modulo 256 as a
+/// `Uint8Array` would store it.
+fn bytes_arg(vm: &mut VM, value: Value) -> Option<Vec<u8>> {
+    let items = vm.array_items(value)?;
+    Some(items.iter().map(|item| item.to_number(&vm.heap) as i64 as u8).collect())
+}
+
+fn bytes_value(vm: &mut VM, bytes: &[u8]) -> Value {
+    let items: Vec<Value> = bytes.iter().map(|&b| Value::number(b as f64)).collect();
+    vm.array_value(&items)
+}
+
+/// A `DOMException` from `crypto`, which ends the script.
+fn crypto_error(error: CryptoError) -> VmError {
+    dom_exception(error.name())
+}
+
+fn dom_exception(name: &str) -> VmError {
+    VmError {
+        message: format!("{name}: The operation failed"),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// crypto.subtle
+// ─────────────────────────────────────────────────────────────────────────────
+
+const SUBTLE_METHODS: [(&str, NativeFn); 9] = [
+    ("digest", subtle_digest),
+    ("importKey", subtle_import_key),
+    ("exportKey", subtle_export_key),
+    ("generateKey", subtle_generate_key),
+    ("sign", subtle_sign),
+    ("verify", subtle_verify),
+    ("encrypt", subtle_encrypt),
+    ("decrypt", subtle_decrypt),
+    ("deriveBits", subtle_derive_bits),
+];
+
+const SUBTLE_PROMISES: [NativeFn; 9] = [
+    subtle_promise::<0>,
+    subtle_promise::<1>,
+    subtle_promise::<2>,
+    subtle_promise::<3>,
+    subtle_promise::<4>,
+    subtle_promise::<5>,
+    subtle_promise::<6>,
+    subtle_promise::<7>,
+    subtle_promise::<8>,
+];
+
+/// `SUBTLE_METHODS[M]` as a promise: bad arguments reject it too, rather
+/// than throwing.
+fn subtle_promise<const M: usize>(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let result = SUBTLE_METHODS[M].1(vm, args);
+    let promise = new_promise(vm);
+    match result {
+        Ok(value) => settle(vm, promise, false, value),
+        Err(error) => {
+            let reason = error_value(vm, &error);
+            settle(vm, promise, true, reason);
+        }
+    }
+    Ok(promise)
+}
+
+/// `digest(algorithm, data)`.
+fn subtle_digest(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let algorithm = algorithm_arg(vm, args, 0)?;
+    let data = buffer_arg(vm, args, 1)?;
+    let host = host(vm)?;
+    let id = host.subtle.digest(&mut host.promises, &algorithm.name, &data);
+    settled(vm, id)
+}
+
+/// `importKey(format, keyData, algorithm, extractable, keyUsages)`.
+fn subtle_import_key(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let format = string_arg(vm, args, 0);
+    let key_data = buffer_arg(vm, args, 1)?;
+    let algorithm = algorithm_arg(vm, args, 2)?;
+    let extractable = args.get(3).is_some_and(|arg| arg.is_truthy());
+    let usages = usages_arg(vm, args, 4);
+    let usages: Vec<&str> = usages.iter().map(String::as_str).collect();
+    let host = host(vm)?;
+    let id = host.subtle.import_key(
+        &mut host.promises,
+        &format,
+        &key_data,
+        &algorithm,
+        extractable,
+        &usages,
+    );
+    settled(vm, id)
+}
+
+/// `exportKey(format, key)`.
+fn subtle_export_key(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let format = string_arg(vm, args, 0);
+    let key = key_arg(vm, args, 1)?;
+    let host = host(vm)?;
+    let id = host.subtle.export_key(&mut host.promises, &format, key);
+    settled(vm, id)
+}
+
+/// `generateKey(algorithm, extractable, keyUsages)`.
+fn subtle_generate_key(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let algorithm = algorithm_arg(vm, args, 0)?;
+    let extractable = args.get(1).is_some_and(|arg| arg.is_truthy());
+    let usages = usages_arg(vm, args, 2);
+    let usages: Vec<&str> = usages.iter().map(String::as_str).collect();
+    let host = host(vm)?;
+    let id = host
+        .subtle
+        .generate_key(&mut host.promises, &algorithm, extractable, &usages);
+    settled(vm, id)
+}
+
+/// `sign(algorithm, key, data)`.
+fn subtle_sign(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let algorithm = algorithm_arg(vm, args, 0)?;
+    let key = key_arg(vm, args, 1)?;
+    let data = buffer_arg(vm, args, 2)?;
+    let host = host(vm)?;
+    let id = host.subtle.sign(&mut host.promises, &algorithm.name, key, &data);
+    settled(vm, id)
+}
+
+/// `verify(algorithm, key, signature, data)`.
+fn subtle_verify(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let algorithm = algorithm_arg(vm, args, 0)?;
+    let key = key_arg(vm, args, 1)?;
+    let signature = buffer_arg(vm, args, 2)?;
+    let data = buffer_arg(vm, args, 3)?;
+    let host = host(vm)?;
+    let id = host
+        .subtle
+        .verify(&mut host.promises, &algorithm.name, key, &signature, &data);
+    settled(vm, id)
+}
+
+/// `encrypt(algorithm, key, data)`.
+fn subtle_encrypt(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let algorithm = algorithm_arg(vm, args, 0)?;
+    let key = key_arg(vm, args, 1)?;
+    let data = buffer_arg(vm, args, 2)?;
+    let host = host(vm)?;
+    let id = host.subtle.encrypt(&mut host.promises, &algorithm, key, &data);
+    settled(vm, id)
+}
+
+/// `decrypt(algorithm, key, data)`.
+fn subtle_decrypt(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let algorithm = algorithm_arg(vm, args, 0)?;
+    let key = key_arg(vm, args, 1)?;
+    let data = buffer_arg(vm, args, 2)?;
+    let host = host(vm)?;
+    let id = host.subtle.decrypt(&mut host.promises, &algorithm, key, &data);
+    settled(vm, id)
+}
+
+/// `deriveBits(algorithm, baseKey, length)`.
+fn subtle_derive_bits(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let algorithm = algorithm_arg(vm, args, 0)?;
+    let key = key_arg(vm, args, 1)?;
+    let length = args.get(2).map_or(0.0, |arg| arg.to_number(&vm.heap));
+    let host = host(vm)?;
+    let id = host
+        .subtle
+        .derive_bits(&mut host.promises, &algorithm, key, length.max(0.0) as usize);
+    settled(vm, id)
+}
+
+/// The value promise `id` was fulfilled with, or its rejection thrown.
+fn settled(vm: &mut VM, id: usize) -> NativeResult {
+    match host(vm)?.promises.state(id).clone() {
+        PromiseState::Fulfilled(value) => Ok(promise_value(vm, value)),
+        PromiseState::Rejected(PromiseValue::Str(name)) => Err(dom_exception(&name)),
+        _ => Err(dom_exception(CryptoError::Operation.name())),
+    }
+}
+
+fn promise_value(vm: &mut VM, value: PromiseValue) -> Value {
+    match value {
+        PromiseValue::Number(n) => Value::number(n),
+        PromiseValue::Str(s) => string_value(vm, s),
+        PromiseValue::Bool(b) => Value::boolean(b),
+        PromiseValue::Null => Value::null(),
+        PromiseValue::ArrayBuffer(bytes) => bytes_value(vm, &bytes),
+        PromiseValue::CryptoKey(handle) => key_value(vm, handle),
+        PromiseValue::Undefined | PromiseValue::PromiseRef(_) => Value::undefined(),
+    }
+}
+
+/// A `CryptoKey` object for key `handle`.
+fn key_value(vm: &mut VM, handle: usize) -> Value {
+    let Some(key) = host(vm).ok().and_then(|host| host.subtle.key(handle)).cloned() else {
+        return Value::undefined();
+    };
+    let object = Value::ptr(vm.heap.alloc_object().0);
+    let algorithm = Value::ptr(vm.heap.alloc_object().0);
+    let name = string_value(vm, key.algorithm.name().to_string());
+    let _ = vm.set_property(algorithm, "name", name);
+    let _ = vm.set_property(algorithm, "length", Value::number(key.length_bits() as f64));
+    let key_type = string_value(vm, key.key_type().to_string());
+    let usages: Vec<Value> = key.usages.into_iter().map(|u| string_value(vm, u)).collect();
+    let usages = vm.array_value(&usages);
+    let _ = vm.set_property(object, "__key", Value::number(handle as f64));
+    let _ = vm.set_property(object, "type", key_type);
+    let _ = vm.set_property(object, "extractable", Value::boolean(key.extractable));
+    let _ = vm.set_property(object, "algorithm", algorithm);
+    let _ = vm.set_property(object, "usages", usages);
+    object
+}
+
+/// Argument `index` as a `CryptoKey` handle.
+fn key_arg(vm: &mut VM, args: &[Value], index: usize) -> Result<usize, VmError> {
+    let key = args.get(index).copied().unwrap_or(Value::undefined());
+    let handle = if key.is_ptr() {
+        vm.get_property(key, "__key")?
+    } else {
+        Value::undefined()
+    };
+    if !handle.is_number() {
+        return Err(type_error("argument is not a CryptoKey"));
+    }
+    Ok(handle.as_f64() as usize)
+}
+
+/// Argument `index` as a `BufferSource`.
+fn buffer_arg(vm: &mut VM, args: &[Value], index: usize) -> Result<Vec<u8>, VmError> {
+    let value = args.get(index).copied().unwrap_or(Value::undefined());
+    bytes_arg(vm, value).ok_or_else(|| type_error("argument is not a BufferSource"))
+}
+
+/// Argument `index` as an array of key usage strings.
+fn usages_arg(vm: &mut VM, args: &[Value], index: usize) -> Vec<String> {
+    let value = args.get(index).copied().unwrap_or(Value::undefined());
+    let items = vm.array_items(value).unwrap_or_default();
+    items.into_iter().map(|item| js_string(vm, item)).collect()
+}
+
+/// Argument `index` as an algorithm: a name, or a dictionary such as
+/// `{name: "AES-GCM", iv}`.
+fn algorithm_arg(vm: &mut VM, args: &[Value], index: usize) -> Result<AlgorithmParams, VmError> {
+    let value = args.get(index).copied().unwrap_or(Value::undefined());
+    if !value.is_ptr() || !matches!(vm.heap.get(value.as_gc_ref()), Some(GcObject::Object(_))) {
+        return Ok(AlgorithmParams::named(&js_string(vm, value)));
+    }
+    let member = |vm: &mut VM, name: &str| -> Result<Option<Value>, VmError> {
+        let member = vm.get_property(value, name)?;
+        Ok(Some(member).filter(|m| !m.is_undefined()))
+    };
+    let bytes = |vm: &mut VM, name: &str| -> Result<Option<Vec<u8>>, VmError> {
+        match member(vm, name)? {
+            Some(member) => bytes_arg(vm, member)
+                .map(Some)
+                .ok_or_else(|| type_error("algorithm member is not a BufferSource")),
+            None => Ok(None),
+        }
+    };
+    let number = |vm: &mut VM, name: &str| -> Result<Option<usize>, VmError> {
+        Ok(member(vm, name)?.map(|m| m.to_number(&vm.heap).max(0.0) as usize))
+    };
+    let name = member(vm, "name")?.map(|name| js_string(vm, name)).unwrap_or_default();
+    let hash = match member(vm, "hash")? {
+        Some(hash) => Some(algorithm_arg(vm, &[hash], 0)?.name),
+        None => None,
+    };
+    Ok(AlgorithmParams {
+        name,
+        hash,
+        length: number(vm, "length")?,
+        iv: bytes(vm, "iv")?,
+        additional_data: bytes(vm, "additionalData")?,
+        tag_length: number(vm, "tagLength")?,
+        salt: bytes(vm, "salt")?,
+        info: bytes(vm, "info")?,
+    })
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// promises
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A pending promise.
+fn new_promise(vm: &mut VM) -> Value {
+    let promise = Value::ptr(vm.heap.alloc_object().0);
+    let pending = string_value(vm, "pending".into());
+    let reactions = vm.array_value(&[]);
+    let _ = vm.set_property(promise, "__state", pending);
+    let _ = vm.set_property(promise, "__result", Value::undefined());
+    let _ = vm.set_property(promise, "__reactions", reactions);
+    for method in ["then", "catch"] {
+        let native = vm.native_function_value(&format!("promise.{method}"));
+        let _ = vm.set_property(promise, method, native);
+    }
+    promise
+}
+
+/// The `__state` of `value`, if it is a promise.
+fn promise_state(vm: &mut VM, value: Value) -> Option<String> {
+    if !value.is_ptr() || !matches!(vm.heap.get(value.as_gc_ref()), Some(GcObject::Object(_))) {
+        return None;
+    }
+    let state = vm.get_property(value, "__state").ok().filter(|state| state.is_ptr())?;
+    match vm.heap.get(state.as_gc_ref()) {
+        Some(GcObject::String(s)) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+/// Fulfill or reject `promise` and queue its reactions. A promise that is
+/// already settled stays as it is; one fulfilled with another promise
+/// follows that promise instead.
+fn settle(vm: &mut VM, promise: Value, rejected: bool, value: Value) {
+    if promise_state(vm, promise).as_deref() != Some("pending") {
+        return;
+    }
+    if !rejected && promise_state(vm, value).is_some() {
+        let reaction = vm.array_value(&[Value::undefined(), Value::undefined(), promise]);
+        add_reaction(vm, value, reaction);
+        return;
+    }
+    let state = string_value(vm, if rejected { "rejected" } else { "fulfilled" }.into());
+    let _ = vm.set_property(promise, "__state", state);
+    let _ = vm.set_property(promise, "__result", value);
+    let reactions = vm.get_property(promise, "__reactions").unwrap_or(Value::undefined());
+    let empty = vm.array_value(&[]);
+    let _ = vm.set_property(promise, "__reactions", empty);
+    for reaction in vm.array_items(reactions).unwrap_or_default() {
+        queue_reaction(vm, reaction, rejected, value);
+    }
+}
+
+/// Run `reaction`, a `[onFulfilled, onRejected, derived]` array, once
+/// `promise` settles.
+fn add_reaction(vm: &mut VM, promise: Value, reaction: Value) {
+    match promise_state(vm, promise).as_deref() {
+        Some("pending") => {
+            let reactions = vm.get_property(promise, "__reactions").unwrap_or(Value::undefined());
+            let mut items = vm.array_items(reactions).unwrap_or_default();
+            items.push(reaction);
+            let reactions = vm.array_value(&items);
+            let _ = vm.set_property(promise, "__reactions", reactions);
+        }
+        Some(state) => {
+            let rejected = state == "rejected";
+            let result = vm.get_property(promise, "__result").unwrap_or(Value::undefined());
+            queue_reaction(vm, reaction, rejected, result);
+        }
+        None => {}
+    }
+}
+
+/// Queue a microtask that hands `value` to `reaction`.
+fn queue_reaction(vm: &mut VM, reaction: Value, rejected: bool, value: Value) {
+    let job = vm.array_value(&[reaction, Value::boolean(rejected), value]);
+    let queue = vm.get_global_value("__microtasks");
+    let mut jobs = vm.array_items(queue).unwrap_or_default();
+    jobs.push(job);
+    let queue = vm.array_value(&jobs);
+    vm.set_global("__microtasks", queue);
+}
+
+/// Run queued promise reactions, and any they queue in turn, until none
+/// are left. Each reaction settles the promise its `then` returned: with
+/// what the handler returned, with what it threw, or, without a handler,
+/// as the original promise did. Errors from handlers are not reported.
+pub fn run_microtasks(vm: &mut VM) {
+    loop {
+        let queue = vm.get_global_value("__microtasks");
+        let jobs = vm.array_items(queue).unwrap_or_default();
+        if jobs.is_empty() {
+            return;
+        }
+        let empty = vm.array_value(&[]);
+        vm.set_global("__microtasks", empty);
+        for job in jobs {
+            let job = vm.array_items(job).unwrap_or_default();
+            let [reaction, rejected, value] = job[..] else {
+                continue;
+            };
+            let reaction = vm.array_items(reaction).unwrap_or_default();
+            let [on_fulfilled, on_rejected, derived] = reaction[..] else {
+                continue;
+            };
+            let rejected = rejected.as_bool();
+            let handler = if rejected { on_rejected } else { on_fulfilled };
+            if !vm.is_callable(handler) {
+                settle(vm, derived, rejected, value);
+                continue;
+            }
+            match vm.call_function(handler, Value::undefined(), &[value]) {
+                Ok(result) => settle(vm, derived, false, result),
+                Err(error) => {
+                    let reason = error_value(vm, &error);
+                    settle(vm, derived, true, reason);
+                }
+            }
+        }
+    }
+}
+
+/// `promise.then(onFulfilled, onRejected)`.
+fn promise_then(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let on_fulfilled = args.first().copied().unwrap_or(Value::undefined());
+    let on_rejected = args.get(1).copied().unwrap_or(Value::undefined());
+    then(vm, on_fulfilled, on_rejected)
+}
+
+/// `promise.catch(onRejected)`.
+fn promise_catch(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let on_rejected = args.first().copied().unwrap_or(Value::undefined());
+    then(vm, Value::undefined(), on_rejected)
+}
+
+fn then(vm: &mut VM, on_fulfilled: Value, on_rejected: Value) -> NativeResult {
+    let promise = vm.this;
+    if promise_state(vm, promise).is_none() {
+        return Err(type_error("receiver is not a promise"));
+    }
+    let derived = new_promise(vm);
+    let reaction = vm.array_value(&[on_fulfilled, on_rejected, derived]);
+    add_reaction(vm, promise, reaction);
+    Ok(derived)
+}
+
+/// `await value`: the value a promise was fulfilled with, or its reason
+/// thrown. Reactions run first, so a promise that depends on them has
+/// settled; one that never settles gives `undefined`.
+fn await_value(vm: &mut VM, args: &[Value]) -> NativeResult {
+    let value = args.first().copied().unwrap_or(Value::undefined());
+    if promise_state(vm, value).as_deref() == Some("pending") {
+        run_microtasks(vm);
+    }
+    let result = match promise_state(vm, value) {
+        Some(_) => vm.get_property(value, "__result")?,
+        None => return Ok(value),
+    };
+    match promise_state(vm, value).as_deref() {
+        Some("fulfilled") => Ok(result),
+        Some("rejected") => Err(reason_error(vm, result)),
+        _ => Ok(Value::undefined()),
+    }
+}
+
+/// A rejection reason for `error`: a `DOMException` or `TypeError` as a
+/// `{name, message}` object, from a message of the form `"Name: message"`.
+fn error_value(vm: &mut VM, error: &VmError) -> Value {
+    let (name, message) = error.message.split_once(": ").unwrap_or(("Error", &error.message));
+    let object = Value::ptr(vm.heap.alloc_object().0);
+    let name = string_value(vm, name.to_string());
+    let message = string_value(vm, message.to_string());
+    let _ = vm.set_property(object, "name", name);
+    let _ = vm.set_property(object, "message", message);
+    object
+}
+
+/// The error that ends a script awaiting a promise rejected with `reason`.
+fn reason_error(vm: &mut VM, reason: Value) -> VmError {
+    let is_object = reason.is_ptr() && matches!(vm.heap.get(reason.as_gc_ref()), Some(GcObject::Object(_)));
+    let message = if is_object {
+        let name = vm.get_property(reason, "name").unwrap_or(Value::undefined());
+        let message = vm.get_property(reason, "message").unwrap_or(Value::undefined());
+        format!("{}: {}", js_string(vm, name), js_string(vm, message))
+    } else {
+        js_string(vm, reason)
+    };
+    VmError { message }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn host(vm: &mut VM) -> Result<&mut ScriptHost, VmError> {
+    vm.host
+        .as_mut()
+        .and_then(|host| host.downcast_mut::<ScriptHost>())
+        .ok_or_else(|| type_error("no document"))
+}
+
+/// The document of window `window`.
+fn dom(vm: &mut VM, window: usize) -> Result<&mut Dom, VmError> {
+    host(vm)?
+        .windows
+        .get_mut(window)
+        .map(|w| &mut w.dom)
+        .ok_or_else(|| type_error("no document"))
+}
+
+/// The markup argument of a setter: `null` counts as the empty string.
+fn markup_arg(vm: &mut VM, args: &[Value]) -> String {
+    match args.first() {
+        Some(arg) if !arg.is_null() => js_string(vm, *arg),
+        _ => String::new(),
+    }
+}
+
+/// Argument `index` as a string; a missing one is `"undefined"`, as in
+/// JavaScript.
+fn string_arg(vm: &mut VM, args: &[Value], index: usize) -> String {
+    js_string(vm, args.get(index).copied().unwrap_or(Value::undefined()))
+}
+
+/// Argument `index` as a string, unless it is missing or `undefined`.
+fn optional_string_arg(vm: &mut VM, args: &[Value], index: usize) -> Option<String> {
+    args.get(index).filter(|arg| !arg.is_undefined()).map(|&arg| js_string(vm, arg))
+}
+
+fn js_string(vm: &mut VM, value: Value) -> String {
+    let r = value.to_string_val(&mut vm.heap);
+    match vm.heap.get(r) {
+        Some(GcObject::String(s)) => s.clone(),
+        _ => String::new(),
+    }
+}
+
+fn string_value(vm: &mut VM, s: String) -> Value {
+    Value::ptr(vm.heap.alloc_string(s).0)
+}
+
+fn type_error(message: &str) -> VmError {
+    VmError {
+        message: format!("TypeError: {message}"),
+    }
+}
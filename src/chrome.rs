@@ -1,4 +1,6 @@
-//! Browser chrome rendering — tab bar, navigation bar, and status bar.
+//! Browser chrome rendering — tab bar (with site icons), navigation bar (with the site-info
+//! indicator and popover), status bar, and modal prompts (HTTP
+//! authentication).
 //!
 //! Renders the browser UI ("chrome") directly into a `Framebuffer` using
 //! simple rectangle fills and text placeholders.
@@ -6,6 +8,9 @@
 use paint::rasterizer::Framebuffer;
 use paint::font_engine::FontEngine;
 use shell::{BrowserShell, TabId};
+use std::collections::HashMap;
+
+use crate::security::SiteSecurity;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Layout constants
@@ -37,6 +42,30 @@ const COLOR_URL_TEXT: u32 = 0xFF_202124;
 const COLOR_URL_CURSOR: u32 = 0xFF_202124;
 const COLOR_STATUS_BAR_BG: u32 = 0xFF_F8F9FA;
 const COLOR_STATUS_TEXT: u32 = 0xFF_5F6368;
+const COLOR_PROMPT_SCRIM: u32 = 0x80_000000;
+const COLOR_PROMPT_BG: u32 = 0xFF_FFFFFF;
+const COLOR_PROMPT_TEXT: u32 = 0xFF_202124;
+const COLOR_PROMPT_SUBTEXT: u32 = 0xFF_5F6368;
+const COLOR_PROMPT_ERROR: u32 = 0xFF_D93025;
+const COLOR_PROMPT_PRIMARY: u32 = 0xFF_1A73E8;
+const COLOR_SITE_INFO_ICON: u32 = 0xFF_5F6368;
+const COLOR_SITE_INFO_WARNING: u32 = 0xFF_D93025;
+const COLOR_POPOVER_BORDER: u32 = 0xFF_DADCE0;
+const COLOR_READER_ICON: u32 = 0xFF_5F6368;
+const COLOR_READER_ACTIVE_BG: u32 = 0xFF_D2E3FC;
+const COLOR_READER_ACTIVE_ICON: u32 = 0xFF_1A73E8;
+
+/// Auth prompt dialog dimensions.
+const PROMPT_WIDTH: u32 = 420;
+const PROMPT_HEIGHT: u32 = 260;
+
+/// Width of the site-info button at the start of the URL bar.
+const SITE_INFO_BUTTON_WIDTH: u32 = 28;
+/// Width of the reader-mode button at the end of the URL bar.
+const READER_BUTTON_WIDTH: u32 = 32;
+/// Site-info popover width and line height.
+const POPOVER_WIDTH: u32 = 400;
+const POPOVER_LINE_HEIGHT: u32 = 18;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // ChromeState
@@ -50,6 +79,66 @@ pub struct ChromeState {
     pub status_text: String,
     pub width: u32,
     pub height: u32,
+    /// Open HTTP authentication prompt, if any. Modal: takes all input.
+    pub auth_prompt: Option<AuthPrompt>,
+    /// Security of the active tab's page, shown by the site-info button.
+    pub site_security: SiteSecurity,
+    /// Whether the site-info popover is open.
+    pub site_info_open: bool,
+    /// Reader mode for the active tab, shown by the reader button.
+    pub reader: ReaderMode,
+    /// Site icon of each tab that has one, already scaled to
+    /// [`favicon::SIZE`](crate::favicon::SIZE).
+    pub tab_icons: HashMap<TabId, image_decode::Image>,
+}
+
+/// Whether the active page can be, or is being, shown in reader mode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReaderMode {
+    /// Not an article: no reader button.
+    #[default]
+    Unavailable,
+    Available,
+    Active,
+}
+
+/// A credential prompt shown in response to a 401 challenge.
+pub struct AuthPrompt {
+    /// The URL to reload once credentials are entered.
+    pub url: String,
+    /// Origin asking for credentials (`scheme://host[:port]`).
+    pub origin: String,
+    /// Protection space name from the challenge.
+    pub realm: String,
+    pub username: String,
+    pub password: String,
+    /// Whether the password field (rather than username) has focus.
+    pub password_focused: bool,
+    /// Whether the previous credentials for this realm were rejected.
+    pub rejected: bool,
+}
+
+impl AuthPrompt {
+    pub fn new(url: &str, origin: &str, realm: &str, rejected: bool) -> Self {
+        Self {
+            url: url.to_string(),
+            origin: origin.to_string(),
+            realm: realm.to_string(),
+            username: String::new(),
+            password: String::new(),
+            password_focused: false,
+            rejected,
+        }
+    }
+
+    /// The text of the focused field.
+    pub fn focused_text_mut(&mut self) -> &mut String {
+        if self.password_focused {
+            &mut self.password
+        } else {
+            &mut self.username
+        }
+    }
 }
 
 impl ChromeState {
@@ -61,9 +150,25 @@ impl ChromeState {
             status_text: String::new(),
             width,
             height,
+            auth_prompt: None,
+            site_security: SiteSecurity::Internal,
+            site_info_open: false,
+            reader: ReaderMode::Unavailable,
+            tab_icons: HashMap::new(),
         }
     }
 
+    /// Whether the URL bar starts with a site-info button.
+    fn has_site_info(&self) -> bool {
+        self.site_security != SiteSecurity::Internal
+    }
+
+    /// Left edge of the reader button, when one is shown.
+    fn reader_button_x(&self) -> Option<u32> {
+        let url_end = self.width.saturating_sub(8);
+        (self.reader != ReaderMode::Unavailable).then(|| url_end.saturating_sub(READER_BUTTON_WIDTH + 4))
+    }
+
     /// Content area top Y offset (below chrome).
     pub fn content_top(&self) -> u32 {
         CHROME_HEIGHT
@@ -88,6 +193,8 @@ pub enum ChromeHit {
     BackButton,
     ForwardButton,
     ReloadButton,
+    SiteInfoButton,
+    ReaderButton,
     UrlBar,
 }
 
@@ -139,6 +246,18 @@ pub fn chrome_hit_test(x: i32, y: i32, state: &ChromeState, shell: &BrowserShell
         if x >= 2 * BUTTON_SIZE + 8 && x < 3 * BUTTON_SIZE + 12 {
             return ChromeHit::ReloadButton;
         }
+        // Site-info button at the start of the URL bar
+        let url_x = 3 * BUTTON_SIZE + 20;
+        if state.has_site_info() && x >= url_x && x < url_x + SITE_INFO_BUTTON_WIDTH {
+            return ChromeHit::SiteInfoButton;
+        }
+        // Reader button at the end of the URL bar
+        if let Some(reader_x) = state.reader_button_x()
+            && x >= reader_x
+            && x < reader_x + READER_BUTTON_WIDTH
+        {
+            return ChromeHit::ReaderButton;
+        }
         // URL bar (everything else in nav bar)
         return ChromeHit::UrlBar;
     }
@@ -146,6 +265,70 @@ pub fn chrome_hit_test(x: i32, y: i32, state: &ChromeState, shell: &BrowserShell
     ChromeHit::None
 }
 
+/// Result of hit-testing the auth prompt.
+pub enum PromptHit {
+    None,
+    UsernameField,
+    PasswordField,
+    Cancel,
+    Submit,
+}
+
+/// Screen-space rectangle used for prompt layout.
+#[derive(Clone, Copy)]
+struct UiRect {
+    x: i32,
+    y: i32,
+    w: u32,
+    h: u32,
+}
+
+impl UiRect {
+    fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x && y >= self.y && x < self.x + self.w as i32 && y < self.y + self.h as i32
+    }
+}
+
+/// Positions of the auth prompt's parts for a given window size.
+struct PromptLayout {
+    dialog: UiRect,
+    username: UiRect,
+    password: UiRect,
+    cancel: UiRect,
+    submit: UiRect,
+}
+
+fn prompt_layout(width: u32, height: u32) -> PromptLayout {
+    let w = PROMPT_WIDTH.min(width.saturating_sub(16));
+    let x = (width.saturating_sub(w) / 2) as i32;
+    let content_mid = CHROME_HEIGHT + height.saturating_sub(CHROME_HEIGHT + STATUS_BAR_HEIGHT) / 2;
+    let y = content_mid.saturating_sub(PROMPT_HEIGHT / 2).max(CHROME_HEIGHT) as i32;
+    let field_w = w.saturating_sub(40);
+    PromptLayout {
+        dialog: UiRect { x, y, w, h: PROMPT_HEIGHT },
+        username: UiRect { x: x + 20, y: y + 112, w: field_w, h: 28 },
+        password: UiRect { x: x + 20, y: y + 166, w: field_w, h: 28 },
+        cancel: UiRect { x: x + w as i32 - 196, y: y + 214, w: 84, h: 32 },
+        submit: UiRect { x: x + w as i32 - 104, y: y + 214, w: 84, h: 32 },
+    }
+}
+
+/// Hit test the auth prompt. Clicks outside the dialog hit nothing.
+pub fn prompt_hit_test(x: i32, y: i32, state: &ChromeState) -> PromptHit {
+    let layout = prompt_layout(state.width, state.height);
+    if layout.username.contains(x, y) {
+        PromptHit::UsernameField
+    } else if layout.password.contains(x, y) {
+        PromptHit::PasswordField
+    } else if layout.cancel.contains(x, y) {
+        PromptHit::Cancel
+    } else if layout.submit.contains(x, y) {
+        PromptHit::Submit
+    } else {
+        PromptHit::None
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Chrome rendering
 // ─────────────────────────────────────────────────────────────────────────────
@@ -160,6 +343,12 @@ pub fn render_chrome(
     render_tab_bar(fb, state, shell, font_engine.as_deref_mut());
     render_nav_bar(fb, state, font_engine.as_deref_mut());
     render_status_bar(fb, state, font_engine.as_deref_mut());
+    if state.site_info_open {
+        render_site_info(fb, state, font_engine.as_deref_mut());
+    }
+    if let Some(prompt) = &state.auth_prompt {
+        render_auth_prompt(fb, state, prompt, font_engine.as_deref_mut());
+    }
 }
 
 fn compute_tab_width(tab_count: usize, window_width: u32) -> u32 {
@@ -205,10 +394,19 @@ fn render_tab_bar(
             );
         }
 
-        // Tab title (leave room for close button)
-        let title = if tab.title.is_empty() { "New Tab" } else { &tab.title };
+        // Site icon, then the title (leave room for close button)
         let title_y = if is_active { 10 } else { 12 };
-        draw_chrome_text(fb, tx as i32 + 10, title_y, title, COLOR_TAB_TEXT, 12, tab_width.saturating_sub(30), &mut font_engine);
+        let mut title_x = tx + 10;
+        if let Some(icon) = state.tab_icons.get(&tab.id) {
+            let size = crate::favicon::SIZE;
+            let (ix, iy) = (title_x as i32, title_y as i32 - 1);
+            let clip_x1 = (tx + tab_width.saturating_sub(20)) as i32;
+            fb.blit_rgba_scaled(ix, iy, size, size, &icon.data, icon.width, icon.height, ix, iy, clip_x1, iy + size as i32);
+            title_x += size + 6;
+        }
+        let title = if tab.title.is_empty() { "New Tab" } else { &tab.title };
+        let title_width = (tx + tab_width).saturating_sub(title_x + 20);
+        draw_chrome_text(fb, title_x as i32, title_y, title, COLOR_TAB_TEXT, 12, title_width, &mut font_engine);
 
         // Close button "×"
         let close_x = tx + tab_width.saturating_sub(20);
@@ -254,8 +452,49 @@ fn render_nav_bar(fb: &mut Framebuffer, state: &ChromeState, mut font_engine: Op
     // Background pill
     fb.fill_rounded_rect(url_x as i32, y + 4, url_w, BUTTON_SIZE, [20.0; 4], COLOR_URL_BAR_BG, 0, 0, cw, ch);
 
+    // Site-info button: padlock for HTTPS (red when there is a certificate
+    // problem), an info mark for plain HTTP.
+    let mut text_x = url_x as i32 + 12;
+    if state.has_site_info() {
+        let ix = url_x as i32 + 10;
+        match &state.site_security {
+            SiteSecurity::Insecure => {
+                fb.fill_rounded_rect(ix, y + 13, 14, 14, [7.0; 4], COLOR_SITE_INFO_ICON, 0, 0, cw, ch);
+                fb.fill_rect(ix + 6, y + 16, 2, 2, COLOR_URL_BAR_BG);
+                fb.fill_rect(ix + 6, y + 19, 2, 6, COLOR_URL_BAR_BG);
+            }
+            security => {
+                let color = if security.is_warning() { COLOR_SITE_INFO_WARNING } else { COLOR_SITE_INFO_ICON };
+                // Shackle
+                fb.fill_rect(ix + 3, y + 11, 2, 6, color);
+                fb.fill_rect(ix + 9, y + 11, 2, 6, color);
+                fb.fill_rect(ix + 3, y + 10, 8, 2, color);
+                // Body
+                fb.fill_rounded_rect(ix + 1, y + 17, 12, 10, [2.0; 4], color, 0, 0, cw, ch);
+            }
+        }
+        text_x += SITE_INFO_BUTTON_WIDTH as i32 - 6;
+    }
+
+    // Reader button: lines of text, highlighted while reader mode is on.
+    let mut text_end = url_x + url_w;
+    if let Some(reader_x) = state.reader_button_x() {
+        let icon_color = if state.reader == ReaderMode::Active {
+            fb.fill_rounded_rect(reader_x as i32, y + 8, READER_BUTTON_WIDTH, BUTTON_SIZE - 8, [12.0; 4], COLOR_READER_ACTIVE_BG, 0, 0, cw, ch);
+            COLOR_READER_ACTIVE_ICON
+        } else {
+            COLOR_READER_ICON
+        };
+        let ix = reader_x as i32 + 9;
+        for (row, line_w) in [14, 14, 14, 9].into_iter().enumerate() {
+            fb.fill_rect(ix, y + 13 + row as i32 * 4, line_w, 2, icon_color);
+        }
+        text_end = reader_x;
+    }
+
     // URL text
-    draw_chrome_text(fb, url_x as i32 + 12, (y + 12) as u32, &state.url_text, COLOR_URL_TEXT, 13, url_w.saturating_sub(24), &mut font_engine);
+    let text_w = text_end.saturating_sub(text_x as u32 + 12);
+    draw_chrome_text(fb, text_x, (y + 12) as u32, &state.url_text, COLOR_URL_TEXT, 13, text_w, &mut font_engine);
 
     // Cursor (when focused)
     if state.url_focused {
@@ -265,7 +504,7 @@ fn render_nav_bar(fb: &mut Framebuffer, state: &ChromeState, mut font_engine: Op
         } else {
             (state.url_cursor as i32) * 8
         };
-        let cursor_x = url_x as i32 + 12 + cursor_px;
+        let cursor_x = text_x + cursor_px;
         fb.fill_rect(cursor_x, y + 8, 2, 20, COLOR_URL_CURSOR);
     }
 
@@ -279,6 +518,96 @@ fn render_status_bar(fb: &mut Framebuffer, state: &ChromeState, mut font_engine:
     draw_chrome_text(fb, 8, (y + 5) as u32, &state.status_text, COLOR_STATUS_TEXT, 11, state.width.saturating_sub(16), &mut font_engine);
 }
 
+fn render_site_info(fb: &mut Framebuffer, state: &ChromeState, mut font_engine: Option<&mut FontEngine>) {
+    let cw = fb.width as i32;
+    let ch = fb.height as i32;
+    let host = url_parser::Url::parse(&state.url_text)
+        .map(|u| u.host)
+        .unwrap_or_default();
+    let lines = state.site_security.site_info_lines(&host);
+
+    let x = (3 * BUTTON_SIZE + 20) as i32;
+    let y = CHROME_HEIGHT as i32 + 2;
+    let w = POPOVER_WIDTH.min(state.width.saturating_sub(x as u32 + 8));
+    let h = 24 + lines.len() as u32 * POPOVER_LINE_HEIGHT;
+    fb.fill_rounded_rect(x - 1, y - 1, w + 2, h + 2, [9.0; 4], COLOR_POPOVER_BORDER, 0, 0, cw, ch);
+    fb.fill_rounded_rect(x, y, w, h, [8.0; 4], COLOR_PROMPT_BG, 0, 0, cw, ch);
+
+    let text_w = w.saturating_sub(32);
+    for (i, line) in lines.iter().enumerate() {
+        let ly = y as u32 + 12 + i as u32 * POPOVER_LINE_HEIGHT;
+        let (color, size) = match i {
+            0 if state.site_security.is_warning() => (COLOR_PROMPT_ERROR, 14),
+            0 => (COLOR_PROMPT_TEXT, 14),
+            _ => (COLOR_PROMPT_SUBTEXT, 12),
+        };
+        draw_chrome_text(fb, x + 16, ly, line, color, size, text_w, &mut font_engine);
+    }
+}
+
+fn render_auth_prompt(
+    fb: &mut Framebuffer,
+    state: &ChromeState,
+    prompt: &AuthPrompt,
+    mut font_engine: Option<&mut FontEngine>,
+) {
+    let cw = fb.width as i32;
+    let ch = fb.height as i32;
+    let layout = prompt_layout(state.width, state.height);
+    let d = layout.dialog;
+
+    // Dim the page behind the dialog.
+    let content_h = state.height.saturating_sub(CHROME_HEIGHT + STATUS_BAR_HEIGHT);
+    fb.fill_rect(0, CHROME_HEIGHT as i32, state.width, content_h, COLOR_PROMPT_SCRIM);
+
+    fb.fill_rounded_rect(d.x, d.y, d.w, d.h, [8.0; 4], COLOR_PROMPT_BG, 0, 0, cw, ch);
+
+    let text_w = d.w.saturating_sub(40);
+    draw_chrome_text(fb, d.x + 20, d.y as u32 + 16, "Sign in", COLOR_PROMPT_TEXT, 16, text_w, &mut font_engine);
+    draw_chrome_text(fb, d.x + 20, d.y as u32 + 42, &prompt.origin, COLOR_PROMPT_SUBTEXT, 12, text_w, &mut font_engine);
+    let note = if prompt.rejected {
+        "The username or password was incorrect."
+    } else if prompt.origin.starts_with("http://") {
+        "Your connection to this site is not private."
+    } else {
+        ""
+    };
+    let note_color = if prompt.rejected { COLOR_PROMPT_ERROR } else { COLOR_PROMPT_SUBTEXT };
+    draw_chrome_text(fb, d.x + 20, d.y as u32 + 60, note, note_color, 12, text_w, &mut font_engine);
+    if !prompt.realm.is_empty() {
+        let realm = format!("Realm: {}", prompt.realm);
+        draw_chrome_text(fb, d.x + 20, d.y as u32 + 76, &realm, COLOR_PROMPT_SUBTEXT, 12, text_w, &mut font_engine);
+    }
+
+    let masked: String = prompt.password.chars().map(|_| '\u{2022}').collect();
+    let fields = [
+        ("Username", layout.username, prompt.username.as_str(), !prompt.password_focused),
+        ("Password", layout.password, masked.as_str(), prompt.password_focused),
+    ];
+    for (label, r, text, focused) in fields {
+        draw_chrome_text(fb, r.x, r.y as u32 - 18, label, COLOR_PROMPT_TEXT, 12, r.w, &mut font_engine);
+        let border = if focused { COLOR_URL_BAR_FOCUSED } else { COLOR_URL_BAR_BORDER };
+        fb.fill_rounded_rect(r.x - 1, r.y - 1, r.w + 2, r.h + 2, [5.0; 4], border, 0, 0, cw, ch);
+        fb.fill_rounded_rect(r.x, r.y, r.w, r.h, [4.0; 4], COLOR_PROMPT_BG, 0, 0, cw, ch);
+        draw_chrome_text(fb, r.x + 8, r.y as u32 + 7, text, COLOR_URL_TEXT, 13, r.w.saturating_sub(16), &mut font_engine);
+        if focused {
+            let text_px = if let Some(fe) = font_engine.as_deref_mut() {
+                fe.measure_text(text, 13.0) as i32
+            } else {
+                text.chars().count() as i32 * 8
+            };
+            fb.fill_rect(r.x + 8 + text_px, r.y + 5, 2, r.h - 10, COLOR_URL_CURSOR);
+        }
+    }
+
+    let c = layout.cancel;
+    fb.fill_rounded_rect(c.x, c.y, c.w, c.h, [6.0; 4], COLOR_NAV_BUTTON, 0, 0, cw, ch);
+    draw_chrome_text(fb, c.x + 20, c.y as u32 + 9, "Cancel", COLOR_PROMPT_PRIMARY, 13, c.w, &mut font_engine);
+    let s = layout.submit;
+    fb.fill_rounded_rect(s.x, s.y, s.w, s.h, [6.0; 4], COLOR_PROMPT_PRIMARY, 0, 0, cw, ch);
+    draw_chrome_text(fb, s.x + 18, s.y as u32 + 9, "Sign in", COLOR_PROMPT_BG, 13, s.w, &mut font_engine);
+}
+
 /// Draw chrome text using the font engine if available, otherwise fall back to rectangles.
 fn draw_chrome_text(
     fb: &mut Framebuffer,
@@ -0,0 +1,143 @@
+//! Site icons for the tab strip.
+//!
+//! A page names its icons with `<link rel="icon">` (the `sizes` attribute
+//! says which suits the tab best); without one, browsers look for
+//! `/favicon.ico` at the site root. Icons may be ICO files, holding
+//! several sizes, or any other image format, SVG included. Whatever the
+//! source, the result is scaled to [`SIZE`] pixels square.
+
+use dom::Dom;
+use image_decode::{Image, ImageFormat};
+
+use crate::browser::{DOC_ROOT, resolve_url};
+
+/// Side of an icon as drawn in a tab, in pixels.
+pub const SIZE: u32 = 16;
+
+/// URLs to try for the icon of the document at `url`, best first:
+/// `<link rel=icon>`s ordered by how well their `sizes` fit a tab, then
+/// the site's `/favicon.ico`.
+pub fn candidates(dom: &Dom, url: &str, base_url: &str) -> Vec<String> {
+    let mut links: Vec<(u32, usize, String)> = Vec::new();
+    for (order, link) in dom.get_elements_by_tag(DOC_ROOT, "link").into_iter().enumerate() {
+        let Some(element) = dom.nodes.get(link).and_then(|n| n.as_element()) else {
+            continue;
+        };
+        let attr = |name: &str| element.attrs.iter().find(|a| a.name == name).map(|a| a.value.trim());
+        let is_icon = attr("rel").is_some_and(|rel| rel.split_ascii_whitespace().any(|r| r.eq_ignore_ascii_case("icon")));
+        let Some(href) = attr("href").filter(|h| !h.is_empty()) else {
+            continue;
+        };
+        if is_icon {
+            links.push((size_penalty(attr("sizes")), order, resolve_url(href, base_url)));
+        }
+    }
+    links.sort();
+    let mut urls: Vec<String> = links.into_iter().map(|(_, _, url)| url).collect();
+    if let Ok(parsed) = url_parser::Url::parse(url)
+        && matches!(parsed.scheme.as_str(), "http" | "https")
+    {
+        let fallback = format!("{}/favicon.ico", parsed.origin());
+        if !urls.contains(&fallback) {
+            urls.push(fallback);
+        }
+    }
+    urls
+}
+
+/// How badly a `sizes` value fits a tab icon; lower is better. An exact
+/// fit wins, then larger sizes (which scale down cleanly), then `any`
+/// (usually SVG), then unknown, then smaller sizes.
+fn size_penalty(sizes: Option<&str>) -> u32 {
+    let Some(sizes) = sizes else {
+        return 3000;
+    };
+    sizes
+        .split_ascii_whitespace()
+        .map(|size| {
+            if size.eq_ignore_ascii_case("any") {
+                return 2000;
+            }
+            let side = size
+                .split_once(['x', 'X'])
+                .and_then(|(w, h)| Some(w.parse::<u32>().ok()?.min(h.parse().ok()?)));
+            match side {
+                Some(side) if side >= SIZE => side - SIZE,
+                Some(side) => 4000 + SIZE - side,
+                None => 3000,
+            }
+        })
+        .min()
+        .unwrap_or(3000)
+}
+
+/// Decode icon file `bytes` into a [`SIZE`]-pixel square image, keeping
+/// the aspect ratio of non-square images.
+pub fn decode(bytes: &[u8]) -> Option<Image> {
+    let image = match image_decode::detect_format(bytes) {
+        ImageFormat::Ico => image_decode::ico::decode_ico_for_size(bytes, SIZE).ok()?,
+        ImageFormat::Svg => {
+            let doc = svg::SvgDocument::parse(bytes).ok()?;
+            let fb = doc.rasterize(SIZE, SIZE, None);
+            Image { width: fb.width, height: fb.height, data: fb.to_rgba() }
+        }
+        _ => image_decode::decode(bytes).ok()?,
+    };
+    if image.is_empty() {
+        return None;
+    }
+    if image.width == SIZE && image.height == SIZE {
+        return Some(image);
+    }
+    let scale = SIZE as f32 / image.width.max(image.height) as f32;
+    let (w, h) = (
+        ((image.width as f32 * scale).round() as u32).max(1),
+        ((image.height as f32 * scale).round() as u32).max(1),
+    );
+    let scaled = image.resized(w, h);
+    let mut square = Image::new(SIZE, SIZE);
+    let (dx, dy) = ((SIZE - w) / 2, (SIZE - h) / 2);
+    for y in 0..h {
+        for x in 0..w {
+            square.set_pixel(x + dx, y + dy, scaled.get_pixel(x, y));
+        }
+    }
+    Some(square)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candidates_prefer_fitting_sizes_then_fallback() {
+        let dom = html::parse(
+            r#"<link rel="icon" href="big.png" sizes="192x192">
+            <link rel="stylesheet" href="x.css">
+            <link rel="icon" href="tiny.png" sizes="8x8">
+            <link rel="SHORTCUT ICON" href="/legacy.ico">
+            <link rel="icon" href="exact.png" sizes="16x16 32x32">"#,
+        );
+        let url = "https://example.com/docs/page";
+        assert_eq!(
+            candidates(&dom, url, "https://example.com/docs/"),
+            [
+                "https://example.com/docs/exact.png",
+                "https://example.com/docs/big.png",
+                "https://example.com/legacy.ico",
+                "https://example.com/docs/tiny.png",
+                "https://example.com/favicon.ico",
+            ]
+        );
+        assert!(candidates(&html::parse(""), "about:newtab", "about:newtab").is_empty());
+    }
+
+    #[test]
+    fn test_decodes_svg_icon_to_tab_size() {
+        let icon = decode(br#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 2 1"><rect width="2" height="1" fill="red"/></svg>"#)
+            .unwrap();
+        assert_eq!((icon.width, icon.height), (SIZE, SIZE));
+        assert_eq!(icon.get_pixel(8, 8), [255, 0, 0, 255]);
+        assert_eq!(icon.get_pixel(8, 1)[3], 0);
+    }
+}
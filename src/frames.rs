@@ -0,0 +1,261 @@
+//! Nested browsing contexts: the documents shown by `<iframe>` elements.
+//!
+//! Each frame is a [`PageData`] of its own — DOM, style map, layout tree,
+//! display list and scroll offset — laid out at the size of the iframe's
+//! content box and painted into it with clipping. Clicks, hovering and
+//! wheel events over a frame go to its document, in its coordinates.
+//! Scripts reach related frames through `window.parent`, `window.top` and
+//! `contentWindow` (see [`crate::bindings`]), and only same-origin frames
+//! see each other's documents.
+
+use common::Rect;
+use dom::{Dom, ElementData, NodeId};
+use style::ComputedStyle;
+
+use crate::bindings::Window;
+use crate::browser::{PageData, resolve_url};
+
+/// How deeply frames may nest; deeper `<iframe>`s stay empty.
+pub const MAX_DEPTH: usize = 4;
+
+/// The size of an `<iframe>` without `width`/`height` (HTML §15.4.4).
+pub const DEFAULT_SIZE: (f32, f32) = (300.0, 150.0);
+
+/// URL of a document given inline by `srcdoc`.
+pub const SRCDOC_URL: &str = "about:srcdoc";
+
+/// A child document and where its `<iframe>` puts it.
+pub struct Frame {
+    /// The `<iframe>` element in the parent document.
+    pub element: NodeId,
+    /// The iframe's content box, in the parent's document coordinates.
+    pub rect: Rect,
+    pub page: Box<PageData>,
+}
+
+/// The restrictions of a `sandbox` attribute. Everything is forbidden
+/// except what an `allow-*` token lifts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Sandbox {
+    /// `allow-scripts`: run the document's scripts.
+    pub allow_scripts: bool,
+    /// `allow-same-origin`: keep the document's origin instead of an
+    /// opaque one that matches nothing.
+    pub allow_same_origin: bool,
+    /// `allow-top-navigation`: let `target="_top"` links replace the page.
+    pub allow_top_navigation: bool,
+}
+
+impl Sandbox {
+    pub fn parse(value: &str) -> Self {
+        let mut sandbox = Self::default();
+        for token in value.split_ascii_whitespace() {
+            match token.to_ascii_lowercase().as_str() {
+                "allow-scripts" => sandbox.allow_scripts = true,
+                "allow-same-origin" => sandbox.allow_same_origin = true,
+                "allow-top-navigation" => sandbox.allow_top_navigation = true,
+                _ => {}
+            }
+        }
+        sandbox
+    }
+
+    /// The sandbox of the `<iframe>` `iframe`, if it has the attribute.
+    pub fn of(dom: &Dom, iframe: NodeId) -> Option<Self> {
+        attr(dom, iframe, "sandbox").map(Self::parse)
+    }
+
+    /// The flags of a frame sandboxed as `inner` inside one sandboxed as
+    /// `outer`: both sets of restrictions apply.
+    pub fn nested(inner: Option<Self>, outer: Option<Self>) -> Option<Self> {
+        match (inner, outer) {
+            (Some(a), Some(b)) => Some(Self {
+                allow_scripts: a.allow_scripts && b.allow_scripts,
+                allow_same_origin: a.allow_same_origin && b.allow_same_origin,
+                allow_top_navigation: a.allow_top_navigation && b.allow_top_navigation,
+            }),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+/// Where a frame's document comes from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FrameSource {
+    /// Markup given by `srcdoc`, which wins over `src`. Its relative URLs
+    /// resolve against `base_url`, the parent's.
+    Srcdoc { markup: String, base_url: String },
+    /// An absolute URL from `src`.
+    Url(String),
+    /// No source, or `about:blank`: an empty document.
+    Blank,
+}
+
+impl FrameSource {
+    /// The source of the `<iframe>` `iframe` in a document at `base_url`.
+    pub fn of(dom: &Dom, iframe: NodeId, base_url: &str) -> Self {
+        if let Some(markup) = attr(dom, iframe, "srcdoc") {
+            return Self::Srcdoc {
+                markup: markup.to_string(),
+                base_url: base_url.to_string(),
+            };
+        }
+        match attr(dom, iframe, "src").map(str::trim) {
+            None | Some("") | Some("about:blank") => Self::Blank,
+            Some(src) => Self::Url(resolve_url(src, base_url)),
+        }
+    }
+}
+
+/// The origin scripts of a document at `url` run with. `srcdoc` and
+/// `about:blank` documents inherit `parent_origin`; a sandbox without
+/// `allow-same-origin` makes any origin opaque.
+pub fn frame_origin(url: &str, parent_origin: Option<&str>, sandbox: Option<Sandbox>) -> Option<String> {
+    if sandbox.is_some_and(|s| !s.allow_same_origin) {
+        return None;
+    }
+    if url == SRCDOC_URL || url == "about:blank" {
+        return parent_origin.map(str::to_string);
+    }
+    origin_of(url)
+}
+
+/// `scheme://host[:port]` of `url`, or `None` for an opaque origin.
+pub fn origin_of(url: &str) -> Option<String> {
+    url_parser::Url::parse(url).ok().map(|u| u.origin()).filter(|o| o != "null")
+}
+
+/// Size an `<iframe>` from its `width`/`height` attributes, or the default
+/// 300×150, where CSS leaves its size unset.
+pub fn apply_size_hints(computed: &mut ComputedStyle, element: &ElementData) {
+    let dimension = |name: &str| {
+        let value = element.attrs.iter().find(|a| a.name == name)?.value.trim();
+        value.strip_suffix("px").unwrap_or(value).parse::<f32>().ok().filter(|v| *v >= 0.0)
+    };
+    if computed.width.is_none() && computed.width_pct.is_none() {
+        computed.width = Some(dimension("width").unwrap_or(DEFAULT_SIZE.0));
+    }
+    if computed.height.is_none() && computed.height_pct.is_none() {
+        computed.height = Some(dimension("height").unwrap_or(DEFAULT_SIZE.1));
+    }
+}
+
+/// The innermost frame of `page` under the point `(x, y)` in its document
+/// coordinates: the path of frame indexes leading to it, and the point in
+/// the frame document's coordinates. `None` when the point is not over a
+/// frame.
+pub fn frame_at(page: &PageData, x: f32, y: f32) -> Option<(Vec<usize>, f32, f32)> {
+    let (index, frame) = page.frames.iter().enumerate().rev().find(|(_, f)| f.rect.contains(x, y))?;
+    let (fx, fy) = (x - frame.rect.x, y - frame.rect.y + frame.page.scroll_y);
+    match frame_at(&frame.page, fx, fy) {
+        Some((mut path, ix, iy)) => {
+            path.insert(0, index);
+            Some((path, ix, iy))
+        }
+        None => Some((vec![index], fx, fy)),
+    }
+}
+
+/// The frame at the end of `path` (see [`frame_at`]).
+pub fn frame<'a>(page: &'a PageData, path: &[usize]) -> Option<&'a Frame> {
+    let (&first, rest) = path.split_first()?;
+    let frame = page.frames.get(first)?;
+    if rest.is_empty() { Some(frame) } else { self::frame(&frame.page, rest) }
+}
+
+pub fn frame_mut<'a>(page: &'a mut PageData, path: &[usize]) -> Option<&'a mut Frame> {
+    let (&first, rest) = path.split_first()?;
+    let frame = page.frames.get_mut(first)?;
+    if rest.is_empty() { Some(frame) } else { frame_mut(&mut frame.page, rest) }
+}
+
+/// The `target` of the nearest `<a>` at or above `node`.
+pub fn link_target(dom: &Dom, node: NodeId) -> Option<String> {
+    std::iter::once(node)
+        .chain(dom.ancestors(node))
+        .find(|&n| dom.nodes.get(n).and_then(|n| n.as_element()).is_some_and(|e| e.tag_name == "a"))
+        .and_then(|a| attr(dom, a, "target"))
+        .map(|t| t.trim().to_ascii_lowercase())
+}
+
+/// Take the documents of `page` and of the frames along `path` out of them,
+/// to lend to a frame's scripts; the end of `path` comes first.
+pub fn lend_documents(page: &mut PageData, path: &[usize]) -> Vec<Window> {
+    let mut windows = vec![Window {
+        dom: std::mem::take(&mut page.dom),
+        origin: page.origin.clone(),
+        parent: None,
+        frame_element: None,
+    }];
+    let mut current = page;
+    for &index in path {
+        let Some(frame) = current.frames.get_mut(index) else { break };
+        windows.push(Window {
+            dom: std::mem::take(&mut frame.page.dom),
+            origin: frame.page.origin.clone(),
+            parent: None,
+            frame_element: Some(frame.element),
+        });
+        current = &mut frame.page;
+    }
+    windows.reverse();
+    windows
+}
+
+/// Put documents taken by [`lend_documents`] back.
+pub fn return_documents(page: &mut PageData, path: &[usize], windows: Vec<Window>) {
+    let mut windows = windows.into_iter().rev();
+    if let Some(window) = windows.next() {
+        page.dom = window.dom;
+    }
+    let mut current = page;
+    for &index in path {
+        let (Some(frame), Some(window)) = (current.frames.get_mut(index), windows.next()) else { break };
+        frame.page.dom = window.dom;
+        current = &mut frame.page;
+    }
+}
+
+fn attr<'a>(dom: &'a Dom, id: NodeId, name: &str) -> Option<&'a str> {
+    let element = dom.nodes.get(id)?.as_element()?;
+    element.attrs.iter().find(|a| a.name == name).map(|a| a.value.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::browser::DOC_ROOT;
+
+    #[test]
+    fn test_sandbox_tokens() {
+        assert_eq!(Sandbox::parse(""), Sandbox::default());
+        let sandbox = Sandbox::parse(" allow-scripts  ALLOW-SAME-ORIGIN allow-forms");
+        assert!(sandbox.allow_scripts && sandbox.allow_same_origin && !sandbox.allow_top_navigation);
+        let nested = Sandbox::nested(Some(Sandbox::parse("allow-same-origin")), Some(sandbox)).unwrap();
+        assert!(!nested.allow_scripts && nested.allow_same_origin);
+        assert_eq!(Sandbox::nested(None, Some(sandbox)), Some(sandbox));
+    }
+
+    #[test]
+    fn test_sources_and_origins() {
+        let dom = html::parse(
+            r#"<iframe src="/map"></iframe><iframe srcdoc="<p>hi" src="/x"></iframe><iframe></iframe>
+            <iframe sandbox src="https://other.example/"></iframe>"#,
+        );
+        let frames = dom.get_elements_by_tag(DOC_ROOT, "iframe");
+        let base = "https://example.com/page";
+        assert_eq!(FrameSource::of(&dom, frames[0], base), FrameSource::Url("https://example.com/map".into()));
+        assert_eq!(
+            FrameSource::of(&dom, frames[1], base),
+            FrameSource::Srcdoc { markup: "<p>hi".into(), base_url: base.into() }
+        );
+        assert_eq!(FrameSource::of(&dom, frames[2], base), FrameSource::Blank);
+
+        let parent = origin_of(base);
+        assert_eq!(parent.as_deref(), Some("https://example.com"));
+        assert_eq!(frame_origin(SRCDOC_URL, parent.as_deref(), None), parent);
+        assert_eq!(frame_origin("https://other.example/", parent.as_deref(), None).as_deref(), Some("https://other.example"));
+        let sandbox = Sandbox::of(&dom, frames[3]);
+        assert_eq!(frame_origin("https://other.example/", parent.as_deref(), sandbox), None);
+    }
+}
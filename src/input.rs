@@ -44,6 +44,33 @@ pub enum BrowserAction {
     Redraw,
     /// Edit the URL bar text.
     UrlInput(UrlEdit),
+    /// Interact with the open auth prompt.
+    PromptInput(PromptEdit),
+}
+
+/// Which part of the UI receives keyboard input.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum KeyFocus {
+    /// The page content.
+    Content,
+    /// The URL bar.
+    UrlBar,
+    /// A modal prompt (e.g. HTTP authentication).
+    Prompt,
+}
+
+/// An edit operation on the auth prompt.
+pub enum PromptEdit {
+    /// Insert a character into the focused field.
+    Insert(char),
+    /// Delete the last character of the focused field.
+    Backspace,
+    /// Move focus to the other field.
+    NextField,
+    /// Submit the entered credentials.
+    Submit,
+    /// Dismiss the prompt.
+    Cancel,
 }
 
 /// An edit operation on the URL bar text.
@@ -75,11 +102,11 @@ pub enum UrlEdit {
 /// Process a raw X11 event and return the corresponding browser action.
 ///
 /// * `event` — the X11 event to process.
-/// * `url_focused` — whether the URL bar currently has keyboard focus.
+/// * `focus` — which part of the UI currently has keyboard focus.
 /// * `wm_delete_atom` — the X11 atom for WM_DELETE_WINDOW (for graceful close).
 pub fn process_x11_event(
     event: &X11Event,
-    url_focused: bool,
+    focus: KeyFocus,
     wm_delete_atom: u32,
 ) -> BrowserAction {
     match event {
@@ -87,10 +114,10 @@ pub fn process_x11_event(
             keycode, state, ..
         } => {
             let key = keymap::keycode_to_event(*keycode, *state);
-            if url_focused {
-                process_key_url_mode(&key)
-            } else {
-                process_key_content_mode(&key)
+            match focus {
+                KeyFocus::Prompt => process_key_prompt_mode(&key),
+                KeyFocus::UrlBar => process_key_url_mode(&key),
+                KeyFocus::Content => process_key_content_mode(&key),
             }
         }
 
@@ -156,6 +183,20 @@ fn process_key_url_mode(key: &KeyEvent) -> BrowserAction {
     }
 }
 
+/// Process a key event when a modal prompt is open.
+fn process_key_prompt_mode(key: &KeyEvent) -> BrowserAction {
+    match key {
+        KeyEvent::Char(ch) => BrowserAction::PromptInput(PromptEdit::Insert(*ch)),
+        KeyEvent::Backspace => BrowserAction::PromptInput(PromptEdit::Backspace),
+        KeyEvent::Tab | KeyEvent::Up | KeyEvent::Down => {
+            BrowserAction::PromptInput(PromptEdit::NextField)
+        }
+        KeyEvent::Enter => BrowserAction::PromptInput(PromptEdit::Submit),
+        KeyEvent::Escape => BrowserAction::PromptInput(PromptEdit::Cancel),
+        _ => BrowserAction::None,
+    }
+}
+
 /// Process a key event when the content area has focus.
 fn process_key_content_mode(key: &KeyEvent) -> BrowserAction {
     match key {
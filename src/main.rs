@@ -3,10 +3,18 @@
 //! This is the entry point demonstrating all 33 crates working together
 //! in a complete browser engine pipeline.
 
+pub mod bindings;
 pub mod chrome;
+pub mod favicon;
+pub mod frames;
 pub mod input;
 pub mod hittest;
 pub mod browser;
+pub mod reader;
+pub mod refresh;
+pub mod security;
+pub mod streaming;
+pub mod view_source;
 
 use std::collections::HashMap;
 
@@ -316,10 +324,10 @@ fn demo_network_stack() {
     }
 
     // Network Service
-    let mut net_svc = net::NetworkService::new();
+    let net_svc = net::NetworkService::new();
     println!("\n   ✓ NetworkService created (user-agent: {})", net_svc.user_agent);
     println!("     DNS resolver: ready");
-    println!("     Cookie jar: {} cookies", if net_svc.cookie_jar.is_empty() { 0 } else { 1 });
+    println!("     Cookie jar: {} cookies", if net_svc.cookie_jar().is_empty() { 0 } else { 1 });
     println!("     Max redirects: {}", net_svc.max_redirects);
 
     // Demonstrate FetchRequest building
@@ -331,11 +339,11 @@ fn demo_network_stack() {
         req.method, req.url.path, req.headers.len());
 
     // Cookie jar
-    net_svc.cookie_jar.store_from_header(
+    net_svc.cookie_jar().store_from_header(
         "session=abc123; Path=/; HttpOnly; Secure",
         &url_parser::Url::parse("https://example.com/").unwrap(),
     );
-    let cookies = net_svc.cookie_jar.get_cookies(
+    let cookies = net_svc.cookie_jar().get_cookies(
         &url_parser::Url::parse("https://example.com/page").unwrap()
     );
     println!("   ✓ Cookie stored and retrieved: \"{}\"", cookies);
@@ -0,0 +1,675 @@
+//! Reader mode — the main text of an article page, re-rendered with a plain
+//! built-in stylesheet.
+//!
+//! The content is found the way Readability finds it. Every paragraph-like
+//! element scores its parent and grandparent by how much prose it holds
+//! (length and commas). Containers start from a weight for their tag and
+//! for `class`/`id` hints such as `article` or `sidebar`, and are scaled
+//! down by the share of their text that sits inside links. The best
+//! container is kept together with the siblings that score close to it,
+//! then written out as a small whitelist of elements with absolute URLs,
+//! dropping what is left of navigation, share bars and forms.
+
+use std::collections::{HashMap, HashSet};
+
+use dom::{Dom, NodeData, NodeId};
+
+use crate::browser::{DOC_ROOT, document_base_url, resolve_url};
+
+/// What reader mode shows of a page.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Article {
+    pub title: String,
+    pub byline: Option<String>,
+    /// `og:site_name`, or the host the page came from.
+    pub site_name: String,
+    /// The main content as sanitized HTML.
+    pub content: String,
+    /// Absolute URLs of the images in `content`, in document order.
+    pub images: Vec<String>,
+    pub word_count: usize,
+}
+
+/// Elements that never hold article text.
+const SKIPPED_TAGS: [&str; 19] = [
+    "script", "style", "noscript", "template", "nav", "aside", "footer", "form", "button", "input",
+    "select", "textarea", "iframe", "object", "embed", "canvas", "svg", "math", "dialog",
+];
+
+/// Elements scored as paragraphs.
+const PARAGRAPH_TAGS: [&str; 4] = ["p", "pre", "td", "blockquote"];
+
+/// Elements that make a `<div>` a container rather than a paragraph.
+const BLOCK_TAGS: [&str; 17] = [
+    "p", "div", "section", "article", "main", "ul", "ol", "dl", "table", "pre", "blockquote",
+    "figure", "h1", "h2", "h3", "h4", "h5",
+];
+
+/// Elements written out as themselves; everything else contributes only
+/// its children.
+const KEPT_TAGS: [&str; 39] = [
+    "p", "h2", "h3", "h4", "h5", "h6", "ul", "ol", "li", "dl", "dt", "dd", "blockquote", "pre",
+    "code", "em", "strong", "b", "i", "u", "s", "sub", "sup", "br", "hr", "figure", "figcaption",
+    "table", "caption", "thead", "tbody", "tfoot", "tr", "th", "td", "q", "cite", "mark", "small",
+];
+
+/// `class`/`id` words of likely content.
+const POSITIVE_HINTS: [&str; 12] = [
+    "article", "body", "content", "entry", "hentry", "main", "page", "post", "text", "blog", "story",
+    "prose",
+];
+
+/// `class`/`id` words of likely clutter.
+const NEGATIVE_HINTS: [&str; 22] = [
+    "ad", "ads", "advert", "banner", "combx", "comment", "community", "footer", "footnote", "masthead",
+    "menu", "meta", "nav", "outbrain", "promo", "related", "share", "shopping", "sidebar", "sponsor",
+    "social", "widget",
+];
+
+/// `class`/`id` words of subtrees dropped before scoring, unless they also
+/// carry one of [`MAYBE_HINTS`].
+const UNLIKELY_HINTS: [&str; 20] = [
+    "banner", "breadcrumbs", "combx", "comment", "community", "disqus", "extra",
+    "header", "legends", "menu", "related", "remark", "replies", "rss", "shoutbox", "sidebar",
+    "skyscraper", "social", "sponsor", "popup",
+];
+
+const MAYBE_HINTS: [&str; 6] = ["and", "article", "body", "column", "content", "main"];
+
+/// Separators between a headline and the site name in `<title>`.
+const TITLE_SEPARATORS: [&str; 6] = [" | ", " - ", " – ", " — ", " :: ", " » "];
+
+/// Words read per minute, for the reading-time estimate.
+const WORDS_PER_MINUTE: usize = 230;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Detection
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Whether the page looks like an article, which decides if the reader
+/// button is offered: enough long paragraphs outside of likely clutter.
+pub fn is_probably_readerable(dom: &Dom) -> bool {
+    let mut score = 0.0;
+    for id in dom.descendants(DOC_ROOT) {
+        if !matches!(tag(dom, id), "p" | "pre" | "article") || is_hidden(dom, id) || is_unlikely(dom, id) {
+            continue;
+        }
+        let length = inner_text(dom, id).chars().count();
+        if length >= 140 {
+            score += ((length - 140) as f32).sqrt();
+            if score > 20.0 {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Extraction
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Pull the article out of `dom`, the document loaded from `url`. `None`
+/// when nothing scores as content.
+pub fn extract(dom: &Dom, url: &str) -> Option<Article> {
+    let base = document_base_url(dom, url);
+    let body = dom.get_elements_by_tag(DOC_ROOT, "body").first().copied().unwrap_or(DOC_ROOT);
+    let skipped = unlikely_subtrees(dom, body);
+    let scores = score_candidates(dom, body, &skipped);
+    let (&top, &top_score) = scores.iter().max_by(|a, b| a.1.total_cmp(b.1).then(b.0.index.cmp(&a.0.index)))?;
+
+    let title = article_title(dom);
+    let byline_node = byline_element(dom, &skipped);
+    let byline = meta_content(dom, &["author", "article:author", "dc.creator"])
+        .filter(|v| !v.contains("://"))
+        .or_else(|| byline_node.map(|id| inner_text(dom, id)))
+        .filter(|v| !v.is_empty());
+
+    let mut writer = Writer {
+        dom,
+        base: &base,
+        title: title.clone(),
+        skipped,
+        byline: byline_node,
+        out: String::new(),
+        images: Vec::new(),
+        word_count: 0,
+    };
+    for id in article_nodes(dom, top, top_score, &scores) {
+        writer.write(id);
+    }
+    if writer.word_count == 0 {
+        return None;
+    }
+    if writer.images.is_empty()
+        && let Some(src) = meta_content(dom, &["og:image", "twitter:image"]).and_then(|s| absolute_url(&s, &base))
+    {
+        writer.out.insert_str(0, &format!("<figure><img src=\"{}\" alt=\"\"></figure>\n", escape(&src)));
+        writer.images.push(src);
+    }
+
+    let site_name = meta_content(dom, &["og:site_name"])
+        .or_else(|| url_parser::Url::parse(url).ok().map(|u| u.host))
+        .unwrap_or_default();
+    Some(Article {
+        title,
+        byline,
+        site_name,
+        content: writer.out,
+        images: writer.images,
+        word_count: writer.word_count,
+    })
+}
+
+/// Hidden elements and subtrees whose `class`/`id` marks them as clutter.
+fn unlikely_subtrees(dom: &Dom, body: NodeId) -> HashSet<NodeId> {
+    let mut skipped = HashSet::new();
+    for id in dom.descendants(body) {
+        if id == body || !dom.nodes.get(id).is_some_and(|n| n.is_element()) {
+            continue;
+        }
+        let inside_skipped = dom.ancestors(id).iter().any(|a| skipped.contains(a));
+        if !inside_skipped
+            && (SKIPPED_TAGS.contains(&tag(dom, id)) || is_hidden(dom, id) || is_unlikely(dom, id))
+        {
+            skipped.insert(id);
+        }
+    }
+    skipped
+}
+
+/// Score the containers of every paragraph under `body`, each already
+/// scaled by its link density.
+fn score_candidates(dom: &Dom, body: NodeId, skipped: &HashSet<NodeId>) -> HashMap<NodeId, f32> {
+    let mut scores: HashMap<NodeId, f32> = HashMap::new();
+    for id in dom.descendants(body) {
+        if !is_paragraph(dom, id) || dom.ancestors(id).iter().chain([&id]).any(|a| skipped.contains(a)) {
+            continue;
+        }
+        let text = inner_text(dom, id);
+        let length = text.chars().count();
+        if length < 25 {
+            continue;
+        }
+        let score = 1.0 + text.matches([',', '，']).count() as f32 + (length / 100).min(3) as f32;
+        let parent = dom.nodes.get(id).and_then(|n| n.parent);
+        let ancestors = std::iter::successors(parent, |&a| dom.nodes.get(a).and_then(|n| n.parent));
+        for (level, ancestor) in ancestors.take(3).enumerate() {
+            if !dom.nodes.get(ancestor).is_some_and(|n| n.is_element()) {
+                break;
+            }
+            let divider = match level {
+                0 => 1.0,
+                1 => 2.0,
+                _ => level as f32 * 3.0,
+            };
+            *scores.entry(ancestor).or_insert_with(|| initial_score(dom, ancestor)) += score / divider;
+        }
+    }
+    for (&id, score) in scores.iter_mut() {
+        *score *= 1.0 - link_density(dom, id);
+    }
+    scores
+}
+
+/// A `<p>` and friends, or a `<div>`/`<section>` with text but no blocks.
+fn is_paragraph(dom: &Dom, id: NodeId) -> bool {
+    match tag(dom, id) {
+        t if PARAGRAPH_TAGS.contains(&t) => true,
+        "div" | "section" => !dom.descendants(id).into_iter().skip(1).any(|d| BLOCK_TAGS.contains(&tag(dom, d))),
+        _ => false,
+    }
+}
+
+fn initial_score(dom: &Dom, id: NodeId) -> f32 {
+    let by_tag = match tag(dom, id) {
+        "article" => 10.0,
+        "div" | "main" => 5.0,
+        "pre" | "td" | "blockquote" => 3.0,
+        "address" | "ol" | "ul" | "dl" | "dd" | "dt" | "li" | "form" => -3.0,
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th" => -5.0,
+        _ => 0.0,
+    };
+    by_tag + class_weight(dom, id)
+}
+
+/// The top candidate plus the siblings that belong with it: other
+/// well-scoring containers and stray paragraphs of prose.
+fn article_nodes(dom: &Dom, top: NodeId, top_score: f32, scores: &HashMap<NodeId, f32>) -> Vec<NodeId> {
+    let Some(parent) = dom.nodes.get(top).and_then(|n| n.parent) else {
+        return vec![top];
+    };
+    if tag(dom, top) == "body" {
+        return vec![top];
+    }
+    let threshold = (top_score * 0.2).max(10.0);
+    let top_class = dom.nodes.get(top).and_then(|n| n.as_element()).map(|e| e.classes.clone()).unwrap_or_default();
+    dom.children(parent)
+        .into_iter()
+        .filter(|&sibling| {
+            if sibling == top {
+                return true;
+            }
+            let Some(element) = dom.nodes.get(sibling).and_then(|n| n.as_element()) else {
+                return false;
+            };
+            let bonus = if !top_class.is_empty() && element.classes == top_class { top_score * 0.2 } else { 0.0 };
+            if scores.get(&sibling).is_some_and(|s| s + bonus >= threshold) {
+                return true;
+            }
+            if element.tag_name != "p" {
+                return false;
+            }
+            let text = inner_text(dom, sibling);
+            let length = text.chars().count();
+            let density = link_density(dom, sibling);
+            (length > 80 && density < 0.25) || (length > 0 && density == 0.0 && text.contains(". "))
+        })
+        .collect()
+}
+
+/// `og:title`, else `<title>` without the site name, else the first `<h1>`.
+fn article_title(dom: &Dom) -> String {
+    if let Some(title) = meta_content(dom, &["og:title", "twitter:title"]) {
+        return title;
+    }
+    let h1 = || dom.get_elements_by_tag(DOC_ROOT, "h1").first().map(|&h| inner_text(dom, h)).unwrap_or_default();
+    let Some(title) = dom.get_elements_by_tag(DOC_ROOT, "title").first().map(|&t| inner_text(dom, t)) else {
+        return h1();
+    };
+    if title.is_empty() {
+        return h1();
+    }
+    let cut = TITLE_SEPARATORS.iter().filter_map(|sep| title.rfind(sep)).max();
+    match cut {
+        Some(at) if title[..at].split_whitespace().count() >= 3 => title[..at].trim().to_string(),
+        _ => title,
+    }
+}
+
+/// An element naming the author: `rel="author"`, `itemprop="author"`, or a
+/// short one with a `byline`/`author` class or id.
+fn byline_element(dom: &Dom, skipped: &HashSet<NodeId>) -> Option<NodeId> {
+    dom.descendants(DOC_ROOT).into_iter().find(|&id| {
+        let Some(element) = dom.nodes.get(id).and_then(|n| n.as_element()) else {
+            return false;
+        };
+        let marked = attr(dom, id, "rel") == Some("author")
+            || attr(dom, id, "itemprop").is_some_and(|v| v.split_whitespace().any(|w| w == "author"))
+            || hint_words(element.id.as_deref(), &element.classes)
+                .iter()
+                .any(|w| w.contains("byline") || w == "author");
+        marked && !skipped.contains(&id) && (1..100).contains(&inner_text(dom, id).chars().count())
+    })
+}
+
+/// The `content` of the first `<meta>` whose `name` or `property` is one of
+/// `names`, in order of preference.
+fn meta_content(dom: &Dom, names: &[&str]) -> Option<String> {
+    let metas = dom.get_elements_by_tag(DOC_ROOT, "meta");
+    names.iter().find_map(|name| {
+        metas.iter().find_map(|&m| {
+            let key = attr(dom, m, "property").or_else(|| attr(dom, m, "name"))?;
+            key.eq_ignore_ascii_case(name)
+                .then(|| collapse_whitespace(attr(dom, m, "content").unwrap_or_default()))
+                .filter(|v| !v.is_empty())
+        })
+    })
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Output
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Writes the chosen subtrees as clean HTML.
+struct Writer<'a> {
+    dom: &'a Dom,
+    base: &'a str,
+    title: String,
+    skipped: HashSet<NodeId>,
+    byline: Option<NodeId>,
+    out: String,
+    images: Vec<String>,
+    word_count: usize,
+}
+
+impl Writer<'_> {
+    fn write(&mut self, id: NodeId) {
+        let dom = self.dom;
+        let Some(node) = dom.nodes.get(id) else { return };
+        let element = match &node.data {
+            NodeData::Text { data } => {
+                self.word_count += data.split_whitespace().count();
+                self.out.push_str(&escape(data));
+                return;
+            }
+            NodeData::Element(element) => element,
+            _ => return,
+        };
+        if self.skipped.contains(&id) || Some(id) == self.byline || self.is_clutter(id) {
+            return;
+        }
+        let tag = element.tag_name.as_str();
+        match tag {
+            "img" => self.write_image(id),
+            "a" => match attr(dom, id, "href").and_then(|href| absolute_url(href, self.base)) {
+                Some(href) => {
+                    self.out.push_str(&format!("<a href=\"{}\">", escape(&href)));
+                    self.write_children(id);
+                    self.out.push_str("</a>");
+                }
+                None => self.write_children(id),
+            },
+            // The title is shown above the content already.
+            "h1" if inner_text(dom, id) == self.title => {}
+            "h1" => self.write_wrapped(id, "h2"),
+            "div" | "section" | "article" | "main" | "header" => self.write_wrapped(id, "div"),
+            t if KEPT_TAGS.contains(&t) => self.write_wrapped(id, t),
+            _ => self.write_children(id),
+        }
+    }
+
+    fn write_wrapped(&mut self, id: NodeId, tag: &str) {
+        self.out.push_str(&format!("<{tag}>"));
+        if tag != "br" && tag != "hr" {
+            self.write_children(id);
+            self.out.push_str(&format!("</{tag}>"));
+        }
+        if matches!(tag, "p" | "div" | "li" | "tr" | "figure" | "pre" | "blockquote" | "ul" | "ol" | "table") {
+            self.out.push('\n');
+        }
+    }
+
+    fn write_children(&mut self, id: NodeId) {
+        for child in self.dom.children(id) {
+            self.write(child);
+        }
+    }
+
+    /// An `<img>` with its lazy-loading source resolved; tracking pixels
+    /// and inline placeholders are dropped.
+    fn write_image(&mut self, id: NodeId) {
+        let dom = self.dom;
+        let tiny = ["width", "height"]
+            .iter()
+            .any(|a| attr(dom, id, a).and_then(|v| v.trim().parse::<u32>().ok()).is_some_and(|v| v <= 2));
+        let src = ["data-src", "data-original", "data-lazy-src", "src"]
+            .iter()
+            .filter_map(|a| attr(dom, id, a))
+            .chain(attr(dom, id, "srcset").and_then(|s| s.split_whitespace().next()))
+            .find(|v| !v.trim().is_empty() && !v.starts_with("data:"))
+            .and_then(|v| absolute_url(v.trim(), self.base));
+        let Some(src) = src.filter(|_| !tiny) else { return };
+        let alt = attr(dom, id, "alt").unwrap_or_default();
+        self.out.push_str(&format!("<img src=\"{}\" alt=\"{}\">", escape(&src), escape(alt)));
+        self.images.push(src);
+    }
+
+    /// Containers inside the article that read like navigation or chrome:
+    /// negative hints, mostly links, or too little text to be content.
+    fn is_clutter(&self, id: NodeId) -> bool {
+        let dom = self.dom;
+        if !matches!(tag(dom, id), "div" | "section" | "ul" | "ol" | "table" | "header") {
+            return false;
+        }
+        let weight = class_weight(dom, id);
+        if weight < 0.0 {
+            return true;
+        }
+        let text = inner_text(dom, id);
+        let images = dom.get_elements_by_tag(id, "img").len();
+        let paragraphs = dom.get_elements_by_tag(id, "p").len();
+        let short = text.chars().count() < 25 && images == 0 && !matches!(tag(dom, id), "ul" | "ol" | "table");
+        (link_density(dom, id) > 0.5 && weight < 25.0) || short || (images > 2 && images > paragraphs + 1 && text.chars().count() < 100)
+    }
+}
+
+/// The reader page for `article`.
+pub fn reader_html(article: &Article) -> String {
+    let byline = article
+        .byline
+        .as_ref()
+        .map(|b| format!("<p class=\"byline\">{}</p>\n", escape(b)))
+        .unwrap_or_default();
+    let minutes = article.word_count.div_ceil(WORDS_PER_MINUTE).max(1);
+    format!(
+        r#"<!DOCTYPE html>
+<html><head><meta charset="utf-8"><title>{title}</title>
+<style>
+body {{ background: #fbfaf7; color: #1f1f1f; font-family: Georgia, serif; margin: 0; }}
+.reader {{ max-width: 680px; margin: 0 auto; padding: 32px 24px 64px; }}
+.site {{ color: #8a6d3b; font-family: sans-serif; font-size: 13px; text-transform: uppercase; letter-spacing: 1px; margin: 0 0 8px; }}
+h1 {{ font-size: 34px; line-height: 1.2; margin: 0 0 12px; }}
+.byline, .time {{ color: #5f6368; font-family: sans-serif; font-size: 14px; margin: 4px 0; }}
+hr {{ border: none; border-top: 1px solid #e0ddd5; margin: 24px 0; }}
+.content {{ font-size: 19px; line-height: 1.6; }}
+.content p {{ margin: 0 0 18px; }}
+.content h2, .content h3, .content h4 {{ font-family: sans-serif; line-height: 1.3; margin: 32px 0 12px; }}
+.content img {{ max-width: 100%; height: auto; }}
+.content figure {{ margin: 24px 0; }}
+.content figcaption {{ color: #5f6368; font-size: 14px; }}
+.content blockquote {{ border-left: 3px solid #d6d2c8; margin: 18px 0; padding-left: 16px; color: #444; }}
+.content pre {{ background: #f1eee6; padding: 12px; font-family: monospace; font-size: 14px; white-space: pre-wrap; }}
+.content code {{ font-family: monospace; font-size: 15px; }}
+.content a {{ color: #1a5fb4; }}
+</style></head>
+<body><div class="reader">
+<p class="site">{site}</p>
+<h1>{title}</h1>
+{byline}<p class="time">{minutes} min read</p>
+<hr>
+<div class="content">
+{content}
+</div>
+</div></body></html>"#,
+        title = escape(&article.title),
+        site = escape(&article.site_name),
+        content = article.content,
+    )
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn tag(dom: &Dom, id: NodeId) -> &str {
+    dom.nodes.get(id).and_then(|n| n.as_element()).map_or("", |e| e.tag_name.as_str())
+}
+
+fn attr<'a>(dom: &'a Dom, id: NodeId, name: &str) -> Option<&'a str> {
+    let element = dom.nodes.get(id)?.as_element()?;
+    element.attrs.iter().find(|a| a.name == name).map(|a| a.value.as_str())
+}
+
+/// The visible text under `id`, whitespace collapsed.
+fn inner_text(dom: &Dom, id: NodeId) -> String {
+    fn collect(dom: &Dom, id: NodeId, out: &mut String) {
+        match dom.nodes.get(id).map(|n| &n.data) {
+            Some(NodeData::Text { data }) => out.push_str(data),
+            Some(NodeData::Element(e)) if matches!(e.tag_name.as_str(), "script" | "style" | "noscript" | "template") => {}
+            Some(_) => {
+                for child in dom.children(id) {
+                    collect(dom, child, out);
+                }
+            }
+            None => {}
+        }
+    }
+    let mut text = String::new();
+    collect(dom, id, &mut text);
+    collapse_whitespace(&text)
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The share of the text under `id` that is link text.
+fn link_density(dom: &Dom, id: NodeId) -> f32 {
+    let length = inner_text(dom, id).chars().count();
+    if length == 0 {
+        return 0.0;
+    }
+    let linked: usize = dom
+        .get_elements_by_tag(id, "a")
+        .into_iter()
+        .map(|a| {
+            // In-page anchors (footnotes, tables of contents) count less.
+            let weight = if attr(dom, a, "href").is_some_and(|h| h.starts_with('#')) { 0.3 } else { 1.0 };
+            (inner_text(dom, a).chars().count() as f32 * weight) as usize
+        })
+        .sum();
+    (linked as f32 / length as f32).min(1.0)
+}
+
+/// The lowercase words of an element's `id` and classes, split at
+/// punctuation so that `ad-slot` yields `ad` and `slot`.
+fn hint_words(id: Option<&str>, classes: &[String]) -> Vec<String> {
+    id.into_iter()
+        .chain(classes.iter().map(String::as_str))
+        .flat_map(|v| v.split(|c: char| !c.is_ascii_alphanumeric()))
+        .filter(|w| !w.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect()
+}
+
+/// Whether any word matches one of `hints`: exactly, or by containing a
+/// hint of five letters or more (`comments`, `articlebody`).
+fn has_hint(words: &[String], hints: &[&str]) -> bool {
+    words.iter().any(|w| hints.iter().any(|h| w == h || (h.len() >= 5 && w.contains(h))))
+}
+
+fn class_weight(dom: &Dom, id: NodeId) -> f32 {
+    let Some(element) = dom.nodes.get(id).and_then(|n| n.as_element()) else {
+        return 0.0;
+    };
+    let words = hint_words(element.id.as_deref(), &element.classes);
+    let mut weight = 0.0;
+    if has_hint(&words, &NEGATIVE_HINTS) {
+        weight -= 25.0;
+    }
+    if has_hint(&words, &POSITIVE_HINTS) {
+        weight += 25.0;
+    }
+    weight
+}
+
+fn is_unlikely(dom: &Dom, id: NodeId) -> bool {
+    let Some(element) = dom.nodes.get(id).and_then(|n| n.as_element()) else {
+        return false;
+    };
+    if matches!(element.tag_name.as_str(), "html" | "body" | "article" | "main" | "a") {
+        return false;
+    }
+    let words = hint_words(element.id.as_deref(), &element.classes);
+    attr(dom, id, "role").is_some_and(|r| matches!(r, "navigation" | "complementary" | "banner" | "dialog"))
+        || (has_hint(&words, &UNLIKELY_HINTS) && !has_hint(&words, &MAYBE_HINTS))
+}
+
+fn is_hidden(dom: &Dom, id: NodeId) -> bool {
+    attr(dom, id, "hidden").is_some()
+        || attr(dom, id, "aria-hidden") == Some("true")
+        || attr(dom, id, "style").is_some_and(|s| {
+            let s: String = s.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_ascii_lowercase();
+            s.contains("display:none") || s.contains("visibility:hidden")
+        })
+}
+
+/// `href` resolved against `base`; `None` for in-page fragments and for
+/// schemes other than http(s) and mailto.
+fn absolute_url(href: &str, base: &str) -> Option<String> {
+    let href = href.trim();
+    if href.is_empty() || href.starts_with('#') {
+        return None;
+    }
+    if let Some(rest) = href.strip_prefix("//") {
+        let scheme = base.split_once("://").map_or("https", |(s, _)| s);
+        return Some(format!("{scheme}://{rest}"));
+    }
+    let scheme = href.split_once(':').map(|(s, _)| s).filter(|s| s.chars().all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c)));
+    match scheme.map(str::to_ascii_lowercase).as_deref() {
+        Some("http" | "https" | "mailto") => Some(href.to_string()),
+        Some(_) => None,
+        None => Some(resolve_url(href, base)),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAGRAPH: &str = "The committee met on Tuesday to discuss the proposal, which had been \
+        circulating for several weeks, and after a long debate about costs, schedules and the \
+        likely effect on residents it voted to approve the first phase of the plan.";
+
+    fn article_page() -> String {
+        format!(
+            r#"<html><head><title>Council approves new bridge plan | The Daily Example</title>
+            <meta name="author" content="Jane Doe"></head><body>
+            <nav><a href="/">Home</a> <a href="/news">News</a> <a href="/sport">Sport</a></nav>
+            <div class="sidebar"><p>{PARAGRAPH}</p></div>
+            <div id="main-content" class="article-body">
+              <h1>Council approves new bridge plan</h1>
+              <p>{PARAGRAPH}</p>
+              <p>{PARAGRAPH} <a href="/bridge">Read more about the bridge</a>.</p>
+              <figure><img data-src="/img/bridge.jpg" src="data:image/gif;base64,R0lGOD" alt="The bridge"><figcaption>An artist's impression.</figcaption></figure>
+              <div class="share-tools"><a href="https://social.example/share">Share</a></div>
+              <p>{PARAGRAPH}</p>
+            </div>
+            <div class="comments"><p>{PARAGRAPH}</p></div>
+            <footer><p>Copyright The Daily Example</p></footer>
+            </body></html>"#
+        )
+    }
+
+    #[test]
+    fn test_extracts_main_content_title_and_byline() {
+        let dom = html::parse(&article_page());
+        assert!(is_probably_readerable(&dom));
+        let article = extract(&dom, "https://news.example/local/bridge.html").unwrap();
+        assert_eq!(article.title, "Council approves new bridge plan");
+        assert_eq!(article.byline.as_deref(), Some("Jane Doe"));
+        assert_eq!(article.site_name, "news.example");
+        assert_eq!(article.content.matches("<p>").count(), 3, "{}", article.content);
+        assert!(article.content.contains("<a href=\"https://news.example/bridge\">"));
+        assert!(!article.content.contains("Share"));
+        assert!(!article.content.contains("Home"));
+        assert!(!article.content.contains("<h"), "the headline is shown once, above the content");
+        assert_eq!(article.images, vec!["https://news.example/img/bridge.jpg".to_string()]);
+        assert!(article.word_count > 120);
+    }
+
+    #[test]
+    fn test_short_pages_are_not_readerable() {
+        let dom = html::parse("<p>Welcome!</p><ul><li><a href=/a>A</a></li></ul>");
+        assert!(!is_probably_readerable(&dom));
+        assert_eq!(extract(&dom, "https://example.com/"), None);
+    }
+
+    #[test]
+    fn test_byline_element_and_lead_image() {
+        let page = format!(
+            r#"<head><meta property="og:title" content="A Headline"><meta property="og:image" content="//cdn.example/lead.png">
+            <meta property="og:site_name" content="Example Times"></head>
+            <article><p class="byline">By Sam Smith</p><p>{PARAGRAPH}</p><p>{PARAGRAPH}</p></article>"#
+        );
+        let dom = html::parse(&page);
+        let article = extract(&dom, "https://example.com/story").unwrap();
+        assert_eq!(article.title, "A Headline");
+        assert_eq!(article.byline.as_deref(), Some("By Sam Smith"));
+        assert_eq!(article.site_name, "Example Times");
+        assert!(!article.content.contains("Sam Smith"));
+        assert_eq!(article.images, vec!["https://cdn.example/lead.png".to_string()]);
+
+        let html = reader_html(&article);
+        assert!(html.contains("<h1>A Headline</h1>"));
+        assert!(html.contains("1 min read"));
+    }
+}
@@ -0,0 +1,105 @@
+//! Declarative refresh: `<meta http-equiv="refresh">` and the `Refresh`
+//! response header, which reload the page or redirect it after a delay.
+//!
+//! Both use the same value syntax, `5` or `0; url=https://example.com/`,
+//! parsed by the "shared declarative refresh steps" (HTML §7.11.1).
+
+use std::time::Duration;
+
+use dom::Dom;
+
+use crate::browser::{DOC_ROOT, resolve_url};
+
+/// A navigation a document asks for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Refresh {
+    pub delay: Duration,
+    /// Absolute URL to load; the document's own URL for a plain reload.
+    pub url: String,
+}
+
+/// Parse a refresh value for the document at `url`, resolving a target
+/// against `base_url`. `None` if the value is malformed.
+pub fn parse(value: &str, url: &str, base_url: &str) -> Option<Refresh> {
+    let value = value.trim_start_matches(is_space);
+    let digits = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+    if digits == 0 && !value.starts_with('.') {
+        return None;
+    }
+    let seconds: u64 = value[..digits].parse().unwrap_or(0);
+    // Fractional seconds are allowed but ignored.
+    let rest = value[digits..].trim_start_matches(|c: char| c.is_ascii_digit() || c == '.');
+    let delay = Duration::from_secs(seconds);
+
+    let rest = rest.trim_start_matches(is_space);
+    if rest.is_empty() {
+        return Some(Refresh { delay, url: url.to_string() });
+    }
+    let rest = rest.strip_prefix([';', ','])?.trim_start_matches(is_space);
+    let rest = match rest.get(..3) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("url") => {
+            let after = rest[3..].trim_start_matches(is_space);
+            match after.strip_prefix('=') {
+                Some(after) => after.trim_start_matches(is_space),
+                None => rest,
+            }
+        }
+        _ => rest,
+    };
+    let target = match rest.chars().next() {
+        Some(quote @ ('"' | '\'')) => {
+            let inner = &rest[1..];
+            inner.find(quote).map_or(inner, |end| &inner[..end])
+        }
+        _ => rest,
+    };
+    let target = target.trim_end_matches(is_space);
+    let url = if target.is_empty() { url.to_string() } else { resolve_url(target, base_url) };
+    Some(Refresh { delay, url })
+}
+
+/// The refresh asked for by the first `<meta http-equiv="refresh">` of
+/// `dom` with a valid `content`.
+pub fn from_meta(dom: &Dom, url: &str, base_url: &str) -> Option<Refresh> {
+    dom.get_elements_by_tag(DOC_ROOT, "meta").into_iter().find_map(|meta| {
+        let element = dom.nodes.get(meta)?.as_element()?;
+        let attr = |name: &str| element.attrs.iter().find(|a| a.name == name).map(|a| a.value.as_str());
+        if !attr("http-equiv")?.trim().eq_ignore_ascii_case("refresh") {
+            return None;
+        }
+        parse(attr("content")?, url, base_url)
+    })
+}
+
+fn is_space(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\x0C' | '\r')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://example.com/dir/page";
+
+    #[test]
+    fn test_delays_and_targets() {
+        let reload = parse(" 5", URL, URL).unwrap();
+        assert_eq!((reload.delay, reload.url.as_str()), (Duration::from_secs(5), URL));
+        let redirect = parse("0; URL = 'next.html'", URL, URL).unwrap();
+        assert_eq!(redirect.delay, Duration::ZERO);
+        assert_eq!(redirect.url, "https://example.com/dir/next.html");
+        let bare = parse("2.5,https://other.example/", URL, URL).unwrap();
+        assert_eq!((bare.delay.as_secs(), bare.url.as_str()), (2, "https://other.example/"));
+        assert_eq!(parse("soon", URL, URL), None);
+        assert_eq!(parse("3 url=x", URL, URL), None);
+    }
+
+    #[test]
+    fn test_meta_refresh_resolves_against_base() {
+        let dom = html::parse(
+            r#"<base href="https://cdn.example/a/"><meta http-equiv="Refresh" content="1;url=b.html">"#,
+        );
+        let refresh = from_meta(&dom, URL, "https://cdn.example/a/").unwrap();
+        assert_eq!(refresh.url, "https://cdn.example/a/b.html");
+    }
+}
@@ -0,0 +1,260 @@
+//! Connection security shown to the user — the certificate error
+//! interstitial and the address bar's site-info popover.
+//!
+//! A page records how it was loaded as a [`SiteSecurity`]. When TLS
+//! validation fails the navigation renders [`certificate_error_html`]
+//! instead of the page; its "Proceed" link ([`PROCEED_URL`]) adds a
+//! per-host exception for the rest of the session and reloads.
+
+use net::{CertificateError, InvalidCertificate, X509Certificate};
+
+/// Link target of the interstitial's "Proceed" link. Handled by the
+/// browser rather than navigated to.
+pub const PROCEED_URL: &str = "about:certerror-proceed";
+
+/// The fields of a server certificate shown to the user.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CertificateSummary {
+    pub subject: String,
+    pub issuer: String,
+    /// DNS names the certificate is valid for (the subject CN if it has
+    /// no SAN extension).
+    pub names: Vec<String>,
+    pub valid_from: String,
+    pub valid_until: String,
+    /// Serial number as colon-separated hex.
+    pub serial: String,
+    /// SHA-256 fingerprint, which identifies the certificate for exceptions.
+    pub fingerprint: [u8; 32],
+}
+
+impl CertificateSummary {
+    pub fn new(cert: &X509Certificate) -> Self {
+        let names = if cert.san_dns.is_empty() {
+            vec![cert.subject.clone()]
+        } else {
+            cert.san_dns.clone()
+        };
+        Self {
+            subject: cert.subject.clone(),
+            issuer: cert.issuer.clone(),
+            names,
+            valid_from: tls::x509::format_time(&cert.not_before),
+            valid_until: tls::x509::format_time(&cert.not_after),
+            serial: hex(&cert.serial_number, ":"),
+            fingerprint: cert.fingerprint,
+        }
+    }
+
+    /// The fingerprint as space-separated hex pairs.
+    pub fn fingerprint_hex(&self) -> String {
+        hex(&self.fingerprint, " ")
+    }
+}
+
+/// How the page in a tab was loaded.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum SiteSecurity {
+    /// Built-in pages (`about:`); no indicator is shown.
+    #[default]
+    Internal,
+    /// Plain HTTP.
+    Insecure,
+    /// HTTPS with a certificate that passed validation.
+    Secure(CertificateSummary),
+    /// HTTPS allowed through a user exception despite `error`.
+    Exception {
+        certificate: Option<CertificateSummary>,
+        error: CertificateError,
+    },
+    /// The interstitial shown in place of a page whose certificate failed.
+    Blocked {
+        certificate: Option<CertificateSummary>,
+        error: CertificateError,
+    },
+}
+
+impl SiteSecurity {
+    /// Classify a completed fetch.
+    pub fn from_response(response: &net::FetchResponse) -> Self {
+        if !response.was_tls {
+            return Self::Insecure;
+        }
+        let certificate = response.certificate.as_ref().map(CertificateSummary::new);
+        match (&response.certificate_error, certificate) {
+            (Some(error), certificate) => Self::Exception {
+                certificate,
+                error: error.clone(),
+            },
+            (None, Some(certificate)) => Self::Secure(certificate),
+            (None, None) => Self::Insecure,
+        }
+    }
+
+    pub fn blocked(invalid: &InvalidCertificate) -> Self {
+        Self::Blocked {
+            certificate: invalid.chain.first().map(CertificateSummary::new),
+            error: invalid.error.clone(),
+        }
+    }
+
+    /// Whether the address bar shows the warning (red) indicator.
+    pub fn is_warning(&self) -> bool {
+        matches!(self, Self::Exception { .. } | Self::Blocked { .. })
+    }
+
+    fn certificate(&self) -> Option<&CertificateSummary> {
+        match self {
+            Self::Secure(certificate) => Some(certificate),
+            Self::Exception { certificate, .. } | Self::Blocked { certificate, .. } => {
+                certificate.as_ref()
+            }
+            Self::Internal | Self::Insecure => None,
+        }
+    }
+
+    /// Lines of the site-info popover: a headline, an explanation, then
+    /// certificate details when there is a certificate.
+    pub fn site_info_lines(&self, host: &str) -> Vec<String> {
+        let mut lines = match self {
+            Self::Internal => vec!["This is a built-in browser page.".to_string()],
+            Self::Insecure => vec![
+                "Connection is not secure".to_string(),
+                format!("Information you send to {host} could be read by others."),
+            ],
+            Self::Secure(_) => vec![
+                "Connection is secure".to_string(),
+                format!("Your connection to {host} is encrypted and its certificate is valid."),
+            ],
+            Self::Exception { error, .. } => vec![
+                "Connection is not secure".to_string(),
+                format!("You chose to proceed despite an error: {error}."),
+            ],
+            Self::Blocked { error, .. } => vec![
+                "Certificate error".to_string(),
+                format!("The connection was stopped: {error}."),
+            ],
+        };
+        if let Some(cert) = self.certificate() {
+            lines.push(format!("Issued to: {}", cert.subject));
+            lines.push(format!("Issued by: {}", cert.issuer));
+            lines.push(format!("Valid until: {}", cert.valid_until));
+            // Split so each half fits the popover.
+            lines.push(format!("SHA-256: {}", hex(&cert.fingerprint[..16], " ")));
+            lines.push(format!("         {}", hex(&cert.fingerprint[16..], " ")));
+        }
+        lines
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Interstitial
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// What went wrong, phrased for someone who doesn't know what a
+/// certificate is.
+fn explain(error: &CertificateError, host: &str) -> String {
+    let host = escape(host);
+    match error {
+        CertificateError::HostnameMismatch { names, .. } => format!(
+            "This server could not prove that it is <b>{host}</b>; its security certificate \
+             is from <b>{}</b>. This may be caused by a misconfiguration or an attacker \
+             intercepting your connection.",
+            escape(&names.join(", "))
+        ),
+        CertificateError::Expired { not_after } => format!(
+            "This server could not prove that it is <b>{host}</b>; its security certificate \
+             expired on {}. If your computer's clock is correct, the site may be \
+             misconfigured or someone may be intercepting your connection.",
+            tls::x509::format_time(not_after)
+        ),
+        CertificateError::NotYetValid { not_before } => format!(
+            "This server could not prove that it is <b>{host}</b>; its security certificate \
+             is not valid until {}. Your computer's clock may be set wrong.",
+            tls::x509::format_time(not_before)
+        ),
+        CertificateError::SelfSigned { .. } => format!(
+            "This server could not prove that it is <b>{host}</b>; its security certificate \
+             is self-signed, so no trusted authority vouches for it."
+        ),
+        CertificateError::UntrustedIssuer { issuer } => format!(
+            "This server could not prove that it is <b>{host}</b>; its security certificate \
+             was issued by <b>{}</b>, which is not a trusted certificate authority.",
+            escape(issuer)
+        ),
+        CertificateError::Empty
+        | CertificateError::Malformed(_)
+        | CertificateError::BadChain { .. }
+        | CertificateError::BadSignature { .. } => format!(
+            "<b>{host}</b> sent an invalid security certificate, so the connection \
+             cannot be verified."
+        ),
+    }
+}
+
+/// The page shown instead of `url` when its certificate was rejected.
+pub fn certificate_error_html(url: &str, host: &str, invalid: &InvalidCertificate) -> String {
+    let explanation = explain(&invalid.error, host);
+    let code = invalid.error.code();
+    let details = match invalid.chain.first().map(CertificateSummary::new) {
+        Some(cert) => {
+            let rows = [
+                ("Issued to", cert.subject.clone()),
+                ("Issued by", cert.issuer.clone()),
+                ("Names", cert.names.join(", ")),
+                ("Valid from", cert.valid_from.clone()),
+                ("Valid until", cert.valid_until.clone()),
+                ("Serial number", cert.serial.clone()),
+                ("SHA-256", cert.fingerprint_hex()),
+            ];
+            let rows: String = rows
+                .iter()
+                .map(|(k, v)| format!("<tr><th>{k}</th><td>{}</td></tr>\n", escape(v)))
+                .collect();
+            format!(
+                "<h2>Certificate</h2>\n<table>\n{rows}</table>\n\
+                 <p class=\"proceed\"><a href=\"{PROCEED_URL}\">Proceed to {} (unsafe)</a></p>",
+                escape(host)
+            )
+        }
+        None => String::new(),
+    };
+    let url = escape(url);
+    format!(
+        r#"<html><head><title>Privacy error</title><style>
+body {{ background: #ffffff; color: #333; padding: 50px 20px; }}
+.box {{ max-width: 600px; margin: 0 auto; }}
+h1 {{ font-size: 28px; color: #d93025; margin: 0 0 12px 0; }}
+h2 {{ font-size: 16px; margin: 24px 0 8px 0; }}
+p {{ font-size: 15px; color: #555; margin: 8px 0; }}
+.code {{ font-size: 12px; color: #999; font-family: monospace; }}
+.url {{ font-size: 13px; color: #999; }}
+th {{ text-align: left; font-size: 13px; color: #666; padding: 2px 12px 2px 0; }}
+td {{ font-size: 13px; font-family: monospace; }}
+.back {{ margin-top: 20px; }}
+.proceed a {{ color: #d93025; font-size: 13px; }}
+</style></head><body><div class="box">
+<h1>Your connection is not private</h1>
+<p>{explanation}</p>
+<p class="url">{url}</p>
+<p class="code">{code}</p>
+<p class="back"><a href="about:newtab">Back to safety</a></p>
+{details}
+</div></body></html>"#
+    )
+}
+
+fn hex(bytes: &[u8], sep: &str) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{b:02X}"))
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
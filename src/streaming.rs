@@ -0,0 +1,337 @@
+//! Incremental document loading.
+//!
+//! A [`DocumentStream`] takes the response body piece by piece while it
+//! downloads: it decodes it, feeds the HTML parser, runs parser-inserted
+//! scripts (applying their `document.write` output) and passes the
+//! subresources found by the preload scanner, and those named by `Link`
+//! headers, to a [`Preloader`], which fetches them on a background thread
+//! so they are ready when the page needs them.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::mpsc;
+
+use dom::{Dom, NodeData, NodeId};
+use encoding::{Confidence, EncodingLabel, StreamDecoder};
+use html::preload::{PreloadRequest, PreloadScanner};
+use html::{ParseStatus, Parser};
+
+use crate::bindings::ScriptHost;
+use crate::browser::{document_base_url, resolve_url, run_js_in};
+use crate::frames;
+
+type FetchResult = Result<Vec<u8>, String>;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Preloader
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Fetches subresources on a background thread, one at a time, using its
+/// own [`net::NetworkService`].
+pub struct Preloader {
+    requests: mpsc::Sender<String>,
+    results: mpsc::Receiver<(String, FetchResult)>,
+    /// URLs requested and not yet taken.
+    requested: HashSet<String>,
+    /// Results received while waiting for a different URL.
+    finished: HashMap<String, FetchResult>,
+}
+
+impl Preloader {
+    pub fn new(mut network: net::NetworkService) -> Self {
+        let (requests, queue) = mpsc::channel::<String>();
+        let (done, results) = mpsc::channel();
+        std::thread::spawn(move || {
+            for url in queue {
+                let result = net::FetchRequest::get(&url).and_then(|request| {
+                    network
+                        .fetch(request)
+                        .map(|response| response.body)
+                        .map_err(|e| e.to_string())
+                });
+                if done.send((url, result)).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            requests,
+            results,
+            requested: HashSet::new(),
+            finished: HashMap::new(),
+        }
+    }
+
+    /// Start fetching `url` unless it was already requested.
+    pub fn request(&mut self, url: &str) {
+        if self.requested.insert(url.to_string()) {
+            let _ = self.requests.send(url.to_string());
+        }
+    }
+
+    /// The body of a requested URL, waiting for it if it is still
+    /// downloading. `None` if `url` was never requested.
+    pub fn take(&mut self, url: &str) -> Option<FetchResult> {
+        if !self.requested.remove(url) {
+            return None;
+        }
+        loop {
+            if let Some(result) = self.finished.remove(url) {
+                return Some(result);
+            }
+            let (done, result) = self.results.recv().ok()?;
+            self.finished.insert(done, result);
+        }
+    }
+
+    /// Wait for every outstanding request and return the bodies that were
+    /// fetched successfully, keyed by URL.
+    pub fn finish(self) -> HashMap<String, Vec<u8>> {
+        let Self {
+            requests,
+            results,
+            finished,
+            ..
+        } = self;
+        // Closing the queue lets the worker exit once it is drained.
+        drop(requests);
+        finished
+            .into_iter()
+            .chain(results)
+            .filter_map(|(url, result)| Some((url, result.ok()?)))
+            .collect()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// DocumentStream
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A document parsed by a [`DocumentStream`].
+pub struct ParsedDocument {
+    pub dom: Dom,
+    /// The raw body, kept so it can be decoded again if a late `<meta>`
+    /// declares a different encoding.
+    pub bytes: Vec<u8>,
+    pub encoding: EncodingLabel,
+    pub confidence: Confidence,
+    /// Preloaded subresources, keyed by resolved URL.
+    pub preloaded: HashMap<String, Vec<u8>>,
+    /// The windows the document's scripts could reach, its own (now
+    /// empty) first.
+    pub host: ScriptHost,
+}
+
+/// An HTML document being parsed while it downloads.
+pub struct DocumentStream {
+    url: String,
+    bytes: Vec<u8>,
+    content_type: Option<String>,
+    /// Whether the response headers have been looked at.
+    headers_seen: bool,
+    /// Set once the encoding is known; until then bytes are only buffered.
+    decoder: Option<(StreamDecoder, Confidence)>,
+    parser: Parser,
+    scanner: PreloadScanner,
+    preloader: Preloader,
+    /// What scripts run against. The document itself stays in the parser
+    /// and is only lent to `host.windows[0]` while a script runs.
+    host: ScriptHost,
+    scripts_enabled: bool,
+}
+
+impl DocumentStream {
+    pub fn new(url: &str, preloader: Preloader) -> Self {
+        Self {
+            url: url.to_string(),
+            bytes: Vec::new(),
+            content_type: None,
+            headers_seen: false,
+            decoder: None,
+            parser: Parser::new(),
+            scanner: PreloadScanner::new(),
+            preloader,
+            host: ScriptHost::new(Dom::default(), frames::origin_of(url)),
+            scripts_enabled: true,
+        }
+    }
+
+    /// Run scripts against `host` instead of a lone top-level window, for
+    /// a document in a frame. Its first window's document is replaced.
+    pub fn set_script_host(&mut self, host: ScriptHost) {
+        self.host = host;
+    }
+
+    /// Parse without running scripts, for a sandboxed frame.
+    pub fn disable_scripts(&mut self) {
+        self.scripts_enabled = false;
+    }
+
+    /// Take the next piece of the response body.
+    pub fn feed(&mut self, headers: &[(String, String)], bytes: &[u8]) {
+        if !self.headers_seen {
+            self.headers_seen = true;
+            self.content_type = headers
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                .map(|(_, value)| value.clone());
+            for link in net::links_from_headers(headers) {
+                if link.has_rel("preload") || link.has_rel("stylesheet") {
+                    self.preloader.request(&resolve_url(&link.target, &self.url));
+                }
+            }
+        }
+        self.bytes.extend_from_slice(bytes);
+        let text = match &mut self.decoder {
+            Some((decoder, _)) => decoder.decode(bytes),
+            None => {
+                let Some((encoding, confidence)) =
+                    early_encoding(&self.bytes, self.content_type.as_deref())
+                else {
+                    return;
+                };
+                let mut decoder = StreamDecoder::new(encoding);
+                let text = decoder.decode(&self.bytes);
+                self.decoder = Some((decoder, confidence));
+                text
+            }
+        };
+        self.push_text(&text);
+    }
+
+    /// Take document text that is already decoded (e.g. when re-parsing).
+    pub fn feed_text(&mut self, text: &str) {
+        if self.decoder.is_none() {
+            self.decoder = Some((StreamDecoder::new(EncodingLabel::Utf8), Confidence::Certain));
+        }
+        self.push_text(text);
+    }
+
+    /// The tree parsed so far.
+    pub fn dom(&self) -> &Dom {
+        self.parser.dom()
+    }
+
+    /// Whether the parser has put anything into `<body>` yet, i.e. whether
+    /// painting the partial document would show something.
+    pub fn has_body_content(&self) -> bool {
+        let dom = self.parser.dom();
+        dom.get_elements_by_tag(crate::browser::DOC_ROOT, "body")
+            .first()
+            .is_some_and(|&body| !dom.children(body).is_empty())
+    }
+
+    /// Parse the rest of the document and wait for outstanding preloads.
+    pub fn finish(mut self) -> ParsedDocument {
+        let text = match &mut self.decoder {
+            Some((decoder, _)) => decoder.finish(),
+            None => {
+                // Too short to prescan, or no declared encoding: sniff the
+                // whole body.
+                let (encoding, confidence) =
+                    encoding::sniff_encoding(&self.bytes, self.content_type.as_deref());
+                self.decoder = Some((StreamDecoder::new(encoding), confidence));
+                encoding::decode_to_utf8(&self.bytes, encoding)
+            }
+        };
+        self.push_text(&text);
+        for request in self.scanner.end() {
+            self.preload(&request);
+        }
+        self.parser.end();
+        self.run();
+
+        let (decoder, confidence) = self.decoder.expect("encoding decided above");
+        ParsedDocument {
+            dom: self.parser.into_dom(),
+            bytes: self.bytes,
+            encoding: decoder.encoding(),
+            confidence,
+            preloaded: self.preloader.finish(),
+            host: self.host,
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        for request in self.scanner.feed(text) {
+            self.preload(&request);
+        }
+        self.parser.feed(text);
+        self.run();
+    }
+
+    fn preload(&mut self, request: &PreloadRequest) {
+        let base = match &request.base {
+            Some(base) => resolve_url(base, &self.url),
+            None => self.url.clone(),
+        };
+        self.preloader.request(&resolve_url(&request.url, &base));
+    }
+
+    /// Parse as far as the input allows, running scripts as they close.
+    fn run(&mut self) {
+        while let ParseStatus::Script(script) = self.parser.run() {
+            if self.scripts_enabled {
+                self.run_script(script);
+            }
+        }
+    }
+
+    fn run_script(&mut self, script: NodeId) {
+        let dom = self.parser.dom();
+        let src = dom
+            .nodes
+            .get(script)
+            .and_then(|n| n.as_element())
+            .and_then(|e| e.attrs.iter().find(|a| a.name == "src"))
+            .map(|a| a.value.trim().to_string());
+        let source = match src {
+            Some(src) if src.is_empty() => return,
+            Some(src) => {
+                let url = resolve_url(&src, &document_base_url(dom, &self.url));
+                self.preloader.request(&url);
+                match self.preloader.take(&url) {
+                    Some(Ok(bytes)) => String::from_utf8_lossy(&bytes).into_owned(),
+                    Some(Err(e)) => {
+                        eprintln!("  ⚠ Failed to fetch script {}: {}", url, e);
+                        return;
+                    }
+                    None => return,
+                }
+            }
+            None => dom
+                .children(script)
+                .into_iter()
+                .filter_map(|child| match &dom.nodes.get(child)?.data {
+                    NodeData::Text { data } => Some(data.as_str()),
+                    _ => None,
+                })
+                .collect(),
+        };
+        self.host.windows[0].dom = std::mem::take(self.parser.dom_mut());
+        let written = run_js_in(&source, &mut self.host);
+        *self.parser.dom_mut() = std::mem::take(&mut self.host.windows[0].dom);
+        if !written.is_empty() {
+            self.parser.document_write(&written);
+        }
+    }
+}
+
+/// The encoding of a document from the start of its body, if it can be
+/// known before the whole body has arrived: from a BOM, the `Content-Type`
+/// header, or a `<meta>` within the prescan window. Otherwise the encoding
+/// is guessed from the complete body in [`DocumentStream::finish`].
+fn early_encoding(bytes: &[u8], content_type: Option<&str>) -> Option<(EncodingLabel, Confidence)> {
+    if let Some(encoding) = encoding::bom_sniff(bytes)
+        .or_else(|| content_type.and_then(encoding::charset_from_content_type))
+    {
+        // Wait for enough bytes to tell a UTF-16 BOM from a UTF-8 one.
+        return (bytes.len() >= 3).then_some((encoding, Confidence::Certain));
+    }
+    if bytes.len() < encoding::PRESCAN_LEN {
+        return None;
+    }
+    encoding::prescan(bytes).map(|encoding| (encoding, Confidence::Tentative))
+}
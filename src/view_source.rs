@@ -0,0 +1,617 @@
+//! `view-source:` pages — a response body exactly as the server sent it,
+//! with line numbers, syntax highlighting and the parser's complaints.
+//!
+//! The markup is read by the same tokenizer and tree builder that parse
+//! pages, so the highlighting follows the real tokenization: a `</script>`
+//! inside a JS string ends the script here too. Text inside `<style>` goes
+//! through the CSS tokenizer and text inside `<script>` through the JS
+//! lexer. Parse errors are listed under the line they occur on, with their
+//! line and column.
+
+use css::token::{CssToken, CssTokenizer};
+use html::token::{HtmlToken, SourceError};
+use html::{Tokenizer, TreeBuilder};
+use js_lexer::{JsToken, Keyword, Lexer};
+
+use crate::browser::resolve_url;
+
+/// URL prefix that shows the source of the URL after it.
+pub const SCHEME: &str = "view-source:";
+
+/// What a stretch of source is; each kind is a CSS class on the page.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Text,
+    /// `<`, `</`, `/>` and `>` around a tag.
+    Punctuation,
+    TagName,
+    AttrName,
+    AttrValue,
+    Comment,
+    Doctype,
+    Entity,
+    /// CSS at-keywords and JS keywords and literals.
+    Keyword,
+    /// A CSS property name.
+    Property,
+    /// A CSS function name.
+    Function,
+    String,
+    Number,
+    /// Something the JS lexer could not read.
+    Invalid,
+}
+
+impl Kind {
+    fn class(self) -> Option<&'static str> {
+        Some(match self {
+            Kind::Text => return None,
+            Kind::Punctuation => "p",
+            Kind::TagName => "tag",
+            Kind::AttrName => "attr",
+            Kind::AttrValue => "val",
+            Kind::Comment => "com",
+            Kind::Doctype => "doc",
+            Kind::Entity => "ent",
+            Kind::Keyword => "kw",
+            Kind::Property => "prop",
+            Kind::Function => "fn",
+            Kind::String => "str",
+            Kind::Number => "num",
+            Kind::Invalid => "bad",
+        })
+    }
+}
+
+/// A highlighted range of characters, optionally linking somewhere.
+#[derive(Clone, Debug, PartialEq)]
+struct Span {
+    start: usize,
+    end: usize,
+    kind: Kind,
+    link: Option<String>,
+}
+
+/// Which tokenizer the text of the current element belongs to.
+#[derive(Clone, Copy, PartialEq)]
+enum TextKind {
+    Markup,
+    Css,
+    Js,
+}
+
+/// Attributes whose values are URLs, linked to their own source.
+const URL_ATTRIBUTES: [&str; 7] = ["href", "src", "action", "formaction", "poster", "cite", "data"];
+
+/// The page shown for `view-source:<url>`. `source` is the decoded response
+/// body and `url` the address it came from, which relative links in it are
+/// resolved against.
+pub fn view_source_html(url: &str, source: &str) -> String {
+    let chars = normalize_newlines(source);
+    let (spans, errors) = highlight(&chars, url);
+
+    let mut rows = String::new();
+    let mut errors = errors.into_iter().peekable();
+    let mut line = 1;
+    let mut line_start = 0;
+    let mut code = String::new();
+    let mut flush_line = |rows: &mut String, code: &mut String, line: usize, line_start: usize, end: usize| {
+        if code.is_empty() {
+            code.push(' ');
+        }
+        rows.push_str(&format!("<tr><td class=\"ln\">{line}</td><td class=\"src\">{code}</td></tr>\n"));
+        code.clear();
+        while let Some(error) = errors.next_if(|e| e.offset <= end) {
+            let column = error.offset.saturating_sub(line_start) + 1;
+            rows.push_str(&format!(
+                "<tr class=\"error\"><td></td><td class=\"msg\">⚠ {line}:{column} {}</td></tr>\n",
+                error.code
+            ));
+        }
+    };
+    for span in &spans {
+        let mut piece = String::new();
+        for (i, &c) in chars.iter().enumerate().take(span.end).skip(span.start) {
+            if c == '\n' {
+                push_piece(&mut code, &piece, span);
+                piece.clear();
+                flush_line(&mut rows, &mut code, line, line_start, i);
+                line += 1;
+                line_start = i + 1;
+            } else {
+                push_escaped(&mut piece, c);
+            }
+        }
+        push_piece(&mut code, &piece, span);
+    }
+    flush_line(&mut rows, &mut code, line, line_start, usize::MAX);
+
+    let title = escape(&format!("{SCHEME}{url}"));
+    format!(
+        r#"<html><head><title>{title}</title><style>
+body {{ margin: 0; background: #ffffff; color: #000; font-family: monospace; font-size: 13px; }}
+table {{ border-collapse: collapse; }}
+td {{ padding: 0 8px; vertical-align: top; line-height: 18px; }}
+td.ln {{ color: #999; text-align: right; border-right: 1px solid #ddd; }}
+td.src {{ white-space: pre-wrap; }}
+tr.error td.msg {{ color: #c5221f; background: #fce8e6; }}
+.p {{ color: #881280; }}
+.tag {{ color: #881280; }}
+.attr {{ color: #994500; }}
+.val {{ color: #1a1aa6; }}
+.com {{ color: #236e25; }}
+.doc {{ color: #808080; }}
+.ent {{ color: #b1b100; }}
+.kw {{ color: #aa0d91; }}
+.prop {{ color: #c80000; }}
+.fn {{ color: #3f6e74; }}
+.str {{ color: #c41a16; }}
+.num {{ color: #1c00cf; }}
+.bad {{ color: #ffffff; background: #c5221f; }}
+</style></head><body><table>
+{rows}</table></body></html>"#
+    )
+}
+
+/// Source text with CR LF and lone CRs read as LF, as the tokenizer sees
+/// it, so its offsets index this directly.
+fn normalize_newlines(source: &str) -> Vec<char> {
+    let mut chars = Vec::with_capacity(source.len());
+    let mut after_cr = false;
+    for c in source.chars() {
+        match c {
+            '\r' => chars.push('\n'),
+            '\n' if after_cr => {}
+            c => chars.push(c),
+        }
+        after_cr = c == '\r';
+    }
+    chars
+}
+
+fn push_piece(code: &mut String, piece: &str, span: &Span) {
+    if piece.is_empty() {
+        return;
+    }
+    match (&span.link, span.kind.class()) {
+        (Some(link), class) => {
+            let class = class.unwrap_or_default();
+            code.push_str(&format!("<a class=\"{class}\" href=\"{}\">{piece}</a>", escape(link)));
+        }
+        (None, Some(class)) => code.push_str(&format!("<span class=\"{class}\">{piece}</span>")),
+        (None, None) => code.push_str(piece),
+    }
+}
+
+fn push_escaped(out: &mut String, c: char) {
+    match c {
+        '&' => out.push_str("&amp;"),
+        '<' => out.push_str("&lt;"),
+        '>' => out.push_str("&gt;"),
+        '"' => out.push_str("&quot;"),
+        c => out.push(c),
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        push_escaped(&mut out, c);
+    }
+    out
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Markup
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Split `chars` into highlighted spans covering all of it, and collect the
+/// tokenizer's and tree builder's parse errors in source order.
+fn highlight(chars: &[char], base_url: &str) -> (Vec<Span>, Vec<SourceError>) {
+    let source: String = chars.iter().collect();
+    let mut tokenizer = Tokenizer::new(&source);
+    let mut builder = TreeBuilder::new();
+    tokenizer.set_error_reporting(true);
+    builder.set_error_reporting(true);
+
+    let mut spans = Vec::new();
+    let mut errors = Vec::new();
+    let mut text_start = 0;
+    let mut text_kind = TextKind::Markup;
+    loop {
+        let start = tokenizer.position();
+        let token = tokenizer.next_token();
+        let end = tokenizer.position();
+        if !matches!(token, HtmlToken::Character(_)) {
+            match text_kind {
+                TextKind::Markup => markup_text_spans(chars, text_start, start, &mut spans),
+                TextKind::Css => css_spans(chars, text_start, start, &mut spans),
+                TextKind::Js => js_spans(chars, text_start, start, &mut spans),
+            }
+            text_kind = TextKind::Markup;
+            text_start = end;
+            token_spans(chars, &token, start, end, base_url, &mut spans);
+        }
+
+        let eof = token == HtmlToken::EOF;
+        builder.set_position(start);
+        builder.process_token(token);
+        if let Some(tag) = builder.pending_rawtext_tag.take() {
+            text_kind = match tag.as_str() {
+                "style" => TextKind::Css,
+                "script" => TextKind::Js,
+                _ => TextKind::Markup,
+            };
+            tokenizer.switch_to_rawtext(&tag);
+        }
+        tokenizer.set_cdata_allowed(builder.in_foreign_content());
+        errors.extend(tokenizer.take_errors());
+        errors.extend(builder.take_errors());
+        if eof {
+            break;
+        }
+    }
+    errors.sort_by_key(|e| e.offset);
+    (spans, errors)
+}
+
+fn push_span(spans: &mut Vec<Span>, start: usize, end: usize, kind: Kind) {
+    if start < end {
+        spans.push(Span { start, end, kind, link: None });
+    }
+}
+
+/// Text between tags, with character references picked out.
+fn markup_text_spans(chars: &[char], start: usize, end: usize, spans: &mut Vec<Span>) {
+    let mut plain = start;
+    let mut i = start;
+    while i < end {
+        if chars[i] == '&' {
+            let name = chars[i + 1..end]
+                .iter()
+                .take_while(|c| c.is_ascii_alphanumeric() || **c == '#')
+                .count();
+            if name > 0 && chars.get(i + 1 + name) == Some(&';') && i + name + 2 <= end {
+                push_span(spans, plain, i, Kind::Text);
+                push_span(spans, i, i + name + 2, Kind::Entity);
+                i += name + 2;
+                plain = i;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    push_span(spans, plain, end, Kind::Text);
+}
+
+/// Spans for the source of a non-character token.
+fn token_spans(chars: &[char], token: &HtmlToken, start: usize, end: usize, base_url: &str, spans: &mut Vec<Span>) {
+    let text = &chars[start..end];
+    match token {
+        HtmlToken::Doctype { .. } => push_span(spans, start, end, Kind::Doctype),
+        HtmlToken::Comment(_) => push_span(spans, start, end, Kind::Comment),
+        HtmlToken::StartTag { attrs, .. } => tag_spans(chars, start, end, attrs, base_url, spans),
+        HtmlToken::EndTag { .. } => tag_spans(chars, start, end, &[], base_url, spans),
+        // An unfinished construct the end of the input cut off.
+        HtmlToken::EOF if text.starts_with(&['<', '!']) => push_span(spans, start, end, Kind::Comment),
+        HtmlToken::EOF if text.first() == Some(&'<') => tag_spans(chars, start, end, &[], base_url, spans),
+        _ => push_span(spans, start, end, Kind::Text),
+    }
+}
+
+/// Spans for the source of a tag: punctuation, name, attribute names and
+/// values. `attrs` are the tokenizer's (decoded) attributes, which give the
+/// URLs that values link to.
+fn tag_spans(
+    chars: &[char],
+    start: usize,
+    end: usize,
+    attrs: &[(String, String)],
+    base_url: &str,
+    spans: &mut Vec<Span>,
+) {
+    let is_space = |c: char| matches!(c, '\t' | '\n' | '\x0C' | ' ');
+    let mut i = start + 1;
+    if chars.get(i) == Some(&'/') {
+        i += 1;
+    }
+    push_span(spans, start, i, Kind::Punctuation);
+    let name_start = i;
+    while i < end && !is_space(chars[i]) && chars[i] != '/' && chars[i] != '>' {
+        i += 1;
+    }
+    push_span(spans, name_start, i, Kind::TagName);
+
+    while i < end {
+        let c = chars[i];
+        if is_space(c) {
+            let from = i;
+            while i < end && is_space(chars[i]) {
+                i += 1;
+            }
+            push_span(spans, from, i, Kind::Text);
+        } else if c == '/' || c == '>' {
+            push_span(spans, i, i + 1, Kind::Punctuation);
+            i += 1;
+        } else {
+            // An attribute: name, then optionally `=` and a value. A
+            // leading `=` is part of the name.
+            let from = i;
+            i += 1;
+            while i < end && !is_space(chars[i]) && !matches!(chars[i], '/' | '>' | '=') {
+                i += 1;
+            }
+            push_span(spans, from, i, Kind::AttrName);
+            let name: String = chars[from..i].iter().collect::<String>().to_ascii_lowercase();
+            let mut j = i;
+            while j < end && is_space(chars[j]) {
+                j += 1;
+            }
+            if j >= end || chars[j] != '=' {
+                continue;
+            }
+            j += 1;
+            while j < end && is_space(chars[j]) {
+                j += 1;
+            }
+            push_span(spans, i, j, Kind::Text);
+            i = j;
+            let value_start = i;
+            match chars.get(i) {
+                Some(&quote @ ('"' | '\'')) if i < end => {
+                    i += 1;
+                    while i < end && chars[i] != quote {
+                        i += 1;
+                    }
+                    i = (i + 1).min(end);
+                }
+                _ => {
+                    while i < end && !is_space(chars[i]) && chars[i] != '>' {
+                        i += 1;
+                    }
+                }
+            }
+            let link = URL_ATTRIBUTES
+                .contains(&name.as_str())
+                .then(|| attrs.iter().find(|(n, _)| *n == name))
+                .flatten()
+                .and_then(|(_, value)| link_target(value, base_url));
+            if value_start < i {
+                spans.push(Span { start: value_start, end: i, kind: Kind::AttrValue, link });
+            }
+        }
+    }
+}
+
+/// Where a URL attribute value links to: the source of the resource it
+/// names. Fragments and non-HTTP schemes (`javascript:`, `mailto:`, …) are
+/// not linked.
+fn link_target(value: &str, base_url: &str) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() || value.starts_with('#') {
+        return None;
+    }
+    let scheme = value
+        .split_once(':')
+        .map(|(scheme, _)| scheme)
+        .filter(|s| s.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')));
+    if let Some(scheme) = scheme
+        && !scheme.eq_ignore_ascii_case("http")
+        && !scheme.eq_ignore_ascii_case("https")
+    {
+        return None;
+    }
+    Some(format!("{SCHEME}{}", resolve_url(value, base_url)))
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CSS and JavaScript
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Peel the comments (and, for JS, whitespace) the tokenizers skip in front
+/// of a token off `start..end`, returning where the token itself starts.
+fn leading_trivia(chars: &[char], start: usize, end: usize, js: bool, spans: &mut Vec<Span>) -> usize {
+    let mut i = start;
+    loop {
+        let rest = &chars[i..end];
+        if rest.starts_with(&['/', '*']) {
+            let close = rest[2..].windows(2).position(|w| w == ['*', '/']).map_or(rest.len(), |p| p + 4);
+            push_span(spans, i, i + close, Kind::Comment);
+            i += close;
+        } else if js && rest.starts_with(&['/', '/']) {
+            let close = rest.iter().position(|&c| c == '\n').unwrap_or(rest.len());
+            push_span(spans, i, i + close, Kind::Comment);
+            i += close;
+        } else if js && rest.first().is_some_and(|c| c.is_whitespace()) {
+            let run = rest.iter().take_while(|c| c.is_whitespace()).count();
+            push_span(spans, i, i + run, Kind::Text);
+            i += run;
+        } else {
+            return i;
+        }
+    }
+}
+
+/// The contents of a `<style>` element.
+fn css_spans(chars: &[char], start: usize, end: usize, spans: &mut Vec<Span>) {
+    let source: String = chars[start..end].iter().collect();
+    let mut tokenizer = CssTokenizer::new(&source);
+    let mut tokens = Vec::new();
+    loop {
+        let from = start + tokenizer.position();
+        let token = tokenizer.next_token();
+        let to = start + tokenizer.position();
+        let token_start = leading_trivia(chars, from, to, false, spans);
+        if token == CssToken::EOF {
+            break;
+        }
+        tokens.push((token, token_start, to));
+    }
+
+    let mut depth = 0usize;
+    for (i, (token, from, to)) in tokens.iter().enumerate() {
+        let kind = match token {
+            CssToken::LBrace => {
+                depth += 1;
+                Kind::Text
+            }
+            CssToken::RBrace => {
+                depth = depth.saturating_sub(1);
+                Kind::Text
+            }
+            CssToken::AtKeyword(_) => Kind::Keyword,
+            CssToken::Function(_) => Kind::Function,
+            CssToken::String(_) | CssToken::Url(_) => Kind::String,
+            CssToken::Number { .. } | CssToken::Percentage(_) | CssToken::Dimension { .. } => Kind::Number,
+            CssToken::Hash { .. } if depth > 0 => Kind::Number,
+            CssToken::CDO | CssToken::CDC => Kind::Comment,
+            CssToken::Ident(_) if depth > 0 => {
+                let next = tokens[i + 1..].iter().find(|(t, ..)| *t != CssToken::Whitespace);
+                if next.is_some_and(|(t, ..)| *t == CssToken::Colon) {
+                    Kind::Property
+                } else {
+                    Kind::Text
+                }
+            }
+            _ => Kind::Text,
+        };
+        push_span(spans, *from, *to, kind);
+    }
+}
+
+/// The contents of a `<script>` element.
+fn js_spans(chars: &[char], start: usize, end: usize, spans: &mut Vec<Span>) {
+    let source: String = chars[start..end].iter().collect();
+    let mut lexer = Lexer::new(&source);
+    let mut previous: Option<JsToken> = None;
+    loop {
+        let from = start + lexer.position();
+        let mut result = lexer.next_token();
+        // A `/` where no operand came before starts a regular expression.
+        if matches!(result, Ok(JsToken::Slash | JsToken::SlashAssign)) && !ends_operand(previous.as_ref()) {
+            result = lexer.reread_slash_as_regexp();
+        }
+        let to = start + lexer.position();
+        let token_start = leading_trivia(chars, from, to, true, spans);
+        let token = match result {
+            Ok(JsToken::Eof) => break,
+            Ok(token) => token,
+            Err(_) if to > from => {
+                push_span(spans, token_start, to, Kind::Invalid);
+                previous = None;
+                continue;
+            }
+            // No progress: leave the rest unhighlighted.
+            Err(_) => {
+                push_span(spans, from, end, Kind::Text);
+                break;
+            }
+        };
+        let kind = match &token {
+            JsToken::Keyword(_) | JsToken::Null | JsToken::True | JsToken::False => Kind::Keyword,
+            JsToken::String(_)
+            | JsToken::TemplateHead(_)
+            | JsToken::TemplateMiddle(_)
+            | JsToken::TemplateTail(_)
+            | JsToken::RegExp { .. } => Kind::String,
+            JsToken::Number(_) => Kind::Number,
+            _ => Kind::Text,
+        };
+        push_span(spans, token_start, to, kind);
+        previous = Some(token);
+    }
+}
+
+/// Whether a `/` after `token` divides (rather than starting a regular
+/// expression).
+fn ends_operand(token: Option<&JsToken>) -> bool {
+    matches!(
+        token,
+        Some(
+            JsToken::Identifier(_)
+                | JsToken::Number(_)
+                | JsToken::String(_)
+                | JsToken::TemplateTail(_)
+                | JsToken::RegExp { .. }
+                | JsToken::Null
+                | JsToken::True
+                | JsToken::False
+                | JsToken::Keyword(Keyword::This | Keyword::Super)
+                | JsToken::RParen
+                | JsToken::RBracket
+                | JsToken::RBrace
+                | JsToken::PlusPlus
+                | JsToken::MinusMinus
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(source: &str) -> Vec<(String, Kind)> {
+        let chars = normalize_newlines(source);
+        let (spans, _) = highlight(&chars, "https://example.com/dir/page.html");
+        spans
+            .iter()
+            .filter(|s| s.kind != Kind::Text)
+            .map(|s| (chars[s.start..s.end].iter().collect(), s.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_spans_cover_the_source_in_order() {
+        let source = "<!DOCTYPE html>\r\n<p class=x>a &amp; b<!-- c --></p>";
+        let chars = normalize_newlines(source);
+        let (spans, _) = highlight(&chars, "https://example.com/");
+        let mut next = 0;
+        for span in &spans {
+            assert_eq!(span.start, next, "{spans:?}");
+            next = span.end;
+        }
+        assert_eq!(next, chars.len());
+    }
+
+    #[test]
+    fn test_markup_css_and_js_are_highlighted() {
+        let highlighted = kinds(
+            "<a href='x.html'>&lt;</a><style>p { color: red } /* c */</style>\
+             <script>if (a / 2) x = /re/g; // done\n</script>",
+        );
+        let expected = [
+            ("<", Kind::Punctuation),
+            ("a", Kind::TagName),
+            ("href", Kind::AttrName),
+            ("'x.html'", Kind::AttrValue),
+            (">", Kind::Punctuation),
+            ("&lt;", Kind::Entity),
+            ("color", Kind::Property),
+            ("/* c */", Kind::Comment),
+            ("if", Kind::Keyword),
+            ("2", Kind::Number),
+            ("/re/g", Kind::String),
+            ("// done", Kind::Comment),
+        ];
+        for (text, kind) in expected {
+            assert!(highlighted.contains(&(text.to_string(), kind)), "{text} {kind:?}: {highlighted:?}");
+        }
+    }
+
+    #[test]
+    fn test_url_attributes_link_to_their_source() {
+        let chars = normalize_newlines("<a href=\"x.html\">a</a><a href=\"javascript:go()\">b</a>");
+        let (spans, _) = highlight(&chars, "https://example.com/dir/page.html");
+        let links: Vec<_> = spans.iter().filter_map(|s| s.link.as_deref()).collect();
+        assert_eq!(links, ["view-source:https://example.com/dir/x.html"]);
+    }
+
+    #[test]
+    fn test_parse_errors_appear_under_their_line() {
+        let page = view_source_html("https://example.com/", "<p>\n<b><i>x</b>");
+        assert!(page.contains("⚠ 1:1 missing-doctype"), "{page}");
+        assert!(page.contains("⚠ 2:8 misnested-formatting-element"), "{page}");
+        let second_line = page.find("<td class=\"ln\">2</td>").unwrap();
+        assert!(page.find("misnested").unwrap() > second_line);
+    }
+}